@@ -0,0 +1,71 @@
+use std::{io::Write, sync::Arc};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use zarrs::storage::{
+    store::MemoryStore, ListableStorageTraits, ReadableStorageTraits, StoreKey,
+    WritableStorageTraits,
+};
+use zarrs_zip::ZipStorageAdapter;
+
+/// Write an array to a zip file with stored (uncompressed) values, and return a store holding the zip file at `array.zip`.
+fn array_zip(size: u64) -> Arc<MemoryStore> {
+    let store = Arc::new(MemoryStore::new());
+    let array = zarrs::array::ArrayBuilder::new(
+        vec![size; 3],
+        zarrs::array::DataType::UInt8,
+        vec![32; 3].try_into().unwrap(),
+        zarrs::array::FillValue::from(0u8),
+    )
+    .build(store.clone(), "/")
+    .unwrap();
+    array.store_metadata().unwrap();
+    let data = vec![1u8; (size * size * size).try_into().unwrap()];
+    array
+        .store_array_subset_elements(&array.subset_all(), &data)
+        .unwrap();
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options =
+        zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    for key in store.list().unwrap() {
+        zip.start_file(key.as_str(), options).unwrap();
+        zip.write_all(&store.get(&key).unwrap().unwrap()).unwrap();
+    }
+    let zip = zip.finish().unwrap().into_inner();
+
+    let zip_store = Arc::new(MemoryStore::new());
+    zip_store
+        .set(&StoreKey::new("array.zip").unwrap(), zip.into())
+        .unwrap();
+    zip_store
+}
+
+fn array_read_all_zip(c: &mut Criterion) {
+    let mut group = c.benchmark_group("array_read_all_zip");
+    let size = 256u64;
+    let zip_store = array_zip(size);
+    group.throughput(Throughput::Bytes(size * size * size));
+    for max_readers in [1usize, 4, 16] {
+        let store = Arc::new(
+            ZipStorageAdapter::new(zip_store.clone(), StoreKey::new("array.zip").unwrap())
+                .unwrap()
+                .with_max_readers(max_readers),
+        );
+        let array = zarrs::array::Array::open(store, "/").unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(max_readers),
+            &max_readers,
+            |b, _| {
+                b.iter(|| {
+                    array
+                        .retrieve_array_subset_elements::<u8>(&array.subset_all())
+                        .unwrap()
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, array_read_all_zip);
+criterion_main!(benches);
@@ -9,7 +9,7 @@ use zarrs::array::{
         bytes_to_bytes::blosc::{BloscCompressor, BloscShuffleMode},
         ArrayToBytesCodecTraits, BloscCodec, BytesCodec, BytesToBytesCodecTraits, CodecOptions,
     },
-    BytesRepresentation, ChunkRepresentation, DataType, Element, Endianness,
+    BytesRepresentation, ChunkRepresentation, DataType, Element, Endianness, FillValue,
 };
 
 fn codec_bytes(c: &mut Criterion) {
@@ -34,7 +34,8 @@ fn codec_bytes(c: &mut Criterion) {
         .unwrap();
 
         let data = vec![0u8; size3.try_into().unwrap()];
-        let bytes = Element::into_array_bytes(&DataType::UInt8, &data).unwrap();
+        let bytes =
+            Element::into_array_bytes(&DataType::UInt8, &FillValue::from(0u8), &data).unwrap();
         group.throughput(Throughput::Bytes(size3));
         // encode and decode have the same implementation
         group.bench_function(BenchmarkId::new("encode_decode", size3), |b| {
@@ -23,6 +23,7 @@
 
 mod array_builder;
 mod array_bytes;
+mod array_copy;
 mod array_errors;
 mod array_metadata_options;
 mod array_representation;
@@ -30,19 +31,30 @@ mod bytes_representation;
 mod chunk_cache;
 pub mod chunk_grid;
 pub mod chunk_key_encoding;
+mod chunk_occupancy;
+mod chunk_validation;
 pub mod codec;
 pub mod concurrency;
 pub mod data_type;
+#[cfg(feature = "dlpack")]
+pub mod dlpack;
 mod element;
+mod element_cast;
 mod fill_value;
+mod partial_decoder_cache;
 pub mod storage_transformer;
 
 #[cfg(feature = "sharding")]
 mod array_sharded_ext;
 #[cfg(feature = "sharding")]
 mod array_sync_sharded_readable_ext;
+#[cfg(feature = "sharding")]
+mod array_sync_sharded_writer;
 
-use std::sync::Arc;
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+};
 
 pub use self::{
     array_builder::ArrayBuilder,
@@ -50,6 +62,7 @@ pub use self::{
         copy_fill_value_into, update_array_bytes, ArrayBytes, ArrayBytesError, RawBytes,
         RawBytesOffsets,
     },
+    array_copy::{copy_array, ArrayCopyError, ArrayCopyOptions, ArrayCopyProgressCallback},
     array_errors::{ArrayCreateError, ArrayError},
     array_metadata_options::ArrayMetadataOptions,
     array_representation::{
@@ -58,22 +71,28 @@ pub use self::{
     bytes_representation::BytesRepresentation,
     chunk_grid::ChunkGrid,
     chunk_key_encoding::{ChunkKeyEncoding, ChunkKeySeparator},
+    chunk_occupancy::ChunkOccupancy,
+    chunk_validation::ChunkValidation,
     codec::ArrayCodecTraits,
     codec::CodecChain,
     concurrency::RecommendedConcurrency,
     data_type::DataType,
     element::{Element, ElementFixedLength, ElementOwned},
+    element_cast::{ElementCast, ElementCastMode},
     fill_value::FillValue,
     storage_transformer::StorageTransformerChain,
 };
 pub use crate::metadata::v2::ArrayMetadataV2;
-use crate::metadata::v2_to_v3::ArrayMetadataV2ToV3ConversionError;
+use crate::metadata::v2_to_v3::{
+    ArrayMetadataV2ToV3ConversionError, ArrayMetadataV2ToV3ConversionOptions,
+};
 pub use crate::metadata::v3::{
     array::data_type::DataTypeSize,
     array::fill_value::FillValueMetadataV3,
     array::nan_representations::{ZARR_NAN_BF16, ZARR_NAN_F16, ZARR_NAN_F32, ZARR_NAN_F64},
     ArrayMetadataV3,
 };
+use crate::metadata::v3_to_v2::ArrayMetadataV3ToV2ConversionError;
 pub use crate::metadata::{ArrayMetadata, ArrayShape, ChunkShape, DimensionName, Endianness};
 
 /// An alias for [`FillValueMetadataV3`].
@@ -81,27 +100,42 @@ pub use crate::metadata::{ArrayMetadata, ArrayShape, ChunkShape, DimensionName,
 pub type FillValueMetadata = crate::metadata::v3::array::fill_value::FillValueMetadataV3;
 
 pub use chunk_cache::array_chunk_cache_ext_sync::ArrayChunkCacheExt;
+use chunk_cache::ArrayChunkCache;
 pub use chunk_cache::{
     chunk_cache_lru::*, ChunkCache, ChunkCacheType, ChunkCacheTypeDecoded, ChunkCacheTypeEncoded,
 };
+use codec::{options::CodecOptions, ArrayToBytesCodecTraits};
+use partial_decoder_cache::ArrayPartialDecoderLruCache;
 
 #[cfg(feature = "sharding")]
 pub use array_sharded_ext::ArrayShardedExt;
 #[cfg(feature = "sharding")]
 pub use array_sync_sharded_readable_ext::{ArrayShardedReadableExt, ArrayShardedReadableExtCache};
+#[cfg(feature = "sharding")]
+pub use array_sync_sharded_writer::ShardedArrayWriter;
 // TODO: Add AsyncArrayShardedReadableExt and AsyncArrayShardedReadableExtCache
 
 use crate::{
     array_subset::{ArraySubset, IncompatibleDimensionalityError},
-    config::MetadataConvertVersion,
-    metadata::{v2_to_v3::array_metadata_v2_to_v3, v3::AdditionalFields},
+    config::{global_config, MetadataConvertVersion},
+    metadata::{
+        v2::nczarr::nczarr_dimension_names,
+        v2_to_v3::{array_metadata_v2_to_v3, array_metadata_v2_to_v3_opt},
+        v3::AdditionalFields,
+        v3_to_v2::array_metadata_v3_to_v2,
+    },
     node::{data_key, NodePath},
-    storage::StoreKey,
+    storage::{storage_adapter::read_only::ReadOnlyStorageAdapter, Bytes, StoreKey, StorePrefix},
 };
 
 /// An ND index to an element in an array.
 pub type ArrayIndices = Vec<u64>;
 
+/// A decoded chunk yielded by [`Array::chunks_iter`]: its chunk grid indices, its array subset, and its bytes.
+///
+/// The array subset of a chunk at the edge of the array may extend beyond the array shape.
+pub type ArrayDecodedChunk = (ArrayIndices, ArraySubset, ArrayBytes<'static>);
+
 /// Convert a [`ChunkShape`] reference to an [`ArrayShape`].
 #[must_use]
 pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> ArrayShape {
@@ -147,6 +181,9 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 ///  - [`attributes`](Array::attributes) / [`attributes_mut`](Array::attributes_mut)
 ///  - [`dimension_names`](Array::dimension_names) / [`set_dimension_names`](Array::set_dimension_names)
 ///
+/// Attributes can also be updated without rewriting the rest of the stored metadata with [`mutate_attributes`](Array::mutate_attributes) / [`store_attributes`](Array::store_attributes).
+/// These preserve fields written by other tools, and fail if the stored metadata has otherwise changed.
+///
 /// ### `zarrs` Metadata
 /// By default, the `zarrs` version and a link to its source code is written to the `_zarrs` attribute in array metadata when calling [`store_metadata`](Array::store_metadata).
 /// Override this behaviour globally with [`Config::set_include_zarrs_metadata`](crate::config::Config::set_include_zarrs_metadata) or call [`store_metadata_opt`](Array::store_metadata_opt) with an explicit [`ArrayMetadataOptions`].
@@ -171,6 +208,7 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 ///    - [`erase_chunk`](Array::erase_chunk)
 ///    - [`erase_chunks`](Array::erase_chunks)
 ///  - [`[Async]ReadableWritableStorageTraits`](crate::storage::ReadableWritableStorageTraits): store operations requiring reading *and* writing
+///    - [`mutate_attributes`](Array::mutate_attributes) / [`store_attributes`](Array::store_attributes)
 ///    - [`store_chunk_subset`](Array::store_chunk_subset)
 ///    - [`store_array_subset`](Array::store_array_subset)
 ///    - [`partial_encoder`](Array::partial_encoder)
@@ -183,6 +221,11 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 ///   - Variants without the `_opt` suffix use default [`CodecOptions`](crate::array::codec::CodecOptions).
 ///   - **Experimental**: `async_` prefix variants can be used with async stores (requires `async` feature).
 ///
+/// The element type of `_elements` and `_ndarray` variants must be compatible with the array data type (see [`Element`] and [`ElementOwned`]).
+/// Variable-length data types are supported, e.g. elements of the `string` data type can be stored as `&str` or [`String`] and retrieved as [`String`], and elements of the `bytes` data type can be stored as `&[u8]` or `Vec<u8>` and retrieved as `Vec<u8>`.
+/// Elements of type [`Option<T>`] map [`None`] to the fill value of the array, which is useful if the fill value is a sentinel for missing data.
+/// Elements of the `float16` and `bfloat16` data types are [`half::f16`] and [`half::bf16`], and are stored and retrieved bitwise (e.g. NaN payloads and subnormal values are preserved).
+///
 /// Additional methods are offered by extension traits:
 ///  - [`ArrayShardedExt`] and [`ArrayShardedReadableExt`]: see [Reading Sharded Arrays](#reading-sharded-arrays)
 ///  - [`ArrayChunkCacheExt`]: see [Chunk Caching](#chunk-caching)
@@ -298,6 +341,10 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 ///
 /// `zarrs` consumers can create custom caches by implementing the [`ChunkCache`] trait.
 ///
+/// Alternatively, a decoded chunk cache can be attached to an array with [`with_chunk_cache`](Array::with_chunk_cache).
+/// The sync retrieve methods then use the cache, and storing or erasing a chunk through the array removes it from the cache.
+/// A thread-local cache is not recommended for this purpose, since chunks retrieved in parallel are cached on different threads.
+///
 /// Chunk caching is likely to be effective for remote stores where redundant retrievals are costly.
 /// Chunk caching may not outperform disk caching with a filesystem store.
 /// The above caches use internal locking to support multithreading, which has a performance overhead.
@@ -315,6 +362,7 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 ///
 /// The [`ArrayShardedExt`] trait provides additional methods to [`Array`] to query if an array is sharded and retrieve the inner chunk shape.
 /// Additionally, the *inner chunk grid* can be queried, which is a [`ChunkGrid`](chunk_grid) where chunk indices refer to inner chunks rather than shards.
+/// Inner chunk indices can be converted to shard indices with [`inner_chunk_indices_to_shard`](ArrayShardedExt::inner_chunk_indices_to_shard), and the inner chunks intersecting an array subset are returned by [`array_subset_to_inner_chunks`](ArrayShardedExt::array_subset_to_inner_chunks).
 ///
 /// The [`ArrayShardedReadableExt`] trait adds [`Array`] methods to conveniently and efficiently access the data in a sharded array (with `_elements` and `_ndarray` variants):
 ///  - [`retrieve_inner_chunk_opt`](ArrayShardedReadableExt::retrieve_inner_chunk_opt)
@@ -324,6 +372,10 @@ pub fn chunk_shape_to_array_shape(chunk_shape: &[std::num::NonZeroU64]) -> Array
 /// For unsharded arrays, these methods gracefully fallback to referencing standard chunks.
 /// Each method has a `cache` parameter ([`ArrayShardedReadableExtCache`]) that stores shard indexes so that they do not have to be repeatedly retrieved and decoded.
 ///
+/// ## Writing Sharded Arrays
+/// Storing the inner chunks of a shard one at a time requires the shard to be repeatedly read, decoded, encoded, and written.
+/// A [`ShardedArrayWriter`] stages inner chunks in memory and stores the whole shard with a single write when finalised.
+///
 /// ## Parallelism and Concurrency
 /// ### Sync API
 /// Codecs run in parallel using a dedicated threadpool.
@@ -364,12 +416,24 @@ pub struct Array<TStorage: ?Sized> {
     // attributes: serde_json::Map<String, serde_json::Value>,
     /// An optional list of storage transformers.
     storage_transformers: StorageTransformerChain,
+    /// The storage transformers applied to the storage, including inherited storage transformers.
+    applied_storage_transformers: StorageTransformerChain,
     /// An optional list of dimension names.
     dimension_names: Option<Vec<DimensionName>>,
     // /// Additional fields annotated with `"must_understand": false`.
     // additional_fields: AdditionalFields,
     /// Metadata used to create the array
     metadata: ArrayMetadata,
+    /// An optional decoded chunk cache.
+    chunk_cache: Option<ArrayChunkCache>,
+    /// An optional partial decoder cache.
+    partial_decoder_cache: Option<Arc<ArrayPartialDecoderLruCache>>,
+    /// The indices of chunks with a corrupt shard index that were substituted with the fill value.
+    corrupt_chunks: Mutex<BTreeSet<ArrayIndices>>,
+    /// Overrides [`Config::validate_checksums`](crate::config::Config::validate_checksums) for this array.
+    validate_checksums: Option<bool>,
+    /// Overrides [`Config::store_empty_chunks`](crate::config::Config::store_empty_chunks) for this array.
+    store_empty_chunks: Option<bool>,
 }
 
 impl<TStorage: ?Sized> Array<TStorage> {
@@ -388,11 +452,16 @@ impl<TStorage: ?Sized> Array<TStorage> {
         let path = NodePath::new(path)?;
 
         // Convert V2 metadata to V3 if it is a compatible subset
-        let metadata_v3 = match &metadata {
+        let mut metadata_v3 = match &metadata {
             ArrayMetadata::V3(v3) => Ok(v3.clone()),
             ArrayMetadata::V2(v2) => array_metadata_v2_to_v3(v2)
                 .map_err(|err| ArrayCreateError::UnsupportedZarrV2Array(err.to_string())),
         }?;
+        if let ArrayMetadata::V2(v2) = &metadata {
+            if metadata_v3.dimension_names.is_none() && global_config().nczarr_compatibility() {
+                metadata_v3.dimension_names = nczarr_dimension_names(v2);
+            }
+        }
 
         let data_type = DataType::from_metadata(&metadata_v3.data_type)
             .map_err(ArrayCreateError::DataTypeCreateError)?;
@@ -424,6 +493,17 @@ impl<TStorage: ?Sized> Array<TStorage> {
                 ));
             }
         }
+        if let Ok(Some(chunk_shape)) =
+            chunk_grid.chunk_shape(&vec![0; metadata_v3.shape.len()], &metadata_v3.shape)
+        {
+            if let Ok(chunk_representation) = ChunkRepresentation::new(
+                chunk_shape.to_vec(),
+                data_type.clone(),
+                fill_value.clone(),
+            ) {
+                validate_codecs(&codecs, &chunk_representation)?;
+            }
+        }
 
         Ok(Self {
             storage,
@@ -436,9 +516,15 @@ impl<TStorage: ?Sized> Array<TStorage> {
             codecs,
             // attributes: metadata_v3.attributes,
             // additional_fields: metadata_v3.additional_fields,
+            applied_storage_transformers: storage_transformers.clone(),
             storage_transformers,
             dimension_names: metadata_v3.dimension_names,
             metadata,
+            chunk_cache: None,
+            partial_decoder_cache: None,
+            corrupt_chunks: Mutex::default(),
+            validate_checksums: None,
+            store_empty_chunks: None,
         })
     }
 
@@ -493,6 +579,34 @@ impl<TStorage: ?Sized> Array<TStorage> {
         &self.codecs
     }
 
+    /// Explain how the codecs encode the chunk at the origin of the array.
+    ///
+    /// See [`CodecChain::explain`].
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if the chunk representation cannot be determined or is not supported by the codecs.
+    pub fn explain_codecs(&self) -> Result<codec::CodecChainExplanation, ArrayError> {
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        Ok(self.codecs.explain(&chunk_representation)?)
+    }
+
+    #[cfg(feature = "sharding")]
+    /// Return the `sharding` codec if the array is [exclusively sharded](ArrayShardedExt::is_exclusively_sharded).
+    pub(crate) fn exclusive_sharding_codec(&self) -> Option<codec::ShardingCodec> {
+        if !self.is_exclusively_sharded() {
+            return None;
+        }
+        // TODO: Trait upcasting
+        let configuration = self
+            .codecs
+            .array_to_bytes_codec()
+            .create_metadata()?
+            .to_configuration::<codec::ShardingCodecConfiguration>()
+            .ok()?;
+        codec::ShardingCodec::new_with_configuration(&configuration).ok()
+    }
+
     /// Get the chunk grid.
     #[must_use]
     pub const fn chunk_grid(&self) -> &ChunkGrid {
@@ -505,12 +619,250 @@ impl<TStorage: ?Sized> Array<TStorage> {
         &self.chunk_key_encoding
     }
 
-    /// Get the storage transformers.
+    /// Get the storage transformers in the array metadata.
     #[must_use]
     pub const fn storage_transformers(&self) -> &StorageTransformerChain {
         &self.storage_transformers
     }
 
+    /// Get the storage transformers applied to the storage.
+    ///
+    /// These are the [`storage_transformers`](Array::storage_transformers) in the array metadata preceded by any inherited storage transformers, such as those of ancestor groups or those supplied to [`open_with_storage_transformers`](Array::open_with_storage_transformers).
+    /// See the [storage transformer ordering](crate::array::storage_transformer#ordering).
+    #[must_use]
+    pub const fn applied_storage_transformers(&self) -> &StorageTransformerChain {
+        &self.applied_storage_transformers
+    }
+
+    /// Apply `storage_transformers` before the storage transformers in the array metadata, replacing any previously inherited storage transformers.
+    #[must_use]
+    pub(crate) fn with_inherited_storage_transformers(
+        mut self,
+        storage_transformers: &StorageTransformerChain,
+    ) -> Self {
+        self.applied_storage_transformers = storage_transformers.chain(&self.storage_transformers);
+        self
+    }
+
+    /// Attach a decoded chunk cache to the array.
+    ///
+    /// Decoded chunks are retrieved from the cache where possible, and chunks that are not in the cache are fully decoded and inserted into the cache.
+    /// This applies to the sync [`retrieve_chunk`](Array::retrieve_chunk), [`retrieve_chunks`](Array::retrieve_chunks), [`retrieve_chunk_subset`](Array::retrieve_chunk_subset), and [`retrieve_array_subset`](Array::retrieve_array_subset) method families.
+    /// A [`partial_decoder`](Array::partial_decoder) reads from the cache if it holds the chunk.
+    /// Storing or erasing a chunk through the array removes it from the cache.
+    ///
+    /// The cache is not aware of the array, so a cache should not be shared between arrays.
+    /// Chunks modified by other writers (including another [`Array`] in the same store) are not removed from the cache.
+    #[must_use]
+    pub fn with_chunk_cache(
+        mut self,
+        chunk_cache: Arc<dyn ChunkCache<ChunkCacheTypeDecoded>>,
+    ) -> Self {
+        self.chunk_cache = Some(ArrayChunkCache(chunk_cache));
+        self
+    }
+
+    /// Return a read only view of the array.
+    ///
+    /// The storage of the returned array is wrapped in a [`ReadOnlyStorageAdapter`], so store methods and metadata writes fail with [`StorageError::ReadOnly`](crate::storage::StorageError::ReadOnly) and the underlying store is never modified.
+    /// The returned array shares the decoded chunk cache and the partial decoder cache of this array, if any.
+    #[must_use]
+    pub fn readonly(&self) -> Array<ReadOnlyStorageAdapter<TStorage>> {
+        Array {
+            storage: Arc::new(ReadOnlyStorageAdapter::new(self.storage.clone())),
+            path: self.path.clone(),
+            data_type: self.data_type.clone(),
+            chunk_grid: self.chunk_grid.clone(),
+            chunk_key_encoding: self.chunk_key_encoding.clone(),
+            fill_value: self.fill_value.clone(),
+            codecs: self.codecs.clone(),
+            storage_transformers: self.storage_transformers.clone(),
+            applied_storage_transformers: self.applied_storage_transformers.clone(),
+            dimension_names: self.dimension_names.clone(),
+            metadata: self.metadata.clone(),
+            chunk_cache: self.chunk_cache.clone(),
+            partial_decoder_cache: self.partial_decoder_cache.clone(),
+            corrupt_chunks: Mutex::default(),
+            validate_checksums: self.validate_checksums,
+            store_empty_chunks: self.store_empty_chunks,
+        }
+    }
+
+    /// Get the decoded chunk cache.
+    #[must_use]
+    pub fn chunk_cache(&self) -> Option<&Arc<dyn ChunkCache<ChunkCacheTypeDecoded>>> {
+        self.chunk_cache.as_ref().map(|chunk_cache| &chunk_cache.0)
+    }
+
+    /// Attach a partial decoder cache with a capacity of `capacity` chunks to the array.
+    ///
+    /// Partial decoders created by the array are retained in an LRU (least recently used) cache keyed by chunk indices, and are reused by later calls.
+    /// This applies to [`partial_decoder`](Array::partial_decoder) and the [`retrieve_chunk_subset`](Array::retrieve_chunk_subset) and [`retrieve_array_subset`](Array::retrieve_array_subset) method families, and their async variants.
+    /// Partial decoders can hold data read when they are created, such as the shard index of a sharded chunk, so the cache avoids reading it again for each call.
+    /// Storing or erasing a chunk through the array removes its partial decoders from the cache.
+    ///
+    /// A cached partial decoder keeps the [`CodecOptions`](codec::CodecOptions) it was created with for any decoding performed on creation.
+    /// Chunks modified by other writers (including another [`Array`] in the same store) are not removed from the cache.
+    /// A capacity of zero disables the cache.
+    #[must_use]
+    pub fn with_partial_decoder_cache(mut self, capacity: usize) -> Self {
+        self.partial_decoder_cache = std::num::NonZeroUsize::new(capacity)
+            .map(|capacity| Arc::new(ArrayPartialDecoderLruCache::new(capacity)));
+        self
+    }
+
+    /// Get the capacity in chunks of the [partial decoder cache](Array::with_partial_decoder_cache), if any.
+    #[must_use]
+    pub fn partial_decoder_cache_capacity(&self) -> Option<usize> {
+        self.partial_decoder_cache
+            .as_ref()
+            .map(|partial_decoder_cache| partial_decoder_cache.capacity().get())
+    }
+
+    /// Override [`Config::validate_checksums`](crate::config::Config::validate_checksums) for this array.
+    ///
+    /// This sets [`CodecOptions::validate_checksums`](crate::array::codec::CodecOptions::validate_checksums) for methods of the array without explicit [`CodecOptions`](crate::array::codec::CodecOptions) (i.e. without an `_opt` suffix).
+    /// If [`None`], the global configuration is used.
+    pub fn set_validate_checksums(&mut self, validate_checksums: Option<bool>) -> &mut Self {
+        self.validate_checksums = validate_checksums;
+        self
+    }
+
+    /// Get the [`Config::validate_checksums`](crate::config::Config::validate_checksums) override for this array.
+    #[must_use]
+    pub const fn validate_checksums(&self) -> Option<bool> {
+        self.validate_checksums
+    }
+
+    /// Override [`Config::store_empty_chunks`](crate::config::Config::store_empty_chunks) for this array.
+    ///
+    /// This sets [`CodecOptions::store_empty_chunks`](crate::array::codec::CodecOptions::store_empty_chunks) for methods of the array without explicit [`CodecOptions`](crate::array::codec::CodecOptions) (i.e. without an `_opt` suffix).
+    /// If [`None`], the global configuration is used.
+    ///
+    /// If `true`, chunks that are entirely the fill value are stored rather than erased (e.g. if a consumer of the array requires every chunk to be present).
+    pub fn set_store_empty_chunks(&mut self, store_empty_chunks: Option<bool>) -> &mut Self {
+        self.store_empty_chunks = store_empty_chunks;
+        self
+    }
+
+    /// Get the [`Config::store_empty_chunks`](crate::config::Config::store_empty_chunks) override for this array.
+    #[must_use]
+    pub const fn store_empty_chunks(&self) -> Option<bool> {
+        self.store_empty_chunks
+    }
+
+    /// The codec options for methods of the array without explicit [`CodecOptions`](crate::array::codec::CodecOptions).
+    pub(crate) fn codec_options(&self) -> codec::CodecOptions {
+        let mut options = codec::CodecOptions::default();
+        if let Some(validate_checksums) = self.validate_checksums {
+            options.set_validate_checksums(validate_checksums);
+        }
+        if let Some(store_empty_chunks) = self.store_empty_chunks {
+            options.set_store_empty_chunks(store_empty_chunks);
+        }
+        options
+    }
+
+    /// Remove the chunk at `chunk_indices` from the decoded chunk cache and the partial decoder cache.
+    fn invalidate_cached_chunk(&self, chunk_indices: &[u64]) {
+        if let Some(chunk_cache) = &self.chunk_cache {
+            chunk_cache.remove(chunk_indices);
+        }
+        if let Some(partial_decoder_cache) = &self.partial_decoder_cache {
+            partial_decoder_cache.remove(chunk_indices);
+        }
+    }
+
+    /// Return the partial decoder of the chunk at `chunk_indices` from the partial decoder cache, or create it with `create`.
+    fn cached_partial_decoder<E>(
+        &self,
+        chunk_indices: &[u64],
+        create: impl FnOnce() -> Result<Arc<dyn codec::ArrayPartialDecoderTraits>, E>,
+    ) -> Result<Arc<dyn codec::ArrayPartialDecoderTraits>, E> {
+        match &self.partial_decoder_cache {
+            Some(partial_decoder_cache) => {
+                partial_decoder_cache.get_or_insert_with(chunk_indices, create)
+            }
+            None => create(),
+        }
+    }
+
+    #[cfg(feature = "async")]
+    /// Async variant of [`cached_partial_decoder`](Array::cached_partial_decoder).
+    async fn async_cached_partial_decoder<E>(
+        &self,
+        chunk_indices: &[u64],
+        create: impl std::future::Future<
+            Output = Result<Arc<dyn codec::AsyncArrayPartialDecoderTraits>, E>,
+        >,
+    ) -> Result<Arc<dyn codec::AsyncArrayPartialDecoderTraits>, E> {
+        match &self.partial_decoder_cache {
+            Some(partial_decoder_cache) => {
+                partial_decoder_cache
+                    .async_get_or_insert_with(chunk_indices, create)
+                    .await
+            }
+            None => create.await,
+        }
+    }
+
+    /// Return the indices of chunks with a corrupt shard index that have been substituted with the fill value.
+    ///
+    /// Chunks are recorded by array retrieval methods if [`CodecOptions::treat_corrupt_shards_as_missing`](crate::array::codec::CodecOptions::treat_corrupt_shards_as_missing) is enabled.
+    ///
+    /// # Panics
+    /// Panics if the record of corrupt chunks is poisoned.
+    #[must_use]
+    pub fn corrupt_chunks(&self) -> Vec<ArrayIndices> {
+        self.corrupt_chunks
+            .lock()
+            .unwrap()
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Clear the record of chunks with a corrupt shard index.
+    ///
+    /// # Panics
+    /// Panics if the record of corrupt chunks is poisoned.
+    pub fn clear_corrupt_chunks(&self) {
+        self.corrupt_chunks.lock().unwrap().clear();
+    }
+
+    /// Handle the result of decoding the chunk at `chunk_indices`.
+    ///
+    /// If the shard index of the chunk is corrupt and [`CodecOptions::treat_corrupt_shards_as_missing`](crate::array::codec::CodecOptions::treat_corrupt_shards_as_missing) is enabled, the chunk is recorded as corrupt and [`None`] is returned.
+    /// The caller should substitute the fill value.
+    /// Otherwise, the chunk key is added to a [`ShardIndexCorrupt`](codec::CodecError::ShardIndexCorrupt) error.
+    fn recover_corrupt_shard<T>(
+        &self,
+        chunk_indices: &[u64],
+        result: Result<T, codec::CodecError>,
+        options: &codec::CodecOptions,
+    ) -> Result<Option<T>, ArrayError> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(codec::CodecError::ShardIndexCorrupt { key, reason }) => {
+                if options.treat_corrupt_shards_as_missing() {
+                    self.corrupt_chunks
+                        .lock()
+                        .unwrap()
+                        .insert(chunk_indices.to_vec());
+                    Ok(None)
+                } else {
+                    Err(ArrayError::CodecError(
+                        codec::CodecError::ShardIndexCorrupt {
+                            key: key.or_else(|| Some(self.chunk_key(chunk_indices))),
+                            reason,
+                        },
+                    ))
+                }
+            }
+            Err(err) => Err(ArrayError::CodecError(err)),
+        }
+    }
+
     /// Get the dimension names.
     #[must_use]
     pub const fn dimension_names(&self) -> &Option<Vec<DimensionName>> {
@@ -545,6 +897,8 @@ impl<TStorage: ?Sized> Array<TStorage> {
     }
 
     /// Get the additional fields.
+    ///
+    /// These include any [understood additional fields](crate::config::Config#understood-additional-fields) with `"must_understand": true`.
     #[must_use]
     pub const fn additional_fields(&self) -> &AdditionalFields {
         match &self.metadata {
@@ -623,6 +977,8 @@ impl<TStorage: ?Sized> Array<TStorage> {
     }
 
     /// Create an array builder matching the parameters of this array.
+    ///
+    /// See [`ArrayBuilder::from_array`].
     #[must_use]
     pub fn builder(&self) -> ArrayBuilder {
         ArrayBuilder::from_array(self)
@@ -770,6 +1126,449 @@ impl<TStorage: ?Sized> Array<TStorage> {
             .chunks_in_array_subset(array_subset, self.shape())
     }
 
+    /// Split `array_subset` into blocks along its outermost dimension for streaming in C order.
+    ///
+    /// Each block holds at most `memory_budget` bytes of decoded elements, but no less than one row of `array_subset` (an outermost extent of one).
+    /// Block boundaries are aligned to chunk boundaries where possible, so a chunk is decoded by one block unless a row of chunks exceeds the budget.
+    fn array_subset_blocks(
+        &self,
+        array_subset: &ArraySubset,
+        memory_budget: usize,
+    ) -> Result<Vec<ArraySubset>, ArrayError> {
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(format!(
+                "cannot stream the variable sized data type {}",
+                self.data_type()
+            )));
+        };
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        if array_subset.is_empty() {
+            return Ok(vec![]);
+        } else if array_subset.dimensionality() == 0 {
+            return Ok(vec![array_subset.clone()]);
+        }
+
+        let row_size = array_subset.num_elements_usize()
+            / usize::try_from(array_subset.shape()[0]).unwrap()
+            * data_type_size;
+        let rows_per_block = (memory_budget / row_size.max(1)).max(1) as u64;
+
+        let mut blocks = Vec::new();
+        let mut block_start = array_subset.start().to_vec();
+        let end = array_subset.end_exc()[0];
+        while block_start[0] < end {
+            let start = block_start[0];
+            let mut block_end = end.min(start.saturating_add(rows_per_block));
+            if block_end < end {
+                // Snap the end of the block back to the origin of the chunk it would split
+                let mut indices = block_start.clone();
+                indices[0] = block_end;
+                if let Some(chunk_indices) =
+                    self.chunk_grid().chunk_indices(&indices, self.shape())?
+                {
+                    let chunk_origin = self.chunk_origin(&chunk_indices)?;
+                    if chunk_origin[0] > start {
+                        block_end = chunk_origin[0];
+                    }
+                }
+            }
+            let mut block_shape = array_subset.shape().to_vec();
+            block_shape[0] = block_end - start;
+            blocks.push(unsafe {
+                ArraySubset::new_with_start_shape_unchecked(block_start.clone(), block_shape)
+            });
+            block_start[0] = block_end;
+        }
+        Ok(blocks)
+    }
+
+    /// Validate `new_shape` for a resize and return the shape of the region shared with the current array shape.
+    fn resize_shared_shape(&self, new_shape: &[u64]) -> Result<ArrayShape, ArrayError> {
+        if new_shape.len() != self.dimensionality() {
+            return Err(IncompatibleDimensionalityError::new(
+                new_shape.len(),
+                self.dimensionality(),
+            )
+            .into());
+        }
+        if self.chunk_grid().grid_shape(new_shape)?.is_none() {
+            return Err(ArrayError::IncompatibleArrayShape(new_shape.to_vec()));
+        }
+        Ok(std::iter::zip(self.shape(), new_shape)
+            .map(|(&shape, &new_shape)| shape.min(new_shape))
+            .collect())
+    }
+
+    /// Return the chunks intersecting the region with `shared_shape` that extend beyond it.
+    ///
+    /// Elements of these chunks outside of the shared region may hold stale data, so they are reset to the fill value on resize.
+    fn resize_chunks_to_reset(
+        &self,
+        shared_shape: &[u64],
+    ) -> Result<Vec<ArrayIndices>, ArrayError> {
+        let mut chunks = std::collections::BTreeSet::new();
+        if shared_shape.contains(&0) {
+            return Ok(vec![]);
+        }
+        for axis in 0..shared_shape.len() {
+            // The hyperplane of elements at the end of the shared region along this axis
+            let mut start = vec![0; shared_shape.len()];
+            start[axis] = shared_shape[axis] - 1;
+            let subset = unsafe {
+                ArraySubset::new_with_start_end_exc_unchecked(start, shared_shape.to_vec())
+            };
+            let Some(subset_chunks) = self.chunks_in_array_subset(&subset)? else {
+                return Err(ArrayError::InvalidArraySubset(
+                    subset,
+                    self.shape().to_vec(),
+                ));
+            };
+            for chunk_indices in &subset_chunks.indices() {
+                let chunk_subset = self.chunk_subset(&chunk_indices)?;
+                if std::iter::zip(chunk_subset.end_exc(), shared_shape)
+                    .any(|(end, shared)| end > *shared)
+                {
+                    chunks.insert(chunk_indices);
+                }
+            }
+        }
+        Ok(chunks.into_iter().collect())
+    }
+
+    /// Return the chunks intersecting the current array shape that are entirely outside of `new_shape`.
+    fn resize_chunks_to_erase(&self, new_shape: &[u64]) -> Result<Vec<ArrayIndices>, ArrayError> {
+        let shape = self.shape();
+        let mut chunks = std::collections::BTreeSet::new();
+        for axis in 0..shape.len() {
+            if new_shape[axis] >= shape[axis] {
+                continue;
+            }
+            // The region of the array beyond the new shape along this axis
+            let mut start = vec![0; shape.len()];
+            start[axis] = new_shape[axis];
+            let subset =
+                unsafe { ArraySubset::new_with_start_end_exc_unchecked(start, shape.to_vec()) };
+            let Some(subset_chunks) = self.chunks_in_array_subset(&subset)? else {
+                return Err(ArrayError::InvalidArraySubset(subset, shape.to_vec()));
+            };
+            for chunk_indices in &subset_chunks.indices() {
+                let chunk_subset = self.chunk_subset(&chunk_indices)?;
+                if std::iter::zip(chunk_subset.start(), new_shape)
+                    .any(|(start, new_shape)| start >= new_shape)
+                {
+                    chunks.insert(chunk_indices);
+                }
+            }
+        }
+        Ok(chunks.into_iter().collect())
+    }
+
+    /// Reset the elements of `chunk_bytes` of the chunk at `chunk_indices` outside of the region with `shared_shape` to the fill value.
+    fn resize_reset_chunk_bytes(
+        &self,
+        chunk_indices: &[u64],
+        chunk_bytes: &ArrayBytes<'_>,
+        shared_shape: &[u64],
+    ) -> Result<ArrayBytes<'static>, ArrayError> {
+        let chunk_subset = self.chunk_subset(chunk_indices)?;
+        let chunk_shape = chunk_subset.shape().to_vec();
+        let shared_subset = ArraySubset::new_with_shape(shared_shape.to_vec());
+        let keep_subset = chunk_subset
+            .overlap(&shared_subset)?
+            .relative_to(chunk_subset.start())?;
+        let keep_bytes =
+            chunk_bytes.extract_array_subset(&keep_subset, &chunk_shape, self.data_type())?;
+        let fill_value_bytes = ArrayBytes::new_fill_value(
+            ArraySize::new(self.data_type().size(), chunk_subset.num_elements()),
+            self.fill_value(),
+        );
+        Ok(unsafe {
+            update_array_bytes(
+                fill_value_bytes,
+                &chunk_shape,
+                &keep_subset,
+                &keep_bytes,
+                self.data_type().size(),
+            )
+        })
+    }
+
+    /// Return the new array shape and the array subset to store `subset_bytes` when appending along `axis`.
+    fn append_shape_and_subset(
+        &self,
+        axis: usize,
+        subset_bytes: &ArrayBytes<'_>,
+    ) -> Result<(ArrayShape, ArraySubset), ArrayError> {
+        let shape = self.shape();
+        let num_elements = match (subset_bytes, self.data_type().size()) {
+            (ArrayBytes::Fixed(bytes), DataTypeSize::Fixed(data_type_size)) => {
+                (bytes.len() / data_type_size) as u64
+            }
+            (ArrayBytes::Variable(_, offsets), DataTypeSize::Variable) => {
+                offsets.len().saturating_sub(1) as u64
+            }
+            // Mismatched fixed/variable length bytes are rejected by validation below
+            _ => 0,
+        };
+        let invalid = || ArrayError::InvalidAppend(num_elements, axis, shape.to_vec());
+        if axis >= shape.len() {
+            return Err(invalid());
+        }
+        let num_elements_hyperplane: u64 = shape
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &shape)| (i != axis).then_some(shape))
+            .product();
+        let length = if num_elements == 0 {
+            0
+        } else if num_elements_hyperplane != 0 && num_elements % num_elements_hyperplane == 0 {
+            num_elements / num_elements_hyperplane
+        } else {
+            return Err(invalid());
+        };
+        subset_bytes.validate(num_elements, self.data_type().size())?;
+
+        let mut start = vec![0; shape.len()];
+        start[axis] = shape[axis];
+        let mut subset_shape = shape.to_vec();
+        subset_shape[axis] = length;
+        let mut new_shape = shape.to_vec();
+        new_shape[axis] += length;
+        let subset = unsafe { ArraySubset::new_with_start_shape_unchecked(start, subset_shape) };
+        Ok((new_shape, subset))
+    }
+
+    /// Validate that the array can be filled with `value`.
+    fn validate_fill(&self, value: &FillValue) -> Result<(), ArrayError> {
+        match self.data_type().size() {
+            DataTypeSize::Fixed(data_type_size) if value.size() == data_type_size => Ok(()),
+            DataTypeSize::Fixed(data_type_size) => Err(ArrayError::InvalidBytesInputSize(
+                value.size(),
+                data_type_size as u64,
+            )),
+            DataTypeSize::Variable => Err(ArrayError::UnsupportedMethod(format!(
+                "cannot fill with the variable sized data type {}",
+                self.data_type()
+            ))),
+        }
+    }
+
+    /// Return the bytes of `num_elements` elements equal to `value`.
+    ///
+    /// `value` must have been validated with [`validate_fill`](Array::validate_fill).
+    fn fill_bytes(&self, value: &FillValue, num_elements: u64) -> ArrayBytes<'static> {
+        ArrayBytes::new_fill_value(ArraySize::new(self.data_type().size(), num_elements), value)
+    }
+
+    /// Encode the chunks in `chunks` from `chunks_bytes` concurrently.
+    ///
+    /// Returns the chunk indices and encoded bytes of each chunk, or [`None`] if the chunk is composed entirely of the fill value and should be erased.
+    fn encode_chunks_batched(
+        &self,
+        chunks: &ArraySubset,
+        chunks_bytes: &ArrayBytes<'_>,
+        options: &CodecOptions,
+    ) -> Result<Vec<(ArrayIndices, Option<Bytes>)>, ArrayError>
+    where
+        TStorage: Send + Sync,
+    {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+        use rayon_iter_concurrent_limit::iter_concurrent_limit;
+
+        let array_subset = self.chunks_subset(chunks)?;
+        chunks_bytes.validate(array_subset.num_elements(), self.data_type().size())?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency::concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.num_elements_usize(),
+            options,
+            &codec_concurrency,
+        );
+
+        let encode_chunk =
+            |chunk_indices: ArrayIndices| -> Result<(ArrayIndices, Option<Bytes>), ArrayError> {
+                let chunk_subset = self.chunk_subset(&chunk_indices)?;
+                let chunk_subset_bytes = chunks_bytes.extract_array_subset(
+                    &chunk_subset.relative_to(array_subset.start())?,
+                    array_subset.shape(),
+                    self.data_type(),
+                )?;
+                if !options.store_empty_chunks()
+                    && chunk_subset_bytes.is_fill_value(self.fill_value())
+                {
+                    return Ok((chunk_indices, None));
+                }
+                let chunk_array_representation = self.chunk_array_representation(&chunk_indices)?;
+                let chunk_encoded = self
+                    .codecs()
+                    .encode(chunk_subset_bytes, &chunk_array_representation, &options)
+                    .map_err(ArrayError::CodecError)?;
+                Ok((chunk_indices, Some(Bytes::from(chunk_encoded.into_owned()))))
+            };
+
+        let indices = chunks.indices();
+        options.install(|| {
+            iter_concurrent_limit!(chunk_concurrent_limit, indices, map, encode_chunk)
+                .collect::<Result<Vec<_>, ArrayError>>()
+        })
+    }
+
+    /// Decode the chunk grid indices of the chunk keys in `keys` listed under the array `prefix`.
+    ///
+    /// Keys that are not chunk keys of this array are skipped.
+    fn stored_chunks_from_keys(
+        &self,
+        prefix: &StorePrefix,
+        keys: &[StoreKey],
+    ) -> Vec<ArrayIndices> {
+        if self.dimensionality() == 0 {
+            // The chunk key of a zero-dimensional array may be ambiguous when decoded
+            let chunk_key = self.chunk_key(&[]);
+            return if keys.contains(&chunk_key) {
+                vec![vec![]]
+            } else {
+                vec![]
+            };
+        }
+        let mut chunks: Vec<ArrayIndices> = keys
+            .iter()
+            .filter_map(|key| {
+                let key = key.as_str().strip_prefix(prefix.as_str())?;
+                let key = StoreKey::new(key).ok()?;
+                self.chunk_key_encoding.decode(&key)
+            })
+            .filter(|chunk_indices| chunk_indices.len() == self.dimensionality())
+            .collect();
+        chunks.sort();
+        chunks
+    }
+
+    /// Return the chunk grid indices of the chunks intersecting `array_subset`.
+    ///
+    /// Returns [`ArrayError::InvalidArraySubset`] if `array_subset` is incompatible with the array.
+    fn chunks_in_array_subset_indices(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<Vec<ArrayIndices>, ArrayError> {
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        let Some(chunks) = self.chunks_in_array_subset(array_subset)? else {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        };
+        Ok(chunks.indices().into_iter().collect())
+    }
+
+    /// Merge the decoded `chunks` intersecting `array_subset` into its bytes, and return a mask of the elements that are within stored chunks.
+    ///
+    /// A chunk with [`None`] bytes is missing, and its elements are the fill value.
+    /// The mask is in C order of the elements of `array_subset`.
+    fn merge_chunks_optional(
+        &self,
+        array_subset: &ArraySubset,
+        chunks: Vec<(ArrayIndices, Option<ArrayBytes<'_>>)>,
+    ) -> Result<(ArrayBytes<'static>, Vec<bool>), ArrayError> {
+        let mut mask = vec![false; array_subset.num_elements_usize()];
+        let mut chunk_bytes_and_subsets = Vec::with_capacity(chunks.len());
+        for (chunk_indices, chunk_bytes) in chunks {
+            let chunk_subset = self.chunk_subset(&chunk_indices)?;
+            let overlap = chunk_subset.overlap(array_subset)?;
+            let overlap_in_array_subset = overlap.relative_to(array_subset.start())?;
+            let overlap_bytes = if let Some(chunk_bytes) = chunk_bytes {
+                // SAFETY: the overlap is within the array subset
+                let contiguous_indices = unsafe {
+                    overlap_in_array_subset
+                        .contiguous_linearised_indices_unchecked(array_subset.shape())
+                };
+                let length = contiguous_indices.contiguous_elements_usize();
+                for index in &contiguous_indices {
+                    let index = usize::try_from(index).unwrap();
+                    mask[index..index + length].fill(true);
+                }
+                chunk_bytes
+                    .extract_array_subset(
+                        &overlap.relative_to(chunk_subset.start())?,
+                        chunk_subset.shape(),
+                        self.data_type(),
+                    )?
+                    .into_owned()
+            } else {
+                let array_size = ArraySize::new(self.data_type().size(), overlap.num_elements());
+                ArrayBytes::new_fill_value(array_size, self.fill_value())
+            };
+            chunk_bytes_and_subsets.push((overlap_bytes, overlap_in_array_subset));
+        }
+
+        let bytes = match self.data_type().size() {
+            DataTypeSize::Variable => {
+                array_bytes::merge_chunks_vlen(chunk_bytes_and_subsets, array_subset.shape())?
+            }
+            DataTypeSize::Fixed(data_type_size) => {
+                let mut bytes = ArrayBytes::new_flen(vec![
+                    0;
+                    array_subset.num_elements_usize()
+                        * data_type_size
+                ]);
+                for (overlap_bytes, overlap_in_array_subset) in &chunk_bytes_and_subsets {
+                    // SAFETY: the overlap is within the array subset and the bytes are fixed length
+                    bytes = unsafe {
+                        update_array_bytes(
+                            bytes,
+                            array_subset.shape(),
+                            overlap_in_array_subset,
+                            overlap_bytes,
+                            self.data_type().size(),
+                        )
+                    };
+                }
+                bytes
+            }
+        };
+        Ok((bytes, mask))
+    }
+
+    /// Return the chunk grid indices of the chunks intersecting `array_subset`, or all chunks if [`None`].
+    ///
+    /// The chunk grid indices are in lexicographical order.
+    fn chunks_iter_indices(
+        &self,
+        array_subset: Option<&ArraySubset>,
+    ) -> Result<impl Iterator<Item = ArrayIndices> + Send + 'static, ArrayError> {
+        let array_subset = array_subset
+            .cloned()
+            .unwrap_or_else(|| ArraySubset::new_with_shape(self.shape().to_vec()));
+        let invalid =
+            || ArrayError::InvalidArraySubset(array_subset.clone(), self.shape().to_vec());
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(invalid());
+        }
+        let chunks = self
+            .chunks_in_array_subset(&array_subset)?
+            .ok_or_else(invalid)?;
+        let chunks_start = chunks.start().to_vec();
+        let chunks_shape = chunks.shape().to_vec();
+        Ok((0..chunks.num_elements()).map(move |index| {
+            std::iter::zip(unravel_index(index, &chunks_shape), &chunks_start)
+                .map(|(index, start)| index + start)
+                .collect()
+        }))
+    }
+
     /// Calculate the recommended codec concurrency.
     fn recommended_codec_concurrency(
         &self,
@@ -780,14 +1579,26 @@ impl<TStorage: ?Sized> Array<TStorage> {
             .recommended_concurrency(chunk_representation)?)
     }
 
-    /// Convert the array to Zarr V3.
+    /// Convert the array to Zarr V3 with default [`ArrayMetadataV2ToV3ConversionOptions`].
     ///
     /// # Errors
     /// Returns a [`ArrayMetadataV2ToV3ConversionError`] if the metadata is not compatible with Zarr V3 metadata.
     pub fn to_v3(self) -> Result<Self, ArrayMetadataV2ToV3ConversionError> {
+        self.to_v3_opt(&ArrayMetadataV2ToV3ConversionOptions::default())
+    }
+
+    /// Convert the array to Zarr V3.
+    ///
+    /// # Errors
+    /// Returns a [`ArrayMetadataV2ToV3ConversionError`] if the metadata is not compatible with Zarr V3 metadata.
+    pub fn to_v3_opt(
+        self,
+        options: &ArrayMetadataV2ToV3ConversionOptions,
+    ) -> Result<Self, ArrayMetadataV2ToV3ConversionError> {
         match self.metadata {
             ArrayMetadata::V2(metadata) => {
-                let metadata: ArrayMetadata = array_metadata_v2_to_v3(&metadata)?.into();
+                let metadata = array_metadata_v2_to_v3_opt(&metadata, options)?;
+                let dimension_names = metadata.dimension_names.clone().or(self.dimension_names);
                 Ok(Self {
                     storage: self.storage,
                     path: self.path,
@@ -797,13 +1608,32 @@ impl<TStorage: ?Sized> Array<TStorage> {
                     fill_value: self.fill_value,
                     codecs: self.codecs,
                     storage_transformers: self.storage_transformers,
-                    dimension_names: self.dimension_names,
-                    metadata,
+                    applied_storage_transformers: self.applied_storage_transformers,
+                    dimension_names,
+                    metadata: metadata.into(),
+                    chunk_cache: self.chunk_cache,
+                    partial_decoder_cache: self.partial_decoder_cache,
+                    corrupt_chunks: self.corrupt_chunks,
+                    validate_checksums: self.validate_checksums,
+                    store_empty_chunks: self.store_empty_chunks,
                 })
             }
             ArrayMetadata::V3(_) => Ok(self),
         }
     }
+
+    /// Return the array metadata converted to Zarr V2.
+    ///
+    /// See [`array_metadata_v3_to_v2`](crate::metadata::v3_to_v2::array_metadata_v3_to_v2) for the conditions under which Zarr V3 metadata can be converted.
+    ///
+    /// # Errors
+    /// Returns a [`ArrayMetadataV3ToV2ConversionError`] if the metadata is not compatible with Zarr V2 metadata.
+    pub fn to_v2_metadata(&self) -> Result<ArrayMetadataV2, ArrayMetadataV3ToV2ConversionError> {
+        match &self.metadata {
+            ArrayMetadata::V2(metadata) => Ok(metadata.clone()),
+            ArrayMetadata::V3(metadata) => array_metadata_v3_to_v2(metadata),
+        }
+    }
 }
 
 #[cfg(feature = "ndarray")]
@@ -818,6 +1648,49 @@ fn ndarray_into_vec<T: Clone, D: ndarray::Dimension>(array: ndarray::Array<T, D>
     .into_raw_vec()
 }
 
+#[cfg(feature = "ndarray")]
+/// Validate that the shape of an ndarray appended along `axis` matches the array shape in every other dimension.
+fn validate_append_ndarray_shape(
+    array_shape: &[u64],
+    axis: usize,
+    ndarray_shape: &[usize],
+) -> Result<(), ArrayError> {
+    let expected_shape: Vec<usize> = array_shape
+        .iter()
+        .enumerate()
+        .map(|(i, &shape)| {
+            if i == axis {
+                ndarray_shape.get(i).copied().unwrap_or_default()
+            } else {
+                usize::try_from(shape).unwrap_or(usize::MAX)
+            }
+        })
+        .collect();
+    if ndarray_shape == expected_shape {
+        Ok(())
+    } else {
+        Err(ArrayError::InvalidDataShape(
+            ndarray_shape.to_vec(),
+            expected_shape,
+        ))
+    }
+}
+
+/// Validate that every codec of `codecs` supports the data type of `chunk_representation`.
+pub(crate) fn validate_codecs(
+    codecs: &CodecChain,
+    chunk_representation: &ChunkRepresentation,
+) -> Result<(), ArrayCreateError> {
+    let incompatibilities = codec::ArrayToBytesCodecTraits::validate(codecs, chunk_representation);
+    if incompatibilities.is_empty() {
+        Ok(())
+    } else {
+        Err(ArrayCreateError::CodecIncompatibleWithDataType(
+            incompatibilities,
+        ))
+    }
+}
+
 mod array_sync_readable;
 
 mod array_sync_writable;
@@ -833,6 +1706,11 @@ mod array_async_writable;
 #[cfg(feature = "async")]
 mod array_async_readable_writable;
 
+mod array_sync_listable;
+
+#[cfg(feature = "async")]
+mod array_async_listable;
+
 /// Transmute from `Vec<u8>` to `Vec<T>`.
 #[must_use]
 pub fn convert_from_bytes_slice<T: bytemuck::Pod>(from: &[u8]) -> Vec<T> {
@@ -937,6 +1815,8 @@ pub fn bytes_to_ndarray<T: bytemuck::Pod>(
 #[cfg(test)]
 mod tests {
     use crate::storage::store::MemoryStore;
+    use chunk_grid::RectangularChunkGridDimensionConfiguration;
+    use chunk_key_encoding::V2ChunkKeyEncoding;
     use zarrs_filesystem::FilesystemStore;
 
     use super::*;
@@ -961,6 +1841,63 @@ mod tests {
         assert_eq!(array_other.metadata(), &stored_metadata);
     }
 
+    #[test]
+    fn array_mutate_attributes() {
+        use crate::storage::{ReadableStorageTraits, WritableStorageTraits};
+
+        let store = Arc::new(MemoryStore::new());
+        let array_path = "/array";
+        let key = crate::node::meta_key_v3(&NodePath::new(array_path).unwrap());
+        let array = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::UInt8,
+            vec![4, 4].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .dimension_names(["y", "x"].into())
+        .build(store.clone(), array_path)
+        .unwrap();
+        array.store_metadata().unwrap();
+        let mut array = Array::open(store.clone(), array_path).unwrap();
+
+        // Simulate another writer adding an attribute and an additional field
+        let stored_document = |store: &MemoryStore| -> serde_json::Value {
+            serde_json::from_slice(&store.get(&key).unwrap().unwrap()).unwrap()
+        };
+        let mut document = stored_document(&store);
+        document["attributes"]["other"] = 1.into();
+        document["ext"] = serde_json::json!({"must_understand": false});
+        store
+            .set(&key, serde_json::to_vec(&document).unwrap().into())
+            .unwrap();
+
+        array
+            .mutate_attributes(|attributes| {
+                attributes.insert("key".to_string(), "value".into());
+            })
+            .unwrap();
+        assert_eq!(array.attributes()["key"], "value");
+        assert_eq!(array.attributes()["other"], 1);
+        let document = stored_document(&store);
+        assert_eq!(document["attributes"]["key"], "value");
+        assert_eq!(document["attributes"]["other"], 1);
+        assert_eq!(
+            document["ext"],
+            serde_json::json!({"must_understand": false})
+        );
+        assert_eq!(document["dimension_names"], serde_json::json!(["y", "x"]));
+
+        // The non-attribute metadata has been changed by another writer
+        let mut document = stored_document(&store);
+        document["dimension_names"] = serde_json::json!(["a", "b"]);
+        store
+            .set(&key, serde_json::to_vec(&document).unwrap().into())
+            .unwrap();
+        assert!(array.store_attributes().is_err());
+        assert!(array.mutate_attributes(serde_json::Map::clear).is_err());
+        assert_eq!(stored_document(&store), document);
+    }
+
     #[test]
     fn array_set_shape_and_attributes() {
         let store = MemoryStore::new();
@@ -1102,6 +2039,299 @@ mod tests {
         )
     }
 
+    #[test]
+    fn array_v2_to_v3_to_v2() {
+        for name in [
+            "adler32_C",
+            "bitshuffle_C",
+            "blosc_C",
+            "blosc_F",
+            "bz2_C",
+            "crc32_C",
+            "datetime64_ns",
+            "gzip_C",
+            "jenkins_lookup3_C",
+            "lz4_C",
+            "lzma_C",
+            "none_C",
+            "none_F",
+            "pcodec_C",
+            "structured",
+            "structured_big",
+            "timedelta64_10us",
+            "zstd_C",
+        ] {
+            let metadata_v2: ArrayMetadataV2 = serde_json::from_slice(
+                &std::fs::read(format!("tests/data/v2/array_{name}.zarr/.zarray")).unwrap(),
+            )
+            .unwrap();
+            let metadata_v3 = array_metadata_v2_to_v3(&metadata_v2).unwrap();
+            let metadata_v2_round_trip = array_metadata_v3_to_v2(&metadata_v3).unwrap();
+            assert_eq!(
+                array_metadata_v2_to_v3(&metadata_v2_round_trip).unwrap(),
+                metadata_v3,
+                "{name}"
+            );
+            if name != "zstd_C" {
+                // numcodecs zstd metadata gains a checksum field
+                assert_eq!(metadata_v2_round_trip, metadata_v2, "{name}");
+            }
+        }
+
+        // Convertible and inconvertible metadata
+        let store = Arc::new(MemoryStore::default());
+        let mut builder = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::Float32,
+            vec![4, 4].try_into().unwrap(),
+            FillValue::from(0.0f32),
+        );
+        builder.dimension_names(["y", "x"].into());
+        let array = builder.build(store.clone(), "/").unwrap();
+        assert!(matches!(
+            array.to_v2_metadata(),
+            Err(ArrayMetadataV3ToV2ConversionError::UnsupportedChunkKeyEncoding(_))
+        ));
+
+        builder.chunk_key_encoding(ChunkKeyEncoding::new(V2ChunkKeyEncoding::new_dot()));
+        let array = builder.build(store.clone(), "/").unwrap();
+        let metadata_v2 = array.to_v2_metadata().unwrap();
+        let dtype = if cfg!(target_endian = "big") {
+            ">f4"
+        } else {
+            "<f4"
+        };
+        assert_eq!(metadata_v2.dtype, dtype.into());
+        assert_eq!(
+            metadata_v2.attributes.get("_ARRAY_DIMENSIONS"),
+            Some(&serde_json::json!(["y", "x"]))
+        );
+
+        #[cfg(feature = "sharding")]
+        {
+            let array = ArrayBuilder::from_array(&array)
+                .array_to_bytes_codec(Arc::new(
+                    crate::array::codec::array_to_bytes::sharding::ShardingCodecBuilder::new(
+                        vec![2, 2].try_into().unwrap(),
+                    )
+                    .build(),
+                ))
+                .build(store.clone(), "/")
+                .unwrap();
+            assert!(matches!(
+                array.to_v2_metadata(),
+                Err(ArrayMetadataV3ToV2ConversionError::UnsupportedCodec(..))
+            ));
+        }
+
+        let array = builder
+            .rectangular_chunk_grid(&[
+                vec![4, 4].try_into().unwrap(),
+                RectangularChunkGridDimensionConfiguration::Fixed(4.try_into().unwrap()),
+            ])
+            .build(store.clone(), "/")
+            .unwrap();
+        assert!(matches!(
+            array.to_v2_metadata(),
+            Err(ArrayMetadataV3ToV2ConversionError::UnsupportedChunkGrid(_))
+        ));
+
+        let array = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::String,
+            vec![4, 4].try_into().unwrap(),
+            FillValue::from(""),
+        )
+        .chunk_key_encoding(ChunkKeyEncoding::new(V2ChunkKeyEncoding::new_dot()))
+        .build(store, "/")
+        .unwrap();
+        assert!(array.to_v2_metadata().is_err());
+    }
+
+    #[test]
+    fn array_v2_xarray_dimension_names() {
+        let store = Arc::new(FilesystemStore::new("tests/data/v2/xarray.zarr").unwrap());
+        for (path, dimension_names) in [("/temperature", vec!["y", "x"]), ("/x", vec!["x"])] {
+            let dimension_names = dimension_names
+                .into_iter()
+                .map(DimensionName::new)
+                .collect::<Vec<_>>();
+
+            // Kept as an attribute by default
+            let array = Array::open(store.clone(), path).unwrap().to_v3().unwrap();
+            assert!(array.dimension_names().is_none());
+            assert!(array.attributes().contains_key("_ARRAY_DIMENSIONS"));
+
+            let options = ArrayMetadataV2ToV3ConversionOptions::default()
+                .with_array_dimensions_to_dimension_names(true);
+            let array = Array::open(store.clone(), path)
+                .unwrap()
+                .to_v3_opt(&options)
+                .unwrap();
+            let ArrayMetadata::V3(metadata) = array.metadata() else {
+                panic!()
+            };
+            assert_eq!(metadata.dimension_names.as_ref(), Some(&dimension_names));
+            assert_eq!(array.dimension_names().as_ref(), Some(&dimension_names));
+            assert!(array.attributes().contains_key("_ARRAY_DIMENSIONS"));
+
+            let array = Array::open(store.clone(), path)
+                .unwrap()
+                .to_v3_opt(&options.clone().with_remove_array_dimensions(true))
+                .unwrap();
+            assert_eq!(array.dimension_names().as_ref(), Some(&dimension_names));
+            assert!(!array.attributes().contains_key("_ARRAY_DIMENSIONS"));
+        }
+    }
+
+    #[test]
+    fn array_v2_datetime64() {
+        let store =
+            Arc::new(FilesystemStore::new("tests/data/v2/array_datetime64_ns.zarr").unwrap());
+        let array = Array::open(store, "/").unwrap();
+        assert_eq!(
+            array.data_type(),
+            &DataType::NumpyDateTime64 {
+                unit: crate::metadata::v3::array::data_type::NumpyTimeUnit::Nanosecond,
+                scale_factor: std::num::NonZeroU32::new(1).unwrap(),
+            }
+        );
+        assert_eq!(array.fill_value().as_ne_bytes(), i64::MIN.to_ne_bytes());
+        let elements = array
+            .retrieve_array_subset_elements::<i64>(&array.subset_all())
+            .unwrap();
+        assert_eq!(
+            elements,
+            [
+                0,
+                946_684_800_000_000_000,
+                i64::MIN,
+                1_736_512_496_789_000_000,
+                -1,
+                i64::MAX
+            ]
+        );
+
+        let metadata = array.metadata_opt(
+            &ArrayMetadataOptions::default()
+                .with_metadata_convert_version(MetadataConvertVersion::V3),
+        );
+        let ArrayMetadata::V3(metadata) = metadata else {
+            panic!()
+        };
+        assert_eq!(
+            serde_json::to_value(&metadata.data_type).unwrap(),
+            serde_json::json!({"name": "numpy.datetime64", "configuration": {"unit": "ns", "scale_factor": 1}})
+        );
+        assert_eq!(
+            serde_json::to_value(&metadata.fill_value).unwrap(),
+            serde_json::json!(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn array_v2_timedelta64() {
+        let store =
+            Arc::new(FilesystemStore::new("tests/data/v2/array_timedelta64_10us.zarr").unwrap());
+        let array = Array::open(store, "/").unwrap();
+        assert_eq!(array.data_type().name(), "numpy.timedelta64");
+        assert_eq!(
+            serde_json::to_value(array.data_type().metadata()).unwrap(),
+            serde_json::json!({"name": "numpy.timedelta64", "configuration": {"unit": "us", "scale_factor": 10}})
+        );
+        let elements = array
+            .retrieve_array_subset_elements::<i64>(&array.subset_all())
+            .unwrap();
+        assert_eq!(elements, [0, 100_000, -1, i64::MIN, 8_640_000_000, 42]);
+        assert!(array
+            .retrieve_array_subset_elements::<u64>(&array.subset_all())
+            .is_err());
+    }
+
+    #[test]
+    fn array_v2_structured() {
+        let store = Arc::new(FilesystemStore::new("tests/data/v2/array_structured.zarr").unwrap());
+        let array = Array::open(store, "/").unwrap();
+        assert_eq!(
+            serde_json::to_value(array.data_type().metadata()).unwrap(),
+            serde_json::json!({"name": "structured", "configuration": {"fields": [
+                {"name": "x", "data_type": "float32", "offset": 0},
+                {"name": "y", "data_type": "float32", "offset": 4},
+                {"name": "id", "data_type": "uint32", "offset": 8},
+            ]}})
+        );
+        assert_eq!(array.data_type().fixed_size(), Some(12));
+
+        let records = array
+            .retrieve_array_subset_elements::<[u8; 12]>(&array.subset_all())
+            .unwrap();
+        let records: Vec<(f32, f32, u32)> = records
+            .iter()
+            .map(|record| {
+                (
+                    f32::from_ne_bytes(record[0..4].try_into().unwrap()),
+                    f32::from_ne_bytes(record[4..8].try_into().unwrap()),
+                    u32::from_ne_bytes(record[8..12].try_into().unwrap()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            records,
+            [
+                (0.5, 1.5, 1),
+                (2.5, 3.5, 2),
+                (-1.0, -1.0, 0),
+                (-1.0, -1.0, 0)
+            ]
+        );
+        assert!(array
+            .retrieve_array_subset_elements::<[u8; 11]>(&array.subset_all())
+            .is_err());
+    }
+
+    #[test]
+    fn array_v2_structured_big_endian() {
+        let store =
+            Arc::new(FilesystemStore::new("tests/data/v2/array_structured_big.zarr").unwrap());
+        let array = Array::open(store, "/").unwrap();
+        assert_eq!(array.data_type().fixed_size(), Some(11));
+
+        let records = array
+            .retrieve_array_subset_elements::<[u8; 11]>(&array.subset_all())
+            .unwrap();
+        let records: Vec<(i16, bool, f64)> = records
+            .iter()
+            .map(|record| {
+                (
+                    i16::from_ne_bytes(record[0..2].try_into().unwrap()),
+                    record[2] != 0,
+                    f64::from_ne_bytes(record[3..11].try_into().unwrap()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            records,
+            [
+                (-2, false, 1.25),
+                (300, true, -3.0),
+                (7, true, 0.5),
+                (7, true, 0.5)
+            ]
+        );
+
+        let metadata = array.metadata_opt(
+            &ArrayMetadataOptions::default()
+                .with_metadata_convert_version(MetadataConvertVersion::V3),
+        );
+        let ArrayMetadata::V3(metadata) = metadata else {
+            panic!()
+        };
+        assert_eq!(
+            serde_json::to_value(&metadata.fill_value).unwrap(),
+            serde_json::json!([7, 0, 1, 0, 0, 0, 0, 0, 0, 224, 63])
+        );
+    }
+
     #[ignore] // FIXME: Reported upstream https://github.com/zarr-developers/zarr-python/issues/2675
     #[test]
     fn array_v2_none_f() {
@@ -1152,6 +2382,66 @@ mod tests {
         )
     }
 
+    #[cfg(feature = "crc32")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn array_v2_crc32_c() {
+        array_v2_to_v3(
+            "tests/data/v2/array_crc32_C.zarr",
+            "tests/data/v3/array_crc32.zarr",
+        )
+    }
+
+    #[cfg(feature = "adler32")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn array_v2_adler32_c() {
+        array_v2_to_v3(
+            "tests/data/v2/array_adler32_C.zarr",
+            "tests/data/v3/array_adler32.zarr",
+        )
+    }
+
+    #[cfg(feature = "jenkins_lookup3")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn array_v2_jenkins_lookup3_c() {
+        array_v2_to_v3(
+            "tests/data/v2/array_jenkins_lookup3_C.zarr",
+            "tests/data/v3/array_jenkins_lookup3.zarr",
+        )
+    }
+
+    #[cfg(feature = "bitshuffle")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn array_v2_bitshuffle_c() {
+        array_v2_to_v3(
+            "tests/data/v2/array_bitshuffle_C.zarr",
+            "tests/data/v3/array_bitshuffle.zarr",
+        )
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn array_v2_lz4_c() {
+        array_v2_to_v3(
+            "tests/data/v2/array_lz4_C.zarr",
+            "tests/data/v3/array_lz4.zarr",
+        )
+    }
+
+    #[cfg(feature = "lzma")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn array_v2_lzma_c() {
+        array_v2_to_v3(
+            "tests/data/v2/array_lzma_C.zarr",
+            "tests/data/v3/array_lzma.zarr",
+        )
+    }
+
     #[cfg(feature = "zfp")]
     #[test]
     #[cfg_attr(miri, ignore)]
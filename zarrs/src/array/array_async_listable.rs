@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::storage::{AsyncListableStorageTraits, StorageError, StorageHandle, StorePrefix};
+
+use super::{Array, ArrayError, ArrayIndices};
+
+impl<TStorage: ?Sized + AsyncListableStorageTraits + 'static> Array<TStorage> {
+    /// Async variant of [`stored_chunks`](Array::stored_chunks).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_stored_chunks(&self) -> Result<Vec<ArrayIndices>, ArrayError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_async_listable_transformer(storage_handle)
+            .await?;
+        let prefix: StorePrefix = self.path().try_into().map_err(StorageError::from)?;
+        let keys = storage_transformer.list_prefix(&prefix).await?;
+        Ok(self.stored_chunks_from_keys(&prefix, &keys))
+    }
+}
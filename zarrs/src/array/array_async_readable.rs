@@ -1,12 +1,15 @@
 use std::{borrow::Cow, sync::Arc};
 
-use futures::{StreamExt, TryStreamExt};
+use futures::{AsyncWriteExt, Stream, StreamExt, TryStreamExt};
 use unsafe_cell_slice::UnsafeCellSlice;
 
 use crate::{
     array_subset::ArraySubset,
     config::MetadataRetrieveVersion,
-    node::{meta_key_v2_array, meta_key_v2_attributes, meta_key_v3, NodePath},
+    node::{
+        array_metadata_v2_from_documents, meta_key_v2_array, meta_key_v2_attributes, meta_key_v3,
+        NodeOpenOptions, NodeOpenWarning, NodePath,
+    },
     storage::{AsyncBytes, AsyncReadableStorageTraits, StorageError, StorageHandle},
 };
 
@@ -18,8 +21,9 @@ use super::{
     },
     concurrency::concurrency_chunks_and_codec,
     element::ElementOwned,
-    Array, ArrayBytes, ArrayCreateError, ArrayError, ArrayMetadata, ArrayMetadataV2,
-    ArrayMetadataV3, ArraySize, DataTypeSize,
+    storage_transformer::{StorageTransformer, StorageTransformerChain},
+    Array, ArrayBytes, ArrayCreateError, ArrayDecodedChunk, ArrayError, ArrayIndices,
+    ArrayMetadata, ArraySize, ChunkOccupancy, DataTypeSize,
 };
 
 #[cfg(feature = "ndarray")]
@@ -42,15 +46,54 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         path: &str,
         version: &MetadataRetrieveVersion,
     ) -> Result<Array<TStorage>, ArrayCreateError> {
+        let options = NodeOpenOptions::default().with_version(version.clone());
+        Self::async_open_with_options(storage, path, &options)
+            .await
+            .map(|(array, _warnings)| array)
+    }
+
+    /// Async variant of [`open_with_options`](Array::open_with_options).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_open_with_options(
+        storage: Arc<TStorage>,
+        path: &str,
+        options: &NodeOpenOptions,
+    ) -> Result<(Array<TStorage>, Vec<NodeOpenWarning>), ArrayCreateError> {
+        let (array, mut warnings) = Self::async_open_metadata(storage, path, options).await?;
+        warnings.extend(options.check_additional_fields(array.additional_fields())?);
+        Ok((array, warnings))
+    }
+
+    /// Async variant of [`open_with_storage_transformers`](Array::open_with_storage_transformers).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_open_with_storage_transformers(
+        storage: Arc<TStorage>,
+        path: &str,
+        storage_transformers: &[StorageTransformer],
+    ) -> Result<Array<TStorage>, ArrayCreateError> {
+        let storage_transformers = StorageTransformerChain::new(storage_transformers.to_vec());
+        Ok(Self::async_open(storage, path)
+            .await?
+            .with_inherited_storage_transformers(&storage_transformers))
+    }
+
+    async fn async_open_metadata(
+        storage: Arc<TStorage>,
+        path: &str,
+        options: &NodeOpenOptions,
+    ) -> Result<(Array<TStorage>, Vec<NodeOpenWarning>), ArrayCreateError> {
+        let version = options.version();
         let node_path = NodePath::new(path)?;
 
         if let MetadataRetrieveVersion::Default | MetadataRetrieveVersion::V3 = version {
             // Try V3
             let key_v3 = meta_key_v3(&node_path);
             if let Some(metadata) = storage.get(&key_v3).await? {
-                let metadata: ArrayMetadataV3 = serde_json::from_slice(&metadata)
+                let (metadata, warnings) = options
+                    .array_metadata_v3_from_slice(&metadata)
                     .map_err(|err| StorageError::InvalidMetadata(key_v3, err.to_string()))?;
-                return Self::new_with_metadata(storage, path, ArrayMetadata::V3(metadata));
+                let array = Self::new_with_metadata(storage, path, ArrayMetadata::V3(metadata))?;
+                return Ok((array, warnings));
             }
         }
 
@@ -58,21 +101,39 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
             // Try V2
             let key_v2 = meta_key_v2_array(&node_path);
             if let Some(metadata) = storage.get(&key_v2).await? {
-                let mut metadata: ArrayMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(key_v2, err.to_string()))?;
-
                 let attributes_key = meta_key_v2_attributes(&node_path);
                 let attributes = storage.get(&attributes_key).await?;
-                if let Some(attributes) = attributes {
-                    metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
-                    })?;
-                }
+                let metadata = array_metadata_v2_from_documents(
+                    &key_v2,
+                    &metadata,
+                    &attributes_key,
+                    attributes,
+                )?;
 
-                return Self::new_with_metadata(storage, path, ArrayMetadata::V2(metadata));
+                let array = Self::new_with_metadata(storage, path, ArrayMetadata::V2(metadata))?;
+                return Ok((array, vec![]));
             }
         }
 
+        // Check for metadata of the other Zarr version
+        let found = match version {
+            MetadataRetrieveVersion::V3 => storage
+                .size_key(&meta_key_v2_array(&node_path))
+                .await?
+                .map(|_| MetadataRetrieveVersion::V2),
+            MetadataRetrieveVersion::V2 => storage
+                .size_key(&meta_key_v3(&node_path))
+                .await?
+                .map(|_| MetadataRetrieveVersion::V3),
+            MetadataRetrieveVersion::Default => None,
+        };
+        if let Some(found) = found {
+            return Err(ArrayCreateError::MetadataVersionMismatch {
+                expected: version,
+                found,
+            });
+        }
+
         Err(ArrayCreateError::MissingMetadata)
     }
 
@@ -82,17 +143,67 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<ArrayBytes<'_>>, ArrayError> {
-        self.async_retrieve_chunk_if_exists_opt(chunk_indices, &CodecOptions::default())
+        self.async_retrieve_chunk_if_exists_opt(chunk_indices, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`chunk_occupancy`](Array::chunk_occupancy).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_chunk_occupancy(
+        &self,
+        chunk_indices: &[u64],
+    ) -> Result<ChunkOccupancy, ArrayError> {
+        let Some(bytes) = self.async_retrieve_chunk_if_exists(chunk_indices).await? else {
+            return Ok(ChunkOccupancy::Missing);
+        };
+        Ok(match bytes.num_non_fill_value_elements(self.fill_value()) {
+            0 => ChunkOccupancy::AllFillValue,
+            count => ChunkOccupancy::NonFill { count },
+        })
+    }
+
+    #[cfg(feature = "sharding")]
+    /// Async variant of [`shard_index`](Array::shard_index).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_shard_index(
+        &self,
+        shard_indices: &[u64],
+    ) -> Result<Option<super::codec::ShardIndex>, ArrayError> {
+        self.async_shard_index_opt(shard_indices, &self.codec_options())
             .await
     }
 
+    #[cfg(feature = "sharding")]
+    /// Async variant of [`shard_index_opt`](Array::shard_index_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_shard_index_opt(
+        &self,
+        shard_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Option<super::codec::ShardIndex>, ArrayError> {
+        let sharding_codec = self.exclusive_sharding_codec().ok_or_else(|| {
+            ArrayError::UnsupportedMethod("the array is not exclusively sharded".to_string())
+        })?;
+        let shard_representation = self.chunk_array_representation(shard_indices)?;
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_async_readable_transformer(storage_handle)
+            .await?;
+        let input_handle =
+            AsyncStoragePartialDecoder::new(storage_transformer, self.chunk_key(shard_indices));
+        Ok(sharding_codec
+            .async_decode_index(&input_handle, &shard_representation, options)
+            .await?)
+    }
+
     /// Async variant of [`retrieve_chunk_elements_if_exists`](Array::retrieve_chunk_elements_if_exists).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     pub async fn async_retrieve_chunk_elements_if_exists<T: ElementOwned + Send + Sync>(
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<Vec<T>>, ArrayError> {
-        self.async_retrieve_chunk_elements_if_exists_opt(chunk_indices, &CodecOptions::default())
+        self.async_retrieve_chunk_elements_if_exists_opt(chunk_indices, &self.codec_options())
             .await
     }
 
@@ -103,7 +214,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<ndarray::ArrayD<T>>, ArrayError> {
-        self.async_retrieve_chunk_ndarray_if_exists_opt(chunk_indices, &CodecOptions::default())
+        self.async_retrieve_chunk_ndarray_if_exists_opt(chunk_indices, &self.codec_options())
             .await
     }
 
@@ -118,7 +229,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Option<AsyncBytes>, StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_readable_transformer(storage_handle)
             .await?;
 
@@ -127,13 +238,32 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
             .await
     }
 
+    /// Async variant of [`chunk_exists`](Array::chunk_exists).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_chunk_exists(&self, chunk_indices: &[u64]) -> Result<bool, ArrayError> {
+        if chunk_indices.len() != self.dimensionality() {
+            return Err(ArrayError::InvalidChunkGridIndicesError(
+                chunk_indices.to_vec(),
+            ));
+        }
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_async_readable_transformer(storage_handle)
+            .await?;
+        Ok(storage_transformer
+            .size_key(&self.chunk_key(chunk_indices))
+            .await?
+            .is_some())
+    }
+
     /// Async variant of [`retrieve_chunk`](Array::retrieve_chunk).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     pub async fn async_retrieve_chunk(
         &self,
         chunk_indices: &[u64],
     ) -> Result<ArrayBytes<'_>, ArrayError> {
-        self.async_retrieve_chunk_opt(chunk_indices, &CodecOptions::default())
+        self.async_retrieve_chunk_opt(chunk_indices, &self.codec_options())
             .await
     }
 
@@ -143,7 +273,17 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Vec<T>, ArrayError> {
-        self.async_retrieve_chunk_elements_opt(chunk_indices, &CodecOptions::default())
+        self.async_retrieve_chunk_elements_opt(chunk_indices, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`retrieve_chunk_elements_string`](Array::retrieve_chunk_elements_string).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_chunk_elements_string(
+        &self,
+        chunk_indices: &[u64],
+    ) -> Result<Vec<String>, ArrayError> {
+        self.async_retrieve_chunk_elements::<String>(chunk_indices)
             .await
     }
 
@@ -154,7 +294,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<ndarray::ArrayD<T>, ArrayError> {
-        self.async_retrieve_chunk_ndarray_opt(chunk_indices, &CodecOptions::default())
+        self.async_retrieve_chunk_ndarray_opt(chunk_indices, &self.codec_options())
             .await
     }
 
@@ -164,7 +304,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunks: &ArraySubset,
     ) -> Result<ArrayBytes<'_>, ArrayError> {
-        self.async_retrieve_chunks_opt(chunks, &CodecOptions::default())
+        self.async_retrieve_chunks_opt(chunks, &self.codec_options())
             .await
     }
 
@@ -174,7 +314,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunks: &ArraySubset,
     ) -> Result<Vec<T>, ArrayError> {
-        self.async_retrieve_chunks_elements_opt(chunks, &CodecOptions::default())
+        self.async_retrieve_chunks_elements_opt(chunks, &self.codec_options())
             .await
     }
 
@@ -185,10 +325,26 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunks: &ArraySubset,
     ) -> Result<ndarray::ArrayD<T>, ArrayError> {
-        self.async_retrieve_chunks_ndarray_opt(chunks, &CodecOptions::default())
+        self.async_retrieve_chunks_ndarray_opt(chunks, &self.codec_options())
             .await
     }
 
+    /// Return a stream that reads and decodes each chunk intersecting `array_subset`, or each chunk of the array if [`None`].
+    ///
+    /// This is the async counterpart of [`chunks_iter`](Array::chunks_iter).
+    /// Up to [`concurrent_target`](CodecOptions::concurrent_target) chunks are retrieved concurrently, and items are yielded in lexicographical order of their chunk grid indices.
+    ///
+    /// # Errors
+    /// Returns [`ArrayError::InvalidArraySubset`] if `array_subset` is incompatible with the array.
+    /// An item is an [`ArrayError`] if there is a codec decoding error or an underlying store error.
+    pub fn async_chunks_stream(
+        &self,
+        array_subset: Option<&ArraySubset>,
+    ) -> Result<impl Stream<Item = Result<ArrayDecodedChunk, ArrayError>> + '_, ArrayError> {
+        let options = self.codec_options();
+        self.async_chunks_stream_opt(array_subset, options.concurrent_target(), &options)
+    }
+
     /// Async variant of [`retrieve_chunk_subset`](Array::retrieve_chunk_subset).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     pub async fn async_retrieve_chunk_subset(
@@ -196,7 +352,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_subset: &ArraySubset,
     ) -> Result<ArrayBytes<'_>, ArrayError> {
-        self.async_retrieve_chunk_subset_opt(chunk_indices, chunk_subset, &CodecOptions::default())
+        self.async_retrieve_chunk_subset_opt(chunk_indices, chunk_subset, &self.codec_options())
             .await
     }
 
@@ -210,7 +366,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         self.async_retrieve_chunk_subset_elements_opt(
             chunk_indices,
             chunk_subset,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
         .await
     }
@@ -226,7 +382,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         self.async_retrieve_chunk_subset_ndarray_opt(
             chunk_indices,
             chunk_subset,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
         .await
     }
@@ -237,7 +393,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         array_subset: &ArraySubset,
     ) -> Result<ArrayBytes<'_>, ArrayError> {
-        self.async_retrieve_array_subset_opt(array_subset, &CodecOptions::default())
+        self.async_retrieve_array_subset_opt(array_subset, &self.codec_options())
             .await
     }
 
@@ -247,7 +403,17 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         array_subset: &ArraySubset,
     ) -> Result<Vec<T>, ArrayError> {
-        self.async_retrieve_array_subset_elements_opt(array_subset, &CodecOptions::default())
+        self.async_retrieve_array_subset_elements_opt(array_subset, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`retrieve_array_subset_elements_string`](Array::retrieve_array_subset_elements_string).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_array_subset_elements_string(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<Vec<String>, ArrayError> {
+        self.async_retrieve_array_subset_elements::<String>(array_subset)
             .await
     }
 
@@ -258,7 +424,75 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         array_subset: &ArraySubset,
     ) -> Result<ndarray::ArrayD<T>, ArrayError> {
-        self.async_retrieve_array_subset_ndarray_opt(array_subset, &CodecOptions::default())
+        self.async_retrieve_array_subset_ndarray_opt(array_subset, &self.codec_options())
+            .await
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Async variant of [`retrieve_array_subset_ndarray_string`](Array::retrieve_array_subset_ndarray_string).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_array_subset_ndarray_string(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<ndarray::ArrayD<String>, ArrayError> {
+        self.async_retrieve_array_subset_ndarray::<String>(array_subset)
+            .await
+    }
+
+    /// Async variant of [`retrieve_array_subset_into`](Array::retrieve_array_subset_into).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_array_subset_into(
+        &self,
+        array_subset: &ArraySubset,
+        output: &mut [u8],
+    ) -> Result<(), ArrayError> {
+        self.async_retrieve_array_subset_into_opt(array_subset, output, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`retrieve_array_subset_elements_into`](Array::retrieve_array_subset_elements_into).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_array_subset_elements_into<
+        T: ElementOwned + bytemuck::Pod + Send + Sync,
+    >(
+        &self,
+        array_subset: &ArraySubset,
+        output: &mut [T],
+    ) -> Result<(), ArrayError> {
+        self.async_retrieve_array_subset_elements_into_opt(
+            array_subset,
+            output,
+            &self.codec_options(),
+        )
+        .await
+    }
+
+    /// Async variant of [`retrieve_array_subset_into_writer`](Array::retrieve_array_subset_into_writer).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_array_subset_into_writer<
+        W: futures::io::AsyncWrite + Unpin + Send,
+    >(
+        &self,
+        array_subset: &ArraySubset,
+        writer: W,
+        memory_budget: usize,
+    ) -> Result<(), ArrayError> {
+        self.async_retrieve_array_subset_into_writer_opt(
+            array_subset,
+            writer,
+            memory_budget,
+            &self.codec_options(),
+        )
+        .await
+    }
+
+    /// Async variant of [`retrieve_array_subset_optional`](Array::retrieve_array_subset_optional).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_array_subset_optional(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<(ArrayBytes<'_>, Vec<bool>), ArrayError> {
+        self.async_retrieve_array_subset_optional_opt(array_subset, &self.codec_options())
             .await
     }
 
@@ -268,7 +502,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, ArrayError> {
-        self.async_partial_decoder_opt(chunk_indices, &CodecOptions::default())
+        self.async_partial_decoder_opt(chunk_indices, &self.codec_options())
             .await
     }
 
@@ -290,7 +524,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         }
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_readable_transformer(storage_handle)
             .await?;
         let chunk_encoded = storage_transformer
@@ -301,9 +535,19 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
             let chunk_encoded: Vec<u8> = chunk_encoded.into();
             let chunk_representation = self.chunk_array_representation(chunk_indices)?;
             let bytes = self
-                .codecs()
-                .decode(Cow::Owned(chunk_encoded), &chunk_representation, options)
-                .map_err(ArrayError::CodecError)?;
+                .recover_corrupt_shard(
+                    chunk_indices,
+                    self.codecs()
+                        .decode(Cow::Owned(chunk_encoded), &chunk_representation, options),
+                    options,
+                )?
+                .unwrap_or_else(|| {
+                    let array_size = ArraySize::new(
+                        self.data_type().size(),
+                        chunk_representation.num_elements(),
+                    );
+                    ArrayBytes::new_fill_value(array_size, self.fill_value())
+                });
             bytes.validate(
                 chunk_representation.num_elements(),
                 chunk_representation.data_type().size(),
@@ -350,7 +594,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         }
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_readable_transformer(storage_handle)
             .await?;
         let chunk_encoded = storage_transformer
@@ -360,9 +604,10 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         if let Some(chunk_encoded) = chunk_encoded {
             let chunk_encoded: Vec<u8> = chunk_encoded.into();
             let chunk_representation = self.chunk_array_representation(chunk_indices)?;
-            unsafe {
-                self.codecs()
-                    .decode_into(
+            let decoded = self.recover_corrupt_shard(
+                chunk_indices,
+                unsafe {
+                    self.codecs().decode_into(
                         Cow::Owned(chunk_encoded),
                         &chunk_representation,
                         output,
@@ -370,8 +615,22 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
                         output_subset,
                         options,
                     )
-                    .map_err(ArrayError::CodecError)
+                },
+                options,
+            )?;
+            if decoded.is_none() {
+                unsafe {
+                    copy_fill_value_into(
+                        self.data_type(),
+                        self.fill_value(),
+                        output,
+                        output_shape,
+                        output_subset,
+                    )
+                    .map_err(ArrayError::CodecError)?;
+                }
             }
+            Ok(())
         } else {
             unsafe {
                 copy_fill_value_into(
@@ -397,7 +656,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
             .async_retrieve_chunk_if_exists_opt(chunk_indices, options)
             .await?
         {
-            let elements = T::from_array_bytes(self.data_type(), bytes)?;
+            let elements = T::from_array_bytes(self.data_type(), self.fill_value(), bytes)?;
             Ok(Some(elements))
         } else {
             Ok(None)
@@ -414,7 +673,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         let bytes = self
             .async_retrieve_chunk_opt(chunk_indices, options)
             .await?;
-        let elements = T::from_array_bytes(self.data_type(), bytes)?;
+        let elements = T::from_array_bytes(self.data_type(), self.fill_value(), bytes)?;
         Ok(elements)
     }
 
@@ -474,7 +733,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Vec<Option<AsyncBytes>>, StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_readable_transformer(storage_handle)
             .await?;
 
@@ -522,7 +781,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         options: &CodecOptions,
     ) -> Result<Vec<T>, ArrayError> {
         let bytes = self.async_retrieve_chunks_opt(chunks, options).await?;
-        let elements = T::from_array_bytes(self.data_type(), bytes)?;
+        let elements = T::from_array_bytes(self.data_type(), self.fill_value(), bytes)?;
         Ok(elements)
     }
 
@@ -541,6 +800,33 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         elements_to_ndarray(array_subset.shape(), elements)
     }
 
+    /// Explicit options version of [`async_chunks_stream`](Array::async_chunks_stream).
+    ///
+    /// Up to `concurrent_limit` chunks are retrieved concurrently.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn async_chunks_stream_opt(
+        &self,
+        array_subset: Option<&ArraySubset>,
+        concurrent_limit: usize,
+        options: &CodecOptions,
+    ) -> Result<impl Stream<Item = Result<ArrayDecodedChunk, ArrayError>> + '_, ArrayError> {
+        let chunks_indices = self.chunks_iter_indices(array_subset)?;
+        let options = options.clone();
+        Ok(futures::stream::iter(chunks_indices)
+            .map(move |chunk_indices| {
+                let options = options.clone();
+                async move {
+                    let chunk_subset = self.chunk_subset(&chunk_indices)?;
+                    let chunk_bytes = self
+                        .async_retrieve_chunk_opt(&chunk_indices, &options)
+                        .await?
+                        .into_owned();
+                    Ok((chunk_indices, chunk_subset, chunk_bytes))
+                }
+            })
+            .buffered(concurrent_limit.max(1)))
+    }
+
     /// Async variant of [`retrieve_array_subset_opt`](Array::retrieve_array_subset_opt).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     #[allow(clippy::too_many_lines)]
@@ -622,11 +908,11 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
                             }
                         };
 
-                        // TODO: chunk_concurrent_limit
-                        let chunk_bytes_and_subsets = futures::future::try_join_all(
-                            chunks.indices().iter().map(retrieve_chunk),
-                        )
-                        .await?;
+                        let chunk_bytes_and_subsets =
+                            futures::stream::iter(chunks.indices().iter().map(retrieve_chunk))
+                                .buffered(chunk_concurrent_limit)
+                                .try_collect::<Vec<_>>()
+                                .await?;
 
                         Ok(merge_chunks_vlen(
                             chunk_bytes_and_subsets,
@@ -644,53 +930,16 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
                         {
                             let output =
                                 UnsafeCellSlice::new_from_vec_with_spare_capacity(&mut output);
-                            let retrieve_chunk = |chunk_indices: Vec<u64>| {
-                                let options = options.clone();
-                                async move {
-                                    let chunk_subset = self.chunk_subset(&chunk_indices)?;
-                                    let chunk_subset_overlap =
-                                        chunk_subset.overlap(array_subset)?;
-                                    unsafe {
-                                        self.async_retrieve_chunk_subset_into(
-                                            &chunk_indices,
-                                            &chunk_subset_overlap
-                                                .relative_to(chunk_subset.start())?,
-                                            &output,
-                                            array_subset.shape(),
-                                            &chunk_subset_overlap
-                                                .relative_to(array_subset.start())?,
-                                            &options,
-                                        )
-                                        .await?;
-                                    }
-                                    // let chunk_subset_bytes = self
-                                    //     .async_retrieve_chunk_subset_opt(
-                                    //         &chunk_indices,
-                                    //         &chunk_subset_overlap
-                                    //             .relative_to(chunk_subset.start())?,
-                                    //         &options,
-                                    //     )
-                                    //     .await?;
-                                    // let chunk_subset_bytes = chunk_subset_bytes.into_fixed()?;
-                                    // let output = unsafe { output.as_mut_slice() };
-                                    // update_bytes_flen(
-                                    //     output,
-                                    //     array_subset.shape(),
-                                    //     &chunk_subset_bytes,
-                                    //     &chunk_subset_overlap.relative_to(array_subset.start())?,
-                                    //     data_type_size,
-                                    // );
-                                    Ok::<_, ArrayError>(())
-                                }
-                            };
-
-                            futures::stream::iter(&chunks.indices())
-                                .map(Ok)
-                                .try_for_each_concurrent(
-                                    Some(chunk_concurrent_limit),
-                                    retrieve_chunk,
+                            unsafe {
+                                self.async_retrieve_array_subset_into_impl(
+                                    array_subset,
+                                    &chunks,
+                                    &output,
+                                    chunk_concurrent_limit,
+                                    &options,
                                 )
                                 .await?;
+                            }
                         }
                         unsafe { output.set_len(size_output) };
                         Ok(ArrayBytes::from(output))
@@ -700,6 +949,189 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         }
     }
 
+    /// Retrieve the chunks intersecting `array_subset` and decode them into `output`.
+    ///
+    /// # Safety
+    /// `output` must hold `array_subset` with a fixed size data type and `chunks` must be the chunks intersecting `array_subset`.
+    async unsafe fn async_retrieve_array_subset_into_impl(
+        &self,
+        array_subset: &ArraySubset,
+        chunks: &ArraySubset,
+        output: &UnsafeCellSlice<'_, u8>,
+        chunk_concurrent_limit: usize,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let retrieve_chunk = |chunk_indices: Vec<u64>| {
+            let options = options.clone();
+            async move {
+                let chunk_subset = self.chunk_subset(&chunk_indices)?;
+                let chunk_subset_overlap = chunk_subset.overlap(array_subset)?;
+                unsafe {
+                    self.async_retrieve_chunk_subset_into(
+                        &chunk_indices,
+                        &chunk_subset_overlap.relative_to(chunk_subset.start())?,
+                        output,
+                        array_subset.shape(),
+                        &chunk_subset_overlap.relative_to(array_subset.start())?,
+                        &options,
+                    )
+                    .await?;
+                }
+                Ok::<_, ArrayError>(())
+            }
+        };
+        futures::stream::iter(&chunks.indices())
+            .map(Ok)
+            .try_for_each_concurrent(Some(chunk_concurrent_limit), retrieve_chunk)
+            .await
+    }
+
+    /// Async variant of [`retrieve_array_subset_into_opt`](Array::retrieve_array_subset_into_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_array_subset_into_opt(
+        &self,
+        array_subset: &ArraySubset,
+        output: &mut [u8],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(format!(
+                "cannot retrieve into a preallocated output with the variable sized data type {}",
+                self.data_type()
+            )));
+        };
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        let size_output = array_subset.num_elements_usize() * data_type_size;
+        if output.len() != size_output {
+            return Err(ArrayError::InvalidBytesInputSize(
+                output.len(),
+                size_output as u64,
+            ));
+        }
+
+        // Find the chunks intersecting this array subset
+        let Some(chunks) = self.chunks_in_array_subset(array_subset)? else {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        };
+        let num_chunks = chunks.num_elements_usize();
+        if num_chunks == 0 || size_output == 0 {
+            return Ok(());
+        }
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            num_chunks,
+            options,
+            &codec_concurrency,
+        );
+
+        let output = UnsafeCellSlice::new(output);
+        // SAFETY: the output length has been validated against the array subset
+        unsafe {
+            self.async_retrieve_array_subset_into_impl(
+                array_subset,
+                &chunks,
+                &output,
+                chunk_concurrent_limit,
+                &options,
+            )
+            .await
+        }
+    }
+
+    /// Async variant of [`retrieve_array_subset_elements_into_opt`](Array::retrieve_array_subset_elements_into_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_array_subset_elements_into_opt<
+        T: ElementOwned + bytemuck::Pod + Send + Sync,
+    >(
+        &self,
+        array_subset: &ArraySubset,
+        output: &mut [T],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        T::validate_data_type(self.data_type())?;
+        self.async_retrieve_array_subset_into_opt(
+            array_subset,
+            bytemuck::cast_slice_mut(output),
+            options,
+        )
+        .await
+    }
+
+    /// Async variant of [`retrieve_array_subset_into_writer_opt`](Array::retrieve_array_subset_into_writer_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_retrieve_array_subset_into_writer_opt<
+        W: futures::io::AsyncWrite + Unpin + Send,
+    >(
+        &self,
+        array_subset: &ArraySubset,
+        mut writer: W,
+        memory_budget: usize,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let data_type_size = self.data_type().fixed_size().unwrap_or_default();
+        let mut block_bytes = Vec::new();
+        for block in self.array_subset_blocks(array_subset, memory_budget)? {
+            block_bytes.resize(block.num_elements_usize() * data_type_size, 0);
+            self.async_retrieve_array_subset_into_opt(&block, &mut block_bytes, options)
+                .await?;
+            writer
+                .write_all(&block_bytes)
+                .await
+                .map_err(StorageError::from)?;
+        }
+        writer.flush().await.map_err(StorageError::from)?;
+        Ok(())
+    }
+
+    /// Async variant of [`retrieve_array_subset_optional_opt`](Array::retrieve_array_subset_optional_opt).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub async fn async_retrieve_array_subset_optional_opt(
+        &self,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<(ArrayBytes<'_>, Vec<bool>), ArrayError> {
+        let chunks = self.chunks_in_array_subset_indices(array_subset)?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        let retrieve_chunk = |chunk_indices: ArrayIndices| {
+            let options = options.clone();
+            async move {
+                let chunk_bytes = self
+                    .async_retrieve_chunk_if_exists_opt(&chunk_indices, &options)
+                    .await?;
+                Ok::<_, ArrayError>((chunk_indices, chunk_bytes))
+            }
+        };
+        let chunks = futures::stream::iter(chunks.into_iter().map(retrieve_chunk))
+            .buffered(chunk_concurrent_limit)
+            .try_collect::<Vec<_>>()
+            .await?;
+        self.merge_chunks_optional(array_subset, chunks)
+    }
+
     /// Async variant of [`retrieve_array_subset_elements_opt`](Array::retrieve_array_subset_elements_opt).
     #[allow(clippy::missing_errors_doc)]
     pub async fn async_retrieve_array_subset_elements_opt<T: ElementOwned + Send + Sync>(
@@ -710,7 +1142,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         let bytes = self
             .async_retrieve_array_subset_opt(array_subset, options)
             .await?;
-        let elements = T::from_array_bytes(self.data_type(), bytes)?;
+        let elements = T::from_array_bytes(self.data_type(), self.fill_value(), bytes)?;
         Ok(elements)
     }
 
@@ -753,21 +1185,36 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         } else {
             let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
             let storage_transformer = self
-                .storage_transformers()
+                .applied_storage_transformers()
                 .create_async_readable_transformer(storage_handle)
                 .await?;
             let input_handle = Arc::new(AsyncStoragePartialDecoder::new(
                 storage_transformer,
                 self.chunk_key(chunk_indices),
             ));
-            self.codecs
-                .clone()
-                .async_partial_decoder(input_handle, &chunk_representation, options)
-                .await?
-                .partial_decode(&[chunk_subset.clone()], options)
-                .await?
-                .remove(0)
-                .into_owned()
+            let bytes = async {
+                Ok(self
+                    .async_cached_partial_decoder(
+                        chunk_indices,
+                        self.codecs.clone().async_partial_decoder(
+                            input_handle,
+                            &chunk_representation,
+                            options,
+                        ),
+                    )
+                    .await?
+                    .partial_decode(std::slice::from_ref(chunk_subset), options)
+                    .await?
+                    .remove(0)
+                    .into_owned())
+            }
+            .await;
+            self.recover_corrupt_shard(chunk_indices, bytes, options)?
+                .unwrap_or_else(|| {
+                    let array_size =
+                        ArraySize::new(self.data_type().size(), chunk_subset.num_elements());
+                    ArrayBytes::new_fill_value(array_size, self.fill_value())
+                })
         };
         bytes.validate(chunk_subset.num_elements(), self.data_type().size())?;
         Ok(bytes)
@@ -807,7 +1254,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         } else {
             let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
             let storage_transformer = self
-                .storage_transformers()
+                .applied_storage_transformers()
                 .create_async_readable_transformer(storage_handle)
                 .await?;
             let input_handle = Arc::new(AsyncStoragePartialDecoder::new(
@@ -815,13 +1262,36 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
                 self.chunk_key(chunk_indices),
             ));
 
-            unsafe {
-                self.codecs
-                    .clone()
-                    .async_partial_decoder(input_handle, &chunk_representation, options)
+            let decoded = async {
+                unsafe {
+                    self.async_cached_partial_decoder(
+                        chunk_indices,
+                        self.codecs.clone().async_partial_decoder(
+                            input_handle,
+                            &chunk_representation,
+                            options,
+                        ),
+                    )
                     .await?
                     .partial_decode_into(chunk_subset, output, output_shape, output_subset, options)
-                    .await?;
+                    .await
+                }
+            }
+            .await;
+            if self
+                .recover_corrupt_shard(chunk_indices, decoded, options)?
+                .is_none()
+            {
+                unsafe {
+                    copy_fill_value_into(
+                        self.data_type(),
+                        self.fill_value(),
+                        output,
+                        output_shape,
+                        output_subset,
+                    )
+                    .map_err(ArrayError::CodecError)?;
+                }
             }
             Ok(())
         }
@@ -838,7 +1308,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         let bytes = self
             .async_retrieve_chunk_subset_opt(chunk_indices, chunk_subset, options)
             .await?;
-        let elements = T::from_array_bytes(self.data_type(), bytes)?;
+        let elements = T::from_array_bytes(self.data_type(), self.fill_value(), bytes)?;
         Ok(elements)
     }
 
@@ -866,7 +1336,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, ArrayError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_readable_transformer(storage_handle)
             .await?;
         let input_handle = Arc::new(AsyncStoragePartialDecoder::new(
@@ -875,9 +1345,14 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + 'static> Array<TStorage> {
         ));
         let chunk_representation = self.chunk_array_representation(chunk_indices)?;
         Ok(self
-            .codecs
-            .clone()
-            .async_partial_decoder(input_handle, &chunk_representation, options)
+            .async_cached_partial_decoder(
+                chunk_indices,
+                self.codecs.clone().async_partial_decoder(
+                    input_handle,
+                    &chunk_representation,
+                    options,
+                ),
+            )
             .await?)
     }
 }
@@ -1,15 +1,90 @@
+use std::sync::Arc;
+
 use futures::{StreamExt, TryStreamExt};
 
 use crate::{
-    array::ArrayBytes, array_subset::ArraySubset, storage::AsyncReadableWritableStorageTraits,
+    array::ArrayBytes,
+    array_subset::ArraySubset,
+    metadata::v3::AdditionalFields,
+    node::{
+        meta_key_v2_array, meta_key_v2_attributes, meta_key_v3, mutate_document_attributes,
+        serialize_document, stored_attributes_v2, stored_metadata_document,
+    },
+    storage::{AsyncReadableWritableStorageTraits, StorageError, StorageHandle},
 };
 
 use super::{
     array_bytes::update_array_bytes, codec::options::CodecOptions,
-    concurrency::concurrency_chunks_and_codec, Array, ArrayError, Element,
+    concurrency::concurrency_chunks_and_codec, Array, ArrayError, ArrayIndices, ArrayMetadata,
+    ArrayMetadataOptions, ArrayShape, Element, FillValue,
 };
 
 impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TStorage> {
+    /// Async variant of [`store_attributes`](Array::store_attributes).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_attributes(&self) -> Result<(), StorageError> {
+        let attributes = self.attributes().clone();
+        self.async_update_stored_attributes(|stored_attributes| *stored_attributes = attributes)
+            .await?;
+        Ok(())
+    }
+
+    /// Async variant of [`mutate_attributes`](Array::mutate_attributes).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_mutate_attributes(
+        &mut self,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>) + Send,
+    ) -> Result<(), StorageError> {
+        *self.attributes_mut() = self.async_update_stored_attributes(f).await?;
+        Ok(())
+    }
+
+    async fn async_update_stored_attributes(
+        &self,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>) + Send,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, StorageError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_async_writable_transformer(storage_handle)
+            .await?;
+
+        let path = self.path();
+        match self.metadata_opt(&ArrayMetadataOptions::default()) {
+            ArrayMetadata::V3(metadata) => {
+                let key = meta_key_v3(path);
+                let mut document = stored_metadata_document(
+                    &key,
+                    self.storage.get(&key).await?,
+                    &metadata,
+                    |m| {
+                        m.attributes.clear();
+                        m.additional_fields = AdditionalFields::default();
+                    },
+                )?;
+                let attributes = mutate_document_attributes(&mut document, f);
+                storage_transformer
+                    .set(&key, serialize_document(&key, &document)?)
+                    .await?;
+                Ok(attributes)
+            }
+            ArrayMetadata::V2(metadata) => {
+                let key = meta_key_v2_array(path);
+                stored_metadata_document(&key, self.storage.get(&key).await?, &metadata, |m| {
+                    m.attributes.clear();
+                    m.additional_fields = AdditionalFields::default();
+                })?;
+                let key = meta_key_v2_attributes(path);
+                let mut attributes = stored_attributes_v2(&key, self.storage.get(&key).await?)?;
+                f(&mut attributes);
+                storage_transformer
+                    .set(&key, serialize_document(&key, &attributes)?)
+                    .await?;
+                Ok(attributes)
+            }
+        }
+    }
+
     /// Async variant of [`store_chunk_subset`](Array::store_chunk_subset).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     pub async fn async_store_chunk_subset<'a>(
@@ -22,7 +97,7 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
             chunk_indices,
             chunk_subset,
             chunk_subset_bytes,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
         .await
     }
@@ -39,7 +114,7 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
             chunk_indices,
             chunk_subset,
             chunk_subset_elements,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
         .await
     }
@@ -60,7 +135,7 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
             chunk_indices,
             chunk_subset_start,
             chunk_subset_array,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
         .await
     }
@@ -72,7 +147,7 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
         array_subset: &ArraySubset,
         subset_bytes: impl Into<ArrayBytes<'a>> + Send,
     ) -> Result<(), ArrayError> {
-        self.async_store_array_subset_opt(array_subset, subset_bytes, &CodecOptions::default())
+        self.async_store_array_subset_opt(array_subset, subset_bytes, &self.codec_options())
             .await
     }
 
@@ -86,11 +161,23 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
         self.async_store_array_subset_elements_opt(
             array_subset,
             subset_elements,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
         .await
     }
 
+    /// Async variant of [`store_array_subset_elements_string`](Array::store_array_subset_elements_string).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_array_subset_elements_string(
+        &self,
+        array_subset: &ArraySubset,
+        subset_elements: &[impl AsRef<str> + Sync],
+    ) -> Result<(), ArrayError> {
+        let subset_elements: Vec<&str> = subset_elements.iter().map(AsRef::as_ref).collect();
+        self.async_store_array_subset_elements(array_subset, &subset_elements)
+            .await
+    }
+
     #[cfg(feature = "ndarray")]
     /// Async variant of [`store_array_subset_ndarray`](Array::store_array_subset_ndarray).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
@@ -102,12 +189,64 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
         subset_start: &[u64],
         subset_array: impl Into<ndarray::Array<T, D>> + Send,
     ) -> Result<(), ArrayError> {
-        self.async_store_array_subset_ndarray_opt(
-            subset_start,
-            subset_array,
-            &CodecOptions::default(),
-        )
-        .await
+        self.async_store_array_subset_ndarray_opt(subset_start, subset_array, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`fill_array_subset`](Array::fill_array_subset).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_fill_array_subset(
+        &self,
+        array_subset: &ArraySubset,
+        value: &FillValue,
+    ) -> Result<(), ArrayError> {
+        self.async_fill_array_subset_opt(array_subset, value, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`resize`](Array::resize).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_resize(
+        &mut self,
+        new_shape: ArrayShape,
+        erase_out_of_bounds: bool,
+    ) -> Result<(), ArrayError> {
+        self.async_resize_opt(new_shape, erase_out_of_bounds, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`append`](Array::append).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_append<'a>(
+        &mut self,
+        axis: usize,
+        subset_bytes: impl Into<ArrayBytes<'a>> + Send,
+    ) -> Result<(), ArrayError> {
+        self.async_append_opt(axis, subset_bytes, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`append_elements`](Array::append_elements).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_append_elements<T: Element + Send + Sync>(
+        &mut self,
+        axis: usize,
+        subset_elements: &[T],
+    ) -> Result<(), ArrayError> {
+        self.async_append_elements_opt(axis, subset_elements, &self.codec_options())
+            .await
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Async variant of [`append_ndarray`](Array::append_ndarray).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_append_ndarray<T: Element + Send + Sync, D: ndarray::Dimension>(
+        &mut self,
+        axis: usize,
+        subset_array: impl Into<ndarray::Array<T, D>> + Send,
+    ) -> Result<(), ArrayError> {
+        self.async_append_ndarray_opt(axis, subset_array, &self.codec_options())
+            .await
     }
 
     /////////////////////////////////////////////////////////////////////////////
@@ -183,7 +322,8 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
         chunk_subset_elements: &[T],
         options: &CodecOptions,
     ) -> Result<(), ArrayError> {
-        let chunk_subset_bytes = T::into_array_bytes(self.data_type(), chunk_subset_elements)?;
+        let chunk_subset_bytes =
+            T::into_array_bytes(self.data_type(), self.fill_value(), chunk_subset_elements)?;
         self.async_store_chunk_subset_opt(chunk_indices, chunk_subset, chunk_subset_bytes, options)
             .await
     }
@@ -320,7 +460,8 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
         subset_elements: &[T],
         options: &CodecOptions,
     ) -> Result<(), ArrayError> {
-        let subset_bytes = T::into_array_bytes(self.data_type(), subset_elements)?;
+        let subset_bytes =
+            T::into_array_bytes(self.data_type(), self.fill_value(), subset_elements)?;
         self.async_store_array_subset_opt(array_subset, subset_bytes, options)
             .await
     }
@@ -346,4 +487,165 @@ impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits + 'static> Array<TSto
         self.async_store_array_subset_elements_opt(&subset, &subset_array, options)
             .await
     }
+
+    /// Async variant of [`fill_array_subset_opt`](Array::fill_array_subset_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_fill_array_subset_opt(
+        &self,
+        array_subset: &ArraySubset,
+        value: &FillValue,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        self.validate_fill(value)?;
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        let Some(chunks) = self.chunks_in_array_subset(array_subset)? else {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        };
+
+        // Calculate chunk/codec concurrency
+        let num_chunks = chunks.num_elements_usize();
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            num_chunks,
+            options,
+            &codec_concurrency,
+        );
+
+        let fill_chunk = |chunk_indices: Vec<u64>| {
+            let options = options.clone();
+            async move {
+                let chunk_subset_in_array = self.chunk_subset_bounded(&chunk_indices)?;
+                let overlap = unsafe { array_subset.overlap_unchecked(&chunk_subset_in_array) };
+                if overlap == chunk_subset_in_array {
+                    // The subset covers the chunk, so skip decoding
+                    self.async_fill_chunk_opt(&chunk_indices, value, &options)
+                        .await
+                } else {
+                    let array_subset_in_chunk_subset =
+                        unsafe { overlap.relative_to_unchecked(chunk_subset_in_array.start()) };
+                    self.async_store_chunk_subset_opt(
+                        &chunk_indices,
+                        &array_subset_in_chunk_subset,
+                        self.fill_bytes(value, overlap.num_elements()),
+                        &options,
+                    )
+                    .await
+                }
+            }
+        };
+        futures::stream::iter(&chunks.indices())
+            .map(Ok)
+            .try_for_each_concurrent(Some(chunk_concurrent_limit), fill_chunk)
+            .await
+    }
+
+    /// Async variant of [`resize_opt`](Array::resize_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_resize_opt(
+        &mut self,
+        new_shape: ArrayShape,
+        erase_out_of_bounds: bool,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let shared_shape = self.resize_shared_shape(&new_shape)?;
+        let chunks_to_erase = if erase_out_of_bounds {
+            self.resize_chunks_to_erase(&new_shape)?
+        } else {
+            vec![]
+        };
+
+        // Reset elements outside of the shared region in existing chunks that extend beyond it
+        let array = &*self;
+        let reset_chunk = |chunk_indices: ArrayIndices| {
+            let shared_shape = &shared_shape;
+            async move {
+                if let Some(chunk_bytes) = array
+                    .async_retrieve_chunk_if_exists_opt(&chunk_indices, options)
+                    .await?
+                {
+                    let chunk_bytes = array.resize_reset_chunk_bytes(
+                        &chunk_indices,
+                        &chunk_bytes,
+                        shared_shape,
+                    )?;
+                    array
+                        .async_store_chunk_opt(&chunk_indices, chunk_bytes, options)
+                        .await?;
+                }
+                Ok::<_, ArrayError>(())
+            }
+        };
+        futures::stream::iter(self.resize_chunks_to_reset(&shared_shape)?)
+            .map(Ok)
+            .try_for_each_concurrent(Some(options.chunk_concurrent_maximum().max(1)), reset_chunk)
+            .await?;
+
+        self.set_shape(new_shape);
+        self.async_store_metadata().await?;
+
+        let array = &*self;
+        let erase_chunk = |chunk_indices: ArrayIndices| async move {
+            array.async_erase_chunk(&chunk_indices).await
+        };
+        futures::stream::iter(chunks_to_erase)
+            .map(Ok)
+            .try_for_each_concurrent(Some(options.chunk_concurrent_maximum().max(1)), erase_chunk)
+            .await?;
+        Ok(())
+    }
+
+    /// Async variant of [`append_opt`](Array::append_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_append_opt<'a>(
+        &mut self,
+        axis: usize,
+        subset_bytes: impl Into<ArrayBytes<'a>> + Send,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let subset_bytes = subset_bytes.into();
+        let (new_shape, array_subset) = self.append_shape_and_subset(axis, &subset_bytes)?;
+        self.async_resize_opt(new_shape, false, options).await?;
+        self.async_store_array_subset_opt(&array_subset, subset_bytes, options)
+            .await
+    }
+
+    /// Async variant of [`append_elements_opt`](Array::append_elements_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_append_elements_opt<T: Element + Send + Sync>(
+        &mut self,
+        axis: usize,
+        subset_elements: &[T],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let subset_bytes =
+            T::into_array_bytes(self.data_type(), self.fill_value(), subset_elements)?;
+        self.async_append_opt(axis, subset_bytes, options).await
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Async variant of [`append_ndarray_opt`](Array::append_ndarray_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_append_ndarray_opt<T: Element + Send + Sync, D: ndarray::Dimension>(
+        &mut self,
+        axis: usize,
+        subset_array: impl Into<ndarray::Array<T, D>> + Send,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let subset_array: ndarray::Array<T, D> = subset_array.into();
+        super::validate_append_ndarray_shape(self.shape(), axis, subset_array.shape())?;
+        let subset_array = super::ndarray_into_vec(subset_array);
+        self.async_append_elements_opt(axis, &subset_array, options)
+            .await
+    }
 }
@@ -6,14 +6,17 @@ use crate::{
     array::ArrayBytes,
     array_subset::ArraySubset,
     config::{global_config, MetadataEraseVersion},
-    node::{meta_key_v2_array, meta_key_v2_attributes, meta_key_v3},
+    node::{
+        check_additional_fields_must_understand, meta_key_v2_array, meta_key_v2_attributes,
+        meta_key_v3,
+    },
     storage::{AsyncBytes, AsyncWritableStorageTraits, StorageError, StorageHandle},
 };
 
 use super::{
     codec::{options::CodecOptions, ArrayToBytesCodecTraits},
     concurrency::concurrency_chunks_and_codec,
-    Array, ArrayError, ArrayMetadata, ArrayMetadataOptions, Element,
+    Array, ArrayError, ArrayIndices, ArrayMetadata, ArrayMetadataOptions, Element, FillValue,
 };
 
 impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
@@ -32,7 +35,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<(), StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_writable_transformer(storage_handle)
             .await?;
 
@@ -44,11 +47,20 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         match metadata {
             ArrayMetadata::V3(metadata) => {
                 let key = meta_key_v3(path);
+                if options.strict_additional_fields() {
+                    check_additional_fields_must_understand(&key, &metadata.additional_fields)?;
+                }
                 let json = serde_json::to_vec_pretty(&metadata)
                     .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
                 storage_transformer.set(&key, json.into()).await
             }
             ArrayMetadata::V2(metadata) => {
+                if options.strict_additional_fields() {
+                    check_additional_fields_must_understand(
+                        &meta_key_v2_array(path),
+                        &metadata.additional_fields,
+                    )?;
+                }
                 let mut metadata = metadata.clone();
 
                 if !metadata.attributes.is_empty() {
@@ -80,7 +92,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_bytes: impl Into<ArrayBytes<'a>> + Send,
     ) -> Result<(), ArrayError> {
-        self.async_store_chunk_opt(chunk_indices, chunk_bytes, &CodecOptions::default())
+        self.async_store_chunk_opt(chunk_indices, chunk_bytes, &self.codec_options())
             .await
     }
 
@@ -91,7 +103,19 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_elements: &[T],
     ) -> Result<(), ArrayError> {
-        self.async_store_chunk_elements_opt(chunk_indices, chunk_elements, &CodecOptions::default())
+        self.async_store_chunk_elements_opt(chunk_indices, chunk_elements, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`store_chunk_elements_string`](Array::store_chunk_elements_string).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_chunk_elements_string(
+        &self,
+        chunk_indices: &[u64],
+        chunk_elements: &[impl AsRef<str> + Sync],
+    ) -> Result<(), ArrayError> {
+        let chunk_elements: Vec<&str> = chunk_elements.iter().map(AsRef::as_ref).collect();
+        self.async_store_chunk_elements(chunk_indices, &chunk_elements)
             .await
     }
 
@@ -103,7 +127,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_array: impl Into<ndarray::Array<T, D>> + Send,
     ) -> Result<(), ArrayError> {
-        self.async_store_chunk_ndarray_opt(chunk_indices, chunk_array, &CodecOptions::default())
+        self.async_store_chunk_ndarray_opt(chunk_indices, chunk_array, &self.codec_options())
             .await
     }
 
@@ -115,7 +139,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         chunks: &ArraySubset,
         chunks_bytes: impl Into<ArrayBytes<'a>> + Send,
     ) -> Result<(), ArrayError> {
-        self.async_store_chunks_opt(chunks, chunks_bytes, &CodecOptions::default())
+        self.async_store_chunks_opt(chunks, chunks_bytes, &self.codec_options())
             .await
     }
 
@@ -126,7 +150,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         chunks: &ArraySubset,
         chunks_elements: &[T],
     ) -> Result<(), ArrayError> {
-        self.async_store_chunks_elements_opt(chunks, chunks_elements, &CodecOptions::default())
+        self.async_store_chunks_elements_opt(chunks, chunks_elements, &self.codec_options())
             .await
     }
 
@@ -138,7 +162,18 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         chunks: &ArraySubset,
         chunks_array: impl Into<ndarray::Array<T, D>> + Send,
     ) -> Result<(), ArrayError> {
-        self.async_store_chunks_ndarray_opt(chunks, chunks_array, &CodecOptions::default())
+        self.async_store_chunks_ndarray_opt(chunks, chunks_array, &self.codec_options())
+            .await
+    }
+
+    /// Async variant of [`store_chunks_batched`](Array::store_chunks_batched).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_chunks_batched<'a>(
+        &self,
+        chunks: &ArraySubset,
+        chunks_bytes: impl Into<ArrayBytes<'a>> + Send,
+    ) -> Result<(), ArrayError> {
+        self.async_store_chunks_batched_opt(chunks, chunks_bytes, &self.codec_options())
             .await
     }
 
@@ -194,12 +229,14 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
     pub async fn async_erase_chunk(&self, chunk_indices: &[u64]) -> Result<(), StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_writable_transformer(storage_handle)
             .await?;
         storage_transformer
             .erase(&self.chunk_key(chunk_indices))
-            .await
+            .await?;
+        self.invalidate_cached_chunk(chunk_indices);
+        Ok(())
     }
 
     /// Async variant of [`erase_chunks`](Array::erase_chunks).
@@ -207,7 +244,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
     pub async fn async_erase_chunks(&self, chunks: &ArraySubset) -> Result<(), StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_writable_transformer(storage_handle)
             .await?;
         let erase_chunk = |chunk_indices: Vec<u64>| {
@@ -215,12 +252,26 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
             async move {
                 storage_transformer
                     .erase(&self.chunk_key(&chunk_indices))
-                    .await
+                    .await?;
+                self.invalidate_cached_chunk(&chunk_indices);
+                Ok(())
             }
         };
-        futures::stream::iter(chunks.indices().into_iter())
+        let chunk_concurrent_maximum = global_config().chunk_concurrent_maximum().max(1);
+        futures::stream::iter(&chunks.indices())
             .map(Ok)
-            .try_for_each_concurrent(None, erase_chunk)
+            .try_for_each_concurrent(Some(chunk_concurrent_maximum), erase_chunk)
+            .await
+    }
+
+    /// Async variant of [`fill_chunks`](Array::fill_chunks).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_fill_chunks(
+        &self,
+        chunks: &ArraySubset,
+        value: &FillValue,
+    ) -> Result<(), ArrayError> {
+        self.async_fill_chunks_opt(chunks, value, &self.codec_options())
             .await
     }
 
@@ -269,12 +320,13 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<(), ArrayError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_async_writable_transformer(storage_handle)
             .await?;
         storage_transformer
             .set(&self.chunk_key(chunk_indices), encoded_chunk_bytes)
             .await?;
+        self.invalidate_cached_chunk(chunk_indices);
         Ok(())
     }
 
@@ -286,7 +338,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         chunk_elements: &[T],
         options: &CodecOptions,
     ) -> Result<(), ArrayError> {
-        let bytes = T::into_array_bytes(self.data_type(), chunk_elements)?;
+        let bytes = T::into_array_bytes(self.data_type(), self.fill_value(), chunk_elements)?;
         self.async_store_chunk_opt(chunk_indices, bytes, options)
             .await
     }
@@ -376,6 +428,121 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         Ok(())
     }
 
+    /// Async variant of [`store_chunks_batched_opt`](Array::store_chunks_batched_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_chunks_batched_opt<'a>(
+        &self,
+        chunks: &ArraySubset,
+        chunks_bytes: impl Into<ArrayBytes<'a>> + Send,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let chunks_bytes = chunks_bytes.into();
+        let (encoded_chunks, erased_chunks): (Vec<_>, Vec<_>) = self
+            .encode_chunks_batched(chunks, &chunks_bytes, options)?
+            .into_iter()
+            .partition(|(_, chunk_encoded)| chunk_encoded.is_some());
+        if !erased_chunks.is_empty() {
+            let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+            let storage_transformer = self
+                .applied_storage_transformers()
+                .create_async_writable_transformer(storage_handle)
+                .await?;
+            let keys: Vec<_> = erased_chunks
+                .iter()
+                .map(|(chunk_indices, _)| self.chunk_key(chunk_indices))
+                .collect();
+            storage_transformer.erase_values(&keys).await?;
+            for (chunk_indices, _) in &erased_chunks {
+                self.invalidate_cached_chunk(chunk_indices);
+            }
+        }
+        let encoded_chunks: Vec<_> = encoded_chunks
+            .into_iter()
+            .filter_map(|(chunk_indices, chunk_encoded)| Some((chunk_indices, chunk_encoded?)))
+            .collect();
+        unsafe { self.async_store_encoded_chunks_batched(&encoded_chunks) }.await
+    }
+
+    /// Async variant of [`store_encoded_chunks_batched`](Array::store_encoded_chunks_batched).
+    #[allow(clippy::missing_errors_doc, clippy::missing_safety_doc)]
+    pub async unsafe fn async_store_encoded_chunks_batched(
+        &self,
+        encoded_chunks: &[(ArrayIndices, AsyncBytes)],
+    ) -> Result<(), ArrayError> {
+        if encoded_chunks.is_empty() {
+            return Ok(());
+        }
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_async_writable_transformer(storage_handle)
+            .await?;
+        let key_values: Vec<_> = encoded_chunks
+            .iter()
+            .map(|(chunk_indices, encoded_chunk_bytes)| {
+                (self.chunk_key(chunk_indices), encoded_chunk_bytes.clone())
+            })
+            .collect();
+        storage_transformer.set_many(&key_values).await?;
+        for (chunk_indices, _) in encoded_chunks {
+            self.invalidate_cached_chunk(chunk_indices);
+        }
+        Ok(())
+    }
+
+    /// Async variant of [`fill_chunks_opt`](Array::fill_chunks_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_fill_chunks_opt(
+        &self,
+        chunks: &ArraySubset,
+        value: &FillValue,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        self.validate_fill(value)?;
+
+        // Calculate chunk/codec concurrency
+        let num_chunks = chunks.num_elements_usize();
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            num_chunks,
+            options,
+            &codec_concurrency,
+        );
+
+        let fill_chunk = |chunk_indices: Vec<u64>| {
+            let options = options.clone();
+            async move {
+                self.async_fill_chunk_opt(&chunk_indices, value, &options)
+                    .await
+            }
+        };
+        futures::stream::iter(&chunks.indices())
+            .map(Ok)
+            .try_for_each_concurrent(Some(chunk_concurrent_limit), fill_chunk)
+            .await
+    }
+
+    /// Async variant of [`fill_chunk_opt`](Array::fill_chunk_opt).
+    pub(super) async fn async_fill_chunk_opt(
+        &self,
+        chunk_indices: &[u64],
+        value: &FillValue,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let chunk_representation = self.chunk_array_representation(chunk_indices)?;
+        if value == self.fill_value() {
+            self.async_erase_chunk(chunk_indices).await?;
+            Ok(())
+        } else {
+            let chunk_bytes = self.fill_bytes(value, chunk_representation.num_elements());
+            self.async_store_chunk_opt(chunk_indices, chunk_bytes, options)
+                .await
+        }
+    }
+
     /// Async variant of [`store_chunks_elements_opt`](Array::store_chunks_elements_opt).
     #[allow(clippy::missing_errors_doc)]
     pub async fn async_store_chunks_elements_opt<T: Element + Send + Sync>(
@@ -384,7 +551,8 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits + 'static> Array<TStorage> {
         chunks_elements: &[T],
         options: &CodecOptions,
     ) -> Result<(), ArrayError> {
-        let chunks_bytes = T::into_array_bytes(self.data_type(), chunks_elements)?;
+        let chunks_bytes =
+            T::into_array_bytes(self.data_type(), self.fill_value(), chunks_elements)?;
         self.async_store_chunks_opt(chunks, chunks_bytes, options)
             .await
     }
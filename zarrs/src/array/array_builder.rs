@@ -1,28 +1,38 @@
 use std::sync::Arc;
 
+use itertools::Itertools;
+
 use crate::{
-    metadata::{v3::AdditionalFields, ChunkKeySeparator},
+    metadata::{
+        v3::AdditionalFields, v3_to_v2::array_metadata_v3_to_v2, ChunkKeySeparator, ZarrVersion,
+    },
     node::NodePath,
 };
 
 use super::{
-    chunk_key_encoding::{ChunkKeyEncoding, DefaultChunkKeyEncoding},
+    chunk_grid::{
+        rectangular, RectangularChunkGrid, RectangularChunkGridConfiguration,
+        RectangularChunkGridDimensionConfiguration,
+    },
+    chunk_key_encoding::{ChunkKeyEncoding, DefaultChunkKeyEncoding, V2ChunkKeyEncoding},
     codec::{
         array_to_bytes::vlen::VlenCodec, ArrayToArrayCodecTraits, ArrayToBytesCodecTraits,
         BytesCodec, BytesToBytesCodecTraits,
     },
     data_type::IncompatibleFillValueError,
-    Array, ArrayCreateError, ArrayMetadata, ArrayMetadataV3, ArrayShape, ChunkGrid, CodecChain,
-    DataType, DimensionName, FillValue, StorageTransformerChain,
+    validate_codecs, Array, ArrayCreateError, ArrayMetadata, ArrayMetadataV3, ArrayShape,
+    ChunkGrid, ChunkRepresentation, ChunkShape, CodecChain, DataType, DimensionName, Endianness,
+    FillValue, StorageTransformerChain,
 };
 
 /// An [`Array`] builder.
 ///
 /// The array builder is initialised from an array shape, data type, chunk grid, and fill value.
-///  - The only codec enabled by default is `bytes` (with native endian encoding), so the output is uncompressed.
+///  - The only codec enabled by default is `bytes` (with little endian encoding), so the output is uncompressed.
 ///  - The default chunk key encoding is `default` with the `/` chunk key separator.
 ///  - Attributes, storage transformers, and dimension names are empty.
 ///  - Codecs are configured to use multiple threads where possible.
+///  - The array has Zarr V3 metadata. Use [`ArrayBuilder::zarr_format`] to create a Zarr V2 array.
 ///
 /// Use the methods in the array builder to change the configuration away from these defaults, and then build the array at a path of some storage with [`ArrayBuilder::build`].
 /// Note that [`build`](ArrayBuilder::build) does not modify the store; the array metadata has to be explicitly written with [`Array::store_metadata`].
@@ -83,6 +93,8 @@ pub struct ArrayBuilder {
     pub dimension_names: Option<Vec<DimensionName>>,
     /// Additional fields.
     pub additional_fields: AdditionalFields,
+    /// Zarr format.
+    pub zarr_format: ZarrVersion,
 }
 
 impl ArrayBuilder {
@@ -90,6 +102,8 @@ impl ArrayBuilder {
     ///
     /// The length of the array shape must match the dimensionality of the intended array, but it can be all zeros on initialisation.
     /// The shape of the [`Array`] can be be updated as required.
+    ///
+    /// A zero-dimensional (scalar) array has an empty shape and chunk shape, and a single chunk with empty chunk indices.
     #[must_use]
     pub fn new(
         shape: ArrayShape,
@@ -116,10 +130,17 @@ impl ArrayBuilder {
             storage_transformers: StorageTransformerChain::default(),
             dimension_names: None,
             additional_fields: AdditionalFields::default(),
+            zarr_format: ZarrVersion::V3,
         }
     }
 
     /// Create a new builder copying the configuration of an existing array.
+    ///
+    /// The shape, data type, chunk grid, chunk key encoding, fill value, codecs, storage transformers, attributes, additional fields, and dimension names are copied from `array`.
+    /// Any of these can then be overridden before building a derived array.
+    ///
+    /// The attributes, additional fields, and dimension names are deep copied, so modifying them on the builder does not affect `array`.
+    /// The codecs and storage transformers are shared with `array` through reference counting, which is cheap since they are immutable.
     #[must_use]
     pub fn from_array<T: ?Sized>(array: &Array<T>) -> Self {
         let mut builder = Self::new(
@@ -158,6 +179,18 @@ impl ArrayBuilder {
         self
     }
 
+    /// Set the chunk grid to a `rectangular` chunk grid with chunk shapes `chunk_shapes`.
+    ///
+    /// Each dimension has either a fixed chunk size or a list of varying chunk sizes.
+    /// Varying chunk sizes must sum to the array shape along that dimension, otherwise [`build`](ArrayBuilder::build) will fail.
+    pub fn rectangular_chunk_grid(
+        &mut self,
+        chunk_shapes: &[RectangularChunkGridDimensionConfiguration],
+    ) -> &mut Self {
+        self.chunk_grid = ChunkGrid::new(RectangularChunkGrid::new(chunk_shapes));
+        self
+    }
+
     /// Set the fill value.
     pub fn fill_value(&mut self, fill_value: FillValue) -> &mut Self {
         self.fill_value = fill_value;
@@ -167,6 +200,8 @@ impl ArrayBuilder {
     /// Set the chunk key encoding.
     ///
     /// If left unmodified, the array will use `default` chunk key encoding with the `/` chunk key separator.
+    ///
+    /// For example, [`V2ChunkKeyEncoding::new_dot()`] produces chunk keys like `0.0.0` that match the layout of Zarr V2 arrays.
     pub fn chunk_key_encoding(&mut self, chunk_key_encoding: ChunkKeyEncoding) -> &mut Self {
         self.chunk_key_encoding = chunk_key_encoding;
         self
@@ -183,6 +218,15 @@ impl ArrayBuilder {
         self
     }
 
+    /// Set the chunk key encoding to `v2` with `separator`.
+    ///
+    /// The chunk keys have no `c` prefix, e.g. `0.0.0` with the `.` separator or `0/0/0` with the `/` separator.
+    /// The chunk key of a zero-dimensional array is `0`.
+    pub fn chunk_key_encoding_v2_separator(&mut self, separator: ChunkKeySeparator) -> &mut Self {
+        self.chunk_key_encoding = ChunkKeyEncoding::new(V2ChunkKeyEncoding::new(separator));
+        self
+    }
+
     /// Set the array to array codecs.
     ///
     /// If left unmodified, the array will have no array to array codecs.
@@ -196,7 +240,7 @@ impl ArrayBuilder {
 
     /// Set the array to bytes codec.
     ///
-    /// If left unmodified, the array will default to using the `bytes` codec with native endian encoding.
+    /// If left unmodified, the array will default to using the `bytes` codec with little endian encoding.
     pub fn array_to_bytes_codec(
         &mut self,
         array_to_bytes_codec: Arc<dyn ArrayToBytesCodecTraits>,
@@ -205,6 +249,16 @@ impl ArrayBuilder {
         self
     }
 
+    /// Set the array to bytes codec to the `bytes` codec with `endianness`.
+    ///
+    /// This replaces the array to bytes codec, so it should not be combined with [`array_to_bytes_codec`](ArrayBuilder::array_to_bytes_codec).
+    /// If left unmodified, the array will default to using the `bytes` codec with little endian encoding.
+    /// Use [`Endianness::native`] to explicitly encode with the native endianness of the CPU.
+    pub fn bytes_endianness(&mut self, endianness: Endianness) -> &mut Self {
+        self.array_to_bytes_codec = Arc::new(BytesCodec::new(Some(endianness)));
+        self
+    }
+
     /// Set the bytes to bytes codecs.
     ///
     /// If left unmodified, the array will have no bytes to bytes codecs.
@@ -271,12 +325,31 @@ impl ArrayBuilder {
         self
     }
 
+    /// Set the Zarr format of the array metadata.
+    ///
+    /// If left unmodified, the array has Zarr V3 metadata.
+    ///
+    /// Setting [`ZarrVersion::V2`] also sets the chunk key encoding to `v2` with the `.` separator (the Zarr V2 default).
+    /// Call [`ArrayBuilder::chunk_key_encoding_v2_separator`] afterwards to use the `/` separator.
+    ///
+    /// A Zarr V2 array must be representable in Zarr V2 metadata (`.zarray` and `.zattrs`), otherwise [`build`](ArrayBuilder::build) returns [`ArrayCreateError::IncompatibleZarrV2`].
+    /// See [`array_metadata_v3_to_v2`] for the supported configurations.
+    /// For example, sharding, the `string` data type, and the `rectangular` chunk grid are not supported.
+    pub fn zarr_format(&mut self, zarr_format: ZarrVersion) -> &mut Self {
+        if zarr_format == ZarrVersion::V2 {
+            self.chunk_key_encoding = ChunkKeyEncoding::new(V2ChunkKeyEncoding::new_dot());
+        }
+        self.zarr_format = zarr_format;
+        self
+    }
+
     /// Build into an [`Array`].
     ///
     /// # Errors
     ///
     /// Returns [`ArrayCreateError`] if there is an error creating the array.
     /// This can be due to a storage error, an invalid path, or a problem with array configuration.
+    /// Returns [`ArrayCreateError::IncompatibleZarrV2`] if the [Zarr format](ArrayBuilder::zarr_format) is V2 and the configuration is not supported by Zarr V2.
     pub fn build<TStorage: ?Sized>(
         &self,
         storage: Arc<TStorage>,
@@ -308,26 +381,50 @@ impl ArrayBuilder {
             }
         }
 
+        if matches!(self.chunk_grid.grid_shape(&self.shape), Ok(None)) {
+            return Err(ArrayCreateError::IncompatibleChunkGrid(self.shape.clone()));
+        }
+
         let codec_chain = CodecChain::new(
             self.array_to_array_codecs.clone(),
             self.array_to_bytes_codec.clone(),
             self.bytes_to_bytes_codecs.clone(),
         );
 
-        let array_metadata = ArrayMetadata::V3(
-            ArrayMetadataV3::new(
-                self.shape.clone(),
-                self.chunk_grid.create_metadata(),
-                self.data_type.metadata(),
-                self.data_type.metadata_fill_value(&self.fill_value),
-                codec_chain.create_metadatas(),
-            )
-            .with_attributes(self.attributes.clone())
-            .with_additional_fields(self.additional_fields.clone())
-            .with_chunk_key_encoding(self.chunk_key_encoding.create_metadata())
-            .with_dimension_names(self.dimension_names.clone())
-            .with_storage_transformers(self.storage_transformers.create_metadatas()),
-        );
+        // Check that the codecs support the data type, and every chunk shape in the chunk grid
+        for (i, chunk_shape) in self.distinct_chunk_shapes().into_iter().enumerate() {
+            let chunk_representation = ChunkRepresentation::new(
+                chunk_shape.to_vec(),
+                self.data_type.clone(),
+                self.fill_value.clone(),
+            )?;
+            if i == 0 {
+                validate_codecs(&codec_chain, &chunk_representation)?;
+            }
+            if let Err(err) = codec_chain.compute_encoded_size(&chunk_representation) {
+                return Err(ArrayCreateError::IncompatibleChunkShape(
+                    chunk_shape.to_array_shape(),
+                    err,
+                ));
+            }
+        }
+
+        let array_metadata = ArrayMetadataV3::new(
+            self.shape.clone(),
+            self.chunk_grid.create_metadata(),
+            self.data_type.metadata(),
+            self.data_type.metadata_fill_value(&self.fill_value),
+            codec_chain.create_metadatas(),
+        )
+        .with_attributes(self.attributes.clone())
+        .with_additional_fields(self.additional_fields.clone())
+        .with_chunk_key_encoding(self.chunk_key_encoding.create_metadata())
+        .with_dimension_names(self.dimension_names.clone())
+        .with_storage_transformers(self.storage_transformers.create_metadatas());
+        let array_metadata = match self.zarr_format {
+            ZarrVersion::V3 => ArrayMetadata::V3(array_metadata),
+            ZarrVersion::V2 => ArrayMetadata::V2(array_metadata_v3_to_v2(&array_metadata)?),
+        };
 
         Ok(Array {
             storage,
@@ -343,13 +440,50 @@ impl ArrayBuilder {
                 self.bytes_to_bytes_codecs.clone(),
             )),
             storage_transformers: self.storage_transformers.clone(),
+            applied_storage_transformers: self.storage_transformers.clone(),
             // attributes: self.attributes.clone(),
             dimension_names: self.dimension_names.clone(),
             // additional_fields: self.additional_fields.clone(),
             metadata: array_metadata,
+            chunk_cache: None,
+            partial_decoder_cache: None,
+            corrupt_chunks: std::sync::Mutex::default(),
+            validate_checksums: None,
+            store_empty_chunks: None,
         })
     }
 
+    /// Return the distinct chunk shapes of the chunk grid.
+    ///
+    /// Only the chunk at the origin is considered for chunk grids other than `rectangular`.
+    fn distinct_chunk_shapes(&self) -> Vec<ChunkShape> {
+        let metadata = self.chunk_grid.create_metadata();
+        if metadata.name() == rectangular::IDENTIFIER {
+            if let Ok(configuration) =
+                metadata.to_configuration::<RectangularChunkGridConfiguration>()
+            {
+                return configuration
+                    .chunk_shape
+                    .iter()
+                    .map(|chunk_dim| match chunk_dim {
+                        RectangularChunkGridDimensionConfiguration::Fixed(size) => vec![*size],
+                        RectangularChunkGridDimensionConfiguration::Varying(sizes) => {
+                            sizes.iter().copied().unique().collect()
+                        }
+                    })
+                    .multi_cartesian_product()
+                    .map(ChunkShape::from)
+                    .collect();
+            }
+        }
+        self.chunk_grid
+            .chunk_shape(&vec![0; self.shape.len()], &self.shape)
+            .ok()
+            .flatten()
+            .into_iter()
+            .collect()
+    }
+
     /// Build into an [`Arc<Array>`].
     ///
     /// # Errors
@@ -404,6 +538,7 @@ mod tests {
         builder.additional_fields(additional_fields.clone());
 
         builder.chunk_key_encoding(V2ChunkKeyEncoding::new_dot().into());
+        builder.chunk_key_encoding_v2_separator(ChunkKeySeparator::Slash);
         builder.chunk_key_encoding_default_separator(ChunkKeySeparator::Dot); // overrides previous
         let log_writer = Arc::new(std::sync::Mutex::new(std::io::stdout()));
 
@@ -459,4 +594,104 @@ mod tests {
         builder.dimension_names(["z", "y", "x"].into());
         assert!(builder.build(storage.clone(), "/").is_err());
     }
+
+    #[test]
+    fn array_builder_rectangular() {
+        let storage = Arc::new(MemoryStore::new());
+        let mut builder = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::UInt16,
+            vec![8, 8].try_into().unwrap(),
+            FillValue::from(0u16),
+        );
+        builder.rectangular_chunk_grid(&[
+            vec![1, 2, 5].try_into().unwrap(),
+            RectangularChunkGridDimensionConfiguration::Fixed(4.try_into().unwrap()),
+        ]);
+        let array = builder.build(storage.clone(), "/").unwrap();
+        array.store_metadata().unwrap();
+        assert_eq!(array.chunk_grid_shape(), Some(vec![3, 2]));
+        assert_eq!(
+            array.chunk_shape(&[2, 1]).unwrap(),
+            vec![5, 4].try_into().unwrap()
+        );
+        assert_eq!(
+            Array::open(storage.clone(), "/")
+                .unwrap()
+                .chunk_grid()
+                .create_metadata(),
+            array.chunk_grid().create_metadata()
+        );
+
+        let elements: Vec<u16> = (0..64).collect();
+        array
+            .store_array_subset_elements(&array.subset_all(), &elements)
+            .unwrap();
+        assert_eq!(
+            array
+                .retrieve_array_subset_elements::<u16>(&array.subset_all())
+                .unwrap(),
+            elements
+        );
+        assert_eq!(
+            array.retrieve_chunk_elements::<u16>(&[1, 0]).unwrap(),
+            vec![8, 9, 10, 11, 16, 17, 18, 19]
+        );
+    }
+
+    #[test]
+    fn array_builder_rectangular_invalid() {
+        let storage = Arc::new(MemoryStore::new());
+        let mut builder = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::UInt16,
+            vec![8, 8].try_into().unwrap(),
+            FillValue::from(0u16),
+        );
+        // Chunk sizes do not sum to the array shape
+        builder.rectangular_chunk_grid(&[
+            vec![1, 2, 3].try_into().unwrap(),
+            RectangularChunkGridDimensionConfiguration::Fixed(4.try_into().unwrap()),
+        ]);
+        assert!(matches!(
+            builder.build(storage.clone(), "/"),
+            Err(ArrayCreateError::IncompatibleChunkGrid(_))
+        ));
+    }
+
+    #[cfg(feature = "sharding")]
+    #[test]
+    fn array_builder_rectangular_sharding_invalid() {
+        use crate::array::codec::array_to_bytes::sharding::ShardingCodecBuilder;
+
+        let storage = Arc::new(MemoryStore::new());
+        let mut builder = ArrayBuilder::new(
+            vec![8, 8],
+            DataType::UInt16,
+            vec![8, 8].try_into().unwrap(),
+            FillValue::from(0u16),
+        );
+        builder.array_to_bytes_codec(Arc::new(
+            ShardingCodecBuilder::new(vec![2, 2].try_into().unwrap()).build(),
+        ));
+
+        // Every shard shape is divisible by the inner chunk shape
+        builder.rectangular_chunk_grid(&[
+            vec![2, 6].try_into().unwrap(),
+            RectangularChunkGridDimensionConfiguration::Fixed(4.try_into().unwrap()),
+        ]);
+        assert!(builder.build(storage.clone(), "/").is_ok());
+
+        // The inner chunk shape does not divide a shard of shape [3, 4]
+        builder.rectangular_chunk_grid(&[
+            vec![2, 3, 3].try_into().unwrap(),
+            RectangularChunkGridDimensionConfiguration::Fixed(4.try_into().unwrap()),
+        ]);
+        let Err(ArrayCreateError::IncompatibleChunkShape(chunk_shape, _)) =
+            builder.build(storage.clone(), "/")
+        else {
+            panic!("expected an incompatible chunk shape error");
+        };
+        assert_eq!(chunk_shape, vec![3, 4]);
+    }
 }
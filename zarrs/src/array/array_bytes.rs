@@ -145,14 +145,52 @@ impl<'a> ArrayBytes<'a> {
     }
 
     /// Returns [`true`] if the array is empty for the given fill value.
+    ///
+    /// For a variable-length array, the bytes of each element are compared against the fill value.
     #[must_use]
     pub fn is_fill_value(&self, fill_value: &FillValue) -> bool {
         match self {
             Self::Fixed(bytes) => fill_value.equals_all(bytes),
-            Self::Variable(bytes, _offsets) => fill_value.equals_all(bytes),
+            Self::Variable(bytes, offsets) => {
+                let fill_value = fill_value.as_ne_bytes();
+                // Every element must have the size of the fill value
+                if bytes.len() != fill_value.len() * offsets.len().saturating_sub(1) {
+                    return false;
+                }
+                offsets
+                    .iter()
+                    .tuple_windows()
+                    .all(|(&start, &end)| bytes.get(start..end) == Some(fill_value))
+            }
         }
     }
 
+    /// Returns the number of elements that are not the fill value.
+    ///
+    /// For a variable-length array, the bytes of each element are compared against the fill value.
+    #[must_use]
+    pub fn num_non_fill_value_elements(&self, fill_value: &FillValue) -> u64 {
+        let fill_value_bytes = fill_value.as_ne_bytes();
+        let count = match self {
+            Self::Fixed(bytes) => {
+                if fill_value_bytes.is_empty() || fill_value.equals_all(bytes) {
+                    0
+                } else {
+                    bytes
+                        .chunks_exact(fill_value_bytes.len())
+                        .filter(|element| *element != fill_value_bytes)
+                        .count()
+                }
+            }
+            Self::Variable(bytes, offsets) => offsets
+                .iter()
+                .tuple_windows()
+                .filter(|(&start, &end)| bytes.get(start..end) != Some(fill_value_bytes))
+                .count(),
+        };
+        count as u64
+    }
+
     /// Extract a subset of the array bytes.
     ///
     /// # Errors
@@ -611,7 +649,7 @@ mod tests {
     #[test]
     fn array_bytes_flen() -> Result<(), Box<dyn Error>> {
         let data = [0u32, 1, 2, 3, 4];
-        let bytes = Element::into_array_bytes(&DataType::UInt32, &data)?;
+        let bytes = Element::into_array_bytes(&DataType::UInt32, &FillValue::from(0u32), &data)?;
         let ArrayBytes::Fixed(bytes) = bytes else {
             panic!()
         };
@@ -623,7 +661,7 @@ mod tests {
     #[test]
     fn array_bytes_str() -> Result<(), Box<dyn Error>> {
         let data = ["a", "bb", "ccc"];
-        let bytes = Element::into_array_bytes(&DataType::String, &data)?;
+        let bytes = Element::into_array_bytes(&DataType::String, &FillValue::from(""), &data)?;
         let ArrayBytes::Variable(bytes, offsets) = bytes else {
             panic!()
         };
@@ -633,6 +671,58 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn array_bytes_vlen_is_fill_value() -> Result<(), Box<dyn Error>> {
+        let fill_value = FillValue::from("ab");
+        let bytes = Element::into_array_bytes(
+            &DataType::String,
+            &FillValue::from(""),
+            &["ab", "ab", "ab"],
+        )?;
+        assert!(bytes.is_fill_value(&fill_value));
+        let bytes = Element::into_array_bytes(
+            &DataType::String,
+            &FillValue::from(""),
+            &["abab", "", "ab"],
+        )?;
+        assert!(!bytes.is_fill_value(&fill_value));
+        let bytes = Element::into_array_bytes(
+            &DataType::String,
+            &FillValue::from(""),
+            &["ab", "ab", "ba"],
+        )?;
+        assert!(!bytes.is_fill_value(&fill_value));
+
+        let fill_value = FillValue::from("");
+        let bytes =
+            Element::into_array_bytes(&DataType::String, &FillValue::from(""), &["", "", ""])?;
+        assert!(bytes.is_fill_value(&fill_value));
+        let bytes =
+            Element::into_array_bytes(&DataType::String, &FillValue::from(""), &["", "a", ""])?;
+        assert!(!bytes.is_fill_value(&fill_value));
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_bytes_num_non_fill_value_elements() -> Result<(), Box<dyn Error>> {
+        let fill_value = FillValue::from(7u16);
+        let bytes = Element::into_array_bytes(&DataType::UInt16, &fill_value, &[7u16, 7, 7, 7])?;
+        assert_eq!(bytes.num_non_fill_value_elements(&fill_value), 0);
+        let bytes = Element::into_array_bytes(&DataType::UInt16, &fill_value, &[7u16, 0, 7, 1])?;
+        assert_eq!(bytes.num_non_fill_value_elements(&fill_value), 2);
+
+        let fill_value = FillValue::from("ab");
+        let bytes = Element::into_array_bytes(
+            &DataType::String,
+            &FillValue::from(""),
+            &["ab", "abab", "", "ab"],
+        )?;
+        assert_eq!(bytes.num_non_fill_value_elements(&fill_value), 2);
+
+        Ok(())
+    }
+
     #[test]
     fn test_flen_update_subset() {
         let mut bytes_array = vec![0u8; 4 * 4];
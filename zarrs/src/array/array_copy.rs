@@ -0,0 +1,193 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use thiserror::Error;
+
+use crate::{
+    array_subset::ArraySubset,
+    storage::{ReadableStorageTraits, ReadableWritableStorageTraits},
+};
+
+use super::{
+    codec::CodecOptions, concurrency::concurrency_chunks_and_codec, Array, ArrayBuilder,
+    ArrayCreateError, ArrayError, ArrayShape, DataType,
+};
+
+/// A progress callback for [`copy_array`].
+///
+/// The arguments are the number of destination chunks that have been written and the total number of destination chunks.
+pub type ArrayCopyProgressCallback = dyn Fn(u64, u64) + Send + Sync;
+
+/// Options for [`copy_array`].
+#[derive(Clone, Default)]
+pub struct ArrayCopyOptions {
+    codec_options: CodecOptions,
+    progress_callback: Option<Arc<ArrayCopyProgressCallback>>,
+}
+
+impl std::fmt::Debug for ArrayCopyOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrayCopyOptions")
+            .field("codec_options", &self.codec_options)
+            .field("progress_callback", &self.progress_callback.is_some())
+            .finish()
+    }
+}
+
+impl ArrayCopyOptions {
+    /// Get the codec options.
+    #[must_use]
+    pub fn codec_options(&self) -> &CodecOptions {
+        &self.codec_options
+    }
+
+    /// Set the codec options.
+    ///
+    /// The number of destination chunks processed concurrently is bounded by the [`CodecOptions::chunk_concurrent_maximum`].
+    #[must_use]
+    pub fn with_codec_options(mut self, codec_options: CodecOptions) -> Self {
+        self.codec_options = codec_options;
+        self
+    }
+
+    /// Set the codec options.
+    pub fn set_codec_options(&mut self, codec_options: CodecOptions) -> &mut Self {
+        self.codec_options = codec_options;
+        self
+    }
+
+    /// Get the progress callback.
+    #[must_use]
+    pub fn progress_callback(&self) -> Option<&ArrayCopyProgressCallback> {
+        self.progress_callback.as_deref()
+    }
+
+    /// Set the progress callback.
+    #[must_use]
+    pub fn with_progress_callback(
+        mut self,
+        progress_callback: impl Fn(u64, u64) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress_callback = Some(Arc::new(progress_callback));
+        self
+    }
+
+    /// Set the progress callback.
+    pub fn set_progress_callback(
+        &mut self,
+        progress_callback: impl Fn(u64, u64) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.progress_callback = Some(Arc::new(progress_callback));
+        self
+    }
+}
+
+/// An array copy error.
+#[derive(Debug, Error)]
+pub enum ArrayCopyError {
+    /// The destination array could not be created.
+    #[error(transparent)]
+    ArrayCreateError(#[from] ArrayCreateError),
+    /// An error reading the source array or writing the destination array.
+    #[error(transparent)]
+    ArrayError(#[from] ArrayError),
+    /// The destination shape does not match the source shape.
+    #[error("destination array shape {_1:?} does not match source array shape {_0:?}")]
+    IncompatibleShape(ArrayShape, ArrayShape),
+    /// The destination data type does not match the source data type.
+    #[error("destination data type {_1} does not match source data type {_0}")]
+    IncompatibleDataType(DataType, DataType),
+}
+
+/// Copy the `src` array to a new array created with `dst_builder` in `dst_storage` at `dst_path`.
+///
+/// This can change the chunk grid, codecs, chunk key encoding, etc. of an array (e.g. rechunking or recompressing).
+/// The shape and data type of `dst_builder` must match `src`.
+/// The attributes and dimension names of `src` are copied to the destination array, unless they are set in `dst_builder`.
+///
+/// The destination metadata is stored, then each destination chunk (or shard) is retrieved from `src` and stored.
+/// Destination chunks are processed in parallel, and the number processed concurrently is bounded by the [`CodecOptions::chunk_concurrent_maximum`] of the [`ArrayCopyOptions`].
+/// Destination chunks that are entirely the fill value are not stored, unless the `store_empty_chunks` codec option is set.
+///
+/// # Errors
+/// Returns an [`ArrayCopyError`] if
+///  - the destination shape or data type does not match `src`,
+///  - the destination array cannot be created, or
+///  - there is an error reading `src` or writing the destination array.
+pub fn copy_array<
+    TSrcStorage: ?Sized + ReadableStorageTraits + 'static,
+    TDstStorage: ?Sized + ReadableWritableStorageTraits + 'static,
+>(
+    src: &Array<TSrcStorage>,
+    mut dst_builder: ArrayBuilder,
+    dst_storage: Arc<TDstStorage>,
+    dst_path: &str,
+    options: &ArrayCopyOptions,
+) -> Result<Array<TDstStorage>, ArrayCopyError> {
+    if dst_builder.shape != src.shape() {
+        return Err(ArrayCopyError::IncompatibleShape(
+            src.shape().to_vec(),
+            dst_builder.shape,
+        ));
+    }
+    if &dst_builder.data_type != src.data_type() {
+        return Err(ArrayCopyError::IncompatibleDataType(
+            src.data_type().clone(),
+            dst_builder.data_type,
+        ));
+    }
+    if dst_builder.attributes.is_empty() {
+        dst_builder.attributes.clone_from(src.attributes());
+    }
+    if dst_builder.dimension_names.is_none() {
+        dst_builder
+            .dimension_names
+            .clone_from(src.dimension_names());
+    }
+
+    let dst = dst_builder.build(dst_storage, dst_path)?;
+    dst.store_metadata().map_err(ArrayError::from)?;
+
+    let Some(chunk_grid_shape) = dst.chunk_grid_shape() else {
+        return Err(ArrayError::IncompatibleArrayShape(dst.shape().to_vec()).into());
+    };
+    let chunks = ArraySubset::new_with_shape(chunk_grid_shape);
+    let num_chunks = chunks.num_elements();
+
+    let chunk_representation = dst.chunk_array_representation(&vec![0; dst.dimensionality()])?;
+    let codec_concurrency = dst.recommended_codec_concurrency(&chunk_representation)?;
+    let (chunk_concurrent_limit, codec_options) = concurrency_chunks_and_codec(
+        options.codec_options().concurrent_target(),
+        chunks.num_elements_usize(),
+        options.codec_options(),
+        &codec_concurrency,
+    );
+
+    let num_chunks_copied = AtomicU64::new(0);
+    let copy_chunk = |chunk_indices: Vec<u64>| -> Result<(), ArrayError> {
+        // The subset is bounded, so edge chunks only retrieve the elements of `src` within the array shape
+        let chunk_subset = dst.chunk_subset_bounded(&chunk_indices)?;
+        let bytes = src.retrieve_array_subset_opt(&chunk_subset, &codec_options)?;
+        dst.store_array_subset_opt(&chunk_subset, bytes, &codec_options)?;
+        if let Some(progress_callback) = options.progress_callback() {
+            let num_chunks_copied = num_chunks_copied.fetch_add(1, Ordering::Relaxed) + 1;
+            progress_callback(num_chunks_copied, num_chunks);
+        }
+        Ok(())
+    };
+
+    let indices = chunks.indices();
+    codec_options.install(|| {
+        rayon_iter_concurrent_limit::iter_concurrent_limit!(
+            chunk_concurrent_limit,
+            indices,
+            try_for_each,
+            copy_chunk
+        )
+    })?;
+
+    Ok(dst)
+}
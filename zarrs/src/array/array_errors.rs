@@ -2,14 +2,15 @@ use thiserror::Error;
 
 use crate::{
     array_subset::{ArraySubset, IncompatibleDimensionalityError},
-    metadata::v3::UnsupportedAdditionalFieldError,
+    config::MetadataRetrieveVersion,
+    metadata::{v3::UnsupportedAdditionalFieldError, v3_to_v2::ArrayMetadataV3ToV2ConversionError},
     node::NodePathError,
     plugin::PluginCreateError,
     storage::StorageError,
 };
 
 use super::{
-    codec::CodecError,
+    codec::{CodecError, CodecIncompatibleWithDataTypeError},
     data_type::{
         IncompatibleFillValueError, IncompatibleFillValueMetadataError, UnsupportedDataTypeError,
     },
@@ -52,15 +53,35 @@ pub enum ArrayCreateError {
     /// The number of dimension names does not match the array dimensionality.
     #[error("the number of dimension names {0} does not match array dimensionality {1}")]
     InvalidDimensionNames(usize, usize),
+    /// The chunk grid is incompatible with the array shape.
+    #[error("the chunk grid is incompatible with the array shape {0:?}")]
+    IncompatibleChunkGrid(ArrayShape),
+    /// The codecs are incompatible with the data type.
+    #[error("the codecs are incompatible with the data type: {}", _0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    CodecIncompatibleWithDataType(Vec<CodecIncompatibleWithDataTypeError>),
+    /// A chunk shape of the chunk grid is incompatible with the codecs.
+    #[error("chunk shape {0:?} is incompatible with the codecs: {1}")]
+    IncompatibleChunkShape(ArrayShape, CodecError),
     /// Storage error.
     #[error(transparent)]
     StorageError(#[from] StorageError),
     /// Missing metadata.
     #[error("array metadata is missing")]
     MissingMetadata,
+    /// The array metadata is not the requested Zarr version.
+    #[error("expected Zarr {expected:?} array metadata, but found Zarr {found:?} array metadata")]
+    MetadataVersionMismatch {
+        /// The requested metadata version.
+        expected: MetadataRetrieveVersion,
+        /// The metadata version found in the store.
+        found: MetadataRetrieveVersion,
+    },
     /// The Zarr V2 array is unsupported.
     #[error("unsupported Zarr V2 array: {_0}")]
     UnsupportedZarrV2Array(String),
+    /// The array configuration cannot be represented in Zarr V2 metadata.
+    #[error("the array is incompatible with Zarr V2: {_0}")]
+    IncompatibleZarrV2(#[from] ArrayMetadataV3ToV2ConversionError),
 }
 
 /// Array errors.
@@ -112,4 +133,13 @@ pub enum ArrayError {
     /// Unsupported method.
     #[error("unsupported array method: {_0}")]
     UnsupportedMethod(String),
+    /// The array shape is incompatible with the chunk grid.
+    #[error("array shape {_0:?} is incompatible with the chunk grid")]
+    IncompatibleArrayShape(ArrayShape),
+    /// Invalid append.
+    #[error("cannot append {_0} elements along axis {_1} of an array with shape {_2:?}")]
+    InvalidAppend(u64, usize, ArrayShape),
+    /// An element is outside the range of the type it is cast to.
+    #[error("cannot cast an element of type {_0} to {_1}, it is out of range")]
+    ElementCastOutOfRange(String, String),
 }
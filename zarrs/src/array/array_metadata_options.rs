@@ -6,6 +6,7 @@ pub struct ArrayMetadataOptions {
     experimental_codec_store_metadata_if_encode_only: bool,
     convert_version: MetadataConvertVersion,
     include_zarrs_metadata: bool,
+    strict_additional_fields: bool,
 }
 
 impl Default for ArrayMetadataOptions {
@@ -14,6 +15,7 @@ impl Default for ArrayMetadataOptions {
             experimental_codec_store_metadata_if_encode_only: false,
             convert_version: global_config().metadata_convert_version(),
             include_zarrs_metadata: global_config().include_zarrs_metadata(),
+            strict_additional_fields: global_config().strict_additional_fields(),
         }
     }
 }
@@ -84,4 +86,23 @@ impl ArrayMetadataOptions {
         self.include_zarrs_metadata = include_zarrs_metadata;
         self
     }
+
+    /// Get the [strict additional fields](crate::config::Config#strict-additional-fields) configuration.
+    #[must_use]
+    pub fn strict_additional_fields(&self) -> bool {
+        self.strict_additional_fields
+    }
+
+    /// Set the [strict additional fields](crate::config::Config#strict-additional-fields) configuration.
+    #[must_use]
+    pub fn with_strict_additional_fields(mut self, strict_additional_fields: bool) -> Self {
+        self.strict_additional_fields = strict_additional_fields;
+        self
+    }
+
+    /// Set the [strict additional fields](crate::config::Config#strict-additional-fields) configuration.
+    pub fn set_strict_additional_fields(&mut self, strict_additional_fields: bool) -> &mut Self {
+        self.strict_additional_fields = strict_additional_fields;
+        self
+    }
 }
@@ -1,4 +1,9 @@
-use super::{codec::ShardingCodecConfiguration, Array, ArrayShape, ChunkGrid, ChunkShape};
+use crate::array_subset::ArraySubset;
+
+use super::{
+    chunk_grid::RegularChunkGridConfiguration, codec::ShardingCodecConfiguration, Array,
+    ArrayError, ArrayIndices, ArrayShape, ChunkGrid, ChunkShape,
+};
 
 /// An [`Array`] extension trait to simplify working with arrays using the `sharding_indexed` codec.
 pub trait ArrayShardedExt: private::Sealed {
@@ -31,6 +36,57 @@ pub trait ArrayShardedExt: private::Sealed {
     ///
     /// Returns the normal chunk grid shape for an unsharded array.
     fn inner_chunk_grid_shape(&self) -> Option<ArrayShape>;
+
+    /// Return the effective chunk shape.
+    ///
+    /// This is the [effective inner chunk shape](ArrayShardedExt::effective_inner_chunk_shape) for a sharded array, and the chunk shape of the regular chunk grid for an unsharded array.
+    ///
+    /// # Errors
+    /// Returns [`ArrayError::UnsupportedMethod`] if the array is unsharded and does not have a regular chunk grid.
+    fn effective_chunk_shape(&self) -> Result<ChunkShape, ArrayError>;
+
+    /// Return the number of inner chunks along each dimension of the shard at `shard_indices`.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the array is not sharded, or
+    ///  - `shard_indices` are incompatible with the chunk grid.
+    fn chunks_per_shard(&self, shard_indices: &[u64]) -> Result<ArrayShape, ArrayError>;
+
+    /// Convert inner chunk indices (in the [inner chunk grid](ArrayShardedExt::inner_chunk_grid)) to the indices of the shard containing the inner chunk and the indices of the inner chunk within that shard.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the array is not sharded, or
+    ///  - `inner_chunk_indices` are incompatible with the inner chunk grid or out of bounds of the array.
+    fn inner_chunk_indices_to_shard(
+        &self,
+        inner_chunk_indices: &[u64],
+    ) -> Result<(ArrayIndices, ArrayIndices), ArrayError>;
+
+    /// Return the inner chunks (in the [inner chunk grid](ArrayShardedExt::inner_chunk_grid)) intersecting `array_subset`.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the array is not sharded, or
+    ///  - `array_subset` is incompatible with the array shape.
+    fn array_subset_to_inner_chunks(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<ArraySubset, ArrayError>;
+}
+
+/// Return the inner chunk grid of a sharded array, or an error if the array is not sharded.
+fn sharded_inner_chunk_grid<TStorage: ?Sized>(
+    array: &Array<TStorage>,
+) -> Result<ChunkGrid, ArrayError> {
+    if array.is_sharded() {
+        Ok(array.inner_chunk_grid())
+    } else {
+        Err(ArrayError::UnsupportedMethod(
+            "the array is not sharded".to_string(),
+        ))
+    }
 }
 
 impl<TStorage: ?Sized> ArrayShardedExt for Array<TStorage> {
@@ -94,6 +150,78 @@ impl<TStorage: ?Sized> ArrayShardedExt for Array<TStorage> {
             self.inner_chunk_grid().grid_shape_unchecked(self.shape())
         }
     }
+
+    fn effective_chunk_shape(&self) -> Result<ChunkShape, ArrayError> {
+        if let Some(effective_inner_chunk_shape) = self.effective_inner_chunk_shape() {
+            return Ok(effective_inner_chunk_shape);
+        }
+        self.chunk_grid()
+            .create_metadata()
+            .to_configuration::<RegularChunkGridConfiguration>()
+            .map(|configuration| configuration.chunk_shape)
+            .map_err(|_| {
+                ArrayError::UnsupportedMethod(
+                    "the array is not sharded and does not have a regular chunk grid".to_string(),
+                )
+            })
+    }
+
+    fn chunks_per_shard(&self, shard_indices: &[u64]) -> Result<ArrayShape, ArrayError> {
+        let effective_inner_chunk_shape = self
+            .effective_inner_chunk_shape()
+            .ok_or_else(|| ArrayError::UnsupportedMethod("the array is not sharded".to_string()))?;
+        let shard_shape = self.chunk_shape(shard_indices)?;
+        Ok(std::iter::zip(
+            shard_shape.as_slice(),
+            effective_inner_chunk_shape.as_slice(),
+        )
+        .map(|(shard, inner)| shard.get().div_ceil(inner.get()))
+        .collect())
+    }
+
+    fn inner_chunk_indices_to_shard(
+        &self,
+        inner_chunk_indices: &[u64],
+    ) -> Result<(ArrayIndices, ArrayIndices), ArrayError> {
+        let inner_chunk_grid = sharded_inner_chunk_grid(self)?;
+        let invalid_indices =
+            || ArrayError::InvalidChunkGridIndicesError(inner_chunk_indices.to_vec());
+        if !inner_chunk_grid.chunk_indices_inbounds(inner_chunk_indices, self.shape()) {
+            return Err(invalid_indices());
+        }
+        let inner_chunk_subset = inner_chunk_grid
+            .subset(inner_chunk_indices, self.shape())?
+            .ok_or_else(invalid_indices)?;
+        let shard_indices = self
+            .chunk_grid()
+            .chunk_indices(inner_chunk_subset.start(), self.shape())?
+            .ok_or_else(invalid_indices)?;
+        let shard_inner_chunk_indices = inner_chunk_grid
+            .chunk_indices(&self.chunk_origin(&shard_indices)?, self.shape())?
+            .ok_or_else(invalid_indices)?;
+        let inner_chunk_indices_in_shard =
+            std::iter::zip(inner_chunk_indices, shard_inner_chunk_indices)
+                .map(|(inner, shard_inner)| inner - shard_inner)
+                .collect();
+        Ok((shard_indices, inner_chunk_indices_in_shard))
+    }
+
+    fn array_subset_to_inner_chunks(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<ArraySubset, ArrayError> {
+        let inner_chunk_grid = sharded_inner_chunk_grid(self)?;
+        let invalid_subset =
+            || ArrayError::InvalidArraySubset(array_subset.clone(), self.shape().to_vec());
+        if array_subset.dimensionality() != self.dimensionality()
+            || !array_subset.inbounds(self.shape())
+        {
+            return Err(invalid_subset());
+        }
+        inner_chunk_grid
+            .chunks_in_array_subset(array_subset, self.shape())?
+            .ok_or_else(invalid_subset)
+    }
 }
 
 mod private {
@@ -103,3 +231,144 @@ mod private {
 
     impl<TStorage: ?Sized> Sealed for Array<TStorage> {}
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        array::{
+            chunk_grid::RectangularChunkGridDimensionConfiguration,
+            codec::array_to_bytes::sharding::ShardingCodecBuilder, ArrayBuilder, DataType,
+            FillValue,
+        },
+        storage::store::MemoryStore,
+    };
+
+    use super::*;
+
+    fn array_builder() -> ArrayBuilder {
+        ArrayBuilder::new(
+            vec![10, 7, 5], // array shape
+            DataType::UInt8,
+            vec![4, 4, 4].try_into().unwrap(), // regular chunk (shard) shape
+            FillValue::from(0u8),
+        )
+    }
+
+    #[test]
+    fn array_sharded_ext_coordinates() -> Result<(), Box<dyn std::error::Error>> {
+        let array = array_builder()
+            .array_to_bytes_codec(Arc::new(
+                ShardingCodecBuilder::new(vec![2, 2, 2].try_into()?).build(),
+            ))
+            .build(Arc::new(MemoryStore::new()), "/")?;
+
+        assert_eq!(array.effective_chunk_shape()?, vec![2, 2, 2].try_into()?);
+        assert_eq!(array.chunk_grid_shape(), Some(vec![3, 2, 2]));
+        assert_eq!(array.inner_chunk_grid_shape(), Some(vec![5, 4, 3]));
+        assert_eq!(array.chunks_per_shard(&[0, 0, 0])?, vec![2, 2, 2]);
+        assert_eq!(array.chunks_per_shard(&[2, 1, 1])?, vec![2, 2, 2]);
+
+        assert_eq!(
+            array.inner_chunk_indices_to_shard(&[0, 0, 0])?,
+            (vec![0, 0, 0], vec![0, 0, 0])
+        );
+        assert_eq!(
+            array.inner_chunk_indices_to_shard(&[3, 1, 2])?,
+            (vec![1, 0, 1], vec![1, 1, 0])
+        );
+        // Edge inner chunks extend beyond the array shape
+        assert_eq!(
+            array.inner_chunk_indices_to_shard(&[4, 3, 2])?,
+            (vec![2, 1, 1], vec![0, 1, 0])
+        );
+        assert!(matches!(
+            array.inner_chunk_indices_to_shard(&[5, 0, 0]),
+            Err(ArrayError::InvalidChunkGridIndicesError(_))
+        ));
+        assert!(array.inner_chunk_indices_to_shard(&[0, 0]).is_err());
+
+        assert_eq!(
+            array.array_subset_to_inner_chunks(&ArraySubset::new_with_ranges(&[
+                3..9,
+                0..7,
+                4..5
+            ]))?,
+            ArraySubset::new_with_ranges(&[1..5, 0..4, 2..3])
+        );
+        assert_eq!(
+            array.array_subset_to_inner_chunks(&array.subset_all())?,
+            ArraySubset::new_with_shape(vec![5, 4, 3])
+        );
+        assert!(matches!(
+            array.array_subset_to_inner_chunks(&ArraySubset::new_with_ranges(&[0..11, 0..7, 0..5])),
+            Err(ArrayError::InvalidArraySubset(_, _))
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "transpose")]
+    #[test]
+    fn array_sharded_ext_coordinates_transpose() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::array::codec::TransposeCodec;
+        use zarrs_metadata::v3::array::codec::transpose::TransposeOrder;
+
+        let array = array_builder()
+            .array_to_array_codecs(vec![Arc::new(TransposeCodec::new(TransposeOrder::new(
+                &[2, 1, 0],
+            )?))])
+            .array_to_bytes_codec(Arc::new(
+                ShardingCodecBuilder::new(vec![1, 2, 4].try_into()?).build(),
+            ))
+            .build(Arc::new(MemoryStore::new()), "/")?;
+
+        assert_eq!(array.effective_chunk_shape()?, vec![4, 2, 1].try_into()?);
+        assert_eq!(array.chunks_per_shard(&[1, 1, 1])?, vec![1, 2, 4]);
+        assert_eq!(
+            array.inner_chunk_indices_to_shard(&[2, 3, 4])?,
+            (vec![2, 1, 1], vec![0, 1, 0])
+        );
+        assert_eq!(
+            array.array_subset_to_inner_chunks(&ArraySubset::new_with_ranges(&[
+                3..9,
+                0..7,
+                4..5
+            ]))?,
+            ArraySubset::new_with_ranges(&[0..3, 0..4, 4..5])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn array_sharded_ext_coordinates_unsharded() -> Result<(), Box<dyn std::error::Error>> {
+        let array = array_builder().build(Arc::new(MemoryStore::new()), "/")?;
+
+        assert_eq!(array.effective_chunk_shape()?, vec![4, 4, 4].try_into()?);
+        assert!(matches!(
+            array.chunks_per_shard(&[0, 0, 0]),
+            Err(ArrayError::UnsupportedMethod(_))
+        ));
+        assert!(matches!(
+            array.inner_chunk_indices_to_shard(&[0, 0, 0]),
+            Err(ArrayError::UnsupportedMethod(_))
+        ));
+        assert!(matches!(
+            array.array_subset_to_inner_chunks(&array.subset_all()),
+            Err(ArrayError::UnsupportedMethod(_))
+        ));
+
+        let array = array_builder()
+            .rectangular_chunk_grid(&[
+                vec![4, 6].try_into()?,
+                RectangularChunkGridDimensionConfiguration::Fixed(4.try_into()?),
+                RectangularChunkGridDimensionConfiguration::Fixed(5.try_into()?),
+            ])
+            .build(Arc::new(MemoryStore::new()), "/")?;
+        assert!(matches!(
+            array.effective_chunk_shape(),
+            Err(ArrayError::UnsupportedMethod(_))
+        ));
+        Ok(())
+    }
+}
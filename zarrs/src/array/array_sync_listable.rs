@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::storage::{ListableStorageTraits, StorageError, StorageHandle, StorePrefix};
+
+use super::{Array, ArrayError, ArrayIndices};
+
+impl<TStorage: ?Sized + ListableStorageTraits + 'static> Array<TStorage> {
+    /// Return the chunk grid indices of all chunks stored in the array.
+    ///
+    /// Keys under the array prefix are listed and decoded with the chunk key encoding.
+    /// Keys that are not chunk keys (e.g. `zarr.json`) are skipped.
+    /// Chunks outside of the bounds of the array shape are included if they are present in the store.
+    ///
+    /// The chunk grid indices are sorted in lexicographical order.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if there is an underlying store error.
+    pub fn stored_chunks(&self) -> Result<Vec<ArrayIndices>, ArrayError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_listable_transformer(storage_handle)?;
+        let prefix: StorePrefix = self.path().try_into().map_err(StorageError::from)?;
+        let keys = storage_transformer.list_prefix(&prefix)?;
+        Ok(self.stored_chunks_from_keys(&prefix, &keys))
+    }
+}
@@ -1,31 +1,41 @@
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::VecDeque, sync::Arc};
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use rayon_iter_concurrent_limit::iter_concurrent_limit;
 use unsafe_cell_slice::UnsafeCellSlice;
 
 use crate::{
-    array::{ArrayBytes, ArrayMetadataV2},
+    array::{ArrayBytes, ChunkCache},
     array_subset::ArraySubset,
     config::MetadataRetrieveVersion,
-    node::{meta_key_v2_array, meta_key_v2_attributes, meta_key_v3, NodePath},
+    node::{
+        array_metadata_v2_from_documents, meta_key_v2_array, meta_key_v2_attributes, meta_key_v3,
+        NodeOpenOptions, NodeOpenWarning, NodePath,
+    },
     storage::{ReadableStorageTraits, StorageError, StorageHandle},
 };
 
 use super::{
-    array_bytes::{copy_fill_value_into, merge_chunks_vlen},
+    array_bytes::{copy_fill_value_into, merge_chunks_vlen, update_bytes_flen},
     codec::{
-        options::CodecOptions, ArrayPartialDecoderTraits, ArrayToBytesCodecTraits,
-        StoragePartialDecoder,
+        options::CodecOptions, ArrayPartialDecoderCache, ArrayPartialDecoderTraits,
+        ArrayToBytesCodecTraits, StoragePartialDecoder,
     },
     concurrency::concurrency_chunks_and_codec,
     element::ElementOwned,
-    Array, ArrayCreateError, ArrayError, ArrayMetadata, ArrayMetadataV3, ArraySize, DataTypeSize,
+    element_cast::{cast_bytes_to_elements, validate_element_cast_data_type},
+    storage_transformer::{StorageTransformer, StorageTransformerChain},
+    Array, ArrayChunkCacheExt, ArrayCreateError, ArrayDecodedChunk, ArrayError, ArrayIndices,
+    ArrayMetadata, ArraySize, ChunkOccupancy, ChunkValidation, DataTypeSize, ElementCast,
+    ElementCastMode,
 };
 
 #[cfg(feature = "ndarray")]
 use super::elements_to_ndarray;
 
+#[cfg(feature = "dlpack")]
+use super::dlpack::{dlpack_data_type, AlignedBuffer, ManagedTensor};
+
 impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     /// Open an existing array in `storage` at `path` with default [`MetadataRetrieveVersion`].
     /// The metadata is read from the store.
@@ -46,15 +56,64 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         path: &str,
         version: &MetadataRetrieveVersion,
     ) -> Result<Self, ArrayCreateError> {
+        let options = NodeOpenOptions::default().with_version(version.clone());
+        Self::open_with_options(storage, path, &options).map(|(array, _warnings)| array)
+    }
+
+    /// Open an existing array in `storage` at `path` with [`NodeOpenOptions`].
+    /// The metadata is read from the store.
+    ///
+    /// Returns the array and a [`NodeOpenWarning`] for each part of the metadata that was ignored.
+    ///
+    /// # Errors
+    /// Returns [`ArrayCreateError`] if there is a storage error or any metadata is invalid, including if
+    ///  - only metadata of a different Zarr version than requested is found,
+    ///  - the metadata has an additional field with `"must_understand": true` that is not an [understood additional field](crate::config::Config#understood-additional-fields), or
+    ///  - the metadata has additional fields and [strict extensions](NodeOpenOptions::strict_extensions) are enabled.
+    pub fn open_with_options(
+        storage: Arc<TStorage>,
+        path: &str,
+        options: &NodeOpenOptions,
+    ) -> Result<(Self, Vec<NodeOpenWarning>), ArrayCreateError> {
+        let (array, mut warnings) = Self::open_metadata(storage, path, options)?;
+        warnings.extend(options.check_additional_fields(array.additional_fields())?);
+        Ok((array, warnings))
+    }
+
+    /// Open an existing array in `storage` at `path` and apply `storage_transformers` before the storage transformers in the array metadata.
+    /// The metadata is read from the store.
+    ///
+    /// The `storage_transformers` are the innermost storage transformers, so they wrap `storage` directly and see requests after any storage transformers in the array metadata.
+    /// See the [storage transformer ordering](crate::array::storage_transformer#ordering).
+    ///
+    /// # Errors
+    /// Returns [`ArrayCreateError`] if there is a storage error or any metadata is invalid.
+    pub fn open_with_storage_transformers(
+        storage: Arc<TStorage>,
+        path: &str,
+        storage_transformers: &[StorageTransformer],
+    ) -> Result<Self, ArrayCreateError> {
+        let storage_transformers = StorageTransformerChain::new(storage_transformers.to_vec());
+        Ok(Self::open(storage, path)?.with_inherited_storage_transformers(&storage_transformers))
+    }
+
+    fn open_metadata(
+        storage: Arc<TStorage>,
+        path: &str,
+        options: &NodeOpenOptions,
+    ) -> Result<(Self, Vec<NodeOpenWarning>), ArrayCreateError> {
+        let version = options.version();
         let node_path = NodePath::new(path)?;
 
         if let MetadataRetrieveVersion::Default | MetadataRetrieveVersion::V3 = version {
             // Try V3
             let key_v3 = meta_key_v3(&node_path);
             if let Some(metadata) = storage.get(&key_v3)? {
-                let metadata: ArrayMetadataV3 = serde_json::from_slice(&metadata)
+                let (metadata, warnings) = options
+                    .array_metadata_v3_from_slice(&metadata)
                     .map_err(|err| StorageError::InvalidMetadata(key_v3, err.to_string()))?;
-                return Self::new_with_metadata(storage, path, ArrayMetadata::V3(metadata));
+                let array = Self::new_with_metadata(storage, path, ArrayMetadata::V3(metadata))?;
+                return Ok((array, warnings));
             }
         }
 
@@ -62,21 +121,37 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
             // Try V2
             let key_v2 = meta_key_v2_array(&node_path);
             if let Some(metadata) = storage.get(&key_v2)? {
-                let mut metadata: ArrayMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(key_v2, err.to_string()))?;
-
                 let attributes_key = meta_key_v2_attributes(&node_path);
                 let attributes = storage.get(&attributes_key)?;
-                if let Some(attributes) = attributes {
-                    metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
-                    })?;
-                }
+                let metadata = array_metadata_v2_from_documents(
+                    &key_v2,
+                    &metadata,
+                    &attributes_key,
+                    attributes,
+                )?;
 
-                return Self::new_with_metadata(storage, path, ArrayMetadata::V2(metadata));
+                let array = Self::new_with_metadata(storage, path, ArrayMetadata::V2(metadata))?;
+                return Ok((array, vec![]));
             }
         }
 
+        // Check for metadata of the other Zarr version
+        let found = match version {
+            MetadataRetrieveVersion::V3 => storage
+                .size_key(&meta_key_v2_array(&node_path))?
+                .map(|_| MetadataRetrieveVersion::V2),
+            MetadataRetrieveVersion::V2 => storage
+                .size_key(&meta_key_v3(&node_path))?
+                .map(|_| MetadataRetrieveVersion::V3),
+            MetadataRetrieveVersion::Default => None,
+        };
+        if let Some(found) = found {
+            return Err(ArrayCreateError::MetadataVersionMismatch {
+                expected: version,
+                found,
+            });
+        }
+
         Err(ArrayCreateError::MissingMetadata)
     }
 
@@ -94,7 +169,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<ArrayBytes<'_>>, ArrayError> {
-        self.retrieve_chunk_if_exists_opt(chunk_indices, &CodecOptions::default())
+        self.retrieve_chunk_if_exists_opt(chunk_indices, &self.codec_options())
     }
 
     /// Read and decode the chunk at `chunk_indices` into a vector of its elements if it exists with default codec options.
@@ -110,7 +185,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<Vec<T>>, ArrayError> {
-        self.retrieve_chunk_elements_if_exists_opt(chunk_indices, &CodecOptions::default())
+        self.retrieve_chunk_elements_if_exists_opt(chunk_indices, &self.codec_options())
     }
 
     #[cfg(feature = "ndarray")]
@@ -130,7 +205,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Option<ndarray::ArrayD<T>>, ArrayError> {
-        self.retrieve_chunk_ndarray_if_exists_opt(chunk_indices, &CodecOptions::default())
+        self.retrieve_chunk_ndarray_if_exists_opt(chunk_indices, &self.codec_options())
     }
 
     /// Retrieve the encoded bytes of a chunk.
@@ -144,7 +219,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Option<Vec<u8>>, StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_readable_transformer(storage_handle)?;
 
         storage_transformer
@@ -152,6 +227,168 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
             .map(|maybe_bytes| maybe_bytes.map(|bytes| bytes.to_vec()))
     }
 
+    /// Return true if the chunk at `chunk_indices` exists in the store.
+    ///
+    /// The chunk is not retrieved, only its size is queried.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - `chunk_indices` are invalid, or
+    ///  - an underlying store error.
+    pub fn chunk_exists(&self, chunk_indices: &[u64]) -> Result<bool, ArrayError> {
+        if chunk_indices.len() != self.dimensionality() {
+            return Err(ArrayError::InvalidChunkGridIndicesError(
+                chunk_indices.to_vec(),
+            ));
+        }
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_readable_transformer(storage_handle)?;
+        Ok(storage_transformer
+            .size_key(&self.chunk_key(chunk_indices))?
+            .is_some())
+    }
+
+    /// Validate the encoded chunk at `chunk_indices`.
+    ///
+    /// If the last bytes to bytes codec embeds a checksum (e.g. `crc32c`), the checksum is verified without decoding the chunk.
+    /// Otherwise, the chunk is decoded with checksum validation enabled and the decoded bytes are discarded.
+    /// The chunk cache is bypassed.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - `chunk_indices` are invalid, or
+    ///  - an underlying store error.
+    pub fn validate_chunk(&self, chunk_indices: &[u64]) -> Result<ChunkValidation, ArrayError> {
+        if chunk_indices.len() != self.dimensionality() {
+            return Err(ArrayError::InvalidChunkGridIndicesError(
+                chunk_indices.to_vec(),
+            ));
+        }
+        let Some(chunk_encoded) = self.retrieve_encoded_chunk(chunk_indices)? else {
+            return Ok(ChunkValidation::Missing);
+        };
+
+        if let Some(codec) = self.codecs().bytes_to_bytes_codecs().last() {
+            match codec.checksums(&chunk_encoded) {
+                Ok(Some((expected, actual))) => {
+                    return Ok(if expected == actual {
+                        ChunkValidation::Ok
+                    } else {
+                        ChunkValidation::ChecksumMismatch { expected, actual }
+                    });
+                }
+                Ok(None) => {}
+                Err(err) => return Ok(ChunkValidation::DecodeError(err)),
+            }
+        }
+
+        let chunk_representation = self.chunk_array_representation(chunk_indices)?;
+        let mut options = CodecOptions::default();
+        options.set_validate_checksums(true);
+        match self
+            .codecs()
+            .decode(Cow::Owned(chunk_encoded), &chunk_representation, &options)
+        {
+            Ok(_) => Ok(ChunkValidation::Ok),
+            Err(err) => Ok(ChunkValidation::DecodeError(err)),
+        }
+    }
+
+    /// Validate all chunks in the chunk grid of the array.
+    ///
+    /// Returns the chunk indices and [`ChunkValidation`] of every chunk, including those that do not exist.
+    /// If `parallel` is true, chunks are validated in parallel.
+    /// See [`Array::validate_chunk`].
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the array shape is incompatible with the chunk grid, or
+    ///  - an underlying store error.
+    pub fn validate_all_chunks(
+        &self,
+        parallel: bool,
+    ) -> Result<Vec<(ArrayIndices, ChunkValidation)>, ArrayError> {
+        let chunk_grid_shape = self
+            .chunk_grid_shape()
+            .ok_or_else(|| ArrayError::IncompatibleArrayShape(self.shape().to_vec()))?;
+        let chunks = ArraySubset::new_with_shape(chunk_grid_shape);
+        let validate_chunk = |chunk_indices: ArrayIndices| {
+            self.validate_chunk(&chunk_indices)
+                .map(|validation| (chunk_indices, validation))
+        };
+        if parallel {
+            chunks
+                .indices()
+                .into_par_iter()
+                .map(validate_chunk)
+                .collect()
+        } else {
+            chunks.indices().into_iter().map(validate_chunk).collect()
+        }
+    }
+
+    /// Return the [`ChunkOccupancy`] of the chunk at `chunk_indices`.
+    ///
+    /// Distinguishes a chunk that does not exist from one that exists but only contains the fill value.
+    /// Existing chunks are decoded and their elements are compared against the fill value in place.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - `chunk_indices` are invalid,
+    ///  - there is a codec decoding error, or
+    ///  - an underlying store error.
+    pub fn chunk_occupancy(&self, chunk_indices: &[u64]) -> Result<ChunkOccupancy, ArrayError> {
+        let Some(bytes) = self.retrieve_chunk_if_exists(chunk_indices)? else {
+            return Ok(ChunkOccupancy::Missing);
+        };
+        Ok(match bytes.num_non_fill_value_elements(self.fill_value()) {
+            0 => ChunkOccupancy::AllFillValue,
+            count => ChunkOccupancy::NonFill { count },
+        })
+    }
+
+    #[cfg(feature = "sharding")]
+    /// Read and decode the index of the shard at `shard_indices` with default codec options.
+    ///
+    /// Only the bytes of the shard index are read, no inner chunks are read or decoded.
+    /// Returns [`None`] if the shard does not exist.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the array is not [exclusively sharded](super::ArrayShardedExt::is_exclusively_sharded),
+    ///  - `shard_indices` are invalid,
+    ///  - the shard index cannot be decoded, or
+    ///  - an underlying store error.
+    pub fn shard_index(
+        &self,
+        shard_indices: &[u64],
+    ) -> Result<Option<super::codec::ShardIndex>, ArrayError> {
+        self.shard_index_opt(shard_indices, &self.codec_options())
+    }
+
+    #[cfg(feature = "sharding")]
+    /// Explicit options version of [`shard_index`](Array::shard_index).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn shard_index_opt(
+        &self,
+        shard_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Option<super::codec::ShardIndex>, ArrayError> {
+        let sharding_codec = self.exclusive_sharding_codec().ok_or_else(|| {
+            ArrayError::UnsupportedMethod("the array is not exclusively sharded".to_string())
+        })?;
+        let shard_representation = self.chunk_array_representation(shard_indices)?;
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_readable_transformer(storage_handle)?;
+        let input_handle =
+            StoragePartialDecoder::new(storage_transformer, self.chunk_key(shard_indices));
+        Ok(sharding_codec.decode_index(&input_handle, &shard_representation, options)?)
+    }
+
     /// Read and decode the chunk at `chunk_indices` into its bytes or the fill value if it does not exist with default codec options.
     ///
     /// # Errors
@@ -163,7 +400,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     /// # Panics
     /// Panics if the number of elements in the chunk exceeds `usize::MAX`.
     pub fn retrieve_chunk(&self, chunk_indices: &[u64]) -> Result<ArrayBytes<'_>, ArrayError> {
-        self.retrieve_chunk_opt(chunk_indices, &CodecOptions::default())
+        self.retrieve_chunk_opt(chunk_indices, &self.codec_options())
     }
 
     /// Read and decode the chunk at `chunk_indices` into a vector of its elements or the fill value if it does not exist.
@@ -179,7 +416,23 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Vec<T>, ArrayError> {
-        self.retrieve_chunk_elements_opt(chunk_indices, &CodecOptions::default())
+        self.retrieve_chunk_elements_opt(chunk_indices, &self.codec_options())
+    }
+
+    /// Read and decode the chunk at `chunk_indices` into a vector of its strings or the fill value if it does not exist.
+    ///
+    /// The elements are unpacked from the variable-length bytes and offsets of the `string` data type.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is not a string data type,
+    ///  - an element is not valid UTF-8, or
+    ///  - a [`retrieve_chunk_elements`](Array::retrieve_chunk_elements) error condition is met.
+    pub fn retrieve_chunk_elements_string(
+        &self,
+        chunk_indices: &[u64],
+    ) -> Result<Vec<String>, ArrayError> {
+        self.retrieve_chunk_elements::<String>(chunk_indices)
     }
 
     #[cfg(feature = "ndarray")]
@@ -199,7 +452,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<ndarray::ArrayD<T>, ArrayError> {
-        self.retrieve_chunk_ndarray_opt(chunk_indices, &CodecOptions::default())
+        self.retrieve_chunk_ndarray_opt(chunk_indices, &self.codec_options())
     }
 
     /// Retrieve the encoded bytes of the chunks in `chunks`.
@@ -215,7 +468,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Vec<Option<Vec<u8>>>, StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_readable_transformer(storage_handle)?;
 
         let retrieve_encoded_chunk = |chunk_indices: Vec<u64>| {
@@ -225,13 +478,15 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         };
 
         let indices = chunks.indices();
-        iter_concurrent_limit!(
-            options.concurrent_target(),
-            indices,
-            map,
-            retrieve_encoded_chunk
-        )
-        .collect()
+        options.install(|| {
+            iter_concurrent_limit!(
+                options.concurrent_target(),
+                indices,
+                map,
+                retrieve_encoded_chunk
+            )
+            .collect()
+        })
     }
 
     /// Read and decode the chunks at `chunks` into their bytes.
@@ -245,7 +500,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     /// # Panics
     /// Panics if the number of array elements in the chunk exceeds `usize::MAX`.
     pub fn retrieve_chunks(&self, chunks: &ArraySubset) -> Result<ArrayBytes<'_>, ArrayError> {
-        self.retrieve_chunks_opt(chunks, &CodecOptions::default())
+        self.retrieve_chunks_opt(chunks, &self.codec_options())
     }
 
     /// Read and decode the chunks at `chunks` into a vector of their elements.
@@ -259,7 +514,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunks: &ArraySubset,
     ) -> Result<Vec<T>, ArrayError> {
-        self.retrieve_chunks_elements_opt(chunks, &CodecOptions::default())
+        self.retrieve_chunks_elements_opt(chunks, &self.codec_options())
     }
 
     #[cfg(feature = "ndarray")]
@@ -274,7 +529,61 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunks: &ArraySubset,
     ) -> Result<ndarray::ArrayD<T>, ArrayError> {
-        self.retrieve_chunks_ndarray_opt(chunks, &CodecOptions::default())
+        self.retrieve_chunks_ndarray_opt(chunks, &self.codec_options())
+    }
+
+    #[cfg(feature = "dlpack")]
+    /// Read and decode the chunk at `chunk_indices` into a `DLPack` tensor.
+    ///
+    /// The chunk is decoded directly into the aligned buffer of the tensor.
+    /// See [`dlpack`](crate::array::dlpack) for the supported data types.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is not supported by `DLPack`,
+    ///  - `chunk_indices` are invalid,
+    ///  - there is a codec decoding error, or
+    ///  - an underlying store error.
+    pub fn retrieve_chunk_dlpack(
+        &self,
+        chunk_indices: &[u64],
+    ) -> Result<ManagedTensor, ArrayError> {
+        self.retrieve_chunk_dlpack_opt(chunk_indices, &self.codec_options())
+    }
+
+    #[cfg(feature = "dlpack")]
+    /// Read and decode the `array_subset` of the array into a `DLPack` tensor.
+    ///
+    /// The array subset is decoded directly into the aligned buffer of the tensor.
+    /// See [`dlpack`](crate::array::dlpack) for the supported data types.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if the data type is not supported by `DLPack` or a [`retrieve_array_subset_into`](Array::retrieve_array_subset_into) error condition is met.
+    pub fn retrieve_array_subset_dlpack(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<ManagedTensor, ArrayError> {
+        self.retrieve_array_subset_dlpack_opt(array_subset, &self.codec_options())
+    }
+
+    /// Return an iterator that reads and decodes each chunk intersecting `array_subset`, or each chunk of the array if [`None`].
+    ///
+    /// Chunks are decoded in lexicographical order of their chunk grid indices.
+    /// Each item holds the chunk grid indices, the array subset of the chunk, and the decoded chunk bytes.
+    /// The array subset of a chunk at the edge of the array may extend beyond the array shape.
+    ///
+    /// Up to [`concurrent_target`](CodecOptions::concurrent_target) chunks are decoded in parallel ahead of the consumer.
+    /// See [`chunks_iter_opt`](Array::chunks_iter_opt) to change the number of chunks decoded ahead.
+    ///
+    /// # Errors
+    /// Returns [`ArrayError::InvalidArraySubset`] if `array_subset` is incompatible with the array.
+    /// An item is an [`ArrayError`] if there is a codec decoding error or an underlying store error.
+    pub fn chunks_iter(
+        &self,
+        array_subset: Option<&ArraySubset>,
+    ) -> Result<impl Iterator<Item = Result<ArrayDecodedChunk, ArrayError>> + '_, ArrayError> {
+        let options = self.codec_options();
+        self.chunks_iter_opt(array_subset, options.concurrent_target(), &options)
     }
 
     /// Read and decode the `chunk_subset` of the chunk at `chunk_indices` into its bytes.
@@ -293,7 +602,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_subset: &ArraySubset,
     ) -> Result<ArrayBytes<'_>, ArrayError> {
-        self.retrieve_chunk_subset_opt(chunk_indices, chunk_subset, &CodecOptions::default())
+        self.retrieve_chunk_subset_opt(chunk_indices, chunk_subset, &self.codec_options())
     }
 
     /// Read and decode the `chunk_subset` of the chunk at `chunk_indices` into its elements.
@@ -309,11 +618,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_subset: &ArraySubset,
     ) -> Result<Vec<T>, ArrayError> {
-        self.retrieve_chunk_subset_elements_opt(
-            chunk_indices,
-            chunk_subset,
-            &CodecOptions::default(),
-        )
+        self.retrieve_chunk_subset_elements_opt(chunk_indices, chunk_subset, &self.codec_options())
     }
 
     #[cfg(feature = "ndarray")]
@@ -333,11 +638,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_subset: &ArraySubset,
     ) -> Result<ndarray::ArrayD<T>, ArrayError> {
-        self.retrieve_chunk_subset_ndarray_opt(
-            chunk_indices,
-            chunk_subset,
-            &CodecOptions::default(),
-        )
+        self.retrieve_chunk_subset_ndarray_opt(chunk_indices, chunk_subset, &self.codec_options())
     }
 
     /// Read and decode the `array_subset` of array into its bytes.
@@ -356,7 +657,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         array_subset: &ArraySubset,
     ) -> Result<ArrayBytes<'_>, ArrayError> {
-        self.retrieve_array_subset_opt(array_subset, &CodecOptions::default())
+        self.retrieve_array_subset_opt(array_subset, &self.codec_options())
     }
 
     /// Read and decode the `array_subset` of array into a vector of its elements.
@@ -372,7 +673,43 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         array_subset: &ArraySubset,
     ) -> Result<Vec<T>, ArrayError> {
-        self.retrieve_array_subset_elements_opt(array_subset, &CodecOptions::default())
+        self.retrieve_array_subset_elements_opt(array_subset, &self.codec_options())
+    }
+
+    /// Read and decode the `array_subset` of array into a vector of its strings.
+    ///
+    /// The elements are unpacked from the variable-length bytes and offsets of the `string` data type.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is not a string data type,
+    ///  - an element is not valid UTF-8, or
+    ///  - a [`retrieve_array_subset_elements`](Array::retrieve_array_subset_elements) error condition is met.
+    pub fn retrieve_array_subset_elements_string(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<Vec<String>, ArrayError> {
+        self.retrieve_array_subset_elements::<String>(array_subset)
+    }
+
+    /// Read and decode the `array_subset` of array into a vector of its elements cast to `T`.
+    ///
+    /// Elements are decoded in the data type of the array and cast to `T` chunk-by-chunk according to `mode`, without an intermediate copy of the whole subset.
+    /// See [`ElementCastMode`] for the casting policy.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if:
+    ///  - the data type of the array does not support casting (see [`ElementCast`]),
+    ///  - an element is out of range of `T` with [`ElementCastMode::Checked`],
+    ///  - an array subset is invalid or out of bounds of the array,
+    ///  - there is a codec decoding error, or
+    ///  - an underlying store error.
+    pub fn retrieve_array_subset_elements_cast<T: ElementCast>(
+        &self,
+        array_subset: &ArraySubset,
+        mode: ElementCastMode,
+    ) -> Result<Vec<T>, ArrayError> {
+        self.retrieve_array_subset_elements_cast_opt(array_subset, mode, &self.codec_options())
     }
 
     #[cfg(feature = "ndarray")]
@@ -390,7 +727,112 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         array_subset: &ArraySubset,
     ) -> Result<ndarray::ArrayD<T>, ArrayError> {
-        self.retrieve_array_subset_ndarray_opt(array_subset, &CodecOptions::default())
+        self.retrieve_array_subset_ndarray_opt(array_subset, &self.codec_options())
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Read and decode the `array_subset` of array into an [`ndarray::ArrayD`] of strings.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is not a string data type,
+    ///  - an element is not valid UTF-8, or
+    ///  - a [`retrieve_array_subset_ndarray`](Array::retrieve_array_subset_ndarray) error condition is met.
+    ///
+    /// # Panics
+    /// Will panic if any dimension in `array_subset` is `usize::MAX` or larger.
+    pub fn retrieve_array_subset_ndarray_string(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<ndarray::ArrayD<String>, ArrayError> {
+        self.retrieve_array_subset_ndarray::<String>(array_subset)
+    }
+
+    /// Read and decode the `array_subset` of array into the preallocated `output` buffer.
+    ///
+    /// `output` must have a length of `array_subset.num_elements() * data_type.fixed_size()`.
+    /// Chunks are decoded directly into `output` (concurrently if `array_subset` intersects multiple chunks), avoiding an intermediate allocation.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is variable sized,
+    ///  - the length of `output` is not compatible with `array_subset` and the data type,
+    ///  - the `array_subset` dimensionality does not match the chunk grid dimensionality,
+    ///  - there is a codec decoding error, or
+    ///  - an underlying store error.
+    pub fn retrieve_array_subset_into(
+        &self,
+        array_subset: &ArraySubset,
+        output: &mut [u8],
+    ) -> Result<(), ArrayError> {
+        self.retrieve_array_subset_into_opt(array_subset, output, &self.codec_options())
+    }
+
+    /// Read and decode the `array_subset` of array into the preallocated `output` elements.
+    ///
+    /// `output` must have a length of `array_subset.num_elements()`.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the size of `T` does not match the data type size,
+    ///  - the length of `output` does not match the number of elements in `array_subset`, or
+    ///  - a [`retrieve_array_subset_into`](Array::retrieve_array_subset_into) error condition is met.
+    pub fn retrieve_array_subset_elements_into<T: ElementOwned + bytemuck::Pod>(
+        &self,
+        array_subset: &ArraySubset,
+        output: &mut [T],
+    ) -> Result<(), ArrayError> {
+        self.retrieve_array_subset_elements_into_opt(array_subset, output, &self.codec_options())
+    }
+
+    /// Read and decode the `array_subset` of array and write its bytes to `writer` in C order.
+    ///
+    /// Unlike [`retrieve_array_subset`](Array::retrieve_array_subset), the subset is never held in memory in its entirety.
+    /// It is decoded in blocks along its outermost dimension that hold at most `memory_budget` bytes, but no less than one row (an outermost extent of one).
+    /// Blocks are aligned to chunk boundaries where the budget allows, so each intersecting chunk is decoded at most once per block.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is variable sized,
+    ///  - the `array_subset` dimensionality does not match the chunk grid dimensionality,
+    ///  - there is a codec decoding error,
+    ///  - an underlying store error, or
+    ///  - writing to `writer` fails.
+    pub fn retrieve_array_subset_into_writer<W: std::io::Write>(
+        &self,
+        array_subset: &ArraySubset,
+        writer: W,
+        memory_budget: usize,
+    ) -> Result<(), ArrayError> {
+        self.retrieve_array_subset_into_writer_opt(
+            array_subset,
+            writer,
+            memory_budget,
+            &self.codec_options(),
+        )
+    }
+
+    /// Read and decode the `array_subset` of array into its bytes, and return a mask of the elements that are within stored chunks.
+    ///
+    /// The mask is in C order of the elements of `array_subset`.
+    /// An element is `true` if its chunk exists in the store, and `false` if its chunk is missing and the element was synthesised from the fill value.
+    /// This distinguishes an element that was stored with the fill value from one that was never stored.
+    ///
+    /// Each chunk intersecting `array_subset` is retrieved in full, and the mask is derived from whether the retrieval found the chunk.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if:
+    ///  - the `array_subset` dimensionality does not match the chunk grid dimensionality,
+    ///  - there is a codec decoding error, or
+    ///  - an underlying store error.
+    ///
+    /// # Panics
+    /// Panics if attempting to reference a byte beyond `usize::MAX`.
+    pub fn retrieve_array_subset_optional(
+        &self,
+        array_subset: &ArraySubset,
+    ) -> Result<(ArrayBytes<'_>, Vec<bool>), ArrayError> {
+        self.retrieve_array_subset_optional_opt(array_subset, &self.codec_options())
     }
 
     /// Initialises a partial decoder for the chunk at `chunk_indices`.
@@ -401,7 +843,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
     ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, ArrayError> {
-        self.partial_decoder_opt(chunk_indices, &CodecOptions::default())
+        self.partial_decoder_opt(chunk_indices, &self.codec_options())
     }
 
     /////////////////////////////////////////////////////////////////////////////
@@ -422,7 +864,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         }
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_readable_transformer(storage_handle)?;
         let chunk_encoded = storage_transformer
             .get(&self.chunk_key(chunk_indices))
@@ -430,11 +872,17 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         if let Some(chunk_encoded) = chunk_encoded {
             let chunk_encoded: Vec<u8> = chunk_encoded.into();
             let chunk_representation = self.chunk_array_representation(chunk_indices)?;
-            let bytes = self
-                .codecs()
-                .decode(Cow::Owned(chunk_encoded), &chunk_representation, options)
-                .map_err(ArrayError::CodecError)?;
-            Ok(Some(bytes))
+            let bytes = self.recover_corrupt_shard(
+                chunk_indices,
+                self.codecs()
+                    .decode(Cow::Owned(chunk_encoded), &chunk_representation, options),
+                options,
+            )?;
+            Ok(Some(bytes.unwrap_or_else(|| {
+                let array_size =
+                    ArraySize::new(self.data_type().size(), chunk_representation.num_elements());
+                ArrayBytes::new_fill_value(array_size, self.fill_value())
+            })))
         } else {
             Ok(None)
         }
@@ -446,6 +894,23 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         &self,
         chunk_indices: &[u64],
         options: &CodecOptions,
+    ) -> Result<ArrayBytes<'_>, ArrayError> {
+        if let Some(chunk_cache) = &self.chunk_cache {
+            Ok(Arc::unwrap_or_clone(chunk_cache.retrieve_chunk(
+                self,
+                chunk_indices,
+                options,
+            )?))
+        } else {
+            self.retrieve_chunk_opt_uncached(chunk_indices, options)
+        }
+    }
+
+    /// Read and decode the chunk at `chunk_indices` without the decoded chunk cache.
+    pub(crate) fn retrieve_chunk_opt_uncached(
+        &self,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
     ) -> Result<ArrayBytes<'_>, ArrayError> {
         let chunk = self.retrieve_chunk_if_exists_opt(chunk_indices, options)?;
         if let Some(chunk) = chunk {
@@ -473,7 +938,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         }
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_readable_transformer(storage_handle)?;
         let chunk_encoded = storage_transformer
             .get(&self.chunk_key(chunk_indices))
@@ -481,17 +946,33 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         if let Some(chunk_encoded) = chunk_encoded {
             let chunk_encoded: Vec<u8> = chunk_encoded.into();
             let chunk_representation = self.chunk_array_representation(chunk_indices)?;
-            unsafe {
-                self.codecs().decode_into(
-                    Cow::Owned(chunk_encoded),
-                    &chunk_representation,
-                    output,
-                    output_shape,
-                    output_subset,
-                    options,
-                )
+            let decoded = self.recover_corrupt_shard(
+                chunk_indices,
+                unsafe {
+                    self.codecs().decode_into(
+                        Cow::Owned(chunk_encoded),
+                        &chunk_representation,
+                        output,
+                        output_shape,
+                        output_subset,
+                        options,
+                    )
+                },
+                options,
+            )?;
+            if decoded.is_none() {
+                unsafe {
+                    copy_fill_value_into(
+                        self.data_type(),
+                        self.fill_value(),
+                        output,
+                        output_shape,
+                        output_subset,
+                    )
+                }
+                .map_err(ArrayError::CodecError)?;
             }
-            .map_err(ArrayError::CodecError)
+            Ok(())
         } else {
             unsafe {
                 copy_fill_value_into(
@@ -514,7 +995,11 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         options: &CodecOptions,
     ) -> Result<Option<Vec<T>>, ArrayError> {
         if let Some(bytes) = self.retrieve_chunk_if_exists_opt(chunk_indices, options)? {
-            Ok(Some(T::from_array_bytes(self.data_type(), bytes)?))
+            Ok(Some(T::from_array_bytes(
+                self.data_type(),
+                self.fill_value(),
+                bytes,
+            )?))
         } else {
             Ok(None)
         }
@@ -529,6 +1014,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_chunk_opt(chunk_indices, options)?,
         )
     }
@@ -596,7 +1082,11 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         chunks: &ArraySubset,
         options: &CodecOptions,
     ) -> Result<Vec<T>, ArrayError> {
-        T::from_array_bytes(self.data_type(), self.retrieve_chunks_opt(chunks, options)?)
+        T::from_array_bytes(
+            self.data_type(),
+            self.fill_value(),
+            self.retrieve_chunks_opt(chunks, options)?,
+        )
     }
 
     #[cfg(feature = "ndarray")]
@@ -612,6 +1102,72 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         elements_to_ndarray(array_subset.shape(), elements)
     }
 
+    #[cfg(feature = "dlpack")]
+    /// Explicit options version of [`retrieve_chunk_dlpack`](Array::retrieve_chunk_dlpack).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_chunk_dlpack_opt(
+        &self,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<ManagedTensor, ArrayError> {
+        let chunk_subset = self.chunk_subset(chunk_indices)?;
+        self.retrieve_array_subset_dlpack_opt(&chunk_subset, options)
+    }
+
+    #[cfg(feature = "dlpack")]
+    /// Explicit options version of [`retrieve_array_subset_dlpack`](Array::retrieve_array_subset_dlpack).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_array_subset_dlpack_opt(
+        &self,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<ManagedTensor, ArrayError> {
+        let dtype = dlpack_data_type(self.data_type())?;
+        let mut data = AlignedBuffer::new_zeroed(array_subset.num_elements_usize() * dtype.size());
+        self.retrieve_array_subset_into_opt(array_subset, data.as_mut_slice(), options)?;
+        Ok(ManagedTensor::new(data, array_subset.shape(), dtype))
+    }
+
+    /// Explicit options version of [`chunks_iter`](Array::chunks_iter).
+    ///
+    /// Whenever the chunks decoded ahead are exhausted, the next `prefetch` chunks are decoded in parallel with `rayon`.
+    /// Chunks are decoded one at a time if `prefetch` is 0 or 1.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn chunks_iter_opt(
+        &self,
+        array_subset: Option<&ArraySubset>,
+        prefetch: usize,
+        options: &CodecOptions,
+    ) -> Result<impl Iterator<Item = Result<ArrayDecodedChunk, ArrayError>> + '_, ArrayError> {
+        let mut chunks_indices = self.chunks_iter_indices(array_subset)?;
+        let options = options.clone();
+        let retrieve_chunk = move |chunk_indices: ArrayIndices| {
+            let chunk_subset = self.chunk_subset(&chunk_indices)?;
+            let chunk_bytes = self
+                .retrieve_chunk_opt(&chunk_indices, &options)?
+                .into_owned();
+            Ok((chunk_indices, chunk_subset, chunk_bytes))
+        };
+        let mut decoded = VecDeque::new();
+        Ok(std::iter::from_fn(move || {
+            if decoded.is_empty() {
+                let chunks_indices: Vec<ArrayIndices> =
+                    chunks_indices.by_ref().take(prefetch.max(1)).collect();
+                if chunks_indices.len() > 1 {
+                    decoded.extend(
+                        chunks_indices
+                            .into_par_iter()
+                            .map(&retrieve_chunk)
+                            .collect::<Vec<_>>(),
+                    );
+                } else {
+                    decoded.extend(chunks_indices.into_iter().map(&retrieve_chunk));
+                }
+            }
+            decoded.pop_front()
+        }))
+    }
+
     /// Explicit options version of [`retrieve_array_subset`](Array::retrieve_array_subset).
     #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
     #[allow(clippy::too_many_lines)]
@@ -620,6 +1176,10 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         array_subset: &ArraySubset,
         options: &CodecOptions,
     ) -> Result<ArrayBytes<'_>, ArrayError> {
+        if let Some(chunk_cache) = &self.chunk_cache {
+            return self.retrieve_array_subset_opt_cached(chunk_cache, array_subset, options);
+        }
+
         if array_subset.dimensionality() != self.dimensionality() {
             return Err(ArrayError::InvalidArraySubset(
                 array_subset.clone(),
@@ -693,13 +1253,15 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
                             ))
                         };
                         let chunk_indices = chunks.indices();
-                        let chunk_bytes_and_subsets = iter_concurrent_limit!(
-                            chunk_concurrent_limit,
-                            chunk_indices,
-                            map,
-                            retrieve_chunk
-                        )
-                        .collect::<Result<Vec<_>, _>>()?;
+                        let chunk_bytes_and_subsets = options.install(|| {
+                            iter_concurrent_limit!(
+                                chunk_concurrent_limit,
+                                chunk_indices,
+                                map,
+                                retrieve_chunk
+                            )
+                            .collect::<Result<Vec<_>, _>>()
+                        })?;
 
                         Ok(merge_chunks_vlen(
                             chunk_bytes_and_subsets,
@@ -713,44 +1275,18 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
                             return Ok(ArrayBytes::new_flen(vec![]));
                         }
                         let mut output = Vec::with_capacity(size_output);
-
                         {
                             let output =
                                 UnsafeCellSlice::new_from_vec_with_spare_capacity(&mut output);
-                            let retrieve_chunk = |chunk_indices: Vec<u64>| {
-                                let chunk_subset = self.chunk_subset(&chunk_indices)?;
-                                let chunk_subset_overlap = chunk_subset.overlap(array_subset)?;
-                                unsafe {
-                                    self.retrieve_chunk_subset_into(
-                                        &chunk_indices,
-                                        &chunk_subset_overlap.relative_to(chunk_subset.start())?,
-                                        &output,
-                                        array_subset.shape(),
-                                        &chunk_subset_overlap.relative_to(array_subset.start())?,
-                                        &options,
-                                    )?;
-                                }
-                                // let chunk_subset_bytes = self.retrieve_chunk_subset_opt(
-                                //     &chunk_indices,
-                                //     &chunk_subset_overlap.relative_to(chunk_subset.start())?,
-                                //     &options,
-                                // )?;
-                                // update_bytes_flen(
-                                //     &output,
-                                //     array_subset.shape(),
-                                //     &chunk_subset_bytes.into_fixed()?,
-                                //     &chunk_subset_overlap.relative_to(array_subset.start())?,
-                                //     data_type_size,
-                                // );
-                                Ok::<_, ArrayError>(())
-                            };
-                            let indices = chunks.indices();
-                            iter_concurrent_limit!(
-                                chunk_concurrent_limit,
-                                indices,
-                                try_for_each,
-                                retrieve_chunk
-                            )?;
+                            unsafe {
+                                self.retrieve_array_subset_into_impl(
+                                    array_subset,
+                                    &chunks,
+                                    &output,
+                                    chunk_concurrent_limit,
+                                    &options,
+                                )?;
+                            }
                         }
                         unsafe { output.set_len(size_output) };
                         Ok(ArrayBytes::from(output))
@@ -760,6 +1296,179 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         }
     }
 
+    /// Retrieve the chunks intersecting `array_subset` and decode them into `output`.
+    ///
+    /// # Safety
+    /// `output` must hold `array_subset` with a fixed size data type and `chunks` must be the chunks intersecting `array_subset`.
+    unsafe fn retrieve_array_subset_into_impl(
+        &self,
+        array_subset: &ArraySubset,
+        chunks: &ArraySubset,
+        output: &UnsafeCellSlice<u8>,
+        chunk_concurrent_limit: usize,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let retrieve_chunk = |chunk_indices: Vec<u64>| {
+            let chunk_subset = self.chunk_subset(&chunk_indices)?;
+            let chunk_subset_overlap = chunk_subset.overlap(array_subset)?;
+            unsafe {
+                self.retrieve_chunk_subset_into(
+                    &chunk_indices,
+                    &chunk_subset_overlap.relative_to(chunk_subset.start())?,
+                    output,
+                    array_subset.shape(),
+                    &chunk_subset_overlap.relative_to(array_subset.start())?,
+                    options,
+                )?;
+            }
+            Ok::<_, ArrayError>(())
+        };
+        let indices = chunks.indices();
+        options.install(|| {
+            iter_concurrent_limit!(
+                chunk_concurrent_limit,
+                indices,
+                try_for_each,
+                retrieve_chunk
+            )
+        })
+    }
+
+    /// Explicit options version of [`retrieve_array_subset_into`](Array::retrieve_array_subset_into).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_array_subset_into_opt(
+        &self,
+        array_subset: &ArraySubset,
+        output: &mut [u8],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let DataTypeSize::Fixed(data_type_size) = self.data_type().size() else {
+            return Err(ArrayError::UnsupportedMethod(format!(
+                "cannot retrieve into a preallocated output with the variable sized data type {}",
+                self.data_type()
+            )));
+        };
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        let size_output = array_subset.num_elements_usize() * data_type_size;
+        if output.len() != size_output {
+            return Err(ArrayError::InvalidBytesInputSize(
+                output.len(),
+                size_output as u64,
+            ));
+        }
+
+        // Find the chunks intersecting this array subset
+        let Some(chunks) = self.chunks_in_array_subset(array_subset)? else {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        };
+        let num_chunks = chunks.num_elements_usize();
+        if num_chunks == 0 || size_output == 0 {
+            return Ok(());
+        }
+
+        if let Some(chunk_cache) = &self.chunk_cache {
+            let bytes = self
+                .retrieve_array_subset_opt_cached(chunk_cache, array_subset, options)?
+                .into_fixed()?;
+            output.copy_from_slice(&bytes);
+            return Ok(());
+        }
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            num_chunks,
+            options,
+            &codec_concurrency,
+        );
+
+        let output = UnsafeCellSlice::new(output);
+        // SAFETY: the output length has been validated against the array subset
+        unsafe {
+            self.retrieve_array_subset_into_impl(
+                array_subset,
+                &chunks,
+                &output,
+                chunk_concurrent_limit,
+                &options,
+            )
+        }
+    }
+
+    /// Explicit options version of [`retrieve_array_subset_elements_into`](Array::retrieve_array_subset_elements_into).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_array_subset_elements_into_opt<T: ElementOwned + bytemuck::Pod>(
+        &self,
+        array_subset: &ArraySubset,
+        output: &mut [T],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        T::validate_data_type(self.data_type())?;
+        self.retrieve_array_subset_into_opt(array_subset, bytemuck::cast_slice_mut(output), options)
+    }
+
+    /// Explicit options version of [`retrieve_array_subset_into_writer`](Array::retrieve_array_subset_into_writer).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_array_subset_into_writer_opt<W: std::io::Write>(
+        &self,
+        array_subset: &ArraySubset,
+        mut writer: W,
+        memory_budget: usize,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let data_type_size = self.data_type().fixed_size().unwrap_or_default();
+        let mut block_bytes = Vec::new();
+        for block in self.array_subset_blocks(array_subset, memory_budget)? {
+            block_bytes.resize(block.num_elements_usize() * data_type_size, 0);
+            self.retrieve_array_subset_into_opt(&block, &mut block_bytes, options)?;
+            writer.write_all(&block_bytes).map_err(StorageError::from)?;
+        }
+        writer.flush().map_err(StorageError::from)?;
+        Ok(())
+    }
+
+    /// Explicit options version of [`retrieve_array_subset_optional`](Array::retrieve_array_subset_optional).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn retrieve_array_subset_optional_opt(
+        &self,
+        array_subset: &ArraySubset,
+        options: &CodecOptions,
+    ) -> Result<(ArrayBytes<'_>, Vec<bool>), ArrayError> {
+        let chunks = self.chunks_in_array_subset_indices(array_subset)?;
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.len(),
+            options,
+            &codec_concurrency,
+        );
+
+        let retrieve_chunk = |chunk_indices: ArrayIndices| {
+            let chunk_bytes = self.retrieve_chunk_if_exists_opt(&chunk_indices, &options)?;
+            Ok::<_, ArrayError>((chunk_indices, chunk_bytes))
+        };
+        let chunks = options.install(|| {
+            iter_concurrent_limit!(chunk_concurrent_limit, chunks, map, retrieve_chunk)
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        self.merge_chunks_optional(array_subset, chunks)
+    }
+
     /// Explicit options version of [`retrieve_array_subset_elements`](Array::retrieve_array_subset_elements).
     #[allow(clippy::missing_errors_doc)]
     pub fn retrieve_array_subset_elements_opt<T: ElementOwned>(
@@ -769,10 +1478,83 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_array_subset_opt(array_subset, options)?,
         )
     }
 
+    /// Explicit options version of [`retrieve_array_subset_elements_cast`](Array::retrieve_array_subset_elements_cast).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn retrieve_array_subset_elements_cast_opt<T: ElementCast>(
+        &self,
+        array_subset: &ArraySubset,
+        mode: ElementCastMode,
+        options: &CodecOptions,
+    ) -> Result<Vec<T>, ArrayError> {
+        validate_element_cast_data_type(self.data_type())?;
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+
+        // Find the chunks intersecting this array subset
+        let Some(chunks) = self.chunks_in_array_subset(array_subset)? else {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        };
+
+        // Calculate chunk/codec concurrency
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            chunks.num_elements_usize(),
+            options,
+            &codec_concurrency,
+        );
+
+        let mut output = vec![T::zeroed(); array_subset.num_elements_usize()];
+        {
+            let output = UnsafeCellSlice::new(bytemuck::cast_slice_mut::<T, u8>(&mut output));
+            let retrieve_chunk = |chunk_indices: Vec<u64>| -> Result<(), ArrayError> {
+                let chunk_subset = self.chunk_subset(&chunk_indices)?;
+                let chunk_subset_overlap = chunk_subset.overlap(array_subset)?;
+                let chunk_bytes = self
+                    .retrieve_chunk_subset_opt(
+                        &chunk_indices,
+                        &chunk_subset_overlap.relative_to(chunk_subset.start())?,
+                        &options,
+                    )?
+                    .into_fixed()?;
+                let chunk_elements =
+                    cast_bytes_to_elements::<T>(&chunk_bytes, self.data_type(), mode)?;
+                update_bytes_flen(
+                    &output,
+                    array_subset.shape(),
+                    &bytemuck::cast_slice(&chunk_elements).into(),
+                    &chunk_subset_overlap.relative_to(array_subset.start())?,
+                    size_of::<T>(),
+                );
+                Ok(())
+            };
+            let indices = chunks.indices();
+            options.install(|| {
+                iter_concurrent_limit!(
+                    chunk_concurrent_limit,
+                    indices,
+                    try_for_each,
+                    retrieve_chunk
+                )
+            })?;
+        }
+        Ok(output)
+    }
+
     #[cfg(feature = "ndarray")]
     /// Explicit options version of [`retrieve_array_subset_ndarray`](Array::retrieve_array_subset_ndarray).
     #[allow(clippy::missing_errors_doc)]
@@ -793,6 +1575,15 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         chunk_subset: &ArraySubset,
         options: &CodecOptions,
     ) -> Result<ArrayBytes<'_>, ArrayError> {
+        if let Some(chunk_cache) = &self.chunk_cache {
+            return self.retrieve_chunk_subset_opt_cached(
+                chunk_cache,
+                chunk_indices,
+                chunk_subset,
+                options,
+            );
+        }
+
         let chunk_representation = self.chunk_array_representation(chunk_indices)?;
         if !chunk_subset.inbounds(&chunk_representation.shape_u64()) {
             return Err(ArrayError::InvalidArraySubset(
@@ -809,19 +1600,35 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         } else {
             let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
             let storage_transformer = self
-                .storage_transformers()
+                .applied_storage_transformers()
                 .create_readable_transformer(storage_handle)?;
             let input_handle = Arc::new(StoragePartialDecoder::new(
                 storage_transformer,
                 self.chunk_key(chunk_indices),
             ));
 
-            self.codecs
-                .clone()
-                .partial_decoder(input_handle, &chunk_representation, options)?
-                .partial_decode(&[chunk_subset.clone()], options)?
-                .remove(0)
-                .into_owned()
+            let bytes = self.recover_corrupt_shard(
+                chunk_indices,
+                self.cached_partial_decoder(chunk_indices, || {
+                    self.codecs.clone().partial_decoder(
+                        input_handle,
+                        &chunk_representation,
+                        options,
+                    )
+                })
+                .and_then(|partial_decoder| {
+                    Ok(partial_decoder
+                        .partial_decode(std::slice::from_ref(chunk_subset), options)?
+                        .remove(0)
+                        .into_owned())
+                }),
+                options,
+            )?;
+            bytes.unwrap_or_else(|| {
+                let array_size =
+                    ArraySize::new(self.data_type().size(), chunk_subset.num_elements());
+                ArrayBytes::new_fill_value(array_size, self.fill_value())
+            })
         };
         bytes.validate(chunk_subset.num_elements(), self.data_type().size())?;
         Ok(bytes)
@@ -860,24 +1667,44 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         } else {
             let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
             let storage_transformer = self
-                .storage_transformers()
+                .applied_storage_transformers()
                 .create_readable_transformer(storage_handle)?;
             let input_handle = Arc::new(StoragePartialDecoder::new(
                 storage_transformer,
                 self.chunk_key(chunk_indices),
             ));
 
-            unsafe {
-                self.codecs
-                    .clone()
-                    .partial_decoder(input_handle, &chunk_representation, options)?
-                    .partial_decode_into(
+            let decoded = self.recover_corrupt_shard(
+                chunk_indices,
+                self.cached_partial_decoder(chunk_indices, || {
+                    self.codecs.clone().partial_decoder(
+                        input_handle,
+                        &chunk_representation,
+                        options,
+                    )
+                })
+                .and_then(|partial_decoder| unsafe {
+                    partial_decoder.partial_decode_into(
                         chunk_subset,
                         output,
                         output_shape,
                         output_subset,
                         options,
-                    )?;
+                    )
+                }),
+                options,
+            )?;
+            if decoded.is_none() {
+                unsafe {
+                    copy_fill_value_into(
+                        self.data_type(),
+                        self.fill_value(),
+                        output,
+                        output_shape,
+                        output_subset,
+                    )
+                }
+                .map_err(ArrayError::CodecError)?;
             }
             Ok(())
         }
@@ -893,6 +1720,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_chunk_subset_opt(chunk_indices, chunk_subset, options)?,
         )
     }
@@ -918,18 +1746,30 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         options: &CodecOptions,
     ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, ArrayError> {
+        let chunk_representation = self.chunk_array_representation(chunk_indices)?;
+        if let Some(chunk) = self
+            .chunk_cache
+            .as_ref()
+            .and_then(|chunk_cache| chunk_cache.get(chunk_indices))
+        {
+            return Ok(Arc::new(ArrayPartialDecoderCache::new_with_bytes(
+                chunk_representation,
+                Arc::unwrap_or_clone(chunk),
+            )));
+        }
+
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_readable_transformer(storage_handle)?;
         let input_handle = Arc::new(StoragePartialDecoder::new(
             storage_transformer,
             self.chunk_key(chunk_indices),
         ));
-        let chunk_representation = self.chunk_array_representation(chunk_indices)?;
-        Ok(self
-            .codecs
-            .clone()
-            .partial_decoder(input_handle, &chunk_representation, options)?)
+        Ok(self.cached_partial_decoder(chunk_indices, || {
+            self.codecs
+                .clone()
+                .partial_decoder(input_handle, &chunk_representation, options)
+        })?)
     }
 }
@@ -5,24 +5,105 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use crate::{
     array::ArrayBytes,
     array_subset::ArraySubset,
-    storage::{ReadableWritableStorageTraits, StorageHandle},
+    metadata::v3::AdditionalFields,
+    node::{
+        meta_key_v2_array, meta_key_v2_attributes, meta_key_v3, mutate_document_attributes,
+        serialize_document, stored_attributes_v2, stored_metadata_document,
+    },
+    storage::{
+        Bytes, ReadableWritableStorageTraits, StorageError, StorageHandle, ValueDigestTraits,
+    },
 };
 
+#[cfg(feature = "dlpack")]
+use super::dlpack::{dlpack_data_type, ManagedTensor};
 use super::{
     codec::{
-        options::CodecOptions, ArrayPartialEncoderTraits, ArrayToBytesCodecTraits,
+        options::CodecOptions, ArrayPartialEncoderTraits, ArrayToBytesCodecTraits, CodecTraits,
         StoragePartialDecoder, StoragePartialEncoder,
     },
     concurrency::concurrency_chunks_and_codec,
-    update_array_bytes, Array, ArrayError, Element,
+    element_cast::cast_elements_to_bytes,
+    update_array_bytes, Array, ArrayError, ArrayIndices, ArrayMetadata, ArrayMetadataOptions,
+    ArrayShape, Element, ElementCast, ElementCastMode, FillValue,
 };
 
 impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage> {
+    /// Store the attributes of the array, preserving any other fields of the stored metadata.
+    ///
+    /// Unlike [`store_metadata`](Array::store_metadata), the stored metadata is read and only its attributes are replaced.
+    /// Fields written by other tools (e.g. additional fields) are preserved.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if
+    ///  - the stored metadata is missing or invalid,
+    ///  - the stored metadata differs from the array metadata (ignoring attributes and additional fields), or
+    ///  - there is an underlying store error.
+    pub fn store_attributes(&self) -> Result<(), StorageError> {
+        let attributes = self.attributes().clone();
+        self.update_stored_attributes(|stored_attributes| *stored_attributes = attributes)?;
+        Ok(())
+    }
+
+    /// Mutate the attributes of the array with `f` and store them, preserving any other fields of the stored metadata.
+    ///
+    /// `f` is applied to the attributes of the stored metadata, rather than those held by this array, so attributes written by other tools are preserved.
+    /// The attributes of this array are then replaced with the stored attributes.
+    ///
+    /// # Errors
+    /// See [`store_attributes`](Array::store_attributes).
+    pub fn mutate_attributes(
+        &mut self,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>),
+    ) -> Result<(), StorageError> {
+        *self.attributes_mut() = self.update_stored_attributes(f)?;
+        Ok(())
+    }
+
+    fn update_stored_attributes(
+        &self,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>),
+    ) -> Result<serde_json::Map<String, serde_json::Value>, StorageError> {
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_writable_transformer(storage_handle)?;
+
+        let path = self.path();
+        match self.metadata_opt(&ArrayMetadataOptions::default()) {
+            ArrayMetadata::V3(metadata) => {
+                let key = meta_key_v3(path);
+                let mut document =
+                    stored_metadata_document(&key, self.storage.get(&key)?, &metadata, |m| {
+                        m.attributes.clear();
+                        m.additional_fields = AdditionalFields::default();
+                    })?;
+                let attributes = mutate_document_attributes(&mut document, f);
+                storage_transformer.set(&key, serialize_document(&key, &document)?)?;
+                Ok(attributes)
+            }
+            ArrayMetadata::V2(metadata) => {
+                let key = meta_key_v2_array(path);
+                stored_metadata_document(&key, self.storage.get(&key)?, &metadata, |m| {
+                    m.attributes.clear();
+                    m.additional_fields = AdditionalFields::default();
+                })?;
+                let key = meta_key_v2_attributes(path);
+                let mut attributes = stored_attributes_v2(&key, self.storage.get(&key)?)?;
+                f(&mut attributes);
+                storage_transformer.set(&key, serialize_document(&key, &attributes)?)?;
+                Ok(attributes)
+            }
+        }
+    }
+
     /// Encode `chunk_subset_bytes` and store in `chunk_subset` of the chunk at `chunk_indices` with default codec options.
     ///
     /// Use [`store_chunk_subset_opt`](Array::store_chunk_subset_opt) to control codec options.
     /// Prefer to use [`store_chunk`](Array::store_chunk) where possible, since this function may decode the chunk before updating it and reencoding it.
     ///
+    /// If the codecs [support partial encoding](crate::array::codec::CodecTraits::supports_partial_encode) (e.g. `bytes` with an optional `transpose`) and the store supports partial writes, only the bytes of `chunk_subset` are written to an existing chunk.
+    ///
     /// # Errors
     /// Returns an [`ArrayError`] if
     ///  - `chunk_subset` is invalid or out of bounds of the chunk,
@@ -42,7 +123,7 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
             chunk_indices,
             chunk_subset,
             chunk_subset_bytes,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
     }
 
@@ -66,7 +147,7 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
             chunk_indices,
             chunk_subset,
             chunk_subset_elements,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
     }
 
@@ -88,7 +169,7 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
             chunk_indices,
             chunk_subset_start,
             chunk_subset_array,
-            &CodecOptions::default(),
+            &self.codec_options(),
         )
     }
 
@@ -109,7 +190,7 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         array_subset: &ArraySubset,
         subset_bytes: impl Into<ArrayBytes<'a>>,
     ) -> Result<(), ArrayError> {
-        self.store_array_subset_opt(array_subset, subset_bytes, &CodecOptions::default())
+        self.store_array_subset_opt(array_subset, subset_bytes, &self.codec_options())
     }
 
     /// Encode `subset_elements` and store in `array_subset`.
@@ -127,10 +208,46 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         array_subset: &ArraySubset,
         subset_elements: &[T],
     ) -> Result<(), ArrayError> {
-        self.store_array_subset_elements_opt(
+        self.store_array_subset_elements_opt(array_subset, subset_elements, &self.codec_options())
+    }
+
+    /// Encode `subset_elements` strings and store in `array_subset`.
+    ///
+    /// The elements are packed into the variable-length bytes and offsets of the `string` data type.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is not a string data type, or
+    ///  - a [`store_array_subset_elements`](Array::store_array_subset_elements) error condition is met.
+    pub fn store_array_subset_elements_string(
+        &self,
+        array_subset: &ArraySubset,
+        subset_elements: &[impl AsRef<str>],
+    ) -> Result<(), ArrayError> {
+        let subset_elements: Vec<&str> = subset_elements.iter().map(AsRef::as_ref).collect();
+        self.store_array_subset_elements(array_subset, &subset_elements)
+    }
+
+    /// Cast `subset_elements` to the data type of the array, then encode and store in `array_subset`.
+    ///
+    /// Elements are cast according to `mode`, see [`ElementCastMode`] for the casting policy.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type of the array does not support casting (see [`ElementCast`]),
+    ///  - an element is out of range of the data type of the array with [`ElementCastMode::Checked`], or
+    ///  - a [`store_array_subset`](Array::store_array_subset) error condition is met.
+    pub fn store_array_subset_elements_cast<T: ElementCast>(
+        &self,
+        array_subset: &ArraySubset,
+        subset_elements: &[T],
+        mode: ElementCastMode,
+    ) -> Result<(), ArrayError> {
+        self.store_array_subset_elements_cast_opt(
             array_subset,
             subset_elements,
-            &CodecOptions::default(),
+            mode,
+            &self.codec_options(),
         )
     }
 
@@ -148,7 +265,163 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         subset_start: &[u64],
         subset_array: impl Into<ndarray::Array<T, D>>,
     ) -> Result<(), ArrayError> {
-        self.store_array_subset_ndarray_opt(subset_start, subset_array, &CodecOptions::default())
+        self.store_array_subset_ndarray_opt(subset_start, subset_array, &self.codec_options())
+    }
+
+    #[cfg(feature = "dlpack")]
+    /// Encode the `DLPack` `tensor` and store it in the array subset starting at `subset_start`.
+    ///
+    /// Use [`store_array_subset_dlpack_opt`](Array::store_array_subset_dlpack_opt) to control codec options.
+    /// The tensor is encoded without copying if it is compact and row-major.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type of `tensor` does not match the data type of the array,
+    ///  - `tensor` is not compact and row-major on the CPU,
+    ///  - the shape of `tensor` is incompatible with `subset_start` or the array, or
+    ///  - a [`store_array_subset`](Array::store_array_subset) error condition is met.
+    pub fn store_array_subset_dlpack(
+        &self,
+        subset_start: &[u64],
+        tensor: &ManagedTensor,
+    ) -> Result<(), ArrayError> {
+        self.store_array_subset_dlpack_opt(subset_start, tensor, &self.codec_options())
+    }
+
+    /// Fill `array_subset` with `value`.
+    ///
+    /// Use [`fill_array_subset_opt`](Array::fill_array_subset_opt) to control codec options.
+    /// Unlike [`store_array_subset`](Array::store_array_subset), the bytes of the whole subset are never materialised.
+    /// Chunks with all of their elements within the array shape covered by `array_subset` are filled with [`fill_chunks`](Array::fill_chunks) semantics: they are erased if `value` is the fill value of the array, otherwise they are encoded from `value` and stored without being decoded.
+    /// The remaining chunks are updated with [`store_chunk_subset`](Array::store_chunk_subset).
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is variable sized,
+    ///  - the size of `value` does not match the data type size,
+    ///  - the dimensionality of `array_subset` does not match the chunk grid dimensionality,
+    ///  - there is a codec encoding or decoding error, or
+    ///  - an underlying store error.
+    pub fn fill_array_subset(
+        &self,
+        array_subset: &ArraySubset,
+        value: &FillValue,
+    ) -> Result<(), ArrayError> {
+        self.fill_array_subset_opt(array_subset, value, &self.codec_options())
+    }
+
+    /// Resize the array to `new_shape` and store the updated metadata.
+    ///
+    /// Use [`resize_opt`](Array::resize_opt) to control codec options.
+    ///
+    /// Elements of existing chunks that are outside of the current or new array shape are reset to the fill value.
+    /// This ensures that previously out-of-bounds regions of edge chunks read as the fill value when growing, and that stale data does not persist beyond the new bounds when shrinking.
+    ///
+    /// If `erase_out_of_bounds` is true, chunks that are entirely outside of the new array shape are erased.
+    /// Otherwise, they are retained in the store and are not inspected on subsequent resizes.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the dimensionality of `new_shape` does not match the array dimensionality,
+    ///  - `new_shape` is incompatible with the chunk grid,
+    ///  - there is a codec encoding or decoding error, or
+    ///  - an underlying store error.
+    pub fn resize(
+        &mut self,
+        new_shape: ArrayShape,
+        erase_out_of_bounds: bool,
+    ) -> Result<(), ArrayError> {
+        self.resize_opt(new_shape, erase_out_of_bounds, &self.codec_options())
+    }
+
+    /// Encode `subset_bytes` and append it to the end of the array along `axis`.
+    ///
+    /// Use [`append_opt`](Array::append_opt) to control codec options.
+    /// The array is grown with [`resize`](Array::resize) to accommodate the data, and the shape of `subset_bytes` must match the array shape in every dimension except `axis`.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - `axis` is out of bounds,
+    ///  - the number of elements in `subset_bytes` is not a multiple of the number of elements in a hyperplane of the array perpendicular to `axis`, or
+    ///  - a [`resize`](Array::resize) or [`store_array_subset`](Array::store_array_subset) error condition is met.
+    pub fn append<'a>(
+        &mut self,
+        axis: usize,
+        subset_bytes: impl Into<ArrayBytes<'a>>,
+    ) -> Result<(), ArrayError> {
+        self.append_opt(axis, subset_bytes, &self.codec_options())
+    }
+
+    /// Encode `subset_elements` and append it to the end of the array along `axis`.
+    ///
+    /// Use [`append_elements_opt`](Array::append_elements_opt) to control codec options.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the size of `T` does not match the data type size, or
+    ///  - an [`append`](Array::append) error condition is met.
+    pub fn append_elements<T: Element>(
+        &mut self,
+        axis: usize,
+        subset_elements: &[T],
+    ) -> Result<(), ArrayError> {
+        self.append_elements_opt(axis, subset_elements, &self.codec_options())
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Encode `subset_array` and append it to the end of the array along `axis`.
+    ///
+    /// Use [`append_ndarray_opt`](Array::append_ndarray_opt) to control codec options.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the shape of `subset_array` does not match the array shape in every dimension except `axis`, or
+    ///  - an [`append_elements`](Array::append_elements) error condition is met.
+    pub fn append_ndarray<T: Element, D: ndarray::Dimension>(
+        &mut self,
+        axis: usize,
+        subset_array: impl Into<ndarray::Array<T, D>>,
+    ) -> Result<(), ArrayError> {
+        self.append_ndarray_opt(axis, subset_array, &self.codec_options())
+    }
+
+    #[cfg(feature = "sharding")]
+    /// Compact the shard at `shard_indices` by removing encoded inner chunks that are no longer referenced by the shard index.
+    ///
+    /// Use [`compact_shard_opt`](Array::compact_shard_opt) to control codec options.
+    /// [Experimental partial encoding](crate::config::Config#experimental-partial-encoding) appends updated inner chunks to a shard rather than rewriting it, so a shard grows with each partial update.
+    /// Compaction rewrites the shard without the stale inner chunks, and the inner chunks are not reencoded.
+    ///
+    /// Returns true if the shard was rewritten.
+    /// Returns false if the array is not exclusively sharded, the shard does not exist, or the shard is already compact.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - `shard_indices` are invalid,
+    ///  - there is a codec encoding or decoding error, or
+    ///  - an underlying store error.
+    pub fn compact_shard(&self, shard_indices: &[u64]) -> Result<bool, ArrayError> {
+        self.compact_shard_opt(shard_indices, &self.codec_options())
+    }
+
+    /// Encode `chunk_bytes` and store at `chunk_indices` only if the encoded chunk differs from the stored chunk.
+    ///
+    /// This avoids rewriting identical chunks, which can be costly for object stores (e.g. request costs and versioned buckets).
+    /// The size of the stored chunk is retrieved first, and the stored chunk is only retrieved and compared if the sizes match.
+    /// Use [`store_chunk_if_changed_opt`](Array::store_chunk_if_changed_opt) to compare with a digest provided by the store instead.
+    ///
+    /// A chunk composed entirely of the fill value is erased if it exists, as with [`store_chunk`](Array::store_chunk).
+    ///
+    /// Returns true if the store was modified.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if a [`store_chunk`](Array::store_chunk) error condition is met.
+    pub fn store_chunk_if_changed<'a>(
+        &self,
+        chunk_indices: &[u64],
+        chunk_bytes: impl Into<ArrayBytes<'a>>,
+    ) -> Result<bool, ArrayError> {
+        self.store_chunk_if_changed_opt(chunk_indices, chunk_bytes, None, &self.codec_options())
     }
 
     /////////////////////////////////////////////////////////////////////////////
@@ -190,10 +463,20 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
             // let mutex = self.storage.mutex(&key)?;
             // let _lock = mutex.lock();
 
-            if options.experimental_partial_encoding() {
+            // Partially encode the chunk if the codecs can write the subset without decoding the chunk
+            let partial_encode = (options.experimental_partial_encoding()
+                || self.codecs().supports_partial_encode())
+                && self
+                    .applied_storage_transformers()
+                    .create_writable_transformer(Arc::new(StorageHandle::new(
+                        self.storage.clone(),
+                    )))?
+                    .supports_set_partial();
+            if partial_encode {
                 let partial_encoder = self.partial_encoder(chunk_indices, options)?;
-                Ok(partial_encoder
-                    .partial_encode(&[(chunk_subset, chunk_subset_bytes)], options)?)
+                partial_encoder.partial_encode(&[(chunk_subset, chunk_subset_bytes)], options)?;
+                self.invalidate_cached_chunk(chunk_indices);
+                Ok(())
             } else {
                 // Decode the entire chunk
                 let chunk_bytes_old = self.retrieve_chunk_opt(chunk_indices, options)?;
@@ -225,7 +508,8 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         chunk_subset_elements: &[T],
         options: &CodecOptions,
     ) -> Result<(), ArrayError> {
-        let chunk_subset_bytes = T::into_array_bytes(self.data_type(), chunk_subset_elements)?;
+        let chunk_subset_bytes =
+            T::into_array_bytes(self.data_type(), self.fill_value(), chunk_subset_elements)?;
         self.store_chunk_subset_opt(chunk_indices, chunk_subset, chunk_subset_bytes, options)
     }
 
@@ -329,12 +613,14 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
             };
 
             let indices = chunks.indices();
-            rayon_iter_concurrent_limit::iter_concurrent_limit!(
-                chunk_concurrent_limit,
-                indices,
-                try_for_each,
-                store_chunk
-            )?;
+            options.install(|| {
+                rayon_iter_concurrent_limit::iter_concurrent_limit!(
+                    chunk_concurrent_limit,
+                    indices,
+                    try_for_each,
+                    store_chunk
+                )
+            })?;
         }
         Ok(())
     }
@@ -347,7 +633,21 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         subset_elements: &[T],
         options: &CodecOptions,
     ) -> Result<(), ArrayError> {
-        let subset_bytes = T::into_array_bytes(self.data_type(), subset_elements)?;
+        let subset_bytes =
+            T::into_array_bytes(self.data_type(), self.fill_value(), subset_elements)?;
+        self.store_array_subset_opt(array_subset, subset_bytes, options)
+    }
+
+    /// Explicit options version of [`store_array_subset_elements_cast`](Array::store_array_subset_elements_cast).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn store_array_subset_elements_cast_opt<T: ElementCast>(
+        &self,
+        array_subset: &ArraySubset,
+        subset_elements: &[T],
+        mode: ElementCastMode,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let subset_bytes = cast_elements_to_bytes(subset_elements, self.data_type(), mode)?;
         self.store_array_subset_opt(array_subset, subset_bytes, options)
     }
 
@@ -369,6 +669,283 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         self.store_array_subset_elements_opt(&subset, &subset_array, options)
     }
 
+    #[cfg(feature = "dlpack")]
+    /// Explicit options version of [`store_array_subset_dlpack`](Array::store_array_subset_dlpack).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn store_array_subset_dlpack_opt(
+        &self,
+        subset_start: &[u64],
+        tensor: &ManagedTensor,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        if tensor.dtype() != dlpack_data_type(self.data_type())? {
+            return Err(ArrayError::IncompatibleElementType);
+        }
+        let subset_shape = tensor
+            .shape()
+            .iter()
+            .map(|&size| u64::try_from(size))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                ArrayError::UnsupportedMethod(format!(
+                    "cannot store a DLPack tensor with shape {:?}",
+                    tensor.shape()
+                ))
+            })?;
+        let subset = ArraySubset::new_with_start_shape(subset_start.to_vec(), subset_shape)?;
+        let subset_bytes = ArrayBytes::new_flen(tensor.as_bytes()?);
+        self.store_array_subset_opt(&subset, subset_bytes, options)
+    }
+
+    /// Explicit options version of [`fill_array_subset`](Array::fill_array_subset).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn fill_array_subset_opt(
+        &self,
+        array_subset: &ArraySubset,
+        value: &FillValue,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        self.validate_fill(value)?;
+        if array_subset.dimensionality() != self.dimensionality() {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        }
+        let Some(chunks) = self.chunks_in_array_subset(array_subset)? else {
+            return Err(ArrayError::InvalidArraySubset(
+                array_subset.clone(),
+                self.shape().to_vec(),
+            ));
+        };
+
+        // Calculate chunk/codec concurrency
+        let num_chunks = chunks.num_elements_usize();
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            num_chunks,
+            options,
+            &codec_concurrency,
+        );
+
+        let fill_chunk = |chunk_indices: Vec<u64>| -> Result<(), ArrayError> {
+            let chunk_subset_in_array = self.chunk_subset_bounded(&chunk_indices)?;
+            let overlap = unsafe { array_subset.overlap_unchecked(&chunk_subset_in_array) };
+            if overlap == chunk_subset_in_array {
+                // The subset covers the chunk, so skip decoding
+                self.fill_chunk_opt(&chunk_indices, value, &options)
+            } else {
+                let array_subset_in_chunk_subset =
+                    unsafe { overlap.relative_to_unchecked(chunk_subset_in_array.start()) };
+                self.store_chunk_subset_opt(
+                    &chunk_indices,
+                    &array_subset_in_chunk_subset,
+                    self.fill_bytes(value, overlap.num_elements()),
+                    &options,
+                )
+            }
+        };
+        let indices = chunks.indices();
+        options.install(|| {
+            rayon_iter_concurrent_limit::iter_concurrent_limit!(
+                chunk_concurrent_limit,
+                indices,
+                try_for_each,
+                fill_chunk
+            )
+        })
+    }
+
+    /// Explicit options version of [`resize`](Array::resize).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn resize_opt(
+        &mut self,
+        new_shape: ArrayShape,
+        erase_out_of_bounds: bool,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let shared_shape = self.resize_shared_shape(&new_shape)?;
+        let chunks_to_erase = if erase_out_of_bounds {
+            self.resize_chunks_to_erase(&new_shape)?
+        } else {
+            vec![]
+        };
+
+        // Reset elements outside of the shared region in existing chunks that extend beyond it
+        let reset_chunk = |chunk_indices: ArrayIndices| -> Result<(), ArrayError> {
+            if let Some(chunk_bytes) = self.retrieve_chunk_if_exists_opt(&chunk_indices, options)? {
+                let chunk_bytes =
+                    self.resize_reset_chunk_bytes(&chunk_indices, &chunk_bytes, &shared_shape)?;
+                self.store_chunk_opt(&chunk_indices, chunk_bytes, options)?;
+            }
+            Ok(())
+        };
+        self.resize_chunks_to_reset(&shared_shape)?
+            .into_par_iter()
+            .try_for_each(reset_chunk)?;
+
+        self.set_shape(new_shape);
+        self.store_metadata()?;
+
+        chunks_to_erase
+            .into_par_iter()
+            .try_for_each(|chunk_indices| self.erase_chunk(&chunk_indices))?;
+        Ok(())
+    }
+
+    /// Explicit options version of [`append`](Array::append).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn append_opt<'a>(
+        &mut self,
+        axis: usize,
+        subset_bytes: impl Into<ArrayBytes<'a>>,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let subset_bytes = subset_bytes.into();
+        let (new_shape, array_subset) = self.append_shape_and_subset(axis, &subset_bytes)?;
+        self.resize_opt(new_shape, false, options)?;
+        self.store_array_subset_opt(&array_subset, subset_bytes, options)
+    }
+
+    /// Explicit options version of [`append_elements`](Array::append_elements).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn append_elements_opt<T: Element>(
+        &mut self,
+        axis: usize,
+        subset_elements: &[T],
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let subset_bytes =
+            T::into_array_bytes(self.data_type(), self.fill_value(), subset_elements)?;
+        self.append_opt(axis, subset_bytes, options)
+    }
+
+    #[cfg(feature = "ndarray")]
+    /// Explicit options version of [`append_ndarray`](Array::append_ndarray).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn append_ndarray_opt<T: Element, D: ndarray::Dimension>(
+        &mut self,
+        axis: usize,
+        subset_array: impl Into<ndarray::Array<T, D>>,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let subset_array: ndarray::Array<T, D> = subset_array.into();
+        super::validate_append_ndarray_shape(self.shape(), axis, subset_array.shape())?;
+        let subset_array = super::ndarray_into_vec(subset_array);
+        self.append_elements_opt(axis, &subset_array, options)
+    }
+
+    #[cfg(feature = "sharding")]
+    /// Explicit options version of [`compact_shard`](Array::compact_shard).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn compact_shard_opt(
+        &self,
+        shard_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<bool, ArrayError> {
+        use super::{
+            codec::{ShardingCodec, ShardingCodecConfiguration},
+            ArrayShardedExt,
+        };
+
+        if !self.is_exclusively_sharded() {
+            return Ok(false);
+        }
+        let shard_representation = self.chunk_array_representation(shard_indices)?;
+        let Some(encoded_shard) = self.retrieve_encoded_chunk(shard_indices)? else {
+            return Ok(false);
+        };
+
+        let sharding_codec_metadata = self
+            .codecs()
+            .array_to_bytes_codec()
+            .create_metadata()
+            .expect("valid sharding metadata");
+        let sharding_codec_configuration = sharding_codec_metadata
+            .to_configuration::<ShardingCodecConfiguration>()
+            .expect("valid sharding configuration");
+        let sharding_codec = ShardingCodec::new_with_configuration(&sharding_codec_configuration)
+            .expect("supported sharding codec configuration, already instantiated in array");
+
+        let encoded_shard_compacted =
+            sharding_codec.compact(&encoded_shard, &shard_representation, options)?;
+        if encoded_shard_compacted.len() < encoded_shard.len() {
+            unsafe {
+                self.store_encoded_chunk(shard_indices, encoded_shard_compacted.into())?;
+            }
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Explicit options version of [`store_chunk_if_changed`](Array::store_chunk_if_changed).
+    ///
+    /// If `digest` is set and the store provides the digest of the stored chunk with [`ReadableStorageTraits::digest_key`](crate::storage::ReadableStorageTraits::digest_key), the stored chunk is compared by digest rather than retrieved.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn store_chunk_if_changed_opt<'a>(
+        &self,
+        chunk_indices: &[u64],
+        chunk_bytes: impl Into<ArrayBytes<'a>>,
+        digest: Option<&dyn ValueDigestTraits>,
+        options: &CodecOptions,
+    ) -> Result<bool, ArrayError> {
+        let chunk_bytes = chunk_bytes.into();
+
+        // Validation
+        let chunk_array_representation = self.chunk_array_representation(chunk_indices)?;
+        chunk_bytes.validate(
+            chunk_array_representation.num_elements(),
+            chunk_array_representation.data_type().size(),
+        )?;
+
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_readable_transformer(storage_handle)?;
+        let chunk_key = self.chunk_key(chunk_indices);
+
+        let is_fill_value =
+            !options.store_empty_chunks() && chunk_bytes.is_fill_value(self.fill_value());
+        if is_fill_value {
+            if storage_transformer.size_key(&chunk_key)?.is_none() {
+                return Ok(false);
+            }
+            self.erase_chunk(chunk_indices)?;
+            return Ok(true);
+        }
+
+        let chunk_encoded = self
+            .codecs()
+            .encode(chunk_bytes, &chunk_array_representation, options)
+            .map_err(ArrayError::CodecError)?
+            .into_owned();
+        if storage_transformer.size_key(&chunk_key)? == Some(chunk_encoded.len() as u64) {
+            let stored_digest = match digest {
+                Some(digest) => storage_transformer
+                    .digest_key(&chunk_key, digest.algorithm())?
+                    .map(|stored_digest| (digest, stored_digest)),
+                None => None,
+            };
+            let unchanged = if let Some((digest, stored_digest)) = stored_digest {
+                stored_digest == digest.digest(&chunk_encoded)
+            } else {
+                storage_transformer
+                    .get(&chunk_key)?
+                    .is_some_and(|stored| stored == chunk_encoded)
+            };
+            if unchanged {
+                return Ok(false);
+            }
+        }
+
+        unsafe { self.store_encoded_chunk(chunk_indices, Bytes::from(chunk_encoded)) }?;
+        Ok(true)
+    }
+
     /// Initialises a partial encoder for the chunk at `chunk_indices`.
     ///
     /// Only one partial encoder should be created for a chunk at a time because:
@@ -377,6 +954,8 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
     ///
     /// Partial encoding with [`ArrayPartialEncoderTraits::partial_encode`] will use parallelism internally where possible.
     ///
+    /// The chunk is removed from the [chunk cache](Array::with_chunk_cache) when the partial encoder is created, but not after it encodes.
+    ///
     /// # Errors
     /// Returns an [`ArrayError`] if initialisation of the partial encoder fails.
     pub fn partial_encoder(
@@ -384,11 +963,14 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
         chunk_indices: &[u64],
         options: &CodecOptions,
     ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, ArrayError> {
+        // The chunk is assumed to be modified by the partial encoder
+        self.invalidate_cached_chunk(chunk_indices);
+
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
 
         // Input
         let storage_transformer_read = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_readable_transformer(storage_handle.clone())?;
         let input_handle = Arc::new(StoragePartialDecoder::new(
             storage_transformer_read,
@@ -398,7 +980,7 @@ impl<TStorage: ?Sized + ReadableWritableStorageTraits + 'static> Array<TStorage>
 
         // Output
         let storage_transformer_write = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_writable_transformer(storage_handle)?;
         let output_handle = Arc::new(StoragePartialEncoder::new(
             storage_transformer_write,
@@ -3,13 +3,12 @@ use std::{collections::HashMap, sync::Arc};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use rayon_iter_concurrent_limit::iter_concurrent_limit;
 use unsafe_cell_slice::UnsafeCellSlice;
-use zarrs_metadata::v3::array::codec::sharding::ShardingCodecConfiguration;
 use zarrs_storage::byte_range::ByteRange;
 use zarrs_storage::StorageHandle;
 
 use super::array_bytes::{merge_chunks_vlen, update_bytes_flen};
 use super::codec::array_to_bytes::sharding::ShardingPartialDecoder;
-use super::codec::{CodecError, ShardingCodec};
+use super::codec::CodecError;
 use super::element::ElementOwned;
 use super::{
     codec::CodecOptions, concurrency::concurrency_chunks_and_codec, Array, ArrayError,
@@ -117,7 +116,7 @@ impl ArrayShardedReadableExtCache {
             // Create the sharding partial decoder directly, without a codec chain
             let storage_handle = Arc::new(StorageHandle::new(array.storage.clone()));
             let storage_transformer = array
-                .storage_transformers()
+                .applied_storage_transformers()
                 .create_readable_transformer(storage_handle)?;
             let input_handle = Arc::new(StoragePartialDecoder::new(
                 storage_transformer,
@@ -126,19 +125,9 @@ impl ArrayShardedReadableExtCache {
 
             // --- Workaround for lack off trait upcasting ---
             let chunk_representation = array.chunk_array_representation(shard_indices)?;
-            let sharding_codec_metadata = array
-                .codecs()
-                .array_to_bytes_codec()
-                .create_metadata()
-                .expect("valid sharding metadata");
-            let sharding_codec_configuration = sharding_codec_metadata
-                .to_configuration::<ShardingCodecConfiguration>()
-                .expect("valid sharding configuration");
-            let sharding_codec = Arc::new(
-                ShardingCodec::new_with_configuration(&sharding_codec_configuration).expect(
-                    "supported sharding codec configuration, already instantiated in array",
-                ),
-            );
+            let sharding_codec = array
+                .exclusive_sharding_codec()
+                .expect("supported sharding codec configuration, already instantiated in array");
             let partial_decoder =
                 MaybeShardingPartialDecoder::Sharding(Arc::new(ShardingPartialDecoder::new(
                     input_handle,
@@ -147,7 +136,7 @@ impl ArrayShardedReadableExtCache {
                     sharding_codec.inner_codecs.clone(),
                     &sharding_codec.index_codecs,
                     sharding_codec.index_location,
-                    &CodecOptions::default(),
+                    &array.codec_options(),
                 )?));
             // // TODO: Trait upcasting
             // let partial_decoder = array
@@ -432,6 +421,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayShardedReadableExt
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_inner_chunk_opt(cache, inner_chunk_indices, options)?,
         )
     }
@@ -486,6 +476,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayShardedReadableExt
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_inner_chunks_opt(cache, inner_chunks, options)?,
         )
     }
@@ -573,13 +564,15 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayShardedReadableExt
                         };
 
                         let indices = shards.indices();
-                        let chunk_bytes_and_subsets = iter_concurrent_limit!(
-                            chunk_concurrent_limit,
-                            indices,
-                            map,
-                            retrieve_inner_chunk
-                        )
-                        .collect::<Result<Vec<_>, _>>()?;
+                        let chunk_bytes_and_subsets = options.install(|| {
+                            iter_concurrent_limit!(
+                                chunk_concurrent_limit,
+                                indices,
+                                map,
+                                retrieve_inner_chunk
+                            )
+                            .collect::<Result<Vec<_>, _>>()
+                        })?;
 
                         Ok(merge_chunks_vlen(
                             chunk_bytes_and_subsets,
@@ -622,12 +615,14 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayShardedReadableExt
                                 Ok::<_, ArrayError>(())
                             };
                             let indices = shards.indices();
-                            iter_concurrent_limit!(
-                                chunk_concurrent_limit,
-                                indices,
-                                try_for_each,
-                                retrieve_shard_into_slice
-                            )?;
+                            options.install(|| {
+                                iter_concurrent_limit!(
+                                    chunk_concurrent_limit,
+                                    indices,
+                                    try_for_each,
+                                    retrieve_shard_into_slice
+                                )
+                            })?;
                         }
                         unsafe { output.set_len(size_output) };
                         Ok(ArrayBytes::from(output))
@@ -647,6 +642,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayShardedReadableExt
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_array_subset_sharded_opt(cache, array_subset, options)?,
         )
     }
@@ -816,7 +812,7 @@ mod tests {
                 encoded_inner_chunk.len() as u64
             );
             // assert_eq!(
-            //     u16::from_array_bytes(array.data_type(), encoded_inner_chunk.into())?,
+            //     u16::from_array_bytes(array.data_type(), array.fill_value(), encoded_inner_chunk.into())?,
             //     array.retrieve_chunk_elements::<u16>(&[0, 0])?
             // );
         } else {
@@ -947,7 +943,7 @@ mod tests {
             array_sharded_ext_impl_transpose(false)
                 .unwrap_err()
                 .to_string(),
-            "invalid inner chunk shape [1, 3, 3], it must evenly divide [4, 8, 3]"
+            "chunk shape [8, 4, 3] is incompatible with the codecs: invalid inner chunk shape [1, 3, 3], it must evenly divide [4, 8, 3]"
         )
     }
 }
@@ -0,0 +1,376 @@
+use std::collections::BTreeMap;
+
+use unsafe_cell_slice::UnsafeCellSlice;
+
+use super::array_bytes::{merge_chunks_vlen, update_bytes_flen};
+use super::codec::CodecOptions;
+use super::element::Element;
+use super::{Array, ArrayBytes, ArrayError, ArrayShardedExt, ArraySize, ChunkGrid, DataTypeSize};
+use crate::{array_subset::ArraySubset, storage::WritableStorageTraits};
+
+/// A writer that stages the inner chunks of a shard in memory and stores the whole shard at once.
+///
+/// Storing the inner chunks of a sharded array one at a time with [`Array::store_array_subset`] or [`Array::store_chunk_subset`] repeatedly reads, decodes, encodes, and writes the shard.
+/// A [`ShardedArrayWriter`] instead accumulates decoded inner chunks, and [`finalize`](ShardedArrayWriter::finalize) encodes the shard (inner chunks, shard index, and index codecs) and writes it to the store with a single request.
+///
+/// Inner chunks that are not staged, or that are entirely the fill value, are absent from the shard index.
+/// The shard index location (start or end) is determined by the `sharding_indexed` codec configuration of the array.
+///
+/// Inner chunk indices refer to the inner chunk grid of the array (see [`ArrayShardedExt::inner_chunk_grid`]).
+/// For an unsharded array, the inner chunk grid is the chunk grid and the only inner chunk of a "shard" is the chunk itself.
+///
+/// ### Example
+/// ```rust
+/// # use std::sync::Arc;
+/// # use zarrs::array::{ArrayBuilder, DataType, FillValue, ShardedArrayWriter};
+/// # use zarrs::array::codec::array_to_bytes::sharding::ShardingCodecBuilder;
+/// # let store = Arc::new(zarrs_storage::store::MemoryStore::new());
+/// let array = ArrayBuilder::new(
+///     vec![4, 4], // array shape
+///     DataType::UInt8,
+///     vec![4, 4].try_into()?, // shard shape
+///     FillValue::from(0u8),
+/// )
+/// .array_to_bytes_codec(Arc::new(
+///     ShardingCodecBuilder::new(vec![2, 2].try_into()?).build(),
+/// ))
+/// .build(store, "/array")?;
+///
+/// let mut writer = ShardedArrayWriter::new(&array, &[0, 0])?;
+/// writer.store_inner_chunk_elements::<u8>(&[0, 0], &[1, 2, 3, 4])?;
+/// writer.store_inner_chunk_elements::<u8>(&[1, 1], &[5, 6, 7, 8])?;
+/// writer.finalize()?; // a single write to the store
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct ShardedArrayWriter<'a, TStorage: ?Sized> {
+    array: &'a Array<TStorage>,
+    shard_indices: Vec<u64>,
+    shard_subset: ArraySubset,
+    inner_chunk_grid: ChunkGrid,
+    inner_chunks: BTreeMap<Vec<u64>, ArrayBytes<'static>>,
+}
+
+impl<'a, TStorage: ?Sized + WritableStorageTraits + 'static> ShardedArrayWriter<'a, TStorage> {
+    /// Create a new writer for the shard of `array` at `shard_indices`.
+    ///
+    /// # Errors
+    /// Returns [`ArrayError::InvalidChunkGridIndicesError`] if `shard_indices` are invalid or out of bounds.
+    pub fn new(array: &'a Array<TStorage>, shard_indices: &[u64]) -> Result<Self, ArrayError> {
+        let shard_subset = array.chunk_subset(shard_indices)?;
+        let inbounds = array.chunk_grid_shape().is_some_and(|chunk_grid_shape| {
+            std::iter::zip(shard_indices, &chunk_grid_shape).all(|(index, shape)| index < shape)
+        });
+        if !inbounds {
+            return Err(ArrayError::InvalidChunkGridIndicesError(
+                shard_indices.to_vec(),
+            ));
+        }
+        Ok(Self {
+            array,
+            shard_indices: shard_indices.to_vec(),
+            shard_subset,
+            inner_chunk_grid: array.inner_chunk_grid(),
+            inner_chunks: BTreeMap::new(),
+        })
+    }
+
+    /// Return the indices of the shard.
+    #[must_use]
+    pub fn shard_indices(&self) -> &[u64] {
+        &self.shard_indices
+    }
+
+    /// Return the number of staged inner chunks.
+    #[must_use]
+    pub fn num_inner_chunks(&self) -> usize {
+        self.inner_chunks.len()
+    }
+
+    /// Return the subset of the inner chunk at `inner_chunk_indices` in the array.
+    fn inner_chunk_subset(&self, inner_chunk_indices: &[u64]) -> Result<ArraySubset, ArrayError> {
+        let inner_chunk_subset = self
+            .inner_chunk_grid
+            .subset(inner_chunk_indices, self.array.shape())?
+            .ok_or_else(|| {
+                ArrayError::InvalidChunkGridIndicesError(inner_chunk_indices.to_vec())
+            })?;
+        let in_shard = std::iter::zip(inner_chunk_subset.start(), self.shard_subset.start())
+            .all(|(inner, shard)| inner >= shard)
+            && std::iter::zip(inner_chunk_subset.end_exc(), self.shard_subset.end_exc())
+                .all(|(inner, shard)| inner <= shard);
+        if in_shard {
+            Ok(inner_chunk_subset)
+        } else {
+            Err(ArrayError::InvalidChunkGridIndicesError(
+                inner_chunk_indices.to_vec(),
+            ))
+        }
+    }
+
+    /// Stage `inner_chunk_bytes` as the inner chunk at `inner_chunk_indices`.
+    ///
+    /// A previously staged inner chunk at `inner_chunk_indices` is replaced.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - `inner_chunk_indices` are invalid or the inner chunk is not in the shard, or
+    ///  - the length of `inner_chunk_bytes` does not match the expected length of the inner chunk.
+    pub fn store_inner_chunk<'b>(
+        &mut self,
+        inner_chunk_indices: &[u64],
+        inner_chunk_bytes: impl Into<ArrayBytes<'b>>,
+    ) -> Result<(), ArrayError> {
+        let inner_chunk_bytes = inner_chunk_bytes.into();
+        let inner_chunk_subset = self.inner_chunk_subset(inner_chunk_indices)?;
+        inner_chunk_bytes.validate(
+            inner_chunk_subset.num_elements(),
+            self.array.data_type().size(),
+        )?;
+        self.inner_chunks
+            .insert(inner_chunk_indices.to_vec(), inner_chunk_bytes.into_owned());
+        Ok(())
+    }
+
+    /// Stage `inner_chunk_elements` as the inner chunk at `inner_chunk_indices`.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the size of `T` does not match the data type size, or
+    ///  - a [`store_inner_chunk`](ShardedArrayWriter::store_inner_chunk) error condition is met.
+    pub fn store_inner_chunk_elements<T: Element>(
+        &mut self,
+        inner_chunk_indices: &[u64],
+        inner_chunk_elements: &[T],
+    ) -> Result<(), ArrayError> {
+        let inner_chunk_bytes = T::into_array_bytes(
+            self.array.data_type(),
+            self.array.fill_value(),
+            inner_chunk_elements,
+        )?;
+        self.store_inner_chunk(inner_chunk_indices, inner_chunk_bytes)
+    }
+
+    /// Encode the shard and store it.
+    ///
+    /// Use [`finalize_opt`](ShardedArrayWriter::finalize_opt) to control codec options.
+    /// If no inner chunks are staged (or they are all the fill value), the shard is erased.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if there is a codec encoding error or an underlying store error.
+    pub fn finalize(self) -> Result<(), ArrayError> {
+        let options = self.array.codec_options();
+        self.finalize_opt(&options)
+    }
+
+    /// Explicit options version of [`finalize`](ShardedArrayWriter::finalize).
+    #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+    pub fn finalize_opt(mut self, options: &CodecOptions) -> Result<(), ArrayError> {
+        let shard_shape = self.shard_subset.shape().to_vec();
+        let data_type_size = self.array.data_type().size();
+        let inner_chunks = self
+            .inner_chunk_grid
+            .chunks_in_array_subset(&self.shard_subset, self.array.shape())?
+            .ok_or_else(|| ArrayError::InvalidChunkGridIndicesError(self.shard_indices.clone()))?;
+
+        let shard_bytes = match data_type_size {
+            DataTypeSize::Variable => {
+                let mut inner_chunk_bytes_and_subsets = Vec::new();
+                for inner_chunk_indices in &inner_chunks.indices() {
+                    let inner_chunk_subset = self.inner_chunk_subset(&inner_chunk_indices)?;
+                    let inner_chunk_bytes = self
+                        .inner_chunks
+                        .remove(&inner_chunk_indices)
+                        .unwrap_or_else(|| {
+                            ArrayBytes::new_fill_value(
+                                ArraySize::new(data_type_size, inner_chunk_subset.num_elements()),
+                                self.array.fill_value(),
+                            )
+                        });
+                    inner_chunk_bytes_and_subsets.push((
+                        inner_chunk_bytes,
+                        inner_chunk_subset.relative_to(self.shard_subset.start())?,
+                    ));
+                }
+                merge_chunks_vlen(inner_chunk_bytes_and_subsets, &shard_shape)?
+            }
+            DataTypeSize::Fixed(data_type_size) => {
+                let mut shard_bytes = ArrayBytes::new_fill_value(
+                    ArraySize::new(
+                        DataTypeSize::Fixed(data_type_size),
+                        self.shard_subset.num_elements(),
+                    ),
+                    self.array.fill_value(),
+                )
+                .into_fixed()?
+                .into_owned();
+                {
+                    let shard_bytes = UnsafeCellSlice::new(&mut shard_bytes);
+                    for (inner_chunk_indices, inner_chunk_bytes) in
+                        std::mem::take(&mut self.inner_chunks)
+                    {
+                        let inner_chunk_subset = self.inner_chunk_subset(&inner_chunk_indices)?;
+                        update_bytes_flen(
+                            &shard_bytes,
+                            &shard_shape,
+                            &inner_chunk_bytes.into_fixed()?,
+                            &inner_chunk_subset.relative_to(self.shard_subset.start())?,
+                            data_type_size,
+                        );
+                    }
+                }
+                ArrayBytes::from(shard_bytes)
+            }
+        };
+
+        self.array
+            .store_chunk_opt(&self.shard_indices, shard_bytes, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        array::{
+            codec::array_to_bytes::sharding::{ShardingCodecBuilder, ShardingIndexLocation},
+            ArrayBuilder, ArrayShardedReadableExt, ArrayShardedReadableExtCache, DataType,
+            FillValue,
+        },
+        array_subset::ArraySubset,
+        storage::{
+            storage_adapter::performance_metrics::PerformanceMetricsStorageAdapter,
+            store::MemoryStore, ReadableStorageTraits,
+        },
+    };
+
+    use super::*;
+
+    fn sharded_array_writer_impl(
+        index_location: ShardingIndexLocation,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let store = Arc::new(MemoryStore::default());
+        let store_perf = Arc::new(PerformanceMetricsStorageAdapter::new(store.clone()));
+        let array = ArrayBuilder::new(
+            vec![4, 8], // array shape
+            DataType::UInt16,
+            vec![4, 4].try_into()?, // shard shape
+            FillValue::from(0u16),
+        )
+        .array_to_bytes_codec(Arc::new(
+            ShardingCodecBuilder::new(vec![2, 2].try_into()?)
+                .index_location(index_location)
+                .build(),
+        ))
+        .build(store_perf.clone(), "/array")?;
+
+        // A complete shard
+        let mut writer = ShardedArrayWriter::new(&array, &[0, 1])?;
+        for (i, inner_chunk_indices) in [[0, 2], [0, 3], [1, 2], [1, 3]].iter().enumerate() {
+            let i = u16::try_from(i)?;
+            writer.store_inner_chunk_elements::<u16>(
+                inner_chunk_indices,
+                &[i * 4 + 1, i * 4 + 2, i * 4 + 3, i * 4 + 4],
+            )?;
+        }
+        assert_eq!(writer.num_inner_chunks(), 4);
+        writer.finalize()?;
+        assert_eq!(store_perf.writes(), 1);
+        assert_eq!(store_perf.reads(), 0);
+
+        let cache = ArrayShardedReadableExtCache::new(&array);
+        assert_eq!(
+            array.retrieve_inner_chunk_elements_opt::<u16>(
+                &cache,
+                &[1, 2],
+                &CodecOptions::default()
+            )?,
+            vec![9, 10, 11, 12]
+        );
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[
+                1..3,
+                4..8
+            ]))?,
+            vec![3, 4, 7, 8, 9, 10, 13, 14]
+        );
+
+        // A shard with missing inner chunks
+        store_perf.reset();
+        let mut writer = ShardedArrayWriter::new(&array, &[0, 0])?;
+        writer.store_inner_chunk_elements::<u16>(&[1, 0], &[1, 2, 3, 4])?;
+        writer.store_inner_chunk_elements::<u16>(&[0, 1], &[0, 0, 0, 0])?;
+        writer.finalize()?;
+        assert_eq!(store_perf.writes(), 1);
+        assert_eq!(
+            array.retrieve_chunk_elements::<u16>(&[0, 0])?,
+            vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4, 0, 0]
+        );
+
+        // Only the staged non-fill value inner chunk is in the shard index
+        let shard = store.get(&array.chunk_key(&[0, 0]))?.unwrap();
+        let index = match index_location {
+            ShardingIndexLocation::Start => &shard[..64],
+            ShardingIndexLocation::End => &shard[shard.len() - 64 - 4..shard.len() - 4],
+        };
+        let index: Vec<u64> = index
+            .chunks_exact(8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+            .collect();
+        let present: Vec<bool> = index
+            .chunks_exact(2)
+            .map(|entry| entry != [u64::MAX, u64::MAX])
+            .collect();
+        assert_eq!(present, [false, false, true, false]);
+
+        // No inner chunks erases the shard
+        ShardedArrayWriter::new(&array, &[0, 0])?.finalize()?;
+        assert!(store.get(&array.chunk_key(&[0, 0]))?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "crc32c")]
+    fn sharded_array_writer_index_start() -> Result<(), Box<dyn std::error::Error>> {
+        sharded_array_writer_impl(ShardingIndexLocation::Start)
+    }
+
+    #[test]
+    #[cfg(feature = "crc32c")]
+    fn sharded_array_writer_index_end() -> Result<(), Box<dyn std::error::Error>> {
+        sharded_array_writer_impl(ShardingIndexLocation::End)
+    }
+
+    #[test]
+    fn sharded_array_writer_invalid() -> Result<(), Box<dyn std::error::Error>> {
+        let store = Arc::new(MemoryStore::default());
+        let array = ArrayBuilder::new(
+            vec![4, 8], // array shape
+            DataType::UInt8,
+            vec![4, 4].try_into()?, // shard shape
+            FillValue::from(0u8),
+        )
+        .array_to_bytes_codec(Arc::new(
+            ShardingCodecBuilder::new(vec![2, 2].try_into()?).build(),
+        ))
+        .build(store, "/array")?;
+
+        assert!(ShardedArrayWriter::new(&array, &[0, 2]).is_err());
+        let mut writer = ShardedArrayWriter::new(&array, &[0, 0])?;
+        // Inner chunk in another shard
+        assert!(writer
+            .store_inner_chunk_elements::<u8>(&[0, 2], &[1, 2, 3, 4])
+            .is_err());
+        // Incorrect number of elements
+        assert!(writer
+            .store_inner_chunk_elements::<u8>(&[0, 0], &[1, 2, 3])
+            .is_err());
+        // Incorrect element type
+        assert!(writer
+            .store_inner_chunk_elements::<u16>(&[0, 0], &[1, 2, 3, 4])
+            .is_err());
+        assert_eq!(writer.num_inner_chunks(), 0);
+        Ok(())
+    }
+}
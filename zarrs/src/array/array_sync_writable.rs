@@ -7,14 +7,17 @@ use crate::{
     array::ArrayBytes,
     array_subset::ArraySubset,
     config::{global_config, MetadataEraseVersion},
-    node::{meta_key_v2_array, meta_key_v2_attributes, meta_key_v3},
+    node::{
+        check_additional_fields_must_understand, meta_key_v2_array, meta_key_v2_attributes,
+        meta_key_v3,
+    },
     storage::{Bytes, StorageError, StorageHandle, WritableStorageTraits},
 };
 
 use super::{
     codec::{options::CodecOptions, ArrayToBytesCodecTraits},
     concurrency::concurrency_chunks_and_codec,
-    Array, ArrayError, ArrayMetadata, ArrayMetadataOptions, Element,
+    Array, ArrayError, ArrayIndices, ArrayMetadata, ArrayMetadataOptions, Element, FillValue,
 };
 
 impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
@@ -37,7 +40,7 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
     pub fn store_metadata_opt(&self, options: &ArrayMetadataOptions) -> Result<(), StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_writable_transformer(storage_handle)?;
 
         // Get the metadata with options applied and store
@@ -48,11 +51,20 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         match metadata {
             ArrayMetadata::V3(metadata) => {
                 let key = meta_key_v3(path);
+                if options.strict_additional_fields() {
+                    check_additional_fields_must_understand(&key, &metadata.additional_fields)?;
+                }
                 let json = serde_json::to_vec_pretty(&metadata)
                     .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
                 storage_transformer.set(&key, json.into())
             }
             ArrayMetadata::V2(metadata) => {
+                if options.strict_additional_fields() {
+                    check_additional_fields_must_understand(
+                        &meta_key_v2_array(path),
+                        &metadata.additional_fields,
+                    )?;
+                }
                 let mut metadata = metadata.clone();
 
                 if !metadata.attributes.is_empty() {
@@ -91,7 +103,7 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_bytes: impl Into<ArrayBytes<'a>>,
     ) -> Result<(), ArrayError> {
-        self.store_chunk_opt(chunk_indices, chunk_bytes, &CodecOptions::default())
+        self.store_chunk_opt(chunk_indices, chunk_bytes, &self.codec_options())
     }
 
     /// Encode `chunk_elements` and store at `chunk_indices`.
@@ -108,7 +120,24 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_elements: &[T],
     ) -> Result<(), ArrayError> {
-        self.store_chunk_elements_opt(chunk_indices, chunk_elements, &CodecOptions::default())
+        self.store_chunk_elements_opt(chunk_indices, chunk_elements, &self.codec_options())
+    }
+
+    /// Encode `chunk_elements` strings and store at `chunk_indices`.
+    ///
+    /// The elements are packed into the variable-length bytes and offsets of the `string` data type.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is not a string data type, or
+    ///  - a [`store_chunk_elements`](Array::store_chunk_elements) error condition is met.
+    pub fn store_chunk_elements_string(
+        &self,
+        chunk_indices: &[u64],
+        chunk_elements: &[impl AsRef<str>],
+    ) -> Result<(), ArrayError> {
+        let chunk_elements: Vec<&str> = chunk_elements.iter().map(AsRef::as_ref).collect();
+        self.store_chunk_elements(chunk_indices, &chunk_elements)
     }
 
     #[cfg(feature = "ndarray")]
@@ -126,7 +155,7 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         chunk_indices: &[u64],
         chunk_array: impl Into<ndarray::Array<T, D>>,
     ) -> Result<(), ArrayError> {
-        self.store_chunk_ndarray_opt(chunk_indices, chunk_array, &CodecOptions::default())
+        self.store_chunk_ndarray_opt(chunk_indices, chunk_array, &self.codec_options())
     }
 
     /// Encode `chunks_bytes` and store at the chunks with indices represented by the `chunks` array subset.
@@ -147,7 +176,7 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         chunks: &ArraySubset,
         chunks_bytes: impl Into<ArrayBytes<'a>>,
     ) -> Result<(), ArrayError> {
-        self.store_chunks_opt(chunks, chunks_bytes, &CodecOptions::default())
+        self.store_chunks_opt(chunks, chunks_bytes, &self.codec_options())
     }
 
     /// Encode `chunks_elements` and store at the chunks with indices represented by the `chunks` array subset.
@@ -162,7 +191,7 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         chunks: &ArraySubset,
         chunks_elements: &[T],
     ) -> Result<(), ArrayError> {
-        self.store_chunks_elements_opt(chunks, chunks_elements, &CodecOptions::default())
+        self.store_chunks_elements_opt(chunks, chunks_elements, &self.codec_options())
     }
 
     #[cfg(feature = "ndarray")]
@@ -178,7 +207,24 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         chunks: &ArraySubset,
         chunks_array: impl Into<ndarray::Array<T, D>>,
     ) -> Result<(), ArrayError> {
-        self.store_chunks_ndarray_opt(chunks, chunks_array, &CodecOptions::default())
+        self.store_chunks_ndarray_opt(chunks, chunks_array, &self.codec_options())
+    }
+
+    /// Encode `chunks_bytes` and store at the chunks with indices represented by the `chunks` array subset in a single batch.
+    ///
+    /// Use [`store_chunks_batched_opt`](Array::store_chunks_batched_opt) to control codec options.
+    /// Unlike [`store_chunks`](Array::store_chunks), which stores each chunk as soon as it is encoded, all chunks are encoded concurrently and then written with a single [`WritableStorageTraits::set_many`] call.
+    /// This reduces the number of store round trips if the store implements [`set_many`](WritableStorageTraits::set_many) efficiently (e.g. with concurrent uploads), at the cost of holding all encoded chunks in memory.
+    /// Chunks composed entirely of the fill value are erased with a single [`WritableStorageTraits::erase_values`] call.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if a [`store_chunks`](Array::store_chunks) error condition is met.
+    pub fn store_chunks_batched<'a>(
+        &self,
+        chunks: &ArraySubset,
+        chunks_bytes: impl Into<ArrayBytes<'a>>,
+    ) -> Result<(), ArrayError> {
+        self.store_chunks_batched_opt(chunks, chunks_bytes, &self.codec_options())
     }
 
     /// Erase the metadata with default [`MetadataEraseVersion`] options.
@@ -230,9 +276,11 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
     pub fn erase_chunk(&self, chunk_indices: &[u64]) -> Result<(), StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_writable_transformer(storage_handle)?;
-        storage_transformer.erase(&self.chunk_key(chunk_indices))
+        storage_transformer.erase(&self.chunk_key(chunk_indices))?;
+        self.invalidate_cached_chunk(chunk_indices);
+        Ok(())
     }
 
     /// Erase the chunks in `chunks`.
@@ -242,14 +290,34 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
     pub fn erase_chunks(&self, chunks: &ArraySubset) -> Result<(), StorageError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_writable_transformer(storage_handle)?;
-        let erase_chunk =
-            |chunk_indices: Vec<u64>| storage_transformer.erase(&self.chunk_key(&chunk_indices));
+        let erase_chunk = |chunk_indices: Vec<u64>| {
+            storage_transformer.erase(&self.chunk_key(&chunk_indices))?;
+            self.invalidate_cached_chunk(&chunk_indices);
+            Ok(())
+        };
 
         chunks.indices().into_par_iter().try_for_each(erase_chunk)
     }
 
+    /// Fill the chunks in `chunks` with `value`.
+    ///
+    /// Use [`fill_chunks_opt`](Array::fill_chunks_opt) to control codec options.
+    /// Chunks are erased if `value` is the fill value of the array.
+    /// Otherwise, the bytes of each chunk are created from `value` and stored, so at most one chunk is held in memory per concurrently filled chunk.
+    ///
+    /// # Errors
+    /// Returns an [`ArrayError`] if
+    ///  - the data type is variable sized,
+    ///  - the size of `value` does not match the data type size,
+    ///  - a chunk in `chunks` is incompatible with the chunk grid,
+    ///  - there is a codec encoding error, or
+    ///  - an underlying store error.
+    pub fn fill_chunks(&self, chunks: &ArraySubset, value: &FillValue) -> Result<(), ArrayError> {
+        self.fill_chunks_opt(chunks, value, &self.codec_options())
+    }
+
     /////////////////////////////////////////////////////////////////////////////
     // Advanced methods
     /////////////////////////////////////////////////////////////////////////////
@@ -300,9 +368,10 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
     ) -> Result<(), ArrayError> {
         let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
         let storage_transformer = self
-            .storage_transformers()
+            .applied_storage_transformers()
             .create_writable_transformer(storage_handle)?;
         storage_transformer.set(&self.chunk_key(chunk_indices), encoded_chunk_bytes)?;
+        self.invalidate_cached_chunk(chunk_indices);
 
         Ok(())
     }
@@ -315,7 +384,7 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         chunk_elements: &[T],
         options: &CodecOptions,
     ) -> Result<(), ArrayError> {
-        let chunk_bytes = T::into_array_bytes(self.data_type(), chunk_elements)?;
+        let chunk_bytes = T::into_array_bytes(self.data_type(), self.fill_value(), chunk_elements)?;
         self.store_chunk_opt(chunk_indices, chunk_bytes, options)
     }
 
@@ -388,13 +457,137 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
                 };
 
                 let indices = chunks.indices();
-                iter_concurrent_limit!(chunk_concurrent_limit, indices, try_for_each, store_chunk)?;
+                options.install(|| {
+                    iter_concurrent_limit!(
+                        chunk_concurrent_limit,
+                        indices,
+                        try_for_each,
+                        store_chunk
+                    )
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Explicit options version of [`store_chunks_batched`](Array::store_chunks_batched).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn store_chunks_batched_opt<'a>(
+        &self,
+        chunks: &ArraySubset,
+        chunks_bytes: impl Into<ArrayBytes<'a>>,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let chunks_bytes = chunks_bytes.into();
+        let (encoded_chunks, erased_chunks): (Vec<_>, Vec<_>) = self
+            .encode_chunks_batched(chunks, &chunks_bytes, options)?
+            .into_iter()
+            .partition(|(_, chunk_encoded)| chunk_encoded.is_some());
+        if !erased_chunks.is_empty() {
+            let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+            let storage_transformer = self
+                .applied_storage_transformers()
+                .create_writable_transformer(storage_handle)?;
+            let keys: Vec<_> = erased_chunks
+                .iter()
+                .map(|(chunk_indices, _)| self.chunk_key(chunk_indices))
+                .collect();
+            storage_transformer.erase_values(&keys)?;
+            for (chunk_indices, _) in &erased_chunks {
+                self.invalidate_cached_chunk(chunk_indices);
             }
         }
+        let encoded_chunks: Vec<_> = encoded_chunks
+            .into_iter()
+            .filter_map(|(chunk_indices, chunk_encoded)| Some((chunk_indices, chunk_encoded?)))
+            .collect();
+        unsafe { self.store_encoded_chunks_batched(&encoded_chunks) }
+    }
 
+    /// Store a batch of encoded chunks with a single [`WritableStorageTraits::set_many`] call.
+    ///
+    /// Each element of `encoded_chunks` is a pair of chunk indices and encoded chunk bytes.
+    ///
+    /// # Safety
+    /// The responsibility is on the caller to ensure the chunks are encoded correctly
+    ///
+    /// # Errors
+    /// Returns [`StorageError`] if there is an underlying store error.
+    pub unsafe fn store_encoded_chunks_batched(
+        &self,
+        encoded_chunks: &[(ArrayIndices, bytes::Bytes)],
+    ) -> Result<(), ArrayError> {
+        if encoded_chunks.is_empty() {
+            return Ok(());
+        }
+        let storage_handle = Arc::new(StorageHandle::new(self.storage.clone()));
+        let storage_transformer = self
+            .applied_storage_transformers()
+            .create_writable_transformer(storage_handle)?;
+        let key_values: Vec<_> = encoded_chunks
+            .iter()
+            .map(|(chunk_indices, encoded_chunk_bytes)| {
+                (self.chunk_key(chunk_indices), encoded_chunk_bytes.clone())
+            })
+            .collect();
+        storage_transformer.set_many(&key_values)?;
+        for (chunk_indices, _) in encoded_chunks {
+            self.invalidate_cached_chunk(chunk_indices);
+        }
         Ok(())
     }
 
+    /// Explicit options version of [`fill_chunks`](Array::fill_chunks).
+    #[allow(clippy::missing_errors_doc)]
+    pub fn fill_chunks_opt(
+        &self,
+        chunks: &ArraySubset,
+        value: &FillValue,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        self.validate_fill(value)?;
+
+        // Calculate chunk/codec concurrency
+        let num_chunks = chunks.num_elements_usize();
+        let chunk_representation =
+            self.chunk_array_representation(&vec![0; self.dimensionality()])?;
+        let codec_concurrency = self.recommended_codec_concurrency(&chunk_representation)?;
+        let (chunk_concurrent_limit, options) = concurrency_chunks_and_codec(
+            options.concurrent_target(),
+            num_chunks,
+            options,
+            &codec_concurrency,
+        );
+
+        let fill_chunk = |chunk_indices: Vec<u64>| -> Result<(), ArrayError> {
+            self.fill_chunk_opt(&chunk_indices, value, &options)
+        };
+        let indices = chunks.indices();
+        options.install(|| {
+            iter_concurrent_limit!(chunk_concurrent_limit, indices, try_for_each, fill_chunk)
+        })
+    }
+
+    /// Fill the chunk at `chunk_indices` with `value`, erasing it if `value` is the fill value.
+    ///
+    /// `value` must have been validated with [`validate_fill`](Array::validate_fill).
+    pub(super) fn fill_chunk_opt(
+        &self,
+        chunk_indices: &[u64],
+        value: &FillValue,
+        options: &CodecOptions,
+    ) -> Result<(), ArrayError> {
+        let chunk_representation = self.chunk_array_representation(chunk_indices)?;
+        if value == self.fill_value() {
+            self.erase_chunk(chunk_indices)?;
+            Ok(())
+        } else {
+            let chunk_bytes = self.fill_bytes(value, chunk_representation.num_elements());
+            self.store_chunk_opt(chunk_indices, chunk_bytes, options)
+        }
+    }
+
     /// Explicit options version of [`store_chunks_elements`](Array::store_chunks_elements).
     #[allow(clippy::missing_errors_doc)]
     pub fn store_chunks_elements_opt<T: Element>(
@@ -403,7 +596,8 @@ impl<TStorage: ?Sized + WritableStorageTraits + 'static> Array<TStorage> {
         chunks_elements: &[T],
         options: &CodecOptions,
     ) -> Result<(), ArrayError> {
-        let chunks_bytes = T::into_array_bytes(self.data_type(), chunks_elements)?;
+        let chunks_bytes =
+            T::into_array_bytes(self.data_type(), self.fill_value(), chunks_elements)?;
         self.store_chunks_opt(chunks, chunks_bytes, options)
     }
 
@@ -30,12 +30,18 @@ impl ChunkCacheType for ChunkCacheTypeEncoded {
 }
 
 impl ChunkCacheType for ChunkCacheTypeDecoded {
+    /// The size of the chunk in bytes, including the element offsets of a variable sized chunk.
     fn size(&self) -> usize {
         ArrayBytes::size(self)
+            + self
+                .offsets()
+                .map_or(0, |offsets| offsets.len() * size_of::<usize>())
     }
 }
 
 /// Traits for a chunk cache.
+///
+/// A decoded chunk cache can be attached to an [`Array`] with [`Array::with_chunk_cache`].
 pub trait ChunkCache<CT: ChunkCacheType>: Send + Sync {
     /// Retrieve and decode a chunk.
     ///
@@ -46,7 +52,9 @@ pub trait ChunkCache<CT: ChunkCacheType>: Send + Sync {
         array: &Array<TStorage>,
         chunk_indices: &[u64],
         options: &CodecOptions,
-    ) -> Result<Arc<ArrayBytes<'static>>, ArrayError>;
+    ) -> Result<Arc<ArrayBytes<'static>>, ArrayError>
+    where
+        Self: Sized;
 
     /// Retrieve a chunk from the cache. Returns [`None`] if the chunk is not present.
     ///
@@ -56,6 +64,11 @@ pub trait ChunkCache<CT: ChunkCacheType>: Send + Sync {
     /// Insert a chunk into the cache.
     fn insert(&self, chunk_indices: Vec<u64>, chunk: Arc<CT>);
 
+    /// Remove a chunk from the cache.
+    ///
+    /// For a thread-local cache, the chunk is removed from the cache of every thread.
+    fn remove(&self, chunk_indices: &[u64]);
+
     /// Get or insert a chunk in the cache.
     ///
     /// Override the default implementation if a chunk offers a more performant implementation.
@@ -68,6 +81,7 @@ pub trait ChunkCache<CT: ChunkCacheType>: Send + Sync {
         f: F,
     ) -> Result<Arc<CT>, Arc<ArrayError>>
     where
+        Self: Sized,
         F: FnOnce() -> Result<Arc<CT>, ArrayError>,
     {
         let chunk_indices = chunk_indices.clone();
@@ -91,4 +105,53 @@ pub trait ChunkCache<CT: ChunkCacheType>: Send + Sync {
     }
 }
 
+/// The decoded chunk cache of an [`Array`] set with [`Array::with_chunk_cache`].
+#[derive(Clone)]
+pub(crate) struct ArrayChunkCache(pub(crate) Arc<dyn ChunkCache<ChunkCacheTypeDecoded>>);
+
+impl core::fmt::Debug for ArrayChunkCache {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ArrayChunkCache")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+impl ChunkCache<ChunkCacheTypeDecoded> for ArrayChunkCache {
+    fn retrieve_chunk<TStorage: ?Sized + ReadableStorageTraits + 'static>(
+        &self,
+        array: &Array<TStorage>,
+        chunk_indices: &[u64],
+        options: &CodecOptions,
+    ) -> Result<Arc<ArrayBytes<'static>>, ArrayError> {
+        if let Some(chunk) = self.0.get(chunk_indices) {
+            Ok(chunk)
+        } else {
+            let chunk = Arc::new(
+                array
+                    .retrieve_chunk_opt_uncached(chunk_indices, options)?
+                    .into_owned(),
+            );
+            self.0.insert(chunk_indices.to_vec(), chunk.clone());
+            Ok(chunk)
+        }
+    }
+
+    fn get(&self, chunk_indices: &[u64]) -> Option<Arc<ChunkCacheTypeDecoded>> {
+        self.0.get(chunk_indices)
+    }
+
+    fn insert(&self, chunk_indices: Vec<u64>, chunk: Arc<ChunkCacheTypeDecoded>) {
+        self.0.insert(chunk_indices, chunk);
+    }
+
+    fn remove(&self, chunk_indices: &[u64]) {
+        self.0.remove(chunk_indices);
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
 // TODO: AsyncChunkCache
@@ -159,6 +159,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayChunkCacheExt<TSto
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             Arc::unwrap_or_clone(self.retrieve_chunk_opt_cached(cache, chunk_indices, options)?),
         )
     }
@@ -205,6 +206,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayChunkCacheExt<TSto
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_chunks_opt_cached(cache, chunks, options)?,
         )
     }
@@ -264,6 +266,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayChunkCacheExt<TSto
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_chunk_subset_opt_cached(cache, chunk_indices, chunk_subset, options)?,
         )
     }
@@ -353,13 +356,14 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayChunkCacheExt<TSto
 
                 // Retrieve chunks
                 let indices = chunks.indices();
-                let chunk_bytes_and_subsets =
+                let chunk_bytes_and_subsets = options.install(|| {
                     iter_concurrent_limit!(chunk_concurrent_limit, indices, map, |chunk_indices| {
                         let chunk_subset = self.chunk_subset(&chunk_indices)?;
                         self.retrieve_chunk_opt_cached(cache, &chunk_indices, &options)
                             .map(|bytes| (bytes, chunk_subset))
                     })
-                    .collect::<Result<Vec<_>, ArrayError>>()?;
+                    .collect::<Result<Vec<_>, ArrayError>>()
+                })?;
 
                 // Merge
                 match self.data_type().size() {
@@ -417,12 +421,14 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayChunkCacheExt<TSto
                                 );
                                 Ok::<_, ArrayError>(())
                             };
-                            iter_concurrent_limit!(
-                                chunk_concurrent_limit,
-                                chunk_bytes_and_subsets,
-                                try_for_each,
-                                update_output
-                            )?;
+                            options.install(|| {
+                                iter_concurrent_limit!(
+                                    chunk_concurrent_limit,
+                                    chunk_bytes_and_subsets,
+                                    try_for_each,
+                                    update_output
+                                )
+                            })?;
                         }
                         unsafe { output.set_len(size_output) };
                         Ok(ArrayBytes::from(output))
@@ -440,6 +446,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits + 'static> ArrayChunkCacheExt<TSto
     ) -> Result<Vec<T>, ArrayError> {
         T::from_array_bytes(
             self.data_type(),
+            self.fill_value(),
             self.retrieve_array_subset_opt_cached(cache, array_subset, options)?,
         )
     }
@@ -21,6 +21,9 @@ use std::borrow::Cow;
 
 type ChunkIndices = ArrayIndices;
 
+/// An LRU cache and the size of its chunks.
+type LruCacheWithSize<T> = (Mutex<LruCache<ChunkIndices, Arc<T>>>, AtomicUsize);
+
 /// A chunk cache with a fixed chunk capacity.
 pub struct ChunkCacheLruChunkLimit<T: ChunkCacheType> {
     cache: Cache<ChunkIndices, Arc<T>>,
@@ -59,9 +62,8 @@ pub type ChunkCacheDecodedLruChunkLimitThreadLocal =
 
 /// A thread local chunk cache with a fixed chunk capacity per thread.
 pub struct ChunkCacheLruSizeLimitThreadLocal<T: ChunkCacheType> {
-    cache: ThreadLocal<Mutex<LruCache<ChunkIndices, Arc<T>>>>,
+    cache: ThreadLocal<LruCacheWithSize<T>>,
     capacity: usize,
-    size: ThreadLocal<AtomicUsize>,
 }
 
 /// An LRU (least recently used) encoded chunk cache with a fixed chunk capacity.
@@ -121,12 +123,17 @@ impl<CT: ChunkCacheType> ChunkCacheLruSizeLimitThreadLocal<CT> {
         Self {
             cache,
             capacity: usize::try_from(capacity).unwrap_or(usize::MAX),
-            size: ThreadLocal::new(),
         }
     }
 
+    /// Returns the cache of the current thread and the size of its chunks.
+    fn cache_and_size(&self) -> &LruCacheWithSize<CT> {
+        self.cache
+            .get_or(|| (Mutex::new(LruCache::unbounded()), AtomicUsize::new(0)))
+    }
+
     fn cache(&self) -> &Mutex<LruCache<ChunkIndices, Arc<CT>>> {
-        self.cache.get_or(|| Mutex::new(LruCache::unbounded()))
+        &self.cache_and_size().0
     }
 }
 
@@ -140,6 +147,10 @@ macro_rules! impl_ChunkCacheLruCommon {
             self.cache.insert(chunk_indices, chunk);
         }
 
+        fn remove(&self, chunk_indices: &[u64]) {
+            self.cache.invalidate(&chunk_indices.to_vec());
+        }
+
         fn try_get_or_insert_with<F, E>(
             &self,
             chunk_indices: Vec<u64>,
@@ -190,9 +201,22 @@ macro_rules! impl_ChunkCacheLruEncoded {
             if let Some(chunk_encoded) = chunk_encoded.as_ref() {
                 let chunk_representation = array.chunk_array_representation(chunk_indices)?;
                 let bytes = array
-                    .codecs()
-                    .decode(Cow::Borrowed(chunk_encoded), &chunk_representation, options)
-                    .map_err(ArrayError::CodecError)?;
+                    .recover_corrupt_shard(
+                        chunk_indices,
+                        array.codecs().decode(
+                            Cow::Borrowed(chunk_encoded),
+                            &chunk_representation,
+                            options,
+                        ),
+                        options,
+                    )?
+                    .unwrap_or_else(|| {
+                        let array_size = ArraySize::new(
+                            array.data_type().size(),
+                            chunk_representation.num_elements(),
+                        );
+                        ArrayBytes::new_fill_value(array_size, array.fill_value())
+                    });
                 bytes.validate(
                     chunk_representation.num_elements(),
                     chunk_representation.data_type().size(),
@@ -222,7 +246,7 @@ macro_rules! impl_ChunkCacheLruDecoded {
             self.try_get_or_insert_with::<_, ArrayError>(chunk_indices.to_vec(), || {
                 Ok(Arc::new(
                     array
-                        .retrieve_chunk_opt(chunk_indices, options)?
+                        .retrieve_chunk_opt_uncached(chunk_indices, options)?
                         .into_owned(),
                 ))
             })
@@ -280,6 +304,13 @@ macro_rules! impl_ChunkCacheLruChunkLimitThreadLocal {
             self.cache().lock().unwrap().push(chunk_indices, chunk);
         }
 
+        fn remove(&self, chunk_indices: &[u64]) {
+            let chunk_indices = chunk_indices.to_vec();
+            for cache in &self.cache {
+                cache.lock().unwrap().pop(&chunk_indices);
+            }
+        }
+
         fn try_get_or_insert_with<F, E>(
             &self,
             chunk_indices: Vec<u64>,
@@ -313,7 +344,7 @@ macro_rules! impl_ChunkCacheLruSizeLimitThreadLocal {
         }
 
         fn insert(&self, chunk_indices: ChunkIndices, chunk: Arc<$ct>) {
-            let size = self.size.get_or_default();
+            let size = &self.cache_and_size().1;
             let size_old = size.fetch_add(chunk.size(), atomic::Ordering::SeqCst);
             if size_old + chunk.size() > self.capacity {
                 let old = self.cache().lock().unwrap().pop_lru();
@@ -328,6 +359,15 @@ macro_rules! impl_ChunkCacheLruSizeLimitThreadLocal {
             }
         }
 
+        fn remove(&self, chunk_indices: &[u64]) {
+            let chunk_indices = chunk_indices.to_vec();
+            for (cache, size) in &self.cache {
+                if let Some(old) = cache.lock().unwrap().pop(&chunk_indices) {
+                    size.fetch_sub(old.size(), atomic::Ordering::SeqCst);
+                }
+            }
+        }
+
         fn len(&self) -> usize {
             self.cache().lock().unwrap().len()
         }
@@ -560,4 +600,127 @@ mod tests {
         let cache = ChunkCacheDecodedLruSizeLimitThreadLocal::new(2 * chunk_size as u64);
         array_chunk_cache_impl(cache, true)
     }
+
+    fn array_with_chunk_cache_impl(cache: impl ChunkCache<ChunkCacheTypeDecoded> + 'static) {
+        let cache: Arc<dyn ChunkCache<ChunkCacheTypeDecoded>> = Arc::new(cache);
+        let store = Arc::new(MemoryStore::default());
+        let store = Arc::new(PerformanceMetricsStorageAdapter::new(store));
+        let array = ArrayBuilder::new(
+            vec![8, 8], // array shape
+            DataType::UInt8,
+            vec![4, 4].try_into().unwrap(), // regular chunk shape
+            FillValue::from(0u8),
+        )
+        .build(store.clone(), "/")
+        .unwrap()
+        .with_chunk_cache(cache.clone());
+
+        let data: Vec<u8> = (0..64).collect();
+        array
+            .store_array_subset_elements(&array.subset_all(), &data)
+            .unwrap();
+        assert_eq!(store.reads(), 0);
+        assert!(cache.is_empty());
+
+        // Retrieve a subset straddling all chunks
+        let subset = ArraySubset::new_with_ranges(&[3..5, 3..5]);
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&subset).unwrap(),
+            vec![27, 28, 35, 36]
+        );
+        assert_eq!(store.reads(), 4);
+        assert_eq!(cache.len(), 4);
+
+        // Retrieve from the cache
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&subset).unwrap(),
+            vec![27, 28, 35, 36]
+        );
+        let mut output = vec![0u8; 4];
+        array
+            .retrieve_array_subset_elements_into(&subset, &mut output)
+            .unwrap();
+        assert_eq!(output, vec![27, 28, 35, 36]);
+        assert_eq!(array.retrieve_chunk_elements::<u8>(&[1, 1]).unwrap()[0], 36);
+        assert_eq!(
+            array
+                .retrieve_chunk_subset_elements::<u8>(
+                    &[0, 0],
+                    &ArraySubset::new_with_ranges(&[3..4, 2..4])
+                )
+                .unwrap(),
+            vec![26, 27]
+        );
+        assert_eq!(
+            array
+                .partial_decoder(&[0, 1])
+                .unwrap()
+                .partial_decode(
+                    &[ArraySubset::new_with_ranges(&[0..1, 0..2])],
+                    &CodecOptions::default()
+                )
+                .unwrap(),
+            vec![ArrayBytes::from(vec![4u8, 5])]
+        );
+        assert_eq!(store.reads(), 4);
+
+        // Storing or erasing a chunk removes it from the cache
+        array.store_chunk_elements::<u8>(&[0, 0], &[1; 16]).unwrap();
+        array.erase_chunk(&[1, 1]).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&subset).unwrap(),
+            vec![1, 28, 35, 0]
+        );
+        assert_eq!(store.reads(), 6);
+        array
+            .store_array_subset_elements::<u8>(&ArraySubset::new_with_ranges(&[4..5, 4..5]), &[9])
+            .unwrap();
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&subset).unwrap(),
+            vec![1, 28, 35, 9]
+        );
+        assert_eq!(store.reads(), 8); // the partial encoder checks if the chunk exists
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn array_with_chunk_cache_chunks() {
+        array_with_chunk_cache_impl(ChunkCacheDecodedLruChunkLimit::new(4));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn array_with_chunk_cache_size() {
+        // Create a cache with a size limit equivalent to 4 chunks
+        let chunk_size = 4 * 4 * size_of::<u8>();
+        array_with_chunk_cache_impl(ChunkCacheDecodedLruSizeLimit::new(4 * chunk_size as u64));
+    }
+
+    #[test]
+    fn chunk_cache_decoded_size_variable() {
+        let chunk: ChunkCacheTypeDecoded = ArrayBytes::new_vlen(vec![0u8; 6], vec![0, 2, 6]);
+        assert_eq!(ChunkCacheType::size(&chunk), 6 + 3 * size_of::<usize>());
+    }
+
+    #[test]
+    fn chunk_cache_thread_local_remove() {
+        let chunk: Arc<ChunkCacheTypeDecoded> = Arc::new(vec![0u8; 16].into());
+        let cache = ChunkCacheDecodedLruSizeLimitThreadLocal::new(16);
+        cache.insert(vec![0], chunk.clone());
+        std::thread::scope(|s| {
+            s.spawn(|| cache.insert(vec![0], chunk.clone()));
+        });
+        cache.remove(&[0]);
+        assert!(cache.is_empty());
+        std::thread::scope(|s| {
+            s.spawn(|| assert!(cache.get(&[0]).is_none()));
+        });
+
+        // The size of removed chunks is no longer counted
+        cache.insert(vec![1], chunk.clone());
+        cache.insert(vec![2], chunk);
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&[2]).is_some());
+    }
 }
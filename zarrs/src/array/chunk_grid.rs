@@ -28,7 +28,7 @@ use derive_more::{Deref, From};
 use crate::{
     array_subset::{ArraySubset, IncompatibleDimensionalityError},
     metadata::v3::MetadataV3,
-    plugin::{Plugin, PluginCreateError},
+    plugin::{Plugin, PluginCreateError, PluginKnownName, PluginUnsupportedError},
 };
 
 use super::{ArrayIndices, ArrayShape, ChunkShape};
@@ -41,6 +41,12 @@ pub struct ChunkGrid(Arc<dyn ChunkGridTraits>);
 pub type ChunkGridPlugin = Plugin<ChunkGrid>;
 inventory::collect!(ChunkGridPlugin);
 
+/// Known chunk grids, their aliases, and the crate feature that enables them.
+const KNOWN_CHUNK_GRIDS: &[PluginKnownName] = &[
+    PluginKnownName::new("regular", &[], None),
+    PluginKnownName::new("rectangular", &[], None),
+];
+
 impl ChunkGrid {
     /// Create a chunk grid.
     pub fn new<T: ChunkGridTraits + 'static>(chunk_grid: T) -> Self {
@@ -72,10 +78,15 @@ impl ChunkGrid {
                 _ => {}
             }
         }
-        Err(PluginCreateError::Unsupported {
-            name: metadata.name().to_string(),
-            plugin_type: "chunk grid".to_string(),
-        })
+        Err(PluginUnsupportedError::with_known_names(
+            metadata.name().to_string(),
+            "chunk grid".to_string(),
+            KNOWN_CHUNK_GRIDS,
+            inventory::iter::<ChunkGridPlugin>
+                .into_iter()
+                .map(ChunkGridPlugin::identifier),
+        )
+        .into())
     }
 }
 
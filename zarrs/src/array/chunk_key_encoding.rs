@@ -1,24 +1,30 @@
 //! Zarr chunk key encodings. Includes a [default](default::DefaultChunkKeyEncoding) and [v2](v2::V2ChunkKeyEncoding) implementation.
 //!
+//! The experimental [`zarrs.template`](template::TemplateChunkKeyEncoding) chunk key encoding supports arbitrary key templates.
+//!
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#chunk-key-encoding>.
 
 pub mod default;
+pub mod template;
 pub mod v2;
 
 use std::sync::Arc;
 
 pub use crate::metadata::{
     v3::array::chunk_key_encoding::{
-        default::DefaultChunkKeyEncodingConfiguration, v2::V2ChunkKeyEncodingConfiguration,
+        default::DefaultChunkKeyEncodingConfiguration,
+        template::TemplateChunkKeyEncodingConfiguration, v2::V2ChunkKeyEncodingConfiguration,
     },
     ChunkKeySeparator,
 };
 pub use default::DefaultChunkKeyEncoding;
+pub use template::TemplateChunkKeyEncoding;
 pub use v2::V2ChunkKeyEncoding;
 
 use crate::{
+    array::ArrayIndices,
     metadata::v3::MetadataV3,
-    plugin::{Plugin, PluginCreateError},
+    plugin::{Plugin, PluginCreateError, PluginKnownName, PluginUnsupportedError},
     storage::StoreKey,
 };
 
@@ -32,6 +38,13 @@ pub struct ChunkKeyEncoding(Arc<dyn ChunkKeyEncodingTraits>);
 pub type ChunkKeyEncodingPlugin = Plugin<ChunkKeyEncoding>;
 inventory::collect!(ChunkKeyEncodingPlugin);
 
+/// Known chunk key encodings, their aliases, and the crate feature that enables them.
+const KNOWN_CHUNK_KEY_ENCODINGS: &[PluginKnownName] = &[
+    PluginKnownName::new("default", &[], None),
+    PluginKnownName::new("v2", &[], None),
+    PluginKnownName::new("zarrs.template", &[], None),
+];
+
 impl ChunkKeyEncoding {
     /// Create a chunk key encoding.
     pub fn new<T: ChunkKeyEncodingTraits + 'static>(chunk_key_encoding: T) -> Self {
@@ -60,13 +73,21 @@ impl ChunkKeyEncoding {
                 v2::IDENTIFIER => {
                     return v2::create_chunk_key_encoding_v2(metadata);
                 }
+                template::IDENTIFIER => {
+                    return template::create_chunk_key_encoding_template(metadata);
+                }
                 _ => {}
             }
         }
-        Err(PluginCreateError::Unsupported {
-            name: metadata.name().to_string(),
-            plugin_type: "chunk key encoding".to_string(),
-        })
+        Err(PluginUnsupportedError::with_known_names(
+            metadata.name().to_string(),
+            "chunk key encoding".to_string(),
+            KNOWN_CHUNK_KEY_ENCODINGS,
+            inventory::iter::<ChunkKeyEncodingPlugin>
+                .into_iter()
+                .map(ChunkKeyEncodingPlugin::identifier),
+        )
+        .into())
     }
 }
 
@@ -86,4 +107,10 @@ pub trait ChunkKeyEncodingTraits: core::fmt::Debug + Send + Sync {
 
     /// Encode chunk grid indices (grid cell coordinates) into a store key.
     fn encode(&self, chunk_grid_indices: &[u64]) -> StoreKey;
+
+    /// Decode a store key into chunk grid indices (grid cell coordinates).
+    ///
+    /// This is the inverse of [`encode`](ChunkKeyEncodingTraits::encode).
+    /// Returns [`None`] if `key` is not a chunk key of this encoding.
+    fn decode(&self, key: &StoreKey) -> Option<ArrayIndices>;
 }
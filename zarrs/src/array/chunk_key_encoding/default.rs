@@ -1,7 +1,7 @@
 //! The default chunk key encoding.
 
 use crate::{
-    array::chunk_key_encoding::ChunkKeyEncodingPlugin,
+    array::{chunk_key_encoding::ChunkKeyEncodingPlugin, ArrayIndices},
     metadata::v3::{array::chunk_key_encoding::default, MetadataV3},
     plugin::{PluginCreateError, PluginMetadataInvalidError},
     storage::StoreKey,
@@ -100,6 +100,22 @@ impl ChunkKeyEncodingTraits for DefaultChunkKeyEncoding {
         }
         unsafe { StoreKey::new_unchecked(key) }
     }
+
+    fn decode(&self, key: &StoreKey) -> Option<ArrayIndices> {
+        let indices = key.as_str().strip_prefix('c')?;
+        let chunk_grid_indices = if indices.is_empty() {
+            vec![]
+        } else {
+            let separator = self.separator.to_string();
+            indices
+                .strip_prefix(&separator)?
+                .split(&separator)
+                .map(|index| index.parse::<u64>().ok())
+                .collect::<Option<Vec<_>>>()?
+        };
+        // Reject keys that are not canonical (e.g. with leading zeros)
+        (&self.encode(&chunk_grid_indices) == key).then_some(chunk_grid_indices)
+    }
 }
 
 #[cfg(test)]
@@ -135,4 +151,23 @@ mod tests {
         let key = data_key(&NodePath::root(), &chunk_key_encoding.encode(&[]));
         assert_eq!(key, StoreKey::new("c").unwrap());
     }
+
+    #[test]
+    fn decode() {
+        let chunk_key_encoding: ChunkKeyEncoding = DefaultChunkKeyEncoding::new_slash().into();
+        let decode = |key: &str| chunk_key_encoding.decode(&StoreKey::new(key).unwrap());
+        assert_eq!(decode("c/1/23/45"), Some(vec![1, 23, 45]));
+        assert_eq!(decode("c"), Some(vec![]));
+        assert_eq!(decode("c.1.23.45"), None);
+        assert_eq!(decode("c/01/23"), None);
+        assert_eq!(decode("c/1/x"), None);
+        assert_eq!(decode("1/23/45"), None);
+        assert_eq!(decode("zarr.json"), None);
+
+        let chunk_key_encoding: ChunkKeyEncoding = DefaultChunkKeyEncoding::new_dot().into();
+        let decode = |key: &str| chunk_key_encoding.decode(&StoreKey::new(key).unwrap());
+        assert_eq!(decode("c.1.23.45"), Some(vec![1, 23, 45]));
+        assert_eq!(decode("c/1/23/45"), None);
+        assert_eq!(decode("c.-1"), None);
+    }
 }
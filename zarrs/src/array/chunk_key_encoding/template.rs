@@ -0,0 +1,349 @@
+//! The `zarrs.template` chunk key encoding.
+//!
+//! This chunk key encoding is experimental and is not part of the Zarr V3 specification.
+
+use std::fmt::Write;
+
+use thiserror::Error;
+
+use crate::{
+    array::{chunk_key_encoding::ChunkKeyEncodingPlugin, ArrayIndices},
+    metadata::v3::{array::chunk_key_encoding::template, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+    storage::StoreKey,
+};
+
+use super::{ChunkKeyEncoding, ChunkKeyEncodingTraits, TemplateChunkKeyEncodingConfiguration};
+
+pub use template::IDENTIFIER;
+
+// Register the chunk key encoding.
+inventory::submit! {
+    ChunkKeyEncodingPlugin::new(IDENTIFIER, is_name_template, create_chunk_key_encoding_template)
+}
+
+fn is_name_template(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+}
+
+pub(crate) fn create_chunk_key_encoding_template(
+    metadata: &MetadataV3,
+) -> Result<ChunkKeyEncoding, PluginCreateError> {
+    let configuration: TemplateChunkKeyEncodingConfiguration =
+        metadata.to_configuration().map_err(|_| {
+            PluginMetadataInvalidError::new(IDENTIFIER, "chunk key encoding", metadata.clone())
+        })?;
+    let template =
+        TemplateChunkKeyEncoding::new(&configuration.template, configuration.zero_padding)
+            .map_err(|err| PluginCreateError::Other(err.to_string()))?;
+    Ok(ChunkKeyEncoding::new(template))
+}
+
+/// An invalid chunk key template error.
+#[derive(Debug, Error)]
+#[error("invalid chunk key template {0}")]
+pub struct InvalidChunkKeyTemplateError(String);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplateSegment {
+    Literal(String),
+    Dimension(usize),
+}
+
+/// A `zarrs.template` chunk key encoding.
+///
+/// The key for a chunk is formed by substituting each `{dimN}` placeholder in a template with the chunk index in dimension `N`.
+/// Chunk indices are formatted as ASCII decimal strings, padded with leading zeros to the optional zero padding width.
+///
+/// The template must have exactly one placeholder for each dimension of the array.
+/// So that keys can be decoded unambiguously, placeholders must be separated by a literal that does not start with a digit.
+///
+/// For example, the template `c_{dim0}_{dim1}.bin` with a zero padding of 3 encodes the chunk `[1, 23]` as `c_001_023.bin`.
+#[derive(Debug, Clone)]
+pub struct TemplateChunkKeyEncoding {
+    template: String,
+    zero_padding: Option<usize>,
+    segments: Vec<TemplateSegment>,
+}
+
+impl TemplateChunkKeyEncoding {
+    /// Create a new `zarrs.template` chunk key encoding with `template` and an optional `zero_padding` width.
+    ///
+    /// # Errors
+    /// Returns [`InvalidChunkKeyTemplateError`] if:
+    ///  - the template has unmatched braces or a placeholder other than `{dimN}`,
+    ///  - the placeholders are not `{dim0}`, `{dim1}`, …, `{dimN}` each appearing once (in any order),
+    ///  - placeholders are not separated by a literal that does not start with a digit, or
+    ///  - the template does not encode valid store keys.
+    pub fn new(
+        template: &str,
+        zero_padding: Option<usize>,
+    ) -> Result<Self, InvalidChunkKeyTemplateError> {
+        let err = || InvalidChunkKeyTemplateError(template.to_string());
+
+        // Split the template into literal and placeholder segments
+        let mut segments = Vec::new();
+        let mut rest = template;
+        while !rest.is_empty() {
+            if let Some(placeholder) = rest.strip_prefix('{') {
+                let end = placeholder.find('}').ok_or_else(err)?;
+                let dimension = placeholder[..end]
+                    .strip_prefix("dim")
+                    .filter(|dimension| dimension.bytes().all(|b| b.is_ascii_digit()))
+                    .and_then(|dimension| dimension.parse::<usize>().ok())
+                    .ok_or_else(err)?;
+                segments.push(TemplateSegment::Dimension(dimension));
+                rest = &placeholder[end + 1..];
+            } else {
+                let end = rest.find(['{', '}']).unwrap_or(rest.len());
+                if rest[end..].starts_with('}') {
+                    return Err(err());
+                }
+                segments.push(TemplateSegment::Literal(rest[..end].to_string()));
+                rest = &rest[end..];
+            }
+        }
+
+        // Check the placeholders are a permutation of the dimensions
+        let mut dimensions: Vec<usize> = segments
+            .iter()
+            .filter_map(|segment| match segment {
+                TemplateSegment::Dimension(dimension) => Some(*dimension),
+                TemplateSegment::Literal(_) => None,
+            })
+            .collect();
+        dimensions.sort_unstable();
+        if !dimensions.iter().copied().eq(0..dimensions.len()) {
+            return Err(err());
+        }
+
+        // Check that the end of each chunk index is unambiguous
+        for pair in segments.windows(2) {
+            match pair {
+                [TemplateSegment::Dimension(_), TemplateSegment::Dimension(_)] => {
+                    return Err(err());
+                }
+                [TemplateSegment::Dimension(_), TemplateSegment::Literal(literal)]
+                    if literal.starts_with(|c: char| c.is_ascii_digit()) =>
+                {
+                    return Err(err());
+                }
+                _ => {}
+            }
+        }
+
+        let chunk_key_encoding = Self {
+            template: template.to_string(),
+            zero_padding,
+            segments,
+        };
+
+        // Chunk indices never contain a `/`, so the validity of a key only depends on the literals
+        let key = chunk_key_encoding.encode_string(&vec![0; dimensions.len()]);
+        if StoreKey::validate(&key) {
+            Ok(chunk_key_encoding)
+        } else {
+            Err(err())
+        }
+    }
+
+    /// Return the template.
+    #[must_use]
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Return the zero padding width.
+    #[must_use]
+    pub const fn zero_padding(&self) -> Option<usize> {
+        self.zero_padding
+    }
+
+    /// Return the number of dimensions of the template.
+    #[must_use]
+    pub fn dimensionality(&self) -> usize {
+        self.segments
+            .iter()
+            .filter(|segment| matches!(segment, TemplateSegment::Dimension(_)))
+            .count()
+    }
+
+    fn encode_string(&self, chunk_grid_indices: &[u64]) -> String {
+        let width = self.zero_padding.unwrap_or_default();
+        let mut key = String::new();
+        for segment in &self.segments {
+            match segment {
+                TemplateSegment::Literal(literal) => key.push_str(literal),
+                TemplateSegment::Dimension(dimension) => {
+                    let index = chunk_grid_indices.get(*dimension).copied().unwrap_or(0);
+                    write!(key, "{index:0width$}").unwrap();
+                }
+            }
+        }
+        key
+    }
+}
+
+impl ChunkKeyEncodingTraits for TemplateChunkKeyEncoding {
+    fn create_metadata(&self) -> MetadataV3 {
+        let configuration = TemplateChunkKeyEncodingConfiguration {
+            template: self.template.clone(),
+            zero_padding: self.zero_padding,
+        };
+        MetadataV3::new_with_serializable_configuration(IDENTIFIER, &configuration).unwrap()
+    }
+
+    /// Encode chunk grid indices into a store key.
+    ///
+    /// A placeholder for a dimension beyond the length of `chunk_grid_indices` is encoded as a chunk index of zero.
+    fn encode(&self, chunk_grid_indices: &[u64]) -> StoreKey {
+        unsafe { StoreKey::new_unchecked(self.encode_string(chunk_grid_indices)) }
+    }
+
+    fn decode(&self, key: &StoreKey) -> Option<ArrayIndices> {
+        let mut chunk_grid_indices = vec![0; self.dimensionality()];
+        let mut rest = key.as_str();
+        for segment in &self.segments {
+            match segment {
+                TemplateSegment::Literal(literal) => {
+                    rest = rest.strip_prefix(literal.as_str())?;
+                }
+                TemplateSegment::Dimension(dimension) => {
+                    let end = rest
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(rest.len());
+                    chunk_grid_indices[*dimension] = rest[..end].parse::<u64>().ok()?;
+                    rest = &rest[end..];
+                }
+            }
+        }
+        if !rest.is_empty() {
+            return None;
+        }
+        // Reject keys that are not canonical (e.g. with incorrect zero padding)
+        (self.encode_string(&chunk_grid_indices) == key.as_str()).then_some(chunk_grid_indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::node::{data_key, NodePath};
+
+    use super::*;
+
+    #[test]
+    fn template_encode() {
+        let chunk_key_encoding: ChunkKeyEncoding =
+            TemplateChunkKeyEncoding::new("c_{dim0}_{dim1}.bin", Some(3))
+                .unwrap()
+                .into();
+        let key = data_key(&NodePath::root(), &chunk_key_encoding.encode(&[1, 23]));
+        assert_eq!(key, StoreKey::new("c_001_023.bin").unwrap());
+        let key = chunk_key_encoding.encode(&[1234, 0]);
+        assert_eq!(key, StoreKey::new("c_1234_000.bin").unwrap());
+
+        let chunk_key_encoding: ChunkKeyEncoding =
+            TemplateChunkKeyEncoding::new("{dim2}/x{dim0}/y{dim1}", None)
+                .unwrap()
+                .into();
+        let key = chunk_key_encoding.encode(&[1, 23, 45]);
+        assert_eq!(key, StoreKey::new("45/x1/y23").unwrap());
+
+        let chunk_key_encoding: ChunkKeyEncoding = TemplateChunkKeyEncoding::new("scalar", None)
+            .unwrap()
+            .into();
+        assert_eq!(
+            chunk_key_encoding.encode(&[]),
+            StoreKey::new("scalar").unwrap()
+        );
+    }
+
+    #[test]
+    fn template_round_trip() {
+        for (template, zero_padding) in [
+            ("c_{dim0}_{dim1}_{dim2}.bin", Some(3)),
+            ("c_{dim0}_{dim1}_{dim2}.bin", None),
+            ("{dim2}/{dim1}/{dim0}", Some(2)),
+            ("chunks/{dim1}-{dim0}/{dim2}", None),
+        ] {
+            let chunk_key_encoding = TemplateChunkKeyEncoding::new(template, zero_padding).unwrap();
+            for indices in [[0, 0, 0], [1, 23, 456], [7, 1000, 12], [u64::MAX, 0, 9]] {
+                let key = chunk_key_encoding.encode(&indices);
+                assert_eq!(chunk_key_encoding.decode(&key), Some(indices.to_vec()));
+            }
+        }
+    }
+
+    #[test]
+    fn template_decode() {
+        let chunk_key_encoding =
+            TemplateChunkKeyEncoding::new("c_{dim0}_{dim1}.bin", Some(3)).unwrap();
+        let decode = |key: &str| chunk_key_encoding.decode(&StoreKey::new(key).unwrap());
+        assert_eq!(decode("c_001_023.bin"), Some(vec![1, 23]));
+        assert_eq!(decode("c_1234_000.bin"), Some(vec![1234, 0]));
+        assert_eq!(decode("c_1_23.bin"), None);
+        assert_eq!(decode("c_0001_023.bin"), None);
+        assert_eq!(decode("c_001_023"), None);
+        assert_eq!(decode("c_001_023.bin.bak"), None);
+        assert_eq!(decode("c__023.bin"), None);
+        assert_eq!(decode("c_+01_023.bin"), None);
+        assert_eq!(decode("zarr.json"), None);
+
+        let chunk_key_encoding = TemplateChunkKeyEncoding::new("{dim1}/{dim0}", None).unwrap();
+        let decode = |key: &str| chunk_key_encoding.decode(&StoreKey::new(key).unwrap());
+        assert_eq!(decode("23/1"), Some(vec![1, 23]));
+        assert_eq!(decode("23/01"), None);
+        assert_eq!(decode("23/1/0"), None);
+    }
+
+    #[test]
+    fn template_invalid() {
+        for template in [
+            "c_{dim0}{dim1}",
+            "c_{dim0}0{dim1}",
+            "c_{dim0}_{dim0}",
+            "c_{dim1}",
+            "c_{dim0}_{dim2}",
+            "c_{dim0",
+            "c_dim0}",
+            "c_{x}",
+            "c_{dim+0}",
+            "c_{}",
+            "/{dim0}",
+            "{dim0}/",
+            "{dim0}//{dim1}",
+            "",
+        ] {
+            assert!(
+                TemplateChunkKeyEncoding::new(template, None).is_err(),
+                "{template}"
+            );
+        }
+    }
+
+    #[test]
+    fn template_metadata() {
+        let metadata: MetadataV3 = serde_json::from_str(
+            r#"{"name":"zarrs.template","configuration":{"template":"c_{dim0}_{dim1}.bin","zero_padding":3}}"#,
+        )
+        .unwrap();
+        let chunk_key_encoding = ChunkKeyEncoding::from_metadata(&metadata).unwrap();
+        assert_eq!(chunk_key_encoding.create_metadata(), metadata);
+        assert_eq!(
+            chunk_key_encoding.encode(&[1, 2]),
+            StoreKey::new("c_001_002.bin").unwrap()
+        );
+
+        let metadata: MetadataV3 = serde_json::from_str(
+            r#"{"name":"zarrs.template","configuration":{"template":"c_{dim0}{dim1}"}}"#,
+        )
+        .unwrap();
+        assert!(ChunkKeyEncoding::from_metadata(&metadata).is_err());
+
+        let metadata: MetadataV3 = serde_json::from_str(
+            r#"{"name":"zarrs.template","configuration":{"template":"c_{dim0}","separator":"/"}}"#,
+        )
+        .unwrap();
+        assert!(ChunkKeyEncoding::from_metadata(&metadata).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 //! The v2 chunk key encoding.
 
 use crate::{
-    array::chunk_key_encoding::ChunkKeyEncodingPlugin,
+    array::{chunk_key_encoding::ChunkKeyEncodingPlugin, ArrayIndices},
     metadata::v3::{array::chunk_key_encoding::v2, MetadataV3},
     plugin::{PluginCreateError, PluginMetadataInvalidError},
     storage::StoreKey,
@@ -98,6 +98,19 @@ impl ChunkKeyEncodingTraits for V2ChunkKeyEncoding {
         };
         unsafe { StoreKey::new_unchecked(key) }
     }
+
+    /// Decode a store key into chunk grid indices.
+    ///
+    /// The key of the chunk of a zero-dimensional array (`0`) is decoded as `[0]`.
+    fn decode(&self, key: &StoreKey) -> Option<ArrayIndices> {
+        let chunk_grid_indices = key
+            .as_str()
+            .split(&self.separator.to_string())
+            .map(|index| index.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        // Reject keys that are not canonical (e.g. with leading zeros)
+        (&self.encode(&chunk_grid_indices) == key).then_some(chunk_grid_indices)
+    }
 }
 
 #[cfg(test)]
@@ -133,4 +146,22 @@ mod tests {
         let key = data_key(&NodePath::root(), &chunk_key_encoding.encode(&[]));
         assert_eq!(key, StoreKey::new("0").unwrap());
     }
+
+    #[test]
+    fn decode() {
+        let chunk_key_encoding: ChunkKeyEncoding = V2ChunkKeyEncoding::new_dot().into();
+        let decode = |key: &str| chunk_key_encoding.decode(&StoreKey::new(key).unwrap());
+        assert_eq!(decode("1.23.45"), Some(vec![1, 23, 45]));
+        assert_eq!(decode("0"), Some(vec![0]));
+        assert_eq!(decode("1/23/45"), None);
+        assert_eq!(decode("1.023"), None);
+        assert_eq!(decode(".zarray"), None);
+        assert_eq!(decode("zarr.json"), None);
+
+        let chunk_key_encoding: ChunkKeyEncoding = V2ChunkKeyEncoding::new_slash().into();
+        let decode = |key: &str| chunk_key_encoding.decode(&StoreKey::new(key).unwrap());
+        assert_eq!(decode("1/23/45"), Some(vec![1, 23, 45]));
+        assert_eq!(decode("1.23.45"), None);
+        assert_eq!(decode("+1/23"), None);
+    }
 }
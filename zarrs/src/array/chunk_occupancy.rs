@@ -0,0 +1,26 @@
+/// The occupancy of a chunk in the store.
+///
+/// See [`Array::chunk_occupancy`](crate::array::Array::chunk_occupancy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChunkOccupancy {
+    /// The chunk does not exist.
+    Missing,
+    /// The chunk exists, but every element is the fill value.
+    AllFillValue,
+    /// The chunk exists and has elements that are not the fill value.
+    NonFill {
+        /// The number of elements that are not the fill value.
+        count: u64,
+    },
+}
+
+impl ChunkOccupancy {
+    /// Returns the number of elements that are not the fill value.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        match self {
+            Self::Missing | Self::AllFillValue => 0,
+            Self::NonFill { count } => *count,
+        }
+    }
+}
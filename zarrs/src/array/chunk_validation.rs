@@ -0,0 +1,29 @@
+use super::codec::CodecError;
+
+/// The status of an encoded chunk in the store.
+///
+/// See [`Array::validate_chunk`](crate::array::Array::validate_chunk) and [`Array::validate_all_chunks`](crate::array::Array::validate_all_chunks).
+#[derive(Debug)]
+pub enum ChunkValidation {
+    /// The chunk is valid.
+    Ok,
+    /// The chunk does not exist.
+    Missing,
+    /// The checksum embedded in the chunk does not match the checksum of its content.
+    ChecksumMismatch {
+        /// The checksum embedded in the chunk.
+        expected: Vec<u8>,
+        /// The checksum of the content of the chunk.
+        actual: Vec<u8>,
+    },
+    /// The chunk could not be decoded.
+    DecodeError(CodecError),
+}
+
+impl ChunkValidation {
+    /// Returns true if the chunk is valid or does not exist.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Ok | Self::Missing)
+    }
+}
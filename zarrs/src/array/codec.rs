@@ -22,6 +22,14 @@ pub use options::{CodecOptions, CodecOptionsBuilder};
 pub use array_to_array::bitround::{
     BitroundCodec, BitroundCodecConfiguration, BitroundCodecConfigurationV1,
 };
+#[cfg(feature = "expand_dims")]
+pub use array_to_array::expand_dims::{
+    ExpandDimsCodec, ExpandDimsCodecConfiguration, ExpandDimsCodecConfigurationV1,
+};
+#[cfg(feature = "quantize")]
+pub use array_to_array::quantize::{
+    QuantizeCodec, QuantizeCodecConfiguration, QuantizeCodecConfigurationV1,
+};
 #[cfg(feature = "transpose")]
 pub use array_to_array::transpose::{
     TransposeCodec, TransposeCodecConfiguration, TransposeCodecConfigurationV1,
@@ -29,31 +37,61 @@ pub use array_to_array::transpose::{
 
 // Array to bytes
 pub use array_to_bytes::bytes::{BytesCodec, BytesCodecConfiguration, BytesCodecConfigurationV1};
-pub use array_to_bytes::codec_chain::CodecChain;
+pub use array_to_bytes::codec_chain::{
+    CodecChain, CodecChainExplanation, CodecKind, CodecStageExplanation, CodecStageInfo,
+    CodecStageRepresentation,
+};
+pub use array_to_bytes::image::{ImageCodec, ImageCodecTraits, ImageRepresentation};
+#[cfg(feature = "jpegxl")]
+pub use array_to_bytes::jpegxl::{
+    JpegXlCodec, JpegXlCodecConfiguration, JpegXlCodecConfigurationV1,
+};
 #[cfg(feature = "pcodec")]
 pub use array_to_bytes::pcodec::{
     PcodecCodec, PcodecCodecConfiguration, PcodecCodecConfigurationV1,
 };
 #[cfg(feature = "sharding")]
 pub use array_to_bytes::sharding::{
-    ShardingCodec, ShardingCodecConfiguration, ShardingCodecConfigurationV1,
+    ShardIndex, ShardingCodec, ShardingCodecConfiguration, ShardingCodecConfigurationV1,
 };
+#[cfg(feature = "webp")]
+pub use array_to_bytes::webp::{WebpCodec, WebpCodecConfiguration, WebpCodecConfigurationV1};
 #[cfg(feature = "zfp")]
 pub use array_to_bytes::zfp::{ZfpCodec, ZfpCodecConfiguration, ZfpCodecConfigurationV1};
 
 // Bytes to bytes
+#[cfg(feature = "adler32")]
+pub use bytes_to_bytes::adler32::{
+    Adler32Codec, Adler32CodecConfiguration, Adler32CodecConfigurationV1,
+};
+#[cfg(feature = "bitshuffle")]
+pub use bytes_to_bytes::bitshuffle::{
+    BitshuffleCodec, BitshuffleCodecConfiguration, BitshuffleCodecConfigurationV1,
+};
 #[cfg(feature = "blosc")]
 pub use bytes_to_bytes::blosc::{BloscCodec, BloscCodecConfiguration, BloscCodecConfigurationV1};
 #[cfg(feature = "bz2")]
 pub use bytes_to_bytes::bz2::{Bz2Codec, Bz2CodecConfiguration, Bz2CodecConfigurationV1};
+#[cfg(feature = "crc32")]
+pub use bytes_to_bytes::crc32::{Crc32Codec, Crc32CodecConfiguration, Crc32CodecConfigurationV1};
 #[cfg(feature = "crc32c")]
 pub use bytes_to_bytes::crc32c::{
     Crc32cCodec, Crc32cCodecConfiguration, Crc32cCodecConfigurationV1,
 };
 #[cfg(feature = "gzip")]
 pub use bytes_to_bytes::gzip::{GzipCodec, GzipCodecConfiguration, GzipCodecConfigurationV1};
+#[cfg(feature = "jenkins_lookup3")]
+pub use bytes_to_bytes::jenkins_lookup3::{
+    JenkinsLookup3Codec, JenkinsLookup3CodecConfiguration, JenkinsLookup3CodecConfigurationV1,
+};
+#[cfg(feature = "lz4")]
+pub use bytes_to_bytes::lz4::{Lz4Codec, Lz4CodecConfiguration, Lz4CodecConfigurationV1};
+#[cfg(feature = "lzma")]
+pub use bytes_to_bytes::lzma::{LzmaCodec, LzmaCodecConfiguration, LzmaCodecConfigurationV1};
 #[cfg(feature = "zstd")]
-pub use bytes_to_bytes::zstd::{ZstdCodec, ZstdCodecConfiguration, ZstdCodecConfigurationV1};
+pub use bytes_to_bytes::zstd::{
+    train_zstd_dictionary, ZstdCodec, ZstdCodecConfiguration, ZstdCodecConfigurationV1,
+};
 
 use thiserror::Error;
 
@@ -62,6 +100,11 @@ mod bytes_partial_decoder_cache;
 pub(crate) use array_partial_decoder_cache::ArrayPartialDecoderCache;
 pub(crate) use bytes_partial_decoder_cache::BytesPartialDecoderCache;
 
+mod bytes_partial_decoder_range_cache;
+#[cfg(feature = "async")]
+pub(crate) use bytes_partial_decoder_range_cache::AsyncBytesPartialDecoderRangeCache;
+pub(crate) use bytes_partial_decoder_range_cache::BytesPartialDecoderRangeCache;
+
 mod byte_interval_partial_decoder;
 pub use byte_interval_partial_decoder::ByteIntervalPartialDecoder;
 
@@ -69,6 +112,11 @@ pub use byte_interval_partial_decoder::ByteIntervalPartialDecoder;
 pub use byte_interval_partial_decoder::AsyncByteIntervalPartialDecoder;
 use unsafe_cell_slice::UnsafeCellSlice;
 
+mod array_partial_decoder_default;
+pub use array_partial_decoder_default::ArrayPartialDecoderDefault;
+#[cfg(feature = "async")]
+pub use array_partial_decoder_default::AsyncArrayPartialDecoderDefault;
+
 mod array_partial_encoder_default;
 pub use array_partial_encoder_default::ArrayPartialEncoderDefault;
 
@@ -81,9 +129,13 @@ pub use bytes_partial_encoder_default::BytesPartialEncoderDefault;
 use crate::storage::{StoreKeyOffsetValue, WritableStorage};
 use crate::{
     array_subset::{ArraySubset, IncompatibleArraySubsetAndShapeError},
-    byte_range::{extract_byte_ranges_read_seek, ByteOffset, ByteRange, InvalidByteRangeError},
+    byte_range::{
+        coalesce_byte_ranges, extract_byte_ranges_read_seek, ByteOffset, ByteRange,
+        InvalidByteRangeError,
+    },
+    config::global_config,
     metadata::v3::MetadataV3,
-    plugin::{Plugin, PluginCreateError},
+    plugin::{Plugin, PluginCreateError, PluginKnownName, PluginUnsupportedError},
     storage::{ReadableStorage, StorageError, StoreKey},
 };
 
@@ -101,10 +153,48 @@ use super::{
 };
 use super::{ArrayBytes, RawBytes};
 
+/// The checksum embedded in an encoded value and the checksum of its content.
+///
+/// See [`BytesToBytesCodecTraits::checksums`].
+pub type Checksums = (Vec<u8>, Vec<u8>);
+
 /// A codec plugin.
 pub type CodecPlugin = Plugin<Codec>;
 inventory::collect!(CodecPlugin);
 
+/// Known codecs, their aliases, and the crate feature that enables them.
+#[rustfmt::skip]
+const KNOWN_CODECS: &[PluginKnownName] = &[
+    // Array to array
+    PluginKnownName::new("bitround", &["https://codec.zarrs.dev/array_to_array/bitround"], Some("bitround")),
+    PluginKnownName::new("expand_dims", &["https://codec.zarrs.dev/array_to_array/expand_dims"], Some("expand_dims")),
+    PluginKnownName::new("quantize", &["numcodecs.quantize"], Some("quantize")),
+    PluginKnownName::new("transpose", &[], Some("transpose")),
+    // Array to bytes
+    PluginKnownName::new("bytes", &["endian"], None),
+    PluginKnownName::new("jpegxl", &["zarrs.jpegxl"], Some("jpegxl")),
+    PluginKnownName::new("pcodec", &["https://codec.zarrs.dev/array_to_bytes/pcodec"], Some("pcodec")),
+    PluginKnownName::new("sharding_indexed", &[], Some("sharding")),
+    PluginKnownName::new("vlen", &["https://codec.zarrs.dev/array_to_bytes/vlen"], None),
+    PluginKnownName::new("vlen_v2", &["vlen-array", "vlen-bytes", "vlen-utf8"], None),
+    PluginKnownName::new("webp", &["zarrs.webp"], Some("webp")),
+    PluginKnownName::new("zfp", &["zfpy", "https://codec.zarrs.dev/array_to_bytes/zfp"], Some("zfp")),
+    // Bytes to bytes
+    PluginKnownName::new("adler32", &["numcodecs.adler32"], Some("adler32")),
+    PluginKnownName::new("bitshuffle", &["numcodecs.bitshuffle"], Some("bitshuffle")),
+    PluginKnownName::new("blosc", &[], Some("blosc")),
+    PluginKnownName::new("bz2", &["https://codec.zarrs.dev/bytes_to_bytes/bz2"], Some("bz2")),
+    PluginKnownName::new("crc32", &["numcodecs.crc32"], Some("crc32")),
+    PluginKnownName::new("crc32c", &[], Some("crc32c")),
+    PluginKnownName::new("fletcher32", &["https://codec.zarrs.dev/bytes_to_bytes/fletcher32"], Some("fletcher32")),
+    PluginKnownName::new("gdeflate", &[], Some("gdeflate")),
+    PluginKnownName::new("gzip", &[], Some("gzip")),
+    PluginKnownName::new("jenkins_lookup3", &["numcodecs.jenkins_lookup3"], Some("jenkins_lookup3")),
+    PluginKnownName::new("lz4", &["numcodecs.lz4"], Some("lz4")),
+    PluginKnownName::new("lzma", &["numcodecs.lzma"], Some("lzma")),
+    PluginKnownName::new("zstd", &[], Some("zstd")),
+];
+
 /// A generic array to array, array to bytes, or bytes to bytes codec.
 #[derive(Debug)]
 pub enum Codec {
@@ -139,6 +229,14 @@ impl Codec {
                 array_to_array::bitround::IDENTIFIER => {
                     return array_to_array::bitround::create_codec_bitround(metadata);
                 }
+                #[cfg(feature = "expand_dims")]
+                array_to_array::expand_dims::IDENTIFIER => {
+                    return array_to_array::expand_dims::create_codec_expand_dims(metadata);
+                }
+                #[cfg(feature = "quantize")]
+                array_to_array::quantize::IDENTIFIER => {
+                    return array_to_array::quantize::create_codec_quantize(metadata);
+                }
                 array_to_bytes::bytes::IDENTIFIER => {
                     return array_to_bytes::bytes::create_codec_bytes(metadata);
                 }
@@ -160,6 +258,14 @@ impl Codec {
                 array_to_bytes::vlen_v2::IDENTIFIER => {
                     return array_to_bytes::vlen_v2::create_codec_vlen_v2(metadata);
                 }
+                #[cfg(feature = "adler32")]
+                bytes_to_bytes::adler32::IDENTIFIER => {
+                    return bytes_to_bytes::adler32::create_codec_adler32(metadata);
+                }
+                #[cfg(feature = "bitshuffle")]
+                bytes_to_bytes::bitshuffle::IDENTIFIER => {
+                    return bytes_to_bytes::bitshuffle::create_codec_bitshuffle(metadata);
+                }
                 #[cfg(feature = "blosc")]
                 bytes_to_bytes::blosc::IDENTIFIER => {
                     return bytes_to_bytes::blosc::create_codec_blosc(metadata);
@@ -168,6 +274,10 @@ impl Codec {
                 bytes_to_bytes::bz2::IDENTIFIER => {
                     return bytes_to_bytes::bz2::create_codec_bz2(metadata);
                 }
+                #[cfg(feature = "crc32")]
+                bytes_to_bytes::crc32::IDENTIFIER => {
+                    return bytes_to_bytes::crc32::create_codec_crc32(metadata);
+                }
                 #[cfg(feature = "crc32c")]
                 bytes_to_bytes::crc32c::IDENTIFIER => {
                     return bytes_to_bytes::crc32c::create_codec_crc32c(metadata);
@@ -180,6 +290,18 @@ impl Codec {
                 bytes_to_bytes::gzip::IDENTIFIER => {
                     return bytes_to_bytes::gzip::create_codec_gzip(metadata);
                 }
+                #[cfg(feature = "jenkins_lookup3")]
+                bytes_to_bytes::jenkins_lookup3::IDENTIFIER => {
+                    return bytes_to_bytes::jenkins_lookup3::create_codec_jenkins_lookup3(metadata);
+                }
+                #[cfg(feature = "lz4")]
+                bytes_to_bytes::lz4::IDENTIFIER => {
+                    return bytes_to_bytes::lz4::create_codec_lz4(metadata);
+                }
+                #[cfg(feature = "lzma")]
+                bytes_to_bytes::lzma::IDENTIFIER => {
+                    return bytes_to_bytes::lzma::create_codec_lzma(metadata);
+                }
                 #[cfg(feature = "zstd")]
                 bytes_to_bytes::zstd::IDENTIFIER => {
                     return bytes_to_bytes::zstd::create_codec_zstd(metadata);
@@ -187,13 +309,29 @@ impl Codec {
                 _ => {}
             }
         }
-        Err(PluginCreateError::Unsupported {
-            name: metadata.name().to_string(),
-            plugin_type: "codec".to_string(),
-        })
+        Err(codec_unsupported_error(metadata.name()).into())
     }
 }
 
+/// Create an unsupported codec error, with hints from the known and registered codec names.
+fn codec_unsupported_error(name: &str) -> PluginUnsupportedError {
+    let config = global_config();
+    let mut registered: Vec<&str> = config
+        .experimental_codec_names()
+        .values()
+        .map(String::as_str)
+        .collect();
+    for plugin in inventory::iter::<CodecPlugin> {
+        registered.push(plugin.identifier());
+    }
+    PluginUnsupportedError::with_known_names(
+        name.to_string(),
+        "codec".to_string(),
+        KNOWN_CODECS,
+        registered,
+    )
+}
+
 /// Codec traits.
 pub trait CodecTraits: Send + Sync {
     /// Create metadata.
@@ -215,6 +353,21 @@ pub trait CodecTraits: Send + Sync {
     /// Indicates if a partial decoder decodes all bytes from its input handle and its output should be cached for optimal performance.
     /// If true, a cache will be inserted at some point *after* it in a [`CodecChain`] partial decoder.
     fn partial_decoder_decodes_all(&self) -> bool;
+
+    /// Indicates if a codecs partial decoder decodes a subset of a chunk without decoding the entire chunk.
+    ///
+    /// This is informative only, see [`CodecChain::explain`].
+    /// The default implementation returns the inverse of [`partial_decoder_decodes_all`](CodecTraits::partial_decoder_decodes_all).
+    fn supports_partial_decode(&self) -> bool {
+        !self.partial_decoder_decodes_all()
+    }
+
+    /// Indicates if a codecs partial encoder writes chunk subsets without decoding and reencoding the entire chunk.
+    ///
+    /// If true for a [`CodecChain`], [`Array::store_chunk_subset`](crate::array::Array::store_chunk_subset) and variants use partial encoding if the store supports partial writes.
+    fn supports_partial_encode(&self) -> bool {
+        false
+    }
 }
 
 /// Traits for both array to array and array to bytes codecs.
@@ -493,12 +646,39 @@ impl StoragePartialDecoder {
     }
 }
 
+/// Extract the byte ranges of a store value from the bytes of coalesced byte ranges.
+///
+/// `mapping` is the byte range mapping returned by [`coalesce_byte_ranges`].
+fn split_coalesced_bytes(
+    coalesced_bytes: &[bytes::Bytes],
+    mapping: &[(usize, ByteRange)],
+) -> Result<Vec<RawBytes<'static>>, CodecError> {
+    mapping
+        .iter()
+        .map(|(index, byte_range)| {
+            let bytes = &coalesced_bytes[*index];
+            bytes
+                .get(byte_range.to_range_usize(bytes.len() as u64))
+                .map(|bytes| Cow::Owned(bytes.to_vec()))
+                .ok_or_else(|| InvalidByteRangeError::new(*byte_range, bytes.len() as u64).into())
+        })
+        .collect()
+}
+
 impl BytesPartialDecoderTraits for StoragePartialDecoder {
     fn partial_decode(
         &self,
         decoded_regions: &[ByteRange],
-        _options: &CodecOptions,
+        options: &CodecOptions,
     ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        if let Some(gap) = options.range_coalesce_gap() {
+            let (byte_ranges, mapping) = coalesce_byte_ranges(decoded_regions, gap);
+            return self
+                .storage
+                .get_partial_values_key(&self.key, &byte_ranges)?
+                .map(|vec_bytes| split_coalesced_bytes(&vec_bytes, &mapping))
+                .transpose();
+        }
         Ok(self
             .storage
             .get_partial_values_key(&self.key, decoded_regions)?
@@ -532,8 +712,17 @@ impl AsyncBytesPartialDecoderTraits for AsyncStoragePartialDecoder {
     async fn partial_decode(
         &self,
         decoded_regions: &[ByteRange],
-        _options: &CodecOptions,
+        options: &CodecOptions,
     ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        if let Some(gap) = options.range_coalesce_gap() {
+            let (byte_ranges, mapping) = coalesce_byte_ranges(decoded_regions, gap);
+            return self
+                .storage
+                .get_partial_values_key(&self.key, &byte_ranges)
+                .await?
+                .map(|vec_bytes| split_coalesced_bytes(&vec_bytes, &mapping))
+                .transpose();
+        }
         Ok(self
             .storage
             .get_partial_values_key(&self.key, decoded_regions)
@@ -604,6 +793,17 @@ pub trait ArrayToArrayCodecTraits: ArrayCodecTraits + core::fmt::Debug {
         encoded_representation: ChunkShape,
     ) -> Result<ChunkShape, CodecError>;
 
+    /// Validate that the codec supports the data type of `decoded_representation`.
+    ///
+    /// Returns every incompatibility found, which is empty if the codec is compatible.
+    /// The default implementation reports a [`CodecError::UnsupportedDataType`] returned by [`compute_encoded_size`](ArrayToArrayCodecTraits::compute_encoded_size).
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        unsupported_data_type_incompatibilities(self.compute_encoded_size(decoded_representation))
+    }
+
     /// Encode a chunk.
     ///
     /// # Errors
@@ -679,6 +879,17 @@ pub trait ArrayToBytesCodecTraits: ArrayCodecTraits + core::fmt::Debug {
         decoded_representation: &ChunkRepresentation,
     ) -> Result<BytesRepresentation, CodecError>;
 
+    /// Validate that the codec supports the data type of `decoded_representation`.
+    ///
+    /// Returns every incompatibility found, which is empty if the codec is compatible.
+    /// The default implementation reports a [`CodecError::UnsupportedDataType`] returned by [`compute_encoded_size`](ArrayToBytesCodecTraits::compute_encoded_size).
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        unsupported_data_type_incompatibilities(self.compute_encoded_size(decoded_representation))
+    }
+
     /// Encode a chunk.
     ///
     /// # Errors
@@ -826,6 +1037,17 @@ pub trait BytesToBytesCodecTraits: CodecTraits + core::fmt::Debug {
         options: &CodecOptions,
     ) -> Result<RawBytes<'a>, CodecError>;
 
+    /// Return the checksum embedded in `encoded_value` and the checksum of its content if the codec embeds a checksum.
+    ///
+    /// This enables an encoded value to be validated without decoding it.
+    /// Returns [`None`] if the codec does not embed a checksum.
+    ///
+    /// # Errors
+    /// Returns [`CodecError`] if `encoded_value` is too small to contain a checksum.
+    fn checksums(&self, _encoded_value: &[u8]) -> Result<Option<Checksums>, CodecError> {
+        Ok(None)
+    }
+
     /// Initialises a partial decoder.
     ///
     /// # Errors
@@ -1002,6 +1224,69 @@ pub enum CodecError {
     /// Expected variable length bytes.
     #[error("Expected variable length array bytes")]
     ExpectedVariableLengthBytes,
+    /// The index of a shard is corrupt (e.g. the shard was truncated by an interrupted write).
+    #[error("the shard index{} is corrupt: {reason}", key.as_ref().map_or_else(String::new, |key| format!(" of {key}")))]
+    ShardIndexCorrupt {
+        /// The store key of the shard, if known.
+        key: Option<StoreKey>,
+        /// The reason the shard index is corrupt.
+        reason: String,
+    },
+}
+
+/// A codec is incompatible with the data type of the chunks it encodes.
+#[derive(Clone, Debug, Error)]
+#[error("codec {codec} is incompatible with data type {data_type}: {reason}")]
+pub struct CodecIncompatibleWithDataTypeError {
+    codec: String,
+    data_type: DataType,
+    reason: String,
+}
+
+impl CodecIncompatibleWithDataTypeError {
+    /// Create a new codec data type incompatibility error.
+    #[must_use]
+    pub fn new(codec: impl Into<String>, data_type: DataType, reason: impl Into<String>) -> Self {
+        Self {
+            codec: codec.into(),
+            data_type,
+            reason: reason.into(),
+        }
+    }
+
+    /// Return the codec identifier.
+    #[must_use]
+    pub fn codec(&self) -> &str {
+        &self.codec
+    }
+
+    /// Return the data type.
+    #[must_use]
+    pub const fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Return the reason the codec is incompatible with the data type.
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// Convert an [`CodecError::UnsupportedDataType`] error from a codec into an incompatibility.
+fn unsupported_data_type_incompatibilities<T>(
+    result: Result<T, CodecError>,
+) -> Vec<CodecIncompatibleWithDataTypeError> {
+    match result {
+        Err(CodecError::UnsupportedDataType(data_type, codec)) => {
+            vec![CodecIncompatibleWithDataTypeError::new(
+                codec,
+                data_type,
+                "the data type is not supported by the codec",
+            )]
+        }
+        _ => vec![],
+    }
 }
 
 impl From<&str> for CodecError {
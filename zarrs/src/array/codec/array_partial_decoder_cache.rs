@@ -38,6 +38,17 @@ impl ArrayPartialDecoderCache {
         })
     }
 
+    /// Create a new partial decoder cache from decoded `bytes`.
+    pub(crate) fn new_with_bytes(
+        decoded_representation: ChunkRepresentation,
+        bytes: ArrayBytes<'static>,
+    ) -> Self {
+        Self {
+            decoded_representation,
+            cache: bytes,
+        }
+    }
+
     #[cfg(feature = "async")]
     /// Create a new asynchronous partial decoder cache.
     ///
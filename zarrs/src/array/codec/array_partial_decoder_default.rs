@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{ArrayBytes, ArraySize, ChunkRepresentation, DataType, RawBytes},
+    array_subset::ArraySubset,
+};
+
+use super::{
+    ArrayPartialDecoderTraits, ArrayToBytesCodecTraits, BytesPartialDecoderTraits, CodecError,
+    CodecOptions,
+};
+#[cfg(feature = "async")]
+use super::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits};
+
+/// The default array (chunk) partial decoder. Decodes the entire chunk, and extracts the requested subsets.
+pub struct ArrayPartialDecoderDefault {
+    input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    codec: Arc<dyn ArrayToBytesCodecTraits>,
+}
+
+impl ArrayPartialDecoderDefault {
+    /// Create a new [`ArrayPartialDecoderDefault`].
+    #[must_use]
+    pub fn new(
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        codec: Arc<dyn ArrayToBytesCodecTraits>,
+    ) -> Self {
+        Self {
+            input_handle,
+            decoded_representation,
+            codec,
+        }
+    }
+}
+
+fn partial_decode<'a>(
+    codec: &dyn ArrayToBytesCodecTraits,
+    chunk_bytes: Option<RawBytes<'_>>,
+    decoded_representation: &ChunkRepresentation,
+    decoded_regions: &[ArraySubset],
+    options: &CodecOptions,
+) -> Result<Vec<ArrayBytes<'a>>, CodecError> {
+    let data_type = decoded_representation.data_type();
+    if let Some(chunk_bytes) = chunk_bytes {
+        let chunk_shape = decoded_representation.shape_u64();
+        let chunk_bytes = codec.decode(chunk_bytes, decoded_representation, options)?;
+        decoded_regions
+            .iter()
+            .map(|decoded_region| {
+                Ok(chunk_bytes
+                    .extract_array_subset(decoded_region, &chunk_shape, data_type)?
+                    .into_owned())
+            })
+            .collect()
+    } else {
+        Ok(decoded_regions
+            .iter()
+            .map(|decoded_region| {
+                let array_size = ArraySize::new(data_type.size(), decoded_region.num_elements());
+                ArrayBytes::new_fill_value(array_size, decoded_representation.fill_value())
+            })
+            .collect())
+    }
+}
+
+impl ArrayPartialDecoderTraits for ArrayPartialDecoderDefault {
+    fn data_type(&self) -> &DataType {
+        self.decoded_representation.data_type()
+    }
+
+    fn partial_decode(
+        &self,
+        decoded_regions: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let chunk_bytes = self.input_handle.decode(options)?;
+        partial_decode(
+            self.codec.as_ref(),
+            chunk_bytes,
+            &self.decoded_representation,
+            decoded_regions,
+            options,
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+/// The default asynchronous array (chunk) partial decoder. Decodes the entire chunk, and extracts the requested subsets.
+pub struct AsyncArrayPartialDecoderDefault {
+    input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    codec: Arc<dyn ArrayToBytesCodecTraits>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncArrayPartialDecoderDefault {
+    /// Create a new [`AsyncArrayPartialDecoderDefault`].
+    #[must_use]
+    pub fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        codec: Arc<dyn ArrayToBytesCodecTraits>,
+    ) -> Self {
+        Self {
+            input_handle,
+            decoded_representation,
+            codec,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncArrayPartialDecoderTraits for AsyncArrayPartialDecoderDefault {
+    fn data_type(&self) -> &DataType {
+        self.decoded_representation.data_type()
+    }
+
+    async fn partial_decode(
+        &self,
+        decoded_regions: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let chunk_bytes = self.input_handle.decode(options).await?;
+        partial_decode(
+            self.codec.as_ref(),
+            chunk_bytes,
+            &self.decoded_representation,
+            decoded_regions,
+            options,
+        )
+    }
+}
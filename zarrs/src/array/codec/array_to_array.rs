@@ -2,5 +2,9 @@
 
 #[cfg(feature = "bitround")]
 pub mod bitround;
+#[cfg(feature = "expand_dims")]
+pub mod expand_dims;
+#[cfg(feature = "quantize")]
+pub mod quantize;
 #[cfg(feature = "transpose")]
 pub mod transpose;
@@ -5,7 +5,7 @@ use crate::{
         codec::{
             options::CodecOptions, ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits,
             ArrayPartialEncoderTraits, ArrayToArrayCodecTraits, ArrayToArrayPartialEncoderDefault,
-            CodecError, CodecTraits, RecommendedConcurrency,
+            CodecError, CodecIncompatibleWithDataTypeError, CodecTraits, RecommendedConcurrency,
         },
         ArrayMetadataOptions, ChunkRepresentation, ChunkShape, DataType,
     },
@@ -192,4 +192,19 @@ impl ArrayToArrayCodecTraits for BitroundCodec {
     fn compute_decoded_shape(&self, encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
         Ok(encoded_shape)
     }
+
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        if self.compute_encoded_size(decoded_representation).is_ok() {
+            vec![]
+        } else {
+            vec![CodecIncompatibleWithDataTypeError::new(
+                IDENTIFIER,
+                decoded_representation.data_type().clone(),
+                "only integer, floating point, and complex data types are supported",
+            )]
+        }
+    }
 }
@@ -0,0 +1,222 @@
+//! The `expand_dims` array to array codec.
+//!
+//! Inserts singleton axes into the encoded representation of a chunk, as in `numpy.expand_dims`.
+//! For example, with `"axes": [0]` a `[H, W]` chunk is encoded as a `[1, H, W]` chunk.
+//! The elements are passed through unchanged.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `expand_dims` feature, which is disabled by default.
+//!
+//! See [`ExpandDimsCodecConfigurationV1`] for example `JSON` metadata.
+
+mod expand_dims_codec;
+mod expand_dims_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::expand_dims::{
+    ExpandDimsCodecConfiguration, ExpandDimsCodecConfigurationV1,
+};
+pub use expand_dims_codec::ExpandDimsCodec;
+
+use crate::{
+    array::codec::{Codec, CodecError, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::expand_dims, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use expand_dims::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_expand_dims, create_codec_expand_dims)
+}
+
+fn is_name_expand_dims(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_expand_dims(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: ExpandDimsCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(ExpandDimsCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::ArrayToArray(codec))
+}
+
+/// Insert `value` at each of the `axes` of the output.
+fn insert_axes<T: Copy>(values: &[T], axes: &[usize], value: T) -> Result<Vec<T>, CodecError> {
+    let dimensionality = values.len() + axes.len();
+    if axes.iter().any(|axis| *axis >= dimensionality) {
+        return Err(invalid_axes_error(axes, values.len()));
+    }
+    let mut values = values.iter();
+    Ok((0..dimensionality)
+        .map(|axis| {
+            if axes.contains(&axis) {
+                value
+            } else {
+                *values
+                    .next()
+                    .expect("values has dimensionality - axes.len() elements")
+            }
+        })
+        .collect())
+}
+
+/// Remove each of the `axes` from `values`, which must have a size of `size` on each of the `axes`.
+fn remove_axes<T: Copy + PartialEq>(
+    values: &[T],
+    axes: &[usize],
+    size: T,
+) -> Result<Vec<T>, CodecError> {
+    if values.len() < axes.len()
+        || axes
+            .iter()
+            .any(|axis| values.get(*axis).is_none_or(|value| *value != size))
+    {
+        return Err(invalid_axes_error(
+            axes,
+            values.len().saturating_sub(axes.len()),
+        ));
+    }
+    Ok(values
+        .iter()
+        .enumerate()
+        .filter(|(axis, _)| !axes.contains(axis))
+        .map(|(_, value)| *value)
+        .collect())
+}
+
+fn invalid_axes_error(axes: &[usize], dimensionality: usize) -> CodecError {
+    CodecError::Other(format!(
+        "expand_dims axes {axes:?} are invalid for a chunk with {dimensionality} dimensions"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{
+                ArrayToArrayCodecTraits, ArrayToBytesCodecTraits, BytesCodec, CodecOptions,
+                CodecTraits,
+            },
+            ArrayBytes, ChunkRepresentation, DataType, FillValue,
+        },
+        array_subset::ArraySubset,
+    };
+
+    use super::*;
+
+    #[test]
+    fn codec_expand_dims_axes() {
+        assert_eq!(insert_axes(&[3, 4], &[0], 1).unwrap(), [1, 3, 4]);
+        assert_eq!(insert_axes(&[3, 4], &[3, 1], 1).unwrap(), [3, 1, 4, 1]);
+        assert!(insert_axes(&[3, 4], &[3], 1).is_err());
+        assert_eq!(remove_axes(&[3, 1, 4, 1], &[3, 1], 1).unwrap(), [3, 4]);
+        assert!(remove_axes(&[3, 2, 4], &[1], 1).is_err());
+        assert!(remove_axes(&[3, 4], &[2], 1).is_err());
+    }
+
+    #[test]
+    fn codec_expand_dims_round_trip() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(2).unwrap(), NonZeroU64::new(3).unwrap()],
+            DataType::UInt16,
+            FillValue::from(0u16),
+        )
+        .unwrap();
+        let elements: Vec<u16> = (0..6).collect();
+        let bytes: ArrayBytes = crate::array::transmute_to_bytes_vec(elements).into();
+
+        let codec = ExpandDimsCodec::new(vec![2, 0]).unwrap();
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        assert_eq!(encoded_representation.shape_u64(), [1, 2, 1, 3]);
+        assert_eq!(
+            codec
+                .compute_decoded_shape(encoded_representation.shape().into())
+                .unwrap()
+                .to_array_shape(),
+            [2, 3]
+        );
+
+        let encoded = codec
+            .encode(
+                bytes.clone(),
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded);
+        assert_eq!(
+            codec.create_metadata().unwrap().name(),
+            "https://codec.zarrs.dev/array_to_array/expand_dims"
+        );
+    }
+
+    #[test]
+    fn codec_expand_dims_partial_decode() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(4).unwrap(), NonZeroU64::new(4).unwrap()],
+            DataType::UInt8,
+            FillValue::from(0u8),
+        )
+        .unwrap();
+        let elements: Vec<u8> = (0..16).collect();
+        let bytes: ArrayBytes = elements.into();
+
+        let codec = Arc::new(ExpandDimsCodec::new(vec![1]).unwrap());
+        let encoded_representation = codec.compute_encoded_size(&chunk_representation).unwrap();
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let encoded = Arc::new(BytesCodec::default())
+            .encode(encoded, &encoded_representation, &CodecOptions::default())
+            .unwrap()
+            .into_owned();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let bytes_partial_decoder = Arc::new(BytesCodec::default())
+            .partial_decoder(
+                input_handle,
+                &encoded_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let partial_decoder = codec
+            .partial_decoder(
+                bytes_partial_decoder,
+                &chunk_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_regions = [ArraySubset::new_with_ranges(&[1..3, 2..4])];
+        let decoded = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(
+            decoded[0].clone().into_fixed().unwrap().as_ref(),
+            [6, 7, 10, 11]
+        );
+        assert!(partial_decoder
+            .partial_decode(
+                &[ArraySubset::new_with_ranges(&[0..1, 0..1, 0..1])],
+                &CodecOptions::default()
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{
+            options::CodecOptions, ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits,
+            ArrayPartialEncoderTraits, ArrayToArrayCodecTraits, ArrayToArrayPartialEncoderDefault,
+            CodecError, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, ChunkRepresentation, ChunkShape,
+    },
+    config::global_config,
+    metadata::v3::{array::codec::expand_dims::ExpandDimsCodecConfigurationV1, MetadataV3},
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncArrayPartialDecoderTraits;
+
+use super::{insert_axes, remove_axes, ExpandDimsCodecConfiguration, IDENTIFIER};
+
+/// An `expand_dims` codec implementation.
+#[derive(Clone, Debug)]
+pub struct ExpandDimsCodec {
+    axes: Vec<usize>,
+}
+
+impl ExpandDimsCodec {
+    /// Create a new `expand_dims` codec from configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginCreateError`] if there is a configuration issue.
+    pub fn new_with_configuration(
+        configuration: &ExpandDimsCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let ExpandDimsCodecConfiguration::V1(configuration) = configuration;
+        Self::new(configuration.axes.clone())
+    }
+
+    /// Create a new `expand_dims` codec.
+    ///
+    /// `axes` are the positions of the inserted singleton axes in the encoded representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginCreateError`] if `axes` are not unique.
+    pub fn new(axes: Vec<usize>) -> Result<Self, PluginCreateError> {
+        if axes
+            .iter()
+            .enumerate()
+            .any(|(i, axis)| axes[..i].contains(axis))
+        {
+            return Err(PluginCreateError::Other(
+                "expand_dims axes must be unique".to_string(),
+            ));
+        }
+        Ok(Self { axes })
+    }
+}
+
+impl CodecTraits for ExpandDimsCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = ExpandDimsCodecConfigurationV1 {
+            axes: self.axes.clone(),
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .expect("expand_dims configuration is valid json"),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl ArrayToArrayCodecTraits for ExpandDimsCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToArrayCodecTraits> {
+        self as Arc<dyn ArrayToArrayCodecTraits>
+    }
+
+    fn compute_decoded_shape(&self, encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
+        Ok(remove_axes(&encoded_shape, &self.axes, std::num::NonZeroU64::MIN)?.into())
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        bytes.validate(
+            decoded_representation.num_elements(),
+            decoded_representation.data_type().size(),
+        )?;
+        Ok(bytes)
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        bytes.validate(
+            decoded_representation.num_elements(),
+            decoded_representation.data_type().size(),
+        )?;
+        Ok(bytes)
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            super::expand_dims_partial_decoder::ExpandDimsPartialDecoder::new(
+                input_handle,
+                decoded_representation.clone(),
+                self.axes.clone(),
+            ),
+        ))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            super::expand_dims_partial_decoder::AsyncExpandDimsPartialDecoder::new(
+                input_handle,
+                decoded_representation.clone(),
+                self.axes.clone(),
+            ),
+        ))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ChunkRepresentation, CodecError> {
+        let encoded_shape = insert_axes(
+            decoded_representation.shape(),
+            &self.axes,
+            std::num::NonZeroU64::MIN,
+        )?;
+        Ok(unsafe {
+            ChunkRepresentation::new_unchecked(
+                encoded_shape,
+                decoded_representation.data_type().clone(),
+                decoded_representation.fill_value().clone(),
+            )
+        })
+    }
+}
+
+impl ArrayCodecTraits for ExpandDimsCodec {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use super::insert_axes;
+use crate::array::{
+    codec::{ArrayBytes, ArrayPartialDecoderTraits, ArraySubset, CodecError, CodecOptions},
+    ChunkRepresentation, DataType,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncArrayPartialDecoderTraits;
+
+/// Partial decoder for the `expand_dims` codec.
+pub(crate) struct ExpandDimsPartialDecoder {
+    input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    axes: Vec<usize>,
+}
+
+impl ExpandDimsPartialDecoder {
+    /// Create a new partial decoder for the `expand_dims` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        axes: Vec<usize>,
+    ) -> Self {
+        Self {
+            input_handle,
+            decoded_representation,
+            axes,
+        }
+    }
+}
+
+/// Map each decoded region to the encoded region with the singleton axes inserted.
+fn get_encoded_regions(
+    decoded_regions: &[ArraySubset],
+    dimensionality: usize,
+    axes: &[usize],
+) -> Result<Vec<ArraySubset>, CodecError> {
+    decoded_regions
+        .iter()
+        .map(|decoded_region| {
+            if decoded_region.dimensionality() != dimensionality {
+                return Err(CodecError::InvalidArraySubsetDimensionalityError(
+                    decoded_region.clone(),
+                    dimensionality,
+                ));
+            }
+            let start = insert_axes(decoded_region.start(), axes, 0)?;
+            let shape = insert_axes(decoded_region.shape(), axes, 1)?;
+            Ok(unsafe { ArraySubset::new_with_start_shape_unchecked(start, shape) })
+        })
+        .collect()
+}
+
+impl ArrayPartialDecoderTraits for ExpandDimsPartialDecoder {
+    fn data_type(&self) -> &DataType {
+        self.decoded_representation.data_type()
+    }
+
+    fn partial_decode(
+        &self,
+        decoded_regions: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let encoded_regions = get_encoded_regions(
+            decoded_regions,
+            self.decoded_representation.dimensionality(),
+            &self.axes,
+        )?;
+        self.input_handle.partial_decode(&encoded_regions, options)
+    }
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial decoder for the `expand_dims` codec.
+pub(crate) struct AsyncExpandDimsPartialDecoder {
+    input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    axes: Vec<usize>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncExpandDimsPartialDecoder {
+    /// Create a new partial decoder for the `expand_dims` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        axes: Vec<usize>,
+    ) -> Self {
+        Self {
+            input_handle,
+            decoded_representation,
+            axes,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncArrayPartialDecoderTraits for AsyncExpandDimsPartialDecoder {
+    fn data_type(&self) -> &DataType {
+        self.decoded_representation.data_type()
+    }
+
+    async fn partial_decode(
+        &self,
+        decoded_regions: &[ArraySubset],
+        options: &CodecOptions,
+    ) -> Result<Vec<ArrayBytes<'_>>, CodecError> {
+        let encoded_regions = get_encoded_regions(
+            decoded_regions,
+            self.decoded_representation.dimensionality(),
+            &self.axes,
+        )?;
+        self.input_handle
+            .partial_decode(&encoded_regions, options)
+            .await
+    }
+}
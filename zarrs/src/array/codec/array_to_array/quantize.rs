@@ -0,0 +1,380 @@
+//! The `quantize` array to array codec.
+//!
+//! Rounds floating point data to a number of decimal digits, as in the `numcodecs` `quantize` codec.
+//! Elements are rounded to the nearest multiple of the smallest power of two reciprocal that is at most `10^-digits`, so the absolute error is at most half of `10^-digits`.
+//! Encoding applies the rounding, and decoding passes elements through unchanged.
+//! The rounded data generally compresses better with a subsequent lossless codec.
+//!
+//! <div class="warning">
+//! This codec is lossy.
+//! It is also experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `quantize` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `quantize` codec (`numcodecs.quantize` in Zarr V3 and `quantize` in Zarr V2).
+//! Only the `float32` and `float64` data types are supported, and `astype` must match `dtype`.
+//!
+//! See [`QuantizeCodecConfigurationV1`] for example `JSON` metadata.
+
+mod quantize_codec;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::quantize::{
+    QuantizeCodecConfiguration, QuantizeCodecConfigurationV1,
+};
+pub use quantize_codec::QuantizeCodec;
+
+use crate::{
+    array::{
+        codec::{Codec, CodecError, CodecPlugin},
+        DataType,
+    },
+    config::global_config,
+    metadata::v3::{array::codec::quantize, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use quantize::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_quantize, create_codec_quantize)
+}
+
+fn is_name_quantize(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_quantize(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: QuantizeCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(QuantizeCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::ArrayToArray(codec))
+}
+
+/// Return the data type of a `numpy` data type string (e.g. `<f4`), or [`None`] if it is not supported.
+fn quantize_data_type(dtype: &str) -> Option<DataType> {
+    match dtype.trim_start_matches(['<', '>', '=', '|']) {
+        "f4" | "float32" => Some(DataType::Float32),
+        "f8" | "float64" => Some(DataType::Float64),
+        _ => None,
+    }
+}
+
+/// Return the scale of the `numcodecs` `quantize` codec for `digits` decimal digits.
+///
+/// This is the smallest power of two that is at least `10^digits`, computed exactly as in `numcodecs`.
+fn quantize_scale(digits: i32) -> f64 {
+    let precision = 10f64.powf(-f64::from(digits));
+    let exp = precision.ln() / 10f64.ln();
+    let exp = if exp < 0.0 { exp.floor() } else { exp.ceil() };
+    let bits = (10f64.powf(-exp).ln() / 2f64.ln()).ceil();
+    2f64.powf(bits)
+}
+
+/// Round the elements in `bytes` to `digits` decimal digits.
+///
+/// As in `numpy.around`, halfway values are rounded to even.
+fn quantize_bytes(bytes: &mut [u8], data_type: &DataType, digits: i32) -> Result<(), CodecError> {
+    let scale = quantize_scale(digits);
+    match data_type {
+        DataType::Float32 => {
+            #[allow(clippy::cast_possible_truncation)]
+            let scale = scale as f32;
+            let quantize = |chunk: &mut [u8]| {
+                let element = f32::from_ne_bytes(chunk.try_into().unwrap());
+                let element = (element * scale).round_ties_even() / scale;
+                chunk.copy_from_slice(&element.to_ne_bytes());
+            };
+            bytes.chunks_exact_mut(4).for_each(quantize);
+            Ok(())
+        }
+        DataType::Float64 => {
+            let quantize = |chunk: &mut [u8]| {
+                let element = f64::from_ne_bytes(chunk.try_into().unwrap());
+                let element = (element * scale).round_ties_even() / scale;
+                chunk.copy_from_slice(&element.to_ne_bytes());
+            };
+            bytes.chunks_exact_mut(8).for_each(quantize);
+            Ok(())
+        }
+        _ => Err(CodecError::UnsupportedDataType(
+            data_type.clone(),
+            IDENTIFIER.to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use crate::{
+        array::{
+            codec::{ArrayToArrayCodecTraits, CodecOptions},
+            Array, ArrayBuilder, ArrayBytes, ArrayCreateError, ArrayMetadata, ChunkRepresentation,
+            FillValue,
+        },
+        storage::store::MemoryStore,
+    };
+
+    use super::*;
+
+    const VALUES: [f64; 12] = [
+        0.0,
+        -0.0,
+        0.03125,
+        0.09375,
+        1.0 / 3.0,
+        -2.0 / 3.0,
+        3.316_624_790_355_4,
+        -2.645_751_311_064_590_7,
+        123.456_789,
+        -9_876.543_21,
+        1.0e-5,
+        65_504.5,
+    ];
+
+    /// The bit patterns of `numcodecs.Quantize(digits, dtype="<f8").encode(VALUES)`.
+    #[rustfmt::skip]
+    const NUMCODECS_FLOAT64: [(i32, [u64; 12]); 5] = [
+        (0, [0x0000_0000_0000_0000, 0x8000_0000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000, 0xbff0_0000_0000_0000, 0x4008_0000_0000_0000, 0xc008_0000_0000_0000, 0x405e_c000_0000_0000, 0xc0c3_4a80_0000_0000, 0x0000_0000_0000_0000, 0x40ef_fc00_0000_0000]),
+        (1, [0x0000_0000_0000_0000, 0x8000_0000_0000_0000, 0x0000_0000_0000_0000, 0x3fc0_0000_0000_0000, 0x3fd4_0000_0000_0000, 0xbfe6_0000_0000_0000, 0x400a_8000_0000_0000, 0xc005_0000_0000_0000, 0x405e_dc00_0000_0000, 0xc0c3_4a48_0000_0000, 0x0000_0000_0000_0000, 0x40ef_fc10_0000_0000]),
+        (2, [0x0000_0000_0000_0000, 0x8000_0000_0000_0000, 0x3fa0_0000_0000_0000, 0x3fb8_0000_0000_0000, 0x3fd5_8000_0000_0000, 0xbfe5_4000_0000_0000, 0x400a_9000_0000_0000, 0xc005_3000_0000_0000, 0x405e_dd00_0000_0000, 0xc0c3_4a46_0000_0000, 0x0000_0000_0000_0000, 0x40ef_fc10_0000_0000]),
+        (3, [0x0000_0000_0000_0000, 0x8000_0000_0000_0000, 0x3fa0_0000_0000_0000, 0x3fb8_0000_0000_0000, 0x3fd5_5000_0000_0000, 0xbfe5_5800_0000_0000, 0x400a_8800_0000_0000, 0xc005_2a00_0000_0000, 0x405e_dd40_0000_0000, 0xc0c3_4a45_8000_0000, 0x0000_0000_0000_0000, 0x40ef_fc10_0000_0000]),
+        (5, [0x0000_0000_0000_0000, 0x8000_0000_0000_0000, 0x3fa0_0000_0000_0000, 0x3fb8_0000_0000_0000, 0x3fd5_5560_0000_0000, 0xbfe5_5550_0000_0000, 0x400a_8874_0000_0000, 0xc005_2a80_0000_0000, 0x405e_dd3c_0000_0000, 0xc0c3_4a45_8800_0000, 0x3ee0_0000_0000_0000, 0x40ef_fc10_0000_0000]),
+    ];
+
+    /// The bit patterns of `numcodecs.Quantize(digits, dtype="<f4").encode(VALUES.astype("<f4"))`.
+    #[rustfmt::skip]
+    const NUMCODECS_FLOAT32: [(i32, [u32; 12]); 5] = [
+        (0, [0x0000_0000, 0x8000_0000, 0x0000_0000, 0x0000_0000, 0x0000_0000, 0xbf80_0000, 0x4040_0000, 0xc040_0000, 0x42f6_0000, 0xc61a_5400, 0x0000_0000, 0x477f_e000]),
+        (1, [0x0000_0000, 0x8000_0000, 0x0000_0000, 0x3e00_0000, 0x3ea0_0000, 0xbf30_0000, 0x4054_0000, 0xc028_0000, 0x42f6_e000, 0xc61a_5240, 0x0000_0000, 0x477f_e080]),
+        (2, [0x0000_0000, 0x8000_0000, 0x3d00_0000, 0x3dc0_0000, 0x3eac_0000, 0xbf2a_0000, 0x4054_8000, 0xc029_8000, 0x42f6_e800, 0xc61a_5230, 0x0000_0000, 0x477f_e080]),
+        (3, [0x0000_0000, 0x8000_0000, 0x3d00_0000, 0x3dc0_0000, 0x3eaa_8000, 0xbf2a_c000, 0x4054_4000, 0xc029_5000, 0x42f6_ea00, 0xc61a_522c, 0x0000_0000, 0x477f_e080]),
+        (5, [0x0000_0000, 0x8000_0000, 0x3d00_0000, 0x3dc0_0000, 0x3eaa_ab00, 0xbf2a_aa80, 0x4054_43a0, 0xc029_5400, 0x42f6_e9e0, 0xc61a_522c, 0x3700_0000, 0x477f_e080]),
+    ];
+
+    fn chunk_representation(data_type: DataType, num_elements: u64) -> ChunkRepresentation {
+        let fill_value = match data_type {
+            DataType::Float32 => FillValue::from(0.0f32),
+            DataType::Int32 => FillValue::from(0i32),
+            _ => FillValue::from(0.0f64),
+        };
+        ChunkRepresentation::new(
+            vec![NonZeroU64::new(num_elements).unwrap()],
+            data_type,
+            fill_value,
+        )
+        .unwrap()
+    }
+
+    fn encode<T: bytemuck::Pod>(
+        codec: &QuantizeCodec,
+        data_type: DataType,
+        elements: &[T],
+    ) -> Vec<T> {
+        let representation = chunk_representation(data_type, elements.len() as u64);
+        let bytes = ArrayBytes::from(crate::array::transmute_to_bytes(elements));
+        let encoded = codec
+            .encode(bytes, &representation, &CodecOptions::default())
+            .unwrap();
+        let decoded = codec
+            .decode(encoded.clone(), &representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(encoded, decoded);
+        crate::array::transmute_from_bytes_vec(encoded.into_fixed().unwrap().into_owned())
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn codec_quantize_scale() {
+        for (digits, scale) in [
+            (-2, 0.015_625),
+            (-1, 0.125),
+            (0, 1.0),
+            (1, 16.0),
+            (2, 128.0),
+            (3, 1024.0),
+            (6, 1_048_576.0),
+            (7, 16_777_216.0),
+        ] {
+            assert_eq!(quantize_scale(digits), scale);
+        }
+    }
+
+    #[test]
+    fn codec_quantize_numcodecs_float64() {
+        for (digits, expected) in NUMCODECS_FLOAT64 {
+            let codec = QuantizeCodec::new(digits, DataType::Float64).unwrap();
+            let encoded = encode(&codec, DataType::Float64, &VALUES);
+            let encoded: Vec<u64> = encoded.iter().map(|element| element.to_bits()).collect();
+            assert_eq!(encoded, expected, "digits: {digits}");
+        }
+    }
+
+    #[test]
+    fn codec_quantize_numcodecs_float32() {
+        #[allow(clippy::cast_possible_truncation)]
+        let values: Vec<f32> = VALUES.iter().map(|&value| value as f32).collect();
+        for (digits, expected) in NUMCODECS_FLOAT32 {
+            let codec = QuantizeCodec::new(digits, DataType::Float32).unwrap();
+            let encoded = encode(&codec, DataType::Float32, &values);
+            let encoded: Vec<u32> = encoded.iter().map(|element| element.to_bits()).collect();
+            assert_eq!(encoded, expected, "digits: {digits}");
+        }
+    }
+
+    #[test]
+    #[allow(clippy::unreadable_literal)]
+    fn codec_quantize_numcodecs_docs() {
+        // The example in the documentation of `numcodecs.Quantize`
+        let values: Vec<f64> = (0..10).map(|i| f64::from(i) / 9.0).collect();
+        for (digits, expected) in [
+            (
+                1,
+                [
+                    0.0, 0.125, 0.25, 0.3125, 0.4375, 0.5625, 0.6875, 0.75, 0.875, 1.0,
+                ],
+            ),
+            (
+                2,
+                [
+                    0.0, 0.109375, 0.21875, 0.3359375, 0.4453125, 0.5546875, 0.6640625, 0.78125,
+                    0.890625, 1.0,
+                ],
+            ),
+        ] {
+            let configuration: QuantizeCodecConfiguration = serde_json::from_str(&format!(
+                r#"{{"digits": {digits}, "dtype": "<f8", "astype": "<f8"}}"#
+            ))
+            .unwrap();
+            let codec = QuantizeCodec::new_with_configuration(&configuration).unwrap();
+            assert_eq!(encode(&codec, DataType::Float64, &values), expected);
+        }
+    }
+
+    #[test]
+    fn codec_quantize_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        // Values spanning several orders of magnitude
+        let mut state = 0x2545_f491_u64;
+        let values: Vec<f64> = (0..256)
+            .map(|i| {
+                state = state
+                    .wrapping_mul(6_364_136_223_846_793_005)
+                    .wrapping_add(1);
+                #[allow(clippy::cast_precision_loss)]
+                let unit = (state >> 11) as f64 / (1u64 << 53) as f64;
+                (unit - 0.5) * 10f64.powi(i % 7 - 2)
+            })
+            .collect();
+        for digits in [0, 1, 3, 5] {
+            let bound = 0.5 * 10f64.powi(-digits);
+            for data_type in [DataType::Float32, DataType::Float64] {
+                let fill_value = if data_type == DataType::Float32 {
+                    FillValue::from(0.0f32)
+                } else {
+                    FillValue::from(0.0f64)
+                };
+                let array = ArrayBuilder::new(
+                    vec![256],
+                    data_type.clone(),
+                    vec![64].try_into()?,
+                    fill_value,
+                )
+                .array_to_array_codecs(vec![Arc::new(QuantizeCodec::new(
+                    digits,
+                    data_type.clone(),
+                )?)])
+                .build(Arc::new(MemoryStore::new()), "/")?;
+                if data_type == DataType::Float32 {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let values: Vec<f32> = values.iter().map(|&value| value as f32).collect();
+                    array.store_array_subset_elements(&array.subset_all(), &values)?;
+                    let decoded =
+                        array.retrieve_array_subset_elements::<f32>(&array.subset_all())?;
+                    for (value, decoded) in values.iter().zip(decoded) {
+                        assert!(f64::from((value - decoded).abs()) <= bound);
+                    }
+                } else {
+                    array.store_array_subset_elements(&array.subset_all(), &values)?;
+                    let decoded =
+                        array.retrieve_array_subset_elements::<f64>(&array.subset_all())?;
+                    for (value, decoded) in values.iter().zip(decoded) {
+                        assert!((value - decoded).abs() <= bound);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn codec_quantize_unsupported_data_type() {
+        assert!(QuantizeCodec::new(2, DataType::Int32).is_err());
+        let codec = QuantizeCodec::new(2, DataType::Float64).unwrap();
+        for data_type in [DataType::Int32, DataType::Float32] {
+            let representation = chunk_representation(data_type, 2);
+            let bytes = ArrayBytes::from(vec![0u8; 8]);
+            assert!(codec
+                .encode(bytes, &representation, &CodecOptions::default())
+                .is_err());
+        }
+        let builder = ArrayBuilder::new(
+            vec![4],
+            DataType::Int32,
+            vec![2].try_into().unwrap(),
+            FillValue::from(0i32),
+        )
+        .array_to_array_codecs(vec![Arc::new(codec)])
+        .build(Arc::new(MemoryStore::new()), "/");
+        assert!(matches!(
+            builder,
+            Err(ArrayCreateError::CodecIncompatibleWithDataType(_))
+        ));
+    }
+
+    #[test]
+    fn codec_quantize_invalid_configuration() {
+        for json in [
+            r#"{"digits": 2, "dtype": "<i4"}"#,
+            r#"{"digits": 2, "dtype": "<f8", "astype": "<f4"}"#,
+        ] {
+            let configuration: QuantizeCodecConfiguration = serde_json::from_str(json).unwrap();
+            assert!(QuantizeCodec::new_with_configuration(&configuration).is_err());
+        }
+    }
+
+    #[test]
+    fn codec_quantize_v2() -> Result<(), Box<dyn std::error::Error>> {
+        let metadata: ArrayMetadata = serde_json::from_str(
+            r#"{
+                "zarr_format": 2,
+                "shape": [4],
+                "chunks": [4],
+                "dtype": "<f8",
+                "compressor": null,
+                "fill_value": 0.0,
+                "order": "C",
+                "filters": [{"id": "quantize", "digits": 1, "dtype": "<f8", "astype": "<f8"}]
+            }"#,
+        )?;
+        let array = Array::new_with_metadata(Arc::new(MemoryStore::new()), "/", metadata)?;
+        array.store_array_subset_elements::<f64>(&array.subset_all(), &VALUES[2..6])?;
+        assert_eq!(
+            array.retrieve_array_subset_elements::<f64>(&array.subset_all())?,
+            [0.0, 0.125, 0.3125, -0.6875]
+        );
+        let filters = array.to_v2_metadata()?.filters.unwrap();
+        assert_eq!(filters[0].id(), IDENTIFIER);
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{
+            options::CodecOptions, ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderTraits,
+            ArrayPartialEncoderTraits, ArrayToArrayCodecTraits, ArrayToArrayPartialEncoderDefault,
+            CodecError, CodecIncompatibleWithDataTypeError, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, ChunkRepresentation, ChunkShape, DataType,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncArrayPartialDecoderTraits;
+
+use super::{
+    quantize_bytes, quantize_data_type, QuantizeCodecConfiguration, QuantizeCodecConfigurationV1,
+    IDENTIFIER,
+};
+
+/// A `quantize` codec implementation.
+///
+/// <div class="warning">
+/// This codec is lossy. Encoded elements are rounded to the configured number of decimal digits, and decoding does not restore them.
+/// </div>
+#[derive(Clone, Debug)]
+pub struct QuantizeCodec {
+    digits: i32,
+    data_type: DataType,
+}
+
+impl QuantizeCodec {
+    /// Create a new `quantize` codec.
+    ///
+    /// `digits` is the number of decimal digits to keep.
+    /// `data_type` is the data type of the array, which must be [`DataType::Float32`] or [`DataType::Float64`].
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `data_type` is not supported.
+    pub fn new(digits: i32, data_type: DataType) -> Result<Self, PluginCreateError> {
+        match data_type {
+            DataType::Float32 | DataType::Float64 => Ok(Self { digits, data_type }),
+            _ => Err(PluginCreateError::Other(format!(
+                "quantize data type {data_type} is not supported"
+            ))),
+        }
+    }
+
+    /// Create a new `quantize` codec from a configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `dtype` is not `float32` or `float64`, or `astype` does not match `dtype`.
+    pub fn new_with_configuration(
+        configuration: &QuantizeCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let QuantizeCodecConfiguration::V1(configuration) = configuration;
+        let data_type = quantize_data_type(&configuration.dtype).ok_or_else(|| {
+            PluginCreateError::Other(format!(
+                "quantize dtype {} is not supported",
+                configuration.dtype
+            ))
+        })?;
+        if let Some(astype) = &configuration.astype {
+            if quantize_data_type(astype).as_ref() != Some(&data_type) {
+                return Err(PluginCreateError::Other(format!(
+                    "quantize astype {astype} must match dtype {}",
+                    configuration.dtype
+                )));
+            }
+        }
+        Self::new(configuration.digits, data_type)
+    }
+
+    /// Return the number of decimal digits to keep.
+    #[must_use]
+    pub const fn digits(&self) -> i32 {
+        self.digits
+    }
+}
+
+impl CodecTraits for QuantizeCodec {
+    fn create_metadata_opt(&self, options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        if options.experimental_codec_store_metadata_if_encode_only() {
+            let dtype = match self.data_type {
+                DataType::Float32 => "<f4",
+                _ => "<f8",
+            };
+            let configuration = QuantizeCodecConfigurationV1 {
+                digits: self.digits,
+                dtype: dtype.to_string(),
+                astype: Some(dtype.to_string()),
+            };
+            Some(
+                MetadataV3::new_with_serializable_configuration(
+                    global_config()
+                        .experimental_codec_names()
+                        .get(super::IDENTIFIER)
+                        .expect("experimental codec identifier in global map"),
+                    &configuration,
+                )
+                .unwrap(),
+            )
+        } else {
+            None
+        }
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+impl ArrayCodecTraits for QuantizeCodec {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl ArrayToArrayCodecTraits for QuantizeCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToArrayCodecTraits> {
+        self as Arc<dyn ArrayToArrayCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        self.compute_encoded_size(decoded_representation)?;
+        let mut bytes = bytes.into_fixed()?;
+        quantize_bytes(
+            bytes.to_mut(),
+            decoded_representation.data_type(),
+            self.digits,
+        )?;
+        Ok(ArrayBytes::from(bytes))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        _decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        Ok(bytes)
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        _decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        // Decoding is a passthrough
+        Ok(input_handle)
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn ArrayPartialDecoderTraits>,
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayToArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncArrayPartialDecoderTraits>,
+        _decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        // Decoding is a passthrough
+        Ok(input_handle)
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ChunkRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if data_type == &self.data_type {
+            Ok(decoded_representation.clone())
+        } else {
+            Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ))
+        }
+    }
+
+    fn compute_decoded_shape(&self, encoded_shape: ChunkShape) -> Result<ChunkShape, CodecError> {
+        Ok(encoded_shape)
+    }
+
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        if self.compute_encoded_size(decoded_representation).is_ok() {
+            vec![]
+        } else {
+            vec![CodecIncompatibleWithDataTypeError::new(
+                IDENTIFIER,
+                decoded_representation.data_type().clone(),
+                format!(
+                    "only the {} data type of the codec configuration is supported",
+                    self.data_type
+                ),
+            )]
+        }
+    }
+}
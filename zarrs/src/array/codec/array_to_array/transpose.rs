@@ -6,6 +6,7 @@
 
 mod transpose_codec;
 mod transpose_partial_decoder;
+mod transpose_partial_encoder;
 
 use std::sync::Arc;
 
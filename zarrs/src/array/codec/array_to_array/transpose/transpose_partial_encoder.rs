@@ -0,0 +1,96 @@
+use std::{num::NonZeroU64, sync::Arc};
+
+use super::{permute, TransposeCodec, TransposeOrder};
+use crate::array::{
+    codec::{
+        ArrayBytes, ArrayPartialEncoderTraits, ArraySubset, ArrayToArrayCodecTraits, CodecError,
+        CodecOptions,
+    },
+    ChunkRepresentation,
+};
+
+/// Partial encoder for the Transpose codec.
+///
+/// Each chunk subset is transposed and written to the transposed subset of the encoded chunk.
+pub(crate) struct TransposePartialEncoder {
+    output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    codec: Arc<TransposeCodec>,
+    order: TransposeOrder,
+}
+
+impl TransposePartialEncoder {
+    /// Create a new partial encoder for the Transpose codec.
+    pub(crate) fn new(
+        output_handle: Arc<dyn ArrayPartialEncoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        codec: Arc<TransposeCodec>,
+        order: TransposeOrder,
+    ) -> Self {
+        Self {
+            output_handle,
+            decoded_representation,
+            codec,
+            order,
+        }
+    }
+}
+
+impl ArrayPartialEncoderTraits for TransposePartialEncoder {
+    fn erase(&self) -> Result<(), CodecError> {
+        self.output_handle.erase()
+    }
+
+    fn partial_encode(
+        &self,
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        let dimensionality = self.decoded_representation.dimensionality();
+        let mut encoded_subsets = Vec::with_capacity(subsets_and_bytes.len());
+        let mut encoded_bytes = Vec::with_capacity(subsets_and_bytes.len());
+        for (chunk_subset, chunk_subset_bytes) in subsets_and_bytes {
+            if chunk_subset.dimensionality() != dimensionality {
+                return Err(CodecError::InvalidArraySubsetDimensionalityError(
+                    (*chunk_subset).clone(),
+                    dimensionality,
+                ));
+            }
+            let Some(chunk_subset_shape) = chunk_subset
+                .shape()
+                .iter()
+                .map(|s| NonZeroU64::new(*s))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+
+            // SAFETY: the data type and fill value are from the decoded representation
+            let chunk_subset_representation = unsafe {
+                ChunkRepresentation::new_unchecked(
+                    chunk_subset_shape,
+                    self.decoded_representation.data_type().clone(),
+                    self.decoded_representation.fill_value().clone(),
+                )
+            };
+            encoded_bytes.push(self.codec.encode(
+                chunk_subset_bytes.clone(),
+                &chunk_subset_representation,
+                options,
+            )?);
+
+            let start = permute(chunk_subset.start(), &self.order.0);
+            let shape = permute(chunk_subset.shape(), &self.order.0);
+            // SAFETY: the dimensionality of start and shape match
+            encoded_subsets
+                .push(unsafe { ArraySubset::new_with_start_shape_unchecked(start, shape) });
+        }
+
+        let encoded_subsets_and_bytes = encoded_subsets
+            .iter()
+            .zip(encoded_bytes)
+            .collect::<Vec<_>>();
+        self.output_handle
+            .partial_encode(&encoded_subsets_and_bytes, options)
+    }
+}
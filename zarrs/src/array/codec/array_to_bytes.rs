@@ -2,6 +2,7 @@
 
 pub mod bytes;
 pub mod codec_chain;
+pub mod image;
 pub mod vlen;
 pub mod vlen_array;
 pub mod vlen_bytes;
@@ -9,9 +10,13 @@ pub mod vlen_utf8;
 
 pub(crate) mod vlen_v2;
 
+#[cfg(feature = "jpegxl")]
+pub mod jpegxl;
 #[cfg(feature = "pcodec")]
 pub mod pcodec;
 #[cfg(feature = "sharding")]
 pub mod sharding;
+#[cfg(feature = "webp")]
+pub mod webp;
 #[cfg(feature = "zfp")]
 pub mod zfp;
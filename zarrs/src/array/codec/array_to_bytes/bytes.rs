@@ -6,6 +6,7 @@
 
 mod bytes_codec;
 mod bytes_partial_decoder;
+mod bytes_partial_encoder;
 
 use std::sync::Arc;
 
@@ -49,28 +50,73 @@ pub(crate) fn create_codec_bytes(metadata: &MetadataV3) -> Result<Codec, PluginC
 /// Reverse the endianness of bytes for a given data type.
 pub(crate) fn reverse_endianness(v: &mut [u8], data_type: &DataType) {
     match data_type {
-        DataType::Bool | DataType::Int8 | DataType::UInt8 | DataType::RawBits(_) => {}
-        DataType::Int16 | DataType::UInt16 | DataType::Float16 | DataType::BFloat16 => {
+        DataType::Bool
+        | DataType::Int8
+        | DataType::UInt8
+        | DataType::RawBits(_)
+        | DataType::FixedLengthUtf8(_)
+        | DataType::FixedLengthBytes(_)
+        | DataType::Float4E2M1FN
+        | DataType::Float6E2M3FN
+        | DataType::Float6E3M2FN
+        | DataType::Float8E3M4
+        | DataType::Float8E4M3
+        | DataType::Float8E4M3B11FNUZ
+        | DataType::Float8E4M3FNUZ
+        | DataType::Float8E5M2
+        | DataType::Float8E5M2FNUZ
+        | DataType::Float8E8M0FNU => {}
+        DataType::Int16
+        | DataType::UInt16
+        | DataType::Float16
+        | DataType::BFloat16
+        | DataType::ComplexFloat16
+        | DataType::ComplexBFloat16 => {
             let swap = |chunk: &mut [u8]| {
                 let bytes = u16::from_ne_bytes(unsafe { chunk.try_into().unwrap_unchecked() });
                 chunk.copy_from_slice(bytes.swap_bytes().to_ne_bytes().as_slice());
             };
             v.chunks_exact_mut(2).for_each(swap);
         }
-        DataType::Int32 | DataType::UInt32 | DataType::Float32 | DataType::Complex64 => {
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::ComplexFloat32
+        | DataType::Complex64 => {
             let swap = |chunk: &mut [u8]| {
                 let bytes = u32::from_ne_bytes(unsafe { chunk.try_into().unwrap_unchecked() });
                 chunk.copy_from_slice(bytes.swap_bytes().to_ne_bytes().as_slice());
             };
             v.chunks_exact_mut(4).for_each(swap);
         }
-        DataType::Int64 | DataType::UInt64 | DataType::Float64 | DataType::Complex128 => {
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Complex128
+        | DataType::NumpyDateTime64 { .. }
+        | DataType::NumpyTimeDelta64 { .. } => {
             let swap = |chunk: &mut [u8]| {
                 let bytes = u64::from_ne_bytes(unsafe { chunk.try_into().unwrap_unchecked() });
                 chunk.copy_from_slice(bytes.swap_bytes().to_ne_bytes().as_slice());
             };
             v.chunks_exact_mut(8).for_each(swap);
         }
+        DataType::Structured(configuration) => {
+            let fields = configuration
+                .fields()
+                .iter()
+                .filter_map(|field| {
+                    let data_type = DataType::from_metadata(&field.data_type).ok()?;
+                    let size = data_type.fixed_size()?;
+                    Some((field.offset..field.offset + size, data_type))
+                })
+                .collect::<Vec<_>>();
+            for record in v.chunks_exact_mut(configuration.size()) {
+                for (range, data_type) in &fields {
+                    reverse_endianness(&mut record[range.clone()], data_type);
+                }
+            }
+        }
         // Variable-sized data types are not supported and are rejected outside of this function
         DataType::String | DataType::Bytes => unreachable!(),
     }
@@ -245,6 +291,160 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn codec_bytes_round_trip_float8() {
+        codec_bytes_round_trip_impl(None, DataType::Float8E4M3, FillValue::from(0u8)).unwrap();
+        codec_bytes_round_trip_impl(
+            Some(Endianness::Big),
+            DataType::Float8E5M2,
+            FillValue::from(0u8),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_bytes_structured() {
+        use crate::metadata::v3::array::data_type::{
+            DataTypeMetadataV3, StructuredDataTypeConfiguration,
+        };
+
+        let data_type = DataType::Structured(
+            StructuredDataTypeConfiguration::new_packed([
+                ("a".to_string(), DataTypeMetadataV3::Int16),
+                ("b".to_string(), DataTypeMetadataV3::UInt8),
+                ("c".to_string(), DataTypeMetadataV3::Complex64),
+            ])
+            .unwrap(),
+        );
+        let fill_value = FillValue::new(vec![0; 11]);
+        codec_bytes_round_trip_impl(Some(Endianness::Big), data_type.clone(), fill_value.clone())
+            .unwrap();
+        assert!(codec_bytes_round_trip_impl(None, data_type.clone(), fill_value).is_err());
+
+        // Fields are reversed individually
+        let mut bytes = (0..11).collect::<Vec<u8>>();
+        reverse_endianness(&mut bytes, &data_type);
+        assert_eq!(bytes, [1, 0, 2, 6, 5, 4, 3, 10, 9, 8, 7]);
+
+        // Single byte fields have no endianness
+        let data_type = DataType::Structured(
+            StructuredDataTypeConfiguration::new_packed([
+                ("a".to_string(), DataTypeMetadataV3::UInt8),
+                ("b".to_string(), DataTypeMetadataV3::Bool),
+            ])
+            .unwrap(),
+        );
+        codec_bytes_round_trip_impl(None, data_type, FillValue::new(vec![0; 2])).unwrap();
+    }
+
+    #[test]
+    fn codec_bytes_sub_byte() {
+        for data_type in [
+            DataType::Float4E2M1FN,
+            DataType::Float6E2M3FN,
+            DataType::Float6E3M2FN,
+        ] {
+            let err = codec_bytes_round_trip_impl(None, data_type, FillValue::from(0u8))
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("packbits"), "{err}");
+        }
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(4).unwrap()],
+            DataType::Float4E2M1FN,
+            FillValue::from(0u8),
+        )
+        .unwrap();
+        assert!(BytesCodec::new(None)
+            .compute_encoded_size(&chunk_representation)
+            .is_err());
+    }
+
+    #[test]
+    fn codec_bytes_round_trip_complex_bfloat16() {
+        let fill_value = FillValue::from(num::complex::Complex::<half::bf16>::new(
+            half::bf16::ZERO,
+            half::bf16::ZERO,
+        ));
+        codec_bytes_round_trip_impl(
+            Some(Endianness::Big),
+            DataType::ComplexBFloat16,
+            fill_value.clone(),
+        )
+        .unwrap();
+        codec_bytes_round_trip_impl(
+            Some(Endianness::Little),
+            DataType::ComplexBFloat16,
+            fill_value,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_bytes_round_trip_complex_float16() {
+        let fill_value = FillValue::from(num::complex::Complex::<half::f16>::new(
+            half::f16::ZERO,
+            half::f16::ZERO,
+        ));
+        codec_bytes_round_trip_impl(
+            Some(Endianness::Big),
+            DataType::ComplexFloat16,
+            fill_value.clone(),
+        )
+        .unwrap();
+        codec_bytes_round_trip_impl(
+            Some(Endianness::Little),
+            DataType::ComplexFloat16,
+            fill_value,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_bytes_round_trip_complex_float32() {
+        codec_bytes_round_trip_impl(
+            Some(Endianness::Big),
+            DataType::ComplexFloat32,
+            FillValue::from(num::complex::Complex32::new(0.0, 0.0)),
+        )
+        .unwrap();
+        codec_bytes_round_trip_impl(
+            Some(Endianness::Little),
+            DataType::ComplexFloat32,
+            FillValue::from(num::complex::Complex32::new(0.0, 0.0)),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_bytes_complex_float16_big() {
+        let chunk_representation = ChunkRepresentation::new(
+            vec![NonZeroU64::new(2).unwrap()],
+            DataType::ComplexFloat16,
+            FillValue::from(num::complex::Complex::<half::f16>::new(
+                half::f16::ZERO,
+                half::f16::ZERO,
+            )),
+        )
+        .unwrap();
+        let elements = [
+            num::complex::Complex::new(half::f16::from_f32(1.0), half::f16::from_f32(2.0)),
+            num::complex::Complex::new(half::f16::from_f32(3.0), half::f16::from_f32(4.0)),
+        ];
+        let bytes: ArrayBytes = crate::array::transmute_to_bytes(&elements).to_vec().into();
+
+        // Each component is encoded as a consecutive big endian half-precision float
+        let codec = BytesCodec::new(Some(Endianness::Big));
+        let encoded = codec
+            .encode(bytes, &chunk_representation, &CodecOptions::default())
+            .unwrap();
+        let expected: Vec<u8> = [1.0f32, 2.0, 3.0, 4.0]
+            .into_iter()
+            .flat_map(|f| half::f16::from_f32(f).to_be_bytes())
+            .collect();
+        assert_eq!(encoded.as_ref(), expected.as_slice());
+    }
+
     #[test]
     fn codec_bytes_round_trip_complex128() {
         codec_bytes_round_trip_impl(
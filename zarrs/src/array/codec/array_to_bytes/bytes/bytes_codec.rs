@@ -1,17 +1,15 @@
-// Note: No validation that this codec is created *without* a specified endianness for multi-byte data types.
-
 use std::sync::Arc;
 
 use crate::{
     array::{
         codec::{
-            ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderDefault,
-            ArrayPartialEncoderTraits, ArrayToBytesCodecTraits, BytesPartialDecoderTraits,
-            BytesPartialEncoderTraits, CodecError, CodecOptions, CodecTraits,
+            ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderTraits,
+            ArrayToBytesCodecTraits, BytesPartialDecoderTraits, BytesPartialEncoderTraits,
+            CodecError, CodecIncompatibleWithDataTypeError, CodecOptions, CodecTraits,
             RecommendedConcurrency,
         },
-        ArrayBytes, ArrayMetadataOptions, BytesRepresentation, ChunkRepresentation, DataTypeSize,
-        RawBytes,
+        ArrayBytes, ArrayMetadataOptions, BytesRepresentation, ChunkRepresentation, DataType,
+        DataTypeSize, RawBytes,
     },
     metadata::v3::MetadataV3,
 };
@@ -20,11 +18,14 @@ use crate::{
 use crate::array::codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits};
 
 use super::{
-    bytes_partial_decoder, reverse_endianness, BytesCodecConfiguration, BytesCodecConfigurationV1,
-    Endianness,
+    bytes_partial_decoder, bytes_partial_encoder, reverse_endianness, BytesCodecConfiguration,
+    BytesCodecConfigurationV1, Endianness,
 };
 
 /// A `bytes` codec implementation.
+///
+/// The default `bytes` codec encodes with little endian byte order.
+/// Use [`Endianness::native`] to explicitly encode with the native endianness of the CPU.
 #[derive(Debug, Clone)]
 pub struct BytesCodec {
     endian: Option<Endianness>,
@@ -32,7 +33,7 @@ pub struct BytesCodec {
 
 impl Default for BytesCodec {
     fn default() -> Self {
-        Self::new(Some(Endianness::native()))
+        Self::little()
     }
 }
 
@@ -40,6 +41,7 @@ impl BytesCodec {
     /// Create a new `bytes` codec.
     ///
     /// `endian` is optional because an 8-bit type has no endianness.
+    /// It is required for multi-byte data types, otherwise the codec is incompatible with the data type.
     #[must_use]
     pub const fn new(endian: Option<Endianness>) -> Self {
         Self { endian }
@@ -69,6 +71,7 @@ impl BytesCodec {
         mut value: RawBytes<'a>,
         decoded_representation: &ChunkRepresentation,
     ) -> Result<RawBytes<'a>, CodecError> {
+        validate_not_sub_byte(decoded_representation.data_type())?;
         match decoded_representation.data_type().size() {
             DataTypeSize::Variable => {
                 return Err(CodecError::UnsupportedDataType(
@@ -83,7 +86,9 @@ impl BytesCodec {
                         value.len(),
                         array_size,
                     ));
-                } else if data_type_size > 1 && self.endian.is_none() {
+                } else if self.endian.is_none()
+                    && has_endianness(decoded_representation.data_type())
+                {
                     return Err(CodecError::Other(format!(
                         "tried to encode an array with element size {data_type_size} with endianness None"
                     )));
@@ -118,6 +123,10 @@ impl CodecTraits for BytesCodec {
     fn partial_decoder_decodes_all(&self) -> bool {
         false
     }
+
+    fn supports_partial_encode(&self) -> bool {
+        true
+    }
 }
 
 impl ArrayCodecTraits for BytesCodec {
@@ -192,7 +201,7 @@ impl ArrayToBytesCodecTraits for BytesCodec {
         decoded_representation: &ChunkRepresentation,
         _options: &CodecOptions,
     ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
-        Ok(Arc::new(ArrayPartialEncoderDefault::new(
+        Ok(Arc::new(bytes_partial_encoder::BytesPartialEncoder::new(
             input_handle,
             output_handle,
             decoded_representation.clone(),
@@ -220,6 +229,7 @@ impl ArrayToBytesCodecTraits for BytesCodec {
         &self,
         decoded_representation: &ChunkRepresentation,
     ) -> Result<BytesRepresentation, CodecError> {
+        validate_not_sub_byte(decoded_representation.data_type())?;
         match decoded_representation.data_type().size() {
             DataTypeSize::Variable => Err(CodecError::UnsupportedDataType(
                 decoded_representation.data_type().clone(),
@@ -230,4 +240,50 @@ impl ArrayToBytesCodecTraits for BytesCodec {
             )),
         }
     }
+
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        let data_type = decoded_representation.data_type();
+        let reason = if let Some(bits) = data_type.sub_byte_bits() {
+            format!(
+                "{bits}-bit data types must be encoded with the packbits codec (not yet supported)"
+            )
+        } else if data_type.size() == DataTypeSize::Variable {
+            "variable-sized data types must be encoded with the vlen or vlen-utf8 codec".to_string()
+        } else if self.endian.is_none() && has_endianness(data_type) {
+            "the endian configuration is required for multi-byte data types".to_string()
+        } else {
+            return vec![];
+        };
+        vec![CodecIncompatibleWithDataTypeError::new(
+            super::IDENTIFIER,
+            data_type.clone(),
+            reason,
+        )]
+    }
+}
+
+/// Sub-byte data types must be packed by a bit packing codec (e.g. `packbits`), so they are rejected by the `bytes` codec.
+fn validate_not_sub_byte(data_type: &DataType) -> Result<(), CodecError> {
+    if let Some(bits) = data_type.sub_byte_bits() {
+        Err(CodecError::Other(format!(
+            "the bytes codec does not support the {bits}-bit data type {data_type}, sub-byte data types must be encoded with the packbits codec (not yet supported)"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns true if the data type has an endianness, i.e. it has a multi-byte component.
+fn has_endianness(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Structured(configuration) => configuration.fields().iter().any(|field| {
+            DataType::from_metadata(&field.data_type)
+                .is_ok_and(|data_type| has_endianness(&data_type))
+        }),
+        DataType::FixedLengthUtf8(_) | DataType::FixedLengthBytes(_) => false,
+        _ => data_type.fixed_size().is_some_and(|size| size > 1),
+    }
 }
@@ -0,0 +1,182 @@
+use std::{num::NonZeroU64, sync::Arc};
+
+use crate::{
+    array::{
+        array_bytes::update_array_bytes,
+        codec::{
+            ArrayPartialEncoderTraits, ArraySubset, ArrayToBytesCodecTraits,
+            BytesPartialDecoderTraits, BytesPartialEncoderTraits, CodecError, CodecOptions,
+        },
+        ArrayBytes, ArraySize, ChunkRepresentation, DataTypeSize, RawBytes,
+    },
+    array_subset::IncompatibleArraySubsetAndShapeError,
+    byte_range::ByteRange,
+};
+
+use super::BytesCodec;
+
+/// Partial encoder for the `bytes` codec.
+///
+/// The encoded bytes of each chunk subset are written directly to their byte ranges in the encoded chunk, so the chunk is not read.
+/// If the chunk does not exist, it is written in its entirety.
+///
+/// Unlike [`ArrayPartialEncoderDefault`](crate::array::codec::ArrayPartialEncoderDefault), an existing chunk that becomes entirely the fill value is not erased.
+pub(crate) struct BytesPartialEncoder {
+    input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    output_handle: Arc<dyn BytesPartialEncoderTraits>,
+    decoded_representation: ChunkRepresentation,
+    codec: Arc<BytesCodec>,
+}
+
+impl BytesPartialEncoder {
+    /// Create a new partial encoder for the `bytes` codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: ChunkRepresentation,
+        codec: Arc<BytesCodec>,
+    ) -> Self {
+        Self {
+            input_handle,
+            output_handle,
+            decoded_representation,
+            codec,
+        }
+    }
+}
+
+impl BytesPartialEncoder {
+    /// Encode the chunk subsets into a chunk that does not exist, which is otherwise the fill value.
+    fn encode_missing_chunk(
+        &self,
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        let chunk_shape = self.decoded_representation.shape_u64();
+        let data_type_size = self.decoded_representation.data_type().size();
+        let array_size = ArraySize::new(data_type_size, self.decoded_representation.num_elements());
+        let mut chunk_bytes =
+            ArrayBytes::new_fill_value(array_size, self.decoded_representation.fill_value());
+        for (chunk_subset, chunk_subset_bytes) in subsets_and_bytes {
+            if !chunk_subset.inbounds(&chunk_shape) {
+                return Err(IncompatibleArraySubsetAndShapeError::from((
+                    (*chunk_subset).clone(),
+                    chunk_shape,
+                ))
+                .into());
+            }
+            chunk_subset_bytes.validate(chunk_subset.num_elements(), data_type_size)?;
+            // SAFETY: the chunk subset is within the chunk and the bytes have been validated
+            chunk_bytes = unsafe {
+                update_array_bytes(
+                    chunk_bytes,
+                    &chunk_shape,
+                    chunk_subset,
+                    chunk_subset_bytes,
+                    data_type_size,
+                )
+            };
+        }
+
+        if !options.store_empty_chunks()
+            && chunk_bytes.is_fill_value(self.decoded_representation.fill_value())
+        {
+            Ok(())
+        } else {
+            let chunk_bytes =
+                self.codec
+                    .encode(chunk_bytes, &self.decoded_representation, options)?;
+            self.output_handle
+                .partial_encode(&[(0, chunk_bytes)], options)
+        }
+    }
+}
+
+impl ArrayPartialEncoderTraits for BytesPartialEncoder {
+    fn erase(&self) -> Result<(), CodecError> {
+        self.output_handle.erase()
+    }
+
+    fn partial_encode(
+        &self,
+        subsets_and_bytes: &[(&ArraySubset, ArrayBytes<'_>)],
+        options: &CodecOptions,
+    ) -> Result<(), CodecError> {
+        let data_type = self.decoded_representation.data_type();
+        let DataTypeSize::Fixed(data_type_size) = data_type.size() else {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                super::IDENTIFIER.to_string(),
+            ));
+        };
+
+        // Write the entire chunk if it does not exist
+        let chunk_exists = self
+            .input_handle
+            .partial_decode(&[ByteRange::FromStart(0, Some(0))], options)?
+            .is_some();
+        if !chunk_exists {
+            return self.encode_missing_chunk(subsets_and_bytes, options);
+        }
+
+        // Encode each chunk subset
+        let chunk_shape = self.decoded_representation.shape_u64();
+        let mut encoded_subsets: Vec<(Vec<u64>, usize, RawBytes<'_>)> =
+            Vec::with_capacity(subsets_and_bytes.len());
+        for (chunk_subset, chunk_subset_bytes) in subsets_and_bytes {
+            let contiguous_indices = chunk_subset
+                .contiguous_linearised_indices(&chunk_shape)
+                .map_err(|_| {
+                    IncompatibleArraySubsetAndShapeError::from((
+                        (*chunk_subset).clone(),
+                        chunk_shape.clone(),
+                    ))
+                })?;
+            chunk_subset_bytes.validate(chunk_subset.num_elements(), data_type.size())?;
+            let Some(chunk_subset_shape) = chunk_subset
+                .shape()
+                .iter()
+                .map(|s| NonZeroU64::new(*s))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+
+            // SAFETY: the data type and fill value are from the decoded representation
+            let chunk_subset_representation = unsafe {
+                ChunkRepresentation::new_unchecked(
+                    chunk_subset_shape,
+                    data_type.clone(),
+                    self.decoded_representation.fill_value().clone(),
+                )
+            };
+            let ArrayBytes::Fixed(chunk_subset_bytes) = chunk_subset_bytes else {
+                unreachable!("validated as fixed length")
+            };
+            let encoded = self.codec.encode(
+                ArrayBytes::new_flen(&**chunk_subset_bytes),
+                &chunk_subset_representation,
+                options,
+            )?;
+            let byte_offsets = contiguous_indices
+                .iter()
+                .map(|index| index * data_type_size as u64)
+                .collect();
+            let byte_length = contiguous_indices.contiguous_elements_usize() * data_type_size;
+            encoded_subsets.push((byte_offsets, byte_length, encoded));
+        }
+
+        // Write the contiguous runs of each chunk subset
+        let offsets_and_bytes = encoded_subsets
+            .iter()
+            .flat_map(|(byte_offsets, byte_length, encoded)| {
+                byte_offsets
+                    .iter()
+                    .zip(encoded.chunks_exact(*byte_length))
+                    .map(|(offset, bytes)| (*offset, RawBytes::from(bytes)))
+            })
+            .collect::<Vec<_>>();
+        self.output_handle
+            .partial_encode(&offsets_and_bytes, options)
+    }
+}
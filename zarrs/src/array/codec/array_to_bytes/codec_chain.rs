@@ -1,7 +1,13 @@
 //! An array to bytes codec formed by joining an array to array sequence, array to bytes, and bytes to bytes sequence of codecs.
 
+mod codec_chain_explanation;
+
 use std::sync::Arc;
 
+pub use codec_chain_explanation::{
+    CodecChainExplanation, CodecKind, CodecStageExplanation, CodecStageInfo,
+    CodecStageRepresentation,
+};
 use unsafe_cell_slice::UnsafeCellSlice;
 
 use crate::{
@@ -11,7 +17,8 @@ use crate::{
             ArrayCodecTraits, ArrayPartialDecoderCache, ArrayPartialDecoderTraits,
             ArrayPartialEncoderTraits, ArrayToArrayCodecTraits, ArrayToBytesCodecTraits,
             BytesPartialDecoderCache, BytesPartialDecoderTraits, BytesPartialEncoderTraits,
-            BytesToBytesCodecTraits, Codec, CodecError, CodecOptions, CodecTraits,
+            BytesToBytesCodecTraits, Codec, CodecError, CodecIncompatibleWithDataTypeError,
+            CodecOptions, CodecTraits,
         },
         concurrency::RecommendedConcurrency,
         ArrayBytes, ArrayMetadataOptions, BytesRepresentation, ChunkRepresentation, ChunkShape,
@@ -181,6 +188,79 @@ impl CodecChain {
         &self.bytes_to_bytes
     }
 
+    /// Return information about each codec in encoding order.
+    #[must_use]
+    pub fn stages(&self) -> Vec<CodecStageInfo> {
+        let mut stages =
+            Vec::with_capacity(self.array_to_array.len() + 1 + self.bytes_to_bytes.len());
+        for codec in &self.array_to_array {
+            stages.push(CodecStageInfo::new(CodecKind::ArrayToArray, codec.as_ref()));
+        }
+        stages.push(CodecStageInfo::new(
+            CodecKind::ArrayToBytes,
+            self.array_to_bytes.as_ref(),
+        ));
+        for codec in &self.bytes_to_bytes {
+            stages.push(CodecStageInfo::new(CodecKind::BytesToBytes, codec.as_ref()));
+        }
+        stages
+    }
+
+    /// Explain how each codec encodes the `decoded_representation`.
+    ///
+    /// The explanation includes the input and output representation and recommended concurrency of each codec.
+    /// It is intended for debugging and performance tuning, and its [`Display`](core::fmt::Display) implementation produces a readable table.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the `decoded_representation` is not supported by the codec chain.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn explain(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<CodecChainExplanation, CodecError> {
+        let array_representations =
+            self.get_array_representations(decoded_representation.clone())?;
+        let bytes_representations =
+            self.get_bytes_representations(array_representations.last().unwrap())?;
+
+        let mut stages = self.stages().into_iter();
+        let mut explanations = Vec::with_capacity(stages.len());
+        for (codec, representations) in
+            std::iter::zip(&self.array_to_array, array_representations.windows(2))
+        {
+            explanations.push(CodecStageExplanation::new(
+                stages.next().unwrap(),
+                CodecStageRepresentation::Array(representations[0].clone()),
+                CodecStageRepresentation::Array(representations[1].clone()),
+                codec.recommended_concurrency(&representations[0])?,
+            ));
+        }
+        let array_representation_last = array_representations.last().unwrap();
+        explanations.push(CodecStageExplanation::new(
+            stages.next().unwrap(),
+            CodecStageRepresentation::Array(array_representation_last.clone()),
+            CodecStageRepresentation::Bytes(bytes_representations[0]),
+            self.array_to_bytes
+                .recommended_concurrency(array_representation_last)?,
+        ));
+        for (codec, representations) in
+            std::iter::zip(&self.bytes_to_bytes, bytes_representations.windows(2))
+        {
+            explanations.push(CodecStageExplanation::new(
+                stages.next().unwrap(),
+                CodecStageRepresentation::Bytes(representations[0]),
+                CodecStageRepresentation::Bytes(representations[1]),
+                codec.recommended_concurrency(&representations[0])?,
+            ));
+        }
+
+        Ok(CodecChainExplanation::new(
+            explanations,
+            self.recommended_concurrency(decoded_representation)?,
+            self.partial_decode_granularity(decoded_representation),
+        ))
+    }
+
     fn get_array_representations(
         &self,
         decoded_representation: ChunkRepresentation,
@@ -226,6 +306,30 @@ impl CodecTraits for CodecChain {
     fn partial_decoder_decodes_all(&self) -> bool {
         false
     }
+
+    /// Returns true if all codecs in the chain support partial decoding.
+    fn supports_partial_decode(&self) -> bool {
+        self.array_to_array
+            .iter()
+            .all(|codec| codec.supports_partial_decode())
+            && self.array_to_bytes.supports_partial_decode()
+            && self
+                .bytes_to_bytes
+                .iter()
+                .all(|codec| codec.supports_partial_decode())
+    }
+
+    /// Returns true if all codecs in the chain support partial encoding.
+    fn supports_partial_encode(&self) -> bool {
+        self.array_to_array
+            .iter()
+            .all(|codec| codec.supports_partial_encode())
+            && self.array_to_bytes.supports_partial_encode()
+            && self
+                .bytes_to_bytes
+                .iter()
+                .all(|codec| codec.supports_partial_encode())
+    }
 }
 
 #[cfg_attr(feature = "async", async_trait::async_trait)]
@@ -240,34 +344,36 @@ impl ArrayToBytesCodecTraits for CodecChain {
         decoded_representation: &ChunkRepresentation,
         options: &CodecOptions,
     ) -> Result<RawBytes<'a>, CodecError> {
-        bytes.validate(
-            decoded_representation.num_elements(),
-            decoded_representation.data_type().size(),
-        )?;
-
-        let mut decoded_representation = decoded_representation.clone();
+        options.install(|| {
+            bytes.validate(
+                decoded_representation.num_elements(),
+                decoded_representation.data_type().size(),
+            )?;
 
-        // array->array
-        for codec in &self.array_to_array {
-            bytes = codec.encode(bytes, &decoded_representation, options)?;
-            decoded_representation = codec.compute_encoded_size(&decoded_representation)?;
-        }
+            let mut decoded_representation = decoded_representation.clone();
 
-        // array->bytes
-        let mut bytes = self
-            .array_to_bytes
-            .encode(bytes, &decoded_representation, options)?;
-        let mut decoded_representation = self
-            .array_to_bytes
-            .compute_encoded_size(&decoded_representation)?;
+            // array->array
+            for codec in &self.array_to_array {
+                bytes = codec.encode(bytes, &decoded_representation, options)?;
+                decoded_representation = codec.compute_encoded_size(&decoded_representation)?;
+            }
 
-        // bytes->bytes
-        for codec in &self.bytes_to_bytes {
-            bytes = codec.encode(bytes, options)?;
-            decoded_representation = codec.compute_encoded_size(&decoded_representation);
-        }
+            // array->bytes
+            let mut bytes = self
+                .array_to_bytes
+                .encode(bytes, &decoded_representation, options)?;
+            let mut decoded_representation = self
+                .array_to_bytes
+                .compute_encoded_size(&decoded_representation)?;
+
+            // bytes->bytes
+            for codec in &self.bytes_to_bytes {
+                bytes = codec.encode(bytes, options)?;
+                decoded_representation = codec.compute_encoded_size(&decoded_representation);
+            }
 
-        Ok(bytes)
+            Ok(bytes)
+        })
     }
 
     fn decode<'a>(
@@ -276,37 +382,41 @@ impl ArrayToBytesCodecTraits for CodecChain {
         decoded_representation: &ChunkRepresentation,
         options: &CodecOptions,
     ) -> Result<ArrayBytes<'a>, CodecError> {
-        let array_representations =
-            self.get_array_representations(decoded_representation.clone())?;
-        let bytes_representations =
-            self.get_bytes_representations(array_representations.last().unwrap())?;
-
-        // bytes->bytes
-        for (codec, bytes_representation) in std::iter::zip(
-            self.bytes_to_bytes.iter().rev(),
-            bytes_representations.iter().rev().skip(1),
-        ) {
-            bytes = codec.decode(bytes, bytes_representation, options)?;
-        }
+        options.install(|| {
+            let array_representations =
+                self.get_array_representations(decoded_representation.clone())?;
+            let bytes_representations =
+                self.get_bytes_representations(array_representations.last().unwrap())?;
+
+            // bytes->bytes
+            for (codec, bytes_representation) in std::iter::zip(
+                self.bytes_to_bytes.iter().rev(),
+                bytes_representations.iter().rev().skip(1),
+            ) {
+                bytes = codec.decode(bytes, bytes_representation, options)?;
+            }
 
-        // bytes->array
-        let mut bytes =
-            self.array_to_bytes
-                .decode(bytes, array_representations.last().unwrap(), options)?;
+            // bytes->array
+            let mut bytes = self.array_to_bytes.decode(
+                bytes,
+                array_representations.last().unwrap(),
+                options,
+            )?;
 
-        // array->array
-        for (codec, array_representation) in std::iter::zip(
-            self.array_to_array.iter().rev(),
-            array_representations.iter().rev().skip(1),
-        ) {
-            bytes = codec.decode(bytes, array_representation, options)?;
-        }
+            // array->array
+            for (codec, array_representation) in std::iter::zip(
+                self.array_to_array.iter().rev(),
+                array_representations.iter().rev().skip(1),
+            ) {
+                bytes = codec.decode(bytes, array_representation, options)?;
+            }
 
-        bytes.validate(
-            decoded_representation.num_elements(),
-            decoded_representation.data_type().size(),
-        )?;
-        Ok(bytes)
+            bytes.validate(
+                decoded_representation.num_elements(),
+                decoded_representation.data_type().size(),
+            )?;
+            Ok(bytes)
+        })
     }
 
     unsafe fn decode_into(
@@ -318,77 +428,81 @@ impl ArrayToBytesCodecTraits for CodecChain {
         output_subset: &ArraySubset,
         options: &CodecOptions,
     ) -> Result<(), CodecError> {
-        let array_representations =
-            self.get_array_representations(decoded_representation.clone())?;
-        let bytes_representations =
-            self.get_bytes_representations(array_representations.last().unwrap())?;
+        options.install(|| {
+            let array_representations =
+                self.get_array_representations(decoded_representation.clone())?;
+            let bytes_representations =
+                self.get_bytes_representations(array_representations.last().unwrap())?;
+
+            if self.bytes_to_bytes.is_empty() && self.array_to_array.is_empty() {
+                // Fast path if no bytes to bytes or array to array codecs
+                return unsafe {
+                    self.array_to_bytes.decode_into(
+                        bytes,
+                        array_representations.last().unwrap(),
+                        output,
+                        output_shape,
+                        output_subset,
+                        options,
+                    )
+                };
+            }
 
-        if self.bytes_to_bytes.is_empty() && self.array_to_array.is_empty() {
-            // Fast path if no bytes to bytes or array to array codecs
-            return unsafe {
-                self.array_to_bytes.decode_into(
-                    bytes,
-                    array_representations.last().unwrap(),
-                    output,
-                    output_shape,
-                    output_subset,
-                    options,
-                )
-            };
-        }
+            // bytes->bytes
+            for (codec, bytes_representation) in std::iter::zip(
+                self.bytes_to_bytes.iter().rev(),
+                bytes_representations.iter().rev().skip(1),
+            ) {
+                bytes = codec.decode(bytes, bytes_representation, options)?;
+            }
 
-        // bytes->bytes
-        for (codec, bytes_representation) in std::iter::zip(
-            self.bytes_to_bytes.iter().rev(),
-            bytes_representations.iter().rev().skip(1),
-        ) {
-            bytes = codec.decode(bytes, bytes_representation, options)?;
-        }
+            if self.array_to_array.is_empty() {
+                // Fast path if no array to array codecs
+                return unsafe {
+                    self.array_to_bytes.decode_into(
+                        bytes,
+                        array_representations.last().unwrap(),
+                        output,
+                        output_shape,
+                        output_subset,
+                        options,
+                    )
+                };
+            }
 
-        if self.array_to_array.is_empty() {
-            // Fast path if no array to array codecs
-            return unsafe {
-                self.array_to_bytes.decode_into(
-                    bytes,
-                    array_representations.last().unwrap(),
+            // bytes->array
+            let mut bytes = self.array_to_bytes.decode(
+                bytes,
+                array_representations.last().unwrap(),
+                options,
+            )?;
+
+            // array->array
+            for (codec, array_representation) in std::iter::zip(
+                self.array_to_array.iter().rev(),
+                array_representations.iter().rev().skip(1),
+            ) {
+                bytes = codec.decode(bytes, array_representation, options)?;
+            }
+            bytes.validate(
+                decoded_representation.num_elements(),
+                decoded_representation.data_type().size(),
+            )?;
+
+            if let ArrayBytes::Fixed(decoded_value) = bytes {
+                update_bytes_flen(
                     output,
                     output_shape,
+                    &decoded_value,
                     output_subset,
-                    options,
-                )
-            };
-        }
-
-        // bytes->array
-        let mut bytes =
-            self.array_to_bytes
-                .decode(bytes, array_representations.last().unwrap(), options)?;
-
-        // array->array
-        for (codec, array_representation) in std::iter::zip(
-            self.array_to_array.iter().rev(),
-            array_representations.iter().rev().skip(1),
-        ) {
-            bytes = codec.decode(bytes, array_representation, options)?;
-        }
-        bytes.validate(
-            decoded_representation.num_elements(),
-            decoded_representation.data_type().size(),
-        )?;
-
-        if let ArrayBytes::Fixed(decoded_value) = bytes {
-            update_bytes_flen(
-                output,
-                output_shape,
-                &decoded_value,
-                output_subset,
-                decoded_representation.data_type().fixed_size().unwrap(),
-            );
-        } else {
-            // TODO: Variable length data type support?
-            return Err(CodecError::ExpectedFixedLengthBytes);
-        }
-        Ok(())
+                    decoded_representation.data_type().fixed_size().unwrap(),
+                );
+            } else {
+                // TODO: Variable length data type support?
+                return Err(CodecError::ExpectedFixedLengthBytes);
+            }
+            Ok(())
+        })
     }
 
     fn partial_decoder(
@@ -622,6 +736,24 @@ impl ArrayToBytesCodecTraits for CodecChain {
 
         Ok(bytes_representation)
     }
+
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        // Validate every codec rather than stopping at the first incompatibility
+        let mut incompatibilities = Vec::new();
+        let mut decoded_representation = decoded_representation.clone();
+        for codec in &self.array_to_array {
+            incompatibilities.extend(codec.validate(&decoded_representation));
+            if let Ok(encoded_representation) = codec.compute_encoded_size(&decoded_representation)
+            {
+                decoded_representation = encoded_representation;
+            }
+        }
+        incompatibilities.extend(self.array_to_bytes.validate(&decoded_representation));
+        incompatibilities
+    }
 }
 
 impl ArrayCodecTraits for CodecChain {
@@ -0,0 +1,248 @@
+use derive_more::Display;
+
+use crate::{
+    array::{
+        codec::CodecTraits, concurrency::RecommendedConcurrency, BytesRepresentation,
+        ChunkRepresentation, ChunkShape,
+    },
+    metadata::v3::{MetadataConfiguration, MetadataV3},
+};
+
+/// The kind of a codec in a [`CodecChain`](super::CodecChain).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Display)]
+pub enum CodecKind {
+    /// An array to array codec.
+    #[display("array->array")]
+    ArrayToArray,
+    /// An array to bytes codec.
+    #[display("array->bytes")]
+    ArrayToBytes,
+    /// A bytes to bytes codec.
+    #[display("bytes->bytes")]
+    BytesToBytes,
+}
+
+/// Information about a codec in a [`CodecChain`](super::CodecChain), independent of the representation it encodes.
+#[derive(Clone, Debug)]
+pub struct CodecStageInfo {
+    kind: CodecKind,
+    metadata: Option<MetadataV3>,
+    partial_decode_capable: bool,
+    partial_decoder_decodes_all: bool,
+    supports_partial_encode: bool,
+}
+
+impl CodecStageInfo {
+    pub(super) fn new(kind: CodecKind, codec: &dyn CodecTraits) -> Self {
+        Self {
+            kind,
+            metadata: codec.create_metadata(),
+            partial_decode_capable: codec.supports_partial_decode(),
+            partial_decoder_decodes_all: codec.partial_decoder_decodes_all(),
+            supports_partial_encode: codec.supports_partial_encode(),
+        }
+    }
+
+    /// Returns the kind of the codec.
+    #[must_use]
+    pub const fn kind(&self) -> CodecKind {
+        self.kind
+    }
+
+    /// Returns the metadata of the codec, or [`None`] for a hidden codec.
+    #[must_use]
+    pub const fn metadata(&self) -> Option<&MetadataV3> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the name (identifier) of the codec, or [`None`] for a hidden codec.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.metadata.as_ref().map(MetadataV3::name)
+    }
+
+    /// Returns the configuration of the codec, or [`None`] for a hidden codec or a codec without a configuration.
+    #[must_use]
+    pub fn configuration(&self) -> Option<&MetadataConfiguration> {
+        self.metadata.as_ref().and_then(MetadataV3::configuration)
+    }
+
+    /// Returns [`CodecTraits::supports_partial_decode`] for the codec.
+    #[must_use]
+    pub const fn partial_decode_capable(&self) -> bool {
+        self.partial_decode_capable
+    }
+
+    /// Returns [`CodecTraits::partial_decoder_decodes_all`] for the codec.
+    #[must_use]
+    pub const fn partial_decoder_decodes_all(&self) -> bool {
+        self.partial_decoder_decodes_all
+    }
+
+    /// Returns [`CodecTraits::supports_partial_encode`] for the codec.
+    #[must_use]
+    pub const fn supports_partial_encode(&self) -> bool {
+        self.supports_partial_encode
+    }
+}
+
+/// The representation of the input or output of a codec.
+#[derive(Clone, Debug, Display)]
+pub enum CodecStageRepresentation {
+    /// An array.
+    #[display("array {_0}")]
+    Array(ChunkRepresentation),
+    /// Bytes.
+    #[display("bytes {_0}")]
+    Bytes(BytesRepresentation),
+}
+
+/// An explanation of a codec in a [`CodecChain`](super::CodecChain) for a decoded chunk representation.
+#[derive(Clone, Debug)]
+pub struct CodecStageExplanation {
+    info: CodecStageInfo,
+    input: CodecStageRepresentation,
+    output: CodecStageRepresentation,
+    recommended_concurrency: RecommendedConcurrency,
+}
+
+impl CodecStageExplanation {
+    pub(super) fn new(
+        info: CodecStageInfo,
+        input: CodecStageRepresentation,
+        output: CodecStageRepresentation,
+        recommended_concurrency: RecommendedConcurrency,
+    ) -> Self {
+        Self {
+            info,
+            input,
+            output,
+            recommended_concurrency,
+        }
+    }
+
+    /// Returns information about the codec.
+    #[must_use]
+    pub const fn info(&self) -> &CodecStageInfo {
+        &self.info
+    }
+
+    /// Returns the input representation of the codec when encoding (i.e. its decoded representation).
+    #[must_use]
+    pub const fn input(&self) -> &CodecStageRepresentation {
+        &self.input
+    }
+
+    /// Returns the output representation of the codec when encoding (i.e. its encoded representation).
+    #[must_use]
+    pub const fn output(&self) -> &CodecStageRepresentation {
+        &self.output
+    }
+
+    /// Returns the recommended concurrency of the codec for its input representation.
+    #[must_use]
+    pub const fn recommended_concurrency(&self) -> &RecommendedConcurrency {
+        &self.recommended_concurrency
+    }
+}
+
+/// An explanation of the codecs in a [`CodecChain`](super::CodecChain) for a decoded chunk representation.
+///
+/// The [`Display`](core::fmt::Display) implementation produces a table with a row for each codec in encoding order.
+#[derive(Clone, Debug)]
+pub struct CodecChainExplanation {
+    stages: Vec<CodecStageExplanation>,
+    recommended_concurrency: RecommendedConcurrency,
+    partial_decode_granularity: ChunkShape,
+}
+
+impl CodecChainExplanation {
+    pub(super) fn new(
+        stages: Vec<CodecStageExplanation>,
+        recommended_concurrency: RecommendedConcurrency,
+        partial_decode_granularity: ChunkShape,
+    ) -> Self {
+        Self {
+            stages,
+            recommended_concurrency,
+            partial_decode_granularity,
+        }
+    }
+
+    /// Returns the explanation of each codec in encoding order.
+    #[must_use]
+    pub fn stages(&self) -> &[CodecStageExplanation] {
+        &self.stages
+    }
+
+    /// Returns the recommended concurrency of the codec chain.
+    #[must_use]
+    pub const fn recommended_concurrency(&self) -> &RecommendedConcurrency {
+        &self.recommended_concurrency
+    }
+
+    /// Returns the partial decode granularity of the codec chain.
+    #[must_use]
+    pub fn partial_decode_granularity(&self) -> &ChunkShape {
+        &self.partial_decode_granularity
+    }
+}
+
+fn format_concurrency(recommended_concurrency: &RecommendedConcurrency) -> String {
+    format!(
+        "{}-{}",
+        recommended_concurrency.min(),
+        recommended_concurrency.max()
+    )
+}
+
+impl core::fmt::Display for CodecChainExplanation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let header = [
+            "#",
+            "kind",
+            "codec",
+            "input",
+            "output",
+            "partial decode",
+            "concurrency",
+        ]
+        .map(ToString::to_string);
+        let mut rows = vec![header];
+        for (index, stage) in self.stages.iter().enumerate() {
+            rows.push([
+                index.to_string(),
+                stage.info.kind.to_string(),
+                stage.info.name().unwrap_or("-").to_string(),
+                stage.input.to_string(),
+                stage.output.to_string(),
+                stage.info.partial_decode_capable.to_string(),
+                format_concurrency(&stage.recommended_concurrency),
+            ]);
+        }
+
+        let mut widths = [0; 7];
+        for row in &rows {
+            for (width, cell) in std::iter::zip(&mut widths, row) {
+                *width = std::cmp::max(*width, cell.chars().count());
+            }
+        }
+        for row in &rows {
+            let line = std::iter::zip(widths, row)
+                .map(|(width, cell)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        writeln!(
+            f,
+            "recommended concurrency: {}",
+            format_concurrency(&self.recommended_concurrency)
+        )?;
+        write!(
+            f,
+            "partial decode granularity: {:?}",
+            self.partial_decode_granularity.to_array_shape()
+        )
+    }
+}
@@ -0,0 +1,475 @@
+//! Image array to bytes codec adapter.
+//!
+//! Image codecs (e.g. WebP, PNG) encode a 2D grid of pixels and need to know the image dimensions.
+//! A [`BytesToBytesCodecTraits`](crate::array::codec::BytesToBytesCodecTraits) codec only receives flat bytes, so image codecs should instead implement [`ImageCodecTraits`] and be wrapped in an [`ImageCodec`].
+//! The [`ImageCodec`] adapter is an array to bytes codec that derives an [`ImageRepresentation`] from the chunk representation.
+//!
+//! Chunks with a shape of `[height, width]` are mapped to single channel images, and chunks with a shape of `[height, width, channels]` are mapped to images with interleaved channels.
+//! Partial decoding decodes the entire chunk.
+
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{
+        codec::{
+            ArrayBytes, ArrayCodecTraits, ArrayPartialDecoderDefault, ArrayPartialDecoderTraits,
+            ArrayPartialEncoderDefault, ArrayPartialEncoderTraits, ArrayToBytesCodecTraits,
+            BytesPartialDecoderTraits, BytesPartialEncoderTraits, CodecError, CodecOptions,
+            CodecTraits, RawBytes, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, ChunkRepresentation, DataType,
+    },
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::{
+    AsyncArrayPartialDecoderDefault, AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecoderTraits,
+};
+
+/// The representation of an image chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageRepresentation {
+    height: u32,
+    width: u32,
+    channels: u32,
+    data_type: DataType,
+}
+
+impl ImageRepresentation {
+    /// Create a new image representation.
+    #[must_use]
+    pub const fn new(height: u32, width: u32, channels: u32, data_type: DataType) -> Self {
+        Self {
+            height,
+            width,
+            channels,
+            data_type,
+        }
+    }
+
+    /// Create an image representation from a chunk representation.
+    ///
+    /// A chunk shape of `[height, width]` has a single channel, and a chunk shape of `[height, width, channels]` has interleaved channels.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the chunk dimensionality is not 2 or 3, a dimension exceeds [`u32::MAX`], or the data type is variable sized.
+    pub fn from_chunk_representation(
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<Self, CodecError> {
+        let shape = decoded_representation.shape_u64();
+        let (height, width, channels) = match shape.as_slice() {
+            [height, width] => (*height, *width, 1),
+            [height, width, channels] => (*height, *width, *channels),
+            _ => {
+                return Err(CodecError::Other(format!(
+                    "image codecs require a chunk shape of [height, width] or [height, width, channels], got {shape:?}"
+                )))
+            }
+        };
+        let to_u32 = |dim: u64| {
+            u32::try_from(dim).map_err(|_| {
+                CodecError::Other(format!(
+                    "image chunk shape {shape:?} exceeds the maximum image dimensions"
+                ))
+            })
+        };
+        let data_type = decoded_representation.data_type();
+        if data_type.fixed_size().is_none() {
+            return Err(CodecError::Other(format!(
+                "image codecs do not support the variable sized data type {data_type}"
+            )));
+        }
+        Ok(Self::new(
+            to_u32(height)?,
+            to_u32(width)?,
+            to_u32(channels)?,
+            data_type.clone(),
+        ))
+    }
+
+    /// Return the image height.
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Return the image width.
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Return the number of channels per pixel.
+    #[must_use]
+    pub const fn channels(&self) -> u32 {
+        self.channels
+    }
+
+    /// Return the data type of each channel.
+    #[must_use]
+    pub const fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Return the size in bytes of the decoded image.
+    ///
+    /// # Panics
+    /// Panics if the data type is variable sized or the size exceeds [`usize::MAX`].
+    #[must_use]
+    pub fn size(&self) -> usize {
+        usize::try_from(
+            u64::from(self.height)
+                * u64::from(self.width)
+                * u64::from(self.channels)
+                * self.data_type.fixed_size().unwrap() as u64,
+        )
+        .unwrap()
+    }
+}
+
+/// Traits for image codecs.
+///
+/// An image codec is used as an array to bytes codec by wrapping it in an [`ImageCodec`].
+pub trait ImageCodecTraits: CodecTraits + core::fmt::Debug {
+    /// Return the image representation of a chunk.
+    ///
+    /// The default implementation calls [`ImageRepresentation::from_chunk_representation`].
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the decoded representation is not supported by this codec.
+    fn image_representation(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ImageRepresentation, CodecError> {
+        ImageRepresentation::from_chunk_representation(decoded_representation)
+    }
+
+    /// Encode an image.
+    ///
+    /// `pixels` are in row-major order with interleaved channels.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the image representation is not supported or encoding fails.
+    fn encode_image(
+        &self,
+        pixels: &[u8],
+        image_representation: &ImageRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Vec<u8>, CodecError>;
+
+    /// Decode an image.
+    ///
+    /// The decoded pixels must be in row-major order with interleaved channels.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the image representation is not supported or decoding fails.
+    fn decode_image(
+        &self,
+        bytes: &[u8],
+        image_representation: &ImageRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Vec<u8>, CodecError>;
+}
+
+/// An array to bytes codec adapter for an [`ImageCodecTraits`] codec.
+#[derive(Debug, Clone)]
+pub struct ImageCodec<T: ImageCodecTraits> {
+    codec: T,
+}
+
+impl<T: ImageCodecTraits> ImageCodec<T> {
+    /// Create a new image codec adapter.
+    #[must_use]
+    pub const fn new(codec: T) -> Self {
+        Self { codec }
+    }
+
+    /// Return the inner image codec.
+    #[must_use]
+    pub const fn codec(&self) -> &T {
+        &self.codec
+    }
+}
+
+impl<T: ImageCodecTraits> CodecTraits for ImageCodec<T> {
+    fn create_metadata_opt(&self, options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        self.codec.create_metadata_opt(options)
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        self.codec.partial_decoder_should_cache_input()
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+impl<T: ImageCodecTraits> ArrayCodecTraits for ImageCodec<T> {
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &ChunkRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl<T: ImageCodecTraits + 'static> ArrayToBytesCodecTraits for ImageCodec<T> {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn ArrayToBytesCodecTraits> {
+        self as Arc<dyn ArrayToBytesCodecTraits>
+    }
+
+    fn encode<'a>(
+        &self,
+        bytes: ArrayBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let image_representation = self.codec.image_representation(decoded_representation)?;
+        let bytes = bytes.into_fixed()?;
+        if bytes.len() != image_representation.size() {
+            return Err(CodecError::UnexpectedChunkDecodedSize(
+                bytes.len(),
+                image_representation.size() as u64,
+            ));
+        }
+        let encoded = self
+            .codec
+            .encode_image(&bytes, &image_representation, options)?;
+        Ok(Cow::Owned(encoded))
+    }
+
+    fn decode<'a>(
+        &self,
+        bytes: RawBytes<'a>,
+        decoded_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<ArrayBytes<'a>, CodecError> {
+        let image_representation = self.codec.image_representation(decoded_representation)?;
+        let decoded = self
+            .codec
+            .decode_image(&bytes, &image_representation, options)?;
+        if decoded.len() != image_representation.size() {
+            return Err(CodecError::UnexpectedChunkDecodedSize(
+                decoded.len(),
+                image_representation.size() as u64,
+            ));
+        }
+        Ok(ArrayBytes::from(decoded))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayPartialDecoderDefault::new(
+            input_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn ArrayPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(ArrayPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: &ChunkRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncArrayPartialDecoderDefault::new(
+            input_handle,
+            decoded_representation.clone(),
+            self,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<BytesRepresentation, CodecError> {
+        self.codec.image_representation(decoded_representation)?;
+        Ok(BytesRepresentation::UnboundedSize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        array::{ArrayBuilder, ArrayCreateError, DataType, FillValue},
+        array_subset::ArraySubset,
+        storage::{store::MemoryStore, ReadableStorageTraits},
+    };
+
+    use super::*;
+
+    /// A test image codec that stores channels as separate planes, which requires the image dimensions.
+    #[derive(Debug)]
+    struct PlanarImageCodec;
+
+    impl CodecTraits for PlanarImageCodec {
+        fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+            Some(MetadataV3::new("zarrs.test_planar_image"))
+        }
+
+        fn partial_decoder_should_cache_input(&self) -> bool {
+            false
+        }
+
+        fn partial_decoder_decodes_all(&self) -> bool {
+            true
+        }
+    }
+
+    impl ImageCodecTraits for PlanarImageCodec {
+        fn encode_image(
+            &self,
+            pixels: &[u8],
+            image_representation: &ImageRepresentation,
+            _options: &CodecOptions,
+        ) -> Result<Vec<u8>, CodecError> {
+            let channels = image_representation.channels() as usize;
+            Ok((0..channels)
+                .flat_map(|channel| pixels.iter().skip(channel).step_by(channels).copied())
+                .collect())
+        }
+
+        fn decode_image(
+            &self,
+            bytes: &[u8],
+            image_representation: &ImageRepresentation,
+            _options: &CodecOptions,
+        ) -> Result<Vec<u8>, CodecError> {
+            let channels = image_representation.channels() as usize;
+            let pixels =
+                image_representation.height() as usize * image_representation.width() as usize;
+            Ok((0..pixels * channels)
+                .map(|i| bytes[(i % channels) * pixels + i / channels])
+                .collect())
+        }
+    }
+
+    #[test]
+    fn codec_image_representation() {
+        let representation = |shape: Vec<u64>, data_type: DataType, fill_value: FillValue| {
+            ChunkRepresentation::new(
+                shape.into_iter().map(|s| s.try_into().unwrap()).collect(),
+                data_type,
+                fill_value,
+            )
+            .unwrap()
+        };
+        let image = ImageRepresentation::from_chunk_representation(&representation(
+            vec![4, 6, 3],
+            DataType::UInt8,
+            FillValue::from(0u8),
+        ))
+        .unwrap();
+        assert_eq!(image, ImageRepresentation::new(4, 6, 3, DataType::UInt8));
+        assert_eq!(image.size(), 4 * 6 * 3);
+
+        let image = ImageRepresentation::from_chunk_representation(&representation(
+            vec![4, 6],
+            DataType::UInt16,
+            FillValue::from(0u16),
+        ))
+        .unwrap();
+        assert_eq!(image, ImageRepresentation::new(4, 6, 1, DataType::UInt16));
+        assert_eq!(image.size(), 4 * 6 * 2);
+
+        assert!(
+            ImageRepresentation::from_chunk_representation(&representation(
+                vec![4],
+                DataType::UInt8,
+                FillValue::from(0u8),
+            ))
+            .is_err()
+        );
+        assert!(
+            ImageRepresentation::from_chunk_representation(&representation(
+                vec![1, 4, 6, 3],
+                DataType::UInt8,
+                FillValue::from(0u8),
+            ))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn codec_image_array_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let store = Arc::new(MemoryStore::default());
+        let array = ArrayBuilder::new(
+            vec![6, 5, 3],
+            DataType::UInt8,
+            vec![4, 4, 3].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .array_to_bytes_codec(Arc::new(ImageCodec::new(PlanarImageCodec)))
+        .build(store.clone(), "/")?;
+
+        let chunk: Vec<u8> = (0..4 * 4 * 3).collect();
+        array.store_chunk_elements(&[0, 0, 0], &chunk)?;
+        assert_eq!(array.retrieve_chunk_elements::<u8>(&[0, 0, 0])?, chunk);
+
+        // Stored in planar order
+        let key = array.chunk_key(&[0, 0, 0]);
+        let encoded = store.get(&key)?.unwrap();
+        assert_eq!(&encoded[..4], &[0, 3, 6, 9]);
+
+        // Partial decoding
+        let subset = ArraySubset::new_with_ranges(&[1..2, 1..3, 0..3]);
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&subset)?,
+            vec![15, 16, 17, 18, 19, 20]
+        );
+
+        // Chunks on the array edge are padded with the fill value
+        let subset = ArraySubset::new_with_ranges(&[4..6, 4..5, 0..3]);
+        array.store_array_subset_elements::<u8>(&subset, &[1, 2, 3, 4, 5, 6])?;
+        let chunk = array.retrieve_chunk_elements::<u8>(&[1, 1, 0])?;
+        assert_eq!(&chunk[..6], &[1, 2, 3, 0, 0, 0]);
+        assert_eq!(&chunk[12..15], &[4, 5, 6]);
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&subset)?,
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn codec_image_array_unsupported_shape() {
+        let store = Arc::new(MemoryStore::default());
+        let array = ArrayBuilder::new(
+            vec![8],
+            DataType::UInt8,
+            vec![4].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .array_to_bytes_codec(Arc::new(ImageCodec::new(PlanarImageCodec)))
+        .build(store, "/");
+        assert!(matches!(
+            array,
+            Err(ArrayCreateError::IncompatibleChunkShape(_, _))
+        ));
+    }
+}
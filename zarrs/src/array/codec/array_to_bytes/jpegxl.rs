@@ -0,0 +1,249 @@
+//! The `jpegxl` array to bytes codec.
+//!
+//! Encodes 2D `uint8` or `uint16` chunks as [JPEG XL](https://jpeg.org/jpegxl/) images, with lossless or lossy compression.
+//! JPEG XL typically outperforms general purpose compressors (e.g. `zstd`, `gzip`) on natural-image-like data, such as microscopy.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `jpegxl` feature, which is disabled by default.
+//! It links to a vendored build of [libjxl](https://github.com/libjxl/libjxl) via [`jpegxl-rs`](https://docs.rs/jpegxl-rs).
+//!
+//! Chunks must have a shape of `[height, width]` or `[height, width, channels]` where `channels` is:
+//!  - 1: grayscale,
+//!  - 2: grayscale with alpha,
+//!  - 3: RGB, or
+//!  - 4: RGBA.
+//!
+//! Partial decoding decodes the entire chunk.
+//!
+//! See [`JpegXlCodecConfigurationV1`] for example `JSON` metadata.
+
+mod jpegxl_codec;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::jpegxl::{
+    JpegXlCodecConfiguration, JpegXlCodecConfigurationV1,
+};
+pub use jpegxl_codec::JpegXlCodec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin, ImageCodec},
+    config::global_config,
+    metadata::v3::{array::codec::jpegxl, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use jpegxl::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_jpegxl, create_codec_jpegxl)
+}
+
+fn is_name_jpegxl(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_jpegxl(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = JpegXlCodec::new_with_configuration(&configuration)?;
+    Ok(Codec::ArrayToBytes(Arc::new(ImageCodec::new(codec))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{ArrayToBytesCodecTraits, CodecOptions, CodecTraits},
+            transmute_to_bytes_vec, Array, ArrayBuilder, ArrayBytes, ChunkRepresentation, DataType,
+            FillValue,
+        },
+        storage::store::MemoryStore,
+    };
+
+    use super::*;
+
+    const JSON_LOSSLESS: &str = r#"{
+        "lossless": true,
+        "distance": 0.0,
+        "effort": 7
+    }"#;
+
+    const JSON_LOSSY: &str = r#"{
+        "lossless": false,
+        "distance": 1.0,
+        "effort": 7
+    }"#;
+
+    fn codec(json: &str) -> Arc<ImageCodec<JpegXlCodec>> {
+        let configuration: JpegXlCodecConfiguration = serde_json::from_str(json).unwrap();
+        Arc::new(ImageCodec::new(
+            JpegXlCodec::new_with_configuration(&configuration).unwrap(),
+        ))
+    }
+
+    fn chunk_representation(shape: &[u64], data_type: DataType) -> ChunkRepresentation {
+        let fill_value = FillValue::new(vec![0; data_type.fixed_size().unwrap()]);
+        ChunkRepresentation::new(
+            shape.iter().map(|&s| NonZeroU64::new(s).unwrap()).collect(),
+            data_type,
+            fill_value,
+        )
+        .unwrap()
+    }
+
+    /// A smooth test image with values up to `max`.
+    fn image(shape: &[u64], max: u64) -> Vec<u64> {
+        let channels = shape.get(2).copied().unwrap_or(1);
+        let mut pixels = Vec::new();
+        for y in 0..shape[0] {
+            for x in 0..shape[1] {
+                for c in 0..channels {
+                    pixels
+                        .push((y * 3 + x * 5 + c * 40) * max / (shape[0] * 3 + shape[1] * 5 + 160));
+                }
+            }
+        }
+        pixels
+    }
+
+    fn image_u8(shape: &[u64]) -> Vec<u8> {
+        image(shape, u64::from(u8::MAX))
+            .into_iter()
+            .map(|v| u8::try_from(v).unwrap())
+            .collect()
+    }
+
+    fn image_u16(shape: &[u64]) -> Vec<u16> {
+        image(shape, u64::from(u16::MAX))
+            .into_iter()
+            .map(|v| u16::try_from(v).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn codec_jpegxl_configuration() {
+        assert!(JpegXlCodec::new(false, 1.0, 7).is_ok());
+        assert!(JpegXlCodec::new(false, 26.0, 7).is_err());
+        assert!(JpegXlCodec::new(true, 0.0, 0).is_err());
+        assert!(JpegXlCodec::new(true, 0.0, 11).is_err());
+        let metadata = codec(JSON_LOSSY).create_metadata().unwrap();
+        assert_eq!(metadata.name(), "zarrs.jpegxl");
+        assert_eq!(
+            metadata
+                .to_configuration::<JpegXlCodecConfiguration>()
+                .unwrap(),
+            serde_json::from_str(JSON_LOSSY).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_jpegxl_round_trip_lossless() -> Result<(), Box<dyn std::error::Error>> {
+        let codec = codec(JSON_LOSSLESS);
+        for shape in [
+            vec![16, 16],
+            vec![9, 7, 1],
+            vec![12, 20, 2],
+            vec![12, 20, 3],
+            vec![7, 9, 4],
+        ] {
+            for (data_type, bytes) in [
+                (DataType::UInt8, image_u8(&shape)),
+                (DataType::UInt16, transmute_to_bytes_vec(image_u16(&shape))),
+            ] {
+                let chunk_representation = chunk_representation(&shape, data_type);
+                let encoded = codec.encode(
+                    ArrayBytes::from(bytes.clone()),
+                    &chunk_representation,
+                    &CodecOptions::default(),
+                )?;
+                let decoded =
+                    codec.decode(encoded, &chunk_representation, &CodecOptions::default())?;
+                assert_eq!(decoded, ArrayBytes::from(bytes));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_jpegxl_round_trip_lossy() -> Result<(), Box<dyn std::error::Error>> {
+        let codec = codec(JSON_LOSSY);
+        let shape = [64, 64];
+        let chunk_representation = chunk_representation(&shape, DataType::UInt16);
+        let elements = image_u16(&shape);
+        let bytes = transmute_to_bytes_vec(elements.clone());
+        let encoded = codec.encode(
+            ArrayBytes::from(bytes.clone()),
+            &chunk_representation,
+            &CodecOptions::default(),
+        )?;
+        assert!(encoded.len() * 4 < bytes.len());
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())?
+            .into_fixed()?;
+        let decoded = crate::array::convert_from_bytes_slice::<u16>(&decoded);
+        assert_eq!(decoded.len(), elements.len());
+        let max_abs_error = elements
+            .iter()
+            .zip(decoded.iter())
+            .map(|(&a, &b)| a.abs_diff(b))
+            .max()
+            .unwrap();
+        assert!(max_abs_error < u16::MAX / 32);
+        Ok(())
+    }
+
+    #[test]
+    fn codec_jpegxl_unsupported() {
+        let codec = codec(JSON_LOSSLESS);
+        for (shape, data_type) in [
+            (vec![8, 8], DataType::UInt32),
+            (vec![8, 8, 3], DataType::Int8),
+            (vec![8, 8, 5], DataType::UInt8),
+            (vec![8], DataType::UInt8),
+            (vec![2, 8, 8, 3], DataType::UInt16),
+        ] {
+            let chunk_representation = chunk_representation(&shape, data_type);
+            assert!(codec.compute_encoded_size(&chunk_representation).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_jpegxl_array() -> Result<(), Box<dyn std::error::Error>> {
+        let store = Arc::new(MemoryStore::default());
+        let array = ArrayBuilder::new(
+            vec![20, 13],
+            DataType::UInt16,
+            vec![8, 8].try_into().unwrap(),
+            FillValue::from(0u16),
+        )
+        .array_to_bytes_codec(codec(JSON_LOSSLESS))
+        .build(store.clone(), "/")?;
+        array.store_metadata()?;
+        let array = Array::open(store, "/")?;
+
+        let subset = array.subset_all();
+        let elements = image_u16(subset.shape());
+        array.store_array_subset_elements(&subset, &elements)?;
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u16>(&subset)?,
+            elements
+        );
+        Ok(())
+    }
+}
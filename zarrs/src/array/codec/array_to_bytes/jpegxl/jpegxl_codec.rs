@@ -0,0 +1,239 @@
+use jpegxl_rs::{
+    decode::{DecodeError, PixelFormat},
+    decoder_builder,
+    encode::{ColorEncoding, EncodeError, EncoderFrame, EncoderResult, EncoderSpeed},
+    encoder_builder, Endianness,
+};
+
+use crate::{
+    array::{
+        codec::{CodecError, CodecOptions, CodecTraits, ImageCodecTraits, ImageRepresentation},
+        convert_from_bytes_slice, transmute_to_bytes_vec, ArrayMetadataOptions,
+        ChunkRepresentation, DataType,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+use super::{JpegXlCodecConfiguration, JpegXlCodecConfigurationV1, IDENTIFIER};
+
+/// The maximum Butteraugli distance supported by the encoder.
+const JPEGXL_MAX_DISTANCE: f32 = 25.0;
+
+/// A `jpegxl` codec implementation.
+///
+/// This is an [`ImageCodecTraits`] codec, so it must be wrapped in an [`ImageCodec`](crate::array::codec::ImageCodec) to be used as an array to bytes codec.
+#[derive(Debug, Clone)]
+pub struct JpegXlCodec {
+    lossless: bool,
+    distance: f32,
+    effort: u8,
+}
+
+impl JpegXlCodec {
+    /// Create a new `jpegxl` codec.
+    ///
+    /// `distance` is the Butteraugli distance used for lossy encoding and is ignored if `lossless` is true.
+    /// `effort` controls the trade-off between encoding speed and size.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `distance` is not between 0.0 and 25.0 or `effort` is not between 1 and 10.
+    pub fn new(lossless: bool, distance: f32, effort: u8) -> Result<Self, PluginCreateError> {
+        if !(0.0..=JPEGXL_MAX_DISTANCE).contains(&distance) {
+            return Err(PluginCreateError::Other(format!(
+                "jpegxl distance {distance} is invalid, must be between 0.0 and {JPEGXL_MAX_DISTANCE}"
+            )));
+        }
+        if !(1..=10).contains(&effort) {
+            return Err(PluginCreateError::Other(format!(
+                "jpegxl effort {effort} is invalid, must be between 1 and 10"
+            )));
+        }
+        Ok(Self {
+            lossless,
+            distance,
+            effort,
+        })
+    }
+
+    /// Create a new `jpegxl` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &JpegXlCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let JpegXlCodecConfiguration::V1(configuration) = configuration;
+        Self::new(
+            configuration.lossless,
+            configuration.distance,
+            configuration.effort,
+        )
+    }
+
+    fn speed(&self) -> EncoderSpeed {
+        match self.effort {
+            1 => EncoderSpeed::Lightning,
+            2 => EncoderSpeed::Thunder,
+            3 => EncoderSpeed::Falcon,
+            4 => EncoderSpeed::Cheetah,
+            5 => EncoderSpeed::Hare,
+            6 => EncoderSpeed::Wombat,
+            7 => EncoderSpeed::Squirrel,
+            8 => EncoderSpeed::Kitten,
+            9 => EncoderSpeed::Tortoise,
+            10 => EncoderSpeed::Glacier,
+            _ => unreachable!("validated by new"),
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn encode_error(err: EncodeError) -> CodecError {
+    CodecError::Other(format!("jpegxl encoding failed: {err}"))
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn decode_error(err: DecodeError) -> CodecError {
+    CodecError::Other(format!("jpegxl decoding failed: {err}"))
+}
+
+impl CodecTraits for JpegXlCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = JpegXlCodecConfiguration::V1(JpegXlCodecConfigurationV1 {
+            lossless: self.lossless,
+            distance: self.distance,
+            effort: self.effort,
+        });
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .expect("jpegxl configuration is valid json"),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+impl ImageCodecTraits for JpegXlCodec {
+    fn image_representation(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ImageRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if !matches!(data_type, DataType::UInt8 | DataType::UInt16) {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ));
+        }
+        let image_representation =
+            ImageRepresentation::from_chunk_representation(decoded_representation)?;
+        if !(1..=4).contains(&image_representation.channels()) {
+            return Err(CodecError::Other(format!(
+                "jpegxl codec supports 1 (grayscale), 2 (grayscale and alpha), 3 (RGB), or 4 (RGBA) channels, got {}",
+                image_representation.channels()
+            )));
+        }
+        Ok(image_representation)
+    }
+
+    fn encode_image(
+        &self,
+        pixels: &[u8],
+        image_representation: &ImageRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Vec<u8>, CodecError> {
+        let channels = image_representation.channels();
+        let color_encoding = if channels < 3 {
+            ColorEncoding::SrgbLuma
+        } else {
+            ColorEncoding::Srgb
+        };
+        let mut jxl_encoder = encoder_builder()
+            .has_alpha(channels % 2 == 0)
+            .lossless(self.lossless)
+            // Lossless encoding requires the original colour profile
+            .uses_original_profile(self.lossless)
+            .quality(if self.lossless { 0.0 } else { self.distance })
+            .speed(self.speed())
+            .color_encoding(color_encoding)
+            .build()
+            .map_err(encode_error)?;
+
+        let (width, height) = (image_representation.width(), image_representation.height());
+        let encoded: EncoderResult<u8> = match image_representation.data_type() {
+            DataType::UInt8 => {
+                let frame = EncoderFrame::new(pixels).num_channels(channels);
+                jxl_encoder.encode_frame(&frame, width, height)
+            }
+            DataType::UInt16 => {
+                let pixels = convert_from_bytes_slice::<u16>(pixels);
+                let frame = EncoderFrame::new(&pixels)
+                    .num_channels(channels)
+                    .endianness(Endianness::Native);
+                jxl_encoder.encode_frame(&frame, width, height)
+            }
+            _ => unreachable!("validated by image_representation"),
+        }
+        .map_err(encode_error)?;
+        Ok(encoded.data)
+    }
+
+    fn decode_image(
+        &self,
+        bytes: &[u8],
+        image_representation: &ImageRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Vec<u8>, CodecError> {
+        let jxl_decoder = decoder_builder()
+            .pixel_format(PixelFormat {
+                num_channels: image_representation.channels(),
+                endianness: Endianness::Native,
+                align: 0,
+            })
+            .build()
+            .map_err(decode_error)?;
+
+        let (metadata, pixels) = match image_representation.data_type() {
+            DataType::UInt8 => jxl_decoder.decode_with::<u8>(bytes).map_err(decode_error)?,
+            DataType::UInt16 => {
+                let (metadata, pixels) = jxl_decoder
+                    .decode_with::<u16>(bytes)
+                    .map_err(decode_error)?;
+                (metadata, transmute_to_bytes_vec(pixels))
+            }
+            _ => unreachable!("validated by image_representation"),
+        };
+        if metadata.width != image_representation.width()
+            || metadata.height != image_representation.height()
+        {
+            return Err(CodecError::Other(format!(
+                "decoded jpegxl image is {}x{}, expected {}x{}",
+                metadata.width,
+                metadata.height,
+                image_representation.width(),
+                image_representation.height()
+            )));
+        }
+        if pixels.len() != image_representation.size() {
+            return Err(CodecError::UnexpectedChunkDecodedSize(
+                pixels.len(),
+                image_representation.size() as u64,
+            ));
+        }
+        Ok(pixels)
+    }
+}
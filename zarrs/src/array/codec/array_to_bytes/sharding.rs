@@ -27,15 +27,18 @@ pub(crate) use sharding_partial_decoder::ShardingPartialDecoder;
 
 use crate::{
     array::{
+        chunk_shape_to_array_shape,
         codec::{
             ArrayToBytesCodecTraits, BytesPartialDecoderTraits, Codec, CodecError, CodecOptions,
             CodecPlugin,
         },
-        BytesRepresentation, ChunkRepresentation, ChunkShape, CodecChain, DataType, FillValue,
+        ravel_indices, unravel_index, ArrayIndices, ArrayShape, BytesRepresentation,
+        ChunkRepresentation, ChunkShape, CodecChain, DataType, FillValue,
     },
     byte_range::ByteRange,
     metadata::v3::{array::codec::sharding, MetadataV3},
     plugin::{PluginCreateError, PluginMetadataInvalidError},
+    storage::StorageError,
 };
 
 pub use sharding::IDENTIFIER;
@@ -57,6 +60,110 @@ pub(crate) fn create_codec_sharding(metadata: &MetadataV3) -> Result<Codec, Plug
     Ok(Codec::ArrayToBytes(codec))
 }
 
+/// A decoded shard index.
+///
+/// The shard index holds the byte range of each inner chunk within an encoded shard.
+/// It can be retrieved without decoding any inner chunks with [`ShardingCodec::decode_index`] or [`Array::shard_index`](crate::array::Array::shard_index).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShardIndex {
+    chunks_per_shard: ArrayShape,
+    offsets_and_nbytes: Vec<u64>,
+}
+
+impl ShardIndex {
+    fn new(chunks_per_shard: &[NonZeroU64], offsets_and_nbytes: Vec<u64>) -> Self {
+        Self {
+            chunks_per_shard: chunk_shape_to_array_shape(chunks_per_shard),
+            offsets_and_nbytes,
+        }
+    }
+
+    /// Return the number of inner chunks along each dimension of the shard.
+    #[must_use]
+    pub fn chunks_per_shard(&self) -> &[u64] {
+        &self.chunks_per_shard
+    }
+
+    /// Return the number of inner chunks in the shard, including those that are missing.
+    #[must_use]
+    pub fn num_chunks(&self) -> u64 {
+        self.offsets_and_nbytes.len() as u64 / 2
+    }
+
+    /// Return the number of inner chunks present in the shard.
+    #[must_use]
+    pub fn num_present(&self) -> u64 {
+        self.num_chunks() - self.num_missing()
+    }
+
+    /// Return the number of inner chunks missing from the shard.
+    ///
+    /// Missing inner chunks are decoded as the fill value.
+    #[must_use]
+    pub fn num_missing(&self) -> u64 {
+        self.offsets_and_nbytes
+            .chunks_exact(2)
+            .filter(|offset_and_nbytes| is_missing(offset_and_nbytes))
+            .count() as u64
+    }
+
+    /// Return the byte range of the inner chunk at `chunk_indices` within the encoded shard.
+    ///
+    /// The `chunk_indices` are relative to the start of the shard.
+    /// Returns [`None`] if the inner chunk is missing or `chunk_indices` are outside of the shard.
+    #[must_use]
+    pub fn inner_chunk_byte_range(&self, chunk_indices: &[u64]) -> Option<ByteRange> {
+        if chunk_indices.len() != self.chunks_per_shard.len()
+            || std::iter::zip(chunk_indices, &self.chunks_per_shard).any(|(i, n)| i >= n)
+        {
+            return None;
+        }
+        let index = usize::try_from(ravel_indices(chunk_indices, &self.chunks_per_shard)).ok()?;
+        self.offsets_and_nbytes
+            .chunks_exact(2)
+            .nth(index)
+            .and_then(offset_and_nbytes_to_byte_range)
+    }
+
+    /// Return an iterator over the indices and byte ranges of the inner chunks present in the shard.
+    ///
+    /// Inner chunks are visited in lexicographical order of their indices.
+    pub fn iter_present(&self) -> impl Iterator<Item = (ArrayIndices, ByteRange)> + '_ {
+        self.offsets_and_nbytes
+            .chunks_exact(2)
+            .enumerate()
+            .filter_map(|(index, offset_and_nbytes)| {
+                offset_and_nbytes_to_byte_range(offset_and_nbytes).map(|byte_range| {
+                    (
+                        unravel_index(index as u64, &self.chunks_per_shard),
+                        byte_range,
+                    )
+                })
+            })
+    }
+
+    /// Return the decoded shard index, which holds the offset and size in bytes of each inner chunk.
+    ///
+    /// A missing inner chunk has an offset and size of [`u64::MAX`].
+    #[must_use]
+    pub fn as_slice(&self) -> &[u64] {
+        &self.offsets_and_nbytes
+    }
+}
+
+fn is_missing(offset_and_nbytes: &[u64]) -> bool {
+    offset_and_nbytes == [u64::MAX, u64::MAX]
+}
+
+fn offset_and_nbytes_to_byte_range(offset_and_nbytes: &[u64]) -> Option<ByteRange> {
+    if is_missing(offset_and_nbytes) {
+        None
+    } else {
+        let (offset, nbytes) = (offset_and_nbytes[0], offset_and_nbytes[1]);
+        Some(ByteRange::new(offset..offset.saturating_add(nbytes)))
+    }
+}
+
 fn calculate_chunks_per_shard(
     shard_shape: &[NonZeroU64],
     chunk_shape: &[NonZeroU64],
@@ -104,11 +211,16 @@ fn decode_shard_index(
     options: &CodecOptions,
 ) -> Result<Vec<u64>, CodecError> {
     // Decode the shard index
-    let decoded_shard_index = index_codecs.decode(
-        Cow::Borrowed(encoded_shard_index),
-        index_array_representation,
-        options,
-    )?;
+    let decoded_shard_index = index_codecs
+        .decode(
+            Cow::Borrowed(encoded_shard_index),
+            index_array_representation,
+            options,
+        )
+        .map_err(|err| CodecError::ShardIndexCorrupt {
+            key: None,
+            reason: err.to_string(),
+        })?;
     let decoded_shard_index = decoded_shard_index.into_fixed()?;
     Ok(decoded_shard_index
         .chunks_exact(size_of::<u64>())
@@ -151,6 +263,22 @@ fn get_index_byte_range(
     })
 }
 
+/// Map an invalid byte range error to [`CodecError::ShardIndexCorrupt`] with `reason`.
+///
+/// An invalid byte range is requested when the encoded shard is truncated.
+fn shard_index_corrupt_if_byte_range_err(err: CodecError, reason: &str) -> CodecError {
+    match err {
+        CodecError::InvalidByteRangeError(_)
+        | CodecError::StorageError(StorageError::InvalidByteRangeError(_)) => {
+            CodecError::ShardIndexCorrupt {
+                key: None,
+                reason: reason.to_string(),
+            }
+        }
+        err => err,
+    }
+}
+
 /// Returns `None` if there is no shard.
 fn decode_shard_index_partial_decoder(
     input_handle: &dyn BytesPartialDecoderTraits,
@@ -165,7 +293,13 @@ fn decode_shard_index_partial_decoder(
     let index_byte_range =
         get_index_byte_range(&index_array_representation, index_codecs, index_location)?;
     let encoded_shard_index = input_handle
-        .partial_decode(&[index_byte_range], options)?
+        .partial_decode(&[index_byte_range], options)
+        .map_err(|err| {
+            shard_index_corrupt_if_byte_range_err(
+                err,
+                "the encoded shard is smaller than the expected size of its index",
+            )
+        })?
         .map(|mut v| v.remove(0));
     Ok(match encoded_shard_index {
         Some(encoded_shard_index) => Some(decode_shard_index(
@@ -194,7 +328,13 @@ async fn decode_shard_index_async_partial_decoder(
         get_index_byte_range(&index_array_representation, index_codecs, index_location)?;
     let encoded_shard_index = input_handle
         .partial_decode(&[index_byte_range], options)
-        .await?
+        .await
+        .map_err(|err| {
+            shard_index_corrupt_if_byte_range_err(
+                err,
+                "the encoded shard is smaller than the expected size of its index",
+            )
+        })?
         .map(|mut v| v.remove(0));
     Ok(match encoded_shard_index {
         Some(encoded_shard_index) => Some(decode_shard_index(
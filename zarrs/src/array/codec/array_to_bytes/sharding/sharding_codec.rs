@@ -11,7 +11,8 @@ use crate::{
         codec::{
             ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderTraits,
             ArrayToBytesCodecTraits, BytesPartialDecoderTraits, BytesPartialEncoderTraits,
-            CodecChain, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+            CodecChain, CodecError, CodecIncompatibleWithDataTypeError, CodecOptions, CodecTraits,
+            RecommendedConcurrency,
         },
         concurrency::calc_concurrency_outer_inner,
         transmute_to_bytes_vec, unravel_index, ArrayBytes, ArrayMetadataOptions, ArraySize,
@@ -27,10 +28,15 @@ use crate::array::codec::{AsyncArrayPartialDecoderTraits, AsyncBytesPartialDecod
 
 use super::{
     calculate_chunks_per_shard, compute_index_encoded_size, decode_shard_index,
-    sharding_index_decoded_representation, sharding_partial_decoder, sharding_partial_encoder,
-    ShardingCodecConfiguration, ShardingCodecConfigurationV1, ShardingIndexLocation, IDENTIFIER,
+    decode_shard_index_partial_decoder, sharding_index_decoded_representation,
+    sharding_partial_decoder, sharding_partial_encoder, ShardIndex, ShardingCodecConfiguration,
+    ShardingCodecConfigurationV1, ShardingIndexLocation, IDENTIFIER,
 };
 
+#[cfg(feature = "async")]
+use super::decode_shard_index_async_partial_decoder;
+
+use itertools::Itertools;
 use rayon::prelude::*;
 use unsafe_cell_slice::UnsafeCellSlice;
 
@@ -82,6 +88,150 @@ impl ShardingCodec {
             configuration.index_location,
         ))
     }
+
+    /// Decode the index of an encoded shard, reading only the bytes of the index from `input_handle`.
+    ///
+    /// No inner chunks are read or decoded.
+    /// Returns [`None`] if the shard does not exist.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the shard index cannot be read or decoded (e.g. the shard is truncated).
+    pub fn decode_index(
+        &self,
+        input_handle: &dyn BytesPartialDecoderTraits,
+        shard_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Option<ShardIndex>, CodecError> {
+        let chunks_per_shard =
+            calculate_chunks_per_shard(shard_representation.shape(), self.chunk_shape.as_slice())?;
+        let shard_index = decode_shard_index_partial_decoder(
+            input_handle,
+            &self.index_codecs,
+            self.index_location,
+            self.chunk_shape.as_slice(),
+            shard_representation,
+            options,
+        )?;
+        Ok(
+            shard_index
+                .map(|shard_index| ShardIndex::new(chunks_per_shard.as_slice(), shard_index)),
+        )
+    }
+
+    #[cfg(feature = "async")]
+    /// Asynchronous variant of [`decode_index`](ShardingCodec::decode_index).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_decode_index(
+        &self,
+        input_handle: &dyn AsyncBytesPartialDecoderTraits,
+        shard_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Option<ShardIndex>, CodecError> {
+        let chunks_per_shard =
+            calculate_chunks_per_shard(shard_representation.shape(), self.chunk_shape.as_slice())?;
+        let shard_index = decode_shard_index_async_partial_decoder(
+            input_handle,
+            &self.index_codecs,
+            self.index_location,
+            self.chunk_shape.as_slice(),
+            shard_representation,
+            options,
+        )
+        .await?;
+        Ok(
+            shard_index
+                .map(|shard_index| ShardIndex::new(chunks_per_shard.as_slice(), shard_index)),
+        )
+    }
+
+    /// Decode the index of an encoded shard held in memory.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the shard index cannot be decoded (e.g. the shard is truncated).
+    pub fn decode_index_from_bytes(
+        &self,
+        encoded_shard: &[u8],
+        shard_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<ShardIndex, CodecError> {
+        let chunks_per_shard =
+            calculate_chunks_per_shard(shard_representation.shape(), self.chunk_shape.as_slice())?;
+        let shard_index =
+            self.decode_index_vec(encoded_shard, chunks_per_shard.as_slice(), options)?;
+        Ok(ShardIndex::new(chunks_per_shard.as_slice(), shard_index))
+    }
+
+    /// Compact an encoded shard by removing bytes that are not referenced by the shard index.
+    ///
+    /// Partial encoding appends updated inner chunks to the end of a shard, leaving the previously encoded inner chunks unreferenced.
+    /// The compacted shard has contiguous inner chunks in the order of the shard index, and the inner chunks are not reencoded.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if the shard index cannot be decoded or encoded, or it references bytes outside of the shard.
+    pub fn compact(
+        &self,
+        encoded_shard: &[u8],
+        shard_representation: &ChunkRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Vec<u8>, CodecError> {
+        let chunks_per_shard =
+            calculate_chunks_per_shard(shard_representation.shape(), self.chunk_shape.as_slice())?;
+        let shard_index =
+            self.decode_index_vec(encoded_shard, chunks_per_shard.as_slice(), options)?;
+        let index_decoded_representation =
+            sharding_index_decoded_representation(chunks_per_shard.as_slice());
+        let index_encoded_size =
+            compute_index_encoded_size(self.index_codecs.as_ref(), &index_decoded_representation)?;
+
+        // Build the compacted shard index
+        let mut shard_index_compacted = Vec::with_capacity(shard_index.len());
+        let mut inner_chunks = Vec::new();
+        let mut offset = match self.index_location {
+            ShardingIndexLocation::Start => index_encoded_size,
+            ShardingIndexLocation::End => 0,
+        };
+        for (&chunk_offset, &chunk_size) in shard_index.iter().tuples() {
+            if chunk_offset == u64::MAX && chunk_size == u64::MAX {
+                shard_index_compacted.extend([u64::MAX, u64::MAX]);
+            } else {
+                let inner_chunk = usize::try_from(chunk_offset)
+                    .ok()
+                    .zip(usize::try_from(chunk_offset.saturating_add(chunk_size)).ok())
+                    .and_then(|(start, end)| encoded_shard.get(start..end))
+                    .ok_or_else(|| {
+                        CodecError::Other(format!(
+                            "the shard index references bytes {chunk_offset}..{} outside of the shard of size {}",
+                            chunk_offset.saturating_add(chunk_size),
+                            encoded_shard.len()
+                        ))
+                    })?;
+                shard_index_compacted.extend([offset, chunk_size]);
+                offset += chunk_size;
+                inner_chunks.push(inner_chunk);
+            }
+        }
+
+        // Encode the compacted shard
+        let encoded_array_index = self
+            .index_codecs
+            .encode(
+                ArrayBytes::from(transmute_to_bytes_vec(shard_index_compacted)),
+                &index_decoded_representation,
+                options,
+            )?
+            .into_owned();
+        let mut shard = Vec::with_capacity(encoded_shard.len());
+        if self.index_location == ShardingIndexLocation::Start {
+            shard.extend_from_slice(&encoded_array_index);
+        }
+        for inner_chunk in inner_chunks {
+            shard.extend_from_slice(inner_chunk);
+        }
+        if self.index_location == ShardingIndexLocation::End {
+            shard.extend_from_slice(&encoded_array_index);
+        }
+        Ok(shard)
+    }
 }
 
 impl CodecTraits for ShardingCodec {
@@ -199,7 +349,7 @@ impl ArrayToBytesCodecTraits for ShardingCodec {
             .product::<usize>();
 
         let shard_index =
-            self.decode_index(&encoded_shard, chunks_per_shard.as_slice(), options)?;
+            self.decode_index_vec(&encoded_shard, chunks_per_shard.as_slice(), options)?;
 
         let any_empty = shard_index
             .par_iter()
@@ -233,11 +383,14 @@ impl ArrayToBytesCodecTraits for ShardingCodec {
                             chunk_representation.num_elements(),
                         );
                         ArrayBytes::new_fill_value(array_size, chunk_representation.fill_value())
-                    } else if usize::try_from(offset + size).unwrap() > encoded_shard.len() {
-                        return Err(CodecError::Other(
-                            "The shard index references out-of-bounds bytes. The chunk may be corrupted."
-                                .to_string(),
-                        ));
+                    } else if offset
+                        .checked_add(size)
+                        .is_none_or(|end| end > encoded_shard.len() as u64)
+                    {
+                        return Err(CodecError::ShardIndexCorrupt {
+                            key: None,
+                            reason: "the shard index references out-of-bounds bytes".to_string(),
+                        });
                     } else {
                         let offset: usize = offset.try_into().unwrap();
                         let size: usize = size.try_into().unwrap();
@@ -314,11 +467,15 @@ impl ArrayToBytesCodecTraits for ShardingCodec {
                             } else {
                                 unreachable!();
                             }
-                        } else if usize::try_from(offset + size).unwrap() > encoded_shard.len() {
-                            return Err(CodecError::Other(
-                                "The shard index references out-of-bounds bytes. The chunk may be corrupted."
+                        } else if offset
+                            .checked_add(size)
+                            .is_none_or(|end| end > encoded_shard.len() as u64)
+                        {
+                            return Err(CodecError::ShardIndexCorrupt {
+                                key: None,
+                                reason: "the shard index references out-of-bounds bytes"
                                     .to_string(),
-                            ));
+                            });
                         } else {
                             let offset: usize = offset.try_into().unwrap();
                             let size: usize = size.try_into().unwrap();
@@ -380,7 +537,7 @@ impl ArrayToBytesCodecTraits for ShardingCodec {
             .product::<usize>();
 
         let shard_index =
-            self.decode_index(&encoded_shard, chunks_per_shard.as_slice(), options)?;
+            self.decode_index_vec(&encoded_shard, chunks_per_shard.as_slice(), options)?;
 
         let any_empty = shard_index
             .par_iter()
@@ -454,11 +611,15 @@ impl ArrayToBytesCodecTraits for ShardingCodec {
                             } else {
                                 unreachable!();
                             }
-                        } else if usize::try_from(offset + size).unwrap() > encoded_shard.len() {
-                            return Err(CodecError::Other(
-                                "The shard index references out-of-bounds bytes. The chunk may be corrupted."
+                        } else if offset
+                            .checked_add(size)
+                            .is_none_or(|end| end > encoded_shard.len() as u64)
+                        {
+                            return Err(CodecError::ShardIndexCorrupt {
+                                key: None,
+                                reason: "the shard index references out-of-bounds bytes"
                                     .to_string(),
-                            ));
+                            });
                         } else {
                             let offset: usize = offset.try_into().unwrap();
                             let size: usize = size.try_into().unwrap();
@@ -556,6 +717,12 @@ impl ArrayToBytesCodecTraits for ShardingCodec {
         &self,
         decoded_representation: &ChunkRepresentation,
     ) -> Result<BytesRepresentation, CodecError> {
+        // Check the inner chunk shape evenly divides the shard shape
+        let chunks_per_shard = calculate_chunks_per_shard(
+            decoded_representation.shape(),
+            self.chunk_shape.as_slice(),
+        )?;
+
         // Get the maximum size of encoded chunks
         let chunk_representation = unsafe {
             ChunkRepresentation::new_unchecked(
@@ -570,10 +737,6 @@ impl ArrayToBytesCodecTraits for ShardingCodec {
 
         match chunk_bytes_representation {
             BytesRepresentation::BoundedSize(size) | BytesRepresentation::FixedSize(size) => {
-                let chunks_per_shard = calculate_chunks_per_shard(
-                    decoded_representation.shape(),
-                    self.chunk_shape.as_slice(),
-                )?;
                 let index_decoded_representation =
                     sharding_index_decoded_representation(chunks_per_shard.as_slice());
                 let index_encoded_size = compute_index_encoded_size(
@@ -590,6 +753,25 @@ impl ArrayToBytesCodecTraits for ShardingCodec {
             BytesRepresentation::UnboundedSize => Ok(BytesRepresentation::UnboundedSize),
         }
     }
+
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        // Inner chunks have the data type of the shard, and the index is always uint64
+        let chunk_representation = unsafe {
+            ChunkRepresentation::new_unchecked(
+                self.chunk_shape.as_slice().to_vec(),
+                decoded_representation.data_type().clone(),
+                decoded_representation.fill_value().clone(),
+            )
+        };
+        let index_decoded_representation =
+            sharding_index_decoded_representation(&vec![NonZeroU64::MIN; self.chunk_shape.len()]);
+        let mut incompatibilities = self.inner_codecs.validate(&chunk_representation);
+        incompatibilities.extend(self.index_codecs.validate(&index_decoded_representation));
+        incompatibilities
+    }
 }
 
 impl ShardingCodec {
@@ -904,7 +1086,7 @@ impl ShardingCodec {
         Ok(shard)
     }
 
-    fn decode_index(
+    fn decode_index_vec(
         &self,
         encoded_shard: &[u8],
         chunks_per_shard: &[NonZeroU64],
@@ -917,9 +1099,11 @@ impl ShardingCodec {
 
         // Get encoded shard index
         if (encoded_shard.len() as u64) < index_encoded_size {
-            return Err(CodecError::Other(
-                "The encoded shard is smaller than the expected size of its index.".to_string(),
-            ));
+            return Err(CodecError::ShardIndexCorrupt {
+                key: None,
+                reason: "the encoded shard is smaller than the expected size of its index"
+                    .to_string(),
+            });
         }
 
         let encoded_shard_index = match self.index_location {
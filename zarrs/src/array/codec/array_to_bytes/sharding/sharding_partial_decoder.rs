@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{num::NonZeroU64, sync::Arc};
 
 use rayon::prelude::*;
 use unsafe_cell_slice::UnsafeCellSlice;
@@ -8,8 +8,8 @@ use crate::array::{
     array_bytes::{merge_chunks_vlen, update_bytes_flen},
     codec::{
         ArrayCodecTraits, ArrayPartialDecoderTraits, ArraySubset, ArrayToBytesCodecTraits,
-        ByteIntervalPartialDecoder, BytesPartialDecoderTraits, CodecChain, CodecError,
-        CodecOptions,
+        ByteIntervalPartialDecoder, BytesPartialDecoderRangeCache, BytesPartialDecoderTraits,
+        CodecChain, CodecError, CodecOptions,
     },
     concurrency::{calc_concurrency_outer_inner, RecommendedConcurrency},
     ravel_indices, ArrayBytes, ArraySize, ChunkRepresentation, ChunkShape, DataType, DataTypeSize,
@@ -19,10 +19,37 @@ use crate::array::{
 #[cfg(feature = "async")]
 use crate::array::codec::{
     byte_interval_partial_decoder::AsyncByteIntervalPartialDecoder, AsyncArrayPartialDecoderTraits,
-    AsyncBytesPartialDecoderTraits,
+    AsyncBytesPartialDecoderRangeCache, AsyncBytesPartialDecoderTraits,
 };
 
-use super::{calculate_chunks_per_shard, ShardingIndexLocation};
+use super::{
+    calculate_chunks_per_shard, shard_index_corrupt_if_byte_range_err, ShardingIndexLocation,
+};
+
+/// Return the byte ranges of the stored inner chunks intersecting `array_subsets`.
+fn inner_chunk_byte_ranges(
+    array_subsets: &[ArraySubset],
+    chunk_shape: &[NonZeroU64],
+    chunks_per_shard: &[u64],
+    shard_index: &[u64],
+) -> Vec<ByteRange> {
+    let mut byte_ranges = Vec::new();
+    for array_subset in array_subsets {
+        let chunks = unsafe { array_subset.chunks_unchecked(chunk_shape) };
+        for (chunk_indices, _) in &chunks {
+            let shard_index_idx =
+                usize::try_from(ravel_indices(&chunk_indices, chunks_per_shard) * 2).unwrap();
+            let offset = shard_index[shard_index_idx];
+            let size = shard_index[shard_index_idx + 1];
+            if !(offset == u64::MAX && size == u64::MAX) {
+                byte_ranges.push(ByteRange::new(offset..offset.saturating_add(size)));
+            }
+        }
+    }
+    byte_ranges.sort();
+    byte_ranges.dedup();
+    byte_ranges
+}
 
 /// Partial decoder for the sharding codec.
 pub(crate) struct ShardingPartialDecoder {
@@ -78,7 +105,7 @@ impl ShardingPartialDecoder {
                 usize::try_from(ravel_indices(chunk_indices, &chunks_per_shard) * 2).unwrap();
             let offset = shard_index[shard_index_idx];
             let size = shard_index[shard_index_idx + 1];
-            Ok(Some(ByteRange::new(offset..offset + size)))
+            Ok(Some(ByteRange::new(offset..offset.saturating_add(size))))
         } else {
             Ok(None)
         }
@@ -149,6 +176,23 @@ impl ArrayPartialDecoderTraits for ShardingPartialDecoder {
         let chunks_per_shard = chunks_per_shard.to_array_shape();
         let num_chunks = usize::try_from(chunks_per_shard.iter().product::<u64>()).unwrap();
 
+        // Retrieve the intersecting inner chunks with a single request if byte ranges are coalesced
+        let input_handle: Arc<dyn BytesPartialDecoderTraits> =
+            if options.range_coalesce_gap().is_some() {
+                Arc::new(BytesPartialDecoderRangeCache::new(
+                    self.input_handle.clone(),
+                    &inner_chunk_byte_ranges(
+                        array_subsets,
+                        chunk_representation.shape(),
+                        &chunks_per_shard,
+                        shard_index,
+                    ),
+                    options,
+                )?)
+            } else {
+                self.input_handle.clone()
+            };
+
         // Calculate inner chunk/codec concurrency
         let (inner_chunk_concurrent_limit, concurrency_limit_codec) = calc_concurrency_outer_inner(
             options.concurrent_target(),
@@ -171,65 +215,65 @@ impl ArrayPartialDecoderTraits for ShardingPartialDecoder {
 
             match self.decoded_representation.element_size() {
                 DataTypeSize::Variable => {
-                    let decode_inner_chunk_subset = |(chunk_indices, chunk_subset): (
-                        Vec<u64>,
-                        _,
-                    )| {
-                        let shard_index_idx: usize =
-                            usize::try_from(ravel_indices(&chunk_indices, &chunks_per_shard) * 2)
-                                .unwrap();
-                        let offset = shard_index[shard_index_idx];
-                        let size = shard_index[shard_index_idx + 1];
-
-                        // Get the subset of bytes from the chunk which intersect the array
-                        let chunk_subset_overlap =
-                            unsafe { array_subset.overlap_unchecked(&chunk_subset) };
-
-                        let chunk_subset_bytes = if offset == u64::MAX && size == u64::MAX {
-                            let array_size = ArraySize::new(
-                                chunk_representation.data_type().size(),
-                                chunk_subset_overlap.num_elements(),
-                            );
-                            ArrayBytes::new_fill_value(
-                                array_size,
-                                chunk_representation.fill_value(),
-                            )
-                        } else {
-                            // Partially decode the inner chunk
-                            let partial_decoder = self.inner_codecs.clone().partial_decoder(
-                                Arc::new(ByteIntervalPartialDecoder::new(
-                                    self.input_handle.clone(),
-                                    offset,
-                                    size,
-                                )),
-                                &chunk_representation,
-                                &options,
+                    let decode_inner_chunk_subset =
+                        |(chunk_indices, chunk_subset): (Vec<u64>, _)| {
+                            let shard_index_idx: usize = usize::try_from(
+                                ravel_indices(&chunk_indices, &chunks_per_shard) * 2,
                             )
-                            .map_err(|err| if let CodecError::InvalidByteRangeError(_) = err {
-                                CodecError::Other(
-                                    "The shard index references out-of-bounds bytes. The chunk may be corrupted."
-                                        .to_string(),
+                            .unwrap();
+                            let offset = shard_index[shard_index_idx];
+                            let size = shard_index[shard_index_idx + 1];
+
+                            // Get the subset of bytes from the chunk which intersect the array
+                            let chunk_subset_overlap =
+                                unsafe { array_subset.overlap_unchecked(&chunk_subset) };
+
+                            let chunk_subset_bytes = if offset == u64::MAX && size == u64::MAX {
+                                let array_size = ArraySize::new(
+                                    chunk_representation.data_type().size(),
+                                    chunk_subset_overlap.num_elements(),
+                                );
+                                ArrayBytes::new_fill_value(
+                                    array_size,
+                                    chunk_representation.fill_value(),
                                 )
                             } else {
-                                err
-                            })?;
-                            partial_decoder
-                                .partial_decode(
-                                    &[chunk_subset_overlap
-                                        .relative_to(chunk_subset.start())
-                                        .unwrap()],
-                                    &options,
-                                )?
-                                .remove(0)
-                                .into_owned()
+                                // Partially decode the inner chunk
+                                let partial_decoder = self
+                                    .inner_codecs
+                                    .clone()
+                                    .partial_decoder(
+                                        Arc::new(ByteIntervalPartialDecoder::new(
+                                            input_handle.clone(),
+                                            offset,
+                                            size,
+                                        )),
+                                        &chunk_representation,
+                                        &options,
+                                    )
+                                    .map_err(|err| {
+                                        shard_index_corrupt_if_byte_range_err(
+                                            err,
+                                            "the shard index references out-of-bounds bytes",
+                                        )
+                                    })?;
+                                partial_decoder
+                                    .partial_decode(
+                                        &[chunk_subset_overlap
+                                            .relative_to(chunk_subset.start())
+                                            .unwrap()],
+                                        &options,
+                                    )?
+                                    .remove(0)
+                                    .into_owned()
+                            };
+                            Ok::<_, CodecError>((
+                                chunk_subset_bytes,
+                                chunk_subset_overlap
+                                    .relative_to(array_subset.start())
+                                    .unwrap(),
+                            ))
                         };
-                        Ok::<_, CodecError>((
-                            chunk_subset_bytes,
-                            chunk_subset_overlap
-                                .relative_to(array_subset.start())
-                                .unwrap(),
-                        ))
-                    };
 
                     // Decode the inner chunk subsets
                     let chunk_bytes_and_subsets =
@@ -252,70 +296,70 @@ impl ArrayPartialDecoderTraits for ShardingPartialDecoder {
                     let out_array_subset_slice =
                         UnsafeCellSlice::new(out_array_subset.as_mut_slice());
 
-                    let decode_inner_chunk_subset_into_slice = |(chunk_indices, chunk_subset): (
-                        Vec<u64>,
-                        _,
-                    )| {
-                        let shard_index_idx: usize =
-                            usize::try_from(ravel_indices(&chunk_indices, &chunks_per_shard) * 2)
-                                .unwrap();
-                        let offset = shard_index[shard_index_idx];
-                        let size = shard_index[shard_index_idx + 1];
-
-                        // Get the subset of bytes from the chunk which intersect the array
-                        let chunk_subset_overlap =
-                            unsafe { array_subset.overlap_unchecked(&chunk_subset) };
-
-                        let decoded_bytes = if offset == u64::MAX && size == u64::MAX {
-                            let array_size = ArraySize::new(
-                                chunk_representation.data_type().size(),
-                                chunk_subset_overlap.num_elements(),
-                            );
-                            ArrayBytes::new_fill_value(
-                                array_size,
-                                chunk_representation.fill_value(),
+                    let decode_inner_chunk_subset_into_slice =
+                        |(chunk_indices, chunk_subset): (Vec<u64>, _)| {
+                            let shard_index_idx: usize = usize::try_from(
+                                ravel_indices(&chunk_indices, &chunks_per_shard) * 2,
                             )
-                        } else {
-                            // Partially decode the inner chunk
-                            let partial_decoder = self.inner_codecs.clone().partial_decoder(
-                                Arc::new(ByteIntervalPartialDecoder::new(
-                                    self.input_handle.clone(),
-                                    offset,
-                                    size,
-                                )),
-                                &chunk_representation,
-                                &options,
-                            )
-                            .map_err(|err| if let CodecError::InvalidByteRangeError(_) = err {
-                                CodecError::Other(
-                                    "The shard index references out-of-bounds bytes. The chunk may be corrupted."
-                                        .to_string(),
+                            .unwrap();
+                            let offset = shard_index[shard_index_idx];
+                            let size = shard_index[shard_index_idx + 1];
+
+                            // Get the subset of bytes from the chunk which intersect the array
+                            let chunk_subset_overlap =
+                                unsafe { array_subset.overlap_unchecked(&chunk_subset) };
+
+                            let decoded_bytes = if offset == u64::MAX && size == u64::MAX {
+                                let array_size = ArraySize::new(
+                                    chunk_representation.data_type().size(),
+                                    chunk_subset_overlap.num_elements(),
+                                );
+                                ArrayBytes::new_fill_value(
+                                    array_size,
+                                    chunk_representation.fill_value(),
                                 )
                             } else {
-                                err
-                            })?;
-                            partial_decoder
-                                .partial_decode(
-                                    &[chunk_subset_overlap
-                                        .relative_to(chunk_subset.start())
-                                        .unwrap()],
-                                    &options,
-                                )?
-                                .remove(0)
-                                .into_owned()
+                                // Partially decode the inner chunk
+                                let partial_decoder = self
+                                    .inner_codecs
+                                    .clone()
+                                    .partial_decoder(
+                                        Arc::new(ByteIntervalPartialDecoder::new(
+                                            input_handle.clone(),
+                                            offset,
+                                            size,
+                                        )),
+                                        &chunk_representation,
+                                        &options,
+                                    )
+                                    .map_err(|err| {
+                                        shard_index_corrupt_if_byte_range_err(
+                                            err,
+                                            "the shard index references out-of-bounds bytes",
+                                        )
+                                    })?;
+                                partial_decoder
+                                    .partial_decode(
+                                        &[chunk_subset_overlap
+                                            .relative_to(chunk_subset.start())
+                                            .unwrap()],
+                                        &options,
+                                    )?
+                                    .remove(0)
+                                    .into_owned()
+                            };
+                            let decoded_bytes = decoded_bytes.into_fixed()?;
+                            update_bytes_flen(
+                                &out_array_subset_slice,
+                                array_subset.shape(),
+                                &decoded_bytes,
+                                &chunk_subset_overlap
+                                    .relative_to(array_subset.start())
+                                    .unwrap(),
+                                data_type_size,
+                            );
+                            Ok::<_, CodecError>(())
                         };
-                        let decoded_bytes = decoded_bytes.into_fixed()?;
-                        update_bytes_flen(
-                            &out_array_subset_slice,
-                            array_subset.shape(),
-                            &decoded_bytes,
-                            &chunk_subset_overlap
-                                .relative_to(array_subset.start())
-                                .unwrap(),
-                            data_type_size,
-                        );
-                        Ok::<_, CodecError>(())
-                    };
 
                     rayon_iter_concurrent_limit::iter_concurrent_limit!(
                         inner_chunk_concurrent_limit,
@@ -419,6 +463,27 @@ impl AsyncArrayPartialDecoderTraits for AsyncShardingPartialDecoder {
             )
         };
 
+        // Retrieve the intersecting inner chunks with a single request if byte ranges are coalesced
+        let input_handle: Arc<dyn AsyncBytesPartialDecoderTraits> =
+            if options.range_coalesce_gap().is_some() {
+                Arc::new(
+                    AsyncBytesPartialDecoderRangeCache::async_new(
+                        self.input_handle.clone(),
+                        &inner_chunk_byte_ranges(
+                            array_subsets,
+                            chunk_representation.shape(),
+                            &chunks_per_shard,
+                            shard_index,
+                        ),
+                        options,
+                    )
+                    .await?,
+                )
+            } else {
+                self.input_handle.clone()
+            };
+        let input_handle = &input_handle;
+
         let mut out = Vec::with_capacity(array_subsets.len());
         // TODO: Could go parallel here?
         for array_subset in array_subsets {
@@ -427,69 +492,70 @@ impl AsyncArrayPartialDecoderTraits for AsyncShardingPartialDecoder {
                     let chunks =
                         unsafe { array_subset.chunks_unchecked(chunk_representation.shape()) };
 
-                    let decode_inner_chunk_subset = |(chunk_indices, chunk_subset): (
-                        Vec<u64>,
-                        _,
-                    )| {
-                        let shard_index_idx: usize =
-                            usize::try_from(ravel_indices(&chunk_indices, &chunks_per_shard) * 2)
-                                .unwrap();
-                        let chunk_representation = chunk_representation.clone();
-                        async move {
-                            let offset = shard_index[shard_index_idx];
-                            let size = shard_index[shard_index_idx + 1];
-
-                            // Get the subset of bytes from the chunk which intersect the array
-                            let chunk_subset_overlap =
-                                unsafe { array_subset.overlap_unchecked(&chunk_subset) };
+                    let decode_inner_chunk_subset =
+                        |(chunk_indices, chunk_subset): (Vec<u64>, _)| {
+                            let shard_index_idx: usize = usize::try_from(
+                                ravel_indices(&chunk_indices, &chunks_per_shard) * 2,
+                            )
+                            .unwrap();
+                            let chunk_representation = chunk_representation.clone();
+                            async move {
+                                let offset = shard_index[shard_index_idx];
+                                let size = shard_index[shard_index_idx + 1];
 
-                            let chunk_subset_bytes = if offset == u64::MAX && size == u64::MAX {
-                                let array_size = ArraySize::new(
-                                    self.data_type().size(),
-                                    chunk_subset_overlap.num_elements(),
-                                );
-                                ArrayBytes::new_fill_value(
-                                    array_size,
-                                    chunk_representation.fill_value(),
-                                )
-                            } else {
-                                // Partially decode the inner chunk
-                                let partial_decoder = self.inner_codecs.clone().async_partial_decoder(
-                                    Arc::new(AsyncByteIntervalPartialDecoder::new(
-                                        self.input_handle.clone(),
-                                        offset,
-                                        size,
-                                    )),
-                                    &chunk_representation,
-                                    options,
-                                ).await
-                                .map_err(|err| if let CodecError::InvalidByteRangeError(_) = err {
-                                    CodecError::Other(
-                                        "The shard index references out-of-bounds bytes. The chunk may be corrupted."
-                                            .to_string(),
+                                // Get the subset of bytes from the chunk which intersect the array
+                                let chunk_subset_overlap =
+                                    unsafe { array_subset.overlap_unchecked(&chunk_subset) };
+
+                                let chunk_subset_bytes = if offset == u64::MAX && size == u64::MAX {
+                                    let array_size = ArraySize::new(
+                                        self.data_type().size(),
+                                        chunk_subset_overlap.num_elements(),
+                                    );
+                                    ArrayBytes::new_fill_value(
+                                        array_size,
+                                        chunk_representation.fill_value(),
                                     )
                                 } else {
-                                    err
-                                })?;
-                                partial_decoder
-                                    .partial_decode(
-                                        &[chunk_subset_overlap
-                                            .relative_to(chunk_subset.start())
-                                            .unwrap()],
-                                        options,
-                                    )
-                                    .await?
-                                    .remove(0)
-                                    .into_owned()
-                            };
-                            Ok::<_, CodecError>((
-                                chunk_subset_bytes,
-                                chunk_subset_overlap
-                                    .relative_to(array_subset.start())
-                                    .unwrap(),
-                            ))
-                        }
-                    };
+                                    // Partially decode the inner chunk
+                                    let partial_decoder = self
+                                        .inner_codecs
+                                        .clone()
+                                        .async_partial_decoder(
+                                            Arc::new(AsyncByteIntervalPartialDecoder::new(
+                                                input_handle.clone(),
+                                                offset,
+                                                size,
+                                            )),
+                                            &chunk_representation,
+                                            options,
+                                        )
+                                        .await
+                                        .map_err(|err| {
+                                            shard_index_corrupt_if_byte_range_err(
+                                                err,
+                                                "the shard index references out-of-bounds bytes",
+                                            )
+                                        })?;
+                                    partial_decoder
+                                        .partial_decode(
+                                            &[chunk_subset_overlap
+                                                .relative_to(chunk_subset.start())
+                                                .unwrap()],
+                                            options,
+                                        )
+                                        .await?
+                                        .remove(0)
+                                        .into_owned()
+                                };
+                                Ok::<_, CodecError>((
+                                    chunk_subset_bytes,
+                                    chunk_subset_overlap
+                                        .relative_to(array_subset.start())
+                                        .unwrap(),
+                                ))
+                            }
+                        };
 
                     // Decode the inner chunk subsets
                     let futures = chunks.iter().map(decode_inner_chunk_subset);
@@ -538,53 +604,58 @@ impl AsyncArrayPartialDecoderTraits for AsyncShardingPartialDecoder {
                             .map(|(chunk_subset, (offset, size))| {
                                 let chunk_representation = chunk_representation.clone();
                                 async move {
-                                let partial_decoder = self
-                                    .inner_codecs
-                                    .clone()
-                                    .async_partial_decoder(
-                                        Arc::new(AsyncByteIntervalPartialDecoder::new(
-                                            self.input_handle.clone(),
-                                            u64::try_from(*offset).unwrap(),
-                                            u64::try_from(*size).unwrap(),
-                                        )),
-                                        &chunk_representation,
-                                        options, // TODO: Adjust options for partial decoding?
-                                    )
-                                    .await
-                                    .map_err(|err| if let CodecError::InvalidByteRangeError(_) = err {
-                                        CodecError::Other(
-                                            "The shard index references out-of-bounds bytes. The chunk may be corrupted."
-                                                .to_string(),
+                                    let partial_decoder = self
+                                        .inner_codecs
+                                        .clone()
+                                        .async_partial_decoder(
+                                            Arc::new(AsyncByteIntervalPartialDecoder::new(
+                                                input_handle.clone(),
+                                                u64::try_from(*offset).unwrap(),
+                                                u64::try_from(*size).unwrap(),
+                                            )),
+                                            &chunk_representation,
+                                            options, // TODO: Adjust options for partial decoding?
                                         )
-                                    } else {
-                                        err
-                                    })?;
-                                let chunk_subset_overlap = unsafe { array_subset.overlap_unchecked(chunk_subset) };
-                                // Partial decoding is actually really slow with the blosc codec! Assume sharded chunks are small, and just decode the whole thing and extract bytes
-                                // TODO: Investigate further
-                                // let decoded_chunk = partial_decoder
-                                //     .partial_decode(&[chunk_subset_overlap.relative_to(chunk_subset.start())?])
-                                //     .await?
-                                //     .remove(0);
-                                let decoded_chunk = partial_decoder
-                                    .partial_decode(
-                                        &[ArraySubset::new_with_shape(chunk_subset.shape().to_vec())],
-                                        options,
-                                    ) // TODO: Adjust options for partial decoding
-                                    .await?
-                                    .remove(0).into_owned();
-                                let decoded_chunk = decoded_chunk
-                                    .extract_array_subset(
-                                        &chunk_subset_overlap.relative_to(chunk_subset.start()).unwrap(),
-                                        chunk_subset.shape(),
-                                        self.decoded_representation.data_type()
-                                    )?
-                                    .into_fixed()?
-                                    .into_owned();
-                                Ok::<_, CodecError>((decoded_chunk, chunk_subset_overlap))
-                            }}),
-                        )
-                        .await;
+                                        .await
+                                        .map_err(|err| {
+                                            shard_index_corrupt_if_byte_range_err(
+                                                err,
+                                                "the shard index references out-of-bounds bytes",
+                                            )
+                                        })?;
+                                    let chunk_subset_overlap =
+                                        unsafe { array_subset.overlap_unchecked(chunk_subset) };
+                                    // Partial decoding is actually really slow with the blosc codec! Assume sharded chunks are small, and just decode the whole thing and extract bytes
+                                    // TODO: Investigate further
+                                    // let decoded_chunk = partial_decoder
+                                    //     .partial_decode(&[chunk_subset_overlap.relative_to(chunk_subset.start())?])
+                                    //     .await?
+                                    //     .remove(0);
+                                    let decoded_chunk = partial_decoder
+                                        .partial_decode(
+                                            &[ArraySubset::new_with_shape(
+                                                chunk_subset.shape().to_vec(),
+                                            )],
+                                            options,
+                                        ) // TODO: Adjust options for partial decoding
+                                        .await?
+                                        .remove(0)
+                                        .into_owned();
+                                    let decoded_chunk = decoded_chunk
+                                        .extract_array_subset(
+                                            &chunk_subset_overlap
+                                                .relative_to(chunk_subset.start())
+                                                .unwrap(),
+                                            chunk_subset.shape(),
+                                            self.decoded_representation.data_type(),
+                                        )?
+                                        .into_fixed()?
+                                        .into_owned();
+                                    Ok::<_, CodecError>((decoded_chunk, chunk_subset_overlap))
+                                }
+                            }),
+                    )
+                    .await;
                     // FIXME: Concurrency limit for futures
 
                     if !results.is_empty() {
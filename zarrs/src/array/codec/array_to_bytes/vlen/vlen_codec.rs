@@ -5,8 +5,8 @@ use crate::{
         codec::{
             ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderDefault,
             ArrayPartialEncoderTraits, ArrayToBytesCodecTraits, BytesCodec,
-            BytesPartialDecoderTraits, BytesPartialEncoderTraits, CodecError, CodecOptions,
-            CodecTraits, RecommendedConcurrency,
+            BytesPartialDecoderTraits, BytesPartialEncoderTraits, CodecError,
+            CodecIncompatibleWithDataTypeError, CodecOptions, CodecTraits, RecommendedConcurrency,
         },
         transmute_to_bytes_vec, ArrayBytes, ArrayMetadataOptions, BytesRepresentation,
         ChunkRepresentation, CodecChain, DataType, DataTypeSize, Endianness, FillValue, RawBytes,
@@ -335,4 +335,18 @@ impl ArrayToBytesCodecTraits for VlenCodec {
             )),
         }
     }
+
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        match decoded_representation.data_type().size() {
+            DataTypeSize::Variable => vec![],
+            DataTypeSize::Fixed(_) => vec![CodecIncompatibleWithDataTypeError::new(
+                super::IDENTIFIER,
+                decoded_representation.data_type().clone(),
+                "fixed-sized data types must be encoded with the bytes codec",
+            )],
+        }
+    }
 }
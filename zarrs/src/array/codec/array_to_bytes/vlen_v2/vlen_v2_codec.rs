@@ -7,8 +7,8 @@ use crate::{
         codec::{
             ArrayCodecTraits, ArrayPartialDecoderTraits, ArrayPartialEncoderDefault,
             ArrayPartialEncoderTraits, ArrayToBytesCodecTraits, BytesPartialDecoderTraits,
-            BytesPartialEncoderTraits, CodecError, CodecOptions, CodecTraits,
-            RecommendedConcurrency,
+            BytesPartialEncoderTraits, CodecError, CodecIncompatibleWithDataTypeError,
+            CodecOptions, CodecTraits, RecommendedConcurrency,
         },
         ArrayBytes, ArrayMetadataOptions, BytesRepresentation, ChunkRepresentation, DataTypeSize,
         RawBytes,
@@ -170,4 +170,18 @@ impl ArrayToBytesCodecTraits for VlenV2Codec {
             )),
         }
     }
+
+    fn validate(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Vec<CodecIncompatibleWithDataTypeError> {
+        match decoded_representation.data_type().size() {
+            DataTypeSize::Variable => vec![],
+            DataTypeSize::Fixed(_) => vec![CodecIncompatibleWithDataTypeError::new(
+                super::IDENTIFIER,
+                decoded_representation.data_type().clone(),
+                "fixed-sized data types must be encoded with the bytes codec",
+            )],
+        }
+    }
 }
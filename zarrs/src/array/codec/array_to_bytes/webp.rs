@@ -0,0 +1,269 @@
+//! The `webp` array to bytes codec.
+//!
+//! Encodes 2D `uint8` chunks as [WebP](https://developers.google.com/speed/webp) images, with lossless or lossy compression.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `webp` feature, which is disabled by default.
+//!
+//! Chunks must have a shape of `[height, width]` or `[height, width, channels]` where `channels` is:
+//!  - 1: grayscale,
+//!  - 3: RGB, or
+//!  - 4: RGBA.
+//!
+//! Partial decoding decodes the entire chunk.
+//!
+//! See [`WebpCodecConfigurationV1`] for example `JSON` metadata.
+
+mod webp_codec;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::webp::{
+    WebpCodecConfiguration, WebpCodecConfigurationV1,
+};
+pub use webp_codec::WebpCodec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin, ImageCodec},
+    config::global_config,
+    metadata::v3::{array::codec::webp, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use webp::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_webp, create_codec_webp)
+}
+
+fn is_name_webp(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_webp(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = WebpCodec::new_with_configuration(&configuration)?;
+    Ok(Codec::ArrayToBytes(Arc::new(ImageCodec::new(codec))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{ArrayToBytesCodecTraits, CodecOptions, CodecTraits},
+            Array, ArrayBuilder, ArrayBytes, ChunkRepresentation, DataType, FillValue,
+        },
+        array_subset::ArraySubset,
+        storage::store::MemoryStore,
+    };
+
+    use super::*;
+
+    const JSON_LOSSLESS: &str = r#"{
+        "lossless": true,
+        "quality": 75.0
+    }"#;
+
+    const JSON_LOSSY: &str = r#"{
+        "lossless": false,
+        "quality": 50.0
+    }"#;
+
+    fn codec(json: &str) -> Arc<ImageCodec<WebpCodec>> {
+        let configuration: WebpCodecConfiguration = serde_json::from_str(json).unwrap();
+        Arc::new(ImageCodec::new(
+            WebpCodec::new_with_configuration(&configuration).unwrap(),
+        ))
+    }
+
+    fn chunk_representation(shape: &[u64], data_type: DataType) -> ChunkRepresentation {
+        let fill_value = FillValue::new(vec![0; data_type.fixed_size().unwrap()]);
+        ChunkRepresentation::new(
+            shape.iter().map(|&s| NonZeroU64::new(s).unwrap()).collect(),
+            data_type,
+            fill_value,
+        )
+        .unwrap()
+    }
+
+    /// A smooth test image.
+    fn image(shape: &[u64]) -> Vec<u8> {
+        let channels = shape.get(2).copied().unwrap_or(1);
+        let mut pixels = Vec::new();
+        for y in 0..shape[0] {
+            for x in 0..shape[1] {
+                for c in 0..channels {
+                    pixels.push(u8::try_from((y * 3 + x * 5 + c * 40) % 256).unwrap());
+                }
+            }
+        }
+        pixels
+    }
+
+    #[test]
+    fn codec_webp_configuration() {
+        assert!(WebpCodec::new(false, 50.0).is_ok());
+        assert!(WebpCodec::new(false, 100.5).is_err());
+        assert!(WebpCodec::new(true, -1.0).is_err());
+        let metadata = codec(JSON_LOSSY).create_metadata().unwrap();
+        assert_eq!(metadata.name(), "zarrs.webp");
+        assert_eq!(
+            metadata
+                .to_configuration::<WebpCodecConfiguration>()
+                .unwrap(),
+            serde_json::from_str(JSON_LOSSY).unwrap()
+        );
+    }
+
+    #[test]
+    fn codec_webp_round_trip_lossless() -> Result<(), Box<dyn std::error::Error>> {
+        let codec = codec(JSON_LOSSLESS);
+        for shape in [vec![16, 16], vec![9, 7, 1], vec![12, 20, 3], vec![7, 9, 4]] {
+            let chunk_representation = chunk_representation(&shape, DataType::UInt8);
+            let bytes = image(&shape);
+            let encoded = codec.encode(
+                ArrayBytes::from(bytes.clone()),
+                &chunk_representation,
+                &CodecOptions::default(),
+            )?;
+            let decoded = codec.decode(encoded, &chunk_representation, &CodecOptions::default())?;
+            assert_eq!(decoded, ArrayBytes::from(bytes));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn codec_webp_round_trip_lossless_alpha() -> Result<(), Box<dyn std::error::Error>> {
+        let codec = codec(JSON_LOSSLESS);
+        let chunk_representation = chunk_representation(&[4, 4, 4], DataType::UInt8);
+
+        // Opaque, the alpha channel is not encoded
+        let mut bytes = image(&[4, 4, 4]);
+        bytes.iter_mut().skip(3).step_by(4).for_each(|a| *a = 255);
+        let encoded = codec.encode(
+            ArrayBytes::from(bytes.clone()),
+            &chunk_representation,
+            &CodecOptions::default(),
+        )?;
+        let decoded = codec.decode(encoded, &chunk_representation, &CodecOptions::default())?;
+        assert_eq!(decoded, ArrayBytes::from(bytes.clone()));
+
+        // Transparent pixels retain their colour
+        bytes.iter_mut().skip(3).step_by(8).for_each(|a| *a = 0);
+        let encoded = codec.encode(
+            ArrayBytes::from(bytes.clone()),
+            &chunk_representation,
+            &CodecOptions::default(),
+        )?;
+        let decoded = codec.decode(encoded, &chunk_representation, &CodecOptions::default())?;
+        assert_eq!(decoded, ArrayBytes::from(bytes));
+        Ok(())
+    }
+
+    #[test]
+    fn codec_webp_round_trip_lossy() -> Result<(), Box<dyn std::error::Error>> {
+        let codec = codec(JSON_LOSSY);
+        let shape = [64, 64, 3];
+        let chunk_representation = chunk_representation(&shape, DataType::UInt8);
+        let bytes = image(&shape);
+        let encoded = codec.encode(
+            ArrayBytes::from(bytes.clone()),
+            &chunk_representation,
+            &CodecOptions::default(),
+        )?;
+        assert!(encoded.len() * 4 < bytes.len());
+        let decoded = codec
+            .decode(encoded, &chunk_representation, &CodecOptions::default())?
+            .into_fixed()?;
+        assert_eq!(decoded.len(), bytes.len());
+        let mean_abs_error = bytes
+            .iter()
+            .zip(decoded.iter())
+            .map(|(&a, &b)| u64::from(a.abs_diff(b)))
+            .sum::<u64>()
+            / bytes.len() as u64;
+        assert!(mean_abs_error < 16);
+        Ok(())
+    }
+
+    #[test]
+    fn codec_webp_unsupported() {
+        let codec = codec(JSON_LOSSLESS);
+        for (shape, data_type) in [
+            (vec![8, 8], DataType::UInt16),
+            (vec![8, 8, 3], DataType::Int8),
+            (vec![8, 8, 2], DataType::UInt8),
+            (vec![8, 8, 5], DataType::UInt8),
+            (vec![8], DataType::UInt8),
+            (vec![2, 8, 8, 3], DataType::UInt8),
+        ] {
+            let chunk_representation = chunk_representation(&shape, data_type);
+            assert!(codec.compute_encoded_size(&chunk_representation).is_err());
+            let num_bytes = chunk_representation.num_elements_usize()
+                * chunk_representation.data_type().fixed_size().unwrap();
+            assert!(codec
+                .encode(
+                    ArrayBytes::from(vec![0u8; num_bytes]),
+                    &chunk_representation,
+                    &CodecOptions::default()
+                )
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn codec_webp_array() -> Result<(), Box<dyn std::error::Error>> {
+        let store = Arc::new(MemoryStore::default());
+        let array = ArrayBuilder::new(
+            vec![20, 13, 3],
+            DataType::UInt8,
+            vec![8, 8, 3].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .array_to_bytes_codec(codec(JSON_LOSSLESS))
+        .build(store.clone(), "/")?;
+        array.store_metadata()?;
+        let array = Array::open(store, "/")?;
+
+        // Edge chunks are padded by the fill value
+        let subset = array.subset_all();
+        let elements = image(subset.shape());
+        array.store_array_subset_elements(&subset, &elements)?;
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&subset)?,
+            elements
+        );
+
+        let chunk = array.retrieve_chunk_elements::<u8>(&[2, 1, 0])?;
+        assert_eq!(chunk.len(), 8 * 8 * 3);
+        assert!(chunk[4 * 8 * 3..].iter().all(|&v| v == 0));
+
+        // Partial decoding
+        let subset = ArraySubset::new_with_ranges(&[5..10, 3..12, 1..2]);
+        let expected = array
+            .retrieve_array_subset_elements::<u8>(&array.subset_all())?
+            .chunks_exact(3)
+            .enumerate()
+            .filter(|(i, _)| (5..10).contains(&(i / 13)) && (3..12).contains(&(i % 13)))
+            .map(|(_, pixel)| pixel[1])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u8>(&subset)?,
+            expected
+        );
+        Ok(())
+    }
+}
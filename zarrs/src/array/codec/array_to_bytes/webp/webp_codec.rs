@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+
+use webp::{Decoder, Encoder, PixelLayout, WebPConfig};
+
+use crate::{
+    array::{
+        codec::{CodecError, CodecOptions, CodecTraits, ImageCodecTraits, ImageRepresentation},
+        ArrayMetadataOptions, ChunkRepresentation, DataType,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+use super::{WebpCodecConfiguration, WebpCodecConfigurationV1, IDENTIFIER};
+
+/// The maximum width and height of a WebP image.
+const WEBP_MAX_DIMENSION: u32 = 16383;
+
+/// A `webp` codec implementation.
+///
+/// This is an [`ImageCodecTraits`] codec, so it must be wrapped in an [`ImageCodec`](crate::array::codec::ImageCodec) to be used as an array to bytes codec.
+#[derive(Debug, Clone)]
+pub struct WebpCodec {
+    lossless: bool,
+    quality: f32,
+}
+
+impl WebpCodec {
+    /// Create a new `webp` codec.
+    ///
+    /// In lossy mode, `quality` controls the trade-off between size and quality.
+    /// In lossless mode, `quality` controls the compression effort.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `quality` is not between 0.0 and 100.0.
+    pub fn new(lossless: bool, quality: f32) -> Result<Self, PluginCreateError> {
+        if (0.0..=100.0).contains(&quality) {
+            Ok(Self { lossless, quality })
+        } else {
+            Err(PluginCreateError::Other(format!(
+                "webp quality {quality} is invalid, must be between 0.0 and 100.0"
+            )))
+        }
+    }
+
+    /// Create a new `webp` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &WebpCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let WebpCodecConfiguration::V1(configuration) = configuration;
+        Self::new(configuration.lossless, configuration.quality)
+    }
+
+    fn config(&self) -> Result<WebPConfig, CodecError> {
+        let mut config = WebPConfig::new()
+            .map_err(|()| CodecError::from("failed to initialise the webp configuration"))?;
+        config.lossless = i32::from(self.lossless);
+        config.quality = self.quality;
+        // Preserve the colour of transparent pixels
+        config.exact = 1;
+        Ok(config)
+    }
+}
+
+impl CodecTraits for WebpCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = WebpCodecConfiguration::V1(WebpCodecConfigurationV1 {
+            lossless: self.lossless,
+            quality: self.quality,
+        });
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .expect("webp configuration is valid json"),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+impl ImageCodecTraits for WebpCodec {
+    fn image_representation(
+        &self,
+        decoded_representation: &ChunkRepresentation,
+    ) -> Result<ImageRepresentation, CodecError> {
+        let data_type = decoded_representation.data_type();
+        if data_type != &DataType::UInt8 {
+            return Err(CodecError::UnsupportedDataType(
+                data_type.clone(),
+                IDENTIFIER.to_string(),
+            ));
+        }
+        let image_representation =
+            ImageRepresentation::from_chunk_representation(decoded_representation)?;
+        if ![1, 3, 4].contains(&image_representation.channels()) {
+            return Err(CodecError::Other(format!(
+                "webp codec supports 1 (grayscale), 3 (RGB), or 4 (RGBA) channels, got {}",
+                image_representation.channels()
+            )));
+        }
+        if image_representation.width() > WEBP_MAX_DIMENSION
+            || image_representation.height() > WEBP_MAX_DIMENSION
+        {
+            return Err(CodecError::Other(format!(
+                "webp codec supports images up to {WEBP_MAX_DIMENSION}x{WEBP_MAX_DIMENSION}, got {}x{}",
+                image_representation.width(),
+                image_representation.height()
+            )));
+        }
+        Ok(image_representation)
+    }
+
+    fn encode_image(
+        &self,
+        pixels: &[u8],
+        image_representation: &ImageRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Vec<u8>, CodecError> {
+        let (layout, pixels) = match image_representation.channels() {
+            // WebP does not support grayscale, so encode as RGB
+            1 => (
+                PixelLayout::Rgb,
+                Cow::Owned(pixels.iter().flat_map(|&v| [v, v, v]).collect()),
+            ),
+            3 => (PixelLayout::Rgb, Cow::Borrowed(pixels)),
+            4 => (PixelLayout::Rgba, Cow::Borrowed(pixels)),
+            _ => unreachable!("validated by image_representation"),
+        };
+        let encoded = Encoder::new(
+            &pixels,
+            layout,
+            image_representation.width(),
+            image_representation.height(),
+        )
+        .encode_advanced(&self.config()?)
+        .map_err(|err| CodecError::Other(format!("webp encoding failed: {err:?}")))?;
+        Ok(encoded.to_vec())
+    }
+
+    fn decode_image(
+        &self,
+        bytes: &[u8],
+        image_representation: &ImageRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Vec<u8>, CodecError> {
+        let image = Decoder::new(bytes)
+            .decode()
+            .ok_or_else(|| CodecError::from("webp decoding failed"))?;
+        if image.width() != image_representation.width()
+            || image.height() != image_representation.height()
+        {
+            return Err(CodecError::Other(format!(
+                "decoded webp image is {}x{}, expected {}x{}",
+                image.width(),
+                image.height(),
+                image_representation.width(),
+                image_representation.height()
+            )));
+        }
+
+        let bytes_per_pixel = usize::from(image.layout().bytes_per_pixel());
+        let pixels = image.chunks_exact(bytes_per_pixel);
+        let decoded = match (image_representation.channels(), image.layout()) {
+            (3, PixelLayout::Rgb) | (4, PixelLayout::Rgba) => image.to_vec(),
+            (1, _) => pixels.map(|pixel| pixel[0]).collect(),
+            (3, PixelLayout::Rgba) => pixels.flat_map(|pixel| &pixel[..3]).copied().collect(),
+            // The alpha channel is omitted by the encoder if it is fully opaque
+            (4, PixelLayout::Rgb) => pixels
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], u8::MAX])
+                .collect(),
+            _ => unreachable!("validated by image_representation"),
+        };
+        Ok(decoded)
+    }
+}
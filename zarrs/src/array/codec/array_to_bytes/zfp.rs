@@ -337,7 +337,12 @@ mod tests {
         i32: num::traits::AsPrimitive<T>,
     {
         let elements: Vec<T> = (0..27).map(|i: i32| i.as_()).collect();
-        let bytes = T::into_array_bytes(chunk_representation.data_type(), &elements).unwrap();
+        let bytes = T::into_array_bytes(
+            chunk_representation.data_type(),
+            chunk_representation.fill_value(),
+            &elements,
+        )
+        .unwrap();
 
         let configuration: ZfpCodecConfiguration = serde_json::from_str(configuration).unwrap();
         let codec = ZfpCodec::new_with_configuration(&configuration);
@@ -357,8 +362,12 @@ mod tests {
             )
             .unwrap()
             .into_owned();
-        let decoded_elements =
-            T::from_array_bytes(chunk_representation.data_type(), decoded).unwrap();
+        let decoded_elements = T::from_array_bytes(
+            chunk_representation.data_type(),
+            chunk_representation.fill_value(),
+            decoded,
+        )
+        .unwrap();
         assert_eq!(elements, decoded_elements);
     }
 
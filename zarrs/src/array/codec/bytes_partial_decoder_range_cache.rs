@@ -0,0 +1,141 @@
+//! A byte range cache for partial decoders.
+
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::RawBytes,
+    byte_range::{ByteOffset, ByteRange},
+};
+
+use super::{BytesPartialDecoderTraits, CodecError, CodecOptions};
+
+#[cfg(feature = "async")]
+use super::AsyncBytesPartialDecoderTraits;
+
+/// Cached bytes of byte ranges, ordered by offset.
+struct ByteRangeCache(Vec<(ByteOffset, Vec<u8>)>);
+
+impl ByteRangeCache {
+    fn new(byte_ranges: &[ByteRange], bytes: Option<Vec<RawBytes<'_>>>) -> Self {
+        let mut cache: Vec<(ByteOffset, Vec<u8>)> = bytes
+            .map(|bytes| {
+                std::iter::zip(byte_ranges, bytes)
+                    .filter_map(|(byte_range, bytes)| match byte_range {
+                        ByteRange::FromStart(offset, Some(_)) => {
+                            Some((*offset, bytes.into_owned()))
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        cache.sort_by_key(|(offset, _)| *offset);
+        Self(cache)
+    }
+
+    /// Return the cached bytes of a bounded byte range, or [`None`] if it is not cached.
+    fn get(&self, byte_range: &ByteRange) -> Option<&[u8]> {
+        let ByteRange::FromStart(offset, Some(length)) = byte_range else {
+            return None;
+        };
+        let index = self
+            .0
+            .partition_point(|(cache_offset, _)| cache_offset <= offset)
+            .checked_sub(1)?;
+        let (cache_offset, bytes) = &self.0[index];
+        let start = usize::try_from(offset - cache_offset).unwrap();
+        let end = start + usize::try_from(*length).unwrap();
+        bytes.get(start..end)
+    }
+
+    /// Return the cached bytes of `byte_ranges`, or [`None`] if any are not cached.
+    fn get_all(&self, byte_ranges: &[ByteRange]) -> Option<Vec<RawBytes<'_>>> {
+        byte_ranges
+            .iter()
+            .map(|byte_range| self.get(byte_range).map(Cow::Borrowed))
+            .collect()
+    }
+}
+
+/// A byte range cache for a [`BytesPartialDecoderTraits`] partial decoder.
+///
+/// Retrieves a set of byte ranges from the inner partial decoder upfront with a single request.
+/// Requests for byte ranges within a cached byte range are served from the cache, and all other requests are forwarded to the inner partial decoder.
+pub(crate) struct BytesPartialDecoderRangeCache {
+    inner: Arc<dyn BytesPartialDecoderTraits>,
+    cache: ByteRangeCache,
+}
+
+impl BytesPartialDecoderRangeCache {
+    /// Create a new byte range cache of `byte_ranges` for the `inner` partial decoder.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if caching fails.
+    pub(crate) fn new(
+        inner: Arc<dyn BytesPartialDecoderTraits>,
+        byte_ranges: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Self, CodecError> {
+        let cache = ByteRangeCache::new(byte_ranges, inner.partial_decode(byte_ranges, options)?);
+        Ok(Self { inner, cache })
+    }
+}
+
+impl BytesPartialDecoderTraits for BytesPartialDecoderRangeCache {
+    fn partial_decode(
+        &self,
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        if let Some(bytes) = self.cache.get_all(decoded_regions) {
+            Ok(Some(bytes))
+        } else {
+            self.inner.partial_decode(decoded_regions, options)
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+/// A byte range cache for an [`AsyncBytesPartialDecoderTraits`] partial decoder.
+///
+/// Retrieves a set of byte ranges from the inner partial decoder upfront with a single request.
+/// Requests for byte ranges within a cached byte range are served from the cache, and all other requests are forwarded to the inner partial decoder.
+pub(crate) struct AsyncBytesPartialDecoderRangeCache {
+    inner: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    cache: ByteRangeCache,
+}
+
+#[cfg(feature = "async")]
+impl AsyncBytesPartialDecoderRangeCache {
+    /// Create a new asynchronous byte range cache of `byte_ranges` for the `inner` partial decoder.
+    ///
+    /// # Errors
+    /// Returns a [`CodecError`] if caching fails.
+    pub(crate) async fn async_new(
+        inner: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        byte_ranges: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Self, CodecError> {
+        let cache = ByteRangeCache::new(
+            byte_ranges,
+            inner.partial_decode(byte_ranges, options).await?,
+        );
+        Ok(Self { inner, cache })
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncBytesPartialDecoderTraits for AsyncBytesPartialDecoderRangeCache {
+    async fn partial_decode(
+        &self,
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        if let Some(bytes) = self.cache.get_all(decoded_regions) {
+            Ok(Some(bytes))
+        } else {
+            self.inner.partial_decode(decoded_regions, options).await
+        }
+    }
+}
@@ -1,9 +1,15 @@
 //! Bytes to bytes codecs.
 
+#[cfg(feature = "adler32")]
+pub mod adler32;
+#[cfg(feature = "bitshuffle")]
+pub mod bitshuffle;
 #[cfg(feature = "blosc")]
 pub mod blosc;
 #[cfg(feature = "bz2")]
 pub mod bz2;
+#[cfg(feature = "crc32")]
+pub mod crc32;
 #[cfg(feature = "crc32c")]
 pub mod crc32c;
 #[cfg(feature = "fletcher32")]
@@ -12,11 +18,33 @@ pub mod fletcher32;
 pub mod gdeflate;
 #[cfg(feature = "gzip")]
 pub mod gzip;
+#[cfg(feature = "jenkins_lookup3")]
+pub mod jenkins_lookup3;
+#[cfg(feature = "lz4")]
+pub mod lz4;
+#[cfg(feature = "lzma")]
+pub mod lzma;
 #[cfg(feature = "zstd")]
 pub mod zstd;
 
 #[cfg(test)]
 pub mod test_unbounded;
 
-#[cfg(any(feature = "crc32c", feature = "fletcher32"))]
+#[cfg(any(
+    feature = "adler32",
+    feature = "crc32",
+    feature = "crc32c",
+    feature = "fletcher32",
+    feature = "jenkins_lookup3"
+))]
+mod checksum_partial_decoder;
+#[cfg(any(feature = "adler32", feature = "crc32"))]
+mod strip_prefix_partial_decoder;
+#[cfg(any(
+    feature = "adler32",
+    feature = "crc32",
+    feature = "crc32c",
+    feature = "fletcher32",
+    feature = "jenkins_lookup3"
+))]
 mod strip_suffix_partial_decoder;
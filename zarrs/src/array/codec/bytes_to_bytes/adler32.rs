@@ -0,0 +1,232 @@
+//! The `adler32` bytes to bytes codec.
+//!
+//! Prepends or appends an Adler-32 checksum of the input bytestream.
+//!
+//! This is based on the `numcodecs` implementation.
+//! See <https://numcodecs.readthedocs.io/en/latest/checksum32.html#adler32>.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `adler32` feature, which is disabled by default.
+//!
+//! See [`Adler32CodecConfigurationV1`] for example `JSON` metadata.
+
+mod adler32_codec;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::adler32::{
+    Adler32CodecConfiguration, Adler32CodecConfigurationV1, ChecksumLocation,
+};
+pub use adler32_codec::Adler32Codec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::adler32, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use adler32::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_adler32, create_codec_adler32)
+}
+
+fn is_name_adler32(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_adler32(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(Adler32Codec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+const CHECKSUM_SIZE: usize = size_of::<u32>();
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits},
+            BytesRepresentation,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    #[test]
+    fn codec_adler32_configuration() {
+        let codec_configuration: Adler32CodecConfiguration = serde_json::from_str("{}").unwrap();
+        let codec = Adler32Codec::new_with_configuration(&codec_configuration);
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(
+            serde_json::to_string(&metadata).unwrap(),
+            r#"{"name":"numcodecs.adler32","configuration":{"location":"start"}}"#
+        );
+    }
+
+    #[test]
+    fn codec_adler32_start() {
+        let bytes: Vec<u8> = (0..6).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Adler32Codec::new(ChecksumLocation::Start);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        // Checksum from zlib.adler32
+        assert_eq!(&encoded[..CHECKSUM_SIZE], &[16, 0, 41, 0]);
+        assert_eq!(&encoded[CHECKSUM_SIZE..], &bytes);
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_adler32_end() {
+        let bytes: Vec<u8> = (0..6).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Adler32Codec::new(ChecksumLocation::End);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(&encoded[..bytes.len()], &bytes);
+        assert_eq!(&encoded[bytes.len()..], &[16, 0, 41, 0]);
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_adler32_large() {
+        // Exceeds the number of bytes that can be summed without a modulo reduction
+        let bytes: Vec<u8> = (0..100_000_u32).map(|i| (i % 251) as u8).collect();
+        let codec = Adler32Codec::new(ChecksumLocation::End);
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        // Checksum from zlib.adler32
+        assert_eq!(&encoded[bytes.len()..], &2_227_939_732_u32.to_le_bytes());
+    }
+
+    #[test]
+    fn codec_adler32_invalid_checksum() {
+        let bytes: Vec<u8> = (0..6).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Adler32Codec::new(ChecksumLocation::Start);
+
+        let mut encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap()
+            .to_vec();
+        encoded[CHECKSUM_SIZE] ^= 1;
+        assert!(matches!(
+            codec.decode(
+                Cow::Borrowed(&encoded),
+                &bytes_representation,
+                &CodecOptions::default()
+            ),
+            Err(CodecError::InvalidChecksum)
+        ));
+        let options = CodecOptions::builder().validate_checksums(false).build();
+        assert!(codec
+            .decode(Cow::Borrowed(&encoded), &bytes_representation, &options)
+            .is_ok());
+        assert!(codec
+            .decode(
+                Cow::Borrowed(&encoded[..2]),
+                &bytes_representation,
+                &CodecOptions::default()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn codec_adler32_partial_decode() {
+        for location in [ChecksumLocation::Start, ChecksumLocation::End] {
+            let bytes: Vec<u8> = (0..32).collect();
+            let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+            let codec = Arc::new(Adler32Codec::new(location));
+
+            let encoded = codec
+                .encode(Cow::Owned(bytes), &CodecOptions::default())
+                .unwrap();
+            let decoded_regions = [ByteRange::FromStart(3, Some(2)), ByteRange::Suffix(2)];
+            let input_handle = Arc::new(std::io::Cursor::new(encoded));
+            let partial_decoder = codec
+                .partial_decoder(
+                    input_handle,
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .unwrap();
+            let decoded_partial_chunk = partial_decoder
+                .partial_decode(&decoded_regions, &CodecOptions::default())
+                .unwrap()
+                .unwrap();
+            let answer: &[Vec<u8>] = &[vec![3, 4], vec![30, 31]];
+            assert_eq!(
+                answer,
+                decoded_partial_chunk
+                    .into_iter()
+                    .map(|v| v.to_vec())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_adler32_async_partial_decode() {
+        for location in [ChecksumLocation::Start, ChecksumLocation::End] {
+            let bytes: Vec<u8> = (0..32).collect();
+            let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+            let codec = Arc::new(Adler32Codec::new(location));
+
+            let encoded = codec
+                .encode(Cow::Owned(bytes), &CodecOptions::default())
+                .unwrap();
+            let decoded_regions = [ByteRange::FromStart(3, Some(2)), ByteRange::Suffix(2)];
+            let input_handle = Arc::new(std::io::Cursor::new(encoded));
+            let partial_decoder = codec
+                .async_partial_decoder(
+                    input_handle,
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .await
+                .unwrap();
+            let decoded_partial_chunk = partial_decoder
+                .partial_decode(&decoded_regions, &CodecOptions::default())
+                .await
+                .unwrap()
+                .unwrap();
+            let answer: &[Vec<u8>] = &[vec![3, 4], vec![30, 31]];
+            assert_eq!(
+                answer,
+                decoded_partial_chunk
+                    .into_iter()
+                    .map(|v| v.to_vec())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{
+        codec::{
+            bytes_to_bytes::{
+                checksum_partial_decoder::ChecksumPartialDecoder,
+                strip_prefix_partial_decoder::StripPrefixPartialDecoder,
+                strip_suffix_partial_decoder::StripSuffixPartialDecoder,
+            },
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, Checksums, CodecError, CodecOptions, CodecTraits,
+            RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+#[cfg(feature = "async")]
+use crate::array::codec::bytes_to_bytes::{
+    checksum_partial_decoder::AsyncChecksumPartialDecoder,
+    strip_prefix_partial_decoder::AsyncStripPrefixPartialDecoder,
+    strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder,
+};
+
+use super::{
+    Adler32CodecConfiguration, Adler32CodecConfigurationV1, ChecksumLocation, CHECKSUM_SIZE,
+    IDENTIFIER,
+};
+
+/// A `adler32` (Adler-32 checksum) codec implementation.
+#[derive(Clone, Debug, Default)]
+pub struct Adler32Codec {
+    location: ChecksumLocation,
+}
+
+impl Adler32Codec {
+    /// Create a new `adler32` codec.
+    #[must_use]
+    pub const fn new(location: ChecksumLocation) -> Self {
+        Self { location }
+    }
+
+    /// Create a new `adler32` codec from configuration.
+    #[must_use]
+    pub const fn new_with_configuration(configuration: &Adler32CodecConfiguration) -> Self {
+        let Adler32CodecConfiguration::V1(configuration) = configuration;
+        Self::new(configuration.location)
+    }
+}
+
+impl CodecTraits for Adler32Codec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = Adler32CodecConfigurationV1 {
+            location: self.location,
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .expect("adler32 configuration is valid json"),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+/// Adler-32.
+///
+/// Matches `zlib.adler32` as used by `numcodecs`.
+/// See <https://datatracker.ietf.org/doc/html/rfc1950#section-9>.
+fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    // The largest number of bytes that can be summed before `sum2` may overflow
+    const NMAX: usize = 5552;
+
+    let mut sum1: u32 = 1;
+    let mut sum2: u32 = 0;
+    for chunk in data.chunks(NMAX) {
+        for &byte in chunk {
+            sum1 += u32::from(byte);
+            sum2 += sum1;
+        }
+        sum1 %= MOD_ADLER;
+        sum2 %= MOD_ADLER;
+    }
+
+    (sum2 << 16) | sum1
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for Adler32Codec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let checksum = adler32(&decoded_value).to_le_bytes();
+        let mut encoded_value: Vec<u8> = Vec::with_capacity(decoded_value.len() + checksum.len());
+        match self.location {
+            ChecksumLocation::Start => {
+                encoded_value.extend_from_slice(&checksum);
+                encoded_value.extend_from_slice(&decoded_value);
+            }
+            ChecksumLocation::End => {
+                encoded_value.extend_from_slice(&decoded_value);
+                encoded_value.extend_from_slice(&checksum);
+            }
+        }
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        if encoded_value.len() < CHECKSUM_SIZE {
+            return Err(CodecError::Other(
+                "adler32 decoder expects a 32 bit input".to_string(),
+            ));
+        }
+        let (checksum, decoded_value) = match self.location {
+            ChecksumLocation::Start => {
+                let (checksum, decoded_value) = encoded_value.split_at(CHECKSUM_SIZE);
+                (checksum, decoded_value)
+            }
+            ChecksumLocation::End => {
+                let (decoded_value, checksum) =
+                    encoded_value.split_at(encoded_value.len() - CHECKSUM_SIZE);
+                (checksum, decoded_value)
+            }
+        };
+        if options.validate_checksums() && adler32(decoded_value).to_le_bytes() != checksum {
+            return Err(CodecError::InvalidChecksum);
+        }
+        Ok(Cow::Owned(decoded_value.to_vec()))
+    }
+
+    fn checksums(&self, encoded_value: &[u8]) -> Result<Option<Checksums>, CodecError> {
+        if encoded_value.len() < CHECKSUM_SIZE {
+            return Err(CodecError::Other(
+                "adler32 decoder expects a 32 bit input".to_string(),
+            ));
+        }
+        let (checksum, decoded_value) = match self.location {
+            ChecksumLocation::Start => encoded_value.split_at(CHECKSUM_SIZE),
+            ChecksumLocation::End => {
+                let (decoded_value, checksum) =
+                    encoded_value.split_at(encoded_value.len() - CHECKSUM_SIZE);
+                (checksum, decoded_value)
+            }
+        };
+        Ok(Some((
+            checksum.to_vec(),
+            adler32(decoded_value).to_le_bytes().to_vec(),
+        )))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        let strip_partial_decoder: Arc<dyn BytesPartialDecoderTraits> = match self.location {
+            ChecksumLocation::Start => Arc::new(StripPrefixPartialDecoder::new(
+                input_handle.clone(),
+                CHECKSUM_SIZE,
+            )),
+            ChecksumLocation::End => Arc::new(StripSuffixPartialDecoder::new(
+                input_handle.clone(),
+                CHECKSUM_SIZE,
+            )),
+        };
+        Ok(Arc::new(ChecksumPartialDecoder::new(
+            input_handle,
+            strip_partial_decoder,
+            self,
+            *decoded_representation,
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        let strip_partial_decoder: Arc<dyn AsyncBytesPartialDecoderTraits> = match self.location {
+            ChecksumLocation::Start => Arc::new(AsyncStripPrefixPartialDecoder::new(
+                input_handle.clone(),
+                CHECKSUM_SIZE,
+            )),
+            ChecksumLocation::End => Arc::new(AsyncStripSuffixPartialDecoder::new(
+                input_handle.clone(),
+                CHECKSUM_SIZE,
+            )),
+        };
+        Ok(Arc::new(AsyncChecksumPartialDecoder::new(
+            input_handle,
+            strip_partial_decoder,
+            self,
+            *decoded_representation,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match decoded_representation {
+            BytesRepresentation::FixedSize(size) => {
+                BytesRepresentation::FixedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::BoundedSize(size) => {
+                BytesRepresentation::BoundedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
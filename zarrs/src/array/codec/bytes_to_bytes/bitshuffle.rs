@@ -0,0 +1,300 @@
+//! The `bitshuffle` bytes to bytes codec.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `bitshuffle` feature, which is disabled by default.
+//!
+//! Transposes the bits of elements within blocks, so that bit `k` of every element in a block is stored contiguously.
+//! This is based on the [bitshuffle](https://github.com/kiyo-masui/bitshuffle) reference implementation.
+//! The final partial block is truncated to a multiple of 8 elements, and any remaining elements are stored verbatim.
+//!
+//! Each block can optionally be LZ4 compressed.
+//! The encoded representation is then that of the HDF5 bitshuffle filter (as used by `hdf5plugin` and often referred to as `bslz4`):
+//!  - the decoded size in bytes (big-endian `u64`),
+//!  - the block size in bytes (big-endian `u32`),
+//!  - each LZ4 compressed block prefixed with its compressed size in bytes (big-endian `u32`), then
+//!  - the remaining elements.
+//!
+//! This codec is identified by `numcodecs.bitshuffle` in Zarr V3 and `bitshuffle` in Zarr V2.
+//!
+//! See [`BitshuffleCodecConfigurationV1`] for example `JSON` metadata.
+
+mod bitshuffle_codec;
+mod bitshuffle_partial_decoder;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::bitshuffle::{
+    BitshuffleCodecConfiguration, BitshuffleCodecConfigurationV1, BitshuffleCompression,
+};
+pub use bitshuffle_codec::BitshuffleCodec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::bitshuffle, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use bitshuffle::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_bitshuffle, create_codec_bitshuffle)
+}
+
+fn is_name_bitshuffle(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_bitshuffle(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: BitshuffleCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::BytesToBytes(codec))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions, CodecTraits},
+            BytesRepresentation,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON_LZ4: &str = r#"{
+        "elementsize": 4,
+        "blocksize": 16,
+        "cname": "lz4"
+    }"#;
+
+    /// 45 `u16` elements: two full blocks of 16, a partial block of 8, and 5 leftover elements.
+    fn reference_none_decoded() -> Vec<u8> {
+        (0..45u16)
+            .flat_map(|i| (i * 37 + 5).to_le_bytes())
+            .collect()
+    }
+
+    // Encoded with a port of the bitshuffle scalar reference implementation
+    const REFERENCE_NONE_ENCODED: [u8; 90] = [
+        85, 85, 102, 102, 45, 45, 182, 73, 56, 142, 106, 165, 76, 54, 112, 56, 128, 63, 0, 192, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85, 85, 102, 102, 45, 45, 182, 73, 199, 113, 82, 43, 155,
+        77, 28, 142, 224, 15, 255, 15, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85, 102, 45, 182, 56,
+        149, 38, 199, 248, 0, 255, 0, 0, 0, 0, 0, 205, 5, 242, 5, 23, 6, 60, 6, 97, 6,
+    ];
+
+    /// 27 pseudo-random `u32` elements: one full block of 16, a partial block of 8, and 3 leftover elements.
+    fn reference_lz4_decoded() -> Vec<u8> {
+        let mut x: u32 = 12345;
+        (0..27)
+            .flat_map(|_| {
+                x = x.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                x.to_le_bytes()
+            })
+            .collect()
+    }
+
+    // Encoded with a port of the bitshuffle scalar reference implementation with the HDF5 filter header
+    // The bitshuffled blocks have no repeated sequences, so LZ4 stores them as literals
+    const REFERENCE_LZ4_ENCODED: [u8; 132] = [
+        0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 64, 0, 0, 0, 66, 240, 49, 170, 170, 51, 51, 15, 15, 119,
+        136, 235, 99, 173, 248, 171, 55, 58, 158, 194, 203, 107, 59, 223, 45, 212, 200, 29, 179,
+        162, 123, 96, 69, 200, 156, 116, 106, 152, 121, 119, 54, 249, 61, 209, 249, 44, 134, 133,
+        163, 41, 7, 203, 70, 183, 186, 238, 218, 232, 134, 37, 168, 162, 215, 85, 228, 215, 10, 0,
+        0, 0, 34, 240, 17, 170, 51, 15, 119, 20, 185, 238, 162, 9, 75, 218, 85, 145, 92, 134, 150,
+        117, 80, 86, 119, 61, 215, 151, 84, 210, 115, 149, 242, 250, 131, 237, 27, 6, 98, 29, 170,
+        199, 192, 4, 185, 244, 230, 172, 218,
+    ];
+
+    #[test]
+    fn codec_bitshuffle_configuration() {
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(JSON_LZ4).unwrap();
+        let codec = BitshuffleCodec::new_with_configuration(&configuration).unwrap();
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(
+            serde_json::to_string(&metadata).unwrap(),
+            r#"{"name":"numcodecs.bitshuffle","configuration":{"elementsize":4,"blocksize":16,"cname":"lz4"}}"#
+        );
+    }
+
+    #[test]
+    fn codec_bitshuffle_invalid() {
+        assert!(BitshuffleCodec::new(0, 0, BitshuffleCompression::None).is_err());
+        assert!(BitshuffleCodec::new(4, 12, BitshuffleCompression::None).is_err());
+
+        let codec = BitshuffleCodec::new(4, 0, BitshuffleCompression::Lz4).unwrap();
+        let bytes_representation = BytesRepresentation::UnboundedSize;
+        assert!(codec
+            .encode(Cow::Owned(vec![0; 7]), &CodecOptions::default())
+            .is_err());
+        let encoded = codec
+            .encode(Cow::Owned(vec![1; 400]), &CodecOptions::default())
+            .unwrap();
+        assert!(codec
+            .decode(
+                Cow::Borrowed(&encoded[..encoded.len() - 1]),
+                &bytes_representation,
+                &CodecOptions::default()
+            )
+            .is_err());
+        assert!(codec
+            .decode(
+                Cow::Borrowed(&encoded[..8]),
+                &bytes_representation,
+                &CodecOptions::default()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn codec_bitshuffle_reference_none() {
+        let bytes = reference_none_decoded();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = BitshuffleCodec::new(2, 16, BitshuffleCompression::None).unwrap();
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(encoded.as_ref(), REFERENCE_NONE_ENCODED);
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(decoded, bytes);
+    }
+
+    #[test]
+    fn codec_bitshuffle_reference_lz4() {
+        let bytes = reference_lz4_decoded();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(JSON_LZ4).unwrap();
+        let codec = BitshuffleCodec::new_with_configuration(&configuration).unwrap();
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(encoded.as_ref(), REFERENCE_LZ4_ENCODED);
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(decoded, bytes);
+    }
+
+    #[test]
+    fn codec_bitshuffle_empty() {
+        let bytes_representation = BytesRepresentation::FixedSize(0);
+        for cname in [BitshuffleCompression::None, BitshuffleCompression::Lz4] {
+            let codec = BitshuffleCodec::new(4, 0, cname).unwrap();
+            let encoded = codec
+                .encode(Cow::Owned(vec![]), &CodecOptions::default())
+                .unwrap();
+            match cname {
+                BitshuffleCompression::None => assert!(encoded.is_empty()),
+                BitshuffleCompression::Lz4 => {
+                    assert_eq!(encoded.as_ref(), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0]);
+                }
+            }
+            let decoded = codec
+                .decode(encoded, &bytes_representation, &CodecOptions::default())
+                .unwrap();
+            assert!(decoded.is_empty());
+        }
+    }
+
+    #[test]
+    fn codec_bitshuffle_round_trip() {
+        for cname in [BitshuffleCompression::None, BitshuffleCompression::Lz4] {
+            for elementsize in [1, 2, 3, 4, 8, 16] {
+                for blocksize in [0, 8, 64] {
+                    for num_elements in [1, 7, 8, 9, 63, 1000, 4099] {
+                        let bytes: Vec<u8> = (0..num_elements * elementsize)
+                            .map(|i| u8::try_from(((i % 253) ^ (i / 253)) % 256).unwrap())
+                            .collect();
+                        let bytes_representation =
+                            BytesRepresentation::FixedSize(bytes.len() as u64);
+                        let codec = BitshuffleCodec::new(elementsize, blocksize, cname).unwrap();
+                        let encoded = codec
+                            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                            .unwrap();
+                        let encoded_size = codec.compute_encoded_size(&bytes_representation);
+                        assert!(encoded.len() as u64 <= encoded_size.size().unwrap());
+                        let decoded = codec
+                            .decode(encoded, &bytes_representation, &CodecOptions::default())
+                            .unwrap();
+                        assert_eq!(decoded, bytes);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn codec_bitshuffle_partial_decode() {
+        let bytes = reference_lz4_decoded();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(JSON_LZ4).unwrap();
+        let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration).unwrap());
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [ByteRange::FromStart(4, Some(4)), ByteRange::Suffix(6)];
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.to_vec()));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded_partial_chunk[0], &bytes[4..8]);
+        assert_eq!(decoded_partial_chunk[1], &bytes[bytes.len() - 6..]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_bitshuffle_async_partial_decode() {
+        let bytes = reference_lz4_decoded();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let configuration: BitshuffleCodecConfiguration = serde_json::from_str(JSON_LZ4).unwrap();
+        let codec = Arc::new(BitshuffleCodec::new_with_configuration(&configuration).unwrap());
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [ByteRange::FromStart(4, Some(4)), ByteRange::Suffix(6)];
+        let input_handle = Arc::new(std::io::Cursor::new(encoded.to_vec()));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded_partial_chunk[0], &bytes[4..8]);
+        assert_eq!(decoded_partial_chunk[1], &bytes[bytes.len() - 6..]);
+    }
+}
@@ -0,0 +1,439 @@
+use std::{borrow::Cow, ops::Range, sync::Arc};
+
+use lz4::block::CompressionMode;
+
+use crate::{
+    array::{
+        codec::{
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+use super::{
+    bitshuffle_partial_decoder, BitshuffleCodecConfiguration, BitshuffleCodecConfigurationV1,
+    BitshuffleCompression, IDENTIFIER,
+};
+
+/// The number of elements in a block must be a multiple of this.
+const BLOCKED_MULT: usize = 8;
+
+/// The target size of a block in bytes when the block size is automatically determined.
+const TARGET_BLOCK_SIZE_B: usize = 8192;
+
+/// The minimum number of elements in an automatically determined block.
+const MIN_RECOMMEND_BLOCK: usize = 128;
+
+/// The size of the header of LZ4 compressed data: the decoded size (`u64`) and block size in bytes (`u32`).
+const LZ4_HEADER_SIZE: usize = size_of::<u64>() + size_of::<u32>();
+
+/// The size of the header of each LZ4 compressed block: the compressed size (`u32`).
+const LZ4_BLOCK_HEADER_SIZE: usize = size_of::<u32>();
+
+/// The default number of elements in a block for a given element size.
+///
+/// This matches `bshuf_default_block_size` in the reference implementation.
+fn default_block_size(elementsize: usize) -> usize {
+    let block_size = (TARGET_BLOCK_SIZE_B / elementsize) / BLOCKED_MULT * BLOCKED_MULT;
+    block_size.max(MIN_RECOMMEND_BLOCK)
+}
+
+/// An upper bound on the size of LZ4 compressed data, as in `LZ4_compressBound`.
+const fn lz4_compress_bound(size: usize) -> usize {
+    size + size / 255 + 16
+}
+
+/// The element ranges of the bitshuffled blocks of `num_elements` elements.
+///
+/// The final partial block is truncated to a multiple of 8 elements.
+/// The remaining `num_elements % 8` elements are stored verbatim after the last block.
+fn blocks(num_elements: usize, block_size: usize) -> impl Iterator<Item = Range<usize>> {
+    let num_full_blocks = num_elements / block_size;
+    let last_block_size = num_elements % block_size / BLOCKED_MULT * BLOCKED_MULT;
+    let last_block_start = num_full_blocks * block_size;
+    (0..num_full_blocks)
+        .map(move |block| block * block_size..(block + 1) * block_size)
+        .chain(
+            (last_block_size > 0).then_some(last_block_start..last_block_start + last_block_size),
+        )
+}
+
+/// Transpose an 8x8 bit matrix stored in the bytes of a `u64`.
+///
+/// Bit `j` of byte `i` is swapped with bit `i` of byte `j`.
+const fn transpose_bit_8x8(mut x: u64) -> u64 {
+    let t = (x ^ (x >> 7)) & 0x00AA_00AA_00AA_00AA;
+    x = x ^ t ^ (t << 7);
+    let t = (x ^ (x >> 14)) & 0x0000_CCCC_0000_CCCC;
+    x = x ^ t ^ (t << 14);
+    let t = (x ^ (x >> 28)) & 0x0000_0000_F0F0_F0F0;
+    x ^ t ^ (t << 28)
+}
+
+/// Bitshuffle a block of elements.
+///
+/// Bit `k` of byte `j` of each element is written to bit plane `8 * j + k`.
+/// Each group of 8 elements is transposed as an 8x8 bit matrix, which the compiler can vectorise.
+fn bitshuffle_block(decoded: &[u8], encoded: &mut [u8], elementsize: usize) {
+    let bytes_per_plane = decoded.len() / elementsize / BLOCKED_MULT;
+    for (group, elements) in decoded.chunks_exact(elementsize * BLOCKED_MULT).enumerate() {
+        for byte in 0..elementsize {
+            let x = u64::from_le_bytes(std::array::from_fn(|element| {
+                elements[element * elementsize + byte]
+            }));
+            let planes = transpose_bit_8x8(x).to_le_bytes();
+            for (bit, plane) in planes.into_iter().enumerate() {
+                encoded[(byte * 8 + bit) * bytes_per_plane + group] = plane;
+            }
+        }
+    }
+}
+
+/// Reverse [`bitshuffle_block`].
+fn bitunshuffle_block(encoded: &[u8], decoded: &mut [u8], elementsize: usize) {
+    let bytes_per_plane = encoded.len() / elementsize / BLOCKED_MULT;
+    for (group, elements) in decoded
+        .chunks_exact_mut(elementsize * BLOCKED_MULT)
+        .enumerate()
+    {
+        for byte in 0..elementsize {
+            let x = u64::from_le_bytes(std::array::from_fn(|bit| {
+                encoded[(byte * 8 + bit) * bytes_per_plane + group]
+            }));
+            let bytes = transpose_bit_8x8(x).to_le_bytes();
+            for (element, value) in bytes.into_iter().enumerate() {
+                elements[element * elementsize + byte] = value;
+            }
+        }
+    }
+}
+
+/// Read a big-endian `u32` from the start of `bytes`.
+fn read_u32_be(bytes: &[u8]) -> Option<u32> {
+    Some(u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?))
+}
+
+/// A `bitshuffle` codec implementation.
+#[derive(Clone, Debug)]
+pub struct BitshuffleCodec {
+    elementsize: usize,
+    blocksize: usize,
+    cname: BitshuffleCompression,
+}
+
+impl BitshuffleCodec {
+    /// Create a new `bitshuffle` codec.
+    ///
+    /// `blocksize` is the number of elements in each block, or 0 to use the default block size.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if `elementsize` is 0 or `blocksize` is not a multiple of 8.
+    pub fn new(
+        elementsize: usize,
+        blocksize: usize,
+        cname: BitshuffleCompression,
+    ) -> Result<Self, PluginCreateError> {
+        if elementsize == 0 {
+            return Err(PluginCreateError::from(
+                "bitshuffle elementsize must be greater than 0",
+            ));
+        }
+        if blocksize % BLOCKED_MULT != 0 {
+            return Err(PluginCreateError::Other(format!(
+                "bitshuffle blocksize {blocksize} is invalid, must be a multiple of {BLOCKED_MULT}"
+            )));
+        }
+        Ok(Self {
+            elementsize,
+            blocksize,
+            cname,
+        })
+    }
+
+    /// Create a new `bitshuffle` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &BitshuffleCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let BitshuffleCodecConfiguration::V1(configuration) = configuration;
+        Self::new(
+            configuration.elementsize,
+            configuration.blocksize,
+            configuration.cname,
+        )
+    }
+
+    /// The number of elements in each block.
+    fn block_size(&self) -> usize {
+        if self.blocksize == 0 {
+            default_block_size(self.elementsize)
+        } else {
+            self.blocksize
+        }
+    }
+
+    fn num_elements(&self, num_bytes: usize) -> Result<usize, CodecError> {
+        if num_bytes % self.elementsize == 0 {
+            Ok(num_bytes / self.elementsize)
+        } else {
+            Err(CodecError::Other(format!(
+                "bitshuffle input of {num_bytes} bytes is not a multiple of the element size {}",
+                self.elementsize
+            )))
+        }
+    }
+
+    fn encode_bytes(&self, decoded_value: &[u8]) -> Result<Vec<u8>, CodecError> {
+        let elementsize = self.elementsize;
+        let num_elements = self.num_elements(decoded_value.len())?;
+        let block_size = self.block_size();
+        let leftover = &decoded_value[num_elements / BLOCKED_MULT * BLOCKED_MULT * elementsize..];
+
+        let encoded_value = match self.cname {
+            BitshuffleCompression::None => {
+                let mut encoded_value = vec![0; decoded_value.len()];
+                for block in blocks(num_elements, block_size) {
+                    let bytes = block.start * elementsize..block.end * elementsize;
+                    bitshuffle_block(
+                        &decoded_value[bytes.clone()],
+                        &mut encoded_value[bytes],
+                        elementsize,
+                    );
+                }
+                let leftover_start = decoded_value.len() - leftover.len();
+                encoded_value[leftover_start..].copy_from_slice(leftover);
+                encoded_value
+            }
+            BitshuffleCompression::Lz4 => {
+                let block_size_bytes = u32::try_from(block_size * elementsize).map_err(|_| {
+                    CodecError::from("bitshuffle block size exceeds the maximum LZ4 block size")
+                })?;
+                let mut encoded_value = Vec::with_capacity(lz4_compress_bound(decoded_value.len()));
+                encoded_value.extend_from_slice(&(decoded_value.len() as u64).to_be_bytes());
+                encoded_value.extend_from_slice(&block_size_bytes.to_be_bytes());
+                let mut shuffled = vec![0; block_size * elementsize];
+                for block in blocks(num_elements, block_size) {
+                    let shuffled = &mut shuffled[..block.len() * elementsize];
+                    bitshuffle_block(
+                        &decoded_value[block.start * elementsize..block.end * elementsize],
+                        shuffled,
+                        elementsize,
+                    );
+                    let compressed =
+                        lz4::block::compress(shuffled, Some(CompressionMode::DEFAULT), false)?;
+                    let compressed_size = u32::try_from(compressed.len()).unwrap(); // bounded by the block size
+                    encoded_value.extend_from_slice(&compressed_size.to_be_bytes());
+                    encoded_value.extend_from_slice(&compressed);
+                }
+                encoded_value.extend_from_slice(leftover);
+                encoded_value
+            }
+        };
+        Ok(encoded_value)
+    }
+
+    /// Decode `bitshuffle` encoded bytes.
+    pub(super) fn decode_bytes(&self, encoded_value: &[u8]) -> Result<Vec<u8>, CodecError> {
+        let elementsize = self.elementsize;
+        match self.cname {
+            BitshuffleCompression::None => {
+                let num_elements = self.num_elements(encoded_value.len())?;
+                let mut decoded_value = vec![0; encoded_value.len()];
+                for block in blocks(num_elements, self.block_size()) {
+                    let bytes = block.start * elementsize..block.end * elementsize;
+                    bitunshuffle_block(
+                        &encoded_value[bytes.clone()],
+                        &mut decoded_value[bytes],
+                        elementsize,
+                    );
+                }
+                let leftover_start = num_elements / BLOCKED_MULT * BLOCKED_MULT * elementsize;
+                decoded_value[leftover_start..].copy_from_slice(&encoded_value[leftover_start..]);
+                Ok(decoded_value)
+            }
+            BitshuffleCompression::Lz4 => {
+                let invalid = || CodecError::from("bitshuffle encoded value is invalid");
+                if encoded_value.len() < LZ4_HEADER_SIZE {
+                    return Err(invalid());
+                }
+                let (header, mut encoded_value) = encoded_value.split_at(LZ4_HEADER_SIZE);
+                let decoded_size = u64::from_be_bytes(header[..8].try_into().unwrap());
+                let decoded_size = usize::try_from(decoded_size).map_err(|_| invalid())?;
+                let block_size_bytes = read_u32_be(&header[8..]).ok_or_else(invalid)? as usize;
+                // The block size is read from the header, as in the reference implementation
+                if block_size_bytes == 0 || block_size_bytes % (elementsize * BLOCKED_MULT) != 0 {
+                    return Err(CodecError::Other(format!(
+                        "bitshuffle block size of {block_size_bytes} bytes is invalid for an element size of {elementsize}"
+                    )));
+                }
+                let block_size = block_size_bytes / elementsize;
+                let num_elements = self.num_elements(decoded_size)?;
+
+                let mut decoded_value = Vec::with_capacity(decoded_size);
+                let mut shuffled = Vec::with_capacity(block_size_bytes);
+                for block in blocks(num_elements, block_size) {
+                    let block_size_bytes = block.len() * elementsize;
+                    let compressed_size = read_u32_be(encoded_value).ok_or_else(invalid)? as usize;
+                    let compressed = encoded_value
+                        .get(LZ4_BLOCK_HEADER_SIZE..LZ4_BLOCK_HEADER_SIZE + compressed_size)
+                        .ok_or_else(invalid)?;
+                    shuffled.clear();
+                    shuffled.extend(lz4::block::decompress(
+                        compressed,
+                        Some(i32::try_from(block_size_bytes).map_err(|_| invalid())?),
+                    )?);
+                    if shuffled.len() != block_size_bytes {
+                        return Err(invalid());
+                    }
+                    let decoded_start = decoded_value.len();
+                    decoded_value.resize(decoded_start + block_size_bytes, 0);
+                    bitunshuffle_block(&shuffled, &mut decoded_value[decoded_start..], elementsize);
+                    encoded_value = &encoded_value[LZ4_BLOCK_HEADER_SIZE + compressed_size..];
+                }
+                if decoded_value.len() + encoded_value.len() != decoded_size {
+                    return Err(invalid());
+                }
+                decoded_value.extend_from_slice(encoded_value);
+                Ok(decoded_value)
+            }
+        }
+    }
+}
+
+impl CodecTraits for BitshuffleCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = BitshuffleCodecConfigurationV1 {
+            elementsize: self.elementsize,
+            blocksize: self.blocksize,
+            cname: self.cname,
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .expect("bitshuffle configuration is valid json"),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for BitshuffleCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        Ok(Cow::Owned(self.encode_bytes(&decoded_value)?))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        Ok(Cow::Owned(self.decode_bytes(&encoded_value)?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            bitshuffle_partial_decoder::BitshufflePartialDecoder::new(input_handle, self),
+        ))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            bitshuffle_partial_decoder::AsyncBitshufflePartialDecoder::new(input_handle, self),
+        ))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match (self.cname, decoded_representation) {
+            (BitshuffleCompression::None, _) => *decoded_representation,
+            (
+                BitshuffleCompression::Lz4,
+                BytesRepresentation::FixedSize(size) | BytesRepresentation::BoundedSize(size),
+            ) => {
+                let block_size_bytes = (self.block_size() * self.elementsize) as u64;
+                let num_full_blocks = size / block_size_bytes;
+                let last_block_size = size % block_size_bytes;
+                let block_bound = |size: u64| {
+                    (LZ4_BLOCK_HEADER_SIZE + lz4_compress_bound(usize::try_from(size).unwrap()))
+                        as u64
+                };
+                let mut encoded_size =
+                    LZ4_HEADER_SIZE as u64 + num_full_blocks * block_bound(block_size_bytes);
+                if last_block_size > 0 {
+                    // includes the uncompressed leftover elements
+                    encoded_size += block_bound(last_block_size);
+                }
+                BytesRepresentation::BoundedSize(encoded_size)
+            }
+            (BitshuffleCompression::Lz4, BytesRepresentation::UnboundedSize) => {
+                BytesRepresentation::UnboundedSize
+            }
+        }
+    }
+}
@@ -383,6 +383,67 @@ mod tests {
         assert_eq!(answer, decoded);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_blosc_thread_budget() {
+        use crate::array::ArrayBuilder;
+        use crate::storage::store::MemoryStore;
+
+        // Chunks are large enough for blosc to use internal threads
+        let codec = BloscCodec::new(
+            BloscCompressor::LZ4,
+            5.try_into().unwrap(),
+            None,
+            BloscShuffleMode::NoShuffle,
+            None,
+        )
+        .unwrap();
+        let n_threads_log = codec.n_threads_log.clone();
+        let array = ArrayBuilder::new(
+            vec![8000, 2000],
+            DataType::UInt8,
+            vec![2000, 2000].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .bytes_to_bytes_codecs(vec![Arc::new(codec)])
+        .build(Arc::new(MemoryStore::new()), "/")
+        .unwrap();
+        let options = CodecOptions::builder()
+            .concurrent_target(4)
+            .chunk_concurrent_maximum(4)
+            .build();
+        let take_log = || std::mem::take(&mut *n_threads_log.lock().unwrap());
+
+        // Chunk parallel operations give blosc one thread per chunk
+        let subset = ArraySubset::new_with_shape(array.shape().to_vec());
+        let elements = vec![1u8; 8000 * 2000];
+        array
+            .store_array_subset_elements_opt(&subset, &elements, &options)
+            .unwrap();
+        assert_eq!(take_log(), [1; 4]);
+        array
+            .retrieve_array_subset_elements_opt::<u8>(&subset, &options)
+            .unwrap();
+        assert_eq!(take_log(), [1; 4]);
+
+        // Single chunk operations give blosc the concurrent target
+        array
+            .store_chunk_elements_opt(&[1, 0], &elements[..2000 * 2000], &options)
+            .unwrap();
+        assert_eq!(take_log(), [4]);
+        array
+            .retrieve_chunk_elements_opt::<u8>(&[1, 0], &options)
+            .unwrap();
+        assert_eq!(take_log(), [4]);
+        array
+            .retrieve_array_subset_elements_opt::<u8>(
+                &ArraySubset::new_with_ranges(&[0..2000, 0..2000]),
+                &options,
+            )
+            .unwrap();
+        assert_eq!(take_log(), [4]);
+    }
+
     #[cfg(feature = "async")]
     #[tokio::test]
     #[cfg_attr(miri, ignore)]
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, ffi::c_char, sync::Arc};
 
-use blosc_sys::{blosc_get_complib_info, BLOSC_MAX_OVERHEAD};
+use blosc_sys::{blosc_get_complib_info, BLOSC_MAX_OVERHEAD, BLOSC_MAX_THREADS};
 
 use crate::{
     array::{
@@ -20,10 +20,14 @@ use crate::array::codec::AsyncBytesPartialDecoderTraits;
 use super::{
     blosc_compress_bytes, blosc_decompress_bytes, blosc_partial_decoder, blosc_validate,
     compressor_as_cstr, BloscCodecConfiguration, BloscCodecConfigurationV1, BloscCompressionLevel,
-    BloscCompressor, BloscError, BloscShuffleMode, IDENTIFIER,
+    BloscCompressor, BloscError, BloscShuffleMode, IDENTIFIER, MIN_PARALLEL_LENGTH,
 };
 
 /// A `blosc` codec implementation.
+///
+/// Blosc uses internal threads to encode and decode values of at least 4 MB.
+/// The number of threads is the [`CodecOptions::concurrent_target`] received by the codec.
+/// Array operations partition their concurrent target between chunks and codecs, so blosc runs single threaded when chunk parallelism saturates the concurrent target and uses up to the concurrent target threads when a single chunk is encoded or decoded.
 #[derive(Clone, Debug)]
 pub struct BloscCodec {
     cname: BloscCompressor,
@@ -31,6 +35,9 @@ pub struct BloscCodec {
     blocksize: usize,
     shuffle_mode: Option<BloscShuffleMode>,
     typesize: Option<usize>,
+    /// The thread budget received by each encode or decode.
+    #[cfg(test)]
+    pub(super) n_threads_log: Arc<std::sync::Mutex<Vec<usize>>>,
 }
 
 impl BloscCodec {
@@ -79,6 +86,8 @@ impl BloscCodec {
             blocksize: blocksize.unwrap_or_default(),
             shuffle_mode: Some(shuffle_mode),
             typesize,
+            #[cfg(test)]
+            n_threads_log: Arc::default(),
         })
     }
 
@@ -165,23 +174,29 @@ impl BytesToBytesCodecTraits for BloscCodec {
 
     fn recommended_concurrency(
         &self,
-        _decoded_representation: &BytesRepresentation,
+        decoded_representation: &BytesRepresentation,
     ) -> Result<RecommendedConcurrency, CodecError> {
-        // TODO: Dependent on the block size, recommended concurrency could be > 1
-        Ok(RecommendedConcurrency::new_maximum(1))
+        // Blosc only uses internal threads for large values
+        let parallel = decoded_representation
+            .size()
+            .is_none_or(|size| size >= MIN_PARALLEL_LENGTH as u64);
+        if parallel {
+            Ok(RecommendedConcurrency::new_maximum(
+                BLOSC_MAX_THREADS as usize,
+            ))
+        } else {
+            Ok(RecommendedConcurrency::new_maximum(1))
+        }
     }
 
     fn encode<'a>(
         &self,
         decoded_value: RawBytes<'a>,
-        _options: &CodecOptions,
+        options: &CodecOptions,
     ) -> Result<RawBytes<'a>, CodecError> {
-        // let n_threads = std::cmp::min(
-        //     options.concurrent_limit(),
-        //     std::thread::available_parallelism().unwrap(),
-        // )
-        // .get();
-        let n_threads = 1;
+        let n_threads = options.concurrent_target().max(1);
+        #[cfg(test)]
+        self.n_threads_log.lock().unwrap().push(n_threads);
         Ok(Cow::Owned(self.do_encode(&decoded_value, n_threads)?))
     }
 
@@ -189,14 +204,11 @@ impl BytesToBytesCodecTraits for BloscCodec {
         &self,
         encoded_value: RawBytes<'a>,
         _decoded_representation: &BytesRepresentation,
-        _options: &CodecOptions,
+        options: &CodecOptions,
     ) -> Result<RawBytes<'a>, CodecError> {
-        // let n_threads = std::cmp::min(
-        //     options.concurrent_limit(),
-        //     std::thread::available_parallelism().unwrap(),
-        // )
-        // .get();
-        let n_threads = 1;
+        let n_threads = options.concurrent_target().max(1);
+        #[cfg(test)]
+        self.n_threads_log.lock().unwrap().push(n_threads);
         Ok(Cow::Owned(Self::do_decode(&encoded_value, n_threads)?))
     }
 
@@ -0,0 +1,126 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{
+        codec::{BytesPartialDecoderTraits, BytesToBytesCodecTraits, CodecError, CodecOptions},
+        BytesRepresentation, RawBytes,
+    },
+    byte_range::{extract_byte_ranges, ByteRange},
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+/// Partial decoder for checksum codecs.
+///
+/// If [`CodecOptions::validate_checksums`] is enabled, the entire encoded value is decoded by the codec to validate the checksum.
+/// Otherwise, decoding is delegated to a partial decoder that strips the checksum without reading the entire encoded value.
+pub(crate) struct ChecksumPartialDecoder {
+    input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    strip_partial_decoder: Arc<dyn BytesPartialDecoderTraits>,
+    codec: Arc<dyn BytesToBytesCodecTraits>,
+    decoded_representation: BytesRepresentation,
+}
+
+impl ChecksumPartialDecoder {
+    /// Create a new partial decoder for a checksum codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        strip_partial_decoder: Arc<dyn BytesPartialDecoderTraits>,
+        codec: Arc<dyn BytesToBytesCodecTraits>,
+        decoded_representation: BytesRepresentation,
+    ) -> Self {
+        Self {
+            input_handle,
+            strip_partial_decoder,
+            codec,
+            decoded_representation,
+        }
+    }
+}
+
+impl BytesPartialDecoderTraits for ChecksumPartialDecoder {
+    fn partial_decode(
+        &self,
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        if !options.validate_checksums() {
+            return self
+                .strip_partial_decoder
+                .partial_decode(decoded_regions, options);
+        }
+
+        let Some(encoded_value) = self.input_handle.decode(options)? else {
+            return Ok(None);
+        };
+        let decoded_value =
+            self.codec
+                .decode(encoded_value, &self.decoded_representation, options)?;
+        Ok(Some(
+            extract_byte_ranges(&decoded_value, decoded_regions)
+                .map_err(CodecError::InvalidByteRangeError)?
+                .into_iter()
+                .map(Cow::Owned)
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial decoder for checksum codecs.
+pub(crate) struct AsyncChecksumPartialDecoder {
+    input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    strip_partial_decoder: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    codec: Arc<dyn BytesToBytesCodecTraits>,
+    decoded_representation: BytesRepresentation,
+}
+
+#[cfg(feature = "async")]
+impl AsyncChecksumPartialDecoder {
+    /// Create a new partial decoder for a checksum codec.
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        strip_partial_decoder: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        codec: Arc<dyn BytesToBytesCodecTraits>,
+        decoded_representation: BytesRepresentation,
+    ) -> Self {
+        Self {
+            input_handle,
+            strip_partial_decoder,
+            codec,
+            decoded_representation,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncBytesPartialDecoderTraits for AsyncChecksumPartialDecoder {
+    async fn partial_decode(
+        &self,
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        if !options.validate_checksums() {
+            return self
+                .strip_partial_decoder
+                .partial_decode(decoded_regions, options)
+                .await;
+        }
+
+        let Some(encoded_value) = self.input_handle.decode(options).await? else {
+            return Ok(None);
+        };
+        let decoded_value =
+            self.codec
+                .decode(encoded_value, &self.decoded_representation, options)?;
+        Ok(Some(
+            extract_byte_ranges(&decoded_value, decoded_regions)
+                .map_err(CodecError::InvalidByteRangeError)?
+                .into_iter()
+                .map(Cow::Owned)
+                .collect(),
+        ))
+    }
+}
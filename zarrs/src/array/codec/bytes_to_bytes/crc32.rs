@@ -0,0 +1,220 @@
+//! The `crc32` bytes to bytes codec.
+//!
+//! Prepends or appends a CRC32 checksum of the input bytestream.
+//!
+//! This is based on the `numcodecs` implementation.
+//! See <https://numcodecs.readthedocs.io/en/latest/checksum32.html#crc32>.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `crc32` feature, which is disabled by default.
+//!
+//! See [`Crc32CodecConfigurationV1`] for example `JSON` metadata.
+
+mod crc32_codec;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::crc32::{
+    ChecksumLocation, Crc32CodecConfiguration, Crc32CodecConfigurationV1,
+};
+pub use crc32_codec::Crc32Codec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::crc32, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use crc32::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_crc32, create_codec_crc32)
+}
+
+fn is_name_crc32(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_crc32(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(Crc32Codec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+const CHECKSUM_SIZE: usize = size_of::<u32>();
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits},
+            BytesRepresentation,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    #[test]
+    fn codec_crc32_configuration() {
+        let codec_configuration: Crc32CodecConfiguration = serde_json::from_str("{}").unwrap();
+        let codec = Crc32Codec::new_with_configuration(&codec_configuration);
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(
+            serde_json::to_string(&metadata).unwrap(),
+            r#"{"name":"numcodecs.crc32","configuration":{"location":"start"}}"#
+        );
+    }
+
+    #[test]
+    fn codec_crc32_start() {
+        let bytes: Vec<u8> = (0..6).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Crc32Codec::new(ChecksumLocation::Start);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        // Checksum from zlib.crc32
+        assert_eq!(&encoded[..CHECKSUM_SIZE], &[74, 207, 235, 48]);
+        assert_eq!(&encoded[CHECKSUM_SIZE..], &bytes);
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_crc32_end() {
+        let bytes: Vec<u8> = (0..6).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Crc32Codec::new(ChecksumLocation::End);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(&encoded[..bytes.len()], &bytes);
+        assert_eq!(&encoded[bytes.len()..], &[74, 207, 235, 48]);
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_crc32_invalid_checksum() {
+        let bytes: Vec<u8> = (0..6).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Crc32Codec::new(ChecksumLocation::Start);
+
+        let mut encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap()
+            .to_vec();
+        encoded[CHECKSUM_SIZE] ^= 1;
+        assert!(matches!(
+            codec.decode(
+                Cow::Borrowed(&encoded),
+                &bytes_representation,
+                &CodecOptions::default()
+            ),
+            Err(CodecError::InvalidChecksum)
+        ));
+        let options = CodecOptions::builder().validate_checksums(false).build();
+        assert!(codec
+            .decode(Cow::Borrowed(&encoded), &bytes_representation, &options)
+            .is_ok());
+        assert!(codec
+            .decode(
+                Cow::Borrowed(&encoded[..2]),
+                &bytes_representation,
+                &CodecOptions::default()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn codec_crc32_partial_decode() {
+        for location in [ChecksumLocation::Start, ChecksumLocation::End] {
+            let bytes: Vec<u8> = (0..32).collect();
+            let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+            let codec = Arc::new(Crc32Codec::new(location));
+
+            let encoded = codec
+                .encode(Cow::Owned(bytes), &CodecOptions::default())
+                .unwrap();
+            let decoded_regions = [ByteRange::FromStart(3, Some(2)), ByteRange::Suffix(2)];
+            let input_handle = Arc::new(std::io::Cursor::new(encoded));
+            let partial_decoder = codec
+                .partial_decoder(
+                    input_handle,
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .unwrap();
+            let decoded_partial_chunk = partial_decoder
+                .partial_decode(&decoded_regions, &CodecOptions::default())
+                .unwrap()
+                .unwrap();
+            let answer: &[Vec<u8>] = &[vec![3, 4], vec![30, 31]];
+            assert_eq!(
+                answer,
+                decoded_partial_chunk
+                    .into_iter()
+                    .map(|v| v.to_vec())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_crc32_async_partial_decode() {
+        for location in [ChecksumLocation::Start, ChecksumLocation::End] {
+            let bytes: Vec<u8> = (0..32).collect();
+            let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+            let codec = Arc::new(Crc32Codec::new(location));
+
+            let encoded = codec
+                .encode(Cow::Owned(bytes), &CodecOptions::default())
+                .unwrap();
+            let decoded_regions = [ByteRange::FromStart(3, Some(2)), ByteRange::Suffix(2)];
+            let input_handle = Arc::new(std::io::Cursor::new(encoded));
+            let partial_decoder = codec
+                .async_partial_decoder(
+                    input_handle,
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .await
+                .unwrap();
+            let decoded_partial_chunk = partial_decoder
+                .partial_decode(&decoded_regions, &CodecOptions::default())
+                .await
+                .unwrap()
+                .unwrap();
+            let answer: &[Vec<u8>] = &[vec![3, 4], vec![30, 31]];
+            assert_eq!(
+                answer,
+                decoded_partial_chunk
+                    .into_iter()
+                    .map(|v| v.to_vec())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{
+        codec::{
+            bytes_to_bytes::{
+                checksum_partial_decoder::ChecksumPartialDecoder,
+                strip_prefix_partial_decoder::StripPrefixPartialDecoder,
+                strip_suffix_partial_decoder::StripSuffixPartialDecoder,
+            },
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, Checksums, CodecError, CodecOptions, CodecTraits,
+            RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+#[cfg(feature = "async")]
+use crate::array::codec::bytes_to_bytes::{
+    checksum_partial_decoder::AsyncChecksumPartialDecoder,
+    strip_prefix_partial_decoder::AsyncStripPrefixPartialDecoder,
+    strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder,
+};
+
+use super::{
+    ChecksumLocation, Crc32CodecConfiguration, Crc32CodecConfigurationV1, CHECKSUM_SIZE, IDENTIFIER,
+};
+
+/// A `crc32` (CRC32 checksum) codec implementation.
+#[derive(Clone, Debug, Default)]
+pub struct Crc32Codec {
+    location: ChecksumLocation,
+}
+
+impl Crc32Codec {
+    /// Create a new `crc32` codec.
+    #[must_use]
+    pub const fn new(location: ChecksumLocation) -> Self {
+        Self { location }
+    }
+
+    /// Create a new `crc32` codec from configuration.
+    #[must_use]
+    pub const fn new_with_configuration(configuration: &Crc32CodecConfiguration) -> Self {
+        let Crc32CodecConfiguration::V1(configuration) = configuration;
+        Self::new(configuration.location)
+    }
+}
+
+impl CodecTraits for Crc32Codec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = Crc32CodecConfigurationV1 {
+            location: self.location,
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .expect("crc32 configuration is valid json"),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for Crc32Codec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let checksum = crc32fast::hash(&decoded_value).to_le_bytes();
+        let mut encoded_value: Vec<u8> = Vec::with_capacity(decoded_value.len() + checksum.len());
+        match self.location {
+            ChecksumLocation::Start => {
+                encoded_value.extend_from_slice(&checksum);
+                encoded_value.extend_from_slice(&decoded_value);
+            }
+            ChecksumLocation::End => {
+                encoded_value.extend_from_slice(&decoded_value);
+                encoded_value.extend_from_slice(&checksum);
+            }
+        }
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        if encoded_value.len() < CHECKSUM_SIZE {
+            return Err(CodecError::Other(
+                "crc32 decoder expects a 32 bit input".to_string(),
+            ));
+        }
+        let (checksum, decoded_value) = match self.location {
+            ChecksumLocation::Start => {
+                let (checksum, decoded_value) = encoded_value.split_at(CHECKSUM_SIZE);
+                (checksum, decoded_value)
+            }
+            ChecksumLocation::End => {
+                let (decoded_value, checksum) =
+                    encoded_value.split_at(encoded_value.len() - CHECKSUM_SIZE);
+                (checksum, decoded_value)
+            }
+        };
+        if options.validate_checksums() && crc32fast::hash(decoded_value).to_le_bytes() != checksum
+        {
+            return Err(CodecError::InvalidChecksum);
+        }
+        Ok(Cow::Owned(decoded_value.to_vec()))
+    }
+
+    fn checksums(&self, encoded_value: &[u8]) -> Result<Option<Checksums>, CodecError> {
+        if encoded_value.len() < CHECKSUM_SIZE {
+            return Err(CodecError::Other(
+                "crc32 decoder expects a 32 bit input".to_string(),
+            ));
+        }
+        let (checksum, decoded_value) = match self.location {
+            ChecksumLocation::Start => encoded_value.split_at(CHECKSUM_SIZE),
+            ChecksumLocation::End => {
+                let (decoded_value, checksum) =
+                    encoded_value.split_at(encoded_value.len() - CHECKSUM_SIZE);
+                (checksum, decoded_value)
+            }
+        };
+        Ok(Some((
+            checksum.to_vec(),
+            crc32fast::hash(decoded_value).to_le_bytes().to_vec(),
+        )))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        let strip_partial_decoder: Arc<dyn BytesPartialDecoderTraits> = match self.location {
+            ChecksumLocation::Start => Arc::new(StripPrefixPartialDecoder::new(
+                input_handle.clone(),
+                CHECKSUM_SIZE,
+            )),
+            ChecksumLocation::End => Arc::new(StripSuffixPartialDecoder::new(
+                input_handle.clone(),
+                CHECKSUM_SIZE,
+            )),
+        };
+        Ok(Arc::new(ChecksumPartialDecoder::new(
+            input_handle,
+            strip_partial_decoder,
+            self,
+            *decoded_representation,
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        let strip_partial_decoder: Arc<dyn AsyncBytesPartialDecoderTraits> = match self.location {
+            ChecksumLocation::Start => Arc::new(AsyncStripPrefixPartialDecoder::new(
+                input_handle.clone(),
+                CHECKSUM_SIZE,
+            )),
+            ChecksumLocation::End => Arc::new(AsyncStripSuffixPartialDecoder::new(
+                input_handle.clone(),
+                CHECKSUM_SIZE,
+            )),
+        };
+        Ok(Arc::new(AsyncChecksumPartialDecoder::new(
+            input_handle,
+            strip_partial_decoder,
+            self,
+            *decoded_representation,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match decoded_representation {
+            BytesRepresentation::FixedSize(size) => {
+                BytesRepresentation::FixedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::BoundedSize(size) => {
+                BytesRepresentation::BoundedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
@@ -3,9 +3,13 @@ use std::{borrow::Cow, sync::Arc};
 use crate::{
     array::{
         codec::{
-            bytes_to_bytes::strip_suffix_partial_decoder::StripSuffixPartialDecoder,
+            bytes_to_bytes::{
+                checksum_partial_decoder::ChecksumPartialDecoder,
+                strip_suffix_partial_decoder::StripSuffixPartialDecoder,
+            },
             BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
-            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+            BytesToBytesCodecTraits, Checksums, CodecError, CodecOptions, CodecTraits,
+            RecommendedConcurrency,
         },
         ArrayMetadataOptions, BytesRepresentation, RawBytes,
     },
@@ -16,7 +20,10 @@ use crate::{
 use crate::array::codec::AsyncBytesPartialDecoderTraits;
 
 #[cfg(feature = "async")]
-use crate::array::codec::bytes_to_bytes::strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder;
+use crate::array::codec::bytes_to_bytes::{
+    checksum_partial_decoder::AsyncChecksumPartialDecoder,
+    strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder,
+};
 
 use super::{Crc32cCodecConfiguration, Crc32cCodecConfigurationV1, CHECKSUM_SIZE, IDENTIFIER};
 
@@ -101,15 +108,32 @@ impl BytesToBytesCodecTraits for Crc32cCodec {
         }
     }
 
+    fn checksums(&self, encoded_value: &[u8]) -> Result<Option<Checksums>, CodecError> {
+        if encoded_value.len() >= CHECKSUM_SIZE {
+            let (decoded_value, checksum) =
+                encoded_value.split_at(encoded_value.len() - CHECKSUM_SIZE);
+            Ok(Some((
+                checksum.to_vec(),
+                crc32c::crc32c(decoded_value).to_le_bytes().to_vec(),
+            )))
+        } else {
+            Err(CodecError::Other(
+                "crc32c decoder expects a 32 bit input".to_string(),
+            ))
+        }
+    }
+
     fn partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn BytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
+        decoded_representation: &BytesRepresentation,
         _options: &CodecOptions,
     ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
-        Ok(Arc::new(StripSuffixPartialDecoder::new(
-            input_handle,
-            CHECKSUM_SIZE,
+        Ok(Arc::new(ChecksumPartialDecoder::new(
+            input_handle.clone(),
+            Arc::new(StripSuffixPartialDecoder::new(input_handle, CHECKSUM_SIZE)),
+            self,
+            *decoded_representation,
         )))
     }
 
@@ -132,12 +156,17 @@ impl BytesToBytesCodecTraits for Crc32cCodec {
     async fn async_partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
+        decoded_representation: &BytesRepresentation,
         _options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
-        Ok(Arc::new(AsyncStripSuffixPartialDecoder::new(
-            input_handle,
-            CHECKSUM_SIZE,
+        Ok(Arc::new(AsyncChecksumPartialDecoder::new(
+            input_handle.clone(),
+            Arc::new(AsyncStripSuffixPartialDecoder::new(
+                input_handle,
+                CHECKSUM_SIZE,
+            )),
+            self,
+            *decoded_representation,
         )))
     }
 
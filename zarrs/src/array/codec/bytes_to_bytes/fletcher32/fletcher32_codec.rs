@@ -5,9 +5,13 @@ use num::Integer;
 use crate::{
     array::{
         codec::{
-            bytes_to_bytes::strip_suffix_partial_decoder::StripSuffixPartialDecoder,
+            bytes_to_bytes::{
+                checksum_partial_decoder::ChecksumPartialDecoder,
+                strip_suffix_partial_decoder::StripSuffixPartialDecoder,
+            },
             BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
-            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+            BytesToBytesCodecTraits, Checksums, CodecError, CodecOptions, CodecTraits,
+            RecommendedConcurrency,
         },
         ArrayMetadataOptions, BytesRepresentation, RawBytes,
     },
@@ -18,7 +22,10 @@ use crate::{
 use crate::array::codec::AsyncBytesPartialDecoderTraits;
 
 #[cfg(feature = "async")]
-use crate::array::codec::bytes_to_bytes::strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder;
+use crate::array::codec::bytes_to_bytes::{
+    checksum_partial_decoder::AsyncChecksumPartialDecoder,
+    strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder,
+};
 
 use super::{
     Fletcher32CodecConfiguration, Fletcher32CodecConfigurationV1, CHECKSUM_SIZE, IDENTIFIER,
@@ -142,15 +149,32 @@ impl BytesToBytesCodecTraits for Fletcher32Codec {
         }
     }
 
+    fn checksums(&self, encoded_value: &[u8]) -> Result<Option<Checksums>, CodecError> {
+        if encoded_value.len() >= CHECKSUM_SIZE {
+            let (decoded_value, checksum) =
+                encoded_value.split_at(encoded_value.len() - CHECKSUM_SIZE);
+            Ok(Some((
+                checksum.to_vec(),
+                h5_checksum_fletcher32(decoded_value).to_le_bytes().to_vec(),
+            )))
+        } else {
+            Err(CodecError::Other(
+                "fletcher32 decoder expects a 32 bit input".to_string(),
+            ))
+        }
+    }
+
     fn partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn BytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
+        decoded_representation: &BytesRepresentation,
         _options: &CodecOptions,
     ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
-        Ok(Arc::new(StripSuffixPartialDecoder::new(
-            input_handle,
-            CHECKSUM_SIZE,
+        Ok(Arc::new(ChecksumPartialDecoder::new(
+            input_handle.clone(),
+            Arc::new(StripSuffixPartialDecoder::new(input_handle, CHECKSUM_SIZE)),
+            self,
+            *decoded_representation,
         )))
     }
 
@@ -173,12 +197,17 @@ impl BytesToBytesCodecTraits for Fletcher32Codec {
     async fn async_partial_decoder(
         self: Arc<Self>,
         input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
-        _decoded_representation: &BytesRepresentation,
+        decoded_representation: &BytesRepresentation,
         _options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
-        Ok(Arc::new(AsyncStripSuffixPartialDecoder::new(
-            input_handle,
-            CHECKSUM_SIZE,
+        Ok(Arc::new(AsyncChecksumPartialDecoder::new(
+            input_handle.clone(),
+            Arc::new(AsyncStripSuffixPartialDecoder::new(
+                input_handle,
+                CHECKSUM_SIZE,
+            )),
+            self,
+            *decoded_representation,
         )))
     }
 
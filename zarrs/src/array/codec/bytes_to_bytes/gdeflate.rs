@@ -16,19 +16,23 @@
 //!  - `COMPRESSED_PAGE_SIZES`: `NUMBER_OF_PAGES` little-endian 64-bit unsigned integers holding the compressed sizes of each page.
 //!
 //! The remaining bytes are the `gdeflate` encoded pages of total length equal to the sum of all `COMPRESSED_PAGE_SIZES`.
+//!
+//! Each page is the compressed bytes of a tile of the input with a length of `tile_size` (the last tile may be shorter).
+//! The `tile_size` is set in the codec configuration and defaults to 65536 (the maximum `gdeflate` page size).
+//! Pages are decompressed in parallel, and partial decoding only decompresses the pages intersecting the requested byte ranges.
 
 mod gdeflate_codec;
 mod gdeflate_partial_decoder;
 
 pub use crate::metadata::v3::array::codec::gdeflate::{
     GDeflateCodecConfiguration, GDeflateCodecConfigurationV1, GDeflateCompressionLevel,
-    GDeflateCompressionLevelError,
+    GDeflateCompressionLevelError, GDeflateTileSize, GDeflateTileSizeError,
 };
 pub use gdeflate_codec::GDeflateCodec;
 
 use crate::{
     array::{
-        codec::{Codec, CodecError, CodecPlugin},
+        codec::{Codec, CodecError, CodecOptions, CodecPlugin},
         RawBytes,
     },
     metadata::v3::{array::codec::gdeflate, MetadataV3},
@@ -39,6 +43,8 @@ pub use gdeflate::IDENTIFIER;
 
 use std::sync::Arc;
 
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
 // Register the codec.
 inventory::submit! {
     CodecPlugin::new(IDENTIFIER, is_name_gdeflate, create_codec_gdeflate)
@@ -56,63 +62,104 @@ pub(crate) fn create_codec_gdeflate(metadata: &MetadataV3) -> Result<Codec, Plug
     Ok(Codec::BytesToBytes(codec))
 }
 
-const GDEFLATE_PAGE_SIZE_UNCOMPRESSED: usize = 65536;
 const GDEFLATE_STATIC_HEADER_LENGTH: usize = 2 * size_of::<u64>();
 
-fn gdeflate_decode(encoded_value: &RawBytes<'_>) -> Result<Vec<u8>, CodecError> {
-    if encoded_value.len() < GDEFLATE_STATIC_HEADER_LENGTH {
+/// Decode the static header of `gdeflate` encoded data.
+///
+/// Returns the decoded value length and the number of pages.
+/// Errors if the number of pages is inconsistent with the decoded value length and `tile_size`.
+fn gdeflate_decode_static_header(
+    static_header: &[u8],
+    tile_size: usize,
+) -> Result<(usize, usize), CodecError> {
+    if static_header.len() < GDEFLATE_STATIC_HEADER_LENGTH {
         return Err(CodecError::UnexpectedChunkDecodedSize(
-            encoded_value.len(),
+            static_header.len(),
             GDEFLATE_STATIC_HEADER_LENGTH as u64,
         ));
     }
-
-    // Decode the static header
-    let as_u64 = |bytes: &[u8]| -> u64 { u64::from_le_bytes(bytes.try_into().unwrap()) };
-    let decoded_value_len = as_u64(&encoded_value[0..size_of::<u64>()]);
+    let decoded_value_len = u64::from_le_bytes(static_header[0..8].try_into().unwrap());
     let decoded_value_len = usize::try_from(decoded_value_len).unwrap();
-    let num_pages = as_u64(&encoded_value[size_of::<u64>()..2 * size_of::<u64>()]);
+    let num_pages = u64::from_le_bytes(static_header[8..16].try_into().unwrap());
     let num_pages = usize::try_from(num_pages).unwrap();
-
-    // Check length of dynamic header
-    let dynamic_header_length = num_pages * size_of::<u64>();
-    if encoded_value.len() < GDEFLATE_STATIC_HEADER_LENGTH + dynamic_header_length {
-        return Err(CodecError::UnexpectedChunkDecodedSize(
-            encoded_value.len(),
-            (GDEFLATE_STATIC_HEADER_LENGTH + dynamic_header_length) as u64,
-        ));
+    if num_pages != decoded_value_len.div_ceil(tile_size) {
+        return Err(CodecError::Other(format!(
+            "gdeflate encoded data has {num_pages} pages, which is inconsistent with a decoded length of {decoded_value_len} and a tile size of {tile_size}"
+        )));
     }
+    Ok((decoded_value_len, num_pages))
+}
 
-    // Decode the pages
-    let decompressor = GDeflateDecompressor::new()?;
-    let mut decoded_value = Vec::with_capacity(decoded_value_len);
-    let mut page_offset = GDEFLATE_STATIC_HEADER_LENGTH + dynamic_header_length;
-    for page in 0..num_pages {
-        // Get the compressed page length
-        let page_size_compressed_offset = GDEFLATE_STATIC_HEADER_LENGTH + page * size_of::<u64>();
-        let page_size_compressed = as_u64(
-            &encoded_value
-                [page_size_compressed_offset..page_size_compressed_offset + size_of::<u64>()],
-        );
-        let page_size_compressed = usize::try_from(page_size_compressed).unwrap();
+/// Decode the compressed page sizes in the dynamic header of `gdeflate` encoded data.
+fn gdeflate_decode_dynamic_header(dynamic_header: &[u8]) -> Vec<usize> {
+    dynamic_header
+        .chunks_exact(size_of::<u64>())
+        .map(|bytes| usize::try_from(u64::from_le_bytes(bytes.try_into().unwrap())).unwrap())
+        .collect()
+}
 
-        // Get the compressed page data
-        let page_data = &encoded_value[page_offset..page_offset + page_size_compressed];
+/// Decompress `gdeflate` pages into their decoded bytes in parallel.
+///
+/// Each page is a tuple of the compressed page and the decoded bytes of the page.
+/// Pages are decompressed with a concurrency of up to the [`CodecOptions::concurrent_target`].
+fn gdeflate_decompress_pages(
+    pages: Vec<(&[u8], &mut [u8])>,
+    options: &CodecOptions,
+) -> Result<(), CodecError> {
+    let decompress_page = |(page_compressed, page_decoded): (&[u8], &mut [u8])| {
+        let decompressor = GDeflateDecompressor::new()?;
         let in_page = gdeflate_sys::libdeflate_gdeflate_in_page {
-            data: page_data.as_ptr().cast(),
-            nbytes: page_data.len(),
+            data: page_compressed.as_ptr().cast(),
+            nbytes: page_compressed.len(),
         };
+        decompressor.decompress_page(in_page, page_decoded)
+    };
+    rayon_iter_concurrent_limit::iter_concurrent_limit!(
+        options.concurrent_target(),
+        pages,
+        try_for_each,
+        decompress_page
+    )
+}
 
-        // Decompress the page
-        let data_out = decoded_value.spare_capacity_mut();
-        let page_size_uncompressed =
-            decompressor.decompress_page(in_page, data_out.as_mut_ptr().cast(), data_out.len())?;
+fn gdeflate_decode(
+    encoded_value: &RawBytes<'_>,
+    tile_size: usize,
+    options: &CodecOptions,
+) -> Result<Vec<u8>, CodecError> {
+    let (decoded_value_len, num_pages) = gdeflate_decode_static_header(encoded_value, tile_size)?;
 
-        unsafe {
-            decoded_value.set_len(decoded_value.len() + page_size_uncompressed);
-        }
+    // Decode the dynamic header
+    let header_length = GDEFLATE_STATIC_HEADER_LENGTH + num_pages * size_of::<u64>();
+    if encoded_value.len() < header_length {
+        return Err(CodecError::UnexpectedChunkDecodedSize(
+            encoded_value.len(),
+            header_length as u64,
+        ));
+    }
+    let page_sizes_compressed = gdeflate_decode_dynamic_header(
+        &encoded_value[GDEFLATE_STATIC_HEADER_LENGTH..header_length],
+    );
+    let encoded_value_len = header_length + page_sizes_compressed.iter().sum::<usize>();
+    if encoded_value.len() != encoded_value_len {
+        return Err(CodecError::UnexpectedChunkDecodedSize(
+            encoded_value.len(),
+            encoded_value_len as u64,
+        ));
+    }
+
+    // Decode the pages
+    let mut decoded_value = vec![0; decoded_value_len];
+    let mut pages_compressed = Vec::with_capacity(num_pages);
+    let mut page_offset = header_length;
+    for page_size_compressed in page_sizes_compressed {
+        pages_compressed.push(&encoded_value[page_offset..page_offset + page_size_compressed]);
         page_offset += page_size_compressed;
     }
+    gdeflate_decompress_pages(
+        std::iter::zip(pages_compressed, decoded_value.chunks_mut(tile_size)).collect(),
+        options,
+    )?;
 
     Ok(decoded_value)
 }
@@ -141,26 +188,27 @@ impl GDeflateCompressor {
         (out_npages, compress_bound)
     }
 
+    /// Return the upper bound of the compressed size of `input_length` bytes split into pages of `tile_size` bytes.
+    fn compress_bound(&self, input_length: usize, tile_size: usize) -> usize {
+        let (_, compress_bound_page) = self.get_npages_compress_bound(tile_size);
+        input_length.div_ceil(tile_size) * compress_bound_page
+    }
+
     pub(crate) fn compress(
         &self,
         uncompressed_bytes: &[u8],
+        tile_size: usize,
     ) -> Result<(Vec<usize>, Vec<u8>), CodecError> {
-        let (out_npages, compress_bound) = self.get_npages_compress_bound(uncompressed_bytes.len());
-        // let compress_bound_page = compress_bound / out_npages;
-
-        let mut compressed_bytes = Vec::with_capacity(compress_bound);
-        let mut page_sizes = Vec::with_capacity(out_npages);
-        for i in 0..out_npages {
-            let page_offset = i * GDEFLATE_PAGE_SIZE_UNCOMPRESSED;
-
-            let data_out = compressed_bytes.spare_capacity_mut();
+        let (_, compress_bound_page) = self.get_npages_compress_bound(tile_size);
+        let mut page_compressed = Vec::<u8>::with_capacity(compress_bound_page);
+        let mut compressed_bytes = Vec::new();
+        let mut page_sizes = Vec::with_capacity(uncompressed_bytes.len().div_ceil(tile_size));
+        for data_in in uncompressed_bytes.chunks(tile_size) {
+            let data_out = page_compressed.spare_capacity_mut();
             let mut out_page = gdeflate_sys::libdeflate_gdeflate_out_page {
                 data: data_out.as_mut_ptr().cast(),
                 nbytes: data_out.len(),
             };
-
-            let data_in = &uncompressed_bytes[page_offset
-                ..(page_offset + GDEFLATE_PAGE_SIZE_UNCOMPRESSED).min(uncompressed_bytes.len())];
             let compressed_size = unsafe {
                 gdeflate_sys::libdeflate_gdeflate_compress(
                     self.0,
@@ -175,8 +223,10 @@ impl GDeflateCompressor {
             }
             page_sizes.push(compressed_size);
             unsafe {
-                compressed_bytes.set_len(compressed_bytes.len() + compressed_size);
+                page_compressed.set_len(compressed_size);
             }
+            compressed_bytes.extend_from_slice(&page_compressed);
+            page_compressed.clear();
         }
 
         Ok((page_sizes, compressed_bytes))
@@ -206,27 +256,30 @@ impl GDeflateDecompressor {
     pub(crate) fn decompress_page(
         &self,
         mut in_page: gdeflate_sys::libdeflate_gdeflate_in_page,
-        out: *mut u8,
-        out_nbytes_avail: usize,
-    ) -> Result<usize, CodecError> {
+        out: &mut [u8],
+    ) -> Result<(), CodecError> {
         let mut actual_out_nbytes: usize = 0;
         let result = unsafe {
             gdeflate_sys::libdeflate_gdeflate_decompress(
                 self.0,
                 &mut in_page,
                 1,
-                out.cast(),
-                out_nbytes_avail,
+                out.as_mut_ptr().cast(),
+                out.len(),
                 &mut actual_out_nbytes,
             )
         };
-        assert_eq!(actual_out_nbytes, out_nbytes_avail);
-        if result == 0 {
-            Ok(actual_out_nbytes)
-        } else {
+        if result != 0 {
             Err(CodecError::Other(
                 "gdeflate page decompression failed".to_string(),
             ))
+        } else if actual_out_nbytes != out.len() {
+            Err(CodecError::UnexpectedChunkDecodedSize(
+                actual_out_nbytes,
+                out.len() as u64,
+            ))
+        } else {
+            Ok(())
         }
     }
 }
@@ -243,7 +296,7 @@ mod tests {
 
     use crate::{
         array::{
-            codec::{BytesToBytesCodecTraits, CodecOptions},
+            codec::{BytesToBytesCodecTraits, CodecOptions, CodecTraits},
             BytesRepresentation,
         },
         byte_range::ByteRange,
@@ -335,6 +388,121 @@ mod tests {
         assert_eq!(answer, decoded_partial_chunk);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_gdeflate_round_trip_default_tile_size() {
+        // Multiple pages with the default (and previously fixed) tile size of 65536
+        let bytes: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: GDeflateCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
+        let codec = GDeflateCodec::new_with_configuration(&configuration);
+        assert_eq!(codec.tile_size().as_u32(), 65536);
+        assert_eq!(
+            codec
+                .create_metadata()
+                .unwrap()
+                .configuration()
+                .unwrap()
+                .len(),
+            1,
+            "the tile size is omitted from the metadata if unset"
+        );
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(u64::from_le_bytes(encoded[8..16].try_into().unwrap()), 4);
+
+        // Data written without a tile size decodes with an explicit default tile size
+        let codec = GDeflateCodec::new(1)
+            .unwrap()
+            .with_tile_size(GDeflateTileSize::new(65536).unwrap());
+        for concurrent_target in [1, 4] {
+            let options = CodecOptions::builder()
+                .concurrent_target(concurrent_target)
+                .build();
+            let decoded = codec
+                .decode(encoded.clone(), &bytes_representation, &options)
+                .unwrap();
+            assert_eq!(bytes, decoded.to_vec());
+        }
+
+        // Data cannot be decoded with a mismatched tile size
+        let codec = GDeflateCodec::new(1)
+            .unwrap()
+            .with_tile_size(GDeflateTileSize::new(4096).unwrap());
+        assert!(codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .is_err());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_gdeflate_partial_decode_tile_boundaries() {
+        let bytes: Vec<u8> = (0..100).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let configuration: GDeflateCodecConfiguration =
+            serde_json::from_str(r#"{"level": 5, "tile_size": 16}"#).unwrap();
+        let codec = Arc::new(GDeflateCodec::new_with_configuration(&configuration));
+        assert_eq!(
+            codec
+                .create_metadata()
+                .unwrap()
+                .to_configuration::<GDeflateCodecConfiguration>()
+                .unwrap(),
+            configuration
+        );
+        let encoded = codec
+            .encode(Cow::Owned(bytes.clone()), &CodecOptions::default())
+            .unwrap();
+        assert!(
+            Some(encoded.len() as u64) <= codec.compute_encoded_size(&bytes_representation).size()
+        );
+        assert_eq!(
+            codec
+                .decode(
+                    encoded.clone(),
+                    &bytes_representation,
+                    &CodecOptions::default()
+                )
+                .unwrap(),
+            bytes
+        );
+
+        let decoded_regions = [
+            ByteRange::FromStart(15, Some(2)),  // spans a tile boundary
+            ByteRange::FromStart(16, Some(16)), // exactly one tile
+            ByteRange::FromStart(40, Some(0)),  // empty
+            ByteRange::FromStart(10, Some(60)), // spans several tiles
+            ByteRange::FromStart(96, None),     // the last (partial) tile
+            ByteRange::Suffix(5),
+            ByteRange::FromStart(0, None),
+        ];
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            decoded,
+            crate::byte_range::extract_byte_ranges(&bytes, &decoded_regions).unwrap()
+        );
+        assert!(partial_decoder
+            .partial_decode(
+                &[ByteRange::FromStart(90, Some(11))],
+                &CodecOptions::default()
+            )
+            .is_err());
+    }
+
     #[cfg(feature = "async")]
     #[tokio::test]
     #[cfg_attr(miri, ignore)]
@@ -17,13 +17,14 @@ use crate::array::codec::AsyncBytesPartialDecoderTraits;
 use super::{
     gdeflate_decode, gdeflate_partial_decoder, GDeflateCodecConfiguration,
     GDeflateCodecConfigurationV1, GDeflateCompressionLevel, GDeflateCompressionLevelError,
-    GDeflateCompressor, GDEFLATE_STATIC_HEADER_LENGTH, IDENTIFIER,
+    GDeflateCompressor, GDeflateTileSize, GDEFLATE_STATIC_HEADER_LENGTH, IDENTIFIER,
 };
 
 /// A `gdeflate` codec implementation.
 #[derive(Clone, Debug)]
 pub struct GDeflateCodec {
     compression_level: GDeflateCompressionLevel,
+    tile_size: Option<GDeflateTileSize>,
 }
 
 impl GDeflateCodec {
@@ -34,15 +35,39 @@ impl GDeflateCodec {
     pub fn new(compression_level: u32) -> Result<Self, GDeflateCompressionLevelError> {
         let compression_level: GDeflateCompressionLevel = compression_level.try_into()?;
         // let compression_level = compression_level.into();
-        Ok(Self { compression_level })
+        Ok(Self {
+            compression_level,
+            tile_size: None,
+        })
     }
 
     /// Create a new `gdeflate` codec from configuration.
     #[must_use]
     pub fn new_with_configuration(configuration: &GDeflateCodecConfiguration) -> Self {
         let GDeflateCodecConfiguration::V1(configuration) = configuration;
-        let compression_level = configuration.level;
-        Self { compression_level }
+        Self {
+            compression_level: configuration.level,
+            tile_size: configuration.tile_size,
+        }
+    }
+
+    /// Set the uncompressed size of each compressed page (tile).
+    ///
+    /// If unset, the tile size is [`GDeflateTileSize::MAX`] and is omitted from the codec metadata.
+    #[must_use]
+    pub fn with_tile_size(mut self, tile_size: GDeflateTileSize) -> Self {
+        self.tile_size = Some(tile_size);
+        self
+    }
+
+    /// Return the uncompressed size of each compressed page (tile).
+    #[must_use]
+    pub fn tile_size(&self) -> GDeflateTileSize {
+        self.tile_size.unwrap_or_default()
+    }
+
+    fn tile_size_usize(&self) -> usize {
+        usize::try_from(self.tile_size().as_u32()).unwrap()
     }
 }
 
@@ -50,6 +75,7 @@ impl CodecTraits for GDeflateCodec {
     fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
         let configuration = GDeflateCodecConfigurationV1 {
             level: self.compression_level,
+            tile_size: self.tile_size,
         };
         Some(MetadataV3::new_with_serializable_configuration(IDENTIFIER, &configuration).unwrap())
     }
@@ -59,7 +85,7 @@ impl CodecTraits for GDeflateCodec {
     }
 
     fn partial_decoder_decodes_all(&self) -> bool {
-        true
+        false
     }
 }
 
@@ -71,9 +97,15 @@ impl BytesToBytesCodecTraits for GDeflateCodec {
 
     fn recommended_concurrency(
         &self,
-        _decoded_representation: &BytesRepresentation,
+        decoded_representation: &BytesRepresentation,
     ) -> Result<RecommendedConcurrency, CodecError> {
-        Ok(RecommendedConcurrency::new_maximum(1))
+        // Pages are decompressed in parallel
+        let num_pages = decoded_representation.size().map_or(1, |size| {
+            usize::try_from(size)
+                .unwrap()
+                .div_ceil(self.tile_size_usize())
+        });
+        Ok(RecommendedConcurrency::new_maximum(num_pages.max(1)))
     }
 
     fn encode<'a>(
@@ -84,7 +116,7 @@ impl BytesToBytesCodecTraits for GDeflateCodec {
         let compressor = GDeflateCompressor::new(self.compression_level)
             .map_err(|err| CodecError::Other(err.to_string()))?;
         let (page_sizes, encoded_bytes) = compressor
-            .compress(&decoded_value)
+            .compress(&decoded_value, self.tile_size_usize())
             .map_err(|err| CodecError::Other(err.to_string()))?;
         let mut encoded_value = Vec::with_capacity(
             GDEFLATE_STATIC_HEADER_LENGTH
@@ -112,9 +144,13 @@ impl BytesToBytesCodecTraits for GDeflateCodec {
         &self,
         encoded_value: RawBytes<'a>,
         _decoded_representation: &BytesRepresentation,
-        _options: &CodecOptions,
+        options: &CodecOptions,
     ) -> Result<RawBytes<'a>, CodecError> {
-        Ok(Cow::Owned(gdeflate_decode(&encoded_value)?))
+        Ok(Cow::Owned(gdeflate_decode(
+            &encoded_value,
+            self.tile_size_usize(),
+            options,
+        )?))
     }
 
     fn partial_decoder(
@@ -124,7 +160,7 @@ impl BytesToBytesCodecTraits for GDeflateCodec {
         _options: &CodecOptions,
     ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
         Ok(Arc::new(
-            gdeflate_partial_decoder::GDeflatePartialDecoder::new(r),
+            gdeflate_partial_decoder::GDeflatePartialDecoder::new(r, self.tile_size_usize()),
         ))
     }
 
@@ -151,7 +187,7 @@ impl BytesToBytesCodecTraits for GDeflateCodec {
         _options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
         Ok(Arc::new(
-            gdeflate_partial_decoder::AsyncGDeflatePartialDecoder::new(r),
+            gdeflate_partial_decoder::AsyncGDeflatePartialDecoder::new(r, self.tile_size_usize()),
         ))
     }
 
@@ -163,8 +199,11 @@ impl BytesToBytesCodecTraits for GDeflateCodec {
             BytesRepresentation::BoundedSize(size) | BytesRepresentation::FixedSize(size) => {
                 let compressor = GDeflateCompressor::new(self.compression_level).unwrap(); // FIXME: Make compute_encoded_size fallible?
                 let size = usize::try_from(*size).unwrap();
-                let (_, compress_bound) = compressor.get_npages_compress_bound(size);
-                let compress_bound = u64::try_from(compress_bound).unwrap();
+                let tile_size = self.tile_size_usize();
+                let header_length =
+                    GDEFLATE_STATIC_HEADER_LENGTH + size.div_ceil(tile_size) * size_of::<u64>();
+                let compress_bound = compressor.compress_bound(size, tile_size);
+                let compress_bound = u64::try_from(header_length + compress_bound).unwrap();
                 BytesRepresentation::BoundedSize(compress_bound)
             }
             BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
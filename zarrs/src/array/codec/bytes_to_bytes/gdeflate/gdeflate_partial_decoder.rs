@@ -1,27 +1,145 @@
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::BTreeSet, sync::Arc};
 
 use crate::{
     array::{
         codec::{BytesPartialDecoderTraits, CodecError, CodecOptions},
         RawBytes,
     },
-    byte_range::{extract_byte_ranges, ByteRange},
+    byte_range::{validate_byte_ranges, ByteRange},
 };
 
 #[cfg(feature = "async")]
 use crate::array::codec::AsyncBytesPartialDecoderTraits;
 
-use super::gdeflate_decode;
+use super::{
+    gdeflate_decode_dynamic_header, gdeflate_decode_static_header, gdeflate_decompress_pages,
+    GDEFLATE_STATIC_HEADER_LENGTH,
+};
+
+/// The byte range of the static header of `gdeflate` encoded data.
+const STATIC_HEADER_BYTE_RANGE: ByteRange =
+    ByteRange::FromStart(0, Some(GDEFLATE_STATIC_HEADER_LENGTH as u64));
+
+/// Return the byte range of the dynamic header of `gdeflate` encoded data with `num_pages` pages.
+fn dynamic_header_byte_range(num_pages: usize) -> ByteRange {
+    ByteRange::FromStart(
+        GDEFLATE_STATIC_HEADER_LENGTH as u64,
+        Some((num_pages * size_of::<u64>()) as u64),
+    )
+}
+
+/// The pages of `gdeflate` encoded data intersecting the decoded regions of a partial decode.
+struct GDeflatePartialPages {
+    decoded_value_len: usize,
+    tile_size: usize,
+    /// The sorted indices of the pages intersecting the decoded regions.
+    pages: Vec<usize>,
+    /// The encoded byte ranges of `pages`.
+    byte_ranges: Vec<ByteRange>,
+}
+
+impl GDeflatePartialPages {
+    fn new(
+        decoded_value_len: usize,
+        tile_size: usize,
+        page_sizes_compressed: &[usize],
+        decoded_regions: &[ByteRange],
+    ) -> Result<Self, CodecError> {
+        validate_byte_ranges(decoded_regions, decoded_value_len as u64)?;
+
+        let mut pages = BTreeSet::new();
+        for decoded_region in decoded_regions {
+            let range = decoded_region.to_range_usize(decoded_value_len as u64);
+            if !range.is_empty() {
+                pages.extend(range.start / tile_size..=(range.end - 1) / tile_size);
+            }
+        }
+        let pages: Vec<usize> = pages.into_iter().collect();
+
+        let mut page_offsets = Vec::with_capacity(page_sizes_compressed.len());
+        let mut page_offset =
+            GDEFLATE_STATIC_HEADER_LENGTH + page_sizes_compressed.len() * size_of::<u64>();
+        for page_size_compressed in page_sizes_compressed {
+            page_offsets.push(page_offset);
+            page_offset += page_size_compressed;
+        }
+        let byte_ranges = pages
+            .iter()
+            .map(|&page| {
+                ByteRange::FromStart(
+                    page_offsets[page] as u64,
+                    Some(page_sizes_compressed[page] as u64),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            decoded_value_len,
+            tile_size,
+            pages,
+            byte_ranges,
+        })
+    }
+
+    /// Decompress the `pages_compressed` and extract the `decoded_regions`.
+    fn decode(
+        &self,
+        pages_compressed: &[RawBytes<'_>],
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Vec<RawBytes<'static>>, CodecError> {
+        let mut pages_decoded: Vec<Vec<u8>> = self
+            .pages
+            .iter()
+            .map(|page| {
+                let page_start = page * self.tile_size;
+                vec![0; self.tile_size.min(self.decoded_value_len - page_start)]
+            })
+            .collect();
+        gdeflate_decompress_pages(
+            std::iter::zip(
+                pages_compressed.iter().map(AsRef::as_ref),
+                pages_decoded.iter_mut().map(Vec::as_mut_slice),
+            )
+            .collect(),
+            options,
+        )?;
+
+        let mut decoded_values = Vec::with_capacity(decoded_regions.len());
+        for decoded_region in decoded_regions {
+            let range = decoded_region.to_range_usize(self.decoded_value_len as u64);
+            let mut decoded_value = Vec::with_capacity(range.len());
+            let mut position = range.start;
+            while position < range.end {
+                let page = position / self.tile_size;
+                let page_bytes = &pages_decoded[self.pages.binary_search(&page).unwrap()];
+                let page_start = page * self.tile_size;
+                let end = range.end.min(page_start + page_bytes.len());
+                decoded_value
+                    .extend_from_slice(&page_bytes[position - page_start..end - page_start]);
+                position = end;
+            }
+            decoded_values.push(Cow::Owned(decoded_value));
+        }
+        Ok(decoded_values)
+    }
+}
 
 /// Partial decoder for the `gdeflate` codec.
+///
+/// Only the pages intersecting the decoded regions are retrieved and decompressed.
 pub(crate) struct GDeflatePartialDecoder {
     input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    tile_size: usize,
 }
 
 impl GDeflatePartialDecoder {
     /// Create a new partial decoder for the `gdeflate` codec.
-    pub(crate) fn new(input_handle: Arc<dyn BytesPartialDecoderTraits>) -> Self {
-        Self { input_handle }
+    pub(crate) fn new(input_handle: Arc<dyn BytesPartialDecoderTraits>, tile_size: usize) -> Self {
+        Self {
+            input_handle,
+            tile_size,
+        }
     }
 }
 
@@ -31,34 +149,63 @@ impl BytesPartialDecoderTraits for GDeflatePartialDecoder {
         decoded_regions: &[ByteRange],
         options: &CodecOptions,
     ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
-        let encoded_value = self.input_handle.decode(options)?;
-        let Some(encoded_value) = encoded_value else {
+        let Some(static_header) = self
+            .input_handle
+            .partial_decode(&[STATIC_HEADER_BYTE_RANGE], options)?
+        else {
             return Ok(None);
         };
+        let (decoded_value_len, num_pages) =
+            gdeflate_decode_static_header(&static_header[0], self.tile_size)?;
 
-        let decoded_value = gdeflate_decode(&encoded_value)?;
+        let Some(dynamic_header) = self
+            .input_handle
+            .partial_decode(&[dynamic_header_byte_range(num_pages)], options)?
+        else {
+            return Ok(None);
+        };
+        let page_sizes_compressed = gdeflate_decode_dynamic_header(&dynamic_header[0]);
 
-        Ok(Some(
-            extract_byte_ranges(&decoded_value, decoded_regions)
-                .map_err(CodecError::InvalidByteRangeError)?
-                .into_iter()
-                .map(Cow::Owned)
-                .collect(),
-        ))
+        let pages = GDeflatePartialPages::new(
+            decoded_value_len,
+            self.tile_size,
+            &page_sizes_compressed,
+            decoded_regions,
+        )?;
+        let Some(pages_compressed) = self
+            .input_handle
+            .partial_decode(&pages.byte_ranges, options)?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(pages.decode(
+            &pages_compressed,
+            decoded_regions,
+            options,
+        )?))
     }
 }
 
 #[cfg(feature = "async")]
 /// Asynchronous partial decoder for the `gdeflate` codec.
+///
+/// Only the pages intersecting the decoded regions are retrieved and decompressed.
 pub(crate) struct AsyncGDeflatePartialDecoder {
     input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    tile_size: usize,
 }
 
 #[cfg(feature = "async")]
 impl AsyncGDeflatePartialDecoder {
     /// Create a new partial decoder for the `gdeflate` codec.
-    pub(crate) fn new(input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>) -> Self {
-        Self { input_handle }
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        tile_size: usize,
+    ) -> Self {
+        Self {
+            input_handle,
+            tile_size,
+        }
     }
 }
 
@@ -70,19 +217,42 @@ impl AsyncBytesPartialDecoderTraits for AsyncGDeflatePartialDecoder {
         decoded_regions: &[ByteRange],
         options: &CodecOptions,
     ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
-        let encoded_value = self.input_handle.decode(options).await?;
-        let Some(encoded_value) = encoded_value else {
+        let Some(static_header) = self
+            .input_handle
+            .partial_decode(&[STATIC_HEADER_BYTE_RANGE], options)
+            .await?
+        else {
             return Ok(None);
         };
+        let (decoded_value_len, num_pages) =
+            gdeflate_decode_static_header(&static_header[0], self.tile_size)?;
 
-        let decoded_value = gdeflate_decode(&encoded_value)?;
+        let Some(dynamic_header) = self
+            .input_handle
+            .partial_decode(&[dynamic_header_byte_range(num_pages)], options)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let page_sizes_compressed = gdeflate_decode_dynamic_header(&dynamic_header[0]);
 
-        Ok(Some(
-            extract_byte_ranges(&decoded_value, decoded_regions)
-                .map_err(CodecError::InvalidByteRangeError)?
-                .into_iter()
-                .map(Cow::Owned)
-                .collect(),
-        ))
+        let pages = GDeflatePartialPages::new(
+            decoded_value_len,
+            self.tile_size,
+            &page_sizes_compressed,
+            decoded_regions,
+        )?;
+        let Some(pages_compressed) = self
+            .input_handle
+            .partial_decode(&pages.byte_ranges, options)
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(pages.decode(
+            &pages_compressed,
+            decoded_regions,
+            options,
+        )?))
     }
 }
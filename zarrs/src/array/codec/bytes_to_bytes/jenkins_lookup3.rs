@@ -0,0 +1,213 @@
+//! The `jenkins_lookup3` bytes to bytes codec.
+//!
+//! Appends a Bob Jenkins' `lookup3` checksum of the input bytestream.
+//!
+//! This is based on the `numcodecs` implementation.
+//! See <https://numcodecs.readthedocs.io/en/latest/checksum32.html#jenkinslookup3>.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `jenkins_lookup3` feature, which is disabled by default.
+//!
+//! See [`JenkinsLookup3CodecConfigurationV1`] for example `JSON` metadata.
+
+mod jenkins_lookup3_codec;
+mod lookup3;
+
+use std::sync::Arc;
+
+pub use crate::metadata::v3::array::codec::jenkins_lookup3::{
+    JenkinsLookup3CodecConfiguration, JenkinsLookup3CodecConfigurationV1,
+};
+pub use jenkins_lookup3_codec::JenkinsLookup3Codec;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::jenkins_lookup3, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use jenkins_lookup3::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_jenkins_lookup3, create_codec_jenkins_lookup3)
+}
+
+fn is_name_jenkins_lookup3(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_jenkins_lookup3(
+    metadata: &MetadataV3,
+) -> Result<Codec, PluginCreateError> {
+    let configuration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(JenkinsLookup3Codec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+const CHECKSUM_SIZE: usize = size_of::<u32>();
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits},
+            BytesRepresentation,
+        },
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    #[test]
+    fn codec_jenkins_lookup3_configuration() {
+        let codec_configuration: JenkinsLookup3CodecConfiguration =
+            serde_json::from_str(r#"{"initval":1,"prefix":[1,2]}"#).unwrap();
+        let codec = JenkinsLookup3Codec::new_with_configuration(&codec_configuration);
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(
+            serde_json::to_string(&metadata).unwrap(),
+            r#"{"name":"numcodecs.jenkins_lookup3","configuration":{"initval":1,"prefix":[1,2]}}"#
+        );
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3() {
+        let bytes = b"Four score and seven years ago".to_vec();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = JenkinsLookup3Codec::new(1, None);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(&encoded[..bytes.len()], &bytes);
+        assert_eq!(&encoded[bytes.len()..], &0xcd62_8161_u32.to_le_bytes());
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_prefix() {
+        let bytes = b"seven years ago".to_vec();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = JenkinsLookup3Codec::new(1, Some(b"Four score and ".to_vec()));
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        // The prefix is hashed but not stored
+        assert_eq!(&encoded[..bytes.len()], &bytes);
+        assert_eq!(&encoded[bytes.len()..], &0xcd62_8161_u32.to_le_bytes());
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_invalid_checksum() {
+        let bytes: Vec<u8> = (0..6).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = JenkinsLookup3Codec::new(0, None);
+
+        let mut encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap()
+            .to_vec();
+        encoded[0] ^= 1;
+        assert!(matches!(
+            codec.decode(
+                Cow::Borrowed(&encoded),
+                &bytes_representation,
+                &CodecOptions::default()
+            ),
+            Err(CodecError::InvalidChecksum)
+        ));
+        let options = CodecOptions::builder().validate_checksums(false).build();
+        assert!(codec
+            .decode(Cow::Borrowed(&encoded), &bytes_representation, &options)
+            .is_ok());
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_partial_decode() {
+        let bytes: Vec<u8> = (0..32).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Arc::new(JenkinsLookup3Codec::new(0, None));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [ByteRange::FromStart(3, Some(2)), ByteRange::Suffix(2)];
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+        let answer: &[Vec<u8>] = &[vec![3, 4], vec![30, 31]];
+        assert_eq!(
+            answer,
+            decoded_partial_chunk
+                .into_iter()
+                .map(|v| v.to_vec())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn codec_jenkins_lookup3_async_partial_decode() {
+        let bytes: Vec<u8> = (0..32).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+        let codec = Arc::new(JenkinsLookup3Codec::new(0, None));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions = [ByteRange::FromStart(3, Some(2)), ByteRange::Suffix(2)];
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+        let answer: &[Vec<u8>] = &[vec![3, 4], vec![30, 31]];
+        assert_eq!(
+            answer,
+            decoded_partial_chunk
+                .into_iter()
+                .map(|v| v.to_vec())
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::{borrow::Cow, sync::Arc};
+
+use crate::{
+    array::{
+        codec::{
+            bytes_to_bytes::{
+                checksum_partial_decoder::ChecksumPartialDecoder,
+                strip_suffix_partial_decoder::StripSuffixPartialDecoder,
+            },
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, Checksums, CodecError, CodecOptions, CodecTraits,
+            RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+#[cfg(feature = "async")]
+use crate::array::codec::bytes_to_bytes::{
+    checksum_partial_decoder::AsyncChecksumPartialDecoder,
+    strip_suffix_partial_decoder::AsyncStripSuffixPartialDecoder,
+};
+
+use super::{
+    lookup3::hashlittle, JenkinsLookup3CodecConfiguration, JenkinsLookup3CodecConfigurationV1,
+    CHECKSUM_SIZE, IDENTIFIER,
+};
+
+/// A `jenkins_lookup3` (Bob Jenkins' `lookup3` checksum) codec implementation.
+#[derive(Clone, Debug, Default)]
+pub struct JenkinsLookup3Codec {
+    initval: u32,
+    prefix: Option<Vec<u8>>,
+}
+
+impl JenkinsLookup3Codec {
+    /// Create a new `jenkins_lookup3` codec.
+    ///
+    /// If `prefix` is set, it is prepended to the input when computing the checksum but is not stored.
+    #[must_use]
+    pub const fn new(initval: u32, prefix: Option<Vec<u8>>) -> Self {
+        Self { initval, prefix }
+    }
+
+    /// Create a new `jenkins_lookup3` codec from configuration.
+    #[must_use]
+    pub fn new_with_configuration(configuration: &JenkinsLookup3CodecConfiguration) -> Self {
+        let JenkinsLookup3CodecConfiguration::V1(configuration) = configuration;
+        Self::new(configuration.initval, configuration.prefix.clone())
+    }
+
+    fn checksum(&self, bytes: &[u8]) -> [u8; CHECKSUM_SIZE] {
+        let prefix = self.prefix.as_deref().unwrap_or_default();
+        hashlittle(&[prefix, bytes], self.initval).to_le_bytes()
+    }
+}
+
+impl CodecTraits for JenkinsLookup3Codec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = JenkinsLookup3CodecConfigurationV1 {
+            initval: self.initval,
+            prefix: self.prefix.clone(),
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .expect("jenkins_lookup3 configuration is valid json"),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        false
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for JenkinsLookup3Codec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let checksum = self.checksum(&decoded_value);
+        let mut encoded_value: Vec<u8> = Vec::with_capacity(decoded_value.len() + checksum.len());
+        encoded_value.extend_from_slice(&decoded_value);
+        encoded_value.extend_from_slice(&checksum);
+        Ok(Cow::Owned(encoded_value))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        if encoded_value.len() < CHECKSUM_SIZE {
+            return Err(CodecError::Other(
+                "jenkins_lookup3 decoder expects a 32 bit input".to_string(),
+            ));
+        }
+        let (decoded_value, checksum) = encoded_value.split_at(encoded_value.len() - CHECKSUM_SIZE);
+        if options.validate_checksums() && self.checksum(decoded_value) != checksum {
+            return Err(CodecError::InvalidChecksum);
+        }
+        Ok(Cow::Owned(decoded_value.to_vec()))
+    }
+
+    fn checksums(&self, encoded_value: &[u8]) -> Result<Option<Checksums>, CodecError> {
+        if encoded_value.len() < CHECKSUM_SIZE {
+            return Err(CodecError::Other(
+                "jenkins_lookup3 decoder expects a 32 bit input".to_string(),
+            ));
+        }
+        let (decoded_value, checksum) = encoded_value.split_at(encoded_value.len() - CHECKSUM_SIZE);
+        Ok(Some((
+            checksum.to_vec(),
+            self.checksum(decoded_value).to_vec(),
+        )))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(ChecksumPartialDecoder::new(
+            input_handle.clone(),
+            Arc::new(StripSuffixPartialDecoder::new(input_handle, CHECKSUM_SIZE)),
+            self,
+            *decoded_representation,
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(AsyncChecksumPartialDecoder::new(
+            input_handle.clone(),
+            Arc::new(AsyncStripSuffixPartialDecoder::new(
+                input_handle,
+                CHECKSUM_SIZE,
+            )),
+            self,
+            *decoded_representation,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        match decoded_representation {
+            BytesRepresentation::FixedSize(size) => {
+                BytesRepresentation::FixedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::BoundedSize(size) => {
+                BytesRepresentation::BoundedSize(size + CHECKSUM_SIZE as u64)
+            }
+            BytesRepresentation::UnboundedSize => BytesRepresentation::UnboundedSize,
+        }
+    }
+}
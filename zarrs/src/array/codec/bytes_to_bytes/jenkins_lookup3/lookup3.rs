@@ -0,0 +1,116 @@
+//! Bob Jenkins' `lookup3` `hashlittle` hash function.
+//!
+//! Based on <https://www.burtleburtle.net/bob/c/lookup3.c>.
+
+#[inline]
+fn mix(a: &mut u32, b: &mut u32, c: &mut u32) {
+    *a = a.wrapping_sub(*c);
+    *a ^= c.rotate_left(4);
+    *c = c.wrapping_add(*b);
+    *b = b.wrapping_sub(*a);
+    *b ^= a.rotate_left(6);
+    *a = a.wrapping_add(*c);
+    *c = c.wrapping_sub(*b);
+    *c ^= b.rotate_left(8);
+    *b = b.wrapping_add(*a);
+    *a = a.wrapping_sub(*c);
+    *a ^= c.rotate_left(16);
+    *c = c.wrapping_add(*b);
+    *b = b.wrapping_sub(*a);
+    *b ^= a.rotate_left(19);
+    *a = a.wrapping_add(*c);
+    *c = c.wrapping_sub(*b);
+    *c ^= b.rotate_left(4);
+    *b = b.wrapping_add(*a);
+}
+
+#[inline]
+fn final_mix(a: &mut u32, b: &mut u32, c: &mut u32) {
+    *c ^= *b;
+    *c = c.wrapping_sub(b.rotate_left(14));
+    *a ^= *c;
+    *a = a.wrapping_sub(c.rotate_left(11));
+    *b ^= *a;
+    *b = b.wrapping_sub(a.rotate_left(25));
+    *c ^= *b;
+    *c = c.wrapping_sub(b.rotate_left(16));
+    *a ^= *c;
+    *a = a.wrapping_sub(c.rotate_left(4));
+    *b ^= *a;
+    *b = b.wrapping_sub(a.rotate_left(14));
+    *c ^= *b;
+    *c = c.wrapping_sub(b.rotate_left(24));
+}
+
+/// Read up to 4 bytes as a little-endian `u32`, zero padding missing bytes.
+#[inline]
+fn read_le(bytes: &[u8]) -> u32 {
+    let mut word = [0u8; 4];
+    word[..bytes.len()].copy_from_slice(bytes);
+    u32::from_le_bytes(word)
+}
+
+/// Hash `data` with the `lookup3` `hashlittle` function.
+///
+/// The hash of each `data` slice is computed as if they were concatenated.
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn hashlittle(data: &[&[u8]], initval: u32) -> u32 {
+    let length: usize = data.iter().map(|data| data.len()).sum();
+    let mut bytes = data.iter().flat_map(|data| data.iter().copied());
+
+    // The length is truncated to 32 bits, as in the reference implementation
+    let init = 0xdead_beef_u32
+        .wrapping_add(length as u32)
+        .wrapping_add(initval);
+    let (mut a, mut b, mut c) = (init, init, init);
+
+    let mut block = [0u8; 12];
+    let mut remaining = length;
+    while remaining > 12 {
+        for byte in &mut block {
+            *byte = bytes.next().unwrap();
+        }
+        a = a.wrapping_add(read_le(&block[0..4]));
+        b = b.wrapping_add(read_le(&block[4..8]));
+        c = c.wrapping_add(read_le(&block[8..12]));
+        mix(&mut a, &mut b, &mut c);
+        remaining -= 12;
+    }
+
+    // The last block is between 0 and 12 bytes
+    if remaining == 0 {
+        return c;
+    }
+    let block = &mut block[..remaining];
+    for byte in block.iter_mut() {
+        *byte = bytes.next().unwrap();
+    }
+    a = a.wrapping_add(read_le(&block[..remaining.min(4)]));
+    if remaining > 4 {
+        b = b.wrapping_add(read_le(&block[4..remaining.min(8)]));
+    }
+    if remaining > 8 {
+        c = c.wrapping_add(read_le(&block[8..]));
+    }
+    final_mix(&mut a, &mut b, &mut c);
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup3_hashlittle() {
+        // Test vectors from lookup3.c
+        assert_eq!(hashlittle(&[b""], 0), 0xdead_beef);
+        assert_eq!(hashlittle(&[b""], 0xdead_beef), 0xbd5b_7dde);
+        let four_score = b"Four score and seven years ago";
+        assert_eq!(hashlittle(&[four_score], 0), 0x1777_0551);
+        assert_eq!(hashlittle(&[four_score], 1), 0xcd62_8161);
+        assert_eq!(
+            hashlittle(&[&four_score[..7], &four_score[7..]], 1),
+            0xcd62_8161
+        );
+    }
+}
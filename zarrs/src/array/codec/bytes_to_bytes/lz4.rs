@@ -0,0 +1,250 @@
+//! The `lz4` bytes to bytes codec.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `lz4` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `lz4` codec (`numcodecs.lz4` in Zarr V3 and `lz4` in Zarr V2).
+//! Encoded data is an LZ4 block prefixed with the decoded size as a little-endian `u32`.
+//!
+//! See [`Lz4CodecConfigurationV1`] for example `JSON` metadata.
+
+mod lz4_codec;
+mod lz4_partial_decoder;
+
+use std::sync::Arc;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::lz4, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use crate::metadata::v3::array::codec::lz4::{Lz4CodecConfiguration, Lz4CodecConfigurationV1};
+
+pub use self::lz4_codec::Lz4Codec;
+
+pub use lz4::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_lz4, create_codec_lz4)
+}
+
+fn is_name_lz4(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_lz4(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: Lz4CodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(Lz4Codec::new_with_configuration(&configuration));
+    Ok(Codec::BytesToBytes(codec))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions, CodecTraits},
+            ArrayRepresentation, BytesRepresentation, DataType, FillValue,
+        },
+        array_subset::ArraySubset,
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON_VALID1: &str = r#"
+{
+    "acceleration": 1
+}"#;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lz4_round_trip() {
+        let elements: Vec<u16> = (0..32).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        for acceleration in [-1, 0, 1, 10, 65537] {
+            let codec = Lz4Codec::new(acceleration);
+            let encoded = codec
+                .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+                .unwrap();
+            assert_eq!(
+                &encoded[..4],
+                u32::try_from(bytes.len()).unwrap().to_le_bytes()
+            );
+            let decoded = codec
+                .decode(encoded, &bytes_representation, &CodecOptions::default())
+                .unwrap();
+            assert_eq!(bytes, decoded.to_vec());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lz4_round_trip_empty() {
+        let codec = Lz4Codec::new(1);
+        let encoded = codec
+            .encode(Cow::Borrowed(&[]), &CodecOptions::default())
+            .unwrap();
+        let decoded = codec
+            .decode(
+                encoded,
+                &BytesRepresentation::FixedSize(0),
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert!(decoded.is_empty());
+    }
+
+    #[test]
+    fn codec_lz4_metadata() {
+        let codec_configuration: Lz4CodecConfiguration = serde_json::from_str(JSON_VALID1).unwrap();
+        let codec = Lz4Codec::new_with_configuration(&codec_configuration);
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(metadata.name(), "numcodecs.lz4");
+        assert_eq!(
+            metadata
+                .to_configuration::<Lz4CodecConfiguration>()
+                .unwrap(),
+            codec_configuration
+        );
+        assert_eq!(
+            Lz4Codec::new(0).create_metadata().unwrap().configuration(),
+            codec.create_metadata().unwrap().configuration()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lz4_decode_numcodecs() {
+        // numcodecs.LZ4(acceleration=1).encode(np.arange(16, dtype="<u2"))
+        let encoded: &[u8] = &[
+            32, 0, 0, 0, 240, 17, 0, 0, 1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0, 7, 0, 8, 0, 9, 0, 10,
+            0, 11, 0, 12, 0, 13, 0, 14, 0, 15, 0,
+        ];
+        let codec = Lz4Codec::new(1);
+        let decoded = codec
+            .decode(
+                Cow::Borrowed(encoded),
+                &BytesRepresentation::FixedSize(32),
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let expected: Vec<u8> = (0..16u16).flat_map(u16::to_le_bytes).collect();
+        assert_eq!(decoded.to_vec(), expected);
+        let reencoded = codec
+            .encode(Cow::Owned(expected), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(reencoded.as_ref(), encoded);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lz4_partial_decode() {
+        let array_representation =
+            ArrayRepresentation::new(vec![2, 2, 2], DataType::UInt16, FillValue::from(0u16))
+                .unwrap();
+        let data_type_size = array_representation.data_type().fixed_size().unwrap();
+        let array_size = array_representation.num_elements_usize() * data_type_size;
+        let bytes_representation = BytesRepresentation::FixedSize(array_size as u64);
+
+        let elements: Vec<u16> =
+            (0..u16::try_from(array_representation.num_elements()).unwrap()).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+
+        let codec_configuration: Lz4CodecConfiguration = serde_json::from_str(JSON_VALID1).unwrap();
+        let codec = Arc::new(Lz4Codec::new_with_configuration(&codec_configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions: Vec<ByteRange> = ArraySubset::new_with_ranges(&[0..2, 1..2, 0..1])
+            .byte_ranges(array_representation.shape(), data_type_size)
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+
+        let decoded: Vec<u16> = decoded
+            .to_vec()
+            .chunks_exact(size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+
+        let answer: Vec<u16> = vec![2, 6];
+        assert_eq!(answer, decoded);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn codec_lz4_async_partial_decode() {
+        let array_representation =
+            ArrayRepresentation::new(vec![2, 2, 2], DataType::UInt16, FillValue::from(0u16))
+                .unwrap();
+        let data_type_size = array_representation.data_type().fixed_size().unwrap();
+        let array_size = array_representation.num_elements_usize() * data_type_size;
+        let bytes_representation = BytesRepresentation::FixedSize(array_size as u64);
+
+        let elements: Vec<u16> =
+            (0..u16::try_from(array_representation.num_elements()).unwrap()).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+
+        let codec_configuration: Lz4CodecConfiguration = serde_json::from_str(JSON_VALID1).unwrap();
+        let codec = Arc::new(Lz4Codec::new_with_configuration(&codec_configuration));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions: Vec<ByteRange> = ArraySubset::new_with_ranges(&[0..2, 1..2, 0..1])
+            .byte_ranges(array_representation.shape(), data_type_size)
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let decoded: Vec<u16> = decoded
+            .to_vec()
+            .chunks_exact(size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+
+        let answer: Vec<u16> = vec![2, 6];
+        assert_eq!(answer, decoded);
+    }
+}
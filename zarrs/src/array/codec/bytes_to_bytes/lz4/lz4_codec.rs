@@ -0,0 +1,172 @@
+use std::{borrow::Cow, sync::Arc};
+
+use lz4::block::CompressionMode;
+
+use crate::{
+    array::{
+        codec::{
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::MetadataV3,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+use super::{lz4_partial_decoder, Lz4CodecConfiguration, Lz4CodecConfigurationV1};
+
+/// The default `lz4` acceleration.
+const LZ4_DEFAULT_ACCELERATION: i32 = 1;
+
+/// Decode `lz4` encoded bytes.
+///
+/// The encoded bytes are an LZ4 block prefixed with the decoded size as a little-endian `u32`, as in `numcodecs`.
+pub(super) fn lz4_decompress(encoded_value: &[u8]) -> Result<Vec<u8>, CodecError> {
+    Ok(lz4::block::decompress(encoded_value, None)?)
+}
+
+/// A `lz4` codec implementation.
+#[derive(Clone, Debug)]
+pub struct Lz4Codec {
+    acceleration: i32,
+}
+
+impl Lz4Codec {
+    /// Create a new `lz4` codec.
+    ///
+    /// Higher `acceleration` values are faster but produce less compression.
+    /// Values less than or equal to 0 are replaced by the default of 1.
+    #[must_use]
+    pub fn new(acceleration: i32) -> Self {
+        let acceleration = if acceleration <= 0 {
+            LZ4_DEFAULT_ACCELERATION
+        } else {
+            acceleration
+        };
+        Self { acceleration }
+    }
+
+    /// Create a new `lz4` codec from configuration.
+    #[must_use]
+    pub fn new_with_configuration(configuration: &Lz4CodecConfiguration) -> Self {
+        let Lz4CodecConfiguration::V1(configuration) = configuration;
+        Self::new(configuration.acceleration)
+    }
+}
+
+impl CodecTraits for Lz4Codec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = Lz4CodecConfigurationV1 {
+            acceleration: self.acceleration,
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(super::IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for Lz4Codec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        // lz4 block compression does not support parallel encode/decode
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let encoded = lz4::block::compress(
+            &decoded_value,
+            Some(CompressionMode::FAST(self.acceleration)),
+            true,
+        )?;
+        Ok(Cow::Owned(encoded))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        Ok(Cow::Owned(lz4_decompress(&encoded_value)?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(lz4_partial_decoder::Lz4PartialDecoder::new(
+            input_handle,
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(lz4_partial_decoder::AsyncLz4PartialDecoder::new(
+            input_handle,
+        )))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        decoded_representation
+            .size()
+            .map_or(BytesRepresentation::UnboundedSize, |size| {
+                // Size prefix (4 bytes) + LZ4_COMPRESSBOUND
+                BytesRepresentation::BoundedSize(4 + size + size / 255 + 16)
+            })
+    }
+}
@@ -0,0 +1,350 @@
+//! The `lzma` bytes to bytes codec.
+//!
+//! <div class="warning">
+//! This codec is experimental and may be incompatible with other Zarr V3 implementations.
+//! </div>
+//!
+//! This codec requires the `lzma` feature, which is disabled by default.
+//!
+//! This codec is compatible with the `numcodecs` `lzma` codec (`numcodecs.lzma` in Zarr V3 and `lzma` in Zarr V2).
+//! The `xz` and `alone` formats are supported, as are custom filter chains with the LZMA1, LZMA2, and branch/call/jump filters.
+//! The `raw` format and the delta filter are not currently supported.
+//!
+//! See [`LzmaCodecConfigurationV1`] for example `JSON` metadata.
+
+mod lzma_codec;
+mod lzma_partial_decoder;
+
+use std::sync::Arc;
+
+use crate::{
+    array::codec::{Codec, CodecPlugin},
+    config::global_config,
+    metadata::v3::{array::codec::lzma, MetadataV3},
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+};
+
+pub use crate::metadata::v3::array::codec::lzma::{
+    LzmaCheck, LzmaCodecConfiguration, LzmaCodecConfigurationV1, LzmaFilter, LzmaFilterId,
+    LzmaFormat, LzmaMatchFinder, LzmaMode, LZMA_PRESET_EXTREME,
+};
+
+pub use self::lzma_codec::LzmaCodec;
+
+pub use lzma::IDENTIFIER;
+
+// Register the codec.
+inventory::submit! {
+    CodecPlugin::new(IDENTIFIER, is_name_lzma, create_codec_lzma)
+}
+
+fn is_name_lzma(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+        || name
+            == global_config()
+                .experimental_codec_names()
+                .get(IDENTIFIER)
+                .expect("experimental codec identifier in global map")
+}
+
+pub(crate) fn create_codec_lzma(metadata: &MetadataV3) -> Result<Codec, PluginCreateError> {
+    let configuration: LzmaCodecConfiguration = metadata
+        .to_configuration()
+        .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
+    let codec = Arc::new(LzmaCodec::new_with_configuration(&configuration)?);
+    Ok(Codec::BytesToBytes(codec))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, sync::Arc};
+
+    use crate::{
+        array::{
+            codec::{BytesToBytesCodecTraits, CodecOptions, CodecTraits},
+            ArrayRepresentation, BytesRepresentation, DataType, FillValue,
+        },
+        array_subset::ArraySubset,
+        byte_range::ByteRange,
+    };
+
+    use super::*;
+
+    const JSON_NUMCODECS_DEFAULT: &str = r#"
+{
+    "format": 1,
+    "check": -1,
+    "preset": null,
+    "filters": null
+}"#;
+
+    fn codec(json: &str) -> LzmaCodec {
+        let codec_configuration: LzmaCodecConfiguration = serde_json::from_str(json).unwrap();
+        LzmaCodec::new_with_configuration(&codec_configuration).unwrap()
+    }
+
+    fn round_trip(codec: &LzmaCodec) {
+        let elements: Vec<u16> = (0..256).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        let encoded = codec
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded = codec
+            .decode(encoded, &bytes_representation, &CodecOptions::default())
+            .unwrap();
+        assert_eq!(bytes, decoded.to_vec());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lzma_round_trip_presets() {
+        for preset in [0, 1, 6, 9, 9 | LZMA_PRESET_EXTREME] {
+            for format in [LzmaFormat::Xz, LzmaFormat::Alone] {
+                round_trip(
+                    &LzmaCodec::new(format, LzmaCheck::Default, Some(preset), None).unwrap(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lzma_round_trip_checks() {
+        for check in [
+            LzmaCheck::None,
+            LzmaCheck::Crc32,
+            LzmaCheck::Crc64,
+            LzmaCheck::Sha256,
+        ] {
+            round_trip(&LzmaCodec::new(LzmaFormat::Xz, check, None, None).unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lzma_round_trip_filters() {
+        let mut lzma1 = LzmaFilter::new(LzmaFilterId::Lzma1);
+        lzma1.preset = Some(2);
+        lzma1.mode = Some(LzmaMode::Normal);
+        lzma1.mf = Some(LzmaMatchFinder::BinaryTree4);
+        round_trip(
+            &LzmaCodec::new(LzmaFormat::Alone, LzmaCheck::None, None, Some(vec![lzma1])).unwrap(),
+        );
+
+        let x86 = LzmaFilter::new(LzmaFilterId::X86);
+        let mut lzma2 = LzmaFilter::new(LzmaFilterId::Lzma2);
+        lzma2.dict_size = Some(1 << 16);
+        round_trip(
+            &LzmaCodec::new(
+                LzmaFormat::Xz,
+                LzmaCheck::Crc32,
+                None,
+                Some(vec![x86, lzma2]),
+            )
+            .unwrap(),
+        );
+    }
+
+    #[test]
+    fn codec_lzma_invalid() {
+        let lzma1 = LzmaFilter::new(LzmaFilterId::Lzma1);
+        let lzma2 = LzmaFilter::new(LzmaFilterId::Lzma2);
+        for (format, check, preset, filters) in [
+            (
+                LzmaFormat::Xz,
+                LzmaCheck::Default,
+                Some(1),
+                Some(vec![lzma2.clone()]),
+            ),
+            (
+                LzmaFormat::Raw,
+                LzmaCheck::None,
+                None,
+                Some(vec![lzma1.clone()]),
+            ),
+            (LzmaFormat::Alone, LzmaCheck::Crc32, None, None),
+            (
+                LzmaFormat::Alone,
+                LzmaCheck::Default,
+                None,
+                Some(vec![lzma2.clone()]),
+            ),
+            (
+                LzmaFormat::Alone,
+                LzmaCheck::Default,
+                None,
+                Some(vec![lzma1.clone(), lzma1]),
+            ),
+        ] {
+            assert!(LzmaCodec::new(format, check, preset, filters).is_err());
+        }
+
+        let mut delta = LzmaFilter::new(LzmaFilterId::Delta);
+        delta.dist = Some(1);
+        let mut x86_preset = LzmaFilter::new(LzmaFilterId::X86);
+        x86_preset.preset = Some(1);
+        let mut x86_offset = LzmaFilter::new(LzmaFilterId::X86);
+        x86_offset.start_offset = Some(16);
+        let mut lzma2_dist = LzmaFilter::new(LzmaFilterId::Lzma2);
+        lzma2_dist.dist = Some(1);
+        for filter in [delta, x86_preset, x86_offset, lzma2_dist] {
+            let filters = Some(vec![filter, lzma2.clone()]);
+            assert!(LzmaCodec::new(LzmaFormat::Xz, LzmaCheck::Default, None, filters).is_err());
+        }
+
+        let codec = LzmaCodec::new(LzmaFormat::Auto, LzmaCheck::Default, None, None).unwrap();
+        assert!(codec
+            .encode(Cow::Borrowed(&[0, 1, 2]), &CodecOptions::default())
+            .is_err());
+    }
+
+    #[test]
+    fn codec_lzma_metadata() {
+        let codec = codec(JSON_NUMCODECS_DEFAULT);
+        let metadata = codec.create_metadata().unwrap();
+        assert_eq!(metadata.name(), "numcodecs.lzma");
+        assert_eq!(
+            serde_json::to_value(metadata.configuration().unwrap()).unwrap(),
+            serde_json::from_str::<serde_json::Value>(JSON_NUMCODECS_DEFAULT).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lzma_decode_numcodecs() {
+        let bytes: Vec<u8> = (0..16).collect();
+        let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
+
+        // numcodecs.LZMA().encode(bytes(range(16)))
+        let encoded_xz: &[u8] = &[
+            253, 55, 122, 88, 90, 0, 0, 4, 230, 214, 180, 70, 2, 0, 33, 1, 22, 0, 0, 0, 116, 47,
+            229, 163, 1, 0, 15, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 86, 83,
+            152, 182, 33, 228, 100, 122, 0, 1, 40, 16, 229, 11, 108, 96, 31, 182, 243, 125, 1, 0,
+            0, 0, 0, 4, 89, 90,
+        ];
+        // numcodecs.LZMA(format=lzma.FORMAT_ALONE, preset=1).encode(bytes(range(16)))
+        let encoded_alone: &[u8] = &[
+            93, 0, 0, 16, 0, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 82, 80, 10, 132, 249,
+            155, 178, 128, 33, 169, 105, 214, 39, 224, 207, 232, 111, 255, 255, 183, 36, 0, 0,
+        ];
+
+        let codec_alone =
+            LzmaCodec::new(LzmaFormat::Alone, LzmaCheck::Default, Some(1), None).unwrap();
+        let codec_auto = LzmaCodec::new(LzmaFormat::Auto, LzmaCheck::Default, None, None).unwrap();
+        for (codec, encoded) in [
+            (codec(JSON_NUMCODECS_DEFAULT), encoded_xz),
+            (codec_alone, encoded_alone),
+            (codec_auto.clone(), encoded_xz),
+            (codec_auto, encoded_alone),
+        ] {
+            let decoded = codec
+                .decode(
+                    Cow::Borrowed(encoded),
+                    &bytes_representation,
+                    &CodecOptions::default(),
+                )
+                .unwrap();
+            assert_eq!(decoded.to_vec(), bytes);
+        }
+
+        let encoded = codec(JSON_NUMCODECS_DEFAULT)
+            .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
+            .unwrap();
+        assert_eq!(encoded.as_ref(), encoded_xz);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_lzma_partial_decode() {
+        let array_representation =
+            ArrayRepresentation::new(vec![2, 2, 2], DataType::UInt16, FillValue::from(0u16))
+                .unwrap();
+        let data_type_size = array_representation.data_type().fixed_size().unwrap();
+        let array_size = array_representation.num_elements_usize() * data_type_size;
+        let bytes_representation = BytesRepresentation::FixedSize(array_size as u64);
+
+        let elements: Vec<u16> =
+            (0..u16::try_from(array_representation.num_elements()).unwrap()).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+
+        let codec = Arc::new(codec(JSON_NUMCODECS_DEFAULT));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions: Vec<ByteRange> = ArraySubset::new_with_ranges(&[0..2, 1..2, 0..1])
+            .byte_ranges(array_representation.shape(), data_type_size)
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .unwrap()
+            .unwrap();
+
+        let decoded: Vec<u16> = decoded
+            .to_vec()
+            .chunks_exact(size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+
+        let answer: Vec<u16> = vec![2, 6];
+        assert_eq!(answer, decoded);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn codec_lzma_async_partial_decode() {
+        let array_representation =
+            ArrayRepresentation::new(vec![2, 2, 2], DataType::UInt16, FillValue::from(0u16))
+                .unwrap();
+        let data_type_size = array_representation.data_type().fixed_size().unwrap();
+        let array_size = array_representation.num_elements_usize() * data_type_size;
+        let bytes_representation = BytesRepresentation::FixedSize(array_size as u64);
+
+        let elements: Vec<u16> =
+            (0..u16::try_from(array_representation.num_elements()).unwrap()).collect();
+        let bytes = crate::array::transmute_to_bytes_vec(elements);
+
+        let codec = Arc::new(codec(JSON_NUMCODECS_DEFAULT));
+
+        let encoded = codec
+            .encode(Cow::Owned(bytes), &CodecOptions::default())
+            .unwrap();
+        let decoded_regions: Vec<ByteRange> = ArraySubset::new_with_ranges(&[0..2, 1..2, 0..1])
+            .byte_ranges(array_representation.shape(), data_type_size)
+            .unwrap();
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec
+            .async_partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .await
+            .unwrap();
+        let decoded = partial_decoder
+            .partial_decode_concat(&decoded_regions, &CodecOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let decoded: Vec<u16> = decoded
+            .to_vec()
+            .chunks_exact(size_of::<u16>())
+            .map(|b| u16::from_ne_bytes(b.try_into().unwrap()))
+            .collect();
+
+        let answer: Vec<u16> = vec![2, 6];
+        assert_eq!(answer, decoded);
+    }
+}
@@ -0,0 +1,363 @@
+use std::{
+    borrow::Cow,
+    io::{Cursor, Read},
+    sync::Arc,
+};
+
+use xz2::stream::{Check, Filters, LzmaOptions, MatchFinder, Mode, Stream, CONCATENATED};
+
+use crate::{
+    array::{
+        codec::{
+            BytesPartialDecoderTraits, BytesPartialEncoderDefault, BytesPartialEncoderTraits,
+            BytesToBytesCodecTraits, CodecError, CodecOptions, CodecTraits, RecommendedConcurrency,
+        },
+        ArrayMetadataOptions, BytesRepresentation, RawBytes,
+    },
+    config::global_config,
+    metadata::v3::{
+        array::codec::lzma::{
+            LzmaCheck, LzmaFilter, LzmaFilterId, LzmaFormat, LzmaMatchFinder, LzmaMode,
+        },
+        MetadataV3,
+    },
+    plugin::PluginCreateError,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+use super::{lzma_partial_decoder, LzmaCodecConfiguration, LzmaCodecConfigurationV1};
+
+/// The default `lzma` preset.
+const LZMA_PRESET_DEFAULT: u32 = 6;
+
+/// A `lzma` codec implementation.
+#[derive(Clone, Debug)]
+pub struct LzmaCodec {
+    format: LzmaFormat,
+    check: LzmaCheck,
+    preset: Option<u32>,
+    filters: Option<Vec<LzmaFilter>>,
+}
+
+impl LzmaCodec {
+    /// Create a new `lzma` codec.
+    ///
+    /// The parameters match those of the Python [`lzma`](https://docs.python.org/3/library/lzma.html) module.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the combination of parameters is not supported, such as:
+    ///  - the [`LzmaFormat::Raw`] format, which is not currently supported,
+    ///  - both `preset` and `filters` are set,
+    ///  - `check` is set for a format other than [`LzmaFormat::Xz`], or
+    ///  - a filter is invalid or not supported.
+    pub fn new(
+        format: LzmaFormat,
+        check: LzmaCheck,
+        preset: Option<u32>,
+        filters: Option<Vec<LzmaFilter>>,
+    ) -> Result<Self, PluginCreateError> {
+        if preset.is_some() && filters.is_some() {
+            return Err(PluginCreateError::from(
+                "lzma preset and filters cannot both be set",
+            ));
+        }
+        if format == LzmaFormat::Raw {
+            return Err(PluginCreateError::from("lzma raw format is not supported"));
+        }
+        if format != LzmaFormat::Xz && !matches!(check, LzmaCheck::Default | LzmaCheck::None) {
+            return Err(PluginCreateError::Other(format!(
+                "lzma check {check} is only supported by the xz format"
+            )));
+        }
+        if let (LzmaFormat::Alone, Some(filters)) = (format, &filters) {
+            if filters.len() != 1 || filters[0].id != LzmaFilterId::Lzma1 {
+                return Err(PluginCreateError::from(
+                    "lzma alone format requires a single lzma1 filter",
+                ));
+            }
+        }
+        if let Some(filters) = &filters {
+            lzma_filters(filters).map_err(|err| PluginCreateError::Other(err.to_string()))?;
+        }
+        Ok(Self {
+            format,
+            check,
+            preset,
+            filters,
+        })
+    }
+
+    /// Create a new `lzma` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration is not supported.
+    pub fn new_with_configuration(
+        configuration: &LzmaCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let LzmaCodecConfiguration::V1(configuration) = configuration;
+        Self::new(
+            configuration.format,
+            configuration.check,
+            configuration.preset,
+            configuration.filters.clone(),
+        )
+    }
+
+    fn preset(&self) -> u32 {
+        self.preset.unwrap_or(LZMA_PRESET_DEFAULT)
+    }
+
+    fn encoder(&self) -> Result<Stream, CodecError> {
+        let stream = match (self.format, &self.filters) {
+            (LzmaFormat::Xz, None) => {
+                Stream::new_easy_encoder(self.preset(), lzma_check(self.check))
+            }
+            (LzmaFormat::Xz, Some(filters)) => {
+                Stream::new_stream_encoder(&lzma_filters(filters)?, lzma_check(self.check))
+            }
+            (LzmaFormat::Alone, None) => Stream::new_lzma_encoder(&lzma_options(self.preset())?),
+            (LzmaFormat::Alone, Some(filters)) => {
+                Stream::new_lzma_encoder(&lzma_filter_options(&filters[0])?)
+            }
+            (LzmaFormat::Raw, _) => unreachable!("validated by new"),
+            (LzmaFormat::Auto, _) => {
+                return Err(CodecError::from(
+                    "lzma codec does not support encoding with the auto format",
+                ))
+            }
+        };
+        stream.map_err(lzma_error)
+    }
+
+    fn decoder(&self) -> Result<Stream, CodecError> {
+        let stream = match self.format {
+            LzmaFormat::Auto => Stream::new_auto_decoder(u64::MAX, CONCATENATED),
+            LzmaFormat::Xz => Stream::new_stream_decoder(u64::MAX, CONCATENATED),
+            LzmaFormat::Alone => Stream::new_lzma_decoder(u64::MAX),
+            LzmaFormat::Raw => unreachable!("validated by new"),
+        };
+        stream.map_err(lzma_error)
+    }
+
+    /// Decode `lzma` encoded bytes.
+    pub(super) fn decompress(&self, encoded_value: &[u8]) -> Result<Vec<u8>, CodecError> {
+        let mut decoder = xz2::read::XzDecoder::new_stream(encoded_value, self.decoder()?);
+        let mut out: Vec<u8> = Vec::new();
+        decoder.read_to_end(&mut out)?;
+        Ok(out)
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn lzma_error(err: xz2::stream::Error) -> CodecError {
+    CodecError::Other(format!("lzma error: {err}"))
+}
+
+fn lzma_check(check: LzmaCheck) -> Check {
+    match check {
+        LzmaCheck::Default | LzmaCheck::Crc64 => Check::Crc64,
+        LzmaCheck::None => Check::None,
+        LzmaCheck::Crc32 => Check::Crc32,
+        LzmaCheck::Sha256 => Check::Sha256,
+    }
+}
+
+fn lzma_options(preset: u32) -> Result<LzmaOptions, CodecError> {
+    LzmaOptions::new_preset(preset)
+        .map_err(|_| CodecError::Other(format!("lzma preset {preset} is invalid")))
+}
+
+fn lzma_filter_options(filter: &LzmaFilter) -> Result<LzmaOptions, CodecError> {
+    let mut options = lzma_options(filter.preset.unwrap_or(LZMA_PRESET_DEFAULT))?;
+    if let Some(dict_size) = filter.dict_size {
+        options.dict_size(dict_size);
+    }
+    if let Some(lc) = filter.lc {
+        options.literal_context_bits(lc);
+    }
+    if let Some(lp) = filter.lp {
+        options.literal_position_bits(lp);
+    }
+    if let Some(pb) = filter.pb {
+        options.position_bits(pb);
+    }
+    if let Some(mode) = filter.mode {
+        options.mode(match mode {
+            LzmaMode::Fast => Mode::Fast,
+            LzmaMode::Normal => Mode::Normal,
+        });
+    }
+    if let Some(nice_len) = filter.nice_len {
+        options.nice_len(nice_len);
+    }
+    if let Some(mf) = filter.mf {
+        options.match_finder(match mf {
+            LzmaMatchFinder::HashChain3 => MatchFinder::HashChain3,
+            LzmaMatchFinder::HashChain4 => MatchFinder::HashChain4,
+            LzmaMatchFinder::BinaryTree2 => MatchFinder::BinaryTree2,
+            LzmaMatchFinder::BinaryTree3 => MatchFinder::BinaryTree3,
+            LzmaMatchFinder::BinaryTree4 => MatchFinder::BinaryTree4,
+        });
+    }
+    if let Some(depth) = filter.depth {
+        options.depth(depth);
+    }
+    Ok(options)
+}
+
+fn lzma_filters(filters: &[LzmaFilter]) -> Result<Filters, CodecError> {
+    let mut lzma_filters = Filters::new();
+    for filter in filters {
+        let has_lzma_options = filter.preset.is_some()
+            || filter.dict_size.is_some()
+            || filter.lc.is_some()
+            || filter.lp.is_some()
+            || filter.pb.is_some()
+            || filter.mode.is_some()
+            || filter.nice_len.is_some()
+            || filter.mf.is_some()
+            || filter.depth.is_some();
+        let is_lzma = matches!(filter.id, LzmaFilterId::Lzma1 | LzmaFilterId::Lzma2);
+        if (filter.dist.is_some() && filter.id != LzmaFilterId::Delta)
+            || (has_lzma_options && !is_lzma)
+        {
+            return Err(CodecError::Other(format!(
+                "lzma filter {} has options that are not applicable",
+                filter.id
+            )));
+        }
+        if filter.start_offset.is_some() {
+            return Err(CodecError::from(
+                "lzma filter start_offset is not supported",
+            ));
+        }
+
+        match filter.id {
+            LzmaFilterId::Lzma1 => lzma_filters.lzma1(&lzma_filter_options(filter)?),
+            LzmaFilterId::Lzma2 => lzma_filters.lzma2(&lzma_filter_options(filter)?),
+            LzmaFilterId::Delta => {
+                return Err(CodecError::from("lzma delta filter is not supported"));
+            }
+            LzmaFilterId::X86 => lzma_filters.x86(),
+            LzmaFilterId::PowerPc => lzma_filters.powerpc(),
+            LzmaFilterId::Ia64 => lzma_filters.ia64(),
+            LzmaFilterId::Arm => lzma_filters.arm(),
+            LzmaFilterId::ArmThumb => lzma_filters.arm_thumb(),
+            LzmaFilterId::Sparc => lzma_filters.sparc(),
+        };
+    }
+    Ok(lzma_filters)
+}
+
+impl CodecTraits for LzmaCodec {
+    fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        let configuration = LzmaCodecConfigurationV1 {
+            format: self.format,
+            check: self.check,
+            preset: self.preset,
+            filters: self.filters.clone(),
+        };
+        Some(
+            MetadataV3::new_with_serializable_configuration(
+                global_config()
+                    .experimental_codec_names()
+                    .get(super::IDENTIFIER)
+                    .expect("experimental codec identifier in global map"),
+                &configuration,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        false
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        true
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for LzmaCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        Ok(RecommendedConcurrency::new_maximum(1))
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        let mut encoder =
+            xz2::read::XzEncoder::new_stream(Cursor::new(decoded_value), self.encoder()?);
+        let mut out: Vec<u8> = Vec::new();
+        encoder.read_to_end(&mut out)?;
+        Ok(Cow::Owned(out))
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        Ok(Cow::Owned(self.decompress(&encoded_value)?))
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(lzma_partial_decoder::LzmaPartialDecoder::new(
+            input_handle,
+            self,
+        )))
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        Ok(Arc::new(BytesPartialEncoderDefault::new(
+            input_handle,
+            output_handle,
+            *decoded_representation,
+            self,
+        )))
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        _decoded_representation: &BytesRepresentation,
+        _options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        Ok(Arc::new(
+            lzma_partial_decoder::AsyncLzmaPartialDecoder::new(input_handle, self),
+        ))
+    }
+
+    fn compute_encoded_size(
+        &self,
+        _decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        // The container overhead depends on the format and filters
+        BytesRepresentation::UnboundedSize
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        codec::{BytesPartialDecoderTraits, CodecError, CodecOptions},
+        RawBytes,
+    },
+    byte_range::ByteRange,
+};
+
+#[cfg(feature = "async")]
+use crate::array::codec::AsyncBytesPartialDecoderTraits;
+
+/// Offset decoded byte ranges past a prefix of `prefix_size` bytes.
+fn offset_byte_ranges(decoded_regions: &[ByteRange], prefix_size: usize) -> Vec<ByteRange> {
+    decoded_regions
+        .iter()
+        .map(|byte_range| match byte_range {
+            ByteRange::FromStart(offset, length) => {
+                ByteRange::FromStart(offset + prefix_size as u64, *length)
+            }
+            ByteRange::Suffix(length) => ByteRange::Suffix(*length),
+        })
+        .collect()
+}
+
+/// Partial decoder for stripping a prefix (e.g. checksum).
+pub(crate) struct StripPrefixPartialDecoder {
+    input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    prefix_size: usize,
+}
+
+impl StripPrefixPartialDecoder {
+    /// Create a new "strip prefix" partial decoder.
+    pub(crate) fn new(
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        prefix_size: usize,
+    ) -> Self {
+        Self {
+            input_handle,
+            prefix_size,
+        }
+    }
+}
+
+impl BytesPartialDecoderTraits for StripPrefixPartialDecoder {
+    fn partial_decode(
+        &self,
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        // Skip the leading checksum
+        let encoded_regions = offset_byte_ranges(decoded_regions, self.prefix_size);
+        self.input_handle.partial_decode(&encoded_regions, options)
+    }
+}
+
+#[cfg(feature = "async")]
+/// Asynchronous partial decoder for stripping a prefix (e.g. checksum).
+pub(crate) struct AsyncStripPrefixPartialDecoder {
+    input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    prefix_size: usize,
+}
+
+#[cfg(feature = "async")]
+impl AsyncStripPrefixPartialDecoder {
+    /// Create a new "strip prefix" partial decoder.
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        prefix_size: usize,
+    ) -> Self {
+        Self {
+            input_handle,
+            prefix_size,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl AsyncBytesPartialDecoderTraits for AsyncStripPrefixPartialDecoder {
+    async fn partial_decode(
+        &self,
+        decoded_regions: &[ByteRange],
+        options: &CodecOptions,
+    ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        // Skip the leading checksum
+        let encoded_regions = offset_byte_ranges(decoded_regions, self.prefix_size);
+        self.input_handle
+            .partial_decode(&encoded_regions, options)
+            .await
+    }
+}
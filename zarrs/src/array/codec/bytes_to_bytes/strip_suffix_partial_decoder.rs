@@ -11,6 +11,17 @@ use crate::{
 #[cfg(feature = "async")]
 use crate::array::codec::AsyncBytesPartialDecoderTraits;
 
+/// Extend decoded suffix byte ranges to include a suffix of `suffix_size` bytes.
+fn extend_suffix_byte_ranges(decoded_regions: &[ByteRange], suffix_size: usize) -> Vec<ByteRange> {
+    decoded_regions
+        .iter()
+        .map(|byte_range| match byte_range {
+            ByteRange::FromStart(offset, length) => ByteRange::FromStart(*offset, *length),
+            ByteRange::Suffix(length) => ByteRange::Suffix(length + suffix_size as u64),
+        })
+        .collect()
+}
+
 /// Partial decoder for stripping a suffix (e.g. checksum).
 pub(crate) struct StripSuffixPartialDecoder {
     input_handle: Arc<dyn BytesPartialDecoderTraits>,
@@ -36,7 +47,10 @@ impl BytesPartialDecoderTraits for StripSuffixPartialDecoder {
         decoded_regions: &[ByteRange],
         options: &CodecOptions,
     ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
-        let bytes = self.input_handle.partial_decode(decoded_regions, options)?;
+        let encoded_regions = extend_suffix_byte_ranges(decoded_regions, self.suffix_size);
+        let bytes = self
+            .input_handle
+            .partial_decode(&encoded_regions, options)?;
         let Some(bytes) = bytes else {
             return Ok(None);
         };
@@ -92,9 +106,10 @@ impl AsyncBytesPartialDecoderTraits for AsyncStripSuffixPartialDecoder {
         decoded_regions: &[ByteRange],
         options: &CodecOptions,
     ) -> Result<Option<Vec<RawBytes<'_>>>, CodecError> {
+        let encoded_regions = extend_suffix_byte_ranges(decoded_regions, self.suffix_size);
         let bytes = self
             .input_handle
-            .partial_decode(decoded_regions, options)
+            .partial_decode(&encoded_regions, options)
             .await?;
         let Some(bytes) = bytes else {
             return Ok(None);
@@ -7,8 +7,19 @@
 //! </div>
 //!
 //! See <https://github.com/zarr-developers/zarr-specs/pull/256>.
+//!
+//! ### Dictionaries
+//! `zarrs` supports compression with a `zstd` dictionary as an experimental extension of the codec configuration.
+//! A dictionary can dramatically improve the compression ratio of many small chunks with similar content (e.g. sparse labels).
+//! Train a dictionary from sample chunks with [`train_zstd_dictionary`] and create the codec with [`ZstdCodec::new_with_dictionary`].
+//!
+//! The dictionary is embedded as base64 in the `dictionary` member of the codec configuration, alongside its `dict_id`.
+//! Embedding the dictionary keeps the codec self-contained, so it can be created from its metadata alone (e.g. as an inner codec of the `sharding_indexed` codec).
+//! The dictionary is prepared once per codec and is reused for every chunk of an array.
+//! Other Zarr V3 implementations are unlikely to support these members, and arrays with a dictionary cannot be converted to Zarr V2.
 
 mod zstd_codec;
+mod zstd_dictionary;
 mod zstd_partial_decoder;
 
 use std::sync::Arc;
@@ -17,6 +28,7 @@ pub use crate::metadata::v3::array::codec::zstd::{
     ZstdCodecConfiguration, ZstdCodecConfigurationV1, ZstdCompressionLevel,
 };
 pub use zstd_codec::ZstdCodec;
+pub use zstd_dictionary::train_zstd_dictionary;
 
 use crate::{
     array::codec::{Codec, CodecPlugin},
@@ -39,7 +51,7 @@ pub(crate) fn create_codec_zstd(metadata: &MetadataV3) -> Result<Codec, PluginCr
     let configuration: ZstdCodecConfiguration = metadata
         .to_configuration()
         .map_err(|_| PluginMetadataInvalidError::new(IDENTIFIER, "codec", metadata.clone()))?;
-    let codec = Arc::new(ZstdCodec::new_with_configuration(&configuration));
+    let codec = Arc::new(ZstdCodec::new_with_configuration(&configuration)?);
     Ok(Codec::BytesToBytes(codec))
 }
 
@@ -49,10 +61,11 @@ mod tests {
 
     use crate::{
         array::{
-            codec::{BytesToBytesCodecTraits, CodecOptions},
+            codec::{BytesToBytesCodecTraits, CodecOptions, CodecTraits},
             BytesRepresentation,
         },
         byte_range::ByteRange,
+        storage::Bytes,
     };
 
     use super::*;
@@ -70,7 +83,7 @@ mod tests {
         let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
 
         let configuration: ZstdCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
-        let codec = ZstdCodec::new_with_configuration(&configuration);
+        let codec = ZstdCodec::new_with_configuration(&configuration).unwrap();
 
         let encoded = codec
             .encode(Cow::Borrowed(&bytes), &CodecOptions::default())
@@ -81,6 +94,151 @@ mod tests {
         assert_eq!(bytes, decoded.to_vec());
     }
 
+    /// Synthetic chunks of repetitive data, composed of fragments from a pool shared across chunks.
+    #[allow(clippy::cast_possible_truncation)]
+    fn repetitive_chunks(num_chunks: usize) -> Vec<Bytes> {
+        // A linear congruential generator for deterministic pseudo-random bytes
+        let mut state = 12345u64;
+        let mut random = move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) as usize
+        };
+        let fragments: Vec<Vec<u8>> = (0..32)
+            .map(|_| (0..32).map(|_| random() as u8).collect())
+            .collect();
+        (0..num_chunks)
+            .map(|_| {
+                let chunk: Vec<u8> = (0..8)
+                    .flat_map(|_| fragments[random() % fragments.len()].clone())
+                    .collect();
+                Bytes::from(chunk)
+            })
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_zstd_dictionary_round_trip() {
+        let chunks = repetitive_chunks(1000);
+        let dictionary = train_zstd_dictionary(&chunks, 4096).unwrap();
+        let codec = Arc::new(ZstdCodec::new_with_dictionary(3, true, dictionary.clone()));
+        assert_eq!(
+            codec.dict_id(),
+            ::zstd::zstd_safe::get_dict_id_from_dict(&dictionary).map(u32::from)
+        );
+        assert!(codec.dict_id().is_some());
+
+        // The codec is recreated from its metadata with the embedded dictionary
+        let metadata = codec.create_metadata().unwrap();
+        let Codec::BytesToBytes(codec_from_metadata) = create_codec_zstd(&metadata).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(codec_from_metadata.create_metadata().unwrap(), metadata);
+
+        let chunk = chunks[123].to_vec();
+        let bytes_representation = BytesRepresentation::FixedSize(chunk.len() as u64);
+        let encoded = codec
+            .encode(Cow::Owned(chunk.clone()), &CodecOptions::default())
+            .unwrap();
+        let decoded = codec_from_metadata
+            .decode(
+                encoded.clone(),
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(chunk, decoded.to_vec());
+
+        // The dictionary is required to decode
+        assert!(ZstdCodec::new(3, true)
+            .decode(
+                encoded.clone(),
+                &bytes_representation,
+                &CodecOptions::default()
+            )
+            .is_err());
+
+        let input_handle = Arc::new(std::io::Cursor::new(encoded));
+        let partial_decoder = codec_from_metadata
+            .partial_decoder(
+                input_handle,
+                &bytes_representation,
+                &CodecOptions::default(),
+            )
+            .unwrap();
+        let decoded_partial_chunk = partial_decoder
+            .partial_decode_concat(
+                &[ByteRange::FromStart(4, Some(8))],
+                &CodecOptions::default(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded_partial_chunk.to_vec(), chunk[4..12]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_zstd_dictionary_ratio() {
+        let chunks = repetitive_chunks(1000);
+        let dictionary = train_zstd_dictionary(&chunks[..500], 4096).unwrap();
+        let codec = ZstdCodec::new(3, false);
+        let codec_dictionary = ZstdCodec::new_with_dictionary(3, false, dictionary);
+        let encoded_size = |codec: &ZstdCodec| -> usize {
+            chunks[500..]
+                .iter()
+                .map(|chunk| {
+                    codec
+                        .encode(Cow::Borrowed(chunk), &CodecOptions::default())
+                        .unwrap()
+                        .len()
+                })
+                .sum()
+        };
+        let size = encoded_size(&codec);
+        let size_dictionary = encoded_size(&codec_dictionary);
+        assert!(
+            size_dictionary * 2 < size,
+            "{size_dictionary} is not less than half of {size}"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn codec_zstd_dictionary_configuration_invalid() {
+        // The dict_id must match the dictionary
+        let dictionary = train_zstd_dictionary(&repetitive_chunks(1000), 4096).unwrap();
+        let dict_id = ::zstd::zstd_safe::get_dict_id_from_dict(&dictionary)
+            .unwrap()
+            .get();
+        let configuration = ZstdCodecConfigurationV1::new_with_dictionary(
+            ZstdCompressionLevel::new(3),
+            false,
+            Some(dict_id.wrapping_add(1)),
+            dictionary,
+        );
+        assert!(ZstdCodec::new_with_configuration(&configuration.into()).is_err());
+
+        // The dictionary must be in the configuration
+        let configuration: ZstdCodecConfiguration =
+            serde_json::from_str(r#"{"level":3,"checksum":false,"dict_id":1234}"#).unwrap();
+        assert!(ZstdCodec::new_with_configuration(&configuration).is_err());
+
+        // A raw content dictionary has no dict_id
+        let configuration: ZstdCodecConfiguration =
+            serde_json::from_str(r#"{"level":3,"checksum":false,"dictionary":"AAECAwQFBgcICQ=="}"#)
+                .unwrap();
+        let codec = ZstdCodec::new_with_configuration(&configuration).unwrap();
+        assert_eq!(codec.dict_id(), None);
+        assert_eq!(
+            codec.create_metadata().unwrap().configuration(),
+            MetadataV3::new_with_serializable_configuration(IDENTIFIER, &configuration)
+                .unwrap()
+                .configuration()
+        );
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn codec_zstd_partial_decode() {
@@ -89,7 +247,7 @@ mod tests {
         let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
 
         let configuration: ZstdCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
-        let codec = Arc::new(ZstdCodec::new_with_configuration(&configuration));
+        let codec = Arc::new(ZstdCodec::new_with_configuration(&configuration).unwrap());
 
         let encoded = codec
             .encode(Cow::Owned(bytes), &CodecOptions::default())
@@ -130,7 +288,7 @@ mod tests {
         let bytes_representation = BytesRepresentation::FixedSize(bytes.len() as u64);
 
         let configuration: ZstdCodecConfiguration = serde_json::from_str(JSON_VALID).unwrap();
-        let codec = Arc::new(ZstdCodec::new_with_configuration(&configuration));
+        let codec = Arc::new(ZstdCodec::new_with_configuration(&configuration).unwrap());
 
         let encoded = codec
             .encode(Cow::Owned(bytes), &CodecOptions::default())
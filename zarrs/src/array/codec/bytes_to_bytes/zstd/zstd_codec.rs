@@ -11,18 +11,23 @@ use crate::{
         ArrayMetadataOptions, BytesRepresentation, RawBytes,
     },
     metadata::v3::MetadataV3,
+    plugin::PluginCreateError,
 };
 
 #[cfg(feature = "async")]
 use crate::array::codec::AsyncBytesPartialDecoderTraits;
 
-use super::{zstd_partial_decoder, ZstdCodecConfiguration, ZstdCodecConfigurationV1, IDENTIFIER};
+use super::{
+    zstd_dictionary::{zstd_decode, ZstdDictionary},
+    zstd_partial_decoder, ZstdCodecConfiguration, ZstdCodecConfigurationV1, IDENTIFIER,
+};
 
 /// A `zstd` codec implementation.
 #[derive(Clone, Debug)]
 pub struct ZstdCodec {
     compression: zstd_safe::CompressionLevel,
     checksum: bool,
+    dictionary: Option<Arc<ZstdDictionary>>,
 }
 
 impl ZstdCodec {
@@ -32,25 +37,81 @@ impl ZstdCodec {
         Self {
             compression,
             checksum,
+            dictionary: None,
         }
     }
 
-    /// Create a new `Zstd` codec from configuration.
+    /// Create a new `Zstd` codec that compresses with a `dictionary` (experimental).
+    ///
+    /// The `dictionary` is typically trained from sample chunks with [`train_zstd_dictionary`](super::train_zstd_dictionary), but it can also be raw content.
+    /// It is embedded in the codec metadata, so the same dictionary is used to decompress chunks.
+    /// The dictionary is prepared for compression and decompression once, when the codec is created.
     #[must_use]
-    pub fn new_with_configuration(configuration: &ZstdCodecConfiguration) -> Self {
-        let ZstdCodecConfiguration::V1(configuration) = configuration;
+    pub fn new_with_dictionary(
+        compression: zstd_safe::CompressionLevel,
+        checksum: bool,
+        dictionary: Vec<u8>,
+    ) -> Self {
         Self {
-            compression: configuration.level.clone().into(),
-            checksum: configuration.checksum,
+            compression,
+            checksum,
+            dictionary: Some(Arc::new(ZstdDictionary::new(dictionary, compression))),
         }
     }
+
+    /// Create a new `Zstd` codec from configuration.
+    ///
+    /// # Errors
+    /// Returns [`PluginCreateError`] if the configuration has a `dict_id` that does not match its dictionary, or has a `dict_id` without a dictionary.
+    pub fn new_with_configuration(
+        configuration: &ZstdCodecConfiguration,
+    ) -> Result<Self, PluginCreateError> {
+        let ZstdCodecConfiguration::V1(configuration) = configuration;
+        let compression = configuration.level.clone().into();
+        match (&configuration.dictionary, configuration.dict_id) {
+            (Some(dictionary), dict_id) => {
+                let codec = Self::new_with_dictionary(
+                    compression,
+                    configuration.checksum,
+                    dictionary.clone(),
+                );
+                if dict_id.is_some() && dict_id != codec.dict_id() {
+                    return Err(PluginCreateError::from(format!(
+                        "zstd dictionary ID {:?} does not match the configured dict_id {dict_id:?}",
+                        codec.dict_id()
+                    )));
+                }
+                Ok(codec)
+            }
+            (None, Some(dict_id)) => Err(PluginCreateError::from(format!(
+                "zstd dictionary with dict_id {dict_id} is not in the codec configuration"
+            ))),
+            (None, None) => Ok(Self::new(compression, configuration.checksum)),
+        }
+    }
+
+    /// Return the ID of the dictionary of the codec.
+    ///
+    /// Returns [`None`] if the codec does not have a dictionary, or it is a raw content dictionary.
+    #[must_use]
+    pub fn dict_id(&self) -> Option<u32> {
+        self.dictionary
+            .as_ref()
+            .and_then(|dictionary| dictionary.dict_id())
+    }
 }
 
 impl CodecTraits for ZstdCodec {
     fn create_metadata_opt(&self, _options: &ArrayMetadataOptions) -> Option<MetadataV3> {
-        let configuration = ZstdCodecConfigurationV1 {
-            level: self.compression.into(),
-            checksum: self.checksum,
+        let configuration = if let Some(dictionary) = &self.dictionary {
+            ZstdCodecConfigurationV1::new_with_dictionary(
+                self.compression.into(),
+                self.checksum,
+                dictionary.dict_id(),
+                dictionary.dictionary().to_vec(),
+            )
+        } else {
+            ZstdCodecConfigurationV1::new(self.compression.into(), self.checksum)
         };
         Some(MetadataV3::new_with_serializable_configuration(IDENTIFIER, &configuration).unwrap())
     }
@@ -84,7 +145,11 @@ impl BytesToBytesCodecTraits for ZstdCodec {
         _options: &CodecOptions,
     ) -> Result<RawBytes<'a>, CodecError> {
         let mut result = Vec::<u8>::new();
-        let mut encoder = zstd::Encoder::new(&mut result, self.compression)?;
+        let mut encoder = if let Some(dictionary) = &self.dictionary {
+            zstd::Encoder::with_prepared_dictionary(&mut result, dictionary.encoder_dictionary())?
+        } else {
+            zstd::Encoder::new(&mut result, self.compression)?
+        };
         encoder.include_checksum(self.checksum)?;
         // if parallel {
         //     let n_threads = std::thread::available_parallelism().unwrap().get();
@@ -101,9 +166,7 @@ impl BytesToBytesCodecTraits for ZstdCodec {
         _decoded_representation: &BytesRepresentation,
         _options: &CodecOptions,
     ) -> Result<RawBytes<'a>, CodecError> {
-        zstd::decode_all(std::io::Cursor::new(&encoded_value))
-            .map_err(CodecError::IOError)
-            .map(Cow::Owned)
+        zstd_decode(&encoded_value, self.dictionary.as_deref()).map(Cow::Owned)
     }
 
     fn partial_decoder(
@@ -112,7 +175,10 @@ impl BytesToBytesCodecTraits for ZstdCodec {
         _decoded_representation: &BytesRepresentation,
         _options: &CodecOptions,
     ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
-        Ok(Arc::new(zstd_partial_decoder::ZstdPartialDecoder::new(r)))
+        Ok(Arc::new(zstd_partial_decoder::ZstdPartialDecoder::new(
+            r,
+            self.dictionary.clone(),
+        )))
     }
 
     fn partial_encoder(
@@ -138,7 +204,7 @@ impl BytesToBytesCodecTraits for ZstdCodec {
         _options: &CodecOptions,
     ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
         Ok(Arc::new(
-            zstd_partial_decoder::AsyncZstdPartialDecoder::new(r),
+            zstd_partial_decoder::AsyncZstdPartialDecoder::new(r, self.dictionary.clone()),
         ))
     }
 
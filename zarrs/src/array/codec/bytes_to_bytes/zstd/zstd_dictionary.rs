@@ -0,0 +1,79 @@
+use std::io::Read;
+
+use zstd::dict::{DecoderDictionary, EncoderDictionary};
+
+use crate::{array::codec::CodecError, storage::Bytes};
+
+/// A `zstd` dictionary prepared for compression and decompression.
+pub(crate) struct ZstdDictionary {
+    dict_id: Option<u32>,
+    dictionary: Vec<u8>,
+    encoder_dictionary: EncoderDictionary<'static>,
+    decoder_dictionary: DecoderDictionary<'static>,
+}
+
+impl core::fmt::Debug for ZstdDictionary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ZstdDictionary")
+            .field("dict_id", &self.dict_id)
+            .field("size", &self.dictionary.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ZstdDictionary {
+    /// Prepare `dictionary` for compression at compression level `level` and decompression.
+    pub(crate) fn new(dictionary: Vec<u8>, level: zstd::zstd_safe::CompressionLevel) -> Self {
+        Self {
+            dict_id: zstd::zstd_safe::get_dict_id_from_dict(&dictionary).map(u32::from),
+            encoder_dictionary: EncoderDictionary::copy(&dictionary, level),
+            decoder_dictionary: DecoderDictionary::copy(&dictionary),
+            dictionary,
+        }
+    }
+
+    /// Return the ID of the dictionary, or [`None`] for a raw content dictionary.
+    pub(crate) const fn dict_id(&self) -> Option<u32> {
+        self.dict_id
+    }
+
+    /// Return the dictionary bytes.
+    pub(crate) fn dictionary(&self) -> &[u8] {
+        &self.dictionary
+    }
+
+    /// Return the dictionary prepared for compression.
+    pub(crate) const fn encoder_dictionary(&self) -> &EncoderDictionary<'static> {
+        &self.encoder_dictionary
+    }
+}
+
+/// Decompress the `zstd` frames in `encoded_value`, optionally with a `dictionary`.
+pub(crate) fn zstd_decode(
+    encoded_value: &[u8],
+    dictionary: Option<&ZstdDictionary>,
+) -> Result<Vec<u8>, CodecError> {
+    if let Some(dictionary) = dictionary {
+        let mut decoder = zstd::stream::read::Decoder::with_prepared_dictionary(
+            encoded_value,
+            &dictionary.decoder_dictionary,
+        )?;
+        let mut decoded_value = Vec::new();
+        decoder.read_to_end(&mut decoded_value)?;
+        Ok(decoded_value)
+    } else {
+        Ok(zstd::decode_all(encoded_value)?)
+    }
+}
+
+/// Train a `zstd` dictionary of at most `max_size` bytes from `samples`.
+///
+/// The samples should be representative of the input to the `zstd` codec, such as the bytes of decoded chunks.
+/// A dictionary is most effective for many small chunks with similar content, where it can dramatically improve the compression ratio.
+/// The trained dictionary can be used with [`ZstdCodec::new_with_dictionary`](super::ZstdCodec::new_with_dictionary).
+///
+/// # Errors
+/// Returns a [`CodecError`] if training fails, such as if there are too few samples.
+pub fn train_zstd_dictionary(samples: &[Bytes], max_size: usize) -> Result<Vec<u8>, CodecError> {
+    Ok(zstd::dict::from_samples(samples, max_size)?)
+}
@@ -1,7 +1,5 @@
 use std::{borrow::Cow, sync::Arc};
 
-use bytes::Buf;
-
 use crate::{
     array::{
         codec::{BytesPartialDecoderTraits, CodecError, CodecOptions},
@@ -13,15 +11,24 @@ use crate::{
 #[cfg(feature = "async")]
 use crate::array::codec::AsyncBytesPartialDecoderTraits;
 
+use super::zstd_dictionary::{zstd_decode, ZstdDictionary};
+
 /// Partial decoder for the `zstd` codec.
 pub(crate) struct ZstdPartialDecoder {
     input_handle: Arc<dyn BytesPartialDecoderTraits>,
+    dictionary: Option<Arc<ZstdDictionary>>,
 }
 
 impl ZstdPartialDecoder {
     /// Create a new partial decoder for the `zstd` codec.
-    pub(crate) fn new(input_handle: Arc<dyn BytesPartialDecoderTraits>) -> Self {
-        Self { input_handle }
+    pub(crate) fn new(
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        dictionary: Option<Arc<ZstdDictionary>>,
+    ) -> Self {
+        Self {
+            input_handle,
+            dictionary,
+        }
     }
 }
 
@@ -36,7 +43,7 @@ impl BytesPartialDecoderTraits for ZstdPartialDecoder {
             return Ok(None);
         };
 
-        let decompressed = zstd::decode_all(encoded_value.reader()).map_err(CodecError::IOError)?;
+        let decompressed = zstd_decode(&encoded_value, self.dictionary.as_deref())?;
 
         Ok(Some(
             extract_byte_ranges(&decompressed, decoded_regions)
@@ -52,13 +59,20 @@ impl BytesPartialDecoderTraits for ZstdPartialDecoder {
 /// Asynchronous partial decoder for the `zstd` codec.
 pub(crate) struct AsyncZstdPartialDecoder {
     input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+    dictionary: Option<Arc<ZstdDictionary>>,
 }
 
 #[cfg(feature = "async")]
 impl AsyncZstdPartialDecoder {
     /// Create a new partial decoder for the `zstd` codec.
-    pub(crate) fn new(input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>) -> Self {
-        Self { input_handle }
+    pub(crate) fn new(
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        dictionary: Option<Arc<ZstdDictionary>>,
+    ) -> Self {
+        Self {
+            input_handle,
+            dictionary,
+        }
     }
 }
 
@@ -75,7 +89,7 @@ impl AsyncBytesPartialDecoderTraits for AsyncZstdPartialDecoder {
             return Ok(None);
         };
 
-        let decompressed = zstd::decode_all(encoded_value.reader()).map_err(CodecError::IOError)?;
+        let decompressed = zstd_decode(&encoded_value, self.dictionary.as_deref())?;
 
         Ok(Some(
             extract_byte_ranges(&decompressed, decoded_regions)
@@ -1,16 +1,25 @@
 //! Codec options for encoding and decoding.
 
+use std::sync::Arc;
+
+use rayon::ThreadPool;
+
 use crate::config::global_config;
 
 /// Codec options for encoding/decoding.
 ///
 /// Default values for these options are set by the global [`Config`](crate::config::Config).
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct CodecOptions {
     validate_checksums: bool,
     store_empty_chunks: bool,
     concurrent_target: usize,
+    chunk_concurrent_maximum: usize,
+    range_coalesce_gap: Option<u64>,
     experimental_partial_encoding: bool,
+    treat_corrupt_shards_as_missing: bool,
+    thread_pool: Option<Arc<ThreadPool>>,
 }
 
 impl Default for CodecOptions {
@@ -19,7 +28,11 @@ impl Default for CodecOptions {
             validate_checksums: global_config().validate_checksums(),
             store_empty_chunks: global_config().store_empty_chunks(),
             concurrent_target: global_config().codec_concurrent_target(),
+            chunk_concurrent_maximum: global_config().chunk_concurrent_maximum(),
+            range_coalesce_gap: global_config().range_coalesce_gap(),
             experimental_partial_encoding: global_config().experimental_partial_encoding(),
+            treat_corrupt_shards_as_missing: false,
+            thread_pool: global_config().codec_thread_pool().cloned(),
         }
     }
 }
@@ -38,7 +51,11 @@ impl CodecOptions {
             validate_checksums: self.validate_checksums,
             store_empty_chunks: self.store_empty_chunks,
             concurrent_target: self.concurrent_target,
+            chunk_concurrent_maximum: self.chunk_concurrent_maximum,
+            range_coalesce_gap: self.range_coalesce_gap,
             experimental_partial_encoding: self.experimental_partial_encoding,
+            treat_corrupt_shards_as_missing: self.treat_corrupt_shards_as_missing,
+            thread_pool: self.thread_pool.clone(),
         }
     }
 
@@ -78,6 +95,30 @@ impl CodecOptions {
         self
     }
 
+    /// Return the chunk concurrent maximum.
+    #[must_use]
+    pub fn chunk_concurrent_maximum(&self) -> usize {
+        self.chunk_concurrent_maximum
+    }
+
+    /// Set the chunk concurrent maximum.
+    pub fn set_chunk_concurrent_maximum(&mut self, chunk_concurrent_maximum: usize) -> &mut Self {
+        self.chunk_concurrent_maximum = chunk_concurrent_maximum;
+        self
+    }
+
+    /// Return the range coalesce gap.
+    #[must_use]
+    pub fn range_coalesce_gap(&self) -> Option<u64> {
+        self.range_coalesce_gap
+    }
+
+    /// Set the range coalesce gap.
+    pub fn set_range_coalesce_gap(&mut self, range_coalesce_gap: Option<u64>) -> &mut Self {
+        self.range_coalesce_gap = range_coalesce_gap;
+        self
+    }
+
     /// Return the experimental partial encoding setting.
     #[must_use]
     pub fn experimental_partial_encoding(&self) -> bool {
@@ -92,17 +133,65 @@ impl CodecOptions {
         self.experimental_partial_encoding = experimental_partial_encoding;
         self
     }
+
+    /// Return the treat corrupt shards as missing setting.
+    #[must_use]
+    pub fn treat_corrupt_shards_as_missing(&self) -> bool {
+        self.treat_corrupt_shards_as_missing
+    }
+
+    /// Set whether or not to treat shards with a corrupt index as missing.
+    ///
+    /// If enabled, array retrieval methods substitute the fill value for a chunk (shard) if its shard index is corrupt (i.e. [`CodecError::ShardIndexCorrupt`](super::CodecError::ShardIndexCorrupt)) rather than returning an error.
+    /// The affected chunks are recorded and can be retrieved with [`Array::corrupt_chunks`](crate::array::Array::corrupt_chunks).
+    pub fn set_treat_corrupt_shards_as_missing(
+        &mut self,
+        treat_corrupt_shards_as_missing: bool,
+    ) -> &mut Self {
+        self.treat_corrupt_shards_as_missing = treat_corrupt_shards_as_missing;
+        self
+    }
+
+    /// Return the codec thread pool.
+    #[must_use]
+    pub fn thread_pool(&self) -> Option<&Arc<ThreadPool>> {
+        self.thread_pool.as_ref()
+    }
+
+    /// Set the codec thread pool.
+    ///
+    /// If set, parallel codec encoding/decoding and array operations involving multiple chunks run in this thread pool rather than the current (e.g. global) [`rayon`] thread pool.
+    pub fn set_thread_pool(&mut self, thread_pool: Option<Arc<ThreadPool>>) -> &mut Self {
+        self.thread_pool = thread_pool;
+        self
+    }
+
+    /// Execute `op` within the codec thread pool if set, otherwise execute it in the current thread pool.
+    ///
+    /// See [`ThreadPool::install`].
+    pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        if let Some(thread_pool) = &self.thread_pool {
+            thread_pool.install(op)
+        } else {
+            op()
+        }
+    }
 }
 
 /// Builder for [`CodecOptions`].
 ///
 /// Default values for these options are set by the global [`Config`](crate::config::Config).
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct CodecOptionsBuilder {
     validate_checksums: bool,
     store_empty_chunks: bool,
     concurrent_target: usize,
+    chunk_concurrent_maximum: usize,
+    range_coalesce_gap: Option<u64>,
     experimental_partial_encoding: bool,
+    treat_corrupt_shards_as_missing: bool,
+    thread_pool: Option<Arc<ThreadPool>>,
 }
 
 impl Default for CodecOptionsBuilder {
@@ -119,7 +208,11 @@ impl CodecOptionsBuilder {
             validate_checksums: global_config().validate_checksums(),
             store_empty_chunks: global_config().store_empty_chunks(),
             concurrent_target: global_config().codec_concurrent_target(),
+            chunk_concurrent_maximum: global_config().chunk_concurrent_maximum(),
+            range_coalesce_gap: global_config().range_coalesce_gap(),
             experimental_partial_encoding: global_config().experimental_partial_encoding(),
+            treat_corrupt_shards_as_missing: false,
+            thread_pool: global_config().codec_thread_pool().cloned(),
         }
     }
 
@@ -130,7 +223,11 @@ impl CodecOptionsBuilder {
             validate_checksums: self.validate_checksums,
             store_empty_chunks: self.store_empty_chunks,
             concurrent_target: self.concurrent_target,
+            chunk_concurrent_maximum: self.chunk_concurrent_maximum,
+            range_coalesce_gap: self.range_coalesce_gap,
             experimental_partial_encoding: self.experimental_partial_encoding,
+            treat_corrupt_shards_as_missing: self.treat_corrupt_shards_as_missing,
+            thread_pool: self.thread_pool.clone(),
         }
     }
 
@@ -155,10 +252,47 @@ impl CodecOptionsBuilder {
         self
     }
 
+    /// Set the maximum number of chunks processed concurrently by array operations involving multiple chunks.
+    #[must_use]
+    pub fn chunk_concurrent_maximum(mut self, chunk_concurrent_maximum: usize) -> Self {
+        self.chunk_concurrent_maximum = chunk_concurrent_maximum;
+        self
+    }
+
+    /// Set the maximum gap in bytes between byte ranges that are coalesced into a single request when partial decoding.
+    ///
+    /// Byte ranges are not coalesced if [`None`].
+    #[must_use]
+    pub fn range_coalesce_gap(mut self, range_coalesce_gap: Option<u64>) -> Self {
+        self.range_coalesce_gap = range_coalesce_gap;
+        self
+    }
+
     /// Set whether or not to use experimental partial encoding.
     #[must_use]
     pub fn experimental_partial_encoding(mut self, experimental_partial_encoding: bool) -> Self {
         self.experimental_partial_encoding = experimental_partial_encoding;
         self
     }
+
+    /// Set whether or not to treat shards with a corrupt index as missing.
+    ///
+    /// See [`CodecOptions::set_treat_corrupt_shards_as_missing`].
+    #[must_use]
+    pub fn treat_corrupt_shards_as_missing(
+        mut self,
+        treat_corrupt_shards_as_missing: bool,
+    ) -> Self {
+        self.treat_corrupt_shards_as_missing = treat_corrupt_shards_as_missing;
+        self
+    }
+
+    /// Set the codec thread pool.
+    ///
+    /// See [`CodecOptions::set_thread_pool`].
+    #[must_use]
+    pub fn thread_pool(mut self, thread_pool: Option<Arc<ThreadPool>>) -> Self {
+        self.thread_pool = thread_pool;
+        self
+    }
 }
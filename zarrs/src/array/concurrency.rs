@@ -120,6 +120,8 @@ pub fn calc_concurrency_outer_inner(
 }
 
 /// Calculate the outer concurrency and inner options for a codec.
+///
+/// The outer (chunk) concurrency does not exceed the [`CodecOptions::chunk_concurrent_maximum`].
 #[must_use]
 pub fn concurrency_chunks_and_codec(
     concurrency_target: usize,
@@ -129,8 +131,11 @@ pub fn concurrency_chunks_and_codec(
 ) -> (usize, CodecOptions) {
     // core::cmp::minmax https://github.com/rust-lang/rust/issues/115939
     let chunk_concurrent_minimum = global_config().chunk_concurrent_minimum();
-    let min_concurrent_chunks = std::cmp::min(chunk_concurrent_minimum, num_chunks);
-    let max_concurrent_chunks = std::cmp::max(chunk_concurrent_minimum, num_chunks);
+    let chunk_concurrent_maximum = codec_options.chunk_concurrent_maximum().max(1);
+    let min_concurrent_chunks =
+        std::cmp::min(chunk_concurrent_minimum, num_chunks).min(chunk_concurrent_maximum);
+    let max_concurrent_chunks =
+        std::cmp::max(chunk_concurrent_minimum, num_chunks).min(chunk_concurrent_maximum);
     let (self_concurrent_limit, codec_concurrent_limit) = calc_concurrency_outer_inner(
         concurrency_target,
         &RecommendedConcurrency::new(min_concurrent_chunks..max_concurrent_chunks),
@@ -2,19 +2,25 @@
 //!
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#data-types>.
 
+use std::num::NonZeroU32;
+
 use derive_more::From;
 use half::{bf16, f16};
 use thiserror::Error;
 
 use crate::metadata::v3::array::{
-    data_type::{DataTypeMetadataV3, DataTypeSize},
+    data_type::{DataTypeMetadataV3, DataTypeSize, NumpyTimeUnit, StructuredDataTypeConfiguration},
     fill_value::{
         bfloat16_to_fill_value, float16_to_fill_value, float32_to_fill_value,
-        float64_to_fill_value, FillValueFloat, FillValueMetadataV3,
+        float64_to_fill_value, FillValueFloat, FillValueMetadataV3, HexString,
     },
 };
+use crate::plugin::close_matches;
+
+use super::{codec::array_to_bytes::bytes::reverse_endianness, Endianness, FillValue};
 
-use super::FillValue;
+mod minifloat;
+use minifloat::Minifloat;
 
 /// A data type.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -47,18 +53,117 @@ pub enum DataType {
     Float64,
     /// `bfloat16` brain floating point data type: sign bit, 5 bits exponent, 10 bits mantissa.
     BFloat16,
+    /// `float4_e2m1fn` 4-bit floating point: sign bit, 2 bits exponent, 1 bit mantissa, no infinity or NaN.
+    Float4E2M1FN,
+    /// `float6_e2m3fn` 6-bit floating point: sign bit, 2 bits exponent, 3 bits mantissa, no infinity or NaN.
+    Float6E2M3FN,
+    /// `float6_e3m2fn` 6-bit floating point: sign bit, 3 bits exponent, 2 bits mantissa, no infinity or NaN.
+    Float6E3M2FN,
+    /// `float8_e3m4` 8-bit floating point: sign bit, 3 bits exponent, 4 bits mantissa, IEEE 754 special values.
+    Float8E3M4,
+    /// `float8_e4m3` 8-bit floating point: sign bit, 4 bits exponent, 3 bits mantissa, IEEE 754 special values.
+    Float8E4M3,
+    /// `float8_e4m3b11fnuz` 8-bit floating point: sign bit, 4 bits exponent (bias 11), 3 bits mantissa, no infinity or negative zero.
+    Float8E4M3B11FNUZ,
+    /// `float8_e4m3fnuz` 8-bit floating point: sign bit, 4 bits exponent, 3 bits mantissa, no infinity or negative zero.
+    Float8E4M3FNUZ,
+    /// `float8_e5m2` 8-bit floating point: sign bit, 5 bits exponent, 2 bits mantissa, IEEE 754 special values.
+    Float8E5M2,
+    /// `float8_e5m2fnuz` 8-bit floating point: sign bit, 5 bits exponent, 2 bits mantissa, no infinity or negative zero.
+    Float8E5M2FNUZ,
+    /// `float8_e8m0fnu` 8-bit unsigned power of two: 8 bits exponent, no zero or infinity.
+    Float8E8M0FNU,
+    /// `complex_bfloat16` real and complex components are each bfloat16 brain floating point.
+    ComplexBFloat16,
+    /// `complex_float16` real and complex components are each IEEE 754 half-precision floating point.
+    ComplexFloat16,
+    /// `complex_float32` real and complex components are each IEEE 754 single-precision floating point.
+    ComplexFloat32,
     /// `complex64` real and complex components are each IEEE 754 single-precision floating point.
     Complex64,
     /// `complex128` real and complex components are each IEEE 754 double-precision floating point.
     Complex128,
     /// `r*` raw bits, variable size given by *, limited to be a multiple of 8.
     RawBits(usize), // the stored usize is the size in bytes
+    /// `numpy.datetime64` a 64-bit signed integer count of `scale_factor` `unit`s since the Unix epoch.
+    ///
+    /// The minimum value ([`i64::MIN`]) is the `NaT` (not a time) sentinel.
+    NumpyDateTime64 {
+        /// The time unit.
+        unit: NumpyTimeUnit,
+        /// The number of `unit`s per increment.
+        scale_factor: NonZeroU32,
+    },
+    /// `numpy.timedelta64` a 64-bit signed integer count of `scale_factor` `unit`s.
+    ///
+    /// The minimum value ([`i64::MIN`]) is the `NaT` (not a time) sentinel.
+    NumpyTimeDelta64 {
+        /// The time unit.
+        unit: NumpyTimeUnit,
+        /// The number of `unit`s per increment.
+        scale_factor: NonZeroU32,
+    },
+    /// `structured` a fixed-size record of named fields.
+    ///
+    /// Records can be accessed as raw bytes (e.g. `[u8; N]` elements), with the field layout given by the configuration.
+    Structured(StructuredDataTypeConfiguration),
+    /// `fixed_length_utf8` a UTF-8 encoded string of fixed length in bytes.
+    ///
+    /// Strings shorter than the length are padded with trailing null bytes, which are trimmed when elements are retrieved as [`String`]s.
+    /// Storing a string that is longer than the length is an error.
+    FixedLengthUtf8(usize), // the stored usize is the length in bytes
+    /// `fixed_length_bytes` binary data of fixed length in bytes.
+    ///
+    /// Values shorter than the length are padded with trailing null bytes, which are trimmed when elements are retrieved as [`Vec<u8>`]s.
+    /// Elements can also be accessed untrimmed as `[u8; N]`.
+    FixedLengthBytes(usize), // the stored usize is the length in bytes
     /// A UTF-8 encoded string.
     String,
     /// Variable-sized binary data.
     Bytes,
 }
 
+/// Names of the data types supported by [`DataType::from_metadata`], excluding raw bits.
+///
+/// These are suggested as close matches of unsupported data type names.
+const KNOWN_DATA_TYPE_NAMES: &[&str] = &[
+    "bool",
+    "int8",
+    "int16",
+    "int32",
+    "int64",
+    "uint8",
+    "uint16",
+    "uint32",
+    "uint64",
+    "float16",
+    "float32",
+    "float64",
+    "bfloat16",
+    "float4_e2m1fn",
+    "float6_e2m3fn",
+    "float6_e3m2fn",
+    "float8_e3m4",
+    "float8_e4m3",
+    "float8_e4m3b11fnuz",
+    "float8_e4m3fnuz",
+    "float8_e5m2",
+    "float8_e5m2fnuz",
+    "float8_e8m0fnu",
+    "complex_bfloat16",
+    "complex_float16",
+    "complex_float32",
+    "complex64",
+    "complex128",
+    "string",
+    "bytes",
+    "numpy.datetime64",
+    "numpy.timedelta64",
+    "structured",
+    "fixed_length_utf8",
+    "fixed_length_bytes",
+];
+
 /// An unsupported data type error.
 #[derive(Debug, Error, From)]
 #[error("unsupported data type {_0}")]
@@ -71,7 +176,7 @@ pub struct IncompatibleFillValueMetadataError(String, FillValueMetadataV3);
 
 /// A fill value incompatibility error.
 #[derive(Debug, Error)]
-#[error("incompatible fill value {1} for data type {0}")]
+#[error("incompatible fill value {1} ({size} bytes) for data type {0}", size = _1.size())]
 pub struct IncompatibleFillValueError(String, FillValue);
 
 impl IncompatibleFillValueError {
@@ -100,9 +205,27 @@ impl DataType {
             Self::Float32 => "float32",
             Self::Float64 => "float64",
             Self::BFloat16 => "bfloat16",
+            Self::Float4E2M1FN => "float4_e2m1fn",
+            Self::Float6E2M3FN => "float6_e2m3fn",
+            Self::Float6E3M2FN => "float6_e3m2fn",
+            Self::Float8E3M4 => "float8_e3m4",
+            Self::Float8E4M3 => "float8_e4m3",
+            Self::Float8E4M3B11FNUZ => "float8_e4m3b11fnuz",
+            Self::Float8E4M3FNUZ => "float8_e4m3fnuz",
+            Self::Float8E5M2 => "float8_e5m2",
+            Self::Float8E5M2FNUZ => "float8_e5m2fnuz",
+            Self::Float8E8M0FNU => "float8_e8m0fnu",
+            Self::ComplexBFloat16 => "complex_bfloat16",
+            Self::ComplexFloat16 => "complex_float16",
+            Self::ComplexFloat32 => "complex_float32",
             Self::Complex64 => "complex64",
             Self::Complex128 => "complex128",
             Self::RawBits(_usize) => "r*",
+            Self::NumpyDateTime64 { .. } => "numpy.datetime64",
+            Self::NumpyTimeDelta64 { .. } => "numpy.timedelta64",
+            Self::Structured(_) => "structured",
+            Self::FixedLengthUtf8(_) => "fixed_length_utf8",
+            Self::FixedLengthBytes(_) => "fixed_length_bytes",
             Self::String => "string",
             Self::Bytes => "bytes",
             // Self::Extension(extension) => extension.identifier(),
@@ -136,24 +259,82 @@ impl DataType {
             Self::Float32 => DataTypeMetadataV3::Float32,
             Self::Float64 => DataTypeMetadataV3::Float64,
             Self::BFloat16 => DataTypeMetadataV3::BFloat16,
+            Self::Float4E2M1FN => DataTypeMetadataV3::Float4E2M1FN,
+            Self::Float6E2M3FN => DataTypeMetadataV3::Float6E2M3FN,
+            Self::Float6E3M2FN => DataTypeMetadataV3::Float6E3M2FN,
+            Self::Float8E3M4 => DataTypeMetadataV3::Float8E3M4,
+            Self::Float8E4M3 => DataTypeMetadataV3::Float8E4M3,
+            Self::Float8E4M3B11FNUZ => DataTypeMetadataV3::Float8E4M3B11FNUZ,
+            Self::Float8E4M3FNUZ => DataTypeMetadataV3::Float8E4M3FNUZ,
+            Self::Float8E5M2 => DataTypeMetadataV3::Float8E5M2,
+            Self::Float8E5M2FNUZ => DataTypeMetadataV3::Float8E5M2FNUZ,
+            Self::Float8E8M0FNU => DataTypeMetadataV3::Float8E8M0FNU,
+            Self::ComplexBFloat16 => DataTypeMetadataV3::ComplexBFloat16,
+            Self::ComplexFloat16 => DataTypeMetadataV3::ComplexFloat16,
+            Self::ComplexFloat32 => DataTypeMetadataV3::ComplexFloat32,
             Self::Complex64 => DataTypeMetadataV3::Complex64,
             Self::Complex128 => DataTypeMetadataV3::Complex128,
             Self::RawBits(size) => DataTypeMetadataV3::RawBits(*size),
+            Self::NumpyDateTime64 { unit, scale_factor } => DataTypeMetadataV3::NumpyDateTime64 {
+                unit: *unit,
+                scale_factor: *scale_factor,
+            },
+            Self::NumpyTimeDelta64 { unit, scale_factor } => DataTypeMetadataV3::NumpyTimeDelta64 {
+                unit: *unit,
+                scale_factor: *scale_factor,
+            },
+            Self::Structured(configuration) => {
+                DataTypeMetadataV3::Structured(configuration.clone())
+            }
+            Self::FixedLengthUtf8(length_bytes) => {
+                DataTypeMetadataV3::FixedLengthUtf8(*length_bytes)
+            }
+            Self::FixedLengthBytes(length_bytes) => {
+                DataTypeMetadataV3::FixedLengthBytes(*length_bytes)
+            }
             Self::String => DataTypeMetadataV3::String,
             Self::Bytes => DataTypeMetadataV3::Bytes,
         }
     }
 
     /// Returns the [`DataTypeSize`].
+    ///
+    /// Sub-byte data types (e.g. [`DataType::Float4E2M1FN`]) have a size of one byte.
     #[must_use]
     pub const fn size(&self) -> DataTypeSize {
         match self {
-            Self::Bool | Self::Int8 | Self::UInt8 => DataTypeSize::Fixed(1),
+            Self::Bool
+            | Self::Int8
+            | Self::UInt8
+            | Self::Float4E2M1FN
+            | Self::Float6E2M3FN
+            | Self::Float6E3M2FN
+            | Self::Float8E3M4
+            | Self::Float8E4M3
+            | Self::Float8E4M3B11FNUZ
+            | Self::Float8E4M3FNUZ
+            | Self::Float8E5M2
+            | Self::Float8E5M2FNUZ
+            | Self::Float8E8M0FNU => DataTypeSize::Fixed(1),
             Self::Int16 | Self::UInt16 | Self::Float16 | Self::BFloat16 => DataTypeSize::Fixed(2),
-            Self::Int32 | Self::UInt32 | Self::Float32 => DataTypeSize::Fixed(4),
-            Self::Int64 | Self::UInt64 | Self::Float64 | Self::Complex64 => DataTypeSize::Fixed(8),
+            Self::Int32
+            | Self::UInt32
+            | Self::Float32
+            | Self::ComplexFloat16
+            | Self::ComplexBFloat16 => DataTypeSize::Fixed(4),
+            Self::Int64
+            | Self::UInt64
+            | Self::Float64
+            | Self::ComplexFloat32
+            | Self::Complex64
+            | Self::NumpyDateTime64 { .. }
+            | Self::NumpyTimeDelta64 { .. } => DataTypeSize::Fixed(8),
             Self::Complex128 => DataTypeSize::Fixed(16),
             Self::RawBits(size) => DataTypeSize::Fixed(*size),
+            Self::Structured(configuration) => DataTypeSize::Fixed(configuration.size()),
+            Self::FixedLengthUtf8(length_bytes) | Self::FixedLengthBytes(length_bytes) => {
+                DataTypeSize::Fixed(*length_bytes)
+            }
             Self::String | Self::Bytes => DataTypeSize::Variable,
             // Self::Extension(extension) => extension.size(),
         }
@@ -168,6 +349,34 @@ impl DataType {
         }
     }
 
+    /// Returns the number of bits of a sub-byte data type, otherwise returns [`None`].
+    ///
+    /// Elements of a sub-byte data type occupy the least significant bits of a byte.
+    #[must_use]
+    pub const fn sub_byte_bits(&self) -> Option<u32> {
+        match self.minifloat() {
+            Some(minifloat) if minifloat.bits() < 8 => Some(minifloat.bits()),
+            _ => None,
+        }
+    }
+
+    /// Returns the low-precision floating point format of the data type, if any.
+    const fn minifloat(&self) -> Option<Minifloat> {
+        match self {
+            Self::Float4E2M1FN => Some(Minifloat::FLOAT4_E2M1FN),
+            Self::Float6E2M3FN => Some(Minifloat::FLOAT6_E2M3FN),
+            Self::Float6E3M2FN => Some(Minifloat::FLOAT6_E3M2FN),
+            Self::Float8E3M4 => Some(Minifloat::FLOAT8_E3M4),
+            Self::Float8E4M3 => Some(Minifloat::FLOAT8_E4M3),
+            Self::Float8E4M3B11FNUZ => Some(Minifloat::FLOAT8_E4M3B11FNUZ),
+            Self::Float8E4M3FNUZ => Some(Minifloat::FLOAT8_E4M3FNUZ),
+            Self::Float8E5M2 => Some(Minifloat::FLOAT8_E5M2),
+            Self::Float8E5M2FNUZ => Some(Minifloat::FLOAT8_E5M2FNUZ),
+            Self::Float8E8M0FNU => Some(Minifloat::FLOAT8_E8M0FNU),
+            _ => None,
+        }
+    }
+
     /// Create a data type from metadata.
     ///
     /// # Errors
@@ -188,13 +397,59 @@ impl DataType {
             DataTypeMetadataV3::Float32 => Ok(Self::Float32),
             DataTypeMetadataV3::Float64 => Ok(Self::Float64),
             DataTypeMetadataV3::BFloat16 => Ok(Self::BFloat16),
+            DataTypeMetadataV3::Float4E2M1FN => Ok(Self::Float4E2M1FN),
+            DataTypeMetadataV3::Float6E2M3FN => Ok(Self::Float6E2M3FN),
+            DataTypeMetadataV3::Float6E3M2FN => Ok(Self::Float6E3M2FN),
+            DataTypeMetadataV3::Float8E3M4 => Ok(Self::Float8E3M4),
+            DataTypeMetadataV3::Float8E4M3 => Ok(Self::Float8E4M3),
+            DataTypeMetadataV3::Float8E4M3B11FNUZ => Ok(Self::Float8E4M3B11FNUZ),
+            DataTypeMetadataV3::Float8E4M3FNUZ => Ok(Self::Float8E4M3FNUZ),
+            DataTypeMetadataV3::Float8E5M2 => Ok(Self::Float8E5M2),
+            DataTypeMetadataV3::Float8E5M2FNUZ => Ok(Self::Float8E5M2FNUZ),
+            DataTypeMetadataV3::Float8E8M0FNU => Ok(Self::Float8E8M0FNU),
+            DataTypeMetadataV3::ComplexBFloat16 => Ok(Self::ComplexBFloat16),
+            DataTypeMetadataV3::ComplexFloat16 => Ok(Self::ComplexFloat16),
+            DataTypeMetadataV3::ComplexFloat32 => Ok(Self::ComplexFloat32),
             DataTypeMetadataV3::Complex64 => Ok(Self::Complex64),
             DataTypeMetadataV3::Complex128 => Ok(Self::Complex128),
             DataTypeMetadataV3::RawBits(size) => Ok(Self::RawBits(*size)),
+            DataTypeMetadataV3::NumpyDateTime64 { unit, scale_factor } => {
+                Ok(Self::NumpyDateTime64 {
+                    unit: *unit,
+                    scale_factor: *scale_factor,
+                })
+            }
+            DataTypeMetadataV3::NumpyTimeDelta64 { unit, scale_factor } => {
+                Ok(Self::NumpyTimeDelta64 {
+                    unit: *unit,
+                    scale_factor: *scale_factor,
+                })
+            }
+            DataTypeMetadataV3::Structured(configuration) => {
+                for field in configuration.fields() {
+                    Self::from_metadata(&field.data_type)?;
+                }
+                Ok(Self::Structured(configuration.clone()))
+            }
+            DataTypeMetadataV3::FixedLengthUtf8(length_bytes) => {
+                Ok(Self::FixedLengthUtf8(*length_bytes))
+            }
+            DataTypeMetadataV3::FixedLengthBytes(length_bytes) => {
+                Ok(Self::FixedLengthBytes(*length_bytes))
+            }
             DataTypeMetadataV3::String => Ok(Self::String),
             DataTypeMetadataV3::Bytes => Ok(Self::Bytes),
             DataTypeMetadataV3::Unknown(metadata) => {
-                Err(UnsupportedDataTypeError(metadata.to_string()))
+                let suggestions =
+                    close_matches(metadata.name(), KNOWN_DATA_TYPE_NAMES.iter().copied());
+                if suggestions.is_empty() {
+                    Err(UnsupportedDataTypeError(metadata.to_string()))
+                } else {
+                    Err(UnsupportedDataTypeError(format!(
+                        "{metadata} (did you mean {}?)",
+                        suggestions.join(", ")
+                    )))
+                }
             }
             _ => Err(UnsupportedDataTypeError(metadata.to_string())),
         }
@@ -205,6 +460,7 @@ impl DataType {
     /// # Errors
     ///
     /// Returns [`IncompatibleFillValueMetadataError`] if the fill value is incompatible with the data type.
+    #[allow(clippy::too_many_lines)]
     pub fn fill_value_from_metadata(
         &self,
         fill_value: &FillValueMetadataV3,
@@ -225,7 +481,39 @@ impl DataType {
             Self::Float32 => Ok(FV::from(fill_value.try_as_float::<f32>().ok_or_else(err)?)),
             Self::Float64 => Ok(FV::from(fill_value.try_as_float::<f64>().ok_or_else(err)?)),
             Self::BFloat16 => Ok(FV::from(fill_value.try_as_bfloat16().ok_or_else(err)?)),
-            Self::Complex64 => {
+            Self::Float4E2M1FN
+            | Self::Float6E2M3FN
+            | Self::Float6E3M2FN
+            | Self::Float8E3M4
+            | Self::Float8E4M3
+            | Self::Float8E4M3B11FNUZ
+            | Self::Float8E4M3FNUZ
+            | Self::Float8E5M2
+            | Self::Float8E5M2FNUZ
+            | Self::Float8E8M0FNU => {
+                let minifloat = self.minifloat().ok_or_else(err)?;
+                let bits = match fill_value {
+                    FillValueMetadataV3::Float(FillValueFloat::HexString(hex_string)) => {
+                        match hex_string.as_be_bytes() {
+                            [bits] if minifloat.is_valid(*bits) => Some(*bits),
+                            _ => None,
+                        }
+                    }
+                    _ => fill_value
+                        .try_as_float::<f64>()
+                        .and_then(|value| minifloat.encode(value)),
+                };
+                Ok(FV::new(vec![bits.ok_or_else(err)?]))
+            }
+            Self::ComplexBFloat16 => {
+                let (re, im) = fill_value.try_as_bfloat16_pair().ok_or_else(err)?;
+                Ok(FV::from(num::complex::Complex::<bf16>::new(re, im)))
+            }
+            Self::ComplexFloat16 => {
+                let (re, im) = fill_value.try_as_float16_pair().ok_or_else(err)?;
+                Ok(FV::from(num::complex::Complex::<f16>::new(re, im)))
+            }
+            Self::ComplexFloat32 | Self::Complex64 => {
                 let (re, im) = fill_value.try_as_float_pair::<f32>().ok_or_else(err)?;
                 Ok(FV::from(num::complex::Complex32::new(re, im)))
             }
@@ -233,6 +521,10 @@ impl DataType {
                 let (re, im) = fill_value.try_as_float_pair::<f64>().ok_or_else(err)?;
                 Ok(FV::from(num::complex::Complex64::new(re, im)))
             }
+            Self::NumpyDateTime64 { .. } | Self::NumpyTimeDelta64 { .. } => match fill_value {
+                FillValueMetadataV3::String(string) if string == "NaT" => Ok(FV::from(i64::MIN)),
+                _ => Ok(FV::from(fill_value.try_as_int::<i64>().ok_or_else(err)?)),
+            },
             Self::RawBits(size) => {
                 if let FillValueMetadataV3::ByteArray(bytes) = fill_value {
                     if bytes.len() == *size {
@@ -241,6 +533,35 @@ impl DataType {
                 }
                 Err(err())
             }
+            Self::Structured(configuration) => {
+                // The fill value metadata is the little endian bytes of a record
+                if let FillValueMetadataV3::ByteArray(bytes) = fill_value {
+                    if bytes.len() == configuration.size() {
+                        let mut bytes = bytes.clone();
+                        if !Endianness::Little.is_native() {
+                            reverse_endianness(&mut bytes, self);
+                        }
+                        return Ok(FillValue::new(bytes));
+                    }
+                }
+                Err(err())
+            }
+            Self::FixedLengthUtf8(length_bytes) => {
+                if let FillValueMetadataV3::String(string) = fill_value {
+                    if let Some(bytes) = pad_fixed_length(string.as_bytes(), *length_bytes) {
+                        return Ok(FillValue::new(bytes));
+                    }
+                }
+                Err(err())
+            }
+            Self::FixedLengthBytes(length_bytes) => {
+                if let FillValueMetadataV3::ByteArray(bytes) = fill_value {
+                    if let Some(bytes) = pad_fixed_length(bytes, *length_bytes) {
+                        return Ok(FillValue::new(bytes));
+                    }
+                }
+                Err(err())
+            }
             Self::Bytes => {
                 if let FillValueMetadataV3::ByteArray(bytes) = fill_value {
                     Ok(FillValue::new(bytes.clone()))
@@ -271,6 +592,7 @@ impl DataType {
     /// Panics if the metadata cannot be created from the fill value.
     /// This would indicate an implementation error with a data type.
     #[must_use]
+    #[allow(clippy::too_many_lines)]
     pub fn metadata_fill_value(&self, fill_value: &FillValue) -> FillValueMetadataV3 {
         let bytes = fill_value.as_ne_bytes();
         match self {
@@ -284,7 +606,9 @@ impl DataType {
             Self::Int32 => {
                 FillValueMetadataV3::Int(i64::from(i32::from_ne_bytes(bytes.try_into().unwrap())))
             }
-            Self::Int64 => FillValueMetadataV3::Int(i64::from_ne_bytes(bytes.try_into().unwrap())),
+            Self::Int64 | Self::NumpyDateTime64 { .. } | Self::NumpyTimeDelta64 { .. } => {
+                FillValueMetadataV3::Int(i64::from_ne_bytes(bytes.try_into().unwrap()))
+            }
             Self::UInt8 => {
                 FillValueMetadataV3::UInt(u64::from(u8::from_ne_bytes(bytes.try_into().unwrap())))
             }
@@ -311,7 +635,36 @@ impl DataType {
                 let fill_value = bf16::from_ne_bytes(fill_value.as_ne_bytes().try_into().unwrap());
                 FillValueMetadataV3::Float(bfloat16_to_fill_value(fill_value))
             }
-            Self::Complex64 => {
+            Self::Float4E2M1FN
+            | Self::Float6E2M3FN
+            | Self::Float6E3M2FN
+            | Self::Float8E3M4
+            | Self::Float8E4M3
+            | Self::Float8E4M3B11FNUZ
+            | Self::Float8E4M3FNUZ
+            | Self::Float8E5M2
+            | Self::Float8E5M2FNUZ
+            | Self::Float8E8M0FNU => {
+                let minifloat = self.minifloat().unwrap();
+                let bits = bytes[0];
+                let value = minifloat.decode(bits);
+                if value.is_nan() && minifloat.nan() != Some(bits) {
+                    FillValueMetadataV3::Float(HexString::new(vec![bits]).into())
+                } else {
+                    FillValueMetadataV3::Float(float64_to_fill_value(value))
+                }
+            }
+            Self::ComplexBFloat16 => {
+                let re = bf16::from_ne_bytes(bytes[0..2].try_into().unwrap());
+                let im = bf16::from_ne_bytes(bytes[2..4].try_into().unwrap());
+                FillValueMetadataV3::Complex(bfloat16_to_fill_value(re), bfloat16_to_fill_value(im))
+            }
+            Self::ComplexFloat16 => {
+                let re = f16::from_ne_bytes(bytes[0..2].try_into().unwrap());
+                let im = f16::from_ne_bytes(bytes[2..4].try_into().unwrap());
+                FillValueMetadataV3::Complex(float16_to_fill_value(re), float16_to_fill_value(im))
+            }
+            Self::ComplexFloat32 | Self::Complex64 => {
                 let re = f32::from_ne_bytes(bytes[0..4].try_into().unwrap());
                 let im = f32::from_ne_bytes(bytes[4..8].try_into().unwrap());
                 FillValueMetadataV3::Complex(float32_to_fill_value(re), float32_to_fill_value(im))
@@ -325,6 +678,20 @@ impl DataType {
                 debug_assert_eq!(fill_value.as_ne_bytes().len(), *size);
                 FillValueMetadataV3::ByteArray(fill_value.as_ne_bytes().to_vec())
             }
+            Self::Structured(_) => {
+                let mut bytes = bytes.to_vec();
+                if !Endianness::Little.is_native() {
+                    reverse_endianness(&mut bytes, self);
+                }
+                FillValueMetadataV3::ByteArray(bytes)
+            }
+            Self::FixedLengthUtf8(_) => match std::str::from_utf8(trim_fixed_length(bytes)) {
+                Ok(string) => FillValueMetadataV3::String(string.to_string()),
+                Err(_) => FillValueMetadataV3::ByteArray(bytes.to_vec()),
+            },
+            Self::FixedLengthBytes(_) => {
+                FillValueMetadataV3::ByteArray(trim_fixed_length(bytes).to_vec())
+            }
             // DataType::Extension(extension) => extension.metadata_fill_value(fill_value),
             Self::String => FillValueMetadataV3::String(
                 String::from_utf8(fill_value.as_ne_bytes().to_vec()).unwrap(),
@@ -332,6 +699,64 @@ impl DataType {
             Self::Bytes => FillValueMetadataV3::ByteArray(fill_value.as_ne_bytes().to_vec()),
         }
     }
+
+    /// Format a fill value as a human-readable string.
+    ///
+    /// The fill value is decoded according to the data type.
+    /// Non-canonical `NaN`s are formatted as hex strings (e.g. `0x7fc00001`), complex numbers as `[re, im]`, and strings as quoted UTF-8 (with fixed length padding trimmed).
+    /// Raw bits, structured, fixed length bytes, and bytes fill values, and fill values that are incompatible with the data type, are formatted as a list of bytes.
+    #[must_use]
+    pub fn format_fill_value(&self, fill_value: &FillValue) -> String {
+        #[allow(clippy::cast_possible_truncation)]
+        fn format_float(float: &FillValueFloat, double_precision: bool) -> String {
+            match float {
+                FillValueFloat::Float(float) if double_precision => format!("{float:?}"),
+                // Lower precision floats are exactly representable as f32, which is formatted with fewer digits
+                FillValueFloat::Float(float) => format!("{:?}", *float as f32),
+                FillValueFloat::HexString(hex_string) => String::from(hex_string),
+                FillValueFloat::NonFinite(non_finite) => String::from(non_finite),
+            }
+        }
+
+        if self
+            .fixed_size()
+            .is_some_and(|size| size != fill_value.size())
+        {
+            return fill_value.to_string();
+        }
+        match self {
+            Self::NumpyDateTime64 { .. } | Self::NumpyTimeDelta64 { .. }
+                if fill_value.try_as::<i64>() == Some(i64::MIN) =>
+            {
+                "NaT".to_string()
+            }
+            Self::String => match std::str::from_utf8(fill_value.as_ne_bytes()) {
+                Ok(string) => format!("{string:?}"),
+                Err(_) => fill_value.to_string(),
+            },
+            Self::FixedLengthUtf8(_) => {
+                match std::str::from_utf8(trim_fixed_length(fill_value.as_ne_bytes())) {
+                    Ok(string) => format!("{string:?}"),
+                    Err(_) => fill_value.to_string(),
+                }
+            }
+            Self::RawBits(_) | Self::FixedLengthBytes(_) | Self::Bytes => fill_value.to_string(),
+            _ => match self.metadata_fill_value(fill_value) {
+                FillValueMetadataV3::Float(float) => {
+                    format_float(&float, matches!(self, Self::Float64))
+                }
+                FillValueMetadataV3::Complex(re, im) => {
+                    let double_precision = matches!(self, Self::Complex128);
+                    format!(
+                        "[{}, {}]",
+                        format_float(&re, double_precision),
+                        format_float(&im, double_precision)
+                    )
+                }
+                metadata => metadata.to_string(),
+            },
+        }
+    }
 }
 
 impl TryFrom<DataTypeMetadataV3> for DataType {
@@ -348,6 +773,27 @@ impl core::fmt::Display for DataType {
     }
 }
 
+/// Pad `bytes` with trailing null bytes to `length_bytes` bytes.
+///
+/// Returns [`None`] if `bytes` is longer than `length_bytes`.
+pub(crate) fn pad_fixed_length(bytes: &[u8], length_bytes: usize) -> Option<Vec<u8>> {
+    (bytes.len() <= length_bytes).then(|| {
+        let mut padded = Vec::with_capacity(length_bytes);
+        padded.extend_from_slice(bytes);
+        padded.resize(length_bytes, 0);
+        padded
+    })
+}
+
+/// Trim the trailing null bytes of a fixed length element.
+pub(crate) fn trim_fixed_length(bytes: &[u8]) -> &[u8] {
+    let length = bytes
+        .iter()
+        .rposition(|&byte| byte != 0)
+        .map_or(0, |i| i + 1);
+    &bytes[..length]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -783,6 +1229,319 @@ mod tests {
         assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
     }
 
+    #[test]
+    fn data_type_float8_e4m3() {
+        let json = r#""float8_e4m3""#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type, DataType::Float8E4M3);
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(1));
+        assert_eq!(data_type.sub_byte_bits(), None);
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("-1.5").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0xbc]);
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("2").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0x40]);
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""0x7f""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0x7f]);
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""NaN""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0x7c]);
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""-Infinity""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0xf8]);
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""0x7f00""#).unwrap();
+        assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn data_type_float8_e4m3fnuz() {
+        let json = r#""float8_e4m3fnuz""#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type, DataType::Float8E4M3FNUZ);
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""NaN""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0x80]);
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""Infinity""#).unwrap();
+        assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn data_type_float4_e2m1fn() {
+        let json = r#""float4_e2m1fn""#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type, DataType::Float4E2M1FN);
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(1));
+        assert_eq!(data_type.sub_byte_bits(), Some(4));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("-6.0").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0xf]);
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""0x0e""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [0xe]);
+
+        for invalid in [r#""0x1f""#, r#""NaN""#, "100.0"] {
+            let metadata = serde_json::from_str::<FillValueMetadataV3>(invalid).unwrap();
+            assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn data_type_low_precision_floats() {
+        for (name, data_type) in [
+            ("float4_e2m1fn", DataType::Float4E2M1FN),
+            ("float6_e2m3fn", DataType::Float6E2M3FN),
+            ("float6_e3m2fn", DataType::Float6E3M2FN),
+            ("float8_e3m4", DataType::Float8E3M4),
+            ("float8_e4m3", DataType::Float8E4M3),
+            ("float8_e4m3b11fnuz", DataType::Float8E4M3B11FNUZ),
+            ("float8_e4m3fnuz", DataType::Float8E4M3FNUZ),
+            ("float8_e5m2", DataType::Float8E5M2),
+            ("float8_e5m2fnuz", DataType::Float8E5M2FNUZ),
+            ("float8_e8m0fnu", DataType::Float8E8M0FNU),
+        ] {
+            let metadata: DataTypeMetadataV3 =
+                serde_json::from_str(&format!(r#""{name}""#)).unwrap();
+            assert_eq!(DataType::from_metadata(&metadata).unwrap(), data_type);
+            assert_eq!(data_type.name(), name);
+            assert_eq!(data_type.size(), DataTypeSize::Fixed(1));
+
+            let metadata = serde_json::from_str::<FillValueMetadataV3>("1.0").unwrap();
+            let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+            assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+        }
+    }
+
+    #[test]
+    fn data_type_numpy_datetime64() {
+        let json = r#"{"name":"numpy.datetime64","configuration":{"unit":"ns","scale_factor":1}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(
+            data_type,
+            DataType::NumpyDateTime64 {
+                unit: NumpyTimeUnit::Nanosecond,
+                scale_factor: NonZeroU32::new(1).unwrap(),
+            }
+        );
+        assert_eq!(data_type.name(), "numpy.datetime64");
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(8));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""NaT""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), i64::MIN.to_ne_bytes());
+        assert_eq!(
+            data_type.metadata_fill_value(&fill_value),
+            FillValueMetadataV3::Int(i64::MIN)
+        );
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("-1").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), (-1i64).to_ne_bytes());
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""1970-01-01""#).unwrap();
+        assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn data_type_numpy_timedelta64() {
+        let json =
+            r#"{"name":"numpy.timedelta64","configuration":{"unit":"us","scale_factor":10}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(
+            data_type,
+            DataType::NumpyTimeDelta64 {
+                unit: NumpyTimeUnit::Microsecond,
+                scale_factor: NonZeroU32::new(10).unwrap(),
+            }
+        );
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(8));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("0").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), 0i64.to_ne_bytes());
+    }
+
+    #[test]
+    fn data_type_numpy_time_invalid() {
+        for json in [
+            r#""numpy.datetime64""#,
+            r#"{"name":"numpy.datetime64","configuration":{"unit":"ns","scale_factor":0}}"#,
+            r#"{"name":"numpy.timedelta64","configuration":{"unit":"ks","scale_factor":1}}"#,
+            r#"{"name":"numpy.timedelta64","configuration":{"unit":"s","scale_factor":1,"a":1}}"#,
+        ] {
+            let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+            assert!(matches!(metadata, DataTypeMetadataV3::Unknown(_)));
+            assert!(DataType::from_metadata(&metadata).is_err());
+        }
+    }
+
+    #[test]
+    fn data_type_structured() {
+        let json = r#"{"name":"structured","configuration":{"fields":[{"name":"a","data_type":"int16","offset":0},{"name":"b","data_type":"complex64","offset":4}]}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type.name(), "structured");
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(12));
+
+        let metadata =
+            serde_json::from_str::<FillValueMetadataV3>("[1, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64]")
+                .unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        let bytes = fill_value.as_ne_bytes();
+        assert_eq!(i16::from_ne_bytes(bytes[0..2].try_into().unwrap()), 1);
+        assert_eq!(bytes[4..8], 1.0f32.to_ne_bytes());
+        assert_eq!(bytes[8..12], 2.0f32.to_ne_bytes());
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("[0, 0]").unwrap();
+        assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+
+        let json = r#"{"name":"structured","configuration":{"fields":[{"name":"a","data_type":{"name":"unknown"},"offset":0}]}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(DataType::from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn data_type_complex_bfloat16() {
+        let json = r#""complex_bfloat16""#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type, DataType::ComplexBFloat16);
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(4));
+
+        let metadata =
+            serde_json::from_str::<FillValueMetadataV3>(r#"[-7.0, "Infinity"]"#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            bf16::from_f32_const(-7.0)
+                .to_ne_bytes()
+                .iter()
+                .chain(bf16::INFINITY.to_ne_bytes().iter())
+                .copied()
+                .collect::<Vec<u8>>()
+        );
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#"["NaN", "0x7fc1"]"#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            ZARR_NAN_BF16
+                .to_ne_bytes()
+                .iter()
+                .chain(bf16::from_bits(0x7fc1).to_ne_bytes().iter())
+                .copied()
+                .collect::<Vec<u8>>()
+        );
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+    }
+
+    #[test]
+    fn data_type_complex_float16() {
+        let json = r#""complex_float16""#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type, DataType::ComplexFloat16);
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(4));
+
+        let metadata =
+            serde_json::from_str::<FillValueMetadataV3>(r#"[-7.0, "Infinity"]"#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            f16::from_f32_const(-7.0)
+                .to_ne_bytes()
+                .iter()
+                .chain(f16::INFINITY.to_ne_bytes().iter())
+                .copied()
+                .collect::<Vec<u8>>()
+        );
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#"["NaN", "0x7e01"]"#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            ZARR_NAN_F16
+                .to_ne_bytes()
+                .iter()
+                .chain(f16::from_bits(0x7e01).to_ne_bytes().iter())
+                .copied()
+                .collect::<Vec<u8>>()
+        );
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+    }
+
+    #[test]
+    fn data_type_complex_float32() {
+        let json = r#""complex_float32""#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type, DataType::ComplexFloat32);
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(8));
+
+        let metadata =
+            serde_json::from_str::<FillValueMetadataV3>(r#"[-7.0, "Infinity"]"#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            (-7.0f32)
+                .to_ne_bytes()
+                .iter()
+                .chain(f32::INFINITY.to_ne_bytes().iter())
+                .copied()
+                .collect::<Vec<u8>>()
+        );
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+
+        let metadata =
+            serde_json::from_str::<FillValueMetadataV3>(r#"["NaN", "0x7fc00001"]"#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(
+            fill_value.as_ne_bytes(),
+            ZARR_NAN_F32
+                .to_ne_bytes()
+                .iter()
+                .chain(f32::from_bits(0x7fc0_0001).to_ne_bytes().iter())
+                .copied()
+                .collect::<Vec<u8>>()
+        );
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+    }
+
     #[test]
     fn data_type_complex128() {
         let json = r#""complex128""#;
@@ -806,6 +1565,56 @@ mod tests {
         assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
     }
 
+    #[test]
+    fn data_type_fixed_length_utf8() {
+        let json = r#"{"name":"fixed_length_utf8","configuration":{"length_bytes":8}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type, DataType::FixedLengthUtf8(8));
+        assert_eq!(data_type.name(), "fixed_length_utf8");
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(8));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""ab€""#).unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), b"ab\xe2\x82\xac\0\0\0");
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+        assert_eq!(data_type.format_fill_value(&fill_value), r#""ab€""#);
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>(r#""€€€""#).unwrap();
+        assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("[97]").unwrap();
+        assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+
+        let json = r#"{"name":"fixed_length_utf8","configuration":{"length_bytes":0}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        assert!(DataType::from_metadata(&metadata).is_err());
+    }
+
+    #[test]
+    fn data_type_fixed_length_bytes() {
+        let json = r#"{"name":"fixed_length_bytes","configuration":{"length_bytes":4}}"#;
+        let metadata: DataTypeMetadataV3 = serde_json::from_str(json).unwrap();
+        let data_type = DataType::from_metadata(&metadata).unwrap();
+        assert_eq!(json, serde_json::to_string(&data_type.metadata()).unwrap());
+        assert_eq!(data_type, DataType::FixedLengthBytes(4));
+        assert_eq!(data_type.name(), "fixed_length_bytes");
+        assert_eq!(data_type.size(), DataTypeSize::Fixed(4));
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("[1, 2]").unwrap();
+        let fill_value = data_type.fill_value_from_metadata(&metadata).unwrap();
+        assert_eq!(fill_value.as_ne_bytes(), [1, 2, 0, 0]);
+        assert_eq!(metadata, data_type.metadata_fill_value(&fill_value));
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("[1, 2, 0, 0]").unwrap();
+        assert_eq!(
+            data_type.fill_value_from_metadata(&metadata).unwrap(),
+            fill_value
+        );
+
+        let metadata = serde_json::from_str::<FillValueMetadataV3>("[1, 2, 3, 4, 5]").unwrap();
+        assert!(data_type.fill_value_from_metadata(&metadata).is_err());
+    }
+
     #[test]
     fn data_type_r8() {
         let json = r#""r8""#;
@@ -1040,7 +1849,112 @@ mod tests {
         let err = IncompatibleFillValueError::new("bool".to_string(), FillValue::from(1.0f32));
         assert_eq!(
             err.to_string(),
-            "incompatible fill value [0, 0, 128, 63] for data type bool"
+            "incompatible fill value [0, 0, 128, 63] (4 bytes) for data type bool"
+        );
+    }
+
+    #[test]
+    fn format_fill_value() {
+        fn format(data_type: &str, fill_value: &str) -> String {
+            let data_type =
+                DataType::from_metadata(&serde_json::from_str(data_type).unwrap()).unwrap();
+            let fill_value = data_type
+                .fill_value_from_metadata(&serde_json::from_str(fill_value).unwrap())
+                .unwrap();
+            data_type.format_fill_value(&fill_value)
+        }
+
+        assert_eq!(format(r#""bool""#, "true"), "true");
+        assert_eq!(format(r#""int8""#, "-8"), "-8");
+        assert_eq!(format(r#""int16""#, "-16"), "-16");
+        assert_eq!(format(r#""int32""#, "-32"), "-32");
+        assert_eq!(format(r#""int64""#, "-64"), "-64");
+        assert_eq!(format(r#""uint8""#, "8"), "8");
+        assert_eq!(format(r#""uint16""#, "16"), "16");
+        assert_eq!(format(r#""uint32""#, "32"), "32");
+        assert_eq!(
+            format(r#""uint64""#, "18446744073709551615"),
+            "18446744073709551615"
+        );
+        assert_eq!(format(r#""float16""#, "1.5"), "1.5");
+        assert_eq!(format(r#""float16""#, r#""-Infinity""#), "-Infinity");
+        assert_eq!(format(r#""float16""#, r#""0x7e01""#), "0x7e01");
+        assert_eq!(format(r#""float32""#, "0.1"), "0.1");
+        assert_eq!(format(r#""float32""#, "1"), "1.0");
+        assert_eq!(format(r#""float32""#, r#""NaN""#), "NaN");
+        assert_eq!(format(r#""float32""#, r#""0x7fc00001""#), "0x7fc00001");
+        assert_eq!(format(r#""float64""#, "0.1"), "0.1");
+        assert_eq!(format(r#""float64""#, r#""Infinity""#), "Infinity");
+        assert_eq!(
+            format(r#""float64""#, r#""0x7ff8000000000001""#),
+            "0x7ff8000000000001"
+        );
+        assert_eq!(format(r#""bfloat16""#, "-2"), "-2.0");
+        assert_eq!(format(r#""bfloat16""#, r#""0x7fc1""#), "0x7fc1");
+        assert_eq!(format(r#""float4_e2m1fn""#, "1.5"), "1.5");
+        assert_eq!(format(r#""float6_e2m3fn""#, "0.125"), "0.125");
+        assert_eq!(format(r#""float6_e3m2fn""#, "-0.25"), "-0.25");
+        assert_eq!(format(r#""float8_e3m4""#, "1.25"), "1.25");
+        assert_eq!(format(r#""float8_e4m3""#, r#""NaN""#), "NaN");
+        assert_eq!(format(r#""float8_e4m3b11fnuz""#, "2"), "2.0");
+        assert_eq!(format(r#""float8_e4m3fnuz""#, "-4"), "-4.0");
+        assert_eq!(format(r#""float8_e5m2""#, r#""-Infinity""#), "-Infinity");
+        assert_eq!(format(r#""float8_e5m2fnuz""#, "0.5"), "0.5");
+        assert_eq!(format(r#""float8_e8m0fnu""#, "4"), "4.0");
+        assert_eq!(format(r#""complex_bfloat16""#, "[1, -2]"), "[1.0, -2.0]");
+        assert_eq!(
+            format(r#""complex_float16""#, r#"["NaN", 0.5]"#),
+            "[NaN, 0.5]"
+        );
+        assert_eq!(format(r#""complex_float32""#, "[0.1, 0]"), "[0.1, 0.0]");
+        assert_eq!(
+            format(r#""complex64""#, r#"["0x7fc00001", "Infinity"]"#),
+            "[0x7fc00001, Infinity]"
+        );
+        assert_eq!(format(r#""complex128""#, "[0.1, -0.2]"), "[0.1, -0.2]");
+        assert_eq!(format(r#""r16""#, "[1, 2]"), "[1, 2]");
+        assert_eq!(
+            format(
+                r#"{"name":"numpy.datetime64","configuration":{"unit":"s","scale_factor":1}}"#,
+                "1700000000"
+            ),
+            "1700000000"
+        );
+        assert_eq!(
+            format(
+                r#"{"name":"numpy.datetime64","configuration":{"unit":"s","scale_factor":1}}"#,
+                r#""NaT""#
+            ),
+            "NaT"
+        );
+        assert_eq!(
+            format(
+                r#"{"name":"numpy.timedelta64","configuration":{"unit":"ms","scale_factor":10}}"#,
+                "-5"
+            ),
+            "-5"
+        );
+        assert_eq!(
+            format(
+                r#"{"name":"structured","configuration":{"fields":[{"name":"a","data_type":"int16","offset":0}]}}"#,
+                "[1, 0]"
+            ),
+            "[1, 0]"
+        );
+        assert_eq!(
+            format(r#""string""#, r#""hello \"world\"""#),
+            r#""hello \"world\"""#
+        );
+        assert_eq!(format(r#""bytes""#, "[1, 2, 3]"), "[1, 2, 3]");
+
+        // Invalid fill values are formatted as bytes
+        assert_eq!(
+            DataType::Bool.format_fill_value(&FillValue::from(1.0f32)),
+            "[0, 0, 128, 63]"
+        );
+        assert_eq!(
+            DataType::String.format_fill_value(&FillValue::from([255u8])),
+            "[255]"
         );
     }
 
@@ -1081,6 +1995,15 @@ mod tests {
         assert!(DataType::Complex128
             .fill_value_from_metadata(&metadata)
             .is_err());
+        assert!(DataType::ComplexBFloat16
+            .fill_value_from_metadata(&metadata)
+            .is_err());
+        assert!(DataType::ComplexFloat16
+            .fill_value_from_metadata(&metadata)
+            .is_err());
+        assert!(DataType::ComplexFloat32
+            .fill_value_from_metadata(&metadata)
+            .is_err());
         assert!(DataType::RawBits(1)
             .fill_value_from_metadata(&metadata)
             .is_err());
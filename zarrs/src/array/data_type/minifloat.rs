@@ -0,0 +1,318 @@
+//! Low-precision floating point formats.
+//!
+//! The formats and their special values follow the [`ml_dtypes`](https://github.com/jax-ml/ml_dtypes) definitions.
+
+/// The special values of a low-precision floating point format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum MinifloatKind {
+    /// IEEE 754-like: an all-ones exponent encodes infinity (zero mantissa) or NaN.
+    Ieee,
+    /// Finite: no infinity or NaN.
+    Finite,
+    /// Finite with unsigned zero: no infinity, and the negative zero bit pattern encodes NaN.
+    FiniteUnsignedZero,
+    /// Unsigned exponent only: no zero, sign, or infinity, and an all-ones exponent encodes NaN.
+    ExponentOnly,
+}
+
+/// A low-precision floating point format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Minifloat {
+    pub(crate) exponent_bits: u32,
+    pub(crate) mantissa_bits: u32,
+    pub(crate) bias: i32,
+    pub(crate) kind: MinifloatKind,
+}
+
+impl Minifloat {
+    pub(crate) const FLOAT4_E2M1FN: Self = Self::new(2, 1, 1, MinifloatKind::Finite);
+    pub(crate) const FLOAT6_E2M3FN: Self = Self::new(2, 3, 1, MinifloatKind::Finite);
+    pub(crate) const FLOAT6_E3M2FN: Self = Self::new(3, 2, 3, MinifloatKind::Finite);
+    pub(crate) const FLOAT8_E3M4: Self = Self::new(3, 4, 3, MinifloatKind::Ieee);
+    pub(crate) const FLOAT8_E4M3: Self = Self::new(4, 3, 7, MinifloatKind::Ieee);
+    pub(crate) const FLOAT8_E4M3B11FNUZ: Self =
+        Self::new(4, 3, 11, MinifloatKind::FiniteUnsignedZero);
+    pub(crate) const FLOAT8_E4M3FNUZ: Self = Self::new(4, 3, 8, MinifloatKind::FiniteUnsignedZero);
+    pub(crate) const FLOAT8_E5M2: Self = Self::new(5, 2, 15, MinifloatKind::Ieee);
+    pub(crate) const FLOAT8_E5M2FNUZ: Self = Self::new(5, 2, 16, MinifloatKind::FiniteUnsignedZero);
+    pub(crate) const FLOAT8_E8M0FNU: Self = Self::new(8, 0, 127, MinifloatKind::ExponentOnly);
+
+    const fn new(exponent_bits: u32, mantissa_bits: u32, bias: i32, kind: MinifloatKind) -> Self {
+        Self {
+            exponent_bits,
+            mantissa_bits,
+            bias,
+            kind,
+        }
+    }
+
+    /// The number of bits in the format.
+    pub(crate) const fn bits(&self) -> u32 {
+        match self.kind {
+            MinifloatKind::ExponentOnly => self.exponent_bits + self.mantissa_bits,
+            _ => 1 + self.exponent_bits + self.mantissa_bits,
+        }
+    }
+
+    const fn sign_mask(&self) -> u8 {
+        match self.kind {
+            MinifloatKind::ExponentOnly => 0,
+            _ => 1 << (self.exponent_bits + self.mantissa_bits),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    const fn exponent_max(&self) -> u8 {
+        ((1u16 << self.exponent_bits) - 1) as u8
+    }
+
+    /// Returns true if `bits` is a valid bit pattern of the format.
+    pub(crate) const fn is_valid(&self, bits: u8) -> bool {
+        self.bits() == 8 || bits >> self.bits() == 0
+    }
+
+    /// The canonical NaN bit pattern, if the format has NaN.
+    pub(crate) const fn nan(&self) -> Option<u8> {
+        match self.kind {
+            MinifloatKind::Ieee => {
+                Some((self.exponent_max() << self.mantissa_bits) | (1 << (self.mantissa_bits - 1)))
+            }
+            MinifloatKind::Finite => None,
+            MinifloatKind::FiniteUnsignedZero => Some(self.sign_mask()),
+            MinifloatKind::ExponentOnly => Some(u8::MAX),
+        }
+    }
+
+    /// Returns true if `bits` is a NaN.
+    pub(crate) const fn is_nan(&self, bits: u8) -> bool {
+        let exponent = (bits >> self.mantissa_bits) & self.exponent_max();
+        let mantissa = bits & ((1 << self.mantissa_bits) - 1);
+        match self.kind {
+            MinifloatKind::Ieee => exponent == self.exponent_max() && mantissa != 0,
+            MinifloatKind::Finite => false,
+            MinifloatKind::FiniteUnsignedZero => bits == self.sign_mask(),
+            MinifloatKind::ExponentOnly => bits == u8::MAX,
+        }
+    }
+
+    /// Convert the bit pattern `bits` to a [`f64`].
+    pub(crate) fn decode(self, bits: u8) -> f64 {
+        if self.is_nan(bits) {
+            return f64::NAN;
+        }
+        let negative = bits & self.sign_mask() != 0;
+        let exponent = (bits >> self.mantissa_bits) & self.exponent_max();
+        let mantissa = f64::from(bits & ((1 << self.mantissa_bits) - 1));
+        let mantissa_scale = f64::from(1u32 << self.mantissa_bits);
+        let magnitude = if self.kind == MinifloatKind::Ieee && exponent == self.exponent_max() {
+            f64::INFINITY
+        } else if exponent == 0 && self.kind != MinifloatKind::ExponentOnly {
+            // Subnormal
+            mantissa / mantissa_scale * 2f64.powi(1 - self.bias)
+        } else {
+            (1.0 + mantissa / mantissa_scale) * 2f64.powi(i32::from(exponent) - self.bias)
+        };
+        if negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Convert `value` to the nearest bit pattern, rounding ties to even.
+    ///
+    /// Values beyond the largest finite value round to infinity if the format supports infinity.
+    /// Returns [`None`] if `value` cannot be represented (e.g. NaN without NaN support, or a value out of range without infinity support).
+    pub(crate) fn encode(self, value: f64) -> Option<u8> {
+        if value.is_nan() {
+            return self.nan();
+        } else if self.kind == MinifloatKind::ExponentOnly && value <= 0.0 {
+            return None;
+        }
+        let finite = (0..=u8::MAX >> (8 - self.bits()))
+            .filter(|&bits| !self.is_nan(bits))
+            .map(|bits| (bits, self.decode(bits)))
+            .filter(|(_, finite)| finite.is_finite());
+        let max = finite
+            .clone()
+            .map(|(_, finite)| finite)
+            .max_by(f64::total_cmp)
+            .expect("formats have finite values");
+        let below_max = finite
+            .clone()
+            .map(|(_, finite)| finite)
+            .filter(|&finite| finite < max)
+            .max_by(f64::total_cmp)
+            .unwrap_or(max);
+
+        // Overflow
+        if value.abs() >= max + (max - below_max) / 2.0 {
+            return (self.kind == MinifloatKind::Ieee).then(|| {
+                let infinity = self.exponent_max() << self.mantissa_bits;
+                if value.is_sign_negative() {
+                    infinity | self.sign_mask()
+                } else {
+                    infinity
+                }
+            });
+        }
+
+        // Round to the nearest representable value, preferring a matching sign and then an even mantissa
+        let negative = value.is_sign_negative();
+        finite
+            .min_by(|(bits_a, a), (bits_b, b)| {
+                (a - value)
+                    .abs()
+                    .total_cmp(&(b - value).abs())
+                    .then_with(|| {
+                        let sign_a = (bits_a & self.sign_mask() != 0) != negative;
+                        let sign_b = (bits_b & self.sign_mask() != 0) != negative;
+                        sign_a.cmp(&sign_b)
+                    })
+                    .then_with(|| (bits_a & 1).cmp(&(bits_b & 1)))
+            })
+            .map(|(bits, _)| bits)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    /// Check every bit pattern of `minifloat` against a table of positive values.
+    fn check_table(minifloat: Minifloat, positive: &[f64]) {
+        for (bits, &value) in positive.iter().enumerate() {
+            let bits = u8::try_from(bits).unwrap();
+            assert_eq!(minifloat.decode(bits), value, "{bits:#x}");
+            assert_eq!(minifloat.encode(value), Some(bits), "{value}");
+            if minifloat.sign_mask() != 0 {
+                let bits = bits | minifloat.sign_mask();
+                assert_eq!(minifloat.decode(bits), -value, "{bits:#x}");
+            }
+        }
+    }
+
+    #[test]
+    fn minifloat_float4_e2m1fn() {
+        let minifloat = Minifloat::FLOAT4_E2M1FN;
+        check_table(minifloat, &[0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0]);
+        assert_eq!(minifloat.encode(-0.0), Some(0x8));
+        assert_eq!(minifloat.encode(2.5), Some(0x4)); // tie to even
+        assert_eq!(minifloat.encode(5.0), Some(0x6)); // tie to even
+        assert_eq!(minifloat.encode(6.9), Some(0x7));
+        assert_eq!(minifloat.encode(7.0), None);
+        assert_eq!(minifloat.encode(f64::INFINITY), None);
+        assert_eq!(minifloat.encode(f64::NAN), None);
+        assert!(minifloat.is_valid(0xf));
+        assert!(!minifloat.is_valid(0x10));
+    }
+
+    #[test]
+    fn minifloat_float6_e2m3fn() {
+        let minifloat = Minifloat::FLOAT6_E2M3FN;
+        assert_eq!(minifloat.decode(0x01), 0.125);
+        assert_eq!(minifloat.decode(0x08), 1.0);
+        assert_eq!(minifloat.decode(0x1f), 7.5);
+        assert_eq!(minifloat.decode(0x3f), -7.5);
+        assert_eq!(minifloat.encode(7.5), Some(0x1f));
+        assert_eq!(minifloat.encode(-0.0625), Some(0x20)); // tie to even (-0)
+        assert!(!minifloat.is_valid(0x40));
+    }
+
+    #[test]
+    fn minifloat_float6_e3m2fn() {
+        let minifloat = Minifloat::FLOAT6_E3M2FN;
+        assert_eq!(minifloat.decode(0x01), 0.0625);
+        assert_eq!(minifloat.decode(0x0c), 1.0);
+        assert_eq!(minifloat.decode(0x1f), 28.0);
+        assert_eq!(minifloat.encode(28.0), Some(0x1f));
+        assert_eq!(minifloat.encode(-1.0), Some(0x2c));
+    }
+
+    #[test]
+    fn minifloat_float8_e3m4() {
+        let minifloat = Minifloat::FLOAT8_E3M4;
+        assert_eq!(minifloat.decode(0x01), 0.015_625);
+        assert_eq!(minifloat.decode(0x30), 1.0);
+        assert_eq!(minifloat.decode(0x6f), 15.5);
+        assert_eq!(minifloat.decode(0x70), f64::INFINITY);
+        assert_eq!(minifloat.decode(0xf0), f64::NEG_INFINITY);
+        assert!(minifloat.decode(0x71).is_nan());
+        assert_eq!(minifloat.encode(16.0), Some(0x70));
+    }
+
+    #[test]
+    fn minifloat_float8_e4m3() {
+        let minifloat = Minifloat::FLOAT8_E4M3;
+        assert_eq!(minifloat.decode(0x01), 2f64.powi(-9));
+        assert_eq!(minifloat.decode(0x38), 1.0);
+        assert_eq!(minifloat.decode(0x77), 240.0);
+        assert_eq!(minifloat.decode(0x78), f64::INFINITY);
+        assert!(minifloat.decode(0x7f).is_nan());
+        assert_eq!(minifloat.encode(0.3), Some(0x2a)); // 0.3125
+        assert_eq!(minifloat.encode(247.0), Some(0x77));
+        assert_eq!(minifloat.encode(248.0), Some(0x78));
+        assert_eq!(minifloat.encode(-1000.0), Some(0xf8));
+        assert_eq!(minifloat.encode(f64::NAN), Some(0x7c));
+        assert_eq!(minifloat.encode(-0.0), Some(0x80));
+    }
+
+    #[test]
+    fn minifloat_float8_e4m3fnuz() {
+        let minifloat = Minifloat::FLOAT8_E4M3FNUZ;
+        assert_eq!(minifloat.decode(0x01), 2f64.powi(-10));
+        assert_eq!(minifloat.decode(0x40), 1.0);
+        assert_eq!(minifloat.decode(0x7f), 240.0);
+        assert_eq!(minifloat.decode(0xff), -240.0);
+        assert!(minifloat.decode(0x80).is_nan());
+        assert_eq!(minifloat.encode(-0.0), Some(0x00));
+        assert_eq!(minifloat.encode(f64::NAN), Some(0x80));
+        assert_eq!(minifloat.encode(f64::INFINITY), None);
+        assert_eq!(minifloat.encode(1000.0), None);
+    }
+
+    #[test]
+    fn minifloat_float8_e4m3b11fnuz() {
+        let minifloat = Minifloat::FLOAT8_E4M3B11FNUZ;
+        assert_eq!(minifloat.decode(0x01), 2f64.powi(-13));
+        assert_eq!(minifloat.decode(0x58), 1.0);
+        assert_eq!(minifloat.decode(0x7f), 30.0);
+        assert!(minifloat.decode(0x80).is_nan());
+    }
+
+    #[test]
+    fn minifloat_float8_e5m2() {
+        let minifloat = Minifloat::FLOAT8_E5M2;
+        assert_eq!(minifloat.decode(0x01), 2f64.powi(-16));
+        assert_eq!(minifloat.decode(0x3c), 1.0);
+        assert_eq!(minifloat.decode(0x7b), 57344.0);
+        assert_eq!(minifloat.decode(0x7c), f64::INFINITY);
+        assert!(minifloat.decode(0x7d).is_nan());
+        assert_eq!(minifloat.encode(f64::NEG_INFINITY), Some(0xfc));
+        assert_eq!(minifloat.encode(f64::NAN), Some(0x7e));
+    }
+
+    #[test]
+    fn minifloat_float8_e5m2fnuz() {
+        let minifloat = Minifloat::FLOAT8_E5M2FNUZ;
+        assert_eq!(minifloat.decode(0x01), 2f64.powi(-17));
+        assert_eq!(minifloat.decode(0x40), 1.0);
+        assert_eq!(minifloat.decode(0x7f), 57344.0);
+        assert!(minifloat.decode(0x80).is_nan());
+    }
+
+    #[test]
+    fn minifloat_float8_e8m0fnu() {
+        let minifloat = Minifloat::FLOAT8_E8M0FNU;
+        assert_eq!(minifloat.decode(0x00), 2f64.powi(-127));
+        assert_eq!(minifloat.decode(0x7f), 1.0);
+        assert_eq!(minifloat.decode(0xfe), 2f64.powi(127));
+        assert!(minifloat.decode(0xff).is_nan());
+        assert_eq!(minifloat.encode(1.0), Some(0x7f));
+        assert_eq!(minifloat.encode(0.25), Some(0x7d));
+        assert_eq!(minifloat.encode(0.0), None);
+        assert_eq!(minifloat.encode(-1.0), None);
+        assert_eq!(minifloat.encode(f64::NAN), Some(0xff));
+    }
+}
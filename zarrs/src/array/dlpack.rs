@@ -0,0 +1,460 @@
+//! [DLPack](https://dmlc.github.io/dlpack/latest/) tensor interoperability.
+//!
+//! This module defines the `DLPack` C ABI (`dlpack.h` v0.8) and a [`ManagedTensor`] that owns a [`DLManagedTensor`].
+//! [`Array::retrieve_chunk_dlpack`](crate::array::Array::retrieve_chunk_dlpack) and [`Array::retrieve_array_subset_dlpack`](crate::array::Array::retrieve_array_subset_dlpack) decode directly into the buffer of a tensor without copying.
+//! [`Array::store_array_subset_dlpack`](crate::array::Array::store_array_subset_dlpack) encodes and stores a tensor.
+//!
+//! A tensor can be passed to other `DLPack` consumers with [`ManagedTensor::into_raw`] and received from producers with [`ManagedTensor::from_raw`].
+//!
+//! The following data types are supported:
+//!  - `bool`,
+//!  - `int8`, `int16`, `int32`, `int64`, `uint8`, `uint16`, `uint32`, `uint64`,
+//!  - `float16`, `float32`, `float64`, `bfloat16`, and
+//!  - `complex_float16`, `complex_float32`, `complex64`, `complex128`.
+
+use std::{alloc::Layout, ffi::c_void, ptr::NonNull};
+
+use super::{ArrayError, DataType};
+
+/// The alignment in bytes of the data of tensors created by `zarrs`.
+///
+/// `DLPack` requires that the data pointer is aligned to 256 bytes on some devices, and this is also suitable for SIMD on the CPU.
+pub const DLPACK_ALIGNMENT: usize = 256;
+
+/// A `DLPack` device (`DLDevice`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDevice {
+    /// The device type (`DLDeviceType`).
+    pub device_type: i32,
+    /// The device index.
+    pub device_id: i32,
+}
+
+impl DLDevice {
+    /// The `kDLCPU` device type.
+    pub const DEVICE_TYPE_CPU: i32 = 1;
+
+    /// The CPU device.
+    pub const CPU: Self = Self {
+        device_type: Self::DEVICE_TYPE_CPU,
+        device_id: 0,
+    };
+}
+
+/// A `DLPack` data type (`DLDataType`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DLDataType {
+    /// The type code (`DLDataTypeCode`).
+    pub code: u8,
+    /// The number of bits of each lane.
+    pub bits: u8,
+    /// The number of lanes.
+    pub lanes: u16,
+}
+
+impl DLDataType {
+    /// The `kDLInt` type code.
+    pub const CODE_INT: u8 = 0;
+    /// The `kDLUInt` type code.
+    pub const CODE_UINT: u8 = 1;
+    /// The `kDLFloat` type code.
+    pub const CODE_FLOAT: u8 = 2;
+    /// The `kDLOpaqueHandle` type code.
+    pub const CODE_OPAQUE_HANDLE: u8 = 3;
+    /// The `kDLBfloat` type code.
+    pub const CODE_BFLOAT: u8 = 4;
+    /// The `kDLComplex` type code.
+    pub const CODE_COMPLEX: u8 = 5;
+    /// The `kDLBool` type code.
+    pub const CODE_BOOL: u8 = 6;
+
+    /// Create a single lane data type.
+    #[must_use]
+    pub const fn new(code: u8, bits: u8) -> Self {
+        Self {
+            code,
+            bits,
+            lanes: 1,
+        }
+    }
+
+    /// Return the `DLPack` data type of `data_type`, or [`None`] if it is unsupported.
+    #[must_use]
+    pub fn from_data_type(data_type: &DataType) -> Option<Self> {
+        match data_type {
+            DataType::Bool => Some(Self::new(Self::CODE_BOOL, 8)),
+            DataType::Int8 => Some(Self::new(Self::CODE_INT, 8)),
+            DataType::Int16 => Some(Self::new(Self::CODE_INT, 16)),
+            DataType::Int32 => Some(Self::new(Self::CODE_INT, 32)),
+            DataType::Int64 => Some(Self::new(Self::CODE_INT, 64)),
+            DataType::UInt8 => Some(Self::new(Self::CODE_UINT, 8)),
+            DataType::UInt16 => Some(Self::new(Self::CODE_UINT, 16)),
+            DataType::UInt32 => Some(Self::new(Self::CODE_UINT, 32)),
+            DataType::UInt64 => Some(Self::new(Self::CODE_UINT, 64)),
+            DataType::Float16 => Some(Self::new(Self::CODE_FLOAT, 16)),
+            DataType::Float32 => Some(Self::new(Self::CODE_FLOAT, 32)),
+            DataType::Float64 => Some(Self::new(Self::CODE_FLOAT, 64)),
+            DataType::BFloat16 => Some(Self::new(Self::CODE_BFLOAT, 16)),
+            DataType::ComplexFloat16 => Some(Self::new(Self::CODE_COMPLEX, 32)),
+            DataType::ComplexFloat32 | DataType::Complex64 => {
+                Some(Self::new(Self::CODE_COMPLEX, 64))
+            }
+            DataType::Complex128 => Some(Self::new(Self::CODE_COMPLEX, 128)),
+            _ => None,
+        }
+    }
+
+    /// Return the size in bytes of an element of this data type.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        (usize::from(self.bits) * usize::from(self.lanes)).div_ceil(8)
+    }
+}
+
+/// A `DLPack` tensor (`DLTensor`).
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLTensor {
+    /// The opaque data pointer.
+    pub data: *mut c_void,
+    /// The device of the tensor.
+    pub device: DLDevice,
+    /// The number of dimensions.
+    pub ndim: i32,
+    /// The data type.
+    pub dtype: DLDataType,
+    /// The shape of the tensor, with `ndim` elements.
+    pub shape: *mut i64,
+    /// The strides of the tensor in elements, with `ndim` elements, or null if the tensor is compact and row-major.
+    pub strides: *mut i64,
+    /// The offset in bytes to the beginning of the data.
+    pub byte_offset: u64,
+}
+
+/// A `DLPack` managed tensor (`DLManagedTensor`).
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLManagedTensor {
+    /// The tensor.
+    pub dl_tensor: DLTensor,
+    /// The context of the producer of the tensor.
+    pub manager_ctx: *mut c_void,
+    /// The destructor of the managed tensor.
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+/// An owned `DLPack` managed tensor.
+///
+/// The deleter of the managed tensor is called on drop.
+#[derive(Debug)]
+pub struct ManagedTensor(NonNull<DLManagedTensor>);
+
+impl ManagedTensor {
+    /// Create a tensor on the CPU with `shape` and `dtype` that owns `data`.
+    ///
+    /// The strides are those of a compact row-major tensor.
+    pub(crate) fn new(data: AlignedBuffer, shape: &[u64], dtype: DLDataType) -> Self {
+        let shape: Vec<i64> = shape
+            .iter()
+            .map(|&size| i64::try_from(size).expect("shape fits in i64"))
+            .collect();
+        let mut strides = vec![1; shape.len()];
+        for i in (0..shape.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * shape[i + 1];
+        }
+        let mut context = Box::new(ManagedTensorContext {
+            data,
+            shape,
+            strides,
+        });
+        let dl_tensor = DLTensor {
+            data: context.data.as_mut_ptr().cast(),
+            device: DLDevice::CPU,
+            ndim: i32::try_from(context.shape.len()).expect("dimensionality fits in i32"),
+            dtype,
+            shape: context.shape.as_mut_ptr(),
+            strides: context.strides.as_mut_ptr(),
+            byte_offset: 0,
+        };
+        let managed_tensor = Box::new(DLManagedTensor {
+            dl_tensor,
+            manager_ctx: Box::into_raw(context).cast(),
+            deleter: Some(managed_tensor_deleter),
+        });
+        Self(NonNull::from(Box::leak(managed_tensor)))
+    }
+
+    /// Take ownership of a managed tensor from a `DLPack` producer.
+    ///
+    /// Returns [`None`] if `managed_tensor` is null.
+    ///
+    /// # Safety
+    /// `managed_tensor` must point to a valid [`DLManagedTensor`] that is not used elsewhere once owned.
+    #[must_use]
+    pub unsafe fn from_raw(managed_tensor: *mut DLManagedTensor) -> Option<Self> {
+        NonNull::new(managed_tensor).map(Self)
+    }
+
+    /// Release ownership of the managed tensor, for example to pass it to a `DLPack` consumer.
+    ///
+    /// The consumer is responsible for calling its deleter.
+    #[must_use]
+    pub fn into_raw(self) -> *mut DLManagedTensor {
+        let managed_tensor = self.0.as_ptr();
+        std::mem::forget(self);
+        managed_tensor
+    }
+
+    /// Return the underlying [`DLTensor`].
+    #[must_use]
+    pub fn dl_tensor(&self) -> &DLTensor {
+        // SAFETY: the managed tensor is valid while owned
+        unsafe { &self.0.as_ref().dl_tensor }
+    }
+
+    /// Return the device of the tensor.
+    #[must_use]
+    pub fn device(&self) -> DLDevice {
+        self.dl_tensor().device
+    }
+
+    /// Return the data type of the tensor.
+    #[must_use]
+    pub fn dtype(&self) -> DLDataType {
+        self.dl_tensor().dtype
+    }
+
+    /// Return the shape of the tensor.
+    #[must_use]
+    pub fn shape(&self) -> &[i64] {
+        let dl_tensor = self.dl_tensor();
+        let ndim = usize::try_from(dl_tensor.ndim).unwrap_or_default();
+        if ndim == 0 || dl_tensor.shape.is_null() {
+            &[]
+        } else {
+            // SAFETY: the shape of a valid tensor has ndim elements
+            unsafe { std::slice::from_raw_parts(dl_tensor.shape, ndim) }
+        }
+    }
+
+    /// Return the strides of the tensor in elements, or [`None`] if the tensor is compact and row-major.
+    #[must_use]
+    pub fn strides(&self) -> Option<&[i64]> {
+        let dl_tensor = self.dl_tensor();
+        let ndim = usize::try_from(dl_tensor.ndim).unwrap_or_default();
+        if dl_tensor.strides.is_null() {
+            None
+        } else if ndim == 0 {
+            Some(&[])
+        } else {
+            // SAFETY: the strides of a valid tensor have ndim elements
+            Some(unsafe { std::slice::from_raw_parts(dl_tensor.strides, ndim) })
+        }
+    }
+
+    /// Returns true if the tensor is compact and row-major.
+    #[must_use]
+    pub fn is_contiguous(&self) -> bool {
+        let Some(strides) = self.strides() else {
+            return true;
+        };
+        let mut stride_expected = 1;
+        for (&size, &stride) in std::iter::zip(self.shape(), strides).rev() {
+            if size != 1 && stride != stride_expected {
+                return false;
+            }
+            stride_expected *= size;
+        }
+        true
+    }
+
+    /// Return the number of elements in the tensor.
+    ///
+    /// Returns [`None`] if the shape is invalid.
+    #[must_use]
+    pub fn num_elements(&self) -> Option<u64> {
+        self.shape().iter().try_fold(1u64, |num_elements, &size| {
+            num_elements.checked_mul(u64::try_from(size).ok()?)
+        })
+    }
+
+    /// Return the bytes of a contiguous tensor on the CPU.
+    ///
+    /// # Errors
+    /// Returns [`ArrayError::UnsupportedMethod`] if the tensor is not on the CPU, is not contiguous, or has an invalid shape.
+    pub fn as_bytes(&self) -> Result<&[u8], ArrayError> {
+        let unsupported = |reason: &str| {
+            Err(ArrayError::UnsupportedMethod(format!(
+                "cannot access the bytes of a DLPack tensor that {reason}"
+            )))
+        };
+        if self.device().device_type != DLDevice::DEVICE_TYPE_CPU {
+            return unsupported("is not on the CPU");
+        }
+        if !self.is_contiguous() {
+            return unsupported("is not compact and row-major");
+        }
+        let Some(size) = self
+            .num_elements()
+            .and_then(|num_elements| usize::try_from(num_elements).ok())
+            .and_then(|num_elements| num_elements.checked_mul(self.dtype().size()))
+        else {
+            return unsupported("has an invalid shape");
+        };
+        let dl_tensor = self.dl_tensor();
+        if size == 0 {
+            Ok(&[])
+        } else if dl_tensor.data.is_null() {
+            unsupported("has no data")
+        } else {
+            // SAFETY: a valid contiguous tensor on the CPU has `size` bytes from its data pointer and byte offset
+            unsafe {
+                let data = dl_tensor
+                    .data
+                    .cast::<u8>()
+                    .add(usize::try_from(dl_tensor.byte_offset).unwrap_or_default());
+                Ok(std::slice::from_raw_parts(data, size))
+            }
+        }
+    }
+}
+
+impl Drop for ManagedTensor {
+    fn drop(&mut self) {
+        // SAFETY: the managed tensor is valid while owned, and is not used after its deleter is called
+        unsafe {
+            if let Some(deleter) = self.0.as_ref().deleter {
+                deleter(self.0.as_ptr());
+            }
+        }
+    }
+}
+
+/// The context of a managed tensor created by `zarrs`.
+struct ManagedTensorContext {
+    data: AlignedBuffer,
+    shape: Vec<i64>,
+    strides: Vec<i64>,
+}
+
+unsafe extern "C" fn managed_tensor_deleter(managed_tensor: *mut DLManagedTensor) {
+    // SAFETY: the managed tensor and its context were created by ManagedTensor::new
+    unsafe {
+        let managed_tensor = Box::from_raw(managed_tensor);
+        drop(Box::from_raw(
+            managed_tensor.manager_ctx.cast::<ManagedTensorContext>(),
+        ));
+    }
+}
+
+/// A zero-initialised byte buffer aligned to [`DLPACK_ALIGNMENT`].
+pub(crate) struct AlignedBuffer {
+    data: NonNull<u8>,
+    len: usize,
+}
+
+impl AlignedBuffer {
+    /// Allocate a zero-initialised buffer of `len` bytes.
+    pub(crate) fn new_zeroed(len: usize) -> Self {
+        let layout = Self::layout(len);
+        // SAFETY: the layout has a non-zero size
+        let data = unsafe { std::alloc::alloc_zeroed(layout) };
+        let Some(data) = NonNull::new(data) else {
+            std::alloc::handle_alloc_error(layout)
+        };
+        Self { data, len }
+    }
+
+    fn layout(len: usize) -> Layout {
+        Layout::from_size_align(len.max(1), DLPACK_ALIGNMENT).expect("valid layout")
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: the buffer has `len` initialised bytes
+        unsafe { std::slice::from_raw_parts_mut(self.data.as_ptr(), self.len) }
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.data.as_ptr()
+    }
+}
+
+impl Drop for AlignedBuffer {
+    fn drop(&mut self) {
+        // SAFETY: the buffer was allocated with this layout
+        unsafe { std::alloc::dealloc(self.data.as_ptr(), Self::layout(self.len)) }
+    }
+}
+
+/// Return the `DLPack` data type of `data_type`.
+pub(crate) fn dlpack_data_type(data_type: &DataType) -> Result<DLDataType, ArrayError> {
+    DLDataType::from_data_type(data_type).ok_or_else(|| {
+        ArrayError::UnsupportedMethod(format!(
+            "the data type {data_type} is not supported by DLPack"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dlpack_data_type() {
+        assert_eq!(
+            DLDataType::from_data_type(&DataType::Int16),
+            Some(DLDataType::new(DLDataType::CODE_INT, 16))
+        );
+        assert_eq!(
+            DLDataType::from_data_type(&DataType::BFloat16),
+            Some(DLDataType::new(DLDataType::CODE_BFLOAT, 16))
+        );
+        assert_eq!(
+            DLDataType::from_data_type(&DataType::Complex128),
+            Some(DLDataType::new(DLDataType::CODE_COMPLEX, 128))
+        );
+        assert_eq!(DLDataType::from_data_type(&DataType::String), None);
+        assert_eq!(DLDataType::new(DLDataType::CODE_BOOL, 8).size(), 1);
+    }
+
+    #[test]
+    fn dlpack_managed_tensor() {
+        let mut data = AlignedBuffer::new_zeroed(24);
+        data.as_mut_slice()[0] = 1;
+        let tensor = ManagedTensor::new(data, &[2, 3], DLDataType::new(DLDataType::CODE_INT, 32));
+        assert_eq!(tensor.dl_tensor().data as usize % DLPACK_ALIGNMENT, 0);
+        assert_eq!(tensor.device(), DLDevice::CPU);
+        assert_eq!(tensor.shape(), &[2, 3]);
+        assert_eq!(tensor.strides(), Some([3, 1].as_slice()));
+        assert!(tensor.is_contiguous());
+        assert_eq!(tensor.num_elements(), Some(6));
+        assert_eq!(tensor.as_bytes().unwrap().len(), 24);
+        assert_eq!(tensor.as_bytes().unwrap()[0], 1);
+
+        // Round trip through the raw managed tensor
+        let tensor = unsafe { ManagedTensor::from_raw(tensor.into_raw()) }.unwrap();
+        assert_eq!(tensor.shape(), &[2, 3]);
+
+        // Zero-dimensional
+        let tensor = ManagedTensor::new(
+            AlignedBuffer::new_zeroed(1),
+            &[],
+            DLDataType::new(DLDataType::CODE_UINT, 8),
+        );
+        assert_eq!(tensor.shape(), &[] as &[i64]);
+        assert_eq!(tensor.strides(), Some([].as_slice()));
+        assert_eq!(tensor.num_elements(), Some(1));
+        assert_eq!(tensor.as_bytes().unwrap(), &[0]);
+
+        // Empty
+        let tensor = ManagedTensor::new(
+            AlignedBuffer::new_zeroed(0),
+            &[2, 0],
+            DLDataType::new(DLDataType::CODE_FLOAT, 64),
+        );
+        assert_eq!(tensor.num_elements(), Some(0));
+        assert!(tensor.as_bytes().unwrap().is_empty());
+    }
+}
@@ -3,7 +3,11 @@ use std::mem::ManuallyDrop;
 use itertools::Itertools;
 use ArrayError::IncompatibleElementType as IET;
 
-use super::{convert_from_bytes_slice, transmute_to_bytes, ArrayBytes, ArrayError, DataType};
+use super::{
+    convert_from_bytes_slice,
+    data_type::{pad_fixed_length, trim_fixed_length},
+    transmute_to_bytes, ArrayBytes, ArrayError, ArraySize, DataType, FillValue,
+};
 
 /// A trait representing an array element type.
 pub trait Element: Sized + Clone {
@@ -15,10 +19,13 @@ pub trait Element: Sized + Clone {
 
     /// Convert a slice of elements into [`ArrayBytes`].
     ///
+    /// The `fill_value` is that of the array, and is used by elements that can represent missing data (e.g. [`Option`]).
+    ///
     /// # Errors
     /// Returns an [`ArrayError`] if the data type is incompatible with [`Element`].
     fn into_array_bytes<'a>(
         data_type: &DataType,
+        fill_value: &FillValue,
         elements: &'a [Self],
     ) -> Result<ArrayBytes<'a>, ArrayError>;
 }
@@ -27,10 +34,13 @@ pub trait Element: Sized + Clone {
 pub trait ElementOwned: Element {
     /// Convert bytes into a [`Vec<ElementOwned>`].
     ///
+    /// The `fill_value` is that of the array, and is used by elements that can represent missing data (e.g. [`Option`]).
+    ///
     /// # Errors
     /// Returns an [`ArrayError`] if the data type is incompatible with [`Element`].
     fn from_array_bytes(
         data_type: &DataType,
+        fill_value: &FillValue,
         bytes: ArrayBytes<'_>,
     ) -> Result<Vec<Self>, ArrayError>;
 }
@@ -51,6 +61,8 @@ impl ElementFixedLength for half::f16 {}
 impl ElementFixedLength for half::bf16 {}
 impl ElementFixedLength for f32 {}
 impl ElementFixedLength for f64 {}
+impl ElementFixedLength for num::complex::Complex<half::bf16> {}
+impl ElementFixedLength for num::complex::Complex<half::f16> {}
 impl ElementFixedLength for num::complex::Complex32 {}
 impl ElementFixedLength for num::complex::Complex64 {}
 impl<const N: usize> ElementFixedLength for [u8; N] {}
@@ -62,6 +74,7 @@ impl Element for bool {
 
     fn into_array_bytes<'a>(
         data_type: &DataType,
+        _fill_value: &FillValue,
         elements: &'a [Self],
     ) -> Result<ArrayBytes<'a>, ArrayError> {
         Self::validate_data_type(data_type)?;
@@ -72,6 +85,7 @@ impl Element for bool {
 impl ElementOwned for bool {
     fn from_array_bytes(
         data_type: &DataType,
+        _fill_value: &FillValue,
         bytes: ArrayBytes<'_>,
     ) -> Result<Vec<Self>, ArrayError> {
         Self::validate_data_type(data_type)?;
@@ -91,14 +105,15 @@ impl ElementOwned for bool {
 }
 
 macro_rules! impl_element_pod {
-    ($raw_type:ty, $data_type:expr) => {
+    ($raw_type:ty, $($data_type:pat_param)|+) => {
         impl Element for $raw_type {
             fn validate_data_type(data_type: &DataType) -> Result<(), ArrayError> {
-                (data_type == &$data_type).then_some(()).ok_or(IET)
+                matches!(data_type, $($data_type)|+).then_some(()).ok_or(IET)
             }
 
             fn into_array_bytes<'a>(
                 data_type: &DataType,
+                _fill_value: &FillValue,
                 elements: &'a [Self],
             ) -> Result<ArrayBytes<'a>, ArrayError> {
                 Self::validate_data_type(data_type)?;
@@ -109,6 +124,7 @@ macro_rules! impl_element_pod {
         impl ElementOwned for $raw_type {
             fn from_array_bytes(
                 data_type: &DataType,
+                _fill_value: &FillValue,
                 bytes: ArrayBytes<'_>,
             ) -> Result<Vec<Self>, ArrayError> {
                 Self::validate_data_type(data_type)?;
@@ -122,7 +138,10 @@ macro_rules! impl_element_pod {
 impl_element_pod!(i8, DataType::Int8);
 impl_element_pod!(i16, DataType::Int16);
 impl_element_pod!(i32, DataType::Int32);
-impl_element_pod!(i64, DataType::Int64);
+impl_element_pod!(
+    i64,
+    DataType::Int64 | DataType::NumpyDateTime64 { .. } | DataType::NumpyTimeDelta64 { .. }
+);
 impl_element_pod!(u8, DataType::UInt8);
 impl_element_pod!(u16, DataType::UInt16);
 impl_element_pod!(u32, DataType::UInt32);
@@ -131,20 +150,30 @@ impl_element_pod!(half::f16, DataType::Float16);
 impl_element_pod!(f32, DataType::Float32);
 impl_element_pod!(f64, DataType::Float64);
 impl_element_pod!(half::bf16, DataType::BFloat16);
-impl_element_pod!(num::complex::Complex32, DataType::Complex64);
+impl_element_pod!(num::complex::Complex<half::bf16>, DataType::ComplexBFloat16);
+impl_element_pod!(num::complex::Complex<half::f16>, DataType::ComplexFloat16);
+impl_element_pod!(
+    num::complex::Complex32,
+    DataType::ComplexFloat32 | DataType::Complex64
+);
 impl_element_pod!(num::complex::Complex64, DataType::Complex128);
 
 impl<const N: usize> Element for [u8; N] {
     fn validate_data_type(data_type: &DataType) -> Result<(), ArrayError> {
-        if let DataType::RawBits(n) = data_type {
-            (*n == N).then_some(()).ok_or(IET)
-        } else {
-            Err(IET)
+        match data_type {
+            DataType::RawBits(n) | DataType::FixedLengthBytes(n) => {
+                (*n == N).then_some(()).ok_or(IET)
+            }
+            DataType::Structured(configuration) => {
+                (configuration.size() == N).then_some(()).ok_or(IET)
+            }
+            _ => Err(IET),
         }
     }
 
     fn into_array_bytes<'a>(
         data_type: &DataType,
+        _fill_value: &FillValue,
         elements: &'a [Self],
     ) -> Result<ArrayBytes<'a>, ArrayError> {
         Self::validate_data_type(data_type)?;
@@ -155,6 +184,7 @@ impl<const N: usize> Element for [u8; N] {
 impl<const N: usize> ElementOwned for [u8; N] {
     fn from_array_bytes(
         data_type: &DataType,
+        _fill_value: &FillValue,
         bytes: ArrayBytes<'_>,
     ) -> Result<Vec<Self>, ArrayError> {
         Self::validate_data_type(data_type)?;
@@ -163,18 +193,43 @@ impl<const N: usize> ElementOwned for [u8; N] {
     }
 }
 
+/// Concatenate elements padded with trailing null bytes to `length_bytes` bytes.
+///
+/// Returns [`ArrayError::InvalidElementValue`] if an element is longer than `length_bytes`.
+fn into_array_bytes_fixed_length<'a, 'b>(
+    elements: impl ExactSizeIterator<Item = &'b [u8]>,
+    length_bytes: usize,
+) -> Result<ArrayBytes<'a>, ArrayError> {
+    let mut bytes = Vec::with_capacity(elements.len() * length_bytes);
+    for element in elements {
+        bytes.extend(
+            pad_fixed_length(element, length_bytes).ok_or(ArrayError::InvalidElementValue)?,
+        );
+    }
+    Ok(bytes.into())
+}
+
 macro_rules! impl_element_string {
     ($raw_type:ty) => {
         impl Element for $raw_type {
             fn validate_data_type(data_type: &DataType) -> Result<(), ArrayError> {
-                (data_type == &DataType::String).then_some(()).ok_or(IET)
+                matches!(data_type, DataType::String | DataType::FixedLengthUtf8(_))
+                    .then_some(())
+                    .ok_or(IET)
             }
 
             fn into_array_bytes<'a>(
                 data_type: &DataType,
+                _fill_value: &FillValue,
                 elements: &'a [Self],
             ) -> Result<ArrayBytes<'a>, ArrayError> {
                 Self::validate_data_type(data_type)?;
+                if let DataType::FixedLengthUtf8(length_bytes) = data_type {
+                    return into_array_bytes_fixed_length(
+                        elements.iter().map(|element| element.as_bytes()),
+                        *length_bytes,
+                    );
+                }
 
                 // Calculate offsets
                 let mut len: usize = 0;
@@ -202,9 +257,20 @@ impl_element_string!(String);
 impl ElementOwned for String {
     fn from_array_bytes(
         data_type: &DataType,
+        _fill_value: &FillValue,
         bytes: ArrayBytes<'_>,
     ) -> Result<Vec<Self>, ArrayError> {
         Self::validate_data_type(data_type)?;
+        if let DataType::FixedLengthUtf8(length_bytes) = data_type {
+            let bytes = bytes.into_fixed()?;
+            return bytes
+                .chunks_exact(*length_bytes)
+                .map(|element| {
+                    Self::from_utf8(trim_fixed_length(element).to_vec())
+                        .map_err(|_| ArrayError::InvalidElementValue)
+                })
+                .collect();
+        }
         let (bytes, offsets) = bytes.into_variable()?;
         let mut elements = Vec::with_capacity(offsets.len());
         for (curr, next) in offsets.iter().tuple_windows() {
@@ -221,14 +287,23 @@ macro_rules! impl_element_binary {
     ($raw_type:ty) => {
         impl Element for $raw_type {
             fn validate_data_type(data_type: &DataType) -> Result<(), ArrayError> {
-                (data_type == &DataType::Bytes).then_some(()).ok_or(IET)
+                matches!(data_type, DataType::Bytes | DataType::FixedLengthBytes(_))
+                    .then_some(())
+                    .ok_or(IET)
             }
 
             fn into_array_bytes<'a>(
                 data_type: &DataType,
+                _fill_value: &FillValue,
                 elements: &'a [Self],
             ) -> Result<ArrayBytes<'a>, ArrayError> {
                 Self::validate_data_type(data_type)?;
+                if let DataType::FixedLengthBytes(length_bytes) = data_type {
+                    return into_array_bytes_fixed_length(
+                        elements.iter().map(|element| &element[..]),
+                        *length_bytes,
+                    );
+                }
 
                 // Calculate offsets
                 let mut len: usize = 0;
@@ -254,9 +329,17 @@ impl_element_binary!(Vec<u8>);
 impl ElementOwned for Vec<u8> {
     fn from_array_bytes(
         data_type: &DataType,
+        _fill_value: &FillValue,
         bytes: ArrayBytes<'_>,
     ) -> Result<Vec<Self>, ArrayError> {
         Self::validate_data_type(data_type)?;
+        if let DataType::FixedLengthBytes(length_bytes) = data_type {
+            let bytes = bytes.into_fixed()?;
+            return Ok(bytes
+                .chunks_exact(*length_bytes)
+                .map(|element| trim_fixed_length(element).to_vec())
+                .collect());
+        }
         let (bytes, offsets) = bytes.into_variable()?;
         let mut elements = Vec::with_capacity(offsets.len());
         for (curr, next) in offsets.iter().tuple_windows() {
@@ -265,3 +348,65 @@ impl ElementOwned for Vec<u8> {
         Ok(elements)
     }
 }
+
+/// Missing elements are represented by the fill value of the array.
+///
+/// On store, [`None`] is replaced by the fill value.
+/// On retrieve, elements that are bitwise equal to the fill value are [`None`], so a `NaN` fill value is supported.
+impl<T: ElementOwned> Element for Option<T> {
+    fn validate_data_type(data_type: &DataType) -> Result<(), ArrayError> {
+        T::validate_data_type(data_type)
+    }
+
+    fn into_array_bytes<'a>(
+        data_type: &DataType,
+        fill_value: &FillValue,
+        elements: &'a [Self],
+    ) -> Result<ArrayBytes<'a>, ArrayError> {
+        Self::validate_data_type(data_type)?;
+        let fill_value_element = T::from_array_bytes(
+            data_type,
+            fill_value,
+            ArrayBytes::new_fill_value(ArraySize::new(data_type.size(), 1), fill_value),
+        )?
+        .pop()
+        .ok_or(ArrayError::InvalidElementValue)?;
+        let elements = elements
+            .iter()
+            .map(|element| {
+                element
+                    .clone()
+                    .unwrap_or_else(|| fill_value_element.clone())
+            })
+            .collect::<Vec<_>>();
+        Ok(T::into_array_bytes(data_type, fill_value, &elements)?.into_owned())
+    }
+}
+
+impl<T: ElementOwned> ElementOwned for Option<T> {
+    fn from_array_bytes(
+        data_type: &DataType,
+        fill_value: &FillValue,
+        bytes: ArrayBytes<'_>,
+    ) -> Result<Vec<Self>, ArrayError> {
+        Self::validate_data_type(data_type)?;
+        let fill_value_bytes = fill_value.as_ne_bytes();
+        let is_fill_value: Vec<bool> = match &bytes {
+            ArrayBytes::Fixed(bytes) => bytes
+                .chunks(fill_value_bytes.len().max(1))
+                .map(|element| element == fill_value_bytes)
+                .collect(),
+            ArrayBytes::Variable(bytes, offsets) => offsets
+                .iter()
+                .tuple_windows()
+                .map(|(curr, next)| &bytes[*curr..*next] == fill_value_bytes)
+                .collect(),
+        };
+        let elements = T::from_array_bytes(data_type, fill_value, bytes)?;
+        Ok(elements
+            .into_iter()
+            .zip(is_fill_value)
+            .map(|(element, is_fill_value)| (!is_fill_value).then_some(element))
+            .collect())
+    }
+}
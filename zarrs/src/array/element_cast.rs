@@ -0,0 +1,278 @@
+use super::{ArrayError, DataType};
+
+/// The policy for casting array elements between numeric types.
+///
+/// In all modes:
+///  - integers are cast to floating point types by rounding to the nearest representable value,
+///  - floating point values are cast to integers by rounding toward zero, and
+///  - floating point values are cast to narrower floating point types by rounding to the nearest representable value.
+///
+/// The modes differ in their handling of values outside the range of the target type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ElementCastMode {
+    /// Values outside the range of the target type are clamped to its minimum or maximum.
+    ///
+    /// `NaN` is cast to zero for integer target types, and infinities are clamped.
+    #[default]
+    Saturating,
+    /// An error is returned if any value is outside the range of the target type.
+    ///
+    /// `NaN` and infinities cannot be cast to integer target types, and finite values cannot be cast to infinity.
+    Checked,
+}
+
+/// A numeric element type that can be cast to and from the numeric data types of an array.
+///
+/// This is implemented for [`u8`], [`u16`], [`u32`], [`u64`], [`i8`], [`i16`], [`i32`], [`i64`], [`half::f16`], [`half::bf16`], [`f32`], and [`f64`].
+/// These correspond to the data types that support casting.
+/// Other data types (e.g. `bool`, complex, raw bits, and variable-size data types) are not supported.
+///
+/// See [`ElementCastMode`] for the casting policy.
+pub trait ElementCast: bytemuck::Pod + Send + Sync + private::Sealed {
+    /// Cast an integer to this type, or return [`None`] if it is out of range in [`ElementCastMode::Checked`].
+    fn cast_from_int(value: i128, mode: ElementCastMode) -> Option<Self>;
+
+    /// Cast a floating point value to this type, or return [`None`] if it is out of range in [`ElementCastMode::Checked`].
+    fn cast_from_float(value: f64, mode: ElementCastMode) -> Option<Self>;
+
+    /// Cast this value to `T`, or return [`None`] if it is out of range in [`ElementCastMode::Checked`].
+    fn cast_into<T: ElementCast>(self, mode: ElementCastMode) -> Option<T>;
+}
+
+macro_rules! impl_element_cast_int {
+    ($t:ty) => {
+        impl private::Sealed for $t {}
+
+        impl ElementCast for $t {
+            fn cast_from_int(value: i128, mode: ElementCastMode) -> Option<Self> {
+                <$t>::try_from(value).ok().or(match mode {
+                    ElementCastMode::Saturating => {
+                        Some(if value < 0 { <$t>::MIN } else { <$t>::MAX })
+                    }
+                    ElementCastMode::Checked => None,
+                })
+            }
+
+            fn cast_from_float(value: f64, mode: ElementCastMode) -> Option<Self> {
+                #[allow(clippy::cast_precision_loss)]
+                const I128_BOUND: f64 = i128::MAX as f64;
+                if value.is_nan() {
+                    match mode {
+                        ElementCastMode::Saturating => Some(0),
+                        ElementCastMode::Checked => None,
+                    }
+                } else if value.trunc().abs() < I128_BOUND {
+                    #[allow(clippy::cast_possible_truncation)]
+                    Self::cast_from_int(value.trunc() as i128, mode)
+                } else {
+                    Self::cast_from_int(if value < 0.0 { i128::MIN } else { i128::MAX }, mode)
+                }
+            }
+
+            fn cast_into<T: ElementCast>(self, mode: ElementCastMode) -> Option<T> {
+                T::cast_from_int(i128::from(self), mode)
+            }
+        }
+    };
+}
+
+macro_rules! impl_element_cast_float {
+    ($t:ty, $from_f64:expr, $to_f64:expr) => {
+        impl private::Sealed for $t {}
+
+        impl ElementCast for $t {
+            fn cast_from_int(value: i128, mode: ElementCastMode) -> Option<Self> {
+                #[allow(clippy::cast_precision_loss)]
+                Self::cast_from_float(value as f64, mode)
+            }
+
+            fn cast_from_float(value: f64, mode: ElementCastMode) -> Option<Self> {
+                #[allow(clippy::cast_possible_truncation)]
+                let cast: $t = $from_f64(value);
+                if value.is_finite() && !$to_f64(cast).is_finite() {
+                    match mode {
+                        ElementCastMode::Saturating => {
+                            Some(if value < 0.0 { <$t>::MIN } else { <$t>::MAX })
+                        }
+                        ElementCastMode::Checked => None,
+                    }
+                } else {
+                    Some(cast)
+                }
+            }
+
+            fn cast_into<T: ElementCast>(self, mode: ElementCastMode) -> Option<T> {
+                T::cast_from_float($to_f64(self), mode)
+            }
+        }
+    };
+}
+
+impl_element_cast_int!(u8);
+impl_element_cast_int!(u16);
+impl_element_cast_int!(u32);
+impl_element_cast_int!(u64);
+impl_element_cast_int!(i8);
+impl_element_cast_int!(i16);
+impl_element_cast_int!(i32);
+impl_element_cast_int!(i64);
+impl_element_cast_float!(half::f16, half::f16::from_f64, half::f16::to_f64);
+impl_element_cast_float!(half::bf16, half::bf16::from_f64, half::bf16::to_f64);
+impl_element_cast_float!(f32, |value| value as f32, f64::from);
+impl_element_cast_float!(f64, std::convert::identity, std::convert::identity);
+
+/// Evaluate `$body` with `$s` as the [`ElementCast`] type corresponding to `$data_type`.
+macro_rules! with_element_cast_type {
+    ($data_type:expr, $s:ident => $body:expr) => {
+        match $data_type {
+            DataType::Int8 => {
+                type $s = i8;
+                $body
+            }
+            DataType::Int16 => {
+                type $s = i16;
+                $body
+            }
+            DataType::Int32 => {
+                type $s = i32;
+                $body
+            }
+            DataType::Int64 => {
+                type $s = i64;
+                $body
+            }
+            DataType::UInt8 => {
+                type $s = u8;
+                $body
+            }
+            DataType::UInt16 => {
+                type $s = u16;
+                $body
+            }
+            DataType::UInt32 => {
+                type $s = u32;
+                $body
+            }
+            DataType::UInt64 => {
+                type $s = u64;
+                $body
+            }
+            DataType::Float16 => {
+                type $s = half::f16;
+                $body
+            }
+            DataType::BFloat16 => {
+                type $s = half::bf16;
+                $body
+            }
+            DataType::Float32 => {
+                type $s = f32;
+                $body
+            }
+            DataType::Float64 => {
+                type $s = f64;
+                $body
+            }
+            _ => Err(ArrayError::IncompatibleElementType),
+        }
+    };
+}
+
+/// Validate that elements of `data_type` can be cast.
+pub(crate) fn validate_element_cast_data_type(data_type: &DataType) -> Result<(), ArrayError> {
+    with_element_cast_type!(data_type, _S => Ok(()))
+}
+
+/// Cast native endian `bytes` with `data_type` to elements of type `T`.
+pub(crate) fn cast_bytes_to_elements<T: ElementCast>(
+    bytes: &[u8],
+    data_type: &DataType,
+    mode: ElementCastMode,
+) -> Result<Vec<T>, ArrayError> {
+    with_element_cast_type!(data_type, S => bytes
+        .chunks_exact(std::mem::size_of::<S>())
+        .map(|bytes| {
+            bytemuck::pod_read_unaligned::<S>(bytes)
+                .cast_into::<T>(mode)
+                .ok_or_else(|| {
+                    ArrayError::ElementCastOutOfRange(
+                        data_type.to_string(),
+                        std::any::type_name::<T>().to_string(),
+                    )
+                })
+        })
+        .collect())
+}
+
+/// Cast `elements` of type `T` to native endian bytes with `data_type`.
+pub(crate) fn cast_elements_to_bytes<T: ElementCast>(
+    elements: &[T],
+    data_type: &DataType,
+    mode: ElementCastMode,
+) -> Result<Vec<u8>, ArrayError> {
+    with_element_cast_type!(data_type, S => {
+        let elements = elements
+            .iter()
+            .map(|element| {
+                element
+                    .cast_into::<S>(mode)
+                    .ok_or_else(|| {
+                        ArrayError::ElementCastOutOfRange(
+                            std::any::type_name::<T>().to_string(),
+                            data_type.to_string(),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<S>, _>>()?;
+        Ok(bytemuck::cast_slice(&elements).to_vec())
+    })
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_cast_int() {
+        let saturating = ElementCastMode::Saturating;
+        let checked = ElementCastMode::Checked;
+        assert_eq!(300u16.cast_into::<u8>(saturating), Some(u8::MAX));
+        assert_eq!(300u16.cast_into::<u8>(checked), None);
+        assert_eq!((-1i8).cast_into::<u64>(saturating), Some(0));
+        assert_eq!((-1i8).cast_into::<u64>(checked), None);
+        assert_eq!(u64::MAX.cast_into::<i64>(saturating), Some(i64::MAX));
+        assert_eq!(200u8.cast_into::<i16>(checked), Some(200));
+    }
+
+    #[test]
+    fn element_cast_float() {
+        let saturating = ElementCastMode::Saturating;
+        let checked = ElementCastMode::Checked;
+        assert_eq!((-2.7f32).cast_into::<i8>(checked), Some(-2));
+        assert_eq!(1e10f64.cast_into::<i32>(saturating), Some(i32::MAX));
+        assert_eq!(1e10f64.cast_into::<i32>(checked), None);
+        assert_eq!(1e30f64.cast_into::<u64>(saturating), Some(u64::MAX));
+        assert_eq!(f32::NAN.cast_into::<u8>(saturating), Some(0));
+        assert_eq!(f32::NAN.cast_into::<u8>(checked), None);
+        assert_eq!(
+            f32::NEG_INFINITY.cast_into::<i16>(saturating),
+            Some(i16::MIN)
+        );
+        assert_eq!(1e300f64.cast_into::<f32>(saturating), Some(f32::MAX));
+        assert_eq!(1e300f64.cast_into::<f32>(checked), None);
+        assert_eq!(
+            1e5f32.cast_into::<half::f16>(saturating),
+            Some(half::f16::MAX)
+        );
+        assert!(f64::INFINITY
+            .cast_into::<f32>(checked)
+            .unwrap()
+            .is_infinite());
+        assert!(f64::NAN.cast_into::<half::bf16>(checked).unwrap().is_nan());
+        assert_eq!(half::bf16::from_f32(2.5).cast_into::<u8>(checked), Some(2));
+    }
+}
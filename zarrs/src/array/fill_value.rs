@@ -116,6 +116,24 @@ impl From<f64> for FillValue {
     }
 }
 
+impl From<num::complex::Complex<half::bf16>> for FillValue {
+    fn from(value: num::complex::Complex<half::bf16>) -> Self {
+        let mut bytes = Vec::with_capacity(size_of::<num::complex::Complex<half::bf16>>());
+        bytes.extend(value.re.to_ne_bytes());
+        bytes.extend(value.im.to_ne_bytes());
+        Self(bytes)
+    }
+}
+
+impl From<num::complex::Complex<half::f16>> for FillValue {
+    fn from(value: num::complex::Complex<half::f16>) -> Self {
+        let mut bytes = Vec::with_capacity(size_of::<num::complex::Complex<half::f16>>());
+        bytes.extend(value.re.to_ne_bytes());
+        bytes.extend(value.im.to_ne_bytes());
+        Self(bytes)
+    }
+}
+
 impl From<num::complex::Complex32> for FillValue {
     fn from(value: num::complex::Complex32) -> Self {
         let mut bytes = Vec::with_capacity(size_of::<num::complex::Complex32>());
@@ -165,6 +183,15 @@ impl FillValue {
         &self.0
     }
 
+    /// Interpret the fill value as a `T`.
+    ///
+    /// Returns [`None`] if the size of the fill value does not match the size of `T`.
+    /// No data type validation is performed, so the bytes are reinterpreted as-is.
+    #[must_use]
+    pub fn try_as<T: bytemuck::Pod>(&self) -> Option<T> {
+        bytemuck::try_pod_read_unaligned(&self.0).ok()
+    }
+
     /// Check if the bytes are equal to a sequence of the fill value.
     #[allow(clippy::missing_panics_doc)]
     #[must_use]
@@ -231,6 +258,56 @@ impl FillValue {
     }
 }
 
+impl PartialEq<bool> for FillValue {
+    fn eq(&self, other: &bool) -> bool {
+        self.0 == [u8::from(*other)]
+    }
+}
+
+macro_rules! impl_partial_eq_fill_value {
+    ($($t:ty),*) => {
+        $(
+            /// The comparison is bitwise, so a `NaN` fill value equals a `NaN` with the same bit pattern.
+            impl PartialEq<$t> for FillValue {
+                fn eq(&self, other: &$t) -> bool {
+                    self.0 == bytemuck::bytes_of(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_eq_fill_value!(
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    half::f16,
+    half::bf16,
+    f32,
+    f64,
+    num::complex::Complex<half::bf16>,
+    num::complex::Complex<half::f16>,
+    num::complex::Complex32,
+    num::complex::Complex64
+);
+
+impl PartialEq<str> for FillValue {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other.as_bytes()
+    }
+}
+
+impl PartialEq<&str> for FillValue {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == other.as_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::array::transmute_to_bytes_vec;
@@ -331,4 +408,49 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn fill_value_try_as() {
+        assert_eq!(FillValue::from(1u8).try_as::<u8>(), Some(1));
+        assert_eq!(FillValue::from(-1i16).try_as::<i16>(), Some(-1));
+        assert_eq!(FillValue::from(1.5f32).try_as::<f32>(), Some(1.5));
+        assert_eq!(FillValue::from(1.5f64).try_as::<f64>(), Some(1.5));
+        assert_eq!(
+            FillValue::from(half::bf16::ONE).try_as::<half::bf16>(),
+            Some(half::bf16::ONE)
+        );
+        assert_eq!(
+            FillValue::from(num::complex::Complex64::new(1.0, 2.0))
+                .try_as::<num::complex::Complex64>(),
+            Some(num::complex::Complex64::new(1.0, 2.0))
+        );
+        assert_eq!(
+            FillValue::from([1u8, 2, 3]).try_as::<[u8; 3]>(),
+            Some([1, 2, 3])
+        );
+        // Size mismatch
+        assert_eq!(FillValue::from(1u8).try_as::<u16>(), None);
+        assert_eq!(FillValue::from(1.0f64).try_as::<f32>(), None);
+        assert_eq!(FillValue::from("").try_as::<u8>(), None);
+    }
+
+    #[test]
+    fn fill_value_partial_eq() {
+        assert_eq!(FillValue::from(true), true);
+        assert_ne!(FillValue::from(true), false);
+        assert_eq!(FillValue::from(1u8), 1u8);
+        assert_ne!(FillValue::from(1u8), 1u16);
+        assert_eq!(FillValue::from(-1i64), -1i64);
+        assert_eq!(FillValue::from(half::f16::ONE), half::f16::ONE);
+        assert_eq!(FillValue::from(1.0f32), 1.0f32);
+        assert_ne!(FillValue::from(1.0f32), 1.0f64);
+        assert_eq!(FillValue::from(f64::NAN), f64::NAN);
+        assert_ne!(FillValue::from(0.0f64), -0.0f64);
+        assert_eq!(
+            FillValue::from(num::complex::Complex32::new(1.0, 2.0)),
+            num::complex::Complex32::new(1.0, 2.0)
+        );
+        assert_eq!(FillValue::from("abc"), "abc");
+        assert_ne!(FillValue::from("abc"), *"ab");
+    }
 }
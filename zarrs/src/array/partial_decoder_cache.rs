@@ -0,0 +1,99 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+};
+
+use lru::LruCache;
+
+use super::{codec::ArrayPartialDecoderTraits, ArrayIndices};
+
+#[cfg(feature = "async")]
+use super::codec::AsyncArrayPartialDecoderTraits;
+
+/// An LRU (least recently used) cache of the partial decoders of the chunks of an array.
+///
+/// Partial decoders retain data read on creation (e.g. the shard index of a sharded chunk), so reusing them avoids reading it again.
+pub(crate) struct ArrayPartialDecoderLruCache {
+    capacity: NonZeroUsize,
+    partial_decoders: Mutex<LruCache<ArrayIndices, Arc<dyn ArrayPartialDecoderTraits>>>,
+    #[cfg(feature = "async")]
+    async_partial_decoders: Mutex<LruCache<ArrayIndices, Arc<dyn AsyncArrayPartialDecoderTraits>>>,
+}
+
+impl core::fmt::Debug for ArrayPartialDecoderLruCache {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ArrayPartialDecoderLruCache")
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ArrayPartialDecoderLruCache {
+    /// Create a new partial decoder cache with a capacity in chunks of `capacity`.
+    pub(crate) fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            partial_decoders: Mutex::new(LruCache::new(capacity)),
+            #[cfg(feature = "async")]
+            async_partial_decoders: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Return the capacity of the cache in chunks.
+    pub(crate) fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    /// Return the cached partial decoder of the chunk at `chunk_indices`, or create it with `create` and insert it into the cache.
+    ///
+    /// The cache is not locked while a partial decoder is created.
+    pub(crate) fn get_or_insert_with<E>(
+        &self,
+        chunk_indices: &[u64],
+        create: impl FnOnce() -> Result<Arc<dyn ArrayPartialDecoderTraits>, E>,
+    ) -> Result<Arc<dyn ArrayPartialDecoderTraits>, E> {
+        if let Some(partial_decoder) = self.partial_decoders.lock().unwrap().get(chunk_indices) {
+            return Ok(partial_decoder.clone());
+        }
+        let partial_decoder = create()?;
+        self.partial_decoders
+            .lock()
+            .unwrap()
+            .put(chunk_indices.to_vec(), partial_decoder.clone());
+        Ok(partial_decoder)
+    }
+
+    #[cfg(feature = "async")]
+    /// Async variant of [`get_or_insert_with`](ArrayPartialDecoderLruCache::get_or_insert_with).
+    pub(crate) async fn async_get_or_insert_with<E>(
+        &self,
+        chunk_indices: &[u64],
+        create: impl std::future::Future<Output = Result<Arc<dyn AsyncArrayPartialDecoderTraits>, E>>,
+    ) -> Result<Arc<dyn AsyncArrayPartialDecoderTraits>, E> {
+        let partial_decoder = self
+            .async_partial_decoders
+            .lock()
+            .unwrap()
+            .get(chunk_indices)
+            .cloned();
+        if let Some(partial_decoder) = partial_decoder {
+            return Ok(partial_decoder);
+        }
+        let partial_decoder = create.await?;
+        self.async_partial_decoders
+            .lock()
+            .unwrap()
+            .put(chunk_indices.to_vec(), partial_decoder.clone());
+        Ok(partial_decoder)
+    }
+
+    /// Remove the partial decoders of the chunk at `chunk_indices` from the cache.
+    pub(crate) fn remove(&self, chunk_indices: &[u64]) {
+        self.partial_decoders.lock().unwrap().pop(chunk_indices);
+        #[cfg(feature = "async")]
+        self.async_partial_decoders
+            .lock()
+            .unwrap()
+            .pop(chunk_indices);
+    }
+}
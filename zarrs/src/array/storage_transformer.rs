@@ -4,6 +4,16 @@
 //! A [`StorageTransformerChain`] represents a sequence of storage transformers.
 //! A storage transformer chain and individual storage transformers all have the same interface as a [store](crate::storage::store).
 //!
+//! ## Ordering
+//! The storage transformers of an [`Array`](crate::array::Array) are applied to its storage in the following order, from innermost to outermost:
+//!  1. programmatic storage transformers supplied to [`Array::open_with_storage_transformers`](crate::array::Array::open_with_storage_transformers),
+//!  2. the storage transformers of ancestor groups, starting from the group closest to the root, and
+//!  3. the storage transformers in the array metadata, in the order they are listed.
+//!
+//! A request to read or write data passes through the outermost storage transformer first and the innermost storage transformer last before reaching the store.
+//! Storage transformers of ancestor groups are only applied to arrays opened through a [`Group`](crate::group::Group), such as with [`Group::child_arrays`](crate::group::Group::child_arrays).
+//! See [`Group::storage_transformers`](crate::group::Group::storage_transformers).
+//!
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#id23>.
 
 mod storage_transformer_chain;
@@ -12,12 +22,15 @@ pub use storage_transformer_chain::StorageTransformerChain;
 mod storage_transformer_plugin;
 pub use storage_transformer_plugin::StorageTransformerPlugin;
 
+#[cfg(feature = "encryption")]
+pub mod encryption;
+
 use std::sync::Arc;
 
 use crate::{
     metadata::v3::MetadataV3,
     node::NodePath,
-    plugin::PluginCreateError,
+    plugin::{PluginCreateError, PluginKnownName, PluginUnsupportedError},
     storage::{ListableStorage, ReadableStorage, StorageError, WritableStorage},
 };
 
@@ -27,6 +40,13 @@ use crate::storage::{AsyncListableStorage, AsyncReadableStorage, AsyncWritableSt
 /// An [`Arc`] wrapped storage transformer.
 pub type StorageTransformer = Arc<dyn StorageTransformerExtension>;
 
+/// Known storage transformers, their aliases, and the crate feature that enables them.
+const KNOWN_STORAGE_TRANSFORMERS: &[PluginKnownName] = &[PluginKnownName::new(
+    "zarrs.encryption",
+    &[],
+    Some("encryption"),
+)];
+
 /// Create a storage transformer from metadata.
 ///
 /// # Errors
@@ -41,10 +61,15 @@ pub fn try_create_storage_transformer(
             return plugin.create(metadata, path);
         }
     }
-    Err(PluginCreateError::Unsupported {
-        name: metadata.name().to_string(),
-        plugin_type: "storage transformer".to_string(),
-    })
+    Err(PluginUnsupportedError::with_known_names(
+        metadata.name().to_string(),
+        "storage transformer".to_string(),
+        KNOWN_STORAGE_TRANSFORMERS,
+        inventory::iter::<StorageTransformerPlugin>
+            .into_iter()
+            .map(StorageTransformerPlugin::identifier),
+    )
+    .into())
 }
 
 /// A storage transformer extension.
@@ -0,0 +1,725 @@
+//! The `zarrs.encryption` storage transformer (Experimental).
+//!
+//! <div class="warning">
+//! This storage transformer is experimental and is not supported by other Zarr implementations.
+//! </div>
+//!
+//! Encrypts values with AES-256-GCM on write and decrypts them on read.
+//! Metadata documents (e.g. `zarr.json`, `.zarray`, `.zattrs`) are not encrypted, so an encrypted array can be opened and inspected without the key material.
+//!
+//! ### Key Material
+//! Key material is never stored in array metadata, which only holds the algorithm, the key derivation function, and its salt.
+//! The encryption key is derived from the key material and the salt with HKDF-SHA256.
+//!  - A transformer created with [`EncryptionStorageTransformer::new`] holds its key material, and can be passed to [`ArrayBuilder::storage_transformers`](crate::array::ArrayBuilder::storage_transformers).
+//!  - A transformer created from array metadata (e.g. by [`Array::open`](crate::array::Array::open)) retrieves key material from the provider set with [`set_encryption_key_provider`].
+//!    Reading or writing array data fails if there is no provider or it returns [`None`].
+//!
+//! ### Stored Values
+//! An encrypted value is a random 96-bit nonce, followed by the ciphertext and a 128-bit authentication tag.
+//! The store key is used as associated data, so an encrypted value cannot be moved to another key.
+//!
+//! Partial reads retrieve and decrypt the entire value before extracting the requested byte ranges.
+//! Partial writes are not supported and return [`StorageError::Unsupported`].
+//! The size of a value returned by [`size_key`](crate::storage::ReadableStorageTraits::size_key) is the size of the decrypted value, whereas the size of a prefix is the size of the stored (encrypted) values.
+//!
+//! ### Compatible Implementations
+//! None
+//!
+//! ### Specification
+//! None
+//!
+//! ### Configuration Parameters
+//! #### Example
+//! ```json
+//! {
+//!     "algorithm": "aes_256_gcm",
+//!     "kdf": "hkdf_sha256",
+//!     "salt": "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8="
+//! }
+//! ```
+
+use std::sync::{Arc, LazyLock, RwLock};
+
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    hkdf,
+    rand::{SecureRandom, SystemRandom},
+};
+
+pub use crate::metadata::v3::array::storage_transformer::encryption::{
+    EncryptionAlgorithm, EncryptionKeyDerivationFunction,
+    EncryptionStorageTransformerConfiguration, EncryptionStorageTransformerConfigurationV1,
+    IDENTIFIER,
+};
+
+use crate::{
+    byte_range::{extract_byte_ranges, ByteRange, MaxBatch},
+    metadata::v3::MetadataV3,
+    node::NodePath,
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+    storage::{
+        Bytes, ListableStorage, ListableStorageTraits, MaybeBytes, ReadableStorage,
+        ReadableStorageTraits, StorageError, StoreKey, StoreKeyOffsetValue, StoreKeys,
+        StoreKeysPrefixes, StorePrefix, WritableStorage, WritableStorageTraits,
+    },
+};
+
+#[cfg(feature = "async")]
+use crate::storage::{
+    AsyncListableStorage, AsyncListableStorageTraits, AsyncReadableStorage,
+    AsyncReadableStorageTraits, AsyncWritableStorage, AsyncWritableStorageTraits,
+};
+
+use super::{StorageTransformer, StorageTransformerExtension, StorageTransformerPlugin};
+
+// Register the storage transformer.
+inventory::submit! {
+    StorageTransformerPlugin::new(IDENTIFIER, is_name_encryption, create_storage_transformer_encryption)
+}
+
+fn is_name_encryption(name: &str) -> bool {
+    name.eq(IDENTIFIER)
+}
+
+pub(crate) fn create_storage_transformer_encryption(
+    metadata: &MetadataV3,
+    path: &NodePath,
+) -> Result<StorageTransformer, PluginCreateError> {
+    let configuration: EncryptionStorageTransformerConfiguration =
+        metadata.to_configuration().map_err(|_| {
+            PluginMetadataInvalidError::new(IDENTIFIER, "storage transformer", metadata.clone())
+        })?;
+    let key_material = KEY_PROVIDER
+        .read()
+        .unwrap()
+        .as_ref()
+        .and_then(|provider| provider(path));
+    Ok(Arc::new(
+        EncryptionStorageTransformer::new_with_configuration(
+            &configuration,
+            key_material.as_deref(),
+        ),
+    ))
+}
+
+/// A function returning the key material for the `zarrs.encryption` storage transformer of the array at a path.
+pub type EncryptionKeyProvider = Arc<dyn Fn(&NodePath) -> Option<Vec<u8>> + Send + Sync>;
+
+static KEY_PROVIDER: LazyLock<RwLock<Option<EncryptionKeyProvider>>> =
+    LazyLock::new(|| RwLock::new(None));
+
+/// Set the global key provider for `zarrs.encryption` storage transformers created from array metadata.
+///
+/// The provider is queried when a storage transformer is created, e.g. when an array is opened.
+///
+/// # Panics
+/// Panics if the underlying lock has been poisoned.
+pub fn set_encryption_key_provider(provider: Option<EncryptionKeyProvider>) {
+    *KEY_PROVIDER.write().unwrap() = provider;
+}
+
+/// The size of the authentication tag appended to an encrypted value.
+const TAG_LEN: usize = 16;
+
+/// The size of the salt of a new [`EncryptionStorageTransformer`].
+const SALT_LEN: usize = 32;
+
+/// The `zarrs.encryption` storage transformer (Experimental).
+///
+/// See the [module documentation](self).
+#[derive(Debug)]
+pub struct EncryptionStorageTransformer {
+    salt: Vec<u8>,
+    key: Option<LessSafeKey>,
+}
+
+impl EncryptionStorageTransformer {
+    /// Create a new `zarrs.encryption` storage transformer with `key_material` and a random salt.
+    ///
+    /// # Panics
+    /// Panics if the system random number generator fails.
+    #[must_use]
+    pub fn new(key_material: &[u8]) -> Self {
+        let mut salt = vec![0; SALT_LEN];
+        SystemRandom::new()
+            .fill(&mut salt)
+            .expect("the system random number generator should not fail");
+        Self::new_with_salt(key_material, salt)
+    }
+
+    /// Create a new `zarrs.encryption` storage transformer with `key_material` and `salt`.
+    #[must_use]
+    pub fn new_with_salt(key_material: &[u8], salt: Vec<u8>) -> Self {
+        let key = Some(derive_key(key_material, &salt));
+        Self { salt, key }
+    }
+
+    /// Create a new `zarrs.encryption` storage transformer from a configuration and optional `key_material`.
+    ///
+    /// If `key_material` is [`None`], reading or writing encrypted values will fail.
+    #[must_use]
+    pub fn new_with_configuration(
+        configuration: &EncryptionStorageTransformerConfiguration,
+        key_material: Option<&[u8]>,
+    ) -> Self {
+        let EncryptionStorageTransformerConfiguration::V1(configuration) = configuration;
+        let salt = configuration.salt.clone();
+        let key = key_material.map(|key_material| derive_key(key_material, &salt));
+        Self { salt, key }
+    }
+
+    fn key(&self) -> Result<&LessSafeKey, StorageError> {
+        self.key.as_ref().ok_or_else(|| {
+            StorageError::Other(format!(
+                "{IDENTIFIER} storage transformer has no key material"
+            ))
+        })
+    }
+
+    /// Encrypt `value` stored at `key`.
+    fn encrypt(&self, key: &StoreKey, value: &[u8]) -> Result<Bytes, StorageError> {
+        let aead_key = self.key()?;
+        let mut nonce = [0; NONCE_LEN];
+        SystemRandom::new()
+            .fill(&mut nonce)
+            .map_err(|_| StorageError::Other("failed to generate a nonce".to_string()))?;
+
+        let mut encrypted = Vec::with_capacity(NONCE_LEN + value.len() + TAG_LEN);
+        encrypted.extend_from_slice(&nonce);
+        encrypted.extend_from_slice(value);
+        let tag = aead_key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(key.as_str().as_bytes()),
+                &mut encrypted[NONCE_LEN..],
+            )
+            .map_err(|_| StorageError::Other(format!("failed to encrypt {key}")))?;
+        encrypted.extend_from_slice(tag.as_ref());
+        Ok(encrypted.into())
+    }
+
+    /// Decrypt `value` stored at `key`.
+    fn decrypt(&self, key: &StoreKey, value: &[u8]) -> Result<Bytes, StorageError> {
+        let aead_key = self.key()?;
+        let decrypt_error = || StorageError::Other(format!("failed to decrypt {key}"));
+        if value.len() < NONCE_LEN + TAG_LEN {
+            return Err(decrypt_error());
+        }
+        let nonce =
+            Nonce::try_assume_unique_for_key(&value[..NONCE_LEN]).map_err(|_| decrypt_error())?;
+        let mut decrypted = value[NONCE_LEN..].to_vec();
+        let decrypted_len = aead_key
+            .open_in_place(nonce, Aad::from(key.as_str().as_bytes()), &mut decrypted)
+            .map_err(|_| decrypt_error())?
+            .len();
+        decrypted.truncate(decrypted_len);
+        Ok(decrypted.into())
+    }
+
+    fn create_metadata_configuration(&self) -> EncryptionStorageTransformerConfigurationV1 {
+        EncryptionStorageTransformerConfigurationV1::new(
+            EncryptionAlgorithm::Aes256Gcm,
+            EncryptionKeyDerivationFunction::HkdfSha256,
+            self.salt.clone(),
+        )
+    }
+}
+
+/// Derive an AES-256-GCM key from `key_material` and `salt`.
+fn derive_key(key_material: &[u8], salt: &[u8]) -> LessSafeKey {
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, salt).extract(key_material);
+    let info = [IDENTIFIER.as_bytes()];
+    let okm = prk
+        .expand(&info, &AES_256_GCM)
+        .expect("the key length is valid for HKDF-SHA256");
+    LessSafeKey::new(UnboundKey::from(okm))
+}
+
+/// Returns true if `key` is a metadata document, which is not encrypted.
+fn is_metadata_key(key: &StoreKey) -> bool {
+    let name = key.as_str().rsplit('/').next().unwrap_or_default();
+    matches!(
+        name,
+        "zarr.json" | ".zarray" | ".zattrs" | ".zgroup" | ".zmetadata"
+    )
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl StorageTransformerExtension for EncryptionStorageTransformer {
+    fn create_metadata(&self) -> MetadataV3 {
+        MetadataV3::new_with_serializable_configuration(
+            IDENTIFIER,
+            &self.create_metadata_configuration(),
+        )
+        .unwrap()
+    }
+
+    fn create_readable_transformer(
+        self: Arc<Self>,
+        storage: ReadableStorage,
+    ) -> Result<ReadableStorage, StorageError> {
+        Ok(Arc::new(EncryptionStorage::new(storage, self)))
+    }
+
+    fn create_writable_transformer(
+        self: Arc<Self>,
+        storage: WritableStorage,
+    ) -> Result<WritableStorage, StorageError> {
+        Ok(Arc::new(EncryptionStorage::new(storage, self)))
+    }
+
+    fn create_listable_transformer(
+        self: Arc<Self>,
+        storage: ListableStorage,
+    ) -> Result<ListableStorage, StorageError> {
+        Ok(Arc::new(EncryptionStorage::new(storage, self)))
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_readable_transformer(
+        self: Arc<Self>,
+        storage: AsyncReadableStorage,
+    ) -> Result<AsyncReadableStorage, StorageError> {
+        Ok(Arc::new(EncryptionStorage::new(storage, self)))
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_writable_transformer(
+        self: Arc<Self>,
+        storage: AsyncWritableStorage,
+    ) -> Result<AsyncWritableStorage, StorageError> {
+        Ok(Arc::new(EncryptionStorage::new(storage, self)))
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_listable_transformer(
+        self: Arc<Self>,
+        storage: AsyncListableStorage,
+    ) -> Result<AsyncListableStorage, StorageError> {
+        Ok(Arc::new(EncryptionStorage::new(storage, self)))
+    }
+}
+
+/// Storage wrapped by an [`EncryptionStorageTransformer`].
+struct EncryptionStorage<TStorage: ?Sized> {
+    storage: Arc<TStorage>,
+    transformer: Arc<EncryptionStorageTransformer>,
+}
+
+impl<TStorage: ?Sized> EncryptionStorage<TStorage> {
+    fn new(storage: Arc<TStorage>, transformer: Arc<EncryptionStorageTransformer>) -> Self {
+        Self {
+            storage,
+            transformer,
+        }
+    }
+
+    fn decrypt(&self, key: &StoreKey, value: MaybeBytes) -> Result<MaybeBytes, StorageError> {
+        value
+            .map(|value| self.transformer.decrypt(key, &value))
+            .transpose()
+    }
+
+    fn extract_byte_ranges(
+        &self,
+        key: &StoreKey,
+        value: MaybeBytes,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        let Some(value) = self.decrypt(key, value)? else {
+            return Ok(None);
+        };
+        Ok(Some(
+            extract_byte_ranges(&value, byte_ranges)?
+                .into_iter()
+                .map(Bytes::from)
+                .collect(),
+        ))
+    }
+
+    fn partial_write_error() -> StorageError {
+        StorageError::Unsupported(format!(
+            "the {IDENTIFIER} storage transformer does not support partial writes"
+        ))
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
+    for EncryptionStorage<TStorage>
+{
+    fn get(&self, key: &StoreKey) -> Result<MaybeBytes, StorageError> {
+        if is_metadata_key(key) {
+            return self.storage.get(key);
+        }
+        self.decrypt(key, self.storage.get(key)?)
+    }
+
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        if is_metadata_key(key) {
+            return self.storage.get_partial_values_key(key, byte_ranges);
+        }
+        self.extract_byte_ranges(key, self.storage.get(key)?, byte_ranges)
+    }
+
+    fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        if is_metadata_key(key) {
+            return self
+                .storage
+                .get_partial_values_key_batched(key, byte_ranges, max_batch);
+        }
+        // The entire value is retrieved and decrypted once, irrespective of the batch size
+        self.extract_byte_ranges(key, self.storage.get(key)?, byte_ranges)
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        let size = self.storage.size_key(key)?;
+        if is_metadata_key(key) {
+            Ok(size)
+        } else {
+            Ok(size.map(|size| size.saturating_sub((NONCE_LEN + TAG_LEN) as u64)))
+        }
+    }
+}
+
+impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
+    for EncryptionStorage<TStorage>
+{
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.storage.list()
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.storage.list_prefix(prefix)
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.storage.list_dir(prefix)
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.storage.size_prefix(prefix)
+    }
+}
+
+impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
+    for EncryptionStorage<TStorage>
+{
+    fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        if is_metadata_key(key) {
+            return self.storage.set(key, value);
+        }
+        self.storage
+            .set(key, self.transformer.encrypt(key, &value)?)
+    }
+
+    fn set_many(&self, key_values: &[(StoreKey, Bytes)]) -> Result<(), StorageError> {
+        let key_values = key_values
+            .iter()
+            .map(|(key, value)| {
+                if is_metadata_key(key) {
+                    Ok((key.clone(), value.clone()))
+                } else {
+                    Ok((key.clone(), self.transformer.encrypt(key, value)?))
+                }
+            })
+            .collect::<Result<Vec<_>, StorageError>>()?;
+        self.storage.set_many(&key_values)
+    }
+
+    fn set_partial_values(
+        &self,
+        _key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        Err(Self::partial_write_error())
+    }
+
+    fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.storage.erase(key)
+    }
+
+    fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
+        self.storage.erase_values(keys)
+    }
+
+    fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.storage.erase_prefix(prefix)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
+    for EncryptionStorage<TStorage>
+{
+    async fn get(&self, key: &StoreKey) -> Result<MaybeBytes, StorageError> {
+        if is_metadata_key(key) {
+            return self.storage.get(key).await;
+        }
+        self.decrypt(key, self.storage.get(key).await?)
+    }
+
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        if is_metadata_key(key) {
+            return self.storage.get_partial_values_key(key, byte_ranges).await;
+        }
+        self.extract_byte_ranges(key, self.storage.get(key).await?, byte_ranges)
+    }
+
+    async fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        if is_metadata_key(key) {
+            return self
+                .storage
+                .get_partial_values_key_batched(key, byte_ranges, max_batch)
+                .await;
+        }
+        // The entire value is retrieved and decrypted once, irrespective of the batch size
+        self.extract_byte_ranges(key, self.storage.get(key).await?, byte_ranges)
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        let size = self.storage.size_key(key).await?;
+        if is_metadata_key(key) {
+            Ok(size)
+        } else {
+            Ok(size.map(|size| size.saturating_sub((NONCE_LEN + TAG_LEN) as u64)))
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncListableStorageTraits> AsyncListableStorageTraits
+    for EncryptionStorage<TStorage>
+{
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.storage.list().await
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.storage.list_prefix(prefix).await
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.storage.list_dir(prefix).await
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.storage.size_prefix(prefix).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
+    for EncryptionStorage<TStorage>
+{
+    async fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        if is_metadata_key(key) {
+            return self.storage.set(key, value).await;
+        }
+        self.storage
+            .set(key, self.transformer.encrypt(key, &value)?)
+            .await
+    }
+
+    async fn set_many(&self, key_values: &[(StoreKey, Bytes)]) -> Result<(), StorageError> {
+        let key_values = key_values
+            .iter()
+            .map(|(key, value)| {
+                if is_metadata_key(key) {
+                    Ok((key.clone(), value.clone()))
+                } else {
+                    Ok((key.clone(), self.transformer.encrypt(key, value)?))
+                }
+            })
+            .collect::<Result<Vec<_>, StorageError>>()?;
+        self.storage.set_many(&key_values).await
+    }
+
+    async fn set_partial_values(
+        &self,
+        _key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        Err(Self::partial_write_error())
+    }
+
+    async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.storage.erase(key).await
+    }
+
+    async fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
+        self.storage.erase_values(keys).await
+    }
+
+    async fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.storage.erase_prefix(prefix).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        array::{
+            storage_transformer::StorageTransformerChain, Array, ArrayBuilder, DataType, FillValue,
+        },
+        array_subset::ArraySubset,
+        storage::store::MemoryStore,
+    };
+
+    use super::*;
+
+    #[test]
+    fn storage_transformer_encryption_round_trip() {
+        let store = Arc::new(MemoryStore::new());
+        let transformer = Arc::new(EncryptionStorageTransformer::new(b"key material"));
+        let readable = transformer
+            .clone()
+            .create_readable_transformer(store.clone())
+            .unwrap();
+        let writable = transformer
+            .clone()
+            .create_writable_transformer(store.clone())
+            .unwrap();
+
+        let key = StoreKey::new("array/c/0").unwrap();
+        let value = Bytes::from_static(b"plaintext");
+        writable.set(&key, value.clone()).unwrap();
+        let stored = store.get(&key).unwrap().unwrap();
+        assert_eq!(stored.len(), value.len() + NONCE_LEN + TAG_LEN);
+        assert!(!stored.windows(value.len()).any(|window| window == value));
+        assert_eq!(readable.get(&key).unwrap(), Some(value.clone()));
+        assert_eq!(readable.size_key(&key).unwrap(), Some(value.len() as u64));
+        assert_eq!(
+            readable
+                .get_partial_values_key(&key, &[ByteRange::new(0..5), ByteRange::Suffix(4)])
+                .unwrap(),
+            Some(vec![
+                Bytes::from_static(b"plain"),
+                Bytes::from_static(b"text")
+            ])
+        );
+        assert!(writable
+            .set_partial_values(&[StoreKeyOffsetValue::new(key.clone(), 0, b"a")])
+            .is_err());
+
+        // Metadata is not encrypted
+        let key_metadata = StoreKey::new("array/zarr.json").unwrap();
+        writable.set(&key_metadata, value.clone()).unwrap();
+        assert_eq!(store.get(&key_metadata).unwrap(), Some(value.clone()));
+
+        // The store key is authenticated
+        let key_other = StoreKey::new("array/c/1").unwrap();
+        store.set(&key_other, stored).unwrap();
+        assert!(readable.get(&key_other).is_err());
+
+        // Decryption requires the key material and salt
+        let configuration = EncryptionStorageTransformerConfiguration::V1(
+            transformer.create_metadata_configuration(),
+        );
+        for transformer in [
+            EncryptionStorageTransformer::new_with_configuration(&configuration, None),
+            EncryptionStorageTransformer::new_with_configuration(&configuration, Some(b"other")),
+            EncryptionStorageTransformer::new(b"key material"),
+        ] {
+            let readable = Arc::new(transformer)
+                .create_readable_transformer(store.clone())
+                .unwrap();
+            assert!(readable.get(&key).is_err());
+        }
+        let readable = EncryptionStorageTransformer::new_with_configuration(
+            &configuration,
+            Some(b"key material"),
+        );
+        assert_eq!(
+            Arc::new(readable)
+                .create_readable_transformer(store)
+                .unwrap()
+                .get(&key)
+                .unwrap(),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn storage_transformer_encryption_array() {
+        let store = Arc::new(MemoryStore::new());
+        let transformer: StorageTransformer =
+            Arc::new(EncryptionStorageTransformer::new(b"array key material"));
+        let array = ArrayBuilder::new(
+            vec![4, 4],
+            DataType::UInt16,
+            vec![2, 2].try_into().unwrap(),
+            FillValue::from(0u16),
+        )
+        .storage_transformers(StorageTransformerChain::new(vec![transformer]))
+        .build(store.clone(), "/array")
+        .unwrap();
+        array.store_metadata().unwrap();
+        let elements: Vec<u16> = (0..16).collect();
+        array
+            .store_array_subset_elements(&array.subset_all(), &elements)
+            .unwrap();
+
+        // The chunks are encrypted, but the metadata is not
+        let chunk = store
+            .get(&StoreKey::new("array/c/0/0").unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(chunk.len(), 8 + NONCE_LEN + TAG_LEN);
+        let metadata = store
+            .get(&StoreKey::new("array/zarr.json").unwrap())
+            .unwrap()
+            .unwrap();
+        let metadata: serde_json::Value = serde_json::from_slice(&metadata).unwrap();
+        assert_eq!(
+            metadata["storage_transformers"][0]["name"],
+            serde_json::json!(IDENTIFIER)
+        );
+
+        // Opening the array without key material succeeds, but retrieving data fails
+        set_encryption_key_provider(None);
+        let array = Array::open(store.clone(), "/array").unwrap();
+        assert!(array
+            .retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_shape(vec![4, 4]))
+            .is_err());
+
+        set_encryption_key_provider(Some(Arc::new(|path: &NodePath| {
+            (path.as_str() == "/array").then(|| b"array key material".to_vec())
+        })));
+        let array = Array::open(store, "/array").unwrap();
+        set_encryption_key_provider(None);
+        assert_eq!(
+            array
+                .retrieve_array_subset_elements::<u16>(&array.subset_all())
+                .unwrap(),
+            elements
+        );
+        assert_eq!(
+            array
+                .retrieve_chunk_subset_elements::<u16>(
+                    &[1, 1],
+                    &ArraySubset::new_with_ranges(&[1..2, 0..2])
+                )
+                .unwrap(),
+            vec![14, 15]
+        );
+    }
+}
@@ -16,6 +16,9 @@ use crate::storage::{AsyncListableStorage, AsyncReadableStorage, AsyncWritableSt
 use super::{try_create_storage_transformer, StorageTransformer};
 
 /// Configuration for a storage transformer chain.
+///
+/// The first storage transformer in the chain is applied directly to the storage, and each subsequent storage transformer wraps the previous one.
+/// Requests therefore pass through the storage transformers in reverse order, from the last (outermost) to the first (innermost).
 #[derive(Debug, Clone, Default, From)]
 pub struct StorageTransformerChain(Vec<StorageTransformer>);
 
@@ -43,6 +46,20 @@ impl StorageTransformerChain {
         Ok(Self(storage_transformers))
     }
 
+    /// Create a storage transformer chain with the storage transformers of `self` followed by those of `outer`.
+    ///
+    /// The storage transformers of `outer` wrap those of `self`.
+    #[must_use]
+    pub fn chain(&self, outer: &Self) -> Self {
+        Self(self.0.iter().chain(&outer.0).cloned().collect())
+    }
+
+    /// Returns true if the chain has no storage transformers.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     /// Create storage transformer chain metadata.
     #[must_use]
     pub fn create_metadatas(&self) -> Vec<MetadataV3> {
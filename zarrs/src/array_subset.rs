@@ -6,12 +6,15 @@
 //! [`iterators`] includes various types of [`ArraySubset`] iterators.
 //!
 //! This module also provides convenience functions for:
-//!  - computing the byte ranges of array subsets within an array with a fixed element size.
+//!  - computing the byte ranges of array subsets within an array with a fixed element size, and
+//!  - set operations on array subsets ([`ArraySubset::union`] and [`ArraySubset::difference`]) that produce an [`ArraySubsetList`] of disjoint subsets.
 
+mod array_subset_list;
 pub mod iterators;
 
 use std::{fmt::Debug, num::NonZeroU64, ops::Range};
 
+pub use array_subset_list::{ArraySubsetList, ArraySubsetListLinearisedIndices};
 use iterators::{
     Chunks, ContiguousIndices, ContiguousLinearisedIndices, Indices, LinearisedIndices,
 };
@@ -495,6 +498,8 @@ impl ArraySubset {
 
     /// Return the overlapping subset between this array subset and `subset_other`.
     ///
+    /// The overlapping subset is empty if the array subsets do not overlap.
+    ///
     /// # Errors
     ///
     /// Returns [`IncompatibleDimensionalityError`] if the dimensionality of `subset_other` does not match the dimensionality of this array subset.
@@ -525,11 +530,58 @@ impl ArraySubset {
         ) {
             let overlap_start = *std::cmp::max(start, other_start);
             let overlap_end = std::cmp::min(start + size, other_start + other_size);
+            // The overlap is empty if the subsets are disjoint
+            let overlap_end = std::cmp::max(overlap_start, overlap_end);
             ranges.push(overlap_start..overlap_end);
         }
         Self::new_with_ranges(&ranges)
     }
 
+    /// Return the elements in this array subset that are not in `subset_other` as a list of disjoint subsets.
+    ///
+    /// The overlap with `subset_other` is removed by splitting this array subset along each dimension in turn.
+    /// This produces at most two subsets per dimension.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleDimensionalityError`] if the dimensionality of `subset_other` does not match the dimensionality of this array subset.
+    pub fn difference(
+        &self,
+        subset_other: &Self,
+    ) -> Result<ArraySubsetList, IncompatibleDimensionalityError> {
+        let overlap = self.overlap(subset_other)?;
+        if overlap.is_empty() {
+            return Ok(ArraySubsetList::new_with_subset(self.clone()));
+        }
+
+        let mut difference = ArraySubsetList::new_empty(self.dimensionality());
+        let mut remainder = self.to_ranges();
+        for (dim, overlap_range) in overlap.to_ranges().into_iter().enumerate() {
+            let range = remainder[dim].clone();
+            for split in [
+                range.start..overlap_range.start,
+                overlap_range.end..range.end,
+            ] {
+                let mut ranges = remainder.clone();
+                ranges[dim] = split;
+                difference.push(ArraySubset::new_with_ranges(&ranges));
+            }
+            // Only the part of the remainder within the overlap is split along subsequent dimensions
+            remainder[dim] = overlap_range;
+        }
+        Ok(difference)
+    }
+
+    /// Return the elements in this array subset or in `subset_other` as a list of disjoint subsets.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleDimensionalityError`] if the dimensionality of `subset_other` does not match the dimensionality of this array subset.
+    pub fn union(
+        &self,
+        subset_other: &Self,
+    ) -> Result<ArraySubsetList, IncompatibleDimensionalityError> {
+        ArraySubsetList::new_with_subset(self.clone()).union(subset_other)
+    }
+
     /// Return the subset relative to `start`.
     ///
     /// Creates an array subset starting at [`ArraySubset::start()`] - `start`.
@@ -0,0 +1,318 @@
+use std::iter::Flatten;
+
+use super::{
+    iterators::LinearisedIndices, ArraySubset, IncompatibleArraySubsetAndShapeError,
+    IncompatibleDimensionalityError,
+};
+
+/// A list of disjoint array subsets (hyperrectangles) with the same dimensionality.
+///
+/// An [`ArraySubsetList`] is the result of set operations on array subsets that cannot be represented by a single [`ArraySubset`], such as [`ArraySubset::union`] and [`ArraySubset::difference`].
+/// The subsets in the list are non-empty and do not overlap.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ArraySubsetList {
+    dimensionality: usize,
+    subsets: Vec<ArraySubset>,
+}
+
+impl ArraySubsetList {
+    /// Create a new empty array subset list with `dimensionality`.
+    #[must_use]
+    pub fn new_empty(dimensionality: usize) -> Self {
+        Self {
+            dimensionality,
+            subsets: vec![],
+        }
+    }
+
+    /// Create an array subset list holding a single `subset`.
+    #[must_use]
+    pub fn new_with_subset(subset: ArraySubset) -> Self {
+        let mut list = Self::new_empty(subset.dimensionality());
+        list.push(subset);
+        list
+    }
+
+    /// Push a `subset` that is disjoint with the subsets in the list, skipping it if it is empty.
+    pub(super) fn push(&mut self, subset: ArraySubset) {
+        debug_assert_eq!(subset.dimensionality(), self.dimensionality);
+        if !subset.is_empty() {
+            self.subsets.push(subset);
+        }
+    }
+
+    /// Return the dimensionality of the subsets in the list.
+    #[must_use]
+    pub fn dimensionality(&self) -> usize {
+        self.dimensionality
+    }
+
+    /// Return the disjoint subsets in the list.
+    #[must_use]
+    pub fn subsets(&self) -> &[ArraySubset] {
+        &self.subsets
+    }
+
+    /// Return the number of subsets in the list.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.subsets.len()
+    }
+
+    /// Returns true if the list has no subsets (i.e. it has no elements).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.subsets.is_empty()
+    }
+
+    /// Return the total number of elements of the subsets in the list.
+    #[must_use]
+    pub fn num_elements(&self) -> u64 {
+        self.subsets.iter().map(ArraySubset::num_elements).sum()
+    }
+
+    /// Returns true if `indices` are within a subset in the list.
+    #[must_use]
+    pub fn contains(&self, indices: &[u64]) -> bool {
+        self.subsets.iter().any(|subset| subset.contains(indices))
+    }
+
+    /// Returns an iterator over the subsets in the list.
+    pub fn iter(&self) -> std::slice::Iter<'_, ArraySubset> {
+        self.subsets.iter()
+    }
+
+    /// Returns the linearised indices of the elements in the list which can be iterated over.
+    ///
+    /// The linearised indices of each subset are iterated in turn, so they are not sorted if there are multiple subsets.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleArraySubsetAndShapeError`] if the `array_shape` does not encapsulate every subset in the list.
+    pub fn linearised_indices(
+        &self,
+        array_shape: &[u64],
+    ) -> Result<ArraySubsetListLinearisedIndices, IncompatibleArraySubsetAndShapeError> {
+        Ok(ArraySubsetListLinearisedIndices(
+            self.subsets
+                .iter()
+                .map(|subset| subset.linearised_indices(array_shape))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    /// Return the elements in this list that are not in `subset`.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleDimensionalityError`] if the dimensionality of `subset` does not match the dimensionality of the list.
+    pub fn difference(
+        &self,
+        subset: &ArraySubset,
+    ) -> Result<Self, IncompatibleDimensionalityError> {
+        if subset.dimensionality() != self.dimensionality {
+            return Err(IncompatibleDimensionalityError::new(
+                subset.dimensionality(),
+                self.dimensionality,
+            ));
+        }
+        let mut difference = Self::new_empty(self.dimensionality);
+        for subset_self in &self.subsets {
+            difference
+                .subsets
+                .extend(subset_self.difference(subset)?.subsets);
+        }
+        Ok(difference)
+    }
+
+    /// Return the elements in this list or in `subset`.
+    ///
+    /// # Errors
+    /// Returns [`IncompatibleDimensionalityError`] if the dimensionality of `subset` does not match the dimensionality of the list.
+    pub fn union(&self, subset: &ArraySubset) -> Result<Self, IncompatibleDimensionalityError> {
+        let mut union = self.difference(subset)?;
+        union.push(subset.clone());
+        Ok(union)
+    }
+}
+
+impl<'a> IntoIterator for &'a ArraySubsetList {
+    type Item = &'a ArraySubset;
+    type IntoIter = std::slice::Iter<'a, ArraySubset>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.subsets.iter()
+    }
+}
+
+impl IntoIterator for ArraySubsetList {
+    type Item = ArraySubset;
+    type IntoIter = std::vec::IntoIter<ArraySubset>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.subsets.into_iter()
+    }
+}
+
+/// The linearised indices of the elements in an [`ArraySubsetList`].
+///
+/// See [`ArraySubsetList::linearised_indices`].
+pub struct ArraySubsetListLinearisedIndices(Vec<LinearisedIndices>);
+
+impl ArraySubsetListLinearisedIndices {
+    /// Return the number of indices.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.iter().map(LinearisedIndices::len).sum()
+    }
+
+    /// Returns true if the number of indices is zero.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Create a new serial iterator.
+    pub fn iter(&self) -> Flatten<std::slice::Iter<'_, LinearisedIndices>> {
+        self.0.iter().flatten()
+    }
+}
+
+impl<'a> IntoIterator for &'a ArraySubsetListLinearisedIndices {
+    type Item = u64;
+    type IntoIter = Flatten<std::slice::Iter<'a, LinearisedIndices>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array::ravel_indices;
+
+    use super::*;
+
+    #[test]
+    fn array_subset_difference_union() {
+        let a = ArraySubset::new_with_ranges(&[0..4, 0..4]);
+        let b = ArraySubset::new_with_ranges(&[1..3, 2..6]);
+        let difference = a.difference(&b).unwrap();
+        assert_eq!(
+            difference.subsets(),
+            &[
+                ArraySubset::new_with_ranges(&[0..1, 0..4]),
+                ArraySubset::new_with_ranges(&[3..4, 0..4]),
+                ArraySubset::new_with_ranges(&[1..3, 0..2]),
+            ]
+        );
+        assert_eq!(difference.num_elements(), 12);
+        assert_eq!(
+            difference
+                .linearised_indices(&[4, 4])
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 3, 12, 13, 14, 15, 4, 5, 8, 9]
+        );
+        assert!(difference.linearised_indices(&[4, 3]).is_err());
+
+        let union = a.union(&b).unwrap();
+        assert_eq!(union.len(), 4);
+        assert_eq!(union.num_elements(), 20);
+
+        // No overlap
+        let c = ArraySubset::new_with_ranges(&[4..6, 0..4]);
+        assert_eq!(
+            a.difference(&c).unwrap().subsets(),
+            std::slice::from_ref(&a)
+        );
+        assert_eq!(a.union(&c).unwrap().num_elements(), 24);
+
+        // Complete overlap
+        assert!(b.overlap(&a).unwrap().difference(&b).unwrap().is_empty());
+        assert!(a.difference(&a).unwrap().is_empty());
+
+        // Empty subsets
+        let empty = ArraySubset::new_with_ranges(&[1..1, 0..4]);
+        assert!(empty.difference(&a).unwrap().is_empty());
+        assert_eq!(a.union(&empty).unwrap().subsets(), std::slice::from_ref(&a));
+
+        // Dimensionality mismatch
+        let subset_1d = ArraySubset::new_with_shape(vec![1]);
+        assert!(a.difference(&subset_1d).is_err());
+        assert!(a.union(&subset_1d).is_err());
+    }
+
+    /// A xorshift pseudo random number generator.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self, max: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % max
+        }
+
+        fn subset(&mut self, shape: &[u64]) -> ArraySubset {
+            let ranges: Vec<_> = shape
+                .iter()
+                .map(|&size| {
+                    let start = self.next(size + 1);
+                    let end = start + self.next(size + 1 - start);
+                    start..end
+                })
+                .collect();
+            ArraySubset::new_with_ranges(&ranges)
+        }
+    }
+
+    /// Check `list` holds exactly the elements of `shape` for which `expected` is true, in disjoint subsets.
+    fn check_membership(list: &ArraySubsetList, shape: &[u64], expected: impl Fn(&[u64]) -> bool) {
+        let mut expected_linearised = vec![];
+        for indices in &ArraySubset::new_with_shape(shape.to_vec()).indices() {
+            let num_containing = list
+                .iter()
+                .filter(|subset| subset.contains(&indices))
+                .count();
+            assert!(num_containing <= 1, "subsets are not disjoint");
+            assert_eq!(num_containing == 1, expected(&indices));
+            if expected(&indices) {
+                expected_linearised.push(ravel_indices(&indices, shape));
+            }
+        }
+        assert!(list.iter().all(|subset| !subset.is_empty()));
+        assert_eq!(list.num_elements(), expected_linearised.len() as u64);
+        let mut linearised: Vec<u64> = list.linearised_indices(shape).unwrap().iter().collect();
+        linearised.sort_unstable();
+        assert_eq!(linearised, expected_linearised);
+    }
+
+    #[test]
+    fn array_subset_difference_union_random() {
+        let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+        for dimensionality in 1..=4 {
+            for _ in 0..200 {
+                let shape: Vec<u64> = (0..dimensionality).map(|_| 1 + rng.next(6)).collect();
+                let a = rng.subset(&shape);
+                let b = rng.subset(&shape);
+                let c = rng.subset(&shape);
+
+                let difference = a.difference(&b).unwrap();
+                check_membership(&difference, &shape, |i| a.contains(i) && !b.contains(i));
+
+                let union = a.union(&b).unwrap();
+                check_membership(&union, &shape, |i| a.contains(i) || b.contains(i));
+
+                let union_difference = union.difference(&c).unwrap();
+                check_membership(&union_difference, &shape, |i| {
+                    (a.contains(i) || b.contains(i)) && !c.contains(i)
+                });
+
+                let union_union = union.union(&c).unwrap();
+                check_membership(&union_union, &shape, |i| {
+                    a.contains(i) || b.contains(i) || c.contains(i)
+                });
+            }
+        }
+    }
+}
@@ -3,9 +3,10 @@
 //! See [`Config`] for the list of options.
 
 use crate::metadata::v3::array::codec;
+use rayon::ThreadPool;
 use std::{
-    collections::HashMap,
-    sync::{LazyLock, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    collections::{HashMap, HashSet},
+    sync::{Arc, LazyLock, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 
 #[cfg(doc)]
@@ -22,8 +23,10 @@ use crate::array::{codec::CodecOptions, ArrayMetadataOptions};
 ///
 /// [`CodecOptions::validate_checksums()`] defaults to [`Config::validate_checksums()`].
 ///
-/// If validate checksums is enabled, checksum codecs (e.g. `crc32c`, `fletcher32`) will validate that encoded data matches stored checksums, otherwise validation is skipped.
-/// Note that regardless of this configuration option, checksum codecs may skip validation when partial decoding.
+/// If validate checksums is enabled, checksum codecs (e.g. `crc32c`, `fletcher32`, `adler32`) will validate that encoded data matches stored checksums, otherwise validation is skipped and the checksum is just stripped.
+/// Partial decoders of checksum codecs read the entire encoded value if validation is enabled, so disabling it can reduce the latency of partial decoding.
+///
+/// This can be overridden for an individual array with [`Array::set_validate_checksums`](crate::array::Array::set_validate_checksums).
 ///
 /// ### Store Empty Chunks
 ///  > default: [`false`]
@@ -35,6 +38,8 @@ use crate::array::{codec::CodecOptions, ArrayMetadataOptions};
 /// If `true`, the aforementioned test is skipped and empty chunks will be stored.
 /// Note that empty chunks must still be stored explicitly (e.g. with [`Array::store_chunk`](crate::array::Array::store_chunk)).
 ///
+/// This can be overridden for an individual array with [`Array::set_store_empty_chunks`](crate::array::Array::set_store_empty_chunks).
+///
 /// ### Codec Concurrent Target
 /// > default: [`std::thread::available_parallelism`]`()`
 ///
@@ -54,13 +59,46 @@ use crate::array::{codec::CodecOptions, ArrayMetadataOptions};
 /// This option sets the preferred minimum chunk concurrency.
 /// The concurrency of internal codecs is adjusted to accomodate for the chunk concurrency in accordance with the concurrent target set in the [`CodecOptions`] parameter of an encode or decode method.
 ///
+/// ### Chunk Concurrent Maximum
+/// > default: [`std::thread::available_parallelism`]`()`
+///
+/// [`CodecOptions::chunk_concurrent_maximum()`] defaults to [`Config::chunk_concurrent_maximum()`].
+///
+/// The maximum number of chunks that array operations involving multiple chunks will process concurrently.
+/// This bounds the number of concurrent store requests made by these operations, which is useful for rate limited stores (e.g. cloud object storage).
+/// It takes precedence over the [chunk concurrent minimum](#chunk-concurrent-minimum).
+/// It also bounds the number of child node metadata documents retrieved concurrently by async hierarchy traversal (e.g. [`Node::async_open`](crate::node::Node::async_open)).
+/// A maximum of zero is interpreted as a maximum of one.
+///
+/// ### Range Coalesce Gap
+/// > default: [`None`]
+///
+/// [`CodecOptions::range_coalesce_gap()`] defaults to [`Config::range_coalesce_gap()`].
+///
+/// If set, byte ranges of a store value separated by at most this many bytes are retrieved with a single byte range request when partial decoding.
+/// The sharding codec also prefetches the encoded inner chunks intersecting a partial decode in one request.
+/// This reduces the number of requests to high latency stores (e.g. HTTP or cloud object storage), at the expense of reading some unneeded bytes.
+///
 /// ### Experimental Partial Encoding
 /// > default: [`false`]
 ///
 /// If `true`, [`Array::store_chunk_subset`](crate::array::Array::store_chunk_subset) and [`Array::store_array_subset`](crate::array::Array::store_array_subset) and variants can use partial encoding.
 /// This is relevant when using the sharding codec, as it enables inner chunks to be written without reading and writing entire shards.
+/// Partial encoding is only used if the store [supports partial writes](crate::storage::WritableStorageTraits::supports_set_partial), otherwise the entire chunk is decoded, updated, and reencoded.
+///
+/// Updated inner chunks are appended to the end of a shard and the previously encoded inner chunks are left in place, so a shard grows with each partial update.
+/// Use [`Array::compact_shard`](crate::array::Array::compact_shard) to reclaim this space.
 ///
 /// This is an experimental feature for now until it has more comprehensively tested and support is added in the async API.
+/// Partial encoding is always used if the codecs [support it](crate::array::codec::CodecTraits::supports_partial_encode) (e.g. `bytes` with an optional `transpose`), irrespective of this option.
+///
+/// ### Codec Thread Pool
+/// > default: [`None`]
+///
+/// [`CodecOptions::thread_pool()`] defaults to [`Config::codec_thread_pool()`].
+///
+/// If set, parallel codec encoding/decoding and array operations involving multiple chunks run in this [`rayon::ThreadPool`] rather than the current (e.g. global) thread pool.
+/// This isolates `zarrs` from other users of the global thread pool in an application.
 ///
 /// ## Metadata Options
 ///
@@ -70,7 +108,7 @@ use crate::array::{codec::CodecOptions, ArrayMetadataOptions};
 /// Some codecs perform potentially irreversible transformations during encoding that decoders do not need to be aware of.
 /// If this option is `false`, experimental codecs with this behaviour will not write their metadata.
 /// This enables arrays to be consumed by other zarr3 implementations that do not support the experimental codec.
-/// Currently, this options only affects the `bitround` codec.
+/// Currently, this options only affects the `bitround` and `quantize` codecs.
 ///
 /// ### Metadata Convert Version
 /// > default: [`MetadataConvertVersion::Default`] (keep existing version)
@@ -99,6 +137,35 @@ use crate::array::{codec::CodecOptions, ArrayMetadataOptions};
 ///  }
 /// ```
 ///
+/// ### Strict Additional Fields
+/// > default: [`false`]
+///
+/// [`ArrayMetadataOptions::strict_additional_fields`](crate::array::ArrayMetadataOptions::strict_additional_fields) and [`GroupMetadataOptions::strict_additional_fields`](crate::group::GroupMetadataOptions::strict_additional_fields) default to [`Config::strict_additional_fields`].
+///
+/// Additional fields of array and group metadata with `"must_understand": false` are always preserved when metadata is stored.
+/// An additional field with `"must_understand": true` cannot be opened unless it is an [understood additional field](#understood-additional-fields), but can be added with `additional_fields_mut`.
+/// If this option is `true`, the `store_metadata` methods of [`crate::array::Array`] and [`crate::group::Group`] error if such a field is present and not understood, otherwise it is stored as-is.
+///
+/// ### Understood Additional Fields
+/// > default: empty
+///
+/// The names of additional fields of array and group metadata (e.g. `"myorg:lineage"`) that are understood by the application.
+///
+/// Opening an array or group with an additional field with `"must_understand": true` fails, unless the field name is in this set.
+/// Understood additional fields are not reported as ignored by [`NodeOpenOptions`](crate::node::NodeOpenOptions), and are permitted with [strict extensions](crate::node::NodeOpenOptions::strict_extensions).
+/// The application is responsible for acting on the field, which is accessible with [`Array::additional_fields`](crate::array::Array::additional_fields) or [`Group::additional_fields`](crate::group::Group::additional_fields).
+/// Understood additional fields are preserved when metadata is stored.
+///
+/// ### `NCZarr` Compatibility
+/// > default: [`false`]
+///
+/// If `true`, Zarr V2 arrays and groups written by `netCDF-C` ([NCZarr](https://docs.unidata.ucar.edu/nug/current/nczarr_head.html)) can be opened:
+///  - the `_nczarr_*` members of `.zarray`, `.zgroup`, and `.zattrs` documents are moved to the additional fields of the metadata (with `"must_understand": false`),
+///  - floating point fill values encoded as quoted numbers (e.g. `"-999.0"`) are accepted, and
+///  - the `NCZarr` dimension references of an array are mapped to its [dimension names](crate::array::Array::dimension_names) if it has none.
+///
+/// See [`zarrs_metadata::v2::nczarr`](crate::metadata::v2::nczarr).
+///
 /// ### Experimental Codec Names
 /// > default: See the [crate root documentation](crate#array-support).
 ///
@@ -111,12 +178,18 @@ pub struct Config {
     store_empty_chunks: bool,
     codec_concurrent_target: usize,
     chunk_concurrent_minimum: usize,
+    chunk_concurrent_maximum: usize,
+    range_coalesce_gap: Option<u64>,
     experimental_codec_store_metadata_if_encode_only: bool,
     metadata_convert_version: MetadataConvertVersion,
     metadata_erase_version: MetadataEraseVersion,
     include_zarrs_metadata: bool,
+    strict_additional_fields: bool,
+    understood_additional_fields: HashSet<String>,
+    nczarr_compatibility: bool,
     experimental_codec_names: HashMap<String, String>,
     experimental_partial_encoding: bool,
+    codec_thread_pool: Option<Arc<ThreadPool>>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -127,17 +200,37 @@ impl Default for Config {
             // Array to array
             #[cfg(feature = "bitround")]
             (codec::bitround::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_array/bitround".to_string()),
+            #[cfg(feature = "expand_dims")]
+            (codec::expand_dims::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_array/expand_dims".to_string()),
+            #[cfg(feature = "quantize")]
+            (codec::quantize::IDENTIFIER.to_string(), "numcodecs.quantize".to_string()),
             // Array to bytes
+            #[cfg(feature = "jpegxl")]
+            (codec::jpegxl::IDENTIFIER.to_string(), "zarrs.jpegxl".to_string()),
             #[cfg(feature = "zfp")]
             (codec::zfp::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_bytes/zfp".to_string()),
             #[cfg(feature = "pcodec")]
             (codec::pcodec::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_bytes/pcodec".to_string()),
             (codec::vlen::IDENTIFIER.to_string(), "https://codec.zarrs.dev/array_to_bytes/vlen".to_string()),
+            #[cfg(feature = "webp")]
+            (codec::webp::IDENTIFIER.to_string(), "zarrs.webp".to_string()),
             // Bytes to bytes
+            #[cfg(feature = "adler32")]
+            (codec::adler32::IDENTIFIER.to_string(), "numcodecs.adler32".to_string()),
+            #[cfg(feature = "bitshuffle")]
+            (codec::bitshuffle::IDENTIFIER.to_string(), "numcodecs.bitshuffle".to_string()),
             #[cfg(feature = "bz2")]
             (codec::bz2::IDENTIFIER.to_string(), "https://codec.zarrs.dev/bytes_to_bytes/bz2".to_string()),
+            #[cfg(feature = "crc32")]
+            (codec::crc32::IDENTIFIER.to_string(), "numcodecs.crc32".to_string()),
             #[cfg(feature = "fletcher32")]
             (codec::fletcher32::IDENTIFIER.to_string(), "https://codec.zarrs.dev/bytes_to_bytes/fletcher32".to_string()),
+            #[cfg(feature = "jenkins_lookup3")]
+            (codec::jenkins_lookup3::IDENTIFIER.to_string(), "numcodecs.jenkins_lookup3".to_string()),
+            #[cfg(feature = "lz4")]
+            (codec::lz4::IDENTIFIER.to_string(), "numcodecs.lz4".to_string()),
+            #[cfg(feature = "lzma")]
+            (codec::lzma::IDENTIFIER.to_string(), "numcodecs.lzma".to_string()),
         ]);
 
         Self {
@@ -145,12 +238,18 @@ impl Default for Config {
             store_empty_chunks: false,
             codec_concurrent_target: rayon::current_num_threads(),
             chunk_concurrent_minimum: 4,
+            chunk_concurrent_maximum: rayon::current_num_threads(),
+            range_coalesce_gap: None,
             experimental_codec_store_metadata_if_encode_only: false,
             metadata_convert_version: MetadataConvertVersion::Default,
             metadata_erase_version: MetadataEraseVersion::Default,
             include_zarrs_metadata: true,
+            strict_additional_fields: false,
+            understood_additional_fields: HashSet::new(),
+            nczarr_compatibility: false,
             experimental_codec_names,
             experimental_partial_encoding: false,
+            codec_thread_pool: None,
         }
     }
 }
@@ -204,6 +303,30 @@ impl Config {
         self
     }
 
+    /// Get the [chunk concurrent maximum](#chunk-concurrent-maximum) configuration.
+    #[must_use]
+    pub fn chunk_concurrent_maximum(&self) -> usize {
+        self.chunk_concurrent_maximum
+    }
+
+    /// Set the [chunk concurrent maximum](#chunk-concurrent-maximum) configuration.
+    pub fn set_chunk_concurrent_maximum(&mut self, concurrent_maximum: usize) -> &mut Self {
+        self.chunk_concurrent_maximum = concurrent_maximum;
+        self
+    }
+
+    /// Get the [range coalesce gap](#range-coalesce-gap) configuration.
+    #[must_use]
+    pub fn range_coalesce_gap(&self) -> Option<u64> {
+        self.range_coalesce_gap
+    }
+
+    /// Set the [range coalesce gap](#range-coalesce-gap) configuration.
+    pub fn set_range_coalesce_gap(&mut self, range_coalesce_gap: Option<u64>) -> &mut Self {
+        self.range_coalesce_gap = range_coalesce_gap;
+        self
+    }
+
     /// Get the [experimental codec store metadata if encode only](#experimental-codec-store-metadata-if-encode-only) configuration.
     #[must_use]
     pub fn experimental_codec_store_metadata_if_encode_only(&self) -> bool {
@@ -255,6 +378,41 @@ impl Config {
         self
     }
 
+    /// Get the [strict additional fields](#strict-additional-fields) configuration.
+    #[must_use]
+    pub fn strict_additional_fields(&self) -> bool {
+        self.strict_additional_fields
+    }
+
+    /// Set the [strict additional fields](#strict-additional-fields) configuration.
+    pub fn set_strict_additional_fields(&mut self, strict_additional_fields: bool) -> &mut Self {
+        self.strict_additional_fields = strict_additional_fields;
+        self
+    }
+
+    /// Get the [understood additional fields](#understood-additional-fields) configuration.
+    #[must_use]
+    pub fn understood_additional_fields(&self) -> &HashSet<String> {
+        &self.understood_additional_fields
+    }
+
+    /// Get a mutable reference to the [understood additional fields](#understood-additional-fields) configuration.
+    pub fn understood_additional_fields_mut(&mut self) -> &mut HashSet<String> {
+        &mut self.understood_additional_fields
+    }
+
+    /// Get the [`NCZarr` compatibility](#nczarr-compatibility) configuration.
+    #[must_use]
+    pub fn nczarr_compatibility(&self) -> bool {
+        self.nczarr_compatibility
+    }
+
+    /// Set the [`NCZarr` compatibility](#nczarr-compatibility) configuration.
+    pub fn set_nczarr_compatibility(&mut self, nczarr_compatibility: bool) -> &mut Self {
+        self.nczarr_compatibility = nczarr_compatibility;
+        self
+    }
+
     /// Get the [experimental codec names](#experimental-codec-names) configuration.
     #[must_use]
     pub fn experimental_codec_names(&self) -> &HashMap<String, String> {
@@ -280,6 +438,18 @@ impl Config {
         self.experimental_partial_encoding = experimental_partial_encoding;
         self
     }
+
+    /// Get the [codec thread pool](#codec-thread-pool) configuration.
+    #[must_use]
+    pub fn codec_thread_pool(&self) -> Option<&Arc<ThreadPool>> {
+        self.codec_thread_pool.as_ref()
+    }
+
+    /// Set the [codec thread pool](#codec-thread-pool) configuration.
+    pub fn set_codec_thread_pool(&mut self, thread_pool: Option<Arc<ThreadPool>>) -> &mut Self {
+        self.codec_thread_pool = thread_pool;
+        self
+    }
 }
 
 static CONFIG: LazyLock<RwLock<Config>> = LazyLock::new(|| RwLock::new(Config::default()));
@@ -302,9 +472,11 @@ pub fn global_config_mut() -> RwLockWriteGuard<'static, Config> {
 
 /// The metadata version to retrieve.
 ///
-/// Used with [`crate::array::Array::open_opt`], [`crate::group::Group::open_opt`].
+/// Used with [`crate::array::Array::open_opt`], [`crate::group::Group::open_opt`], and [`NodeOpenOptions`](crate::node::NodeOpenOptions).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub enum MetadataRetrieveVersion {
     /// Either Zarr V3 or V2. V3 is prioritised over V2 if found.
+    #[default]
     Default,
     /// Zarr V3.
     V3,
@@ -0,0 +1,193 @@
+//! Typed accessors for attribute conventions.
+//!
+//! Zarr arrays and groups can hold arbitrary user attributes, and several communities have established conventions for their content.
+//! This module provides typed representations of widely used attribute conventions:
+//!  - [`ome`]: OME-Zarr `multiscales` (0.4 and 0.5),
+//!  - [`xarray`]: the `xarray` `_ARRAY_DIMENSIONS` attribute, and
+//!  - [`cf`]: the CF conventions `units` and `long_name` attributes.
+//!
+//! Any attribute (or the entire attributes object) can be read into a type implementing [`DeserializeOwned`] with [`Array::attribute`] / [`Group::attribute`] or [`Array::attributes_as`] / [`Group::attributes_as`], and written with [`Array::set_attribute`] / [`Group::set_attribute`].
+//! Deserialisation errors include the JSON path of the invalid value (e.g. `multiscales[0].axes[1].type`).
+//!
+//! This module only reads and writes attributes, it does not interpret them (e.g. by resampling multiscale levels).
+//! Attributes not represented by a typed struct are ignored when deserialising, so they are not retained if the struct is written back.
+//!
+//! ```rust
+//! # use std::sync::Arc;
+//! use zarrs::conventions::cf::DescriptiveAttributes;
+//! # let store = Arc::new(zarrs::storage::store::MemoryStore::new());
+//! # let mut array = zarrs::array::ArrayBuilder::new(
+//! #     vec![8], zarrs::array::DataType::Float32, vec![4].try_into()?, zarrs::array::FillValue::from(0.0f32)
+//! # ).build(store, "/array")?;
+//! array.set_attribute("units", "m")?;
+//! array.set_attribute("_ARRAY_DIMENSIONS", &["x"])?;
+//! assert_eq!(array.attribute::<String>("units")?.as_deref(), Some("m"));
+//! assert_eq!(array.attribute::<Vec<String>>("_ARRAY_DIMENSIONS")?, Some(vec!["x".to_string()]));
+//! let descriptive: DescriptiveAttributes = array.attributes_as()?;
+//! assert_eq!(descriptive.long_name, None);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+pub mod cf;
+pub mod ome;
+pub mod xarray;
+
+use serde::{de::DeserializeOwned, Serialize};
+use thiserror::Error;
+
+use crate::{array::Array, group::Group};
+
+/// An attribute error.
+#[derive(Debug, Error)]
+pub enum AttributeError {
+    /// An attribute could not be deserialised.
+    #[error("invalid attribute at {path}: {source}")]
+    Deserialize {
+        /// The JSON path of the invalid value.
+        path: String,
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// An attribute could not be serialised.
+    #[error("failed to serialise attribute {key}: {source}")]
+    Serialize {
+        /// The attribute key.
+        key: String,
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+}
+
+/// Deserialise `value`, the attribute at `key` (or the root attributes object if [`None`]).
+fn deserialize_value<T: DeserializeOwned>(
+    value: &serde_json::Value,
+    key: Option<&str>,
+) -> Result<T, AttributeError> {
+    serde_path_to_error::deserialize(value).map_err(|err| {
+        let path = err.path().to_string();
+        let path = match key {
+            None => path,
+            Some(key) if path == "." => key.to_string(),
+            Some(key) if path.starts_with('[') => format!("{key}{path}"),
+            Some(key) => format!("{key}.{path}"),
+        };
+        AttributeError::Deserialize {
+            path,
+            source: err.into_inner(),
+        }
+    })
+}
+
+/// Deserialise the attributes object `attributes` into `T`.
+///
+/// # Errors
+/// Returns an [`AttributeError`] if `attributes` cannot be deserialised into `T`.
+pub fn attributes_as<T: DeserializeOwned>(
+    attributes: &serde_json::Map<String, serde_json::Value>,
+) -> Result<T, AttributeError> {
+    serde_path_to_error::deserialize(attributes).map_err(|err| AttributeError::Deserialize {
+        path: err.path().to_string(),
+        source: err.into_inner(),
+    })
+}
+
+/// Deserialise the attribute at `key` in `attributes` into `T`.
+///
+/// Returns [`None`] if there is no attribute at `key`.
+///
+/// # Errors
+/// Returns an [`AttributeError`] if the attribute cannot be deserialised into `T`.
+pub fn attribute<T: DeserializeOwned>(
+    attributes: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<Option<T>, AttributeError> {
+    attributes
+        .get(key)
+        .map(|value| deserialize_value(value, Some(key)))
+        .transpose()
+}
+
+/// Serialise `value` and set it as the attribute at `key` in `attributes`, replacing any existing attribute.
+///
+/// # Errors
+/// Returns an [`AttributeError`] if `value` cannot be serialised.
+pub fn set_attribute<T: Serialize + ?Sized>(
+    attributes: &mut serde_json::Map<String, serde_json::Value>,
+    key: &str,
+    value: &T,
+) -> Result<(), AttributeError> {
+    let value = serde_json::to_value(value).map_err(|source| AttributeError::Serialize {
+        key: key.to_string(),
+        source,
+    })?;
+    attributes.insert(key.to_string(), value);
+    Ok(())
+}
+
+impl<TStorage: ?Sized> Array<TStorage> {
+    /// Deserialise the array attributes into `T`.
+    ///
+    /// # Errors
+    /// Returns an [`AttributeError`] if the attributes cannot be deserialised into `T`.
+    pub fn attributes_as<T: DeserializeOwned>(&self) -> Result<T, AttributeError> {
+        attributes_as(self.attributes())
+    }
+
+    /// Deserialise the array attribute at `key` into `T`.
+    ///
+    /// Returns [`None`] if there is no attribute at `key`.
+    ///
+    /// # Errors
+    /// Returns an [`AttributeError`] if the attribute cannot be deserialised into `T`.
+    pub fn attribute<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, AttributeError> {
+        attribute(self.attributes(), key)
+    }
+
+    /// Serialise `value` and set it as the array attribute at `key`.
+    ///
+    /// The attributes must be explicitly stored (e.g. with [`store_metadata`](Array::store_metadata)).
+    ///
+    /// # Errors
+    /// Returns an [`AttributeError`] if `value` cannot be serialised.
+    pub fn set_attribute<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), AttributeError> {
+        set_attribute(self.attributes_mut(), key, value)
+    }
+}
+
+impl<TStorage: ?Sized> Group<TStorage> {
+    /// Deserialise the group attributes into `T`.
+    ///
+    /// # Errors
+    /// Returns an [`AttributeError`] if the attributes cannot be deserialised into `T`.
+    pub fn attributes_as<T: DeserializeOwned>(&self) -> Result<T, AttributeError> {
+        attributes_as(self.attributes())
+    }
+
+    /// Deserialise the group attribute at `key` into `T`.
+    ///
+    /// Returns [`None`] if there is no attribute at `key`.
+    ///
+    /// # Errors
+    /// Returns an [`AttributeError`] if the attribute cannot be deserialised into `T`.
+    pub fn attribute<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, AttributeError> {
+        attribute(self.attributes(), key)
+    }
+
+    /// Serialise `value` and set it as the group attribute at `key`.
+    ///
+    /// The attributes must be explicitly stored (e.g. with [`store_metadata`](Group::store_metadata)).
+    ///
+    /// # Errors
+    /// Returns an [`AttributeError`] if `value` cannot be serialised.
+    pub fn set_attribute<T: Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), AttributeError> {
+        set_attribute(self.attributes_mut(), key, value)
+    }
+}
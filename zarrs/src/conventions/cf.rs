@@ -0,0 +1,24 @@
+//! The CF conventions `units` and `long_name` attributes.
+//!
+//! See <https://cfconventions.org/Data/cf-conventions/cf-conventions-1.11/cf-conventions.html#units>.
+
+use serde::{Deserialize, Serialize};
+
+/// The `units` attribute key.
+pub const UNITS: &str = "units";
+
+/// The `long_name` attribute key.
+pub const LONG_NAME: &str = "long_name";
+
+/// Descriptive attributes of an array.
+///
+/// Deserialise with [`Array::attributes_as`](crate::array::Array::attributes_as).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct DescriptiveAttributes {
+    /// The units of the array elements (e.g. `m s-1`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub units: Option<String>,
+    /// A descriptive name of the array.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub long_name: Option<String>,
+}
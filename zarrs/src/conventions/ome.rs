@@ -0,0 +1,134 @@
+//! OME-Zarr `multiscales` attributes.
+//!
+//! OME-Zarr 0.5 nests the `multiscales` in the `ome` attribute alongside a `version`.
+//! OME-Zarr 0.4 and earlier place the `multiscales` directly in the attributes, with a `version` in each multiscale.
+//! [`multiscales`] and [`set_multiscales`] support both layouts.
+//!
+//! See <https://ngff.openmicroscopy.org/0.4/#multiscale-md> and <https://ngff.openmicroscopy.org/0.5/#multiscale-md>.
+
+use serde::{Deserialize, Serialize};
+
+use super::{attribute, set_attribute, AttributeError};
+
+/// The OME-Zarr 0.5 `ome` attribute key.
+pub const OME: &str = "ome";
+
+/// The `multiscales` attribute key.
+pub const MULTISCALES: &str = "multiscales";
+
+/// The OME-Zarr 0.5 `ome` attribute.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Ome {
+    /// The OME-Zarr version.
+    pub version: String,
+    /// The multiscale images.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub multiscales: Vec<Multiscale>,
+}
+
+/// An OME-Zarr multiscale image.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Multiscale {
+    /// The OME-Zarr version (OME-Zarr 0.4 and earlier).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The name of the multiscale image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The axes of the multiscale image.
+    pub axes: Vec<Axis>,
+    /// The multiscale levels, ordered from the highest to the lowest resolution.
+    pub datasets: Vec<Dataset>,
+    /// Coordinate transformations applied to all datasets.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coordinate_transformations: Option<Vec<CoordinateTransformation>>,
+    /// The type of downscaling method used to generate the multiscale image.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub downscaling_type: Option<String>,
+    /// Unstructured information about the downscaling method.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// An OME-Zarr axis.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Axis {
+    /// The name of the axis.
+    pub name: String,
+    /// The type of the axis (e.g. `space`, `time`, or `channel`).
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub axis_type: Option<String>,
+    /// The unit of the axis (e.g. `micrometer`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+/// An OME-Zarr multiscale level.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Dataset {
+    /// The path of the array relative to the group holding the multiscales.
+    pub path: String,
+    /// The coordinate transformations of the array.
+    pub coordinate_transformations: Vec<CoordinateTransformation>,
+}
+
+/// An OME-Zarr coordinate transformation.
+///
+/// Coordinate transformations referencing a binary data `path` are not supported.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CoordinateTransformation {
+    /// The identity transformation.
+    Identity,
+    /// A scale for each axis.
+    Scale {
+        /// The scale for each axis.
+        scale: Vec<f64>,
+    },
+    /// A translation for each axis.
+    Translation {
+        /// The translation for each axis.
+        translation: Vec<f64>,
+    },
+}
+
+/// Read the OME-Zarr `multiscales` from `attributes`.
+///
+/// The `multiscales` are read from the `ome` attribute (OME-Zarr 0.5) if present, otherwise they are read directly from the attributes (OME-Zarr 0.4 and earlier).
+/// Returns [`None`] if there are no `multiscales`.
+///
+/// # Errors
+/// Returns an [`AttributeError`] if the `multiscales` are invalid.
+pub fn multiscales(
+    attributes: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Option<Vec<Multiscale>>, AttributeError> {
+    if let Some(ome) = attribute::<Ome>(attributes, OME)? {
+        Ok(Some(ome.multiscales))
+    } else {
+        attribute(attributes, MULTISCALES)
+    }
+}
+
+/// Write OME-Zarr `multiscales` to `attributes`.
+///
+/// If `version` is [`Some`], the `multiscales` are written to the `ome` attribute with that version (OME-Zarr 0.5), otherwise they are written directly to the attributes (OME-Zarr 0.4 and earlier).
+///
+/// # Errors
+/// Returns an [`AttributeError`] if the `multiscales` cannot be serialised.
+pub fn set_multiscales(
+    attributes: &mut serde_json::Map<String, serde_json::Value>,
+    multiscales: &[Multiscale],
+    version: Option<&str>,
+) -> Result<(), AttributeError> {
+    if let Some(version) = version {
+        let ome = Ome {
+            version: version.to_string(),
+            multiscales: multiscales.to_vec(),
+        };
+        set_attribute(attributes, OME, &ome)
+    } else {
+        set_attribute(attributes, MULTISCALES, multiscales)
+    }
+}
@@ -0,0 +1,34 @@
+//! The `xarray` `_ARRAY_DIMENSIONS` attribute.
+//!
+//! `xarray` stores the dimension names of a Zarr V2 array in the `_ARRAY_DIMENSIONS` attribute.
+//! Zarr V3 arrays have dimension names in their metadata, see [`Array::dimension_names`](crate::array::Array::dimension_names).
+//!
+//! See <https://docs.xarray.dev/en/latest/internals/zarr-encoding-spec.html>.
+
+use super::{attribute, set_attribute, AttributeError};
+
+pub use crate::metadata::v2_to_v3::ARRAY_DIMENSIONS_ATTRIBUTE as ARRAY_DIMENSIONS;
+
+/// Read the `_ARRAY_DIMENSIONS` attribute from `attributes`.
+///
+/// Returns [`None`] if there is no `_ARRAY_DIMENSIONS` attribute.
+///
+/// # Errors
+/// Returns an [`AttributeError`] if the attribute is not a list of strings.
+pub fn array_dimensions(
+    attributes: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Option<Vec<String>>, AttributeError> {
+    attribute(attributes, ARRAY_DIMENSIONS)
+}
+
+/// Write the `_ARRAY_DIMENSIONS` attribute to `attributes`.
+///
+/// # Errors
+/// Returns an [`AttributeError`] if the dimension names cannot be serialised.
+pub fn set_array_dimensions(
+    attributes: &mut serde_json::Map<String, serde_json::Value>,
+    dimensions: &[impl AsRef<str>],
+) -> Result<(), AttributeError> {
+    let dimensions: Vec<&str> = dimensions.iter().map(AsRef::as_ref).collect();
+    set_attribute(attributes, ARRAY_DIMENSIONS, &dimensions)
+}
@@ -22,6 +22,19 @@
 //! }
 //! ```
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#group-metadata> for more information on group metadata.
+//!
+//! ## Child Nodes
+//! The children of a group in listable storage are discovered with [`children`](Group::children) and related methods.
+//! Storage that is only readable (e.g. a HTTP store) cannot be listed, so children must be retrieved by name with [`child`](Group::child) or [`children_from_names`](Group::children_from_names).
+//! [`children_from_attributes`](Group::children_from_attributes) retrieves the children referenced by OME-Zarr `multiscales` attributes.
+//!
+//! [`child_arrays`](Group::child_arrays) and [`child_groups`](Group::child_groups) return the child [`Array`]s and [`Group`]s of a group, and [`child_array`](Group::child_array) and [`child_group`](Group::child_group) return a child by name.
+//! These use the consolidated metadata of the group if it is present, rather than reading the metadata of each child from the store.
+//!
+//! ## Storage Transformers
+//! Zarr V3 group metadata can have a `storage_transformers` field (an extension to the Zarr V3 specification) with storage transformers that apply to all child nodes of the group.
+//! These are applied to the arrays returned by [`child_arrays`](Group::child_arrays) and inherited by the groups returned by [`child_groups`](Group::child_groups).
+//! See the [storage transformer ordering](crate::array::storage_transformer#ordering).
 
 mod group_builder;
 mod group_metadata_options;
@@ -35,27 +48,36 @@ use zarrs_metadata::NodeMetadata;
 use zarrs_storage::ListableStorageTraits;
 
 use crate::{
-    array::{Array, ArrayCreateError},
+    array::{storage_transformer::StorageTransformerChain, Array, ArrayCreateError},
     config::{
         global_config, MetadataConvertVersion, MetadataEraseVersion, MetadataRetrieveVersion,
     },
     metadata::{
-        v2::GroupMetadataV2,
         v2_to_v3::group_metadata_v2_to_v3,
         v3::{AdditionalFields, UnsupportedAdditionalFieldError},
     },
     node::{
-        _get_child_nodes, meta_key_v2_attributes, meta_key_v2_group, meta_key_v3, Node, NodePath,
+        _get_child_nodes, check_additional_fields_must_understand, erase_node_opt,
+        group_metadata_v2_from_documents, meta_key_v2_attributes, meta_key_v2_group, meta_key_v3,
+        mutate_document_attributes, serialize_document, stored_attributes_v2,
+        stored_metadata_document, validate_relative_node_path, Node, NodeCreateError,
+        NodeEraseError, NodeEraseOptions, NodeOpenOptions, NodeOpenWarning, NodePath,
         NodePathError,
     },
-    storage::{ReadableStorageTraits, StorageError, StorageHandle, WritableStorageTraits},
+    plugin::PluginCreateError,
+    storage::{
+        storage_adapter::read_only::ReadOnlyStorageAdapter, ReadableStorageTraits,
+        ReadableWritableStorageTraits, StorageError, StorageHandle, StoreKeys,
+        WritableStorageTraits,
+    },
 };
 
 #[cfg(feature = "async")]
-use crate::node::_async_get_child_nodes;
+use crate::node::{_async_get_child_nodes, async_erase_node_opt};
 #[cfg(feature = "async")]
 use crate::storage::{
-    AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits,
+    AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncReadableWritableStorageTraits,
+    AsyncWritableStorageTraits,
 };
 
 pub use self::group_builder::GroupBuilder;
@@ -77,6 +99,8 @@ pub struct Group<TStorage: ?Sized> {
     path: NodePath,
     /// The metadata.
     metadata: GroupMetadata,
+    /// The storage transformers applied to child nodes, including inherited storage transformers.
+    storage_transformers: StorageTransformerChain,
 }
 
 impl<TStorage: ?Sized> Group<TStorage> {
@@ -92,13 +116,112 @@ impl<TStorage: ?Sized> Group<TStorage> {
         metadata: GroupMetadata,
     ) -> Result<Self, GroupCreateError> {
         let path = NodePath::new(path)?;
+        let storage_transformers = group_storage_transformers(&metadata, &path)
+            .map_err(GroupCreateError::StorageTransformersCreateError)?;
         Ok(Self {
             storage,
             path,
             metadata,
+            storage_transformers,
         })
     }
 
+    /// Get the storage transformers applied to the child nodes of the group.
+    ///
+    /// These are the storage transformers in the group metadata preceded by those inherited from ancestor groups that the group was opened through.
+    /// See the [storage transformer ordering](crate::array::storage_transformer#ordering).
+    #[must_use]
+    pub const fn storage_transformers(&self) -> &StorageTransformerChain {
+        &self.storage_transformers
+    }
+
+    /// Return a read only view of the group.
+    ///
+    /// The storage of the returned group is wrapped in a [`ReadOnlyStorageAdapter`], so metadata writes fail with [`StorageError::ReadOnly`] and the underlying store is never modified.
+    /// Child arrays and groups opened through the returned group are also read only.
+    #[must_use]
+    pub fn readonly(&self) -> Group<ReadOnlyStorageAdapter<TStorage>> {
+        Group {
+            storage: Arc::new(ReadOnlyStorageAdapter::new(self.storage.clone())),
+            path: self.path.clone(),
+            metadata: self.metadata.clone(),
+            storage_transformers: self.storage_transformers.clone(),
+        }
+    }
+
+    /// Apply `storage_transformers` before the storage transformers in the group metadata.
+    #[must_use]
+    fn with_inherited_storage_transformers(
+        mut self,
+        storage_transformers: &StorageTransformerChain,
+    ) -> Self {
+        self.storage_transformers = storage_transformers.chain(&self.storage_transformers);
+        self
+    }
+
+    /// Return the groups and arrays in `nodes` and their descendants with the storage transformers they inherit.
+    fn child_nodes_with_storage_transformers(
+        &self,
+        nodes: Vec<Node>,
+    ) -> Result<Vec<(NodePath, NodeMetadata, StorageTransformerChain)>, PluginCreateError> {
+        fn flatten(
+            nodes: Vec<Node>,
+            storage_transformers: &StorageTransformerChain,
+            flattened: &mut Vec<(NodePath, NodeMetadata, StorageTransformerChain)>,
+        ) -> Result<(), PluginCreateError> {
+            for node in nodes {
+                let (path, metadata, children) = node.into_parts();
+                if let NodeMetadata::Group(metadata) = &metadata {
+                    let group_storage_transformers =
+                        storage_transformers.chain(&group_storage_transformers(metadata, &path)?);
+                    flatten(children, &group_storage_transformers, flattened)?;
+                }
+                flattened.push((path, metadata, storage_transformers.clone()));
+            }
+            Ok(())
+        }
+
+        let mut flattened = Vec::new();
+        flatten(nodes, &self.storage_transformers, &mut flattened)?;
+        flattened.sort_by(|(a, _, _), (b, _, _)| a.as_str().cmp(b.as_str()));
+        Ok(flattened)
+    }
+
+    /// Return the groups in `nodes` and their descendants.
+    fn child_groups_from_nodes(&self, nodes: Vec<Node>) -> Result<Vec<Self>, GroupCreateError> {
+        self.child_nodes_with_storage_transformers(nodes)
+            .map_err(GroupCreateError::StorageTransformersCreateError)?
+            .into_iter()
+            .filter_map(|(path, metadata, storage_transformers)| match metadata {
+                NodeMetadata::Group(metadata) => Some(
+                    Group::new_with_metadata(self.storage.clone(), path.as_str(), metadata).map(
+                        |group| group.with_inherited_storage_transformers(&storage_transformers),
+                    ),
+                ),
+                NodeMetadata::Array(_) => None,
+            })
+            .collect()
+    }
+
+    /// Return the arrays in `nodes` and their descendants.
+    fn child_arrays_from_nodes(
+        &self,
+        nodes: Vec<Node>,
+    ) -> Result<Vec<Array<TStorage>>, ArrayCreateError> {
+        self.child_nodes_with_storage_transformers(nodes)
+            .map_err(ArrayCreateError::StorageTransformersCreateError)?
+            .into_iter()
+            .filter_map(|(path, metadata, storage_transformers)| match metadata {
+                NodeMetadata::Array(metadata) => Some(
+                    Array::new_with_metadata(self.storage.clone(), path.as_str(), metadata).map(
+                        |array| array.with_inherited_storage_transformers(&storage_transformers),
+                    ),
+                ),
+                NodeMetadata::Group(_) => None,
+            })
+            .collect()
+    }
+
     /// Get path.
     #[must_use]
     pub const fn path(&self) -> &NodePath {
@@ -186,6 +309,83 @@ impl<TStorage: ?Sized> Group<TStorage> {
         }
     }
 
+    /// Return the path of the child of the group at the relative path `name`.
+    fn child_path(&self, name: &str) -> Result<NodePath, NodePathError> {
+        let parent = self.path.as_str().trim_end_matches('/');
+        NodePath::new(&format!("{parent}/{name}"))
+    }
+
+    /// Return the consolidated metadata of the child of the group at the relative path `name`, if present.
+    fn consolidated_child_metadata(&self, name: &str) -> Option<&NodeMetadata> {
+        self.consolidated_metadata()
+            .and_then(|consolidated_metadata| consolidated_metadata.metadata.get(name))
+    }
+
+    /// Return the children of the group from its consolidated metadata, or [`None`] if it is absent.
+    fn consolidated_children(&self, recursive: bool) -> Result<Option<Vec<Node>>, NodePathError> {
+        fn children(
+            group: &Group<impl ?Sized>,
+            consolidated_metadata: &ConsolidatedMetadata,
+            parent: &str,
+            recursive: bool,
+        ) -> Result<Vec<Node>, NodePathError> {
+            let mut nodes = Vec::new();
+            for (name, metadata) in &consolidated_metadata.metadata {
+                if name
+                    .rsplit_once('/')
+                    .map_or("", |(name_parent, _)| name_parent)
+                    != parent
+                {
+                    continue;
+                }
+                let grandchildren = match metadata {
+                    NodeMetadata::Group(_) if recursive => {
+                        children(group, consolidated_metadata, name, recursive)?
+                    }
+                    _ => vec![],
+                };
+                nodes.push(Node::new_with_metadata(
+                    group.child_path(name)?,
+                    metadata.clone(),
+                    grandchildren,
+                ));
+            }
+            nodes.sort_by(|a, b| a.path().as_str().cmp(b.path().as_str()));
+            Ok(nodes)
+        }
+
+        self.consolidated_metadata()
+            .map(|consolidated_metadata| children(self, consolidated_metadata, "", recursive))
+            .transpose()
+    }
+
+    /// Return the relative paths of the children of the group referenced by its attributes.
+    ///
+    /// These are the `path`s of the `datasets` of OME-Zarr `multiscales`.
+    /// The `multiscales` are read from the `ome` attribute (OME-Zarr 0.5) or directly from the attributes (OME-Zarr 0.4 and earlier).
+    fn attribute_child_names(&self) -> Vec<String> {
+        let attributes = self.attributes();
+        let multiscales = attributes
+            .get("ome")
+            .and_then(|ome| ome.get("multiscales"))
+            .or_else(|| attributes.get("multiscales"))
+            .and_then(serde_json::Value::as_array);
+        let mut names: Vec<String> = Vec::new();
+        for multiscale in multiscales.into_iter().flatten() {
+            let datasets = multiscale
+                .get("datasets")
+                .and_then(serde_json::Value::as_array);
+            for dataset in datasets.into_iter().flatten() {
+                if let Some(path) = dataset.get("path").and_then(serde_json::Value::as_str) {
+                    if !names.iter().any(|name| name == path) {
+                        names.push(path.to_string());
+                    }
+                }
+            }
+        }
+        names
+    }
+
     /// Convert the group to Zarr V3.
     ///
     /// If the group is already Zarr V3, this is a no-op.
@@ -197,6 +397,7 @@ impl<TStorage: ?Sized> Group<TStorage> {
                 storage: self.storage,
                 path: self.path,
                 metadata,
+                storage_transformers: self.storage_transformers,
             }
         } else {
             self
@@ -223,6 +424,37 @@ impl<TStorage: ?Sized + ReadableStorageTraits> Group<TStorage> {
         storage: Arc<TStorage>,
         path: &str,
         version: &MetadataRetrieveVersion,
+    ) -> Result<Self, GroupCreateError> {
+        let options = NodeOpenOptions::default().with_version(version.clone());
+        Self::open_with_options(storage, path, &options).map(|(group, _warnings)| group)
+    }
+
+    /// Open a group in `storage` at `path` with [`NodeOpenOptions`].
+    /// The metadata is read from the store.
+    ///
+    /// Returns the group and a [`NodeOpenWarning`] for each part of the metadata that was ignored.
+    ///
+    /// # Errors
+    /// Returns [`GroupCreateError`] if there is a storage error or any metadata is invalid, including if
+    ///  - only metadata of a different Zarr version than requested is found,
+    ///  - the metadata has an additional field with `"must_understand": true` that is not an [understood additional field](crate::config::Config#understood-additional-fields), or
+    ///  - the metadata has additional fields and [strict extensions](NodeOpenOptions::strict_extensions) are enabled.
+    pub fn open_with_options(
+        storage: Arc<TStorage>,
+        path: &str,
+        options: &NodeOpenOptions,
+    ) -> Result<(Self, Vec<NodeOpenWarning>), GroupCreateError> {
+        let group = Self::open_metadata(storage, path, options.version())?;
+        let warnings = options
+            .check_additional_fields(group.additional_fields())
+            .map_err(GroupCreateError::UnsupportedAdditionalFieldError)?;
+        Ok((group, warnings))
+    }
+
+    fn open_metadata(
+        storage: Arc<TStorage>,
+        path: &str,
+        version: MetadataRetrieveVersion,
     ) -> Result<Self, GroupCreateError> {
         let node_path = path.try_into()?;
 
@@ -240,27 +472,188 @@ impl<TStorage: ?Sized + ReadableStorageTraits> Group<TStorage> {
             // Try Zarr V2
             let key_v2 = meta_key_v2_group(&node_path);
             if let Some(metadata) = storage.get(&key_v2)? {
-                let mut metadata: GroupMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(key_v2, err.to_string()))?;
                 let attributes_key = meta_key_v2_attributes(&node_path);
                 let attributes = storage.get(&attributes_key)?;
-                if let Some(attributes) = attributes {
-                    metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
-                    })?;
-                }
+                let metadata = group_metadata_v2_from_documents(
+                    &key_v2,
+                    &metadata,
+                    &attributes_key,
+                    attributes,
+                )?;
                 return Self::new_with_metadata(storage, path, GroupMetadata::V2(metadata));
             }
         }
 
+        // Check for metadata of the other Zarr version
+        let found = match version {
+            MetadataRetrieveVersion::V3 => storage
+                .size_key(&meta_key_v2_group(&node_path))?
+                .map(|_| MetadataRetrieveVersion::V2),
+            MetadataRetrieveVersion::V2 => storage
+                .size_key(&meta_key_v3(&node_path))?
+                .map(|_| MetadataRetrieveVersion::V3),
+            MetadataRetrieveVersion::Default => None,
+        };
+        if let Some(found) = found {
+            return Err(GroupCreateError::MetadataVersionMismatch {
+                expected: version,
+                found,
+            });
+        }
+
         // No metadata has been found
         Err(GroupCreateError::MissingMetadata)
     }
+
+    /// Return the child of the group at the relative path `name`, or [`None`] if it does not exist.
+    ///
+    /// Unlike [`children`](Group::children), this does not require the storage to be listable.
+    /// The child metadata is retrieved directly from the store (e.g. `name/zarr.json`, `name/.zarray`, or `name/.zgroup`).
+    /// The children of the returned [`Node`] are not populated.
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if `name` is not a valid relative path, there is a storage error, or any metadata is invalid.
+    pub fn child(&self, name: &str) -> Result<Option<Node>, NodeCreateError> {
+        let path = self.child_path(name)?;
+        match Node::get_metadata(&self.storage, &path, &MetadataRetrieveVersion::Default) {
+            Ok(metadata) => Ok(Some(Node::new_with_metadata(path, metadata, vec![]))),
+            Err(NodeCreateError::MissingMetadata) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Return the children of the group at the relative paths `names`.
+    ///
+    /// Each child is retrieved with [`child`](Group::child), so this does not require the storage to be listable.
+    /// Names that do not refer to a node are skipped.
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if a name is not a valid relative path, there is a storage error, or any metadata is invalid.
+    pub fn children_from_names<T: AsRef<str>>(
+        &self,
+        names: &[T],
+    ) -> Result<Vec<Node>, NodeCreateError> {
+        let mut nodes = Vec::with_capacity(names.len());
+        for name in names {
+            if let Some(node) = self.child(name.as_ref())? {
+                nodes.push(node);
+            }
+        }
+        Ok(nodes)
+    }
+
+    /// Return the children of the group referenced by its attributes.
+    ///
+    /// This is a fallback for discovering children in storage that is not listable (e.g. a HTTP store).
+    /// Children are referenced by the `path`s of the `datasets` of OME-Zarr `multiscales`, in either the `ome` attribute (OME-Zarr 0.5) or directly in the attributes (OME-Zarr 0.4 and earlier).
+    /// Use [`children_from_names`](Group::children_from_names) if the child names are known by other means.
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if a referenced path is invalid, there is a storage error, or any metadata is invalid.
+    pub fn children_from_attributes(&self) -> Result<Vec<Node>, NodeCreateError> {
+        self.children_from_names(&self.attribute_child_names())
+    }
+
+    /// Return the node at the relative path `name` with the storage transformers it inherits, or [`None`] if it does not exist.
+    ///
+    /// The storage transformers of any groups between the group and the node are inherited.
+    /// Metadata is read from the consolidated metadata of the group if present, otherwise from the store.
+    fn child_node_with_storage_transformers<E: From<NodePathError> + From<StorageError>>(
+        &self,
+        name: &str,
+        storage_transformers_err: fn(PluginCreateError) -> E,
+    ) -> Result<Option<(NodePath, NodeMetadata, StorageTransformerChain)>, E> {
+        let name = name.trim_matches('/');
+        let mut storage_transformers = self.storage_transformers.clone();
+        let mut components = name.split('/').peekable();
+        let mut relative_path = String::new();
+        while let Some(component) = components.next() {
+            if !relative_path.is_empty() {
+                relative_path.push('/');
+            }
+            relative_path.push_str(component);
+            let path = self.child_path(&relative_path)?;
+            let metadata = match self.consolidated_child_metadata(&relative_path) {
+                Some(metadata) => Some(metadata.clone()),
+                None => match Node::get_metadata(
+                    &self.storage,
+                    &path,
+                    &MetadataRetrieveVersion::Default,
+                ) {
+                    Ok(metadata) => Some(metadata),
+                    Err(NodeCreateError::NodePathError(err)) => return Err(err.into()),
+                    Err(NodeCreateError::StorageError(err)) => return Err(err.into()),
+                    Err(
+                        NodeCreateError::MissingMetadata | NodeCreateError::MetadataVersionMismatch,
+                    ) => None,
+                },
+            };
+            if components.peek().is_none() {
+                return Ok(metadata.map(|metadata| (path, metadata, storage_transformers)));
+            }
+            match metadata {
+                Some(NodeMetadata::Group(metadata)) => {
+                    storage_transformers = storage_transformers.chain(
+                        &group_storage_transformers(&metadata, &path)
+                            .map_err(storage_transformers_err)?,
+                    );
+                }
+                Some(NodeMetadata::Array(_)) => return Ok(None),
+                None => {}
+            }
+        }
+        Ok(None)
+    }
+
+    /// Return the [`Array`] at the relative path `name`.
+    ///
+    /// The array metadata is read from the consolidated metadata of the group if present, otherwise from the store (Zarr V3 or V2).
+    /// Unlike [`child_arrays`](Group::child_arrays), this does not require the storage to be listable.
+    /// The array inherits the storage transformers of the group and of any groups between the group and the array.
+    ///
+    /// # Errors
+    /// Returns [`ArrayCreateError`] if there is no array at `name`, there is a storage error, or any metadata is invalid.
+    pub fn child_array(&self, name: &str) -> Result<Array<TStorage>, ArrayCreateError> {
+        match self.child_node_with_storage_transformers(
+            name,
+            ArrayCreateError::StorageTransformersCreateError,
+        )? {
+            Some((path, NodeMetadata::Array(metadata), storage_transformers)) => Ok(
+                Array::new_with_metadata(self.storage.clone(), path.as_str(), metadata)?
+                    .with_inherited_storage_transformers(&storage_transformers),
+            ),
+            Some((_, NodeMetadata::Group(_), _)) | None => Err(ArrayCreateError::MissingMetadata),
+        }
+    }
+
+    /// Return the [`Group`] at the relative path `name`.
+    ///
+    /// The group metadata is read from the consolidated metadata of the group if present, otherwise from the store (Zarr V3 or V2).
+    /// Unlike [`child_groups`](Group::child_groups), this does not require the storage to be listable.
+    /// The group inherits the storage transformers of the group and of any groups between the group and the child group.
+    ///
+    /// # Errors
+    /// Returns [`GroupCreateError`] if there is no group at `name`, there is an array at `name`, there is a storage error, or any metadata is invalid.
+    pub fn child_group(&self, name: &str) -> Result<Self, GroupCreateError> {
+        match self.child_node_with_storage_transformers(
+            name,
+            GroupCreateError::StorageTransformersCreateError,
+        )? {
+            Some((path, NodeMetadata::Group(metadata), storage_transformers)) => Ok(
+                Self::new_with_metadata(self.storage.clone(), path.as_str(), metadata)?
+                    .with_inherited_storage_transformers(&storage_transformers),
+            ),
+            Some((path, NodeMetadata::Array(_), _)) => Err(GroupCreateError::ArrayExists(path)),
+            None => Err(GroupCreateError::MissingMetadata),
+        }
+    }
 }
 
 impl<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits> Group<TStorage> {
     /// Return the children of the group
     ///
+    /// See [`child`](Group::child) and [`children_from_attributes`](Group::children_from_attributes) for storage that is not listable.
+    ///
     /// # Errors
     /// Returns [`StorageError`] if there is an underlying error with the store.
     pub fn children(&self, recursive: bool) -> Result<Vec<Node>, StorageError> {
@@ -270,46 +663,32 @@ impl<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits> Group<TSt
 
     /// Return the children of the group that are [`Group`]s
     ///
+    /// The children are read from the consolidated metadata of the group if present, otherwise they are discovered by listing the store.
+    /// See [`child_group`](Group::child_group) to retrieve a child group by name.
+    ///
     /// # Errors
     /// Returns [`GroupCreateError`] if there is a storage error or any metadata is invalid.
     pub fn child_groups(&self, recursive: bool) -> Result<Vec<Self>, GroupCreateError> {
-        self.children(recursive)?
-            .into_iter()
-            .filter_map(|node| {
-                let name = node.name();
-                let metadata: NodeMetadata = node.into();
-                match metadata {
-                    NodeMetadata::Group(metadata) => Some(Group::new_with_metadata(
-                        self.storage.clone(),
-                        name.as_str(),
-                        metadata,
-                    )),
-                    NodeMetadata::Array(_) => None,
-                }
-            })
-            .collect()
+        let nodes = match self.consolidated_children(recursive)? {
+            Some(nodes) => nodes,
+            None => self.children(recursive)?,
+        };
+        self.child_groups_from_nodes(nodes)
     }
 
     /// Return the children of the group that are [`Array`]s
     ///
+    /// The children are read from the consolidated metadata of the group if present, otherwise they are discovered by listing the store.
+    /// See [`child_array`](Group::child_array) to retrieve a child array by name.
+    ///
     /// # Errors
     /// Returns [`ArrayCreateError`] if there is a storage error or any metadata is invalid.
     pub fn child_arrays(&self, recursive: bool) -> Result<Vec<Array<TStorage>>, ArrayCreateError> {
-        self.children(recursive)?
-            .into_iter()
-            .filter_map(|node| {
-                let name = node.name();
-                let metadata: NodeMetadata = node.into();
-                match metadata {
-                    NodeMetadata::Array(metadata) => Some(Array::new_with_metadata(
-                        self.storage.clone(),
-                        name.as_str(),
-                        metadata.clone(),
-                    )),
-                    NodeMetadata::Group(_) => None,
-                }
-            })
-            .collect()
+        let nodes = match self.consolidated_children(recursive)? {
+            Some(nodes) => nodes,
+            None => self.children(recursive)?,
+        };
+        self.child_arrays_from_nodes(nodes)
     }
 
     /// Return the paths of the groups children
@@ -372,6 +751,31 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> Group<TStorage> {
         storage: Arc<TStorage>,
         path: &str,
         version: &MetadataRetrieveVersion,
+    ) -> Result<Self, GroupCreateError> {
+        let options = NodeOpenOptions::default().with_version(version.clone());
+        Self::async_open_with_options(storage, path, &options)
+            .await
+            .map(|(group, _warnings)| group)
+    }
+
+    /// Async variant of [`open_with_options`](Group::open_with_options).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_open_with_options(
+        storage: Arc<TStorage>,
+        path: &str,
+        options: &NodeOpenOptions,
+    ) -> Result<(Self, Vec<NodeOpenWarning>), GroupCreateError> {
+        let group = Self::async_open_metadata(storage, path, options.version()).await?;
+        let warnings = options
+            .check_additional_fields(group.additional_fields())
+            .map_err(GroupCreateError::UnsupportedAdditionalFieldError)?;
+        Ok((group, warnings))
+    }
+
+    async fn async_open_metadata(
+        storage: Arc<TStorage>,
+        path: &str,
+        version: MetadataRetrieveVersion,
     ) -> Result<Self, GroupCreateError> {
         let node_path = path.try_into()?;
 
@@ -389,22 +793,164 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> Group<TStorage> {
             // Try Zarr V2
             let key_v2 = meta_key_v2_group(&node_path);
             if let Some(metadata) = storage.get(&key_v2).await? {
-                let mut metadata: GroupMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(key_v2, err.to_string()))?;
                 let attributes_key = meta_key_v2_attributes(&node_path);
                 let attributes = storage.get(&attributes_key).await?;
-                if let Some(attributes) = attributes {
-                    metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
-                    })?;
-                }
+                let metadata = group_metadata_v2_from_documents(
+                    &key_v2,
+                    &metadata,
+                    &attributes_key,
+                    attributes,
+                )?;
                 return Self::new_with_metadata(storage, path, GroupMetadata::V2(metadata));
             }
         }
 
+        // Check for metadata of the other Zarr version
+        let found = match version {
+            MetadataRetrieveVersion::V3 => storage
+                .size_key(&meta_key_v2_group(&node_path))
+                .await?
+                .map(|_| MetadataRetrieveVersion::V2),
+            MetadataRetrieveVersion::V2 => storage
+                .size_key(&meta_key_v3(&node_path))
+                .await?
+                .map(|_| MetadataRetrieveVersion::V3),
+            MetadataRetrieveVersion::Default => None,
+        };
+        if let Some(found) = found {
+            return Err(GroupCreateError::MetadataVersionMismatch {
+                expected: version,
+                found,
+            });
+        }
+
         // No metadata has been found
         Err(GroupCreateError::MissingMetadata)
     }
+
+    /// Async variant of [`child`](Group::child).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_child(&self, name: &str) -> Result<Option<Node>, NodeCreateError> {
+        let path = self.child_path(name)?;
+        match Node::async_get_metadata(&self.storage, &path, &MetadataRetrieveVersion::Default)
+            .await
+        {
+            Ok(metadata) => Ok(Some(Node::new_with_metadata(path, metadata, vec![]))),
+            Err(NodeCreateError::MissingMetadata) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Async variant of [`children_from_names`](Group::children_from_names).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_children_from_names<T: AsRef<str>>(
+        &self,
+        names: &[T],
+    ) -> Result<Vec<Node>, NodeCreateError> {
+        let mut nodes = Vec::with_capacity(names.len());
+        for name in names {
+            if let Some(node) = self.async_child(name.as_ref()).await? {
+                nodes.push(node);
+            }
+        }
+        Ok(nodes)
+    }
+
+    /// Async variant of [`children_from_attributes`](Group::children_from_attributes).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_children_from_attributes(&self) -> Result<Vec<Node>, NodeCreateError> {
+        self.async_children_from_names(&self.attribute_child_names())
+            .await
+    }
+
+    /// Async variant of [`child_node_with_storage_transformers`](Group::child_node_with_storage_transformers).
+    async fn async_child_node_with_storage_transformers<
+        E: From<NodePathError> + From<StorageError>,
+    >(
+        &self,
+        name: &str,
+        storage_transformers_err: fn(PluginCreateError) -> E,
+    ) -> Result<Option<(NodePath, NodeMetadata, StorageTransformerChain)>, E> {
+        let name = name.trim_matches('/');
+        let mut storage_transformers = self.storage_transformers.clone();
+        let mut components = name.split('/').peekable();
+        let mut relative_path = String::new();
+        while let Some(component) = components.next() {
+            if !relative_path.is_empty() {
+                relative_path.push('/');
+            }
+            relative_path.push_str(component);
+            let path = self.child_path(&relative_path)?;
+            let metadata = match self.consolidated_child_metadata(&relative_path) {
+                Some(metadata) => Some(metadata.clone()),
+                None => match Node::async_get_metadata(
+                    &self.storage,
+                    &path,
+                    &MetadataRetrieveVersion::Default,
+                )
+                .await
+                {
+                    Ok(metadata) => Some(metadata),
+                    Err(NodeCreateError::NodePathError(err)) => return Err(err.into()),
+                    Err(NodeCreateError::StorageError(err)) => return Err(err.into()),
+                    Err(
+                        NodeCreateError::MissingMetadata | NodeCreateError::MetadataVersionMismatch,
+                    ) => None,
+                },
+            };
+            if components.peek().is_none() {
+                return Ok(metadata.map(|metadata| (path, metadata, storage_transformers)));
+            }
+            match metadata {
+                Some(NodeMetadata::Group(metadata)) => {
+                    storage_transformers = storage_transformers.chain(
+                        &group_storage_transformers(&metadata, &path)
+                            .map_err(storage_transformers_err)?,
+                    );
+                }
+                Some(NodeMetadata::Array(_)) => return Ok(None),
+                None => {}
+            }
+        }
+        Ok(None)
+    }
+
+    /// Async variant of [`child_array`](Group::child_array).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_child_array(&self, name: &str) -> Result<Array<TStorage>, ArrayCreateError> {
+        match self
+            .async_child_node_with_storage_transformers(
+                name,
+                ArrayCreateError::StorageTransformersCreateError,
+            )
+            .await?
+        {
+            Some((path, NodeMetadata::Array(metadata), storage_transformers)) => Ok(
+                Array::new_with_metadata(self.storage.clone(), path.as_str(), metadata)?
+                    .with_inherited_storage_transformers(&storage_transformers),
+            ),
+            Some((_, NodeMetadata::Group(_), _)) | None => Err(ArrayCreateError::MissingMetadata),
+        }
+    }
+
+    /// Async variant of [`child_group`](Group::child_group).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_child_group(&self, name: &str) -> Result<Self, GroupCreateError> {
+        match self
+            .async_child_node_with_storage_transformers(
+                name,
+                GroupCreateError::StorageTransformersCreateError,
+            )
+            .await?
+        {
+            Some((path, NodeMetadata::Group(metadata), storage_transformers)) => Ok(
+                Self::new_with_metadata(self.storage.clone(), path.as_str(), metadata)?
+                    .with_inherited_storage_transformers(&storage_transformers),
+            ),
+            Some((path, NodeMetadata::Array(_), _)) => Err(GroupCreateError::ArrayExists(path)),
+            None => Err(GroupCreateError::MissingMetadata),
+        }
+    }
 }
 
 #[cfg(feature = "async")]
@@ -423,22 +969,11 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + AsyncListableStorageTraits>
     /// # Errors
     /// Returns [`GroupCreateError`] if there is a storage error or any metadata is invalid.
     pub async fn async_child_groups(&self, recursive: bool) -> Result<Vec<Self>, GroupCreateError> {
-        self.async_children(recursive)
-            .await?
-            .into_iter()
-            .filter_map(|node| {
-                let name = node.name();
-                let metadata: NodeMetadata = node.into();
-                match metadata {
-                    NodeMetadata::Group(metadata) => Some(Group::new_with_metadata(
-                        self.storage.clone(),
-                        name.as_str(),
-                        metadata,
-                    )),
-                    NodeMetadata::Array(_) => None,
-                }
-            })
-            .collect()
+        let nodes = match self.consolidated_children(recursive)? {
+            Some(nodes) => nodes,
+            None => self.async_children(recursive).await?,
+        };
+        self.child_groups_from_nodes(nodes)
     }
 
     /// Return the children of the group that are [`Array`]s
@@ -449,22 +984,59 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits + AsyncListableStorageTraits>
         &self,
         recursive: bool,
     ) -> Result<Vec<Array<TStorage>>, ArrayCreateError> {
-        self.async_children(recursive)
+        let nodes = match self.consolidated_children(recursive)? {
+            Some(nodes) => nodes,
+            None => self.async_children(recursive).await?,
+        };
+        self.child_arrays_from_nodes(nodes)
+    }
+
+    /// Async variant of [`child_paths`](Group::child_paths).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_child_paths(&self, recursive: bool) -> Result<Vec<NodePath>, StorageError> {
+        let paths = self
+            .async_children(recursive)
             .await?
             .into_iter()
-            .filter_map(|node| {
-                let name = node.name();
-                let metadata: NodeMetadata = node.into();
-                match metadata {
-                    NodeMetadata::Array(metadata) => Some(Array::new_with_metadata(
-                        self.storage.clone(),
-                        name.as_str(),
-                        metadata.clone(),
-                    )),
-                    NodeMetadata::Group(_) => None,
-                }
+            .map(Into::into)
+            .collect();
+        Ok(paths)
+    }
+
+    /// Async variant of [`child_group_paths`](Group::child_group_paths).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_child_group_paths(
+        &self,
+        recursive: bool,
+    ) -> Result<Vec<NodePath>, StorageError> {
+        let paths = self
+            .async_children(recursive)
+            .await?
+            .into_iter()
+            .filter_map(|node| match node.metadata() {
+                NodeMetadata::Group(_) => Some(node.into()),
+                NodeMetadata::Array(_) => None,
             })
-            .collect()
+            .collect();
+        Ok(paths)
+    }
+
+    /// Async variant of [`child_array_paths`](Group::child_array_paths).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_child_array_paths(
+        &self,
+        recursive: bool,
+    ) -> Result<Vec<NodePath>, StorageError> {
+        let paths = self
+            .async_children(recursive)
+            .await?
+            .into_iter()
+            .filter_map(|node| match node.metadata() {
+                NodeMetadata::Array(_) => Some(node.into()),
+                NodeMetadata::Group(_) => None,
+            })
+            .collect();
+        Ok(paths)
     }
 }
 
@@ -483,9 +1055,173 @@ pub enum GroupCreateError {
     /// Missing metadata.
     #[error("group metadata is missing")]
     MissingMetadata,
+    /// The group metadata is not the requested Zarr version.
+    #[error("expected Zarr {expected:?} group metadata, but found Zarr {found:?} group metadata")]
+    MetadataVersionMismatch {
+        /// The requested metadata version.
+        expected: MetadataRetrieveVersion,
+        /// The metadata version found in the store.
+        found: MetadataRetrieveVersion,
+    },
+    /// An array exists where a group was expected.
+    #[error("an array exists at {_0} where a group was expected")]
+    ArrayExists(NodePath),
+    /// Error creating storage transformers.
+    #[error(transparent)]
+    StorageTransformersCreateError(PluginCreateError),
 }
 
-impl<TStorage: ?Sized + ReadableStorageTraits> Group<TStorage> {}
+/// Create the storage transformers in group `metadata`.
+fn group_storage_transformers(
+    metadata: &GroupMetadata,
+    path: &NodePath,
+) -> Result<StorageTransformerChain, PluginCreateError> {
+    match metadata {
+        GroupMetadata::V3(metadata) => {
+            StorageTransformerChain::from_metadata(&metadata.storage_transformers, path)
+        }
+        GroupMetadata::V2(_) => Ok(StorageTransformerChain::default()),
+    }
+}
+
+/// Return `path` and each of its ancestors, starting from the root.
+fn node_path_with_ancestors(path: &NodePath) -> Result<Vec<NodePath>, NodePathError> {
+    let mut paths = vec![NodePath::root()];
+    let mut prefix = String::new();
+    for component in path.as_str().split('/').filter(|c| !c.is_empty()) {
+        prefix.push('/');
+        prefix.push_str(component);
+        paths.push(NodePath::new(&prefix)?);
+    }
+    Ok(paths)
+}
+
+fn implicit_group_metadata(path: &NodePath) -> Result<bytes::Bytes, StorageError> {
+    serde_json::to_vec_pretty(&GroupMetadataV3::default())
+        .map(Into::into)
+        .map_err(|err| StorageError::InvalidMetadata(meta_key_v3(path), err.to_string()))
+}
+
+/// Create a group at `path` in `storage`, along with any missing parent groups.
+///
+/// Zarr V3 group metadata with no attributes is stored for `path` and each of its ancestors that do not have metadata.
+/// Existing group metadata (Zarr V2 or V3) is not modified.
+/// This is useful for creating a hierarchy for an array, e.g. `create_group_recursive(storage, "/a/b")` before storing an array at `/a/b/array`.
+///
+/// # Errors
+/// Returns [`GroupCreateError`] if
+///  - `path` is invalid,
+///  - an array exists at `path` or any of its ancestors,
+///  - any existing metadata is invalid, or
+///  - there is an underlying store error.
+pub fn create_group_recursive<TStorage: ?Sized + ReadableWritableStorageTraits>(
+    storage: Arc<TStorage>,
+    path: &str,
+) -> Result<Group<TStorage>, GroupCreateError> {
+    let node_path = NodePath::new(path)?;
+    for path in node_path_with_ancestors(&node_path)? {
+        match Node::get_metadata(&storage, &path, &MetadataRetrieveVersion::Default) {
+            Ok(NodeMetadata::Group(_)) => {}
+            Ok(NodeMetadata::Array(_)) => return Err(GroupCreateError::ArrayExists(path)),
+            Err(NodeCreateError::MissingMetadata) => {
+                storage.set(&meta_key_v3(&path), implicit_group_metadata(&path)?)?;
+            }
+            Err(err) => return Err(StorageError::from(err).into()),
+        }
+    }
+    Group::open(storage, path)
+}
+
+#[cfg(feature = "async")]
+/// Async variant of [`create_group_recursive`].
+#[allow(clippy::missing_errors_doc)]
+pub async fn async_create_group_recursive<TStorage: ?Sized + AsyncReadableWritableStorageTraits>(
+    storage: Arc<TStorage>,
+    path: &str,
+) -> Result<Group<TStorage>, GroupCreateError> {
+    let node_path = NodePath::new(path)?;
+    for path in node_path_with_ancestors(&node_path)? {
+        match Node::async_get_metadata(&storage, &path, &MetadataRetrieveVersion::Default).await {
+            Ok(NodeMetadata::Group(_)) => {}
+            Ok(NodeMetadata::Array(_)) => return Err(GroupCreateError::ArrayExists(path)),
+            Err(NodeCreateError::MissingMetadata) => {
+                storage
+                    .set(&meta_key_v3(&path), implicit_group_metadata(&path)?)
+                    .await?;
+            }
+            Err(err) => return Err(StorageError::from(err).into()),
+        }
+    }
+    Group::async_open(storage, path).await
+}
+
+impl<TStorage: ?Sized + ReadableWritableStorageTraits> Group<TStorage> {
+    /// Store the attributes of the group, preserving any other fields of the stored metadata.
+    ///
+    /// Unlike [`store_metadata`](Group::store_metadata), the stored metadata is read and only its attributes are replaced.
+    /// Fields written by other tools (e.g. additional fields or consolidated metadata) are preserved.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if
+    ///  - the stored metadata is missing or invalid,
+    ///  - the stored metadata differs from the group metadata (ignoring attributes, additional fields, and consolidated metadata), or
+    ///  - there is an underlying store error.
+    pub fn store_attributes(&self) -> Result<(), StorageError> {
+        let attributes = self.attributes().clone();
+        self.update_stored_attributes(|stored_attributes| *stored_attributes = attributes)?;
+        Ok(())
+    }
+
+    /// Mutate the attributes of the group with `f` and store them, preserving any other fields of the stored metadata.
+    ///
+    /// `f` is applied to the attributes of the stored metadata, rather than those held by this group, so attributes written by other tools are preserved.
+    /// The attributes of this group are then replaced with the stored attributes.
+    ///
+    /// # Errors
+    /// See [`store_attributes`](Group::store_attributes).
+    pub fn mutate_attributes(
+        &mut self,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>),
+    ) -> Result<(), StorageError> {
+        *self.attributes_mut() = self.update_stored_attributes(f)?;
+        Ok(())
+    }
+
+    fn update_stored_attributes(
+        &self,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>),
+    ) -> Result<serde_json::Map<String, serde_json::Value>, StorageError> {
+        let path = self.path();
+        match &self.metadata {
+            GroupMetadata::V3(metadata) => {
+                let key = meta_key_v3(path);
+                let mut document =
+                    stored_metadata_document(&key, self.storage.get(&key)?, metadata, |m| {
+                        m.attributes.clear();
+                        m.consolidated_metadata = None;
+                        m.additional_fields = AdditionalFields::default();
+                    })?;
+                let attributes = mutate_document_attributes(&mut document, f);
+                self.storage
+                    .set(&key, serialize_document(&key, &document)?)?;
+                Ok(attributes)
+            }
+            GroupMetadata::V2(metadata) => {
+                let key = meta_key_v2_group(path);
+                stored_metadata_document(&key, self.storage.get(&key)?, metadata, |m| {
+                    m.attributes.clear();
+                    m.additional_fields = AdditionalFields::default();
+                })?;
+                let key = meta_key_v2_attributes(path);
+                let mut attributes = stored_attributes_v2(&key, self.storage.get(&key)?)?;
+                f(&mut attributes);
+                self.storage
+                    .set(&key, serialize_document(&key, &attributes)?)?;
+                Ok(attributes)
+            }
+        }
+    }
+}
 
 impl<TStorage: ?Sized + WritableStorageTraits> Group<TStorage> {
     /// Store metadata with default [`GroupMetadataOptions`].
@@ -511,11 +1247,20 @@ impl<TStorage: ?Sized + WritableStorageTraits> Group<TStorage> {
         match metadata {
             GroupMetadata::V3(metadata) => {
                 let key = meta_key_v3(path);
+                if options.strict_additional_fields() {
+                    check_additional_fields_must_understand(&key, &metadata.additional_fields)?;
+                }
                 let json = serde_json::to_vec_pretty(&metadata)
                     .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
                 storage_handle.set(&key, json.into())
             }
             GroupMetadata::V2(metadata) => {
+                if options.strict_additional_fields() {
+                    check_additional_fields_must_understand(
+                        &meta_key_v2_group(path),
+                        &metadata.additional_fields,
+                    )?;
+                }
                 let mut metadata = metadata.clone();
 
                 if !metadata.attributes.is_empty() {
@@ -580,6 +1325,60 @@ impl<TStorage: ?Sized + WritableStorageTraits> Group<TStorage> {
     }
 }
 
+impl<TStorage: ?Sized + WritableStorageTraits + ListableStorageTraits> Group<TStorage> {
+    /// Erase the child of the group at the relative path `name` with default [`NodeEraseOptions`].
+    ///
+    /// See [`erase_child_opt`](Group::erase_child_opt).
+    ///
+    /// # Errors
+    /// Returns a [`NodeEraseError`] if `name` is not a valid relative path or there is an underlying store error.
+    pub fn erase_child(&self, name: &str) -> Result<StoreKeys, NodeEraseError> {
+        self.erase_child_opt(name, &NodeEraseOptions::default())
+    }
+
+    /// Erase the child of the group at the relative path `name` with non-default [`NodeEraseOptions`].
+    ///
+    /// The metadata of the child and all keys under its prefix are erased, see [`erase_node_opt`](crate::node::erase_node_opt).
+    /// The keys that were erased, or would be erased in a dry run, are returned.
+    /// Succeeds if the child does not exist.
+    ///
+    /// # Errors
+    /// Returns a [`NodeEraseError`] if
+    ///  - `name` is not a relative path of valid node names (e.g. it is empty or includes `..`), or
+    ///  - there is an underlying store error.
+    pub fn erase_child_opt(
+        &self,
+        name: &str,
+        options: &NodeEraseOptions,
+    ) -> Result<StoreKeys, NodeEraseError> {
+        validate_relative_node_path(name)?;
+        let path = self.child_path(name)?;
+        erase_node_opt(&self.storage, &path, options)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<TStorage: ?Sized + AsyncWritableStorageTraits + AsyncListableStorageTraits> Group<TStorage> {
+    /// Async variant of [`erase_child`](Group::erase_child).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_erase_child(&self, name: &str) -> Result<StoreKeys, NodeEraseError> {
+        self.async_erase_child_opt(name, &NodeEraseOptions::default())
+            .await
+    }
+
+    /// Async variant of [`erase_child_opt`](Group::erase_child_opt).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_erase_child_opt(
+        &self,
+        name: &str,
+        options: &NodeEraseOptions,
+    ) -> Result<StoreKeys, NodeEraseError> {
+        validate_relative_node_path(name)?;
+        let path = self.child_path(name)?;
+        async_erase_node_opt(&self.storage, &path, options).await
+    }
+}
+
 #[cfg(feature = "async")]
 impl<TStorage: ?Sized + AsyncWritableStorageTraits> Group<TStorage> {
     /// Async variant of [`store_metadata`](Group::store_metadata).
@@ -605,11 +1404,20 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> Group<TStorage> {
         match metadata {
             GroupMetadata::V3(metadata) => {
                 let key = meta_key_v3(path);
+                if options.strict_additional_fields() {
+                    check_additional_fields_must_understand(&key, &metadata.additional_fields)?;
+                }
                 let json = serde_json::to_vec_pretty(&metadata)
                     .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
                 storage_handle.set(&key, json.into()).await
             }
             GroupMetadata::V2(metadata) => {
+                if options.strict_additional_fields() {
+                    check_additional_fields_must_understand(
+                        &meta_key_v2_group(path),
+                        &metadata.additional_fields,
+                    )?;
+                }
                 let mut metadata = metadata.clone();
 
                 if !metadata.attributes.is_empty() {
@@ -681,6 +1489,65 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> Group<TStorage> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<TStorage: ?Sized + AsyncReadableWritableStorageTraits> Group<TStorage> {
+    /// Async variant of [`store_attributes`](Group::store_attributes).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_store_attributes(&self) -> Result<(), StorageError> {
+        let attributes = self.attributes().clone();
+        self.async_update_stored_attributes(|stored_attributes| *stored_attributes = attributes)
+            .await?;
+        Ok(())
+    }
+
+    /// Async variant of [`mutate_attributes`](Group::mutate_attributes).
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn async_mutate_attributes(
+        &mut self,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>) + Send,
+    ) -> Result<(), StorageError> {
+        *self.attributes_mut() = self.async_update_stored_attributes(f).await?;
+        Ok(())
+    }
+
+    async fn async_update_stored_attributes(
+        &self,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>) + Send,
+    ) -> Result<serde_json::Map<String, serde_json::Value>, StorageError> {
+        let path = self.path();
+        match &self.metadata {
+            GroupMetadata::V3(metadata) => {
+                let key = meta_key_v3(path);
+                let mut document =
+                    stored_metadata_document(&key, self.storage.get(&key).await?, metadata, |m| {
+                        m.attributes.clear();
+                        m.consolidated_metadata = None;
+                        m.additional_fields = AdditionalFields::default();
+                    })?;
+                let attributes = mutate_document_attributes(&mut document, f);
+                self.storage
+                    .set(&key, serialize_document(&key, &document)?)
+                    .await?;
+                Ok(attributes)
+            }
+            GroupMetadata::V2(metadata) => {
+                let key = meta_key_v2_group(path);
+                stored_metadata_document(&key, self.storage.get(&key).await?, metadata, |m| {
+                    m.attributes.clear();
+                    m.additional_fields = AdditionalFields::default();
+                })?;
+                let key = meta_key_v2_attributes(path);
+                let mut attributes = stored_attributes_v2(&key, self.storage.get(&key).await?)?;
+                f(&mut attributes);
+                self.storage
+                    .set(&key, serialize_document(&key, &attributes)?)
+                    .await?;
+                Ok(attributes)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::storage::{store::MemoryStore, StoreKey};
@@ -848,4 +1715,194 @@ mod tests {
         let group_path = "/group";
         assert!(Group::open(store, group_path).is_err());
     }
+
+    #[test]
+    fn group_mutate_attributes() {
+        let store = std::sync::Arc::new(MemoryStore::new());
+        let group_path = "/group";
+        let key = meta_key_v3(&NodePath::new(group_path).unwrap());
+        let group = GroupBuilder::new()
+            .build(store.clone(), group_path)
+            .unwrap();
+        group.store_metadata().unwrap();
+        let mut group = Group::open(store.clone(), group_path).unwrap();
+
+        // Simulate another writer adding an attribute and an additional field
+        store
+            .set(
+                &key,
+                r#"{"zarr_format":3,"node_type":"group","attributes":{"other":1},"ext":{"must_understand":false}}"#
+                    .into(),
+            )
+            .unwrap();
+
+        group
+            .mutate_attributes(|attributes| {
+                attributes.insert("key".to_string(), "value".into());
+            })
+            .unwrap();
+        assert_eq!(group.attributes().len(), 2);
+        let document: serde_json::Value =
+            serde_json::from_slice(&store.get(&key).unwrap().unwrap()).unwrap();
+        assert_eq!(
+            document,
+            serde_json::json!({
+                "zarr_format": 3,
+                "node_type": "group",
+                "attributes": {"key": "value", "other": 1},
+                "ext": {"must_understand": false},
+            })
+        );
+
+        // Storing replaces the attributes, but preserves the additional field
+        group.attributes_mut().remove("other");
+        group.store_attributes().unwrap();
+        let group = Group::open(store.clone(), group_path).unwrap();
+        assert_eq!(group.attributes().len(), 1);
+        assert!(group.additional_fields().contains_key("ext"));
+    }
+
+    #[test]
+    fn group_mutate_attributes_v2() {
+        let store = std::sync::Arc::new(MemoryStore::new());
+        let group_path = "/group";
+        let node_path = NodePath::new(group_path).unwrap();
+        store
+            .set(
+                &meta_key_v2_group(&node_path),
+                r#"{"zarr_format":2}"#.into(),
+            )
+            .unwrap();
+        let mut group = Group::open(store.clone(), group_path).unwrap();
+        store
+            .set(&meta_key_v2_attributes(&node_path), r#"{"other":1}"#.into())
+            .unwrap();
+        group
+            .mutate_attributes(|attributes| {
+                attributes.insert("key".to_string(), "value".into());
+            })
+            .unwrap();
+        let group = Group::open(store.clone(), group_path).unwrap();
+        assert_eq!(
+            serde_json::Value::Object(group.attributes().clone()),
+            serde_json::json!({"key": "value", "other": 1})
+        );
+    }
+
+    #[test]
+    fn group_mutate_attributes_missing() {
+        let store = std::sync::Arc::new(MemoryStore::new());
+        let mut group = GroupBuilder::new().build(store, "/group").unwrap();
+        assert!(group.mutate_attributes(|_| {}).is_err());
+        assert!(group.store_attributes().is_err());
+    }
+
+    #[test]
+    fn group_create_recursive() {
+        use crate::array::{ArrayBuilder, DataType, FillValue};
+
+        let store = std::sync::Arc::new(MemoryStore::new());
+        let mut existing = GroupBuilder::new().build(store.clone(), "/a").unwrap();
+        existing
+            .attributes_mut()
+            .insert("key".to_string(), "value".into());
+        existing.store_metadata().unwrap();
+
+        let group = create_group_recursive(store.clone(), "/a/b/c").unwrap();
+        assert_eq!(group.path().as_str(), "/a/b/c");
+        let array = ArrayBuilder::new(
+            vec![4],
+            DataType::UInt8,
+            vec![2].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .build(store.clone(), "/a/b/c/array")
+        .unwrap();
+        array.store_metadata().unwrap();
+
+        for path in ["/", "/a", "/a/b", "/a/b/c"] {
+            assert!(store
+                .get(&meta_key_v3(&NodePath::new(path).unwrap()))
+                .unwrap()
+                .is_some());
+            Group::open(store.clone(), path).unwrap();
+        }
+        // Existing groups are not overwritten
+        let group = Group::open(store.clone(), "/a").unwrap();
+        assert_eq!(group.attributes(), existing.attributes());
+        // Idempotent
+        create_group_recursive(store.clone(), "/a/b/c").unwrap();
+
+        assert!(matches!(
+            create_group_recursive(store.clone(), "/a/b/c/array/d"),
+            Err(GroupCreateError::ArrayExists(path)) if path.as_str() == "/a/b/c/array"
+        ));
+        assert!(store
+            .get(&meta_key_v3(&NodePath::new("/a/b/c/array/d").unwrap()))
+            .unwrap()
+            .is_none());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn group_create_recursive_async() {
+        let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(
+            object_store::memory::InMemory::new(),
+        ));
+        let group = async_create_group_recursive(store.clone(), "/a/b")
+            .await
+            .unwrap();
+        assert_eq!(group.path().as_str(), "/a/b");
+        for path in ["/", "/a", "/a/b"] {
+            Group::async_open(store.clone(), path).await.unwrap();
+        }
+    }
+
+    #[test]
+    fn group_erase_child() {
+        use crate::node::node_exists;
+
+        let store = std::sync::Arc::new(MemoryStore::new());
+        let group = create_group_recursive(store.clone(), "/group/a").unwrap();
+        create_group_recursive(store.clone(), "/group/b").unwrap();
+        create_group_recursive(store.clone(), "/group/a/c").unwrap();
+        let group_root = Group::open(store.clone(), "/group").unwrap();
+
+        let options = NodeEraseOptions::default().with_dry_run(true);
+        assert_eq!(group_root.erase_child_opt("a", &options).unwrap().len(), 2);
+        assert_eq!(group_root.erase_child("a").unwrap().len(), 2);
+        assert!(!node_exists(&store, group.path()).unwrap());
+        assert!(!node_exists(&store, &NodePath::new("/group/a/c").unwrap()).unwrap());
+        assert!(node_exists(&store, &NodePath::new("/group/b").unwrap()).unwrap());
+        assert!(group_root.erase_child("a").unwrap().is_empty());
+        assert!(group_root.erase_child("../b").is_err());
+        assert!(group_root.erase_child("").is_err());
+        assert!(node_exists(&store, &NodePath::new("/group/b").unwrap()).unwrap());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn group_erase_child_async() {
+        use crate::node::async_node_exists;
+
+        let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(
+            object_store::memory::InMemory::new(),
+        ));
+        async_create_group_recursive(store.clone(), "/a/b")
+            .await
+            .unwrap();
+        async_create_group_recursive(store.clone(), "/c")
+            .await
+            .unwrap();
+        let group = Group::async_open(store.clone(), "/").await.unwrap();
+        assert_eq!(group.async_erase_child("a").await.unwrap().len(), 2);
+        let path = NodePath::new("/a").unwrap();
+        assert!(!async_node_exists(&store, &path).await.unwrap());
+        let path = NodePath::new("/c").unwrap();
+        assert!(async_node_exists(&store, &path).await.unwrap());
+        assert!(matches!(
+            crate::node::async_erase_node(&store, &NodePath::root()).await,
+            Err(NodeEraseError::RootNode)
+        ));
+    }
 }
@@ -1,8 +1,13 @@
 use std::sync::Arc;
 
-use crate::metadata::{
-    v3::{AdditionalFields, GroupMetadataV3},
-    GroupMetadata,
+use crate::{
+    array::storage_transformer::StorageTransformerChain,
+    metadata::{
+        v2_to_v3::group_metadata_v2_to_v3,
+        v3::{AdditionalFields, GroupMetadataV3},
+        v3_to_v2::group_metadata_v3_to_v2,
+        GroupMetadata, ZarrVersion,
+    },
 };
 
 use super::{Group, GroupCreateError};
@@ -54,6 +59,38 @@ impl GroupBuilder {
         self
     }
 
+    /// Set the storage transformers applied to the child nodes of the group.
+    ///
+    /// Storage transformers are only supported in Zarr V3 group metadata.
+    /// See the [storage transformer ordering](crate::array::storage_transformer#ordering).
+    pub fn storage_transformers(
+        &mut self,
+        storage_transformers: &StorageTransformerChain,
+    ) -> &mut Self {
+        if let GroupMetadata::V3(metadata) = &mut self.metadata {
+            metadata.storage_transformers = storage_transformers.create_metadatas();
+        }
+        self
+    }
+
+    /// Set the Zarr format of the group metadata.
+    ///
+    /// If left unmodified, the group has Zarr V3 metadata.
+    /// A Zarr V2 group is stored as `.zgroup` and `.zattrs` documents.
+    /// Storage transformers are not supported in Zarr V2 group metadata and are discarded.
+    pub fn zarr_format(&mut self, zarr_format: ZarrVersion) -> &mut Self {
+        self.metadata = match (&self.metadata, zarr_format) {
+            (GroupMetadata::V3(metadata), ZarrVersion::V2) => {
+                group_metadata_v3_to_v2(metadata).into()
+            }
+            (GroupMetadata::V2(metadata), ZarrVersion::V3) => {
+                group_metadata_v2_to_v3(metadata).into()
+            }
+            (metadata, _) => metadata.clone(),
+        };
+        self
+    }
+
     /// Build into a [`Group`].
     ///
     /// # Errors
@@ -66,6 +103,19 @@ impl GroupBuilder {
     ) -> Result<Group<TStorage>, GroupCreateError> {
         Group::new_with_metadata(storage, path, self.metadata.clone())
     }
+
+    /// Build into an [`Arc<Group>`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GroupCreateError`] if the group could not be created.
+    pub fn build_arc<TStorage: ?Sized>(
+        &self,
+        storage: Arc<TStorage>,
+        path: &str,
+    ) -> Result<Arc<Group<TStorage>>, GroupCreateError> {
+        Ok(Arc::new(self.build(storage, path)?))
+    }
 }
 
 #[cfg(test)]
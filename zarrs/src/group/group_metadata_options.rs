@@ -4,12 +4,14 @@ use crate::config::{global_config, MetadataConvertVersion};
 #[derive(Debug, Clone)]
 pub struct GroupMetadataOptions {
     convert_version: MetadataConvertVersion,
+    strict_additional_fields: bool,
 }
 
 impl Default for GroupMetadataOptions {
     fn default() -> Self {
         Self {
             convert_version: global_config().metadata_convert_version(),
+            strict_additional_fields: global_config().strict_additional_fields(),
         }
     }
 }
@@ -39,4 +41,23 @@ impl GroupMetadataOptions {
         self.convert_version = convert_version;
         self
     }
+
+    /// Get the [strict additional fields](crate::config::Config#strict-additional-fields) configuration.
+    #[must_use]
+    pub fn strict_additional_fields(&self) -> bool {
+        self.strict_additional_fields
+    }
+
+    /// Set the [strict additional fields](crate::config::Config#strict-additional-fields) configuration.
+    #[must_use]
+    pub fn with_strict_additional_fields(mut self, strict_additional_fields: bool) -> Self {
+        self.strict_additional_fields = strict_additional_fields;
+        self
+    }
+
+    /// Set the [strict additional fields](crate::config::Config#strict-additional-fields) configuration.
+    pub fn set_strict_additional_fields(&mut self, strict_additional_fields: bool) -> &mut Self {
+        self.strict_additional_fields = strict_additional_fields;
+        self
+    }
 }
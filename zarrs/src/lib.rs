@@ -171,7 +171,10 @@
 //!  - `async`: an **experimental** asynchronous API for [`stores`](storage), [`Array`](crate::array::Array), and [`Group`](group::Group).
 //!    - The async API is runtime-agnostic. This has some limitations that are detailed in the [`Array`](crate::array::Array) docs.
 //!    - The async API is not as performant as the sync API.
-//!  - Codecs: `bitround`, `bz2`, `pcodec`, `zfp`, `zstd`.
+//!  - `dlpack`: [`DLPack`](crate::array::dlpack) tensor utility functions for [`Array`](crate::array::Array).
+//!  - `conventions`: typed accessors for [attribute conventions](crate::conventions) (e.g. OME-Zarr `multiscales`).
+//!  - Codecs: `adler32`, `bitround`, `bitshuffle`, `bz2`, `crc32`, `expand_dims`, `jenkins_lookup3`, `jpegxl`, `lz4`, `lzma`, `pcodec`, `quantize`, `webp`, `zfp`, `zstd`.
+//!  - Storage transformers: `encryption`.
 //!
 //! ## `zarrs` Ecosystem
 #![doc = include_str!("../doc/ecosystem.md")]
@@ -187,6 +190,8 @@
 pub mod array;
 pub mod array_subset;
 pub mod config;
+#[cfg(feature = "conventions")]
+pub mod conventions;
 pub mod group;
 pub mod node;
 pub mod plugin;
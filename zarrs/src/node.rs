@@ -5,8 +5,10 @@
 //! A [`Node`] has an associated [`NodePath`], [`NodeMetadata`], and children.
 //!
 //! The [`Node::hierarchy_tree`] function can be used to create a string representation of a the hierarchy below a node.
+//! A [`NodeTree`] is a structured representation of a hierarchy that tolerates nodes with missing or invalid metadata, and its [`Display`](std::fmt::Display) implementation prints a detailed summary of each node.
 
 mod node_name;
+pub(crate) use node_name::validate_relative_node_path;
 pub use node_name::{NodeName, NodeNameError};
 
 mod node_path;
@@ -14,19 +16,46 @@ pub use node_path::{NodePath, NodePathError};
 
 mod node_sync;
 pub(crate) use node_sync::_get_child_nodes;
-pub use node_sync::{get_child_nodes, node_exists, node_exists_listable};
+pub use node_sync::{
+    erase_node, erase_node_opt, get_child_nodes, node_exists, node_exists_listable,
+};
+
+mod node_erase_options;
+pub use node_erase_options::NodeEraseOptions;
+
+mod node_open_options;
+pub use node_open_options::{NodeOpenOptions, NodeOpenWarning};
+
+mod node_tree;
+pub use node_tree::NodeTree;
+
+mod node_attributes;
+pub(crate) use node_attributes::{
+    array_metadata_v2_from_documents, check_additional_fields_must_understand,
+    group_metadata_v2_from_documents, mutate_document_attributes, serialize_document,
+    stored_attributes_v2, stored_metadata_document,
+};
 
 mod key;
 pub use key::{
-    data_key, meta_key, meta_key_v2_array, meta_key_v2_attributes, meta_key_v2_group, meta_key_v3,
+    data_key, meta_key, meta_key_v2_array, meta_key_v2_attributes, meta_key_v2_consolidated,
+    meta_key_v2_group, meta_key_v3,
 };
 
+mod node_consolidated_v2;
+#[cfg(feature = "async")]
+pub use node_consolidated_v2::async_open_consolidated_v2;
+pub use node_consolidated_v2::{open_consolidated_v2, ConsolidatedMetadataV2StorageAdapter};
+
 #[cfg(feature = "async")]
 mod node_async;
 #[cfg(feature = "async")]
 pub(crate) use node_async::_async_get_child_nodes;
 #[cfg(feature = "async")]
-pub use node_async::{async_get_child_nodes, async_node_exists, async_node_exists_listable};
+pub use node_async::{
+    async_erase_node, async_erase_node_opt, async_get_child_nodes, async_node_exists,
+    async_node_exists_listable,
+};
 use zarrs_metadata::v3::group::ConsolidatedMetadataMetadata;
 
 use std::{collections::HashMap, sync::Arc};
@@ -37,10 +66,7 @@ use thiserror::Error;
 use crate::{
     array::ArrayMetadata,
     config::MetadataRetrieveVersion,
-    metadata::{
-        v2::{ArrayMetadataV2, GroupMetadataV2},
-        GroupMetadata,
-    },
+    metadata::GroupMetadata,
     storage::{ListableStorageTraits, ReadableStorageTraits, StorageError},
 };
 
@@ -91,6 +117,23 @@ pub enum NodeCreateError {
     MissingMetadata,
 }
 
+/// A node erase error.
+#[derive(Debug, Error)]
+pub enum NodeEraseError {
+    /// Erasing the root node requires the force option.
+    #[error("erasing the root node requires the force option")]
+    RootNode,
+    /// An invalid node path
+    #[error(transparent)]
+    NodePathError(#[from] NodePathError),
+    /// An invalid node name.
+    #[error(transparent)]
+    NodeNameError(#[from] NodeNameError),
+    /// A storage error.
+    #[error(transparent)]
+    StorageError(#[from] StorageError),
+}
+
 // FIXME: Remove in the next breaking release
 impl From<NodeCreateError> for StorageError {
     fn from(value: NodeCreateError) -> Self {
@@ -108,7 +151,7 @@ impl From<NodeCreateError> for StorageError {
 }
 
 impl Node {
-    fn get_metadata<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits>(
+    pub(crate) fn get_metadata<TStorage: ?Sized + ReadableStorageTraits>(
         storage: &Arc<TStorage>,
         path: &NodePath,
         version: &MetadataRetrieveVersion,
@@ -135,28 +178,26 @@ impl Node {
             let array_key = meta_key_v2_array(path);
             let attributes_key = meta_key_v2_attributes(path);
             if let Some(metadata) = storage.get(&array_key)? {
-                let mut metadata: ArrayMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(array_key, err.to_string()))?;
                 let attributes = storage.get(&attributes_key)?;
-                if let Some(attributes) = attributes {
-                    metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
-                    })?;
-                }
+                let metadata = array_metadata_v2_from_documents(
+                    &array_key,
+                    &metadata,
+                    &attributes_key,
+                    attributes,
+                )?;
                 return Ok(NodeMetadata::Array(ArrayMetadata::V2(metadata)));
             }
 
             // Try a Zarr V2 group
             let group_key = meta_key_v2_group(path);
             if let Some(metadata) = storage.get(&group_key)? {
-                let mut metadata: GroupMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(group_key, err.to_string()))?;
                 let attributes = storage.get(&attributes_key)?;
-                if let Some(attributes) = attributes {
-                    metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
-                    })?;
-                }
+                let metadata = group_metadata_v2_from_documents(
+                    &group_key,
+                    &metadata,
+                    &attributes_key,
+                    attributes,
+                )?;
                 return Ok(NodeMetadata::Group(GroupMetadata::V2(metadata)));
             }
         }
@@ -168,9 +209,7 @@ impl Node {
     #[cfg(feature = "async")]
     // Identical to get_metadata.. with awaits
     // "maybe async" one day?
-    async fn async_get_metadata<
-        TStorage: ?Sized + AsyncReadableStorageTraits + AsyncListableStorageTraits,
-    >(
+    pub(crate) async fn async_get_metadata<TStorage: ?Sized + AsyncReadableStorageTraits>(
         storage: &Arc<TStorage>,
         path: &NodePath,
         version: &MetadataRetrieveVersion,
@@ -197,28 +236,26 @@ impl Node {
             let array_key = meta_key_v2_array(path);
             let attributes_key = meta_key_v2_attributes(path);
             if let Some(metadata) = storage.get(&array_key).await? {
-                let mut metadata: ArrayMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(array_key, err.to_string()))?;
                 let attributes = storage.get(&attributes_key).await?;
-                if let Some(attributes) = attributes {
-                    metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
-                    })?;
-                }
+                let metadata = array_metadata_v2_from_documents(
+                    &array_key,
+                    &metadata,
+                    &attributes_key,
+                    attributes,
+                )?;
                 return Ok(NodeMetadata::Array(ArrayMetadata::V2(metadata)));
             }
 
             // Try a Zarr V2 group
             let group_key = meta_key_v2_group(path);
             if let Some(metadata) = storage.get(&group_key).await? {
-                let mut metadata: GroupMetadataV2 = serde_json::from_slice(&metadata)
-                    .map_err(|err| StorageError::InvalidMetadata(group_key, err.to_string()))?;
                 let attributes = storage.get(&attributes_key).await?;
-                if let Some(attributes) = attributes {
-                    metadata.attributes = serde_json::from_slice(&attributes).map_err(|err| {
-                        StorageError::InvalidMetadata(attributes_key, err.to_string())
-                    })?;
-                }
+                let metadata = group_metadata_v2_from_documents(
+                    &group_key,
+                    &metadata,
+                    &attributes_key,
+                    attributes,
+                )?;
                 return Ok(NodeMetadata::Group(GroupMetadata::V2(metadata)));
             }
         }
@@ -349,6 +386,11 @@ impl Node {
         &self.children
     }
 
+    /// Consume the node and return its path, metadata, and children.
+    pub(crate) fn into_parts(self) -> (NodePath, NodeMetadata, Vec<Self>) {
+        (self.path, self.metadata, self.children)
+    }
+
     /// Return a tree representation of a hierarchy as a string.
     ///
     /// Arrays are annotated with their shape and data type.
@@ -600,4 +642,75 @@ mod tests {
         );
         assert!(node.is_root());
     }
+
+    #[test]
+    fn node_erase() {
+        let store = std::sync::Arc::new(MemoryStore::new());
+        let group_metadata = br#"{"zarr_format":3,"node_type":"group"}"#;
+        for key in ["zarr.json", "group/zarr.json", "other/zarr.json"] {
+            store
+                .set(&StoreKey::new(key).unwrap(), group_metadata.to_vec().into())
+                .unwrap();
+        }
+        let array = ArrayBuilder::new(
+            vec![4],
+            crate::array::DataType::UInt8,
+            vec![2].try_into().unwrap(),
+            FillValue::from(0u8),
+        )
+        .build(store.clone(), "/group/array")
+        .unwrap();
+        array.store_metadata().unwrap();
+        array.store_chunk_elements::<u8>(&[0], &[1, 2]).unwrap();
+        // A Zarr V2 sibling with a name sharing the prefix of the array
+        store
+            .set(
+                &StoreKey::new("group/array2/.zarray").unwrap(),
+                vec![].into(),
+            )
+            .unwrap();
+        store
+            .set(
+                &StoreKey::new("group/array2/.zattrs").unwrap(),
+                vec![].into(),
+            )
+            .unwrap();
+        let num_keys = store.list().unwrap().len();
+
+        let path = NodePath::new("/group/array").unwrap();
+        let options = NodeEraseOptions::default().with_dry_run(true);
+        let keys = erase_node_opt(&store, &path, &options).unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                StoreKey::new("group/array/c/0").unwrap(),
+                StoreKey::new("group/array/zarr.json").unwrap()
+            ]
+        );
+        assert_eq!(store.list().unwrap().len(), num_keys);
+        assert_eq!(erase_node(&store, &path).unwrap(), keys);
+        assert_eq!(store.list().unwrap().len(), num_keys - 2);
+        assert!(!node_exists(&store, &path).unwrap());
+        assert!(erase_node(&store, &path).unwrap().is_empty());
+
+        let sibling = NodePath::new("/group/array2").unwrap();
+        assert!(node_exists(&store, &sibling).unwrap());
+        assert_eq!(erase_node(&store, &sibling).unwrap().len(), 2);
+        assert!(node_exists(&store, &NodePath::new("/group").unwrap()).unwrap());
+        assert!(node_exists(&store, &NodePath::new("/other").unwrap()).unwrap());
+
+        assert!(matches!(
+            erase_node(&store, &NodePath::root()),
+            Err(NodeEraseError::RootNode)
+        ));
+        assert_eq!(store.list().unwrap().len(), num_keys - 4);
+        let options = NodeEraseOptions::default().with_force(true);
+        assert_eq!(
+            erase_node_opt(&store, &NodePath::root(), &options)
+                .unwrap()
+                .len(),
+            num_keys - 4
+        );
+        assert!(store.list().unwrap().is_empty());
+    }
 }
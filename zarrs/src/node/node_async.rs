@@ -1,15 +1,18 @@
 use std::sync::Arc;
 
+use futures::{StreamExt, TryStreamExt};
+
 use crate::{
-    config::MetadataRetrieveVersion,
+    config::{global_config, MetadataRetrieveVersion},
     storage::{
         async_discover_children, AsyncListableStorageTraits, AsyncReadableStorageTraits,
-        StorageError, StorePrefix,
+        AsyncWritableStorageTraits, StorageError, StoreKeys, StorePrefix,
     },
 };
 
 use super::{
-    meta_key_v2_array, meta_key_v2_group, meta_key_v3, Node, NodeMetadata, NodePath, NodePathError,
+    meta_key_v2_array, meta_key_v2_group, meta_key_v3, Node, NodeEraseError, NodeEraseOptions,
+    NodeMetadata, NodePath, NodePathError,
 };
 
 // TODO: Replace async_get_child_nodes with this method in the next breaking release
@@ -24,30 +27,36 @@ where
 {
     let prefix: StorePrefix = path.try_into()?;
     let prefixes = async_discover_children(storage, &prefix).await?;
-    let mut nodes: Vec<Node> = Vec::new();
-    // TODO: Asynchronously get metadata of all prefixes
-    for prefix in &prefixes {
-        let path: NodePath = prefix
-            .try_into()
-            .map_err(|err: NodePathError| StorageError::Other(err.to_string()))?;
-        let child_metadata =
-            Node::async_get_metadata(storage, &path, &MetadataRetrieveVersion::Default).await?;
+    let concurrent_limit = global_config().chunk_concurrent_maximum().max(1);
+    futures::stream::iter(&prefixes)
+        .map(|prefix| async move {
+            let path: NodePath = prefix
+                .try_into()
+                .map_err(|err: NodePathError| StorageError::Other(err.to_string()))?;
+            let child_metadata =
+                Node::async_get_metadata(storage, &path, &MetadataRetrieveVersion::Default).await?;
 
-        let children = if recursive {
-            match child_metadata {
-                NodeMetadata::Array(_) => Vec::default(),
-                NodeMetadata::Group(_) => Box::pin(async_get_child_nodes(storage, &path)).await?,
-            }
-        } else {
-            vec![]
-        };
-        nodes.push(Node::new_with_metadata(path, child_metadata, children));
-    }
-    Ok(nodes)
+            let children = if recursive {
+                match child_metadata {
+                    NodeMetadata::Array(_) => Vec::default(),
+                    NodeMetadata::Group(_) => {
+                        Box::pin(async_get_child_nodes(storage, &path)).await?
+                    }
+                }
+            } else {
+                vec![]
+            };
+            Ok(Node::new_with_metadata(path, child_metadata, children))
+        })
+        .buffered(concurrent_limit)
+        .try_collect()
+        .await
 }
 
 /// Asynchronously get the child nodes.
 ///
+/// The metadata of the children of each group is retrieved concurrently, up to the [chunk concurrent maximum](crate::config::Config#chunk-concurrent-maximum).
+///
 /// # Errors
 /// Returns a [`StorageError`] if there is an underlying error with the store.
 // FIXME: Change to NodeCreateError in the next breaking release
@@ -92,3 +101,46 @@ pub async fn async_node_exists_listable<TStorage: ?Sized + AsyncListableStorageT
             | keys.contains(&meta_key_v2_group(path))
     })
 }
+
+/// Asynchronously erase the node at `path` with default [`NodeEraseOptions`].
+///
+/// See [`async_erase_node_opt`].
+///
+/// # Errors
+/// Returns a [`NodeEraseError`] if `path` is the root node or there is an underlying error with the store.
+pub async fn async_erase_node<TStorage>(
+    storage: &Arc<TStorage>,
+    path: &NodePath,
+) -> Result<StoreKeys, NodeEraseError>
+where
+    TStorage: ?Sized + AsyncWritableStorageTraits + AsyncListableStorageTraits,
+{
+    async_erase_node_opt(storage, path, &NodeEraseOptions::default()).await
+}
+
+/// Asynchronously erase the node at `path` with non-default [`NodeEraseOptions`].
+///
+/// See [`erase_node_opt`](crate::node::erase_node_opt).
+///
+/// # Errors
+/// Returns a [`NodeEraseError`] if
+///  - `path` is the root node and the force option is not set, or
+///  - there is an underlying error with the store.
+pub async fn async_erase_node_opt<TStorage>(
+    storage: &Arc<TStorage>,
+    path: &NodePath,
+    options: &NodeEraseOptions,
+) -> Result<StoreKeys, NodeEraseError>
+where
+    TStorage: ?Sized + AsyncWritableStorageTraits + AsyncListableStorageTraits,
+{
+    if path == &NodePath::root() && !options.force() {
+        return Err(NodeEraseError::RootNode);
+    }
+    let prefix: StorePrefix = path.try_into().map_err(StorageError::from)?;
+    let keys = storage.list_prefix(&prefix).await?;
+    if !options.dry_run() {
+        storage.erase_prefix(&prefix).await?;
+    }
+    Ok(keys)
+}
@@ -0,0 +1,167 @@
+use serde::de::DeserializeOwned;
+
+use crate::{
+    config::global_config,
+    metadata::{
+        v2::{nczarr, ArrayMetadataV2, GroupMetadataV2},
+        v3::AdditionalFields,
+    },
+    storage::{Bytes, MaybeBytes, StorageError, StoreKey},
+};
+
+type JsonObject = serde_json::Map<String, serde_json::Value>;
+
+/// Parse the stored metadata document at `key` and check that it is consistent with `metadata`.
+///
+/// `ignore` clears the fields of the metadata that may have been changed by another writer (e.g. attributes and additional fields), which are not compared.
+/// The document is returned as a JSON object, so that fields unknown to `TMetadata` are preserved if it is rewritten.
+pub(crate) fn stored_metadata_document<TMetadata>(
+    key: &StoreKey,
+    document: MaybeBytes,
+    metadata: &TMetadata,
+    ignore: impl Fn(&mut TMetadata),
+) -> Result<JsonObject, StorageError>
+where
+    TMetadata: Clone + PartialEq + DeserializeOwned,
+{
+    let document = document.ok_or_else(|| StorageError::MissingMetadata(key.parent()))?;
+    let document: JsonObject = serde_json::from_slice(&document)
+        .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
+    let mut stored_metadata: TMetadata =
+        serde_json::from_value(serde_json::Value::Object(document.clone()))
+            .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))?;
+    let mut metadata = metadata.clone();
+    ignore(&mut stored_metadata);
+    ignore(&mut metadata);
+    if stored_metadata == metadata {
+        Ok(document)
+    } else {
+        Err(StorageError::InvalidMetadata(
+            key.clone(),
+            "the stored metadata differs from the metadata of the node".to_string(),
+        ))
+    }
+}
+
+/// Check that none of the `additional_fields` of the metadata document at `key` must be understood.
+///
+/// [Understood additional fields](crate::config::Config#understood-additional-fields) are permitted.
+pub(crate) fn check_additional_fields_must_understand(
+    key: &StoreKey,
+    additional_fields: &AdditionalFields,
+) -> Result<(), StorageError> {
+    let config = global_config();
+    let understood_additional_fields = config.understood_additional_fields();
+    match additional_fields.iter().find(|(name, field)| {
+        field.must_understand() && !understood_additional_fields.contains(*name)
+    }) {
+        Some((name, _)) => Err(StorageError::InvalidMetadata(
+            key.clone(),
+            format!("additional field {name} must be understood"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Parse the stored Zarr V2 attributes document (`.zattrs`) at `key`.
+///
+/// Returns empty attributes if the document does not exist.
+pub(crate) fn stored_attributes_v2(
+    key: &StoreKey,
+    document: MaybeBytes,
+) -> Result<JsonObject, StorageError> {
+    document.map_or_else(
+        || Ok(JsonObject::default()),
+        |document| {
+            serde_json::from_slice(&document)
+                .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))
+        },
+    )
+}
+
+/// Parse the optional Zarr V2 attributes document (`.zattrs`) at `key`.
+fn attributes_v2(key: &StoreKey, document: MaybeBytes) -> Result<Option<JsonObject>, StorageError> {
+    document
+        .map(|document| {
+            serde_json::from_slice(&document)
+                .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))
+        })
+        .transpose()
+}
+
+/// Parse the Zarr V2 array metadata document (`.zarray`) at `key` and its attributes document (`.zattrs`) at `attributes_key`.
+///
+/// [`NCZarr` compatibility](crate::config::Config#nczarr-compatibility) is applied if enabled.
+pub(crate) fn array_metadata_v2_from_documents(
+    key: &StoreKey,
+    document: &[u8],
+    attributes_key: &StoreKey,
+    attributes: MaybeBytes,
+) -> Result<ArrayMetadataV2, StorageError> {
+    let invalid =
+        |err: serde_json::Error| StorageError::InvalidMetadata(key.clone(), err.to_string());
+    if global_config().nczarr_compatibility() {
+        let document: JsonObject = serde_json::from_slice(document).map_err(invalid)?;
+        let attributes = attributes_v2(attributes_key, attributes)?;
+        nczarr::array_metadata_v2_from_nczarr(document, attributes).map_err(invalid)
+    } else {
+        let mut metadata: ArrayMetadataV2 = serde_json::from_slice(document).map_err(invalid)?;
+        if let Some(attributes) = attributes_v2(attributes_key, attributes)? {
+            metadata.attributes = attributes;
+        }
+        Ok(metadata)
+    }
+}
+
+/// Parse the Zarr V2 group metadata document (`.zgroup`) at `key` and its attributes document (`.zattrs`) at `attributes_key`.
+///
+/// [`NCZarr` compatibility](crate::config::Config#nczarr-compatibility) is applied if enabled.
+pub(crate) fn group_metadata_v2_from_documents(
+    key: &StoreKey,
+    document: &[u8],
+    attributes_key: &StoreKey,
+    attributes: MaybeBytes,
+) -> Result<GroupMetadataV2, StorageError> {
+    let invalid =
+        |err: serde_json::Error| StorageError::InvalidMetadata(key.clone(), err.to_string());
+    if global_config().nczarr_compatibility() {
+        let document: JsonObject = serde_json::from_slice(document).map_err(invalid)?;
+        let attributes = attributes_v2(attributes_key, attributes)?;
+        nczarr::group_metadata_v2_from_nczarr(document, attributes).map_err(invalid)
+    } else {
+        let mut metadata: GroupMetadataV2 = serde_json::from_slice(document).map_err(invalid)?;
+        if let Some(attributes) = attributes_v2(attributes_key, attributes)? {
+            metadata.attributes = attributes;
+        }
+        Ok(metadata)
+    }
+}
+
+/// Apply `f` to the `attributes` member of a Zarr V3 metadata `document`, returning the updated attributes.
+pub(crate) fn mutate_document_attributes(
+    document: &mut JsonObject,
+    f: impl FnOnce(&mut JsonObject),
+) -> JsonObject {
+    let mut attributes = match document.remove("attributes") {
+        Some(serde_json::Value::Object(attributes)) => attributes,
+        _ => JsonObject::default(),
+    };
+    f(&mut attributes);
+    if !attributes.is_empty() {
+        document.insert(
+            "attributes".to_string(),
+            serde_json::Value::Object(attributes.clone()),
+        );
+    }
+    attributes
+}
+
+/// Serialise a metadata `document` for storage at `key`.
+pub(crate) fn serialize_document(
+    key: &StoreKey,
+    document: &JsonObject,
+) -> Result<Bytes, StorageError> {
+    serde_json::to_vec_pretty(document)
+        .map(Into::into)
+        .map_err(|err| StorageError::InvalidMetadata(key.clone(), err.to_string()))
+}
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use crate::{
+    byte_range::ByteRange,
+    metadata::v2::{ArrayMetadataV2, ConsolidatedMetadataV2, GroupMetadataV2},
+    storage::{
+        store::MemoryStore, Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits,
+        StorageError, StoreCapabilities, StoreKey, StoreKeys, StoreKeysPrefixes, StorePrefix,
+        WritableStorageTraits,
+    },
+};
+
+#[cfg(feature = "async")]
+use crate::storage::{
+    AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits, MaybeAsyncBytes,
+};
+
+use super::{meta_key_v2_consolidated, NodeCreateError, NodePath};
+
+/// The metadata file names served from consolidated metadata.
+const METADATA_FILE_NAMES: [&str; 4] = ["zarr.json", ".zarray", ".zgroup", ".zattrs"];
+
+/// A storage adapter that serves Zarr V2 node metadata from consolidated metadata (`.zmetadata`).
+///
+/// Requests for node metadata keys (`.zarray`, `.zgroup`, `.zattrs`, and `zarr.json`) at or below the consolidated node are served from the consolidated metadata and never reach the underlying storage.
+/// All other requests (e.g. chunks) are delegated to the underlying storage.
+///
+/// Listing is served from the consolidated metadata, so the hierarchy can be traversed (e.g. with [`Node::open`](crate::node::Node::open) or [`Group::children`](crate::group::Group::children)) even if the underlying storage is not listable (e.g. an HTTP store).
+/// Only metadata keys are listed, chunk keys are not.
+///
+/// The consolidated metadata is read once on creation.
+/// Changes to the hierarchy after that (including changes to the underlying metadata documents) are not visible through the adapter.
+/// This is consistent with `zarr-python`, where consolidated metadata may become stale if the hierarchy is modified after consolidation.
+#[derive(Debug)]
+pub struct ConsolidatedMetadataV2StorageAdapter<TStorage: ?Sized> {
+    storage: Arc<TStorage>,
+    prefix: StorePrefix,
+    metadata: MemoryStore,
+}
+
+impl<TStorage: ?Sized> ConsolidatedMetadataV2StorageAdapter<TStorage> {
+    /// Create a new consolidated metadata storage adapter from the `consolidated_metadata` of the node at `path`.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if a metadata key is invalid or a metadata document in `consolidated_metadata` is invalid.
+    pub fn new(
+        storage: Arc<TStorage>,
+        path: &NodePath,
+        consolidated_metadata: ConsolidatedMetadataV2,
+    ) -> Result<Self, StorageError> {
+        let prefix: StorePrefix = path.try_into()?;
+        let metadata = MemoryStore::new();
+        for (key, document) in consolidated_metadata.metadata {
+            let key = StoreKey::new(prefix.as_str().to_string() + &key)?;
+            let invalid_metadata = |err: serde_json::Error| {
+                StorageError::InvalidMetadata(key.clone(), err.to_string())
+            };
+            match key_file_name(&key) {
+                ".zarray" => {
+                    serde_json::from_value::<ArrayMetadataV2>(document.clone())
+                        .map_err(invalid_metadata)?;
+                }
+                ".zgroup" => {
+                    serde_json::from_value::<GroupMetadataV2>(document.clone())
+                        .map_err(invalid_metadata)?;
+                }
+                ".zattrs" => {
+                    serde_json::from_value::<serde_json::Map<String, serde_json::Value>>(
+                        document.clone(),
+                    )
+                    .map_err(invalid_metadata)?;
+                }
+                _ => continue,
+            }
+            let document = serde_json::to_vec(&document).map_err(invalid_metadata)?;
+            metadata.set(&key, document.into())?;
+        }
+        Ok(Self {
+            storage,
+            prefix,
+            metadata,
+        })
+    }
+
+    /// Returns the underlying storage.
+    #[must_use]
+    pub fn storage(&self) -> &Arc<TStorage> {
+        &self.storage
+    }
+
+    /// Returns the keys of the metadata documents in the consolidated metadata.
+    ///
+    /// # Panics
+    /// Panics if the consolidated metadata mutex is poisoned.
+    #[must_use]
+    pub fn metadata_keys(&self) -> StoreKeys {
+        self.metadata.snapshot().keys()
+    }
+
+    /// Returns true if `key` is served from the consolidated metadata.
+    fn is_consolidated(&self, key: &StoreKey) -> bool {
+        key.has_prefix(&self.prefix) && METADATA_FILE_NAMES.contains(&key_file_name(key))
+    }
+}
+
+/// Returns the last component of `key`.
+fn key_file_name(key: &StoreKey) -> &str {
+    key.as_str().rsplit('/').next().unwrap_or_default()
+}
+
+/// Open the Zarr V2 consolidated metadata (`.zmetadata`) of the node at `path`.
+///
+/// The returned storage serves the metadata of the node and its descendants without further requests to `storage`.
+/// See [`ConsolidatedMetadataV2StorageAdapter`].
+///
+/// ### Example
+/// ```rust
+/// # use std::sync::Arc;
+/// # use zarrs::{array::Array, node::{open_consolidated_v2, Node}};
+/// # use zarrs_filesystem::FilesystemStore;
+/// let store = Arc::new(FilesystemStore::new("tests/data/v2/consolidated.zarr")?);
+/// let storage = open_consolidated_v2(store, "/")?;
+/// let node = Node::open(&storage, "/")?;
+/// let array = Array::open(storage.clone(), "/group/array")?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+/// Returns a [`NodeCreateError`] if
+///  - `path` is invalid,
+///  - the consolidated metadata is missing or invalid, or
+///  - there is an underlying error with the store.
+pub fn open_consolidated_v2<TStorage: ?Sized + ReadableStorageTraits>(
+    storage: Arc<TStorage>,
+    path: &str,
+) -> Result<Arc<ConsolidatedMetadataV2StorageAdapter<TStorage>>, NodeCreateError> {
+    let path: NodePath = path.try_into()?;
+    let key = meta_key_v2_consolidated(&path);
+    let Some(consolidated_metadata) = storage.get(&key)? else {
+        return Err(NodeCreateError::MissingMetadata);
+    };
+    let consolidated_metadata: ConsolidatedMetadataV2 =
+        serde_json::from_slice(&consolidated_metadata)
+            .map_err(|err| StorageError::InvalidMetadata(key, err.to_string()))?;
+    Ok(Arc::new(ConsolidatedMetadataV2StorageAdapter::new(
+        storage,
+        &path,
+        consolidated_metadata,
+    )?))
+}
+
+#[cfg(feature = "async")]
+/// Asynchronously open the Zarr V2 consolidated metadata (`.zmetadata`) of the node at `path`.
+///
+/// See [`open_consolidated_v2`].
+///
+/// # Errors
+/// Returns a [`NodeCreateError`] if
+///  - `path` is invalid,
+///  - the consolidated metadata is missing or invalid, or
+///  - there is an underlying error with the store.
+pub async fn async_open_consolidated_v2<TStorage: ?Sized + AsyncReadableStorageTraits>(
+    storage: Arc<TStorage>,
+    path: &str,
+) -> Result<Arc<ConsolidatedMetadataV2StorageAdapter<TStorage>>, NodeCreateError> {
+    let path: NodePath = path.try_into()?;
+    let key = meta_key_v2_consolidated(&path);
+    let Some(consolidated_metadata) = storage.get(&key).await? else {
+        return Err(NodeCreateError::MissingMetadata);
+    };
+    let consolidated_metadata: ConsolidatedMetadataV2 =
+        serde_json::from_slice(&consolidated_metadata)
+            .map_err(|err| StorageError::InvalidMetadata(key, err.to_string()))?;
+    Ok(Arc::new(ConsolidatedMetadataV2StorageAdapter::new(
+        storage,
+        &path,
+        consolidated_metadata,
+    )?))
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
+    for ConsolidatedMetadataV2StorageAdapter<TStorage>
+{
+    fn get(&self, key: &StoreKey) -> Result<MaybeBytes, StorageError> {
+        if self.is_consolidated(key) {
+            self.metadata.get(key)
+        } else {
+            self.storage.get(key)
+        }
+    }
+
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        if self.is_consolidated(key) {
+            self.metadata.get_partial_values_key(key, byte_ranges)
+        } else {
+            self.storage.get_partial_values_key(key, byte_ranges)
+        }
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        if self.is_consolidated(key) {
+            self.metadata.size_key(key)
+        } else {
+            self.storage.size_key(key)
+        }
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage.capabilities().with_listing(true)
+    }
+}
+
+impl<TStorage: ?Sized + Send + Sync> ListableStorageTraits
+    for ConsolidatedMetadataV2StorageAdapter<TStorage>
+{
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.metadata.list()
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.metadata.list_prefix(prefix)
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.metadata.list_dir(prefix)
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.metadata.size_prefix(prefix)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
+    for ConsolidatedMetadataV2StorageAdapter<TStorage>
+{
+    async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
+        if self.is_consolidated(key) {
+            ReadableStorageTraits::get(&self.metadata, key)
+        } else {
+            self.storage.get(key).await
+        }
+    }
+
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        if self.is_consolidated(key) {
+            ReadableStorageTraits::get_partial_values_key(&self.metadata, key, byte_ranges)
+        } else {
+            self.storage.get_partial_values_key(key, byte_ranges).await
+        }
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        if self.is_consolidated(key) {
+            ReadableStorageTraits::size_key(&self.metadata, key)
+        } else {
+            self.storage.size_key(key).await
+        }
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage.capabilities().with_listing(true)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + Send + Sync> AsyncListableStorageTraits
+    for ConsolidatedMetadataV2StorageAdapter<TStorage>
+{
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        ListableStorageTraits::list(&self.metadata)
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        ListableStorageTraits::list_prefix(&self.metadata, prefix)
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        ListableStorageTraits::list_dir(&self.metadata, prefix)
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        ListableStorageTraits::size_prefix(&self.metadata, prefix)
+    }
+}
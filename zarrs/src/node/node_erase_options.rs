@@ -0,0 +1,50 @@
+/// Options for erasing a node with [`erase_node_opt`](crate::node::erase_node_opt).
+#[derive(Debug, Clone, Default)]
+pub struct NodeEraseOptions {
+    dry_run: bool,
+    force: bool,
+}
+
+impl NodeEraseOptions {
+    /// Get the dry run option.
+    ///
+    /// If true, the keys that would be erased are returned but nothing is erased.
+    #[must_use]
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Set the dry run option.
+    #[must_use]
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Set the dry run option.
+    pub fn set_dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Get the force option.
+    ///
+    /// If true, the root node can be erased.
+    #[must_use]
+    pub fn force(&self) -> bool {
+        self.force
+    }
+
+    /// Set the force option.
+    #[must_use]
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Set the force option.
+    pub fn set_force(&mut self, force: bool) -> &mut Self {
+        self.force = force;
+        self
+    }
+}
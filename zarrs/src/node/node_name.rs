@@ -73,6 +73,18 @@ impl NodeName {
     }
 }
 
+/// Validate that `path` is a relative path of one or more non-root [`NodeName`]s separated by "/".
+pub(crate) fn validate_relative_node_path(path: &str) -> Result<(), NodeNameError> {
+    if path
+        .split('/')
+        .all(|name| !name.is_empty() && NodeName::validate(name))
+    {
+        Ok(())
+    } else {
+        Err(NodeNameError(path.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,134 @@
+use crate::{
+    config::{global_config, MetadataRetrieveVersion},
+    metadata::v3::{AdditionalFields, ArrayMetadataV3, UnsupportedAdditionalFieldError},
+};
+
+/// Options for opening an array or group with [`Array::open_with_options`](crate::array::Array::open_with_options) or [`Group::open_with_options`](crate::group::Group::open_with_options).
+#[derive(Debug, Clone, Default)]
+pub struct NodeOpenOptions {
+    version: MetadataRetrieveVersion,
+    strict_extensions: bool,
+    lenient_numeric_strings: bool,
+}
+
+impl NodeOpenOptions {
+    /// Get the metadata version to retrieve.
+    ///
+    /// If the metadata is not found, but metadata of the other Zarr version is, opening fails with a metadata version mismatch error.
+    #[must_use]
+    pub fn version(&self) -> MetadataRetrieveVersion {
+        self.version.clone()
+    }
+
+    /// Set the metadata version to retrieve.
+    #[must_use]
+    pub fn with_version(mut self, version: MetadataRetrieveVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Set the metadata version to retrieve.
+    pub fn set_version(&mut self, version: MetadataRetrieveVersion) -> &mut Self {
+        self.version = version;
+        self
+    }
+
+    /// Get the strict extensions option.
+    ///
+    /// If true, opening fails if the metadata has any additional fields, even those annotated with `"must_understand": false`, except [understood additional fields](crate::config::Config#understood-additional-fields).
+    /// Otherwise, such fields are ignored and reported as a [`NodeOpenWarning`].
+    #[must_use]
+    pub fn strict_extensions(&self) -> bool {
+        self.strict_extensions
+    }
+
+    /// Set the strict extensions option.
+    #[must_use]
+    pub fn with_strict_extensions(mut self, strict_extensions: bool) -> Self {
+        self.strict_extensions = strict_extensions;
+        self
+    }
+
+    /// Set the strict extensions option.
+    pub fn set_strict_extensions(&mut self, strict_extensions: bool) -> &mut Self {
+        self.strict_extensions = strict_extensions;
+        self
+    }
+
+    /// Get the lenient numeric strings option.
+    ///
+    /// If true, numeric strings in the `shape`, `chunk_grid` configuration, and integer `fill_value` of Zarr V3 array metadata are coerced to integers (see [`ArrayMetadataV3::from_slice_lenient`]).
+    /// Each coerced field is reported as a [`NodeOpenWarning`].
+    /// Otherwise, such metadata is rejected.
+    #[must_use]
+    pub fn lenient_numeric_strings(&self) -> bool {
+        self.lenient_numeric_strings
+    }
+
+    /// Set the lenient numeric strings option.
+    #[must_use]
+    pub fn with_lenient_numeric_strings(mut self, lenient_numeric_strings: bool) -> Self {
+        self.lenient_numeric_strings = lenient_numeric_strings;
+        self
+    }
+
+    /// Set the lenient numeric strings option.
+    pub fn set_lenient_numeric_strings(&mut self, lenient_numeric_strings: bool) -> &mut Self {
+        self.lenient_numeric_strings = lenient_numeric_strings;
+        self
+    }
+
+    /// Deserialise Zarr V3 array metadata according to the options.
+    ///
+    /// Returns a warning for each field with coerced numeric strings.
+    pub(crate) fn array_metadata_v3_from_slice(
+        &self,
+        json: &[u8],
+    ) -> Result<(ArrayMetadataV3, Vec<NodeOpenWarning>), serde_json::Error> {
+        if self.lenient_numeric_strings {
+            let (metadata, coerced) = ArrayMetadataV3::from_slice_lenient(json)?;
+            let warnings = coerced
+                .into_iter()
+                .map(NodeOpenWarning::CoercedNumericStrings)
+                .collect();
+            Ok((metadata, warnings))
+        } else {
+            Ok((serde_json::from_slice(json)?, vec![]))
+        }
+    }
+
+    /// Check the `additional_fields` of opened metadata against the options.
+    ///
+    /// Fields that must be understood are rejected unless they are [understood additional fields](crate::config::Config#understood-additional-fields).
+    /// Returns a warning for each ignored additional field.
+    pub(crate) fn check_additional_fields(
+        &self,
+        additional_fields: &AdditionalFields,
+    ) -> Result<Vec<NodeOpenWarning>, UnsupportedAdditionalFieldError> {
+        let understood_additional_fields = global_config().understood_additional_fields().clone();
+        let mut warnings = vec![];
+        for (name, field) in additional_fields {
+            if understood_additional_fields.contains(name) {
+                continue;
+            }
+            if self.strict_extensions || field.must_understand() {
+                return Err(UnsupportedAdditionalFieldError::new(
+                    name.clone(),
+                    serde_json::to_value(field).unwrap_or_default(),
+                ));
+            }
+            warnings.push(NodeOpenWarning::IgnoredAdditionalField(name.clone()));
+        }
+        Ok(warnings)
+    }
+}
+
+/// A warning raised when opening an array or group with [`NodeOpenOptions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NodeOpenWarning {
+    /// An additional field annotated with `"must_understand": false` was ignored.
+    IgnoredAdditionalField(String),
+    /// Numeric strings in a metadata field were coerced to integers.
+    CoercedNumericStrings(String),
+}
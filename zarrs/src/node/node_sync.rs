@@ -3,12 +3,14 @@ use std::sync::Arc;
 use crate::{
     config::MetadataRetrieveVersion,
     storage::{
-        discover_children, ListableStorageTraits, ReadableStorageTraits, StorageError, StorePrefix,
+        discover_children, ListableStorageTraits, ReadableStorageTraits, StorageError, StoreKeys,
+        StorePrefix, WritableStorageTraits,
     },
 };
 
 use super::{
-    meta_key_v2_array, meta_key_v2_group, meta_key_v3, Node, NodeMetadata, NodePath, NodePathError,
+    meta_key_v2_array, meta_key_v2_group, meta_key_v3, Node, NodeEraseError, NodeEraseOptions,
+    NodeMetadata, NodePath, NodePathError,
 };
 
 // TODO: Replace get_child_nodes with this method in the next breaking release
@@ -84,3 +86,43 @@ pub fn node_exists_listable<TStorage: ?Sized + ListableStorageTraits>(
             | keys.contains(&meta_key_v2_group(path))
     })
 }
+
+/// Erase the node at `path` with default [`NodeEraseOptions`].
+///
+/// See [`erase_node_opt`].
+///
+/// # Errors
+/// Returns a [`NodeEraseError`] if `path` is the root node or there is an underlying error with the store.
+pub fn erase_node<TStorage: ?Sized + WritableStorageTraits + ListableStorageTraits>(
+    storage: &Arc<TStorage>,
+    path: &NodePath,
+) -> Result<StoreKeys, NodeEraseError> {
+    erase_node_opt(storage, path, &NodeEraseOptions::default())
+}
+
+/// Erase the node at `path` with non-default [`NodeEraseOptions`].
+///
+/// The metadata of the node (`zarr.json`, `.zarray`, `.zgroup`, `.zattrs`) and all keys under its prefix are erased.
+/// This includes the chunks of an array, or the child nodes of a group.
+/// The keys that were erased, or would be erased in a dry run, are returned.
+/// Succeeds if the node does not exist.
+///
+/// # Errors
+/// Returns a [`NodeEraseError`] if
+///  - `path` is the root node and the force option is not set, or
+///  - there is an underlying error with the store.
+pub fn erase_node_opt<TStorage: ?Sized + WritableStorageTraits + ListableStorageTraits>(
+    storage: &Arc<TStorage>,
+    path: &NodePath,
+    options: &NodeEraseOptions,
+) -> Result<StoreKeys, NodeEraseError> {
+    if path == &NodePath::root() && !options.force() {
+        return Err(NodeEraseError::RootNode);
+    }
+    let prefix: StorePrefix = path.try_into().map_err(StorageError::from)?;
+    let keys = storage.list_prefix(&prefix)?;
+    if !options.dry_run() {
+        storage.erase_prefix(&prefix)?;
+    }
+    Ok(keys)
+}
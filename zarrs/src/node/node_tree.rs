@@ -0,0 +1,342 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+
+use crate::{
+    array::chunk_grid::RegularChunkGridConfiguration,
+    array::ArrayMetadata,
+    config::MetadataRetrieveVersion,
+    metadata::{
+        v2::{array::DataTypeMetadataV2, MetadataV2},
+        v3::MetadataV3,
+        GroupMetadata,
+    },
+    storage::{
+        discover_children, ListableStorageTraits, ReadableStorageTraits, StorageError, StorePrefix,
+    },
+};
+
+use super::{Node, NodeCreateError, NodeMetadata, NodeName, NodePath, NodePathError};
+
+#[cfg(feature = "async")]
+use futures::StreamExt;
+
+#[cfg(feature = "async")]
+use crate::{
+    config::global_config,
+    storage::{async_discover_children, AsyncListableStorageTraits, AsyncReadableStorageTraits},
+};
+
+/// A structured Zarr hierarchy.
+///
+/// A [`NodeTree`] holds the path and metadata of every node below a root node.
+/// Unlike [`Node::open`], nodes with missing or invalid metadata do not fail the hierarchy traversal and are instead annotated with an error.
+///
+/// The [`Display`](std::fmt::Display) implementation prints an indented tree.
+/// Arrays are annotated with their shape, data type, chunk shape, codecs, and attribute keys, and groups with their attribute keys.
+/// For example:
+/// ```text
+/// / (group) attributes: [title]
+///   a (group)
+///     baz (array) shape: [8, 8], data type: float64, chunk shape: [4, 4], codecs: [bytes, gzip], attributes: [units]
+///   b (error) Metadata is missing
+/// ```
+#[derive(Debug, Clone)]
+pub struct NodeTree {
+    path: NodePath,
+    metadata: Option<NodeMetadata>,
+    error: Option<String>,
+    children: Vec<NodeTree>,
+}
+
+impl NodeTree {
+    /// Open the hierarchy at `path` in `storage`.
+    ///
+    /// If `max_depth` is [`Some`], nodes more than `max_depth` levels below `path` are not retrieved.
+    /// Children are sorted by their path.
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if `path` is invalid or the metadata of the node at `path` cannot be retrieved.
+    pub fn open<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits>(
+        storage: &Arc<TStorage>,
+        path: &str,
+        max_depth: Option<usize>,
+    ) -> Result<Self, NodeCreateError> {
+        let path: NodePath = path.try_into()?;
+        let metadata = Node::get_metadata(storage, &path, &MetadataRetrieveVersion::Default)?;
+        Ok(Self::open_children(storage, path, metadata, max_depth))
+    }
+
+    fn open_children<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits>(
+        storage: &Arc<TStorage>,
+        path: NodePath,
+        metadata: NodeMetadata,
+        max_depth: Option<usize>,
+    ) -> Self {
+        let mut tree = Self {
+            path,
+            metadata: Some(metadata),
+            error: None,
+            children: Vec::new(),
+        };
+        if matches!(tree.metadata, Some(NodeMetadata::Group(_))) && max_depth != Some(0) {
+            match child_paths(storage, &tree.path) {
+                Ok(paths) => {
+                    for path in paths {
+                        let child = match Node::get_metadata(
+                            storage,
+                            &path,
+                            &MetadataRetrieveVersion::Default,
+                        ) {
+                            Ok(metadata) => Self::open_children(
+                                storage,
+                                path,
+                                metadata,
+                                max_depth.map(|depth| depth - 1),
+                            ),
+                            Err(err) => Self {
+                                path,
+                                metadata: None,
+                                error: Some(err.to_string()),
+                                children: Vec::new(),
+                            },
+                        };
+                        tree.children.push(child);
+                    }
+                }
+                Err(err) => tree.error = Some(err.to_string()),
+            }
+        }
+        tree
+    }
+
+    #[cfg(feature = "async")]
+    /// Asynchronously open the hierarchy at `path` in `storage`.
+    ///
+    /// See [`open`](NodeTree::open).
+    /// The metadata of the children of each group is retrieved concurrently, up to the [chunk concurrent maximum](crate::config::Config#chunk-concurrent-maximum).
+    ///
+    /// # Errors
+    /// Returns [`NodeCreateError`] if `path` is invalid or the metadata of the node at `path` cannot be retrieved.
+    pub async fn async_open<
+        TStorage: ?Sized + AsyncReadableStorageTraits + AsyncListableStorageTraits,
+    >(
+        storage: &Arc<TStorage>,
+        path: &str,
+        max_depth: Option<usize>,
+    ) -> Result<Self, NodeCreateError> {
+        let path: NodePath = path.try_into()?;
+        let metadata =
+            Node::async_get_metadata(storage, &path, &MetadataRetrieveVersion::Default).await?;
+        Ok(Self::async_open_children(storage, path, metadata, max_depth).await)
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_open_children<
+        TStorage: ?Sized + AsyncReadableStorageTraits + AsyncListableStorageTraits,
+    >(
+        storage: &Arc<TStorage>,
+        path: NodePath,
+        metadata: NodeMetadata,
+        max_depth: Option<usize>,
+    ) -> Self {
+        let mut tree = Self {
+            path,
+            metadata: Some(metadata),
+            error: None,
+            children: Vec::new(),
+        };
+        if matches!(tree.metadata, Some(NodeMetadata::Group(_))) && max_depth != Some(0) {
+            match async_child_paths(storage, &tree.path).await {
+                Ok(paths) => {
+                    let concurrent_limit = global_config().chunk_concurrent_maximum().max(1);
+                    tree.children = futures::stream::iter(paths)
+                        .map(|path| async move {
+                            match Node::async_get_metadata(
+                                storage,
+                                &path,
+                                &MetadataRetrieveVersion::Default,
+                            )
+                            .await
+                            {
+                                Ok(metadata) => {
+                                    Box::pin(Self::async_open_children(
+                                        storage,
+                                        path,
+                                        metadata,
+                                        max_depth.map(|depth| depth - 1),
+                                    ))
+                                    .await
+                                }
+                                Err(err) => Self {
+                                    path,
+                                    metadata: None,
+                                    error: Some(err.to_string()),
+                                    children: Vec::new(),
+                                },
+                            }
+                        })
+                        .buffered(concurrent_limit)
+                        .collect()
+                        .await;
+                }
+                Err(err) => tree.error = Some(err.to_string()),
+            }
+        }
+        tree
+    }
+
+    /// Returns a reference to the path of the node.
+    #[must_use]
+    pub fn path(&self) -> &NodePath {
+        &self.path
+    }
+
+    /// Returns the name of the node.
+    #[must_use]
+    pub fn name(&self) -> NodeName {
+        let name = self
+            .path
+            .as_str()
+            .split('/')
+            .next_back()
+            .unwrap_or_default();
+        unsafe { NodeName::new_unchecked(name) }
+    }
+
+    /// Returns a reference to the metadata of the node, or [`None`] if it could not be retrieved.
+    #[must_use]
+    pub fn metadata(&self) -> Option<&NodeMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the error encountered retrieving the metadata or children of the node, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns a reference to the children of the node.
+    #[must_use]
+    pub fn children(&self) -> &[Self] {
+        &self.children
+    }
+
+    /// Return a one line description of the node.
+    fn description(&self) -> String {
+        let name = if self.path.as_str() == "/" {
+            "/".to_string()
+        } else {
+            self.name().to_string()
+        };
+        let mut fields = Vec::new();
+        let (kind, attributes) = match &self.metadata {
+            Some(NodeMetadata::Array(ArrayMetadata::V3(metadata))) => {
+                fields.push(format!("shape: {:?}", metadata.shape));
+                fields.push(format!("data type: {}", metadata.data_type.name()));
+                let chunk_shape = metadata
+                    .chunk_grid
+                    .to_configuration::<RegularChunkGridConfiguration>()
+                    .map_or_else(
+                        |_| metadata.chunk_grid.name().to_string(),
+                        |configuration| format!("{:?}", configuration.chunk_shape.to_array_shape()),
+                    );
+                fields.push(format!("chunk shape: {chunk_shape}"));
+                fields.push(format!(
+                    "codecs: [{}]",
+                    metadata.codecs.iter().map(MetadataV3::name).join(", ")
+                ));
+                ("array", Some(&metadata.attributes))
+            }
+            Some(NodeMetadata::Array(ArrayMetadata::V2(metadata))) => {
+                fields.push(format!("shape: {:?}", metadata.shape));
+                let data_type = match &metadata.dtype {
+                    DataTypeMetadataV2::Simple(data_type) => data_type.clone(),
+                    DataTypeMetadataV2::Structured(_) => {
+                        serde_json::to_string(&metadata.dtype).unwrap_or_default()
+                    }
+                };
+                fields.push(format!("data type: {data_type}"));
+                fields.push(format!(
+                    "chunk shape: {:?}",
+                    metadata.chunks.to_array_shape()
+                ));
+                let codecs = metadata
+                    .filters
+                    .iter()
+                    .flatten()
+                    .chain(&metadata.compressor)
+                    .map(MetadataV2::id)
+                    .join(", ");
+                fields.push(format!("codecs: [{codecs}]"));
+                ("array", Some(&metadata.attributes))
+            }
+            Some(NodeMetadata::Group(GroupMetadata::V3(metadata))) => {
+                ("group", Some(&metadata.attributes))
+            }
+            Some(NodeMetadata::Group(GroupMetadata::V2(metadata))) => {
+                ("group", Some(&metadata.attributes))
+            }
+            None => ("error", None),
+        };
+        if let Some(attributes) = attributes.filter(|attributes| !attributes.is_empty()) {
+            fields.push(format!("attributes: [{}]", attributes.keys().join(", ")));
+        }
+        match (&self.metadata, &self.error) {
+            (None, Some(error)) => fields.push(error.clone()),
+            (Some(_), Some(error)) => fields.push(format!("error: {error}")),
+            (_, None) => {}
+        }
+        if fields.is_empty() {
+            format!("{name} ({kind})")
+        } else {
+            format!("{name} ({kind}) {}", fields.join(", "))
+        }
+    }
+
+    fn fmt_depth(&self, f: &mut std::fmt::Formatter<'_>, depth: usize) -> std::fmt::Result {
+        writeln!(f, "{}{}", " ".repeat(depth * 2), self.description())?;
+        for child in &self.children {
+            child.fmt_depth(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for NodeTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_depth(f, 0)
+    }
+}
+
+/// Return the sorted paths of the children of the node at `path`.
+fn child_paths<TStorage: ?Sized + ReadableStorageTraits + ListableStorageTraits>(
+    storage: &Arc<TStorage>,
+    path: &NodePath,
+) -> Result<Vec<NodePath>, NodeCreateError> {
+    let prefix: StorePrefix = path.try_into().map_err(StorageError::from)?;
+    let mut paths = discover_children(storage, &prefix)?
+        .iter()
+        .map(NodePath::try_from)
+        .collect::<Result<Vec<_>, NodePathError>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(feature = "async")]
+/// Asynchronously return the sorted paths of the children of the node at `path`.
+async fn async_child_paths<
+    TStorage: ?Sized + AsyncReadableStorageTraits + AsyncListableStorageTraits,
+>(
+    storage: &Arc<TStorage>,
+    path: &NodePath,
+) -> Result<Vec<NodePath>, NodeCreateError> {
+    let prefix: StorePrefix = path.try_into().map_err(StorageError::from)?;
+    let mut paths = async_discover_children(storage, &prefix)
+        .await?
+        .iter()
+        .map(NodePath::try_from)
+        .collect::<Result<Vec<_>, NodePathError>>()?;
+    paths.sort();
+    Ok(paths)
+}
@@ -44,13 +44,224 @@ impl PluginMetadataInvalidError {
     }
 }
 
+/// A known plugin name.
+///
+/// Known names are consulted when constructing a [`PluginUnsupportedError`], so that names of plugins disabled by a crate feature or known by a legacy alias can be reported.
+#[derive(Debug, Clone, Copy)]
+pub struct PluginKnownName {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    feature: Option<&'static str>,
+}
+
+impl PluginKnownName {
+    /// Create a new [`PluginKnownName`].
+    ///
+    /// `aliases` are alternative (e.g. Zarr V2 or legacy) names of the plugin, and `feature` is the crate feature required to enable the plugin.
+    #[must_use]
+    pub const fn new(
+        name: &'static str,
+        aliases: &'static [&'static str],
+        feature: Option<&'static str>,
+    ) -> Self {
+        Self {
+            name,
+            aliases,
+            feature,
+        }
+    }
+
+    /// Return the name of the plugin.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the known aliases of the plugin.
+    #[must_use]
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Return the crate feature required to enable the plugin.
+    #[must_use]
+    pub const fn feature(&self) -> Option<&'static str> {
+        self.feature
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.name == name || self.aliases.contains(&name)
+    }
+}
+
+/// An unsupported plugin error.
+#[derive(Debug, Error)]
+#[error("{plugin_type} {name} is not supported{}", self.hint())]
+pub struct PluginUnsupportedError {
+    name: String,
+    plugin_type: String,
+    known_name: Option<PluginKnownName>,
+    known_name_registered: bool,
+    suggestions: Vec<String>,
+}
+
+impl PluginUnsupportedError {
+    /// Create a new [`PluginUnsupportedError`].
+    #[must_use]
+    pub fn new(name: String, plugin_type: String) -> Self {
+        Self {
+            name,
+            plugin_type,
+            known_name: None,
+            known_name_registered: false,
+            suggestions: vec![],
+        }
+    }
+
+    /// Create a new [`PluginUnsupportedError`] with hints derived from known and registered plugin names.
+    ///
+    /// `registered` are the names of the registered plugins.
+    /// If `name` is a known plugin name or alias, the error reports the aliases of that plugin and the crate feature required to enable it if it is not registered.
+    /// Otherwise, close matches of `name` in `known_names` and `registered` are suggested.
+    #[must_use]
+    pub fn with_known_names<'a>(
+        name: String,
+        plugin_type: String,
+        known_names: &[PluginKnownName],
+        registered: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let registered: Vec<&str> = registered.into_iter().collect();
+        let known_name = known_names
+            .iter()
+            .find(|known| known.matches(&name))
+            .copied();
+        let known_name_registered =
+            known_name.is_some_and(|known| registered.contains(&known.name));
+        let suggestions = if known_name.is_some() {
+            vec![]
+        } else {
+            let candidates = known_names
+                .iter()
+                .flat_map(|known| std::iter::once(known.name).chain(known.aliases.iter().copied()))
+                .chain(registered);
+            close_matches(&name, candidates)
+        };
+        Self {
+            name,
+            plugin_type,
+            known_name,
+            known_name_registered,
+            suggestions,
+        }
+    }
+
+    /// Return the unsupported plugin name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return the plugin type (e.g. `codec`).
+    #[must_use]
+    pub fn plugin_type(&self) -> &str {
+        &self.plugin_type
+    }
+
+    /// Return the known plugin associated with the unsupported name, if any.
+    #[must_use]
+    pub const fn known_name(&self) -> Option<&PluginKnownName> {
+        self.known_name.as_ref()
+    }
+
+    /// Return names close to the unsupported name.
+    #[must_use]
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    fn hint(&self) -> String {
+        let mut hints = Vec::new();
+        if let Some(known_name) = &self.known_name {
+            if known_name.name != self.name {
+                hints.push(format!("it is an alias of {}", known_name.name));
+            }
+            if let (Some(feature), false) = (known_name.feature, self.known_name_registered) {
+                hints.push(format!("enable the `{feature}` feature"));
+            }
+            if !known_name.aliases.is_empty() {
+                hints.push(format!(
+                    "known aliases of {}: {}",
+                    known_name.name,
+                    known_name.aliases.join(", ")
+                ));
+            }
+        }
+        if !self.suggestions.is_empty() {
+            hints.push(format!("did you mean {}?", self.suggestions.join(", ")));
+        }
+        if hints.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", hints.join("; "))
+        }
+    }
+}
+
+/// Return the sorted and deduplicated `candidates` within a small edit distance of `name`.
+pub(crate) fn close_matches<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut matches: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    matches.sort_unstable();
+    let mut matches: Vec<String> = matches
+        .into_iter()
+        .map(|(_, candidate)| candidate.to_string())
+        .collect();
+    matches.dedup();
+    matches
+}
+
+/// The optimal string alignment distance between `a` and `b`.
+///
+/// This is the Levenshtein distance extended with transpositions of adjacent characters (e.g. `ztsd` and `zstd` are at a distance of 1).
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
 /// A plugin creation error.
 #[derive(Error, Debug)]
-#[allow(missing_docs)]
 pub enum PluginCreateError {
     /// An unsupported plugin.
-    #[error("{plugin_type} {name} is not supported")]
-    Unsupported { name: String, plugin_type: String },
+    #[error(transparent)]
+    Unsupported(Box<PluginUnsupportedError>),
     /// Invalid metadata.
     #[error(transparent)]
     MetadataInvalid(#[from] PluginMetadataInvalidError),
@@ -59,6 +270,12 @@ pub enum PluginCreateError {
     Other(String),
 }
 
+impl From<PluginUnsupportedError> for PluginCreateError {
+    fn from(err: PluginUnsupportedError) -> Self {
+        Self::Unsupported(Box::new(err))
+    }
+}
+
 impl From<&str> for PluginCreateError {
     fn from(err_string: &str) -> Self {
         Self::Other(err_string.to_string())
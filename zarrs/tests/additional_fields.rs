@@ -0,0 +1,206 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, ArrayMetadataOptions},
+    config::global_config_mut,
+    group::{Group, GroupMetadataOptions},
+    metadata::v3::AdditionalField,
+    node::NodeOpenOptions,
+    storage::{store::MemoryStore, ReadableStorageTraits, StoreKey, WritableStorageTraits},
+};
+
+const ADDITIONAL_FIELDS: [&str; 5] = [
+    "ext_string",
+    "ext_number",
+    "ext_bool_null",
+    "ext_array",
+    "ext_object",
+];
+
+const ARRAY_METADATA: &str = r#"{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "uint8",
+    "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [2]}},
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}],
+    "ext_string": {"must_understand": false, "value": "a string"},
+    "ext_number": {"must_understand": false, "int": -1, "float": 0.1, "big": 18446744073709551615},
+    "ext_bool_null": {"must_understand": false, "bool": true, "null": null},
+    "ext_array": {"must_understand": false, "array": [1, "two", [3.0], {"four": 4}]},
+    "ext_object": {"must_understand": false, "object": {"nested": {"deeply": [true, false]}}}
+}"#;
+
+const GROUP_METADATA: &str = r#"{
+    "zarr_format": 3,
+    "node_type": "group",
+    "ext_string": {"must_understand": false, "value": "a string"},
+    "ext_number": {"must_understand": false, "int": -1, "float": 0.1, "big": 18446744073709551615},
+    "ext_bool_null": {"must_understand": false, "bool": true, "null": null},
+    "ext_array": {"must_understand": false, "array": [1, "two", [3.0], {"four": 4}]},
+    "ext_object": {"must_understand": false, "object": {"nested": {"deeply": [true, false]}}}
+}"#;
+
+/// Check that the additional fields of the document at `key` are byte-for-byte identical to those of `expected`.
+fn check_additional_fields(
+    store: &MemoryStore,
+    key: &StoreKey,
+    expected: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let expected: serde_json::Value = serde_json::from_str(expected)?;
+    let stored: serde_json::Value = serde_json::from_slice(&store.get(key)?.unwrap())?;
+    for name in ADDITIONAL_FIELDS {
+        assert_eq!(
+            serde_json::to_string(&stored[name])?,
+            serde_json::to_string(&expected[name])?
+        );
+    }
+    Ok(())
+}
+
+fn must_understand_field() -> AdditionalField {
+    let mut field = serde_json::Map::new();
+    field.insert("must_understand".to_string(), true.into());
+    field.insert("key".to_string(), "value".into());
+    field.into()
+}
+
+#[test]
+fn additional_fields_array_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let key = StoreKey::new("array/zarr.json")?;
+    store.set(&key, ARRAY_METADATA.into())?;
+
+    // open -> mutate -> store
+    let mut array = Array::open(store.clone(), "/array")?;
+    assert_eq!(array.additional_fields().len(), ADDITIONAL_FIELDS.len());
+    array
+        .attributes_mut()
+        .insert("key".to_string(), "value".into());
+    array.set_shape(vec![8]);
+    array.store_metadata()?;
+    check_additional_fields(&store, &key, ARRAY_METADATA)?;
+
+    // reopen -> store
+    let array = Array::open(store.clone(), "/array")?;
+    assert_eq!(array.shape(), &[8]);
+    array.store_metadata()?;
+    check_additional_fields(&store, &key, ARRAY_METADATA)?;
+
+    // builder -> store
+    let key_built = StoreKey::new("built/zarr.json")?;
+    array
+        .builder()
+        .build(store.clone(), "/built")?
+        .store_metadata()?;
+    check_additional_fields(&store, &key_built, ARRAY_METADATA)?;
+
+    // additional fields that must be understood are stored as-is unless strict
+    let mut array = Array::open(store.clone(), "/array")?;
+    array
+        .additional_fields_mut()
+        .insert("ext_must_understand".to_string(), must_understand_field());
+    assert!(array
+        .store_metadata_opt(&ArrayMetadataOptions::default().with_strict_additional_fields(true))
+        .is_err());
+    check_additional_fields(&store, &key, ARRAY_METADATA)?;
+    array.store_metadata()?;
+    let stored: serde_json::Value = serde_json::from_slice(&store.get(&key)?.unwrap())?;
+    assert_eq!(
+        serde_json::to_string(&stored["ext_must_understand"])?,
+        r#"{"must_understand":true,"key":"value"}"#
+    );
+    assert!(Array::open(store.clone(), "/array").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn additional_fields_group_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let key = StoreKey::new("group/zarr.json")?;
+    store.set(&key, GROUP_METADATA.into())?;
+
+    let mut group = Group::open(store.clone(), "/group")?;
+    assert_eq!(group.additional_fields().len(), ADDITIONAL_FIELDS.len());
+    group
+        .attributes_mut()
+        .insert("key".to_string(), "value".into());
+    group.store_metadata()?;
+    check_additional_fields(&store, &key, GROUP_METADATA)?;
+
+    group
+        .additional_fields_mut()
+        .insert("ext_must_understand".to_string(), must_understand_field());
+    assert!(group
+        .store_metadata_opt(&GroupMetadataOptions::default().with_strict_additional_fields(true))
+        .is_err());
+    check_additional_fields(&store, &key, GROUP_METADATA)?;
+    group.store_metadata()?;
+    assert!(Group::open(store.clone(), "/group").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn additional_fields_understood() -> Result<(), Box<dyn std::error::Error>> {
+    const LINEAGE: &str = r#"{"must_understand":true,"source":"s3://bucket/raw.zarr"}"#;
+    let store = Arc::new(MemoryStore::new());
+    let array_key = StoreKey::new("array/zarr.json")?;
+    let mut array_metadata: serde_json::Value = serde_json::from_str(ARRAY_METADATA)?;
+    array_metadata["myorg:lineage"] = serde_json::from_str(LINEAGE)?;
+    store.set(&array_key, serde_json::to_vec(&array_metadata)?.into())?;
+    let group_key = StoreKey::new("group/zarr.json")?;
+    let mut group_metadata: serde_json::Value = serde_json::from_str(GROUP_METADATA)?;
+    group_metadata["myorg:lineage"] = serde_json::from_str(LINEAGE)?;
+    store.set(&group_key, serde_json::to_vec(&group_metadata)?.into())?;
+
+    // rejected unless understood
+    assert!(Array::open(store.clone(), "/array").is_err());
+    assert!(Group::open(store.clone(), "/group").is_err());
+
+    global_config_mut()
+        .understood_additional_fields_mut()
+        .insert("myorg:lineage".to_string());
+
+    let array = Array::open(store.clone(), "/array")?;
+    let lineage = &array.additional_fields()["myorg:lineage"];
+    assert!(lineage.must_understand());
+    assert_eq!(
+        lineage.as_map()["source"],
+        serde_json::Value::from("s3://bucket/raw.zarr")
+    );
+    let group = Group::open(store.clone(), "/group")?;
+    assert!(group.additional_fields()["myorg:lineage"].must_understand());
+
+    // understood fields are not ignored, even with strict extensions
+    let (_, warnings) =
+        Array::open_with_options(store.clone(), "/array", &NodeOpenOptions::default())?;
+    assert_eq!(warnings.len(), ADDITIONAL_FIELDS.len());
+    let mut array = array;
+    array
+        .additional_fields_mut()
+        .retain(|name, _| name == "myorg:lineage");
+    array.store_metadata()?;
+    Array::open_with_options(
+        store.clone(),
+        "/array",
+        &NodeOpenOptions::default().with_strict_extensions(true),
+    )?;
+
+    // preserved on re-store
+    array
+        .store_metadata_opt(&ArrayMetadataOptions::default().with_strict_additional_fields(true))?;
+    group
+        .store_metadata_opt(&GroupMetadataOptions::default().with_strict_additional_fields(true))?;
+    for key in [array_key, group_key] {
+        let stored: serde_json::Value = serde_json::from_slice(&store.get(&key)?.unwrap())?;
+        assert_eq!(serde_json::to_string(&stored["myorg:lineage"])?, LINEAGE);
+    }
+
+    Ok(())
+}
@@ -318,3 +318,336 @@ async fn array_str_async_sharded_transpose() -> Result<(), Box<dyn std::error::E
     let array = builder.build(store, array_path).unwrap();
     array_str_impl(array).await
 }
+
+#[tokio::test]
+async fn array_str_async_fill_value() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(InMemory::new()));
+    let array = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::String,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from("-"),
+    )
+    .build(store, "/array")?;
+
+    // A chunk entirely composed of the fill value is not stored
+    array
+        .async_store_chunk_elements(&[0, 0], &["-", "-", "-", "-"])
+        .await?;
+    assert!(!array.async_chunk_exists(&[0, 0]).await?);
+
+    // A chunk with the same bytes as the fill value, but different elements, is stored
+    array
+        .async_store_chunk_elements(&[0, 0], &["--", "", "-", "-"])
+        .await?;
+    assert!(array.async_chunk_exists(&[0, 0]).await?);
+
+    // A chunk is erased when an array subset update sets it entirely to the fill value
+    array
+        .async_store_array_subset_elements(
+            &ArraySubset::new_with_ranges(&[0..2, 0..2]),
+            &["-", "-", "-", "-"],
+        )
+        .await?;
+    assert!(!array.async_chunk_exists(&[0, 0]).await?);
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[tokio::test]
+async fn array_async_resize_append() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(InMemory::new()));
+    let mut array = ArrayBuilder::new(
+        vec![3, 0], // array shape
+        DataType::UInt8,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .build(store.clone(), "/array")?;
+    array.async_store_metadata().await?;
+
+    array.async_append_elements::<u8>(1, &[1, 2, 3]).await?;
+    array.async_append_ndarray::<u8, _>(1, ndarray::array![[4], [5], [6]]).await?;
+    assert_eq!(array.shape(), &[3, 2]);
+    assert_eq!(Array::async_open(store, "/array").await?.shape(), &[3, 2]);
+
+    array.async_resize(vec![2, 1], true).await?;
+    array.async_resize(vec![3, 3], false).await?;
+    assert_eq!(
+        array.async_retrieve_array_subset_ndarray::<u8>(&array.subset_all()).await?,
+        ndarray::array![
+            [1, 0, 0],
+            [2, 0, 0],
+            [0, 0, 0],
+        ].into_dyn()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn array_async_stored_chunks() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(InMemory::new()));
+    let array = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::UInt8,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .build(store, "/array")?;
+    array.async_store_metadata().await?;
+    array
+        .async_store_chunk_elements::<u8>(&[1, 0], &[1; 4])
+        .await?;
+    array
+        .async_store_chunk_elements::<u8>(&[0, 1], &[1; 4])
+        .await?;
+    assert_eq!(
+        array.async_stored_chunks().await?,
+        vec![vec![0, 1], vec![1, 0]]
+    );
+    assert!(array.async_chunk_exists(&[0, 1]).await?);
+    assert!(!array.async_chunk_exists(&[1, 1]).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn array_async_retrieve_array_subset_into() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(InMemory::new()));
+    let array = ArrayBuilder::new(
+        vec![8, 8], // array shape
+        DataType::UInt16,
+        vec![3, 3].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u16),
+    )
+    .build(store, "/array")?;
+    array
+        .async_store_array_subset_elements::<u16>(
+            &ArraySubset::new_with_ranges(&[0..7, 1..8]),
+            &(0..49).collect::<Vec<u16>>(),
+        )
+        .await?;
+
+    for subset in [
+        array.subset_all(),
+        ArraySubset::new_with_ranges(&[1..7, 2..5]),
+        ArraySubset::new_with_ranges(&[3..5, 4..6]),
+    ] {
+        let expected = array
+            .async_retrieve_array_subset_elements::<u16>(&subset)
+            .await?;
+        let mut elements = vec![u16::MAX; subset.num_elements_usize()];
+        array
+            .async_retrieve_array_subset_elements_into(&subset, &mut elements)
+            .await?;
+        assert_eq!(elements, expected);
+
+        let mut output = vec![u8::MAX; elements.len() * 2];
+        array
+            .async_retrieve_array_subset_into(&subset, &mut output)
+            .await?;
+        assert_eq!(output, bytemuck::cast_slice::<u16, u8>(&expected));
+    }
+
+    let mut output = vec![0u8; 3];
+    assert!(array
+        .async_retrieve_array_subset_into(&array.subset_all(), &mut output)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+/// An async store that records the maximum number of concurrent operations.
+struct ConcurrencyTrackingStore {
+    store: zarrs_object_store::AsyncObjectStore<InMemory>,
+    in_flight: std::sync::atomic::AtomicUsize,
+    max_in_flight: std::sync::atomic::AtomicUsize,
+}
+
+impl ConcurrencyTrackingStore {
+    fn new() -> Self {
+        Self {
+            store: zarrs_object_store::AsyncObjectStore::new(InMemory::new()),
+            in_flight: 0.into(),
+            max_in_flight: 0.into(),
+        }
+    }
+
+    fn take_max_in_flight(&self) -> usize {
+        self.max_in_flight
+            .swap(0, std::sync::atomic::Ordering::SeqCst)
+    }
+
+    async fn track<T>(&self, operation: impl std::future::Future<Output = T>) -> T {
+        use std::sync::atomic::Ordering::SeqCst;
+        let in_flight = self.in_flight.fetch_add(1, SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, SeqCst);
+        tokio::task::yield_now().await;
+        let result = operation.await;
+        self.in_flight.fetch_sub(1, SeqCst);
+        result
+    }
+}
+
+#[async_trait::async_trait]
+impl zarrs::storage::AsyncReadableStorageTraits for ConcurrencyTrackingStore {
+    async fn get_partial_values_key(
+        &self,
+        key: &zarrs::storage::StoreKey,
+        byte_ranges: &[zarrs::byte_range::ByteRange],
+    ) -> Result<Option<Vec<zarrs::storage::AsyncBytes>>, zarrs::storage::StorageError> {
+        self.track(self.store.get_partial_values_key(key, byte_ranges))
+            .await
+    }
+
+    async fn size_key(
+        &self,
+        key: &zarrs::storage::StoreKey,
+    ) -> Result<Option<u64>, zarrs::storage::StorageError> {
+        self.track(self.store.size_key(key)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl zarrs::storage::AsyncWritableStorageTraits for ConcurrencyTrackingStore {
+    async fn set(
+        &self,
+        key: &zarrs::storage::StoreKey,
+        value: zarrs::storage::AsyncBytes,
+    ) -> Result<(), zarrs::storage::StorageError> {
+        self.track(self.store.set(key, value)).await
+    }
+
+    async fn set_partial_values(
+        &self,
+        key_offset_values: &[zarrs::storage::StoreKeyOffsetValue],
+    ) -> Result<(), zarrs::storage::StorageError> {
+        self.track(self.store.set_partial_values(key_offset_values))
+            .await
+    }
+
+    async fn erase(
+        &self,
+        key: &zarrs::storage::StoreKey,
+    ) -> Result<(), zarrs::storage::StorageError> {
+        self.track(self.store.erase(key)).await
+    }
+
+    async fn erase_prefix(
+        &self,
+        prefix: &zarrs::storage::StorePrefix,
+    ) -> Result<(), zarrs::storage::StorageError> {
+        self.track(self.store.erase_prefix(prefix)).await
+    }
+}
+
+#[tokio::test]
+async fn array_async_chunk_concurrent_maximum() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(ConcurrencyTrackingStore::new());
+    let array = ArrayBuilder::new(
+        vec![16, 16], // array shape
+        DataType::UInt8,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .build(store.clone(), "/array")?;
+    let options = CodecOptions::builder().chunk_concurrent_maximum(3).build();
+
+    // Store and retrieve
+    array
+        .async_store_array_subset_elements_opt::<u8>(&array.subset_all(), &[1; 256], &options)
+        .await?;
+    assert!((1..=3).contains(&store.take_max_in_flight()));
+    let subset = ArraySubset::new_with_ranges(&[1..15, 1..15]);
+    let elements = array
+        .async_retrieve_array_subset_elements_opt::<u8>(&subset, &options)
+        .await?;
+    assert_eq!(elements, vec![1; 196]);
+    assert!((1..=3).contains(&store.take_max_in_flight()));
+
+    // Concurrency exceeds the limit if it is not set
+    let options_unbounded = CodecOptions::builder()
+        .chunk_concurrent_maximum(usize::MAX)
+        .build();
+    array
+        .async_retrieve_array_subset_elements_opt::<u8>(&subset, &options_unbounded)
+        .await?;
+    assert!(store.take_max_in_flight() > 3);
+
+    // Variable sized data types
+    let array = ArrayBuilder::new(
+        vec![8], // array shape
+        DataType::String,
+        vec![1].try_into().unwrap(), // regular chunk shape
+        FillValue::from(""),
+    )
+    .build(store.clone(), "/string")?;
+    array
+        .async_store_array_subset_elements_opt(
+            &array.subset_all(),
+            &["a", "b", "c", "d", "e", "f", "g", "h"],
+            &options,
+        )
+        .await?;
+    store.take_max_in_flight();
+    let elements = array
+        .async_retrieve_array_subset_elements_opt::<String>(&array.subset_all(), &options)
+        .await?;
+    assert_eq!(elements, ["a", "b", "c", "d", "e", "f", "g", "h"]);
+    assert!((1..=3).contains(&store.take_max_in_flight()));
+
+    Ok(())
+}
+
+#[cfg(feature = "sharding")]
+#[tokio::test]
+async fn array_async_range_coalesce() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::storage::storage_adapter::performance_metrics::{
+        PerformanceMetricsStorageAdapter, StorageRequestKind,
+    };
+
+    let store = std::sync::Arc::new(zarrs_object_store::AsyncObjectStore::new(InMemory::new()));
+    let store_perf = Arc::new(PerformanceMetricsStorageAdapter::new(store));
+    let array = ArrayBuilder::new(
+        vec![8, 8], // array shape
+        DataType::UInt16,
+        vec![8, 8].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u16),
+    )
+    .array_to_bytes_codec(Arc::new(
+        zarrs::array::codec::array_to_bytes::sharding::ShardingCodecBuilder::new(
+            vec![2, 2].try_into().unwrap(),
+        )
+        .build(),
+    ))
+    .build(store_perf.clone(), "/array")?;
+    array
+        .async_store_array_subset_elements::<u16>(
+            &ArraySubset::new_with_ranges(&[0..4, 0..8]),
+            &(0..32).collect::<Vec<u16>>(),
+        )
+        .await?;
+
+    let subset = ArraySubset::new_with_ranges(&[1..7, 1..7]);
+    store_perf.reset();
+    let elements = array
+        .async_retrieve_array_subset_elements::<u16>(&subset)
+        .await?;
+    let requests = store_perf.requests_by_kind()[&StorageRequestKind::GetPartialValuesKey];
+
+    let options = CodecOptions::builder().range_coalesce_gap(Some(0)).build();
+    store_perf.reset();
+    let elements_coalesced = array
+        .async_retrieve_array_subset_elements_opt::<u16>(&subset, &options)
+        .await?;
+    let requests_coalesced =
+        store_perf.requests_by_kind()[&StorageRequestKind::GetPartialValuesKey];
+    assert_eq!(elements, elements_coalesced);
+    assert_eq!(requests, 1 + 8);
+    assert_eq!(requests_coalesced, 1 + 1);
+
+    Ok(())
+}
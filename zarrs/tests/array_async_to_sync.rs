@@ -2,26 +2,26 @@
 #![cfg(all(feature = "async"))]
 
 use serde_json::json;
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use zarrs::storage::{
-    storage_adapter::async_to_sync::{AsyncToSyncBlockOn, AsyncToSyncStorageAdapter},
-    ReadableWritableStorage,
+    byte_range::ByteRange,
+    storage_adapter::async_to_sync::{
+        AsyncToSyncStorageAdapter, AsyncToSyncStorageAdapterOptions, TokioBlockOn,
+        TokioBlockOnStrategy,
+    },
+    AsyncBytes, AsyncReadableStorageTraits, ReadableStorageTraits, ReadableWritableStorage,
+    StorageError, StoreKey,
 };
 use zarrs::{
-    array::{DataType, FillValue, ZARR_NAN_F32},
+    array::{Array, DataType, FillValue, ZARR_NAN_F32},
     array_subset::ArraySubset,
 };
 
-pub struct TokioBlockOn(pub tokio::runtime::Runtime);
-
-impl AsyncToSyncBlockOn for TokioBlockOn {
-    fn block_on<F: core::future::Future>(&self, future: F) -> F::Output {
-        self.0.block_on(future)
-    }
-}
-
 fn readable_writable_store() -> ReadableWritableStorage {
-    let block_on = TokioBlockOn(tokio::runtime::Runtime::new().unwrap());
+    let block_on = TokioBlockOn::new().unwrap();
     let store = object_store::memory::InMemory::new();
     let async_store = Arc::new(zarrs_object_store::AsyncObjectStore::new(store));
     Arc::new(AsyncToSyncStorageAdapter::new(async_store, block_on))
@@ -72,3 +72,76 @@ fn array_read_and_write_async_storage_adapter() {
     let data = array.retrieve_array_subset_ndarray::<f32>(&subset).unwrap();
     assert_eq!(data, ndarray::array![[2.2, 2.3], [3.2, 3.3]].into_dyn());
 }
+
+fn array_with_chunk(
+    block_on: TokioBlockOn,
+) -> Array<dyn zarrs::storage::ReadableWritableStorageTraits> {
+    let store = object_store::memory::InMemory::new();
+    let async_store = Arc::new(zarrs_object_store::AsyncObjectStore::new(store));
+    let store: ReadableWritableStorage =
+        Arc::new(AsyncToSyncStorageAdapter::new(async_store, block_on));
+    let array = zarrs::array::ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt8,
+        vec![2, 2].try_into().unwrap(),
+        FillValue::from(0u8),
+    )
+    .build(store, "/array")
+    .unwrap();
+    array.store_metadata().unwrap();
+    array
+        .store_chunk_elements::<u8>(&[1, 1], &[1, 2, 3, 4])
+        .unwrap();
+    array
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[cfg_attr(miri, ignore)]
+async fn array_async_storage_adapter_within_runtime() {
+    for strategy in [
+        TokioBlockOnStrategy::Auto,
+        TokioBlockOnStrategy::DedicatedRuntime,
+        TokioBlockOnStrategy::BlockInPlace,
+    ] {
+        let array = array_with_chunk(TokioBlockOn::with_strategy(strategy).unwrap());
+        tokio::spawn(async move {
+            let bytes = array.retrieve_chunk(&[1, 1]).unwrap();
+            assert_eq!(bytes.into_fixed().unwrap().as_ref(), &[1, 2, 3, 4]);
+        })
+        .await
+        .unwrap();
+    }
+}
+
+/// A store that never responds.
+struct PendingStore;
+
+#[async_trait::async_trait]
+impl AsyncReadableStorageTraits for PendingStore {
+    async fn get_partial_values_key(
+        &self,
+        _key: &StoreKey,
+        _byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        futures::future::pending().await
+    }
+
+    async fn size_key(&self, _key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        futures::future::pending().await
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn async_storage_adapter_timeout() {
+    let mut options = AsyncToSyncStorageAdapterOptions::default();
+    options.timeout(Some(Duration::from_millis(100)));
+    let store =
+        AsyncToSyncStorageAdapter::new(Arc::new(PendingStore), TokioBlockOn::new().unwrap())
+            .with_options(options);
+
+    let start = Instant::now();
+    let result = store.get(&StoreKey::new("zarr.json").unwrap());
+    assert!(matches!(result, Err(StorageError::Other(err)) if err == "timeout"));
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
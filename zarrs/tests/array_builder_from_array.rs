@@ -0,0 +1,70 @@
+#![allow(missing_docs)]
+#![cfg(all(feature = "gzip", feature = "crc32c"))]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        codec::{Crc32cCodec, GzipCodec},
+        ArrayBuilder, DataType, FillValue,
+    },
+    group::GroupBuilder,
+    storage::store::MemoryStore,
+};
+
+#[test]
+fn array_builder_from_array() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let mut attributes = serde_json::Map::new();
+    attributes.insert("key".to_string(), "value".into());
+    let array = ArrayBuilder::new(
+        vec![8, 6],
+        DataType::Float32,
+        vec![4, 3].try_into()?,
+        FillValue::from(f32::NAN),
+    )
+    .dimension_names(["y", "x"].into())
+    .attributes(attributes.clone())
+    .bytes_to_bytes_codecs(vec![Arc::new(GzipCodec::new(5)?)])
+    .build_arc(store.clone(), "/source")?;
+
+    let mut builder = array.builder();
+    builder.bytes_to_bytes_codecs(vec![Arc::new(Crc32cCodec::new())]);
+    builder
+        .attributes
+        .insert("other".to_string(), "value".into());
+    let derived = builder.build(store, "/derived")?;
+
+    // Attributes are deep copied
+    assert_eq!(array.attributes(), &attributes);
+    assert_eq!(derived.attributes().len(), 2);
+
+    // Everything other than the codecs and attributes matches the source array
+    let mut source_metadata = serde_json::to_value(array.metadata())?;
+    let mut derived_metadata = serde_json::to_value(derived.metadata())?;
+    for metadata in [&mut source_metadata, &mut derived_metadata] {
+        let metadata = metadata.as_object_mut().unwrap();
+        metadata.remove("codecs");
+        metadata.remove("attributes");
+    }
+    assert_eq!(source_metadata, derived_metadata);
+    assert_eq!(
+        derived.codecs().array_to_bytes_codec().create_metadata(),
+        array.codecs().array_to_bytes_codec().create_metadata()
+    );
+    assert_eq!(
+        derived.codecs().bytes_to_bytes_codecs()[0]
+            .create_metadata()
+            .map(|metadata| metadata.name().to_string()),
+        Some("crc32c".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+fn group_builder_build_arc() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let group = GroupBuilder::new().build_arc(store, "/group")?;
+    assert_eq!(group.path().as_str(), "/group");
+    Ok(())
+}
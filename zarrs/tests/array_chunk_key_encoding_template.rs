@@ -0,0 +1,64 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        chunk_key_encoding::TemplateChunkKeyEncoding, Array, ArrayBuilder, DataType, FillValue,
+    },
+    storage::{store::MemoryStore, ListableStorageTraits, ReadableStorageTraits, StoreKey},
+};
+
+#[test]
+fn array_chunk_key_encoding_template() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 6],
+        DataType::UInt8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u8),
+    )
+    .chunk_key_encoding(TemplateChunkKeyEncoding::new("chunks/{dim1}_{dim0}.bin", Some(2))?.into())
+    .build(store.clone(), "/array")?;
+    array.store_metadata()?;
+    array.store_chunk_elements::<u8>(&[0, 0], &[1, 2, 3, 4])?;
+    array.store_chunk_elements::<u8>(&[1, 2], &[5, 6, 7, 8])?;
+
+    assert_eq!(
+        store.list()?,
+        [
+            StoreKey::new("array/chunks/00_00.bin")?,
+            StoreKey::new("array/chunks/02_01.bin")?,
+            StoreKey::new("array/zarr.json")?,
+        ]
+    );
+
+    // The chunk key encoding round trips through the array metadata
+    let metadata: serde_json::Value =
+        serde_json::from_slice(&store.get(&StoreKey::new("array/zarr.json")?)?.unwrap())?;
+    assert_eq!(
+        metadata["chunk_key_encoding"],
+        serde_json::json!({
+            "name": "zarrs.template",
+            "configuration": {
+                "template": "chunks/{dim1}_{dim0}.bin",
+                "zero_padding": 2
+            }
+        })
+    );
+    let array = Array::open(store.clone(), "/array")?;
+    assert_eq!(
+        array.chunk_key(&[1, 2]),
+        StoreKey::new("array/chunks/02_01.bin")?
+    );
+    assert_eq!(
+        array
+            .chunk_key_encoding()
+            .decode(&StoreKey::new("chunks/02_01.bin")?),
+        Some(vec![1, 2])
+    );
+    assert_eq!(array.retrieve_chunk_elements::<u8>(&[1, 2])?, [5, 6, 7, 8]);
+    assert_eq!(array.retrieve_chunk_elements::<u8>(&[0, 1])?, [0, 0, 0, 0]);
+
+    Ok(())
+}
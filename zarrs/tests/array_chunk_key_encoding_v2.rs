@@ -0,0 +1,174 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, ArrayBuilder, DataType, FillValue},
+    metadata::{ChunkKeySeparator, ZarrVersion},
+    storage::{store::MemoryStore, ListableStorageTraits, ReadableStorageTraits, StoreKey},
+};
+
+fn store_keys(store: &MemoryStore) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    Ok(store
+        .list()?
+        .iter()
+        .map(|key| key.as_str().to_string())
+        .collect())
+}
+
+/// Store the chunks `chunks` of an array with the `v2` chunk key encoding and `separator`, and return the keys in the store.
+fn array_chunk_key_encoding_v2_keys(
+    separator: ChunkKeySeparator,
+    shape: Vec<u64>,
+    chunks: &[Vec<u64>],
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let chunk_shape = vec![2; shape.len()];
+    let num_elements = 1 << shape.len();
+    let array = ArrayBuilder::new(
+        shape,
+        DataType::UInt8,
+        chunk_shape.try_into()?,
+        FillValue::from(0u8),
+    )
+    .chunk_key_encoding_v2_separator(separator)
+    .build(store.clone(), "/array")?;
+    array.store_metadata()?;
+    for (i, chunk_indices) in chunks.iter().enumerate() {
+        let elements = vec![u8::try_from(i)? + 1; num_elements];
+        array.store_chunk_elements::<u8>(chunk_indices, &elements)?;
+    }
+
+    let array = Array::open(store.clone(), "/array")?;
+    for (i, chunk_indices) in chunks.iter().enumerate() {
+        assert_eq!(
+            array.retrieve_chunk_elements::<u8>(chunk_indices)?,
+            vec![u8::try_from(i)? + 1; num_elements]
+        );
+    }
+    let mut chunks_sorted = chunks.to_vec();
+    chunks_sorted.sort();
+    assert_eq!(array.stored_chunks()?, chunks_sorted);
+
+    store_keys(&store)
+}
+
+#[test]
+fn array_chunk_key_encoding_v2_0d() -> Result<(), Box<dyn std::error::Error>> {
+    for separator in [ChunkKeySeparator::Dot, ChunkKeySeparator::Slash] {
+        assert_eq!(
+            array_chunk_key_encoding_v2_keys(separator, vec![], &[vec![]])?,
+            ["array/0", "array/zarr.json"]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn array_chunk_key_encoding_v2_1d() -> Result<(), Box<dyn std::error::Error>> {
+    for separator in [ChunkKeySeparator::Dot, ChunkKeySeparator::Slash] {
+        assert_eq!(
+            array_chunk_key_encoding_v2_keys(separator, vec![24], &[vec![0], vec![10], vec![1]])?,
+            ["array/0", "array/1", "array/10", "array/zarr.json"]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn array_chunk_key_encoding_v2_3d() -> Result<(), Box<dyn std::error::Error>> {
+    let chunks = [vec![0, 0, 0], vec![1, 0, 2], vec![3, 11, 0]];
+    assert_eq!(
+        array_chunk_key_encoding_v2_keys(ChunkKeySeparator::Dot, vec![8, 24, 8], &chunks)?,
+        [
+            "array/0.0.0",
+            "array/1.0.2",
+            "array/3.11.0",
+            "array/zarr.json"
+        ]
+    );
+    assert_eq!(
+        array_chunk_key_encoding_v2_keys(ChunkKeySeparator::Slash, vec![8, 24, 8], &chunks)?,
+        [
+            "array/0/0/0",
+            "array/1/0/2",
+            "array/3/11/0",
+            "array/zarr.json"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn array_chunk_key_encoding_v2_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u8),
+    )
+    .chunk_key_encoding_v2_separator(ChunkKeySeparator::Slash)
+    .build(store.clone(), "/")?;
+    array.store_metadata()?;
+    let metadata: serde_json::Value =
+        serde_json::from_slice(&store.get(&StoreKey::new("zarr.json")?)?.unwrap())?;
+    assert_eq!(
+        metadata["chunk_key_encoding"],
+        serde_json::json!({"name": "v2", "configuration": {"separator": "/"}})
+    );
+
+    // Zarr V2 arrays default to the `.` separator, which can be overridden
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u8),
+    )
+    .zarr_format(ZarrVersion::V2)
+    .chunk_key_encoding_v2_separator(ChunkKeySeparator::Slash)
+    .build(store.clone(), "/")?;
+    array.store_metadata()?;
+    array.store_chunk_elements::<u8>(&[1, 0], &[1; 4])?;
+    let zarray: serde_json::Value =
+        serde_json::from_slice(&store.get(&StoreKey::new(".zarray")?)?.unwrap())?;
+    assert_eq!(zarray["dimension_separator"], "/");
+    assert_eq!(store_keys(&store)?, [".zarray", ".zattrs", "1/0"]);
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_chunk_key_encoding_v2_async() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::storage::AsyncListableStorageTraits;
+
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u8),
+    )
+    .chunk_key_encoding_v2_separator(ChunkKeySeparator::Dot)
+    .build(store.clone(), "/array")?;
+    array.async_store_metadata().await?;
+    array
+        .async_store_chunk_elements::<u8>(&[1, 0], &[1; 4])
+        .await?;
+    assert_eq!(
+        store.list().await?,
+        [
+            StoreKey::new("array/1.0")?,
+            StoreKey::new("array/zarr.json")?
+        ]
+    );
+    assert_eq!(array.async_stored_chunks().await?, [vec![1, 0]]);
+    assert_eq!(
+        array.async_retrieve_chunk_elements::<u8>(&[1, 0]).await?,
+        [1; 4]
+    );
+    Ok(())
+}
@@ -0,0 +1,83 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{codec::CodecOptions, Array, ArrayBuilder, ChunkOccupancy, DataType, FillValue},
+    storage::store::MemoryStore,
+};
+
+fn array_with_chunks(
+    store: Arc<MemoryStore>,
+) -> Result<Array<MemoryStore>, Box<dyn std::error::Error>> {
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store, "/")?;
+    let options = CodecOptions::builder().store_empty_chunks(true).build();
+    // [0, 0] is missing
+    array.store_chunk_elements_opt::<u16>(&[0, 1], &[0, 0, 0, 0], &options)?;
+    array.store_chunk_elements::<u16>(&[1, 0], &[0, 5, 0, 7])?;
+    array.store_chunk_elements::<u16>(&[1, 1], &[1, 2, 3, 4])?;
+    Ok(array)
+}
+
+#[test]
+fn array_chunk_occupancy() -> Result<(), Box<dyn std::error::Error>> {
+    let array = array_with_chunks(Arc::new(MemoryStore::default()))?;
+    assert_eq!(array.chunk_occupancy(&[0, 0])?, ChunkOccupancy::Missing);
+    assert_eq!(
+        array.chunk_occupancy(&[0, 1])?,
+        ChunkOccupancy::AllFillValue
+    );
+    assert_eq!(
+        array.chunk_occupancy(&[1, 0])?,
+        ChunkOccupancy::NonFill { count: 2 }
+    );
+    assert_eq!(
+        array.chunk_occupancy(&[1, 1])?,
+        ChunkOccupancy::NonFill { count: 4 }
+    );
+    assert!(array.retrieve_chunk_if_exists(&[0, 0])?.is_none());
+    assert!(array.chunk_occupancy(&[0]).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_chunk_occupancy_async() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store, "/")?;
+    let options = CodecOptions::builder().store_empty_chunks(true).build();
+    array
+        .async_store_chunk_elements_opt::<u16>(&[0, 1], &[0, 0, 0, 0], &options)
+        .await?;
+    array
+        .async_store_chunk_elements::<u16>(&[1, 0], &[0, 5, 0, 7])
+        .await?;
+
+    assert_eq!(
+        array.async_chunk_occupancy(&[0, 0]).await?,
+        ChunkOccupancy::Missing
+    );
+    assert_eq!(
+        array.async_chunk_occupancy(&[0, 1]).await?,
+        ChunkOccupancy::AllFillValue
+    );
+    assert_eq!(
+        array.async_chunk_occupancy(&[1, 0]).await?,
+        ChunkOccupancy::NonFill { count: 2 }
+    );
+    Ok(())
+}
@@ -0,0 +1,147 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{codec::CodecOptions, Array, ArrayBuilder, ArrayDecodedChunk, DataType, FillValue},
+    array_subset::ArraySubset,
+    storage::{store::MemoryStore, WritableStorageTraits},
+};
+
+fn array_with_chunks<TStorage: ?Sized>(
+    store: Arc<TStorage>,
+) -> Result<Array<TStorage>, Box<dyn std::error::Error>> {
+    // 3x3 chunks, with partial chunks at the edges
+    Ok(ArrayBuilder::new(
+        vec![5, 7],
+        DataType::UInt16,
+        vec![2, 3].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store, "/array")?)
+}
+
+/// Reconstruct `array_subset` from the decoded `chunks`, checking they are in lexicographical order.
+fn reconstruct(
+    array_subset: &ArraySubset,
+    chunks: Vec<ArrayDecodedChunk>,
+) -> Result<Vec<u16>, Box<dyn std::error::Error>> {
+    let mut chunks_indices: Vec<_> = chunks.iter().map(|(indices, _, _)| indices).collect();
+    assert!(chunks_indices.is_sorted());
+    chunks_indices.dedup();
+    assert_eq!(chunks_indices.len(), chunks.len());
+
+    let mut elements = vec![u16::MAX; array_subset.num_elements_usize()];
+    for (_chunk_indices, chunk_subset, chunk_bytes) in chunks {
+        let chunk_elements: Vec<u16> = bytemuck::pod_collect_to_vec(&chunk_bytes.into_fixed()?);
+        let overlap = chunk_subset.overlap(array_subset)?;
+        let overlap_in_chunk = overlap.relative_to(chunk_subset.start())?;
+        let overlap_in_subset = overlap.relative_to(array_subset.start())?;
+        for (index_chunk, index_subset) in std::iter::zip(
+            &overlap_in_chunk.linearised_indices(chunk_subset.shape())?,
+            &overlap_in_subset.linearised_indices(array_subset.shape())?,
+        ) {
+            elements[usize::try_from(index_subset)?] =
+                chunk_elements[usize::try_from(index_chunk)?];
+        }
+    }
+    Ok(elements)
+}
+
+#[test]
+fn array_chunks_iter() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = array_with_chunks(store)?;
+    let elements: Vec<u16> = (0..35).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+    array.erase_chunk(&[1, 1])?; // a missing chunk is decoded as the fill value
+
+    let subsets = [
+        array.subset_all(),
+        ArraySubset::new_with_ranges(&[1..4, 2..6]),
+        ArraySubset::new_with_ranges(&[4..5, 6..7]),
+    ];
+    for array_subset in &subsets {
+        let expected = array.retrieve_array_subset_elements::<u16>(array_subset)?;
+        for prefetch in [0, 1, 4, 64] {
+            let chunks = array
+                .chunks_iter_opt(Some(array_subset), prefetch, &CodecOptions::default())?
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(reconstruct(array_subset, chunks)?, expected);
+        }
+    }
+
+    let chunks = array.chunks_iter(None)?.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(chunks.len(), 9);
+    assert_eq!(chunks[4].0, [1, 1]);
+    assert_eq!(chunks[4].1, ArraySubset::new_with_ranges(&[2..4, 3..6]));
+    assert_eq!(chunks[8].1, ArraySubset::new_with_ranges(&[4..6, 6..9]));
+    assert_eq!(
+        reconstruct(&array.subset_all(), chunks)?,
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?
+    );
+
+    // Items are only decoded on demand
+    let mut chunks = array.chunks_iter_opt(None, 2, &CodecOptions::default())?;
+    assert_eq!(chunks.next().unwrap()?.0, [0, 0]);
+    assert_eq!(chunks.next().unwrap()?.0, [0, 1]);
+    assert_eq!(chunks.next().unwrap()?.0, [0, 2]);
+
+    assert!(array
+        .chunks_iter(Some(&ArraySubset::new_with_shape(vec![1])))
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn array_chunks_iter_error() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = array_with_chunks(store.clone())?;
+    array.store_chunk_elements::<u16>(&[0, 0], &[1, 2, 3, 4, 5, 6])?;
+    store.set(&array.chunk_key(&[0, 1]), vec![0, 1, 2].into())?; // corrupt
+
+    let results: Vec<_> = array
+        .chunks_iter_opt(None, 4, &CodecOptions::default())?
+        .collect();
+    assert_eq!(results.len(), 9);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_chunks_stream_async() -> Result<(), Box<dyn std::error::Error>> {
+    use futures::TryStreamExt;
+
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = array_with_chunks(store)?;
+    let elements: Vec<u16> = (0..35).collect();
+    array
+        .async_store_array_subset_elements(&array.subset_all(), &elements)
+        .await?;
+
+    let array_subset = ArraySubset::new_with_ranges(&[1..4, 2..6]);
+    let expected = array
+        .async_retrieve_array_subset_elements::<u16>(&array_subset)
+        .await?;
+    for concurrent_limit in [0, 1, 4] {
+        let chunks: Vec<_> = array
+            .async_chunks_stream_opt(
+                Some(&array_subset),
+                concurrent_limit,
+                &CodecOptions::default(),
+            )?
+            .try_collect()
+            .await?;
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(reconstruct(&array_subset, chunks)?, expected);
+    }
+
+    let chunks: Vec<_> = array.async_chunks_stream(None)?.try_collect().await?;
+    assert_eq!(reconstruct(&array.subset_all(), chunks)?, elements);
+    Ok(())
+}
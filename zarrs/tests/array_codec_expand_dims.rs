@@ -0,0 +1,123 @@
+#![allow(missing_docs)]
+#![cfg(feature = "expand_dims")]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{codec::ExpandDimsCodec, Array, ArrayBuilder, DataType, FillValue},
+    array_subset::ArraySubset,
+    storage::store::MemoryStore,
+};
+
+#[test]
+fn array_codec_expand_dims() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![6, 8],
+        DataType::UInt16,
+        vec![3, 4].try_into()?,
+        FillValue::from(0u16),
+    )
+    .array_to_array_codecs(vec![Arc::new(ExpandDimsCodec::new(vec![0, 3])?)])
+    .build(store.clone(), "/")?;
+    array.store_metadata()?;
+
+    let elements: Vec<u16> = (0..48).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+
+    // The elements are passed through unchanged
+    assert_eq!(
+        array.retrieve_encoded_chunk(&[0, 1])?.unwrap(),
+        bytemuck::cast_slice::<u16, u8>(&[4, 5, 6, 7, 12, 13, 14, 15, 20, 21, 22, 23])
+    );
+
+    // The codec is recreated from the stored metadata
+    let array = Array::open(store, "/")?;
+    let codecs = serde_json::to_value(array.metadata())?["codecs"].clone();
+    assert_eq!(
+        codecs[0],
+        serde_json::json!({
+            "name": "https://codec.zarrs.dev/array_to_array/expand_dims",
+            "configuration": {"axes": [0, 3]}
+        })
+    );
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?,
+        elements
+    );
+    assert_eq!(
+        array
+            .retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[2..4, 3..5]))?,
+        [19, 20, 27, 28]
+    );
+    Ok(())
+}
+
+#[test]
+fn array_codec_expand_dims_invalid() {
+    assert!(ExpandDimsCodec::new(vec![1, 1]).is_err());
+
+    // An axis beyond the encoded dimensionality
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![4],
+        DataType::UInt8,
+        vec![2].try_into().unwrap(),
+        FillValue::from(0u8),
+    )
+    .array_to_array_codecs(vec![Arc::new(ExpandDimsCodec::new(vec![2]).unwrap())])
+    .build(store, "/");
+    assert!(array.is_err());
+}
+
+#[cfg(all(feature = "transpose", feature = "sharding"))]
+#[test]
+fn array_codec_expand_dims_transpose_sharding() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::array::codec::{
+        array_to_array::transpose::TransposeOrder, array_to_bytes::sharding::ShardingCodecBuilder,
+        TransposeCodec,
+    };
+
+    // [4, 6] chunks are encoded as [6, 1, 4] shards of [3, 1, 2] inner chunks
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![8, 12],
+        DataType::UInt16,
+        vec![4, 6].try_into()?,
+        FillValue::from(0u16),
+    )
+    .array_to_array_codecs(vec![
+        Arc::new(ExpandDimsCodec::new(vec![0])?),
+        Arc::new(TransposeCodec::new(TransposeOrder::new(&[2, 0, 1])?)),
+    ])
+    .array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![3, 1, 2].try_into()?)
+            .array_to_array_codecs(vec![Arc::new(ExpandDimsCodec::new(vec![3])?)])
+            .build(),
+    ))
+    .build(store.clone(), "/")?;
+    array.store_metadata()?;
+
+    let elements: Vec<u16> = (0..96).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+
+    let array = Array::open(store, "/")?;
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?,
+        elements
+    );
+    // Partial decoding through the sharding codec
+    assert_eq!(
+        array.retrieve_chunk_subset_elements::<u16>(
+            &[1, 1],
+            &ArraySubset::new_with_ranges(&[1..3, 2..4])
+        )?,
+        [68, 69, 80, 81]
+    );
+    assert_eq!(
+        array
+            .retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[3..5, 5..7]))?,
+        [41, 42, 53, 54]
+    );
+    Ok(())
+}
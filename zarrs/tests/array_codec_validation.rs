@@ -0,0 +1,226 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        codec::{array_to_bytes::vlen::VlenCodec, BytesCodec},
+        Array, ArrayBuilder, ArrayCreateError, ArrayMetadata, DataType, Endianness, FillValue,
+    },
+    storage::store::MemoryStore,
+};
+
+fn builder(data_type: DataType, fill_value: FillValue) -> ArrayBuilder {
+    ArrayBuilder::new(
+        vec![8, 8],
+        data_type,
+        vec![4, 4].try_into().unwrap(),
+        fill_value,
+    )
+}
+
+/// Build an array and return the `(codec, data type)` of every incompatibility reported.
+fn incompatibilities(builder: &ArrayBuilder) -> Vec<(String, DataType)> {
+    let store = Arc::new(MemoryStore::new());
+    match builder.build(store, "/") {
+        Err(ArrayCreateError::CodecIncompatibleWithDataType(incompatibilities)) => {
+            incompatibilities
+                .iter()
+                .map(|incompatibility| {
+                    assert!(!incompatibility.reason().is_empty());
+                    (
+                        incompatibility.codec().to_string(),
+                        incompatibility.data_type().clone(),
+                    )
+                })
+                .collect()
+        }
+        Err(err) => panic!("unexpected error: {err}"),
+        Ok(_) => vec![],
+    }
+}
+
+#[test]
+fn array_codec_validation_bytes_string() {
+    let mut builder = builder(DataType::String, FillValue::from(""));
+    builder.array_to_bytes_codec(Arc::new(BytesCodec::default()));
+    assert_eq!(
+        incompatibilities(&builder),
+        [("bytes".to_string(), DataType::String)]
+    );
+}
+
+#[test]
+fn array_codec_validation_vlen_float() {
+    let mut builder = builder(DataType::Float32, FillValue::from(0.0f32));
+    builder.array_to_bytes_codec(Arc::new(VlenCodec::default()));
+    assert_eq!(
+        incompatibilities(&builder),
+        [("vlen".to_string(), DataType::Float32)]
+    );
+}
+
+#[test]
+fn array_codec_validation_bytes_sub_byte() {
+    let mut builder = builder(DataType::Float4E2M1FN, FillValue::from(0u8));
+    builder.array_to_bytes_codec(Arc::new(BytesCodec::default()));
+    assert_eq!(
+        incompatibilities(&builder),
+        [("bytes".to_string(), DataType::Float4E2M1FN)]
+    );
+}
+
+#[cfg(feature = "bitround")]
+#[test]
+fn array_codec_validation_multiple() {
+    use zarrs::array::codec::BitroundCodec;
+
+    // Every incompatibility is reported, not just the first
+    let mut builder = builder(DataType::String, FillValue::from(""));
+    builder
+        .array_to_array_codecs(vec![Arc::new(BitroundCodec::new(4))])
+        .array_to_bytes_codec(Arc::new(BytesCodec::default()));
+    assert_eq!(
+        incompatibilities(&builder),
+        [
+            ("bitround".to_string(), DataType::String),
+            ("bytes".to_string(), DataType::String)
+        ]
+    );
+}
+
+#[cfg(feature = "sharding")]
+#[test]
+fn array_codec_validation_sharding() {
+    use zarrs::array::codec::array_to_bytes::sharding::ShardingCodecBuilder;
+
+    // Valid sharded chains
+    let mut builder_uint16 = builder(DataType::UInt16, FillValue::from(0u16));
+    builder_uint16.array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![2, 2].try_into().unwrap()).build(),
+    ));
+    assert!(incompatibilities(&builder_uint16).is_empty());
+
+    let mut builder_string = builder(DataType::String, FillValue::from(""));
+    builder_string.array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![2, 2].try_into().unwrap())
+            .array_to_bytes_codec(Arc::new(VlenCodec::default()))
+            .build(),
+    ));
+    assert!(incompatibilities(&builder_string).is_empty());
+
+    // The inner chain is validated recursively
+    builder_string.array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![2, 2].try_into().unwrap()).build(),
+    ));
+    assert_eq!(
+        incompatibilities(&builder_string),
+        [("bytes".to_string(), DataType::String)]
+    );
+
+    // The index codecs are validated with the uint64 index data type
+    builder_uint16.array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![2, 2].try_into().unwrap())
+            .index_array_to_bytes_codec(Arc::new(VlenCodec::default()))
+            .build(),
+    ));
+    assert_eq!(
+        incompatibilities(&builder_uint16),
+        [("vlen".to_string(), DataType::UInt64)]
+    );
+}
+
+#[test]
+fn array_codec_validation_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let metadata: ArrayMetadata = serde_json::from_str(
+        r#"{
+            "zarr_format": 3,
+            "node_type": "array",
+            "shape": [4],
+            "data_type": "string",
+            "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [2]}},
+            "chunk_key_encoding": {"name": "default"},
+            "fill_value": "",
+            "codecs": [{"name": "bytes"}]
+        }"#,
+    )?;
+    let err = Array::new_with_metadata(store, "/", metadata).unwrap_err();
+    assert!(matches!(
+        err,
+        ArrayCreateError::CodecIncompatibleWithDataType(_)
+    ));
+    assert_eq!(
+        err.to_string(),
+        "the codecs are incompatible with the data type: codec bytes is incompatible with data type string: variable-sized data types must be encoded with the vlen or vlen-utf8 codec"
+    );
+    Ok(())
+}
+
+#[test]
+fn array_codec_validation_bytes_endian() -> Result<(), Box<dyn std::error::Error>> {
+    let endian = |builder: &ArrayBuilder| -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let array = builder.build(Arc::new(MemoryStore::new()), "/")?;
+        let metadata = serde_json::to_value(array.metadata())?;
+        Ok(metadata["codecs"][0]["configuration"]["endian"].clone())
+    };
+
+    // The default is explicitly little endian
+    let mut builder = builder(DataType::UInt16, FillValue::from(0u16));
+    assert_eq!(endian(&builder)?, "little");
+    builder.bytes_endianness(Endianness::Big);
+    assert_eq!(endian(&builder)?, "big");
+    builder.bytes_endianness(Endianness::Little);
+    assert_eq!(endian(&builder)?, "little");
+    builder.bytes_endianness(Endianness::native());
+    assert_eq!(
+        endian(&builder)?,
+        if cfg!(target_endian = "big") {
+            "big"
+        } else {
+            "little"
+        }
+    );
+
+    // A missing endian is only valid for single-byte data types
+    builder.array_to_bytes_codec(Arc::new(BytesCodec::new(None)));
+    assert_eq!(
+        incompatibilities(&builder),
+        [("bytes".to_string(), DataType::UInt16)]
+    );
+    let mut builder = self::builder(DataType::UInt8, FillValue::from(0u8));
+    builder.array_to_bytes_codec(Arc::new(BytesCodec::new(None)));
+    assert!(incompatibilities(&builder).is_empty());
+    Ok(())
+}
+
+#[test]
+fn array_codec_validation_bytes_endian_open() -> Result<(), Box<dyn std::error::Error>> {
+    let metadata = |data_type: &str| -> ArrayMetadata {
+        serde_json::from_str(&format!(
+            r#"{{
+                "zarr_format": 3,
+                "node_type": "array",
+                "shape": [4],
+                "data_type": "{data_type}",
+                "chunk_grid": {{"name": "regular", "configuration": {{"chunk_shape": [2]}}}},
+                "chunk_key_encoding": {{"name": "default"}},
+                "fill_value": 0,
+                "codecs": [{{"name": "bytes"}}]
+            }}"#
+        ))
+        .unwrap()
+    };
+
+    let store = Arc::new(MemoryStore::new());
+    Array::new_with_metadata(store.clone(), "/", metadata("uint8"))?;
+    let err = Array::new_with_metadata(store, "/", metadata("uint16")).unwrap_err();
+    assert!(matches!(
+        err,
+        ArrayCreateError::CodecIncompatibleWithDataType(_)
+    ));
+    assert!(err
+        .to_string()
+        .contains("the endian configuration is required for multi-byte data types"));
+    Ok(())
+}
@@ -0,0 +1,131 @@
+#![allow(missing_docs)]
+#![cfg(all(feature = "sharding", feature = "gzip", feature = "zstd"))]
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use zarrs::{
+    array::{
+        codec::{array_to_bytes::sharding::ShardingCodecBuilder, GzipCodec, ZstdCodec},
+        copy_array, ArrayBuilder, ArrayCopyError, ArrayCopyOptions, DataType, FillValue,
+    },
+    array_subset::ArraySubset,
+};
+use zarrs_storage::store::MemoryStore;
+
+#[test]
+fn array_copy_gzip_to_sharded_zstd() -> Result<(), Box<dyn std::error::Error>> {
+    let src_store = Arc::new(MemoryStore::new());
+    let src = ArrayBuilder::new(
+        vec![13, 9],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    )
+    .bytes_to_bytes_codecs(vec![Arc::new(GzipCodec::new(5)?)])
+    .dimension_names(Some(["y", "x"]))
+    .attributes(
+        serde_json::json!({"key": "value"})
+            .as_object()
+            .unwrap()
+            .clone(),
+    )
+    .build(src_store.clone(), "/src")?;
+    src.store_metadata()?;
+    let elements: Vec<u16> = (0..13 * 9).collect();
+    src.store_array_subset_elements(&src.subset_all(), &elements)?;
+
+    // Shards and inner chunks that do not evenly divide the array or align with the source chunks
+    let dst_store = Arc::new(MemoryStore::new());
+    let mut dst_builder = ArrayBuilder::new(
+        vec![13, 9],
+        DataType::UInt16,
+        vec![6, 6].try_into()?,
+        FillValue::from(0u16),
+    );
+    dst_builder.array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![3, 2].try_into()?)
+            .bytes_to_bytes_codecs(vec![Arc::new(ZstdCodec::new(5, false))])
+            .build(),
+    ));
+    let num_chunks_copied = Arc::new(AtomicU64::new(0));
+    let options = ArrayCopyOptions::default().with_progress_callback({
+        let num_chunks_copied = num_chunks_copied.clone();
+        move |_, total| {
+            assert_eq!(total, 6);
+            num_chunks_copied.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    let dst = copy_array(&src, dst_builder, dst_store.clone(), "/dst", &options)?;
+    assert_eq!(num_chunks_copied.load(Ordering::Relaxed), 6);
+
+    assert_eq!(dst.attributes(), src.attributes());
+    assert_eq!(dst.dimension_names(), src.dimension_names());
+    assert_eq!(
+        dst.retrieve_array_subset_elements::<u16>(&dst.subset_all())?,
+        elements
+    );
+    assert_eq!(
+        dst.retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[5..13, 3..7]))?,
+        src.retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[5..13, 3..7]))?,
+    );
+
+    // The destination metadata is stored
+    let dst = zarrs::array::Array::open(dst_store.clone(), "/dst")?;
+    assert_eq!(
+        dst.retrieve_array_subset_elements::<u16>(&dst.subset_all())?,
+        elements
+    );
+
+    Ok(())
+}
+
+#[test]
+fn array_copy_incompatible() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let src = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u8),
+    )
+    .build(store.clone(), "/src")?;
+
+    let dst_builder = ArrayBuilder::new(
+        vec![4, 5],
+        DataType::UInt8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u8),
+    );
+    assert!(matches!(
+        copy_array(
+            &src,
+            dst_builder,
+            store.clone(),
+            "/dst",
+            &ArrayCopyOptions::default()
+        ),
+        Err(ArrayCopyError::IncompatibleShape(_, _))
+    ));
+
+    let dst_builder = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    );
+    assert!(matches!(
+        copy_array(
+            &src,
+            dst_builder,
+            store,
+            "/dst",
+            &ArrayCopyOptions::default()
+        ),
+        Err(ArrayCopyError::IncompatibleDataType(_, _))
+    ));
+
+    Ok(())
+}
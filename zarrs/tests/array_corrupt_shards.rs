@@ -0,0 +1,112 @@
+#![allow(missing_docs)]
+#![cfg(feature = "sharding")]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        codec::{array_to_bytes::sharding::ShardingCodecBuilder, CodecError, CodecOptions},
+        Array, ArrayBuilder, ArrayError, DataType, FillValue,
+    },
+    array_subset::ArraySubset,
+    storage::{ReadableStorageTraits, StoreKey, WritableStorageTraits},
+};
+use zarrs_storage::store::MemoryStore;
+
+fn sharded_array_with_truncated_shard(
+    truncated_len: usize,
+) -> Result<Array<MemoryStore>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let mut builder = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    );
+    builder.array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![2, 2].try_into()?).build(),
+    ));
+    let array = builder.build(store.clone(), "/")?;
+    let elements: Vec<u16> = (1..=64).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+
+    // Truncate the shard at [1, 0], as if by an interrupted upload
+    let key = StoreKey::new("c/1/0")?;
+    let shard = store.get(&key)?.unwrap();
+    store.set(&key, shard.slice(..truncated_len))?;
+
+    Ok(array)
+}
+
+#[test]
+fn array_corrupt_shard_error() -> Result<(), Box<dyn std::error::Error>> {
+    // The shard is 100 bytes, with a 64 byte index (+ checksum) at the end.
+    // Truncate it to less than the size of its index, and such that its index is read from inner chunk data.
+    for truncated_len in [10, 70] {
+        let array = sharded_array_with_truncated_shard(truncated_len)?;
+
+        // Decoding the whole shard
+        let result = array.retrieve_array_subset_elements::<u16>(&array.subset_all());
+        let Err(ArrayError::CodecError(CodecError::ShardIndexCorrupt { key, .. })) = result else {
+            panic!("expected a corrupt shard index error, got {result:?}");
+        };
+        assert_eq!(key, Some(StoreKey::new("c/1/0")?));
+
+        // Partially decoding the shard
+        let result =
+            array.retrieve_chunk_subset(&[1, 0], &ArraySubset::new_with_ranges(&[1..3, 1..3]));
+        assert!(matches!(
+            result,
+            Err(ArrayError::CodecError(CodecError::ShardIndexCorrupt {
+                key: Some(_),
+                ..
+            }))
+        ));
+
+        // Other shards are unaffected
+        assert_eq!(
+            array.retrieve_chunk_elements::<u16>(&[0, 0])?,
+            [1, 2, 3, 4, 9, 10, 11, 12, 17, 18, 19, 20, 25, 26, 27, 28]
+        );
+        assert!(array.corrupt_chunks().is_empty());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn array_corrupt_shard_as_missing() -> Result<(), Box<dyn std::error::Error>> {
+    let options = CodecOptions::builder()
+        .treat_corrupt_shards_as_missing(true)
+        .build();
+    for truncated_len in [10, 70] {
+        let array = sharded_array_with_truncated_shard(truncated_len)?;
+
+        let elements =
+            array.retrieve_array_subset_elements_opt::<u16>(&array.subset_all(), &options)?;
+        // The elements of the shard at [1, 0] are the fill value
+        let expected: Vec<u16> = (0..64)
+            .map(|i| if i / 8 >= 4 && i % 8 < 4 { 0 } else { i + 1 })
+            .collect();
+        assert_eq!(elements, expected);
+        assert_eq!(array.corrupt_chunks(), [vec![1, 0]]);
+
+        array.clear_corrupt_chunks();
+        assert!(array.corrupt_chunks().is_empty());
+        assert_eq!(
+            array.retrieve_chunk_subset_elements_opt::<u16>(
+                &[1, 0],
+                &ArraySubset::new_with_ranges(&[1..3, 1..3]),
+                &options
+            )?,
+            [0, 0, 0, 0]
+        );
+        assert_eq!(
+            array.retrieve_chunk_elements_opt::<u16>(&[1, 0], &options)?,
+            [0; 16]
+        );
+        assert_eq!(array.corrupt_chunks(), [vec![1, 0]]);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+#![allow(missing_docs)]
+#![cfg(feature = "dlpack")]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        dlpack::{DLDataType, DLDevice, ManagedTensor, DLPACK_ALIGNMENT},
+        Array, ArrayBuilder, DataType, FillValue,
+    },
+    array_subset::ArraySubset,
+    storage::store::MemoryStore,
+};
+
+/// Read the elements of a tensor through the raw `DLPack` tensor, as a consumer would.
+fn tensor_elements<T: bytemuck::Pod>(tensor: ManagedTensor) -> Vec<T> {
+    let managed_tensor = tensor.into_raw();
+    let elements = unsafe {
+        let dl_tensor = &(*managed_tensor).dl_tensor;
+        assert_eq!(dl_tensor.device, DLDevice::CPU);
+        assert_eq!(dl_tensor.data as usize % DLPACK_ALIGNMENT, 0);
+        assert_eq!(dl_tensor.dtype.size(), size_of::<T>());
+        let ndim = usize::try_from(dl_tensor.ndim).unwrap();
+        let shape = std::slice::from_raw_parts(dl_tensor.shape, ndim);
+        let num_elements = usize::try_from(shape.iter().product::<i64>()).unwrap();
+        std::slice::from_raw_parts(dl_tensor.data.cast::<T>(), num_elements).to_vec()
+    };
+    unsafe { ((*managed_tensor).deleter.unwrap())(managed_tensor) };
+    elements
+}
+
+#[test]
+fn array_dlpack_retrieve() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![5, 7],
+        DataType::Float32,
+        vec![2, 3].try_into()?,
+        FillValue::from(-1.0f32),
+    )
+    .build(store, "/")?;
+    let elements: Vec<f32> = (0..35u16).map(f32::from).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+
+    let array_subset = ArraySubset::new_with_ranges(&[1..4, 2..6]);
+    let tensor = array.retrieve_array_subset_dlpack(&array_subset)?;
+    assert_eq!(tensor.dtype(), DLDataType::new(DLDataType::CODE_FLOAT, 32));
+    assert_eq!(tensor.shape(), &[3, 4]);
+    assert_eq!(tensor.strides(), Some([4, 1].as_slice()));
+    assert_eq!(
+        tensor_elements::<f32>(tensor),
+        array.retrieve_array_subset_elements::<f32>(&array_subset)?
+    );
+
+    // Edge chunks include the elements outside of the array shape
+    let tensor = array.retrieve_chunk_dlpack(&[2, 2])?;
+    assert_eq!(tensor.shape(), &[2, 3]);
+    assert_eq!(
+        tensor_elements::<f32>(tensor),
+        array.retrieve_chunk_elements::<f32>(&[2, 2])?
+    );
+
+    assert!(array.retrieve_chunk_dlpack(&[0]).is_err());
+    Ok(())
+}
+
+fn array_4x4(
+    store: &Arc<MemoryStore>,
+    data_type: DataType,
+    fill_value: FillValue,
+    path: &str,
+) -> Result<Array<MemoryStore>, Box<dyn std::error::Error>> {
+    Ok(
+        ArrayBuilder::new(vec![4, 4], data_type, vec![2, 2].try_into()?, fill_value)
+            .build(store.clone(), path)?,
+    )
+}
+
+#[test]
+fn array_dlpack_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array_u16 = array_4x4(&store, DataType::UInt16, FillValue::from(0u16), "/u16")?;
+    let array_bf16 = array_4x4(
+        &store,
+        DataType::BFloat16,
+        FillValue::from(half::bf16::ZERO),
+        "/bf16",
+    )?;
+    let array_string = array_4x4(&store, DataType::String, FillValue::from(""), "/string")?;
+
+    let elements: Vec<u16> = (0..16).collect();
+    array_u16.store_array_subset_elements(&array_u16.subset_all(), &elements)?;
+    let tensor =
+        array_u16.retrieve_array_subset_dlpack(&ArraySubset::new_with_ranges(&[1..3, 1..4]))?;
+
+    // Store the tensor elsewhere in the same array
+    array_u16.store_array_subset_dlpack(&[2, 0], &tensor)?;
+    assert_eq!(
+        array_u16
+            .retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[2..4, 0..3]))?,
+        [5, 6, 7, 9, 10, 11]
+    );
+
+    // Validation of the data type and dimensionality
+    assert!(array_bf16
+        .store_array_subset_dlpack(&[0, 0], &tensor)
+        .is_err());
+    assert!(array_u16.store_array_subset_dlpack(&[0], &tensor).is_err());
+    assert!(array_string.retrieve_chunk_dlpack(&[0, 0]).is_err());
+
+    // bfloat16 round trip
+    let elements: Vec<half::bf16> = (0..16u8).map(half::bf16::from).collect();
+    array_bf16.store_array_subset_elements(&array_bf16.subset_all(), &elements)?;
+    let tensor = array_bf16.retrieve_chunk_dlpack(&[1, 0])?;
+    assert_eq!(tensor.dtype(), DLDataType::new(DLDataType::CODE_BFLOAT, 16));
+    array_bf16.store_array_subset_dlpack(&[0, 2], &tensor)?;
+    assert_eq!(
+        tensor_elements::<half::bf16>(array_bf16.retrieve_chunk_dlpack(&[0, 1])?),
+        array_bf16.retrieve_chunk_elements::<half::bf16>(&[1, 0])?
+    );
+    Ok(())
+}
@@ -0,0 +1,140 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{ArrayBuilder, ArrayError, DataType, ElementCastMode, FillValue},
+    array_subset::ArraySubset,
+};
+use zarrs_storage::store::MemoryStore;
+
+#[test]
+fn array_element_cast_uint16_to_f32() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![5, 7],
+        DataType::UInt16,
+        vec![2, 3].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store, "/")?;
+    let elements: Vec<u16> = (0..35).map(|i| i * 1000).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+
+    let subset = ArraySubset::new_with_ranges(&[1..5, 2..6]);
+    let expected: Vec<f32> = array
+        .retrieve_array_subset_elements::<u16>(&subset)?
+        .into_iter()
+        .map(f32::from)
+        .collect();
+    for mode in [ElementCastMode::Saturating, ElementCastMode::Checked] {
+        assert_eq!(
+            array.retrieve_array_subset_elements_cast::<f32>(&subset, mode)?,
+            expected
+        );
+    }
+
+    // Saturating narrowing to u8, and checked narrowing errors
+    let elements_u8 =
+        array.retrieve_array_subset_elements_cast::<u8>(&subset, ElementCastMode::Saturating)?;
+    assert!(elements_u8.iter().all(|element| *element == u8::MAX));
+    assert!(matches!(
+        array.retrieve_array_subset_elements_cast::<u8>(&subset, ElementCastMode::Checked),
+        Err(ArrayError::ElementCastOutOfRange(_, _))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn array_element_cast_f64_to_f32() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4],
+        DataType::Float64,
+        vec![3].try_into()?,
+        FillValue::from(0.0f64),
+    )
+    .build(store, "/")?;
+    let elements = [0.1f64, 1.0 / 3.0, -2.5, 1e300];
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+
+    let elements_f32 = array.retrieve_array_subset_elements_cast::<f32>(
+        &array.subset_all(),
+        ElementCastMode::Saturating,
+    )?;
+    #[allow(clippy::cast_possible_truncation)]
+    let expected = [0.1f64 as f32, (1.0f64 / 3.0) as f32, -2.5, f32::MAX];
+    assert_eq!(elements_f32, expected);
+    assert!(array
+        .retrieve_array_subset_elements_cast::<f32>(&array.subset_all(), ElementCastMode::Checked)
+        .is_err());
+    assert_eq!(
+        array.retrieve_array_subset_elements_cast::<f32>(
+            &ArraySubset::new_with_shape(vec![3]),
+            ElementCastMode::Checked
+        )?,
+        expected[..3]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn array_element_cast_store() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![2, 3],
+        DataType::Int8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0i8),
+    )
+    .build(store, "/")?;
+
+    let elements = [-1000i32, -1, 0, 1, 127, 128];
+    assert!(matches!(
+        array.store_array_subset_elements_cast(
+            &array.subset_all(),
+            &elements,
+            ElementCastMode::Checked
+        ),
+        Err(ArrayError::ElementCastOutOfRange(_, _))
+    ));
+    array.store_array_subset_elements_cast(
+        &array.subset_all(),
+        &elements,
+        ElementCastMode::Saturating,
+    )?;
+    assert_eq!(
+        array.retrieve_array_subset_elements::<i8>(&array.subset_all())?,
+        [-128, -1, 0, 1, 127, 127]
+    );
+
+    array.store_array_subset_elements_cast(
+        &ArraySubset::new_with_ranges(&[1..2, 0..3]),
+        &[1.9f32, -1.9, f32::NAN],
+        ElementCastMode::Saturating,
+    )?;
+    assert_eq!(
+        array.retrieve_array_subset_elements::<i8>(&array.subset_all())?,
+        [-128, -1, 0, 1, -1, 0]
+    );
+
+    // Unsupported data types
+    let array = ArrayBuilder::new(
+        vec![2],
+        DataType::Bool,
+        vec![2].try_into()?,
+        FillValue::from(false),
+    )
+    .build(Arc::new(MemoryStore::new()), "/")?;
+    assert!(matches!(
+        array.retrieve_array_subset_elements_cast::<u8>(
+            &array.subset_all(),
+            ElementCastMode::Saturating
+        ),
+        Err(ArrayError::IncompatibleElementType)
+    ));
+
+    Ok(())
+}
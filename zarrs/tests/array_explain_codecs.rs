@@ -0,0 +1,84 @@
+#![allow(missing_docs)]
+#![cfg(all(feature = "sharding", feature = "transpose", feature = "zstd"))]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        codec::{CodecKind, CodecStageRepresentation},
+        Array, ArrayMetadata, BytesRepresentation,
+    },
+    storage::store::MemoryStore,
+};
+
+const METADATA: &str = r#"{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [16, 12],
+    "data_type": "uint16",
+    "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [8, 4]}},
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [
+        {"name": "transpose", "configuration": {"order": [1, 0]}},
+        {
+            "name": "sharding_indexed",
+            "configuration": {
+                "chunk_shape": [2, 4],
+                "codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+                "index_codecs": [{"name": "bytes", "configuration": {"endian": "little"}}],
+                "index_location": "end"
+            }
+        },
+        {"name": "zstd", "configuration": {"level": 1, "checksum": false}}
+    ]
+}"#;
+
+const EXPLANATION: &str = "\
+#  kind          codec             input                    output                   partial decode  concurrency
+0  array->array  transpose         array [8, 4] uint16 0    array [4, 8] uint16 0    true            1-1
+1  array->bytes  sharding_indexed  array [4, 8] uint16 0    bytes bounded size: 128  true            1-4
+2  bytes->bytes  zstd              bytes bounded size: 128  bytes bounded size: 153  false           1-1
+recommended concurrency: 1-4
+partial decode granularity: [8, 4]";
+
+#[test]
+fn array_explain_codecs() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let metadata: ArrayMetadata = serde_json::from_str(METADATA)?;
+    let array = Array::new_with_metadata(store, "/", metadata)?;
+
+    let stages = array.codecs().stages();
+    assert_eq!(
+        stages
+            .iter()
+            .map(|stage| (
+                stage.kind(),
+                stage.name().unwrap(),
+                stage.partial_decode_capable()
+            ))
+            .collect::<Vec<_>>(),
+        [
+            (CodecKind::ArrayToArray, "transpose", true),
+            (CodecKind::ArrayToBytes, "sharding_indexed", true),
+            (CodecKind::BytesToBytes, "zstd", false),
+        ]
+    );
+    assert_eq!(
+        stages[2].configuration().unwrap()["level"],
+        serde_json::json!(1)
+    );
+
+    let explanation = array.explain_codecs()?;
+    assert_eq!(explanation.stages().len(), 3);
+    assert!(matches!(
+        explanation.stages()[0].output(),
+        CodecStageRepresentation::Array(_)
+    ));
+    assert!(matches!(
+        explanation.stages()[2].output(),
+        CodecStageRepresentation::Bytes(BytesRepresentation::BoundedSize(_))
+    ));
+    assert_eq!(explanation.to_string(), EXPLANATION);
+    Ok(())
+}
@@ -0,0 +1,221 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, ArrayBuilder, ArrayError, DataType, FillValue},
+    array_subset::ArraySubset,
+    storage::{
+        storage_adapter::performance_metrics::PerformanceMetricsStorageAdapter, store::MemoryStore,
+    },
+};
+
+fn uint16_array<TStorage: ?Sized>(
+    store: Arc<TStorage>,
+    shape: Vec<u64>,
+) -> Result<Array<TStorage>, Box<dyn std::error::Error>> {
+    Ok(ArrayBuilder::new(
+        shape,
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store, "/")?)
+}
+
+#[test]
+fn array_fill_array_subset() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(PerformanceMetricsStorageAdapter::new(Arc::new(
+        MemoryStore::new(),
+    )));
+    let array = uint16_array(store.clone(), vec![4, 4])?;
+
+    // Partial chunk coverage
+    array.fill_array_subset(
+        &ArraySubset::new_with_ranges(&[1..4, 0..3]),
+        &FillValue::from(7u16),
+    )?;
+    #[rustfmt::skip]
+    let expected = [
+        0, 0, 0, 0,
+        7, 7, 7, 0,
+        7, 7, 7, 0,
+        7, 7, 7, 0,
+    ];
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?,
+        expected
+    );
+
+    // Whole chunk coverage does not decode chunks
+    store.reset();
+    array.fill_array_subset(
+        &ArraySubset::new_with_ranges(&[2..4, 0..4]),
+        &FillValue::from(1u16),
+    )?;
+    assert_eq!(store.reads(), 0);
+    assert_eq!(store.writes(), 2);
+    assert_eq!(
+        array
+            .retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[2..4, 0..4]))?,
+        [1; 8]
+    );
+
+    // Whole chunks are erased when filled with the fill value
+    store.reset();
+    array.fill_array_subset(
+        &ArraySubset::new_with_ranges(&[0..2, 0..4]),
+        &FillValue::from(0u16),
+    )?;
+    assert_eq!(store.reads(), 0);
+    assert_eq!(store.writes(), 0);
+    assert_eq!(store.keys_erased(), 2);
+    assert!(array.retrieve_encoded_chunk(&[0, 0])?.is_none());
+    assert!(array.retrieve_encoded_chunk(&[0, 1])?.is_none());
+    Ok(())
+}
+
+#[test]
+fn array_fill_array_subset_edge_chunks() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(PerformanceMetricsStorageAdapter::new(Arc::new(
+        MemoryStore::new(),
+    )));
+    let array = uint16_array(store.clone(), vec![3, 3])?;
+
+    // Edge chunks are covered if all of their elements within the array shape are covered
+    array.fill_array_subset(&array.subset_all(), &FillValue::from(5u16))?;
+    assert_eq!(store.reads(), 0);
+    assert_eq!(store.writes(), 4);
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?,
+        [5; 9]
+    );
+    Ok(())
+}
+
+#[test]
+fn array_fill_chunks() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(PerformanceMetricsStorageAdapter::new(Arc::new(
+        MemoryStore::new(),
+    )));
+    let array = uint16_array(store.clone(), vec![4, 4])?;
+
+    array.fill_chunks(
+        &ArraySubset::new_with_ranges(&[1..2, 0..2]),
+        &FillValue::from(9u16),
+    )?;
+    assert_eq!(store.reads(), 0);
+    assert_eq!(store.writes(), 2);
+    #[rustfmt::skip]
+    let expected = [
+        0, 0, 0, 0,
+        0, 0, 0, 0,
+        9, 9, 9, 9,
+        9, 9, 9, 9,
+    ];
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?,
+        expected
+    );
+
+    store.reset();
+    array.fill_chunks(
+        &ArraySubset::new_with_ranges(&[1..2, 1..2]),
+        &FillValue::from(0u16),
+    )?;
+    assert_eq!(store.writes(), 0);
+    assert_eq!(store.keys_erased(), 1);
+    assert!(array.retrieve_encoded_chunk(&[1, 0])?.is_some());
+    assert!(array.retrieve_encoded_chunk(&[1, 1])?.is_none());
+
+    // Mismatched dimensionality
+    assert!(matches!(
+        array.fill_chunks(
+            &ArraySubset::new_with_ranges(&[0..1, 0..1, 0..1]),
+            &FillValue::from(9u16),
+        ),
+        Err(ArrayError::IncompatibleDimensionalityError(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn array_fill_unsupported() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::String,
+        vec![2, 2].try_into()?,
+        FillValue::from(""),
+    )
+    .build(store.clone(), "/string")?;
+    let err = array
+        .fill_array_subset(&array.subset_all(), &FillValue::from("a"))
+        .unwrap_err();
+    assert!(matches!(err, ArrayError::UnsupportedMethod(_)));
+    assert_eq!(
+        err.to_string(),
+        "unsupported array method: cannot fill with the variable sized data type string"
+    );
+    assert!(matches!(
+        array.fill_chunks(
+            &ArraySubset::new_with_ranges(&[0..1, 0..1]),
+            &FillValue::from("a")
+        ),
+        Err(ArrayError::UnsupportedMethod(_))
+    ));
+
+    // Mismatched value size
+    let array = uint16_array(store, vec![4, 4])?;
+    assert!(matches!(
+        array.fill_array_subset(&array.subset_all(), &FillValue::from(1u8)),
+        Err(ArrayError::InvalidBytesInputSize(1, 2))
+    ));
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_fill_async() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = uint16_array(store, vec![4, 4])?;
+
+    array
+        .async_fill_array_subset(
+            &ArraySubset::new_with_ranges(&[1..4, 0..3]),
+            &FillValue::from(7u16),
+        )
+        .await?;
+    array
+        .async_fill_chunks(
+            &ArraySubset::new_with_ranges(&[0..1, 1..2]),
+            &FillValue::from(3u16),
+        )
+        .await?;
+    #[rustfmt::skip]
+    let expected = [
+        0, 0, 3, 3,
+        7, 7, 3, 3,
+        7, 7, 7, 0,
+        7, 7, 7, 0,
+    ];
+    assert_eq!(
+        array
+            .async_retrieve_array_subset_elements::<u16>(&array.subset_all())
+            .await?,
+        expected
+    );
+
+    array
+        .async_fill_array_subset(&array.subset_all(), &FillValue::from(0u16))
+        .await?;
+    for chunk_indices in &ArraySubset::new_with_ranges(&[0..2, 0..2]).indices() {
+        assert!(array
+            .async_retrieve_encoded_chunk(&chunk_indices)
+            .await?
+            .is_none());
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, ArrayBuilder, ArrayError, DataType, FillValue},
+    array_subset::ArraySubset,
+};
+use zarrs_storage::store::MemoryStore;
+
+#[test]
+fn array_fixed_length_utf8_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![2, 3],
+        DataType::FixedLengthUtf8(6),
+        vec![1, 3].try_into()?,
+        FillValue::new(b"n/a\0\0\0".to_vec()),
+    )
+    .build(store.clone(), "/")?;
+    array.store_metadata()?;
+
+    // Multi-byte characters, an empty string, and a string that fills the length exactly
+    let elements = ["é", "", "a€bc"];
+    array.store_chunk_elements(&[0, 0], &elements)?;
+    assert_eq!(
+        array.retrieve_chunk_elements::<String>(&[0, 0])?,
+        ["é", "", "a€bc"]
+    );
+    assert_eq!(
+        array.retrieve_chunk(&[0, 0])?.into_fixed()?.as_ref(),
+        b"\xc3\xa9\0\0\0\0\0\0\0\0\0\0a\xe2\x82\xacbc"
+    );
+
+    // Chunks that are not stored are retrieved as the fill value
+    assert_eq!(
+        array.retrieve_array_subset_elements::<String>(&ArraySubset::new_with_ranges(&[
+            1..2,
+            0..2
+        ]))?,
+        ["n/a", "n/a"]
+    );
+    assert_eq!(
+        array.retrieve_chunk_elements::<Option<String>>(&[1, 0])?,
+        [None, None, None]
+    );
+
+    // Strings that exceed the length are not truncated, even if some of their characters fit
+    for element in ["abcdefg", "éé€", "aaaaa€"] {
+        assert!(matches!(
+            array.store_chunk_elements(&[1, 0], &["", element, ""]),
+            Err(ArrayError::InvalidElementValue)
+        ));
+    }
+
+    // Variable-length element types are incompatible with other data types
+    assert!(matches!(
+        array.store_chunk_elements(&[1, 0], &[vec![0u8], vec![], vec![]]),
+        Err(ArrayError::IncompatibleElementType)
+    ));
+
+    let array = Array::open(store, "/")?;
+    assert_eq!(array.data_type(), &DataType::FixedLengthUtf8(6));
+    assert_eq!(
+        array.fill_value().as_ne_bytes(),
+        FillValue::new(b"n/a\0\0\0".to_vec()).as_ne_bytes()
+    );
+    assert_eq!(
+        array.retrieve_chunk_elements::<String>(&[0, 0])?,
+        ["é", "", "a€bc"]
+    );
+    Ok(())
+}
+
+#[test]
+fn array_fixed_length_bytes_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4],
+        DataType::FixedLengthBytes(3),
+        vec![4].try_into()?,
+        FillValue::new(vec![0; 3]),
+    )
+    .build(store, "/")?;
+
+    array.store_chunk_elements(&[0], &[vec![1], vec![1, 2, 3], vec![], vec![0, 1]])?;
+    assert_eq!(
+        array.retrieve_chunk_elements::<Vec<u8>>(&[0])?,
+        [vec![1], vec![1, 2, 3], vec![], vec![0, 1]]
+    );
+    assert_eq!(
+        array.retrieve_chunk_elements::<[u8; 3]>(&[0])?,
+        [[1, 0, 0], [1, 2, 3], [0, 0, 0], [0, 1, 0]]
+    );
+
+    assert!(matches!(
+        array.store_chunk_elements(&[0], &[vec![0; 4], vec![], vec![], vec![]]),
+        Err(ArrayError::InvalidElementValue)
+    ));
+    assert!(matches!(
+        array.retrieve_chunk_elements::<[u8; 4]>(&[0]),
+        Err(ArrayError::IncompatibleElementType)
+    ));
+    Ok(())
+}
+
+#[test]
+fn array_fixed_length_open() -> Result<(), Box<dyn std::error::Error>> {
+    // The bytes codec does not need an endianness for fixed-length data types
+    let store = Arc::new(MemoryStore::new());
+    let json = r#"{
+        "zarr_format": 3,
+        "node_type": "array",
+        "shape": [2],
+        "data_type": {"name": "fixed_length_utf8", "configuration": {"length_bytes": 4}},
+        "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [2]}},
+        "chunk_key_encoding": {"name": "default"},
+        "fill_value": "ab",
+        "codecs": [{"name": "bytes"}]
+    }"#;
+    let array = Array::new_with_metadata(store, "/", serde_json::from_str(json)?)?;
+    assert_eq!(array.retrieve_chunk_elements::<String>(&[0])?, ["ab", "ab"]);
+    array.store_chunk_elements(&[0], &["abcd", "ä"])?;
+    assert_eq!(
+        array.retrieve_chunk_elements::<String>(&[0])?,
+        ["abcd", "ä"]
+    );
+    Ok(())
+}
@@ -0,0 +1,121 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use half::{bf16, f16};
+use zarrs::{
+    array::{ArrayBuilder, DataType, FillValue, ZARR_NAN_BF16, ZARR_NAN_F16},
+    array_subset::ArraySubset,
+};
+use zarrs_storage::store::MemoryStore;
+
+/// Finite, infinite, signed zero, subnormal, and NaN (with and without payloads) bit patterns.
+const F16_BITS: [u16; 8] = [
+    0x3C00, // 1.0
+    0xFC00, // -inf
+    0x8000, // -0.0
+    0x0001, // smallest positive subnormal
+    0x83FF, // largest negative subnormal
+    0x7E00, // NaN
+    0x7C01, // signalling NaN
+    0xFE2A, // negative NaN with a payload
+];
+
+const BF16_BITS: [u16; 8] = [
+    0x3F80, // 1.0
+    0xFF80, // -inf
+    0x8000, // -0.0
+    0x0001, // smallest positive subnormal
+    0x807F, // largest negative subnormal
+    0x7FC0, // NaN
+    0x7F81, // signalling NaN
+    0xFFEA, // negative NaN with a payload
+];
+
+#[test]
+fn array_half_f16_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::Float16,
+        vec![2, 4].try_into()?,
+        FillValue::from(ZARR_NAN_F16),
+    )
+    .build(store, "/")?;
+    assert_eq!(
+        array.fill_value().try_as::<f16>().map(f16::to_bits),
+        Some(ZARR_NAN_F16.to_bits())
+    );
+
+    let elements: Vec<f16> = F16_BITS.into_iter().map(f16::from_bits).collect();
+    array.store_chunk_elements(&[0, 0], &elements)?;
+    let retrieved = array.retrieve_chunk_elements::<f16>(&[0, 0])?;
+    assert_eq!(
+        retrieved.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+        F16_BITS
+    );
+
+    // Chunks that are not stored are retrieved as the fill value
+    let retrieved = array.retrieve_chunk_elements::<f16>(&[1, 0])?;
+    assert!(retrieved
+        .iter()
+        .all(|v| v.to_bits() == ZARR_NAN_F16.to_bits()));
+
+    #[cfg(feature = "ndarray")]
+    {
+        let subset = ArraySubset::new_with_ranges(&[2..4, 0..4]);
+        let ndarray = ndarray::Array2::from_shape_vec((2, 4), elements.clone())?.into_dyn();
+        array.store_array_subset_ndarray(subset.start(), ndarray)?;
+        let retrieved = array.retrieve_array_subset_ndarray::<f16>(&subset)?;
+        assert_eq!(retrieved.shape(), &[2, 4]);
+        assert_eq!(
+            retrieved.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+            F16_BITS
+        );
+    }
+
+    let retrieved = array
+        .retrieve_array_subset_elements::<f16>(&ArraySubset::new_with_ranges(&[0..2, 0..4]))?;
+    assert_eq!(
+        retrieved.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+        F16_BITS
+    );
+
+    Ok(())
+}
+
+#[test]
+fn array_half_bf16_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::BFloat16,
+        vec![2, 4].try_into()?,
+        FillValue::from(ZARR_NAN_BF16),
+    )
+    .build(store, "/")?;
+    assert_eq!(
+        array.fill_value().try_as::<bf16>().map(bf16::to_bits),
+        Some(ZARR_NAN_BF16.to_bits())
+    );
+
+    let elements: Vec<bf16> = BF16_BITS.into_iter().map(bf16::from_bits).collect();
+    array.store_chunk_elements(&[1, 0], &elements)?;
+    let retrieved = array.retrieve_array_subset_elements::<bf16>(&array.subset_all())?;
+    let (fill, stored) = retrieved.split_at(8);
+    assert!(fill.iter().all(|v| v.to_bits() == ZARR_NAN_BF16.to_bits()));
+    assert_eq!(
+        stored.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+        BF16_BITS
+    );
+
+    // A chunk of the fill value is not stored, and is retrieved bitwise
+    array.store_chunk_elements(&[0, 0], &[ZARR_NAN_BF16; 8])?;
+    assert!(array.retrieve_encoded_chunk(&[0, 0])?.is_none());
+    let retrieved = array.retrieve_chunk_elements::<bf16>(&[0, 0])?;
+    assert!(retrieved
+        .iter()
+        .all(|v| v.to_bits() == ZARR_NAN_BF16.to_bits()));
+
+    Ok(())
+}
@@ -0,0 +1,226 @@
+#![allow(missing_docs)]
+#![cfg(feature = "sharding")]
+
+use std::sync::{Arc, Mutex};
+
+use zarrs::{
+    array::{
+        codec::{array_to_bytes::sharding::ShardingCodecBuilder, CodecOptions},
+        Array, ArrayBuilder, DataType, FillValue,
+    },
+    array_subset::ArraySubset,
+    byte_range::ByteRange,
+    storage::{
+        store::MemoryStore, Bytes, ReadableStorageTraits, StorageError, StoreKey,
+        WritableStorageTraits,
+    },
+};
+
+/// A store that records the byte ranges of partial value requests.
+#[derive(Default)]
+struct ByteRangeRecordingStore {
+    store: MemoryStore,
+    byte_ranges: Mutex<Vec<(StoreKey, ByteRange)>>,
+}
+
+impl ByteRangeRecordingStore {
+    /// Return the number of suffix byte ranges requested for `key`, which retrieve the index of a shard with the index at the end.
+    fn shard_index_requests(&self, key: &StoreKey) -> usize {
+        self.byte_ranges
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(k, byte_range)| k == key && matches!(byte_range, ByteRange::Suffix(_)))
+            .count()
+    }
+}
+
+impl ReadableStorageTraits for ByteRangeRecordingStore {
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.byte_ranges.lock().unwrap().extend(
+            byte_ranges
+                .iter()
+                .map(|byte_range| (key.clone(), *byte_range)),
+        );
+        self.store.get_partial_values_key(key, byte_ranges)
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.store.size_key(key)
+    }
+}
+
+impl WritableStorageTraits for ByteRangeRecordingStore {
+    fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        self.store.set(key, value)
+    }
+
+    fn set_partial_values(
+        &self,
+        key_offset_values: &[zarrs::storage::StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        self.store.set_partial_values(key_offset_values)
+    }
+
+    fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.store.erase(key)
+    }
+
+    fn erase_prefix(&self, prefix: &zarrs::storage::StorePrefix) -> Result<(), StorageError> {
+        self.store.erase_prefix(prefix)
+    }
+}
+
+/// An 8x8 array with 4x4 shards and 2x2 inner chunks.
+fn array_builder() -> Result<ArrayBuilder, Box<dyn std::error::Error>> {
+    let mut builder = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    );
+    builder.array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![2, 2].try_into()?).build(),
+    ));
+    Ok(builder)
+}
+
+#[test]
+fn array_partial_decoder_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(ByteRangeRecordingStore::default());
+    let array = array_builder()?.build(store.clone(), "/")?;
+    assert_eq!(array.partial_decoder_cache_capacity(), None);
+    let elements: Vec<u16> = (0..64).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+    let key = array.chunk_key(&[0, 0]);
+    let subset_a = ArraySubset::new_with_ranges(&[0..2, 0..2]);
+    let subset_b = ArraySubset::new_with_ranges(&[2..4, 1..3]);
+
+    // The shard index is retrieved by each call without a partial decoder cache
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&subset_a)?,
+        [0, 1, 8, 9]
+    );
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&subset_b)?,
+        [17, 18, 25, 26]
+    );
+    assert_eq!(store.shard_index_requests(&key), 2);
+
+    // The shard index is retrieved once across calls with a partial decoder cache
+    let array = array.with_partial_decoder_cache(4);
+    assert_eq!(array.partial_decoder_cache_capacity(), Some(4));
+    store.byte_ranges.lock().unwrap().clear();
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&subset_a)?,
+        [0, 1, 8, 9]
+    );
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&subset_b)?,
+        [17, 18, 25, 26]
+    );
+    assert_eq!(
+        array
+            .partial_decoder(&[0, 0])?
+            .partial_decode(std::slice::from_ref(&subset_a), &CodecOptions::default())?
+            .remove(0)
+            .into_fixed()?
+            .len(),
+        8
+    );
+    assert_eq!(store.shard_index_requests(&key), 1);
+
+    // Storing a chunk removes its partial decoder from the cache
+    array.store_chunk_elements::<u16>(&[0, 0], &[1; 16])?;
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&subset_a)?,
+        [1, 1, 1, 1]
+    );
+    assert_eq!(store.shard_index_requests(&key), 2);
+
+    // Erasing a chunk removes its partial decoder from the cache
+    array.erase_chunk(&[0, 0])?;
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&subset_b)?,
+        [0, 0, 0, 0]
+    );
+
+    // Partial decoders of other chunks are unaffected
+    let key_other = array.chunk_key(&[1, 1]);
+    let subset_other = ArraySubset::new_with_ranges(&[4..6, 4..6]);
+    for _ in 0..2 {
+        assert_eq!(
+            array.retrieve_array_subset_elements::<u16>(&subset_other)?,
+            [36, 37, 44, 45]
+        );
+    }
+    assert_eq!(store.shard_index_requests(&key_other), 1);
+
+    // A capacity of zero disables the cache
+    let array = Array::new_with_metadata(store, "/", array.metadata().clone())?
+        .with_partial_decoder_cache(0);
+    assert_eq!(array.partial_decoder_cache_capacity(), None);
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_partial_decoder_cache_async() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::storage::storage_adapter::performance_metrics::{
+        PerformanceMetricsStorageAdapter, StorageRequestKind,
+    };
+
+    let store = Arc::new(PerformanceMetricsStorageAdapter::new(Arc::new(
+        zarrs_object_store::AsyncObjectStore::new(object_store::memory::InMemory::new()),
+    )));
+    let array = array_builder()?
+        .build(store.clone(), "/")?
+        .with_partial_decoder_cache(4);
+    let elements: Vec<u16> = (0..64).collect();
+    array
+        .async_store_array_subset_elements(&array.subset_all(), &elements)
+        .await?;
+
+    // The first call retrieves the shard index and the inner chunk, later calls retrieve only inner chunks
+    store.reset();
+    for (subset, expected) in [
+        ([0..2, 0..2], [0, 1, 8, 9]),
+        ([2..4, 2..4], [18, 19, 26, 27]),
+        ([0..2, 0..2], [0, 1, 8, 9]),
+    ] {
+        assert_eq!(
+            array
+                .async_retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&subset))
+                .await?,
+            expected
+        );
+    }
+    assert_eq!(
+        store.requests_by_kind()[&StorageRequestKind::GetPartialValuesKey],
+        4
+    );
+
+    // Storing a chunk removes its partial decoder from the cache
+    array
+        .async_store_chunk_elements::<u16>(&[0, 0], &[1; 16])
+        .await?;
+    store.reset();
+    assert_eq!(
+        array
+            .async_retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[
+                0..2,
+                0..2
+            ]))
+            .await?,
+        [1, 1, 1, 1]
+    );
+    assert_eq!(
+        store.requests_by_kind()[&StorageRequestKind::GetPartialValuesKey],
+        2
+    );
+    Ok(())
+}
@@ -214,6 +214,75 @@ fn array_partial_encode_sharding_index_end() {
     array_partial_encode_sharding(ShardingIndexLocation::End, vec![]).unwrap();
 }
 
+#[test]
+fn array_partial_encode_sharding_compact() -> Result<(), Box<dyn std::error::Error>> {
+    let opt = CodecOptionsBuilder::new()
+        .experimental_partial_encoding(true)
+        .build();
+
+    for index_location in [ShardingIndexLocation::Start, ShardingIndexLocation::End] {
+        let store = Arc::new(MemoryStore::default());
+        let array = ArrayBuilder::new(
+            vec![4, 4],
+            DataType::UInt16,
+            vec![2, 2].try_into().unwrap(),
+            FillValue::from(0u16),
+        )
+        .array_to_bytes_codec(Arc::new(
+            ShardingCodecBuilder::new(vec![1, 1].try_into().unwrap())
+                .index_bytes_to_bytes_codecs(vec![])
+                .index_location(index_location)
+                .build(),
+        ))
+        .build(store.clone(), "/")?;
+
+        let get_bytes_0_0 = || {
+            let key = array.chunk_key_encoding().encode(&[0, 0]);
+            store.get(&key)
+        };
+        let shard_index_size = size_of::<u64>() * 2 * 4;
+
+        // Updating an inner chunk appends it and leaves the previous inner chunk stale
+        array.store_array_subset_elements_opt::<u16>(
+            &ArraySubset::new_with_ranges(&[0..2, 0..2]),
+            &[1, 2, 3, 4],
+            &opt,
+        )?;
+        array.store_array_subset_elements_opt::<u16>(
+            &ArraySubset::new_with_ranges(&[0..1, 0..1]),
+            &[5],
+            &opt,
+        )?;
+        assert_eq!(
+            get_bytes_0_0()?.unwrap().len(),
+            shard_index_size + size_of::<u16>() * 5
+        );
+        assert_eq!(
+            array.retrieve_chunk_elements::<u16>(&[0, 0])?,
+            vec![5, 2, 3, 4]
+        );
+
+        // Remove the stale inner chunk
+        assert!(array.compact_shard(&[0, 0])?);
+        assert_eq!(
+            get_bytes_0_0()?.unwrap().len(),
+            shard_index_size + size_of::<u16>() * 4
+        );
+        assert_eq!(
+            array.retrieve_chunk_elements::<u16>(&[0, 0])?,
+            vec![5, 2, 3, 4]
+        );
+
+        // A compact shard is left unchanged
+        assert!(!array.compact_shard(&[0, 0])?);
+
+        // A missing shard is not compacted
+        assert!(!array.compact_shard(&[1, 1])?);
+    }
+
+    Ok(())
+}
+
 #[cfg(all(
     feature = "gzip",
     feature = "bz2",
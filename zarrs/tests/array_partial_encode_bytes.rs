@@ -0,0 +1,122 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        codec::{ArrayToArrayCodecTraits, BytesCodec, CodecTraits, TransposeCodec},
+        Array, ArrayBuilder, DataType, Endianness, FillValue,
+    },
+    array_subset::ArraySubset,
+    metadata::v3::array::codec::transpose::TransposeOrder,
+};
+use zarrs_storage::{
+    storage_adapter::performance_metrics::PerformanceMetricsStorageAdapter, store::MemoryStore,
+    ReadableStorageTraits,
+};
+
+fn array_partial_encode_bytes(
+    array_to_array_codecs: &[Arc<dyn ArrayToArrayCodecTraits>],
+    endianness: Endianness,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let array_shape = vec![5, 7];
+    let build = |store| {
+        let mut builder = ArrayBuilder::new(
+            array_shape.clone(),
+            DataType::UInt16,
+            vec![3, 4].try_into().unwrap(), // edge chunks are partially outside the array
+            FillValue::from(0u16),
+        );
+        builder
+            .array_to_array_codecs(array_to_array_codecs.to_vec())
+            .array_to_bytes_codec(Arc::new(BytesCodec::new(Some(endianness))));
+        builder.build(store, "/")
+    };
+
+    let store = Arc::new(MemoryStore::default());
+    let store_perf = Arc::new(PerformanceMetricsStorageAdapter::new(store.clone()));
+    let array = build(store_perf.clone())?;
+    assert!(array.codecs().supports_partial_encode());
+
+    let mut elements: Vec<u16> = vec![0; 5 * 7];
+    let mut store_subset = |array: &Array<_>, subset: ArraySubset, offset: u16| {
+        let subset_elements = (0..subset.num_elements())
+            .map(|i| u16::try_from(i).unwrap() + offset)
+            .collect::<Vec<_>>();
+        for (index, element) in subset.indices().iter().zip(&subset_elements) {
+            elements[usize::try_from(index[0] * 7 + index[1]).unwrap()] = *element;
+        }
+        array.store_array_subset_elements::<u16>(&subset, &subset_elements)
+    };
+
+    // Chunks that do not exist are written in their entirety
+    store_subset(&array, ArraySubset::new_with_ranges(&[0..5, 0..7]), 1)?;
+
+    // Subsets of existing chunks are written without reading the chunks
+    store_perf.reset();
+    store_subset(&array, ArraySubset::new_with_ranges(&[1..3, 2..6]), 100)?;
+    store_subset(&array, ArraySubset::new_with_ranges(&[3..5, 5..7]), 200)?;
+    store_subset(&array, ArraySubset::new_with_ranges(&[4..5, 0..7]), 300)?;
+    store_subset(&array, ArraySubset::new_with_ranges(&[0..5, 3..4]), 400)?;
+    assert_eq!(store_perf.bytes_read(), 0);
+
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?,
+        elements
+    );
+
+    // The encoded chunks match those written without partial encoding
+    let store_expected = Arc::new(MemoryStore::default());
+    let array_expected = build(Arc::new(PerformanceMetricsStorageAdapter::new(
+        store_expected.clone(),
+    )))?;
+    array_expected.store_array_subset_elements::<u16>(&array.subset_all(), &elements)?;
+    let chunks = ArraySubset::new_with_shape(array.chunk_grid_shape().unwrap());
+    for chunk_indices in &chunks.indices() {
+        let key = array.chunk_key(&chunk_indices);
+        assert_eq!(store.get(&key)?, store_expected.get(&key)?);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn array_partial_encode_bytes_native() {
+    array_partial_encode_bytes(&[], Endianness::native()).unwrap();
+}
+
+#[test]
+fn array_partial_encode_bytes_little() {
+    array_partial_encode_bytes(&[], Endianness::Little).unwrap();
+}
+
+#[test]
+fn array_partial_encode_bytes_big() {
+    array_partial_encode_bytes(&[], Endianness::Big).unwrap();
+}
+
+#[test]
+fn array_partial_encode_bytes_transpose() {
+    for endianness in [Endianness::Little, Endianness::Big] {
+        array_partial_encode_bytes(
+            &[Arc::new(TransposeCodec::new(
+                TransposeOrder::new(&[1, 0]).unwrap(),
+            ))],
+            endianness,
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn array_partial_encode_bytes_unsupported() {
+    let array = ArrayBuilder::new(
+        vec![5, 7],
+        DataType::String,
+        vec![3, 4].try_into().unwrap(),
+        FillValue::from(""),
+    )
+    .build(Arc::new(MemoryStore::default()), "/")
+    .unwrap();
+    assert!(!array.codecs().supports_partial_encode());
+}
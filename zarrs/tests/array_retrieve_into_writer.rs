@@ -0,0 +1,173 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        chunk_grid::RectangularChunkGrid, Array, ArrayBuilder, ChunkGrid, DataType, FillValue,
+    },
+    array_subset::ArraySubset,
+    storage::store::MemoryStore,
+};
+
+/// A writer that records the size of the largest write.
+#[derive(Default)]
+struct RecordingWriter {
+    bytes: Vec<u8>,
+    max_write: usize,
+}
+
+impl std::io::Write for RecordingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.max_write = self.max_write.max(buf.len());
+        self.bytes.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn array(
+    shape: Vec<u64>,
+    chunk_grid: ChunkGrid,
+) -> Result<Array<MemoryStore>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(shape, DataType::UInt16, chunk_grid, FillValue::from(7u16))
+        .build(store, "/")?;
+    let elements: Vec<u16> = (0..u16::try_from(array.subset_all().num_elements())?).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+    Ok(array)
+}
+
+fn assert_writer_matches(
+    array: &Array<MemoryStore>,
+    array_subset: &ArraySubset,
+    memory_budget: usize,
+    row_size: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut writer = RecordingWriter::default();
+    array.retrieve_array_subset_into_writer(array_subset, &mut writer, memory_budget)?;
+    assert_eq!(
+        writer.bytes,
+        array
+            .retrieve_array_subset(array_subset)?
+            .into_fixed()?
+            .as_ref()
+    );
+    assert!(writer.max_write <= memory_budget.max(row_size));
+    Ok(())
+}
+
+#[test]
+fn array_retrieve_array_subset_into_writer() -> Result<(), Box<dyn std::error::Error>> {
+    let array = array(vec![10, 9], vec![4, 3].try_into()?)?;
+    for memory_budget in [0, 1, 17, 40, 100, 1 << 20] {
+        // Aligned and unaligned subsets
+        assert_writer_matches(&array, &array.subset_all(), memory_budget, 18)?;
+        assert_writer_matches(
+            &array,
+            &ArraySubset::new_with_ranges(&[1..9, 2..7]),
+            memory_budget,
+            10,
+        )?;
+        assert_writer_matches(
+            &array,
+            &ArraySubset::new_with_ranges(&[3..4, 0..9]),
+            memory_budget,
+            18,
+        )?;
+        // Out of bounds elements are the fill value
+        assert_writer_matches(
+            &array,
+            &ArraySubset::new_with_ranges(&[8..13, 7..10]),
+            memory_budget,
+            6,
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn array_retrieve_array_subset_into_writer_3d() -> Result<(), Box<dyn std::error::Error>> {
+    let array = array(vec![6, 5, 7], vec![4, 2, 3].try_into()?)?;
+    let array_subset = ArraySubset::new_with_ranges(&[1..6, 1..4, 2..7]);
+    for memory_budget in [0, 30, 90, 200, 1 << 20] {
+        assert_writer_matches(&array, &array_subset, memory_budget, 30)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn array_retrieve_array_subset_into_writer_rectangular() -> Result<(), Box<dyn std::error::Error>> {
+    let chunk_grid = ChunkGrid::new(RectangularChunkGrid::new(&[
+        [1, 4, 2, 5].try_into()?,
+        4.try_into()?,
+    ]));
+    let array = array(vec![12, 8], chunk_grid)?;
+    let array_subset = ArraySubset::new_with_ranges(&[0..12, 1..7]);
+    for memory_budget in [0, 24, 60, 1 << 20] {
+        assert_writer_matches(&array, &array_subset, memory_budget, 12)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn array_retrieve_array_subset_into_writer_invalid() -> Result<(), Box<dyn std::error::Error>> {
+    let array = array(vec![4, 4], vec![2, 2].try_into()?)?;
+    let mut writer = RecordingWriter::default();
+    assert!(array
+        .retrieve_array_subset_into_writer(&ArraySubset::new_with_shape(vec![2]), &mut writer, 0)
+        .is_err());
+
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::String,
+        vec![2, 2].try_into()?,
+        FillValue::from(""),
+    )
+    .build(store, "/")?;
+    assert!(array
+        .retrieve_array_subset_into_writer(&array.subset_all(), &mut writer, 0)
+        .is_err());
+    assert!(writer.bytes.is_empty());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_retrieve_array_subset_into_writer_async() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = ArrayBuilder::new(
+        vec![10, 9],
+        DataType::UInt16,
+        vec![4, 3].try_into()?,
+        FillValue::from(7u16),
+    )
+    .build(store, "/")?;
+    let elements: Vec<u16> = (0..90).collect();
+    array
+        .async_store_array_subset_elements(&array.subset_all(), &elements)
+        .await?;
+
+    let array_subset = ArraySubset::new_with_ranges(&[1..12, 2..7]);
+    for memory_budget in [0, 25, 1 << 20] {
+        let mut writer = Vec::new();
+        array
+            .async_retrieve_array_subset_into_writer(&array_subset, &mut writer, memory_budget)
+            .await?;
+        assert_eq!(
+            writer,
+            array
+                .async_retrieve_array_subset(&array_subset)
+                .await?
+                .into_fixed()?
+                .as_ref()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{codec::CodecOptions, ArrayBuilder, DataType, FillValue},
+    array_subset::ArraySubset,
+    storage::store::MemoryStore,
+};
+
+#[test]
+fn array_retrieve_array_subset_optional() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 8],
+        DataType::UInt8,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u8),
+    )
+    .build(store, "/")?;
+
+    // Chunk [0, 0] is stored with the fill value, chunk [0, 1] is missing
+    let options = CodecOptions::builder().store_empty_chunks(true).build();
+    array.store_chunk_elements_opt::<u8>(&[0, 0], &[0; 16], &options)?;
+    assert!(array.chunk_exists(&[0, 0])?);
+    assert!(!array.chunk_exists(&[0, 1])?);
+
+    let subset = ArraySubset::new_with_ranges(&[1..3, 2..7]);
+    let (bytes, mask) = array.retrieve_array_subset_optional(&subset)?;
+    assert_eq!(bytes.into_fixed()?.as_ref(), [0; 10]);
+    assert_eq!(
+        mask,
+        [
+            true, true, false, false, false, //
+            true, true, false, false, false,
+        ]
+    );
+
+    // Entirely within the stored chunk
+    let subset = ArraySubset::new_with_ranges(&[0..4, 0..4]);
+    let (_, mask) = array.retrieve_array_subset_optional(&subset)?;
+    assert_eq!(mask, [true; 16]);
+
+    // Entirely within the missing chunk
+    let subset = ArraySubset::new_with_ranges(&[2..4, 4..8]);
+    let (_, mask) = array.retrieve_array_subset_optional(&subset)?;
+    assert_eq!(mask, [false; 8]);
+
+    // Incompatible dimensionality
+    assert!(array
+        .retrieve_array_subset_optional(&ArraySubset::new_with_shape(vec![4]))
+        .is_err());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_async_retrieve_array_subset_optional() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = ArrayBuilder::new(
+        vec![8, 4],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store, "/")?;
+    array
+        .async_store_chunk_elements::<u16>(&[1, 0], &[5; 16])
+        .await?;
+
+    let subset = ArraySubset::new_with_ranges(&[3..6, 1..3]);
+    let (bytes, mask) = array.async_retrieve_array_subset_optional(&subset).await?;
+    assert_eq!(
+        bytes.into_fixed()?.as_ref(),
+        bytemuck::cast_slice::<u16, u8>(&[0, 0, 5, 5, 5, 5])
+    );
+    assert_eq!(mask, [false, false, true, true, true, true]);
+    Ok(())
+}
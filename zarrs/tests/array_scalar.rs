@@ -0,0 +1,159 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, ArrayBuilder, ArrayError, ChunkShape, DataType, FillValue},
+    array_subset::ArraySubset,
+    metadata::ZarrVersion,
+    storage::{store::MemoryStore, ListableStorageTraits, StoreKey},
+};
+
+/// Create a zero-dimensional (scalar) array builder.
+fn scalar_array_builder() -> ArrayBuilder {
+    let chunk_shape: ChunkShape = Vec::<u64>::new().try_into().unwrap();
+    ArrayBuilder::new(
+        vec![],
+        DataType::Float64,
+        chunk_shape.into(),
+        FillValue::from(0.0f64),
+    )
+}
+
+fn scalar_round_trip(
+    builder: &ArrayBuilder,
+    chunk_key: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = builder.build(store.clone(), "/array")?;
+    array.store_metadata()?;
+    assert_eq!(array.dimensionality(), 0);
+    assert_eq!(array.chunk_grid_shape(), Some(vec![]));
+    let chunk_key = StoreKey::new(format!("array/{chunk_key}"))?;
+    assert_eq!(array.chunk_key(&[]), chunk_key);
+    let subset = array.subset_all();
+    assert_eq!(subset, ArraySubset::new_with_ranges(&[]));
+    assert_eq!(subset.num_elements(), 1);
+
+    // The fill value is retrieved before the chunk is stored
+    assert_eq!(array.retrieve_array_subset_elements::<f64>(&subset)?, [0.0]);
+    assert!(array.stored_chunks()?.is_empty());
+
+    array.store_array_subset_elements::<f64>(&subset, &[1.5])?;
+    assert!(store.list()?.contains(&chunk_key));
+    assert_eq!(array.stored_chunks()?, [Vec::<u64>::new()]);
+    assert_eq!(array.retrieve_array_subset_elements::<f64>(&subset)?, [1.5]);
+    assert_eq!(array.retrieve_chunk_elements::<f64>(&[])?, [1.5]);
+    assert_eq!(
+        array.retrieve_chunk_subset_elements::<f64>(&[], &subset)?,
+        [1.5]
+    );
+
+    array.store_chunk_elements::<f64>(&[], &[2.5])?;
+    assert_eq!(
+        Array::open(store.clone(), "/array")?.retrieve_array_subset_elements::<f64>(&subset)?,
+        [2.5]
+    );
+
+    // Storing the fill value erases the chunk
+    array.store_array_subset_elements::<f64>(&subset, &[0.0])?;
+    assert!(array.stored_chunks()?.is_empty());
+
+    // A subset must be zero-dimensional
+    assert!(matches!(
+        array.retrieve_array_subset_elements::<f64>(&ArraySubset::new_with_shape(vec![1])),
+        Err(ArrayError::InvalidArraySubset(..))
+    ));
+    Ok(())
+}
+
+#[test]
+fn array_scalar_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    scalar_round_trip(&scalar_array_builder(), "c")?;
+    scalar_round_trip(scalar_array_builder().zarr_format(ZarrVersion::V2), "0")
+}
+
+#[test]
+fn array_scalar_round_trip_codecs() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "transpose")]
+    {
+        use zarrs::array::codec::{
+            array_to_array::transpose::TransposeOrder, ArrayToArrayCodecTraits, TransposeCodec,
+        };
+        let transpose: Arc<dyn ArrayToArrayCodecTraits> =
+            Arc::new(TransposeCodec::new(TransposeOrder::new(&[])?));
+        scalar_round_trip(
+            scalar_array_builder().array_to_array_codecs(vec![transpose]),
+            "c",
+        )?;
+    }
+    #[cfg(feature = "gzip")]
+    {
+        use zarrs::array::codec::{BytesToBytesCodecTraits, GzipCodec};
+        let gzip: Arc<dyn BytesToBytesCodecTraits> = Arc::new(GzipCodec::new(5)?);
+        scalar_round_trip(
+            scalar_array_builder().bytes_to_bytes_codecs(vec![gzip]),
+            "c",
+        )?;
+    }
+    #[cfg(feature = "sharding")]
+    {
+        use zarrs::array::codec::{
+            array_to_bytes::sharding::ShardingCodecBuilder, ArrayToBytesCodecTraits,
+        };
+        let sharding: Arc<dyn ArrayToBytesCodecTraits> =
+            Arc::new(ShardingCodecBuilder::new(Vec::<u64>::new().try_into()?).build());
+        scalar_round_trip(scalar_array_builder().array_to_bytes_codec(sharding), "c")?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn array_scalar_ndarray() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = scalar_array_builder().build(store, "/")?;
+    array.store_array_subset_ndarray::<f64, _>(&[], ndarray::arr0(1.5).into_dyn())?;
+    let ndarray = array.retrieve_array_subset_ndarray::<f64>(&array.subset_all())?;
+    assert_eq!(ndarray, ndarray::arr0(1.5).into_dyn());
+    assert_eq!(ndarray.ndim(), 0);
+    assert_eq!(
+        array.retrieve_chunk_ndarray::<f64>(&[])?,
+        ndarray::arr0(1.5).into_dyn()
+    );
+    assert_eq!(
+        array.retrieve_chunks_ndarray::<f64>(&ArraySubset::new_with_ranges(&[]))?,
+        ndarray::arr0(1.5).into_dyn()
+    );
+
+    // The ndarray must be zero-dimensional
+    assert!(array
+        .store_array_subset_ndarray::<f64, _>(&[], ndarray::arr1(&[1.5]).into_dyn())
+        .is_err());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_scalar_async() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = scalar_array_builder().build(store.clone(), "/")?;
+    array.async_store_metadata().await?;
+    array
+        .async_store_array_subset_elements::<f64>(&array.subset_all(), &[1.5])
+        .await?;
+    let array = Array::async_open(store, "/").await?;
+    assert_eq!(
+        array
+            .async_retrieve_array_subset_elements::<f64>(&array.subset_all())
+            .await?,
+        [1.5]
+    );
+    assert_eq!(
+        array.async_retrieve_chunk_elements::<f64>(&[]).await?,
+        [1.5]
+    );
+    Ok(())
+}
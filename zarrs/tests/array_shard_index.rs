@@ -0,0 +1,140 @@
+#![allow(missing_docs)]
+#![cfg(feature = "sharding")]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        codec::{
+            array_to_bytes::sharding::{ShardingCodecBuilder, ShardingIndexLocation},
+            CodecOptions, ShardingCodec,
+        },
+        Array, ArrayBuilder, ArrayShardedReadableExt, ArrayShardedReadableExtCache, DataType,
+        FillValue,
+    },
+    storage::store::MemoryStore,
+};
+
+fn sharding_codec(index_location: ShardingIndexLocation) -> ShardingCodec {
+    ShardingCodecBuilder::new(vec![2, 2].try_into().unwrap())
+        .index_location(index_location)
+        .build()
+}
+
+/// An 8x8 array with 4x4 shards and 2x2 inner chunks.
+fn sharded_array(
+    index_location: ShardingIndexLocation,
+) -> Result<Array<MemoryStore>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    )
+    .array_to_bytes_codec(Arc::new(sharding_codec(index_location)))
+    .build(store, "/")?;
+
+    // Inner chunks with an odd sum of indices are entirely the fill value and are not stored
+    let elements: Vec<u16> = (0..64u16)
+        .map(|i| {
+            let (row, col) = (i / 8, i % 8);
+            if (row / 2 + col / 2) % 2 == 0 {
+                i + 1
+            } else {
+                0
+            }
+        })
+        .collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+    array.erase_chunk(&[1, 1])?;
+    Ok(array)
+}
+
+#[test]
+fn array_shard_index() -> Result<(), Box<dyn std::error::Error>> {
+    for index_location in [ShardingIndexLocation::Start, ShardingIndexLocation::End] {
+        let array = sharded_array(index_location)?;
+        let cache = ArrayShardedReadableExtCache::new(&array);
+
+        let shard_index = array.shard_index(&[0, 1])?.unwrap();
+        assert_eq!(shard_index.chunks_per_shard(), [2, 2]);
+        assert_eq!(shard_index.num_chunks(), 4);
+        assert_eq!(shard_index.num_present(), 2);
+        assert_eq!(shard_index.num_missing(), 2);
+        assert!(shard_index.inner_chunk_byte_range(&[0, 1]).is_none());
+        assert!(shard_index.inner_chunk_byte_range(&[2, 0]).is_none());
+
+        // The byte ranges address the encoded inner chunks within the encoded shard
+        let encoded_shard = array.retrieve_encoded_chunk(&[0, 1])?.unwrap();
+        let present: Vec<_> = shard_index.iter_present().collect();
+        assert_eq!(present.len(), 2);
+        assert_eq!(present[0].0, [0, 0]);
+        assert_eq!(present[1].0, [1, 1]);
+        for (inner_chunk_indices, byte_range) in present {
+            assert_eq!(
+                shard_index.inner_chunk_byte_range(&inner_chunk_indices),
+                Some(byte_range)
+            );
+            let range = byte_range.to_range_usize(encoded_shard.len() as u64);
+            let global_inner_chunk_indices = [inner_chunk_indices[0], inner_chunk_indices[1] + 2];
+            assert_eq!(
+                Some(encoded_shard[range].to_vec()),
+                array.retrieve_encoded_inner_chunk(&cache, &global_inner_chunk_indices)?
+            );
+        }
+
+        // Decoding the index from the bytes of a shard gives the same result
+        let shard_representation = array.chunk_array_representation(&[0, 1])?;
+        assert_eq!(
+            sharding_codec(index_location).decode_index_from_bytes(
+                &encoded_shard,
+                &shard_representation,
+                &CodecOptions::default()
+            )?,
+            shard_index
+        );
+
+        // Missing shard
+        assert!(array.shard_index(&[1, 1])?.is_none());
+    }
+    Ok(())
+}
+
+#[test]
+fn array_shard_index_unsharded() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store, "/")?;
+    assert!(array.shard_index(&[0, 0]).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_shard_index_async() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    )
+    .array_to_bytes_codec(Arc::new(sharding_codec(ShardingIndexLocation::End)))
+    .build(store, "/")?;
+    array
+        .async_store_chunk_elements::<u16>(&[0, 0], &[1; 16])
+        .await?;
+
+    let shard_index = array.async_shard_index(&[0, 0]).await?.unwrap();
+    assert_eq!(shard_index.num_present(), 4);
+    assert!(array.async_shard_index(&[0, 1]).await?.is_none());
+    Ok(())
+}
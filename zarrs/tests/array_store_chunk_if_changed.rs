@@ -0,0 +1,161 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{codec::CodecOptions, Array, ArrayBuilder, DataType, FillValue},
+    storage::{
+        byte_range::ByteRange,
+        storage_adapter::performance_metrics::{
+            PerformanceMetricsStorageAdapter, StorageRequestKind,
+        },
+        store::MemoryStore,
+        Bytes, ReadableStorageTraits, StorageError, StoreKey, StoreKeyOffsetValue, StorePrefix,
+        ValueDigestTraits, WritableStorageTraits,
+    },
+};
+
+fn array<TStorage: ?Sized>(
+    store: Arc<TStorage>,
+) -> Result<Array<TStorage>, Box<dyn std::error::Error>> {
+    Ok(ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store, "/")?)
+}
+
+#[test]
+fn array_store_chunk_if_changed() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(PerformanceMetricsStorageAdapter::new(Arc::new(
+        MemoryStore::new(),
+    )));
+    let array = array(store.clone())?;
+
+    assert!(
+        array.store_chunk_if_changed(&[0, 0], bytemuck::cast_slice::<u16, u8>(&[1u16, 2, 3, 4]))?
+    );
+    assert_eq!(store.writes(), 1);
+
+    // Identical chunk
+    store.reset();
+    assert!(!array
+        .store_chunk_if_changed(&[0, 0], bytemuck::cast_slice::<u16, u8>(&[1u16, 2, 3, 4]))?);
+    assert_eq!(store.writes(), 0);
+
+    // Changed chunk, same encoded size
+    assert!(
+        array.store_chunk_if_changed(&[0, 0], bytemuck::cast_slice::<u16, u8>(&[1u16, 2, 3, 5]))?
+    );
+    assert_eq!(store.writes(), 1);
+    assert_eq!(array.retrieve_chunk_elements::<u16>(&[0, 0])?, [1, 2, 3, 5]);
+
+    // Fill value chunks are only erased if they exist
+    store.reset();
+    assert!(!array.store_chunk_if_changed(&[1, 1], &[0u8; 8])?);
+    assert!(array.store_chunk_if_changed(&[0, 0], &[0u8; 8])?);
+    assert_eq!(store.writes(), 0);
+    assert_eq!(store.keys_erased(), 1);
+    assert!(array.retrieve_encoded_chunk(&[0, 0])?.is_none());
+    Ok(())
+}
+
+/// The sum of the bytes of a value, a stand-in for a content hash such as an `ETag`.
+struct SumDigest;
+
+impl ValueDigestTraits for SumDigest {
+    fn algorithm(&self) -> &'static str {
+        "sum"
+    }
+
+    fn digest(&self, value: &[u8]) -> Vec<u8> {
+        value
+            .iter()
+            .map(|byte| u64::from(*byte))
+            .sum::<u64>()
+            .to_le_bytes()
+            .to_vec()
+    }
+}
+
+/// A store that provides the digest of stored values.
+struct DigestStore(MemoryStore);
+
+impl ReadableStorageTraits for DigestStore {
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.0.get_partial_values_key(key, byte_ranges)
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.0.size_key(key)
+    }
+
+    fn digest_key(&self, key: &StoreKey, algorithm: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        if algorithm == SumDigest.algorithm() {
+            Ok(self.0.get(key)?.map(|value| SumDigest.digest(&value)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl WritableStorageTraits for DigestStore {
+    fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        self.0.set(key, value)
+    }
+
+    fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        self.0.set_partial_values(key_offset_values)
+    }
+
+    fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.0.erase(key)
+    }
+
+    fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.0.erase_prefix(prefix)
+    }
+}
+
+#[test]
+fn array_store_chunk_if_changed_digest() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(PerformanceMetricsStorageAdapter::new(Arc::new(
+        DigestStore(MemoryStore::new()),
+    )));
+    let array = array(store.clone())?;
+    let options = CodecOptions::default();
+    array.store_chunk_elements::<u16>(&[0, 1], &[1, 2, 3, 4])?;
+
+    // The stored chunk is compared by digest and is not retrieved
+    store.reset();
+    assert!(!array.store_chunk_if_changed_opt(
+        &[0, 1],
+        bytemuck::cast_slice::<u16, u8>(&[1u16, 2, 3, 4]),
+        Some(&SumDigest),
+        &options
+    )?);
+    let requests = store.requests_by_kind();
+    assert_eq!(requests[&StorageRequestKind::DigestKey], 1);
+    assert_eq!(requests[&StorageRequestKind::Get], 0);
+    assert_eq!(requests[&StorageRequestKind::GetPartialValuesKey], 0);
+    assert_eq!(store.writes(), 0);
+
+    assert!(array.store_chunk_if_changed_opt(
+        &[0, 1],
+        bytemuck::cast_slice::<u16, u8>(&[4u16, 3, 2, 0]),
+        Some(&SumDigest),
+        &options
+    )?);
+    assert_eq!(store.writes(), 1);
+    assert_eq!(array.retrieve_chunk_elements::<u16>(&[0, 1])?, [4, 3, 2, 0]);
+    Ok(())
+}
@@ -0,0 +1,133 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, ArrayBuilder, DataType, FillValue},
+    array_subset::ArraySubset,
+    storage::{
+        storage_adapter::performance_metrics::{
+            PerformanceMetricsStorageAdapter, StorageRequestKind,
+        },
+        store::MemoryStore,
+    },
+};
+
+fn build_array<TStorage: ?Sized>(storage: Arc<TStorage>) -> Array<TStorage> {
+    ArrayBuilder::new(
+        vec![8, 6],
+        DataType::UInt16,
+        vec![4, 2].try_into().unwrap(),
+        FillValue::from(0u16),
+    )
+    .build(storage, "/array")
+    .unwrap()
+}
+
+/// Elements of chunks [0..2, 0..3] where chunk [1, 2] is entirely the fill value.
+fn elements() -> Vec<u16> {
+    (0..48u16)
+        .map(|i| if i % 6 >= 4 && i >= 24 { 0 } else { i + 1 })
+        .collect()
+}
+
+#[test]
+fn array_store_chunks_batched() -> Result<(), Box<dyn std::error::Error>> {
+    let chunks = ArraySubset::new_with_ranges(&[0..2, 0..3]);
+
+    let store_reference = Arc::new(MemoryStore::new());
+    let array_reference = build_array(store_reference.clone());
+    array_reference.store_chunks_elements::<u16>(&chunks, &elements())?;
+
+    let store = Arc::new(MemoryStore::new());
+    let store_perf = Arc::new(PerformanceMetricsStorageAdapter::new(store.clone()));
+    let array = build_array(store_perf.clone());
+    array.store_chunk_elements::<u16>(&[1, 2], &[1; 8])?;
+    store_perf.reset();
+
+    let chunks_bytes = elements()
+        .iter()
+        .flat_map(|element| element.to_ne_bytes())
+        .collect::<Vec<u8>>();
+    array.store_chunks_batched(&chunks, chunks_bytes)?;
+
+    // One batched write and one batched erase of the fill value chunk
+    let requests = store_perf.requests_by_kind();
+    assert_eq!(requests[&StorageRequestKind::SetMany], 1);
+    assert_eq!(requests[&StorageRequestKind::Set], 0);
+    assert_eq!(requests[&StorageRequestKind::EraseValues], 1);
+    assert_eq!(requests[&StorageRequestKind::Erase], 0);
+    assert_eq!(store_perf.writes(), 5);
+    assert_eq!(store_perf.keys_erased(), 1);
+
+    // Equivalent to per-chunk writes
+    let snapshot_reference = store_reference.snapshot();
+    let snapshot = store.snapshot();
+    assert_eq!(snapshot.keys(), snapshot_reference.keys());
+    for key in &snapshot.keys() {
+        assert_eq!(snapshot.get(key), snapshot_reference.get(key));
+    }
+    assert_eq!(array.retrieve_chunks_elements::<u16>(&chunks)?, elements());
+    Ok(())
+}
+
+#[test]
+fn array_store_encoded_chunks_batched() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let store_perf = Arc::new(PerformanceMetricsStorageAdapter::new(store));
+    let array = build_array(store_perf.clone());
+
+    let encoded_chunks: Vec<(Vec<u64>, bytes::Bytes)> = (0..3u16)
+        .map(|i| {
+            let chunk_bytes = [i + 1; 8]
+                .iter()
+                .flat_map(|element| element.to_ne_bytes())
+                .collect::<Vec<u8>>();
+            (vec![0, u64::from(i)], chunk_bytes.into())
+        })
+        .collect();
+    unsafe { array.store_encoded_chunks_batched(&encoded_chunks) }?;
+    assert_eq!(
+        store_perf.requests_by_kind()[&StorageRequestKind::SetMany],
+        1
+    );
+    assert_eq!(array.retrieve_chunk_elements::<u16>(&[0, 2])?, [3; 8]);
+    assert_eq!(array.retrieve_chunk_elements::<u16>(&[1, 0])?, [0; 8]);
+
+    // An empty batch does not reach the store
+    unsafe { array.store_encoded_chunks_batched(&[]) }?;
+    assert_eq!(
+        store_perf.requests_by_kind()[&StorageRequestKind::SetMany],
+        1
+    );
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_store_chunks_batched_async() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let store_perf = Arc::new(PerformanceMetricsStorageAdapter::new(store));
+    let array = build_array(store_perf.clone());
+
+    let chunks = ArraySubset::new_with_ranges(&[0..2, 0..3]);
+    let chunks_bytes = elements()
+        .iter()
+        .flat_map(|element| element.to_ne_bytes())
+        .collect::<Vec<u8>>();
+    array
+        .async_store_chunks_batched(&chunks, chunks_bytes)
+        .await?;
+
+    let requests = store_perf.requests_by_kind();
+    assert_eq!(requests[&StorageRequestKind::SetMany], 1);
+    assert_eq!(requests[&StorageRequestKind::Set], 0);
+    assert_eq!(store_perf.writes(), 5);
+    assert_eq!(
+        array.async_retrieve_chunks_elements::<u16>(&chunks).await?,
+        elements()
+    );
+    Ok(())
+}
@@ -0,0 +1,141 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{codec::CodecOptions, ArrayBuilder, DataType, FillValue},
+    array_subset::ArraySubset,
+    storage::{store::MemoryStore, ReadableStorageTraits},
+};
+
+#[test]
+fn array_store_empty_chunks_array_override() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let mut array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(7u16),
+    )
+    .build(store.clone(), "/")?;
+    assert_eq!(array.store_empty_chunks(), None);
+
+    // Chunks that are entirely the fill value are not stored by default
+    array.store_chunk_elements::<u16>(&[0, 0], &[7; 4])?;
+    assert!(store.get(&array.chunk_key(&[0, 0]))?.is_none());
+
+    array.set_store_empty_chunks(Some(true));
+    assert_eq!(array.store_empty_chunks(), Some(true));
+    array.store_chunk_elements::<u16>(&[0, 0], &[7; 4])?;
+    array.store_chunk_subset_elements::<u16>(
+        &[0, 1],
+        &ArraySubset::new_with_ranges(&[0..1, 0..2]),
+        &[7; 2],
+    )?;
+    array.store_array_subset_elements::<u16>(
+        &ArraySubset::new_with_ranges(&[2..4, 0..4]),
+        &[7; 8],
+    )?;
+    for chunk_indices in [[0, 0], [0, 1], [1, 0], [1, 1]] {
+        assert!(store.get(&array.chunk_key(&chunk_indices))?.is_some());
+        assert_eq!(
+            array.retrieve_chunk_elements::<u16>(&chunk_indices)?,
+            [7; 4]
+        );
+    }
+
+    // Explicit codec options take precedence over the array override
+    let options = CodecOptions::builder().store_empty_chunks(false).build();
+    array.store_chunk_elements_opt::<u16>(&[0, 0], &[7; 4], &options)?;
+    assert!(store.get(&array.chunk_key(&[0, 0]))?.is_none());
+
+    array.set_store_empty_chunks(Some(false));
+    array.store_chunk_elements::<u16>(&[0, 1], &[7; 4])?;
+    assert!(store.get(&array.chunk_key(&[0, 1]))?.is_none());
+    Ok(())
+}
+
+#[test]
+fn array_store_empty_chunks_per_call() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store.clone(), "/")?;
+    let options = CodecOptions::builder().store_empty_chunks(true).build();
+
+    array.store_chunk_elements_opt::<u16>(&[0, 0], &[0; 4], &options)?;
+    array.store_chunk_subset_elements_opt::<u16>(
+        &[0, 1],
+        &ArraySubset::new_with_ranges(&[1..2, 1..2]),
+        &[0],
+        &options,
+    )?;
+    array.store_array_subset_elements_opt::<u16>(
+        &ArraySubset::new_with_ranges(&[2..4, 0..2]),
+        &[0; 4],
+        &options,
+    )?;
+    for chunk_indices in [[0, 0], [0, 1], [1, 0]] {
+        assert!(store.get(&array.chunk_key(&chunk_indices))?.is_some());
+        assert_eq!(
+            array.retrieve_chunk_elements::<u16>(&chunk_indices)?,
+            [0; 4]
+        );
+    }
+    assert!(store.get(&array.chunk_key(&[1, 1]))?.is_none());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_store_empty_chunks_async() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::storage::AsyncReadableStorageTraits;
+
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let mut array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    )
+    .build(store.clone(), "/")?;
+
+    array
+        .async_store_chunk_elements::<u16>(&[0, 0], &[0; 4])
+        .await?;
+    assert!(store.get(&array.chunk_key(&[0, 0])).await?.is_none());
+
+    array.set_store_empty_chunks(Some(true));
+    array
+        .async_store_chunk_elements::<u16>(&[0, 0], &[0; 4])
+        .await?;
+    array
+        .async_store_chunk_subset_elements::<u16>(
+            &[0, 1],
+            &ArraySubset::new_with_ranges(&[0..1, 0..1]),
+            &[0],
+        )
+        .await?;
+    array
+        .async_store_array_subset_elements::<u16>(
+            &ArraySubset::new_with_ranges(&[2..4, 0..2]),
+            &[0; 4],
+        )
+        .await?;
+    for chunk_indices in [[0, 0], [0, 1], [1, 0]] {
+        assert!(store.get(&array.chunk_key(&chunk_indices)).await?.is_some());
+        assert_eq!(
+            array
+                .async_retrieve_chunk_elements::<u16>(&chunk_indices)
+                .await?,
+            [0; 4]
+        );
+    }
+    Ok(())
+}
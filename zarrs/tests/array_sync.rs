@@ -317,6 +317,180 @@ fn array_str_sync_sharded_transpose() -> Result<(), Box<dyn std::error::Error>>
     array_str_impl(array)
 }
 
+#[test]
+fn array_str_sync_fill_value() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::String,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from("-"),
+    )
+    .build(store, "/array")?;
+
+    // A chunk entirely composed of the fill value is not stored
+    array.store_chunk_elements(&[0, 0], &["-", "-", "-", "-"])?;
+    assert!(!array.chunk_exists(&[0, 0])?);
+
+    // A chunk with the same bytes as the fill value, but different elements, is stored
+    array.store_chunk_elements(&[0, 0], &["--", "", "-", "-"])?;
+    assert!(array.chunk_exists(&[0, 0])?);
+    assert_eq!(
+        array.retrieve_chunk_elements::<String>(&[0, 0])?,
+        ["--", "", "-", "-"]
+    );
+
+    // A chunk is erased when an array subset update sets it entirely to the fill value
+    array.store_array_subset_elements(
+        &ArraySubset::new_with_ranges(&[0..1, 0..3]),
+        &["a", "b", "c"],
+    )?;
+    assert!(array.chunk_exists(&[0, 1])?);
+    array.store_array_subset_elements(
+        &ArraySubset::new_with_ranges(&[0..2, 0..3]),
+        &["-", "-", "-", "-", "-", "-"],
+    )?;
+    assert!(!array.chunk_exists(&[0, 0])?);
+    assert!(!array.chunk_exists(&[0, 1])?);
+
+    Ok(())
+}
+
+#[test]
+fn array_str_sync_vlen_utf8_elements() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::array::{codec::array_to_bytes::vlen_utf8::VlenUtf8Codec, ArrayBytes};
+
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![2, 4], // array shape
+        DataType::String,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from("fill"),
+    )
+    .array_to_bytes_codec(Arc::new(VlenUtf8Codec::new()))
+    .build(store, "/array")?;
+
+    // Empty strings and multi-byte UTF-8
+    array.store_chunk_elements_string(&[0, 0], &["", "é", "日本語", "🦀"])?;
+    assert_eq!(
+        array.retrieve_chunk_elements_string(&[0, 0])?,
+        ["", "é", "日本語", "🦀"]
+    );
+
+    // A chunk entirely composed of the fill value
+    assert!(!array.chunk_exists(&[0, 1])?);
+    assert_eq!(
+        array.retrieve_chunk_elements_string(&[0, 1])?,
+        ["fill", "fill", "fill", "fill"]
+    );
+    assert_eq!(
+        array
+            .retrieve_array_subset_elements_string(&ArraySubset::new_with_ranges(&[1..2, 1..3]))?,
+        ["🦀", "fill"]
+    );
+
+    // Owned strings
+    array.store_array_subset_elements_string(
+        &ArraySubset::new_with_ranges(&[0..1, 2..4]),
+        &["ß".to_string(), String::new()],
+    )?;
+    assert!(array.chunk_exists(&[0, 1])?);
+    assert_eq!(
+        array.retrieve_array_subset_ndarray_string(&array.subset_all())?,
+        ndarray::array![["", "é", "ß", ""], ["日本語", "🦀", "fill", "fill"]]
+            .mapv(String::from)
+            .into_dyn()
+    );
+
+    // Invalid UTF-8
+    array.store_chunk(
+        &[0, 0],
+        ArrayBytes::new_vlen(vec![0xff], vec![0, 1, 1, 1, 1]),
+    )?;
+    assert!(array.retrieve_chunk_elements_string(&[0, 0]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn array_option_nan_fill_value() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![2, 4], // array shape
+        DataType::Float32,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(f32::NAN),
+    )
+    .build(store, "/array")?;
+
+    // A chunk entirely composed of missing elements is not stored
+    array.store_chunk_elements::<Option<f32>>(&[0, 1], &[None, None, None, None])?;
+    assert!(!array.chunk_exists(&[0, 1])?);
+
+    array.store_array_subset_elements(
+        &ArraySubset::new_with_ranges(&[0..2, 0..2]),
+        &[Some(1.0f32), None, Some(-0.0), None],
+    )?;
+    assert!(array.chunk_exists(&[0, 0])?);
+    assert_eq!(
+        array.retrieve_array_subset_elements::<Option<f32>>(&array.subset_all())?,
+        [Some(1.0), None, None, None, Some(-0.0), None, None, None]
+    );
+    assert!(array
+        .retrieve_chunk_elements::<f32>(&[0, 0])?
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .all(|element| element.is_nan()));
+    assert_eq!(
+        array.retrieve_array_subset_ndarray::<Option<f32>>(&ArraySubset::new_with_ranges(&[
+            0..2,
+            0..1
+        ]))?,
+        ndarray::array![[Some(1.0)], [Some(-0.0)]].into_dyn()
+    );
+
+    // A chunk is erased when all of its elements are set to missing
+    array.store_chunk_elements::<Option<f32>>(&[0, 0], &[None, None, None, None])?;
+    assert!(!array.chunk_exists(&[0, 0])?);
+
+    Ok(())
+}
+
+#[test]
+fn array_option_sentinel_fill_value() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![4], // array shape
+        DataType::Int16,
+        vec![2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(-9999i16),
+    )
+    .build(store, "/array")?;
+
+    let elements = [Some(0i16), None, None, None];
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+    assert!(array.chunk_exists(&[0])?);
+    assert!(!array.chunk_exists(&[1])?);
+    assert_eq!(
+        array.retrieve_array_subset_elements::<Option<i16>>(&array.subset_all())?,
+        elements
+    );
+    assert_eq!(
+        array.retrieve_array_subset_elements::<i16>(&array.subset_all())?,
+        [0, -9999, -9999, -9999]
+    );
+
+    // Storing the sentinel directly is indistinguishable from a missing element
+    array.store_chunk_elements::<i16>(&[1], &[-9999, 1])?;
+    assert_eq!(
+        array.retrieve_chunk_elements::<Option<i16>>(&[1])?,
+        [None, Some(1)]
+    );
+
+    Ok(())
+}
+
 #[rustfmt::skip]
 #[test]
 fn array_binary() -> Result<(), Box<dyn std::error::Error>> {
@@ -377,3 +551,270 @@ fn array_binary() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[rustfmt::skip]
+#[test]
+fn array_sync_resize_grow() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let mut array = ArrayBuilder::new(
+        vec![4, 4], // array shape
+        DataType::UInt8,
+        vec![3, 3].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .build(store.clone(), "/array")?;
+    array.store_metadata()?;
+
+    // The out-of-bounds elements of edge chunks hold data
+    array.store_chunk_elements::<u8>(&[0, 1], &[1; 9])?;
+    array.store_chunk_elements::<u8>(&[1, 1], &[2; 9])?;
+
+    array.resize(vec![6, 5], false)?;
+    assert_eq!(array.shape(), &[6, 5]);
+    assert_eq!(Array::open(store, "/array")?.shape(), &[6, 5]);
+
+    // Previously out-of-bounds elements read as the fill value
+    assert_eq!(
+        array.retrieve_array_subset_ndarray::<u8>(&array.subset_all())?,
+        ndarray::array![
+            [0, 0, 0, 1, 0],
+            [0, 0, 0, 1, 0],
+            [0, 0, 0, 1, 0],
+            [0, 0, 0, 2, 0],
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0],
+        ].into_dyn()
+    );
+    assert_eq!(array.retrieve_chunk_elements::<u8>(&[1, 1])?, vec![2, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // Invalid resizes
+    assert!(array.resize(vec![6], false).is_err());
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[test]
+fn array_sync_resize_shrink() -> Result<(), Box<dyn std::error::Error>> {
+    for erase_out_of_bounds in [false, true] {
+        let store = std::sync::Arc::new(MemoryStore::default());
+        let mut array = ArrayBuilder::new(
+            vec![6, 6], // array shape
+            DataType::UInt8,
+            vec![2, 2].try_into().unwrap(), // regular chunk shape
+            FillValue::from(0u8),
+        )
+        .build(store.clone(), "/array")?;
+        array.store_metadata()?;
+        array.store_array_subset_elements::<u8>(&array.subset_all(), &[1; 36])?;
+
+        array.resize(vec![3, 3], erase_out_of_bounds)?;
+        assert_eq!(array.shape(), &[3, 3]);
+        assert_eq!(Array::open(store, "/array")?.shape(), &[3, 3]);
+
+        // Stale data beyond the new bounds is not retrieved
+        assert_eq!(
+            array.retrieve_array_subset_ndarray::<u8>(&ArraySubset::new_with_ranges(&[0..4, 0..4]))?,
+            ndarray::array![
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [1, 1, 1, 0],
+                [0, 0, 0, 0],
+            ].into_dyn()
+        );
+        assert_eq!(array.retrieve_chunk_elements::<u8>(&[1, 1])?, vec![1, 0, 0, 0]);
+
+        // Chunks entirely outside of the new bounds
+        for chunk_indices in [[0, 2], [1, 2], [2, 0], [2, 1], [2, 2]] {
+            assert_eq!(
+                array.retrieve_chunk_if_exists(&chunk_indices)?.is_some(),
+                !erase_out_of_bounds
+            );
+        }
+
+        if erase_out_of_bounds {
+            // Growing again only exposes the fill value
+            array.resize(vec![6, 6], false)?;
+            assert_eq!(
+                array.retrieve_array_subset_elements::<u8>(&ArraySubset::new_with_ranges(&[0..6, 3..6]))?,
+                vec![0; 18]
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[rustfmt::skip]
+#[test]
+fn array_sync_append() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let mut array = ArrayBuilder::new(
+        vec![0, 3], // array shape
+        DataType::UInt16,
+        vec![2, 2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u16),
+    )
+    .build(store.clone(), "/array")?;
+    array.store_metadata()?;
+
+    // Append along the first (non-final) axis
+    array.append_elements::<u16>(0, &[1, 2, 3])?;
+    array.append_ndarray::<u16, _>(0, ndarray::array![[4, 5, 6], [7, 8, 9]])?;
+    assert_eq!(array.shape(), &[3, 3]);
+
+    // Append along the final axis
+    array.append_elements::<u16>(1, &[10, 11, 12])?;
+    assert_eq!(array.shape(), &[3, 4]);
+    assert_eq!(Array::open(store, "/array")?.shape(), &[3, 4]);
+    assert_eq!(
+        array.retrieve_array_subset_ndarray::<u16>(&array.subset_all())?,
+        ndarray::array![
+            [1, 2, 3, 10],
+            [4, 5, 6, 11],
+            [7, 8, 9, 12],
+        ].into_dyn()
+    );
+
+    // Invalid appends
+    assert!(array.append_elements::<u16>(0, &[1, 2, 3]).is_err());
+    assert!(array.append_elements::<u16>(2, &[1, 2, 3, 4]).is_err());
+    assert!(array.append_ndarray::<u16, _>(0, ndarray::array![[1, 2], [3, 4]]).is_err());
+    assert_eq!(array.shape(), &[3, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn array_sync_stored_chunks() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::array::chunk_key_encoding::{
+        ChunkKeyEncoding, DefaultChunkKeyEncoding, V2ChunkKeyEncoding,
+    };
+
+    let chunk_key_encodings: [ChunkKeyEncoding; 4] = [
+        DefaultChunkKeyEncoding::new_slash().into(),
+        DefaultChunkKeyEncoding::new_dot().into(),
+        V2ChunkKeyEncoding::new_slash().into(),
+        V2ChunkKeyEncoding::new_dot().into(),
+    ];
+    for chunk_key_encoding in chunk_key_encodings {
+        for path in ["/", "/group/array"] {
+            let store = std::sync::Arc::new(MemoryStore::default());
+            let array = ArrayBuilder::new(
+                vec![8, 8, 8], // array shape
+                DataType::UInt8,
+                vec![2, 2, 2].try_into().unwrap(), // regular chunk shape
+                FillValue::from(0u8),
+            )
+            .chunk_key_encoding(chunk_key_encoding.clone())
+            .attributes(serde_json::Map::from_iter([("key".into(), "value".into())]))
+            .build(store.clone(), path)?;
+            array.store_metadata()?;
+            assert!(array.stored_chunks()?.is_empty());
+
+            let chunks = [vec![0, 0, 1], vec![1, 3, 0], vec![3, 3, 3], vec![2, 0, 2]];
+            for chunk_indices in &chunks {
+                array.store_chunk_elements::<u8>(chunk_indices, &[1; 8])?;
+            }
+            let mut chunks_sorted = chunks.to_vec();
+            chunks_sorted.sort();
+            assert_eq!(array.stored_chunks()?, chunks_sorted);
+
+            assert!(array.chunk_exists(&[1, 3, 0])?);
+            assert!(!array.chunk_exists(&[0, 0, 0])?);
+            assert!(array.chunk_exists(&[0, 0]).is_err());
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn array_sync_stored_chunks_scalar() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::array::chunk_key_encoding::V2ChunkKeyEncoding;
+
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![], // array shape
+        DataType::UInt8,
+        Vec::<u64>::new().try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u8),
+    )
+    .chunk_key_encoding(V2ChunkKeyEncoding::new_dot().into())
+    .build(store, "/")?;
+    array.store_metadata()?;
+    assert!(array.stored_chunks()?.is_empty());
+    array.store_chunk_elements::<u8>(&[], &[1])?;
+    assert_eq!(array.stored_chunks()?, vec![Vec::<u64>::new()]);
+    assert!(array.chunk_exists(&[])?);
+
+    Ok(())
+}
+
+#[test]
+fn array_sync_retrieve_array_subset_into() -> Result<(), Box<dyn std::error::Error>> {
+    let store = std::sync::Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![8, 8], // array shape
+        DataType::UInt16,
+        vec![3, 3].try_into().unwrap(), // regular chunk shape
+        FillValue::from(0u16),
+    )
+    .build(store.clone(), "/array")?;
+    array.store_array_subset_elements::<u16>(
+        &ArraySubset::new_with_ranges(&[0..7, 1..8]),
+        &(0..49).collect::<Vec<u16>>(),
+    )?;
+
+    for subset in [
+        array.subset_all(),                          // all chunks, some empty
+        ArraySubset::new_with_ranges(&[1..7, 2..5]), // multiple chunks
+        ArraySubset::new_with_ranges(&[3..5, 4..6]), // single chunk
+        ArraySubset::new_with_ranges(&[3..6, 3..6]), // single whole chunk
+        ArraySubset::new_with_ranges(&[0..0, 0..8]), // empty
+    ] {
+        let expected = array
+            .retrieve_array_subset(&subset)?
+            .into_fixed()?
+            .into_owned();
+        let mut output = vec![u8::MAX; expected.len()];
+        array.retrieve_array_subset_into(&subset, &mut output)?;
+        assert_eq!(output, expected);
+
+        let mut elements = vec![u16::MAX; subset.num_elements_usize()];
+        array.retrieve_array_subset_elements_into(&subset, &mut elements)?;
+        assert_eq!(
+            elements,
+            array.retrieve_array_subset_elements::<u16>(&subset)?
+        );
+    }
+
+    // Invalid output length, element type, or subset
+    let subset = ArraySubset::new_with_ranges(&[1..7, 2..5]);
+    assert!(array
+        .retrieve_array_subset_into(&subset, &mut [0u8; 35])
+        .is_err());
+    assert!(array
+        .retrieve_array_subset_elements_into(&subset, &mut [0u8; 36])
+        .is_err());
+    assert!(array
+        .retrieve_array_subset_into(
+            &ArraySubset::new_with_ranges(&[0..9, 0..1, 0..1]),
+            &mut [0u8; 18]
+        )
+        .is_err());
+
+    // Variable sized data types are unsupported
+    let array = ArrayBuilder::new(
+        vec![4], // array shape
+        DataType::String,
+        vec![2].try_into().unwrap(), // regular chunk shape
+        FillValue::from(""),
+    )
+    .build(store, "/string")?;
+    assert!(array
+        .retrieve_array_subset_into(&array.subset_all(), &mut [])
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+#![allow(missing_docs)]
+#![cfg(all(feature = "crc32c", feature = "fletcher32"))]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        codec::{
+            bytes_to_bytes::fletcher32::Fletcher32Codec, BytesToBytesCodecTraits, CodecOptions,
+            Crc32cCodec,
+        },
+        Array, ArrayBuilder, DataType, FillValue,
+    },
+    array_subset::ArraySubset,
+    storage::{store::MemoryStore, WritableStorageTraits},
+};
+
+/// A 4x4 array with one chunk that has a corrupt checksum.
+fn array_corrupt_checksum(
+    codec: Arc<dyn BytesToBytesCodecTraits>,
+) -> Result<Array<MemoryStore>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    )
+    .bytes_to_bytes_codecs(vec![codec])
+    .build(store.clone(), "/")?;
+    let elements: Vec<u16> = (0..16).collect();
+    array.store_chunk_elements(&[0, 0], &elements)?;
+
+    let mut encoded_chunk = array.retrieve_encoded_chunk(&[0, 0])?.unwrap();
+    *encoded_chunk.last_mut().unwrap() ^= 0xFF;
+    store.set(&array.chunk_key(&[0, 0]), encoded_chunk.into())?;
+    Ok(array)
+}
+
+#[test]
+fn array_validate_checksums() -> Result<(), Box<dyn std::error::Error>> {
+    let codecs: [Arc<dyn BytesToBytesCodecTraits>; 2] =
+        [Arc::new(Crc32cCodec), Arc::new(Fletcher32Codec)];
+    let validate = CodecOptions::builder().validate_checksums(true).build();
+    let skip = CodecOptions::builder().validate_checksums(false).build();
+    let chunk_subset = ArraySubset::new_with_ranges(&[1..3, 1..3]);
+    for codec in codecs {
+        let array = array_corrupt_checksum(codec)?;
+
+        // Full decoding
+        assert!(array.retrieve_chunk_opt(&[0, 0], &validate).is_err());
+        assert_eq!(
+            array.retrieve_chunk_elements_opt::<u16>(&[0, 0], &skip)?,
+            (0..16).collect::<Vec<_>>()
+        );
+
+        // Partial decoding
+        assert!(array
+            .retrieve_chunk_subset_opt(&[0, 0], &chunk_subset, &validate)
+            .is_err());
+        assert_eq!(
+            array.retrieve_chunk_subset_elements_opt::<u16>(&[0, 0], &chunk_subset, &skip)?,
+            [5, 6, 9, 10]
+        );
+        let partial_decoder = array.partial_decoder(&[0, 0])?;
+        assert!(partial_decoder
+            .partial_decode(std::slice::from_ref(&chunk_subset), &validate)
+            .is_err());
+        assert!(partial_decoder
+            .partial_decode(std::slice::from_ref(&chunk_subset), &skip)
+            .is_ok());
+    }
+    Ok(())
+}
+
+#[test]
+fn array_validate_checksums_array_override() -> Result<(), Box<dyn std::error::Error>> {
+    let mut array = array_corrupt_checksum(Arc::new(Crc32cCodec))?;
+    let chunk_subset = ArraySubset::new_with_ranges(&[0..2, 0..4]);
+    assert_eq!(array.validate_checksums(), None);
+    assert!(array.retrieve_chunk(&[0, 0]).is_err());
+    assert!(array.retrieve_chunk_subset(&[0, 0], &chunk_subset).is_err());
+
+    array.set_validate_checksums(Some(false));
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?,
+        (0..16).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        array.retrieve_chunk_subset_elements::<u16>(&[0, 0], &chunk_subset)?,
+        (0..8).collect::<Vec<_>>()
+    );
+
+    // Explicit codec options take precedence
+    assert!(array
+        .retrieve_chunk_opt(&[0, 0], &CodecOptions::default())
+        .is_err());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn array_validate_checksums_async() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::storage::AsyncWritableStorageTraits;
+
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt16,
+        vec![4, 4].try_into()?,
+        FillValue::from(0u16),
+    )
+    .bytes_to_bytes_codecs(vec![Arc::new(Crc32cCodec)])
+    .build(store.clone(), "/")?;
+    let elements: Vec<u16> = (0..16).collect();
+    array.async_store_chunk_elements(&[0, 0], &elements).await?;
+    let mut encoded_chunk = array
+        .async_retrieve_encoded_chunk(&[0, 0])
+        .await?
+        .unwrap()
+        .to_vec();
+    *encoded_chunk.last_mut().unwrap() ^= 0xFF;
+    store
+        .set(&array.chunk_key(&[0, 0]), encoded_chunk.into())
+        .await?;
+
+    let validate = CodecOptions::builder().validate_checksums(true).build();
+    let skip = CodecOptions::builder().validate_checksums(false).build();
+    let chunk_subset = ArraySubset::new_with_ranges(&[1..3, 1..3]);
+    assert!(array
+        .async_retrieve_chunk_subset_opt(&[0, 0], &chunk_subset, &validate)
+        .await
+        .is_err());
+    assert_eq!(
+        array
+            .async_retrieve_chunk_subset_elements_opt::<u16>(&[0, 0], &chunk_subset, &skip)
+            .await?,
+        [5, 6, 9, 10]
+    );
+    Ok(())
+}
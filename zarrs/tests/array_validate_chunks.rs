@@ -0,0 +1,91 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{ArrayBuilder, ChunkValidation, DataType, FillValue},
+    array_subset::ArraySubset,
+    storage::store::MemoryStore,
+};
+use zarrs_storage::{ReadableStorageTraits, WritableStorageTraits};
+
+#[cfg(feature = "crc32c")]
+#[test]
+fn array_validate_chunks_checksum() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::array::codec::Crc32cCodec;
+
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt8,
+        vec![4, 4].try_into().unwrap(),
+        FillValue::from(0u8),
+    )
+    .bytes_to_bytes_codecs(vec![Arc::new(Crc32cCodec::new())])
+    .build(store.clone(), "/")?;
+    let elements = (0..64).collect::<Vec<u8>>();
+    array.store_array_subset_elements::<u8>(
+        &ArraySubset::new_with_ranges(&[0..8, 0..4]),
+        &elements[..32],
+    )?;
+
+    // Corrupt one byte of one chunk
+    let key = array.chunk_key(&[1, 0]);
+    let mut chunk = store.get(&key)?.unwrap().to_vec();
+    chunk[3] ^= 0xFF;
+    store.set(&key, chunk.into())?;
+
+    for parallel in [false, true] {
+        let validation = array.validate_all_chunks(parallel)?;
+        assert_eq!(validation.len(), 4);
+        for (chunk_indices, chunk_validation) in validation {
+            match chunk_indices.as_slice() {
+                [0, 0] => assert!(matches!(chunk_validation, ChunkValidation::Ok)),
+                [1, 0] => assert!(matches!(
+                    chunk_validation,
+                    ChunkValidation::ChecksumMismatch { ref expected, ref actual } if expected != actual
+                )),
+                _ => assert!(matches!(chunk_validation, ChunkValidation::Missing)),
+            }
+        }
+    }
+    assert!(array.validate_chunk(&[0, 0])?.is_valid());
+    assert!(!array.validate_chunk(&[1, 0])?.is_valid());
+    assert!(array.validate_chunk(&[0]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn array_validate_chunks_decode() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt8,
+        vec![4, 4].try_into().unwrap(),
+        FillValue::from(0u8),
+    )
+    .build(store.clone(), "/")?;
+    array.store_chunk_elements::<u8>(&[0, 0], &[1; 16])?;
+    array.store_chunk_elements::<u8>(&[1, 1], &[2; 16])?;
+
+    // Truncate one chunk
+    let key = array.chunk_key(&[1, 1]);
+    let chunk = store.get(&key)?.unwrap();
+    store.set(&key, chunk.slice(..8))?;
+
+    assert!(matches!(
+        array.validate_chunk(&[0, 0])?,
+        ChunkValidation::Ok
+    ));
+    assert!(matches!(
+        array.validate_chunk(&[0, 1])?,
+        ChunkValidation::Missing
+    ));
+    assert!(matches!(
+        array.validate_chunk(&[1, 1])?,
+        ChunkValidation::DecodeError(_)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,249 @@
+#![allow(missing_docs)]
+#![cfg(feature = "conventions")]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{ArrayBuilder, DataType, FillValue},
+    conventions::{
+        cf::DescriptiveAttributes,
+        ome::{self, Axis, CoordinateTransformation, Multiscale, Ome},
+        xarray, AttributeError,
+    },
+    group::{Group, GroupBuilder},
+    storage::store::MemoryStore,
+};
+
+/// OME-Zarr 0.4 multiscales metadata.
+///
+/// Adapted from <https://ngff.openmicroscopy.org/0.4/#multiscale-md>.
+const OME_ZARR_0_4: &str = r#"{
+    "multiscales": [
+        {
+            "version": "0.4",
+            "name": "example",
+            "axes": [
+                {"name": "t", "type": "time", "unit": "millisecond"},
+                {"name": "c", "type": "channel"},
+                {"name": "z", "type": "space", "unit": "micrometer"},
+                {"name": "y", "type": "space", "unit": "micrometer"},
+                {"name": "x", "type": "space", "unit": "micrometer"}
+            ],
+            "datasets": [
+                {
+                    "path": "0",
+                    "coordinateTransformations": [{"type": "scale", "scale": [1.0, 1.0, 0.5, 0.5, 0.5]}]
+                },
+                {
+                    "path": "1",
+                    "coordinateTransformations": [{"type": "scale", "scale": [1.0, 1.0, 1.0, 1.0, 1.0]}]
+                },
+                {
+                    "path": "2",
+                    "coordinateTransformations": [{"type": "scale", "scale": [1.0, 1.0, 2.0, 2.0, 2.0]}]
+                }
+            ],
+            "coordinateTransformations": [{"type": "scale", "scale": [0.1, 1.0, 1.0, 1.0, 1.0]}],
+            "type": "gaussian",
+            "metadata": {
+                "description": "the fields in metadata depend on the downscaling implementation",
+                "method": "skimage.transform.pyramid_gaussian"
+            }
+        }
+    ]
+}"#;
+
+/// OME-Zarr 0.5 multiscales metadata.
+///
+/// Adapted from <https://ngff.openmicroscopy.org/0.5/#multiscale-md>.
+const OME_ZARR_0_5: &str = r#"{
+    "ome": {
+        "version": "0.5",
+        "multiscales": [
+            {
+                "name": "example",
+                "axes": [
+                    {"name": "z", "type": "space", "unit": "micrometer"},
+                    {"name": "y", "type": "space", "unit": "micrometer"},
+                    {"name": "x", "type": "space", "unit": "micrometer"}
+                ],
+                "datasets": [
+                    {
+                        "path": "0",
+                        "coordinateTransformations": [
+                            {"type": "scale", "scale": [0.5, 0.5, 0.5]},
+                            {"type": "translation", "translation": [0.0, 0.0, 0.0]}
+                        ]
+                    },
+                    {
+                        "path": "1",
+                        "coordinateTransformations": [
+                            {"type": "scale", "scale": [1.0, 1.0, 1.0]},
+                            {"type": "translation", "translation": [0.25, 0.25, 0.25]}
+                        ]
+                    }
+                ],
+                "coordinateTransformations": [{"type": "identity"}]
+            }
+        ]
+    }
+}"#;
+
+fn group_with_attributes(
+    attributes: &str,
+) -> Result<Group<MemoryStore>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let mut group = GroupBuilder::new().build(store, "/")?;
+    *group.attributes_mut() = serde_json::from_str(attributes)?;
+    Ok(group)
+}
+
+#[test]
+fn attribute_conventions_ome_zarr_0_4() -> Result<(), Box<dyn std::error::Error>> {
+    let group = group_with_attributes(OME_ZARR_0_4)?;
+    let multiscales = ome::multiscales(group.attributes())?.unwrap();
+    assert_eq!(multiscales.len(), 1);
+    let multiscale = &multiscales[0];
+    assert_eq!(multiscale.version.as_deref(), Some("0.4"));
+    assert_eq!(multiscale.name.as_deref(), Some("example"));
+    assert_eq!(multiscale.downscaling_type.as_deref(), Some("gaussian"));
+    assert_eq!(
+        multiscale.axes[0],
+        Axis {
+            name: "t".to_string(),
+            axis_type: Some("time".to_string()),
+            unit: Some("millisecond".to_string()),
+        }
+    );
+    assert_eq!(multiscale.axes[1].unit, None);
+    let paths: Vec<&str> = multiscale
+        .datasets
+        .iter()
+        .map(|dataset| dataset.path.as_str())
+        .collect();
+    assert_eq!(paths, ["0", "1", "2"]);
+    assert_eq!(
+        multiscale.datasets[2].coordinate_transformations,
+        [CoordinateTransformation::Scale {
+            scale: vec![1.0, 1.0, 2.0, 2.0, 2.0]
+        }]
+    );
+    assert_eq!(
+        group.attribute::<Vec<Multiscale>>(ome::MULTISCALES)?,
+        Some(multiscales.clone())
+    );
+
+    // Round trip
+    let mut round_trip = group_with_attributes("{}")?;
+    ome::set_multiscales(round_trip.attributes_mut(), &multiscales, None)?;
+    assert_eq!(
+        round_trip.attributes(),
+        &serde_json::from_str::<serde_json::Map<_, _>>(OME_ZARR_0_4)?
+    );
+    Ok(())
+}
+
+#[test]
+fn attribute_conventions_ome_zarr_0_5() -> Result<(), Box<dyn std::error::Error>> {
+    let group = group_with_attributes(OME_ZARR_0_5)?;
+    let ome_attribute = group.attribute::<Ome>(ome::OME)?.unwrap();
+    assert_eq!(ome_attribute.version, "0.5");
+    let multiscales = ome::multiscales(group.attributes())?.unwrap();
+    assert_eq!(multiscales, ome_attribute.multiscales);
+    let multiscale = &multiscales[0];
+    assert_eq!(multiscale.version, None);
+    assert_eq!(multiscale.axes.len(), 3);
+    assert_eq!(
+        multiscale.datasets[1].coordinate_transformations,
+        [
+            CoordinateTransformation::Scale {
+                scale: vec![1.0, 1.0, 1.0]
+            },
+            CoordinateTransformation::Translation {
+                translation: vec![0.25, 0.25, 0.25]
+            }
+        ]
+    );
+    assert_eq!(
+        multiscale.coordinate_transformations,
+        Some(vec![CoordinateTransformation::Identity])
+    );
+
+    // Round trip
+    let mut round_trip = group_with_attributes("{}")?;
+    ome::set_multiscales(round_trip.attributes_mut(), &multiscales, Some("0.5"))?;
+    assert_eq!(
+        round_trip.attributes(),
+        &serde_json::from_str::<serde_json::Map<_, _>>(OME_ZARR_0_5)?
+    );
+    Ok(())
+}
+
+#[test]
+fn attribute_conventions_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let group = group_with_attributes(
+        r#"{"multiscales": [{"axes": [{"name": "y"}, {"name": 1}], "datasets": []}]}"#,
+    )?;
+    let err = ome::multiscales(group.attributes()).unwrap_err();
+    assert!(matches!(
+        &err,
+        AttributeError::Deserialize { path, .. } if path == "multiscales[0].axes[1].name"
+    ));
+    assert!(err
+        .to_string()
+        .starts_with("invalid attribute at multiscales[0].axes[1].name: invalid type"));
+
+    let group = group_with_attributes(r#"{"units": 1}"#)?;
+    let err = group.attributes_as::<DescriptiveAttributes>().unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid attribute at units: invalid type"));
+
+    let group = group_with_attributes(r#"{"_ARRAY_DIMENSIONS": "x"}"#)?;
+    let err = xarray::array_dimensions(group.attributes()).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid attribute at _ARRAY_DIMENSIONS: invalid type"));
+    Ok(())
+}
+
+#[test]
+fn attribute_conventions_array() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let mut array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::Float32,
+        vec![2, 2].try_into()?,
+        FillValue::from(0.0f32),
+    )
+    .build(store.clone(), "/array")?;
+    assert_eq!(array.attribute::<String>("units")?, None);
+    assert_eq!(
+        array.attributes_as::<DescriptiveAttributes>()?,
+        DescriptiveAttributes::default()
+    );
+
+    array.set_attribute("units", "m s-1")?;
+    array.set_attribute("long_name", "wind speed")?;
+    xarray::set_array_dimensions(array.attributes_mut(), &["y", "x"])?;
+    array.store_metadata()?;
+
+    let array = zarrs::array::Array::open(store, "/array")?;
+    assert_eq!(
+        array.attributes_as::<DescriptiveAttributes>()?,
+        DescriptiveAttributes {
+            units: Some("m s-1".to_string()),
+            long_name: Some("wind speed".to_string()),
+        }
+    );
+    assert_eq!(
+        xarray::array_dimensions(array.attributes())?,
+        Some(vec!["y".to_string(), "x".to_string()])
+    );
+    assert_eq!(
+        array.attribute::<Vec<String>>(xarray::ARRAY_DIMENSIONS)?,
+        Some(vec!["y".to_string(), "x".to_string()])
+    );
+    assert!(array.attribute::<u64>("units").is_err());
+    Ok(())
+}
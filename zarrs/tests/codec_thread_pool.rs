@@ -0,0 +1,182 @@
+#![allow(missing_docs)]
+#![cfg(feature = "crc32c")]
+
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use zarrs::{
+    array::{
+        codec::{
+            BytesPartialDecoderTraits, BytesPartialEncoderTraits, BytesToBytesCodecTraits,
+            CodecError, CodecOptions, CodecTraits, Crc32cCodec,
+        },
+        ArrayBuilder, ArrayMetadataOptions, BytesRepresentation, DataType, FillValue, RawBytes,
+        RecommendedConcurrency,
+    },
+    config::global_config_mut,
+    metadata::v3::MetadataV3,
+    storage::store::MemoryStore,
+};
+
+#[cfg(feature = "async")]
+use zarrs::array::codec::AsyncBytesPartialDecoderTraits;
+
+/// A bytes to bytes codec that records the names of the threads that encode and decode with it.
+#[derive(Debug)]
+struct ThreadRecordingCodec {
+    inner: Arc<dyn BytesToBytesCodecTraits>,
+    threads: Arc<Mutex<HashSet<Option<String>>>>,
+}
+
+impl ThreadRecordingCodec {
+    fn record_thread(&self) {
+        let name = std::thread::current().name().map(ToString::to_string);
+        self.threads.lock().unwrap().insert(name);
+    }
+}
+
+impl CodecTraits for ThreadRecordingCodec {
+    fn create_metadata_opt(&self, options: &ArrayMetadataOptions) -> Option<MetadataV3> {
+        self.inner.create_metadata_opt(options)
+    }
+
+    fn partial_decoder_should_cache_input(&self) -> bool {
+        self.inner.partial_decoder_should_cache_input()
+    }
+
+    fn partial_decoder_decodes_all(&self) -> bool {
+        self.inner.partial_decoder_decodes_all()
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl BytesToBytesCodecTraits for ThreadRecordingCodec {
+    fn dynamic(self: Arc<Self>) -> Arc<dyn BytesToBytesCodecTraits> {
+        self as Arc<dyn BytesToBytesCodecTraits>
+    }
+
+    fn recommended_concurrency(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> Result<RecommendedConcurrency, CodecError> {
+        self.inner.recommended_concurrency(decoded_representation)
+    }
+
+    fn compute_encoded_size(
+        &self,
+        decoded_representation: &BytesRepresentation,
+    ) -> BytesRepresentation {
+        self.inner.compute_encoded_size(decoded_representation)
+    }
+
+    fn encode<'a>(
+        &self,
+        decoded_value: RawBytes<'a>,
+        options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        self.record_thread();
+        self.inner.encode(decoded_value, options)
+    }
+
+    fn decode<'a>(
+        &self,
+        encoded_value: RawBytes<'a>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<RawBytes<'a>, CodecError> {
+        self.record_thread();
+        self.inner
+            .decode(encoded_value, decoded_representation, options)
+    }
+
+    fn partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialDecoderTraits>, CodecError> {
+        self.inner
+            .clone()
+            .partial_decoder(input_handle, decoded_representation, options)
+    }
+
+    fn partial_encoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn BytesPartialDecoderTraits>,
+        output_handle: Arc<dyn BytesPartialEncoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn BytesPartialEncoderTraits>, CodecError> {
+        self.inner.clone().partial_encoder(
+            input_handle,
+            output_handle,
+            decoded_representation,
+            options,
+        )
+    }
+
+    #[cfg(feature = "async")]
+    async fn async_partial_decoder(
+        self: Arc<Self>,
+        input_handle: Arc<dyn AsyncBytesPartialDecoderTraits>,
+        decoded_representation: &BytesRepresentation,
+        options: &CodecOptions,
+    ) -> Result<Arc<dyn AsyncBytesPartialDecoderTraits>, CodecError> {
+        self.inner
+            .clone()
+            .async_partial_decoder(input_handle, decoded_representation, options)
+            .await
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn codec_thread_pool() -> Result<(), Box<dyn std::error::Error>> {
+    let threads = Arc::new(Mutex::new(HashSet::new()));
+    let codec = Arc::new(ThreadRecordingCodec {
+        inner: Arc::new(Crc32cCodec::new()),
+        threads: threads.clone(),
+    });
+    let array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt16,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u16),
+    )
+    .bytes_to_bytes_codecs(vec![codec])
+    .build(Arc::new(MemoryStore::new()), "/")?;
+
+    let thread_pool = Arc::new(
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .thread_name(|index| format!("codec-thread-pool-{index}"))
+            .build()?,
+    );
+    let pool_threads = HashSet::from([Some("codec-thread-pool-0".to_string())]);
+
+    // Per-call thread pool
+    let options = CodecOptions::builder()
+        .thread_pool(Some(thread_pool.clone()))
+        .build();
+    let elements: Vec<u16> = (1..=64).collect();
+    array.store_array_subset_elements_opt(&array.subset_all(), &elements, &options)?;
+    assert_eq!(*threads.lock().unwrap(), pool_threads);
+    threads.lock().unwrap().clear();
+    assert_eq!(
+        array.retrieve_array_subset_elements_opt::<u16>(&array.subset_all(), &options)?,
+        elements
+    );
+    assert_eq!(*threads.lock().unwrap(), pool_threads);
+    threads.lock().unwrap().clear();
+
+    // Global thread pool
+    global_config_mut().set_codec_thread_pool(Some(thread_pool));
+    let retrieved = array.retrieve_array_subset_elements::<u16>(&array.subset_all());
+    global_config_mut().set_codec_thread_pool(None);
+    assert_eq!(retrieved?, elements);
+    assert_eq!(*threads.lock().unwrap(), pool_threads);
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+import zarr
+import numpy as np
+
+# zarr<3 is required
+print(zarr.__version__)
+
+path_out = "tests/data/v2/consolidated.zarr"
+root = zarr.open_group(path_out, mode="w")
+root.attrs["title"] = "consolidated"
+
+group = root.create_group("group")
+group.attrs["description"] = "a nested group"
+group.create_group("subgroup")
+
+array = group.create_dataset("array", dtype="<u2", shape=(4, 4), chunks=(2, 2), compressor=None, fill_value=0)
+array[:] = np.arange(16, dtype="<u2").reshape(4, 4)
+array.attrs["units"] = "m"
+
+# No chunks are written, so the array is entirely the fill value
+root.create_dataset("array", dtype="<i4", shape=(3,), chunks=(3,), compressor=None, fill_value=7)
+
+zarr.consolidate_metadata(path_out)
+print(zarr.open_consolidated(path_out).tree())
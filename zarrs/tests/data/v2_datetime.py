@@ -0,0 +1,35 @@
+import zarr
+import numpy as np
+
+for name, dtype, fill_value, data in [
+    (
+        "datetime64_ns",
+        "<M8[ns]",
+        np.datetime64("NaT"),
+        [
+            "1970-01-01T00:00:00",
+            "2000-01-01T00:00:00",
+            "NaT",
+            "2025-01-10T12:34:56.789",
+            "1969-12-31T23:59:59.999999999",
+            "2262-04-11T23:47:16.854775807",
+        ],
+    ),
+    (
+        "timedelta64_10us",
+        "<m8[10us]",
+        np.timedelta64(0, "10us"),
+        [0, 100000, -1, "NaT", 8640000000, 42],
+    ),
+]:
+    array = zarr.create_array(
+        f"tests/data/v2/array_{name}.zarr",
+        overwrite=True,
+        zarr_format=2,
+        shape=[6],
+        chunks=[3],
+        dtype=dtype,
+        fill_value=fill_value,
+        compressors=None,
+    )
+    array[...] = np.array(data, dtype=dtype)
@@ -1,10 +1,15 @@
 import zarr
 import numpy as np
-from numcodecs import Blosc, GZip, BZ2, ZFPY, PCodec, Zstd
+from numcodecs import Blosc, GZip, BZ2, CRC32, Adler32, JenkinsLookup3, LZ4, LZMA, ZFPY, PCodec, Zstd
 
 compressor_blosc = Blosc(cname="zstd", clevel=1, shuffle=Blosc.BITSHUFFLE)
 compressor_gzip = GZip(level=9)
 compressor_bz2 = BZ2(level=9)
+compressor_crc32 = CRC32(location="start")
+compressor_adler32 = Adler32(location="start")
+compressor_jenkins_lookup3 = JenkinsLookup3(initval=0)
+compressor_lz4 = LZ4(acceleration=1)
+compressor_lzma = LZMA()
 serializer_zfpy = ZFPY(mode = 4, tolerance=0.01) # fixed accuracy
 serializer_pcodec = PCodec(level = 8, mode_spec="auto")
 compressor_zstd = Zstd(level=5, checksum=False)
@@ -30,6 +35,11 @@ for order in ["C", "F"]:
         ("blosc", compressor_blosc),
         ("gzip", compressor_gzip),
         ("bz2", compressor_bz2),
+        ("crc32", compressor_crc32),
+        ("adler32", compressor_adler32),
+        ("jenkins_lookup3", compressor_jenkins_lookup3),
+        ("lz4", compressor_lz4),
+        ("lzma", compressor_lzma),
         ("zstd", compressor_zstd),
     ]:
         if order == "F" and compressor is not None and compressor_name != "blosc":
@@ -0,0 +1,30 @@
+import zarr
+import numpy as np
+
+for name, dtype, fill_value, data in [
+    (
+        "structured",
+        [("x", "<f4"), ("y", "<f4"), ("id", "<u4")],
+        (-1.0, -1.0, 0),
+        [(0.5, 1.5, 1), (2.5, 3.5, 2)],
+    ),
+    (
+        "structured_big",
+        [("a", ">i2"), ("flag", "|b1"), ("b", ">f8")],
+        (7, True, 0.5),
+        [(-2, False, 1.25), (300, True, -3.0)],
+    ),
+]:
+    dtype = np.dtype(dtype)
+    array = zarr.create_array(
+        f"tests/data/v2/array_{name}.zarr",
+        overwrite=True,
+        zarr_format=2,
+        shape=[4],
+        chunks=[2],
+        dtype=dtype,
+        fill_value=np.array(fill_value, dtype=dtype),
+        compressors=None,
+    )
+    # Only the first chunk is written, the second chunk is the fill value
+    array[:2] = np.array(data, dtype=dtype)
@@ -0,0 +1,21 @@
+import numpy as np
+import xarray as xr
+
+dataset = xr.Dataset(
+    {
+        "temperature": (
+            ("y", "x"),
+            np.arange(8, dtype="<f4").reshape(2, 4),
+            {"units": "K"},
+        )
+    },
+    coords={"x": np.array([0.0, 0.5, 1.0, 1.5], dtype="<f8")},
+    attrs={"title": "xarray"},
+)
+dataset.to_zarr(
+    "tests/data/v2/xarray.zarr",
+    mode="w",
+    zarr_format=2,
+    consolidated=True,
+    encoding={name: {"compressor": None} for name in ["temperature", "x"]},
+)
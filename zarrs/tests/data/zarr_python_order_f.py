@@ -0,0 +1,17 @@
+import zarr
+import numpy as np
+
+# zarr<3 is required, zarr 3.0 writes F order Zarr V2 chunks in C order (https://github.com/zarr-developers/zarr-python/issues/2675)
+print(zarr.__version__)
+
+# Edge chunks are padded with the fill value
+path_out = "tests/data/zarr_python_compat/order_f.zarr"
+array = zarr.open(path_out, mode="w", dtype="<u2", shape=(7, 5), chunks=(3, 2), compressor=None, fill_value=0, order="F")
+array[:] = np.arange(1, 36, dtype="<u2").reshape(7, 5)
+print(array.info)
+
+# The vlen-utf8 filter encodes the elements of each chunk in F order
+path_out = "tests/data/zarr_python_compat/order_f_vlen_utf8.zarr"
+array = zarr.open(path_out, mode="w", dtype=str, shape=(5, 3), chunks=(2, 2), compressor=None, fill_value="", order="F")
+array[:] = np.array([[f"r{i}c{j}" + "é" * i for j in range(3)] for i in range(5)], dtype=object)
+print(array.info)
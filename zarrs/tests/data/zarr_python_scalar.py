@@ -0,0 +1,15 @@
+import zarr
+import numpy as np
+
+# The single chunk of a zero-dimensional array has the key "0" in Zarr V2 and "c" in Zarr V3
+print(zarr.__version__)
+
+path_out = "tests/data/zarr_python_compat/scalar_v2.zarr"
+array = zarr.open(path_out, mode="w", dtype="<i4", shape=(), chunks=(), compressor=None, fill_value=0, zarr_format=2)
+array[...] = np.int32(42)
+print(array.info)
+
+path_out = "tests/data/zarr_python_compat/scalar_v3.zarr"
+array = zarr.create_array(path_out, overwrite=True, dtype="<i4", shape=(), chunks=(), compressors=None, fill_value=0, zarr_format=3)
+array[...] = np.int32(42)
+print(array.info)
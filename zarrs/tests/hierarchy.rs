@@ -4,10 +4,34 @@
 use std::sync::Arc;
 
 use zarrs::{
-    filesystem::FilesystemStore, group::Group, metadata::v3::group::ConsolidatedMetadata,
-    node::Node,
+    array::{Array, ArrayBuilder, DataType, FillValue},
+    filesystem::FilesystemStore,
+    group::{Group, GroupBuilder},
+    metadata::v3::group::ConsolidatedMetadata,
+    node::{Node, NodeMetadata, NodeTree},
+    storage::{
+        byte_range::ByteRange, store::MemoryStore, ReadableStorageTraits, StorageError, StoreKey,
+        WritableStorageTraits,
+    },
 };
 
+/// A store that is readable but not listable, like a HTTP store.
+struct ReadableOnlyStore(Arc<MemoryStore>);
+
+impl ReadableStorageTraits for ReadableOnlyStore {
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<bytes::Bytes>>, StorageError> {
+        self.0.get_partial_values_key(key, byte_ranges)
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.0.size_key(key)
+    }
+}
+
 #[test]
 fn hierarchy_tree() {
     let store = Arc::new(
@@ -65,3 +89,380 @@ fn consolidated_metadata() {
         assert_eq!(consolidated, actual.metadata());
     }
 }
+
+#[test]
+fn hierarchy_readable_only() -> Result<(), Box<dyn std::error::Error>> {
+    let memory_store = Arc::new(MemoryStore::default());
+    let mut root = GroupBuilder::new().build(memory_store.clone(), "/")?;
+    root.attributes_mut().insert(
+        "ome".to_string(),
+        serde_json::json!({
+            "version": "0.5",
+            "multiscales": [{
+                "datasets": [{"path": "0"}, {"path": "1"}, {"path": "missing"}]
+            }]
+        }),
+    );
+    root.store_metadata()?;
+    for (path, shape) in [("/0", vec![8, 8]), ("/1", vec![4, 4])] {
+        ArrayBuilder::new(
+            shape,
+            DataType::UInt8,
+            vec![4, 4].try_into()?,
+            FillValue::from(0u8),
+        )
+        .build(memory_store.clone(), path)?
+        .store_metadata()?;
+    }
+    GroupBuilder::new()
+        .build(memory_store.clone(), "/labels")?
+        .store_metadata()?;
+    memory_store.set(
+        &StoreKey::new("labels/v2/.zarray")?,
+        br#"{"zarr_format":2,"shape":[4],"chunks":[2],"dtype":"|u1","compressor":null,"fill_value":0,"order":"C","filters":null}"#
+            .to_vec()
+            .into(),
+    )?;
+    memory_store.set(
+        &StoreKey::new("v2group/.zgroup")?,
+        br#"{"zarr_format":2}"#.to_vec().into(),
+    )?;
+
+    let store = Arc::new(ReadableOnlyStore(memory_store));
+    let root = Group::open(store.clone(), "/")?;
+
+    let children = root.children_from_attributes()?;
+    let paths: Vec<&str> = children.iter().map(|node| node.path().as_str()).collect();
+    assert_eq!(paths, ["/0", "/1"]);
+    for child in &children {
+        assert!(matches!(child.metadata(), NodeMetadata::Array(_)));
+        let array = Array::open(store.clone(), child.path().as_str())?;
+        assert_eq!(array.data_type(), &DataType::UInt8);
+    }
+    assert_eq!(Array::open(store.clone(), "/1")?.shape(), &[4, 4]);
+
+    let labels = root.child("labels")?.unwrap();
+    assert!(matches!(labels.metadata(), NodeMetadata::Group(_)));
+    assert!(matches!(
+        root.child("v2group")?.unwrap().metadata(),
+        NodeMetadata::Group(_)
+    ));
+    assert!(root.child("missing")?.is_none());
+    assert!(root.child("/0").is_err());
+
+    let labels = Group::open(store.clone(), labels.path().as_str())?;
+    let v2 = labels.child("v2")?.unwrap();
+    assert_eq!(v2.path().as_str(), "/labels/v2");
+    assert!(matches!(v2.metadata(), NodeMetadata::Array(_)));
+    assert_eq!(Array::open(store.clone(), "/labels/v2")?.shape(), &[4]);
+    assert!(labels.children_from_attributes()?.is_empty());
+
+    let children = root.children_from_names(&["labels/v2", "v2group", "missing"])?;
+    let paths: Vec<&str> = children.iter().map(|node| node.path().as_str()).collect();
+    assert_eq!(paths, ["/labels/v2", "/v2group"]);
+
+    Ok(())
+}
+
+/// A hierarchy with nested groups, Zarr V2 and V3 arrays, and nodes with missing or invalid metadata.
+fn node_tree_store() -> Result<Arc<MemoryStore>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let mut root = GroupBuilder::new().build(store.clone(), "/")?;
+    root.attributes_mut()
+        .insert("title".to_string(), "example".into());
+    root.store_metadata()?;
+    GroupBuilder::new()
+        .build(store.clone(), "/a")?
+        .store_metadata()?;
+    let mut array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::Float64,
+        vec![4, 4].try_into()?,
+        FillValue::from(0.0f64),
+    )
+    .build(store.clone(), "/a/baz")?;
+    array
+        .attributes_mut()
+        .insert("units".to_string(), "m".into());
+    array.store_metadata()?;
+    store.set(
+        &StoreKey::new("a/v2/.zarray")?,
+        br#"{"zarr_format":2,"shape":[4],"chunks":[2],"dtype":"<i4","compressor":{"id":"zlib","level":1},"fill_value":0,"order":"C","filters":null}"#
+            .to_vec()
+            .into(),
+    )?;
+    store.set(&StoreKey::new("b/zarr.json")?, b"{".to_vec().into())?;
+    GroupBuilder::new()
+        .build(store.clone(), "/c/d")?
+        .store_metadata()?;
+    Ok(store)
+}
+
+#[test]
+fn hierarchy_node_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let store = node_tree_store()?;
+    let tree = NodeTree::open(&store, "/", None)?;
+    assert_eq!(tree.children().len(), 3);
+    assert!(tree.children()[1].metadata().is_none());
+    assert!(tree.children()[1].error().is_some());
+    assert_eq!(
+        tree.to_string(),
+        "/ (group) attributes: [title]
+  a (group)
+    baz (array) shape: [8, 8], data type: float64, chunk shape: [4, 4], codecs: [bytes], attributes: [units, _zarrs]
+    v2 (array) shape: [4], data type: <i4, chunk shape: [2], codecs: [zlib]
+  b (error) error parsing metadata for b/zarr.json: EOF while parsing an object at line 1 column 1
+  c (error) Metadata is missing
+"
+    );
+
+    let tree = NodeTree::open(&store, "/", Some(1))?;
+    assert_eq!(
+        tree.to_string(),
+        "/ (group) attributes: [title]
+  a (group)
+  b (error) error parsing metadata for b/zarr.json: EOF while parsing an object at line 1 column 1
+  c (error) Metadata is missing
+"
+    );
+
+    let tree = NodeTree::open(&store, "/a/baz", None)?;
+    assert_eq!(
+        tree.to_string(),
+        "baz (array) shape: [8, 8], data type: float64, chunk shape: [4, 4], codecs: [bytes], attributes: [units, _zarrs]\n"
+    );
+
+    assert!(NodeTree::open(&store, "/b", None).is_err());
+
+    Ok(())
+}
+
+/// A hierarchy with nested Zarr V3 and V2 groups and arrays with stored chunks.
+fn child_accessors_store() -> Result<Arc<MemoryStore>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    for path in ["/", "/a"] {
+        GroupBuilder::new()
+            .build(store.clone(), path)?
+            .store_metadata()?;
+    }
+    let array = ArrayBuilder::new(
+        vec![4],
+        DataType::UInt8,
+        vec![2].try_into()?,
+        FillValue::from(0u8),
+    )
+    .build(store.clone(), "/a/baz")?;
+    array.store_metadata()?;
+    array.store_array_subset_elements::<u8>(&array.subset_all(), &[1, 2, 3, 4])?;
+    store.set(
+        &StoreKey::new("v2group/.zgroup")?,
+        br#"{"zarr_format":2}"#.to_vec().into(),
+    )?;
+    let array = ArrayBuilder::new(
+        vec![2],
+        DataType::Int32,
+        vec![2].try_into()?,
+        FillValue::from(0i32),
+    )
+    .zarr_format(zarrs::metadata::ZarrVersion::V2)
+    .build(store.clone(), "/v2group/x")?;
+    array.store_metadata()?;
+    array.store_chunk_elements::<i32>(&[0], &[5, 6])?;
+    Ok(store)
+}
+
+#[test]
+fn hierarchy_child_accessors() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::{
+        array::ArrayCreateError,
+        group::{GroupCreateError, GroupMetadata},
+    };
+
+    let store = child_accessors_store()?;
+    let root = Group::open(store.clone(), "/")?;
+    assert!(root.child_arrays(false)?.is_empty());
+    let groups = root.child_groups(false)?;
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].path().as_str(), "/a");
+    assert!(matches!(groups[1].metadata(), GroupMetadata::V2(_)));
+    let arrays = root.child_arrays(true)?;
+    let paths: Vec<&str> = arrays.iter().map(|array| array.path().as_str()).collect();
+    assert_eq!(paths, ["/a/baz", "/v2group/x"]);
+    assert_eq!(
+        arrays[0].retrieve_array_subset_elements::<u8>(&arrays[0].subset_all())?,
+        [1, 2, 3, 4]
+    );
+    assert_eq!(arrays[1].retrieve_chunk_elements::<i32>(&[0])?, [5, 6]);
+
+    // Children by name, relative to the root and to a nested group
+    let a = root.child_group("a")?;
+    assert_eq!(a.path().as_str(), "/a");
+    let baz = a.child_array("baz")?;
+    assert_eq!(baz.path().as_str(), "/a/baz");
+    assert_eq!(baz.retrieve_chunk_elements::<u8>(&[1])?, [3, 4]);
+    assert_eq!(root.child_array("a/baz")?.path().as_str(), "/a/baz");
+    let x = root.child_group("v2group")?.child_array("x")?;
+    assert_eq!(x.path().as_str(), "/v2group/x");
+    assert_eq!(x.retrieve_chunk_elements::<i32>(&[0])?, [5, 6]);
+
+    // Missing children and children of the wrong type
+    assert!(matches!(
+        root.child_array("a"),
+        Err(ArrayCreateError::MissingMetadata)
+    ));
+    assert!(matches!(
+        root.child_array("missing"),
+        Err(ArrayCreateError::MissingMetadata)
+    ));
+    assert!(matches!(
+        root.child_array("a/baz/c"),
+        Err(ArrayCreateError::MissingMetadata)
+    ));
+    assert!(matches!(
+        root.child_group("a/baz"),
+        Err(GroupCreateError::ArrayExists(path)) if path.as_str() == "/a/baz"
+    ));
+    assert!(matches!(
+        root.child_group("missing"),
+        Err(GroupCreateError::MissingMetadata)
+    ));
+
+    // Consolidated metadata is used if present
+    let mut root = root;
+    root.set_consolidated_metadata(Some(ConsolidatedMetadata {
+        metadata: Node::open(&store, "/")?.consolidate_metadata().unwrap(),
+        ..Default::default()
+    }));
+    store.erase(&StoreKey::new("a/baz/zarr.json")?)?;
+    assert!(Array::open(store.clone(), "/a/baz").is_err());
+    let baz = root.child_array("a/baz")?;
+    assert_eq!(baz.retrieve_chunk_elements::<u8>(&[0])?, [1, 2]);
+    let arrays = root.child_arrays(true)?;
+    let paths: Vec<&str> = arrays.iter().map(|array| array.path().as_str()).collect();
+    assert_eq!(paths, ["/a/baz", "/v2group/x"]);
+    assert_eq!(root.child_arrays(false)?.len(), 0);
+    assert_eq!(root.child_groups(true)?.len(), 2);
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+async fn copy_to_async_store(
+    store: &MemoryStore,
+) -> Result<Arc<zarrs_object_store::AsyncObjectStore<object_store::memory::InMemory>>, StorageError>
+{
+    use zarrs::storage::{AsyncWritableStorageTraits, ListableStorageTraits};
+
+    let async_store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    for key in store.list()? {
+        async_store.set(&key, store.get(&key)?.unwrap()).await?;
+    }
+    Ok(async_store)
+}
+
+#[cfg(feature = "async")]
+fn array_paths<TStorage: ?Sized>(arrays: &[Array<TStorage>]) -> Vec<&str> {
+    arrays.iter().map(|array| array.path().as_str()).collect()
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn hierarchy_async() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::node::{async_get_child_nodes, get_child_nodes, NodePath};
+
+    let store = Arc::new(MemoryStore::default());
+    for path in ["/", "/a", "/a/b", "/c"] {
+        GroupBuilder::new()
+            .build(store.clone(), path)?
+            .store_metadata()?;
+    }
+    for path in ["/a/b/x", "/a/y", "/c/z", "/w"] {
+        ArrayBuilder::new(
+            vec![4],
+            DataType::UInt8,
+            vec![2].try_into()?,
+            FillValue::from(0u8),
+        )
+        .build(store.clone(), path)?
+        .store_metadata()?;
+    }
+    let async_store = copy_to_async_store(&store).await?;
+
+    let node = Node::open(&store, "/")?;
+    let async_node = Node::async_open(async_store.clone(), "/").await?;
+    assert_eq!(
+        async_node.hierarchy_tree(),
+        "/\n  a\n    b\n      x [4] uint8\n    y [4] uint8\n  c\n    z [4] uint8\n  w [4] uint8\n"
+    );
+    assert_eq!(node.hierarchy_tree(), async_node.hierarchy_tree());
+    assert_eq!(
+        node.consolidate_metadata(),
+        async_node.consolidate_metadata()
+    );
+
+    let path = NodePath::new("/a")?;
+    let nodes = get_child_nodes(&store, &path)?;
+    let async_nodes = async_get_child_nodes(&async_store, &path).await?;
+    assert_eq!(nodes.len(), async_nodes.len());
+    for (node, async_node) in nodes.iter().zip(&async_nodes) {
+        assert_eq!(node.path(), async_node.path());
+        assert_eq!(node.metadata(), async_node.metadata());
+        assert_eq!(node.hierarchy_tree(), async_node.hierarchy_tree());
+    }
+
+    let group = Group::open(store.clone(), "/a")?;
+    let async_group = Group::async_open(async_store.clone(), "/a").await?;
+    for recursive in [false, true] {
+        assert_eq!(
+            group.child_paths(recursive)?,
+            async_group.async_child_paths(recursive).await?
+        );
+        assert_eq!(
+            group.child_group_paths(recursive)?,
+            async_group.async_child_group_paths(recursive).await?
+        );
+        assert_eq!(
+            group.child_array_paths(recursive)?,
+            async_group.async_child_array_paths(recursive).await?
+        );
+        assert_eq!(
+            array_paths(&group.child_arrays(recursive)?),
+            array_paths(&async_group.async_child_arrays(recursive).await?)
+        );
+    }
+
+    let store = child_accessors_store()?;
+    let async_store = copy_to_async_store(&store).await?;
+    let root = Group::async_open(async_store.clone(), "/").await?;
+    assert_eq!(
+        array_paths(&root.async_child_arrays(true).await?),
+        ["/a/baz", "/v2group/x"]
+    );
+    assert_eq!(root.async_child_groups(true).await?.len(), 2);
+    let a = root.async_child_group("a").await?;
+    assert_eq!(a.path().as_str(), "/a");
+    let baz = a.async_child_array("baz").await?;
+    assert_eq!(baz.async_retrieve_chunk_elements::<u8>(&[0]).await?, [1, 2]);
+    let x = root.async_child_array("v2group/x").await?;
+    assert_eq!(x.async_retrieve_chunk_elements::<i32>(&[0]).await?, [5, 6]);
+    assert!(root.async_child_array("a").await.is_err());
+    assert!(root.async_child_group("a/baz").await.is_err());
+
+    let store = node_tree_store()?;
+    let async_store = copy_to_async_store(&store).await?;
+    for max_depth in [None, Some(0), Some(1)] {
+        assert_eq!(
+            NodeTree::open(&store, "/", max_depth)?.to_string(),
+            NodeTree::async_open(&async_store, "/", max_depth)
+                .await?
+                .to_string()
+        );
+    }
+    assert!(NodeTree::async_open(&async_store, "/b", None)
+        .await
+        .is_err());
+
+    Ok(())
+}
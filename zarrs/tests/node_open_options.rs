@@ -0,0 +1,275 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, ArrayCreateError},
+    config::MetadataRetrieveVersion,
+    group::{Group, GroupCreateError},
+    node::{NodeOpenOptions, NodeOpenWarning},
+    storage::{store::MemoryStore, StoreKey, WritableStorageTraits},
+};
+
+const ARRAY_METADATA_V2: &str = r#"{
+    "zarr_format": 2,
+    "shape": [4],
+    "chunks": [2],
+    "dtype": "|u1",
+    "compressor": null,
+    "fill_value": 0,
+    "order": "C",
+    "filters": null
+}"#;
+
+const ARRAY_METADATA_V3: &str = r#"{
+    "zarr_format": 3,
+    "node_type": "array",
+    "shape": [4],
+    "data_type": "uint8",
+    "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [2]}},
+    "chunk_key_encoding": {"name": "default"},
+    "fill_value": 0,
+    "codecs": [{"name": "bytes"}],
+    "ext": {"must_understand": false, "key": "value"}
+}"#;
+
+fn store() -> Result<Arc<MemoryStore>, Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    store.set(&StoreKey::new("v2/.zarray")?, ARRAY_METADATA_V2.into())?;
+    store.set(&StoreKey::new("v2/.zgroup")?, r#"{"zarr_format":2}"#.into())?;
+    store.set(&StoreKey::new("v3/zarr.json")?, ARRAY_METADATA_V3.into())?;
+    store.set(
+        &StoreKey::new("group_v3/zarr.json")?,
+        r#"{"zarr_format":3,"node_type":"group","ext":{"must_understand":false}}"#.into(),
+    )?;
+    Ok(store)
+}
+
+#[test]
+fn array_open_version_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+    let store = store()?;
+    let options = NodeOpenOptions::default().with_version(MetadataRetrieveVersion::V3);
+    assert!(matches!(
+        Array::open_with_options(store.clone(), "/v2", &options),
+        Err(ArrayCreateError::MetadataVersionMismatch {
+            expected: MetadataRetrieveVersion::V3,
+            found: MetadataRetrieveVersion::V2
+        })
+    ));
+    assert!(matches!(
+        Array::open_opt(store.clone(), "/v2", &MetadataRetrieveVersion::V3),
+        Err(ArrayCreateError::MetadataVersionMismatch { .. })
+    ));
+    assert!(matches!(
+        Array::open_opt(store.clone(), "/v3", &MetadataRetrieveVersion::V2),
+        Err(ArrayCreateError::MetadataVersionMismatch {
+            expected: MetadataRetrieveVersion::V2,
+            found: MetadataRetrieveVersion::V3
+        })
+    ));
+    assert!(matches!(
+        Array::open_with_options(store.clone(), "/missing", &options),
+        Err(ArrayCreateError::MissingMetadata)
+    ));
+
+    // The default version opens either
+    let (array, warnings) =
+        Array::open_with_options(store.clone(), "/v2", &NodeOpenOptions::default())?;
+    assert_eq!(array.shape(), &[4]);
+    assert!(warnings.is_empty());
+    Ok(())
+}
+
+#[test]
+fn array_open_extensions() -> Result<(), Box<dyn std::error::Error>> {
+    let store = store()?;
+
+    // Lenient
+    let (array, warnings) =
+        Array::open_with_options(store.clone(), "/v3", &NodeOpenOptions::default())?;
+    assert_eq!(array.shape(), &[4]);
+    assert_eq!(
+        warnings,
+        [NodeOpenWarning::IgnoredAdditionalField("ext".to_string())]
+    );
+    assert!(Array::open(store.clone(), "/v3").is_ok());
+
+    // Strict
+    let options = NodeOpenOptions::default().with_strict_extensions(true);
+    let Err(ArrayCreateError::UnsupportedAdditionalFieldError(err)) =
+        Array::open_with_options(store.clone(), "/v3", &options)
+    else {
+        panic!("expected an unsupported additional field error")
+    };
+    assert_eq!(err.name(), "ext");
+    assert_eq!(err.value()["key"], "value");
+    Ok(())
+}
+
+/// Zarr V3 array metadata with the given `shape`, `chunk_grid`, `data_type`, and `fill_value` JSON.
+fn array_metadata_v3(shape: &str, chunk_grid: &str, data_type: &str, fill_value: &str) -> String {
+    format!(
+        r#"{{
+            "zarr_format": 3,
+            "node_type": "array",
+            "shape": {shape},
+            "data_type": "{data_type}",
+            "chunk_grid": {chunk_grid},
+            "chunk_key_encoding": {{"name": "default"}},
+            "fill_value": {fill_value},
+            "codecs": [{{"name": "bytes", "configuration": {{"endian": "little"}}}}]
+        }}"#
+    )
+}
+
+#[test]
+fn array_open_lenient_numeric_strings() -> Result<(), Box<dyn std::error::Error>> {
+    let regular = r#"{"name": "regular", "configuration": {"chunk_shape": [512, 1024]}}"#;
+    let regular_strings =
+        r#"{"name": "regular", "configuration": {"chunk_shape": ["512", "1024"]}}"#;
+    let rectangular_strings =
+        r#"{"name": "rectangular", "configuration": {"chunk_shape": [["512", 512], "1024"]}}"#;
+    let fixtures = [
+        (
+            array_metadata_v3(r#"["1024", "2048"]"#, regular, "uint8", "0"),
+            vec!["shape"],
+        ),
+        (
+            array_metadata_v3("[1024, 2048]", regular_strings, "uint8", "0"),
+            vec!["chunk_grid"],
+        ),
+        (
+            array_metadata_v3(r#"[1024, "2048"]"#, rectangular_strings, "int16", r#""-1""#),
+            vec!["shape", "chunk_grid", "fill_value"],
+        ),
+    ];
+    let options = NodeOpenOptions::default().with_lenient_numeric_strings(true);
+    for (i, (metadata, coerced)) in fixtures.iter().enumerate() {
+        let store = Arc::new(MemoryStore::new());
+        let key = StoreKey::new("array/zarr.json")?;
+        store.set(&key, metadata.as_bytes().to_vec().into())?;
+
+        // Strict by default
+        assert!(Array::open(store.clone(), "/array").is_err(), "{i}");
+
+        let (array, warnings) = Array::open_with_options(store.clone(), "/array", &options)?;
+        assert_eq!(array.shape(), &[1024, 2048], "{i}");
+        assert_eq!(array.chunk_shape_usize(&[1, 1])?, [512, 1024], "{i}");
+        let warnings_expected: Vec<_> = coerced
+            .iter()
+            .map(|field| NodeOpenWarning::CoercedNumericStrings(field.to_string()))
+            .collect();
+        assert_eq!(warnings, warnings_expected, "{i}");
+
+        // Numbers are written when the metadata is stored again
+        array.store_metadata()?;
+        let stored = zarrs::storage::ReadableStorageTraits::get(&*store, &key)?.unwrap();
+        let stored: serde_json::Value = serde_json::from_slice(&stored)?;
+        assert_eq!(stored["shape"], serde_json::json!([1024, 2048]), "{i}");
+        let chunk_shape = stored["chunk_grid"]["configuration"]["chunk_shape"].to_string();
+        assert!(!chunk_shape.contains('"'), "{i}");
+        assert!(stored["fill_value"].is_number(), "{i}");
+        assert!(Array::open(store.clone(), "/array").is_ok(), "{i}");
+    }
+    Ok(())
+}
+
+#[test]
+fn array_open_lenient_numeric_strings_invalid() -> Result<(), Box<dyn std::error::Error>> {
+    let options = NodeOpenOptions::default().with_lenient_numeric_strings(true);
+    let regular = r#"{"name": "regular", "configuration": {"chunk_shape": [2, 2]}}"#;
+    let store = Arc::new(MemoryStore::new());
+    for (path, metadata) in [
+        // Non-numeric strings are not coerced
+        (
+            "shape",
+            array_metadata_v3(r#"["4", "four"]"#, regular, "uint8", "0"),
+        ),
+        // Hexadecimal integer fill values are not coerced
+        (
+            "fill_value",
+            array_metadata_v3("[4, 4]", regular, "uint8", r#""0x01""#),
+        ),
+    ] {
+        store.set(
+            &StoreKey::new(format!("{path}/zarr.json"))?,
+            metadata.into_bytes().into(),
+        )?;
+        assert!(Array::open_with_options(store.clone(), path, &options).is_err());
+    }
+
+    // String fill values of non-integer data types are not coerced
+    store.set(
+        &StoreKey::new("string/zarr.json")?,
+        array_metadata_v3(
+            "[4]",
+            r#"{"name": "regular", "configuration": {"chunk_shape": [2]}}"#,
+            "string",
+            r#""1""#,
+        )
+        .replace(
+            r#"{"name": "bytes", "configuration": {"endian": "little"}}"#,
+            r#"{"name": "vlen-utf8"}"#,
+        )
+        .into_bytes()
+        .into(),
+    )?;
+    let (array, warnings) = Array::open_with_options(store.clone(), "/string", &options)?;
+    assert!(warnings.is_empty());
+    assert_eq!(array.fill_value().as_ne_bytes(), b"1");
+    Ok(())
+}
+
+#[test]
+fn group_open_options() -> Result<(), Box<dyn std::error::Error>> {
+    let store = store()?;
+
+    let options = NodeOpenOptions::default().with_version(MetadataRetrieveVersion::V3);
+    assert!(matches!(
+        Group::open_with_options(store.clone(), "/v2", &options),
+        Err(GroupCreateError::MetadataVersionMismatch {
+            expected: MetadataRetrieveVersion::V3,
+            found: MetadataRetrieveVersion::V2
+        })
+    ));
+
+    let (_group, warnings) =
+        Group::open_with_options(store.clone(), "/group_v3", &NodeOpenOptions::default())?;
+    assert_eq!(
+        warnings,
+        [NodeOpenWarning::IgnoredAdditionalField("ext".to_string())]
+    );
+
+    let mut options = NodeOpenOptions::default();
+    options
+        .set_version(MetadataRetrieveVersion::V3)
+        .set_strict_extensions(true);
+    assert!(matches!(
+        Group::open_with_options(store.clone(), "/group_v3", &options),
+        Err(GroupCreateError::UnsupportedAdditionalFieldError(_))
+    ));
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn async_open_options() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let sync_store = self::store()?;
+    for key in ["v2/.zarray", "v3/zarr.json"] {
+        let key = StoreKey::new(key)?;
+        let value = zarrs::storage::ReadableStorageTraits::get(&*sync_store, &key)?.unwrap();
+        zarrs::storage::AsyncWritableStorageTraits::set(&*store, &key, value).await?;
+    }
+
+    let options = NodeOpenOptions::default().with_version(MetadataRetrieveVersion::V3);
+    assert!(matches!(
+        Array::async_open_with_options(store.clone(), "/v2", &options).await,
+        Err(ArrayCreateError::MetadataVersionMismatch { .. })
+    ));
+    let (_array, warnings) = Array::async_open_with_options(store.clone(), "/v3", &options).await?;
+    assert_eq!(warnings.len(), 1);
+    Ok(())
+}
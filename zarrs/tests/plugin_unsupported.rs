@@ -0,0 +1,97 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{chunk_grid::ChunkGrid, codec::Codec, Array, ArrayCreateError, DataType},
+    metadata::v3::{array::data_type::DataTypeMetadataV3, MetadataV3},
+    plugin::PluginCreateError,
+    storage::store::MemoryStore,
+};
+
+fn unsupported_codec(name: &str) -> PluginCreateError {
+    Codec::from_metadata(&MetadataV3::new(name)).unwrap_err()
+}
+
+#[test]
+fn plugin_unsupported_typo() {
+    let PluginCreateError::Unsupported(err) = unsupported_codec("ztsd") else {
+        panic!("expected an unsupported plugin error")
+    };
+    assert_eq!(err.name(), "ztsd");
+    assert_eq!(err.plugin_type(), "codec");
+    assert!(err.known_name().is_none());
+    assert_eq!(err.suggestions(), ["zstd"]);
+    assert_eq!(
+        err.to_string(),
+        "codec ztsd is not supported (did you mean zstd?)"
+    );
+
+    assert_eq!(
+        unsupported_codec("gzp").to_string(),
+        "codec gzp is not supported (did you mean gzip?)"
+    );
+    assert_eq!(
+        unsupported_codec("not_a_codec").to_string(),
+        "codec not_a_codec is not supported"
+    );
+    assert_eq!(
+        ChunkGrid::from_metadata(&MetadataV3::new("reglar"))
+            .unwrap_err()
+            .to_string(),
+        "chunk grid reglar is not supported (did you mean regular?)"
+    );
+}
+
+#[test]
+fn plugin_unsupported_alias() {
+    assert_eq!(
+        unsupported_codec("endian").to_string(),
+        "codec endian is not supported (it is an alias of bytes; known aliases of bytes: endian)"
+    );
+}
+
+#[cfg(not(feature = "zfp"))]
+#[test]
+fn plugin_unsupported_feature() {
+    assert_eq!(
+        unsupported_codec("zfp").to_string(),
+        "codec zfp is not supported (enable the `zfp` feature; known aliases of zfp: zfpy, https://codec.zarrs.dev/array_to_bytes/zfp)"
+    );
+    let PluginCreateError::Unsupported(err) = unsupported_codec("zfpy") else {
+        panic!("expected an unsupported plugin error")
+    };
+    let known_name = err.known_name().unwrap();
+    assert_eq!(known_name.name(), "zfp");
+    assert_eq!(known_name.feature(), Some("zfp"));
+    assert!(err.to_string().contains("enable the `zfp` feature"));
+}
+
+#[test]
+fn plugin_unsupported_array_open() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let json = r#"{
+        "zarr_format": 3,
+        "node_type": "array",
+        "shape": [4],
+        "data_type": "uint8",
+        "chunk_grid": {"name": "regular", "configuration": {"chunk_shape": [2]}},
+        "chunk_key_encoding": {"name": "default"},
+        "fill_value": 0,
+        "codecs": [{"name": "bytes"}, {"name": "ztsd"}]
+    }"#;
+    let metadata = serde_json::from_str(json)?;
+    let err = Array::new_with_metadata(store, "/", metadata).unwrap_err();
+    assert!(matches!(err, ArrayCreateError::CodecsCreateError(_)));
+    assert!(err.to_string().contains("did you mean zstd?"));
+    Ok(())
+}
+
+#[test]
+fn data_type_unsupported_typo() {
+    let metadata: DataTypeMetadataV3 = serde_json::from_str(r#""flaot32""#).unwrap();
+    assert_eq!(
+        DataType::from_metadata(&metadata).unwrap_err().to_string(),
+        "unsupported data type flaot32 (did you mean float32?)"
+    );
+}
@@ -0,0 +1,83 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, ArrayBuilder, ArrayError, DataType, FillValue},
+    array_subset::ArraySubset,
+    storage::{ListableStorageTraits, StorageError, StoreKey},
+};
+use zarrs_filesystem::{FilesystemStoreOptions, OffsetFileLayout, OffsetFileStore};
+
+#[test]
+fn storage_offset_file_array() -> Result<(), Box<dyn std::error::Error>> {
+    let path = tempfile::TempDir::new()?;
+    let builder = ArrayBuilder::new(
+        vec![6, 8],
+        DataType::UInt16,
+        vec![3, 4].try_into()?,
+        FillValue::from(0u16),
+    );
+
+    // Compute the layout from the chunk grid of the array
+    let array = builder.build(Arc::new(zarrs::storage::store::MemoryStore::new()), "/")?;
+    let layout = OffsetFileLayout::new(
+        "array/c/",
+        '/',
+        array.chunk_grid_shape().unwrap(),
+        3 * 4 * 2,
+    );
+
+    let store = Arc::new(OffsetFileStore::create(
+        path.path(),
+        &layout,
+        FilesystemStoreOptions::default(),
+    )?);
+    let array = builder.build(store.clone(), "/array")?;
+    array.store_metadata()?;
+    array.store_chunk_elements::<u16>(&[0, 0], &[1; 12])?;
+    array.store_chunk_elements::<u16>(&[1, 1], &(0..12).collect::<Vec<_>>())?;
+    drop(array);
+    drop(store);
+
+    // Reopen from disk
+    let store = Arc::new(OffsetFileStore::open(
+        path.path(),
+        FilesystemStoreOptions::default(),
+    )?);
+    let array = Array::open(store.clone(), "/array")?;
+    assert_eq!(array.retrieve_chunk_elements::<u16>(&[0, 0])?, [1; 12]);
+    assert_eq!(
+        array.retrieve_chunk_elements::<u16>(&[1, 1])?,
+        (0..12).collect::<Vec<_>>()
+    );
+    assert_eq!(array.retrieve_chunk_elements::<u16>(&[0, 1])?, [0; 12]);
+    assert_eq!(
+        array
+            .retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[2..4, 3..5]))?,
+        [1, 0, 0, 0]
+    );
+
+    let expected: Vec<StoreKey> = vec![
+        "array/c/0/0".try_into()?,
+        "array/c/1/1".try_into()?,
+        "array/zarr.json".try_into()?,
+    ];
+    assert_eq!(store.list()?, expected);
+    assert_eq!(store.list_prefix(&"array/c/".try_into()?)?, expected[..2]);
+    assert!(path.path().join("meta/array/zarr.json").exists());
+
+    // Chunks that exceed the slot size are an error
+    let array_large = ArrayBuilder::new(
+        vec![6, 8],
+        DataType::UInt32,
+        vec![3, 4].try_into()?,
+        FillValue::from(0u32),
+    )
+    .build(store.clone(), "/array")?;
+    assert!(matches!(
+        array_large.store_chunk_elements::<u32>(&[0, 1], &[1; 12]),
+        Err(ArrayError::StorageError(StorageError::Other(_)))
+    ));
+    Ok(())
+}
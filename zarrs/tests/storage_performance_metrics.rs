@@ -0,0 +1,122 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{ArrayBuilder, DataType, FillValue},
+    array_subset::ArraySubset,
+};
+use zarrs_storage::{
+    storage_adapter::performance_metrics::{PerformanceMetricsStorageAdapter, StorageRequestKind},
+    store::MemoryStore,
+    ListableStorageTraits,
+};
+
+#[test]
+fn storage_performance_metrics() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::default());
+    let store_perf = Arc::new(PerformanceMetricsStorageAdapter::new(store.clone()));
+
+    let array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt16,
+        vec![4, 4].try_into().unwrap(),
+        FillValue::from(0u16),
+    )
+    .bytes_to_bytes_codecs(vec![])
+    .build(store_perf.clone(), "/array")?;
+
+    array.store_metadata()?;
+    let metadata_size = store_perf.bytes_written();
+    assert!(metadata_size > 0);
+    assert_eq!(store_perf.writes(), 1);
+
+    // Write every chunk
+    let elements: Vec<u16> = (0..64).collect();
+    array.store_array_subset_elements(&ArraySubset::new_with_shape(vec![8, 8]), &elements)?;
+    let chunk_size = 4 * 4 * size_of::<u16>();
+    assert_eq!(store_perf.writes(), 1 + 4);
+    assert_eq!(store_perf.bytes_written(), metadata_size + 4 * chunk_size);
+    assert_eq!(store_perf.reads(), 0);
+
+    // Read every chunk
+    let elements_out =
+        array.retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_shape(vec![8, 8]))?;
+    assert_eq!(elements, elements_out);
+    assert_eq!(store_perf.reads(), 4);
+    assert_eq!(store_perf.bytes_read(), 4 * chunk_size);
+
+    let requests = store_perf.requests_by_kind();
+    assert_eq!(requests[&StorageRequestKind::Set], 1 + 4);
+    assert_eq!(requests[&StorageRequestKind::Get], 4);
+    assert_eq!(requests[&StorageRequestKind::List], 0);
+
+    store_perf.list()?;
+    array.erase_chunk(&[0, 0])?;
+    let requests = store_perf.requests_by_kind();
+    assert_eq!(requests[&StorageRequestKind::List], 1);
+    assert_eq!(requests[&StorageRequestKind::Erase], 1);
+    assert_eq!(store_perf.keys_erased(), 1);
+
+    store_perf.reset();
+    assert_eq!(store_perf.reads(), 0);
+    assert_eq!(store_perf.bytes_read(), 0);
+    assert_eq!(store_perf.writes(), 0);
+    assert_eq!(store_perf.bytes_written(), 0);
+    assert_eq!(store_perf.keys_erased(), 0);
+    assert!(store_perf.requests_by_kind().values().all(|&n| n == 0));
+    Ok(())
+}
+
+#[cfg(feature = "sharding")]
+#[test]
+fn storage_performance_metrics_range_coalesce() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::array::codec::{array_to_bytes::sharding::ShardingCodecBuilder, CodecOptions};
+
+    let store = Arc::new(MemoryStore::default());
+    let store_perf = Arc::new(PerformanceMetricsStorageAdapter::new(store.clone()));
+
+    let array = ArrayBuilder::new(
+        vec![8, 8],
+        DataType::UInt16,
+        vec![8, 8].try_into().unwrap(),
+        FillValue::from(0u16),
+    )
+    .array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![2, 2].try_into().unwrap()).build(),
+    ))
+    .build(store_perf.clone(), "/array")?;
+    // Store the inner chunks in the first half of the shard
+    let elements: Vec<u16> = (0..32).collect();
+    array.store_array_subset_elements(&ArraySubset::new_with_ranges(&[0..4, 0..8]), &elements)?;
+
+    // Read the index and then each inner chunk separately
+    let subset = ArraySubset::new_with_ranges(&[1..7, 1..7]);
+    store_perf.reset();
+    let elements = array.retrieve_array_subset_elements::<u16>(&subset)?;
+    assert_eq!(
+        store_perf.requests_by_kind()[&StorageRequestKind::GetPartialValuesKey],
+        1 + 8
+    );
+
+    // Read the index and then every inner chunk at once
+    let options = CodecOptions::builder().range_coalesce_gap(Some(0)).build();
+    store_perf.reset();
+    let elements_coalesced = array.retrieve_array_subset_elements_opt::<u16>(&subset, &options)?;
+    assert_eq!(
+        store_perf.requests_by_kind()[&StorageRequestKind::GetPartialValuesKey],
+        1 + 1
+    );
+    assert_eq!(elements, elements_coalesced);
+    assert_eq!(
+        elements,
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?[..]
+            .chunks(8)
+            .skip(1)
+            .take(6)
+            .flat_map(|row| row[1..7].to_vec())
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
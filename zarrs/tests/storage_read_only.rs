@@ -0,0 +1,138 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{ArrayBuilder, ArrayError, DataType, FillValue},
+    array_subset::ArraySubset,
+    group::GroupBuilder,
+    storage::{
+        storage_adapter::read_only::ReadOnlyStorageAdapter, store::MemoryStore,
+        ReadableStorageTraits, ReadableWritableStorageTraits, StorageError, StorePrefix,
+        WritableStorageTraits,
+    },
+};
+
+#[test]
+fn storage_read_only_array() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u8),
+    )
+    .build(store.clone(), "/array")?;
+    array.store_metadata()?;
+    array.store_chunk_elements::<u8>(&[0, 0], &[1, 2, 3, 4])?;
+    let snapshot = store.snapshot();
+
+    let array = array.readonly();
+    assert_eq!(array.retrieve_chunk_elements::<u8>(&[0, 0])?, [1, 2, 3, 4]);
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u8>(&ArraySubset::new_with_ranges(&[1..3, 1..3]))?,
+        [4, 0, 0, 0]
+    );
+
+    assert!(matches!(
+        array.store_metadata(),
+        Err(StorageError::ReadOnly)
+    ));
+    assert!(matches!(
+        array.store_chunk_elements::<u8>(&[0, 0], &[5, 6, 7, 8]),
+        Err(ArrayError::StorageError(StorageError::ReadOnly))
+    ));
+    assert!(matches!(
+        array.store_chunk_elements::<u8>(&[1, 1], &[5, 6, 7, 8]),
+        Err(ArrayError::StorageError(StorageError::ReadOnly))
+    ));
+    assert!(matches!(
+        array.erase_chunk(&[0, 0]),
+        Err(StorageError::ReadOnly)
+    ));
+    assert!(matches!(
+        array.erase_metadata(),
+        Err(StorageError::ReadOnly)
+    ));
+
+    // The underlying store is untouched
+    let keys = store.snapshot().keys();
+    assert_eq!(keys, snapshot.keys());
+    for key in &keys {
+        assert_eq!(store.snapshot().get(key), snapshot.get(key));
+    }
+    Ok(())
+}
+
+#[test]
+fn storage_read_only_group() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let group = GroupBuilder::new().build(store.clone(), "/group")?;
+    group.store_metadata()?;
+    let snapshot = store.snapshot();
+
+    let group = group.readonly();
+    assert!(matches!(
+        group.store_metadata(),
+        Err(StorageError::ReadOnly)
+    ));
+    assert_eq!(store.snapshot().keys(), snapshot.keys());
+
+    let store = ReadOnlyStorageAdapter::new(store);
+    assert!(matches!(
+        store.erase_prefix(&"group/".try_into()?),
+        Err(StorageError::ReadOnly)
+    ));
+    assert!(!store.supports_set_partial());
+    assert!(!store.capabilities().writable());
+    assert!(store.capabilities().supports_listing());
+    assert_eq!(store.health_check(&StorePrefix::root())?, None);
+    assert_eq!(store.storage().snapshot().keys(), snapshot.keys());
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn storage_read_only_async() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    let array = ArrayBuilder::new(
+        vec![4, 4],
+        DataType::UInt8,
+        vec![2, 2].try_into()?,
+        FillValue::from(0u8),
+    )
+    .build(store, "/array")?;
+    array.async_store_metadata().await?;
+    array
+        .async_store_chunk_elements::<u8>(&[0, 0], &[1, 2, 3, 4])
+        .await?;
+
+    let array_read_only = array.readonly();
+    assert_eq!(
+        array_read_only
+            .async_retrieve_chunk_elements::<u8>(&[0, 0])
+            .await?,
+        [1, 2, 3, 4]
+    );
+    assert!(matches!(
+        array_read_only.async_store_metadata().await,
+        Err(StorageError::ReadOnly)
+    ));
+    assert!(matches!(
+        array_read_only
+            .async_store_chunk_elements::<u8>(&[0, 0], &[5, 6, 7, 8])
+            .await,
+        Err(ArrayError::StorageError(StorageError::ReadOnly))
+    ));
+    assert!(matches!(
+        array_read_only.async_erase_chunk(&[0, 0]).await,
+        Err(StorageError::ReadOnly)
+    ));
+    assert_eq!(
+        array.async_retrieve_chunk_elements::<u8>(&[0, 0]).await?,
+        [1, 2, 3, 4]
+    );
+    Ok(())
+}
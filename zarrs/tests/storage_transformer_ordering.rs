@@ -0,0 +1,292 @@
+#![allow(missing_docs)]
+
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use zarrs::{
+    array::{
+        storage_transformer::{
+            StorageTransformer, StorageTransformerChain, StorageTransformerExtension,
+            StorageTransformerPlugin,
+        },
+        Array, ArrayBuilder, DataType, FillValue,
+    },
+    group::{Group, GroupBuilder},
+    metadata::v3::MetadataV3,
+    node::NodePath,
+    plugin::{PluginCreateError, PluginMetadataInvalidError},
+    storage::{
+        byte_range::ByteRange, store::MemoryStore, Bytes, ListableStorage, ReadableStorage,
+        ReadableStorageTraits, StorageError, StoreKey, StoreKeyOffsetValue, StorePrefix,
+        WritableStorage, WritableStorageTraits,
+    },
+};
+
+#[cfg(feature = "async")]
+use zarrs::storage::{AsyncListableStorage, AsyncReadableStorage, AsyncWritableStorage};
+
+const IDENTIFIER: &str = "recording";
+
+/// The names of the recording storage transformers in the order that they are invoked.
+static INVOCATIONS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn take_invocations() -> Vec<String> {
+    std::mem::take(&mut INVOCATIONS.lock().unwrap())
+}
+
+inventory::submit! {
+    StorageTransformerPlugin::new(IDENTIFIER, is_name_recording, create_storage_transformer_recording)
+}
+
+fn is_name_recording(name: &str) -> bool {
+    name == IDENTIFIER
+}
+
+fn create_storage_transformer_recording(
+    metadata: &MetadataV3,
+    _path: &NodePath,
+) -> Result<StorageTransformer, PluginCreateError> {
+    let configuration: RecordingConfiguration = metadata.to_configuration().map_err(|_| {
+        PluginMetadataInvalidError::new(IDENTIFIER, "storage transformer", metadata.clone())
+    })?;
+    Ok(Arc::new(RecordingStorageTransformer(configuration.name)))
+}
+
+#[derive(Serialize, Deserialize)]
+struct RecordingConfiguration {
+    name: String,
+}
+
+/// A storage transformer that records its name whenever a value is read or written.
+#[derive(Debug)]
+struct RecordingStorageTransformer(String);
+
+#[cfg_attr(feature = "async", async_trait::async_trait)]
+impl StorageTransformerExtension for RecordingStorageTransformer {
+    fn create_metadata(&self) -> MetadataV3 {
+        MetadataV3::new_with_serializable_configuration(
+            IDENTIFIER,
+            &RecordingConfiguration {
+                name: self.0.clone(),
+            },
+        )
+        .unwrap()
+    }
+
+    fn create_readable_transformer(
+        self: Arc<Self>,
+        storage: ReadableStorage,
+    ) -> Result<ReadableStorage, StorageError> {
+        Ok(Arc::new(RecordingStorage {
+            name: self.0.clone(),
+            storage,
+        }))
+    }
+
+    fn create_writable_transformer(
+        self: Arc<Self>,
+        storage: WritableStorage,
+    ) -> Result<WritableStorage, StorageError> {
+        Ok(Arc::new(RecordingStorage {
+            name: self.0.clone(),
+            storage,
+        }))
+    }
+
+    fn create_listable_transformer(
+        self: Arc<Self>,
+        storage: ListableStorage,
+    ) -> Result<ListableStorage, StorageError> {
+        Ok(storage)
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_readable_transformer(
+        self: Arc<Self>,
+        storage: AsyncReadableStorage,
+    ) -> Result<AsyncReadableStorage, StorageError> {
+        Ok(storage)
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_writable_transformer(
+        self: Arc<Self>,
+        storage: AsyncWritableStorage,
+    ) -> Result<AsyncWritableStorage, StorageError> {
+        Ok(storage)
+    }
+
+    #[cfg(feature = "async")]
+    async fn create_async_listable_transformer(
+        self: Arc<Self>,
+        storage: AsyncListableStorage,
+    ) -> Result<AsyncListableStorage, StorageError> {
+        Ok(storage)
+    }
+}
+
+struct RecordingStorage<TStorage: ?Sized> {
+    name: String,
+    storage: Arc<TStorage>,
+}
+
+impl<TStorage: ?Sized> RecordingStorage<TStorage> {
+    fn record(&self) {
+        INVOCATIONS.lock().unwrap().push(self.name.clone());
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
+    for RecordingStorage<TStorage>
+{
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.record();
+        self.storage.get_partial_values_key(key, byte_ranges)
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.storage.size_key(key)
+    }
+}
+
+impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
+    for RecordingStorage<TStorage>
+{
+    fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        self.record();
+        self.storage.set(key, value)
+    }
+
+    fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        self.record();
+        self.storage.set_partial_values(key_offset_values)
+    }
+
+    fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.storage.erase(key)
+    }
+
+    fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.storage.erase_prefix(prefix)
+    }
+}
+
+fn recording(name: &str) -> StorageTransformer {
+    Arc::new(RecordingStorageTransformer(name.to_string()))
+}
+
+#[test]
+fn storage_transformer_ordering() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    GroupBuilder::new()
+        .build(store.clone(), "/")?
+        .store_metadata()?;
+    GroupBuilder::new()
+        .storage_transformers(&StorageTransformerChain::new(vec![recording("group")]))
+        .build(store.clone(), "/group")?
+        .store_metadata()?;
+    GroupBuilder::new()
+        .storage_transformers(&StorageTransformerChain::new(vec![recording("subgroup")]))
+        .build(store.clone(), "/group/subgroup")?
+        .store_metadata()?;
+    for path in ["/group/array", "/group/subgroup/array"] {
+        let array = ArrayBuilder::new(
+            vec![4],
+            DataType::UInt8,
+            vec![4].try_into()?,
+            FillValue::from(0u8),
+        )
+        .storage_transformers(StorageTransformerChain::new(vec![
+            recording("array_inner"),
+            recording("array_outer"),
+        ]))
+        .build(store.clone(), path)?;
+        array.store_metadata()?;
+        take_invocations();
+        array.store_chunk_elements::<u8>(&[0], &[1, 2, 3, 4])?;
+        assert_eq!(take_invocations(), ["array_outer", "array_inner"]);
+    }
+
+    // Array metadata storage transformers only
+    let array = Array::open(store.clone(), "/group/array")?;
+    assert_eq!(array.retrieve_chunk_elements::<u8>(&[0])?, [1, 2, 3, 4]);
+    assert_eq!(take_invocations(), ["array_outer", "array_inner"]);
+
+    // Programmatic storage transformers are innermost
+    let array = Array::open_with_storage_transformers(
+        store.clone(),
+        "/group/array",
+        &[recording("extra_inner"), recording("extra_outer")],
+    )?;
+    assert_eq!(
+        array
+            .applied_storage_transformers()
+            .create_metadatas()
+            .len(),
+        4
+    );
+    assert_eq!(array.storage_transformers().create_metadatas().len(), 2);
+    assert_eq!(array.retrieve_chunk_elements::<u8>(&[0])?, [1, 2, 3, 4]);
+    assert_eq!(
+        take_invocations(),
+        ["array_outer", "array_inner", "extra_outer", "extra_inner"]
+    );
+
+    // Group storage transformers are applied to child arrays, starting from the group closest to the root
+    let root = Group::open(store.clone(), "/")?;
+    assert!(root.storage_transformers().is_empty());
+    let arrays = root.child_arrays(true)?;
+    assert_eq!(arrays.len(), 2);
+    assert_eq!(arrays[0].path().as_str(), "/group/array");
+    assert_eq!(arrays[0].retrieve_chunk_elements::<u8>(&[0])?, [1, 2, 3, 4]);
+    assert_eq!(take_invocations(), ["array_outer", "array_inner", "group"]);
+    assert_eq!(arrays[1].path().as_str(), "/group/subgroup/array");
+    arrays[1].store_chunk_elements::<u8>(&[0], &[5, 6, 7, 8])?;
+    assert_eq!(
+        take_invocations(),
+        ["array_outer", "array_inner", "subgroup", "group"]
+    );
+
+    // Child arrays retrieved by name inherit the storage transformers of intermediate groups
+    let array = root.child_array("group/subgroup/array")?;
+    assert_eq!(array.retrieve_chunk_elements::<u8>(&[0])?, [5, 6, 7, 8]);
+    assert_eq!(
+        take_invocations(),
+        ["array_outer", "array_inner", "subgroup", "group"]
+    );
+
+    // Child groups inherit storage transformers
+    let groups = root.child_groups(true)?;
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[1].path().as_str(), "/group/subgroup");
+    assert_eq!(
+        groups[1].storage_transformers().create_metadatas(),
+        [
+            recording("group").create_metadata(),
+            recording("subgroup").create_metadata()
+        ]
+    );
+    let arrays = groups[1].child_arrays(false)?;
+    assert_eq!(arrays.len(), 1);
+    assert_eq!(arrays[0].retrieve_chunk_elements::<u8>(&[0])?, [5, 6, 7, 8]);
+    assert_eq!(
+        take_invocations(),
+        ["array_outer", "array_inner", "subgroup", "group"]
+    );
+
+    // Storage transformers are serialised in the group metadata
+    let group = Group::open(store, "/group")?;
+    assert_eq!(
+        serde_json::to_value(group.metadata())?["storage_transformers"],
+        serde_json::json!([{"name": "recording", "configuration": {"name": "group"}}])
+    );
+    assert!(take_invocations().is_empty());
+    Ok(())
+}
@@ -0,0 +1,145 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::Array,
+    filesystem::FilesystemStore,
+    group::Group,
+    node::{open_consolidated_v2, Node, NodeCreateError, NodeMetadata},
+    storage::{
+        storage_adapter::performance_metrics::{
+            PerformanceMetricsStorageAdapter, StorageRequestKind,
+        },
+        store::MemoryStore,
+        ReadableStorageTraits, StorageError, WritableStorageTraits,
+    },
+};
+
+const CONSOLIDATED_PATH: &str = "tests/data/v2/consolidated.zarr";
+
+#[test]
+fn v2_consolidated_open() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(FilesystemStore::new(CONSOLIDATED_PATH)?);
+    let store = Arc::new(PerformanceMetricsStorageAdapter::new(store));
+    let storage = open_consolidated_v2(store.clone(), "/")?;
+    assert_eq!(store.reads(), 1);
+    assert_eq!(storage.metadata_keys().len(), 8);
+
+    let node = Node::open(&storage, "/")?;
+    assert_eq!(
+        node.hierarchy_tree(),
+        "/\n  array [3] Simple(\"<i4\")\n  group\n    array [4, 4] Simple(\"<u2\")\n    subgroup\n"
+    );
+    assert!(matches!(node.metadata(), NodeMetadata::Group(_)));
+    let group = Group::open(storage.clone(), "/")?;
+    assert_eq!(group.attributes()["title"], "consolidated");
+
+    let group = Group::open(storage.clone(), "/group")?;
+    assert_eq!(group.attributes()["description"], "a nested group");
+    let children = group.children(false)?;
+    assert_eq!(children.len(), 2);
+
+    let array = Array::open(storage.clone(), "/group/array")?;
+    assert_eq!(array.shape(), &[4, 4]);
+    assert_eq!(array.attributes()["units"], "m");
+
+    // No metadata requests reached the store after the consolidated metadata
+    let requests = store.requests_by_kind();
+    assert_eq!(requests[&StorageRequestKind::Get], 1);
+    assert_eq!(requests[&StorageRequestKind::List], 0);
+
+    // Chunks are retrieved from the store
+    let elements = array.retrieve_array_subset_elements::<u16>(&array.subset_all())?;
+    assert_eq!(elements, (0..16).collect::<Vec<u16>>());
+    assert_eq!(store.requests_by_kind()[&StorageRequestKind::Get], 1 + 4);
+
+    let array = Array::open(storage, "/array")?;
+    assert_eq!(
+        array.retrieve_array_subset_elements::<i32>(&array.subset_all())?,
+        [7, 7, 7]
+    );
+    Ok(())
+}
+
+#[test]
+fn v2_consolidated_subgroup() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(FilesystemStore::new(CONSOLIDATED_PATH)?);
+    assert!(matches!(
+        open_consolidated_v2(store, "/group"),
+        Err(NodeCreateError::MissingMetadata)
+    ));
+    Ok(())
+}
+
+#[test]
+fn v2_consolidated_stale() -> Result<(), Box<dyn std::error::Error>> {
+    let fs = FilesystemStore::new(CONSOLIDATED_PATH)?;
+    let store = Arc::new(MemoryStore::new());
+    for key in [".zmetadata", ".zgroup", ".zattrs"] {
+        let key = key.try_into()?;
+        store.set(&key, fs.get(&key)?.unwrap())?;
+    }
+    let storage = open_consolidated_v2(store.clone(), "/")?;
+
+    // Changes after consolidation are not visible
+    store.set(&".zattrs".try_into()?, r#"{"title":"stale"}"#.into())?;
+    let group = Group::open(storage, "/")?;
+    assert_eq!(group.attributes()["title"], "consolidated");
+    Ok(())
+}
+
+#[test]
+fn v2_consolidated_invalid() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    store.set(
+        &".zmetadata".try_into()?,
+        r#"{"metadata":{"a/.zarray":{"zarr_format":2}},"zarr_consolidated_format":1}"#.into(),
+    )?;
+    assert!(matches!(
+        open_consolidated_v2(store.clone(), "/"),
+        Err(NodeCreateError::StorageError(StorageError::InvalidMetadata(key, _))) if key.as_str() == "a/.zarray"
+    ));
+
+    store.set(
+        &".zmetadata".try_into()?,
+        r#"{"metadata":{},"zarr_consolidated_format":2}"#.into(),
+    )?;
+    assert!(matches!(
+        open_consolidated_v2(store, "/"),
+        Err(NodeCreateError::StorageError(
+            StorageError::InvalidMetadata(..)
+        ))
+    ));
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn v2_consolidated_async() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::{node::async_open_consolidated_v2, storage::AsyncWritableStorageTraits};
+
+    let fs = FilesystemStore::new(CONSOLIDATED_PATH)?;
+    let store = Arc::new(zarrs_object_store::AsyncObjectStore::new(
+        object_store::memory::InMemory::new(),
+    ));
+    for key in [".zmetadata", "group/array/0.0"] {
+        let key = key.try_into()?;
+        store.set(&key, fs.get(&key)?.unwrap()).await?;
+    }
+    let storage = async_open_consolidated_v2(store, "/").await?;
+    let node = Node::async_open(storage.clone(), "/").await?;
+    assert_eq!(node.children().len(), 2);
+
+    let array = Array::async_open(storage, "/group/array").await?;
+    assert_eq!(array.attributes()["units"], "m");
+    assert_eq!(
+        array.async_retrieve_chunk_elements::<u16>(&[0, 0]).await?,
+        [0, 1, 4, 5]
+    );
+    assert_eq!(
+        array.async_retrieve_chunk_elements::<u16>(&[1, 1]).await?,
+        [0, 0, 0, 0]
+    );
+    Ok(())
+}
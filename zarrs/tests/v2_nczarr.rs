@@ -0,0 +1,83 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{Array, DataType},
+    config::global_config_mut,
+    filesystem::FilesystemStore,
+    group::Group,
+    metadata::DimensionName,
+    node::Node,
+};
+
+const NCZARR_PATH: &str = "tests/data/v2/nczarr.zarr";
+
+#[test]
+fn v2_nczarr_open() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(FilesystemStore::new(NCZARR_PATH)?);
+
+    // The NCZarr metadata is rejected without NCZarr compatibility
+    assert!(Group::open(store.clone(), "/").is_err());
+    assert!(Array::open(store.clone(), "/temperature").is_err());
+
+    global_config_mut().set_nczarr_compatibility(true);
+    let result = v2_nczarr_open_compatible(&store);
+    global_config_mut().set_nczarr_compatibility(false);
+    result
+}
+
+fn v2_nczarr_open_compatible(
+    store: &Arc<FilesystemStore>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let group = Group::open(store.clone(), "/")?;
+    assert_eq!(group.attributes().len(), 1);
+    assert_eq!(group.attributes()["title"], "nczarr");
+    assert_eq!(
+        group.additional_fields()["_nczarr_superblock"].as_map()["version"],
+        "2.0.0"
+    );
+    assert!(group.additional_fields().contains_key("_nczarr_attr"));
+
+    let node = Node::open(store, "/")?;
+    let mut children: Vec<_> = node
+        .children()
+        .iter()
+        .map(|child| child.path().as_str())
+        .collect();
+    children.sort_unstable();
+    assert_eq!(children, ["/lat", "/temperature"]);
+
+    let array = Array::open(store.clone(), "/temperature")?;
+    assert_eq!(array.shape(), &[4, 3]);
+    assert_eq!(array.data_type(), &DataType::Float32);
+    assert_eq!(
+        array.dimension_names(),
+        &Some(vec![DimensionName::new("lat"), DimensionName::new("lon")])
+    );
+    assert_eq!(array.attributes().len(), 2);
+    assert_eq!(array.attributes()["units"], "K");
+    assert!(array.additional_fields().contains_key("_nczarr_array"));
+    assert!(array.additional_fields().contains_key("_nczarr_attr"));
+    assert_eq!(
+        array.retrieve_array_subset_elements::<f32>(&array.subset_all())?,
+        [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, -999.0, -999.0, -999.0, -999.0, -999.0, -999.0]
+    );
+
+    let array = Array::open(store.clone(), "/lat")?;
+    assert_eq!(array.shape(), &[4]);
+    assert_eq!(array.data_type(), &DataType::Float64);
+    assert_eq!(
+        array.dimension_names(),
+        &Some(vec![DimensionName::new("lat")])
+    );
+    assert_eq!(
+        array.fill_value().as_ne_bytes(),
+        9.969_209_968_386_869e36f64.to_ne_bytes()
+    );
+    assert_eq!(
+        array.retrieve_array_subset_elements::<f64>(&array.subset_all())?,
+        [-45.0, -15.0, 15.0, 45.0]
+    );
+    Ok(())
+}
@@ -0,0 +1,184 @@
+#![allow(missing_docs)]
+
+use std::sync::Arc;
+
+use zarrs::{
+    array::{
+        chunk_grid::RectangularChunkGridDimensionConfiguration as Dimension,
+        codec::{
+            array_to_bytes::sharding::ShardingCodecBuilder, BytesToBytesCodecTraits, GzipCodec,
+        },
+        Array, ArrayBuilder, ArrayCreateError, ArrayMetadata, ArrayMetadataOptions, DataType,
+        FillValue,
+    },
+    filesystem::FilesystemStore,
+    group::{Group, GroupBuilder},
+    metadata::{GroupMetadata, ZarrVersion},
+    storage::{store::MemoryStore, ReadableStorageTraits, StoreKey},
+};
+
+fn read_json(
+    store: &impl ReadableStorageTraits,
+    key: &str,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    Ok(serde_json::from_slice(
+        &store.get(&StoreKey::new(key)?)?.unwrap(),
+    )?)
+}
+
+/// Create the `zarr-python` `tests/data/v2/array_{name}_C.zarr` fixture with `zarrs` and compare the metadata and data.
+fn v2_write_compare(
+    name: &str,
+    bytes_to_bytes_codecs: Vec<Arc<dyn BytesToBytesCodecTraits>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FilesystemStore::new(format!("tests/data/v2/array_{name}_C.zarr"))?;
+    let store = Arc::new(MemoryStore::new());
+    let mut attributes = serde_json::Map::new();
+    attributes.insert("key".to_string(), "value".into());
+    let array = ArrayBuilder::new(
+        vec![10, 10],
+        DataType::Float32,
+        vec![5, 5].try_into()?,
+        FillValue::from(0.0f32),
+    )
+    .bytes_to_bytes_codecs(bytes_to_bytes_codecs)
+    .attributes(attributes)
+    .zarr_format(ZarrVersion::V2)
+    .build(store.clone(), "/")?;
+    assert!(matches!(array.metadata(), ArrayMetadata::V2(_)));
+    array
+        .store_metadata_opt(&ArrayMetadataOptions::default().with_include_zarrs_metadata(false))?;
+    let elements: Vec<f32> = (0..100u8).map(f32::from).collect();
+    array.store_array_subset_elements(&array.subset_all(), &elements)?;
+
+    // numcodecs >= 0.13 includes the zstd checksum flag
+    let mut zarray = read_json(store.as_ref(), ".zarray")?;
+    if name == "zstd" {
+        let compressor = zarray["compressor"].as_object_mut().unwrap();
+        assert_eq!(compressor.remove("checksum"), Some(false.into()));
+    }
+    assert_eq!(zarray, read_json(&fixture, ".zarray")?);
+    assert_eq!(
+        read_json(store.as_ref(), ".zattrs")?,
+        read_json(&fixture, ".zattrs")?
+    );
+    assert!(store.get(&StoreKey::new("zarr.json")?)?.is_none());
+    for key in ["0.0", "0.1", "1.0", "1.1"] {
+        let key = StoreKey::new(key)?;
+        let chunk = store.get(&key)?.unwrap();
+        if name == "none" {
+            assert_eq!(chunk, fixture.get(&key)?.unwrap());
+        }
+    }
+
+    // Both arrays can be read by zarrs
+    let array = Array::open(store, "/")?;
+    assert!(matches!(array.metadata(), ArrayMetadata::V2(_)));
+    let array_fixture = Array::open(Arc::new(fixture), "/")?;
+    assert_eq!(
+        array.retrieve_array_subset_elements::<f32>(&array.subset_all())?,
+        elements
+    );
+    assert_eq!(
+        array_fixture.retrieve_array_subset_elements::<f32>(&array_fixture.subset_all())?,
+        elements
+    );
+    Ok(())
+}
+
+#[test]
+fn v2_write_none() -> Result<(), Box<dyn std::error::Error>> {
+    v2_write_compare("none", vec![])
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn v2_write_gzip() -> Result<(), Box<dyn std::error::Error>> {
+    v2_write_compare("gzip", vec![Arc::new(GzipCodec::new(9)?)])
+}
+
+#[cfg(feature = "blosc")]
+#[test]
+fn v2_write_blosc() -> Result<(), Box<dyn std::error::Error>> {
+    use zarrs::array::codec::{
+        bytes_to_bytes::blosc::{BloscCompressionLevel, BloscCompressor, BloscShuffleMode},
+        BloscCodec,
+    };
+    v2_write_compare(
+        "blosc",
+        vec![Arc::new(BloscCodec::new(
+            BloscCompressor::Zstd,
+            BloscCompressionLevel::try_from(1u8).unwrap(),
+            None,
+            BloscShuffleMode::BitShuffle,
+            Some(4),
+        )?)],
+    )
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn v2_write_zstd() -> Result<(), Box<dyn std::error::Error>> {
+    v2_write_compare(
+        "zstd",
+        vec![Arc::new(zarrs::array::codec::ZstdCodec::new(5, false))],
+    )
+}
+
+#[test]
+fn v2_write_incompatible() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let builder = |data_type: DataType, fill_value: FillValue| {
+        let mut builder = ArrayBuilder::new(
+            vec![4, 4],
+            data_type,
+            vec![2, 2].try_into().unwrap(),
+            fill_value,
+        );
+        builder.zarr_format(ZarrVersion::V2);
+        builder
+    };
+
+    let mut sharded = builder(DataType::UInt8, 0u8.into());
+    sharded.array_to_bytes_codec(Arc::new(
+        ShardingCodecBuilder::new(vec![1, 1].try_into()?).build(),
+    ));
+    let string = builder(DataType::String, "".into());
+    let mut rectangular = builder(DataType::UInt8, 0u8.into());
+    rectangular.rectangular_chunk_grid(&[
+        Dimension::Varying(vec![1, 3].try_into()?),
+        Dimension::Fixed(4.try_into()?),
+    ]);
+    for builder in [sharded, string, rectangular] {
+        assert!(matches!(
+            builder.build(store.clone(), "/array"),
+            Err(ArrayCreateError::IncompatibleZarrV2(_))
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn v2_write_group() -> Result<(), Box<dyn std::error::Error>> {
+    let store = Arc::new(MemoryStore::new());
+    let mut attributes = serde_json::Map::new();
+    attributes.insert("key".to_string(), "value".into());
+    let group = GroupBuilder::new()
+        .attributes(attributes.clone())
+        .zarr_format(ZarrVersion::V2)
+        .build(store.clone(), "/group")?;
+    group.store_metadata()?;
+    assert_eq!(
+        read_json(store.as_ref(), "group/.zgroup")?,
+        serde_json::json!({"zarr_format": 2})
+    );
+    assert_eq!(
+        read_json(store.as_ref(), "group/.zattrs")?,
+        serde_json::Value::Object(attributes.clone())
+    );
+
+    let group = Group::open(store, "/group")?;
+    assert!(matches!(group.metadata(), GroupMetadata::V2(_)));
+    assert_eq!(group.attributes(), &attributes);
+    Ok(())
+}
@@ -2,7 +2,11 @@
 
 use std::{error::Error, path::PathBuf, sync::Arc};
 
-use zarrs::{array::Array, array_subset::ArraySubset, storage::StoreKey};
+use zarrs::{
+    array::Array,
+    array_subset::ArraySubset,
+    storage::{store::MemoryStore, ReadableStorageTraits, StoreKey},
+};
 use zarrs_filesystem::FilesystemStore;
 use zarrs_zip::ZipStorageAdapter;
 
@@ -43,3 +47,88 @@ fn zarr_python_compat_fletcher32_v2() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Write `elements` to a copy of the array at `path` with `zarrs` and check the chunks are identical to those of `zarr-python`.
+fn check_chunks_identical<T: zarrs::array::Element>(
+    path: &str,
+    elements: &[T],
+) -> Result<(), Box<dyn Error>> {
+    let store = Arc::new(FilesystemStore::new(path)?);
+    let array = Array::open(store.clone(), "/")?;
+    let store_out = Arc::new(MemoryStore::new());
+    let array_out = Array::new_with_metadata(store_out.clone(), "/", array.metadata().clone())?;
+    array_out.store_array_subset_elements(&array_out.subset_all(), elements)?;
+    for chunk_indices in &ArraySubset::new_with_shape(array.chunk_grid_shape().unwrap()).indices() {
+        let key = array.chunk_key(&chunk_indices);
+        assert_eq!(store_out.get(&key)?, store.get(&key)?, "{key}");
+    }
+    Ok(())
+}
+
+#[test]
+fn zarr_python_compat_v2_order_f() -> Result<(), Box<dyn Error>> {
+    let path = "tests/data/zarr_python_compat/order_f.zarr";
+    let store = Arc::new(FilesystemStore::new(path)?);
+    let array = Array::open(store, "/")?;
+    assert_eq!(array.shape(), vec![7, 5]);
+
+    // Elements are returned in C order, including those in edge chunks
+    let elements: Vec<u16> = (1..=35).collect();
+    assert_eq!(
+        array.retrieve_array_subset_elements::<u16>(&array.subset_all())?,
+        elements
+    );
+    assert_eq!(
+        array
+            .retrieve_array_subset_elements::<u16>(&ArraySubset::new_with_ranges(&[5..7, 1..5]))?,
+        [27, 28, 29, 30, 32, 33, 34, 35]
+    );
+    assert_eq!(
+        array.retrieve_chunk_elements::<u16>(&[2, 2])?,
+        [35, 0, 0, 0, 0, 0]
+    );
+    check_chunks_identical(path, &elements)
+}
+
+#[test]
+fn zarr_python_compat_v2_order_f_vlen_utf8() -> Result<(), Box<dyn Error>> {
+    // The transpose precedes the vlen-utf8 filter
+    let path = "tests/data/zarr_python_compat/order_f_vlen_utf8.zarr";
+    let store = Arc::new(FilesystemStore::new(path)?);
+    let array = Array::open(store, "/")?;
+    assert_eq!(array.shape(), vec![5, 3]);
+
+    let elements: Vec<String> = (0..5)
+        .flat_map(|i| (0..3).map(move |j| format!("r{i}c{j}{}", "é".repeat(i))))
+        .collect();
+    assert_eq!(
+        array.retrieve_array_subset_elements::<String>(&array.subset_all())?,
+        elements
+    );
+    assert_eq!(
+        array.retrieve_chunk_elements::<String>(&[2, 1])?,
+        ["r4c2éééé", "", "", ""]
+    );
+    check_chunks_identical(path, &elements)
+}
+
+#[test]
+fn zarr_python_compat_scalar() -> Result<(), Box<dyn Error>> {
+    for (path, chunk_key) in [
+        ("tests/data/zarr_python_compat/scalar_v2.zarr", "0"),
+        ("tests/data/zarr_python_compat/scalar_v3.zarr", "c"),
+    ] {
+        let store = Arc::new(FilesystemStore::new(path)?);
+        let array = Array::open(store, "/")?;
+        assert_eq!(array.shape(), Vec::<u64>::new());
+        assert_eq!(array.chunk_grid_shape(), Some(vec![]));
+        assert_eq!(array.chunk_key(&[]), StoreKey::new(chunk_key)?);
+        assert_eq!(array.stored_chunks()?, [Vec::<u64>::new()]);
+        assert_eq!(
+            array.retrieve_array_subset_elements::<i32>(&array.subset_all())?,
+            [42]
+        );
+        check_chunks_identical(path, &[42i32])?;
+    }
+    Ok(())
+}
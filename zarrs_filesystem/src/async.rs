@@ -0,0 +1,365 @@
+use zarrs_storage::{
+    byte_range::{ByteOffset, ByteRange},
+    AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits,
+    StorageError, StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes,
+    StorePrefix,
+};
+
+use tokio::sync::{Mutex, RwLock};
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{
+    base_path_readonly, direct_io_enabled, evict_file_handle, evict_file_handles_prefix,
+    key_to_fspath, list_dir, list_files, new_file_handle_cache, open_file_cached,
+    prefix_to_fs_path, read_byte_ranges, remove_dir_all, remove_file, size_dir, size_file,
+    size_file_cached, write_key, FileHandleCache, FilesystemStoreCreateError,
+    FilesystemStoreOptions,
+};
+
+/// Run blocking file system operations on the [`tokio`] blocking thread pool.
+async fn spawn_blocking<T, F>(f: F) -> Result<T, StorageError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, StorageError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|err| StorageError::Other(err.to_string()))?
+}
+
+/// An asynchronous file system store.
+///
+/// File system operations are performed on the [`tokio`] blocking thread pool, so this store must be used within a [`tokio`] runtime.
+///
+/// See <https://zarr-specs.readthedocs.io/en/latest/v3/stores/filesystem/v1.0.html>.
+#[derive(Debug)]
+pub struct AsyncFilesystemStore {
+    base_path: PathBuf,
+    sort: bool,
+    readonly: bool,
+    options: FilesystemStoreOptions,
+    files: Mutex<HashMap<StoreKey, Arc<RwLock<()>>>>,
+    file_handles: Option<Arc<FileHandleCache>>,
+}
+
+impl AsyncFilesystemStore {
+    /// Create a new asynchronous file system store at a given `base_path`.
+    ///
+    /// # Errors
+    /// Returns a [`FilesystemStoreCreateError`] if `base_directory`:
+    ///   - is not valid, or
+    ///   - it points to an existing file rather than a directory.
+    pub fn new<P: AsRef<Path>>(base_path: P) -> Result<Self, FilesystemStoreCreateError> {
+        Self::new_with_options(base_path, FilesystemStoreOptions::default())
+    }
+
+    /// Create a new asynchronous file system store at a given `base_path` and `options`.
+    ///
+    /// # Errors
+    /// Returns a [`FilesystemStoreCreateError`] if `base_directory`:
+    ///   - is not valid, or
+    ///   - it points to an existing file rather than a directory.
+    pub fn new_with_options<P: AsRef<Path>>(
+        base_path: P,
+        options: FilesystemStoreOptions,
+    ) -> Result<Self, FilesystemStoreCreateError> {
+        let base_path = base_path.as_ref().to_path_buf();
+        let readonly = base_path_readonly(&base_path)?;
+
+        let file_handles = new_file_handle_cache(options.file_handle_limit).map(Arc::new);
+
+        Ok(Self {
+            base_path,
+            sort: false,
+            options,
+            readonly,
+            files: Mutex::default(),
+            file_handles,
+        })
+    }
+
+    /// Makes the store sort directories/files when walking.
+    #[must_use]
+    pub const fn sorted(mut self) -> Self {
+        self.sort = true;
+        self
+    }
+
+    /// Maps a [`StoreKey`] to a filesystem [`PathBuf`].
+    #[must_use]
+    pub fn key_to_fspath(&self, key: &StoreKey) -> PathBuf {
+        key_to_fspath(&self.base_path, key)
+    }
+
+    /// Maps a store [`StorePrefix`] to a filesystem [`PathBuf`].
+    #[must_use]
+    pub fn prefix_to_fs_path(&self, prefix: &StorePrefix) -> PathBuf {
+        prefix_to_fs_path(&self.base_path, prefix)
+    }
+
+    async fn get_file_mutex(&self, key: &StoreKey) -> Arc<RwLock<()>> {
+        let mut files = self.files.lock().await;
+        let file = files
+            .entry(key.clone())
+            .or_insert_with(|| Arc::new(RwLock::default()))
+            .clone();
+        drop(files);
+        file
+    }
+
+    async fn set_impl(
+        &self,
+        key: &StoreKey,
+        value: AsyncBytes,
+        offset: ByteOffset,
+        truncate: bool,
+    ) -> Result<(), StorageError> {
+        let file = self.get_file_mutex(key).await;
+        let _lock = file.write().await;
+
+        let key = key.clone();
+        let key_path = self.key_to_fspath(&key);
+        let enable_direct = direct_io_enabled(self.options.direct_io, &value);
+        let file_handles = self.file_handles.clone();
+        spawn_blocking(move || {
+            write_key(
+                &key,
+                &key_path,
+                &value,
+                offset,
+                truncate,
+                enable_direct,
+                file_handles.as_deref(),
+            )
+        })
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncReadableStorageTraits for AsyncFilesystemStore {
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        let file = self.get_file_mutex(key).await;
+        let _lock = file.read().await;
+
+        let key = key.clone();
+        let key_path = self.key_to_fspath(&key);
+        let byte_ranges = byte_ranges.to_vec();
+        let file_handles = self.file_handles.clone();
+        spawn_blocking(move || {
+            let Some(file) = open_file_cached(&key, &key_path, file_handles.as_deref())? else {
+                return Ok(None);
+            };
+            Ok(Some(read_byte_ranges(&file, &byte_ranges)?))
+        })
+        .await
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        let key = key.clone();
+        let key_path = self.key_to_fspath(&key);
+        let file_handles = self.file_handles.clone();
+        spawn_blocking(move || size_file_cached(&key, &key_path, file_handles.as_deref())).await
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        StoreCapabilities::new()
+            .with_writable(!self.readonly)
+            .with_listing(true)
+            .with_partial_read(true)
+            .with_partial_write(!self.readonly)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncWritableStorageTraits for AsyncFilesystemStore {
+    async fn set(&self, key: &StoreKey, value: AsyncBytes) -> Result<(), StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
+        }
+
+        self.set_impl(key, value, 0, true).await
+    }
+
+    async fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
+        }
+
+        for key_offset_value in key_offset_values {
+            self.set_impl(
+                key_offset_value.key(),
+                AsyncBytes::copy_from_slice(key_offset_value.value()),
+                key_offset_value.offset(),
+                false,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.capabilities().supports_partial_write()
+    }
+
+    async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
+        }
+
+        let file = self.get_file_mutex(key).await;
+        let _lock = file.write().await;
+
+        // Close any open handle first, otherwise the file cannot be removed on Windows
+        evict_file_handle(key, self.file_handles.as_deref());
+
+        let key_path = self.key_to_fspath(key);
+        spawn_blocking(move || remove_file(&key_path)).await
+    }
+
+    async fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
+        }
+
+        let _lock = self.files.lock().await; // lock all operations
+
+        evict_file_handles_prefix(prefix, self.file_handles.as_deref());
+
+        let prefix_path = self.prefix_to_fs_path(prefix);
+        spawn_blocking(move || remove_dir_all(&prefix_path)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncListableStorageTraits for AsyncFilesystemStore {
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        let base_path = self.base_path.clone();
+        spawn_blocking(move || Ok(list_files(&base_path, &base_path))).await
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        let base_path = self.base_path.clone();
+        let prefix_path = self.prefix_to_fs_path(prefix);
+        spawn_blocking(move || Ok(list_files(&base_path, &prefix_path))).await
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        let prefix_path = self.prefix_to_fs_path(prefix);
+        let prefix = prefix.clone();
+        let sort = self.sort;
+        spawn_blocking(move || list_dir(&prefix_path, &prefix, sort)).await
+    }
+
+    async fn size(&self) -> Result<u64, StorageError> {
+        let base_path = self.base_path.clone();
+        spawn_blocking(move || Ok(size_dir(&base_path))).await
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        let base_path = self.base_path.clone();
+        let prefix_path = self.prefix_to_fs_path(prefix);
+        spawn_blocking(move || {
+            Ok(list_files(&base_path, &prefix_path)
+                .iter()
+                .filter_map(|key| size_file(&key_to_fspath(&base_path, key)))
+                .sum())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn filesystem_async() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let store = AsyncFilesystemStore::new(path.path())?.sorted();
+        zarrs_storage::store_test::async_store_write(&store).await?;
+        zarrs_storage::store_test::async_store_read(&store).await?;
+        zarrs_storage::store_test::async_store_read_edge_byte_ranges(
+            &store,
+            &"a/b".try_into()?,
+            &"a/d/e".try_into()?,
+        )
+        .await?;
+        zarrs_storage::store_test::async_store_list(&store).await?;
+        zarrs_storage::store_test::async_store_health_check(&store).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn filesystem_async_file_handle_cache() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut opts = FilesystemStoreOptions::default();
+        opts.file_handle_limit(2);
+
+        let store = AsyncFilesystemStore::new_with_options(path.path(), opts)?.sorted();
+        zarrs_storage::store_test::async_store_write(&store).await?;
+        zarrs_storage::store_test::async_store_read(&store).await?;
+        zarrs_storage::store_test::async_store_list(&store).await?;
+
+        // Partial writes at an offset through a cached handle
+        let key_x: StoreKey = "x".try_into()?;
+        store.set(&key_x, vec![0, 1, 2, 3].into()).await?;
+        assert_eq!(store.get(&key_x).await?, Some(vec![0, 1, 2, 3].into()));
+        store
+            .set_partial_values(&[
+                StoreKeyOffsetValue::new(key_x.clone(), 1, &[5]),
+                StoreKeyOffsetValue::new(key_x.clone(), 4, &[6, 7]),
+            ])
+            .await?;
+        assert_eq!(
+            store.get(&key_x).await?,
+            Some(vec![0, 5, 2, 3, 6, 7].into())
+        );
+        assert_eq!(store.size_key(&key_x).await?, Some(6));
+
+        // Erasing closes the cached handle
+        store.erase(&key_x).await?;
+        assert!(store.get(&key_x).await?.is_none());
+        assert!(store.size_key(&key_x).await?.is_none());
+        store.erase_prefix(&StorePrefix::root()).await?;
+        assert!(store.list().await?.is_empty());
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn filesystem_async_direct_io() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut opts = FilesystemStoreOptions::default();
+        opts.direct_io(true);
+
+        let store = AsyncFilesystemStore::new_with_options(path.path(), opts)?.sorted();
+        zarrs_storage::store_test::async_store_write(&store).await?;
+        zarrs_storage::store_test::async_store_read(&store).await?;
+        zarrs_storage::store_test::async_store_read_edge_byte_ranges(
+            &store,
+            &"a/b".try_into()?,
+            &"a/d/e".try_into()?,
+        )
+        .await?;
+        zarrs_storage::store_test::async_store_list(&store).await?;
+        zarrs_storage::store_test::async_store_health_check(&store).await?;
+        Ok(())
+    }
+}
@@ -2,20 +2,35 @@
 //!
 //! This implementation is conformant with the filesystem store defined in the Zarr V3 specification: <https://zarr-specs.readthedocs.io/en/latest/v3/stores/filesystem/v1.0.html>.
 //!
+//! [`OffsetFileStore`] is an experimental store that holds all chunks of an array in fixed-size slots of a single preallocated file.
+//!
+//! ## Crate Features
+//! - `async`: enable the experimental `AsyncFilesystemStore`.
+//!
 //! ## Licence
 //! `zarrs_filesystem` is licensed under either of
 //! - the Apache License, Version 2.0 [LICENSE-APACHE](https://docs.rs/crate/zarrs_filesystem/latest/source/LICENCE-APACHE) or <http://www.apache.org/licenses/LICENSE-2.0> or
 //! - the MIT license [LICENSE-MIT](https://docs.rs/crate/zarrs_filesystem/latest/source/LICENCE-MIT) or <http://opensource.org/licenses/MIT>, at your option.
 
+#[cfg(feature = "async")]
+mod r#async;
+mod offset_file;
+
+pub use offset_file::{OffsetFileLayout, OffsetFileStore, OffsetFileStoreCreateError};
+#[cfg(feature = "async")]
+pub use r#async::AsyncFilesystemStore;
+
 use zarrs_storage::{
-    byte_range::{ByteOffset, ByteRange},
-    store_set_partial_values, Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError,
-    StoreKey, StoreKeyError, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix,
-    StorePrefixes, WritableStorageTraits,
+    byte_range::{validate_byte_ranges, ByteOffset, ByteRange},
+    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StoreKeyError, StoreKeyOffsetValue, StoreKeyRange, StoreKeys,
+    StoreKeysPrefixes, StorePrefix, StorePrefixes, WritableStorageTraits,
 };
 
 use bytes::BytesMut;
+use lru::LruCache;
 use parking_lot::RwLock; // TODO: std::sync::RwLock with Rust 1.78+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use thiserror::Error;
 use walkdir::WalkDir;
 
@@ -23,6 +38,7 @@ use std::{
     collections::HashMap,
     fs::{File, OpenOptions},
     io::{Read, Seek, SeekFrom, Write},
+    num::NonZeroUsize,
     path::{Path, PathBuf},
     sync::{Arc, Mutex},
 };
@@ -62,11 +78,100 @@ fn bytes_aligned(size: usize) -> BytesMut {
     bytes.split_off(offset)
 }
 
-/// Options for use with [`FilesystemStore`]
+/// Read exactly `buf.len()` bytes from `file` starting at `offset`.
+///
+/// This does not depend on the file cursor, so a [`File`] can be shared between concurrent readers.
+fn read_exact_at(file: &File, buf: &mut [u8], offset: u64) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileExt;
+        file.read_exact_at(buf, offset)
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::FileExt;
+        let mut buf = buf;
+        let mut offset = offset;
+        while !buf.is_empty() {
+            match file.seek_read(buf, offset) {
+                Ok(0) => return Err(std::io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => {
+                    buf = &mut buf[n..];
+                    offset += n as u64;
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        use std::io::Read;
+        let mut file = file;
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(buf)
+    }
+}
+
+/// Write all of `buf` to `file` starting at `offset`.
+///
+/// This does not depend on the file cursor, so a [`File`] can be shared between operations.
+fn write_all_at(file: &File, buf: &[u8], offset: u64) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileExt;
+        file.write_all_at(buf, offset)
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::FileExt;
+        let mut buf = buf;
+        let mut offset = offset;
+        while !buf.is_empty() {
+            match file.seek_write(buf, offset) {
+                Ok(0) => return Err(std::io::ErrorKind::WriteZero.into()),
+                Ok(n) => {
+                    buf = &buf[n..];
+                    offset += n as u64;
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let mut file = file;
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(buf)
+    }
+}
+
+/// A cached file handle.
+#[derive(Debug, Clone)]
+struct FileHandle {
+    file: Arc<File>,
+    /// True if the file was opened with write permissions.
+    writable: bool,
+}
+
+/// A least recently used cache of open file handles.
+type FileHandleCache = Mutex<LruCache<StoreKey, FileHandle>>;
+
+/// Create a file handle cache holding up to `file_handle_limit` handles, or [`None`] if the limit is zero.
+fn new_file_handle_cache(file_handle_limit: usize) -> Option<FileHandleCache> {
+    NonZeroUsize::new(file_handle_limit)
+        .map(|file_handle_limit| Mutex::new(LruCache::new(file_handle_limit)))
+}
+
+/// Options for use with [`FilesystemStore`] and `AsyncFilesystemStore`
 #[non_exhaustive]
 #[derive(Debug, Clone, Default)]
 pub struct FilesystemStoreOptions {
     direct_io: bool,
+    file_handle_limit: usize,
 }
 
 impl FilesystemStoreOptions {
@@ -76,6 +181,18 @@ impl FilesystemStoreOptions {
         self.direct_io = direct_io;
         self
     }
+
+    /// Set the maximum number of open file handles retained by the store. Defaults to 0 (disabled).
+    ///
+    /// If non-zero, recently used file handles are kept open in a least recently used cache and reused by subsequent reads and writes.
+    /// This avoids repeated `open`/`close` syscalls when many values are accessed in quick succession.
+    /// The limit should be kept well below the open file limit of the process.
+    ///
+    /// Writes with direct I/O enabled do not use the cache.
+    pub fn file_handle_limit(&mut self, file_handle_limit: usize) -> &mut Self {
+        self.file_handle_limit = file_handle_limit;
+        self
+    }
 }
 
 /// A synchronous file system store.
@@ -88,6 +205,7 @@ pub struct FilesystemStore {
     readonly: bool,
     options: FilesystemStoreOptions,
     files: Mutex<HashMap<StoreKey, Arc<RwLock<()>>>>,
+    file_handles: Option<FileHandleCache>,
     // locks: StoreLocks,
 }
 
@@ -113,20 +231,9 @@ impl FilesystemStore {
         options: FilesystemStoreOptions,
     ) -> Result<Self, FilesystemStoreCreateError> {
         let base_path = base_path.as_ref().to_path_buf();
-        if base_path.to_str().is_none() {
-            return Err(FilesystemStoreCreateError::InvalidBasePath(base_path));
-        }
+        let readonly = base_path_readonly(&base_path)?;
 
-        let readonly = if base_path.exists() {
-            // the path already exists, check if it is read only
-            let md = std::fs::metadata(&base_path).map_err(FilesystemStoreCreateError::IOError)?;
-            md.permissions().readonly()
-        } else {
-            // the path does not exist, so try and create it. If this succeeds, the filesystem is not read only
-            std::fs::create_dir_all(&base_path).map_err(FilesystemStoreCreateError::IOError)?;
-            std::fs::remove_dir(&base_path)?;
-            false
-        };
+        let file_handles = new_file_handle_cache(options.file_handle_limit);
 
         Ok(Self {
             base_path,
@@ -134,6 +241,7 @@ impl FilesystemStore {
             options,
             readonly,
             files: Mutex::default(),
+            file_handles,
         })
     }
 
@@ -147,34 +255,13 @@ impl FilesystemStore {
     /// Maps a [`StoreKey`] to a filesystem [`PathBuf`].
     #[must_use]
     pub fn key_to_fspath(&self, key: &StoreKey) -> PathBuf {
-        let mut path = self.base_path.clone();
-        if !key.as_str().is_empty() {
-            path.push(key.as_str().strip_prefix('/').unwrap_or(key.as_str()));
-        }
-        path
-    }
-
-    /// Maps a filesystem [`PathBuf`] to a [`StoreKey`].
-    fn fspath_to_key(&self, path: &std::path::Path) -> Result<StoreKey, StoreKeyError> {
-        let path = pathdiff::diff_paths(path, &self.base_path)
-            .ok_or_else(|| StoreKeyError::from(path.to_str().unwrap_or_default().to_string()))?;
-        let path_str = path.to_string_lossy();
-        #[cfg(target_os = "windows")]
-        {
-            StoreKey::new(path_str.replace('\\', "/"))
-        }
-        #[cfg(not(target_os = "windows"))]
-        {
-            StoreKey::new(path_str)
-        }
+        key_to_fspath(&self.base_path, key)
     }
 
     /// Maps a store [`StorePrefix`] to a filesystem [`PathBuf`].
     #[must_use]
     pub fn prefix_to_fs_path(&self, prefix: &StorePrefix) -> PathBuf {
-        let mut path = self.base_path.clone();
-        path.push(prefix.as_str());
-        path
+        prefix_to_fs_path(&self.base_path, prefix)
     }
 
     fn get_file_mutex(&self, key: &StoreKey) -> Arc<RwLock<()>> {
@@ -197,55 +284,16 @@ impl FilesystemStore {
         let file = self.get_file_mutex(key);
         let _lock = file.write();
 
-        // Create directories
-        let key_path = self.key_to_fspath(key);
-        if let Some(parent) = key_path.parent() {
-            if !parent.exists() {
-                std::fs::create_dir_all(parent)?;
-            }
-        }
-
-        let mut flags = OpenOptions::new();
-        flags.write(true).create(true).truncate(truncate);
-
-        // TODO: for now, only Linux support; also no support for `offset != 0`
-        let enable_direct =
-            cfg!(target_os = "linux") && self.options.direct_io && offset == 0 && !value.is_empty();
-
-        // If `value` is already page-size aligned, we don't need to copy.
-        let need_copy = value.as_ptr().align_offset(page_size::get()) != 0
-            || value.len() % page_size::get() != 0;
-
-        #[cfg(target_os = "linux")]
-        if enable_direct {
-            flags.custom_flags(O_DIRECT);
-        }
-
-        let mut file = flags.open(key_path)?;
-
-        // Write
-        if enable_direct {
-            if need_copy {
-                let mut buf = bytes_aligned(value.len());
-                buf.extend_from_slice(value);
-
-                // Pad to page size
-                let pad_size = buf.len().next_multiple_of(page_size::get()) - buf.len();
-                buf.extend(std::iter::repeat(0).take(pad_size));
-
-                file.write_all(&buf)?;
-            } else {
-                file.write_all(value)?;
-            }
-
-            // Truncate again to requested size
-            file.set_len(value.len() as u64)?;
-        } else {
-            file.seek(SeekFrom::Start(offset))?;
-            file.write_all(value)?;
-        }
-
-        Ok(())
+        let enable_direct = direct_io_enabled(self.options.direct_io, value);
+        write_key(
+            key,
+            &self.key_to_fspath(key),
+            value,
+            offset,
+            truncate,
+            enable_direct,
+            self.file_handles.as_ref(),
+        )
     }
 }
 
@@ -258,51 +306,74 @@ impl ReadableStorageTraits for FilesystemStore {
         let file = self.get_file_mutex(key);
         let _lock = file.read();
 
-        let mut file = match File::open(self.key_to_fspath(key)) {
-            Ok(file) => file,
-            Err(err) => {
-                if err.kind() == std::io::ErrorKind::NotFound {
-                    return Ok(None);
-                }
-                return Err(err.into());
-            }
+        let Some(file) =
+            open_file_cached(key, &self.key_to_fspath(key), self.file_handles.as_ref())?
+        else {
+            return Ok(None);
         };
 
-        let mut out = Vec::with_capacity(byte_ranges.len());
-        for byte_range in byte_ranges {
-            let bytes = {
-                // Seek
-                match byte_range {
-                    ByteRange::FromStart(offset, _) => file.seek(SeekFrom::Start(*offset)),
-                    ByteRange::Suffix(length) => {
-                        file.seek(SeekFrom::End(-(i64::try_from(*length).unwrap())))
-                    }
-                }?;
-
-                // Read
-                match byte_range {
-                    ByteRange::FromStart(_, None) => {
-                        let mut buffer = Vec::new();
-                        file.read_to_end(&mut buffer)?;
-                        buffer
-                    }
-                    ByteRange::FromStart(_, Some(length)) | ByteRange::Suffix(length) => {
-                        let length = usize::try_from(*length).unwrap();
-                        let mut buffer = vec![0; length];
-                        file.read_exact(&mut buffer)?;
-                        buffer
-                    }
-                }
-            };
-            out.push(Bytes::from(bytes));
+        Ok(Some(read_byte_ranges(&file, byte_ranges)?))
+    }
+
+    /// Retrieve partial bytes from a list of [`StoreKeyRange`].
+    ///
+    /// Byte ranges are grouped by key, and the groups are read in parallel.
+    /// Each file is opened once and all of its byte ranges are read with positional reads.
+    fn get_partial_values(
+        &self,
+        key_ranges: &[StoreKeyRange],
+    ) -> Result<Vec<MaybeBytes>, StorageError> {
+        // Group the byte ranges by key, retaining their position in the output
+        let mut groups: Vec<(&StoreKey, Vec<usize>, Vec<ByteRange>)> = Vec::new();
+        let mut group_indices: HashMap<&StoreKey, usize> = HashMap::new();
+        for (index, key_range) in key_ranges.iter().enumerate() {
+            let group_index = *group_indices.entry(key_range.key()).or_insert_with(|| {
+                groups.push((key_range.key(), Vec::new(), Vec::new()));
+                groups.len() - 1
+            });
+            let (_, indices, byte_ranges) = &mut groups[group_index];
+            indices.push(index);
+            byte_ranges.push(*key_range.byte_range());
         }
 
-        Ok(Some(out))
+        let read_group = |(key, indices, byte_ranges): (&StoreKey, Vec<usize>, Vec<ByteRange>)| {
+            self.get_partial_values_key(key, &byte_ranges)
+                .map(|values| (indices, values))
+        };
+        let groups: Vec<_> = if groups.len() > 1 {
+            groups
+                .into_par_iter()
+                .map(read_group)
+                .collect::<Result<_, _>>()?
+        } else {
+            groups
+                .into_iter()
+                .map(read_group)
+                .collect::<Result<_, _>>()?
+        };
+
+        // Scatter the values of each group to the order of the key ranges
+        let mut out: Vec<MaybeBytes> = vec![None; key_ranges.len()];
+        for (indices, values) in groups {
+            if let Some(values) = values {
+                for (index, value) in indices.into_iter().zip(values) {
+                    out[index] = Some(value);
+                }
+            }
+        }
+        Ok(out)
     }
 
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
-        let key_path = self.key_to_fspath(key);
-        std::fs::metadata(key_path).map_or_else(|_| Ok(None), |metadata| Ok(Some(metadata.len())))
+        size_file_cached(key, &self.key_to_fspath(key), self.file_handles.as_ref())
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        StoreCapabilities::new()
+            .with_writable(!self.readonly)
+            .with_listing(true)
+            .with_partial_read(true)
+            .with_partial_write(!self.readonly)
     }
 }
 
@@ -323,7 +394,19 @@ impl WritableStorageTraits for FilesystemStore {
             return Err(StorageError::ReadOnly);
         }
 
-        store_set_partial_values(self, key_offset_values)
+        for key_offset_value in key_offset_values {
+            self.set_impl(
+                key_offset_value.key(),
+                key_offset_value.value(),
+                key_offset_value.offset(),
+                false,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.capabilities().supports_partial_write()
     }
 
     fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
@@ -334,16 +417,10 @@ impl WritableStorageTraits for FilesystemStore {
         let file = self.get_file_mutex(key);
         let _lock = file.write();
 
-        let key_path = self.key_to_fspath(key);
-        let result = std::fs::remove_file(key_path);
-        if let Err(err) = result {
-            match err.kind() {
-                std::io::ErrorKind::NotFound => Ok(()),
-                _ => Err(err.into()),
-            }
-        } else {
-            Ok(())
-        }
+        // Close any open handle first, otherwise the file cannot be removed on Windows
+        evict_file_handle(key, self.file_handles.as_ref());
+
+        remove_file(&self.key_to_fspath(key))
     }
 
     fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
@@ -353,81 +430,27 @@ impl WritableStorageTraits for FilesystemStore {
 
         let _lock = self.files.lock(); // lock all operations
 
-        let prefix_path = self.prefix_to_fs_path(prefix);
-        let result = std::fs::remove_dir_all(prefix_path);
-        if let Err(err) = result {
-            match err.kind() {
-                std::io::ErrorKind::NotFound => Ok(()),
-                _ => Err(err.into()),
-            }
-        } else {
-            Ok(())
-        }
+        evict_file_handles_prefix(prefix, self.file_handles.as_ref());
+
+        remove_dir_all(&self.prefix_to_fs_path(prefix))
     }
 }
 
 impl ListableStorageTraits for FilesystemStore {
     fn list(&self) -> Result<StoreKeys, StorageError> {
-        Ok(WalkDir::new(&self.base_path)
-            .sort_by_file_name()
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-            .filter(|v| v.path().is_file())
-            .filter_map(|v| self.fspath_to_key(v.path()).ok())
-            .collect())
+        Ok(list_files(&self.base_path, &self.base_path))
     }
 
     fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
-        Ok(WalkDir::new(self.prefix_to_fs_path(prefix))
-            .sort_by_file_name()
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-            .filter(|v| v.path().is_file())
-            .filter_map(|v| self.fspath_to_key(v.path()).ok())
-            .collect())
+        Ok(list_files(&self.base_path, &self.prefix_to_fs_path(prefix)))
     }
 
     fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
-        let prefix_path = self.prefix_to_fs_path(prefix);
-        let mut keys: StoreKeys = vec![];
-        let mut prefixes: StorePrefixes = vec![];
-        let dir = std::fs::read_dir(prefix_path);
-        if let Ok(dir) = dir {
-            for entry in dir {
-                let entry = entry?;
-                let fs_path = entry.path();
-                let path = fs_path.file_name().unwrap();
-                if fs_path.is_dir() {
-                    prefixes.push(StorePrefix::new(
-                        prefix.as_str().to_string() + path.to_str().unwrap() + "/",
-                    )?);
-                } else {
-                    keys.push(StoreKey::new(
-                        prefix.as_str().to_owned() + path.to_str().unwrap(),
-                    )?);
-                }
-            }
-        }
-        if self.sort {
-            keys.sort();
-            prefixes.sort();
-        }
-
-        Ok(StoreKeysPrefixes::new(keys, prefixes))
+        list_dir(&self.prefix_to_fs_path(prefix), prefix, self.sort)
     }
 
     fn size(&self) -> Result<u64, StorageError> {
-        Ok(WalkDir::new(&self.base_path)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-            .filter_map(|v| {
-                if v.path().is_file() {
-                    Some(std::fs::metadata(v.path()).unwrap().len())
-                } else {
-                    None
-                }
-            })
-            .sum())
+        Ok(size_dir(&self.base_path))
     }
 
     fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
@@ -441,6 +464,440 @@ impl ListableStorageTraits for FilesystemStore {
     }
 }
 
+/// Check that `base_path` is valid and return whether or not it is read only.
+fn base_path_readonly(base_path: &Path) -> Result<bool, FilesystemStoreCreateError> {
+    if base_path.to_str().is_none() {
+        return Err(FilesystemStoreCreateError::InvalidBasePath(
+            base_path.to_path_buf(),
+        ));
+    }
+
+    if base_path.exists() {
+        // the path already exists, check if it is read only
+        let md = std::fs::metadata(base_path).map_err(FilesystemStoreCreateError::IOError)?;
+        Ok(md.permissions().readonly())
+    } else {
+        // the path does not exist, so try and create it. If this succeeds, the filesystem is not read only
+        std::fs::create_dir_all(base_path).map_err(FilesystemStoreCreateError::IOError)?;
+        std::fs::remove_dir(base_path)?;
+        Ok(false)
+    }
+}
+
+/// Maps a [`StoreKey`] to a filesystem [`PathBuf`] relative to `base_path`.
+fn key_to_fspath(base_path: &Path, key: &StoreKey) -> PathBuf {
+    let mut path = base_path.to_path_buf();
+    if !key.as_str().is_empty() {
+        path.push(key.as_str().strip_prefix('/').unwrap_or(key.as_str()));
+    }
+    path
+}
+
+/// Maps a filesystem [`Path`] to a [`StoreKey`] relative to `base_path`.
+fn fspath_to_key(base_path: &Path, path: &Path) -> Result<StoreKey, StoreKeyError> {
+    let path = pathdiff::diff_paths(path, base_path)
+        .ok_or_else(|| StoreKeyError::from(path.to_str().unwrap_or_default().to_string()))?;
+    let path_str = path.to_string_lossy();
+    #[cfg(target_os = "windows")]
+    {
+        StoreKey::new(path_str.replace('\\', "/"))
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        StoreKey::new(path_str)
+    }
+}
+
+/// Maps a store [`StorePrefix`] to a filesystem [`PathBuf`] relative to `base_path`.
+fn prefix_to_fs_path(base_path: &Path, prefix: &StorePrefix) -> PathBuf {
+    let mut path = base_path.to_path_buf();
+    path.push(prefix.as_str());
+    path
+}
+
+/// Returns true if a write of `value` at `offset` should use direct I/O.
+fn direct_io_enabled(direct_io: bool, value: &[u8]) -> bool {
+    // TODO: for now, only Linux support
+    cfg!(target_os = "linux") && direct_io && !value.is_empty()
+}
+
+/// Open the file at `path` for reading.
+///
+/// Returns [`None`] if the file does not exist.
+fn open_file(path: &Path) -> std::io::Result<Option<File>> {
+    match File::open(path) {
+        Ok(file) => Ok(Some(file)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Open the file at `key_path` for reading, reusing a handle in `file_handles` if available.
+///
+/// Returns [`None`] if the file does not exist.
+fn open_file_cached(
+    key: &StoreKey,
+    key_path: &Path,
+    file_handles: Option<&FileHandleCache>,
+) -> std::io::Result<Option<Arc<File>>> {
+    let Some(file_handles) = file_handles else {
+        return Ok(open_file(key_path)?.map(Arc::new));
+    };
+
+    let handle = file_handles.lock().unwrap().get(key).cloned();
+    if let Some(handle) = handle {
+        return Ok(Some(handle.file));
+    }
+
+    let Some(file) = open_file(key_path)? else {
+        return Ok(None);
+    };
+    let file = Arc::new(file);
+    file_handles.lock().unwrap().put(
+        key.clone(),
+        FileHandle {
+            file: file.clone(),
+            writable: false,
+        },
+    );
+    Ok(Some(file))
+}
+
+/// Open the file at `key_path` for writing via the file handle cache.
+///
+/// A cached handle that was opened read-only is replaced by a handle with write permissions.
+fn open_file_write_cached(
+    key: &StoreKey,
+    key_path: &Path,
+    file_handles: &FileHandleCache,
+) -> std::io::Result<Arc<File>> {
+    let handle = file_handles.lock().unwrap().get(key).cloned();
+    if let Some(handle) = handle {
+        if handle.writable {
+            return Ok(handle.file);
+        }
+    }
+
+    create_parent_dirs(key_path)?;
+    let file = Arc::new(
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(key_path)?,
+    );
+    file_handles.lock().unwrap().put(
+        key.clone(),
+        FileHandle {
+            file: file.clone(),
+            writable: true,
+        },
+    );
+    Ok(file)
+}
+
+/// Close the cached file handle of `key`.
+fn evict_file_handle(key: &StoreKey, file_handles: Option<&FileHandleCache>) {
+    if let Some(file_handles) = file_handles {
+        file_handles.lock().unwrap().pop(key);
+    }
+}
+
+/// Close the cached file handles of all keys with a given prefix.
+fn evict_file_handles_prefix(prefix: &StorePrefix, file_handles: Option<&FileHandleCache>) {
+    if let Some(file_handles) = file_handles {
+        let mut file_handles = file_handles.lock().unwrap();
+        let keys: Vec<StoreKey> = file_handles
+            .iter()
+            .map(|(key, _)| key)
+            .filter(|key| key.has_prefix(prefix))
+            .cloned()
+            .collect();
+        for key in keys {
+            file_handles.pop(&key);
+        }
+    }
+}
+
+/// Write `value` to the file of `key` at `key_path` starting at `offset`, via a handle in `file_handles` if enabled.
+///
+/// If `truncate` is true, the file is truncated to the end of `value`.
+/// Writes with direct I/O do not use the file handle cache.
+fn write_key(
+    key: &StoreKey,
+    key_path: &Path,
+    value: &[u8],
+    offset: ByteOffset,
+    truncate: bool,
+    enable_direct: bool,
+    file_handles: Option<&FileHandleCache>,
+) -> Result<(), StorageError> {
+    if let (Some(file_handles), false) = (file_handles, enable_direct) {
+        let file = open_file_write_cached(key, key_path, file_handles)?;
+        write_all_at(&file, value, offset)?;
+        if truncate {
+            file.set_len(offset + value.len() as u64)?;
+        }
+        return Ok(());
+    }
+
+    write_file(key_path, value, offset, truncate, enable_direct)
+}
+
+/// Returns the size of the file of `key` at `key_path`, or [`None`] if it does not exist.
+///
+/// A handle in `file_handles` is used if available.
+fn size_file_cached(
+    key: &StoreKey,
+    key_path: &Path,
+    file_handles: Option<&FileHandleCache>,
+) -> Result<Option<u64>, StorageError> {
+    if let Some(file_handles) = file_handles {
+        let handle = file_handles.lock().unwrap().get(key).cloned();
+        if let Some(handle) = handle {
+            return Ok(Some(handle.file.metadata()?.len()));
+        }
+    }
+
+    Ok(size_file(key_path))
+}
+
+/// Read `byte_ranges` from `file`.
+///
+/// The byte ranges are validated against the size of the file before any are read.
+fn read_byte_ranges(file: &File, byte_ranges: &[ByteRange]) -> Result<Vec<Bytes>, StorageError> {
+    let size = file.metadata()?.len();
+    validate_byte_ranges(byte_ranges, size)?;
+    let mut out = Vec::with_capacity(byte_ranges.len());
+    for byte_range in byte_ranges {
+        let length = byte_range.length(size);
+        if length == 0 {
+            out.push(Bytes::new());
+            continue;
+        }
+        let mut buffer = vec![0; usize::try_from(length).unwrap()];
+        read_exact_at(file, &mut buffer, byte_range.start(size))?;
+        out.push(Bytes::from(buffer));
+    }
+    Ok(out)
+}
+
+/// Write `value` to the file at `key_path` starting at `offset`, creating the file if it does not exist.
+///
+/// If `truncate` is true, the file is truncated before writing.
+fn write_file(
+    key_path: &Path,
+    value: &[u8],
+    offset: ByteOffset,
+    truncate: bool,
+    enable_direct: bool,
+) -> Result<(), StorageError> {
+    // Create directories
+    create_parent_dirs(key_path)?;
+
+    // Writes at an offset or without truncation need a read-modify-write of the boundary pages
+    let read_modify_write = enable_direct && (offset != 0 || !truncate);
+
+    let mut flags = OpenOptions::new();
+    flags
+        .read(read_modify_write)
+        .write(true)
+        .create(true)
+        .truncate(truncate);
+
+    // If `value` is already page-size aligned, we don't need to copy.
+    let need_copy =
+        value.as_ptr().align_offset(page_size::get()) != 0 || value.len() % page_size::get() != 0;
+
+    #[cfg(target_os = "linux")]
+    if enable_direct {
+        flags.custom_flags(O_DIRECT);
+    }
+
+    let mut file = flags.open(key_path)?;
+
+    // Write
+    if read_modify_write {
+        write_direct_at(&mut file, value, offset, truncate)?;
+    } else if enable_direct {
+        if need_copy {
+            let mut buf = bytes_aligned(value.len());
+            buf.extend_from_slice(value);
+
+            // Pad to page size
+            let pad_size = buf.len().next_multiple_of(page_size::get()) - buf.len();
+            buf.extend(std::iter::repeat(0).take(pad_size));
+
+            file.write_all(&buf)?;
+        } else {
+            file.write_all(value)?;
+        }
+
+        // Truncate again to requested size
+        file.set_len(value.len() as u64)?;
+    } else {
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(value)?;
+    }
+
+    Ok(())
+}
+
+/// Write `value` to a `file` opened with `O_DIRECT` starting at an arbitrary `offset`.
+///
+/// The write is expanded to page boundaries.
+/// The existing content of the leading and trailing partial pages is read and merged with `value` so that it is preserved.
+fn write_direct_at(
+    file: &mut File,
+    value: &[u8],
+    offset: ByteOffset,
+    truncate: bool,
+) -> std::io::Result<()> {
+    let page_len = page_size::get();
+    let page_size = page_len as u64;
+    let size = file.metadata()?.len();
+    let end = offset + value.len() as u64;
+    let aligned_start = offset - offset % page_size;
+    let aligned_end = end.next_multiple_of(page_size);
+    let aligned_len = usize::try_from(aligned_end - aligned_start).unwrap();
+
+    let mut buf = bytes_aligned(aligned_len);
+    buf.resize(aligned_len, 0);
+
+    // Read the existing content of the leading and trailing partial pages
+    let mut read_page = |page_start: u64| -> std::io::Result<()> {
+        if page_start >= size {
+            return Ok(());
+        }
+        let buf_start = usize::try_from(page_start - aligned_start).unwrap();
+        let page = &mut buf[buf_start..buf_start + page_len];
+        file.seek(SeekFrom::Start(page_start))?;
+        let mut filled = 0;
+        while filled < page.len() {
+            match file.read(&mut page[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    };
+    let head_partial = offset % page_size != 0;
+    let tail_partial = end % page_size != 0;
+    let tail_start = aligned_end - page_size;
+    if head_partial {
+        read_page(aligned_start)?;
+    }
+    if tail_partial && !(head_partial && tail_start == aligned_start) {
+        read_page(tail_start)?;
+    }
+
+    // Merge and write the aligned pages
+    let value_start = usize::try_from(offset - aligned_start).unwrap();
+    buf[value_start..value_start + value.len()].copy_from_slice(value);
+    file.seek(SeekFrom::Start(aligned_start))?;
+    file.write_all(&buf)?;
+
+    // Remove the padding beyond the end of the value
+    file.set_len(if truncate { end } else { end.max(size) })?;
+
+    Ok(())
+}
+
+/// Returns the size of the file at `path`, or [`None`] if it does not exist.
+fn size_file(path: &Path) -> Option<u64> {
+    std::fs::metadata(path).ok().map(|metadata| metadata.len())
+}
+
+/// Remove the file at `path`. Succeeds if the file does not exist.
+fn remove_file(path: &Path) -> Result<(), StorageError> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Remove the directory at `path` and all of its contents. Succeeds if the directory does not exist.
+fn remove_dir_all(path: &Path) -> Result<(), StorageError> {
+    match std::fs::remove_dir_all(path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// List the keys of all files under `path`.
+fn list_files(base_path: &Path, path: &Path) -> StoreKeys {
+    WalkDir::new(path)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(std::result::Result::ok)
+        .filter(|v| v.path().is_file())
+        .filter_map(|v| fspath_to_key(base_path, v.path()).ok())
+        .collect()
+}
+
+/// List the keys and prefixes directly under `prefix` at `prefix_path`.
+fn list_dir(
+    prefix_path: &Path,
+    prefix: &StorePrefix,
+    sort: bool,
+) -> Result<StoreKeysPrefixes, StorageError> {
+    let mut keys: StoreKeys = vec![];
+    let mut prefixes: StorePrefixes = vec![];
+    let dir = std::fs::read_dir(prefix_path);
+    if let Ok(dir) = dir {
+        for entry in dir {
+            let entry = entry?;
+            let fs_path = entry.path();
+            let path = fs_path.file_name().unwrap();
+            if fs_path.is_dir() {
+                prefixes.push(StorePrefix::new(
+                    prefix.as_str().to_string() + path.to_str().unwrap() + "/",
+                )?);
+            } else {
+                keys.push(StoreKey::new(
+                    prefix.as_str().to_owned() + path.to_str().unwrap(),
+                )?);
+            }
+        }
+    }
+    if sort {
+        keys.sort();
+        prefixes.sort();
+    }
+
+    Ok(StoreKeysPrefixes::new(keys, prefixes))
+}
+
+/// Returns the total size of all files under `path`.
+fn size_dir(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(std::result::Result::ok)
+        .filter_map(|v| {
+            if v.path().is_file() {
+                Some(std::fs::metadata(v.path()).unwrap().len())
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+/// Create the parent directories of `path` if they do not exist.
+fn create_parent_dirs(path: &Path) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.exists() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+    Ok(())
+}
+
 /// A filesystem store creation error.
 #[derive(Debug, Error)]
 pub enum FilesystemStoreCreateError {
@@ -465,7 +922,148 @@ mod tests {
         // let store = FilesystemStore::new("tests/data/store")?.sorted();
         zarrs_storage::store_test::store_write(&store)?;
         zarrs_storage::store_test::store_read(&store)?;
+        zarrs_storage::store_test::store_read_edge_byte_ranges(
+            &store,
+            &"a/b".try_into()?,
+            &"a/d/e".try_into()?,
+        )?;
         zarrs_storage::store_test::store_list(&store)?;
+        zarrs_storage::store_test::store_health_check(&store)?;
+        let capabilities = store.capabilities();
+        assert!(capabilities.supports_listing());
+        assert!(capabilities.supports_partial_read());
+        assert!(capabilities.supports_partial_write());
+        assert!(!capabilities.supports_atomic_put());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn filesystem_file_handle_cache() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut opts = FilesystemStoreOptions::default();
+        opts.file_handle_limit(2);
+
+        let store = FilesystemStore::new_with_options(path.path(), opts)?.sorted();
+        zarrs_storage::store_test::store_write(&store)?;
+        zarrs_storage::store_test::store_read(&store)?;
+        zarrs_storage::store_test::store_read_edge_byte_ranges(
+            &store,
+            &"a/b".try_into()?,
+            &"a/d/e".try_into()?,
+        )?;
+        zarrs_storage::store_test::store_list(&store)?;
+
+        // A read-only cached handle is reopened for writing
+        let key_x: StoreKey = "x".try_into()?;
+        let key_y: StoreKey = "y".try_into()?;
+        let key_z: StoreKey = "z".try_into()?;
+        store.set(&key_x, vec![0, 1, 2, 3].into())?;
+        store.set(&key_y, vec![0].into())?;
+        store.set(&key_z, vec![0].into())?; // evicts x
+        assert_eq!(store.get(&key_x)?, Some(vec![0, 1, 2, 3].into()));
+        store.set(&key_x, vec![4, 5].into())?;
+        assert_eq!(store.get(&key_x)?, Some(vec![4, 5].into()));
+        assert_eq!(store.size_key(&key_x)?, Some(2));
+
+        // Erasing closes the cached handle
+        store.erase(&key_x)?;
+        assert!(store.get(&key_x)?.is_none());
+        assert!(store.size_key(&key_x)?.is_none());
+        store.erase_prefix(&StorePrefix::root())?;
+        assert!(store.get(&key_y)?.is_none());
+        assert!(store.list()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn filesystem_get_partial_values_order() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let store = FilesystemStore::new(path.path())?;
+        let key_a: StoreKey = "a".try_into()?;
+        let key_b: StoreKey = "b/c".try_into()?;
+        let key_missing: StoreKey = "missing".try_into()?;
+        store.set(&key_a, vec![0, 1, 2, 3, 4, 5].into())?;
+        store.set(&key_b, vec![10, 11, 12, 13].into())?;
+
+        // Interleaved keys, a missing key, and repeated byte ranges
+        let key_ranges = [
+            StoreKeyRange::new(key_b.clone(), ByteRange::FromStart(1, Some(2))),
+            StoreKeyRange::new(key_a.clone(), ByteRange::Suffix(2)),
+            StoreKeyRange::new(key_missing.clone(), ByteRange::FromStart(0, None)),
+            StoreKeyRange::new(key_a.clone(), ByteRange::FromStart(0, Some(1))),
+            StoreKeyRange::new(key_b.clone(), ByteRange::FromStart(0, None)),
+            StoreKeyRange::new(key_b.clone(), ByteRange::FromStart(1, Some(2))),
+        ];
+        assert_eq!(
+            store.get_partial_values(&key_ranges)?,
+            [
+                Some(vec![11, 12].into()),
+                Some(vec![4, 5].into()),
+                None,
+                Some(vec![0].into()),
+                Some(vec![10, 11, 12, 13].into()),
+                Some(vec![11, 12].into()),
+            ]
+        );
+        assert!(store.get_partial_values(&[])?.is_empty());
+
+        // An invalid byte range is an error
+        assert!(store
+            .get_partial_values(&[
+                StoreKeyRange::new(key_b.clone(), ByteRange::FromStart(0, None)),
+                StoreKeyRange::new(key_a.clone(), ByteRange::Suffix(10)),
+            ])
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn filesystem_get_partial_values_many_keys() -> Result<(), Box<dyn Error>> {
+        const NUM_KEYS: u8 = 48;
+        const VALUE_LEN: u64 = 64 * 1024;
+
+        let path = tempfile::TempDir::new()?;
+        let mut opts = FilesystemStoreOptions::default();
+        opts.file_handle_limit(16);
+        let store = FilesystemStore::new_with_options(path.path(), opts)?;
+
+        let keys = (0..NUM_KEYS)
+            .map(|i| StoreKey::new(format!("c/{i}")))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (i, key) in (0..NUM_KEYS).zip(&keys) {
+            let value: Vec<u8> = (0..VALUE_LEN).map(|j| i.wrapping_add(j as u8)).collect();
+            store.set(key, value.into())?;
+        }
+
+        // Many byte ranges per key, requested in an order that alternates between keys
+        let key_ranges: Vec<StoreKeyRange> = (0..VALUE_LEN / 1024)
+            .flat_map(|chunk| {
+                keys.iter().rev().map(move |key| {
+                    StoreKeyRange::new(
+                        key.clone(),
+                        ByteRange::FromStart(chunk * 1024 + 3, Some(100)),
+                    )
+                })
+            })
+            .collect();
+
+        let parallel = store.get_partial_values(&key_ranges)?;
+        let sequential = store.get_partial_values_batched_by_key(&key_ranges)?;
+        assert_eq!(parallel, sequential);
+        for (key_range, value) in key_ranges.iter().zip(&parallel) {
+            let i: u8 = key_range.key().as_str()[2..].parse()?;
+            let ByteRange::FromStart(offset, _) = key_range.byte_range() else {
+                unreachable!()
+            };
+            let expected: Vec<u8> = (*offset..*offset + 100)
+                .map(|j| i.wrapping_add(j as u8))
+                .collect();
+            assert_eq!(value.as_deref(), Some(expected.as_slice()));
+        }
         Ok(())
     }
 
@@ -480,7 +1078,75 @@ mod tests {
         let store = FilesystemStore::new_with_options(path.path(), opts)?.sorted();
         zarrs_storage::store_test::store_write(&store)?;
         zarrs_storage::store_test::store_read(&store)?;
+        zarrs_storage::store_test::store_read_edge_byte_ranges(
+            &store,
+            &"a/b".try_into()?,
+            &"a/d/e".try_into()?,
+        )?;
         zarrs_storage::store_test::store_list(&store)?;
+        zarrs_storage::store_test::store_health_check(&store)?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn direct_io_set_partial_values() -> Result<(), Box<dyn Error>> {
+        let page_size = page_size::get();
+
+        let path_buffered = tempfile::TempDir::new()?;
+        let store_buffered = FilesystemStore::new(path_buffered.path())?;
+        let path_direct = tempfile::TempDir::new()?;
+        let mut opts = FilesystemStoreOptions::default();
+        opts.direct_io(true);
+        let store_direct = FilesystemStore::new_with_options(path_direct.path(), opts)?;
+
+        let key: StoreKey = "a/b".try_into()?;
+        let initial: Vec<u8> = (0..3 * page_size + 100)
+            .map(|i| u8::try_from(i % 251).unwrap())
+            .collect();
+        let value_a = vec![1; 20];
+        let value_b = vec![2; 10];
+        let value_c = vec![3; page_size];
+        let value_d = vec![4; 30];
+        let value_e = vec![5; 2 * page_size];
+        let key_offset_values = [
+            // within the first page
+            StoreKeyOffsetValue::new(key.clone(), 10, &value_a),
+            // across a page boundary
+            StoreKeyOffsetValue::new(key.clone(), page_size as u64 - 5, &value_b),
+            // starting and ending mid-page
+            StoreKeyOffsetValue::new(key.clone(), 2 * page_size as u64 + 7, &value_c),
+            // beyond the end of the file
+            StoreKeyOffsetValue::new(key.clone(), initial.len() as u64 + 50, &value_d),
+            // page aligned
+            StoreKeyOffsetValue::new(key.clone(), page_size as u64, &value_e),
+        ];
+
+        for store in [&store_buffered, &store_direct] {
+            store.set(&key, initial.clone().into())?;
+            store.set_partial_values(&key_offset_values)?;
+        }
+
+        let mut expected = initial.clone();
+        expected.resize(initial.len() + 80, 0);
+        for key_offset_value in &key_offset_values {
+            let offset = usize::try_from(key_offset_value.offset())?;
+            expected[offset..offset + key_offset_value.value().len()]
+                .copy_from_slice(key_offset_value.value());
+        }
+        let buffered = store_buffered.get(&key)?.unwrap();
+        let direct = store_direct.get(&key)?.unwrap();
+        assert_eq!(buffered, expected);
+        assert_eq!(direct, buffered);
+
+        // A partial write to a missing key
+        let key_new: StoreKey = "c".try_into()?;
+        for store in [&store_buffered, &store_direct] {
+            store.set_partial_values(&[StoreKeyOffsetValue::new(key_new.clone(), 5, &value_a)])?;
+        }
+        assert_eq!(store_direct.get(&key_new)?, store_buffered.get(&key_new)?);
+        assert_eq!(store_direct.size_key(&key_new)?, Some(25));
         Ok(())
     }
 }
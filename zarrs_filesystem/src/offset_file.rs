@@ -0,0 +1,693 @@
+//! An experimental single file container store.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs::{File, OpenOptions},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use zarrs_storage::{
+    byte_range::{validate_byte_ranges, ByteRange},
+    store_set_partial_values, Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix,
+    WritableStorageTraits,
+};
+
+#[cfg(target_os = "linux")]
+use libc::O_DIRECT;
+#[cfg(target_os = "linux")]
+use std::os::unix::fs::OpenOptionsExt;
+
+use crate::{
+    bytes_aligned, read_exact_at, write_all_at, FilesystemStore, FilesystemStoreCreateError,
+    FilesystemStoreOptions,
+};
+
+/// The file name of the layout descriptor of an [`OffsetFileStore`].
+const LAYOUT_FILE_NAME: &str = "zarrs_offset_file.json";
+
+/// The file name of the chunk slot data file of an [`OffsetFileStore`].
+const DATA_FILE_NAME: &str = "chunks.bin";
+
+/// The file name of the chunk slot index file of an [`OffsetFileStore`].
+const INDEX_FILE_NAME: &str = "chunks.index";
+
+/// The directory name of the companion store of an [`OffsetFileStore`] holding all non-chunk keys.
+const META_DIRECTORY_NAME: &str = "meta";
+
+/// The size of an entry in the chunk slot index file.
+const INDEX_ENTRY_SIZE: usize = 8;
+
+/// The layout of the chunk slots of an [`OffsetFileStore`].
+///
+/// A chunk key is the `chunk_key_prefix` followed by the chunk indices joined by the `chunk_key_separator`.
+/// For example, the chunks of an array at `/array` with the `default` chunk key encoding have the prefix `array/c/` and the separator `/`.
+/// With the `v2` chunk key encoding and the `.` separator, the prefix is `array/` and the separator is `.` (e.g. `array/0.1`).
+///
+/// Each chunk is assigned a fixed-size slot in the data file in C order of its chunk indices.
+/// The slot of a chunk starts at its linear index multiplied by the [`slot_stride`](OffsetFileLayout::slot_stride).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffsetFileLayout {
+    chunk_key_prefix: String,
+    chunk_key_separator: char,
+    grid_shape: Vec<u64>,
+    slot_size: u64,
+    slot_alignment: u64,
+}
+
+impl OffsetFileLayout {
+    /// Create a new offset file layout.
+    ///
+    /// `grid_shape` is the shape of the chunk grid and `slot_size` is the maximum size of an encoded chunk.
+    /// For an array without compression, the slot size is the number of elements in a chunk multiplied by the data type size.
+    ///
+    /// The slot alignment defaults to the page size, which is required for direct I/O.
+    #[must_use]
+    pub fn new(
+        chunk_key_prefix: impl Into<String>,
+        chunk_key_separator: char,
+        grid_shape: Vec<u64>,
+        slot_size: u64,
+    ) -> Self {
+        Self {
+            chunk_key_prefix: chunk_key_prefix.into(),
+            chunk_key_separator,
+            grid_shape,
+            slot_size,
+            slot_alignment: page_size::get() as u64,
+        }
+    }
+
+    /// Set the slot alignment in bytes.
+    #[must_use]
+    pub fn with_slot_alignment(mut self, slot_alignment: u64) -> Self {
+        self.slot_alignment = slot_alignment;
+        self
+    }
+
+    /// Returns the chunk key prefix.
+    #[must_use]
+    pub fn chunk_key_prefix(&self) -> &str {
+        &self.chunk_key_prefix
+    }
+
+    /// Returns the chunk key separator.
+    #[must_use]
+    pub const fn chunk_key_separator(&self) -> char {
+        self.chunk_key_separator
+    }
+
+    /// Returns the chunk grid shape.
+    #[must_use]
+    pub fn grid_shape(&self) -> &[u64] {
+        &self.grid_shape
+    }
+
+    /// Returns the slot size. This is the maximum size of a chunk.
+    #[must_use]
+    pub const fn slot_size(&self) -> u64 {
+        self.slot_size
+    }
+
+    /// Returns the slot alignment.
+    #[must_use]
+    pub const fn slot_alignment(&self) -> u64 {
+        self.slot_alignment
+    }
+
+    /// Returns the distance in bytes between the start of consecutive slots.
+    ///
+    /// This is the slot size rounded up to a multiple of the slot alignment.
+    #[must_use]
+    pub const fn slot_stride(&self) -> u64 {
+        self.slot_size.next_multiple_of(self.slot_alignment)
+    }
+
+    /// Returns the number of slots.
+    #[must_use]
+    pub fn num_slots(&self) -> u64 {
+        self.grid_shape.iter().product()
+    }
+
+    /// Returns the slot index of `key`, or [`None`] if `key` is not a chunk key within the grid.
+    #[must_use]
+    pub fn slot_index(&self, key: &StoreKey) -> Option<u64> {
+        let indices = key.as_str().strip_prefix(&self.chunk_key_prefix)?;
+        let indices: Vec<&str> = indices.split(self.chunk_key_separator).collect();
+        if indices.len() != self.grid_shape.len() {
+            return None;
+        }
+        let mut slot_index = 0;
+        for (index, shape) in indices.into_iter().zip(&self.grid_shape) {
+            let index_u64: u64 = index.parse().ok()?;
+            // Reject non-canonical indices (e.g. leading zeros)
+            if index_u64 >= *shape || index_u64.to_string() != index {
+                return None;
+            }
+            slot_index = slot_index * shape + index_u64;
+        }
+        Some(slot_index)
+    }
+
+    /// Returns the chunk key of the slot at `slot_index`.
+    ///
+    /// # Panics
+    /// Panics if the chunk key is not a valid [`StoreKey`].
+    #[must_use]
+    pub fn slot_key(&self, mut slot_index: u64) -> StoreKey {
+        let mut indices = vec![0; self.grid_shape.len()];
+        for (index, shape) in indices.iter_mut().zip(&self.grid_shape).rev() {
+            *index = slot_index % shape;
+            slot_index /= shape;
+        }
+        let indices = indices
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(&self.chunk_key_separator.to_string());
+        StoreKey::new(self.chunk_key_prefix.clone() + &indices).unwrap()
+    }
+
+    /// Check that the layout is valid.
+    fn validate(&self, direct_io: bool) -> Result<(), OffsetFileStoreCreateError> {
+        let invalid = |reason: &str| Err(OffsetFileStoreCreateError::InvalidLayout(reason.into()));
+        if self.grid_shape.is_empty() {
+            return invalid("the grid shape must have at least one dimension");
+        }
+        if self.slot_size == 0 || self.slot_alignment == 0 {
+            return invalid("the slot size and slot alignment must be non-zero");
+        }
+        if self
+            .slot_size
+            .checked_next_multiple_of(self.slot_alignment)
+            .is_none()
+            || self
+                .grid_shape
+                .iter()
+                .try_fold(self.slot_stride(), |size, shape| size.checked_mul(*shape))
+                .is_none()
+        {
+            return invalid("the data file size overflows");
+        }
+        if direct_io && self.slot_alignment % page_size::get() as u64 != 0 {
+            return invalid(
+                "the slot alignment must be a multiple of the page size for direct I/O",
+            );
+        }
+        Ok(())
+    }
+}
+
+/// An offset file store creation error.
+#[derive(Debug, Error)]
+pub enum OffsetFileStoreCreateError {
+    /// An IO error.
+    #[error(transparent)]
+    IOError(#[from] std::io::Error),
+    /// An error creating the companion store.
+    #[error(transparent)]
+    FilesystemStoreCreateError(#[from] FilesystemStoreCreateError),
+    /// The layout is invalid.
+    #[error("invalid offset file layout: {0}")]
+    InvalidLayout(String),
+}
+
+/// An experimental store that holds all chunks of an array in a single preallocated file.
+///
+/// Each chunk key is mapped to a fixed-size slot in the data file according to an [`OffsetFileLayout`].
+/// This avoids the file system metadata overhead of storing many small files, and is well suited to direct I/O acquisition workflows.
+///
+/// The store is a directory containing:
+///  - `zarrs_offset_file.json`: the [`OffsetFileLayout`],
+///  - `chunks.bin`: the chunk slots, preallocated on creation,
+///  - `chunks.index`: the size of the value in each slot (zero if the slot is empty), and
+///  - `meta/`: a [`FilesystemStore`] holding all keys that are not chunk keys of the layout (e.g. metadata).
+///
+/// Chunks must not exceed the slot size of the layout, so this store is only suitable for uncompressed chunks or chunks with a bounded encoded size.
+/// Writing a larger chunk returns an error.
+///
+/// If direct I/O is enabled in the [`FilesystemStoreOptions`], the data file is opened with `O_DIRECT` and entire slots are read and written.
+#[derive(Debug)]
+pub struct OffsetFileStore {
+    layout: OffsetFileLayout,
+    readonly: bool,
+    direct_io: bool,
+    data: File,
+    index: File,
+    meta: FilesystemStore,
+    slots: Mutex<HashMap<u64, Arc<RwLock<()>>>>,
+}
+
+impl OffsetFileStore {
+    /// Create a new offset file store at `path` with `layout`.
+    ///
+    /// The data file is sized to hold every slot of the layout.
+    ///
+    /// # Errors
+    /// Returns an [`OffsetFileStoreCreateError`] if the layout is invalid, a store already exists at `path`, or there is an IO error.
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        layout: &OffsetFileLayout,
+        options: FilesystemStoreOptions,
+    ) -> Result<Self, OffsetFileStoreCreateError> {
+        let path = path.as_ref();
+        layout.validate(options.direct_io)?;
+        std::fs::create_dir_all(path)?;
+
+        let layout_json = serde_json::to_vec_pretty(layout)
+            .map_err(|err| OffsetFileStoreCreateError::InvalidLayout(err.to_string()))?;
+        let mut layout_file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path.join(LAYOUT_FILE_NAME))?;
+        std::io::Write::write_all(&mut layout_file, &layout_json)?;
+
+        let data = File::create(path.join(DATA_FILE_NAME))?;
+        data.set_len(layout.num_slots() * layout.slot_stride())?;
+        let index = File::create(path.join(INDEX_FILE_NAME))?;
+        index.set_len(layout.num_slots() * INDEX_ENTRY_SIZE as u64)?;
+
+        Self::open(path, options)
+    }
+
+    /// Open an existing offset file store at `path`.
+    ///
+    /// # Errors
+    /// Returns an [`OffsetFileStoreCreateError`] if the layout is missing or invalid, or there is an IO error.
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        options: FilesystemStoreOptions,
+    ) -> Result<Self, OffsetFileStoreCreateError> {
+        let path = path.as_ref();
+        let layout: OffsetFileLayout =
+            serde_json::from_slice(&std::fs::read(path.join(LAYOUT_FILE_NAME))?)
+                .map_err(|err| OffsetFileStoreCreateError::InvalidLayout(err.to_string()))?;
+        let direct_io = cfg!(target_os = "linux") && options.direct_io;
+        layout.validate(direct_io)?;
+
+        let readonly = std::fs::metadata(path)?.permissions().readonly();
+        let data = open_file(&path.join(DATA_FILE_NAME), readonly, direct_io)?;
+        let index = open_file(&path.join(INDEX_FILE_NAME), readonly, false)?;
+        let meta = FilesystemStore::new_with_options(meta_path(path), options)?;
+        if data.metadata()?.len() != layout.num_slots() * layout.slot_stride()
+            || index.metadata()?.len() != layout.num_slots() * INDEX_ENTRY_SIZE as u64
+        {
+            return Err(OffsetFileStoreCreateError::InvalidLayout(
+                "the data or index file size does not match the layout".into(),
+            ));
+        }
+
+        Ok(Self {
+            layout,
+            readonly,
+            direct_io,
+            data,
+            index,
+            meta,
+            slots: Mutex::default(),
+        })
+    }
+
+    /// Returns the layout.
+    #[must_use]
+    pub const fn layout(&self) -> &OffsetFileLayout {
+        &self.layout
+    }
+
+    fn get_slot_mutex(&self, slot_index: u64) -> Arc<RwLock<()>> {
+        let mut slots = self.slots.lock().unwrap();
+        let slot = slots.entry(slot_index).or_default().clone();
+        drop(slots);
+        slot
+    }
+
+    /// Returns the size of the value in the slot at `slot_index`, or [`None`] if the slot is empty.
+    fn read_slot_size(&self, slot_index: u64) -> std::io::Result<Option<u64>> {
+        let mut entry = [0; INDEX_ENTRY_SIZE];
+        read_exact_at(
+            &self.index,
+            &mut entry,
+            slot_index * INDEX_ENTRY_SIZE as u64,
+        )?;
+        Ok(u64::from_le_bytes(entry).checked_sub(1))
+    }
+
+    /// Write the size of the value in the slot at `slot_index`, or mark the slot as empty if `size` is [`None`].
+    fn write_slot_size(&self, slot_index: u64, size: Option<u64>) -> std::io::Result<()> {
+        let entry = size.map_or(0, |size| size + 1).to_le_bytes();
+        write_all_at(&self.index, &entry, slot_index * INDEX_ENTRY_SIZE as u64)
+    }
+
+    /// Returns the chunk keys of all occupied slots.
+    fn chunk_keys(&self) -> std::io::Result<StoreKeys> {
+        let mut index = vec![0; usize::try_from(self.index.metadata()?.len()).unwrap()];
+        read_exact_at(&self.index, &mut index, 0)?;
+        Ok(index
+            .chunks_exact(INDEX_ENTRY_SIZE)
+            .enumerate()
+            .filter(|(_, entry)| entry.iter().any(|byte| *byte != 0))
+            .map(|(slot_index, _)| self.layout.slot_key(slot_index as u64))
+            .collect())
+    }
+
+    fn read_slot(
+        &self,
+        slot_index: u64,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        let slot = self.get_slot_mutex(slot_index);
+        let _lock = slot.read();
+
+        let Some(size) = self.read_slot_size(slot_index)? else {
+            return Ok(None);
+        };
+        validate_byte_ranges(byte_ranges, size)?;
+        let offset = slot_index * self.layout.slot_stride();
+
+        if self.direct_io {
+            // Read the entire slot into a page aligned buffer
+            let stride = usize::try_from(self.layout.slot_stride()).unwrap();
+            let mut buf = bytes_aligned(stride);
+            buf.resize(stride, 0);
+            read_exact_at(&self.data, &mut buf, offset)?;
+            let buf = buf.freeze();
+            Ok(Some(
+                byte_ranges
+                    .iter()
+                    .map(|byte_range| {
+                        let start = usize::try_from(byte_range.start(size)).unwrap();
+                        let end = usize::try_from(byte_range.end(size)).unwrap();
+                        buf.slice(start..end)
+                    })
+                    .collect(),
+            ))
+        } else {
+            let mut out = Vec::with_capacity(byte_ranges.len());
+            for byte_range in byte_ranges {
+                let mut buf = vec![0; usize::try_from(byte_range.length(size)).unwrap()];
+                read_exact_at(&self.data, &mut buf, offset + byte_range.start(size))?;
+                out.push(Bytes::from(buf));
+            }
+            Ok(Some(out))
+        }
+    }
+
+    fn write_slot(
+        &self,
+        key: &StoreKey,
+        slot_index: u64,
+        value: &[u8],
+    ) -> Result<(), StorageError> {
+        if value.len() as u64 > self.layout.slot_size {
+            return Err(StorageError::Other(format!(
+                "the value of {key} ({} bytes) exceeds the slot size of the offset file store ({} bytes)",
+                value.len(),
+                self.layout.slot_size
+            )));
+        }
+
+        let slot = self.get_slot_mutex(slot_index);
+        let _lock = slot.write();
+
+        let offset = slot_index * self.layout.slot_stride();
+        if self.direct_io && !value.is_empty() {
+            // Pad to the page size in a page aligned buffer, which fits in the slot stride
+            let mut buf = bytes_aligned(value.len());
+            buf.extend_from_slice(value);
+            buf.resize(value.len().next_multiple_of(page_size::get()), 0);
+            write_all_at(&self.data, &buf, offset)?;
+        } else {
+            write_all_at(&self.data, value, offset)?;
+        }
+        self.write_slot_size(slot_index, Some(value.len() as u64))?;
+        Ok(())
+    }
+}
+
+/// Returns the path of the companion store of an offset file store at `path`.
+fn meta_path(path: &Path) -> PathBuf {
+    path.join(META_DIRECTORY_NAME)
+}
+
+/// Open an existing file at `path`, for writing if not `readonly`.
+fn open_file(path: &Path, readonly: bool, direct_io: bool) -> std::io::Result<File> {
+    let mut flags = OpenOptions::new();
+    flags.read(true).write(!readonly);
+    #[cfg(target_os = "linux")]
+    if direct_io {
+        flags.custom_flags(O_DIRECT);
+    }
+    #[cfg(not(target_os = "linux"))]
+    let _ = direct_io;
+    flags.open(path)
+}
+
+impl ReadableStorageTraits for OffsetFileStore {
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        match self.layout.slot_index(key) {
+            Some(slot_index) => self.read_slot(slot_index, byte_ranges),
+            None => self.meta.get_partial_values_key(key, byte_ranges),
+        }
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        match self.layout.slot_index(key) {
+            Some(slot_index) => Ok(self.read_slot_size(slot_index)?),
+            None => self.meta.size_key(key),
+        }
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        StoreCapabilities::new()
+            .with_writable(!self.readonly)
+            .with_listing(true)
+            .with_partial_read(true)
+    }
+}
+
+impl WritableStorageTraits for OffsetFileStore {
+    fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
+        }
+        match self.layout.slot_index(key) {
+            Some(slot_index) => self.write_slot(key, slot_index, &value),
+            None => self.meta.set(key, value),
+        }
+    }
+
+    fn set_partial_values(
+        &self,
+        key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        store_set_partial_values(self, key_offset_values)
+    }
+
+    fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
+        }
+        match self.layout.slot_index(key) {
+            Some(slot_index) => {
+                let slot = self.get_slot_mutex(slot_index);
+                let _lock = slot.write();
+                Ok(self.write_slot_size(slot_index, None)?)
+            }
+            None => self.meta.erase(key),
+        }
+    }
+
+    fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        if self.readonly {
+            return Err(StorageError::ReadOnly);
+        }
+
+        let _lock = self.slots.lock(); // lock all operations
+
+        for key in self.chunk_keys()? {
+            if key.has_prefix(prefix) {
+                if let Some(slot_index) = self.layout.slot_index(&key) {
+                    self.write_slot_size(slot_index, None)?;
+                }
+            }
+        }
+        self.meta.erase_prefix(prefix)
+    }
+}
+
+impl ListableStorageTraits for OffsetFileStore {
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.list_prefix(&StorePrefix::root())
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        let mut keys: StoreKeys = self
+            .chunk_keys()?
+            .into_iter()
+            .filter(|key| key.has_prefix(prefix))
+            .chain(self.meta.list_prefix(prefix)?)
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        let mut keys = StoreKeys::new();
+        let mut prefixes = BTreeSet::new();
+        for key in self.list_prefix(prefix)? {
+            let child = &key.as_str()[prefix.as_str().len()..];
+            match child.find('/') {
+                Some(end) => {
+                    prefixes.insert(StorePrefix::new(
+                        prefix.as_str().to_string() + &child[..=end],
+                    )?);
+                }
+                None => keys.push(key),
+            }
+        }
+        Ok(StoreKeysPrefixes::new(keys, prefixes.into_iter().collect()))
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        let mut size = 0;
+        for key in self.list_prefix(prefix)? {
+            if let Some(size_key) = self.size_key(&key)? {
+                size += size_key;
+            }
+        }
+        Ok(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn offset_file_layout() {
+        let layout = OffsetFileLayout::new("array/c/", '/', vec![2, 3], 10).with_slot_alignment(8);
+        assert_eq!(layout.slot_stride(), 16);
+        assert_eq!(layout.num_slots(), 6);
+        assert_eq!(
+            layout.slot_index(&"array/c/1/2".try_into().unwrap()),
+            Some(5)
+        );
+        assert_eq!(layout.slot_key(5).as_str(), "array/c/1/2");
+        assert_eq!(layout.slot_index(&"array/c/2/0".try_into().unwrap()), None);
+        assert_eq!(layout.slot_index(&"array/c/01/0".try_into().unwrap()), None);
+        assert_eq!(layout.slot_index(&"array/c/1".try_into().unwrap()), None);
+        assert_eq!(
+            layout.slot_index(&"array/zarr.json".try_into().unwrap()),
+            None
+        );
+
+        let layout = OffsetFileLayout::new("array/", '.', vec![2, 3], 10);
+        assert_eq!(layout.slot_index(&"array/1.2".try_into().unwrap()), Some(5));
+        assert_eq!(layout.slot_key(5).as_str(), "array/1.2");
+        assert_eq!(layout.slot_index(&"array/1/2".try_into().unwrap()), None);
+        assert_eq!(
+            layout.slot_index(&"array/.zarray".try_into().unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn offset_file_store() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let layout = OffsetFileLayout::new("c/", '.', vec![4], 6);
+        let store =
+            OffsetFileStore::create(path.path(), &layout, FilesystemStoreOptions::default())?;
+        assert!(
+            OffsetFileStore::create(path.path(), &layout, FilesystemStoreOptions::default())
+                .is_err()
+        );
+
+        store.set(&"c/0".try_into()?, vec![0, 1, 2].into())?;
+        store.set(&"c/3".try_into()?, vec![3, 4, 5, 6, 7, 8].into())?;
+        store.set(&"c/2".try_into()?, Bytes::new())?;
+        store.set(&"zarr.json".try_into()?, vec![9].into())?;
+        assert!(store
+            .set(&"c/1".try_into()?, vec![0; 7].into())
+            .unwrap_err()
+            .to_string()
+            .contains("exceeds the slot size"));
+
+        assert_eq!(store.get(&"c/0".try_into()?)?, Some(vec![0, 1, 2].into()));
+        assert_eq!(store.get(&"c/1".try_into()?)?, None);
+        assert_eq!(store.get(&"c/2".try_into()?)?, Some(Bytes::new()));
+        assert_eq!(
+            store.get_partial_values_key(&"c/3".try_into()?, &[ByteRange::Suffix(2)])?,
+            Some(vec![vec![7, 8].into()])
+        );
+        assert_eq!(store.size()?, 10);
+        assert_eq!(
+            store.list()?,
+            &[
+                "c/0".try_into()?,
+                "c/2".try_into()?,
+                "c/3".try_into()?,
+                "zarr.json".try_into()?
+            ]
+        );
+        let list_dir = store.list_dir(&StorePrefix::root())?;
+        assert_eq!(list_dir.keys(), &["zarr.json".try_into()?]);
+        assert_eq!(list_dir.prefixes(), &["c/".try_into()?]);
+
+        store.set_partial_values(&[StoreKeyOffsetValue::new("c/0".try_into()?, 2, &[7, 7])])?;
+        assert_eq!(
+            store.get(&"c/0".try_into()?)?,
+            Some(vec![0, 1, 7, 7].into())
+        );
+
+        store.erase(&"c/0".try_into()?)?;
+        assert_eq!(store.get(&"c/0".try_into()?)?, None);
+        store.erase_prefix(&"c/".try_into()?)?;
+        assert_eq!(store.list()?, &["zarr.json".try_into()?]);
+
+        assert!(!store.capabilities().supports_partial_write());
+        zarrs_storage::store_test::store_health_check(&store)?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn offset_file_store_direct_io() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut options = FilesystemStoreOptions::default();
+        options.direct_io(true);
+        let layout = OffsetFileLayout::new("c/", '/', vec![2, 2], 5000);
+        let store = OffsetFileStore::create(path.path(), &layout, options.clone())?;
+        let value: Vec<u8> = (0..5000u32)
+            .map(|i| u8::try_from(i % 251).unwrap())
+            .collect();
+        store.set(&"c/1/0".try_into()?, value.clone().into())?;
+        store.set(&"c/1/1".try_into()?, vec![1; 10].into())?;
+        drop(store);
+
+        let store = OffsetFileStore::open(path.path(), options)?;
+        assert_eq!(store.get(&"c/1/0".try_into()?)?, Some(value.into()));
+        assert_eq!(store.get(&"c/1/1".try_into()?)?, Some(vec![1; 10].into()));
+        assert!(OffsetFileStore::create(
+            tempfile::TempDir::new()?.path(),
+            &OffsetFileLayout::new("c/", '/', vec![1], 1).with_slot_alignment(1),
+            {
+                let mut options = FilesystemStoreOptions::default();
+                options.direct_io(true);
+                options
+            }
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -9,29 +9,221 @@
 //! # Ok::<_, Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! Values served with a `gzip` or `zstd` `Content-Encoding` are decoded.
+//! Byte ranges and sizes refer to the decoded values, see [`HTTPStore`].
+//!
+//! Listing is opt-in, as plain HTTP has no standard way to enumerate resources.
+//! See [`HTTPStoreListingMode`].
+//! ```rust
+//! # use std::sync::Arc;
+//! use zarrs_storage::ReadableListableStorage;
+//! use zarrs_http::{HTTPStore, HTTPStoreListingMode};
+//!
+//! let http_store: ReadableListableStorage = Arc::new(
+//!     HTTPStore::new("http://...")?.with_listing_mode(HTTPStoreListingMode::DirectoryListing),
+//! );
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
 //! ## Licence
 //! `zarrs_http` is licensed under either of
 //! - the Apache License, Version 2.0 [LICENSE-APACHE](https://docs.rs/crate/zarrs_http/latest/source/LICENCE-APACHE) or <http://www.apache.org/licenses/LICENSE-2.0> or
 //! - the MIT license [LICENSE-MIT](https://docs.rs/crate/zarrs_http/latest/source/LICENCE-MIT) or <http://opensource.org/licenses/MIT>, at your option.
 
 use zarrs_storage::{
-    byte_range::ByteRange, Bytes, MaybeBytes, ReadableStorageTraits, StorageError, StoreKey,
+    byte_range::{validate_byte_ranges, ByteRange},
+    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
 };
 
 use itertools::Itertools;
 use reqwest::{
-    header::{HeaderValue, CONTENT_LENGTH, RANGE},
-    StatusCode, Url,
+    header::{
+        HeaderMap, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_RANGE,
+        CONTENT_TYPE, RANGE, RETRY_AFTER,
+    },
+    Method, StatusCode, Url,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    io::Read,
+    str::FromStr,
+    sync::Mutex,
+    time::Duration,
 };
-use std::str::FromStr;
 use thiserror::Error;
 
+/// The listing mode of a [`HTTPStore`].
+///
+/// All listing operations are derived from a listing of every key in the store.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HTTPStoreListingMode {
+    /// Listing is not supported. This is the default.
+    ///
+    /// Listing operations return [`StorageError::Unsupported`].
+    #[default]
+    Disabled,
+    /// Crawl the directory listings (autoindex) served for each prefix.
+    ///
+    /// Both HTML directory listings (e.g. `nginx` `autoindex` or `apache` `mod_autoindex`) and `nginx` JSON directory listings (`autoindex_format json`) are supported.
+    /// A directory listing is requested for a prefix by appending the prefix (with a trailing `/`) to the base URL.
+    DirectoryListing,
+    /// Read a manifest at a [`StoreKey`] containing a JSON array of every key in the store.
+    ///
+    /// For example, a manifest at `.zarrs_keys.json` with content `["zarr.json", "array/zarr.json", "array/c/0/0"]`.
+    Manifest(StoreKey),
+}
+
+/// Options for use with [`HTTPStore`].
+///
+/// Requests that fail with a retryable status code or a retryable error are retried up to [`max_retries`](HTTPStoreOptions::max_retries) times.
+/// The delay before retry `n` (starting at 0) is `backoff_base * 2^n`, limited to `backoff_max`.
+/// If a response includes a `Retry-After` header with a delay in seconds, that delay is used instead (also limited to `backoff_max`).
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct HTTPStoreOptions {
+    max_retries: usize,
+    backoff_base: Duration,
+    backoff_max: Duration,
+    retryable_status_codes: HashSet<StatusCode>,
+    retry_timeouts: bool,
+    retry_connection_errors: bool,
+}
+
+impl Default for HTTPStoreOptions {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff_base: Duration::from_millis(100),
+            backoff_max: Duration::from_secs(10),
+            retryable_status_codes: HashSet::from([
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ]),
+            retry_timeouts: true,
+            retry_connection_errors: true,
+        }
+    }
+}
+
+impl HTTPStoreOptions {
+    /// Set the maximum number of times a failed request is retried. Defaults to 3.
+    ///
+    /// Set to 0 to disable retries.
+    pub fn max_retries(&mut self, max_retries: usize) -> &mut Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the base delay of the exponential backoff between retries. Defaults to 100ms.
+    pub fn backoff_base(&mut self, backoff_base: Duration) -> &mut Self {
+        self.backoff_base = backoff_base;
+        self
+    }
+
+    /// Set the maximum delay between retries. Defaults to 10s.
+    pub fn backoff_max(&mut self, backoff_max: Duration) -> &mut Self {
+        self.backoff_max = backoff_max;
+        self
+    }
+
+    /// Set the response status codes that are retried.
+    ///
+    /// Defaults to 429 (Too Many Requests), 500 (Internal Server Error), 502 (Bad Gateway), 503 (Service Unavailable), and 504 (Gateway Timeout).
+    pub fn retryable_status_codes(
+        &mut self,
+        retryable_status_codes: impl IntoIterator<Item = StatusCode>,
+    ) -> &mut Self {
+        self.retryable_status_codes = retryable_status_codes.into_iter().collect();
+        self
+    }
+
+    /// Set whether requests that time out are retried. Defaults to true.
+    pub fn retry_timeouts(&mut self, retry_timeouts: bool) -> &mut Self {
+        self.retry_timeouts = retry_timeouts;
+        self
+    }
+
+    /// Set whether requests that fail to connect or have their connection reset are retried. Defaults to true.
+    pub fn retry_connection_errors(&mut self, retry_connection_errors: bool) -> &mut Self {
+        self.retry_connection_errors = retry_connection_errors;
+        self
+    }
+
+    /// Returns true if a request that failed with `err` should be retried.
+    fn is_retryable_error(&self, err: &reqwest::Error) -> bool {
+        (self.retry_timeouts && err.is_timeout())
+            || (self.retry_connection_errors && is_connection_error(err))
+    }
+
+    /// Returns the delay before retry `attempt` (starting at 0).
+    fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 2u32.saturating_pow(u32::try_from(attempt).unwrap_or(u32::MAX));
+        self.backoff_base
+            .checked_mul(factor)
+            .unwrap_or(self.backoff_max)
+            .min(self.backoff_max)
+    }
+}
+
+/// Returns true if `err` is a connection error or was caused by a connection reset.
+fn is_connection_error(err: &reqwest::Error) -> bool {
+    if err.is_connect() {
+        return true;
+    }
+    let mut source = std::error::Error::source(err);
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<std::io::Error>() {
+            if matches!(
+                err.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+        source = err.source();
+    }
+    false
+}
+
+/// Returns the delay of a `Retry-After` header of `response` if it is specified in seconds.
+fn retry_after(response: &reqwest::blocking::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|header_value| header_value.to_str().ok())
+        .and_then(|header_str| u64::from_str(header_str.trim()).ok())
+        .map(Duration::from_secs)
+}
+
 /// A synchronous HTTP store.
+///
+/// ### Content encoding
+/// Values served with a `gzip` or `zstd` `Content-Encoding` (e.g. compressed transparently by a static file server) are decoded by [`get`](ReadableStorageTraits::get).
+///
+/// Byte range and size requests are sent with `Accept-Encoding: identity`, so that byte ranges and the `Content-Length` refer to the decoded value.
+/// If the server applies a content encoding regardless, the store falls back to retrieving and decoding the entire value:
+///  - [`size_key`](ReadableStorageTraits::size_key) returns the decoded length, and
+///  - [`get_partial_values_key`](ReadableStorageTraits::get_partial_values_key) slices the decoded value rather than issuing byte range requests.
+///
+/// The decoded sizes of such values are cached by the store, and are refreshed whenever a value is retrieved in its entirety.
 #[derive(Debug)]
 pub struct HTTPStore {
     base_url: Url,
     batch_range_requests: bool,
+    listing_mode: HTTPStoreListingMode,
+    options: HTTPStoreOptions,
     client: reqwest::blocking::Client,
+    default_headers: HeaderMap,
+    query_parameters: Vec<(String, String)>,
+    decoded_sizes: Mutex<HashMap<StoreKey, u64>>,
 }
 
 #[allow(clippy::needless_pass_by_value)]
@@ -43,6 +235,44 @@ fn handle_url_error(err: url::ParseError) -> StorageError {
     StorageError::Other(err.to_string())
 }
 
+/// Returns the `Content-Encoding` of a response, or [`None`] if it is absent or `identity`.
+fn content_encoding(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(CONTENT_ENCODING)
+        .and_then(|header_value| header_value.to_str().ok())
+        .map(|encoding| encoding.trim().to_ascii_lowercase())
+        .filter(|encoding| !encoding.is_empty() && encoding != "identity")
+}
+
+/// Decode the `bytes` of a response with a `Content-Encoding` of `encoding`.
+fn decode_content(encoding: &str, bytes: &[u8]) -> Result<Bytes, StorageError> {
+    let decode_error =
+        |err: std::io::Error| StorageError::Other(format!("http {encoding} decoding error: {err}"));
+    match encoding {
+        "gzip" | "x-gzip" => {
+            let mut decoded = Vec::new();
+            flate2::read::GzDecoder::new(bytes)
+                .read_to_end(&mut decoded)
+                .map_err(decode_error)?;
+            Ok(decoded.into())
+        }
+        "zstd" => Ok(zstd::decode_all(bytes).map_err(decode_error)?.into()),
+        _ => Err(StorageError::Unsupported(format!(
+            "the http content encoding {encoding} is not supported"
+        ))),
+    }
+}
+
+/// Slice `bytes` into `byte_ranges`.
+fn slice_byte_ranges(bytes: &Bytes, byte_ranges: &[ByteRange]) -> Result<Vec<Bytes>, StorageError> {
+    let size = bytes.len() as u64;
+    validate_byte_ranges(byte_ranges, size)?;
+    Ok(byte_ranges
+        .iter()
+        .map(|byte_range| bytes.slice(byte_range.to_range_usize(size)))
+        .collect())
+}
+
 impl HTTPStore {
     /// Create a new HTTP store at a given `base_url`.
     ///
@@ -50,16 +280,82 @@ impl HTTPStore {
     ///
     /// Returns a [`HTTPStoreCreateError`] if `base_url` is not a valid URL.
     pub fn new(base_url: &str) -> Result<Self, HTTPStoreCreateError> {
+        Self::new_with_options(base_url, HTTPStoreOptions::default())
+    }
+
+    /// Create a new HTTP store at a given `base_url` and `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HTTPStoreCreateError`] if `base_url` is not a valid URL.
+    pub fn new_with_options(
+        base_url: &str,
+        options: HTTPStoreOptions,
+    ) -> Result<Self, HTTPStoreCreateError> {
+        let mut store = Self::new_with_client(base_url, reqwest::blocking::Client::new())?;
+        store.options = options;
+        Ok(store)
+    }
+
+    /// Create a new HTTP store at a given `base_url` that sends requests with `client`.
+    ///
+    /// This can be used to configure timeouts, proxies, TLS, etc. of the [`reqwest::blocking::Client`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HTTPStoreCreateError`] if `base_url` is not a valid URL.
+    pub fn new_with_client(
+        base_url: &str,
+        client: reqwest::blocking::Client,
+    ) -> Result<Self, HTTPStoreCreateError> {
         let base_url = Url::from_str(base_url)
             .map_err(|_| HTTPStoreCreateError::InvalidBaseURL(base_url.into()))?;
-        let client = reqwest::blocking::Client::new();
         Ok(Self {
             base_url,
             batch_range_requests: true,
+            listing_mode: HTTPStoreListingMode::default(),
+            options: HTTPStoreOptions::default(),
             client,
+            default_headers: HeaderMap::new(),
+            query_parameters: Vec::new(),
+            decoded_sizes: Mutex::default(),
         })
     }
 
+    /// Set the [`HTTPStoreOptions`] of the store.
+    pub fn set_options(&mut self, options: HTTPStoreOptions) {
+        self.options = options;
+    }
+
+    /// Set headers that are sent with every request (e.g. `Authorization` or API key headers).
+    ///
+    /// These replace any previously set default headers.
+    pub fn set_default_headers(&mut self, default_headers: HeaderMap) {
+        self.default_headers = default_headers;
+    }
+
+    /// Set query parameters that are appended to every request URL (e.g. for signed URLs).
+    ///
+    /// These replace any previously set query parameters.
+    pub fn set_query_parameters<K: Into<String>, V: Into<String>>(
+        &mut self,
+        query_parameters: impl IntoIterator<Item = (K, V)>,
+    ) {
+        self.query_parameters = query_parameters
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+    }
+
+    /// Set the listing mode of the store.
+    ///
+    /// Defaults to [`HTTPStoreListingMode::Disabled`].
+    #[must_use]
+    pub fn with_listing_mode(mut self, listing_mode: HTTPStoreListingMode) -> Self {
+        self.listing_mode = listing_mode;
+        self
+    }
+
     /// Set whether to batch range requests.
     ///
     /// Defaults to true.
@@ -80,17 +376,251 @@ impl HTTPStore {
             url +=
                 ("/".to_string() + key.as_str().strip_prefix('/').unwrap_or(key.as_str())).as_str();
         }
-        Url::parse(&url)
+        Ok(self.append_query_parameters(Url::parse(&url)?))
+    }
+
+    /// Maps a [`StorePrefix`] to a HTTP [`Url`] of its directory listing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid.
+    pub fn prefix_to_url(&self, prefix: &StorePrefix) -> Result<Url, url::ParseError> {
+        let base_url = self.base_url.as_str().trim_end_matches('/');
+        Ok(self.append_query_parameters(Url::parse(&format!("{base_url}/{}", prefix.as_str()))?))
+    }
+
+    fn append_query_parameters(&self, mut url: Url) -> Url {
+        if !self.query_parameters.is_empty() {
+            url.query_pairs_mut().extend_pairs(&self.query_parameters);
+        }
+        url
+    }
+
+    /// Create a request with the default headers of the store.
+    fn request(&self, method: Method, url: &Url) -> reqwest::blocking::RequestBuilder {
+        self.client
+            .request(method, url.clone())
+            .headers(self.default_headers.clone())
+    }
+
+    /// Send the request created by `request`, retrying according to the [`HTTPStoreOptions`] of the store.
+    ///
+    /// If the retries are exhausted, the last response is returned even if it has a retryable status code.
+    fn send(
+        &self,
+        request: impl Fn() -> reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, StorageError> {
+        let mut attempt = 0;
+        loop {
+            let result = request().send();
+            let delay = match &result {
+                Ok(response)
+                    if self
+                        .options
+                        .retryable_status_codes
+                        .contains(&response.status()) =>
+                {
+                    retry_after(response).unwrap_or_else(|| self.options.backoff(attempt))
+                }
+                Err(err) if self.options.is_retryable_error(err) => self.options.backoff(attempt),
+                _ => return result.map_err(handle_reqwest_error),
+            };
+            if attempt >= self.options.max_retries {
+                return result.map_err(handle_reqwest_error);
+            }
+            std::thread::sleep(delay.min(self.options.backoff_max));
+            attempt += 1;
+        }
+    }
+
+    /// Returns the cached decoded size of the value at `key` if it was served with a content encoding.
+    fn decoded_size(&self, key: &StoreKey) -> Option<u64> {
+        self.decoded_sizes.lock().unwrap().get(key).copied()
+    }
+
+    /// Retrieve the entire value at `key` and slice it into `byte_ranges`.
+    fn get_byte_ranges_decoded(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.get(key)?
+            .map(|bytes| slice_byte_ranges(&bytes, byte_ranges))
+            .transpose()
+    }
+
+    /// Return the keys with a given `prefix` according to the listing mode of the store.
+    fn list_keys(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        match &self.listing_mode {
+            HTTPStoreListingMode::Disabled => Err(StorageError::Unsupported(
+                "listing is not enabled for this http store, see HTTPStore::with_listing_mode"
+                    .to_string(),
+            )),
+            HTTPStoreListingMode::DirectoryListing => self.list_keys_directory_listing(prefix),
+            HTTPStoreListingMode::Manifest(manifest_key) => {
+                let Some(manifest) = self.get(manifest_key)? else {
+                    return Err(StorageError::from(format!(
+                        "the http store manifest {manifest_key} does not exist"
+                    )));
+                };
+                let mut keys = parse_manifest(&manifest)?;
+                keys.retain(|key| key.has_prefix(prefix));
+                keys.sort();
+                keys.dedup();
+                Ok(keys)
+            }
+        }
+    }
+
+    /// Recursively crawl the directory listings of `prefix` and its children.
+    fn list_keys_directory_listing(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        let mut keys = StoreKeys::new();
+        let mut prefixes = vec![prefix.clone()];
+        while let Some(prefix) = prefixes.pop() {
+            let url = self.prefix_to_url(&prefix).map_err(handle_url_error)?;
+            let response = self.send(|| self.request(Method::GET, &url))?;
+            match response.status() {
+                StatusCode::OK => {}
+                StatusCode::NOT_FOUND => continue,
+                _ => {
+                    return Err(StorageError::from(format!(
+                        "http directory listing has status code {}",
+                        response.status()
+                    )))
+                }
+            }
+            let is_json = response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|header_value| header_value.to_str().ok())
+                .is_some_and(|content_type| content_type.contains("json"));
+            let listing = response.text().map_err(handle_reqwest_error)?;
+            let entries = if is_json {
+                parse_json_directory_listing(&listing)?
+            } else {
+                parse_html_directory_listing(&url, &listing)
+            };
+            for (name, is_dir) in entries {
+                if is_dir {
+                    prefixes.push(StorePrefix::new(format!("{}{name}/", prefix.as_str()))?);
+                } else {
+                    keys.push(StoreKey::new(format!("{}{name}", prefix.as_str()))?);
+                }
+            }
+        }
+        keys.sort();
+        Ok(keys)
     }
 }
 
+/// Parse a manifest containing a JSON array of store keys.
+fn parse_manifest(manifest: &[u8]) -> Result<StoreKeys, StorageError> {
+    let keys: Vec<String> = serde_json::from_slice(manifest)
+        .map_err(|err| StorageError::from(format!("invalid http store manifest: {err}")))?;
+    keys.into_iter()
+        .map(|key| Ok(StoreKey::new(key)?))
+        .collect()
+}
+
+/// Parse a JSON directory listing in the `nginx` `autoindex_format json` format.
+///
+/// Returns the name of each entry and whether it is a directory.
+fn parse_json_directory_listing(listing: &str) -> Result<Vec<(String, bool)>, StorageError> {
+    let entries: Vec<serde_json::Map<String, serde_json::Value>> = serde_json::from_str(listing)
+        .map_err(|err| StorageError::from(format!("invalid http json directory listing: {err}")))?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| {
+            let name = entry.get("name")?.as_str()?;
+            let is_dir = entry.get("type")?.as_str()? == "directory";
+            let name = name.strip_suffix('/').unwrap_or(name);
+            if name.is_empty() || name == "." || name == ".." || name.contains('/') {
+                None
+            } else {
+                Some((name.to_string(), is_dir))
+            }
+        })
+        .collect())
+}
+
+/// Parse a HTML directory listing at `url`.
+///
+/// Each link (`href`) that resolves to a direct child of `url` is an entry, and it is a directory if it has a trailing `/`.
+/// Links to parent directories, other hosts, or with a query (e.g. sort links) are ignored.
+///
+/// Returns the name of each entry and whether it is a directory.
+fn parse_html_directory_listing(url: &Url, listing: &str) -> Vec<(String, bool)> {
+    let mut entries = Vec::new();
+    let listing_lower = listing.to_ascii_lowercase();
+    let mut position = 0;
+    while let Some(offset) = listing_lower[position..].find("href=") {
+        let start = position + offset + "href=".len();
+        let Some(quote) = listing[start..]
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')
+        else {
+            position = start;
+            continue;
+        };
+        let Some(length) = listing[start + 1..].find(quote) else {
+            break;
+        };
+        let href = &listing[start + 1..start + 1 + length];
+        position = start + 1 + length;
+
+        let Ok(href_url) = url.join(href) else {
+            continue;
+        };
+        if href_url.origin() != url.origin()
+            || href_url.query().is_some()
+            || href_url.fragment().is_some()
+        {
+            continue;
+        }
+        let Some(name) = href_url.path().strip_prefix(url.path()) else {
+            continue;
+        };
+        let (name, is_dir) = name
+            .strip_suffix('/')
+            .map_or((name, false), |name| (name, true));
+        if name.is_empty() || name.contains('/') {
+            continue;
+        }
+        let name = percent_encoding::percent_decode_str(name)
+            .decode_utf8_lossy()
+            .to_string();
+        if !entries.iter().any(|(entry, _)| entry == &name) {
+            entries.push((name, is_dir));
+        }
+    }
+    entries
+}
+
 impl ReadableStorageTraits for HTTPStore {
     fn get(&self, key: &StoreKey) -> Result<MaybeBytes, StorageError> {
         let url = self.key_to_url(key).map_err(handle_url_error)?;
-        let response = self.client.get(url).send().map_err(handle_reqwest_error)?;
+        let response = self.send(|| self.request(Method::GET, &url))?;
         match response.status() {
-            StatusCode::OK => Ok(Some(response.bytes().map_err(handle_reqwest_error)?)),
-            StatusCode::NOT_FOUND => Ok(None),
+            StatusCode::OK => {
+                let encoding = content_encoding(response.headers());
+                let bytes = response.bytes().map_err(handle_reqwest_error)?;
+                let bytes = match &encoding {
+                    Some(encoding) => decode_content(encoding, &bytes)?,
+                    None => bytes,
+                };
+                let mut decoded_sizes = self.decoded_sizes.lock().unwrap();
+                if encoding.is_some() {
+                    decoded_sizes.insert(key.clone(), bytes.len() as u64);
+                } else {
+                    decoded_sizes.remove(key);
+                }
+                Ok(Some(bytes))
+            }
+            StatusCode::NOT_FOUND => {
+                self.decoded_sizes.lock().unwrap().remove(key);
+                Ok(None)
+            }
             _ => Err(StorageError::from(format!(
                 "http unexpected status code: {}",
                 response.status()
@@ -107,34 +637,44 @@ impl ReadableStorageTraits for HTTPStore {
         let Some(size) = self.size_key(key)? else {
             return Ok(None);
         };
+        validate_byte_ranges(byte_ranges, size)?;
+        if self.decoded_size(key).is_some() {
+            // Byte ranges would refer to the encoded value
+            return self.get_byte_ranges_decoded(key, byte_ranges);
+        }
+
+        // Zero-length byte ranges cannot be expressed in a range request
+        let length = |byte_range: &ByteRange| byte_range.length(size);
+        if byte_ranges.iter().all(|byte_range| length(byte_range) == 0) {
+            return Ok(Some(vec![Bytes::new(); byte_ranges.len()]));
+        }
         let bytes_strs = byte_ranges
             .iter()
+            .filter(|byte_range| length(byte_range) != 0)
             .map(|byte_range| format!("{}-{}", byte_range.start(size), byte_range.end(size) - 1))
             .join(", ");
 
         let range = HeaderValue::from_str(&format!("bytes={bytes_strs}")).unwrap();
-        let response = self
-            .client
-            .get(url)
-            .header(RANGE, range)
-            .send()
-            .map_err(handle_reqwest_error)?;
+        let response = self.send(|| {
+            self.request(Method::GET, &url)
+                .header(RANGE, range.clone())
+                .header(ACCEPT_ENCODING, "identity")
+        })?;
+        if content_encoding(response.headers()).is_some() {
+            // The server ignored the identity encoding request, so any byte ranges refer to the encoded value
+            return self.get_byte_ranges_decoded(key, byte_ranges);
+        }
 
         match response.status() {
             StatusCode::NOT_FOUND => Err(StorageError::from("the http server returned a NOT FOUND status for the byte range request, but returned a non zero size for CONTENT_LENGTH")),
             StatusCode::PARTIAL_CONTENT => {
                 // TODO: Gracefully handle a response from the server which does not include all requested by ranges
                 let mut bytes = response.bytes().map_err(handle_reqwest_error)?;
-                if bytes.len() as u64
-                    == byte_ranges
-                        .iter()
-                        .map(|byte_range| byte_range.length(size))
-                        .sum::<u64>()
-                {
+                if bytes.len() as u64 == byte_ranges.iter().map(length).sum::<u64>() {
                     let mut out = Vec::with_capacity(byte_ranges.len());
                     for byte_range in byte_ranges {
                         let bytes_range =
-                            bytes.split_to(usize::try_from(byte_range.length(size)).unwrap());
+                            bytes.split_to(usize::try_from(length(byte_range)).unwrap());
                         out.push(bytes_range);
                     }
                     Ok(Some(out))
@@ -147,13 +687,22 @@ impl ReadableStorageTraits for HTTPStore {
             StatusCode::OK => {
                 // Received all bytes
                 let bytes = response.bytes().map_err(handle_reqwest_error)?;
-                let mut out = Vec::with_capacity(byte_ranges.len());
-                for byte_range in byte_ranges {
-                    let start = usize::try_from(byte_range.start(size)).unwrap();
-                    let end = usize::try_from(byte_range.end(size)).unwrap();
-                    out.push(bytes.slice(start..end));
+                Ok(Some(slice_byte_ranges(&bytes, byte_ranges)?))
+            }
+            StatusCode::RANGE_NOT_SATISFIABLE => {
+                // The value may have changed size since it was queried, the current size is in the CONTENT_RANGE header (e.g. `bytes */1234`)
+                let size = response
+                    .headers()
+                    .get(CONTENT_RANGE)
+                    .and_then(|content_range| content_range.to_str().ok())
+                    .and_then(|content_range| content_range.strip_prefix("bytes */"))
+                    .and_then(|size| size.parse::<u64>().ok());
+                if let Some(size) = size {
+                    validate_byte_ranges(byte_ranges, size)?;
                 }
-                Ok(Some(out))
+                Err(StorageError::from(
+                    "the http server could not satisfy the byte range request",
+                ))
             }
             _ => Err(StorageError::from(format!(
                 "the http server responded with status {} for the byte range request",
@@ -163,9 +712,19 @@ impl ReadableStorageTraits for HTTPStore {
     }
 
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        if let Some(size) = self.decoded_size(key) {
+            return Ok(Some(size));
+        }
         let url = self.key_to_url(key).map_err(handle_url_error)?;
-        let response = self.client.head(url).send().map_err(handle_reqwest_error)?;
+        let response = self.send(|| {
+            self.request(Method::HEAD, &url)
+                .header(ACCEPT_ENCODING, "identity")
+        })?;
         match response.status() {
+            StatusCode::OK if content_encoding(response.headers()).is_some() => {
+                // The content length is that of the encoded value
+                Ok(self.get(key)?.map(|bytes| bytes.len() as u64))
+            }
             StatusCode::OK => {
                 let length = response
                     .headers()
@@ -182,6 +741,47 @@ impl ReadableStorageTraits for HTTPStore {
             ))),
         }
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        StoreCapabilities::new()
+            .with_listing(!matches!(self.listing_mode, HTTPStoreListingMode::Disabled))
+            .with_partial_read(true)
+    }
+}
+
+impl ListableStorageTraits for HTTPStore {
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.list_keys(&StorePrefix::root())
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.list_keys(prefix)
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        let mut keys: StoreKeys = vec![];
+        let mut prefixes: StorePrefixes = vec![];
+        for key in self.list_keys(prefix)? {
+            let child = &key.as_str()[prefix.as_str().len()..];
+            if let Some((child, _)) = child.split_once('/') {
+                prefixes.push(StorePrefix::new(format!("{}{child}/", prefix.as_str()))?);
+            } else {
+                keys.push(key);
+            }
+        }
+        prefixes.dedup();
+        Ok(StoreKeysPrefixes::new(keys, prefixes))
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        let mut size = 0;
+        for key in self.list_prefix(prefix)? {
+            if let Some(size_key) = self.size_key(&key)? {
+                size += size_key;
+            }
+        }
+        Ok(size)
+    }
 }
 
 /// A HTTP store creation error.
@@ -198,11 +798,283 @@ pub enum HTTPStoreCreateError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::error::Error;
+    use std::{
+        error::Error,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Instant,
+    };
+    use zarrs_storage::{store::MemoryStore, WritableStorageTraits};
 
     const HTTP_TEST_PATH_REF: &str =
         "https://raw.githubusercontent.com/LDeakin/zarrs/main/zarrs/tests/data/store";
 
+    /// A request received by the test server.
+    struct TestRequest {
+        method: String,
+        path: String,
+        headers: Vec<(String, String)>,
+    }
+
+    impl TestRequest {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// A response sent by the test server.
+    struct TestResponse {
+        status: &'static str,
+        headers: Vec<(&'static str, String)>,
+        body: Vec<u8>,
+    }
+
+    impl TestResponse {
+        fn new(status: &'static str, content_type: &str, body: Vec<u8>) -> Self {
+            Self {
+                status,
+                headers: vec![("Content-Type", content_type.to_string())],
+                body,
+            }
+        }
+    }
+
+    /// Serve HTTP requests on a local port with `respond`.
+    ///
+    /// Returns the base URL of the server, which includes a `/data` path.
+    fn serve(
+        respond: impl Fn(&TestRequest) -> TestResponse + Send + 'static,
+    ) -> Result<String, Box<dyn Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}/data", listener.local_addr()?);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut request_line = String::new();
+                let mut reader = BufReader::new(&mut stream);
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).is_err() || line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        headers.push((name.trim().to_string(), value.trim().to_string()));
+                    }
+                }
+                let mut request_line = request_line.split_whitespace();
+                let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+                    continue;
+                };
+                let request = TestRequest {
+                    method: method.to_string(),
+                    path: path.to_string(),
+                    headers,
+                };
+                let response = respond(&request);
+
+                let headers = response
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}\r\n"))
+                    .join("");
+                let head = format!(
+                    "HTTP/1.1 {}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                    response.status,
+                    response.body.len()
+                );
+                let _ = stream.write_all(head.as_bytes());
+                if request.method != "HEAD" {
+                    let _ = stream.write_all(&response.body);
+                }
+            }
+        });
+        Ok(base_url)
+    }
+
+    /// Respond to a request for the content of `store`.
+    ///
+    /// Directory listings are served in the HTML format at the root and in the JSON format elsewhere.
+    /// The keys of the store are served as a manifest at `.zarrs_keys.json`.
+    fn respond_store(store: &MemoryStore, request: &TestRequest) -> TestResponse {
+        let path = request.path.split('?').next().unwrap_or_default();
+        let path = path.strip_prefix("/data/").unwrap_or_default();
+        if path == ".zarrs_keys.json" {
+            let keys: Vec<String> = store
+                .list()
+                .unwrap()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            return TestResponse::new(
+                "200 OK",
+                "application/json",
+                serde_json::to_vec(&keys).unwrap(),
+            );
+        }
+
+        if path.is_empty() || path.ends_with('/') {
+            let prefix = StorePrefix::new(path).unwrap();
+            let list_dir = store.list_dir(&prefix).unwrap();
+            let names = list_dir
+                .prefixes()
+                .iter()
+                .map(|prefix| (prefix.as_str().strip_prefix(path).unwrap(), true))
+                .chain(
+                    list_dir
+                        .keys()
+                        .iter()
+                        .map(|key| (key.as_str().strip_prefix(path).unwrap(), false)),
+                );
+            if prefix == StorePrefix::root() {
+                let links = names
+                    .map(|(name, _)| format!("<A HREF='{name}'>{name}</A>"))
+                    .join("");
+                let html = format!(
+                    r#"<html><body><a href="?C=N;O=D">Name</a><a href="../">Parent Directory</a><a href="https://example.com/">Elsewhere</a>{links}</body></html>"#
+                );
+                return TestResponse::new("200 OK", "text/html", html.into_bytes());
+            }
+            let json: Vec<_> = names
+                .map(|(name, is_dir)| {
+                    serde_json::json!({
+                        "name": name.trim_end_matches('/'),
+                        "type": if is_dir { "directory" } else { "file" },
+                    })
+                })
+                .collect();
+            return TestResponse::new(
+                "200 OK",
+                "application/json",
+                serde_json::to_vec(&json).unwrap(),
+            );
+        }
+
+        let Some(value) = StoreKey::new(path)
+            .ok()
+            .and_then(|key| store.get(&key).unwrap())
+        else {
+            return TestResponse::new("404 Not Found", "text/plain", vec![]);
+        };
+
+        // Respond with the entire value if the requested byte ranges are satisfiable
+        let satisfiable = request.header("range").map_or(true, |range| {
+            range
+                .trim_start_matches("bytes=")
+                .split(',')
+                .filter_map(|range| range.trim().split_once('-'))
+                .all(|(_, end)| end.parse::<usize>().is_ok_and(|end| end < value.len()))
+        });
+        if satisfiable {
+            TestResponse::new("200 OK", "application/octet-stream", value.to_vec())
+        } else {
+            TestResponse::new("416 Range Not Satisfiable", "text/plain", vec![])
+        }
+    }
+
+    /// Respond to a request for the content of `store` with values encoded with a `Content-Encoding` of `encoding`.
+    ///
+    /// If `identity` is true, requests with `Accept-Encoding: identity` are served by [`respond_store`].
+    /// Otherwise, byte ranges are applied to the encoded value, like a server that encodes values regardless of the request.
+    fn respond_store_encoded(
+        store: &MemoryStore,
+        request: &TestRequest,
+        encoding: &str,
+        identity: bool,
+    ) -> TestResponse {
+        if identity && request.header("accept-encoding") == Some("identity") {
+            return respond_store(store, request);
+        }
+        let path = request.path.split('?').next().unwrap_or_default();
+        let path = path.strip_prefix("/data/").unwrap_or_default();
+        let Some(value) = StoreKey::new(path)
+            .ok()
+            .and_then(|key| store.get(&key).unwrap())
+        else {
+            return TestResponse::new("404 Not Found", "text/plain", vec![]);
+        };
+        let encoded = match encoding {
+            "gzip" => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(&value).unwrap();
+                encoder.finish().unwrap()
+            }
+            "zstd" => zstd::encode_all(value.as_ref(), 0).unwrap(),
+            _ => unreachable!(),
+        };
+        let mut response = if let Some(range) = request.header("range") {
+            let body = range
+                .trim_start_matches("bytes=")
+                .split(',')
+                .filter_map(|range| range.trim().split_once('-'))
+                .flat_map(|(start, end)| {
+                    let start = start.parse::<usize>().unwrap().min(encoded.len());
+                    let end = (end.parse::<usize>().unwrap() + 1).min(encoded.len());
+                    encoded[start..end].to_vec()
+                })
+                .collect();
+            TestResponse::new("206 Partial Content", "application/octet-stream", body)
+        } else {
+            TestResponse::new("200 OK", "application/octet-stream", encoded)
+        };
+        response
+            .headers
+            .push(("Content-Encoding", encoding.to_string()));
+        response
+    }
+
+    fn test_store() -> Result<Arc<MemoryStore>, Box<dyn Error>> {
+        let store = MemoryStore::new();
+        zarrs_storage::store_test::store_write(&store)?;
+        Ok(Arc::new(store))
+    }
+
+    fn serve_store(store: Arc<MemoryStore>) -> Result<String, Box<dyn Error>> {
+        serve(move |request| respond_store(&store, request))
+    }
+
+    /// Serve `store`, but respond to the first `failures` requests with `failure`.
+    ///
+    /// Returns the base URL of the server and the number of requests received.
+    fn serve_store_failing(
+        store: Arc<MemoryStore>,
+        failures: usize,
+        failure: impl Fn() -> TestResponse + Send + 'static,
+    ) -> Result<(String, Arc<AtomicUsize>), Box<dyn Error>> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let base_url = serve({
+            let requests = requests.clone();
+            move |request| {
+                if requests.fetch_add(1, Ordering::SeqCst) < failures {
+                    failure()
+                } else {
+                    respond_store(&store, request)
+                }
+            }
+        })?;
+        Ok((base_url, requests))
+    }
+
+    fn fast_retry_options(max_retries: usize) -> HTTPStoreOptions {
+        let mut options = HTTPStoreOptions::default();
+        options
+            .max_retries(max_retries)
+            .backoff_base(Duration::from_millis(1))
+            .backoff_max(Duration::from_millis(10));
+        options
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn http_store() -> Result<(), Box<dyn Error>> {
@@ -210,4 +1082,281 @@ mod tests {
         zarrs_storage::store_test::store_read(&store)?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_list_disabled() -> Result<(), Box<dyn Error>> {
+        let base_url = serve_store(test_store()?)?;
+        let store = HTTPStore::new(&base_url)?;
+        zarrs_storage::store_test::store_read(&store)?;
+        assert!(matches!(store.list(), Err(StorageError::Unsupported(_))));
+        assert_eq!(
+            store.capabilities(),
+            StoreCapabilities::new().with_partial_read(true)
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_list_directory_listing() -> Result<(), Box<dyn Error>> {
+        let base_url = serve_store(test_store()?)?;
+        let store =
+            HTTPStore::new(&base_url)?.with_listing_mode(HTTPStoreListingMode::DirectoryListing);
+        zarrs_storage::store_test::store_read(&store)?;
+        zarrs_storage::store_test::store_list(&store)?;
+        assert!(store.capabilities().supports_listing());
+        assert!(!store.capabilities().writable());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_list_manifest() -> Result<(), Box<dyn Error>> {
+        let base_url = serve_store(test_store()?)?;
+        let store = HTTPStore::new(&base_url)?.with_listing_mode(HTTPStoreListingMode::Manifest(
+            ".zarrs_keys.json".try_into()?,
+        ));
+        zarrs_storage::store_test::store_read(&store)?;
+        zarrs_storage::store_test::store_list(&store)?;
+
+        let store = HTTPStore::new(&base_url)?
+            .with_listing_mode(HTTPStoreListingMode::Manifest("missing.json".try_into()?));
+        assert!(store.list().is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_retry() -> Result<(), Box<dyn Error>> {
+        let key: StoreKey = "a/b".try_into()?;
+        let unavailable = || TestResponse::new("503 Service Unavailable", "text/plain", vec![]);
+
+        // get
+        let (base_url, requests) = serve_store_failing(test_store()?, 2, unavailable)?;
+        let store = HTTPStore::new_with_options(&base_url, fast_retry_options(3))?;
+        assert_eq!(store.get(&key)?, Some(vec![0, 1, 2, 3].into()));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        // size_key
+        let (base_url, requests) = serve_store_failing(test_store()?, 3, unavailable)?;
+        let store = HTTPStore::new_with_options(&base_url, fast_retry_options(3))?;
+        assert_eq!(store.size_key(&key)?, Some(4));
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+
+        // get_partial_values_key (a HEAD request for the size, then a ranged GET)
+        let (base_url, requests) = serve_store_failing(test_store()?, 1, unavailable)?;
+        let store = HTTPStore::new_with_options(&base_url, fast_retry_options(3))?;
+        assert_eq!(
+            store.get_partial_values_key(&key, &[ByteRange::FromStart(1, Some(2))])?,
+            Some(vec![vec![1, 2].into()])
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        // Retries are exhausted
+        let (base_url, requests) = serve_store_failing(test_store()?, 10, unavailable)?;
+        let store = HTTPStore::new_with_options(&base_url, fast_retry_options(2))?;
+        assert!(store.get(&key).is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        // Retries are disabled
+        let (base_url, requests) = serve_store_failing(test_store()?, 1, unavailable)?;
+        let store = HTTPStore::new_with_options(&base_url, fast_retry_options(0))?;
+        assert!(store.get(&key).is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_retry_non_retryable() -> Result<(), Box<dyn Error>> {
+        let key: StoreKey = "a/b".try_into()?;
+
+        let (base_url, requests) = serve_store_failing(test_store()?, 1, || {
+            TestResponse::new("403 Forbidden", "text/plain", vec![])
+        })?;
+        let store = HTTPStore::new_with_options(&base_url, fast_retry_options(3))?;
+        assert!(store.get(&key).is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let (base_url, requests) =
+            serve_store_failing(test_store()?, 0, || -> TestResponse { unreachable!() })?;
+        let store = HTTPStore::new_with_options(&base_url, fast_retry_options(3))?;
+        assert!(store.get(&"missing".try_into()?)?.is_none());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_retry_after() -> Result<(), Box<dyn Error>> {
+        let (base_url, requests) = serve_store_failing(test_store()?, 2, || {
+            let mut response = TestResponse::new("429 Too Many Requests", "text/plain", vec![]);
+            response.headers.push(("Retry-After", "0".to_string()));
+            response
+        })?;
+
+        // The backoff would take minutes if Retry-After were not respected
+        let mut options = HTTPStoreOptions::default();
+        options
+            .max_retries(2)
+            .backoff_base(Duration::from_secs(60))
+            .backoff_max(Duration::from_secs(600));
+        let store = HTTPStore::new_with_options(&base_url, options)?;
+        let start = Instant::now();
+        assert_eq!(store.get(&"a/c".try_into()?)?, Some(vec![0].into()));
+        assert!(start.elapsed() < Duration::from_secs(30));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_retry_connection_error() -> Result<(), Box<dyn Error>> {
+        // Nothing is listening on this port once the listener is dropped
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}/data", listener.local_addr()?);
+        drop(listener);
+
+        let store = HTTPStore::new_with_options(&base_url, fast_retry_options(2))?;
+        assert!(store.get(&"a/b".try_into()?).is_err());
+
+        let mut options = fast_retry_options(2);
+        options.retry_connection_errors(false);
+        let store = HTTPStore::new_with_options(&base_url, options)?;
+        assert!(store.get(&"a/b".try_into()?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_headers_query_parameters() -> Result<(), Box<dyn Error>> {
+        let store = test_store()?;
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let base_url = serve({
+            let requests = requests.clone();
+            move |request| {
+                let authorised = request.header("authorization") == Some("Bearer token")
+                    && request.header("x-api-key") == Some("key")
+                    && request.path.ends_with("?signature=abc&expires=1");
+                requests.lock().unwrap().push((
+                    request.method.clone(),
+                    request.header("range").is_some(),
+                    authorised,
+                ));
+                if authorised {
+                    respond_store(&store, request)
+                } else {
+                    TestResponse::new("401 Unauthorized", "text/plain", vec![])
+                }
+            }
+        })?;
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()?;
+        let mut store = HTTPStore::new_with_client(&base_url, client)?
+            .with_listing_mode(HTTPStoreListingMode::DirectoryListing);
+        store.set_options(fast_retry_options(0));
+        let key: StoreKey = "a/b".try_into()?;
+        assert!(store.size_key(&key).is_err());
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Authorization", HeaderValue::from_static("Bearer token"));
+        headers.insert("X-Api-Key", HeaderValue::from_static("key"));
+        store.set_default_headers(headers);
+        store.set_query_parameters([("signature", "abc"), ("expires", "1")]);
+        assert_eq!(
+            store.key_to_url(&key)?.as_str(),
+            format!("{base_url}/a/b?signature=abc&expires=1")
+        );
+
+        requests.lock().unwrap().clear();
+        assert_eq!(store.size_key(&key)?, Some(4));
+        assert_eq!(
+            store.get_partial_values_key(&key, &[ByteRange::FromStart(1, Some(2))])?,
+            Some(vec![vec![1, 2].into()])
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                ("HEAD".to_string(), false, true),
+                ("HEAD".to_string(), false, true),
+                ("GET".to_string(), true, true),
+            ]
+        );
+
+        zarrs_storage::store_test::store_read(&store)?;
+        zarrs_storage::store_test::store_list(&store)?;
+        assert!(requests
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(_, _, authorised)| *authorised));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn http_store_content_encoding() -> Result<(), Box<dyn Error>> {
+        let store = test_store()?;
+        let value: Vec<u8> = (0..1000u32).map(|i| (i * 7 % 251) as u8).collect();
+        let key = StoreKey::new("big")?;
+        store.set(&key, value.clone().into())?;
+        for encoding in ["gzip", "zstd"] {
+            for identity in [false, true] {
+                let base_url = serve({
+                    let store = store.clone();
+                    move |request| respond_store_encoded(&store, request, encoding, identity)
+                })?;
+                let store = HTTPStore::new(&base_url)?;
+                zarrs_storage::store_test::store_read(&store)?;
+
+                let store = HTTPStore::new(&base_url)?;
+                assert_eq!(store.size_key(&key)?, Some(1000));
+                assert_eq!(
+                    store.get_partial_values_key(
+                        &key,
+                        &[ByteRange::FromStart(10, Some(20)), ByteRange::Suffix(5)]
+                    )?,
+                    Some(vec![
+                        Bytes::copy_from_slice(&value[10..30]),
+                        Bytes::copy_from_slice(&value[995..]),
+                    ])
+                );
+                assert_eq!(store.get(&key)?, Some(value.clone().into()));
+                assert!(store
+                    .get_partial_values_key(&key, &[ByteRange::FromStart(990, Some(20))])
+                    .is_err());
+            }
+        }
+
+        // The cached decoded size of a deleted value is discarded
+        let base_url = serve({
+            let store = store.clone();
+            move |request| respond_store_encoded(&store, request, "gzip", false)
+        })?;
+        let http_store = HTTPStore::new(&base_url)?;
+        assert_eq!(http_store.get(&key)?, Some(value.into()));
+        assert_eq!(http_store.size_key(&key)?, Some(1000));
+        store.erase(&key)?;
+        assert_eq!(http_store.get(&key)?, None);
+        assert_eq!(http_store.size_key(&key)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn http_store_options_backoff() {
+        let mut options = HTTPStoreOptions::default();
+        options
+            .backoff_base(Duration::from_millis(100))
+            .backoff_max(Duration::from_millis(500));
+        assert_eq!(options.backoff(0), Duration::from_millis(100));
+        assert_eq!(options.backoff(1), Duration::from_millis(200));
+        assert_eq!(options.backoff(2), Duration::from_millis(400));
+        assert_eq!(options.backoff(3), Duration::from_millis(500));
+        assert_eq!(options.backoff(1000), Duration::from_millis(500));
+    }
 }
@@ -21,12 +21,25 @@ pub mod v2;
 /// Zarr V2 to V3 conversion.
 pub mod v2_to_v3;
 
+/// Zarr V3 to V2 conversion.
+pub mod v3_to_v2;
+
 /// An alias for [`v3::MetadataV3`].
 #[deprecated(since = "0.17.0", note = "use v3::MetadataV3 explicitly")]
 pub type Metadata = v3::MetadataV3;
 
 pub use array::{ArrayShape, ChunkKeySeparator, ChunkShape, DimensionName, Endianness};
 
+/// A Zarr format version.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum ZarrVersion {
+    /// Zarr Version 3.0.
+    #[default]
+    V3,
+    /// Zarr Version 2.0.
+    V2,
+}
+
 /// A wrapper to handle various versions of Zarr array metadata.
 #[derive(Deserialize, Serialize, Clone, PartialEq, Debug, Display, From)]
 #[serde(untagged)]
@@ -75,7 +88,7 @@ pub enum NodeMetadata {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use v3::{AdditionalFields, MetadataV3};
+    use v3::{AdditionalField, AdditionalFields, MetadataV3};
 
     #[test]
     fn metadata() {
@@ -121,6 +134,38 @@ mod tests {
             .contains(r#""must_understand":false"#));
     }
 
+    #[test]
+    fn additional_fields_must_understand() {
+        let additional_field: AdditionalField = serde_json::from_str(
+            r#"{"must_understand": false, "key": "value", "array": [1, "two", null]}"#,
+        )
+        .unwrap();
+        assert!(!additional_field.must_understand());
+        assert_eq!(
+            serde_json::to_string(&additional_field).unwrap(),
+            r#"{"must_understand":false,"key":"value","array":[1,"two",null]}"#
+        );
+
+        let additional_field: AdditionalField =
+            serde_json::from_str(r#"{"key": "value", "must_understand": true}"#).unwrap();
+        assert!(additional_field.must_understand());
+        assert_eq!(
+            serde_json::to_string(&additional_field).unwrap(),
+            r#"{"key":"value","must_understand":true}"#
+        );
+        assert!(serde_json::from_str::<AdditionalField>(r#"{"must_understand": 1}"#).is_err());
+
+        let mut map = serde_json::Map::new();
+        map.insert("key".to_string(), "value".into());
+        map.insert("must_understand".to_string(), true.into());
+        let additional_field = AdditionalField::from(map);
+        assert!(additional_field.must_understand());
+        assert_eq!(
+            serde_json::to_string(&additional_field).unwrap(),
+            r#"{"key":"value","must_understand":true}"#
+        );
+    }
+
     #[test]
     fn additional_fields_valid() {
         let json = r#"{
@@ -4,8 +4,10 @@ pub mod group;
 /// Zarr V2 array metadata.
 pub mod array;
 
+pub mod nczarr;
+
 pub use array::ArrayMetadataV2;
-pub use group::GroupMetadataV2;
+pub use group::{ConsolidatedMetadataV2, ConsolidatedMetadataV2Metadata, GroupMetadataV2};
 
 mod metadata;
 pub use metadata::MetadataV2;
@@ -22,12 +24,16 @@ pub enum NodeMetadataV2 {
 
 #[cfg(test)]
 mod tests {
-    use array::data_type_metadata_v2_to_endianness;
+    use array::{data_type_metadata_v2_to_endianness, DataTypeMetadataV2};
 
     use super::*;
 
     use crate::{
-        v2_to_v3::{array_metadata_v2_to_v3, data_type_metadata_v2_to_v3_data_type},
+        v2_to_v3::{
+            array_metadata_v2_to_v3, array_metadata_v2_to_v3_opt,
+            data_type_metadata_v2_to_v3_data_type, ArrayMetadataV2ToV3ConversionError,
+            ArrayMetadataV2ToV3ConversionOptions,
+        },
         v3::array::codec::{
             blosc::{self, BloscCodecConfigurationV1},
             transpose::{self, TransposeCodecConfigurationV1},
@@ -100,4 +106,198 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn array_v2_numpy_time() {
+        use crate::v3::array::data_type::{DataTypeMetadataV3, NumpyTimeUnit};
+        use std::num::NonZeroU32;
+
+        let data_type = |dtype: &str| {
+            data_type_metadata_v2_to_v3_data_type(&DataTypeMetadataV2::Simple(dtype.to_string()))
+        };
+        assert_eq!(
+            data_type("<M8[ns]").unwrap(),
+            DataTypeMetadataV3::NumpyDateTime64 {
+                unit: NumpyTimeUnit::Nanosecond,
+                scale_factor: NonZeroU32::new(1).unwrap()
+            }
+        );
+        assert_eq!(
+            data_type(">m8[10us]").unwrap(),
+            DataTypeMetadataV3::NumpyTimeDelta64 {
+                unit: NumpyTimeUnit::Microsecond,
+                scale_factor: NonZeroU32::new(10).unwrap()
+            }
+        );
+        assert_eq!(
+            data_type("<M8").unwrap(),
+            DataTypeMetadataV3::NumpyDateTime64 {
+                unit: NumpyTimeUnit::Generic,
+                scale_factor: NonZeroU32::new(1).unwrap()
+            }
+        );
+        assert_eq!(
+            data_type("<m8[D]").unwrap(),
+            DataTypeMetadataV3::NumpyTimeDelta64 {
+                unit: NumpyTimeUnit::Day,
+                scale_factor: NonZeroU32::new(1).unwrap()
+            }
+        );
+        for invalid in ["<M8[0ns]", "<M8[ns", "<M8[xs]", "<M4[ns]", "<m8[-1s]"] {
+            assert!(data_type(invalid).is_err(), "{invalid}");
+        }
+
+        let json = r#"
+            {
+                "shape": [6],
+                "chunks": [3],
+                "fill_value": -9223372036854775808,
+                "order": "C",
+                "filters": null,
+                "dimension_separator": ".",
+                "compressor": null,
+                "zarr_format": 2,
+                "dtype": "<M8[ns]"
+            }"#;
+        let array_metadata_v2: crate::v2::ArrayMetadataV2 = serde_json::from_str(json).unwrap();
+        let array_metadata_v3 = array_metadata_v2_to_v3(&array_metadata_v2).unwrap();
+        assert_eq!(
+            serde_json::to_value(&array_metadata_v3.data_type).unwrap(),
+            serde_json::json!({
+                "name": "numpy.datetime64",
+                "configuration": {
+                    "unit": "ns",
+                    "scale_factor": 1
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&array_metadata_v3.fill_value).unwrap(),
+            serde_json::json!(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn array_v2_structured() {
+        use crate::v3::array::data_type::DataTypeMetadataV3;
+
+        let json = r#"
+            {
+                "shape": [4],
+                "chunks": [2],
+                "fill_value": "AAcBP+AAAAAAAAA=",
+                "order": "C",
+                "filters": null,
+                "dimension_separator": ".",
+                "compressor": null,
+                "zarr_format": 2,
+                "dtype": [["a", ">i2"], ["flag", "|b1"], ["b", ">f8"]]
+            }"#;
+        let array_metadata_v2: crate::v2::ArrayMetadataV2 = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            data_type_metadata_v2_to_endianness(&array_metadata_v2.dtype).unwrap(),
+            Some(Endianness::Big),
+        );
+        let array_metadata_v3 = array_metadata_v2_to_v3(&array_metadata_v2).unwrap();
+        let DataTypeMetadataV3::Structured(configuration) = &array_metadata_v3.data_type else {
+            panic!()
+        };
+        assert_eq!(configuration.size(), 11);
+        assert_eq!(configuration.fields()[1].name, "flag");
+        assert_eq!(
+            configuration.fields()[1].data_type,
+            DataTypeMetadataV3::Bool
+        );
+        assert_eq!(configuration.fields()[2].offset, 3);
+        // The fill value is converted to little endian fields
+        assert_eq!(
+            serde_json::to_value(&array_metadata_v3.fill_value).unwrap(),
+            serde_json::json!([7, 0, 1, 0, 0, 0, 0, 0, 0, 224, 63])
+        );
+        assert_eq!(
+            serde_json::to_value(&array_metadata_v3.codecs[0]).unwrap(),
+            serde_json::json!({"name": "bytes", "configuration": {"endian": "big"}})
+        );
+
+        // Mixed endianness is not supported
+        let data_type: DataTypeMetadataV2 =
+            serde_json::from_str(r#"[["a", "<i2"], ["b", ">i2"]]"#).unwrap();
+        assert!(data_type_metadata_v2_to_endianness(&data_type).is_err());
+
+        // Subarray fields are not supported
+        let data_type: DataTypeMetadataV2 = serde_json::from_str(r#"[["a", "<i2", [2]]]"#).unwrap();
+        assert!(data_type_metadata_v2_to_v3_data_type(&data_type).is_err());
+
+        // Single byte fields have no endianness
+        let data_type: DataTypeMetadataV2 =
+            serde_json::from_str(r#"[["a", "|u1"], ["b", "|b1"]]"#).unwrap();
+        assert_eq!(
+            data_type_metadata_v2_to_endianness(&data_type).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn array_v2_array_dimensions() {
+        let json = r#"
+            {
+                "shape": [2, 4],
+                "chunks": [2, 4],
+                "fill_value": "NaN",
+                "order": "C",
+                "filters": null,
+                "compressor": null,
+                "zarr_format": 2,
+                "dtype": "<f4",
+                "attributes": {
+                    "_ARRAY_DIMENSIONS": ["y", "x"],
+                    "units": "K"
+                }
+            }"#;
+        let array_metadata_v2: crate::v2::ArrayMetadataV2 = serde_json::from_str(json).unwrap();
+
+        // Not converted by default
+        let array_metadata_v3 = array_metadata_v2_to_v3(&array_metadata_v2).unwrap();
+        assert!(array_metadata_v3.dimension_names.is_none());
+        assert!(array_metadata_v3
+            .attributes
+            .contains_key("_ARRAY_DIMENSIONS"));
+
+        // Converted and kept
+        let options = ArrayMetadataV2ToV3ConversionOptions::default()
+            .with_array_dimensions_to_dimension_names(true);
+        let array_metadata_v3 = array_metadata_v2_to_v3_opt(&array_metadata_v2, &options).unwrap();
+        assert_eq!(
+            array_metadata_v3.dimension_names,
+            Some(vec!["y".into(), "x".into()])
+        );
+        assert!(array_metadata_v3
+            .attributes
+            .contains_key("_ARRAY_DIMENSIONS"));
+
+        // Converted and removed
+        let options = options.with_remove_array_dimensions(true);
+        let array_metadata_v3 = array_metadata_v2_to_v3_opt(&array_metadata_v2, &options).unwrap();
+        assert_eq!(
+            array_metadata_v3.dimension_names,
+            Some(vec!["y".into(), "x".into()])
+        );
+        assert!(!array_metadata_v3
+            .attributes
+            .contains_key("_ARRAY_DIMENSIONS"));
+        assert!(array_metadata_v3.attributes.contains_key("units"));
+
+        // Dimensionality mismatch
+        let mut array_metadata_v2 = array_metadata_v2;
+        array_metadata_v2
+            .attributes
+            .insert("_ARRAY_DIMENSIONS".to_string(), serde_json::json!(["x"]));
+        assert!(matches!(
+            array_metadata_v2_to_v3_opt(&array_metadata_v2, &options),
+            Err(ArrayMetadataV2ToV3ConversionError::InvalidArrayDimensions(
+                _,
+                2
+            ))
+        ));
+    }
 }
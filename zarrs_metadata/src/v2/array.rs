@@ -8,16 +8,30 @@ use crate::{
 
 /// Zarr V2 codec metadata.
 pub mod codec {
+    /// `adler32` codec metadata.
+    pub mod adler32;
     /// `bitround` codec metadata.
     pub mod bitround;
+    /// `bitshuffle` codec metadata.
+    pub mod bitshuffle;
     /// `blosc` codec metadata.
     pub mod blosc;
     /// `bz2` codec metadata.
     pub mod bz2;
+    /// `crc32` codec metadata.
+    pub mod crc32;
     /// `fletcher32` codec metadata.
     pub mod fletcher32;
     /// `gzip` codec metadata.
     pub mod gzip;
+    /// `jenkins_lookup3` codec metadata.
+    pub mod jenkins_lookup3;
+    /// `lz4` codec metadata.
+    pub mod lz4;
+    /// `lzma` codec metadata.
+    pub mod lzma;
+    /// `quantize` codec metadata.
+    pub mod quantize;
     /// `vlen-array` codec metadata.
     pub mod vlen_array;
     /// `vlen-bytes` codec metadata.
@@ -59,7 +73,6 @@ pub mod codec {
 /// }
 /// ```
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Display)]
-#[serde(tag = "node_type", rename = "array")]
 #[display("{}", serde_json::to_string(self).unwrap_or_default())]
 pub struct ArrayMetadataV2 {
     /// An integer defining the version of the storage specification to which the array adheres. Must be `2`.
@@ -178,7 +191,7 @@ pub struct DataTypeMetadataV2Structured {
 struct DataTypeMetadataV2StructuredTuple(
     String,
     String,
-    #[serde(skip_serializing_if = "Option::is_none")] Option<Vec<u64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")] Option<Vec<u64>>,
 );
 
 impl From<DataTypeMetadataV2StructuredTuple> for DataTypeMetadataV2Structured {
@@ -192,6 +205,36 @@ impl From<DataTypeMetadataV2StructuredTuple> for DataTypeMetadataV2Structured {
     }
 }
 
+impl DataTypeMetadataV2Structured {
+    /// Create a new structured data type field.
+    #[must_use]
+    pub fn new(fieldname: String, datatype: String, shape: Option<Vec<u64>>) -> Self {
+        Self {
+            fieldname,
+            datatype,
+            shape,
+        }
+    }
+
+    /// Returns the field name.
+    #[must_use]
+    pub fn fieldname(&self) -> &str {
+        &self.fieldname
+    }
+
+    /// Returns the data type.
+    #[must_use]
+    pub fn datatype(&self) -> &str {
+        &self.datatype
+    }
+
+    /// Returns the subarray shape.
+    #[must_use]
+    pub fn shape(&self) -> Option<&[u64]> {
+        self.shape.as_deref()
+    }
+}
+
 impl From<DataTypeMetadataV2Structured> for DataTypeMetadataV2StructuredTuple {
     fn from(value: DataTypeMetadataV2Structured) -> Self {
         Self(value.fieldname, value.datatype, value.shape)
@@ -216,8 +259,10 @@ pub struct DataTypeMetadataV2InvalidEndiannessError(DataTypeMetadataV2);
 
 /// Get the endianness of a Zarr V2 data type.
 ///
+/// The endianness of a structured data type is the endianness of its multi-byte fields, which must match.
+///
 /// # Errors
-/// Returns a [`DataTypeMetadataV2InvalidEndiannessError`] if the data type is not supported or the endianness prefix is invalid.
+/// Returns a [`DataTypeMetadataV2InvalidEndiannessError`] if the data type is not supported, the endianness prefix is invalid, or the fields of a structured data type have different endianness.
 pub fn data_type_metadata_v2_to_endianness(
     data_type: &DataTypeMetadataV2,
 ) -> Result<Option<Endianness>, DataTypeMetadataV2InvalidEndiannessError> {
@@ -233,8 +278,24 @@ pub fn data_type_metadata_v2_to_endianness(
                 Err(DataTypeMetadataV2InvalidEndiannessError(data_type.clone()))
             }
         }
-        DataTypeMetadataV2::Structured(_) => {
-            Err(DataTypeMetadataV2InvalidEndiannessError(data_type.clone()))
+        DataTypeMetadataV2::Structured(fields) => {
+            let mut endianness = None;
+            for field in fields {
+                let field_endianness = data_type_metadata_v2_to_endianness(
+                    &DataTypeMetadataV2::Simple(field.datatype.clone()),
+                )
+                .map_err(|_| DataTypeMetadataV2InvalidEndiannessError(data_type.clone()))?;
+                match (endianness, field_endianness) {
+                    (Some(endianness), Some(field_endianness))
+                        if endianness != field_endianness =>
+                    {
+                        return Err(DataTypeMetadataV2InvalidEndiannessError(data_type.clone()));
+                    }
+                    (None, Some(_)) => endianness = field_endianness,
+                    _ => {}
+                }
+            }
+            Ok(endianness)
         }
     }
 }
@@ -0,0 +1 @@
+pub use crate::v3::array::codec::adler32::{Adler32CodecConfigurationV1, ChecksumLocation};
@@ -0,0 +1,3 @@
+pub use crate::v3::array::codec::bitshuffle::{
+    BitshuffleCodecConfigurationV1, BitshuffleCompression,
+};
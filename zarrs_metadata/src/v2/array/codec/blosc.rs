@@ -86,6 +86,26 @@ pub fn codec_blosc_v2_numcodecs_to_v3(
     })
 }
 
+/// Convert [`BloscCodecConfigurationV1`] to [`BloscCodecConfigurationNumcodecs`].
+///
+/// The type size is not retained, `numcodecs` infers it from the data.
+#[must_use]
+pub fn codec_blosc_v3_to_v2_numcodecs(
+    blosc: &BloscCodecConfigurationV1,
+) -> BloscCodecConfigurationNumcodecs {
+    let shuffle = match blosc.shuffle {
+        BloscShuffleMode::NoShuffle => BloscShuffleModeNumCodecs::NoShuffle,
+        BloscShuffleMode::Shuffle => BloscShuffleModeNumCodecs::Shuffle,
+        BloscShuffleMode::BitShuffle => BloscShuffleModeNumCodecs::BitShuffle,
+    };
+    BloscCodecConfigurationNumcodecs {
+        cname: blosc.cname,
+        clevel: blosc.clevel,
+        shuffle,
+        blocksize: blosc.blocksize,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
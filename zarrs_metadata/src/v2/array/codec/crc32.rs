@@ -0,0 +1 @@
+pub use crate::v3::array::codec::crc32::{ChecksumLocation, Crc32CodecConfigurationV1};
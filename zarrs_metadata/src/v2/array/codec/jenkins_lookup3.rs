@@ -0,0 +1 @@
+pub use crate::v3::array::codec::jenkins_lookup3::JenkinsLookup3CodecConfigurationV1;
@@ -0,0 +1 @@
+pub use crate::v3::array::codec::lz4::Lz4CodecConfigurationV1;
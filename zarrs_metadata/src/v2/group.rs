@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use derive_more::{Display, From};
 use serde::{Deserialize, Serialize};
 
@@ -53,3 +55,27 @@ impl GroupMetadataV2 {
         self
     }
 }
+
+/// Zarr V2 consolidated metadata (`.zmetadata`).
+///
+/// This is not part of Zarr V2, but is written by `zarr-python` 2.x with `zarr.consolidate_metadata`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ConsolidatedMetadataV2 {
+    /// A mapping from metadata keys (`.zarray`, `.zgroup`, `.zattrs`) relative to the consolidated node to their documents.
+    pub metadata: ConsolidatedMetadataV2Metadata,
+    /// The consolidated metadata format. Must be `1`.
+    pub zarr_consolidated_format: monostate::MustBe!(1u64),
+}
+
+/// The `metadata` field of [`ConsolidatedMetadataV2`].
+pub type ConsolidatedMetadataV2Metadata = BTreeMap<String, serde_json::Value>;
+
+impl Default for ConsolidatedMetadataV2 {
+    fn default() -> Self {
+        Self {
+            metadata: BTreeMap::default(),
+            zarr_consolidated_format: monostate::MustBe!(1u64),
+        }
+    }
+}
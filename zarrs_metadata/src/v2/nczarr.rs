@@ -0,0 +1,211 @@
+//! [NCZarr](https://docs.unidata.ucar.edu/nug/current/nczarr_head.html) compatibility.
+//!
+//! `netCDF-C` writes Zarr V2 data with some quirks that are incompatible with strict Zarr V2 metadata deserialisation:
+//!  - special `_nczarr_*` members (e.g. `_nczarr_superblock`, `_nczarr_group`, `_nczarr_array`, `_nczarr_attr`) in `.zgroup`, `.zarray`, and `.zattrs` documents, which are not valid additional fields because they lack a `must_understand` member, and
+//!  - floating point fill values encoded as quoted numbers (e.g. `"fill_value": "-999.0"`).
+//!
+//! The functions in this module deserialise such metadata.
+//! The `_nczarr_*` members are moved to the additional fields of the metadata, where they are treated as `"must_understand": false`.
+
+use serde_json::{Map, Value};
+
+use crate::{
+    v3::{AdditionalField, AdditionalFields},
+    DimensionName,
+};
+
+use super::{
+    array::{DataTypeMetadataV2, FillValueMetadataV2},
+    ArrayMetadataV2, GroupMetadataV2,
+};
+
+/// The prefix of the names of `NCZarr` metadata members and attributes.
+pub const NCZARR_PREFIX: &str = "_nczarr_";
+
+/// The name of the `NCZarr` array metadata member, which holds the dimension references (`dimrefs`) of an array.
+pub const NCZARR_ARRAY: &str = "_nczarr_array";
+
+/// Move the `_nczarr_*` object members of `document` to `additional_fields`.
+fn nczarr_members_to_additional_fields(
+    document: &mut Map<String, Value>,
+    additional_fields: &mut AdditionalFields,
+) {
+    let names: Vec<String> = document
+        .iter()
+        .filter(|(name, value)| name.starts_with(NCZARR_PREFIX) && value.is_object())
+        .map(|(name, _)| name.clone())
+        .collect();
+    for name in names {
+        if let Some(Value::Object(field)) = document.remove(&name) {
+            additional_fields.insert(name, AdditionalField::from(field));
+        }
+    }
+}
+
+/// Convert a floating point fill value encoded as a quoted number (e.g. `"-999.0"`) to a number.
+fn nczarr_fill_value(
+    data_type: &DataTypeMetadataV2,
+    fill_value: FillValueMetadataV2,
+) -> FillValueMetadataV2 {
+    let is_float = matches!(data_type, DataTypeMetadataV2::Simple(data_type) if data_type.get(1..2) == Some("f"));
+    match fill_value {
+        FillValueMetadataV2::String(string) if is_float => match string.trim().parse::<f64>() {
+            Ok(f) if f.is_nan() => FillValueMetadataV2::NaN,
+            Ok(f) if f == f64::INFINITY => FillValueMetadataV2::Infinity,
+            Ok(f) if f == f64::NEG_INFINITY => FillValueMetadataV2::NegInfinity,
+            Ok(f) => serde_json::Number::from_f64(f).map_or(
+                FillValueMetadataV2::String(string),
+                FillValueMetadataV2::Number,
+            ),
+            Err(_) => FillValueMetadataV2::String(string),
+        },
+        fill_value => fill_value,
+    }
+}
+
+/// Deserialise Zarr V2 array metadata written by `NCZarr` from its `.zarray` `document` and `.zattrs` `attributes`.
+///
+/// The `_nczarr_*` members of the document and attributes are moved to the additional fields, and quoted floating point fill values are converted to numbers.
+///
+/// # Errors
+/// Returns a [`serde_json::Error`] if the document is not valid Zarr V2 array metadata.
+pub fn array_metadata_v2_from_nczarr(
+    mut document: Map<String, Value>,
+    attributes: Option<Map<String, Value>>,
+) -> Result<ArrayMetadataV2, serde_json::Error> {
+    let mut additional_fields = AdditionalFields::new();
+    nczarr_members_to_additional_fields(&mut document, &mut additional_fields);
+    let mut metadata: ArrayMetadataV2 = serde_json::from_value(Value::Object(document))?;
+    if let Some(mut attributes) = attributes {
+        nczarr_members_to_additional_fields(&mut attributes, &mut additional_fields);
+        metadata.attributes = attributes;
+    }
+    metadata.additional_fields.extend(additional_fields);
+    metadata.fill_value = nczarr_fill_value(&metadata.dtype, metadata.fill_value);
+    Ok(metadata)
+}
+
+/// Deserialise Zarr V2 group metadata written by `NCZarr` from its `.zgroup` `document` and `.zattrs` `attributes`.
+///
+/// The `_nczarr_*` members of the document and attributes are moved to the additional fields.
+///
+/// # Errors
+/// Returns a [`serde_json::Error`] if the document is not valid Zarr V2 group metadata.
+pub fn group_metadata_v2_from_nczarr(
+    mut document: Map<String, Value>,
+    attributes: Option<Map<String, Value>>,
+) -> Result<GroupMetadataV2, serde_json::Error> {
+    let mut additional_fields = AdditionalFields::new();
+    nczarr_members_to_additional_fields(&mut document, &mut additional_fields);
+    let mut metadata: GroupMetadataV2 = serde_json::from_value(Value::Object(document))?;
+    if let Some(mut attributes) = attributes {
+        nczarr_members_to_additional_fields(&mut attributes, &mut additional_fields);
+        metadata.attributes = attributes;
+    }
+    metadata.additional_fields.extend(additional_fields);
+    Ok(metadata)
+}
+
+/// Return the dimension names of an array from the dimension references of its [`_nczarr_array`](NCZARR_ARRAY) additional field.
+///
+/// A dimension reference is the fully qualified name of a dimension (e.g. `/group/lat`), and the dimension name is its last component (e.g. `lat`).
+/// Returns [`None`] if the field is absent or its dimension references do not match the array dimensionality.
+#[must_use]
+pub fn nczarr_dimension_names(metadata: &ArrayMetadataV2) -> Option<Vec<DimensionName>> {
+    let dimrefs = metadata
+        .additional_fields
+        .get(NCZARR_ARRAY)?
+        .as_map()
+        .get("dimrefs")?
+        .as_array()?;
+    if dimrefs.len() != metadata.shape.len() {
+        return None;
+    }
+    dimrefs
+        .iter()
+        .map(|dimref| {
+            let dimref = dimref.as_str()?;
+            Some(DimensionName::new(
+                dimref.rsplit('/').next().unwrap_or(dimref),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nczarr_array_metadata() {
+        let document = serde_json::json!({
+            "zarr_format": 2,
+            "shape": [4, 3],
+            "dtype": "<f4",
+            "chunks": [2, 3],
+            "fill_value": "-999.0",
+            "order": "C",
+            "compressor": null,
+            "filters": null,
+            "dimension_separator": ".",
+            "_nczarr_array": {"dimrefs": ["/lat", "/lon"], "storage": "chunked"}
+        });
+        let attributes = serde_json::json!({
+            "units": "K",
+            "_nczarr_attr": {"types": {"units": "<U1"}}
+        });
+        let Value::Object(document) = document else {
+            unreachable!()
+        };
+        let Value::Object(attributes) = attributes else {
+            unreachable!()
+        };
+
+        // The NCZarr members are not valid additional fields
+        assert!(
+            serde_json::from_value::<ArrayMetadataV2>(Value::Object(document.clone())).is_err()
+        );
+
+        let metadata = array_metadata_v2_from_nczarr(document, Some(attributes)).unwrap();
+        assert_eq!(
+            metadata.fill_value,
+            FillValueMetadataV2::Number(serde_json::Number::from_f64(-999.0).unwrap())
+        );
+        assert_eq!(metadata.attributes.len(), 1);
+        assert_eq!(metadata.attributes["units"], "K");
+        assert_eq!(
+            metadata.additional_fields.keys().collect::<Vec<_>>(),
+            ["_nczarr_array", "_nczarr_attr"]
+        );
+        assert!(!metadata.additional_fields[NCZARR_ARRAY].must_understand());
+        assert_eq!(
+            nczarr_dimension_names(&metadata).unwrap(),
+            [DimensionName::new("lat"), DimensionName::new("lon")]
+        );
+    }
+
+    #[test]
+    fn nczarr_fill_value_quoted() {
+        let float = DataTypeMetadataV2::Simple("<f8".to_string());
+        let string = |s: &str| FillValueMetadataV2::String(s.to_string());
+        assert_eq!(
+            nczarr_fill_value(&float, string("9.969209968386869e+36")),
+            FillValueMetadataV2::Number(
+                serde_json::Number::from_f64(9.969_209_968_386_869e36).unwrap()
+            )
+        );
+        assert_eq!(
+            nczarr_fill_value(&float, string("nan")),
+            FillValueMetadataV2::NaN
+        );
+        assert_eq!(
+            nczarr_fill_value(&float, string("-inf")),
+            FillValueMetadataV2::NegInfinity
+        );
+        assert_eq!(nczarr_fill_value(&float, string("x")), string("x"));
+
+        // Quoted fill values of other data types are unchanged
+        let int = DataTypeMetadataV2::Simple("<i4".to_string());
+        assert_eq!(nczarr_fill_value(&int, string("1")), string("1"));
+    }
+}
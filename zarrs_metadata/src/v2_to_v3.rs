@@ -1,10 +1,20 @@
+use std::num::NonZeroU32;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
 use thiserror::Error;
 
 use crate::{
     v2::{
         array::{
             codec::{
+                adler32::Adler32CodecConfigurationV1,
+                bitshuffle::BitshuffleCodecConfigurationV1,
                 blosc::{codec_blosc_v2_numcodecs_to_v3, BloscCodecConfigurationNumcodecs},
+                crc32::Crc32CodecConfigurationV1,
+                jenkins_lookup3::JenkinsLookup3CodecConfigurationV1,
+                lz4::Lz4CodecConfigurationV1,
+                lzma::LzmaCodecConfigurationV1,
+                quantize::QuantizeCodecConfigurationV1,
                 zfpy::{codec_zfpy_v2_numcodecs_to_v3, ZfpyCodecConfigurationNumcodecs},
                 zstd::{codec_zstd_v2_numcodecs_to_v3, ZstdCodecConfigurationNumCodecs},
             },
@@ -25,9 +35,12 @@ use crate::{
         },
         ArrayMetadataV3, GroupMetadataV3, MetadataV3,
     },
+    DimensionName, Endianness,
 };
 
-use super::v3::array::data_type::DataTypeMetadataV3;
+use super::v3::array::data_type::{
+    DataTypeMetadataV3, NumpyTimeUnit, StructuredDataTypeConfiguration,
+};
 
 /// Convert Zarr V2 group metadata to V3.
 #[allow(clippy::too_many_lines)]
@@ -53,6 +66,9 @@ pub enum ArrayMetadataV2ToV3ConversionError {
     /// An unsupported fill value.
     #[error("unsupported fill value {_1:?} for data type {_0}")]
     UnsupportedFillValue(String, FillValueMetadataV2),
+    /// An `_ARRAY_DIMENSIONS` attribute that is not a list of dimension names matching the array dimensionality.
+    #[error("invalid _ARRAY_DIMENSIONS attribute {_0} for an array with {_1} dimensions")]
+    InvalidArrayDimensions(serde_json::Value, usize),
     /// Serialization/deserialization error.
     #[error("JSON serialization or deserialization error: {_0}")]
     SerdeError(#[from] serde_json::Error),
@@ -61,13 +77,85 @@ pub enum ArrayMetadataV2ToV3ConversionError {
     Other(String),
 }
 
+/// The name of the attribute holding the dimension names of an array in the `NetCDF`/`xarray` convention.
+pub const ARRAY_DIMENSIONS_ATTRIBUTE: &str = "_ARRAY_DIMENSIONS";
+
+/// Options for converting Zarr V2 array metadata to V3.
+#[derive(Debug, Clone, Default)]
+pub struct ArrayMetadataV2ToV3ConversionOptions {
+    array_dimensions_to_dimension_names: bool,
+    remove_array_dimensions: bool,
+}
+
+impl ArrayMetadataV2ToV3ConversionOptions {
+    /// Return the array dimensions to dimension names setting. Default: `false`.
+    ///
+    /// If true, the [`_ARRAY_DIMENSIONS`](ARRAY_DIMENSIONS_ATTRIBUTE) attribute is converted to `dimension_names`.
+    #[must_use]
+    pub fn array_dimensions_to_dimension_names(&self) -> bool {
+        self.array_dimensions_to_dimension_names
+    }
+
+    /// Set the array dimensions to dimension names setting.
+    #[must_use]
+    pub fn with_array_dimensions_to_dimension_names(mut self, enabled: bool) -> Self {
+        self.array_dimensions_to_dimension_names = enabled;
+        self
+    }
+
+    /// Set the array dimensions to dimension names setting.
+    pub fn set_array_dimensions_to_dimension_names(&mut self, enabled: bool) -> &mut Self {
+        self.array_dimensions_to_dimension_names = enabled;
+        self
+    }
+
+    /// Return the remove array dimensions setting. Default: `false`.
+    ///
+    /// If true, the [`_ARRAY_DIMENSIONS`](ARRAY_DIMENSIONS_ATTRIBUTE) attribute is removed when it is converted to `dimension_names`.
+    /// Keep the attribute if the array will be read by `xarray` after conversion.
+    #[must_use]
+    pub fn remove_array_dimensions(&self) -> bool {
+        self.remove_array_dimensions
+    }
+
+    /// Set the remove array dimensions setting.
+    #[must_use]
+    pub fn with_remove_array_dimensions(mut self, enabled: bool) -> Self {
+        self.remove_array_dimensions = enabled;
+        self
+    }
+
+    /// Set the remove array dimensions setting.
+    pub fn set_remove_array_dimensions(&mut self, enabled: bool) -> &mut Self {
+        self.remove_array_dimensions = enabled;
+        self
+    }
+}
+
+/// Convert Zarr V2 array metadata to V3 with default [`ArrayMetadataV2ToV3ConversionOptions`].
+///
+/// # Errors
+/// Returns a [`ArrayMetadataV2ToV3ConversionError`] if the metadata is invalid or is not compatible with Zarr V3 metadata.
+pub fn array_metadata_v2_to_v3(
+    array_metadata_v2: &ArrayMetadataV2,
+) -> Result<ArrayMetadataV3, ArrayMetadataV2ToV3ConversionError> {
+    array_metadata_v2_to_v3_opt(
+        array_metadata_v2,
+        &ArrayMetadataV2ToV3ConversionOptions::default(),
+    )
+}
+
 /// Convert Zarr V2 array metadata to V3.
 ///
+/// The codecs of an array with `F` order begin with a `transpose` codec that reverses the dimensions.
+/// The filters and compressor then encode chunk elements in `F` order, matching `zarr-python`, while decoded chunks are in C order.
+///
 /// # Errors
 /// Returns a [`ArrayMetadataV2ToV3ConversionError`] if the metadata is invalid or is not compatible with Zarr V3 metadata.
 #[allow(clippy::too_many_lines)]
-pub fn array_metadata_v2_to_v3(
+pub fn array_metadata_v2_to_v3_opt(
     array_metadata_v2: &ArrayMetadataV2,
+    options: &ArrayMetadataV2ToV3ConversionOptions,
 ) -> Result<ArrayMetadataV3, ArrayMetadataV2ToV3ConversionError> {
     let shape = array_metadata_v2.shape.clone();
     let chunk_grid = MetadataV3::new_with_serializable_configuration(
@@ -119,6 +207,26 @@ pub fn array_metadata_v2_to_v3(
         }
     }
 
+    if let (DataTypeMetadataV3::Structured(configuration), FillValueMetadataV3::String(string)) =
+        (&data_type, &fill_value)
+    {
+        // Map a base64 encoded fill value to the little endian bytes of a record
+        let mut bytes = BASE64_STANDARD
+            .decode(string)
+            .ok()
+            .filter(|bytes| bytes.len() == configuration.size())
+            .ok_or_else(|| {
+                ArrayMetadataV2ToV3ConversionError::UnsupportedFillValue(
+                    data_type.to_string(),
+                    array_metadata_v2.fill_value.clone(),
+                )
+            })?;
+        if endianness == Some(Endianness::Big) {
+            reverse_structured_endianness(&mut bytes, configuration);
+        }
+        fill_value = FillValueMetadataV3::ByteArray(bytes);
+    }
+
     let mut codecs: Vec<MetadataV3> = vec![];
 
     // Array-to-array codecs
@@ -152,6 +260,16 @@ pub fn array_metadata_v2_to_v3(
                         MetadataV3::new_with_configuration(filter.id(), serde_json::Map::default());
                     codecs.push(vlen_v2_metadata);
                 }
+                crate::v3::array::codec::quantize::IDENTIFIER => {
+                    // quantize is v2/v3 compatible
+                    let configuration = serde_json::from_value::<QuantizeCodecConfigurationV1>(
+                        serde_json::to_value(filter.configuration())?,
+                    )?;
+                    codecs.push(MetadataV3::new_with_serializable_configuration(
+                        crate::v3::array::codec::quantize::IDENTIFIER,
+                        &configuration,
+                    )?);
+                }
                 _ => {
                     codecs.push(MetadataV3::new_with_configuration(
                         filter.id(),
@@ -205,6 +323,26 @@ pub fn array_metadata_v2_to_v3(
             | crate::v3::array::codec::pcodec::IDENTIFIER => {
                 // already handled above
             }
+            crate::v3::array::codec::adler32::IDENTIFIER => {
+                // adler32 is v2/v3 compatible
+                let configuration = serde_json::from_value::<Adler32CodecConfigurationV1>(
+                    serde_json::to_value(compressor.configuration())?,
+                )?;
+                codecs.push(MetadataV3::new_with_serializable_configuration(
+                    crate::v3::array::codec::adler32::IDENTIFIER,
+                    &configuration,
+                )?);
+            }
+            crate::v3::array::codec::bitshuffle::IDENTIFIER => {
+                // bitshuffle is v2/v3 compatible
+                let configuration = serde_json::from_value::<BitshuffleCodecConfigurationV1>(
+                    serde_json::to_value(compressor.configuration())?,
+                )?;
+                codecs.push(MetadataV3::new_with_serializable_configuration(
+                    crate::v3::array::codec::bitshuffle::IDENTIFIER,
+                    &configuration,
+                )?);
+            }
             crate::v3::array::codec::blosc::IDENTIFIER => {
                 let blosc = serde_json::from_value::<BloscCodecConfigurationNumcodecs>(
                     serde_json::to_value(compressor.configuration())?,
@@ -215,6 +353,46 @@ pub fn array_metadata_v2_to_v3(
                     &configuration,
                 )?);
             }
+            crate::v3::array::codec::crc32::IDENTIFIER => {
+                // crc32 is v2/v3 compatible
+                let configuration = serde_json::from_value::<Crc32CodecConfigurationV1>(
+                    serde_json::to_value(compressor.configuration())?,
+                )?;
+                codecs.push(MetadataV3::new_with_serializable_configuration(
+                    crate::v3::array::codec::crc32::IDENTIFIER,
+                    &configuration,
+                )?);
+            }
+            crate::v3::array::codec::jenkins_lookup3::IDENTIFIER => {
+                // jenkins_lookup3 is v2/v3 compatible
+                let configuration = serde_json::from_value::<JenkinsLookup3CodecConfigurationV1>(
+                    serde_json::to_value(compressor.configuration())?,
+                )?;
+                codecs.push(MetadataV3::new_with_serializable_configuration(
+                    crate::v3::array::codec::jenkins_lookup3::IDENTIFIER,
+                    &configuration,
+                )?);
+            }
+            crate::v3::array::codec::lz4::IDENTIFIER => {
+                // lz4 is v2/v3 compatible
+                let configuration = serde_json::from_value::<Lz4CodecConfigurationV1>(
+                    serde_json::to_value(compressor.configuration())?,
+                )?;
+                codecs.push(MetadataV3::new_with_serializable_configuration(
+                    crate::v3::array::codec::lz4::IDENTIFIER,
+                    &configuration,
+                )?);
+            }
+            crate::v3::array::codec::lzma::IDENTIFIER => {
+                // lzma is v2/v3 compatible
+                let configuration = serde_json::from_value::<LzmaCodecConfigurationV1>(
+                    serde_json::to_value(compressor.configuration())?,
+                )?;
+                codecs.push(MetadataV3::new_with_serializable_configuration(
+                    crate::v3::array::codec::lzma::IDENTIFIER,
+                    &configuration,
+                )?);
+            }
             crate::v3::array::codec::zstd::IDENTIFIER => {
                 let zstd = serde_json::from_value::<ZstdCodecConfigurationNumCodecs>(
                     serde_json::to_value(compressor.configuration())?,
@@ -239,16 +417,46 @@ pub fn array_metadata_v2_to_v3(
         },
     )?;
 
-    let attributes = array_metadata_v2.attributes.clone();
+    let mut attributes = array_metadata_v2.attributes.clone();
+    let dimension_names = if options.array_dimensions_to_dimension_names() {
+        array_dimensions_to_dimension_names(&mut attributes, &array_metadata_v2.shape, options)?
+    } else {
+        None
+    };
 
     Ok(
         ArrayMetadataV3::new(shape, chunk_grid, data_type, fill_value, codecs)
             .with_attributes(attributes)
             .with_additional_fields(array_metadata_v2.additional_fields.clone())
-            .with_chunk_key_encoding(chunk_key_encoding),
+            .with_chunk_key_encoding(chunk_key_encoding)
+            .with_dimension_names(dimension_names),
     )
 }
 
+/// Convert the [`_ARRAY_DIMENSIONS`](ARRAY_DIMENSIONS_ATTRIBUTE) attribute to dimension names, removing it from `attributes` if requested.
+fn array_dimensions_to_dimension_names(
+    attributes: &mut serde_json::Map<String, serde_json::Value>,
+    shape: &[u64],
+    options: &ArrayMetadataV2ToV3ConversionOptions,
+) -> Result<Option<Vec<DimensionName>>, ArrayMetadataV2ToV3ConversionError> {
+    let Some(array_dimensions) = attributes.get(ARRAY_DIMENSIONS_ATTRIBUTE) else {
+        return Ok(None);
+    };
+    let dimension_names = serde_json::from_value::<Vec<DimensionName>>(array_dimensions.clone())
+        .ok()
+        .filter(|dimension_names| dimension_names.len() == shape.len())
+        .ok_or_else(|| {
+            ArrayMetadataV2ToV3ConversionError::InvalidArrayDimensions(
+                array_dimensions.clone(),
+                shape.len(),
+            )
+        })?;
+    if options.remove_array_dimensions() {
+        attributes.remove(ARRAY_DIMENSIONS_ATTRIBUTE);
+    }
+    Ok(Some(dimension_names))
+}
+
 /// An unsupported Zarr V2 data type error.
 #[derive(Debug, Error)]
 #[error("V2 data type {_0:?} is not supported")]
@@ -279,8 +487,11 @@ pub fn data_type_metadata_v2_to_v3_data_type(
                 "<c8" | ">c8" => Ok(DataTypeMetadataV3::Complex64),
                 "<c16" | ">c16" => Ok(DataTypeMetadataV3::Complex128),
                 "|O" => Ok(DataTypeMetadataV3::String), // LEGACY: This is not part of the spec. The dtype for a PyObject, which is what zarr-python 2 uses for string arrays.
-                // TODO "|mX" timedelta
-                // TODO "|MX" datetime
+                dtype if matches!(dtype.get(1..2), Some("M" | "m")) => {
+                    data_type_numpy_time_v2_to_v3(dtype).ok_or_else(|| {
+                        DataTypeMetadataV2UnsupportedDataTypeError(data_type.clone())
+                    })
+                }
                 // TODO "|SX" string (fixed length sequence of char)
                 // TODO "|UX" string (fixed length sequence of Py_UNICODE)
                 // TODO "|VX" other (void * – each item is a fixed-size chunk of memory)
@@ -289,12 +500,90 @@ pub fn data_type_metadata_v2_to_v3_data_type(
                 )),
             }
         }
-        DataTypeMetadataV2::Structured(_) => Err(DataTypeMetadataV2UnsupportedDataTypeError(
-            data_type.clone(),
-        )),
+        DataTypeMetadataV2::Structured(fields) => {
+            let err = || DataTypeMetadataV2UnsupportedDataTypeError(data_type.clone());
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    if field.shape().is_some() {
+                        return Err(err());
+                    }
+                    let field_data_type = data_type_metadata_v2_to_v3_data_type(
+                        &DataTypeMetadataV2::Simple(field.datatype().to_string()),
+                    )
+                    .map_err(|_| err())?;
+                    Ok((field.fieldname().to_string(), field_data_type))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(DataTypeMetadataV3::Structured(
+                StructuredDataTypeConfiguration::new_packed(fields).map_err(|_| err())?,
+            ))
+        }
     }
 }
 
+/// Reverse the endianness of each field of the records in `bytes`.
+pub(crate) fn reverse_structured_endianness(
+    bytes: &mut [u8],
+    configuration: &StructuredDataTypeConfiguration,
+) {
+    for record in bytes.chunks_exact_mut(configuration.size()) {
+        for field in configuration.fields() {
+            let field_size = field.data_type.fixed_size().unwrap_or_default();
+            let field_bytes = &mut record[field.offset..field.offset + field_size];
+            match &field.data_type {
+                DataTypeMetadataV3::Structured(configuration) => {
+                    reverse_structured_endianness(field_bytes, configuration);
+                }
+                DataTypeMetadataV3::RawBits(_)
+                | DataTypeMetadataV3::FixedLengthUtf8(_)
+                | DataTypeMetadataV3::FixedLengthBytes(_) => {}
+                DataTypeMetadataV3::ComplexBFloat16
+                | DataTypeMetadataV3::ComplexFloat16
+                | DataTypeMetadataV3::ComplexFloat32
+                | DataTypeMetadataV3::Complex64
+                | DataTypeMetadataV3::Complex128 => field_bytes
+                    .chunks_exact_mut(field_size / 2)
+                    .for_each(<[u8]>::reverse),
+                _ => field_bytes.reverse(),
+            }
+        }
+    }
+}
+
+/// Convert a `NumPy` datetime (e.g. `<M8[ns]`) or timedelta (e.g. `<m8[10us]`) V2 data type to V3.
+///
+/// Returns [`None`] if the data type is not a valid `NumPy` datetime or timedelta.
+fn data_type_numpy_time_v2_to_v3(data_type: &str) -> Option<DataTypeMetadataV3> {
+    let datetime = match data_type.get(1..3)? {
+        "M8" => true,
+        "m8" => false,
+        _ => return None,
+    };
+    let units = data_type.get(3..)?;
+    let (unit, scale_factor) = if units.is_empty() {
+        (NumpyTimeUnit::Generic, NonZeroU32::MIN)
+    } else {
+        let units = units.strip_prefix('[')?.strip_suffix(']')?;
+        let unit_start = units
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(units.len());
+        let (scale_factor, unit) = units.split_at(unit_start);
+        let scale_factor = if scale_factor.is_empty() {
+            NonZeroU32::MIN
+        } else {
+            scale_factor.parse().ok()?
+        };
+        let unit: NumpyTimeUnit = serde_json::from_value(unit.into()).ok()?;
+        (unit, scale_factor)
+    };
+    Some(if datetime {
+        DataTypeMetadataV3::NumpyDateTime64 { unit, scale_factor }
+    } else {
+        DataTypeMetadataV3::NumpyTimeDelta64 { unit, scale_factor }
+    })
+}
+
 /// Convert Zarr V2 fill value metadata to [`FillValueMetadataV3`].
 ///
 /// Returns [`None`] for [`FillValueMetadataV2::Null`].
@@ -12,8 +12,12 @@ pub mod data_type;
 
 /// Zarr V3 codec metadata.
 pub mod codec {
+    /// `adler32` codec metadata.
+    pub mod adler32;
     /// `bitround` codec metadata.
     pub mod bitround;
+    /// `bitshuffle` codec metadata.
+    pub mod bitshuffle;
     /// `blosc` codec metadata.
     pub mod blosc;
     /// `bytes` codec metadata.
@@ -21,22 +25,38 @@ pub mod codec {
 
     /// `bz2` codec metadata.
     pub mod bz2;
+    /// `crc32` codec metadata.
+    pub mod crc32;
     /// `crc32c` codec metadata.
     pub mod crc32c;
+    /// `expand_dims` codec metadata.
+    pub mod expand_dims;
     /// `fletcher32` codec metadata.
     pub mod fletcher32;
     /// `gdeflate` codec metadata.
     pub mod gdeflate;
     /// `gzip` codec metadata.
     pub mod gzip;
+    /// `jenkins_lookup3` codec metadata.
+    pub mod jenkins_lookup3;
+    /// `jpegxl` codec metadata.
+    pub mod jpegxl;
+    /// `lz4` codec metadata.
+    pub mod lz4;
+    /// `lzma` codec metadata.
+    pub mod lzma;
     /// `pcodec` codec metadata.
     pub mod pcodec;
+    /// `quantize` codec metadata.
+    pub mod quantize;
     /// `sharding` codec metadata.
     pub mod sharding;
     /// `transpose` codec metadata.
     pub mod transpose;
     /// `vlen` codec metadata.
     pub mod vlen;
+    /// `webp` codec metadata.
+    pub mod webp;
     // /// `vlen_v2` codec metadata.
     // pub mod vlen_v2;
     /// `zfp` codec metadata.
@@ -57,10 +77,18 @@ pub mod chunk_grid {
 pub mod chunk_key_encoding {
     /// `default` chunk key encoding metadata.
     pub mod default;
+    /// `zarrs.template` chunk key encoding metadata.
+    pub mod template;
     /// `v2` chunk key encoding metadata.
     pub mod v2;
 }
 
+/// Zarr V3 storage transformer metadata.
+pub mod storage_transformer {
+    /// `zarrs.encryption` storage transformer metadata.
+    pub mod encryption;
+}
+
 pub mod fill_value;
 pub mod nan_representations;
 
@@ -238,4 +266,87 @@ impl ArrayMetadataV3 {
         self.storage_transformers = storage_transformers;
         self
     }
+
+    /// Deserialise Zarr V3 array metadata from JSON, coercing numeric strings to integers.
+    ///
+    /// Some implementations serialise integers as strings (e.g. `"shape": ["1024", "2048"]`).
+    /// Numeric strings are coerced to integers in
+    ///  - the `shape`,
+    ///  - the `chunk_grid` configuration, and
+    ///  - the `fill_value` of integer data types (e.g. `"-1"`, but not `"0x..."`).
+    ///
+    /// Returns the metadata and the name of each field that was coerced.
+    /// Serialising the metadata writes integers as JSON numbers.
+    ///
+    /// # Errors
+    /// Returns a [`serde_json::Error`] if `json` is not valid array metadata after coercion.
+    pub fn from_slice_lenient(json: &[u8]) -> Result<(Self, Vec<String>), serde_json::Error> {
+        let mut value: serde_json::Value = serde_json::from_slice(json)?;
+        let mut coerced = Vec::new();
+        if let serde_json::Value::Object(fields) = &mut value {
+            let is_integer_data_type = fields
+                .get("data_type")
+                .and_then(serde_json::Value::as_str)
+                .is_some_and(|data_type| {
+                    matches!(
+                        data_type,
+                        "int8"
+                            | "int16"
+                            | "int32"
+                            | "int64"
+                            | "uint8"
+                            | "uint16"
+                            | "uint32"
+                            | "uint64"
+                    )
+                });
+            for name in ["shape", "chunk_grid", "fill_value"] {
+                if name == "fill_value" && !is_integer_data_type {
+                    continue;
+                }
+                let field = match (name, fields.get_mut(name)) {
+                    ("chunk_grid", Some(chunk_grid)) => chunk_grid.get_mut("configuration"),
+                    (_, field) => field,
+                };
+                if field.is_some_and(coerce_numeric_strings) {
+                    coerced.push(name.to_string());
+                }
+            }
+        }
+        Ok((serde_json::from_value(value)?, coerced))
+    }
+}
+
+/// Recursively coerce integer strings in `value` to JSON numbers.
+///
+/// Returns true if any string was coerced.
+fn coerce_numeric_strings(value: &mut serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::String(string) => {
+            let number = if let Ok(uint) = string.parse::<u64>() {
+                uint.into()
+            } else if let Ok(int) = string.parse::<i64>() {
+                int.into()
+            } else {
+                return false;
+            };
+            *value = serde_json::Value::Number(number);
+            true
+        }
+        serde_json::Value::Array(values) => {
+            let mut coerced = false;
+            for value in values {
+                coerced |= coerce_numeric_strings(value);
+            }
+            coerced
+        }
+        serde_json::Value::Object(fields) => {
+            let mut coerced = false;
+            for value in fields.values_mut() {
+                coerced |= coerce_numeric_strings(value);
+            }
+            coerced
+        }
+        _ => false,
+    }
 }
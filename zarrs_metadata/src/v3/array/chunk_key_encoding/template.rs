@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use derive_more::Display;
+
+/// The identifier for the `zarrs.template` chunk key encoding.
+pub const IDENTIFIER: &str = "zarrs.template";
+
+/// A `zarrs.template` chunk key encoding configuration.
+///
+/// ### Example: a 2D template chunk key encoding
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "template": "c_{dim0}_{dim1}.bin",
+///     "zero_padding": 3
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::chunk_key_encoding::template::TemplateChunkKeyEncodingConfiguration;
+/// # let configuration: TemplateChunkKeyEncodingConfiguration = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct TemplateChunkKeyEncodingConfiguration {
+    /// The chunk key template, with a `{dimN}` placeholder for the chunk index in each dimension `N`.
+    pub template: String,
+    /// The minimum width of each chunk index, which is padded with leading zeros.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zero_padding: Option<usize>,
+}
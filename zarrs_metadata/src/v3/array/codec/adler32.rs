@@ -0,0 +1,66 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+pub use super::crc32::ChecksumLocation;
+
+/// The identifier for the `adler32` codec.
+pub const IDENTIFIER: &str = "adler32";
+
+/// A wrapper to handle various versions of `adler32` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum Adler32CodecConfiguration {
+    /// Version 1.0 draft.
+    V1(Adler32CodecConfigurationV1),
+}
+
+/// `adler32` (checksum) codec configuration parameters (version 1.0 draft).
+///
+/// This matches the configuration of the `numcodecs` `adler32` codec.
+///
+/// ### Example: store the checksum at the end of the encoded bytes
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "location": "end"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::adler32::Adler32CodecConfigurationV1;
+/// # let configuration: Adler32CodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct Adler32CodecConfigurationV1 {
+    /// The location of the checksum in the encoded bytes.
+    ///
+    /// Defaults to [`ChecksumLocation::Start`] for compatibility with `numcodecs` <0.14.
+    #[serde(default)]
+    pub location: ChecksumLocation,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_adler32_valid() {
+        let json = r#"
+        {
+            "location": "start"
+        }"#;
+        let configuration = serde_json::from_str::<Adler32CodecConfiguration>(json).unwrap();
+        let Adler32CodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.location, ChecksumLocation::Start);
+    }
+
+    #[test]
+    fn codec_adler32_invalid() {
+        let json = r#"
+        {
+            "location": "end",
+            "level": 1
+        }"#;
+        assert!(serde_json::from_str::<Adler32CodecConfiguration>(json).is_err());
+    }
+}
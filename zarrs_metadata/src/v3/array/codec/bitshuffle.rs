@@ -0,0 +1,109 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `bitshuffle` codec.
+pub const IDENTIFIER: &str = "bitshuffle";
+
+/// A wrapper to handle various versions of `bitshuffle` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum BitshuffleCodecConfiguration {
+    /// Version 1.0 draft.
+    V1(BitshuffleCodecConfigurationV1),
+}
+
+/// Configuration parameters for the `bitshuffle` codec (version 1.0 draft).
+///
+/// ### Example: bitshuffle 4 byte elements with the default block size
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "elementsize": 4,
+///     "blocksize": 0,
+///     "cname": "none"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::bitshuffle::BitshuffleCodecConfigurationV1;
+/// # let configuration: BitshuffleCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+///
+/// ### Example: bitshuffle 2 byte elements in blocks of 1024 elements with LZ4 compression
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "elementsize": 2,
+///     "blocksize": 1024,
+///     "cname": "lz4"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::bitshuffle::BitshuffleCodecConfigurationV1;
+/// # let configuration: BitshuffleCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct BitshuffleCodecConfigurationV1 {
+    /// The element size in bytes.
+    pub elementsize: usize,
+    /// The number of elements in each block.
+    ///
+    /// Must be a multiple of 8, or 0 to use the default block size of the reference implementation.
+    #[serde(default)]
+    pub blocksize: usize,
+    /// The compression applied to each bitshuffled block.
+    #[serde(default)]
+    pub cname: BitshuffleCompression,
+}
+
+/// The compression applied to each block by the `bitshuffle` codec.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BitshuffleCompression {
+    /// No compression.
+    #[default]
+    None,
+    /// LZ4 compression of each block, with the framing of the HDF5 bitshuffle filter (`bslz4`).
+    Lz4,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_bitshuffle_valid() {
+        let json = r#"
+        {
+            "elementsize": 2,
+            "blocksize": 1024,
+            "cname": "lz4"
+        }"#;
+        let configuration = serde_json::from_str::<BitshuffleCodecConfiguration>(json).unwrap();
+        let BitshuffleCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.elementsize, 2);
+        assert_eq!(configuration.blocksize, 1024);
+        assert_eq!(configuration.cname, BitshuffleCompression::Lz4);
+    }
+
+    #[test]
+    fn codec_bitshuffle_defaults() {
+        let json = r#"
+        {
+            "elementsize": 4
+        }"#;
+        let configuration = serde_json::from_str::<BitshuffleCodecConfiguration>(json).unwrap();
+        let BitshuffleCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.blocksize, 0);
+        assert_eq!(configuration.cname, BitshuffleCompression::None);
+    }
+
+    #[test]
+    fn codec_bitshuffle_invalid() {
+        let json = r#"
+        {
+            "elementsize": 4,
+            "cname": "zstd"
+        }"#;
+        assert!(serde_json::from_str::<BitshuffleCodecConfiguration>(json).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `crc32` codec.
+pub const IDENTIFIER: &str = "crc32";
+
+/// A wrapper to handle various versions of `crc32` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum Crc32CodecConfiguration {
+    /// Version 1.0 draft.
+    V1(Crc32CodecConfigurationV1),
+}
+
+/// `crc32` (checksum) codec configuration parameters (version 1.0 draft).
+///
+/// This matches the configuration of the `numcodecs` `crc32` codec.
+///
+/// ### Example: store the checksum at the start of the encoded bytes
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "location": "start"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::crc32::Crc32CodecConfigurationV1;
+/// # let configuration: Crc32CodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct Crc32CodecConfigurationV1 {
+    /// The location of the checksum in the encoded bytes.
+    ///
+    /// Defaults to [`ChecksumLocation::Start`] for compatibility with `numcodecs` <0.14.
+    #[serde(default)]
+    pub location: ChecksumLocation,
+}
+
+/// The location of a checksum in the encoded bytes.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug, Display, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ChecksumLocation {
+    /// The checksum is prepended to the encoded bytes.
+    #[default]
+    Start,
+    /// The checksum is appended to the encoded bytes.
+    End,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_crc32_valid() {
+        let json = r#"
+        {
+            "location": "end"
+        }"#;
+        let configuration = serde_json::from_str::<Crc32CodecConfiguration>(json).unwrap();
+        let Crc32CodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.location, ChecksumLocation::End);
+    }
+
+    #[test]
+    fn codec_crc32_default_location() {
+        let configuration = serde_json::from_str::<Crc32CodecConfiguration>("{}").unwrap();
+        let Crc32CodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.location, ChecksumLocation::Start);
+    }
+
+    #[test]
+    fn codec_crc32_invalid() {
+        let json = r#"
+        {
+            "location": "middle"
+        }"#;
+        assert!(serde_json::from_str::<Crc32CodecConfiguration>(json).is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `expand_dims` codec.
+pub const IDENTIFIER: &str = "expand_dims";
+
+/// A wrapper to handle various versions of `expand_dims` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum ExpandDimsCodecConfiguration {
+    /// Version 1.0 draft.
+    V1(ExpandDimsCodecConfigurationV1),
+}
+
+/// `expand_dims` codec configuration parameters (version 1.0 draft).
+///
+/// The `axes` are the positions of the singleton axes inserted in the encoded representation, as in `numpy.expand_dims`.
+/// They must be unique.
+///
+/// ### Example: Encode a 2D chunk as a 3D chunk with a leading singleton axis
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "axes": [0]
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::expand_dims::ExpandDimsCodecConfigurationV1;
+/// # let configuration: ExpandDimsCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct ExpandDimsCodecConfigurationV1 {
+    /// The positions of the inserted singleton axes in the encoded representation.
+    #[serde(deserialize_with = "deserialize_axes")]
+    pub axes: Vec<usize>,
+}
+
+impl ExpandDimsCodecConfigurationV1 {
+    /// Create a new `expand_dims` codec configuration given the `axes` to insert.
+    #[must_use]
+    pub const fn new(axes: Vec<usize>) -> Self {
+        Self { axes }
+    }
+}
+
+fn deserialize_axes<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<usize>, D::Error> {
+    let axes = Vec::<usize>::deserialize(d)?;
+    let mut axes_unique = axes.clone();
+    axes_unique.sort_unstable();
+    axes_unique.dedup();
+    if axes_unique.len() == axes.len() {
+        Ok(axes)
+    } else {
+        Err(serde::de::Error::custom("expand_dims axes must be unique"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v3::MetadataV3;
+
+    use super::*;
+
+    #[test]
+    fn codec_expand_dims_metadata() {
+        serde_json::from_str::<MetadataV3>(
+            r#"{
+            "name": "expand_dims",
+            "configuration": {
+                "axes": [0, 2]
+            }
+        }"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_expand_dims_config() {
+        let configuration = serde_json::from_str::<ExpandDimsCodecConfiguration>(
+            r#"{
+                "axes": [2, 0]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            configuration,
+            ExpandDimsCodecConfiguration::V1(ExpandDimsCodecConfigurationV1::new(vec![2, 0]))
+        );
+    }
+
+    #[test]
+    fn codec_expand_dims_config_invalid() {
+        assert!(serde_json::from_str::<ExpandDimsCodecConfiguration>(
+            r#"{
+                "axes": [1, 1]
+            }"#,
+        )
+        .is_err());
+        assert!(serde_json::from_str::<ExpandDimsCodecConfiguration>(
+            r#"{
+                "axes": [0],
+                "unknown": 1
+            }"#,
+        )
+        .is_err());
+    }
+}
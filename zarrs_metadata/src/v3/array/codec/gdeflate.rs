@@ -24,12 +24,30 @@ pub enum GDeflateCodecConfiguration {
 /// # "#;
 /// # use zarrs_metadata::v3::array::codec::gdeflate::GDeflateCodecConfigurationV1;
 /// # let configuration: GDeflateCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+///
+/// ### Example: encode with a compression level of 5 and a tile size of 16384
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "level": 5,
+///     "tile_size": 16384
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::gdeflate::GDeflateCodecConfigurationV1;
+/// # let configuration: GDeflateCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
 #[serde(deny_unknown_fields)]
 #[display("{}", serde_json::to_string(self).unwrap_or_default())]
 pub struct GDeflateCodecConfigurationV1 {
     /// The compression level.
     pub level: GDeflateCompressionLevel,
+    /// The uncompressed size of each compressed page (tile).
+    ///
+    /// Defaults to [`GDeflateTileSize::MAX`] if omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tile_size: Option<GDeflateTileSize>,
 }
 
 /// An integer from 0 to 12 controlling the compression level
@@ -104,6 +122,53 @@ impl GDeflateCompressionLevel {
     }
 }
 
+/// An integer from 1 to 65536 holding the uncompressed size of each `gdeflate` page (tile) in bytes.
+///
+/// Each tile is compressed independently, so smaller tiles permit finer grained partial decoding at the cost of compression ratio.
+#[derive(Serialize, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct GDeflateTileSize(u32);
+
+/// An invalid gdeflate tile size.
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid gdeflate tile size {0}, must be 1-65536")]
+pub struct GDeflateTileSizeError(u32);
+
+impl<'de> Deserialize<'de> for GDeflateTileSize {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let tile_size = u32::deserialize(d)?;
+        Self::new(tile_size).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Default for GDeflateTileSize {
+    fn default() -> Self {
+        Self(Self::MAX)
+    }
+}
+
+impl GDeflateTileSize {
+    /// The maximum tile size, which is the maximum size of a `gdeflate` page.
+    pub const MAX: u32 = 65536;
+
+    /// Create a new tile size.
+    ///
+    /// # Errors
+    /// Errors if `tile_size` is not between 1-65536.
+    pub fn new(tile_size: u32) -> Result<Self, GDeflateTileSizeError> {
+        if (1..=Self::MAX).contains(&tile_size) {
+            Ok(Self(tile_size))
+        } else {
+            Err(GDeflateTileSizeError(tile_size))
+        }
+    }
+
+    /// The underlying tile size.
+    #[must_use]
+    pub const fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,4 +191,28 @@ mod tests {
         let codec_configuration = serde_json::from_str::<GDeflateCodecConfiguration>(json);
         assert!(codec_configuration.is_err());
     }
+
+    #[test]
+    fn codec_gdeflate_tile_size() {
+        let json = r#"{"level":1,"tile_size":4096}"#;
+        let GDeflateCodecConfiguration::V1(configuration) =
+            serde_json::from_str::<GDeflateCodecConfiguration>(json).unwrap();
+        assert_eq!(
+            configuration.tile_size,
+            Some(GDeflateTileSize::new(4096).unwrap())
+        );
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), json);
+
+        // The tile size is optional and omitted if unset
+        let json = r#"{"level":1}"#;
+        let GDeflateCodecConfiguration::V1(configuration) =
+            serde_json::from_str::<GDeflateCodecConfiguration>(json).unwrap();
+        assert_eq!(configuration.tile_size, None);
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), json);
+
+        for tile_size in ["0", "65537"] {
+            let json = format!(r#"{{"level":1,"tile_size":{tile_size}}}"#);
+            assert!(serde_json::from_str::<GDeflateCodecConfiguration>(&json).is_err());
+        }
+    }
 }
@@ -0,0 +1,77 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `jenkins_lookup3` codec.
+pub const IDENTIFIER: &str = "jenkins_lookup3";
+
+/// A wrapper to handle various versions of `jenkins_lookup3` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum JenkinsLookup3CodecConfiguration {
+    /// Version 1.0 draft.
+    V1(JenkinsLookup3CodecConfigurationV1),
+}
+
+/// `jenkins_lookup3` (checksum) codec configuration parameters (version 1.0 draft).
+///
+/// This matches the configuration of the `numcodecs` `jenkins_lookup3` codec.
+///
+/// ### Example: hash with an initial value of 0 and no prefix
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "initval": 0,
+///     "prefix": null
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::jenkins_lookup3::JenkinsLookup3CodecConfigurationV1;
+/// # let configuration: JenkinsLookup3CodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct JenkinsLookup3CodecConfigurationV1 {
+    /// The initial value of the hash.
+    #[serde(default)]
+    pub initval: u32,
+    /// Bytes prepended to the input when computing the hash.
+    ///
+    /// The prefix is not stored in the encoded bytes.
+    #[serde(default)]
+    pub prefix: Option<Vec<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_jenkins_lookup3_valid() {
+        let json = r#"
+        {
+            "initval": 5,
+            "prefix": [1, 2, 3]
+        }"#;
+        let configuration = serde_json::from_str::<JenkinsLookup3CodecConfiguration>(json).unwrap();
+        let JenkinsLookup3CodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.initval, 5);
+        assert_eq!(configuration.prefix, Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_default() {
+        let configuration = serde_json::from_str::<JenkinsLookup3CodecConfiguration>("{}").unwrap();
+        let JenkinsLookup3CodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.initval, 0);
+        assert_eq!(configuration.prefix, None);
+    }
+
+    #[test]
+    fn codec_jenkins_lookup3_invalid() {
+        let json = r#"
+        {
+            "initval": -1
+        }"#;
+        assert!(serde_json::from_str::<JenkinsLookup3CodecConfiguration>(json).is_err());
+    }
+}
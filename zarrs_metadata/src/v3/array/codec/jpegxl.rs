@@ -0,0 +1,89 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `jpegxl` codec.
+pub const IDENTIFIER: &str = "jpegxl";
+
+/// A wrapper to handle various versions of `jpegxl` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum JpegXlCodecConfiguration {
+    /// Version 1.0 draft.
+    V1(JpegXlCodecConfigurationV1),
+}
+
+/// Configuration parameters for the `jpegxl` codec (version 1.0 draft).
+///
+/// ### Example: encode losslessly
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "lossless": true,
+///     "distance": 0.0,
+///     "effort": 7
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::jpegxl::JpegXlCodecConfigurationV1;
+/// # let configuration: JpegXlCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+///
+/// ### Example: encode lossily with a visually lossless distance
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "lossless": false,
+///     "distance": 1.0,
+///     "effort": 5
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::jpegxl::JpegXlCodecConfigurationV1;
+/// # let configuration: JpegXlCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct JpegXlCodecConfigurationV1 {
+    /// Encode losslessly.
+    ///
+    /// If true, `distance` is ignored.
+    pub lossless: bool,
+    /// The Butteraugli distance from 0.0 to 25.0 for lossy encoding.
+    ///
+    /// 0.0 is mathematically lossless, 1.0 is visually lossless, and higher values are lower quality.
+    pub distance: f32,
+    /// The encoder effort from 1 to 10.
+    ///
+    /// Higher efforts produce smaller output but are slower.
+    pub effort: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn codec_jpegxl_valid() {
+        let json = r#"
+        {
+            "lossless": false,
+            "distance": 1.5,
+            "effort": 3
+        }"#;
+        let configuration = serde_json::from_str::<JpegXlCodecConfiguration>(json).unwrap();
+        let JpegXlCodecConfiguration::V1(configuration) = configuration;
+        assert!(!configuration.lossless);
+        assert_eq!(configuration.distance, 1.5);
+        assert_eq!(configuration.effort, 3);
+    }
+
+    #[test]
+    fn codec_jpegxl_missing_field() {
+        let json = r#"
+        {
+            "lossless": true,
+            "distance": 0.0
+        }"#;
+        assert!(serde_json::from_str::<JpegXlCodecConfiguration>(json).is_err());
+    }
+}
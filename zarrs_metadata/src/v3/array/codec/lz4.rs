@@ -0,0 +1,64 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `lz4` codec.
+pub const IDENTIFIER: &str = "lz4";
+
+/// A wrapper to handle various versions of `lz4` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum Lz4CodecConfiguration {
+    /// Version 1.0 draft.
+    V1(Lz4CodecConfigurationV1),
+}
+
+/// Configuration parameters for the `lz4` codec (version 1.0 draft).
+///
+/// This matches the configuration of the `numcodecs` `lz4` codec.
+///
+/// ### Example: encode with an acceleration of 1
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "acceleration": 1
+/// }
+/// # "#;
+/// # use zarrs_metadata::v2::array::codec::lz4::Lz4CodecConfigurationV1;
+/// # let configuration: Lz4CodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct Lz4CodecConfigurationV1 {
+    /// The acceleration factor.
+    ///
+    /// Higher values are faster but produce less compression.
+    /// Values less than or equal to 0 are replaced by the default of 1.
+    pub acceleration: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_lz4_valid() {
+        let json = r#"
+        {
+            "acceleration": 5
+        }"#;
+        let configuration = serde_json::from_str::<Lz4CodecConfiguration>(json).unwrap();
+        let Lz4CodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.acceleration, 5);
+    }
+
+    #[test]
+    fn codec_lz4_invalid() {
+        let json = r#"
+        {
+            "acceleration": 1,
+            "level": 1
+        }"#;
+        assert!(serde_json::from_str::<Lz4CodecConfiguration>(json).is_err());
+    }
+}
@@ -0,0 +1,282 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// The identifier for the `lzma` codec.
+pub const IDENTIFIER: &str = "lzma";
+
+/// A wrapper to handle various versions of `lzma` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum LzmaCodecConfiguration {
+    /// Version 1.0 draft.
+    V1(LzmaCodecConfigurationV1),
+}
+
+/// Configuration parameters for the `lzma` codec (version 1.0 draft).
+///
+/// This matches the configuration of the `numcodecs` `lzma` codec, which mirrors the Python [`lzma`](https://docs.python.org/3/library/lzma.html) module.
+///
+/// ### Example: encode in the `xz` format with the default preset
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "format": 1,
+///     "check": -1,
+///     "preset": null,
+///     "filters": null
+/// }
+/// # "#;
+/// # use zarrs_metadata::v2::array::codec::lzma::LzmaCodecConfigurationV1;
+/// # let configuration: LzmaCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+///
+/// ### Example: encode in the raw format with a delta and LZMA2 filter chain
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "format": 3,
+///     "check": 0,
+///     "preset": null,
+///     "filters": [
+///         {"id": 3, "dist": 4},
+///         {"id": 33, "preset": 1}
+///     ]
+/// }
+/// # "#;
+/// # use zarrs_metadata::v2::array::codec::lzma::LzmaCodecConfigurationV1;
+/// # let configuration: LzmaCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct LzmaCodecConfigurationV1 {
+    /// The container format.
+    #[serde(default)]
+    pub format: LzmaFormat,
+    /// The integrity check.
+    #[serde(default)]
+    pub check: LzmaCheck,
+    /// The compression preset from 0 to 9, optionally or'd with [`LZMA_PRESET_EXTREME`].
+    ///
+    /// Defaults to 6 if both `preset` and `filters` are unset.
+    #[serde(default)]
+    pub preset: Option<u32>,
+    /// A custom filter chain.
+    #[serde(default)]
+    pub filters: Option<Vec<LzmaFilter>>,
+}
+
+/// A flag that can be or'd with an `lzma` preset to select a slower variant of the preset.
+pub const LZMA_PRESET_EXTREME: u32 = 0x8000_0000;
+
+/// The `lzma` container format.
+#[derive(Serialize_repr, Deserialize_repr, Copy, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[repr(u8)]
+pub enum LzmaFormat {
+    /// Detect the `xz` or `alone` format when decoding.
+    ///
+    /// This is not supported for encoding.
+    Auto = 0,
+    /// The `xz` container format.
+    #[default]
+    Xz = 1,
+    /// The legacy `lzma` (`alone`) container format.
+    Alone = 2,
+    /// A raw stream without a container, which requires a custom filter chain.
+    Raw = 3,
+}
+
+/// The `lzma` integrity check.
+#[derive(Serialize_repr, Deserialize_repr, Copy, Clone, Eq, PartialEq, Debug, Display, Default)]
+#[repr(i8)]
+pub enum LzmaCheck {
+    /// The default check of the format (`crc64` for `xz`, `none` otherwise).
+    #[default]
+    Default = -1,
+    /// No check.
+    None = 0,
+    /// A 32-bit cyclic redundancy check.
+    Crc32 = 1,
+    /// A 64-bit cyclic redundancy check.
+    Crc64 = 4,
+    /// A SHA-256 hash.
+    Sha256 = 10,
+}
+
+/// An `lzma` filter in a custom filter chain.
+///
+/// Options that are not applicable to the filter `id` must be unset.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LzmaFilter {
+    /// The filter identifier.
+    pub id: LzmaFilterId,
+    /// The LZMA compression preset the other LZMA options are applied to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<u32>,
+    /// The LZMA dictionary size in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dict_size: Option<u32>,
+    /// The LZMA number of literal context bits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lc: Option<u32>,
+    /// The LZMA number of literal position bits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lp: Option<u32>,
+    /// The LZMA number of position bits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pb: Option<u32>,
+    /// The LZMA compression mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<LzmaMode>,
+    /// The LZMA match length considered "nice".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice_len: Option<u32>,
+    /// The LZMA match finder.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mf: Option<LzmaMatchFinder>,
+    /// The LZMA maximum match finder search depth (0 is automatic).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depth: Option<u32>,
+    /// The delta filter distance in bytes from 1 to 256.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dist: Option<u32>,
+    /// The branch/call/jump filter start offset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_offset: Option<u32>,
+}
+
+impl LzmaFilter {
+    /// Create a new `lzma` filter with no options set.
+    #[must_use]
+    pub const fn new(id: LzmaFilterId) -> Self {
+        Self {
+            id,
+            preset: None,
+            dict_size: None,
+            lc: None,
+            lp: None,
+            pb: None,
+            mode: None,
+            nice_len: None,
+            mf: None,
+            depth: None,
+            dist: None,
+            start_offset: None,
+        }
+    }
+}
+
+/// An `lzma` filter identifier.
+#[derive(Serialize_repr, Deserialize_repr, Copy, Clone, Eq, PartialEq, Debug, Display)]
+#[repr(u64)]
+pub enum LzmaFilterId {
+    /// The LZMA1 filter, which is only supported by the `alone` and `raw` formats.
+    Lzma1 = 0x4000_0000_0000_0001,
+    /// The LZMA2 filter.
+    Lzma2 = 0x21,
+    /// The delta filter.
+    Delta = 0x03,
+    /// The x86 branch/call/jump filter.
+    X86 = 0x04,
+    /// The PowerPC branch/call/jump filter.
+    PowerPc = 0x05,
+    /// The IA-64 branch/call/jump filter.
+    Ia64 = 0x06,
+    /// The ARM branch/call/jump filter.
+    Arm = 0x07,
+    /// The ARM-Thumb branch/call/jump filter.
+    ArmThumb = 0x08,
+    /// The SPARC branch/call/jump filter.
+    Sparc = 0x09,
+}
+
+/// The LZMA compression mode.
+#[derive(Serialize_repr, Deserialize_repr, Copy, Clone, Eq, PartialEq, Debug, Display)]
+#[repr(u8)]
+pub enum LzmaMode {
+    /// Fast compression.
+    Fast = 1,
+    /// Normal compression.
+    Normal = 2,
+}
+
+/// The LZMA match finder.
+#[derive(Serialize_repr, Deserialize_repr, Copy, Clone, Eq, PartialEq, Debug, Display)]
+#[repr(u8)]
+pub enum LzmaMatchFinder {
+    /// Hash chain with 2 and 3 byte hashing.
+    HashChain3 = 0x03,
+    /// Hash chain with 2, 3, and 4 byte hashing.
+    HashChain4 = 0x04,
+    /// Binary tree with 2 byte hashing.
+    BinaryTree2 = 0x12,
+    /// Binary tree with 2 and 3 byte hashing.
+    BinaryTree3 = 0x13,
+    /// Binary tree with 2, 3, and 4 byte hashing.
+    BinaryTree4 = 0x14,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_lzma_valid_numcodecs_default() {
+        let json = r#"
+        {
+            "format": 1,
+            "check": -1,
+            "preset": null,
+            "filters": null
+        }"#;
+        let configuration = serde_json::from_str::<LzmaCodecConfiguration>(json).unwrap();
+        let LzmaCodecConfiguration::V1(configuration) = &configuration;
+        assert_eq!(configuration.format, LzmaFormat::Xz);
+        assert_eq!(configuration.check, LzmaCheck::Default);
+        assert_eq!(configuration.preset, None);
+        assert_eq!(configuration.filters, None);
+        assert_eq!(
+            serde_json::to_value(configuration).unwrap(),
+            serde_json::from_str::<serde_json::Value>(json).unwrap()
+        );
+    }
+
+    #[test]
+    fn codec_lzma_valid_filters() {
+        let json = r#"
+        {
+            "format": 3,
+            "check": 0,
+            "preset": null,
+            "filters": [
+                {"id": 3, "dist": 4},
+                {"id": 4611686018427387905, "preset": 2147483657, "mf": 20}
+            ]
+        }"#;
+        let configuration = serde_json::from_str::<LzmaCodecConfiguration>(json).unwrap();
+        let LzmaCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.format, LzmaFormat::Raw);
+        let filters = configuration.filters.unwrap();
+        assert_eq!(filters[0].id, LzmaFilterId::Delta);
+        assert_eq!(filters[0].dist, Some(4));
+        assert_eq!(filters[1].id, LzmaFilterId::Lzma1);
+        assert_eq!(filters[1].preset, Some(9 | LZMA_PRESET_EXTREME));
+        assert_eq!(filters[1].mf, Some(LzmaMatchFinder::BinaryTree4));
+    }
+
+    #[test]
+    fn codec_lzma_invalid() {
+        let json = r#"
+        {
+            "format": 4
+        }"#;
+        assert!(serde_json::from_str::<LzmaCodecConfiguration>(json).is_err());
+        let json = r#"
+        {
+            "filters": [{"id": 3, "distance": 4}]
+        }"#;
+        assert!(serde_json::from_str::<LzmaCodecConfiguration>(json).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `quantize` codec.
+pub const IDENTIFIER: &str = "quantize";
+
+/// A wrapper to handle various versions of `quantize` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum QuantizeCodecConfiguration {
+    /// Version 1.0 draft.
+    V1(QuantizeCodecConfigurationV1),
+}
+
+/// Configuration parameters for the `quantize` codec (version 1.0 draft).
+///
+/// This matches the configuration of the `numcodecs` `quantize` codec.
+/// The `dtype` and `astype` are `numpy` data type strings, such as `<f4` or `<f8`.
+///
+/// ### Example: keep 2 decimal digits of `float64` data
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "digits": 2,
+///     "dtype": "<f8",
+///     "astype": "<f8"
+/// }
+/// # "#;
+/// # use zarrs_metadata::v2::array::codec::quantize::QuantizeCodecConfigurationV1;
+/// # let configuration: QuantizeCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct QuantizeCodecConfigurationV1 {
+    /// The number of decimal digits to keep.
+    pub digits: i32,
+    /// The data type of the decoded data.
+    pub dtype: String,
+    /// The data type of the encoded data. Defaults to `dtype`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub astype: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v3::MetadataV3;
+
+    use super::*;
+
+    #[test]
+    fn codec_quantize_metadata() {
+        serde_json::from_str::<MetadataV3>(
+            r#"{
+            "name": "quantize",
+            "configuration": {
+                "digits": 3,
+                "dtype": "<f4"
+            }
+        }"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn codec_quantize_config_numcodecs() {
+        let configuration = serde_json::from_str::<QuantizeCodecConfiguration>(
+            r#"{
+                "digits": 2,
+                "dtype": "<f8",
+                "astype": "<f8"
+            }"#,
+        )
+        .unwrap();
+        let QuantizeCodecConfiguration::V1(configuration) = configuration;
+        assert_eq!(configuration.digits, 2);
+        assert_eq!(configuration.dtype, "<f8");
+        assert_eq!(configuration.astype.as_deref(), Some("<f8"));
+    }
+
+    #[test]
+    fn codec_quantize_config_invalid() {
+        assert!(serde_json::from_str::<QuantizeCodecConfiguration>(r#"{"digits": 2}"#).is_err());
+        assert!(serde_json::from_str::<QuantizeCodecConfiguration>(
+            r#"{"digits": 2, "dtype": "<f8", "keepbits": 3}"#
+        )
+        .is_err());
+    }
+}
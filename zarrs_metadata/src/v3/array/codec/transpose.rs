@@ -69,10 +69,13 @@ impl<'de> serde::Deserialize<'de> for TransposeOrder {
 }
 
 fn validate_permutation(permutation: &[usize]) -> bool {
+    // The empty permutation is valid for zero-dimensional arrays
     let permutation_unique = to_vec_unique(permutation);
-    !permutation.is_empty()
-        && permutation_unique.len() == permutation.len()
-        && *permutation_unique.iter().max().unwrap() == permutation.len() - 1
+    permutation_unique.len() == permutation.len()
+        && permutation_unique
+            .iter()
+            .max()
+            .map_or(true, |max| *max == permutation.len() - 1)
 }
 
 fn to_vec_unique(v: &[usize]) -> Vec<usize> {
@@ -102,6 +105,14 @@ mod tests {
         assert!(serde_json::from_str::<TransposeCodecConfiguration>(json).is_err());
     }
 
+    #[test]
+    fn codec_transpose_scalar() {
+        let json = r#"{
+            "order": []
+        }"#;
+        serde_json::from_str::<TransposeCodecConfiguration>(json).unwrap();
+    }
+
     #[test]
     fn codec_transpose_invalid2() {
         let json = r#"{
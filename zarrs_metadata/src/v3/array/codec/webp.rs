@@ -0,0 +1,81 @@
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+/// The identifier for the `webp` codec.
+pub const IDENTIFIER: &str = "webp";
+
+/// A wrapper to handle various versions of `webp` codec configuration parameters.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum WebpCodecConfiguration {
+    /// Version 1.0 draft.
+    V1(WebpCodecConfigurationV1),
+}
+
+/// Configuration parameters for the `webp` codec (version 1.0 draft).
+///
+/// ### Example: encode losslessly
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "lossless": true,
+///     "quality": 75.0
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::webp::WebpCodecConfigurationV1;
+/// # let configuration: WebpCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+///
+/// ### Example: encode lossily with a quality of 90
+/// ```rust
+/// # let JSON = r#"
+/// {
+///     "lossless": false,
+///     "quality": 90.0
+/// }
+/// # "#;
+/// # use zarrs_metadata::v3::array::codec::webp::WebpCodecConfigurationV1;
+/// # let configuration: WebpCodecConfigurationV1 = serde_json::from_str(JSON).unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct WebpCodecConfigurationV1 {
+    /// Encode losslessly.
+    pub lossless: bool,
+    /// A quality from 0.0 to 100.0.
+    ///
+    /// In lossy mode, this controls the trade-off between size and quality.
+    /// In lossless mode, this controls the compression effort (higher is smaller but slower).
+    pub quality: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn codec_webp_valid() {
+        let json = r#"
+        {
+            "lossless": false,
+            "quality": 50.0
+        }"#;
+        let configuration = serde_json::from_str::<WebpCodecConfiguration>(json).unwrap();
+        let WebpCodecConfiguration::V1(configuration) = configuration;
+        assert!(!configuration.lossless);
+        assert_eq!(configuration.quality, 50.0);
+    }
+
+    #[test]
+    fn codec_webp_invalid() {
+        let json = r#"
+        {
+            "lossless": false,
+            "quality": 50.0,
+            "method": 4
+        }"#;
+        assert!(serde_json::from_str::<WebpCodecConfiguration>(json).is_err());
+    }
+}
@@ -1,5 +1,6 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
 use derive_more::{Display, From};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// The identifier for the `zstd` codec.
 pub const IDENTIFIER: &str = "zstd";
@@ -13,6 +14,19 @@ pub enum ZstdCodecConfiguration {
 }
 
 /// Configuration parameters for the `zstd` codec (version 1.0).
+///
+/// The `dict_id` and `dictionary` members are experimental extensions of `zarrs` for compression with a dictionary.
+/// They are omitted if no dictionary is used, and other Zarr V3 implementations are unlikely to support them.
+///
+/// ### Example (Zarr V3) with a dictionary
+/// ```json
+/// {
+///     "level": 3,
+///     "checksum": false,
+///     "dict_id": 1234,
+///     "dictionary": "N6Qw7NIEAAA..."
+/// }
+/// ```
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
 #[serde(deny_unknown_fields)]
 #[display("{}", serde_json::to_string(self).unwrap_or_default())]
@@ -21,16 +35,66 @@ pub struct ZstdCodecConfigurationV1 {
     pub level: ZstdCompressionLevel,
     /// A boolean that indicates whether to store a checksum when writing that will be verified when reading.
     pub checksum: bool,
+    /// The ID of the dictionary (experimental).
+    ///
+    /// This is the ID in the header of a dictionary trained by `zstd`, and is absent for a raw content dictionary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dict_id: Option<u32>,
+    /// The dictionary (experimental), encoded as base64 in metadata.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub dictionary: Option<Vec<u8>>,
 }
 
 impl ZstdCodecConfigurationV1 {
     /// Create a new `zstd` codec configuration given a [`ZstdCompressionLevel`].
     #[must_use]
     pub const fn new(level: ZstdCompressionLevel, checksum: bool) -> Self {
-        Self { level, checksum }
+        Self {
+            level,
+            checksum,
+            dict_id: None,
+            dictionary: None,
+        }
+    }
+
+    /// Create a new `zstd` codec configuration given a [`ZstdCompressionLevel`] and a `dictionary` with ID `dict_id`.
+    #[must_use]
+    pub const fn new_with_dictionary(
+        level: ZstdCompressionLevel,
+        checksum: bool,
+        dict_id: Option<u32>,
+        dictionary: Vec<u8>,
+    ) -> Self {
+        Self {
+            level,
+            checksum,
+            dict_id,
+            dictionary: Some(dictionary),
+        }
+    }
+}
+
+#[allow(clippy::ref_option)]
+fn serialize_base64<S: Serializer>(bytes: &Option<Vec<u8>>, s: S) -> Result<S::Ok, S::Error> {
+    match bytes {
+        Some(bytes) => s.serialize_str(&BASE64_STANDARD.encode(bytes)),
+        None => s.serialize_none(),
     }
 }
 
+fn deserialize_base64<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Vec<u8>>, D::Error> {
+    let encoded = String::deserialize(d)?;
+    BASE64_STANDARD
+        .decode(encoded)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
 /// A `Zstd` compression level. An integer from -131072 to 22 which controls the speed and level of compression (has no impact on decoding).
 ///
 /// A value of 0 indicates to use the default compression level.
@@ -86,6 +150,24 @@ mod tests {
         serde_json::from_str::<ZstdCodecConfiguration>(JSON_VALID).unwrap();
     }
 
+    #[test]
+    fn codec_zstd_configuration_dictionary() {
+        const JSON_VALID: &str =
+            r#"{"level":3,"checksum":false,"dict_id":1234,"dictionary":"AAECAw=="}"#;
+        let configuration = serde_json::from_str::<ZstdCodecConfiguration>(JSON_VALID).unwrap();
+        let ZstdCodecConfiguration::V1(configuration_v1) = &configuration;
+        assert_eq!(configuration_v1.dict_id, Some(1234));
+        assert_eq!(
+            configuration_v1.dictionary.as_deref(),
+            Some([0, 1, 2, 3].as_slice())
+        );
+        assert_eq!(configuration.to_string(), JSON_VALID);
+
+        // The dictionary members are omitted without a dictionary
+        let configuration = ZstdCodecConfigurationV1::new(ZstdCompressionLevel::new(3), false);
+        assert_eq!(configuration.to_string(), r#"{"level":3,"checksum":false}"#);
+    }
+
     #[test]
     fn codec_zstd_configuration_invalid1() {
         const JSON_INVALID1: &str = r#"{
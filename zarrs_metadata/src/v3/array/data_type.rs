@@ -2,9 +2,17 @@
 //!
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#data-types>.
 
-use derive_more::From;
+use std::num::NonZeroU32;
 
-use crate::v3::MetadataV3;
+use derive_more::{Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::v3::{MetadataConfiguration, MetadataV3};
+
+mod structured;
+pub use structured::{
+    InvalidStructuredDataTypeError, StructuredDataTypeConfiguration, StructuredDataTypeField,
+};
 
 /// A data type.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -37,12 +45,62 @@ pub enum DataTypeMetadataV3 {
     Float64,
     /// `bfloat16` brain floating point data type: sign bit, 5 bits exponent, 10 bits mantissa.
     BFloat16,
+    /// `float4_e2m1fn` 4-bit floating point: sign bit, 2 bits exponent, 1 bit mantissa, no infinity or NaN.
+    Float4E2M1FN,
+    /// `float6_e2m3fn` 6-bit floating point: sign bit, 2 bits exponent, 3 bits mantissa, no infinity or NaN.
+    Float6E2M3FN,
+    /// `float6_e3m2fn` 6-bit floating point: sign bit, 3 bits exponent, 2 bits mantissa, no infinity or NaN.
+    Float6E3M2FN,
+    /// `float8_e3m4` 8-bit floating point: sign bit, 3 bits exponent, 4 bits mantissa, IEEE 754 special values.
+    Float8E3M4,
+    /// `float8_e4m3` 8-bit floating point: sign bit, 4 bits exponent, 3 bits mantissa, IEEE 754 special values.
+    Float8E4M3,
+    /// `float8_e4m3b11fnuz` 8-bit floating point: sign bit, 4 bits exponent (bias 11), 3 bits mantissa, no infinity or negative zero.
+    Float8E4M3B11FNUZ,
+    /// `float8_e4m3fnuz` 8-bit floating point: sign bit, 4 bits exponent, 3 bits mantissa, no infinity or negative zero.
+    Float8E4M3FNUZ,
+    /// `float8_e5m2` 8-bit floating point: sign bit, 5 bits exponent, 2 bits mantissa, IEEE 754 special values.
+    Float8E5M2,
+    /// `float8_e5m2fnuz` 8-bit floating point: sign bit, 5 bits exponent, 2 bits mantissa, no infinity or negative zero.
+    Float8E5M2FNUZ,
+    /// `float8_e8m0fnu` 8-bit unsigned power of two: 8 bits exponent, no zero or infinity.
+    Float8E8M0FNU,
+    /// `complex_bfloat16` real and complex components are each bfloat16 brain floating point.
+    ComplexBFloat16,
+    /// `complex_float16` real and complex components are each IEEE 754 half-precision floating point.
+    ComplexFloat16,
+    /// `complex_float32` real and complex components are each IEEE 754 single-precision floating point.
+    ComplexFloat32,
     /// `complex64` real and complex components are each IEEE 754 single-precision floating point.
     Complex64,
     /// `complex128` real and complex components are each IEEE 754 double-precision floating point.
     Complex128,
     /// `r*` raw bits, variable size given by *, limited to be a multiple of 8.
     RawBits(usize), // the stored usize is the size in bytes
+    /// `numpy.datetime64` a 64-bit signed integer count of `scale_factor` `unit`s since the Unix epoch.
+    ///
+    /// The minimum value ([`i64::MIN`]) is the `NaT` (not a time) sentinel.
+    NumpyDateTime64 {
+        /// The time unit.
+        unit: NumpyTimeUnit,
+        /// The number of `unit`s per increment.
+        scale_factor: NonZeroU32,
+    },
+    /// `numpy.timedelta64` a 64-bit signed integer count of `scale_factor` `unit`s.
+    ///
+    /// The minimum value ([`i64::MIN`]) is the `NaT` (not a time) sentinel.
+    NumpyTimeDelta64 {
+        /// The time unit.
+        unit: NumpyTimeUnit,
+        /// The number of `unit`s per increment.
+        scale_factor: NonZeroU32,
+    },
+    /// `structured` a fixed-size record of named fields.
+    Structured(StructuredDataTypeConfiguration),
+    /// `fixed_length_utf8` a UTF-8 encoded string padded with trailing null bytes to `length_bytes` bytes.
+    FixedLengthUtf8(usize), // the stored usize is the length in bytes
+    /// `fixed_length_bytes` binary data padded with trailing null bytes to `length_bytes` bytes.
+    FixedLengthBytes(usize), // the stored usize is the length in bytes
     /// A UTF-8 encoded string.
     String,
     /// Variable-sized binary data.
@@ -67,6 +125,102 @@ impl<'de> serde::Deserialize<'de> for DataTypeMetadataV3 {
     }
 }
 
+/// The time unit of the `numpy.datetime64` and `numpy.timedelta64` data types.
+///
+/// See <https://numpy.org/doc/stable/reference/arrays.datetime.html#datetime-units>.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+pub enum NumpyTimeUnit {
+    /// Years (`Y`).
+    #[serde(rename = "Y")]
+    #[display("Y")]
+    Year,
+    /// Months (`M`).
+    #[serde(rename = "M")]
+    #[display("M")]
+    Month,
+    /// Weeks (`W`).
+    #[serde(rename = "W")]
+    #[display("W")]
+    Week,
+    /// Days (`D`).
+    #[serde(rename = "D")]
+    #[display("D")]
+    Day,
+    /// Hours (`h`).
+    #[serde(rename = "h")]
+    #[display("h")]
+    Hour,
+    /// Minutes (`m`).
+    #[serde(rename = "m")]
+    #[display("m")]
+    Minute,
+    /// Seconds (`s`).
+    #[serde(rename = "s")]
+    #[display("s")]
+    Second,
+    /// Milliseconds (`ms`).
+    #[serde(rename = "ms")]
+    #[display("ms")]
+    Millisecond,
+    /// Microseconds (`us`).
+    #[serde(rename = "us")]
+    #[display("us")]
+    Microsecond,
+    /// Nanoseconds (`ns`).
+    #[serde(rename = "ns")]
+    #[display("ns")]
+    Nanosecond,
+    /// Picoseconds (`ps`).
+    #[serde(rename = "ps")]
+    #[display("ps")]
+    Picosecond,
+    /// Femtoseconds (`fs`).
+    #[serde(rename = "fs")]
+    #[display("fs")]
+    Femtosecond,
+    /// Attoseconds (`as`).
+    #[serde(rename = "as")]
+    #[display("as")]
+    Attosecond,
+    /// A generic unit, which is resolved by `NumPy` when combined with a value with a specific unit.
+    #[serde(rename = "generic")]
+    #[display("generic")]
+    Generic,
+}
+
+/// The configuration of the `numpy.datetime64` and `numpy.timedelta64` data types.
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "unit": "ns",
+///     "scale_factor": 1
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct NumpyTimeConfiguration {
+    /// The time unit.
+    pub unit: NumpyTimeUnit,
+    /// The number of `unit`s per increment.
+    pub scale_factor: NonZeroU32,
+}
+
+/// The configuration of the `fixed_length_utf8` and `fixed_length_bytes` data types.
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "length_bytes": 16
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FixedLengthDataTypeConfiguration {
+    /// The length of an element in bytes.
+    pub length_bytes: usize,
+}
+
 // /// A data type plugin.
 // pub type DataTypePlugin = Plugin<Box<dyn DataTypeExtension>>;
 // inventory::collect!(DataTypePlugin);
@@ -102,11 +256,29 @@ impl DataTypeMetadataV3 {
             Self::Float32 => "float32".to_string(),
             Self::Float64 => "float64".to_string(),
             Self::BFloat16 => "bfloat16".to_string(),
+            Self::Float4E2M1FN => "float4_e2m1fn".to_string(),
+            Self::Float6E2M3FN => "float6_e2m3fn".to_string(),
+            Self::Float6E3M2FN => "float6_e3m2fn".to_string(),
+            Self::Float8E3M4 => "float8_e3m4".to_string(),
+            Self::Float8E4M3 => "float8_e4m3".to_string(),
+            Self::Float8E4M3B11FNUZ => "float8_e4m3b11fnuz".to_string(),
+            Self::Float8E4M3FNUZ => "float8_e4m3fnuz".to_string(),
+            Self::Float8E5M2 => "float8_e5m2".to_string(),
+            Self::Float8E5M2FNUZ => "float8_e5m2fnuz".to_string(),
+            Self::Float8E8M0FNU => "float8_e8m0fnu".to_string(),
+            Self::ComplexBFloat16 => "complex_bfloat16".to_string(),
+            Self::ComplexFloat16 => "complex_float16".to_string(),
+            Self::ComplexFloat32 => "complex_float32".to_string(),
             Self::Complex64 => "complex64".to_string(),
             Self::Complex128 => "complex128".to_string(),
             Self::String => "string".to_string(),
             Self::Bytes => "bytes".to_string(),
             Self::RawBits(size) => format!("r{}", size * 8),
+            Self::NumpyDateTime64 { .. } => "numpy.datetime64".to_string(),
+            Self::NumpyTimeDelta64 { .. } => "numpy.timedelta64".to_string(),
+            Self::Structured(_) => "structured".to_string(),
+            Self::FixedLengthUtf8(_) => "fixed_length_utf8".to_string(),
+            Self::FixedLengthBytes(_) => "fixed_length_bytes".to_string(),
             Self::Unknown(metadata) => metadata.name().to_string(),
         }
     }
@@ -116,24 +288,72 @@ impl DataTypeMetadataV3 {
     pub fn metadata(&self) -> MetadataV3 {
         match self {
             Self::Unknown(metadata) => metadata.clone(),
+            Self::NumpyDateTime64 { unit, scale_factor }
+            | Self::NumpyTimeDelta64 { unit, scale_factor } => {
+                let configuration = MetadataConfiguration::from_iter([
+                    ("unit".to_string(), unit.to_string().into()),
+                    ("scale_factor".to_string(), scale_factor.get().into()),
+                ]);
+                MetadataV3::new_with_configuration(&self.name(), configuration)
+            }
+            Self::Structured(configuration) => {
+                let configuration = MetadataConfiguration::from_iter([(
+                    "fields".to_string(),
+                    serde_json::to_value(configuration.fields()).unwrap_or_default(),
+                )]);
+                MetadataV3::new_with_configuration(&self.name(), configuration)
+            }
+            Self::FixedLengthUtf8(length_bytes) | Self::FixedLengthBytes(length_bytes) => {
+                let configuration = MetadataConfiguration::from_iter([(
+                    "length_bytes".to_string(),
+                    (*length_bytes).into(),
+                )]);
+                MetadataV3::new_with_configuration(&self.name(), configuration)
+            }
             _ => MetadataV3::new(&self.name()),
         }
     }
 
     /// Returns the [`DataTypeSize`]. Returns [`None`] for an unknown data type.
+    ///
+    /// Sub-byte data types (e.g. `float4_e2m1fn`) have a size of one byte.
     #[must_use]
     pub const fn size(&self) -> Option<DataTypeSize> {
         match self {
-            Self::Bool | Self::Int8 | Self::UInt8 => Some(DataTypeSize::Fixed(1)),
+            Self::Bool
+            | Self::Int8
+            | Self::UInt8
+            | Self::Float4E2M1FN
+            | Self::Float6E2M3FN
+            | Self::Float6E3M2FN
+            | Self::Float8E3M4
+            | Self::Float8E4M3
+            | Self::Float8E4M3B11FNUZ
+            | Self::Float8E4M3FNUZ
+            | Self::Float8E5M2
+            | Self::Float8E5M2FNUZ
+            | Self::Float8E8M0FNU => Some(DataTypeSize::Fixed(1)),
             Self::Int16 | Self::UInt16 | Self::Float16 | Self::BFloat16 => {
                 Some(DataTypeSize::Fixed(2))
             }
-            Self::Int32 | Self::UInt32 | Self::Float32 => Some(DataTypeSize::Fixed(4)),
-            Self::Int64 | Self::UInt64 | Self::Float64 | Self::Complex64 => {
-                Some(DataTypeSize::Fixed(8))
-            }
+            Self::Int32
+            | Self::UInt32
+            | Self::Float32
+            | Self::ComplexFloat16
+            | Self::ComplexBFloat16 => Some(DataTypeSize::Fixed(4)),
+            Self::Int64
+            | Self::UInt64
+            | Self::Float64
+            | Self::ComplexFloat32
+            | Self::Complex64
+            | Self::NumpyDateTime64 { .. }
+            | Self::NumpyTimeDelta64 { .. } => Some(DataTypeSize::Fixed(8)),
             Self::Complex128 => Some(DataTypeSize::Fixed(16)),
             Self::RawBits(size) => Some(DataTypeSize::Fixed(*size)),
+            Self::Structured(configuration) => Some(DataTypeSize::Fixed(configuration.size())),
+            Self::FixedLengthUtf8(length_bytes) | Self::FixedLengthBytes(length_bytes) => {
+                Some(DataTypeSize::Fixed(*length_bytes))
+            }
             Self::String | Self::Bytes => Some(DataTypeSize::Variable),
             Self::Unknown(_) => None,
         }
@@ -167,10 +387,52 @@ impl DataTypeMetadataV3 {
             "float32" => return Self::Float32,
             "float64" => return Self::Float64,
             "bfloat16" => return Self::BFloat16,
+            "float4_e2m1fn" => return Self::Float4E2M1FN,
+            "float6_e2m3fn" => return Self::Float6E2M3FN,
+            "float6_e3m2fn" => return Self::Float6E3M2FN,
+            "float8_e3m4" => return Self::Float8E3M4,
+            "float8_e4m3" => return Self::Float8E4M3,
+            "float8_e4m3b11fnuz" => return Self::Float8E4M3B11FNUZ,
+            "float8_e4m3fnuz" => return Self::Float8E4M3FNUZ,
+            "float8_e5m2" => return Self::Float8E5M2,
+            "float8_e5m2fnuz" => return Self::Float8E5M2FNUZ,
+            "float8_e8m0fnu" => return Self::Float8E8M0FNU,
+            "complex_bfloat16" => return Self::ComplexBFloat16,
+            "complex_float16" => return Self::ComplexFloat16,
+            "complex_float32" => return Self::ComplexFloat32,
             "complex64" => return Self::Complex64,
             "complex128" => return Self::Complex128,
             "string" => return Self::String,
             "bytes" => return Self::Bytes,
+            "numpy.datetime64" | "numpy.timedelta64" => {
+                if let Ok(NumpyTimeConfiguration { unit, scale_factor }) =
+                    metadata.to_configuration()
+                {
+                    return if name == "numpy.datetime64" {
+                        Self::NumpyDateTime64 { unit, scale_factor }
+                    } else {
+                        Self::NumpyTimeDelta64 { unit, scale_factor }
+                    };
+                }
+            }
+            "structured" => {
+                if let Ok(configuration) = metadata.to_configuration() {
+                    return Self::Structured(configuration);
+                }
+            }
+            "fixed_length_utf8" | "fixed_length_bytes" => {
+                if let Ok(FixedLengthDataTypeConfiguration { length_bytes }) =
+                    metadata.to_configuration()
+                {
+                    if length_bytes > 0 {
+                        return if name == "fixed_length_utf8" {
+                            Self::FixedLengthUtf8(length_bytes)
+                        } else {
+                            Self::FixedLengthBytes(length_bytes)
+                        };
+                    }
+                }
+            }
             _ => {}
         };
 
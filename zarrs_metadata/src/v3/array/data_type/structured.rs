@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::DataTypeMetadataV3;
+
+/// A field of a `structured` data type.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct StructuredDataTypeField {
+    /// The field name.
+    pub name: String,
+    /// The field data type.
+    pub data_type: DataTypeMetadataV3,
+    /// The byte offset of the field within a record.
+    pub offset: usize,
+}
+
+/// An invalid `structured` data type error.
+#[derive(Clone, Debug, Error)]
+#[error("invalid structured data type: {_0}")]
+pub struct InvalidStructuredDataTypeError(String);
+
+/// The configuration of the `structured` data type.
+///
+/// A `structured` data type is a fixed-size record of named fields, such as a Zarr V2 structured `NumPy` data type.
+/// The size of a record is the end of its last field.
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "fields": [
+///         { "name": "x", "data_type": "float32", "offset": 0 },
+///         { "name": "y", "data_type": "float32", "offset": 4 },
+///         { "name": "id", "data_type": "uint32", "offset": 8 }
+///     ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(
+    try_from = "StructuredDataTypeConfigurationFields",
+    into = "StructuredDataTypeConfigurationFields"
+)]
+pub struct StructuredDataTypeConfiguration {
+    fields: Vec<StructuredDataTypeField>,
+    size: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StructuredDataTypeConfigurationFields {
+    fields: Vec<StructuredDataTypeField>,
+}
+
+impl TryFrom<StructuredDataTypeConfigurationFields> for StructuredDataTypeConfiguration {
+    type Error = InvalidStructuredDataTypeError;
+
+    fn try_from(value: StructuredDataTypeConfigurationFields) -> Result<Self, Self::Error> {
+        Self::new(value.fields)
+    }
+}
+
+impl From<StructuredDataTypeConfiguration> for StructuredDataTypeConfigurationFields {
+    fn from(value: StructuredDataTypeConfiguration) -> Self {
+        Self {
+            fields: value.fields,
+        }
+    }
+}
+
+impl StructuredDataTypeConfiguration {
+    /// Create a new `structured` data type configuration from `fields`.
+    ///
+    /// # Errors
+    /// Returns an [`InvalidStructuredDataTypeError`] if
+    ///  - there are no fields,
+    ///  - field names are not unique,
+    ///  - a field data type is not a known fixed-size data type, or
+    ///  - fields overlap.
+    pub fn new(
+        fields: Vec<StructuredDataTypeField>,
+    ) -> Result<Self, InvalidStructuredDataTypeError> {
+        let err = |message: String| Err(InvalidStructuredDataTypeError(message));
+        if fields.is_empty() {
+            return err("no fields".to_string());
+        }
+
+        let mut names = HashSet::with_capacity(fields.len());
+        let mut ranges = Vec::with_capacity(fields.len());
+        for field in &fields {
+            if !names.insert(field.name.as_str()) {
+                return err(format!("duplicate field name {}", field.name));
+            }
+            let Some(field_size) = field.data_type.fixed_size() else {
+                return err(format!(
+                    "field {} has unsupported data type {}",
+                    field.name, field.data_type
+                ));
+            };
+            ranges.push((field.offset, field.offset + field_size));
+        }
+
+        ranges.sort_unstable();
+        for (previous, next) in ranges.iter().zip(ranges.iter().skip(1)) {
+            if previous.1 > next.0 {
+                return err("fields overlap".to_string());
+            }
+        }
+        let size = ranges.iter().map(|(_, end)| *end).max().unwrap_or_default();
+
+        Ok(Self { fields, size })
+    }
+
+    /// Create a new `structured` data type configuration from packed fields, where each field immediately follows the previous field.
+    ///
+    /// # Errors
+    /// See [`StructuredDataTypeConfiguration::new`].
+    pub fn new_packed(
+        fields: impl IntoIterator<Item = (String, DataTypeMetadataV3)>,
+    ) -> Result<Self, InvalidStructuredDataTypeError> {
+        let mut offset = 0;
+        let mut packed_fields = Vec::new();
+        for (name, data_type) in fields {
+            let field_size = data_type.fixed_size().unwrap_or_default();
+            packed_fields.push(StructuredDataTypeField {
+                name,
+                data_type,
+                offset,
+            });
+            offset += field_size;
+        }
+        Self::new(packed_fields)
+    }
+
+    /// Returns the fields.
+    #[must_use]
+    pub fn fields(&self) -> &[StructuredDataTypeField] {
+        &self.fields
+    }
+
+    /// Returns the size of a record in bytes.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structured_configuration() {
+        let json = r#"{"fields":[{"name":"x","data_type":"float32","offset":0},{"name":"id","data_type":"uint64","offset":8}]}"#;
+        let configuration: StructuredDataTypeConfiguration = serde_json::from_str(json).unwrap();
+        assert_eq!(configuration.size(), 16);
+        assert_eq!(configuration.fields().len(), 2);
+        assert_eq!(
+            configuration.fields()[1].data_type,
+            DataTypeMetadataV3::UInt64
+        );
+        assert_eq!(serde_json::to_string(&configuration).unwrap(), json);
+    }
+
+    #[test]
+    fn structured_configuration_packed() {
+        let configuration = StructuredDataTypeConfiguration::new_packed([
+            ("x".to_string(), DataTypeMetadataV3::Float32),
+            ("flag".to_string(), DataTypeMetadataV3::Bool),
+            ("id".to_string(), DataTypeMetadataV3::UInt32),
+        ])
+        .unwrap();
+        assert_eq!(configuration.size(), 9);
+        assert_eq!(configuration.fields()[2].offset, 5);
+    }
+
+    #[test]
+    fn structured_configuration_invalid() {
+        for json in [
+            r#"{"fields":[]}"#,
+            r#"{"fields":[{"name":"x","data_type":"float32","offset":0},{"name":"x","data_type":"float32","offset":4}]}"#,
+            r#"{"fields":[{"name":"x","data_type":"float32","offset":0},{"name":"y","data_type":"float32","offset":2}]}"#,
+            r#"{"fields":[{"name":"x","data_type":"string","offset":0}]}"#,
+            r#"{"fields":[{"name":"x","data_type":"unknown","offset":0}]}"#,
+            r#"{"fields":[{"name":"x","data_type":"float32"}]}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<StructuredDataTypeConfiguration>(json).is_err(),
+                "{json}"
+            );
+        }
+    }
+}
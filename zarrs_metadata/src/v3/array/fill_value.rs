@@ -60,6 +60,46 @@ pub enum FillValueFloat {
 }
 
 impl FillValueFloat {
+    fn to_float16(&self) -> Option<f16> {
+        match self {
+            Self::Float(float) => Some(f16::from_f64(*float)),
+            Self::HexString(hex_string) => {
+                let bytes = hex_string.as_be_bytes();
+                bytes
+                    .try_into()
+                    .map_or(None, |bytes| Some(f16::from_be_bytes(bytes)))
+            }
+            Self::NonFinite(nonfinite) => {
+                use FillValueFloatStringNonFinite as NF;
+                Some(match nonfinite {
+                    NF::PosInfinity => f16::INFINITY,
+                    NF::NegInfinity => f16::NEG_INFINITY,
+                    NF::NaN => ZARR_NAN_F16,
+                })
+            }
+        }
+    }
+
+    fn to_bfloat16(&self) -> Option<bf16> {
+        match self {
+            Self::Float(float) => Some(bf16::from_f64(*float)),
+            Self::HexString(hex_string) => {
+                let bytes = hex_string.as_be_bytes();
+                bytes
+                    .try_into()
+                    .map_or(None, |bytes| Some(bf16::from_be_bytes(bytes)))
+            }
+            Self::NonFinite(nonfinite) => {
+                use FillValueFloatStringNonFinite as NF;
+                Some(match nonfinite {
+                    NF::PosInfinity => bf16::INFINITY,
+                    NF::NegInfinity => bf16::NEG_INFINITY,
+                    NF::NaN => ZARR_NAN_BF16,
+                })
+            }
+        }
+    }
+
     fn to_float<T: FloatCore>(&self) -> Option<T> {
         match self {
             Self::Float(float) => T::from(*float),
@@ -294,26 +334,7 @@ impl FillValueMetadataV3 {
                 #[allow(clippy::cast_precision_loss)]
                 Some(f16::from_f64(*uint as f64))
             }
-            Self::Float(float) => {
-                use FillValueFloat as F;
-                match float {
-                    F::Float(float) => Some(f16::from_f64(*float)),
-                    F::HexString(hex_string) => {
-                        let bytes = hex_string.as_be_bytes();
-                        bytes
-                            .try_into()
-                            .map_or(None, |bytes| Some(f16::from_be_bytes(bytes)))
-                    }
-                    F::NonFinite(nonfinite) => {
-                        use FillValueFloatStringNonFinite as NF;
-                        Some(match nonfinite {
-                            NF::PosInfinity => f16::INFINITY,
-                            NF::NegInfinity => f16::NEG_INFINITY,
-                            NF::NaN => ZARR_NAN_F16,
-                        })
-                    }
-                }
-            }
+            Self::Float(float) => float.to_float16(),
             _ => None,
         }
     }
@@ -332,26 +353,25 @@ impl FillValueMetadataV3 {
                 #[allow(clippy::cast_precision_loss)]
                 Some(bf16::from_f64(*uint as f64))
             }
-            Self::Float(float) => {
-                use FillValueFloat as F;
-                match float {
-                    F::Float(float) => Some(bf16::from_f64(*float)),
-                    F::HexString(hex_string) => {
-                        let bytes = hex_string.as_be_bytes();
-                        bytes
-                            .try_into()
-                            .map_or(None, |bytes| Some(bf16::from_be_bytes(bytes)))
-                    }
-                    F::NonFinite(nonfinite) => {
-                        use FillValueFloatStringNonFinite as NF;
-                        Some(match nonfinite {
-                            NF::PosInfinity => bf16::INFINITY,
-                            NF::NegInfinity => bf16::NEG_INFINITY,
-                            NF::NaN => ZARR_NAN_BF16,
-                        })
-                    }
-                }
-            }
+            Self::Float(float) => float.to_bfloat16(),
+            _ => None,
+        }
+    }
+
+    /// Convert the fill value to a complex number with [`struct@f16`] components.
+    #[must_use]
+    pub fn try_as_float16_pair(&self) -> Option<(f16, f16)> {
+        match self {
+            Self::Complex(re, im) => Some((re.to_float16()?, im.to_float16()?)),
+            _ => None,
+        }
+    }
+
+    /// Convert the fill value to a complex number with [`bf16`] components.
+    #[must_use]
+    pub fn try_as_bfloat16_pair(&self) -> Option<(bf16, bf16)> {
+        match self {
+            Self::Complex(re, im) => Some((re.to_bfloat16()?, im.to_bfloat16()?)),
             _ => None,
         }
     }
@@ -0,0 +1,133 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use derive_more::{Display, From};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The identifier for the `zarrs.encryption` storage transformer.
+pub const IDENTIFIER: &str = "zarrs.encryption";
+
+/// A wrapper to handle various versions of `zarrs.encryption` storage transformer configuration parameters.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display, From)]
+#[serde(untagged)]
+pub enum EncryptionStorageTransformerConfiguration {
+    /// Version 1.0.
+    V1(EncryptionStorageTransformerConfigurationV1),
+}
+
+/// `zarrs.encryption` storage transformer configuration parameters (version 1.0).
+///
+/// The configuration does not include any key material.
+///
+/// ### Example (Zarr V3)
+/// ```json
+/// {
+///     "algorithm": "aes_256_gcm",
+///     "kdf": "hkdf_sha256",
+///     "salt": "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8="
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Display)]
+#[serde(deny_unknown_fields)]
+#[display("{}", serde_json::to_string(self).unwrap_or_default())]
+pub struct EncryptionStorageTransformerConfigurationV1 {
+    /// The encryption algorithm.
+    pub algorithm: EncryptionAlgorithm,
+    /// The key derivation function.
+    pub kdf: EncryptionKeyDerivationFunction,
+    /// The key derivation function salt, encoded as base64 in metadata.
+    #[serde(
+        serialize_with = "serialize_base64",
+        deserialize_with = "deserialize_base64"
+    )]
+    pub salt: Vec<u8>,
+}
+
+impl EncryptionStorageTransformerConfigurationV1 {
+    /// Create a new `zarrs.encryption` storage transformer configuration.
+    #[must_use]
+    pub const fn new(
+        algorithm: EncryptionAlgorithm,
+        kdf: EncryptionKeyDerivationFunction,
+        salt: Vec<u8>,
+    ) -> Self {
+        Self {
+            algorithm,
+            kdf,
+            salt,
+        }
+    }
+}
+
+/// An encryption algorithm. Used by the `zarrs.encryption` storage transformer.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug, Display)]
+pub enum EncryptionAlgorithm {
+    /// AES-256 in Galois/Counter Mode.
+    #[serde(rename = "aes_256_gcm")]
+    #[display("aes_256_gcm")]
+    Aes256Gcm,
+}
+
+/// A key derivation function. Used by the `zarrs.encryption` storage transformer.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug, Display)]
+pub enum EncryptionKeyDerivationFunction {
+    /// HKDF with HMAC-SHA256.
+    #[serde(rename = "hkdf_sha256")]
+    #[display("hkdf_sha256")]
+    HkdfSha256,
+}
+
+fn serialize_base64<S: Serializer>(bytes: &[u8], s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(&BASE64_STANDARD.encode(bytes))
+}
+
+fn deserialize_base64<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+    let encoded = String::deserialize(d)?;
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::v3::MetadataV3;
+
+    use super::*;
+
+    #[test]
+    fn storage_transformer_encryption_configuration() {
+        let metadata = serde_json::from_str::<MetadataV3>(
+            r#"{
+            "name": "zarrs.encryption",
+            "configuration": {
+                "algorithm": "aes_256_gcm",
+                "kdf": "hkdf_sha256",
+                "salt": "AAECAw=="
+            }
+        }"#,
+        )
+        .unwrap();
+        let configuration: EncryptionStorageTransformerConfiguration =
+            metadata.to_configuration().unwrap();
+        let EncryptionStorageTransformerConfiguration::V1(configuration_v1) = &configuration;
+        assert_eq!(configuration_v1.algorithm, EncryptionAlgorithm::Aes256Gcm);
+        assert_eq!(configuration_v1.salt, [0, 1, 2, 3]);
+        assert_eq!(
+            configuration.to_string(),
+            r#"{"algorithm":"aes_256_gcm","kdf":"hkdf_sha256","salt":"AAECAw=="}"#
+        );
+    }
+
+    #[test]
+    fn storage_transformer_encryption_configuration_invalid() {
+        for json in [
+            r#"{"algorithm":"aes_128_gcm","kdf":"hkdf_sha256","salt":"AAECAw=="}"#,
+            r#"{"algorithm":"aes_256_gcm","kdf":"hkdf_sha256","salt":"not base64!"}"#,
+            r#"{"algorithm":"aes_256_gcm","kdf":"hkdf_sha256","salt":"AAECAw==","key":"AAECAw=="}"#,
+            r#"{"algorithm":"aes_256_gcm","salt":"AAECAw=="}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<EncryptionStorageTransformerConfiguration>(json).is_err(),
+                "{json}"
+            );
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
-use crate::NodeMetadata;
+use crate::{v3::MetadataV3, NodeMetadata};
 
 use super::AdditionalFields;
 
@@ -35,6 +35,11 @@ pub struct GroupMetadataV3 {
     /// Consolidated metadata.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub consolidated_metadata: Option<ConsolidatedMetadata>,
+    /// Optional storage transformers applied to the child nodes of the group.
+    ///
+    /// This is not part of the Zarr V3 specification.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub storage_transformers: Vec<MetadataV3>,
     /// Additional fields.
     #[serde(flatten)]
     pub additional_fields: AdditionalFields,
@@ -44,6 +49,7 @@ impl std::cmp::PartialEq for GroupMetadataV3 {
     fn eq(&self, other: &Self) -> bool {
         self.attributes == other.attributes
             // && self.consolidated_metadata == other.consolidated_metadata
+            && self.storage_transformers == other.storage_transformers
             && self.additional_fields == other.additional_fields
     }
 }
@@ -66,6 +72,7 @@ impl GroupMetadataV3 {
             attributes: serde_json::Map::new(),
             additional_fields: AdditionalFields::default(),
             consolidated_metadata: None,
+            storage_transformers: Vec::default(),
         }
     }
 
@@ -95,6 +102,13 @@ impl GroupMetadataV3 {
         self.consolidated_metadata = consolidated_metadata;
         self
     }
+
+    /// Set the storage transformers.
+    #[must_use]
+    pub fn with_storage_transformers(mut self, storage_transformers: Vec<MetadataV3>) -> Self {
+        self.storage_transformers = storage_transformers;
+        self
+    }
 }
 
 /// Consolidated metadata of a Zarr hierarchy.
@@ -223,6 +223,12 @@ pub struct UnsupportedAdditionalFieldError {
 }
 
 impl UnsupportedAdditionalFieldError {
+    /// Create a new [`UnsupportedAdditionalFieldError`].
+    #[must_use]
+    pub fn new(name: String, value: serde_json::Value) -> Self {
+        Self { name, value }
+    }
+
     /// Return the name of the unsupported additional field.
     #[must_use]
     pub fn name(&self) -> &str {
@@ -238,11 +244,12 @@ impl UnsupportedAdditionalFieldError {
 
 /// An additional field in array or group metadata.
 ///
-/// Must be an object with a `"must_understand": false` field.
-#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug, Default, From)]
+/// Must be an object with a boolean `must_understand` field.
+/// An additional field with `"must_understand": true` is deserialised, but an implementation must refuse to open the array or group unless it understands the field (see [`AdditionalField::must_understand`]).
+///
+/// An additional field created from a map with a `must_understand` member is serialised with that member as-is.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
 pub struct AdditionalField {
-    must_understand: monostate::MustBe!(false),
-    #[serde(flatten)]
     fields: serde_json::Map<String, serde_json::Value>,
 }
 
@@ -252,6 +259,57 @@ impl AdditionalField {
     pub const fn as_map(&self) -> &serde_json::Map<String, serde_json::Value> {
         &self.fields
     }
+
+    /// Returns true if the additional field must be understood by an implementation.
+    ///
+    /// This is true for an additional field deserialised with `"must_understand": true` or created from a map with a `must_understand` member that is not `false`.
+    #[must_use]
+    pub fn must_understand(&self) -> bool {
+        self.fields
+            .get("must_understand")
+            .is_some_and(|must_understand| must_understand != &serde_json::Value::Bool(false))
+    }
+}
+
+impl<'de> Deserialize<'de> for AdditionalField {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let fields = serde_json::Map::deserialize(d)?;
+        match fields.get("must_understand") {
+            Some(serde_json::Value::Bool(false)) => {
+                // Preserves the order of the other members
+                let fields = fields
+                    .into_iter()
+                    .filter(|(key, _)| key != "must_understand")
+                    .collect();
+                Ok(Self { fields })
+            }
+            Some(serde_json::Value::Bool(true)) => Ok(Self { fields }),
+            _ => Err(serde::de::Error::custom(
+                r#"additional fields must have a "must_understand" boolean member"#,
+            )),
+        }
+    }
+}
+
+impl Serialize for AdditionalField {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.fields.contains_key("must_understand") {
+            self.fields.serialize(s)
+        } else {
+            let mut s = s.serialize_map(Some(self.fields.len() + 1))?;
+            s.serialize_entry("must_understand", &false)?;
+            for (key, value) in &self.fields {
+                s.serialize_entry(key, value)?;
+            }
+            s.end()
+        }
+    }
 }
 
 impl From<AdditionalField> for serde_json::Map<String, serde_json::Value> {
@@ -262,16 +320,14 @@ impl From<AdditionalField> for serde_json::Map<String, serde_json::Value> {
 
 impl From<serde_json::Map<String, serde_json::Value>> for AdditionalField {
     fn from(value: serde_json::Map<String, serde_json::Value>) -> Self {
-        Self {
-            must_understand: monostate::MustBe!(false),
-            fields: value,
-        }
+        Self { fields: value }
     }
 }
 
 /// Additional fields in array or group metadata.
 ///
-/// Additional fields are a JSON object with a `"must_understand": false` key-value pair.
+/// Additional fields are a JSON object with a `"must_understand"` boolean key-value pair.
+/// Fields with `"must_understand": false` can be ignored by an implementation that does not understand them.
 ///
 /// ### Example additional field JSON
 /// ```json
@@ -0,0 +1,408 @@
+use base64::{prelude::BASE64_STANDARD, Engine};
+use thiserror::Error;
+
+use crate::{
+    v2::{
+        array::{
+            codec::{
+                blosc::codec_blosc_v3_to_v2_numcodecs,
+                zstd::{ZstdCodecConfigurationNumCodecs, ZstdCodecConfigurationV1},
+            },
+            ArrayMetadataV2Order, DataTypeMetadataV2, DataTypeMetadataV2Structured,
+            FillValueMetadataV2,
+        },
+        ArrayMetadataV2, GroupMetadataV2, MetadataV2,
+    },
+    v2_to_v3::{reverse_structured_endianness, ARRAY_DIMENSIONS_ATTRIBUTE},
+    v3::{
+        array::{
+            chunk_grid::regular::RegularChunkGridConfiguration,
+            chunk_key_encoding::v2::V2ChunkKeyEncodingConfiguration,
+            codec::{
+                blosc::BloscCodecConfiguration, bytes::BytesCodecConfigurationV1,
+                transpose::TransposeCodecConfigurationV1,
+            },
+            data_type::{DataTypeMetadataV3, NumpyTimeUnit},
+            fill_value::FillValueMetadataV3,
+        },
+        ArrayMetadataV3, ConfigurationInvalidError, GroupMetadataV3,
+    },
+    Endianness,
+};
+
+/// Convert Zarr V3 group metadata to V2.
+///
+/// Consolidated metadata and storage transformers are not retained.
+#[must_use]
+pub fn group_metadata_v3_to_v2(group_metadata_v3: &GroupMetadataV3) -> GroupMetadataV2 {
+    GroupMetadataV2::new()
+        .with_attributes(group_metadata_v3.attributes.clone())
+        .with_additional_fields(group_metadata_v3.additional_fields.clone())
+}
+
+/// An error converting Zarr V3 array metadata to V2.
+#[derive(Debug, Error)]
+pub enum ArrayMetadataV3ToV2ConversionError {
+    /// An unsupported chunk grid.
+    #[error("unsupported chunk grid {_0}, only the regular chunk grid is supported")]
+    UnsupportedChunkGrid(String),
+    /// An unsupported chunk key encoding.
+    #[error("unsupported chunk key encoding {_0}, only the v2 chunk key encoding is supported")]
+    UnsupportedChunkKeyEncoding(String),
+    /// An unsupported data type.
+    #[error("unsupported data type {_0}")]
+    UnsupportedDataType(String),
+    /// An unsupported codec.
+    #[error("unsupported codec {_0}: {_1}")]
+    UnsupportedCodec(String, String),
+    /// An unsupported fill value.
+    #[error("unsupported fill value {_1} for data type {_0}")]
+    UnsupportedFillValue(String, FillValueMetadataV3),
+    /// Storage transformers are not supported.
+    #[error("storage transformers are not supported")]
+    UnsupportedStorageTransformers,
+    /// An invalid configuration.
+    #[error(transparent)]
+    InvalidConfiguration(#[from] ConfigurationInvalidError),
+    /// Serialization/deserialization error.
+    #[error("JSON serialization or deserialization error: {_0}")]
+    SerdeError(#[from] serde_json::Error),
+}
+
+/// Convert Zarr V3 array metadata to V2.
+///
+/// The conversion is supported if the array
+///  - uses the `regular` chunk grid and the `v2` chunk key encoding,
+///  - has no storage transformers,
+///  - has a data type with a Zarr V2 equivalent (excluding `string`), and
+///  - uses the `bytes` (or `pcodec`) array to bytes codec with an optional F order `transpose` codec, optional `bitround` and `quantize` filters, and at most one bytes to bytes codec with a `numcodecs` equivalent.
+///
+/// Dimension names are written to the [`_ARRAY_DIMENSIONS`](ARRAY_DIMENSIONS_ATTRIBUTE) attribute if every dimension is named and the attribute is absent.
+///
+/// # Errors
+/// Returns a [`ArrayMetadataV3ToV2ConversionError`] if the metadata is invalid or is not compatible with Zarr V2 metadata.
+#[allow(clippy::too_many_lines)]
+pub fn array_metadata_v3_to_v2(
+    array_metadata_v3: &ArrayMetadataV3,
+) -> Result<ArrayMetadataV2, ArrayMetadataV3ToV2ConversionError> {
+    let shape = array_metadata_v3.shape.clone();
+
+    // Chunk grid
+    let chunk_grid = &array_metadata_v3.chunk_grid;
+    if chunk_grid.name() != crate::v3::array::chunk_grid::regular::IDENTIFIER {
+        return Err(ArrayMetadataV3ToV2ConversionError::UnsupportedChunkGrid(
+            chunk_grid.name().to_string(),
+        ));
+    }
+    let chunks = chunk_grid
+        .to_configuration::<RegularChunkGridConfiguration>()?
+        .chunk_shape;
+
+    // Chunk key encoding
+    let chunk_key_encoding = &array_metadata_v3.chunk_key_encoding;
+    if chunk_key_encoding.name() != crate::v3::array::chunk_key_encoding::v2::IDENTIFIER {
+        return Err(
+            ArrayMetadataV3ToV2ConversionError::UnsupportedChunkKeyEncoding(
+                chunk_key_encoding.name().to_string(),
+            ),
+        );
+    }
+    let dimension_separator = chunk_key_encoding
+        .to_configuration::<V2ChunkKeyEncodingConfiguration>()?
+        .separator;
+
+    if !array_metadata_v3.storage_transformers.is_empty() {
+        return Err(ArrayMetadataV3ToV2ConversionError::UnsupportedStorageTransformers);
+    }
+
+    // Codecs
+    let unsupported_codec = |name: &str, reason: &str| {
+        ArrayMetadataV3ToV2ConversionError::UnsupportedCodec(name.to_string(), reason.to_string())
+    };
+    let mut order = ArrayMetadataV2Order::C;
+    let mut filters: Vec<MetadataV2> = vec![];
+    let mut compressor: Option<MetadataV2> = None;
+    let mut endianness: Option<Endianness> = None;
+    let mut has_array_to_bytes = false;
+    for (index, codec) in array_metadata_v3.codecs.iter().enumerate() {
+        let name = codec.name();
+        let configuration = codec.configuration().cloned().unwrap_or_default();
+        match name {
+            crate::v3::array::codec::transpose::IDENTIFIER => {
+                let transpose = codec.to_configuration::<TransposeCodecConfigurationV1>()?;
+                let f_order: Vec<usize> = (0..shape.len()).rev().collect();
+                if index != 0 || transpose.order.0 != f_order {
+                    return Err(unsupported_codec(
+                        name,
+                        "only a leading transpose with F order is supported",
+                    ));
+                }
+                order = ArrayMetadataV2Order::F;
+            }
+            crate::v3::array::codec::bitround::IDENTIFIER
+            | crate::v3::array::codec::quantize::IDENTIFIER
+                if !has_array_to_bytes =>
+            {
+                filters.push(MetadataV2::new_with_configuration(name, configuration));
+            }
+            crate::v3::array::codec::bytes::IDENTIFIER if !has_array_to_bytes => {
+                has_array_to_bytes = true;
+                endianness = codec
+                    .to_configuration::<BytesCodecConfigurationV1>()?
+                    .endian;
+            }
+            crate::v3::array::codec::pcodec::IDENTIFIER if !has_array_to_bytes => {
+                // pcodec is v2/v3 compatible
+                has_array_to_bytes = true;
+                compressor = Some(MetadataV2::new_with_configuration(name, configuration));
+            }
+            crate::v3::array::codec::adler32::IDENTIFIER
+            | crate::v3::array::codec::bitshuffle::IDENTIFIER
+            | crate::v3::array::codec::blosc::IDENTIFIER
+            | crate::v3::array::codec::bz2::IDENTIFIER
+            | crate::v3::array::codec::crc32::IDENTIFIER
+            | crate::v3::array::codec::fletcher32::IDENTIFIER
+            | crate::v3::array::codec::gzip::IDENTIFIER
+            | crate::v3::array::codec::jenkins_lookup3::IDENTIFIER
+            | crate::v3::array::codec::lz4::IDENTIFIER
+            | crate::v3::array::codec::lzma::IDENTIFIER
+            | crate::v3::array::codec::zstd::IDENTIFIER
+                if has_array_to_bytes =>
+            {
+                if compressor.is_some() {
+                    return Err(unsupported_codec(
+                        name,
+                        "Zarr V2 supports at most one compressor",
+                    ));
+                }
+                compressor = Some(match name {
+                    crate::v3::array::codec::blosc::IDENTIFIER => {
+                        let BloscCodecConfiguration::V1(blosc) =
+                            codec.to_configuration::<BloscCodecConfiguration>()?;
+                        MetadataV2::new_with_serializable_configuration(
+                            name,
+                            &codec_blosc_v3_to_v2_numcodecs(&blosc),
+                        )?
+                    }
+                    crate::v3::array::codec::zstd::IDENTIFIER => {
+                        let zstd = codec.to_configuration::<ZstdCodecConfigurationV1>()?;
+                        if zstd.dictionary.is_some() {
+                            return Err(unsupported_codec(
+                                name,
+                                "numcodecs does not support zstd dictionaries",
+                            ));
+                        }
+                        MetadataV2::new_with_serializable_configuration(
+                            name,
+                            &ZstdCodecConfigurationNumCodecs::V0_13(zstd),
+                        )?
+                    }
+                    _ => {
+                        // v2/v3 compatible
+                        MetadataV2::new_with_configuration(name, configuration)
+                    }
+                });
+            }
+            _ => {
+                return Err(unsupported_codec(
+                    name,
+                    "the codec has no Zarr V2 equivalent at this position in the codec chain",
+                ));
+            }
+        }
+    }
+    if !has_array_to_bytes {
+        return Err(unsupported_codec(
+            crate::v3::array::codec::bytes::IDENTIFIER,
+            "the codec chain must include an array to bytes codec",
+        ));
+    }
+
+    // Data type
+    let data_type = &array_metadata_v3.data_type;
+    let dtype = data_type_metadata_v3_to_v2(data_type, endianness).ok_or_else(|| {
+        ArrayMetadataV3ToV2ConversionError::UnsupportedDataType(data_type.to_string())
+    })?;
+
+    // Fill value
+    let fill_value =
+        fill_value_metadata_v3_to_v2(data_type, &array_metadata_v3.fill_value, endianness)
+            .ok_or_else(|| {
+                ArrayMetadataV3ToV2ConversionError::UnsupportedFillValue(
+                    data_type.to_string(),
+                    array_metadata_v3.fill_value.clone(),
+                )
+            })?;
+
+    // Attributes
+    let mut attributes = array_metadata_v3.attributes.clone();
+    if let Some(dimension_names) = &array_metadata_v3.dimension_names {
+        let dimension_names = dimension_names
+            .iter()
+            .map(|dimension_name| dimension_name.as_str().map(str::to_string))
+            .collect::<Option<Vec<_>>>();
+        if let Some(dimension_names) = dimension_names {
+            attributes
+                .entry(ARRAY_DIMENSIONS_ATTRIBUTE)
+                .or_insert_with(|| dimension_names.into());
+        }
+    }
+
+    let filters = if filters.is_empty() {
+        None
+    } else {
+        Some(filters)
+    };
+    Ok(
+        ArrayMetadataV2::new(shape, chunks, dtype, fill_value, compressor, filters)
+            .with_order(order)
+            .with_dimension_separator(dimension_separator)
+            .with_attributes(attributes)
+            .with_additional_fields(array_metadata_v3.additional_fields.clone()),
+    )
+}
+
+/// Convert a Zarr V3 data type to a Zarr V2 data type with `endianness`.
+///
+/// Returns [`None`] if the data type has no Zarr V2 equivalent.
+#[must_use]
+pub fn data_type_metadata_v3_to_v2(
+    data_type: &DataTypeMetadataV3,
+    endianness: Option<Endianness>,
+) -> Option<DataTypeMetadataV2> {
+    let prefix = match endianness {
+        Some(Endianness::Big) => '>',
+        Some(Endianness::Little) | None => '<',
+    };
+    let dtype = match data_type {
+        DataTypeMetadataV3::Bool => "|b1".to_string(),
+        DataTypeMetadataV3::Int8 => "|i1".to_string(),
+        DataTypeMetadataV3::UInt8 => "|u1".to_string(),
+        DataTypeMetadataV3::Int16 => format!("{prefix}i2"),
+        DataTypeMetadataV3::Int32 => format!("{prefix}i4"),
+        DataTypeMetadataV3::Int64 => format!("{prefix}i8"),
+        DataTypeMetadataV3::UInt16 => format!("{prefix}u2"),
+        DataTypeMetadataV3::UInt32 => format!("{prefix}u4"),
+        DataTypeMetadataV3::UInt64 => format!("{prefix}u8"),
+        DataTypeMetadataV3::Float16 => format!("{prefix}f2"),
+        DataTypeMetadataV3::Float32 => format!("{prefix}f4"),
+        DataTypeMetadataV3::Float64 => format!("{prefix}f8"),
+        DataTypeMetadataV3::Complex64 => format!("{prefix}c8"),
+        DataTypeMetadataV3::Complex128 => format!("{prefix}c16"),
+        DataTypeMetadataV3::NumpyDateTime64 { unit, scale_factor }
+        | DataTypeMetadataV3::NumpyTimeDelta64 { unit, scale_factor } => {
+            let kind = if matches!(data_type, DataTypeMetadataV3::NumpyDateTime64 { .. }) {
+                'M'
+            } else {
+                'm'
+            };
+            match (unit, scale_factor.get()) {
+                (NumpyTimeUnit::Generic, 1) => {
+                    format!("{prefix}{kind}8")
+                }
+                (NumpyTimeUnit::Generic, _) => return None,
+                (unit, 1) => format!("{prefix}{kind}8[{unit}]"),
+                (unit, scale_factor) => format!("{prefix}{kind}8[{scale_factor}{unit}]"),
+            }
+        }
+        DataTypeMetadataV3::Structured(configuration) => {
+            // Zarr V2 structured data types are packed
+            let mut offset = 0;
+            let mut fields = Vec::with_capacity(configuration.fields().len());
+            for field in configuration.fields() {
+                if field.offset != offset {
+                    return None;
+                }
+                let DataTypeMetadataV2::Simple(field_data_type) =
+                    data_type_metadata_v3_to_v2(&field.data_type, endianness)?
+                else {
+                    return None;
+                };
+                offset += field.data_type.fixed_size()?;
+                fields.push(DataTypeMetadataV2Structured::new(
+                    field.name.clone(),
+                    field_data_type,
+                    None,
+                ));
+            }
+            if offset != configuration.size() {
+                return None;
+            }
+            return Some(DataTypeMetadataV2::Structured(fields));
+        }
+        _ => return None,
+    };
+    Some(DataTypeMetadataV2::Simple(dtype))
+}
+
+/// Convert Zarr V3 fill value metadata to [`FillValueMetadataV2`].
+///
+/// Returns [`None`] if the fill value is not compatible with the data type or has no Zarr V2 equivalent.
+#[must_use]
+pub fn fill_value_metadata_v3_to_v2(
+    data_type: &DataTypeMetadataV3,
+    fill_value: &FillValueMetadataV3,
+    endianness: Option<Endianness>,
+) -> Option<FillValueMetadataV2> {
+    let float = |float: f64| {
+        if float.is_nan() {
+            Some(FillValueMetadataV2::NaN)
+        } else if float == f64::INFINITY {
+            Some(FillValueMetadataV2::Infinity)
+        } else if float == f64::NEG_INFINITY {
+            Some(FillValueMetadataV2::NegInfinity)
+        } else {
+            serde_json::Number::from_f64(float).map(FillValueMetadataV2::Number)
+        }
+    };
+    match data_type {
+        DataTypeMetadataV3::Bool => Some(FillValueMetadataV2::Number(
+            u8::from(fill_value.try_as_bool()?).into(),
+        )),
+        DataTypeMetadataV3::Int8
+        | DataTypeMetadataV3::Int16
+        | DataTypeMetadataV3::Int32
+        | DataTypeMetadataV3::Int64
+        | DataTypeMetadataV3::NumpyDateTime64 { .. }
+        | DataTypeMetadataV3::NumpyTimeDelta64 { .. } => Some(FillValueMetadataV2::Number(
+            fill_value.try_as_int::<i64>()?.into(),
+        )),
+        DataTypeMetadataV3::UInt8
+        | DataTypeMetadataV3::UInt16
+        | DataTypeMetadataV3::UInt32
+        | DataTypeMetadataV3::UInt64 => Some(FillValueMetadataV2::Number(
+            fill_value.try_as_uint::<u64>()?.into(),
+        )),
+        DataTypeMetadataV3::Float16 => float(fill_value.try_as_float16()?.to_f64()),
+        DataTypeMetadataV3::Float32 => float(f64::from(fill_value.try_as_float::<f32>()?)),
+        DataTypeMetadataV3::Float64 => float(fill_value.try_as_float::<f64>()?),
+        DataTypeMetadataV3::Complex64 | DataTypeMetadataV3::Complex128 => {
+            // Only a real fill value can be represented
+            match fill_value {
+                FillValueMetadataV3::Complex(..) => {
+                    let (re, im) = fill_value.try_as_float_pair::<f64>()?;
+                    if im == 0.0 {
+                        float(re)
+                    } else {
+                        None
+                    }
+                }
+                _ => float(fill_value.try_as_float::<f64>()?),
+            }
+        }
+        DataTypeMetadataV3::Structured(configuration) => {
+            let FillValueMetadataV3::ByteArray(bytes) = fill_value else {
+                return None;
+            };
+            if bytes.len() != configuration.size() {
+                return None;
+            }
+            let mut bytes = bytes.clone();
+            if endianness == Some(Endianness::Big) {
+                reverse_structured_endianness(&mut bytes, configuration);
+            }
+            Some(FillValueMetadataV2::String(BASE64_STANDARD.encode(bytes)))
+        }
+        _ => None,
+    }
+}
@@ -40,11 +40,16 @@ use futures::{StreamExt, TryStreamExt};
 use object_store::path::Path;
 
 use zarrs_storage::{
-    async_store_set_partial_values, byte_range::ByteRange, AsyncBytes, AsyncListableStorageTraits,
-    AsyncReadableStorageTraits, AsyncWritableStorageTraits, MaybeAsyncBytes, StorageError,
-    StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix,
+    async_store_set_partial_values,
+    byte_range::{ByteRange, MaxBatch},
+    AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits,
+    MaybeAsyncBytes, StorageError, StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeys,
+    StoreKeysPrefixes, StorePrefix,
 };
 
+/// The maximum number of concurrent uploads in [`AsyncWritableStorageTraits::set_many`].
+pub const SET_MANY_CONCURRENT_LIMIT: usize = 32;
+
 /// Maps a [`StoreKey`] to an [`object_store`] path.
 fn key_to_path(key: &StoreKey) -> object_store::path::Path {
     object_store::path::Path::from(key.as_str())
@@ -70,9 +75,53 @@ fn handle_result<T>(result: Result<T, object_store::Error>) -> Result<T, Storage
     result.map_err(|err| StorageError::Other(err.to_string()))
 }
 
+/// Map [`object_store::Error::AlreadyExists`] and [`object_store::Error::Precondition`] to [`StorageError::PreconditionFailed`], pass through other errors
+fn handle_result_precondition<T>(
+    key: &StoreKey,
+    result: Result<T, object_store::Error>,
+) -> Result<T, StorageError> {
+    result.map_err(|err| match err {
+        object_store::Error::AlreadyExists { .. } | object_store::Error::Precondition { .. } => {
+            StorageError::PreconditionFailed(key.clone())
+        }
+        err => StorageError::Other(err.to_string()),
+    })
+}
+
+/// Returns true if `key` is a Zarr metadata key (`zarr.json`, `.zarray`, `.zgroup`, or `.zattrs`).
+fn is_metadata_key(key: &StoreKey) -> bool {
+    let name = key.as_str().rsplit('/').next().unwrap_or_default();
+    matches!(name, "zarr.json" | ".zarray" | ".zgroup" | ".zattrs")
+}
+
+/// Options for use with [`AsyncObjectStore`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub struct AsyncObjectStoreOptions {
+    create_metadata_if_not_exists: bool,
+}
+
+impl AsyncObjectStoreOptions {
+    /// Set whether or not metadata keys can only be created and never overwritten. Defaults to false.
+    ///
+    /// If enabled, [`set`](AsyncWritableStorageTraits::set) on a Zarr metadata key (`zarr.json`, `.zarray`, `.zgroup`, or `.zattrs`) uses a conditional put ([`PutMode::Create`](object_store::PutMode::Create)).
+    /// This fails with [`StorageError::PreconditionFailed`] if the key already exists, so concurrent writers cannot silently overwrite metadata.
+    /// The underlying [`ObjectStore`](object_store::ObjectStore) must support conditional puts.
+    ///
+    /// Other keys (e.g. chunks) are always written unconditionally.
+    pub fn create_metadata_if_not_exists(
+        &mut self,
+        create_metadata_if_not_exists: bool,
+    ) -> &mut Self {
+        self.create_metadata_if_not_exists = create_metadata_if_not_exists;
+        self
+    }
+}
+
 /// An asynchronous store backed by an [`object_store::ObjectStore`].
 pub struct AsyncObjectStore<T> {
     object_store: T,
+    options: AsyncObjectStoreOptions,
     // locks: AsyncStoreLocks,
 }
 
@@ -80,38 +129,71 @@ impl<T: object_store::ObjectStore> AsyncObjectStore<T> {
     /// Create a new [`AsyncObjectStore`].
     #[must_use]
     pub fn new(object_store: T) -> Self {
-        Self { object_store }
+        Self::new_with_options(object_store, AsyncObjectStoreOptions::default())
     }
-}
 
-#[async_trait::async_trait]
-impl<T: object_store::ObjectStore> AsyncReadableStorageTraits for AsyncObjectStore<T> {
-    async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
-        let get = handle_result_notfound(self.object_store.get(&key_to_path(key)).await)?;
-        if let Some(get) = get {
-            let bytes = handle_result(get.bytes().await)?;
-            Ok(Some(bytes))
-        } else {
-            Ok(None)
+    /// Create a new [`AsyncObjectStore`] with `options`.
+    #[must_use]
+    pub fn new_with_options(object_store: T, options: AsyncObjectStoreOptions) -> Self {
+        Self {
+            object_store,
+            options,
         }
     }
 
-    async fn get_partial_values_key(
+    /// Return the entity tag (`ETag`) of the value at `key`, or [`None`] if the key does not exist or the store does not provide entity tags.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if there is an underlying error with the store.
+    pub async fn e_tag(&self, key: &StoreKey) -> Result<Option<String>, StorageError> {
+        Ok(
+            handle_result_notfound(self.object_store.head(&key_to_path(key)).await)?
+                .and_then(|meta| meta.e_tag),
+        )
+    }
+
+    /// Store `value` at `key` if the entity tag (`ETag`) of the existing value matches `e_tag`.
+    ///
+    /// This is an atomic conditional put ([`PutMode::Update`](object_store::PutMode::Update)) intended for building transactional layers over the store.
+    /// The current entity tag of a value can be retrieved with [`e_tag`](AsyncObjectStore::e_tag).
+    /// Returns the entity tag of the new value, if provided by the store.
+    ///
+    /// # Errors
+    /// Returns [`StorageError::PreconditionFailed`] if the key does not exist or its entity tag does not match `e_tag`.
+    /// Returns a [`StorageError`] if the underlying [`ObjectStore`](object_store::ObjectStore) does not support conditional puts, or there is an underlying error with the store.
+    pub async fn set_if_match(
         &self,
         key: &StoreKey,
+        value: AsyncBytes,
+        e_tag: &str,
+    ) -> Result<Option<String>, StorageError> {
+        let mode = object_store::PutMode::Update(object_store::UpdateVersion {
+            e_tag: Some(e_tag.to_string()),
+            version: None,
+        });
+        let put_result = handle_result_precondition(
+            key,
+            self.object_store
+                .put_opts(&key_to_path(key), value.into(), mode.into())
+                .await,
+        )?;
+        Ok(put_result.e_tag)
+    }
+}
+
+impl<T: object_store::ObjectStore> AsyncObjectStore<T> {
+    /// Retrieve `byte_ranges` of the value at `path` with size `size` with a single [`get_ranges`](object_store::ObjectStore::get_ranges) request.
+    async fn get_ranges(
+        &self,
+        path: &Path,
         byte_ranges: &[ByteRange],
+        size: u64,
     ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
-        let Some(size) = self.size_key(key).await? else {
-            return Ok(None);
-        };
         let ranges = byte_ranges
             .iter()
             .map(|byte_range| byte_range.to_range_usize(size))
             .collect::<Vec<_>>();
-        let get_ranges = self
-            .object_store
-            .get_ranges(&key_to_path(key), &ranges)
-            .await;
+        let get_ranges = self.object_store.get_ranges(path, &ranges).await;
         match get_ranges {
             Ok(get_ranges) => Ok(Some(
                 std::iter::zip(ranges, get_ranges)
@@ -137,6 +219,51 @@ impl<T: object_store::ObjectStore> AsyncReadableStorageTraits for AsyncObjectSto
             }
         }
     }
+}
+
+#[async_trait::async_trait]
+impl<T: object_store::ObjectStore> AsyncReadableStorageTraits for AsyncObjectStore<T> {
+    async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
+        let get = handle_result_notfound(self.object_store.get(&key_to_path(key)).await)?;
+        if let Some(get) = get {
+            let bytes = handle_result(get.bytes().await)?;
+            Ok(Some(bytes))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.get_partial_values_key_batched(key, byte_ranges, MaxBatch::Unlimited)
+            .await
+    }
+
+    async fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        let Some(size) = self.size_key(key).await? else {
+            return Ok(None);
+        };
+        let path = key_to_path(key);
+        let get_ranges = futures::future::try_join_all(
+            max_batch
+                .batches(byte_ranges)
+                .into_iter()
+                .map(|byte_ranges| self.get_ranges(&path, byte_ranges, size)),
+        )
+        .await?;
+        Ok(get_ranges
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .map(|get_ranges| get_ranges.into_iter().flatten().collect()))
+    }
 
     async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
         Ok(
@@ -144,12 +271,43 @@ impl<T: object_store::ObjectStore> AsyncReadableStorageTraits for AsyncObjectSto
                 .map(|meta| meta.size as u64),
         )
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        StoreCapabilities::new()
+            .with_writable(true)
+            .with_listing(true)
+            .with_partial_read(true)
+            .with_atomic_put(true)
+    }
 }
 
 #[async_trait::async_trait]
 impl<T: object_store::ObjectStore> AsyncWritableStorageTraits for AsyncObjectStore<T> {
     async fn set(&self, key: &StoreKey, value: AsyncBytes) -> Result<(), StorageError> {
-        handle_result(self.object_store.put(&key_to_path(key), value.into()).await)?;
+        if self.options.create_metadata_if_not_exists && is_metadata_key(key) {
+            handle_result_precondition(
+                key,
+                self.object_store
+                    .put_opts(
+                        &key_to_path(key),
+                        value.into(),
+                        object_store::PutMode::Create.into(),
+                    )
+                    .await,
+            )?;
+        } else {
+            handle_result(self.object_store.put(&key_to_path(key), value.into()).await)?;
+        }
+        Ok(())
+    }
+
+    /// Values are uploaded concurrently, with at most [`SET_MANY_CONCURRENT_LIMIT`] uploads in flight.
+    async fn set_many(&self, key_values: &[(StoreKey, AsyncBytes)]) -> Result<(), StorageError> {
+        futures::stream::iter(key_values.iter().cloned())
+            .map(|(key, value)| async move { self.set(&key, value).await })
+            .buffer_unordered(SET_MANY_CONCURRENT_LIMIT)
+            .try_collect::<Vec<()>>()
+            .await?;
         Ok(())
     }
 
@@ -273,7 +431,100 @@ impl<T: object_store::ObjectStore> AsyncListableStorageTraits for AsyncObjectSto
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::error::Error;
+    use std::{
+        error::Error,
+        num::NonZeroUsize,
+        ops::Range,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+    };
+
+    use futures::stream::BoxStream;
+    use object_store::{
+        GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore,
+        PutMultipartOpts, PutOptions, PutPayload, PutResult,
+    };
+
+    /// An [`ObjectStore`] that counts `head` and `get_ranges` requests.
+    #[derive(Debug, Default)]
+    struct CountingObjectStore {
+        inner: object_store::memory::InMemory,
+        heads: AtomicUsize,
+        get_ranges: AtomicUsize,
+    }
+
+    impl std::fmt::Display for CountingObjectStore {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "CountingObjectStore({})", self.inner)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl ObjectStore for CountingObjectStore {
+        async fn put_opts(
+            &self,
+            location: &Path,
+            payload: PutPayload,
+            opts: PutOptions,
+        ) -> object_store::Result<PutResult> {
+            self.inner.put_opts(location, payload, opts).await
+        }
+
+        async fn put_multipart_opts(
+            &self,
+            location: &Path,
+            opts: PutMultipartOpts,
+        ) -> object_store::Result<Box<dyn MultipartUpload>> {
+            self.inner.put_multipart_opts(location, opts).await
+        }
+
+        async fn get_opts(
+            &self,
+            location: &Path,
+            options: GetOptions,
+        ) -> object_store::Result<GetResult> {
+            self.inner.get_opts(location, options).await
+        }
+
+        async fn get_ranges(
+            &self,
+            location: &Path,
+            ranges: &[Range<usize>],
+        ) -> object_store::Result<Vec<AsyncBytes>> {
+            self.get_ranges.fetch_add(1, Ordering::Relaxed);
+            self.inner.get_ranges(location, ranges).await
+        }
+
+        async fn head(&self, location: &Path) -> object_store::Result<ObjectMeta> {
+            self.heads.fetch_add(1, Ordering::Relaxed);
+            self.inner.head(location).await
+        }
+
+        async fn delete(&self, location: &Path) -> object_store::Result<()> {
+            self.inner.delete(location).await
+        }
+
+        fn list(&self, prefix: Option<&Path>) -> BoxStream<'_, object_store::Result<ObjectMeta>> {
+            self.inner.list(prefix)
+        }
+
+        async fn list_with_delimiter(
+            &self,
+            prefix: Option<&Path>,
+        ) -> object_store::Result<ListResult> {
+            self.inner.list_with_delimiter(prefix).await
+        }
+
+        async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+            self.inner.copy(from, to).await
+        }
+
+        async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+            self.inner.copy_if_not_exists(from, to).await
+        }
+    }
 
     #[tokio::test]
     async fn memory() -> Result<(), Box<dyn Error>> {
@@ -281,6 +532,11 @@ mod tests {
         zarrs_storage::store_test::async_store_write(&store).await?;
         zarrs_storage::store_test::async_store_read(&store).await?;
         zarrs_storage::store_test::async_store_list(&store).await?;
+        zarrs_storage::store_test::async_store_health_check(&store).await?;
+        let capabilities = store.capabilities();
+        assert!(capabilities.supports_listing());
+        assert!(capabilities.supports_atomic_put());
+        assert!(!capabilities.supports_partial_write());
         Ok(())
     }
 
@@ -294,6 +550,157 @@ mod tests {
         zarrs_storage::store_test::async_store_write(&store).await?;
         zarrs_storage::store_test::async_store_read(&store).await?;
         zarrs_storage::store_test::async_store_list(&store).await?;
+        zarrs_storage::store_test::async_store_health_check(&store).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_partial_values_key_batched() -> Result<(), Box<dyn Error>> {
+        let store = AsyncObjectStore::new(CountingObjectStore::default());
+        let key: StoreKey = "a".try_into()?;
+        store.set(&key, vec![0, 1, 2, 3, 4].into()).await?;
+        let byte_ranges = (0..5)
+            .map(|i| ByteRange::FromStart(i, Some(1)))
+            .collect::<Vec<_>>();
+        let expected = (0..5).map(|i| vec![i].into()).collect::<Vec<AsyncBytes>>();
+
+        // All byte ranges are retrieved with a single request
+        assert_eq!(
+            store
+                .get_partial_values_key_batched(&key, &byte_ranges, MaxBatch::Unlimited)
+                .await?,
+            Some(expected.clone())
+        );
+        assert_eq!(store.object_store.heads.load(Ordering::Relaxed), 1);
+        assert_eq!(store.object_store.get_ranges.load(Ordering::Relaxed), 1);
+
+        // Byte ranges are retrieved with one request per batch, and the size is only retrieved once
+        let max_batch = MaxBatch::Limit(NonZeroUsize::new(2).unwrap());
+        assert_eq!(
+            store
+                .get_partial_values_key_batched(&key, &byte_ranges, max_batch)
+                .await?,
+            Some(expected)
+        );
+        assert_eq!(store.object_store.heads.load(Ordering::Relaxed), 2);
+        assert_eq!(store.object_store.get_ranges.load(Ordering::Relaxed), 4);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_partial_values_key_batched_storage_adapters() -> Result<(), Box<dyn Error>> {
+        use zarrs_storage::storage_adapter::{
+            performance_metrics::{PerformanceMetricsStorageAdapter, StorageRequestKind},
+            read_only::ReadOnlyStorageAdapter,
+            usage_log::UsageLogStorageAdapter,
+        };
+
+        let store = Arc::new(AsyncObjectStore::new(CountingObjectStore::default()));
+        let key: StoreKey = "a".try_into()?;
+        store.set(&key, vec![0, 1, 2, 3, 4].into()).await?;
+        let byte_ranges = (0..5)
+            .map(|i| ByteRange::FromStart(i, Some(1)))
+            .collect::<Vec<_>>();
+        let expected = Some((0..5).map(|i| vec![i].into()).collect::<Vec<AsyncBytes>>());
+        let get_ranges = || store.object_store.get_ranges.load(Ordering::Relaxed);
+
+        // Each storage adapter retrieves all byte ranges with a single request
+        let store_perf = PerformanceMetricsStorageAdapter::new(store.clone());
+        assert_eq!(
+            store_perf
+                .get_partial_values_key_batched(&key, &byte_ranges, MaxBatch::Unlimited)
+                .await?,
+            expected
+        );
+        assert_eq!(get_ranges(), 1);
+        assert_eq!(store_perf.reads(), 5);
+        assert_eq!(
+            store_perf.requests_by_kind()[&StorageRequestKind::GetPartialValuesKeyBatched],
+            1
+        );
+
+        let store_read_only = ReadOnlyStorageAdapter::new(store.clone());
+        assert_eq!(
+            store_read_only
+                .get_partial_values_key_batched(&key, &byte_ranges, MaxBatch::Unlimited)
+                .await?,
+            expected
+        );
+        assert_eq!(get_ranges(), 2);
+
+        let log_writer = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let store_usage_log =
+            UsageLogStorageAdapter::new(store.clone(), log_writer.clone(), String::new);
+        assert_eq!(
+            store_usage_log
+                .get_partial_values_key_batched(&key, &byte_ranges, MaxBatch::Unlimited)
+                .await?,
+            expected
+        );
+        assert_eq!(get_ranges(), 3);
+        assert!(String::from_utf8(log_writer.lock().unwrap().clone())?
+            .starts_with("get_partial_values_key_batched(a, "));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_metadata_if_not_exists() -> Result<(), Box<dyn Error>> {
+        let mut options = AsyncObjectStoreOptions::default();
+        options.create_metadata_if_not_exists(true);
+        let store =
+            AsyncObjectStore::new_with_options(object_store::memory::InMemory::new(), options);
+
+        // Metadata keys can be created but not overwritten
+        let metadata_key: StoreKey = "array/zarr.json".try_into()?;
+        store.set(&metadata_key, vec![0].into()).await?;
+        assert!(matches!(
+            store.set(&metadata_key, vec![1].into()).await,
+            Err(StorageError::PreconditionFailed(key)) if key == metadata_key
+        ));
+        assert_eq!(store.get(&metadata_key).await?, Some(vec![0].into()));
+
+        // Chunk keys are written unconditionally
+        let chunk_key: StoreKey = "array/c/0".try_into()?;
+        store.set(&chunk_key, vec![0].into()).await?;
+        store.set(&chunk_key, vec![1].into()).await?;
+        assert_eq!(store.get(&chunk_key).await?, Some(vec![1].into()));
+
+        // Metadata keys are written unconditionally by default
+        let store = AsyncObjectStore::new(object_store::memory::InMemory::new());
+        store.set(&metadata_key, vec![0].into()).await?;
+        store.set(&metadata_key, vec![1].into()).await?;
+        assert_eq!(store.get(&metadata_key).await?, Some(vec![1].into()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_if_match() -> Result<(), Box<dyn Error>> {
+        let store = AsyncObjectStore::new(object_store::memory::InMemory::new());
+        let key: StoreKey = "a".try_into()?;
+
+        // The key must exist
+        assert!(matches!(
+            store.set_if_match(&key, vec![0].into(), "0").await,
+            Err(StorageError::PreconditionFailed(_))
+        ));
+        assert_eq!(store.e_tag(&key).await?, None);
+
+        store.set(&key, vec![0].into()).await?;
+        let e_tag = store.e_tag(&key).await?.unwrap();
+        let e_tag_new = store.set_if_match(&key, vec![1].into(), &e_tag).await?;
+        assert_eq!(store.get(&key).await?, Some(vec![1].into()));
+        assert_eq!(e_tag_new, store.e_tag(&key).await?);
+
+        // A stale entity tag fails
+        assert!(matches!(
+            store.set_if_match(&key, vec![2].into(), &e_tag).await,
+            Err(StorageError::PreconditionFailed(_))
+        ));
+        assert_eq!(store.get(&key).await?, Some(vec![1].into()));
+
         Ok(())
     }
 }
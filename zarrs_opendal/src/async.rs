@@ -1,13 +1,17 @@
+use futures::{StreamExt, TryStreamExt};
 use opendal::Operator;
 
 use zarrs_storage::{
-    byte_range::{ByteRange, InvalidByteRangeError},
+    byte_range::{validate_byte_ranges, ByteRange, MaxBatch},
     AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits,
-    MaybeAsyncBytes, StorageError, StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes,
-    StorePrefix,
+    MaybeAsyncBytes, StorageError, StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeys,
+    StoreKeysPrefixes, StorePrefix,
 };
 
-use crate::{handle_result, handle_result_notfound};
+use crate::{
+    group_partial_writes, handle_result, handle_result_notfound, partial_writes_to_append,
+    store_capabilities, SET_MANY_CONCURRENT_LIMIT,
+};
 
 /// An asynchronous store backed by an [`opendal::Operator`].
 pub struct AsyncOpendalStore {
@@ -38,21 +42,33 @@ impl AsyncReadableStorageTraits for AsyncOpendalStore {
         &self,
         key: &StoreKey,
         byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.get_partial_values_key_batched(key, byte_ranges, MaxBatch::Unlimited)
+            .await
+    }
+
+    async fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
     ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
         // TODO: Get OpenDAL to return an error if byte range is OOB instead of panic, then don't need to query size
         let (size, reader) = futures::join!(self.size_key(key), self.operator.reader(key.as_str()));
         if let (Some(size), Some(reader)) = (size?, handle_result_notfound(reader)?) {
-            let mut byte_ranges_fetch = Vec::with_capacity(byte_ranges.len());
-            for byte_range in byte_ranges {
-                let byte_range_opendal = byte_range.to_range(size);
-                if byte_range_opendal.end > size {
-                    return Err(InvalidByteRangeError::new(*byte_range, size).into());
-                }
-                byte_ranges_fetch.push(byte_range_opendal);
+            validate_byte_ranges(byte_ranges, size)?;
+            let mut fetches = Vec::new();
+            for byte_ranges in max_batch.batches(byte_ranges) {
+                let byte_ranges_fetch = byte_ranges
+                    .iter()
+                    .map(|byte_range| byte_range.to_range(size))
+                    .collect();
+                fetches.push(reader.fetch(byte_ranges_fetch));
             }
             Ok(Some(
-                handle_result(reader.fetch(byte_ranges_fetch).await)?
+                handle_result(futures::future::try_join_all(fetches).await)?
                     .into_iter()
+                    .flatten()
                     .map(|buf| buf.to_bytes())
                     .collect(),
             ))
@@ -67,6 +83,10 @@ impl AsyncReadableStorageTraits for AsyncOpendalStore {
                 .map(|metadata| metadata.content_length()),
         )
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        store_capabilities(self.operator.info().full_capability())
+    }
 }
 
 #[async_trait::async_trait]
@@ -75,11 +95,44 @@ impl AsyncWritableStorageTraits for AsyncOpendalStore {
         handle_result(self.operator.write(key.as_str(), value).await)
     }
 
+    /// Values are written concurrently, with at most [`SET_MANY_CONCURRENT_LIMIT`] writes in flight.
+    async fn set_many(&self, key_values: &[(StoreKey, AsyncBytes)]) -> Result<(), StorageError> {
+        futures::stream::iter(key_values.iter().cloned())
+            .map(|(key, value)| async move { self.set(&key, value).await })
+            .buffer_unordered(SET_MANY_CONCURRENT_LIMIT)
+            .try_collect::<Vec<()>>()
+            .await?;
+        Ok(())
+    }
+
+    /// Writes that only extend a value are appended if the service supports appending (e.g. `fs`).
+    /// Otherwise, values are retrieved, updated, and rewritten.
     async fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
     ) -> Result<(), StorageError> {
-        zarrs_storage::async_store_set_partial_values(self, key_offset_values).await
+        if !self.operator.info().full_capability().write_can_append {
+            return zarrs_storage::async_store_set_partial_values(self, key_offset_values).await;
+        }
+
+        for (key, group) in group_partial_writes(key_offset_values) {
+            let size = self.size_key(&key).await?;
+            match partial_writes_to_append(size.unwrap_or_default(), &group) {
+                Some(value) if size.is_some() => {
+                    let mut writer =
+                        handle_result(self.operator.writer_with(key.as_str()).append(true).await)?;
+                    handle_result(writer.write(value).await)?;
+                    handle_result(writer.close().await)?;
+                }
+                Some(value) => handle_result(self.operator.write(key.as_str(), value).await)?,
+                None => zarrs_storage::async_store_set_partial_values(self, &group).await?,
+            }
+        }
+        Ok(())
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.capabilities().supports_partial_write()
     }
 
     async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
@@ -211,6 +264,7 @@ mod tests {
         zarrs_storage::store_test::async_store_write(&store).await?;
         zarrs_storage::store_test::async_store_read(&store).await?;
         zarrs_storage::store_test::async_store_list(&store).await?;
+        zarrs_storage::store_test::async_store_health_check(&store).await?;
         Ok(())
     }
 
@@ -224,6 +278,73 @@ mod tests {
         zarrs_storage::store_test::async_store_write(&store).await?;
         zarrs_storage::store_test::async_store_read(&store).await?;
         zarrs_storage::store_test::async_store_list(&store).await?;
+        zarrs_storage::store_test::async_store_health_check(&store).await?;
+        Ok(())
+    }
+
+    async fn set_partial_values(store: &AsyncOpendalStore) -> Result<(), Box<dyn Error>> {
+        let key = StoreKey::new("a/b")?;
+        // Create a value with unsorted adjacent writes
+        store
+            .set_partial_values(&[
+                StoreKeyOffsetValue::new(key.clone(), 2, &[3, 4]),
+                StoreKeyOffsetValue::new(key.clone(), 0, &[1, 2]),
+            ])
+            .await?;
+        assert_eq!(store.get(&key).await?, Some(vec![1, 2, 3, 4].into()));
+        // Extend the value with a gap
+        store
+            .set_partial_values(&[
+                StoreKeyOffsetValue::new(key.clone(), 6, &[7]),
+                StoreKeyOffsetValue::new(key.clone(), 5, &[6]),
+            ])
+            .await?;
+        assert_eq!(
+            store.get(&key).await?,
+            Some(vec![1, 2, 3, 4, 0, 6, 7].into())
+        );
+        // Update and extend the value
+        store
+            .set_partial_values(&[
+                StoreKeyOffsetValue::new(key.clone(), 1, &[9]),
+                StoreKeyOffsetValue::new(key.clone(), 7, &[8]),
+            ])
+            .await?;
+        assert_eq!(
+            store.get(&key).await?,
+            Some(vec![1, 9, 3, 4, 0, 6, 7, 8].into())
+        );
+        // Overlapping writes are applied in order
+        store
+            .set_partial_values(&[
+                StoreKeyOffsetValue::new(key.clone(), 8, &[1, 2]),
+                StoreKeyOffsetValue::new(key.clone(), 9, &[3]),
+            ])
+            .await?;
+        assert_eq!(
+            store.get(&key).await?,
+            Some(vec![1, 9, 3, 4, 0, 6, 7, 8, 1, 3].into())
+        );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn memory_set_partial_values() -> Result<(), Box<dyn Error>> {
+        let builder = opendal::services::Memory::default();
+        let op = Operator::new(builder)?.finish();
+        set_partial_values(&AsyncOpendalStore::new(op)).await
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)]
+    async fn filesystem_set_partial_values() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let builder = opendal::services::Fs::default().root(&path.path().to_string_lossy());
+        let op = Operator::new(builder)?.finish();
+        assert!(op.info().full_capability().write_can_append);
+        let store = AsyncOpendalStore::new(op);
+        assert!(store.capabilities().supports_partial_write());
+        assert!(store.supports_set_partial());
+        set_partial_values(&store).await
+    }
 }
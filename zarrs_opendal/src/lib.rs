@@ -38,7 +38,12 @@ pub use sync::OpendalStore;
 
 pub use opendal;
 
-use zarrs_storage::StorageError;
+use zarrs_storage::{StorageError, StoreCapabilities, StoreKey, StoreKeyOffsetValue};
+
+use std::collections::BTreeMap;
+
+/// The maximum number of concurrent writes in [`AsyncWritableStorageTraits::set_many`](zarrs_storage::AsyncWritableStorageTraits::set_many).
+pub const SET_MANY_CONCURRENT_LIMIT: usize = 32;
 
 /// Map [`opendal::ErrorKind::NotFound`] to None, pass through other errors
 fn handle_result_notfound<T>(result: Result<T, opendal::Error>) -> Result<Option<T>, StorageError> {
@@ -57,3 +62,99 @@ fn handle_result_notfound<T>(result: Result<T, opendal::Error>) -> Result<Option
 fn handle_result<T>(result: Result<T, opendal::Error>) -> Result<T, StorageError> {
     result.map_err(|err| StorageError::Other(err.to_string()))
 }
+
+/// Map the capabilities of an [`opendal`] service to [`StoreCapabilities`].
+///
+/// Partial writes are reported as supported if the service supports appending, since writes that extend a value are appended.
+/// Whether a write is atomic is not exposed by [`opendal`], so atomic puts are conservatively reported as unsupported.
+fn store_capabilities(capability: opendal::Capability) -> StoreCapabilities {
+    let writable = capability.write && capability.delete;
+    StoreCapabilities::new()
+        .with_writable(writable)
+        .with_listing(capability.list)
+        .with_partial_read(capability.read)
+        .with_partial_write(writable && capability.write_can_append)
+}
+
+/// Group partial writes by key.
+///
+/// Groups are ordered by key, and the writes within a group retain their input order.
+fn group_partial_writes<'a>(
+    key_offset_values: &[StoreKeyOffsetValue<'a>],
+) -> Vec<(StoreKey, Vec<StoreKeyOffsetValue<'a>>)> {
+    let mut groups: BTreeMap<StoreKey, Vec<StoreKeyOffsetValue>> = BTreeMap::new();
+    for key_offset_value in key_offset_values {
+        groups
+            .entry(key_offset_value.key().clone())
+            .or_default()
+            .push(key_offset_value.clone());
+    }
+    groups.into_iter().collect()
+}
+
+/// Merge partial writes to a single key into a value to append to an existing value of length `size`.
+///
+/// The writes are sorted by offset and adjacent writes are merged, with gaps filled with zeros.
+/// Returns [`None`] if any writes overlap or start before `size`, in which case the writes cannot be applied with an append.
+fn partial_writes_to_append(
+    size: u64,
+    key_offset_values: &[StoreKeyOffsetValue],
+) -> Option<Vec<u8>> {
+    let mut key_offset_values = key_offset_values.iter().collect::<Vec<_>>();
+    key_offset_values.sort_by_key(|key_offset_value| key_offset_value.offset());
+    let mut value = Vec::new();
+    let mut end = size;
+    for key_offset_value in key_offset_values {
+        if key_offset_value.offset() < end {
+            return None;
+        }
+        let gap = usize::try_from(key_offset_value.offset() - end).ok()?;
+        value.resize(value.len() + gap, 0);
+        value.extend_from_slice(key_offset_value.value());
+        end = key_offset_value.offset() + key_offset_value.value().len() as u64;
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_writes_append() -> Result<(), Box<dyn std::error::Error>> {
+        let a = StoreKey::new("a")?;
+        let b = StoreKey::new("b")?;
+        let key_offset_values = [
+            StoreKeyOffsetValue::new(a.clone(), 7, &[3]),
+            StoreKeyOffsetValue::new(a.clone(), 4, &[1, 2]),
+            StoreKeyOffsetValue::new(b.clone(), 0, &[5]),
+            StoreKeyOffsetValue::new(a.clone(), 6, &[9]),
+        ];
+        let groups = group_partial_writes(&key_offset_values);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, a);
+        assert_eq!(groups[0].1.len(), 3);
+        assert_eq!(groups[0].1[2].offset(), 6);
+        assert_eq!(groups[1].0, b);
+        assert_eq!(groups[1].1.len(), 1);
+
+        // Adjacent and interleaved writes
+        assert_eq!(
+            partial_writes_to_append(4, &groups[0].1),
+            Some(vec![1, 2, 9, 3])
+        );
+        assert_eq!(
+            partial_writes_to_append(2, &groups[0].1),
+            Some(vec![0, 0, 1, 2, 9, 3])
+        );
+        // Writes before the end of the existing value
+        assert_eq!(partial_writes_to_append(5, &groups[0].1), None);
+        // Overlapping writes
+        let key_offset_values = [
+            StoreKeyOffsetValue::new(a.clone(), 0, &[1, 2]),
+            StoreKeyOffsetValue::new(a, 1, &[3]),
+        ];
+        assert_eq!(partial_writes_to_append(0, &key_offset_values), None);
+        Ok(())
+    }
+}
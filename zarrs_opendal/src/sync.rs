@@ -1,12 +1,16 @@
 use opendal::BlockingOperator;
 
 use zarrs_storage::{
-    byte_range::{ByteRange, InvalidByteRangeError},
-    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError, StoreKey,
-    StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix, WritableStorageTraits,
+    byte_range::{validate_byte_ranges, ByteRange},
+    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix,
+    WritableStorageTraits,
 };
 
-use crate::{handle_result, handle_result_notfound};
+use crate::{
+    group_partial_writes, handle_result, handle_result_notfound, partial_writes_to_append,
+    store_capabilities,
+};
 
 /// An asynchronous store backed by an [`opendal::BlockingOperator`].
 pub struct OpendalStore {
@@ -36,14 +40,11 @@ impl ReadableStorageTraits for OpendalStore {
         // TODO: Get OpenDAL to return an error if byte range is OOB instead of panic
         let size = self.size_key(key)?;
         if let Some(size) = size {
+            validate_byte_ranges(byte_ranges, size)?;
             let reader = handle_result(self.operator.reader(key.as_str()))?;
             let mut bytes = Vec::with_capacity(byte_ranges.len());
             for byte_range in byte_ranges {
-                let byte_range_opendal = byte_range.to_range(size);
-                if byte_range_opendal.end > size {
-                    return Err(InvalidByteRangeError::new(*byte_range, size).into());
-                }
-                bytes.push(handle_result(reader.read(byte_range_opendal))?.to_bytes());
+                bytes.push(handle_result(reader.read(byte_range.to_range(size)))?.to_bytes());
             }
             Ok(Some(bytes))
         } else {
@@ -55,6 +56,10 @@ impl ReadableStorageTraits for OpendalStore {
         Ok(handle_result_notfound(self.operator.stat(key.as_str()))?
             .map(|metadata| metadata.content_length()))
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        store_capabilities(self.operator.info().full_capability())
+    }
 }
 
 #[async_trait::async_trait]
@@ -63,11 +68,34 @@ impl WritableStorageTraits for OpendalStore {
         handle_result(self.operator.write(key.as_str(), value))
     }
 
+    /// Writes that only extend a value are appended if the service supports appending (e.g. `fs`).
+    /// Otherwise, values are retrieved, updated, and rewritten.
     fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
     ) -> Result<(), StorageError> {
-        zarrs_storage::store_set_partial_values(self, key_offset_values)
+        if !self.operator.info().full_capability().write_can_append {
+            return zarrs_storage::store_set_partial_values(self, key_offset_values);
+        }
+
+        for (key, group) in group_partial_writes(key_offset_values) {
+            let size = self.size_key(&key)?;
+            match partial_writes_to_append(size.unwrap_or_default(), &group) {
+                Some(value) if size.is_some() => {
+                    let mut writer =
+                        handle_result(self.operator.writer_with(key.as_str()).append(true).call())?;
+                    handle_result(writer.write(value))?;
+                    handle_result(writer.close())?;
+                }
+                Some(value) => handle_result(self.operator.write(key.as_str(), value))?,
+                None => zarrs_storage::store_set_partial_values(self, &group)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.capabilities().supports_partial_write()
     }
 
     fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
@@ -201,6 +229,11 @@ mod tests {
         zarrs_storage::store_test::store_write(&store)?;
         zarrs_storage::store_test::store_read(&store)?;
         zarrs_storage::store_test::store_list(&store)?;
+        zarrs_storage::store_test::store_health_check(&store)?;
+        let capabilities = store.capabilities();
+        assert!(capabilities.supports_listing());
+        assert!(capabilities.supports_partial_read());
+        assert!(!capabilities.supports_atomic_put());
         Ok(())
     }
 
@@ -214,6 +247,59 @@ mod tests {
         zarrs_storage::store_test::store_write(&store)?;
         zarrs_storage::store_test::store_read(&store)?;
         zarrs_storage::store_test::store_list(&store)?;
+        zarrs_storage::store_test::store_health_check(&store)?;
+        Ok(())
+    }
+
+    fn set_partial_values(store: &OpendalStore) -> Result<(), Box<dyn Error>> {
+        let key = StoreKey::new("a/b")?;
+        // Create a value with unsorted adjacent writes
+        store.set_partial_values(&[
+            StoreKeyOffsetValue::new(key.clone(), 2, &[3, 4]),
+            StoreKeyOffsetValue::new(key.clone(), 0, &[1, 2]),
+        ])?;
+        assert_eq!(store.get(&key)?, Some(vec![1, 2, 3, 4].into()));
+        // Extend the value with a gap
+        store.set_partial_values(&[
+            StoreKeyOffsetValue::new(key.clone(), 6, &[7]),
+            StoreKeyOffsetValue::new(key.clone(), 5, &[6]),
+        ])?;
+        assert_eq!(store.get(&key)?, Some(vec![1, 2, 3, 4, 0, 6, 7].into()));
+        // Update and extend the value
+        store.set_partial_values(&[
+            StoreKeyOffsetValue::new(key.clone(), 1, &[9]),
+            StoreKeyOffsetValue::new(key.clone(), 7, &[8]),
+        ])?;
+        assert_eq!(store.get(&key)?, Some(vec![1, 9, 3, 4, 0, 6, 7, 8].into()));
+        // Overlapping writes are applied in order
+        store.set_partial_values(&[
+            StoreKeyOffsetValue::new(key.clone(), 8, &[1, 2]),
+            StoreKeyOffsetValue::new(key.clone(), 9, &[3]),
+        ])?;
+        assert_eq!(
+            store.get(&key)?,
+            Some(vec![1, 9, 3, 4, 0, 6, 7, 8, 1, 3].into())
+        );
         Ok(())
     }
+
+    #[test]
+    fn memory_set_partial_values() -> Result<(), Box<dyn Error>> {
+        let builder = opendal::services::Memory::default();
+        let op = Operator::new(builder)?.finish().blocking();
+        set_partial_values(&OpendalStore::new(op))
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn filesystem_set_partial_values() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let builder = opendal::services::Fs::default().root(&path.path().to_string_lossy());
+        let op = Operator::new(builder)?.finish().blocking();
+        assert!(op.info().full_capability().write_can_append);
+        let store = OpendalStore::new(op);
+        assert!(store.capabilities().supports_partial_write());
+        assert!(store.supports_set_partial());
+        set_partial_values(&store)
+    }
 }
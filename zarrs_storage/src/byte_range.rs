@@ -8,6 +8,7 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
     io::{Read, Seek, SeekFrom},
+    num::NonZeroUsize,
     ops::{
         Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
     },
@@ -175,15 +176,35 @@ impl InvalidByteRangeError {
     pub fn new(byte_range: ByteRange, bytes_len: u64) -> Self {
         Self(byte_range, bytes_len)
     }
+
+    /// Return the invalid byte range.
+    #[must_use]
+    pub fn byte_range(&self) -> ByteRange {
+        self.0
+    }
+
+    /// Return the length of the bytes.
+    #[must_use]
+    pub fn bytes_len(&self) -> u64 {
+        self.1
+    }
 }
 
-fn validate_byte_ranges(
+/// Validate that `byte_ranges` are within bytes of length `bytes_len`.
+///
+/// A zero-length byte range is valid if it starts at or before the end of the bytes.
+///
+/// # Errors
+/// Returns [`InvalidByteRangeError`] for the first byte range that requests bytes beyond the end of the bytes.
+pub fn validate_byte_ranges(
     byte_ranges: &[ByteRange],
     bytes_len: u64,
 ) -> Result<(), InvalidByteRangeError> {
     for byte_range in byte_ranges {
         let valid = match byte_range {
-            ByteRange::FromStart(offset, length) => offset + length.unwrap_or(0) <= bytes_len,
+            ByteRange::FromStart(offset, length) => offset
+                .checked_add(length.unwrap_or(0))
+                .is_some_and(|end| end <= bytes_len),
             ByteRange::Suffix(length) => *length <= bytes_len,
         };
         if !valid {
@@ -288,7 +309,7 @@ pub unsafe fn extract_byte_ranges_concat_unchecked(
 /// # Errors
 ///
 /// Returns a [`std::io::Error`] if there is an error reading or seeking from `bytes`.
-/// This can occur if the byte range is out-of-bounds of the `bytes`.
+/// An [`InvalidByteRangeError`] is returned as a [`std::io::ErrorKind::InvalidInput`] error if a byte range is out-of-bounds of the `bytes`.
 ///
 /// # Panics
 ///
@@ -298,31 +319,13 @@ pub fn extract_byte_ranges_read_seek<T: Read + Seek>(
     byte_ranges: &[ByteRange],
 ) -> std::io::Result<Vec<Vec<u8>>> {
     let len: u64 = bytes.seek(SeekFrom::End(0))?;
+    validate_byte_ranges(byte_ranges, len)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
     let mut out = Vec::with_capacity(byte_ranges.len());
     for byte_range in byte_ranges {
-        let data: Vec<u8> = match byte_range {
-            ByteRange::FromStart(offset, None) => {
-                bytes.seek(SeekFrom::Start(*offset))?;
-                let length = usize::try_from(len).unwrap();
-                let mut data = vec![0; length];
-                bytes.read_exact(&mut data)?;
-                data
-            }
-            ByteRange::FromStart(offset, Some(length)) => {
-                bytes.seek(SeekFrom::Start(*offset))?;
-                let length = usize::try_from(*length).unwrap();
-                let mut data = vec![0; length];
-                bytes.read_exact(&mut data)?;
-                data
-            }
-            ByteRange::Suffix(length) => {
-                bytes.seek(SeekFrom::End(-i64::try_from(*length).unwrap()))?;
-                let length = usize::try_from(*length).unwrap();
-                let mut data = vec![0; length];
-                bytes.read_exact(&mut data)?;
-                data
-            }
-        };
+        bytes.seek(SeekFrom::Start(byte_range.start(len)))?;
+        let mut data = vec![0; usize::try_from(byte_range.length(len)).unwrap()];
+        bytes.read_exact(&mut data)?;
         out.push(data);
     }
     Ok(out)
@@ -333,7 +336,7 @@ pub fn extract_byte_ranges_read_seek<T: Read + Seek>(
 /// # Errors
 ///
 /// Returns a [`std::io::Error`] if there is an error reading from `bytes`.
-/// This can occur if the byte range is out-of-bounds of the `bytes`.
+/// An [`InvalidByteRangeError`] is returned as a [`std::io::ErrorKind::InvalidInput`] error if a byte range is out-of-bounds of `size`.
 ///
 /// # Panics
 ///
@@ -343,6 +346,9 @@ pub fn extract_byte_ranges_read<T: Read>(
     size: u64,
     byte_ranges: &[ByteRange],
 ) -> std::io::Result<Vec<Vec<u8>>> {
+    validate_byte_ranges(byte_ranges, size)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+
     // Could this be cleaner/more efficient?
 
     // Allocate output and find the endpoints of the "segments" of bytes which must be read
@@ -406,10 +412,111 @@ pub fn extract_byte_ranges_read<T: Read>(
     Ok(out)
 }
 
+/// A hint for the maximum number of byte ranges of a value retrieved with a single request.
+///
+/// See [`ReadableStorageTraits::get_partial_values_key_batched`](crate::ReadableStorageTraits::get_partial_values_key_batched).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum MaxBatch {
+    /// All byte ranges can be retrieved with a single request.
+    #[default]
+    Unlimited,
+    /// At most this many byte ranges are retrieved with a single request.
+    Limit(NonZeroUsize),
+}
+
+impl MaxBatch {
+    /// Split `byte_ranges` into batches with at most the maximum number of byte ranges.
+    ///
+    /// There is always at least one batch, which is empty if `byte_ranges` is empty.
+    #[must_use]
+    pub fn batches<'a>(&self, byte_ranges: &'a [ByteRange]) -> Vec<&'a [ByteRange]> {
+        match self {
+            Self::Limit(limit) if !byte_ranges.is_empty() => {
+                byte_ranges.chunks(limit.get()).collect()
+            }
+            _ => vec![byte_ranges],
+        }
+    }
+}
+
+/// Coalesce byte ranges separated by a gap of at most `gap` bytes.
+///
+/// Returns the coalesced byte ranges and, for each byte range in `byte_ranges`, the index of the coalesced byte range containing it and its byte range relative to the start of that coalesced byte range.
+/// Only bounded [`ByteRange::FromStart`] byte ranges are coalesced, others are passed through unchanged.
+#[must_use]
+pub fn coalesce_byte_ranges(
+    byte_ranges: &[ByteRange],
+    gap: ByteLength,
+) -> (Vec<ByteRange>, Vec<(usize, ByteRange)>) {
+    let mut coalesced: Vec<(ByteOffset, ByteOffset)> = Vec::new();
+    let mut mapping = vec![(0, ByteRange::FromStart(0, None)); byte_ranges.len()];
+
+    let bounded = byte_ranges
+        .iter()
+        .enumerate()
+        .filter_map(|(i, byte_range)| match byte_range {
+            ByteRange::FromStart(offset, Some(length)) => Some((i, *offset, offset + length)),
+            _ => None,
+        })
+        .sorted_by_key(|(_, start, end)| (*start, *end));
+    for (i, start, end) in bounded {
+        let num_coalesced = coalesced.len();
+        match coalesced.last_mut() {
+            Some((coalesced_start, coalesced_end))
+                if start <= coalesced_end.saturating_add(gap) =>
+            {
+                *coalesced_end = std::cmp::max(*coalesced_end, end);
+                mapping[i] = (
+                    num_coalesced - 1,
+                    ByteRange::FromStart(start - *coalesced_start, Some(end - start)),
+                );
+            }
+            _ => {
+                mapping[i] = (num_coalesced, ByteRange::FromStart(0, Some(end - start)));
+                coalesced.push((start, end));
+            }
+        }
+    }
+    let mut coalesced: Vec<ByteRange> = coalesced
+        .into_iter()
+        .map(|(start, end)| ByteRange::FromStart(start, Some(end - start)))
+        .collect();
+
+    for (i, byte_range) in byte_ranges.iter().enumerate() {
+        if !matches!(byte_range, ByteRange::FromStart(_, Some(_))) {
+            mapping[i] = (coalesced.len(), ByteRange::FromStart(0, None));
+            coalesced.push(*byte_range);
+        }
+    }
+
+    (coalesced, mapping)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn max_batch() {
+        let byte_ranges = [
+            ByteRange::FromStart(0, Some(1)),
+            ByteRange::FromStart(1, Some(1)),
+            ByteRange::FromStart(2, Some(1)),
+            ByteRange::Suffix(1),
+            ByteRange::FromStart(4, None),
+        ];
+        assert_eq!(
+            MaxBatch::Unlimited.batches(&byte_ranges),
+            vec![&byte_ranges]
+        );
+        let max_batch = MaxBatch::Limit(NonZeroUsize::new(2).unwrap());
+        assert_eq!(
+            max_batch.batches(&byte_ranges),
+            vec![&byte_ranges[0..2], &byte_ranges[2..4], &byte_ranges[4..5]]
+        );
+        assert_eq!(max_batch.batches(&[]), vec![&[] as &[ByteRange]]);
+    }
+
     #[test]
     fn byte_ranges() {
         let byte_range = ByteRange::FromStart(1, None);
@@ -475,4 +582,50 @@ mod tests {
             vec![vec![3, 4, 5], vec![4], vec![1], vec![5, 6, 7, 8, 9]]
         );
     }
+
+    #[test]
+    fn byte_ranges_coalesce() {
+        let byte_ranges = vec![
+            ByteRange::FromStart(10, Some(2)),
+            ByteRange::FromStart(0, Some(3)),
+            ByteRange::Suffix(2),
+            ByteRange::FromStart(4, Some(2)),
+            ByteRange::FromStart(5, None),
+            ByteRange::FromStart(1, Some(1)),
+        ];
+        let (coalesced, mapping) = coalesce_byte_ranges(&byte_ranges, 1);
+        assert_eq!(
+            coalesced,
+            vec![
+                ByteRange::FromStart(0, Some(6)),
+                ByteRange::FromStart(10, Some(2)),
+                ByteRange::Suffix(2),
+                ByteRange::FromStart(5, None),
+            ]
+        );
+        assert_eq!(
+            mapping,
+            vec![
+                (1, ByteRange::FromStart(0, Some(2))),
+                (0, ByteRange::FromStart(0, Some(3))),
+                (2, ByteRange::FromStart(0, None)),
+                (0, ByteRange::FromStart(4, Some(2))),
+                (3, ByteRange::FromStart(0, None)),
+                (0, ByteRange::FromStart(1, Some(1))),
+            ]
+        );
+
+        let data: Vec<u8> = (0..16).collect();
+        let coalesced_bytes = extract_byte_ranges(&data, &coalesced).unwrap();
+        for (byte_range, (index, relative_byte_range)) in byte_ranges.iter().zip(mapping) {
+            let bytes = &coalesced_bytes[index];
+            assert_eq!(
+                &bytes[relative_byte_range.to_range_usize(bytes.len() as u64)],
+                &data[byte_range.to_range_usize(data.len() as u64)]
+            );
+        }
+
+        let (coalesced, _) = coalesce_byte_ranges(&byte_ranges, 0);
+        assert_eq!(coalesced.len(), 5);
+    }
 }
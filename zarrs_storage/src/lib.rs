@@ -17,8 +17,10 @@ mod storage_handle;
 mod storage_sync;
 mod storage_value_io;
 pub mod store;
+mod store_capabilities;
 mod store_key;
 mod store_prefix;
+mod value_digest;
 
 pub mod byte_range;
 use byte_range::{ByteOffset, ByteRange, InvalidByteRangeError};
@@ -34,6 +36,7 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
+pub use store_capabilities::StoreCapabilities;
 pub use store_key::{StoreKey, StoreKeyError, StoreKeys};
 pub use store_prefix::{StorePrefix, StorePrefixError, StorePrefixes};
 
@@ -55,6 +58,8 @@ pub use self::storage_handle::StorageHandle;
 
 pub use storage_value_io::StorageValueIO;
 
+pub use value_digest::ValueDigestTraits;
+
 /// [`Arc`] wrapped readable storage.
 pub type ReadableStorage = Arc<dyn ReadableStorageTraits>;
 
@@ -133,6 +138,18 @@ impl StoreKeyRange {
     pub const fn new(key: StoreKey, byte_range: ByteRange) -> Self {
         Self { key, byte_range }
     }
+
+    /// Get the key.
+    #[must_use]
+    pub const fn key(&self) -> &StoreKey {
+        &self.key
+    }
+
+    /// Get the byte range.
+    #[must_use]
+    pub const fn byte_range(&self) -> &ByteRange {
+        &self.byte_range
+    }
 }
 
 impl std::fmt::Display for StoreKeyRange {
@@ -245,6 +262,9 @@ pub enum StorageError {
     /// Unknown key size where the key size must be known.
     #[error("{0}")]
     UnknownKeySize(StoreKey),
+    /// A conditional write failed because its precondition was not met (e.g. the key already exists or was modified).
+    #[error("the precondition of a conditional write to {0} was not met")]
+    PreconditionFailed(StoreKey),
     /// Any other error.
     #[error("{0}")]
     Other(String),
@@ -6,4 +6,5 @@
 pub mod async_to_sync;
 
 pub mod performance_metrics;
+pub mod read_only;
 pub mod usage_log;
@@ -3,14 +3,30 @@
 //! This adapter has footguns, see [`AsyncToSyncStorageAdapter`].
 //!
 //! The docs for the [`AsyncToSyncBlockOn`] trait include an example implementation for the `tokio` runtime.
+//! With the `tokio` feature, `TokioBlockOn` is a ready-made implementation with a configurable `TokioBlockOnStrategy`.
 
 use crate::{
-    byte_range::ByteRange, AsyncListableStorageTraits, AsyncReadableStorageTraits,
-    AsyncWritableStorageTraits, Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError,
-    StoreKey, StoreKeys, StoreKeysPrefixes, StorePrefix, WritableStorageTraits,
+    byte_range::{ByteRange, MaxBatch},
+    AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits, Bytes,
+    ListableStorageTraits, ReadableStorageTraits, StorageError, StoreCapabilities, StoreKey,
+    StoreKeys, StoreKeysPrefixes, StorePrefix, WritableStorageTraits,
 };
 
-use std::sync::Arc;
+use futures::future::Either;
+use parking_lot::Mutex;
+
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    sync::{mpsc, Arc},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+#[cfg(feature = "tokio")]
+mod tokio_block_on;
+#[cfg(feature = "tokio")]
+pub use tokio_block_on::{TokioBlockOn, TokioBlockOnStrategy};
 
 /// Trait for an asynchronous runtime implementing `block_on`.
 ///
@@ -35,21 +51,115 @@ pub trait AsyncToSyncBlockOn: Send + Sync {
 /// Incompatibility may result in runtime errors. For example:
 /// > there is no reactor running, must be called from the context of a Tokio 1.x runtime
 ///
-/// An [`AsyncToSyncStorageAdapter`] will panic if called within an asynchronous execution context!
+/// Whether an [`AsyncToSyncStorageAdapter`] can be called within an asynchronous execution context depends on the [`AsyncToSyncBlockOn`] implementation.
+/// A naive implementation (like the example on [`AsyncToSyncBlockOn`]) will panic or deadlock, whereas `TokioBlockOn` (with the `tokio` feature) supports this.
 pub struct AsyncToSyncStorageAdapter<TStorage: ?Sized, TBlockOn: AsyncToSyncBlockOn> {
     storage: Arc<TStorage>,
     block_on: TBlockOn,
+    options: AsyncToSyncStorageAdapterOptions,
+}
+
+/// Options for use with [`AsyncToSyncStorageAdapter`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub struct AsyncToSyncStorageAdapterOptions {
+    timeout: Option<Duration>,
+}
+
+impl AsyncToSyncStorageAdapterOptions {
+    /// Set the timeout of each storage operation. Defaults to [`None`] (no timeout).
+    ///
+    /// An operation that does not complete within the timeout is abandoned and fails with `StorageError::Other("timeout")`.
+    /// The timeout is runtime-agnostic, and is implemented with a helper thread per operation.
+    pub fn timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
 }
 
 impl<TStorage: ?Sized, TBlockOn: AsyncToSyncBlockOn> AsyncToSyncStorageAdapter<TStorage, TBlockOn> {
     /// Create a new async to sync storage adapter.
     #[must_use]
     pub fn new(storage: Arc<TStorage>, block_on: TBlockOn) -> Self {
-        Self { storage, block_on }
+        Self {
+            storage,
+            block_on,
+            options: AsyncToSyncStorageAdapterOptions::default(),
+        }
+    }
+
+    /// Set the options of the async to sync storage adapter.
+    #[must_use]
+    pub fn with_options(mut self, options: AsyncToSyncStorageAdapterOptions) -> Self {
+        self.options = options;
+        self
     }
 
-    fn block_on<F: core::future::Future>(&self, future: F) -> F::Output {
-        self.block_on.block_on(future)
+    fn block_on<T, F: Future<Output = Result<T, StorageError>>>(
+        &self,
+        future: F,
+    ) -> Result<T, StorageError> {
+        if let Some(timeout) = self.options.timeout {
+            self.block_on.block_on(async move {
+                match futures::future::select(pin!(future), Timeout::new(timeout)).await {
+                    Either::Left((result, _)) => result,
+                    Either::Right(((), _)) => Err(StorageError::Other("timeout".to_string())),
+                }
+            })
+        } else {
+            self.block_on.block_on(future)
+        }
+    }
+}
+
+#[derive(Default)]
+struct TimeoutState {
+    elapsed: bool,
+    waker: Option<Waker>,
+}
+
+/// A runtime-agnostic future that resolves after a duration.
+///
+/// The duration is awaited on a helper thread, which exits early if the future is dropped.
+struct Timeout {
+    state: Arc<Mutex<TimeoutState>>,
+    _cancel: mpsc::Sender<()>,
+}
+
+impl Timeout {
+    fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new(TimeoutState::default()));
+        let (cancel, cancelled) = mpsc::channel::<()>();
+        std::thread::spawn({
+            let state = state.clone();
+            move || {
+                if let Err(mpsc::RecvTimeoutError::Timeout) = cancelled.recv_timeout(duration) {
+                    let mut state = state.lock();
+                    state.elapsed = true;
+                    if let Some(waker) = state.waker.take() {
+                        waker.wake();
+                    }
+                }
+            }
+        });
+        Self {
+            state,
+            _cancel: cancel,
+        }
+    }
+}
+
+impl Future for Timeout {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock();
+        if state.elapsed {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
     }
 }
 
@@ -64,9 +174,25 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits, TBlockOn: AsyncToSyncBlockOn
         self.block_on(self.storage.get_partial_values_key(key, byte_ranges))
     }
 
+    fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.block_on(
+            self.storage
+                .get_partial_values_key_batched(key, byte_ranges, max_batch),
+        )
+    }
+
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
         self.block_on(self.storage.size_key(key))
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage.capabilities()
+    }
 }
 
 impl<TStorage: ?Sized + AsyncListableStorageTraits, TBlockOn: AsyncToSyncBlockOn>
@@ -103,6 +229,14 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits, TBlockOn: AsyncToSyncBlockOn
         self.block_on(self.storage.set_partial_values(key_offset_values))
     }
 
+    fn set_many(&self, key_values: &[(StoreKey, Bytes)]) -> Result<(), StorageError> {
+        self.block_on(self.storage.set_many(key_values))
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.storage.supports_set_partial()
+    }
+
     fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
         self.block_on(self.storage.erase(key))
     }
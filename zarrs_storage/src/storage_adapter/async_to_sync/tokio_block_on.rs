@@ -0,0 +1,106 @@
+use std::future::Future;
+
+use tokio::runtime::{Handle, Runtime, RuntimeFlavor};
+
+use super::AsyncToSyncBlockOn;
+
+/// The execution strategy of a [`TokioBlockOn`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TokioBlockOnStrategy {
+    /// Use [`BlockInPlace`](TokioBlockOnStrategy::BlockInPlace) if called within a multi-thread `tokio` runtime, otherwise use [`DedicatedRuntime`](TokioBlockOnStrategy::DedicatedRuntime).
+    #[default]
+    Auto,
+    /// Run futures on the runtime owned by the [`TokioBlockOn`].
+    ///
+    /// If called within an asynchronous execution context, the calling thread is blocked while the future runs.
+    DedicatedRuntime,
+    /// Run futures on the current runtime with [`tokio::task::block_in_place`].
+    ///
+    /// Other tasks on the current worker thread are moved to another worker thread while the future runs.
+    /// Falls back to [`DedicatedRuntime`](TokioBlockOnStrategy::DedicatedRuntime) if not called within a `tokio` runtime.
+    ///
+    /// # Panics
+    /// Panics if called within a current-thread `tokio` runtime.
+    BlockInPlace,
+}
+
+/// A `tokio` implementation of [`AsyncToSyncBlockOn`] that can be called from within or outside of a `tokio` runtime.
+///
+/// A [`TokioBlockOn`] owns a dedicated multi-thread runtime, which is used outside of a `tokio` runtime and by the [`TokioBlockOnStrategy::DedicatedRuntime`] strategy.
+#[derive(Debug)]
+pub struct TokioBlockOn {
+    runtime: Option<Runtime>,
+    strategy: TokioBlockOnStrategy,
+}
+
+impl TokioBlockOn {
+    /// Create a new [`TokioBlockOn`] with the [`TokioBlockOnStrategy::Auto`] strategy.
+    ///
+    /// # Errors
+    /// Returns an error if the dedicated runtime cannot be created.
+    pub fn new() -> std::io::Result<Self> {
+        Self::with_strategy(TokioBlockOnStrategy::Auto)
+    }
+
+    /// Create a new [`TokioBlockOn`] with an explicit `strategy`.
+    ///
+    /// # Errors
+    /// Returns an error if the dedicated runtime cannot be created.
+    pub fn with_strategy(strategy: TokioBlockOnStrategy) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            runtime: Some(runtime),
+            strategy,
+        })
+    }
+
+    /// Return the execution strategy.
+    #[must_use]
+    pub fn strategy(&self) -> TokioBlockOnStrategy {
+        self.strategy
+    }
+
+    fn runtime(&self) -> &Runtime {
+        self.runtime
+            .as_ref()
+            .expect("the runtime is only taken on drop")
+    }
+
+    fn block_on_dedicated<F: Future>(&self, future: F) -> F::Output {
+        let runtime = self.runtime();
+        if Handle::try_current().is_ok() {
+            // Runtime::block_on panics within a runtime, so drive the future on the calling thread instead
+            let _guard = runtime.enter();
+            futures::executor::block_on(future)
+        } else {
+            runtime.block_on(future)
+        }
+    }
+}
+
+impl AsyncToSyncBlockOn for TokioBlockOn {
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        match (self.strategy, Handle::try_current()) {
+            (TokioBlockOnStrategy::Auto, Ok(handle))
+                if handle.runtime_flavor() == RuntimeFlavor::MultiThread =>
+            {
+                tokio::task::block_in_place(|| handle.block_on(future))
+            }
+            (TokioBlockOnStrategy::BlockInPlace, Ok(handle)) => {
+                tokio::task::block_in_place(|| handle.block_on(future))
+            }
+            _ => self.block_on_dedicated(future),
+        }
+    }
+}
+
+impl Drop for TokioBlockOn {
+    fn drop(&mut self) {
+        // Dropping a runtime within an asynchronous execution context panics
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
@@ -1,9 +1,10 @@
 //! A storage transformer which records performance metrics.
 
 use crate::{
-    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError, StoreKey,
-    StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes, StorePrefix,
-    WritableStorageTraits,
+    byte_range::{ByteRange, MaxBatch},
+    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes,
+    StorePrefix, WritableStorageTraits,
 };
 
 #[cfg(feature = "async")]
@@ -12,11 +13,76 @@ use crate::{
     MaybeAsyncBytes,
 };
 
-use std::sync::{
-    atomic::{AtomicUsize, Ordering},
-    Arc,
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
+/// A kind of storage request, corresponding to a storage trait method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum StorageRequestKind {
+    /// A `get` request.
+    Get,
+    /// A `get_partial_values_key` request.
+    GetPartialValuesKey,
+    /// A `get_partial_values_key_batched` request.
+    GetPartialValuesKeyBatched,
+    /// A `get_partial_values` request.
+    GetPartialValues,
+    /// A `size_key` request.
+    SizeKey,
+    /// A `digest_key` request.
+    DigestKey,
+    /// A `set` request.
+    Set,
+    /// A `set_partial_values` request.
+    SetPartialValues,
+    /// A `set_many` request.
+    SetMany,
+    /// An `erase` request.
+    Erase,
+    /// An `erase_values` request.
+    EraseValues,
+    /// An `erase_prefix` request.
+    ErasePrefix,
+    /// A `list` request.
+    List,
+    /// A `list_prefix` request.
+    ListPrefix,
+    /// A `list_dir` request.
+    ListDir,
+    /// A `size` request.
+    Size,
+    /// A `size_prefix` request.
+    SizePrefix,
+}
+
+impl StorageRequestKind {
+    const ALL: [Self; 17] = [
+        Self::Get,
+        Self::GetPartialValuesKey,
+        Self::GetPartialValuesKeyBatched,
+        Self::GetPartialValues,
+        Self::SizeKey,
+        Self::DigestKey,
+        Self::Set,
+        Self::SetPartialValues,
+        Self::SetMany,
+        Self::Erase,
+        Self::EraseValues,
+        Self::ErasePrefix,
+        Self::List,
+        Self::ListPrefix,
+        Self::ListDir,
+        Self::Size,
+        Self::SizePrefix,
+    ];
+}
+
 /// The performance metrics storage transformer. Accumulates metrics, such as bytes read and written.
 ///
 /// It is intended to aid in testing by allowing the application to validate that metrics (e.g., bytes read/written, total read/write operations) match expected values for specific operations.
@@ -28,6 +94,7 @@ pub struct PerformanceMetricsStorageAdapter<TStorage: ?Sized> {
     reads: AtomicUsize,
     writes: AtomicUsize,
     keys_erased: AtomicUsize,
+    requests: [AtomicUsize; StorageRequestKind::ALL.len()],
 }
 
 impl<TStorage: ?Sized> PerformanceMetricsStorageAdapter<TStorage> {
@@ -41,6 +108,7 @@ impl<TStorage: ?Sized> PerformanceMetricsStorageAdapter<TStorage> {
             reads: AtomicUsize::default(),
             writes: AtomicUsize::default(),
             keys_erased: AtomicUsize::default(),
+            requests: Default::default(),
         }
     }
 
@@ -50,6 +118,10 @@ impl<TStorage: ?Sized> PerformanceMetricsStorageAdapter<TStorage> {
         self.bytes_written.store(0, Ordering::Relaxed);
         self.reads.store(0, Ordering::Relaxed);
         self.writes.store(0, Ordering::Relaxed);
+        self.keys_erased.store(0, Ordering::Relaxed);
+        for requests in &self.requests {
+            requests.store(0, Ordering::Relaxed);
+        }
     }
 
     /// Returns the number of bytes read.
@@ -78,12 +150,28 @@ impl<TStorage: ?Sized> PerformanceMetricsStorageAdapter<TStorage> {
     pub fn keys_erased(&self) -> usize {
         self.keys_erased.load(Ordering::Relaxed)
     }
+
+    /// Returns the number of requests of each [`StorageRequestKind`].
+    ///
+    /// Each call to a storage method counts as a single request, regardless of the number of keys or byte ranges.
+    pub fn requests_by_kind(&self) -> BTreeMap<StorageRequestKind, usize> {
+        StorageRequestKind::ALL
+            .into_iter()
+            .zip(&self.requests)
+            .map(|(kind, requests)| (kind, requests.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    fn request(&self, kind: StorageRequestKind) {
+        self.requests[kind as usize].fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
     for PerformanceMetricsStorageAdapter<TStorage>
 {
     fn get(&self, key: &StoreKey) -> Result<MaybeBytes, StorageError> {
+        self.request(StorageRequestKind::Get);
         let value = self.storage.get(key);
         let bytes_read = value
             .as_ref()
@@ -96,8 +184,9 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
     fn get_partial_values_key(
         &self,
         key: &StoreKey,
-        byte_ranges: &[crate::byte_range::ByteRange],
+        byte_ranges: &[ByteRange],
     ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.request(StorageRequestKind::GetPartialValuesKey);
         let values = self.storage.get_partial_values_key(key, byte_ranges)?;
         if let Some(values) = &values {
             let bytes_read = values.iter().map(Bytes::len).sum();
@@ -107,10 +196,29 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
         Ok(values)
     }
 
+    fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.request(StorageRequestKind::GetPartialValuesKeyBatched);
+        let values = self
+            .storage
+            .get_partial_values_key_batched(key, byte_ranges, max_batch)?;
+        if let Some(values) = &values {
+            let bytes_read = values.iter().map(Bytes::len).sum();
+            self.bytes_read.fetch_add(bytes_read, Ordering::Relaxed);
+        }
+        self.reads.fetch_add(byte_ranges.len(), Ordering::Relaxed);
+        Ok(values)
+    }
+
     fn get_partial_values(
         &self,
         key_ranges: &[StoreKeyRange],
     ) -> Result<Vec<MaybeBytes>, StorageError> {
+        self.request(StorageRequestKind::GetPartialValues);
         let values = self.storage.get_partial_values(key_ranges)?;
         let bytes_read = values
             .iter()
@@ -122,30 +230,45 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
     }
 
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.request(StorageRequestKind::SizeKey);
         self.storage.size_key(key)
     }
+
+    fn digest_key(&self, key: &StoreKey, algorithm: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        self.request(StorageRequestKind::DigestKey);
+        self.storage.digest_key(key, algorithm)
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage.capabilities()
+    }
 }
 
 impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
     for PerformanceMetricsStorageAdapter<TStorage>
 {
     fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.request(StorageRequestKind::List);
         self.storage.list()
     }
 
     fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.request(StorageRequestKind::ListPrefix);
         self.storage.list_prefix(prefix)
     }
 
     fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.request(StorageRequestKind::ListDir);
         self.storage.list_dir(prefix)
     }
 
     fn size(&self) -> Result<u64, StorageError> {
+        self.request(StorageRequestKind::Size);
         self.storage.size()
     }
 
     fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.request(StorageRequestKind::SizePrefix);
         self.storage.size_prefix(prefix)
     }
 }
@@ -154,6 +277,7 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
     for PerformanceMetricsStorageAdapter<TStorage>
 {
     fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::Set);
         self.bytes_written.fetch_add(value.len(), Ordering::Relaxed);
         self.writes.fetch_add(1, Ordering::Relaxed);
         self.storage.set(key, value)
@@ -163,6 +287,7 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
     ) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::SetPartialValues);
         let bytes_written = key_offset_values
             .iter()
             .map(|ksv| ksv.value().len())
@@ -174,17 +299,36 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
         self.storage.set_partial_values(key_offset_values)
     }
 
+    fn set_many(&self, key_values: &[(StoreKey, Bytes)]) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::SetMany);
+        let bytes_written = key_values
+            .iter()
+            .map(|(_, value)| value.len())
+            .sum::<usize>();
+        self.bytes_written
+            .fetch_add(bytes_written, Ordering::Relaxed);
+        self.writes.fetch_add(key_values.len(), Ordering::Relaxed);
+        self.storage.set_many(key_values)
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.storage.supports_set_partial()
+    }
+
     fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::Erase);
         self.keys_erased.fetch_add(1, Ordering::Relaxed);
         self.storage.erase(key)
     }
 
     fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::EraseValues);
         self.keys_erased.fetch_add(keys.len(), Ordering::Relaxed);
         self.storage.erase_values(keys)
     }
 
     fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::ErasePrefix);
         self.storage.erase_prefix(prefix)
     }
 }
@@ -195,6 +339,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
     for PerformanceMetricsStorageAdapter<TStorage>
 {
     async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
+        self.request(StorageRequestKind::Get);
         let value = self.storage.get(key).await;
         let bytes_read = value
             .as_ref()
@@ -207,8 +352,9 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
     async fn get_partial_values_key(
         &self,
         key: &StoreKey,
-        byte_ranges: &[crate::byte_range::ByteRange],
+        byte_ranges: &[ByteRange],
     ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.request(StorageRequestKind::GetPartialValuesKey);
         let values = self
             .storage
             .get_partial_values_key(key, byte_ranges)
@@ -216,8 +362,27 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
         if let Some(values) = &values {
             let bytes_read = values.iter().map(AsyncBytes::len).sum();
             self.bytes_read.fetch_add(bytes_read, Ordering::Relaxed);
-            self.reads.fetch_add(byte_ranges.len(), Ordering::Relaxed);
         }
+        self.reads.fetch_add(byte_ranges.len(), Ordering::Relaxed);
+        Ok(values)
+    }
+
+    async fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.request(StorageRequestKind::GetPartialValuesKeyBatched);
+        let values = self
+            .storage
+            .get_partial_values_key_batched(key, byte_ranges, max_batch)
+            .await?;
+        if let Some(values) = &values {
+            let bytes_read = values.iter().map(AsyncBytes::len).sum();
+            self.bytes_read.fetch_add(bytes_read, Ordering::Relaxed);
+        }
+        self.reads.fetch_add(byte_ranges.len(), Ordering::Relaxed);
         Ok(values)
     }
 
@@ -225,6 +390,7 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
         &self,
         key_ranges: &[StoreKeyRange],
     ) -> Result<Vec<MaybeAsyncBytes>, StorageError> {
+        self.request(StorageRequestKind::GetPartialValues);
         let values = self.storage.get_partial_values(key_ranges).await?;
         let bytes_read = values
             .iter()
@@ -236,8 +402,13 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
     }
 
     async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.request(StorageRequestKind::SizeKey);
         self.storage.size_key(key).await
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage.capabilities()
+    }
 }
 
 #[cfg(feature = "async")]
@@ -246,22 +417,27 @@ impl<TStorage: ?Sized + AsyncListableStorageTraits> AsyncListableStorageTraits
     for PerformanceMetricsStorageAdapter<TStorage>
 {
     async fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.request(StorageRequestKind::List);
         self.storage.list().await
     }
 
     async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.request(StorageRequestKind::ListPrefix);
         self.storage.list_prefix(prefix).await
     }
 
     async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.request(StorageRequestKind::ListDir);
         self.storage.list_dir(prefix).await
     }
 
     async fn size(&self) -> Result<u64, StorageError> {
+        self.request(StorageRequestKind::Size);
         self.storage.size().await
     }
 
     async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.request(StorageRequestKind::SizePrefix);
         self.storage.size_prefix(prefix).await
     }
 }
@@ -272,6 +448,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
     for PerformanceMetricsStorageAdapter<TStorage>
 {
     async fn set(&self, key: &StoreKey, value: AsyncBytes) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::Set);
         self.bytes_written.fetch_add(value.len(), Ordering::Relaxed);
         self.writes.fetch_add(1, Ordering::Relaxed);
         self.storage.set(key, value).await
@@ -281,6 +458,7 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
     ) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::SetPartialValues);
         let bytes_written = key_offset_values
             .iter()
             .map(|ksv| ksv.value().len())
@@ -292,15 +470,36 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         self.storage.set_partial_values(key_offset_values).await
     }
 
+    async fn set_many(&self, key_values: &[(StoreKey, AsyncBytes)]) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::SetMany);
+        let bytes_written = key_values
+            .iter()
+            .map(|(_, value)| value.len())
+            .sum::<usize>();
+        self.bytes_written
+            .fetch_add(bytes_written, Ordering::Relaxed);
+        self.writes.fetch_add(key_values.len(), Ordering::Relaxed);
+        self.storage.set_many(key_values).await
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.storage.supports_set_partial()
+    }
+
     async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::Erase);
+        self.keys_erased.fetch_add(1, Ordering::Relaxed);
         self.storage.erase(key).await
     }
 
     async fn erase_values(&self, keys: &[StoreKey]) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::EraseValues);
+        self.keys_erased.fetch_add(keys.len(), Ordering::Relaxed);
         self.storage.erase_values(keys).await
     }
 
     async fn erase_prefix(&self, prefix: &StorePrefix) -> Result<(), StorageError> {
+        self.request(StorageRequestKind::ErasePrefix);
         self.storage.erase_prefix(prefix).await
     }
 }
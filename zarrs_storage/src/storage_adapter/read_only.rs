@@ -0,0 +1,271 @@
+//! A storage adapter which prevents writes.
+
+use crate::{
+    byte_range::{ByteRange, MaxBatch},
+    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes,
+    StorePrefix, WritableStorageTraits,
+};
+
+#[cfg(feature = "async")]
+use crate::{
+    AsyncBytes, AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits,
+    MaybeAsyncBytes,
+};
+
+use std::sync::Arc;
+
+/// The read only storage adapter. Fences writes to an otherwise writable store.
+///
+/// Readable and listable storage methods are delegated to the underlying storage.
+/// Writable storage methods return [`StorageError::ReadOnly`] without touching the underlying storage.
+///
+/// It is intended for publication workflows, where a finalised hierarchy must not be mutated by the process even though the underlying store is writable.
+///
+/// ### Example
+/// ```rust
+/// # use std::sync::Arc;
+/// # use zarrs_storage::{StorageError, WritableStorageTraits};
+/// # use zarrs_storage::store::MemoryStore;
+/// # use zarrs_storage::storage_adapter::read_only::ReadOnlyStorageAdapter;
+/// let store = Arc::new(MemoryStore::new());
+/// let store = Arc::new(ReadOnlyStorageAdapter::new(store));
+/// assert!(matches!(
+///     store.set(&"key".try_into()?, vec![0].into()),
+///     Err(StorageError::ReadOnly)
+/// ));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct ReadOnlyStorageAdapter<TStorage: ?Sized> {
+    storage: Arc<TStorage>,
+}
+
+impl<TStorage: ?Sized> ReadOnlyStorageAdapter<TStorage> {
+    /// Create a new read only storage adapter.
+    #[must_use]
+    pub fn new(storage: Arc<TStorage>) -> Self {
+        Self { storage }
+    }
+
+    /// Returns the underlying storage.
+    #[must_use]
+    pub fn storage(&self) -> &Arc<TStorage> {
+        &self.storage
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
+    for ReadOnlyStorageAdapter<TStorage>
+{
+    fn get(&self, key: &StoreKey) -> Result<MaybeBytes, StorageError> {
+        self.storage.get(key)
+    }
+
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.storage.get_partial_values_key(key, byte_ranges)
+    }
+
+    fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.storage
+            .get_partial_values_key_batched(key, byte_ranges, max_batch)
+    }
+
+    fn get_partial_values(
+        &self,
+        key_ranges: &[StoreKeyRange],
+    ) -> Result<Vec<MaybeBytes>, StorageError> {
+        self.storage.get_partial_values(key_ranges)
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.storage.size_key(key)
+    }
+
+    fn digest_key(&self, key: &StoreKey, algorithm: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        self.storage.digest_key(key, algorithm)
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage
+            .capabilities()
+            .with_writable(false)
+            .with_partial_write(false)
+    }
+}
+
+impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
+    for ReadOnlyStorageAdapter<TStorage>
+{
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.storage.list()
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.storage.list_prefix(prefix)
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.storage.list_dir(prefix)
+    }
+
+    fn size(&self) -> Result<u64, StorageError> {
+        self.storage.size()
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.storage.size_prefix(prefix)
+    }
+}
+
+impl<TStorage: ?Sized + Send + Sync> WritableStorageTraits for ReadOnlyStorageAdapter<TStorage> {
+    fn set(&self, _key: &StoreKey, _value: Bytes) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    fn set_partial_values(
+        &self,
+        _key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    fn set_many(&self, _key_values: &[(StoreKey, Bytes)]) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        false
+    }
+
+    fn erase(&self, _key: &StoreKey) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    fn erase_values(&self, _keys: &[StoreKey]) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    fn erase_prefix(&self, _prefix: &StorePrefix) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
+    for ReadOnlyStorageAdapter<TStorage>
+{
+    async fn get(&self, key: &StoreKey) -> Result<MaybeAsyncBytes, StorageError> {
+        self.storage.get(key).await
+    }
+
+    async fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.storage.get_partial_values_key(key, byte_ranges).await
+    }
+
+    async fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.storage
+            .get_partial_values_key_batched(key, byte_ranges, max_batch)
+            .await
+    }
+
+    async fn get_partial_values(
+        &self,
+        key_ranges: &[StoreKeyRange],
+    ) -> Result<Vec<MaybeAsyncBytes>, StorageError> {
+        self.storage.get_partial_values(key_ranges).await
+    }
+
+    async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        self.storage.size_key(key).await
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage
+            .capabilities()
+            .with_writable(false)
+            .with_partial_write(false)
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + AsyncListableStorageTraits> AsyncListableStorageTraits
+    for ReadOnlyStorageAdapter<TStorage>
+{
+    async fn list(&self) -> Result<StoreKeys, StorageError> {
+        self.storage.list().await
+    }
+
+    async fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        self.storage.list_prefix(prefix).await
+    }
+
+    async fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        self.storage.list_dir(prefix).await
+    }
+
+    async fn size(&self) -> Result<u64, StorageError> {
+        self.storage.size().await
+    }
+
+    async fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        self.storage.size_prefix(prefix).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<TStorage: ?Sized + Send + Sync> AsyncWritableStorageTraits
+    for ReadOnlyStorageAdapter<TStorage>
+{
+    async fn set(&self, _key: &StoreKey, _value: AsyncBytes) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    async fn set_partial_values(
+        &self,
+        _key_offset_values: &[StoreKeyOffsetValue],
+    ) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    async fn set_many(&self, _key_values: &[(StoreKey, AsyncBytes)]) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        false
+    }
+
+    async fn erase(&self, _key: &StoreKey) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    async fn erase_values(&self, _keys: &[StoreKey]) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+
+    async fn erase_prefix(&self, _prefix: &StorePrefix) -> Result<(), StorageError> {
+        Err(StorageError::ReadOnly)
+    }
+}
@@ -8,8 +8,9 @@ use std::{
 use itertools::Itertools;
 
 use crate::{
-    byte_range::ByteRange, Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits,
-    StorageError, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes,
+    byte_range::{ByteRange, MaxBatch},
+    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes,
     StorePrefix, WritableStorageTraits,
 };
 
@@ -112,6 +113,28 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
         result
     }
 
+    fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        let result = self
+            .storage
+            .get_partial_values_key_batched(key, byte_ranges, max_batch);
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}get_partial_values_key_batched({key}, [{}], {max_batch:?}) -> len={:?}",
+            (self.prefix_func)(),
+            byte_ranges.iter().format(", "),
+            result.as_ref().map(|v| {
+                v.as_ref()
+                    .map_or(vec![], |v| v.iter().map(Bytes::len).collect_vec())
+            })
+        )?;
+        result
+    }
+
     fn get_partial_values(
         &self,
         key_ranges: &[StoreKeyRange],
@@ -138,6 +161,20 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
         )?;
         result
     }
+
+    fn digest_key(&self, key: &StoreKey, algorithm: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let result = self.storage.digest_key(key, algorithm);
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}digest_key({key}, {algorithm}) -> {result:?}",
+            (self.prefix_func)()
+        )?;
+        result
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage.capabilities()
+    }
 }
 
 impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits
@@ -221,6 +258,22 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
         result
     }
 
+    fn set_many(&self, key_values: &[(StoreKey, Bytes)]) -> Result<(), StorageError> {
+        let result = self.storage.set_many(key_values);
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}set_many([{}]) -> {result:?}",
+            (self.prefix_func)(),
+            key_values
+                .iter()
+                .format_with(", ", |(key, value), f| f(&format_args!(
+                    "{key} len={}",
+                    value.len()
+                )))
+        )?;
+        result
+    }
+
     fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -250,6 +303,10 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits
         result
     }
 
+    fn supports_set_partial(&self) -> bool {
+        self.storage.supports_set_partial()
+    }
+
     fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
         let result = self.storage.erase(key);
         writeln!(
@@ -319,6 +376,29 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
         result
     }
 
+    async fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        let result = self
+            .storage
+            .get_partial_values_key_batched(key, byte_ranges, max_batch)
+            .await;
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}get_partial_values_key_batched({key}, [{}], {max_batch:?}) -> len={:?}",
+            (self.prefix_func)(),
+            byte_ranges.iter().format(", "),
+            result.as_ref().map(|v| {
+                v.as_ref()
+                    .map_or(vec![], |v| v.iter().map(AsyncBytes::len).collect_vec())
+            })
+        )?;
+        result
+    }
+
     async fn get_partial_values(
         &self,
         key_ranges: &[StoreKeyRange],
@@ -346,6 +426,10 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
         )?;
         result
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.storage.capabilities()
+    }
 }
 
 #[cfg(feature = "async")]
@@ -432,6 +516,22 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         result
     }
 
+    async fn set_many(&self, key_values: &[(StoreKey, AsyncBytes)]) -> Result<(), StorageError> {
+        let result = self.storage.set_many(key_values).await;
+        writeln!(
+            self.handle.lock().unwrap(),
+            "{}set_many([{}]) -> {result:?}",
+            (self.prefix_func)(),
+            key_values
+                .iter()
+                .format_with(", ", |(key, value), f| f(&format_args!(
+                    "{key} len={}",
+                    value.len()
+                )))
+        )?;
+        result
+    }
+
     async fn set_partial_values(
         &self,
         key_offset_values: &[StoreKeyOffsetValue],
@@ -445,6 +545,10 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         result
     }
 
+    fn supports_set_partial(&self) -> bool {
+        self.storage.supports_set_partial()
+    }
+
     async fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
         let result = self.storage.erase(key).await;
         writeln!(
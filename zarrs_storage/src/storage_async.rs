@@ -4,8 +4,10 @@ use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
 
 use super::{
-    byte_range::ByteRange, AsyncBytes, MaybeAsyncBytes, StorageError, StoreKey,
-    StoreKeyOffsetValue, StoreKeyRange, StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
+    byte_range::{ByteRange, MaxBatch},
+    store_capabilities::{health_check_key, HEALTH_CHECK_VALUE},
+    AsyncBytes, MaybeAsyncBytes, StorageError, StoreCapabilities, StoreKey, StoreKeyOffsetValue,
+    StoreKeyRange, StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
 };
 
 /// Async readable storage traits.
@@ -38,6 +40,32 @@ pub trait AsyncReadableStorageTraits: Send + Sync {
         byte_ranges: &[ByteRange],
     ) -> Result<Option<Vec<AsyncBytes>>, StorageError>;
 
+    /// Retrieve partial bytes from a list of byte ranges for a store key, with at most `max_batch` byte ranges retrieved per request.
+    ///
+    /// Returns [`None`] if the key is not found.
+    ///
+    /// The default implementation calls [`get_partial_values_key`](AsyncReadableStorageTraits::get_partial_values_key) for each batch of byte ranges.
+    /// Stores that can retrieve multiple byte ranges with a single request should override this method.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StorageError`] if there is an underlying storage error.
+    async fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        let mut bytes = Vec::with_capacity(byte_ranges.len());
+        for byte_ranges in max_batch.batches(byte_ranges) {
+            let Some(bytes_batch) = self.get_partial_values_key(key, byte_ranges).await? else {
+                return Ok(None);
+            };
+            bytes.extend(bytes_batch);
+        }
+        Ok(Some(bytes))
+    }
+
     /// Retrieve partial bytes from a list of [`StoreKeyRange`].
     ///
     /// # Parameters
@@ -64,6 +92,14 @@ pub trait AsyncReadableStorageTraits: Send + Sync {
     /// Returns a [`StorageError`] if there is an underlying storage error.
     async fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError>;
 
+    /// Returns the [`StoreCapabilities`] of the store.
+    ///
+    /// The default implementation reports every capability as unsupported.
+    /// Stores should override this method to report their capabilities accurately.
+    fn capabilities(&self) -> StoreCapabilities {
+        StoreCapabilities::default()
+    }
+
     /// A utility method with the same input and output as [`get_partial_values`](AsyncReadableStorageTraits::get_partial_values) that internally calls [`get_partial_values_key`](AsyncReadableStorageTraits::get_partial_values_key) with byte ranges grouped by key.
     ///
     /// Readable storage can use this function in the implementation of [`get_partial_values`](AsyncReadableStorageTraits::get_partial_values) if that is optimal.
@@ -240,6 +276,32 @@ pub trait AsyncWritableStorageTraits: Send + Sync {
         key_offset_values: &[StoreKeyOffsetValue],
     ) -> Result<(), StorageError>;
 
+    /// Store a list of key-value pairs.
+    ///
+    /// The default implementation concurrently calls [`set`](AsyncWritableStorageTraits::set) for each key-value pair.
+    /// Stores that can write multiple values with fewer round trips should override this method.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] on failure to store.
+    async fn set_many(&self, key_values: &[(StoreKey, AsyncBytes)]) -> Result<(), StorageError> {
+        let futures_set = key_values
+            .iter()
+            .map(|(key, value)| self.set(key, value.clone()));
+        futures::future::join_all(futures_set)
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(())
+    }
+
+    /// Returns true if the store natively supports [`set_partial_values`](AsyncWritableStorageTraits::set_partial_values).
+    ///
+    /// This should be `false` if partial writes are implemented by retrieving, updating, and rewriting entire values (e.g. with [`async_store_set_partial_values`]).
+    /// Stores that also implement [`AsyncReadableStorageTraits`] should return [`StoreCapabilities::supports_partial_write`] of their [`capabilities`](AsyncReadableStorageTraits::capabilities), so that the two cannot disagree.
+    fn supports_set_partial(&self) -> bool {
+        false
+    }
+
     /// Erase a [`StoreKey`].
     ///
     /// Succeeds if the key does not exist.
@@ -273,6 +335,37 @@ pub trait AsyncWritableStorageTraits: Send + Sync {
 pub trait AsyncReadableWritableStorageTraits:
     AsyncReadableStorageTraits + AsyncWritableStorageTraits
 {
+    /// Check the health of the store by writing, reading, and erasing a probe key under `prefix`.
+    ///
+    /// Returns the round trip latency of the probe, or [`None`] if the store is read only.
+    /// The probe key is erased even if reading it back fails.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if the probe fails or the value read back does not match the value written.
+    async fn health_check(
+        &self,
+        prefix: &StorePrefix,
+    ) -> Result<Option<std::time::Duration>, StorageError> {
+        let key = health_check_key(prefix)?;
+        let start = std::time::Instant::now();
+        match self
+            .set(&key, AsyncBytes::from_static(HEALTH_CHECK_VALUE))
+            .await
+        {
+            Err(StorageError::ReadOnly) => return Ok(None),
+            result => result?,
+        }
+        let value = self.get(&key).await;
+        self.erase(&key).await?;
+        let latency = start.elapsed();
+        if value?.as_deref() == Some(HEALTH_CHECK_VALUE) {
+            Ok(Some(latency))
+        } else {
+            Err(StorageError::Other(format!(
+                "health check value read back from {key} does not match the value written"
+            )))
+        }
+    }
 }
 
 impl<T> AsyncReadableWritableStorageTraits for T where
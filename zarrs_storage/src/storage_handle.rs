@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use super::{
-    byte_range::ByteRange, Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits,
-    StorageError, StoreKey, StorePrefix, WritableStorageTraits,
+    byte_range::{ByteRange, MaxBatch},
+    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StorePrefix, WritableStorageTraits,
 };
 
 #[cfg(feature = "async")]
@@ -37,6 +38,16 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits for Storage
         self.0.get_partial_values_key(key, byte_ranges)
     }
 
+    fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        self.0
+            .get_partial_values_key_batched(key, byte_ranges, max_batch)
+    }
+
     fn get_partial_values(
         &self,
         key_ranges: &[super::StoreKeyRange],
@@ -47,6 +58,14 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits for Storage
     fn size_key(&self, key: &super::StoreKey) -> Result<Option<u64>, super::StorageError> {
         self.0.size_key(key)
     }
+
+    fn digest_key(&self, key: &StoreKey, algorithm: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        self.0.digest_key(key, algorithm)
+    }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.0.capabilities()
+    }
 }
 
 impl<TStorage: ?Sized + ListableStorageTraits> ListableStorageTraits for StorageHandle<TStorage> {
@@ -89,6 +108,14 @@ impl<TStorage: ?Sized + WritableStorageTraits> WritableStorageTraits for Storage
         self.0.set_partial_values(key_offset_values)
     }
 
+    fn set_many(&self, key_values: &[(super::StoreKey, Bytes)]) -> Result<(), super::StorageError> {
+        self.0.set_many(key_values)
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.0.supports_set_partial()
+    }
+
     fn erase(&self, key: &super::StoreKey) -> Result<(), super::StorageError> {
         self.0.erase(key)
     }
@@ -119,6 +146,17 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
         self.0.get_partial_values_key(key, byte_ranges).await
     }
 
+    async fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<AsyncBytes>>, StorageError> {
+        self.0
+            .get_partial_values_key_batched(key, byte_ranges, max_batch)
+            .await
+    }
+
     async fn get_partial_values(
         &self,
         key_ranges: &[super::StoreKeyRange],
@@ -129,6 +167,10 @@ impl<TStorage: ?Sized + AsyncReadableStorageTraits> AsyncReadableStorageTraits
     async fn size_key(&self, key: &super::StoreKey) -> Result<Option<u64>, super::StorageError> {
         self.0.size_key(key).await
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        self.0.capabilities()
+    }
 }
 
 #[cfg(feature = "async")]
@@ -179,6 +221,14 @@ impl<TStorage: ?Sized + AsyncWritableStorageTraits> AsyncWritableStorageTraits
         self.0.set_partial_values(key_offset_values).await
     }
 
+    async fn set_many(&self, key_values: &[(StoreKey, AsyncBytes)]) -> Result<(), StorageError> {
+        self.0.set_many(key_values).await
+    }
+
+    fn supports_set_partial(&self) -> bool {
+        self.0.supports_set_partial()
+    }
+
     async fn erase(&self, key: &super::StoreKey) -> Result<(), super::StorageError> {
         self.0.erase(key).await
     }
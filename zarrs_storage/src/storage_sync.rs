@@ -3,7 +3,9 @@ use std::sync::Arc;
 use itertools::Itertools;
 
 use super::{
-    byte_range::ByteRange, Bytes, MaybeBytes, StorageError, StoreKey, StoreKeyOffsetValue,
+    byte_range::{ByteRange, MaxBatch},
+    store_capabilities::{health_check_key, HEALTH_CHECK_VALUE},
+    Bytes, MaybeBytes, StorageError, StoreCapabilities, StoreKey, StoreKeyOffsetValue,
     StoreKeyRange, StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
 };
 
@@ -33,6 +35,31 @@ pub trait ReadableStorageTraits: Send + Sync {
         byte_ranges: &[ByteRange],
     ) -> Result<Option<Vec<Bytes>>, StorageError>;
 
+    /// Retrieve partial bytes from a list of byte ranges for a store key, with at most `max_batch` byte ranges retrieved per request.
+    ///
+    /// Returns [`None`] if the key is not found.
+    ///
+    /// The default implementation calls [`get_partial_values_key`](ReadableStorageTraits::get_partial_values_key) for each batch of byte ranges.
+    /// Stores that can retrieve multiple byte ranges with a single request should override this method.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if there is an underlying storage error.
+    fn get_partial_values_key_batched(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+        max_batch: MaxBatch,
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        let mut bytes = Vec::with_capacity(byte_ranges.len());
+        for byte_ranges in max_batch.batches(byte_ranges) {
+            let Some(bytes_batch) = self.get_partial_values_key(key, byte_ranges)? else {
+                return Ok(None);
+            };
+            bytes.extend(bytes_batch);
+        }
+        Ok(Some(bytes))
+    }
+
     /// Retrieve partial bytes from a list of [`StoreKeyRange`].
     ///
     /// # Parameters
@@ -58,6 +85,27 @@ pub trait ReadableStorageTraits: Send + Sync {
     /// Returns a [`StorageError`] if there is an underlying storage error.
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError>;
 
+    /// Return the digest of the value at `key` computed with the digest `algorithm` (see [`ValueDigestTraits::algorithm`](crate::ValueDigestTraits::algorithm)), without retrieving the value.
+    ///
+    /// Returns [`None`] if the key is not found or the store cannot provide a digest with `algorithm`.
+    /// The default implementation returns [`None`].
+    /// Stores that hold content hashes (e.g. an `ETag` or checksum) should override this method.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if there is an underlying storage error.
+    fn digest_key(&self, key: &StoreKey, algorithm: &str) -> Result<Option<Vec<u8>>, StorageError> {
+        let _ = (key, algorithm);
+        Ok(None)
+    }
+
+    /// Returns the [`StoreCapabilities`] of the store.
+    ///
+    /// The default implementation reports every capability as unsupported.
+    /// Stores should override this method to report their capabilities accurately.
+    fn capabilities(&self) -> StoreCapabilities {
+        StoreCapabilities::default()
+    }
+
     /// A utility method with the same input and output as [`get_partial_values`](ReadableStorageTraits::get_partial_values) that internally calls [`get_partial_values_key`](ReadableStorageTraits::get_partial_values_key) with byte ranges grouped by key.
     ///
     /// Readable storage can use this function in the implementation of [`get_partial_values`](ReadableStorageTraits::get_partial_values) if that is optimal.
@@ -220,6 +268,27 @@ pub trait WritableStorageTraits: Send + Sync {
         key_offset_values: &[StoreKeyOffsetValue],
     ) -> Result<(), StorageError>;
 
+    /// Store a list of key-value pairs.
+    ///
+    /// The default implementation calls [`set`](WritableStorageTraits::set) for each key-value pair.
+    /// Stores that can write multiple values with fewer round trips (e.g. with concurrent uploads) should override this method.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] on failure to store.
+    fn set_many(&self, key_values: &[(StoreKey, Bytes)]) -> Result<(), StorageError> {
+        key_values
+            .iter()
+            .try_for_each(|(key, value)| self.set(key, value.clone()))
+    }
+
+    /// Returns true if the store natively supports [`set_partial_values`](WritableStorageTraits::set_partial_values).
+    ///
+    /// This should be `false` if partial writes are implemented by retrieving, updating, and rewriting entire values (e.g. with [`store_set_partial_values`]).
+    /// Stores that also implement [`ReadableStorageTraits`] should return [`StoreCapabilities::supports_partial_write`] of their [`capabilities`](ReadableStorageTraits::capabilities), so that the two cannot disagree.
+    fn supports_set_partial(&self) -> bool {
+        false
+    }
+
     /// Erase a [`StoreKey`].
     ///
     /// # Errors
@@ -244,6 +313,35 @@ pub trait WritableStorageTraits: Send + Sync {
 
 /// A supertrait of [`ReadableStorageTraits`] and [`WritableStorageTraits`].
 pub trait ReadableWritableStorageTraits: ReadableStorageTraits + WritableStorageTraits {
+    /// Check the health of the store by writing, reading, and erasing a probe key under `prefix`.
+    ///
+    /// Returns the round trip latency of the probe, or [`None`] if the store is read only.
+    /// The probe key is erased even if reading it back fails.
+    ///
+    /// # Errors
+    /// Returns a [`StorageError`] if the probe fails or the value read back does not match the value written.
+    fn health_check(
+        &self,
+        prefix: &StorePrefix,
+    ) -> Result<Option<std::time::Duration>, StorageError> {
+        let key = health_check_key(prefix)?;
+        let start = std::time::Instant::now();
+        match self.set(&key, Bytes::from_static(HEALTH_CHECK_VALUE)) {
+            Err(StorageError::ReadOnly) => return Ok(None),
+            result => result?,
+        }
+        let value = self.get(&key);
+        self.erase(&key)?;
+        let latency = start.elapsed();
+        if value?.as_deref() == Some(HEALTH_CHECK_VALUE) {
+            Ok(Some(latency))
+        } else {
+            Err(StorageError::Other(format!(
+                "health check value read back from {key} does not match the value written"
+            )))
+        }
+    }
+
     // /// Returns the mutex for the store value at `key`.
     // ///
     // /// # Errors
@@ -6,7 +6,8 @@ use std::{
 use super::{byte_range::ByteRange, ReadableStorageTraits, StoreKey};
 
 /// Provides a [`Read`] interface to a storage value.
-#[derive(Clone)]
+///
+/// A clone shares the underlying storage but has an independent position.
 pub struct StorageValueIO<TStorage: ?Sized> {
     storage: Arc<TStorage>,
     key: StoreKey,
@@ -14,6 +15,17 @@ pub struct StorageValueIO<TStorage: ?Sized> {
     size: u64,
 }
 
+impl<TStorage: ?Sized> Clone for StorageValueIO<TStorage> {
+    fn clone(&self) -> Self {
+        Self {
+            storage: self.storage.clone(),
+            key: self.key.clone(),
+            pos: self.pos,
+            size: self.size,
+        }
+    }
+}
+
 impl<TStorage: ?Sized + ReadableStorageTraits> StorageValueIO<TStorage> {
     /// Create a new `StorageValueIO` for the `key` in `storage`.
     pub fn new(storage: Arc<TStorage>, key: StoreKey, size: u64) -> Self {
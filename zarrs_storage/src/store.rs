@@ -3,4 +3,4 @@
 //! See <https://zarr-specs.readthedocs.io/en/latest/v3/core/v3.0.html#id21>
 
 mod memory_store;
-pub use memory_store::MemoryStore;
+pub use memory_store::{MemoryStore, MemoryStoreSnapshot};
@@ -4,9 +4,10 @@ use parking_lot::RwLock; // TODO: std::sync::RwLock with Rust 1.78+
 use std::sync::Mutex;
 
 use crate::{
-    byte_range::{ByteOffset, ByteRange, InvalidByteRangeError},
-    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError, StoreKey,
-    StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix, WritableStorageTraits,
+    byte_range::{validate_byte_ranges, ByteOffset, ByteRange},
+    Bytes, ListableStorageTraits, MaybeBytes, ReadableStorageTraits, StorageError,
+    StoreCapabilities, StoreKey, StoreKeyOffsetValue, StoreKeys, StoreKeysPrefixes, StorePrefix,
+    WritableStorageTraits,
 };
 
 use std::{
@@ -14,13 +15,45 @@ use std::{
     sync::Arc,
 };
 
+type MemoryStoreDataMap = BTreeMap<StoreKey, Arc<RwLock<Bytes>>>;
+
 /// A synchronous in-memory store.
 #[derive(Debug)]
 pub struct MemoryStore {
-    data_map: Mutex<BTreeMap<StoreKey, Arc<RwLock<Vec<u8>>>>>,
+    data_map: Mutex<MemoryStoreDataMap>,
     // locks: StoreLocks,
 }
 
+/// A point-in-time snapshot of the contents of a [`MemoryStore`].
+///
+/// A snapshot shares value buffers with the store it was taken from, so it is cheap to create.
+/// See [`MemoryStore::snapshot`] and [`MemoryStore::restore`].
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStoreSnapshot {
+    data: BTreeMap<StoreKey, Bytes>,
+}
+
+impl MemoryStoreSnapshot {
+    /// Return the keys in the snapshot.
+    #[must_use]
+    pub fn keys(&self) -> StoreKeys {
+        self.data.keys().cloned().collect()
+    }
+
+    /// Return the value of `key` in the snapshot, or [`None`] if it does not exist.
+    #[must_use]
+    pub fn get(&self, key: &StoreKey) -> MaybeBytes {
+        self.data.get(key).cloned()
+    }
+
+    fn to_data_map(&self) -> MemoryStoreDataMap {
+        self.data
+            .iter()
+            .map(|(key, value)| (key.clone(), Arc::new(RwLock::new(value.clone()))))
+            .collect()
+    }
+}
+
 impl Default for MemoryStore {
     fn default() -> Self {
         Self::new()
@@ -36,6 +69,46 @@ impl MemoryStore {
         }
     }
 
+    /// Take a snapshot of the contents of the store.
+    ///
+    /// Values are not copied, the snapshot shares the reference counted [`Bytes`] buffers of the store.
+    /// Subsequent writes to the store do not affect the snapshot.
+    ///
+    /// # Panics
+    /// Panics if the store data mutex is poisoned.
+    #[must_use]
+    pub fn snapshot(&self) -> MemoryStoreSnapshot {
+        let data_map = self.data_map.lock().unwrap();
+        let data = data_map
+            .iter()
+            .map(|(key, value)| (key.clone(), value.read().clone()))
+            .collect();
+        MemoryStoreSnapshot { data }
+    }
+
+    /// Create a new store that is an independent copy of this store.
+    ///
+    /// The fork shares value buffers with this store copy-on-write, so only the key map is cloned.
+    /// Subsequent writes and erases to either store are not visible to the other.
+    ///
+    /// # Panics
+    /// Panics if the store data mutex is poisoned.
+    #[must_use]
+    pub fn fork(&self) -> Self {
+        Self::from(self.snapshot())
+    }
+
+    /// Restore the contents of the store to those of `snapshot`.
+    ///
+    /// Any keys written since the snapshot was taken are removed, and any erased keys are restored.
+    ///
+    /// # Panics
+    /// Panics if the store data mutex is poisoned.
+    pub fn restore(&self, snapshot: &MemoryStoreSnapshot) {
+        let data_map = snapshot.to_data_map();
+        *self.data_map.lock().unwrap() = data_map;
+    }
+
     fn set_impl(&self, key: &StoreKey, value: &[u8], offset: ByteOffset, truncate: bool) {
         let mut data_map = self.data_map.lock().unwrap();
         let data = data_map
@@ -47,16 +120,27 @@ impl MemoryStore {
 
         if offset == 0 && data.is_empty() {
             // fast path
-            *data = value.to_vec();
+            *data = Bytes::copy_from_slice(value);
         } else {
+            // Copy-on-write: the buffer is only reused if it is not shared (e.g. with a snapshot or fork)
+            let mut data_mut = Vec::from(std::mem::take(&mut *data));
             let length = usize::try_from(offset + value.len() as u64).unwrap();
-            if data.len() < length {
-                data.resize(length, 0);
+            if data_mut.len() < length {
+                data_mut.resize(length, 0);
             } else if truncate {
-                data.truncate(length);
+                data_mut.truncate(length);
             }
             let offset = usize::try_from(offset).unwrap();
-            data[offset..offset + value.len()].copy_from_slice(value);
+            data_mut[offset..offset + value.len()].copy_from_slice(value);
+            *data = data_mut.into();
+        }
+    }
+}
+
+impl From<MemoryStoreSnapshot> for MemoryStore {
+    fn from(snapshot: MemoryStoreSnapshot) -> Self {
+        Self {
+            data_map: Mutex::new(snapshot.to_data_map()),
         }
     }
 }
@@ -68,8 +152,8 @@ impl ReadableStorageTraits for MemoryStore {
         if let Some(data) = data {
             let data = data.clone();
             drop(data_map);
-            let data = data.read();
-            Ok(Some(data.clone().into()))
+            let data = data.read().clone();
+            Ok(Some(data))
         } else {
             Ok(None)
         }
@@ -87,14 +171,9 @@ impl ReadableStorageTraits for MemoryStore {
             drop(data_map);
             let data = data.read();
             let mut out = Vec::with_capacity(byte_ranges.len());
+            validate_byte_ranges(byte_ranges, data.len() as u64)?;
             for byte_range in byte_ranges {
-                let start = usize::try_from(byte_range.start(data.len() as u64)).unwrap();
-                let end = usize::try_from(byte_range.end(data.len() as u64)).unwrap();
-                if end > data.len() {
-                    return Err(InvalidByteRangeError::new(*byte_range, data.len() as u64).into());
-                }
-                let bytes = data[start..end].to_vec();
-                out.push(bytes.into());
+                out.push(data.slice(byte_range.to_range_usize(data.len() as u64)));
             }
             Ok(Some(out))
         } else {
@@ -108,11 +187,44 @@ impl ReadableStorageTraits for MemoryStore {
             .get(key)
             .map_or_else(|| Ok(None), |entry| Ok(Some(entry.read().len() as u64)))
     }
+
+    fn capabilities(&self) -> StoreCapabilities {
+        StoreCapabilities::new()
+            .with_writable(true)
+            .with_listing(true)
+            .with_partial_read(true)
+            .with_partial_write(true)
+            .with_atomic_put(true)
+    }
 }
 
 impl WritableStorageTraits for MemoryStore {
     fn set(&self, key: &StoreKey, value: Bytes) -> Result<(), StorageError> {
-        Self::set_impl(self, key, &value, 0, true);
+        let mut data_map = self.data_map.lock().unwrap();
+        let data = data_map
+            .entry(key.clone())
+            .or_insert_with(|| Arc::new(RwLock::default()))
+            .clone();
+        drop(data_map);
+        *data.write() = value;
+        Ok(())
+    }
+
+    fn set_many(&self, key_values: &[(StoreKey, Bytes)]) -> Result<(), StorageError> {
+        let mut data_map = self.data_map.lock().unwrap();
+        let data = key_values
+            .iter()
+            .map(|(key, _)| {
+                data_map
+                    .entry(key.clone())
+                    .or_insert_with(|| Arc::new(RwLock::default()))
+                    .clone()
+            })
+            .collect::<Vec<_>>();
+        drop(data_map);
+        for (data, (_, value)) in data.iter().zip(key_values) {
+            *data.write() = value.clone();
+        }
         Ok(())
     }
 
@@ -142,6 +254,10 @@ impl WritableStorageTraits for MemoryStore {
         Ok(())
     }
 
+    fn supports_set_partial(&self) -> bool {
+        self.capabilities().supports_partial_write()
+    }
+
     fn erase(&self, key: &StoreKey) -> Result<(), StorageError> {
         let mut data_map = self.data_map.lock().unwrap();
         data_map.remove(key);
@@ -221,7 +337,83 @@ mod tests {
         let store = MemoryStore::new();
         crate::store_test::store_write(&store)?;
         crate::store_test::store_read(&store)?;
+        crate::store_test::store_read_edge_byte_ranges(
+            &store,
+            &"a/b".try_into()?,
+            &"a/d/e".try_into()?,
+        )?;
+        crate::store_test::store_list(&store)?;
+        crate::store_test::store_health_check(&store)?;
+        let capabilities = store.capabilities();
+        assert!(capabilities.supports_listing());
+        assert!(capabilities.supports_partial_read());
+        assert!(capabilities.supports_partial_write());
+        assert!(capabilities.supports_atomic_put());
+        Ok(())
+    }
+
+    #[test]
+    fn memory_suffix_out_of_bounds() -> Result<(), Box<dyn Error>> {
+        let store = MemoryStore::new();
+        let a = StoreKey::new("a")?;
+        store.set(&a, vec![0, 1, 2].into())?;
+        assert!(matches!(
+            store.get_partial_values_key(&a, &[ByteRange::Suffix(4)]),
+            Err(StorageError::InvalidByteRangeError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn memory_fork() -> Result<(), Box<dyn Error>> {
+        let store = MemoryStore::new();
+        let a = StoreKey::new("a")?;
+        let b = StoreKey::new("b")?;
+        store.set(&a, vec![0, 1, 2].into())?;
+
+        let fork = store.fork();
+        assert_eq!(fork.get(&a)?, Some(vec![0, 1, 2].into()));
+
+        // Writes to the parent are not visible in the fork
+        store.set(&b, vec![3].into())?;
+        store.set_partial_values(&[StoreKeyOffsetValue::new(a.clone(), 1, &[9])])?;
+        assert_eq!(store.get(&a)?, Some(vec![0, 9, 2].into()));
+        assert_eq!(fork.get(&a)?, Some(vec![0, 1, 2].into()));
+        assert_eq!(fork.get(&b)?, None);
+
+        // Writes and erases to the fork are not visible in the parent
+        fork.set_partial_values(&[StoreKeyOffsetValue::new(a.clone(), 3, &[4])])?;
+        fork.set(&b, vec![5].into())?;
+        assert_eq!(fork.get(&a)?, Some(vec![0, 1, 2, 4].into()));
+        fork.erase(&a)?;
+        assert_eq!(fork.get(&a)?, None);
+        assert_eq!(store.get(&a)?, Some(vec![0, 9, 2].into()));
+        assert_eq!(store.get(&b)?, Some(vec![3].into()));
+        Ok(())
+    }
+
+    #[test]
+    fn memory_snapshot_restore() -> Result<(), Box<dyn Error>> {
+        let store = MemoryStore::new();
+        crate::store_test::store_write(&store)?;
+        let snapshot = store.snapshot();
+        assert_eq!(snapshot.keys(), store.list()?);
+
+        let key = StoreKey::new("a/b")?;
+        let value = store.get(&key)?;
+        assert!(value.is_some());
+        store.erase_prefix(&StorePrefix::root())?;
+        store.set(&StoreKey::new("c")?, vec![0].into())?;
+        assert_eq!(store.list()?, vec![StoreKey::new("c")?]);
+        assert_eq!(store.get(&key)?, None);
+        assert_eq!(snapshot.get(&key), value);
+
+        store.restore(&snapshot);
+        assert_eq!(store.get(&key)?, value);
+        assert_eq!(store.get(&StoreKey::new("c")?)?, None);
+        crate::store_test::store_read(&store)?;
         crate::store_test::store_list(&store)?;
+        crate::store_test::store_health_check(&store)?;
         Ok(())
     }
 }
@@ -0,0 +1,114 @@
+use crate::{StoreKey, StoreKeyError, StorePrefix};
+
+/// The capabilities of a store.
+///
+/// Capabilities are reported by [`ReadableStorageTraits::capabilities`](crate::ReadableStorageTraits::capabilities) so that applications can probe a store before a long running job.
+/// A capability that is not supported natively may still be emulated (e.g. partial reads by retrieving entire values), but it is likely to be less efficient.
+///
+/// The default capabilities are conservative, with every capability unsupported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct StoreCapabilities {
+    writable: bool,
+    listing: bool,
+    partial_read: bool,
+    partial_write: bool,
+    atomic_put: bool,
+}
+
+impl StoreCapabilities {
+    /// Create new store capabilities with every capability unsupported.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            writable: false,
+            listing: false,
+            partial_read: false,
+            partial_write: false,
+            atomic_put: false,
+        }
+    }
+
+    /// Set whether the store is writable.
+    #[must_use]
+    pub const fn with_writable(mut self, writable: bool) -> Self {
+        self.writable = writable;
+        self
+    }
+
+    /// Set whether the store supports listing.
+    #[must_use]
+    pub const fn with_listing(mut self, listing: bool) -> Self {
+        self.listing = listing;
+        self
+    }
+
+    /// Set whether the store natively supports reading byte ranges of a value.
+    #[must_use]
+    pub const fn with_partial_read(mut self, partial_read: bool) -> Self {
+        self.partial_read = partial_read;
+        self
+    }
+
+    /// Set whether the store natively supports writing to a byte offset of a value.
+    #[must_use]
+    pub const fn with_partial_write(mut self, partial_write: bool) -> Self {
+        self.partial_write = partial_write;
+        self
+    }
+
+    /// Set whether writing a value is atomic, such that a concurrent reader never observes a partially written value.
+    #[must_use]
+    pub const fn with_atomic_put(mut self, atomic_put: bool) -> Self {
+        self.atomic_put = atomic_put;
+        self
+    }
+
+    /// Returns true if the store is writable.
+    #[must_use]
+    pub const fn writable(&self) -> bool {
+        self.writable
+    }
+
+    /// Returns true if the store supports listing.
+    #[must_use]
+    pub const fn supports_listing(&self) -> bool {
+        self.listing
+    }
+
+    /// Returns true if the store natively supports reading byte ranges of a value.
+    #[must_use]
+    pub const fn supports_partial_read(&self) -> bool {
+        self.partial_read
+    }
+
+    /// Returns true if the store natively supports writing to a byte offset of a value.
+    #[must_use]
+    pub const fn supports_partial_write(&self) -> bool {
+        self.partial_write
+    }
+
+    /// Returns true if writing a value is atomic.
+    #[must_use]
+    pub const fn supports_atomic_put(&self) -> bool {
+        self.atomic_put
+    }
+}
+
+/// The value written to the probe key of a health check.
+pub(crate) const HEALTH_CHECK_VALUE: &[u8] = b"zarrs health check";
+
+/// Returns a probe key for a health check under `prefix`.
+///
+/// The key is unique to the process and time, so concurrent health checks do not interfere.
+pub(crate) fn health_check_key(prefix: &StorePrefix) -> Result<StoreKey, StoreKeyError> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    StoreKey::new(format!(
+        "{}.zarrs_health_check.{}.{nanos}",
+        prefix.as_str(),
+        std::process::id()
+    ))
+}
@@ -1,12 +1,16 @@
-use std::error::Error;
+use std::{error::Error, num::NonZeroUsize};
 
 use crate::{
-    byte_range::ByteRange, ListableStorageTraits, ReadableStorageTraits, StoreKeyOffsetValue,
-    StoreKeyRange, StorePrefix, WritableStorageTraits,
+    byte_range::{ByteRange, MaxBatch},
+    Bytes, ListableStorageTraits, ReadableStorageTraits, ReadableWritableListableStorageTraits,
+    StorageError, StoreKey, StoreKeyOffsetValue, StoreKeyRange, StorePrefix, WritableStorageTraits,
 };
 
 #[cfg(feature = "async")]
-use crate::{AsyncListableStorageTraits, AsyncReadableStorageTraits, AsyncWritableStorageTraits};
+use crate::{
+    AsyncListableStorageTraits, AsyncReadableStorageTraits,
+    AsyncReadableWritableListableStorageTraits, AsyncWritableStorageTraits,
+};
 
 #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// Create a store with the following data
@@ -33,8 +37,10 @@ pub fn store_write<T: WritableStorageTraits>(store: &T) -> Result<(), Box<dyn Er
 
     store.set(&"a/c".try_into()?, vec![0].into())?;
     store.set(&"a/d/e".try_into()?, vec![].into())?;
-    store.set(&"a/f/g".try_into()?, vec![].into())?;
-    store.set(&"a/f/h".try_into()?, vec![].into())?;
+    store.set_many(&[
+        ("a/f/g".try_into()?, vec![].into()),
+        ("a/f/h".try_into()?, vec![].into()),
+    ])?;
     store.set(&"i/j/k".try_into()?, vec![0, 1].into())?;
 
     store.set(&"erase".try_into()?, vec![].into())?;
@@ -71,6 +77,29 @@ pub fn store_read<T: ReadableStorageTraits>(store: &T) -> Result<(), Box<dyn Err
         )?,
         Some(vec![vec![1].into(), vec![3].into()])
     );
+    assert_eq!(
+        store.get_partial_values_key_batched(
+            &"a/b".try_into()?,
+            &[
+                ByteRange::FromStart(1, Some(1)),
+                ByteRange::Suffix(1),
+                ByteRange::FromStart(0, None)
+            ],
+            MaxBatch::Limit(NonZeroUsize::new(2).unwrap())
+        )?,
+        Some(vec![
+            vec![1].into(),
+            vec![3].into(),
+            vec![0, 1, 2, 3].into()
+        ])
+    );
+    assert!(store
+        .get_partial_values_key_batched(
+            &"notfound".try_into()?,
+            &[ByteRange::Suffix(1)],
+            MaxBatch::Unlimited
+        )?
+        .is_none());
     assert_eq!(
         store.get_partial_values(&[
             StoreKeyRange::new("a/b".try_into()?, ByteRange::FromStart(1, None)),
@@ -93,6 +122,107 @@ pub fn store_read<T: ReadableStorageTraits>(store: &T) -> Result<(), Box<dyn Err
     Ok(())
 }
 
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+/// Read edge case byte ranges from the store and check that they are either empty or return an [`InvalidByteRangeError`](crate::byte_range::InvalidByteRangeError).
+///
+/// The value of `key` must be `[0, 1, 2, 3]` and the value of `empty_key` must be empty (e.g. `a/b` and `a/d/e` after [`store_write`]).
+pub fn store_read_edge_byte_ranges<T: ReadableStorageTraits>(
+    store: &T,
+    key: &StoreKey,
+    empty_key: &StoreKey,
+) -> Result<(), Box<dyn Error>> {
+    let (valid, valid_empty, invalid, invalid_empty) = edge_byte_ranges();
+    assert_eq!(store.get_partial_values_key(key, &valid.0)?, Some(valid.1));
+    assert_eq!(
+        store.get_partial_values_key(empty_key, &valid_empty.0)?,
+        Some(valid_empty.1)
+    );
+    for (store_key, byte_range, bytes_len) in invalid
+        .into_iter()
+        .map(|byte_range| (key, byte_range, 4))
+        .chain(
+            invalid_empty
+                .into_iter()
+                .map(|byte_range| (empty_key, byte_range, 0)),
+        )
+    {
+        check_invalid_byte_range(
+            store.get_partial_values_key(store_key, &[ByteRange::Suffix(0), byte_range]),
+            byte_range,
+            bytes_len,
+        );
+    }
+    Ok(())
+}
+
+type EdgeByteRanges = (
+    (Vec<ByteRange>, Vec<Bytes>),
+    (Vec<ByteRange>, Vec<Bytes>),
+    Vec<ByteRange>,
+    Vec<ByteRange>,
+);
+
+/// Returns valid and invalid byte ranges for the value `[0, 1, 2, 3]` and an empty value.
+fn edge_byte_ranges() -> EdgeByteRanges {
+    let valid = (
+        vec![
+            ByteRange::FromStart(0, Some(0)),
+            ByteRange::FromStart(2, Some(0)),
+            ByteRange::FromStart(4, Some(0)),
+            ByteRange::FromStart(4, None),
+            ByteRange::Suffix(0),
+            ByteRange::FromStart(1, Some(2)),
+            ByteRange::Suffix(4),
+        ],
+        vec![
+            Bytes::new(),
+            Bytes::new(),
+            Bytes::new(),
+            Bytes::new(),
+            Bytes::new(),
+            vec![1, 2].into(),
+            vec![0, 1, 2, 3].into(),
+        ],
+    );
+    let valid_empty = (
+        vec![
+            ByteRange::FromStart(0, None),
+            ByteRange::FromStart(0, Some(0)),
+            ByteRange::Suffix(0),
+        ],
+        vec![Bytes::new(), Bytes::new(), Bytes::new()],
+    );
+    let invalid = vec![
+        ByteRange::FromStart(1, Some(4)),
+        ByteRange::FromStart(4, Some(1)),
+        ByteRange::FromStart(5, None),
+        ByteRange::FromStart(5, Some(0)),
+        ByteRange::Suffix(5),
+        ByteRange::FromStart(u64::MAX, Some(1)),
+        ByteRange::FromStart(1, Some(u64::MAX)),
+    ];
+    let invalid_empty = vec![
+        ByteRange::FromStart(0, Some(1)),
+        ByteRange::FromStart(1, None),
+        ByteRange::Suffix(1),
+    ];
+    (valid, valid_empty, invalid, invalid_empty)
+}
+
+fn check_invalid_byte_range(
+    result: Result<Option<Vec<Bytes>>, StorageError>,
+    byte_range: ByteRange,
+    bytes_len: u64,
+) {
+    match result {
+        Err(StorageError::InvalidByteRangeError(err)) => {
+            assert_eq!(err.byte_range(), byte_range);
+            assert_eq!(err.bytes_len(), bytes_len);
+        }
+        result => panic!("expected an invalid byte range error for {byte_range}, got {result:?}"),
+    }
+}
+
 #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// List the store and check the data matches the expected values after [`store_write`].
 pub fn store_list<T: ListableStorageTraits>(store: &T) -> Result<(), Box<dyn Error>> {
@@ -150,6 +280,24 @@ pub fn store_list<T: ListableStorageTraits>(store: &T) -> Result<(), Box<dyn Err
     Ok(())
 }
 
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+/// Check the health of a writable store and check that the probe key is cleaned up.
+pub fn store_health_check<T: ReadableWritableListableStorageTraits>(
+    store: &T,
+) -> Result<(), Box<dyn Error>> {
+    assert!(store.capabilities().writable());
+    assert_eq!(
+        store.supports_set_partial(),
+        store.capabilities().supports_partial_write()
+    );
+    let keys = store.list()?;
+    for prefix in [StorePrefix::root(), "a/".try_into()?] {
+        assert!(store.health_check(&prefix)?.is_some());
+        assert_eq!(store.list()?, keys);
+    }
+    Ok(())
+}
+
 #[cfg(feature = "async")]
 #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// Create a store with the following data
@@ -186,8 +334,12 @@ pub async fn async_store_write<T: AsyncWritableStorageTraits>(
 
     store.set(&"a/c".try_into()?, vec![0].into()).await?;
     store.set(&"a/d/e".try_into()?, vec![].into()).await?;
-    store.set(&"a/f/g".try_into()?, vec![].into()).await?;
-    store.set(&"a/f/h".try_into()?, vec![].into()).await?;
+    store
+        .set_many(&[
+            ("a/f/g".try_into()?, vec![].into()),
+            ("a/f/h".try_into()?, vec![].into()),
+        ])
+        .await?;
     store.set(&"i/j/k".try_into()?, vec![0, 1].into()).await?;
 
     store.set(&"erase".try_into()?, vec![].into()).await?;
@@ -239,6 +391,32 @@ pub async fn async_store_read<T: AsyncReadableStorageTraits>(
             .await?,
         Some(vec![vec![1].into(), vec![3].into()])
     );
+    assert_eq!(
+        store
+            .get_partial_values_key_batched(
+                &"a/b".try_into()?,
+                &[
+                    ByteRange::FromStart(1, Some(1)),
+                    ByteRange::Suffix(1),
+                    ByteRange::FromStart(0, None)
+                ],
+                MaxBatch::Limit(NonZeroUsize::new(2).unwrap())
+            )
+            .await?,
+        Some(vec![
+            vec![1].into(),
+            vec![3].into(),
+            vec![0, 1, 2, 3].into()
+        ])
+    );
+    assert!(store
+        .get_partial_values_key_batched(
+            &"notfound".try_into()?,
+            &[ByteRange::Suffix(1)],
+            MaxBatch::Unlimited
+        )
+        .await?
+        .is_none());
     assert_eq!(
         store
             .get_partial_values(&[
@@ -264,6 +442,47 @@ pub async fn async_store_read<T: AsyncReadableStorageTraits>(
     Ok(())
 }
 
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+/// Read edge case byte ranges from the store and check that they are either empty or return an [`InvalidByteRangeError`](crate::byte_range::InvalidByteRangeError).
+///
+/// The value of `key` must be `[0, 1, 2, 3]` and the value of `empty_key` must be empty (e.g. `a/b` and `a/d/e` after [`async_store_write`]).
+pub async fn async_store_read_edge_byte_ranges<T: AsyncReadableStorageTraits>(
+    store: &T,
+    key: &StoreKey,
+    empty_key: &StoreKey,
+) -> Result<(), Box<dyn Error>> {
+    let (valid, valid_empty, invalid, invalid_empty) = edge_byte_ranges();
+    assert_eq!(
+        store.get_partial_values_key(key, &valid.0).await?,
+        Some(valid.1)
+    );
+    assert_eq!(
+        store
+            .get_partial_values_key(empty_key, &valid_empty.0)
+            .await?,
+        Some(valid_empty.1)
+    );
+    for (store_key, byte_range, bytes_len) in invalid
+        .into_iter()
+        .map(|byte_range| (key, byte_range, 4))
+        .chain(
+            invalid_empty
+                .into_iter()
+                .map(|byte_range| (empty_key, byte_range, 0)),
+        )
+    {
+        check_invalid_byte_range(
+            store
+                .get_partial_values_key(store_key, &[ByteRange::Suffix(0), byte_range])
+                .await,
+            byte_range,
+            bytes_len,
+        );
+    }
+    Ok(())
+}
+
 #[cfg(feature = "async")]
 #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
 /// List the store and check the data matches the expected values after [`async_store_write`].
@@ -323,3 +542,22 @@ pub async fn async_store_list<T: AsyncListableStorageTraits>(
     }
     Ok(())
 }
+
+#[cfg(feature = "async")]
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+/// Check the health of a writable store and check that the probe key is cleaned up.
+pub async fn async_store_health_check<T: AsyncReadableWritableListableStorageTraits>(
+    store: &T,
+) -> Result<(), Box<dyn Error>> {
+    assert!(store.capabilities().writable());
+    assert_eq!(
+        store.supports_set_partial(),
+        store.capabilities().supports_partial_write()
+    );
+    let keys = store.list().await?;
+    for prefix in [StorePrefix::root(), "a/".try_into()?] {
+        assert!(store.health_check(&prefix).await?.is_some());
+        assert_eq!(store.list().await?, keys);
+    }
+    Ok(())
+}
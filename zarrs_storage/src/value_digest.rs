@@ -0,0 +1,12 @@
+/// A digest of store values, such as a content hash or checksum.
+///
+/// A digest is used to compare a value with a stored value without retrieving it, if the store can provide the digest of a stored value with [`ReadableStorageTraits::digest_key`](crate::ReadableStorageTraits::digest_key).
+pub trait ValueDigestTraits: Send + Sync {
+    /// The name of the digest algorithm (e.g. `md5`, `crc32c`).
+    ///
+    /// This is passed to [`ReadableStorageTraits::digest_key`](crate::ReadableStorageTraits::digest_key).
+    fn algorithm(&self) -> &str;
+
+    /// Compute the digest of `value`.
+    fn digest(&self, value: &[u8]) -> Vec<u8>;
+}
@@ -0,0 +1,505 @@
+//! A storage adapter for `tar` files for the [`zarrs`](https://docs.rs/zarrs/latest/zarrs/index.html) crate.
+//!
+//! ```
+//! # use std::path::PathBuf;
+//! # use std::sync::Arc;
+//! use zarrs_storage::StoreKey;
+//! use zarrs_filesystem::FilesystemStore;
+//! use zarrs_tar::TarStorageAdapter;
+//!
+//! let fs_root = PathBuf::from("/path/to/a/directory");
+//! # let fs_root = PathBuf::from("tests/");
+//! let fs_store = Arc::new(FilesystemStore::new(&fs_root)?);
+//! let tar_key = StoreKey::new("zarr.tar")?;
+//! let tar_store = Arc::new(TarStorageAdapter::new(fs_store, tar_key)?);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ## Licence
+//! `zarrs_tar` is licensed under either of
+//! - the Apache License, Version 2.0 [LICENSE-APACHE](https://docs.rs/crate/zarrs_tar/latest/source/LICENCE-APACHE) or <http://www.apache.org/licenses/LICENSE-2.0> or
+//! - the MIT license [LICENSE-MIT](https://docs.rs/crate/zarrs_tar/latest/source/LICENCE-MIT) or <http://opensource.org/licenses/MIT>, at your option.
+
+use zarrs_storage::{
+    byte_range::{validate_byte_ranges, ByteRange},
+    Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError, StorageValueIO, StoreKey,
+    StoreKeys, StoreKeysPrefixes, StorePrefix,
+};
+
+use tar::Archive;
+use thiserror::Error;
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    sync::Arc,
+};
+
+/// The location of the payload of a file in a tar archive.
+#[derive(Debug, Clone, Copy)]
+struct TarEntry {
+    /// The offset of the payload from the start of the tar file.
+    offset: u64,
+    /// The size of the payload.
+    size: u64,
+}
+
+/// A tar storage adapter.
+///
+/// An index of the files in the tar archive is built from the tar headers when the adapter is created.
+/// Reads are then forwarded to the underlying storage as byte range requests into the tar file.
+///
+/// If the archive contains multiple entries with the same path, the last entry takes precedence.
+pub struct TarStorageAdapter<TStorage: ?Sized> {
+    storage: Arc<TStorage>,
+    key: StoreKey,
+    size: u64,
+    entries: BTreeMap<StoreKey, TarEntry>,
+    prefixes: BTreeSet<StorePrefix>,
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> TarStorageAdapter<TStorage> {
+    /// Create a new tar storage adapter.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TarStorageAdapterCreateError`] if the root path of the store is not a valid tar file.
+    pub fn new(
+        storage: Arc<TStorage>,
+        key: StoreKey,
+    ) -> Result<Self, TarStorageAdapterCreateError> {
+        Self::new_with_path(storage, key, "")
+    }
+
+    /// Create a new tar storage adapter to `path` within the tar file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TarStorageAdapterCreateError`] if the root path of the store is not a valid tar file.
+    pub fn new_with_path<T: Into<PathBuf>>(
+        storage: Arc<TStorage>,
+        key: StoreKey,
+        path: T,
+    ) -> Result<Self, TarStorageAdapterCreateError> {
+        let mut tar_path = tar_path_normalise(&path.into().to_string_lossy());
+        if !tar_path.is_empty() && !tar_path.ends_with('/') {
+            tar_path.push('/');
+        }
+        let size = storage
+            .size_key(&key)?
+            .ok_or::<TarStorageAdapterCreateError>(
+                StorageError::UnknownKeySize(key.clone()).into(),
+            )?;
+
+        let storage_io = StorageValueIO::new(storage.clone(), key.clone(), size);
+        let mut archive = Archive::new(storage_io);
+        let mut entries = BTreeMap::new();
+        let mut prefixes = BTreeSet::new();
+        for entry in archive.entries_with_seek()? {
+            let entry = entry?;
+            let name = String::from_utf8_lossy(&entry.path_bytes()).to_string();
+            let name = tar_path_normalise(&name);
+            let Some(name) = name.strip_prefix(&tar_path).filter(|name| !name.is_empty()) else {
+                continue;
+            };
+
+            let entry_type = entry.header().entry_type();
+            if entry_type.is_dir() {
+                let name = name.strip_suffix('/').unwrap_or(name);
+                if let Ok(prefix) = StorePrefix::new(format!("{name}/")) {
+                    insert_prefix_and_parents(&mut prefixes, prefix);
+                }
+            } else if entry_type.is_file() || entry_type.is_contiguous() {
+                if let Ok(key) = StoreKey::new(name) {
+                    insert_prefix_and_parents(&mut prefixes, key.parent());
+                    entries.insert(
+                        key,
+                        TarEntry {
+                            offset: entry.raw_file_position(),
+                            size: entry.size(),
+                        },
+                    );
+                }
+            }
+        }
+
+        Ok(Self {
+            storage,
+            key,
+            size,
+            entries,
+            prefixes,
+        })
+    }
+}
+
+/// Normalise a path in a tar archive to a store key or store prefix string.
+///
+/// Path separators are converted to `/` and any leading `./` or `/` is removed.
+fn tar_path_normalise(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let mut path = path.as_str();
+    loop {
+        if let Some(stripped) = path.strip_prefix("./") {
+            path = stripped;
+        } else if let Some(stripped) = path.strip_prefix('/') {
+            path = stripped;
+        } else {
+            break;
+        }
+    }
+    if path == "." {
+        String::new()
+    } else {
+        path.to_string()
+    }
+}
+
+/// Insert `prefix` and all of its parents (excluding the root prefix) into `prefixes`.
+fn insert_prefix_and_parents(prefixes: &mut BTreeSet<StorePrefix>, prefix: StorePrefix) {
+    let mut prefix = Some(prefix);
+    while let Some(current) = prefix {
+        if current.as_str().is_empty() || !prefixes.insert(current.clone()) {
+            break;
+        }
+        prefix = current.parent();
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
+    for TarStorageAdapter<TStorage>
+{
+    fn get_partial_values_key(
+        &self,
+        key: &StoreKey,
+        byte_ranges: &[ByteRange],
+    ) -> Result<Option<Vec<Bytes>>, StorageError> {
+        let Some(entry) = self.entries.get(key) else {
+            return Ok(None);
+        };
+
+        validate_byte_ranges(byte_ranges, entry.size)?;
+        let mut byte_ranges_tar = Vec::with_capacity(byte_ranges.len());
+        for byte_range in byte_ranges {
+            byte_ranges_tar.push(ByteRange::FromStart(
+                entry.offset + byte_range.start(entry.size),
+                Some(byte_range.length(entry.size)),
+            ));
+        }
+
+        self.storage
+            .get_partial_values_key(&self.key, &byte_ranges_tar)?
+            .map_or_else(
+                || {
+                    Err(StorageError::Other(format!(
+                        "the tar file {} no longer exists",
+                        self.key
+                    )))
+                },
+                |bytes| Ok(Some(bytes)),
+            )
+    }
+
+    fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
+        Ok(self.entries.get(key).map(|entry| entry.size))
+    }
+}
+
+impl<TStorage: ?Sized + ReadableStorageTraits> ListableStorageTraits
+    for TarStorageAdapter<TStorage>
+{
+    fn list(&self) -> Result<StoreKeys, StorageError> {
+        Ok(self.entries.keys().cloned().collect())
+    }
+
+    fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
+        Ok(self
+            .entries
+            .keys()
+            .filter(|key| key.has_prefix(prefix))
+            .cloned()
+            .collect())
+    }
+
+    fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
+        let keys: StoreKeys = self
+            .entries
+            .keys()
+            .filter(|key| &key.parent() == prefix)
+            .cloned()
+            .collect();
+        let prefixes = self
+            .prefixes
+            .iter()
+            .filter(|child| child.parent().as_ref() == Some(prefix))
+            .cloned()
+            .collect();
+        Ok(StoreKeysPrefixes::new(keys, prefixes))
+    }
+
+    fn size(&self) -> Result<u64, StorageError> {
+        Ok(self.size)
+    }
+
+    fn size_prefix(&self, prefix: &StorePrefix) -> Result<u64, StorageError> {
+        Ok(self
+            .entries
+            .iter()
+            .filter(|(key, _)| key.has_prefix(prefix))
+            .map(|(_, entry)| entry.size)
+            .sum())
+    }
+}
+
+/// A tar store creation error.
+#[derive(Debug, Error)]
+pub enum TarStorageAdapterCreateError {
+    /// An IO error.
+    #[error(transparent)]
+    IOError(#[from] std::io::Error),
+    /// A storage error.
+    #[error(transparent)]
+    StorageError(#[from] StorageError),
+}
+
+#[cfg(test)]
+mod tests {
+    use walkdir::WalkDir;
+
+    use zarrs_filesystem::FilesystemStore;
+    use zarrs_storage::WritableStorageTraits;
+
+    use super::*;
+    use std::{error::Error, fs::File, path::Path};
+
+    fn data_c() -> Vec<u8> {
+        (0..1000u16)
+            .map(|i| u8::try_from(i % 256).unwrap())
+            .collect()
+    }
+
+    fn tar_write(path: &Path) -> Result<(), Box<dyn Error>> {
+        let tmp_path = tempfile::TempDir::new()?;
+        let tmp_path = tmp_path.path();
+        let store = FilesystemStore::new(tmp_path)?.sorted();
+        store.set(&"a/b/zarr.json".try_into()?, vec![0, 1, 2, 3].into())?;
+        store.set(&"a/c/zarr.json".try_into()?, vec![].into())?;
+        store.set(&"a/d/e/zarr.json".try_into()?, vec![].into())?;
+        store.set(&"a/f/g/zarr.json".try_into()?, vec![].into())?;
+        store.set(&"a/f/h/zarr.json".try_into()?, vec![].into())?;
+        store.set(&"b/zarr.json".try_into()?, vec![].into())?;
+        store.set(&"b/c/d/zarr.json".try_into()?, vec![].into())?;
+        store.set(&"c/zarr.json".try_into()?, data_c().into())?;
+        std::fs::create_dir_all(tmp_path.join("d/e"))?;
+
+        let mut builder = tar::Builder::new(File::create(path)?);
+        for entry in WalkDir::new(tmp_path)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(std::result::Result::ok)
+        {
+            let name = entry.path().strip_prefix(tmp_path)?;
+            if entry.path().is_file() {
+                builder.append_path_with_name(entry.path(), name)?;
+            } else if !name.as_os_str().is_empty() {
+                builder.append_dir(name, entry.path())?;
+            }
+        }
+        builder.finish()?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn tar_root() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut path = path.path().to_path_buf();
+        let store = FilesystemStore::new(path.clone())?;
+
+        path.push("test.tar");
+        tar_write(&path)?;
+
+        let store = Arc::new(TarStorageAdapter::new(
+            store.into(),
+            StoreKey::new("test.tar")?,
+        )?);
+
+        assert_eq!(
+            store.list()?,
+            &[
+                "a/b/zarr.json".try_into()?,
+                "a/c/zarr.json".try_into()?,
+                "a/d/e/zarr.json".try_into()?,
+                "a/f/g/zarr.json".try_into()?,
+                "a/f/h/zarr.json".try_into()?,
+                "b/c/d/zarr.json".try_into()?,
+                "b/zarr.json".try_into()?,
+                "c/zarr.json".try_into()?,
+            ]
+        );
+        assert_eq!(
+            store.list_prefix(&"a/".try_into()?)?,
+            &[
+                "a/b/zarr.json".try_into()?,
+                "a/c/zarr.json".try_into()?,
+                "a/d/e/zarr.json".try_into()?,
+                "a/f/g/zarr.json".try_into()?,
+                "a/f/h/zarr.json".try_into()?,
+            ]
+        );
+        assert_eq!(
+            store.list_prefix(&"a/d/".try_into()?)?,
+            &["a/d/e/zarr.json".try_into()?]
+        );
+        assert_eq!(store.list_prefix(&"".try_into()?)?, store.list()?);
+
+        let list = store.list_dir(&"a/".try_into()?)?;
+        assert_eq!(list.keys(), &[]);
+        assert_eq!(
+            list.prefixes(),
+            &[
+                "a/b/".try_into()?,
+                "a/c/".try_into()?,
+                "a/d/".try_into()?,
+                "a/f/".try_into()?,
+            ]
+        );
+
+        // Empty directories
+        let list = store.list_dir(&"".try_into()?)?;
+        assert_eq!(list.keys(), &[]);
+        assert_eq!(
+            list.prefixes(),
+            &[
+                "a/".try_into()?,
+                "b/".try_into()?,
+                "c/".try_into()?,
+                "d/".try_into()?,
+            ]
+        );
+        let list = store.list_dir(&"d/".try_into()?)?;
+        assert_eq!(list.keys(), &[]);
+        assert_eq!(list.prefixes(), &["d/e/".try_into()?]);
+
+        assert_eq!(
+            store.get(&"a/b/zarr.json".try_into()?)?.unwrap(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(store.size_key(&"a/b/zarr.json".try_into()?)?, Some(4));
+
+        // Empty files
+        assert_eq!(
+            store.get(&"a/c/zarr.json".try_into()?)?.unwrap(),
+            Vec::<u8>::new().as_slice()
+        );
+        assert_eq!(store.size_key(&"a/c/zarr.json".try_into()?)?, Some(0));
+        assert!(store.get(&"a/zarr.json".try_into()?)?.is_none());
+
+        // Partial reads
+        let key: StoreKey = "c/zarr.json".try_into()?;
+        let expected = data_c();
+        assert_eq!(
+            store.get_partial_values_key(
+                &key,
+                &[
+                    ByteRange::FromStart(3, Some(5)),
+                    ByteRange::FromStart(990, None),
+                    ByteRange::Suffix(600)
+                ]
+            )?,
+            Some(vec![
+                expected[3..8].to_vec().into(),
+                expected[990..].to_vec().into(),
+                expected[400..].to_vec().into(),
+            ])
+        );
+        assert!(store
+            .get_partial_values_key(&key, &[ByteRange::FromStart(999, Some(2))])
+            .is_err());
+        assert!(store
+            .get_partial_values_key(&key, &[ByteRange::Suffix(1001)])
+            .is_err());
+        assert_eq!(store.size_prefix(&"c/".try_into()?)?, 1000);
+        assert_eq!(store.size_prefix(&"a/".try_into()?)?, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn tar_edge_byte_ranges() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut path = path.path().to_path_buf();
+        let store = FilesystemStore::new(path.clone())?;
+
+        path.push("test.tar");
+        tar_write(&path)?;
+
+        let store = TarStorageAdapter::new(store.into(), StoreKey::new("test.tar")?)?;
+        zarrs_storage::store_test::store_read_edge_byte_ranges(
+            &store,
+            &"a/b/zarr.json".try_into()?,
+            &"a/c/zarr.json".try_into()?,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn tar_path() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut path = path.path().to_path_buf();
+        let store = FilesystemStore::new(path.clone())?;
+        path.push("test.tar");
+        tar_write(&path)?;
+
+        let store = Arc::new(TarStorageAdapter::new_with_path(
+            store.into(),
+            StoreKey::new("test.tar")?,
+            "a/",
+        )?);
+
+        assert_eq!(
+            store.list()?,
+            &[
+                "b/zarr.json".try_into()?,
+                "c/zarr.json".try_into()?,
+                "d/e/zarr.json".try_into()?,
+                "f/g/zarr.json".try_into()?,
+                "f/h/zarr.json".try_into()?,
+            ]
+        );
+        assert_eq!(store.list_prefix(&"a/".try_into()?)?, &[]);
+        assert_eq!(
+            store.list_prefix(&"d/".try_into()?)?,
+            &["d/e/zarr.json".try_into()?]
+        );
+        assert_eq!(store.list_prefix(&"".try_into()?)?, store.list()?);
+
+        let list = store.list_dir(&"".try_into()?)?;
+        assert_eq!(list.keys(), &[]);
+        assert_eq!(
+            list.prefixes(),
+            &[
+                "b/".try_into()?,
+                "c/".try_into()?,
+                "d/".try_into()?,
+                "f/".try_into()?,
+            ]
+        );
+
+        assert_eq!(
+            store.get(&"b/zarr.json".try_into()?)?.unwrap(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            store.get(&"c/zarr.json".try_into()?)?.unwrap(),
+            Vec::<u8>::new().as_slice()
+        );
+        assert!(store.get(&"a/b/zarr.json".try_into()?)?.is_none());
+
+        Ok(())
+    }
+}
@@ -21,23 +21,35 @@
 //! - the MIT license [LICENSE-MIT](https://docs.rs/crate/zarrs_zip/latest/source/LICENCE-MIT) or <http://opensource.org/licenses/MIT>, at your option.
 
 use zarrs_storage::{
-    byte_range::{extract_byte_ranges_read, ByteRange},
+    byte_range::{extract_byte_ranges_read, validate_byte_ranges, ByteRange},
     Bytes, ListableStorageTraits, ReadableStorageTraits, StorageError, StorageValueIO, StoreKey,
     StoreKeys, StoreKeysPrefixes, StorePrefix, StorePrefixes,
 };
 
 use itertools::Itertools;
-use std::sync::Mutex;
+use std::sync::{Condvar, Mutex};
 use thiserror::Error;
-use zip::{result::ZipError, ZipArchive};
-
-use std::{path::PathBuf, sync::Arc};
+use zip::ZipArchive;
+
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    ops::{Deref, DerefMut},
+    path::PathBuf,
+    sync::Arc,
+};
 
 /// A zip storage adapter.
+///
+/// Values can be read concurrently.
+/// Each read checks out a handle to the zip archive from a pool, which is grown lazily up to the [maximum number of concurrent readers](ZipStorageAdapter::with_max_readers).
+/// Each handle has an independent position in the zip file, and all handles share the central directory of the zip archive which is read once on creation.
 pub struct ZipStorageAdapter<TStorage: ?Sized> {
     size: u64,
-    zip_archive: Mutex<ZipArchive<StorageValueIO<TStorage>>>,
+    zip_archive_pool: ZipArchivePool<TStorage>,
     zip_path: PathBuf,
+    /// The index and uncompressed size of each file in the zip archive by name.
+    files: HashMap<String, (usize, u64)>,
 }
 
 impl<TStorage: ?Sized + ReadableStorageTraits> ZipStorageAdapter<TStorage> {
@@ -70,17 +82,43 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ZipStorageAdapter<TStorage> {
                 StorageError::UnknownKeySize(key.clone()).into(),
             )?;
         let storage_io = StorageValueIO::new(storage, key, size);
-        let zip_archive = Mutex::new(
-            ZipArchive::new(storage_io)
-                .map_err(|err| ZipStorageAdapterCreateError::ZipError(err.to_string()))?,
-        );
+        let mut zip_archive = ZipArchive::new(storage_io)
+            .map_err(|err| ZipStorageAdapterCreateError::ZipError(err.to_string()))?;
+        let mut files = HashMap::with_capacity(zip_archive.len());
+        for index in 0..zip_archive.len() {
+            let file = zip_archive
+                .by_index(index)
+                .map_err(|err| ZipStorageAdapterCreateError::ZipError(err.to_string()))?;
+            if file.is_file() {
+                files.insert(file.name().to_string(), (index, file.size()));
+            }
+        }
+        let max_readers = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
         Ok(Self {
             size,
-            zip_archive,
+            zip_archive_pool: ZipArchivePool::new(zip_archive, max_readers),
             zip_path,
+            files,
         })
     }
 
+    /// Set the maximum number of concurrent readers of the zip file.
+    ///
+    /// Reads beyond this limit wait for a reader to become available.
+    /// Defaults to the available parallelism, which matches the default number of threads in the `rayon` global thread pool.
+    /// A value of 0 is treated as 1.
+    #[must_use]
+    pub fn with_max_readers(mut self, max_readers: usize) -> Self {
+        self.zip_archive_pool.max_readers = max_readers.max(1);
+        self
+    }
+
+    /// Return the maximum number of concurrent readers of the zip file.
+    #[must_use]
+    pub fn max_readers(&self) -> usize {
+        self.zip_archive_pool.max_readers
+    }
+
     fn key_str_to_zip_path(&self, key: &str) -> String {
         let mut zip_name = self.zip_path.clone();
         zip_name.push(key);
@@ -92,24 +130,26 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ZipStorageAdapter<TStorage> {
         zip_name.to_string()
     }
 
+    fn file(&self, key: &StoreKey) -> Option<(usize, u64)> {
+        self.files
+            .get(&self.key_str_to_zip_path(key.as_str()))
+            .copied()
+    }
+
     fn get_impl(
         &self,
         key: &StoreKey,
         byte_ranges: &[ByteRange],
     ) -> Result<Option<Vec<Bytes>>, StorageError> {
-        let mut zip_archive = self.zip_archive.lock().unwrap();
-        let mut file = {
-            let zip_file = zip_archive.by_name(&self.key_str_to_zip_path(key.as_str()));
-            match zip_file {
-                Ok(zip_file) => zip_file,
-                Err(err) => match err {
-                    ZipError::FileNotFound => return Ok(None),
-                    _ => return Err(StorageError::Other(err.to_string())),
-                },
-            }
+        let Some((index, size)) = self.file(key) else {
+            return Ok(None);
         };
-        let size = file.size();
+        validate_byte_ranges(byte_ranges, size)?;
 
+        let mut zip_archive = self.zip_archive_pool.checkout();
+        let mut file = zip_archive
+            .by_index(index)
+            .map_err(|err| StorageError::Other(err.to_string()))?;
         let out = extract_byte_ranges_read(&mut file, size, byte_ranges)?
             .into_iter()
             .map(Bytes::from)
@@ -123,6 +163,97 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ZipStorageAdapter<TStorage> {
     }
 }
 
+/// A pool of handles to a zip archive.
+///
+/// The handles are clones of the zip archive, which share its central directory.
+struct ZipArchivePool<TStorage: ?Sized> {
+    zip_archive: ZipArchive<StorageValueIO<TStorage>>,
+    state: Mutex<ZipArchivePoolState<TStorage>>,
+    available: Condvar,
+    max_readers: usize,
+}
+
+struct ZipArchivePoolState<TStorage: ?Sized> {
+    /// The handles that are not checked out.
+    idle: Vec<ZipArchive<StorageValueIO<TStorage>>>,
+    /// The number of handles that have been created.
+    handles: usize,
+}
+
+impl<TStorage: ?Sized> ZipArchivePool<TStorage> {
+    fn new(zip_archive: ZipArchive<StorageValueIO<TStorage>>, max_readers: usize) -> Self {
+        Self {
+            zip_archive,
+            state: Mutex::new(ZipArchivePoolState {
+                idle: Vec::new(),
+                handles: 0,
+            }),
+            available: Condvar::new(),
+            max_readers,
+        }
+    }
+
+    /// Check out a handle, creating one if none are idle and the maximum number of readers has not been reached.
+    ///
+    /// Blocks until a handle is available.
+    fn checkout(&self) -> ZipArchiveHandle<'_, TStorage> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(zip_archive) = state.idle.pop() {
+                return ZipArchiveHandle::new(self, zip_archive);
+            }
+            if state.handles < self.max_readers {
+                state.handles += 1;
+                return ZipArchiveHandle::new(self, self.zip_archive.clone());
+            }
+            state = self.available.wait(state).unwrap();
+        }
+    }
+}
+
+/// A zip archive handle checked out of a [`ZipArchivePool`], which is returned to the pool when dropped.
+struct ZipArchiveHandle<'a, TStorage: ?Sized> {
+    pool: &'a ZipArchivePool<TStorage>,
+    zip_archive: Option<ZipArchive<StorageValueIO<TStorage>>>,
+}
+
+impl<'a, TStorage: ?Sized> ZipArchiveHandle<'a, TStorage> {
+    fn new(
+        pool: &'a ZipArchivePool<TStorage>,
+        zip_archive: ZipArchive<StorageValueIO<TStorage>>,
+    ) -> Self {
+        Self {
+            pool,
+            zip_archive: Some(zip_archive),
+        }
+    }
+}
+
+impl<TStorage: ?Sized> Deref for ZipArchiveHandle<'_, TStorage> {
+    type Target = ZipArchive<StorageValueIO<TStorage>>;
+
+    fn deref(&self) -> &Self::Target {
+        self.zip_archive.as_ref().unwrap()
+    }
+}
+
+impl<TStorage: ?Sized> DerefMut for ZipArchiveHandle<'_, TStorage> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.zip_archive.as_mut().unwrap()
+    }
+}
+
+impl<TStorage: ?Sized> Drop for ZipArchiveHandle<'_, TStorage> {
+    fn drop(&mut self) {
+        if let (Some(zip_archive), Ok(mut state)) =
+            (self.zip_archive.take(), self.pool.state.lock())
+        {
+            state.idle.push(zip_archive);
+            self.pool.available.notify_one();
+        }
+    }
+}
+
 impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
     for ZipStorageAdapter<TStorage>
 {
@@ -135,15 +266,7 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ReadableStorageTraits
     }
 
     fn size_key(&self, key: &StoreKey) -> Result<Option<u64>, StorageError> {
-        let mut zip_archive = self.zip_archive.lock().unwrap();
-        let file = zip_archive.by_name(key.as_str());
-        match file {
-            Ok(file) => Ok(Some(file.compressed_size())),
-            Err(err) => match err {
-                ZipError::FileNotFound => Ok(None),
-                _ => Err(StorageError::Other(err.to_string())),
-            },
-        }
+        Ok(self.file(key).map(|(_index, size)| size))
     }
 }
 
@@ -152,9 +275,8 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ListableStorageTraits
 {
     fn list(&self) -> Result<StoreKeys, StorageError> {
         Ok(self
+            .zip_archive_pool
             .zip_archive
-            .lock()
-            .unwrap()
             .file_names()
             .filter_map(|name| self.zip_file_strip_prefix(name))
             .filter_map(|v| StoreKey::try_from(v).ok())
@@ -163,36 +285,22 @@ impl<TStorage: ?Sized + ReadableStorageTraits> ListableStorageTraits
     }
 
     fn list_prefix(&self, prefix: &StorePrefix) -> Result<StoreKeys, StorageError> {
-        let mut zip_archive = self.zip_archive.lock().unwrap();
-        let file_names: Vec<String> = zip_archive
-            .file_names()
+        Ok(self
+            .files
+            .keys()
             .filter_map(|name| self.zip_file_strip_prefix(name))
-            .map(std::string::ToString::to_string)
-            .collect();
-        Ok(file_names
-            .into_iter()
-            .filter_map(|name| {
-                if name.starts_with(prefix.as_str()) {
-                    if let Ok(file) = zip_archive.by_name(&self.key_str_to_zip_path(&name)) {
-                        if file.is_file() {
-                            let name = name.strip_suffix('/').unwrap_or(&name);
-                            if let Ok(store_key) = StoreKey::try_from(name) {
-                                return Some(store_key);
-                            }
-                        }
-                    }
-                }
-                None
-            })
+            .filter(|name| name.starts_with(prefix.as_str()))
+            .filter_map(|name| StoreKey::try_from(name).ok())
             .sorted()
             .collect())
     }
 
     fn list_dir(&self, prefix: &StorePrefix) -> Result<StoreKeysPrefixes, StorageError> {
-        let zip_archive = self.zip_archive.lock().unwrap();
         let mut keys: StoreKeys = vec![];
         let mut prefixes: StorePrefixes = vec![];
-        for name in zip_archive
+        for name in self
+            .zip_archive_pool
+            .zip_archive
             .file_names()
             .filter_map(|name| self.zip_file_strip_prefix(name))
         {
@@ -295,7 +403,7 @@ mod tests {
         Result::Ok(())
     }
 
-    fn zip_write(path: &Path) -> Result<(), Box<dyn Error>> {
+    fn zip_write(path: &Path, method: zip::CompressionMethod) -> Result<(), Box<dyn Error>> {
         let tmp_path = tempfile::TempDir::new()?;
         let tmp_path = tmp_path.path();
         let store = FilesystemStore::new(tmp_path)?.sorted();
@@ -315,7 +423,7 @@ mod tests {
             &mut walkdir.into_iter().filter_map(std::result::Result::ok),
             tmp_path.to_str().unwrap(),
             file,
-            zip::CompressionMethod::Stored,
+            method,
         )?;
 
         Ok(())
@@ -329,7 +437,7 @@ mod tests {
         let store = FilesystemStore::new(path.clone())?;
 
         path.push("test.zip");
-        zip_write(&path)?;
+        zip_write(&path, zip::CompressionMethod::Stored)?;
 
         let store = Arc::new(ZipStorageAdapter::new(
             store.into(),
@@ -398,6 +506,12 @@ mod tests {
             Vec::<u8>::new().as_slice()
         );
 
+        zarrs_storage::store_test::store_read_edge_byte_ranges(
+            store.as_ref(),
+            &"a/b/zarr.json".try_into()?,
+            &"a/c/zarr.json".try_into()?,
+        )?;
+
         Ok(())
     }
 
@@ -408,7 +522,7 @@ mod tests {
         let mut path = path.path().to_path_buf();
         let store = FilesystemStore::new(path.clone())?;
         path.push("test.zip");
-        zip_write(&path)?;
+        zip_write(&path, zip::CompressionMethod::Stored)?;
 
         let store = Arc::new(ZipStorageAdapter::new_with_path(
             store.into(),
@@ -462,4 +576,116 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn zip_size_key() -> Result<(), Box<dyn Error>> {
+        for method in [
+            zip::CompressionMethod::Stored,
+            zip::CompressionMethod::Deflated,
+        ] {
+            let path = tempfile::TempDir::new()?;
+            let mut path = path.path().to_path_buf();
+            let store = FilesystemStore::new(path.clone())?;
+            path.push("test.zip");
+            zip_write(&path, method)?;
+
+            let store = ZipStorageAdapter::new(store.into(), StoreKey::new("test.zip")?)?;
+            for key in store.list()? {
+                assert_eq!(
+                    store.size_key(&key)?,
+                    Some(store.get(&key)?.unwrap().len() as u64)
+                );
+            }
+            assert_eq!(store.size_key(&"a/b/zarr.json".try_into()?)?, Some(4));
+            assert_eq!(store.size_key(&"a/zarr.json".try_into()?)?, None);
+            assert_eq!(store.size_prefix(&"a/".try_into()?)?, 4);
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn zip_path_size_key() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let mut path = path.path().to_path_buf();
+        let store = FilesystemStore::new(path.clone())?;
+        path.push("test.zip");
+        zip_write(&path, zip::CompressionMethod::Deflated)?;
+
+        let store =
+            ZipStorageAdapter::new_with_path(store.into(), StoreKey::new("test.zip")?, "a/")?;
+        assert_eq!(store.size_key(&"b/zarr.json".try_into()?)?, Some(4));
+        assert_eq!(store.size_key(&"c/zarr.json".try_into()?)?, Some(0));
+        assert_eq!(store.size_key(&"a/b/zarr.json".try_into()?)?, None);
+        assert_eq!(store.size_prefix(&"".try_into()?)?, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn zip_concurrent_reads() -> Result<(), Box<dyn Error>> {
+        let path = tempfile::TempDir::new()?;
+        let data_path = path.path().join("data");
+        let store = FilesystemStore::new(&data_path)?;
+        let keys = (0..256)
+            .map(|i| StoreKey::new(format!("array/c/{}/{}", i / 16, i % 16)))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (i, key) in (0u16..).zip(&keys) {
+            let value: Vec<u8> = (0..500u16)
+                .flat_map(|j| (i * 31 + j).to_le_bytes())
+                .collect();
+            store.set(key, value.into())?;
+        }
+        zip_dir(
+            &mut WalkDir::new(&data_path)
+                .into_iter()
+                .filter_map(std::result::Result::ok),
+            data_path.to_str().unwrap(),
+            File::create(path.path().join("test.zip"))?,
+            zip::CompressionMethod::Stored,
+        )?;
+
+        let store = FilesystemStore::new(path.path())?;
+        let store =
+            ZipStorageAdapter::new(store.into(), StoreKey::new("test.zip")?)?.with_max_readers(3);
+        assert_eq!(store.max_readers(), 3);
+        let byte_ranges = [ByteRange::FromStart(10, Some(100)), ByteRange::Suffix(10)];
+        let serial = keys
+            .iter()
+            .map(|key| {
+                Ok((
+                    store.get(key)?.unwrap(),
+                    store.get_partial_values_key(key, &byte_ranges)?.unwrap(),
+                ))
+            })
+            .collect::<Result<Vec<_>, StorageError>>()?;
+
+        std::thread::scope(|scope| {
+            let threads: Vec<_> = (0..8)
+                .map(|thread| {
+                    let (store, keys, serial) = (&store, &keys, &serial);
+                    scope.spawn(move || {
+                        for i in (0..keys.len()).map(|i| (i + thread * 32) % keys.len()) {
+                            assert_eq!(store.get(&keys[i]).unwrap().unwrap(), serial[i].0);
+                            assert_eq!(
+                                store
+                                    .get_partial_values_key(&keys[i], &byte_ranges)
+                                    .unwrap()
+                                    .unwrap(),
+                                serial[i].1
+                            );
+                        }
+                    })
+                })
+                .collect();
+            for thread in threads {
+                thread.join().unwrap();
+            }
+        });
+        assert!(store.zip_archive_pool.state.lock().unwrap().handles <= 3);
+
+        Ok(())
+    }
 }